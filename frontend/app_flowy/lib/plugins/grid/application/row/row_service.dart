@@ -36,10 +36,14 @@ class RowFFIService {
     return DatabaseEventDeleteRow(payload).send();
   }
 
-  Future<Either<Unit, FlowyError>> duplicateRow(String rowId) {
-    final payload = RowIdPB.create()
+  Future<Either<RowPB, FlowyError>> duplicateRow(
+    String rowId, {
+    bool includeComments = false,
+  }) {
+    final payload = DuplicateRowPayloadPB.create()
       ..databaseId = databaseId
-      ..rowId = rowId;
+      ..rowId = rowId
+      ..includeComments = includeComments;
 
     return DatabaseEventDuplicateRow(payload).send();
   }
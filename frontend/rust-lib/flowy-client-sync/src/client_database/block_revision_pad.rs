@@ -1,7 +1,9 @@
 use crate::errors::{SyncError, SyncResult};
 use crate::util::cal_diff;
 use flowy_sync::util::make_operations_from_revisions;
-use grid_model::{gen_block_id, gen_row_id, CellRevision, DatabaseBlockRevision, RowChangeset, RowRevision};
+use grid_model::{
+    gen_block_id, gen_row_id, CellCommentRevision, CellRevision, DatabaseBlockRevision, RowChangeset, RowRevision,
+};
 use lib_infra::util::md5;
 use lib_ot::core::{DeltaBuilder, DeltaOperations, EmptyAttributes, OperationTransform};
 use revision_model::Revision;
@@ -84,6 +86,25 @@ impl GridBlockRevisionPad {
         })
     }
 
+    /// Inserts the rows at the `index` in one change, keeping their order. The rows are appended to
+    /// the block if the `index` is None or it's out of bounds.
+    pub fn add_row_revs(
+        &mut self,
+        rows: Vec<RowRevision>,
+        index: Option<usize>,
+    ) -> SyncResult<Option<GridBlockRevisionChangeset>> {
+        if rows.is_empty() {
+            return Ok(None);
+        }
+        self.modify(|block_rows| {
+            let index = index
+                .filter(|index| *index <= block_rows.len())
+                .unwrap_or(block_rows.len());
+            block_rows.splice(index..index, rows.into_iter().map(Arc::new));
+            Ok(Some(()))
+        })
+    }
+
     pub fn delete_rows(&mut self, row_ids: Vec<Cow<'_, String>>) -> SyncResult<Option<GridBlockRevisionChangeset>> {
         self.modify(|rows| {
             rows.retain(|row| !row_ids.contains(&Cow::Borrowed(&row.id)));
@@ -157,29 +178,141 @@ impl GridBlockRevisionPad {
 
     pub fn update_row(&mut self, changeset: RowChangeset) -> SyncResult<Option<GridBlockRevisionChangeset>> {
         let row_id = changeset.row_id.clone();
-        self.modify_row(&row_id, |row| {
+        self.modify_row(&row_id, |row| Ok(apply_row_changeset(row, changeset)))
+    }
+
+    /// Applies the changesets of the rows in one revision. The changesets of the rows that can't be
+    /// found are ignored.
+    pub fn update_rows(&mut self, changesets: Vec<RowChangeset>) -> SyncResult<Option<GridBlockRevisionChangeset>> {
+        self.modify(|row_revs| {
             let mut is_changed = None;
-            if let Some(height) = changeset.height {
-                row.height = height;
-                is_changed = Some(());
+            for changeset in changesets {
+                match row_revs.iter_mut().find(|row_rev| row_rev.id == changeset.row_id) {
+                    None => tracing::warn!("[BlockMetaPad]: Can't find any row with id: {}", changeset.row_id),
+                    Some(row_rev) => {
+                        if apply_row_changeset(Arc::make_mut(row_rev), changeset).is_some() {
+                            is_changed = Some(());
+                        }
+                    }
+                }
             }
+            Ok(is_changed)
+        })
+    }
 
-            if let Some(visibility) = changeset.visibility {
-                row.visibility = visibility;
-                is_changed = Some(());
+    /// Removes the cells and the comments whose field ids are not contained in `field_ids` from
+    /// all the rows.
+    pub fn retain_cells(&mut self, field_ids: &[String]) -> SyncResult<Option<GridBlockRevisionChangeset>> {
+        self.modify(|row_revs| {
+            let mut is_changed = None;
+            for row_rev in row_revs.iter_mut() {
+                if row_rev
+                    .cells
+                    .keys()
+                    .chain(row_rev.comments.keys())
+                    .any(|field_id| !field_ids.contains(field_id))
+                {
+                    let row_rev = Arc::make_mut(row_rev);
+                    row_rev.cells.retain(|field_id, _| field_ids.contains(field_id));
+                    row_rev.comments.retain(|field_id, _| field_ids.contains(field_id));
+                    is_changed = Some(());
+                }
             }
+            Ok(is_changed)
+        })
+    }
 
-            if !changeset.cell_by_field_id.is_empty() {
-                is_changed = Some(());
-                changeset.cell_by_field_id.into_iter().for_each(|(field_id, cell)| {
-                    row.cells.insert(field_id, cell);
-                })
+    /// Copies the cells of the field with `from_field_id` to the field with `to_field_id` in all
+    /// the rows, the existing cells of the `to_field_id` are replaced.
+    pub fn copy_cells(
+        &mut self,
+        from_field_id: &str,
+        to_field_id: &str,
+    ) -> SyncResult<Option<GridBlockRevisionChangeset>> {
+        self.modify(|row_revs| {
+            let mut is_changed = None;
+            for row_rev in row_revs.iter_mut() {
+                if let Some(cell_rev) = row_rev.cells.get(from_field_id).cloned() {
+                    Arc::make_mut(row_rev).cells.insert(to_field_id.to_owned(), cell_rev);
+                    is_changed = Some(());
+                }
             }
+            Ok(is_changed)
+        })
+    }
+
+    /// Removes the cells of the fields from all the rows in one revision. The comments of the cells
+    /// are kept.
+    pub fn delete_cells(&mut self, field_ids: &[String]) -> SyncResult<Option<GridBlockRevisionChangeset>> {
+        self.modify(|row_revs| {
+            let mut is_changed = None;
+            for row_rev in row_revs.iter_mut() {
+                if field_ids.iter().any(|field_id| row_rev.cells.contains_key(field_id)) {
+                    let row_rev = Arc::make_mut(row_rev);
+                    for field_id in field_ids {
+                        row_rev.cells.remove(field_id);
+                    }
+                    is_changed = Some(());
+                }
+            }
+            Ok(is_changed)
+        })
+    }
 
+    /// Removes the comments of the fields from all the rows in one revision.
+    pub fn delete_comments(&mut self, field_ids: &[String]) -> SyncResult<Option<GridBlockRevisionChangeset>> {
+        self.modify(|row_revs| {
+            let mut is_changed = None;
+            for row_rev in row_revs.iter_mut() {
+                if field_ids.iter().any(|field_id| row_rev.comments.contains_key(field_id)) {
+                    let row_rev = Arc::make_mut(row_rev);
+                    for field_id in field_ids {
+                        row_rev.comments.remove(field_id);
+                    }
+                    is_changed = Some(());
+                }
+            }
             Ok(is_changed)
         })
     }
 
+    /// Appends the comment to the comments of the cell.
+    pub fn add_cell_comment(
+        &mut self,
+        row_id: &str,
+        field_id: &str,
+        comment: CellCommentRevision,
+    ) -> SyncResult<Option<GridBlockRevisionChangeset>> {
+        self.modify_row(row_id, |row_rev| {
+            row_rev
+                .comments
+                .entry(field_id.to_owned())
+                .or_insert_with(Vec::new)
+                .push(comment);
+            Ok(Some(()))
+        })
+    }
+
+    /// Removes the comment from the comments of the cell. Nothing is changed if the cell doesn't
+    /// have the comment.
+    pub fn delete_cell_comment(
+        &mut self,
+        row_id: &str,
+        field_id: &str,
+        comment_id: &str,
+    ) -> SyncResult<Option<GridBlockRevisionChangeset>> {
+        self.modify_row(row_id, |row_rev| match row_rev.comments.get_mut(field_id) {
+            Some(comments) if comments.iter().any(|comment| comment.id == comment_id) => {
+                comments.retain(|comment| comment.id != comment_id);
+                if comments.is_empty() {
+                    row_rev.comments.shift_remove(field_id);
+                }
+                Ok(Some(()))
+            }
+            _ => Ok(None),
+        })
+    }
+
     pub fn move_row(&mut self, row_id: &str, from: usize, to: usize) -> SyncResult<Option<GridBlockRevisionChangeset>> {
         self.modify(|row_revs| {
             if let Some(position) = row_revs.iter().position(|row_rev| row_rev.id == row_id) {
@@ -246,6 +379,51 @@ impl GridBlockRevisionPad {
     }
 }
 
+fn apply_row_changeset(row: &mut RowRevision, changeset: RowChangeset) -> Option<()> {
+    let mut is_changed = None;
+    if let Some(height) = changeset.height {
+        row.height = height;
+        is_changed = Some(());
+    }
+
+    if let Some(visibility) = changeset.visibility {
+        row.visibility = visibility;
+        is_changed = Some(());
+    }
+
+    if !changeset.cell_by_field_id.is_empty() {
+        is_changed = Some(());
+        changeset.cell_by_field_id.into_iter().for_each(|(field_id, cell)| {
+            row.cells.insert(field_id, cell);
+        })
+    }
+
+    if let Some(modified_at) = changeset.modified_at {
+        row.modified_at = modified_at;
+        is_changed = Some(());
+    }
+
+    if let Some(meta) = changeset.meta {
+        if row.meta.apply_changeset(meta).is_some() {
+            is_changed = Some(());
+        }
+    }
+
+    if let Some(document_id) = changeset.document_id {
+        let document_id = if document_id.is_empty() {
+            None
+        } else {
+            Some(document_id)
+        };
+        if row.document_id != document_id {
+            row.document_id = document_id;
+            is_changed = Some(());
+        }
+    }
+
+    is_changed
+}
+
 pub struct GridBlockRevisionChangeset {
     pub operations: GridBlockOperations,
     /// md5: the md5 of the grid after applying the change.
@@ -282,7 +460,7 @@ impl std::default::Default for GridBlockRevisionPad {
 #[cfg(test)]
 mod tests {
     use crate::client_database::{GridBlockOperations, GridBlockRevisionPad};
-    use grid_model::{RowChangeset, RowRevision};
+    use grid_model::{CellCommentRevision, CellRevision, RowChangeset, RowRevision};
 
     use std::borrow::Cow;
 
@@ -295,6 +473,11 @@ mod tests {
             cells: Default::default(),
             height: 0,
             visibility: false,
+            created_at: 0,
+            modified_at: 0,
+            comments: Default::default(),
+            meta: Default::default(),
+            document_id: None,
         };
 
         let change = pad.add_row_rev(row.clone(), None).unwrap().unwrap();
@@ -342,9 +525,30 @@ mod tests {
             cells: Default::default(),
             height: 0,
             visibility: false,
+            created_at: 0,
+            modified_at: 0,
+            comments: Default::default(),
+            meta: Default::default(),
+            document_id: None,
         }
     }
 
+    #[test]
+    fn block_meta_add_rows() {
+        let mut pad = test_pad();
+        let row_1 = test_row_rev("1", &pad);
+        let row_2 = test_row_rev("2", &pad);
+        let row_3 = test_row_rev("3", &pad);
+        let row_4 = test_row_rev("4", &pad);
+
+        pad.add_row_revs(vec![row_2, row_4], None).unwrap().unwrap();
+        pad.add_row_revs(vec![row_1], Some(0)).unwrap().unwrap();
+        pad.add_row_revs(vec![row_3], Some(2)).unwrap().unwrap();
+        let row_ids = pad.rows.iter().map(|row| row.id.as_str()).collect::<Vec<&str>>();
+        assert_eq!(row_ids, vec!["1", "2", "3", "4"]);
+        assert!(pad.add_row_revs(vec![], None).unwrap().is_none());
+    }
+
     #[test]
     fn block_meta_insert_row2() {
         let mut pad = test_pad();
@@ -387,6 +591,11 @@ mod tests {
             cells: Default::default(),
             height: 0,
             visibility: false,
+            created_at: 0,
+            modified_at: 0,
+            comments: Default::default(),
+            meta: Default::default(),
+            document_id: None,
         };
 
         let _ = pad.add_row_rev(row.clone(), None).unwrap().unwrap();
@@ -408,6 +617,11 @@ mod tests {
             cells: Default::default(),
             height: 0,
             visibility: false,
+            created_at: 0,
+            modified_at: 0,
+            comments: Default::default(),
+            meta: Default::default(),
+            document_id: None,
         };
 
         let changeset = RowChangeset {
@@ -415,6 +629,9 @@ mod tests {
             height: Some(100),
             visibility: Some(true),
             cell_by_field_id: Default::default(),
+            modified_at: None,
+            meta: None,
+            document_id: None,
         };
 
         let _ = pad.add_row_rev(row, None).unwrap().unwrap();
@@ -431,6 +648,113 @@ mod tests {
         );
     }
 
+    #[test]
+    fn block_meta_copy_cells() {
+        let mut pad = test_pad();
+        let mut row_1 = test_row_rev("1", &pad);
+        row_1
+            .cells
+            .insert("a".to_string(), CellRevision::new("hello".to_string()));
+        let row_2 = test_row_rev("2", &pad);
+        let _ = pad.add_row_rev(row_1, None).unwrap().unwrap();
+        let _ = pad.add_row_rev(row_2, None).unwrap().unwrap();
+
+        assert!(pad.copy_cells("a", "b").unwrap().is_some());
+        assert_eq!(pad.rows[0].cells.get("b"), pad.rows[0].cells.get("a"));
+        assert!(pad.rows[1].cells.get("b").is_none());
+
+        // Nothing is changed if none of the rows contains the cell of the field.
+        assert!(pad.copy_cells("c", "d").unwrap().is_none());
+    }
+
+    #[test]
+    fn block_meta_delete_cells() {
+        let mut pad = test_pad();
+        let mut row_1 = test_row_rev("1", &pad);
+        row_1
+            .cells
+            .insert("a".to_string(), CellRevision::new("hello".to_string()));
+        row_1
+            .cells
+            .insert("b".to_string(), CellRevision::new("world".to_string()));
+        let mut row_2 = test_row_rev("2", &pad);
+        row_2.cells.insert("c".to_string(), CellRevision::new("1".to_string()));
+        let _ = pad.add_row_rev(row_1, None).unwrap().unwrap();
+        let _ = pad.add_row_rev(row_2, None).unwrap().unwrap();
+
+        assert!(pad.delete_cells(&["a".to_string(), "b".to_string()]).unwrap().is_some());
+        assert!(pad.rows[0].cells.is_empty());
+        assert!(pad.rows[1].cells.get("c").is_some());
+
+        // Nothing is changed if none of the rows contains the cells of the fields.
+        assert!(pad.delete_cells(&["a".to_string()]).unwrap().is_none());
+    }
+
+    #[test]
+    fn block_meta_cell_comments() {
+        let mut pad = test_pad();
+        let mut row_1 = test_row_rev("1", &pad);
+        row_1
+            .cells
+            .insert("a".to_string(), CellRevision::new("hello".to_string()));
+        let _ = pad.add_row_rev(row_1, None).unwrap().unwrap();
+
+        let comment_1 = CellCommentRevision::new("user".to_string(), "first".to_string(), 1);
+        let comment_2 = CellCommentRevision::new("user".to_string(), "second".to_string(), 2);
+        assert!(pad.add_cell_comment("1", "a", comment_1.clone()).unwrap().is_some());
+        assert!(pad.add_cell_comment("1", "a", comment_2.clone()).unwrap().is_some());
+        assert_eq!(
+            pad.rows[0].comments.get("a").unwrap(),
+            &vec![comment_1.clone(), comment_2]
+        );
+
+        // The comments are not the part of the cell.
+        assert_eq!(pad.rows[0].cells.get("a").unwrap().type_cell_data, "hello");
+
+        // Nothing is changed if the row doesn't exist or the cell doesn't have the comment.
+        assert!(pad.add_cell_comment("2", "a", comment_1.clone()).unwrap().is_none());
+        assert!(pad.delete_cell_comment("1", "b", &comment_1.id).unwrap().is_none());
+
+        assert!(pad.delete_cell_comment("1", "a", &comment_1.id).unwrap().is_some());
+        assert_eq!(pad.rows[0].comments.get("a").unwrap().len(), 1);
+
+        // Clearing the cell doesn't remove its comments.
+        assert!(pad.delete_cells(&["a".to_string()]).unwrap().is_some());
+        assert_eq!(pad.rows[0].comments.get("a").unwrap().len(), 1);
+
+        assert!(pad.delete_comments(&["a".to_string()]).unwrap().is_some());
+        assert!(pad.rows[0].comments.is_empty());
+    }
+
+    #[test]
+    fn block_meta_update_rows() {
+        let mut pad = test_pad();
+        let _ = pad.add_row_rev(test_row_rev("1", &pad), None).unwrap().unwrap();
+        let _ = pad.add_row_rev(test_row_rev("2", &pad), None).unwrap().unwrap();
+
+        let changesets = ["1", "2", "3"]
+            .iter()
+            .map(|row_id| {
+                let mut changeset = RowChangeset::new(row_id.to_string());
+                changeset
+                    .cell_by_field_id
+                    .insert("a".to_string(), CellRevision::new(format!("cell of {}", row_id)));
+                changeset
+            })
+            .collect::<Vec<RowChangeset>>();
+        // The changesets of the rows are applied in one revision, the unknown row is ignored.
+        let change = pad.update_rows(changesets).unwrap();
+        assert!(change.is_some());
+        assert_eq!(pad.rows.len(), 2);
+        assert_eq!(pad.rows[0].cells.get("a").unwrap().type_cell_data, "cell of 1");
+        assert_eq!(pad.rows[1].cells.get("a").unwrap().type_cell_data, "cell of 2");
+
+        assert!(pad
+            .update_rows(vec![RowChangeset::new("3".to_string())])
+            .unwrap()
+            .is_none());
+    }
+
     fn test_pad() -> GridBlockRevisionPad {
         let operations = GridBlockOperations::from_json(r#"[{"insert":"{\"block_id\":\"1\",\"rows\":[]}"}]"#).unwrap();
         GridBlockRevisionPad::from_operations(operations).unwrap()
@@ -1,5 +1,6 @@
 use crate::errors::{SyncError, SyncResult};
 use grid_model::{BuildDatabaseContext, DatabaseBlockRevision, FieldRevision, GridBlockMetaRevision, RowRevision};
+use lib_infra::util::timestamp;
 use std::sync::Arc;
 
 pub struct DatabaseBuilder {
@@ -39,7 +40,9 @@ impl DatabaseBuilder {
     }
 
     pub fn add_empty_row(&mut self) {
-        let row = RowRevision::new(self.block_id());
+        let mut row = RowRevision::new(self.block_id());
+        row.created_at = timestamp();
+        row.modified_at = row.created_at;
         self.add_row(row);
     }
 
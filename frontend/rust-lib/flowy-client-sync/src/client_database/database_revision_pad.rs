@@ -3,7 +3,7 @@ use crate::util::cal_diff;
 use flowy_sync::util::make_operations_from_revisions;
 use grid_model::{
     gen_block_id, gen_grid_id, DatabaseRevision, FieldRevision, FieldTypeRevision, GridBlockMetaRevision,
-    GridBlockMetaRevisionChangeset,
+    GridBlockMetaRevisionChangeset, TrashedRowRevision,
 };
 use lib_infra::util::md5;
 use lib_infra::util::move_vec_element;
@@ -116,6 +116,28 @@ impl DatabaseRevisionPad {
         )
     }
 
+    /// Deletes the fields in one revision. None of them is deleted if one of them is the primary
+    /// field. The ids of the fields that don't exist are ignored.
+    pub fn delete_field_revs(&mut self, field_ids: &[String]) -> SyncResult<Option<DatabaseRevisionChangeset>> {
+        self.modify_grid(|grid_meta| {
+            let is_deleting_primary_field = grid_meta
+                .fields
+                .iter()
+                .any(|field| field.is_primary && field_ids.contains(&field.id));
+            if is_deleting_primary_field {
+                return Err(SyncError::can_not_delete_primary_field());
+            }
+
+            let old_len = grid_meta.fields.len();
+            grid_meta.fields.retain(|field| !field_ids.contains(&field.id));
+            if old_len == grid_meta.fields.len() {
+                Ok(None)
+            } else {
+                Ok(Some(()))
+            }
+        })
+    }
+
     pub fn duplicate_field_rev(
         &mut self,
         field_id: &str,
@@ -128,6 +150,8 @@ impl DatabaseRevisionPad {
                     let mut duplicate_field_rev = grid_meta.fields[index].as_ref().clone();
                     duplicate_field_rev.id = duplicated_field_id.to_string();
                     duplicate_field_rev.name = format!("{} (copy)", duplicate_field_rev.name);
+                    // The database has only one primary field.
+                    duplicate_field_rev.is_primary = false;
                     grid_meta.fields.insert(index + 1, Arc::new(duplicate_field_rev));
                     Ok(Some(()))
                 }
@@ -232,6 +256,71 @@ impl DatabaseRevisionPad {
         })
     }
 
+    /// Makes the field the primary field. The primary flag of the old primary field is cleared in
+    /// the same revision, so the database always has one primary field.
+    pub fn set_primary_field(&mut self, field_id: &str) -> SyncResult<Option<DatabaseRevisionChangeset>> {
+        self.modify_grid(|grid_meta| {
+            match grid_meta.fields.iter().find(|field| field.id == field_id) {
+                None => {
+                    let msg = format!("The field:{} doesn't exist", field_id);
+                    return Err(SyncError::record_not_found().context(msg));
+                }
+                Some(field) if field.is_primary => return Ok(None),
+                Some(_) => {}
+            }
+
+            for field in grid_meta.fields.iter_mut() {
+                let is_primary = field.id == field_id;
+                if field.is_primary != is_primary {
+                    Arc::make_mut(field).is_primary = is_primary;
+                }
+            }
+            Ok(Some(()))
+        })
+    }
+
+    /// Reorders all the fields at once. The `ordered_field_ids` must contain each id of the
+    /// existing fields exactly once, otherwise the fields are left untouched and an error is
+    /// returned.
+    pub fn reorder_fields(&mut self, ordered_field_ids: &[String]) -> SyncResult<Option<DatabaseRevisionChangeset>> {
+        self.modify_grid(|grid_meta| {
+            let mut field_by_id = grid_meta
+                .fields
+                .iter()
+                .map(|field| (field.id.as_str(), field.clone()))
+                .collect::<HashMap<&str, Arc<FieldRevision>>>();
+            if ordered_field_ids.len() != grid_meta.fields.len() {
+                let msg = format!(
+                    "Expect {} field ids but receive {}",
+                    grid_meta.fields.len(),
+                    ordered_field_ids.len()
+                );
+                return Err(SyncError::internal().context(msg));
+            }
+
+            let mut fields = Vec::with_capacity(ordered_field_ids.len());
+            for field_id in ordered_field_ids {
+                match field_by_id.remove(field_id.as_str()) {
+                    None => {
+                        let msg = format!("The field:{} doesn't exist or is duplicated", field_id);
+                        return Err(SyncError::record_not_found().context(msg));
+                    }
+                    Some(field) => fields.push(field),
+                }
+            }
+
+            let is_changed = fields
+                .iter()
+                .zip(grid_meta.fields.iter())
+                .any(|(field, old_field)| field.id != old_field.id);
+            if !is_changed {
+                return Ok(None);
+            }
+            grid_meta.fields = fields;
+            Ok(Some(()))
+        })
+    }
+
     pub fn contain_field(&self, field_id: &str) -> bool {
         self.grid_rev.fields.iter().any(|field| field.id == field_id)
     }
@@ -296,6 +385,18 @@ impl DatabaseRevisionPad {
         })
     }
 
+    pub fn delete_block_meta_revs(&mut self, block_ids: &[String]) -> SyncResult<Option<DatabaseRevisionChangeset>> {
+        self.modify_grid(|grid_meta| {
+            let number_of_blocks = grid_meta.blocks.len();
+            grid_meta.blocks.retain(|block| !block_ids.contains(&block.block_id));
+            if grid_meta.blocks.len() == number_of_blocks {
+                Ok(None)
+            } else {
+                Ok(Some(()))
+            }
+        })
+    }
+
     pub fn get_block_meta_revs(&self) -> Vec<Arc<GridBlockMetaRevision>> {
         self.grid_rev.blocks.clone()
     }
@@ -322,6 +423,88 @@ impl DatabaseRevisionPad {
         })
     }
 
+    /// Applies the changesets of the blocks and inserts the `new_blocks` after the block with the
+    /// `prev_block_id` in one change. The new blocks are appended if the `prev_block_id` is None
+    /// or it's not found. The `start_row_index` of each block is recalculated from the row counts
+    /// of the blocks before it.
+    pub fn update_block_revs(
+        &mut self,
+        changesets: Vec<GridBlockMetaRevisionChangeset>,
+        new_blocks: Vec<GridBlockMetaRevision>,
+        prev_block_id: Option<&str>,
+    ) -> SyncResult<Option<DatabaseRevisionChangeset>> {
+        self.modify_grid(|grid_meta| {
+            apply_block_row_counts(grid_meta, changesets);
+            let index = prev_block_id
+                .and_then(|prev_block_id| {
+                    grid_meta
+                        .blocks
+                        .iter()
+                        .position(|block| block.block_id == prev_block_id)
+                })
+                .map(|index| index + 1)
+                .unwrap_or(grid_meta.blocks.len());
+            grid_meta
+                .blocks
+                .splice(index..index, new_blocks.into_iter().map(Arc::new));
+            reindex_blocks(grid_meta);
+            Ok(Some(()))
+        })
+    }
+
+    pub fn get_trashed_rows(&self) -> Vec<Arc<TrashedRowRevision>> {
+        self.grid_rev.trashed_rows.clone()
+    }
+
+    /// Moves the rows that were deleted from their blocks to the trash, and updates the row counts
+    /// of the blocks in the same change. The trashed row with the same id is replaced.
+    pub fn trash_rows(
+        &mut self,
+        changesets: Vec<GridBlockMetaRevisionChangeset>,
+        trashed_rows: Vec<TrashedRowRevision>,
+    ) -> SyncResult<Option<DatabaseRevisionChangeset>> {
+        self.modify_grid(|grid_meta| {
+            apply_block_row_counts(grid_meta, changesets);
+            reindex_blocks(grid_meta);
+            for trashed_row in trashed_rows {
+                grid_meta
+                    .trashed_rows
+                    .retain(|old_trashed_row| old_trashed_row.row.id != trashed_row.row.id);
+                grid_meta.trashed_rows.push(Arc::new(trashed_row));
+            }
+            Ok(Some(()))
+        })
+    }
+
+    /// Removes the rows from the trash. Returns None if none of the rows is in the trash.
+    pub fn remove_trashed_rows(&mut self, row_ids: &[String]) -> SyncResult<Option<DatabaseRevisionChangeset>> {
+        self.modify_grid(|grid_meta| {
+            let count = grid_meta.trashed_rows.len();
+            grid_meta
+                .trashed_rows
+                .retain(|trashed_row| !row_ids.contains(&trashed_row.row.id));
+            if grid_meta.trashed_rows.len() == count {
+                Ok(None)
+            } else {
+                Ok(Some(()))
+            }
+        })
+    }
+
+    pub fn get_database_name(&self) -> &str {
+        &self.grid_rev.name
+    }
+
+    pub fn update_database_name(&mut self, name: &str) -> SyncResult<Option<DatabaseRevisionChangeset>> {
+        self.modify_grid(|grid_rev| {
+            if grid_rev.name == name {
+                return Ok(None);
+            }
+            grid_rev.name = name.to_owned();
+            Ok(Some(()))
+        })
+    }
+
     pub fn database_md5(&self) -> String {
         md5(&self.operations.json_bytes())
     }
@@ -330,6 +513,22 @@ impl DatabaseRevisionPad {
         self.operations.json_str()
     }
 
+    pub fn get_operations(&self) -> &DatabaseOperations {
+        &self.operations
+    }
+
+    pub fn reset_database(&mut self, operations: DatabaseOperations) -> SyncResult<String> {
+        let database = DatabaseRevisionPad::from_operations(operations)?;
+        self.grid_rev = database.grid_rev;
+        self.operations = database.operations;
+        Ok(self.database_md5())
+    }
+
+    pub fn compose_remote_operations(&mut self, operations: DatabaseOperations) -> SyncResult<String> {
+        let composed_operations = self.operations.compose(&operations)?;
+        self.reset_database(composed_operations)
+    }
+
     pub fn get_fields(&self) -> &[Arc<FieldRevision>] {
         &self.grid_rev.fields
     }
@@ -399,6 +598,31 @@ impl DatabaseRevisionPad {
     }
 }
 
+fn apply_block_row_counts(grid_rev: &mut DatabaseRevision, changesets: Vec<GridBlockMetaRevisionChangeset>) {
+    for changeset in changesets {
+        if let Some(block) = grid_rev
+            .blocks
+            .iter_mut()
+            .find(|block| block.block_id == changeset.block_id)
+        {
+            if let Some(row_count) = changeset.row_count {
+                Arc::make_mut(block).row_count = row_count;
+            }
+        }
+    }
+}
+
+/// Recomputes the start row index of each block from the row counts of the blocks before it.
+fn reindex_blocks(grid_rev: &mut DatabaseRevision) {
+    let mut start_row_index = 0;
+    for block in grid_rev.blocks.iter_mut() {
+        if block.start_row_index != start_row_index {
+            Arc::make_mut(block).start_row_index = start_row_index;
+        }
+        start_row_index += block.row_count;
+    }
+}
+
 pub fn make_database_rev_json_str(grid_revision: &DatabaseRevision) -> SyncResult<String> {
     let json = serde_json::to_string(grid_revision)
         .map_err(|err| internal_sync_error(format!("Serialize grid to json str failed. {:?}", err)))?;
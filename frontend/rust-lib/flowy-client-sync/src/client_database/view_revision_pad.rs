@@ -2,8 +2,8 @@ use crate::errors::{internal_sync_error, SyncError, SyncResult};
 use crate::util::cal_diff;
 use flowy_sync::util::make_operations_from_revisions;
 use grid_model::{
-    DatabaseViewRevision, FieldRevision, FieldTypeRevision, FilterRevision, GroupConfigurationRevision, LayoutRevision,
-    SortRevision,
+    DatabaseViewRevision, FieldRevision, FieldSettingsRevision, FieldTypeRevision, FilterGroupRevision, FilterRevision,
+    FilterSetRevision, GroupConfigurationRevision, LayoutRevision, SortModeRevision, SortRevision,
 };
 use lib_infra::util::md5;
 use lib_ot::core::{DeltaBuilder, DeltaOperations, EmptyAttributes, OperationTransform};
@@ -128,6 +128,8 @@ impl GridViewRevisionPad {
         })
     }
 
+    /// Returns the sorts in the order of their precedence, the rows are sorted by the first sort,
+    /// and then the rows that are equal are sorted by the next sort.
     pub fn get_all_sorts(&self, _field_revs: &[Arc<FieldRevision>]) -> Vec<Arc<SortRevision>> {
         self.sorts.get_all_objects()
     }
@@ -137,6 +139,12 @@ impl GridViewRevisionPad {
         self.sorts.get_objects(field_id, field_type_rev).unwrap_or_default()
     }
 
+    /// Returns the sorts of the field, including the sorts that were created before the field type
+    /// of the field was changed.
+    pub fn get_sorts_of_field(&self, field_id: &str) -> Vec<Arc<SortRevision>> {
+        self.sorts.get_objects_of_field(field_id)
+    }
+
     pub fn get_sort(
         &self,
         field_id: &str,
@@ -171,6 +179,9 @@ impl GridViewRevisionPad {
             {
                 let sort = Arc::make_mut(sort);
                 sort.condition = sort_rev.condition;
+                sort.natural = sort_rev.natural;
+                sort.alphabetical = sort_rev.alphabetical;
+                sort.empty_order = sort_rev.empty_order;
                 Ok(Some(()))
             } else {
                 Ok(None)
@@ -188,6 +199,11 @@ impl GridViewRevisionPad {
         self.modify(|view| {
             if let Some(sorts) = view.sorts.get_mut_objects(field_id, &field_type) {
                 sorts.retain(|sort| sort.id != sort_id);
+                // The sort that is inserted to the field later should be the last sort, so the
+                // field is removed from the sorts after its last sort is deleted.
+                if view.sorts.get_objects_of_field(field_id).is_empty() {
+                    view.sorts.remove_objects(field_id);
+                }
                 Ok(Some(()))
             } else {
                 Ok(None)
@@ -195,6 +211,30 @@ impl GridViewRevisionPad {
         })
     }
 
+    /// Moves the sort to the index of the sorts, which changes the precedence of the sort. Returns
+    /// None if the sort doesn't exist or it's at the index already.
+    pub fn reorder_sort(&mut self, sort_id: &str, new_index: usize) -> SyncResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            let mut sorts = view.sorts.get_all_objects();
+            let old_index = match sorts.iter().position(|sort| sort.id == sort_id) {
+                None => return Ok(None),
+                Some(old_index) => old_index,
+            };
+            let new_index = new_index.min(sorts.len() - 1);
+            if old_index == new_index {
+                return Ok(None);
+            }
+            let sort = sorts.remove(old_index);
+            sorts.insert(new_index, sort);
+            view.sorts.clear();
+            for sort in sorts {
+                view.sorts
+                    .add_object(&sort.field_id, &sort.field_type, sort.as_ref().clone());
+            }
+            Ok(Some(()))
+        })
+    }
+
     pub fn delete_all_sorts(&mut self) -> SyncResult<Option<GridViewRevisionChangeset>> {
         self.modify(|view| {
             view.sorts.clear();
@@ -211,6 +251,12 @@ impl GridViewRevisionPad {
         self.filters.get_objects(field_id, field_type_rev).unwrap_or_default()
     }
 
+    /// Returns the filters of the field, including the filters that were created before the field
+    /// type of the field was changed.
+    pub fn get_filters_of_field(&self, field_id: &str) -> Vec<Arc<FilterRevision>> {
+        self.filters.get_objects_of_field(field_id)
+    }
+
     pub fn get_filter(
         &self,
         field_id: &str,
@@ -228,6 +274,9 @@ impl GridViewRevisionPad {
     ) -> SyncResult<Option<GridViewRevisionChangeset>> {
         self.modify(|view| {
             let field_type = filter_rev.field_type;
+            if let Some(filter_tree) = view.filter_tree.as_mut() {
+                filter_tree.push_filter(&filter_rev.id);
+            }
             view.filters.add_object(field_id, &field_type, filter_rev);
             Ok(Some(()))
         })
@@ -263,6 +312,9 @@ impl GridViewRevisionPad {
         self.modify(|view| {
             if let Some(filters) = view.filters.get_mut_objects(field_id, &field_type) {
                 filters.retain(|filter| filter.id != filter_id);
+                if let Some(filter_tree) = view.filter_tree.as_mut() {
+                    filter_tree.remove_filter(filter_id);
+                }
                 Ok(Some(()))
             } else {
                 Ok(None)
@@ -270,6 +322,298 @@ impl GridViewRevisionPad {
         })
     }
 
+    /// Deletes all the filters of the field, regardless of their field types.
+    pub fn delete_filters_of_field(&mut self, field_id: &str) -> SyncResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            let filters = view.filters.remove_objects(field_id);
+            if filters.is_empty() {
+                return Ok(None);
+            }
+            if let Some(filter_tree) = view.filter_tree.as_mut() {
+                for filter in filters.iter() {
+                    filter_tree.remove_filter(&filter.id);
+                }
+            }
+            Ok(Some(()))
+        })
+    }
+
+    /// Returns the groups that combine the filters. The filters of the view that were never grouped
+    /// are combined by a group with the `And` operator.
+    pub fn get_filter_tree(&self) -> FilterGroupRevision {
+        match self.view.filter_tree.as_ref() {
+            Some(filter_tree) => filter_tree.clone(),
+            None => {
+                let filter_ids = self
+                    .filters
+                    .get_all_objects()
+                    .into_iter()
+                    .map(|filter| filter.id.clone())
+                    .collect();
+                FilterGroupRevision::with_filters(&self.view.view_id, filter_ids)
+            }
+        }
+    }
+
+    pub fn set_filter_tree(
+        &mut self,
+        filter_tree: FilterGroupRevision,
+    ) -> SyncResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            if view.filter_tree.as_ref() == Some(&filter_tree) {
+                return Ok(None);
+            }
+            view.filter_tree = Some(filter_tree);
+            Ok(Some(()))
+        })
+    }
+
+    /// Replaces all the filters of the view and the groups that combine them.
+    pub fn replace_filters(
+        &mut self,
+        filter_revs: Vec<FilterRevision>,
+        filter_tree: Option<FilterGroupRevision>,
+    ) -> SyncResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            view.filters.clear();
+            for filter_rev in filter_revs {
+                let field_id = filter_rev.field_id.clone();
+                let field_type = filter_rev.field_type;
+                view.filters.add_object(&field_id, &field_type, filter_rev);
+            }
+            view.filter_tree = filter_tree;
+            Ok(Some(()))
+        })
+    }
+
+    pub fn get_filter_sets(&self) -> &[FilterSetRevision] {
+        &self.view.filter_sets
+    }
+
+    pub fn get_filter_set(&self, name: &str) -> Option<&FilterSetRevision> {
+        self.view.filter_sets.iter().find(|filter_set| filter_set.name == name)
+    }
+
+    /// Saves the current filters of the view and the groups that combine them as the filter set
+    /// of the name. The existing filter set with the same name is replaced.
+    pub fn save_filter_set(&mut self, name: &str) -> SyncResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            let filter_set = FilterSetRevision {
+                name: name.to_owned(),
+                filters: view
+                    .filters
+                    .get_all_objects()
+                    .into_iter()
+                    .map(|filter_rev| filter_rev.as_ref().clone())
+                    .collect(),
+                filter_tree: view.filter_tree.clone(),
+            };
+            match view.filter_sets.iter_mut().find(|saved| saved.name == name) {
+                None => view.filter_sets.push(filter_set),
+                Some(saved_filter_set) => *saved_filter_set = filter_set,
+            }
+            Ok(Some(()))
+        })
+    }
+
+    pub fn delete_filter_set(&mut self, name: &str) -> SyncResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            let old_len = view.filter_sets.len();
+            view.filter_sets.retain(|filter_set| filter_set.name != name);
+            Ok(if old_len != view.filter_sets.len() {
+                Some(())
+            } else {
+                None
+            })
+        })
+    }
+
+    pub fn get_field_settings(&self) -> Option<&FieldSettingsRevision> {
+        self.view.field_settings.as_ref()
+    }
+
+    /// Creates the field settings of the view that was created before the fields could be
+    /// overridden per view. The fields that are hidden in the database are hidden in the view.
+    pub fn initialize_field_settings(
+        &mut self,
+        hidden_field_ids: Vec<String>,
+    ) -> SyncResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            if view.field_settings.is_some() {
+                return Ok(None);
+            }
+            let visibilities = hidden_field_ids.into_iter().map(|field_id| (field_id, false)).collect();
+            view.field_settings = Some(FieldSettingsRevision {
+                visibilities,
+                ..Default::default()
+            });
+            Ok(Some(()))
+        })
+    }
+
+    pub fn set_field_visibility(
+        &mut self,
+        field_id: &str,
+        visibility: bool,
+    ) -> SyncResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            let field_settings = view.field_settings.get_or_insert_with(Default::default);
+            match field_settings.visibilities.insert(field_id.to_owned(), visibility) {
+                Some(old_visibility) if old_visibility == visibility => Ok(None),
+                _ => Ok(Some(())),
+            }
+        })
+    }
+
+    pub fn set_field_order(&mut self, field_ids: Vec<String>) -> SyncResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            let field_settings = view.field_settings.get_or_insert_with(Default::default);
+            if field_settings.field_order == field_ids {
+                return Ok(None);
+            }
+            field_settings.field_order = field_ids;
+            Ok(Some(()))
+        })
+    }
+
+    pub fn set_field_width(&mut self, field_id: &str, width: i32) -> SyncResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            let field_settings = view.field_settings.get_or_insert_with(Default::default);
+            match field_settings.widths.insert(field_id.to_owned(), width) {
+                Some(old_width) if old_width == width => Ok(None),
+                _ => Ok(Some(())),
+            }
+        })
+    }
+
+    pub fn set_frozen_field_count(&mut self, count: usize) -> SyncResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            let field_settings = view.field_settings.get_or_insert_with(Default::default);
+            if field_settings.frozen_field_count == count {
+                return Ok(None);
+            }
+            field_settings.frozen_field_count = count;
+            Ok(Some(()))
+        })
+    }
+
+    /// Removes all the settings of the fields, for example, the fields were deleted.
+    pub fn delete_field_settings(&mut self, field_ids: &[String]) -> SyncResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| match view.field_settings.as_mut() {
+            None => Ok(None),
+            Some(field_settings) => {
+                let mut is_changed = false;
+                for field_id in field_ids {
+                    is_changed |= field_settings.remove_field(field_id);
+                }
+                Ok(if is_changed { Some(()) } else { None })
+            }
+        })
+    }
+
+    /// Removes the filters, sorts and settings of the field in one revision.
+    pub fn delete_field_rules(&mut self, field_id: &str) -> SyncResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            let filters = view.filters.remove_objects(field_id);
+            if let Some(filter_tree) = view.filter_tree.as_mut() {
+                for filter in filters.iter() {
+                    filter_tree.remove_filter(&filter.id);
+                }
+            }
+            let mut is_changed = !filters.is_empty();
+            is_changed |= !view.sorts.remove_objects(field_id).is_empty();
+            if let Some(field_settings) = view.field_settings.as_mut() {
+                is_changed |= field_settings.remove_field(field_id);
+            }
+            Ok(if is_changed { Some(()) } else { None })
+        })
+    }
+
+    /// Removes the filters, sorts, groups and settings that reference the fields in one revision.
+    pub fn delete_field_objects(&mut self, field_ids: &[String]) -> SyncResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            let mut is_changed = false;
+            for field_id in field_ids {
+                let filters = view.filters.remove_objects(field_id);
+                if let Some(filter_tree) = view.filter_tree.as_mut() {
+                    for filter in filters.iter() {
+                        filter_tree.remove_filter(&filter.id);
+                    }
+                }
+                is_changed |= !filters.is_empty();
+                is_changed |= !view.sorts.remove_objects(field_id).is_empty();
+                is_changed |= !view.groups.remove_objects(field_id).is_empty();
+                if let Some(field_settings) = view.field_settings.as_mut() {
+                    is_changed |= field_settings.remove_field(field_id);
+                }
+            }
+            Ok(if is_changed { Some(()) } else { None })
+        })
+    }
+
+    pub fn get_pinned_row_ids(&self) -> &[String] {
+        &self.view.pinned_row_ids
+    }
+
+    /// Pins the row after the rows that were pinned before. Returns None if the row is pinned.
+    pub fn pin_row(&mut self, row_id: &str) -> SyncResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            if view.pinned_row_ids.iter().any(|pinned_row_id| pinned_row_id == row_id) {
+                return Ok(None);
+            }
+            view.pinned_row_ids.push(row_id.to_owned());
+            Ok(Some(()))
+        })
+    }
+
+    /// Unpins the rows. Returns None if none of the rows is pinned.
+    pub fn unpin_rows(&mut self, row_ids: &[String]) -> SyncResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            let old_len = view.pinned_row_ids.len();
+            view.pinned_row_ids
+                .retain(|pinned_row_id| !row_ids.contains(pinned_row_id));
+            Ok(if old_len != view.pinned_row_ids.len() {
+                Some(())
+            } else {
+                None
+            })
+        })
+    }
+
+    pub fn get_sort_mode(&self) -> Option<SortModeRevision> {
+        self.view.sort_mode
+    }
+
+    /// Sets the special order of the rows, or removes it if the `sort_mode` is None. Returns None if
+    /// the mode is not changed.
+    pub fn set_sort_mode(
+        &mut self,
+        sort_mode: Option<SortModeRevision>,
+    ) -> SyncResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            if view.sort_mode == sort_mode {
+                return Ok(None);
+            }
+            view.sort_mode = sort_mode;
+            Ok(Some(()))
+        })
+    }
+
+    pub fn get_manual_row_order(&self) -> &[String] {
+        &self.view.manual_row_order
+    }
+
+    /// Replaces the manual order of the rows. Returns None if the order is not changed.
+    pub fn set_manual_row_order(&mut self, row_ids: Vec<String>) -> SyncResult<Option<GridViewRevisionChangeset>> {
+        self.modify(|view| {
+            if view.manual_row_order == row_ids {
+                return Ok(None);
+            }
+            view.manual_row_order = row_ids;
+            Ok(Some(()))
+        })
+    }
+
     pub fn json_str(&self) -> SyncResult<String> {
         make_grid_view_rev_json_str(&self.view)
     }
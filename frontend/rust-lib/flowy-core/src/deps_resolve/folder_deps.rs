@@ -246,7 +246,7 @@ impl ViewDataProcessor for GridViewDataProcessor {
         let grid_manager = self.0.clone();
         let view_id = view.id.clone();
         FutureResult::new(async move {
-            let editor = grid_manager.open_database(view_id).await?;
+            let editor = grid_manager.get_database_editor(&view_id).await?;
             let delta_bytes = editor.duplicate_grid().await?;
             Ok(delta_bytes.into())
         })
@@ -1,8 +1,8 @@
 use crate::FlowyError;
 use bytes::Bytes;
 use flowy_client_ws::FlowyWebSocketConnect;
-use flowy_database::manager::{DatabaseManager, DatabaseUser};
-use flowy_database::services::persistence::GridDatabase;
+use flowy_database::manager::{DatabaseManager, DatabaseManagerConfig, DatabaseUser};
+use flowy_database::services::persistence::{DatabaseStorage, GridDatabase};
 use flowy_revision::{RevisionWebSocket, WSStateReceiver};
 use flowy_sqlite::ConnectionPool;
 use flowy_task::TaskDispatcher;
@@ -29,7 +29,9 @@ impl GridDepsResolver {
             user.clone(),
             rev_web_socket,
             task_scheduler,
-            Arc::new(GridDatabaseImpl(user_session)),
+            DatabaseStorage::sqlite(Arc::new(GridDatabaseImpl(user_session))),
+            DatabaseManagerConfig::default(),
+            None,
         ));
 
         if let (Ok(user_id), Ok(token)) = (user.user_id(), user.token()) {
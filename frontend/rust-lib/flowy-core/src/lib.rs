@@ -318,6 +318,8 @@ impl UserStatusListener {
 
     async fn did_expired(&self, _token: &str, user_id: &str) -> FlowyResult<()> {
         self.folder_manager.clear(user_id).await;
+        let database_ids = self.grid_manager.close_all().await;
+        tracing::trace!("Close databases: {:?}", database_ids);
         self.ws_conn.stop().await;
         Ok(())
     }
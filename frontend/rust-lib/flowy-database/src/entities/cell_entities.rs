@@ -1,8 +1,8 @@
 use crate::entities::parser::NotEmptyStr;
 use crate::entities::FieldType;
 use flowy_derive::ProtoBuf;
-use flowy_error::ErrorCode;
-use grid_model::{CellRevision, RowChangeset};
+use flowy_error::{ErrorCode, FlowyError};
+use grid_model::{CellCommentRevision, CellRevision, RowChangeset};
 use std::collections::HashMap;
 
 #[derive(ProtoBuf, Default)]
@@ -153,13 +153,326 @@ pub struct CellChangesetPB {
     pub type_cell_data: String,
 }
 
+/// [CellChangePB] carries the values of the cell before and after it's updated. Both values are
+/// decoded by the type option of the field, the same as the `data` of the [CellPB]. The value is
+/// empty if the cell doesn't exist.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct CellChangePB {
+    #[pb(index = 1)]
+    pub row_id: String,
+
+    #[pb(index = 2)]
+    pub field_id: String,
+
+    #[pb(index = 3)]
+    pub field_type: FieldType,
+
+    #[pb(index = 4)]
+    pub old_data: Vec<u8>,
+
+    #[pb(index = 5)]
+    pub new_data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct UpdateCellsPayloadPB {
+    #[pb(index = 1)]
+    pub database_id: String,
+
+    #[pb(index = 2)]
+    pub changesets: Vec<CellChangesetPB>,
+
+    /// None of the changesets will be applied if any of them is invalid.
+    #[pb(index = 3)]
+    pub strict: bool,
+}
+
+pub struct UpdateCellsParams {
+    pub database_id: String,
+    pub changesets: Vec<CellChangesetPB>,
+    pub strict: bool,
+}
+
+impl TryInto<UpdateCellsParams> for UpdateCellsPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateCellsParams, Self::Error> {
+        let database_id = NotEmptyStr::parse(self.database_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+        Ok(UpdateCellsParams {
+            database_id: database_id.0,
+            changesets: self.changesets,
+            strict: self.strict,
+        })
+    }
+}
+
+/// The error of a changeset that can't be applied to the cell.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct CellUpdateErrorPB {
+    #[pb(index = 1)]
+    pub row_id: String,
+
+    #[pb(index = 2)]
+    pub field_id: String,
+
+    #[pb(index = 3)]
+    pub code: i32,
+
+    #[pb(index = 4)]
+    pub msg: String,
+}
+
+impl CellUpdateErrorPB {
+    pub fn new(changeset: &CellChangesetPB, error: FlowyError) -> Self {
+        Self {
+            row_id: changeset.row_id.clone(),
+            field_id: changeset.field_id.clone(),
+            code: error.code,
+            msg: error.msg,
+        }
+    }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RepeatedCellUpdateErrorPB {
+    #[pb(index = 1)]
+    pub items: Vec<CellUpdateErrorPB>,
+}
+
+impl std::convert::From<Vec<CellUpdateErrorPB>> for RepeatedCellUpdateErrorPB {
+    fn from(items: Vec<CellUpdateErrorPB>) -> Self {
+        Self { items }
+    }
+}
+
+/// [InvalidCellsPB] is the rows whose cells violate the validation rules of the field.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct InvalidCellsPB {
+    #[pb(index = 1)]
+    pub field_id: String,
+
+    #[pb(index = 2)]
+    pub row_ids: Vec<String>,
+}
+
+/// [RangeClipboardPB] is the clipboard of a range of cells. The `tsv` contains the display strings
+/// of the cells, and the `json` is the lossless payload that is used when pasting the cells into
+/// a database. The `json` is empty if the tsv was copied from somewhere else.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct RangeClipboardPB {
+    #[pb(index = 1)]
+    pub tsv: String,
+
+    #[pb(index = 2)]
+    pub json: String,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct CopyRangePayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub row_ids: Vec<String>,
+
+    #[pb(index = 3)]
+    pub field_ids: Vec<String>,
+}
+
+pub struct CopyRangeParams {
+    pub view_id: String,
+    pub row_ids: Vec<String>,
+    pub field_ids: Vec<String>,
+}
+
+impl TryInto<CopyRangeParams> for CopyRangePayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CopyRangeParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+        Ok(CopyRangeParams {
+            view_id: view_id.0,
+            row_ids: self.row_ids,
+            field_ids: self.field_ids,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct PasteRangePayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    /// The row of the top left cell that the clipboard is pasted to.
+    #[pb(index = 2)]
+    pub anchor_row_id: String,
+
+    /// The field of the top left cell that the clipboard is pasted to.
+    #[pb(index = 3)]
+    pub anchor_field_id: String,
+
+    #[pb(index = 4)]
+    pub clipboard: RangeClipboardPB,
+
+    /// Creates the rows for the values that go past the last row of the view. Otherwise, these
+    /// values are dropped.
+    #[pb(index = 5)]
+    pub create_rows: bool,
+}
+
+pub struct PasteRangeParams {
+    pub view_id: String,
+    pub anchor_row_id: String,
+    pub anchor_field_id: String,
+    pub clipboard: RangeClipboardPB,
+    pub create_rows: bool,
+}
+
+impl TryInto<PasteRangeParams> for PasteRangePayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<PasteRangeParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+        let anchor_row_id = NotEmptyStr::parse(self.anchor_row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?;
+        let anchor_field_id = NotEmptyStr::parse(self.anchor_field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+        Ok(PasteRangeParams {
+            view_id: view_id.0,
+            anchor_row_id: anchor_row_id.0,
+            anchor_field_id: anchor_field_id.0,
+            clipboard: self.clipboard,
+            create_rows: self.create_rows,
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct CellCommentPB {
+    #[pb(index = 1)]
+    pub id: String,
+
+    /// The id of the user who wrote the comment.
+    #[pb(index = 2)]
+    pub author: String,
+
+    #[pb(index = 3)]
+    pub content: String,
+
+    #[pb(index = 4)]
+    pub created_at: i64,
+}
+
+impl std::convert::From<&CellCommentRevision> for CellCommentPB {
+    fn from(comment_rev: &CellCommentRevision) -> Self {
+        Self {
+            id: comment_rev.id.clone(),
+            author: comment_rev.author.clone(),
+            content: comment_rev.content.clone(),
+            created_at: comment_rev.created_at,
+        }
+    }
+}
+
+/// The comments of the cell in the order they were added.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RepeatedCellCommentPB {
+    #[pb(index = 1)]
+    pub items: Vec<CellCommentPB>,
+}
+
+impl std::convert::From<&[CellCommentRevision]> for RepeatedCellCommentPB {
+    fn from(comment_revs: &[CellCommentRevision]) -> Self {
+        Self {
+            items: comment_revs.iter().map(CellCommentPB::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct AddCellCommentPayloadPB {
+    #[pb(index = 1)]
+    pub cell_path: CellPathPB,
+
+    #[pb(index = 2)]
+    pub content: String,
+}
+
+pub struct AddCellCommentParams {
+    pub cell_path: CellPathParams,
+    pub content: String,
+}
+
+impl TryInto<AddCellCommentParams> for AddCellCommentPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<AddCellCommentParams, Self::Error> {
+        let cell_path = self.cell_path.try_into()?;
+        let content = NotEmptyStr::parse(self.content).map_err(|_| ErrorCode::CellCommentIsEmpty)?;
+        Ok(AddCellCommentParams {
+            cell_path,
+            content: content.0,
+        })
+    }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct DeleteCellCommentPayloadPB {
+    #[pb(index = 1)]
+    pub cell_path: CellPathPB,
+
+    #[pb(index = 2)]
+    pub comment_id: String,
+}
+
+pub struct DeleteCellCommentParams {
+    pub cell_path: CellPathParams,
+    pub comment_id: String,
+}
+
+impl TryInto<DeleteCellCommentParams> for DeleteCellCommentPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<DeleteCellCommentParams, Self::Error> {
+        let cell_path = self.cell_path.try_into()?;
+        let comment_id = NotEmptyStr::parse(self.comment_id).map_err(|_| ErrorCode::InvalidParams)?;
+        Ok(DeleteCellCommentParams {
+            cell_path,
+            comment_id: comment_id.0,
+        })
+    }
+}
+
+/// [AppendCellPayloadPB] appends the text to the cell instead of replacing its content.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct AppendCellPayloadPB {
+    #[pb(index = 1)]
+    pub cell_path: CellPathPB,
+
+    #[pb(index = 2)]
+    pub text: String,
+}
+
+pub struct AppendCellParams {
+    pub cell_path: CellPathParams,
+    pub text: String,
+}
+
+impl TryInto<AppendCellParams> for AppendCellPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<AppendCellParams, Self::Error> {
+        let cell_path = self.cell_path.try_into()?;
+        Ok(AppendCellParams {
+            cell_path,
+            text: self.text,
+        })
+    }
+}
+
 impl std::convert::From<CellChangesetPB> for RowChangeset {
     fn from(changeset: CellChangesetPB) -> Self {
         let mut cell_by_field_id = HashMap::with_capacity(1);
         let field_id = changeset.field_id;
-        let cell_rev = CellRevision {
-            type_cell_data: changeset.type_cell_data,
-        };
+        let cell_rev = CellRevision::new(changeset.type_cell_data);
         cell_by_field_id.insert(field_id, cell_rev);
 
         RowChangeset {
@@ -167,6 +480,9 @@ impl std::convert::From<CellChangesetPB> for RowChangeset {
             height: None,
             visibility: None,
             cell_by_field_id,
+            modified_at: None,
+            meta: None,
+            document_id: None,
         }
     }
 }
@@ -33,6 +33,15 @@ pub struct FieldPB {
 
     #[pb(index = 8)]
     pub is_primary: bool,
+
+    #[pb(index = 9)]
+    pub description: String,
+
+    #[pb(index = 10)]
+    pub icon: String,
+
+    #[pb(index = 11)]
+    pub locked: bool,
 }
 
 impl std::convert::From<FieldRevision> for FieldPB {
@@ -46,6 +55,9 @@ impl std::convert::From<FieldRevision> for FieldPB {
             visibility: field_rev.visibility,
             width: field_rev.width,
             is_primary: field_rev.is_primary,
+            description: field_rev.description,
+            icon: field_rev.icon,
+            locked: field_rev.locked,
         }
     }
 }
@@ -411,6 +423,11 @@ pub struct FieldChangesetPB {
     pub width: Option<i32>,
     // #[pb(index = 9, one_of)]
     // pub type_option_data: Option<Vec<u8>>,
+    #[pb(index = 10, one_of)]
+    pub description: Option<String>,
+
+    #[pb(index = 11, one_of)]
+    pub icon: Option<String>,
 }
 
 impl TryInto<FieldChangesetParams> for FieldChangesetPB {
@@ -436,6 +453,8 @@ impl TryInto<FieldChangesetParams> for FieldChangesetPB {
             visibility: self.visibility,
             width: self.width,
             // type_option_data: self.type_option_data,
+            description: self.description,
+            icon: self.icon,
         })
     }
 }
@@ -458,6 +477,9 @@ pub struct FieldChangesetParams {
 
     pub width: Option<i32>,
     // pub type_option_data: Option<Vec<u8>>,
+    pub description: Option<String>,
+
+    pub icon: Option<String>,
 }
 /// Certain field types have user-defined options such as color, date format, number format,
 /// or a list of values for a multi-select list. These options are defined within a specialization
@@ -492,6 +514,16 @@ pub enum FieldType {
     Checkbox = 5,
     URL = 6,
     Checklist = 7,
+    CreatedTime = 8,
+    LastEditedTime = 9,
+    Relation = 10,
+    Formula = 11,
+    Rating = 12,
+    Progress = 13,
+    Email = 14,
+    PhoneNumber = 15,
+    Attachment = 16,
+    Person = 17,
 }
 
 pub const RICH_TEXT_FIELD: FieldType = FieldType::RichText;
@@ -502,6 +534,19 @@ pub const MULTI_SELECT_FIELD: FieldType = FieldType::MultiSelect;
 pub const CHECKBOX_FIELD: FieldType = FieldType::Checkbox;
 pub const URL_FIELD: FieldType = FieldType::URL;
 pub const CHECKLIST_FIELD: FieldType = FieldType::Checklist;
+pub const CREATED_TIME_FIELD: FieldType = FieldType::CreatedTime;
+pub const LAST_EDITED_TIME_FIELD: FieldType = FieldType::LastEditedTime;
+pub const RELATION_FIELD: FieldType = FieldType::Relation;
+pub const FORMULA_FIELD: FieldType = FieldType::Formula;
+pub const RATING_FIELD: FieldType = FieldType::Rating;
+pub const PROGRESS_FIELD: FieldType = FieldType::Progress;
+pub const EMAIL_FIELD: FieldType = FieldType::Email;
+pub const PHONE_NUMBER_FIELD: FieldType = FieldType::PhoneNumber;
+pub const ATTACHMENT_FIELD: FieldType = FieldType::Attachment;
+pub const PERSON_FIELD: FieldType = FieldType::Person;
+
+/// The minimum width of the fields in a view, the smaller widths are clamped to it.
+pub const MIN_FIELD_WIDTH: i32 = 50;
 
 impl std::default::Default for FieldType {
     fn default() -> Self {
@@ -528,7 +573,7 @@ impl FieldType {
 
     pub fn default_cell_width(&self) -> i32 {
         match self {
-            FieldType::DateTime => 180,
+            FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => 180,
             _ => 150,
         }
     }
@@ -569,8 +614,85 @@ impl FieldType {
         self == &CHECKLIST_FIELD
     }
 
+    pub fn is_created_time(&self) -> bool {
+        self == &CREATED_TIME_FIELD
+    }
+
+    pub fn is_last_edited_time(&self) -> bool {
+        self == &LAST_EDITED_TIME_FIELD
+    }
+
+    /// Returns true if the cells of the field are derived from the row instead of being stored
+    /// in the row. The cells of these fields can't be edited.
+    pub fn is_timestamp(&self) -> bool {
+        self.is_created_time() || self.is_last_edited_time()
+    }
+
+    pub fn is_relation(&self) -> bool {
+        self == &RELATION_FIELD
+    }
+
+    pub fn is_formula(&self) -> bool {
+        self == &FORMULA_FIELD
+    }
+
+    pub fn is_rating(&self) -> bool {
+        self == &RATING_FIELD
+    }
+
+    pub fn is_progress(&self) -> bool {
+        self == &PROGRESS_FIELD
+    }
+
+    pub fn is_email(&self) -> bool {
+        self == &EMAIL_FIELD
+    }
+
+    pub fn is_phone_number(&self) -> bool {
+        self == &PHONE_NUMBER_FIELD
+    }
+
+    pub fn is_attachment(&self) -> bool {
+        self == &ATTACHMENT_FIELD
+    }
+
+    pub fn is_person(&self) -> bool {
+        self == &PERSON_FIELD
+    }
+
     pub fn can_be_group(&self) -> bool {
-        self.is_select_option() || self.is_checkbox()
+        self.is_select_option() || self.is_checkbox() || self.is_rating() || self.is_person() || self.is_timestamp()
+    }
+
+    /// Every field type has the comparator of its cells, the checkboxes are sorted by unchecked
+    /// before checked and the checklists by the percentage of the done items. The match makes the
+    /// new field type decide whether it can be sorted.
+    pub fn can_be_sorted(&self) -> bool {
+        match self {
+            FieldType::RichText
+            | FieldType::Number
+            | FieldType::DateTime
+            | FieldType::SingleSelect
+            | FieldType::MultiSelect
+            | FieldType::Checkbox
+            | FieldType::URL
+            | FieldType::Checklist
+            | FieldType::CreatedTime
+            | FieldType::LastEditedTime
+            | FieldType::Relation
+            | FieldType::Formula
+            | FieldType::Rating
+            | FieldType::Progress
+            | FieldType::Email
+            | FieldType::PhoneNumber
+            | FieldType::Attachment
+            | FieldType::Person => true,
+        }
+    }
+
+    /// The primary field is used as the title of the rows, so its cells must be displayed as text.
+    pub fn can_be_primary(&self) -> bool {
+        !self.is_checkbox() && !self.is_check_list()
     }
 }
 
@@ -603,6 +725,16 @@ impl std::convert::From<FieldTypeRevision> for FieldType {
             5 => FieldType::Checkbox,
             6 => FieldType::URL,
             7 => FieldType::Checklist,
+            8 => FieldType::CreatedTime,
+            9 => FieldType::LastEditedTime,
+            10 => FieldType::Relation,
+            11 => FieldType::Formula,
+            12 => FieldType::Rating,
+            13 => FieldType::Progress,
+            14 => FieldType::Email,
+            15 => FieldType::PhoneNumber,
+            16 => FieldType::Attachment,
+            17 => FieldType::Person,
             _ => {
                 tracing::error!("Can't convert FieldTypeRevision: {} to FieldType", ty);
                 FieldType::RichText
@@ -628,6 +760,19 @@ pub struct GridFieldIdentifierPayloadPB {
     pub database_id: String,
 }
 
+impl TryInto<FieldIdParams> for GridFieldIdentifierPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<FieldIdParams, Self::Error> {
+        let database_id = NotEmptyStr::parse(self.database_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+        let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+        Ok(FieldIdParams {
+            database_id: database_id.0,
+            field_id: field_id.0,
+        })
+    }
+}
+
 impl TryInto<FieldIdParams> for DuplicateFieldPayloadPB {
     type Error = ErrorCode;
 
@@ -641,6 +786,49 @@ impl TryInto<FieldIdParams> for DuplicateFieldPayloadPB {
     }
 }
 
+/// [DuplicateFieldProgressPB] reports the progress of copying the cells to the duplicated field.
+/// The cells of each block are copied one by one.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct DuplicateFieldProgressPB {
+    #[pb(index = 1)]
+    pub field_id: String,
+
+    #[pb(index = 2)]
+    pub duplicated_field_id: String,
+
+    #[pb(index = 3)]
+    pub copied_block_count: i32,
+
+    #[pb(index = 4)]
+    pub block_count: i32,
+}
+
+impl DuplicateFieldProgressPB {
+    pub fn is_finished(&self) -> bool {
+        self.copied_block_count >= self.block_count
+    }
+}
+
+/// [ConvertFieldProgressPB] reports the progress of converting the cells of the field after its
+/// field type is switched. The cells of each block are converted one by one.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct ConvertFieldProgressPB {
+    #[pb(index = 1)]
+    pub field_id: String,
+
+    #[pb(index = 2)]
+    pub converted_block_count: i32,
+
+    #[pb(index = 3)]
+    pub block_count: i32,
+}
+
+impl ConvertFieldProgressPB {
+    pub fn is_finished(&self) -> bool {
+        self.converted_block_count >= self.block_count
+    }
+}
+
 #[derive(Debug, Clone, Default, ProtoBuf)]
 pub struct DeleteFieldPayloadPB {
     #[pb(index = 1)]
@@ -667,3 +855,115 @@ pub struct FieldIdParams {
     pub field_id: String,
     pub database_id: String,
 }
+
+/// [FieldLockedPayloadPB] locks the field against the edits of its cells, its field type and its
+/// type-option, or unlocks it.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct FieldLockedPayloadPB {
+    #[pb(index = 1)]
+    pub field_id: String,
+
+    #[pb(index = 2)]
+    pub database_id: String,
+
+    #[pb(index = 3)]
+    pub locked: bool,
+}
+
+pub struct FieldLockedParams {
+    pub field_id: String,
+    pub database_id: String,
+    pub locked: bool,
+}
+
+impl TryInto<FieldLockedParams> for FieldLockedPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<FieldLockedParams, Self::Error> {
+        let database_id = NotEmptyStr::parse(self.database_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+        let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+        Ok(FieldLockedParams {
+            field_id: field_id.0,
+            database_id: database_id.0,
+            locked: self.locked,
+        })
+    }
+}
+
+/// [FieldDefaultValuePayloadPB] sets the value that the cells of the field are filled with when
+/// the rows are created, or clears it if the `default_value` is None.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct FieldDefaultValuePayloadPB {
+    #[pb(index = 1)]
+    pub field_id: String,
+
+    #[pb(index = 2)]
+    pub database_id: String,
+
+    #[pb(index = 3, one_of)]
+    pub default_value: Option<String>,
+}
+
+pub struct FieldDefaultValueParams {
+    pub field_id: String,
+    pub database_id: String,
+    pub default_value: Option<String>,
+}
+
+impl TryInto<FieldDefaultValueParams> for FieldDefaultValuePayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<FieldDefaultValueParams, Self::Error> {
+        let database_id = NotEmptyStr::parse(self.database_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+        let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+        Ok(FieldDefaultValueParams {
+            field_id: field_id.0,
+            database_id: database_id.0,
+            default_value: self.default_value,
+        })
+    }
+}
+
+/// [FieldChangePB] describes how a revision changed the field.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct FieldChangePB {
+    #[pb(index = 1)]
+    pub rev_id: i64,
+
+    /// The timestamp, in seconds, when the revision was written to disk.
+    #[pb(index = 2)]
+    pub timestamp: i64,
+
+    /// The field after the change, or the field before it was deleted if the revision deleted it.
+    #[pb(index = 3)]
+    pub field: FieldPB,
+
+    /// The json string of the type option of the field's type.
+    #[pb(index = 4)]
+    pub type_option: String,
+
+    /// The names of the properties of the field that were changed by the revision. It's empty if
+    /// the field was created or deleted by the revision.
+    #[pb(index = 5)]
+    pub changed_properties: Vec<String>,
+
+    /// The field was created by the revision.
+    #[pb(index = 6)]
+    pub is_created: bool,
+
+    /// The field was deleted by the revision.
+    #[pb(index = 7)]
+    pub is_deleted: bool,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct RepeatedFieldChangePB {
+    #[pb(index = 1)]
+    pub items: Vec<FieldChangePB>,
+}
+
+impl std::convert::From<Vec<FieldChangePB>> for RepeatedFieldChangePB {
+    fn from(items: Vec<FieldChangePB>) -> Self {
+        Self { items }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::services::filter::FromFilterString;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+use grid_model::FilterRevision;
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct AttachmentFilterPB {
+    #[pb(index = 1)]
+    pub condition: AttachmentFilterConditionPB,
+
+    /// The mime type of the [AttachmentFilterConditionPB::FileTypeIs] condition. It's either a full
+    /// mime type, for example: `image/png`, or only the top-level type, for example: `image`.
+    #[pb(index = 2)]
+    pub content: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum AttachmentFilterConditionPB {
+    AttachmentIsEmpty = 0,
+    AttachmentIsNotEmpty = 1,
+    /// The cells that contain at least one attachment of the mime type.
+    FileTypeIs = 2,
+}
+
+impl std::convert::From<AttachmentFilterConditionPB> for u32 {
+    fn from(value: AttachmentFilterConditionPB) -> Self {
+        value as u32
+    }
+}
+
+impl std::default::Default for AttachmentFilterConditionPB {
+    fn default() -> Self {
+        AttachmentFilterConditionPB::AttachmentIsNotEmpty
+    }
+}
+
+impl std::convert::TryFrom<u8> for AttachmentFilterConditionPB {
+    type Error = ErrorCode;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(AttachmentFilterConditionPB::AttachmentIsEmpty),
+            1 => Ok(AttachmentFilterConditionPB::AttachmentIsNotEmpty),
+            2 => Ok(AttachmentFilterConditionPB::FileTypeIs),
+            _ => Err(ErrorCode::InvalidData),
+        }
+    }
+}
+
+impl FromFilterString for AttachmentFilterPB {
+    fn from_filter_rev(filter_rev: &FilterRevision) -> Self
+    where
+        Self: Sized,
+    {
+        AttachmentFilterPB {
+            condition: AttachmentFilterConditionPB::try_from(filter_rev.condition)
+                .unwrap_or(AttachmentFilterConditionPB::AttachmentIsNotEmpty),
+            content: filter_rev.content.clone(),
+        }
+    }
+}
+
+impl std::convert::From<&FilterRevision> for AttachmentFilterPB {
+    fn from(rev: &FilterRevision) -> Self {
+        AttachmentFilterPB {
+            condition: AttachmentFilterConditionPB::try_from(rev.condition)
+                .unwrap_or(AttachmentFilterConditionPB::AttachmentIsNotEmpty),
+            content: rev.content.clone(),
+        }
+    }
+}
@@ -14,6 +14,9 @@ pub struct CheckboxFilterPB {
 pub enum CheckboxFilterConditionPB {
     IsChecked = 0,
     IsUnChecked = 1,
+    /// The checkbox has no unset state, so the unchecked checkbox is empty.
+    CheckboxIsEmpty = 2,
+    CheckboxIsNotEmpty = 3,
 }
 
 impl std::convert::From<CheckboxFilterConditionPB> for u32 {
@@ -35,6 +38,8 @@ impl std::convert::TryFrom<u8> for CheckboxFilterConditionPB {
         match value {
             0 => Ok(CheckboxFilterConditionPB::IsChecked),
             1 => Ok(CheckboxFilterConditionPB::IsUnChecked),
+            2 => Ok(CheckboxFilterConditionPB::CheckboxIsEmpty),
+            3 => Ok(CheckboxFilterConditionPB::CheckboxIsNotEmpty),
             _ => Err(ErrorCode::InvalidData),
         }
     }
@@ -7,6 +7,11 @@ use grid_model::FilterRevision;
 pub struct ChecklistFilterPB {
     #[pb(index = 1)]
     pub condition: ChecklistFilterConditionPB,
+
+    /// The percentage of the done items, from 0 to 100, of the
+    /// [ChecklistFilterConditionPB::CompletionAtLeast] condition.
+    #[pb(index = 2)]
+    pub percent: i32,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
@@ -14,6 +19,12 @@ pub struct ChecklistFilterPB {
 pub enum ChecklistFilterConditionPB {
     IsComplete = 0,
     IsIncomplete = 1,
+    /// The percentage of the done items is greater than or equal to the `percent` of the filter.
+    /// The checklist without any item is 0% done.
+    CompletionAtLeast = 2,
+    /// The checklist has no item.
+    ChecklistIsEmpty = 3,
+    ChecklistIsNotEmpty = 4,
 }
 
 impl std::convert::From<ChecklistFilterConditionPB> for u32 {
@@ -35,6 +46,9 @@ impl std::convert::TryFrom<u8> for ChecklistFilterConditionPB {
         match value {
             0 => Ok(ChecklistFilterConditionPB::IsComplete),
             1 => Ok(ChecklistFilterConditionPB::IsIncomplete),
+            2 => Ok(ChecklistFilterConditionPB::CompletionAtLeast),
+            3 => Ok(ChecklistFilterConditionPB::ChecklistIsEmpty),
+            4 => Ok(ChecklistFilterConditionPB::ChecklistIsNotEmpty),
             _ => Err(ErrorCode::InvalidData),
         }
     }
@@ -45,10 +59,7 @@ impl FromFilterString for ChecklistFilterPB {
     where
         Self: Sized,
     {
-        ChecklistFilterPB {
-            condition: ChecklistFilterConditionPB::try_from(filter_rev.condition)
-                .unwrap_or(ChecklistFilterConditionPB::IsIncomplete),
-        }
+        ChecklistFilterPB::from(filter_rev)
     }
 }
 
@@ -57,6 +68,7 @@ impl std::convert::From<&FilterRevision> for ChecklistFilterPB {
         ChecklistFilterPB {
             condition: ChecklistFilterConditionPB::try_from(rev.condition)
                 .unwrap_or(ChecklistFilterConditionPB::IsIncomplete),
+            percent: rev.content.parse::<i32>().unwrap_or(0),
         }
     }
 }
@@ -18,6 +18,10 @@ pub struct DateFilterPB {
 
     #[pb(index = 4, one_of)]
     pub timestamp: Option<i64>,
+
+    /// The number of the days of the `DateIsInLastDays` and `DateIsInNextDays` conditions.
+    #[pb(index = 5, one_of)]
+    pub days: Option<i64>,
 }
 
 #[derive(Deserialize, Serialize, Default, Clone, Debug)]
@@ -25,6 +29,8 @@ pub struct DateFilterContentPB {
     pub start: Option<i64>,
     pub end: Option<i64>,
     pub timestamp: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub days: Option<i64>,
 }
 
 impl ToString for DateFilterContentPB {
@@ -52,6 +58,30 @@ pub enum DateFilterConditionPB {
     DateWithIn = 5,
     DateIsEmpty = 6,
     DateIsNotEmpty = 7,
+    /// The conditions below are relative to the date of today in local time, so the rows they
+    /// show change from day to day.
+    DateIsToday = 8,
+    /// From Monday to Sunday of the current week.
+    DateIsThisWeek = 9,
+    DateIsThisMonth = 10,
+    /// From the date that is `days` before today to today.
+    DateIsInLastDays = 11,
+    /// From today to the date that is `days` after today.
+    DateIsInNextDays = 12,
+}
+
+impl DateFilterConditionPB {
+    /// Returns true if the condition is relative to the date of today.
+    pub fn is_relative(&self) -> bool {
+        matches!(
+            self,
+            DateFilterConditionPB::DateIsToday
+                | DateFilterConditionPB::DateIsThisWeek
+                | DateFilterConditionPB::DateIsThisMonth
+                | DateFilterConditionPB::DateIsInLastDays
+                | DateFilterConditionPB::DateIsInNextDays
+        )
+    }
 }
 
 impl std::convert::From<DateFilterConditionPB> for u32 {
@@ -77,6 +107,12 @@ impl std::convert::TryFrom<u8> for DateFilterConditionPB {
             4 => Ok(DateFilterConditionPB::DateOnOrAfter),
             5 => Ok(DateFilterConditionPB::DateWithIn),
             6 => Ok(DateFilterConditionPB::DateIsEmpty),
+            7 => Ok(DateFilterConditionPB::DateIsNotEmpty),
+            8 => Ok(DateFilterConditionPB::DateIsToday),
+            9 => Ok(DateFilterConditionPB::DateIsThisWeek),
+            10 => Ok(DateFilterConditionPB::DateIsThisMonth),
+            11 => Ok(DateFilterConditionPB::DateIsInLastDays),
+            12 => Ok(DateFilterConditionPB::DateIsInNextDays),
             _ => Err(ErrorCode::InvalidData),
         }
     }
@@ -96,6 +132,7 @@ impl FromFilterString for DateFilterPB {
             filter.start = content.start;
             filter.end = content.end;
             filter.timestamp = content.timestamp;
+            filter.days = content.days;
         };
 
         filter
@@ -113,6 +150,7 @@ impl std::convert::From<&FilterRevision> for DateFilterPB {
             filter.start = content.start;
             filter.end = content.end;
             filter.timestamp = content.timestamp;
+            filter.days = content.days;
         };
 
         filter
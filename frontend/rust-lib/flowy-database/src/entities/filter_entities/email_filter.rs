@@ -0,0 +1,77 @@
+use crate::services::filter::FromFilterString;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+use grid_model::FilterRevision;
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct EmailFilterPB {
+    #[pb(index = 1)]
+    pub condition: EmailFilterConditionPB,
+
+    #[pb(index = 2)]
+    pub content: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum EmailFilterConditionPB {
+    Is = 0,
+    IsNot = 1,
+    Contains = 2,
+    DoesNotContain = 3,
+    EmailIsEmpty = 4,
+    EmailIsNotEmpty = 5,
+    /// The cells that are not empty and not valid emails. For example, the text cells that were
+    /// converted to the email cells.
+    EmailIsInvalid = 6,
+}
+
+impl std::convert::From<EmailFilterConditionPB> for u32 {
+    fn from(value: EmailFilterConditionPB) -> Self {
+        value as u32
+    }
+}
+
+impl std::default::Default for EmailFilterConditionPB {
+    fn default() -> Self {
+        EmailFilterConditionPB::Is
+    }
+}
+
+impl std::convert::TryFrom<u8> for EmailFilterConditionPB {
+    type Error = ErrorCode;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(EmailFilterConditionPB::Is),
+            1 => Ok(EmailFilterConditionPB::IsNot),
+            2 => Ok(EmailFilterConditionPB::Contains),
+            3 => Ok(EmailFilterConditionPB::DoesNotContain),
+            4 => Ok(EmailFilterConditionPB::EmailIsEmpty),
+            5 => Ok(EmailFilterConditionPB::EmailIsNotEmpty),
+            6 => Ok(EmailFilterConditionPB::EmailIsInvalid),
+            _ => Err(ErrorCode::InvalidData),
+        }
+    }
+}
+
+impl FromFilterString for EmailFilterPB {
+    fn from_filter_rev(filter_rev: &FilterRevision) -> Self
+    where
+        Self: Sized,
+    {
+        EmailFilterPB {
+            condition: EmailFilterConditionPB::try_from(filter_rev.condition).unwrap_or(EmailFilterConditionPB::Is),
+            content: filter_rev.content.clone(),
+        }
+    }
+}
+
+impl std::convert::From<&FilterRevision> for EmailFilterPB {
+    fn from(rev: &FilterRevision) -> Self {
+        EmailFilterPB {
+            condition: EmailFilterConditionPB::try_from(rev.condition).unwrap_or(EmailFilterConditionPB::Is),
+            content: rev.content.clone(),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::entities::FilterPB;
+use crate::entities::{FilterGroupPB, FilterPB};
 use flowy_derive::ProtoBuf;
 
 #[derive(Debug, Default, ProtoBuf)]
@@ -14,6 +14,10 @@ pub struct FilterChangesetNotificationPB {
 
     #[pb(index = 4)]
     pub update_filters: Vec<UpdatedFilter>,
+
+    /// The whole filter tree of the view after the change.
+    #[pb(index = 5, one_of)]
+    pub filter_tree: Option<FilterGroupPB>,
 }
 
 #[derive(Debug, Default, ProtoBuf)]
@@ -32,6 +36,7 @@ impl FilterChangesetNotificationPB {
             insert_filters: filters,
             delete_filters: Default::default(),
             update_filters: Default::default(),
+            filter_tree: None,
         }
     }
     pub fn from_delete(view_id: &str, filters: Vec<FilterPB>) -> Self {
@@ -40,6 +45,7 @@ impl FilterChangesetNotificationPB {
             insert_filters: Default::default(),
             delete_filters: filters,
             update_filters: Default::default(),
+            filter_tree: None,
         }
     }
 
@@ -49,6 +55,17 @@ impl FilterChangesetNotificationPB {
             insert_filters: Default::default(),
             delete_filters: Default::default(),
             update_filters: filters,
+            filter_tree: None,
+        }
+    }
+
+    pub fn from_filter_tree(view_id: &str, filter_tree: FilterGroupPB) -> Self {
+        Self {
+            view_id: view_id.to_string(),
+            insert_filters: Default::default(),
+            delete_filters: Default::default(),
+            update_filters: Default::default(),
+            filter_tree: Some(filter_tree),
         }
     }
 }
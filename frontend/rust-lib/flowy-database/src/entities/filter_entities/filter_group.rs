@@ -0,0 +1,145 @@
+use crate::entities::parser::NotEmptyStr;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+use grid_model::{
+    gen_grid_filter_id, FilterGroupRevision, FilterNodeRevision, FilterOperatorRevision, MAX_FILTER_GROUP_DEPTH,
+};
+use std::convert::TryInto;
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum FilterOperatorPB {
+    And = 0,
+    Or = 1,
+}
+
+impl std::default::Default for FilterOperatorPB {
+    fn default() -> Self {
+        FilterOperatorPB::And
+    }
+}
+
+impl std::convert::From<FilterOperatorRevision> for FilterOperatorPB {
+    fn from(rev: FilterOperatorRevision) -> Self {
+        match rev {
+            FilterOperatorRevision::And => FilterOperatorPB::And,
+            FilterOperatorRevision::Or => FilterOperatorPB::Or,
+        }
+    }
+}
+
+impl std::convert::From<FilterOperatorPB> for FilterOperatorRevision {
+    fn from(operator: FilterOperatorPB) -> Self {
+        match operator {
+            FilterOperatorPB::And => FilterOperatorRevision::And,
+            FilterOperatorPB::Or => FilterOperatorRevision::Or,
+        }
+    }
+}
+
+/// [FilterGroupPB] combines its children with the operator. The root group of a view is at the
+/// depth of 1, and the groups can be nested up to the depth of [MAX_FILTER_GROUP_DEPTH].
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct FilterGroupPB {
+    /// A new group is given an id if the id is empty.
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub operator: FilterOperatorPB,
+
+    #[pb(index = 3)]
+    pub children: Vec<FilterNodePB>,
+}
+
+/// [FilterNodePB] is either a filter, referenced by its id, or a nested group.
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct FilterNodePB {
+    #[pb(index = 1, one_of)]
+    pub filter_id: Option<String>,
+
+    #[pb(index = 2, one_of)]
+    pub group: Option<FilterGroupPB>,
+}
+
+impl std::convert::From<&FilterGroupRevision> for FilterGroupPB {
+    fn from(rev: &FilterGroupRevision) -> Self {
+        let children = rev
+            .children
+            .iter()
+            .map(|child| match child {
+                FilterNodeRevision::Filter(filter_id) => FilterNodePB {
+                    filter_id: Some(filter_id.clone()),
+                    group: None,
+                },
+                FilterNodeRevision::Group(group) => FilterNodePB {
+                    filter_id: None,
+                    group: Some(group.into()),
+                },
+            })
+            .collect();
+        Self {
+            id: rev.id.clone(),
+            operator: rev.operator.into(),
+            children,
+        }
+    }
+}
+
+impl TryInto<FilterGroupRevision> for FilterGroupPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<FilterGroupRevision, Self::Error> {
+        let id = if self.id.is_empty() {
+            gen_grid_filter_id()
+        } else {
+            self.id
+        };
+        let mut children = vec![];
+        for child in self.children {
+            let child = match (child.filter_id, child.group) {
+                (Some(filter_id), None) => {
+                    FilterNodeRevision::Filter(NotEmptyStr::parse(filter_id).map_err(|_| ErrorCode::FilterIdIsEmpty)?.0)
+                }
+                (None, Some(group)) => FilterNodeRevision::Group(group.try_into()?),
+                _ => return Err(ErrorCode::InvalidData),
+            };
+            children.push(child);
+        }
+        Ok(FilterGroupRevision {
+            id,
+            operator: self.operator.into(),
+            children,
+        })
+    }
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct UpdateFilterTreePayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub filter_tree: FilterGroupPB,
+}
+
+impl TryInto<UpdateFilterTreeParams> for UpdateFilterTreePayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateFilterTreeParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id)
+            .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+            .0;
+        let filter_tree: FilterGroupRevision = self.filter_tree.try_into()?;
+        if filter_tree.depth() > MAX_FILTER_GROUP_DEPTH {
+            return Err(ErrorCode::FilterGroupTooDeep);
+        }
+        Ok(UpdateFilterTreeParams { view_id, filter_tree })
+    }
+}
+
+#[derive(Debug)]
+pub struct UpdateFilterTreeParams {
+    pub view_id: String,
+    pub filter_tree: FilterGroupRevision,
+}
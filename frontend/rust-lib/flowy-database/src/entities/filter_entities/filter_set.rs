@@ -0,0 +1,82 @@
+use crate::entities::parser::NotEmptyStr;
+use crate::entities::{FilterGroupPB, FilterPB};
+use flowy_derive::ProtoBuf;
+use flowy_error::ErrorCode;
+use grid_model::FilterSetRevision;
+use std::convert::TryInto;
+
+/// [FilterSetPB] is the named snapshot of the filters of a view and the groups that combine them.
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct FilterSetPB {
+    #[pb(index = 1)]
+    pub name: String,
+
+    #[pb(index = 2)]
+    pub filters: Vec<FilterPB>,
+
+    #[pb(index = 3, one_of)]
+    pub filter_tree: Option<FilterGroupPB>,
+}
+
+impl std::convert::From<&FilterSetRevision> for FilterSetPB {
+    fn from(filter_set: &FilterSetRevision) -> Self {
+        Self {
+            name: filter_set.name.clone(),
+            filters: filter_set.filters.iter().map(FilterPB::from).collect(),
+            filter_tree: filter_set.filter_tree.as_ref().map(FilterGroupPB::from),
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct RepeatedFilterSetPB {
+    #[pb(index = 1)]
+    pub items: Vec<FilterSetPB>,
+}
+
+impl std::convert::From<Vec<FilterSetRevision>> for RepeatedFilterSetPB {
+    fn from(filter_sets: Vec<FilterSetRevision>) -> Self {
+        Self {
+            items: filter_sets.iter().map(FilterSetPB::from).collect(),
+        }
+    }
+}
+
+/// [FilterSetPayloadPB] identifies the filter set of the view by its name, it's used to save,
+/// apply and delete the filter set.
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct FilterSetPayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+}
+
+impl TryInto<FilterSetParams> for FilterSetPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<FilterSetParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id)
+            .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+            .0;
+        let name = NotEmptyStr::parse(self.name)
+            .map_err(|_| ErrorCode::FilterSetNameIsEmpty)?
+            .0;
+        Ok(FilterSetParams { view_id, name })
+    }
+}
+
+#[derive(Debug)]
+pub struct FilterSetParams {
+    pub view_id: String,
+    pub name: String,
+}
+
+/// [AppliedFilterSetPB] is the result of applying the filter set. The filters of the deleted fields,
+/// and the filters that can't be converted to the new field types of their fields, are skipped.
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct AppliedFilterSetPB {
+    #[pb(index = 1)]
+    pub skipped_filters: Vec<FilterPB>,
+}
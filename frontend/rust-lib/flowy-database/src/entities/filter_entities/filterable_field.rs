@@ -0,0 +1,209 @@
+use crate::entities::{
+    AttachmentFilterConditionPB, CheckboxFilterConditionPB, ChecklistFilterConditionPB, DateFilterConditionPB,
+    EmailFilterConditionPB, FieldType, NumberFilterConditionPB, PersonFilterConditionPB, SelectOptionConditionPB,
+    TextFilterConditionPB,
+};
+use flowy_derive::ProtoBuf;
+use grid_model::{FieldRevision, FilterRevision};
+use std::fmt::Debug;
+
+/// [FilterConditionPB] is one of the conditions that the filter of the field accepts.
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct FilterConditionPB {
+    /// The value of the condition that is used as the `condition` of the filter.
+    #[pb(index = 1)]
+    pub condition: i32,
+
+    /// The name of the condition, for example: `IsComplete`.
+    #[pb(index = 2)]
+    pub name: String,
+}
+
+/// [FilterableFieldPB] is the field that can be filtered, and the conditions of its field type.
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct FilterableFieldPB {
+    #[pb(index = 1)]
+    pub field_id: String,
+
+    #[pb(index = 2)]
+    pub field_type: FieldType,
+
+    #[pb(index = 3)]
+    pub name: String,
+
+    #[pb(index = 4)]
+    pub conditions: Vec<FilterConditionPB>,
+}
+
+impl std::convert::From<&FieldRevision> for FilterableFieldPB {
+    fn from(field_rev: &FieldRevision) -> Self {
+        let field_type: FieldType = field_rev.ty.into();
+        Self {
+            field_id: field_rev.id.clone(),
+            conditions: filter_conditions_of_field_type(&field_type),
+            field_type,
+            name: field_rev.name.clone(),
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct RepeatedFilterableFieldPB {
+    #[pb(index = 1)]
+    pub items: Vec<FilterableFieldPB>,
+}
+
+/// Returns the conditions that the filter of the field type accepts. The field types share the
+/// conditions of the filters they use, for example, the rating field uses the number conditions.
+pub fn filter_conditions_of_field_type(field_type: &FieldType) -> Vec<FilterConditionPB> {
+    match field_type {
+        FieldType::RichText | FieldType::URL | FieldType::Relation | FieldType::Formula | FieldType::PhoneNumber => {
+            conditions_of::<TextFilterConditionPB>()
+        }
+        FieldType::Number | FieldType::Rating | FieldType::Progress => conditions_of::<NumberFilterConditionPB>(),
+        FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => {
+            conditions_of::<DateFilterConditionPB>()
+        }
+        FieldType::SingleSelect | FieldType::MultiSelect => conditions_of::<SelectOptionConditionPB>(),
+        FieldType::Checklist => conditions_of::<ChecklistFilterConditionPB>(),
+        FieldType::Checkbox => conditions_of::<CheckboxFilterConditionPB>(),
+        FieldType::Email => conditions_of::<EmailFilterConditionPB>(),
+        FieldType::Attachment => conditions_of::<AttachmentFilterConditionPB>(),
+        FieldType::Person => conditions_of::<PersonFilterConditionPB>(),
+    }
+}
+
+/// Converts the filter to the filter of another field type, for example, after the field type of
+/// the filtered field is changed. The condition with the same name, like the `Is` of the text and
+/// the email filters, is kept with the content, and the conditions that check whether the cells
+/// are empty are converted to each other. Returns None if the condition can't be converted.
+pub fn convert_filter_rev(filter_rev: &FilterRevision, field_type: &FieldType) -> Option<FilterRevision> {
+    let from_field_type: FieldType = filter_rev.field_type.into();
+    let from_condition = filter_conditions_of_field_type(&from_field_type)
+        .into_iter()
+        .find(|condition| condition.condition == filter_rev.condition as i32)?;
+    let conditions = filter_conditions_of_field_type(field_type);
+    let (condition, content) = match conditions
+        .iter()
+        .find(|condition| condition.name == from_condition.name)
+    {
+        Some(condition) => (condition, filter_rev.content.clone()),
+        None => {
+            let emptiness = emptiness_of_condition(&from_condition.name)?;
+            let condition = conditions
+                .iter()
+                .find(|condition| emptiness_of_condition(&condition.name) == Some(emptiness))?;
+            (condition, "".to_owned())
+        }
+    };
+    Some(FilterRevision {
+        id: filter_rev.id.clone(),
+        field_id: filter_rev.field_id.clone(),
+        field_type: field_type.clone().into(),
+        condition: condition.condition as u8,
+        content,
+    })
+}
+
+/// Returns Some(true) if the condition of the filter of the field type checks whether the cells are
+/// empty, Some(false) if it checks whether the cells are not empty.
+pub fn emptiness_of_filter_condition(field_type: &FieldType, condition: u8) -> Option<bool> {
+    let condition = filter_conditions_of_field_type(field_type)
+        .into_iter()
+        .find(|filter_condition| filter_condition.condition == condition as i32)?;
+    emptiness_of_condition(&condition.name)
+}
+
+/// Returns Some(true) if the condition checks whether the cell is empty, Some(false) if it checks
+/// whether the cell is not empty, for example: `TextIsEmpty` and `NumberIsNotEmpty`.
+fn emptiness_of_condition(name: &str) -> Option<bool> {
+    if name.ends_with("IsNotEmpty") {
+        Some(false)
+    } else if name.ends_with("IsEmpty") {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+/// The values of the conditions start from 0 and have no gaps, so the conditions are collected
+/// until the value that is not a condition.
+fn conditions_of<T: TryFrom<u8> + Debug>() -> Vec<FilterConditionPB> {
+    (0..=u8::MAX)
+        .map_while(|value| {
+            let condition = T::try_from(value).ok()?;
+            Some(FilterConditionPB {
+                condition: value as i32,
+                name: format!("{:?}", condition),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::{
+        convert_filter_rev, emptiness_of_filter_condition, filter_conditions_of_field_type, CheckboxFilterConditionPB,
+        EmailFilterConditionPB, FieldType, NumberFilterConditionPB, TextFilterConditionPB,
+    };
+    use grid_model::{FieldTypeRevision, FilterRevision};
+    use strum::IntoEnumIterator;
+
+    fn make_filter_rev(field_type: FieldType, condition: u8, content: &str) -> FilterRevision {
+        FilterRevision {
+            id: "filter".to_owned(),
+            field_id: "field".to_owned(),
+            field_type: field_type.into(),
+            condition,
+            content: content.to_owned(),
+        }
+    }
+
+    #[test]
+    fn convert_filter_with_same_condition_name_test() {
+        let filter_rev = make_filter_rev(FieldType::RichText, TextFilterConditionPB::Contains as u8, "appflowy");
+        let converted = convert_filter_rev(&filter_rev, &FieldType::Email).unwrap();
+        assert_eq!(converted.field_type, FieldTypeRevision::from(FieldType::Email));
+        assert_eq!(converted.condition, EmailFilterConditionPB::Contains as u8);
+        assert_eq!(converted.content, "appflowy");
+        assert_eq!(converted.id, filter_rev.id);
+    }
+
+    #[test]
+    fn convert_emptiness_filter_test() {
+        let filter_rev = make_filter_rev(FieldType::Number, NumberFilterConditionPB::NumberIsNotEmpty as u8, "");
+        let converted = convert_filter_rev(&filter_rev, &FieldType::RichText).unwrap();
+        assert_eq!(converted.condition, TextFilterConditionPB::TextIsNotEmpty as u8);
+
+        let filter_rev = make_filter_rev(FieldType::RichText, TextFilterConditionPB::TextIsEmpty as u8, "");
+        let converted = convert_filter_rev(&filter_rev, &FieldType::Number).unwrap();
+        assert_eq!(converted.condition, NumberFilterConditionPB::NumberIsEmpty as u8);
+    }
+
+    #[test]
+    fn convert_unmatched_filter_test() {
+        let filter_rev = make_filter_rev(FieldType::Number, NumberFilterConditionPB::GreaterThan as u8, "1");
+        assert!(convert_filter_rev(&filter_rev, &FieldType::RichText).is_none());
+
+        let filter_rev = make_filter_rev(FieldType::Checkbox, CheckboxFilterConditionPB::IsChecked as u8, "");
+        assert!(convert_filter_rev(&filter_rev, &FieldType::RichText).is_none());
+    }
+
+    #[test]
+    fn convert_emptiness_filter_to_checkbox_test() {
+        let filter_rev = make_filter_rev(FieldType::RichText, TextFilterConditionPB::TextIsEmpty as u8, "");
+        let converted = convert_filter_rev(&filter_rev, &FieldType::Checkbox).unwrap();
+        assert_eq!(converted.condition, CheckboxFilterConditionPB::CheckboxIsEmpty as u8);
+    }
+
+    #[test]
+    fn every_field_type_has_emptiness_conditions_test() {
+        for field_type in FieldType::iter() {
+            let conditions = filter_conditions_of_field_type(&field_type)
+                .into_iter()
+                .flat_map(|condition| emptiness_of_filter_condition(&field_type, condition.condition as u8))
+                .collect::<Vec<_>>();
+            assert_eq!(conditions, vec![true, false], "{:?}", field_type);
+        }
+    }
+}
@@ -1,17 +1,31 @@
+mod attachment_filter;
 mod checkbox_filter;
 mod checklist_filter;
 mod date_filter;
+mod email_filter;
 mod filter_changeset;
+mod filter_group;
+mod filter_set;
+mod filterable_field;
 mod number_filter;
+mod person_filter;
+mod search;
 mod select_option_filter;
 mod text_filter;
 mod util;
 
+pub use attachment_filter::*;
 pub use checkbox_filter::*;
 pub use checklist_filter::*;
 pub use date_filter::*;
+pub use email_filter::*;
 pub use filter_changeset::*;
+pub use filter_group::*;
+pub use filter_set::*;
+pub use filterable_field::*;
 pub use number_filter::*;
+pub use person_filter::*;
+pub use search::*;
 pub use select_option_filter::*;
 pub use text_filter::*;
 pub use util::*;
@@ -2,6 +2,8 @@ use crate::services::filter::FromFilterString;
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::ErrorCode;
 use grid_model::FilterRevision;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
 pub struct NumberFilterPB {
@@ -10,6 +12,66 @@ pub struct NumberFilterPB {
 
     #[pb(index = 2)]
     pub content: String,
+
+    /// The inclusive lower bound of the [NumberFilterConditionPB::Between] condition.
+    #[pb(index = 3, one_of)]
+    pub start: Option<String>,
+
+    /// The inclusive upper bound of the [NumberFilterConditionPB::Between] condition.
+    #[pb(index = 4, one_of)]
+    pub end: Option<String>,
+
+    /// If true, the empty cells are compared as zero instead of being hidden by the comparisons.
+    #[pb(index = 5)]
+    pub treat_empty_as_zero: bool,
+}
+
+/// The content of the [FilterRevision] of the number filter that has the bounds or treats the
+/// empty cells as zero. The content of the other number filters is the plain number string.
+#[derive(Deserialize, Serialize, Default, Clone, Debug)]
+pub struct NumberFilterContentPB {
+    #[serde(default)]
+    pub content: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<String>,
+    #[serde(default)]
+    pub treat_empty_as_zero: bool,
+}
+
+impl NumberFilterContentPB {
+    pub fn from_filter(filter: &NumberFilterPB) -> Self {
+        Self {
+            content: filter.content.clone(),
+            start: filter.start.clone(),
+            end: filter.end.clone(),
+            treat_empty_as_zero: filter.treat_empty_as_zero,
+        }
+    }
+}
+
+impl ToString for NumberFilterContentPB {
+    fn to_string(&self) -> String {
+        if self.start.is_none() && self.end.is_none() && !self.treat_empty_as_zero {
+            return self.content.clone();
+        }
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+impl FromStr for NumberFilterContentPB {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.trim_start().starts_with('{') {
+            return Ok(Self {
+                content: s.to_owned(),
+                ..Default::default()
+            });
+        }
+        serde_json::from_str(s)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
@@ -23,6 +85,7 @@ pub enum NumberFilterConditionPB {
     LessThanOrEqualTo = 5,
     NumberIsEmpty = 6,
     NumberIsNotEmpty = 7,
+    Between = 8,
 }
 
 impl std::default::Default for NumberFilterConditionPB {
@@ -49,6 +112,7 @@ impl std::convert::TryFrom<u8> for NumberFilterConditionPB {
             5 => Ok(NumberFilterConditionPB::LessThanOrEqualTo),
             6 => Ok(NumberFilterConditionPB::NumberIsEmpty),
             7 => Ok(NumberFilterConditionPB::NumberIsNotEmpty),
+            8 => Ok(NumberFilterConditionPB::Between),
             _ => Err(ErrorCode::InvalidData),
         }
     }
@@ -59,18 +123,18 @@ impl FromFilterString for NumberFilterPB {
     where
         Self: Sized,
     {
-        NumberFilterPB {
-            condition: NumberFilterConditionPB::try_from(filter_rev.condition)
-                .unwrap_or(NumberFilterConditionPB::Equal),
-            content: filter_rev.content.clone(),
-        }
+        NumberFilterPB::from(filter_rev)
     }
 }
 impl std::convert::From<&FilterRevision> for NumberFilterPB {
     fn from(rev: &FilterRevision) -> Self {
+        let content = NumberFilterContentPB::from_str(&rev.content).unwrap_or_default();
         NumberFilterPB {
             condition: NumberFilterConditionPB::try_from(rev.condition).unwrap_or(NumberFilterConditionPB::Equal),
-            content: rev.content.clone(),
+            content: content.content,
+            start: content.start,
+            end: content.end,
+            treat_empty_as_zero: content.treat_empty_as_zero,
         }
     }
 }
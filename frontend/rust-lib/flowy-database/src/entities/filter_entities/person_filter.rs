@@ -0,0 +1,77 @@
+use crate::services::field::SelectOptionIds;
+use crate::services::filter::FromFilterString;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+use grid_model::FilterRevision;
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct PersonFilterPB {
+    #[pb(index = 1)]
+    pub condition: PersonFilterConditionPB,
+
+    /// The ids of the members of the [PersonFilterConditionPB::PersonIs] and the
+    /// [PersonFilterConditionPB::PersonIsNot] conditions.
+    #[pb(index = 2)]
+    pub member_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum PersonFilterConditionPB {
+    /// The cells that reference at least one of the members.
+    PersonIs = 0,
+    /// The cells that reference none of the members.
+    PersonIsNot = 1,
+    PersonIsEmpty = 2,
+    PersonIsNotEmpty = 3,
+}
+
+impl std::convert::From<PersonFilterConditionPB> for u32 {
+    fn from(value: PersonFilterConditionPB) -> Self {
+        value as u32
+    }
+}
+
+impl std::default::Default for PersonFilterConditionPB {
+    fn default() -> Self {
+        PersonFilterConditionPB::PersonIs
+    }
+}
+
+impl std::convert::TryFrom<u8> for PersonFilterConditionPB {
+    type Error = ErrorCode;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(PersonFilterConditionPB::PersonIs),
+            1 => Ok(PersonFilterConditionPB::PersonIsNot),
+            2 => Ok(PersonFilterConditionPB::PersonIsEmpty),
+            3 => Ok(PersonFilterConditionPB::PersonIsNotEmpty),
+            _ => Err(ErrorCode::InvalidData),
+        }
+    }
+}
+
+impl FromFilterString for PersonFilterPB {
+    fn from_filter_rev(filter_rev: &FilterRevision) -> Self
+    where
+        Self: Sized,
+    {
+        let ids = SelectOptionIds::from(filter_rev.content.clone());
+        PersonFilterPB {
+            condition: PersonFilterConditionPB::try_from(filter_rev.condition)
+                .unwrap_or(PersonFilterConditionPB::PersonIs),
+            member_ids: ids.into_inner(),
+        }
+    }
+}
+
+impl std::convert::From<&FilterRevision> for PersonFilterPB {
+    fn from(rev: &FilterRevision) -> Self {
+        let ids = SelectOptionIds::from(rev.content.clone());
+        PersonFilterPB {
+            condition: PersonFilterConditionPB::try_from(rev.condition).unwrap_or(PersonFilterConditionPB::PersonIs),
+            member_ids: ids.into_inner(),
+        }
+    }
+}
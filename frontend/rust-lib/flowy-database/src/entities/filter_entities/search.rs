@@ -0,0 +1,35 @@
+use crate::entities::parser::NotEmptyStr;
+use flowy_derive::ProtoBuf;
+use flowy_error::ErrorCode;
+use std::convert::TryInto;
+
+/// [SearchQueryPayloadPB] sets the search query of the view. The rows that don't contain the
+/// query in any of their cells are hidden until the query is cleared by sending None.
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct SearchQueryPayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2, one_of)]
+    pub query: Option<String>,
+}
+
+impl TryInto<SearchQueryParams> for SearchQueryPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<SearchQueryParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id)
+            .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+            .0;
+        Ok(SearchQueryParams {
+            view_id,
+            query: self.query,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct SearchQueryParams {
+    pub view_id: String,
+    pub query: Option<String>,
+}
@@ -20,6 +20,14 @@ pub enum SelectOptionConditionPB {
     OptionIsNot = 1,
     OptionIsEmpty = 2,
     OptionIsNotEmpty = 3,
+    /// The cell contains all the options of the filter.
+    OptionContainsAll = 4,
+    /// The cell contains at least one of the options of the filter.
+    OptionContainsAny = 5,
+    /// The options of the cell are the same as the options of the filter, regardless of the order.
+    OptionIsExactly = 6,
+    /// The cell contains none of the options of the filter.
+    OptionDoesNotContain = 7,
 }
 
 impl std::convert::From<SelectOptionConditionPB> for u32 {
@@ -43,6 +51,10 @@ impl std::convert::TryFrom<u8> for SelectOptionConditionPB {
             1 => Ok(SelectOptionConditionPB::OptionIsNot),
             2 => Ok(SelectOptionConditionPB::OptionIsEmpty),
             3 => Ok(SelectOptionConditionPB::OptionIsNotEmpty),
+            4 => Ok(SelectOptionConditionPB::OptionContainsAll),
+            5 => Ok(SelectOptionConditionPB::OptionContainsAny),
+            6 => Ok(SelectOptionConditionPB::OptionIsExactly),
+            7 => Ok(SelectOptionConditionPB::OptionDoesNotContain),
             _ => Err(ErrorCode::InvalidData),
         }
     }
@@ -1,6 +1,7 @@
 use crate::entities::parser::NotEmptyStr;
 use crate::entities::{
-    CheckboxFilterPB, ChecklistFilterPB, DateFilterContentPB, DateFilterPB, FieldType, NumberFilterPB,
+    AttachmentFilterPB, CheckboxFilterPB, ChecklistFilterConditionPB, ChecklistFilterPB, DateFilterContentPB,
+    DateFilterPB, EmailFilterPB, FieldType, NumberFilterContentPB, NumberFilterPB, PersonFilterPB,
     SelectOptionFilterPB, TextFilterPB,
 };
 use crate::services::field::SelectOptionIds;
@@ -32,13 +33,22 @@ impl std::convert::From<&FilterRevision> for FilterPB {
         let field_type: FieldType = rev.field_type.into();
         let bytes: Bytes = match field_type {
             FieldType::RichText => TextFilterPB::from(rev).try_into().unwrap(),
-            FieldType::Number => NumberFilterPB::from(rev).try_into().unwrap(),
-            FieldType::DateTime => DateFilterPB::from(rev).try_into().unwrap(),
+            FieldType::Number | FieldType::Rating | FieldType::Progress => {
+                NumberFilterPB::from(rev).try_into().unwrap()
+            }
+            FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => {
+                DateFilterPB::from(rev).try_into().unwrap()
+            }
             FieldType::SingleSelect => SelectOptionFilterPB::from(rev).try_into().unwrap(),
             FieldType::MultiSelect => SelectOptionFilterPB::from(rev).try_into().unwrap(),
             FieldType::Checklist => ChecklistFilterPB::from(rev).try_into().unwrap(),
             FieldType::Checkbox => CheckboxFilterPB::from(rev).try_into().unwrap(),
-            FieldType::URL => TextFilterPB::from(rev).try_into().unwrap(),
+            FieldType::Email => EmailFilterPB::from(rev).try_into().unwrap(),
+            FieldType::Attachment => AttachmentFilterPB::from(rev).try_into().unwrap(),
+            FieldType::Person => PersonFilterPB::from(rev).try_into().unwrap(),
+            FieldType::URL | FieldType::Relation | FieldType::Formula | FieldType::PhoneNumber => {
+                TextFilterPB::from(rev).try_into().unwrap()
+            }
         };
         Self {
             id: rev.id.clone(),
@@ -176,7 +186,11 @@ impl TryInto<AlterFilterParams> for AlterFilterPayloadPB {
         let bytes: &[u8] = self.data.as_ref();
 
         match self.field_type {
-            FieldType::RichText | FieldType::URL => {
+            FieldType::RichText
+            | FieldType::URL
+            | FieldType::Relation
+            | FieldType::Formula
+            | FieldType::PhoneNumber => {
                 let filter = TextFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
                 condition = filter.condition as u8;
                 content = filter.content;
@@ -185,26 +199,49 @@ impl TryInto<AlterFilterParams> for AlterFilterPayloadPB {
                 let filter = CheckboxFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
                 condition = filter.condition as u8;
             }
-            FieldType::Number => {
-                let filter = NumberFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
+            FieldType::Email => {
+                let filter = EmailFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
                 condition = filter.condition as u8;
                 content = filter.content;
             }
-            FieldType::DateTime => {
+            FieldType::Attachment => {
+                let filter = AttachmentFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
+                condition = filter.condition as u8;
+                content = filter.content;
+            }
+            FieldType::Number | FieldType::Rating | FieldType::Progress => {
+                let filter = NumberFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
+                condition = filter.condition as u8;
+                content = NumberFilterContentPB::from_filter(&filter).to_string();
+            }
+            FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => {
                 let filter = DateFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
                 condition = filter.condition as u8;
                 content = DateFilterContentPB {
                     start: filter.start,
                     end: filter.end,
                     timestamp: filter.timestamp,
+                    days: filter.days,
                 }
                 .to_string();
             }
-            FieldType::SingleSelect | FieldType::MultiSelect | FieldType::Checklist => {
+            FieldType::Checklist => {
+                let filter = ChecklistFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
+                condition = filter.condition as u8;
+                if filter.condition == ChecklistFilterConditionPB::CompletionAtLeast {
+                    content = filter.percent.clamp(0, 100).to_string();
+                }
+            }
+            FieldType::SingleSelect | FieldType::MultiSelect => {
                 let filter = SelectOptionFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
                 condition = filter.condition as u8;
                 content = SelectOptionIds::from(filter.option_ids).to_string();
             }
+            FieldType::Person => {
+                let filter = PersonFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
+                condition = filter.condition as u8;
+                content = SelectOptionIds::from(filter.member_ids).to_string();
+            }
         }
 
         Ok(AlterFilterParams {
@@ -14,6 +14,11 @@ pub struct DatabasePB {
 
     #[pb(index = 3)]
     pub rows: Vec<RowPB>,
+
+    /// The database was opened from its latest snapshot because it has too many revisions, and
+    /// its revisions are being replayed in the background.
+    #[pb(index = 4)]
+    pub is_repairing: bool,
 }
 
 #[derive(ProtoBuf, Default)]
@@ -34,6 +39,45 @@ impl AsRef<str> for DatabaseIdPB {
     }
 }
 
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct DatabaseNamePB {
+    #[pb(index = 1)]
+    pub database_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+}
+
+/// [DatabaseInfoPB] describes a database that is currently opened.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct DatabaseInfoPB {
+    #[pb(index = 1)]
+    pub id: String,
+
+    /// The number of the components that are holding the database.
+    #[pb(index = 2)]
+    pub ref_count: i64,
+
+    /// The timestamp, in seconds, of the last revision applied to the database.
+    #[pb(index = 3)]
+    pub last_modified: i64,
+
+    #[pb(index = 4)]
+    pub is_archived: bool,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct RepeatedDatabaseInfoPB {
+    #[pb(index = 1)]
+    pub items: Vec<DatabaseInfoPB>,
+}
+
+impl std::convert::From<Vec<DatabaseInfoPB>> for RepeatedDatabaseInfoPB {
+    fn from(items: Vec<DatabaseInfoPB>) -> Self {
+        Self { items }
+    }
+}
+
 #[derive(Debug, Clone, Default, ProtoBuf)]
 pub struct MoveFieldPayloadPB {
     #[pb(index = 1)]
@@ -72,6 +116,163 @@ impl TryInto<MoveFieldParams> for MoveFieldPayloadPB {
     }
 }
 
+/// [ReorderFieldsPayloadPB] moves all the fields at once, the `field_ids` are the ids of all the
+/// fields in their new order.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct ReorderFieldsPayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub field_ids: Vec<String>,
+}
+
+pub struct ReorderFieldsParams {
+    pub view_id: String,
+    pub field_ids: Vec<String>,
+}
+
+impl TryInto<ReorderFieldsParams> for ReorderFieldsPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ReorderFieldsParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+        if self.field_ids.iter().any(|field_id| field_id.is_empty()) {
+            return Err(ErrorCode::FieldIdIsEmpty);
+        }
+        Ok(ReorderFieldsParams {
+            view_id: view_id.0,
+            field_ids: self.field_ids,
+        })
+    }
+}
+
+/// [DeleteFieldsPayloadPB] deletes the fields at once. None of them is deleted if one of them is
+/// the primary field.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct DeleteFieldsPayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub field_ids: Vec<String>,
+}
+
+pub struct DeleteFieldsParams {
+    pub view_id: String,
+    pub field_ids: Vec<String>,
+}
+
+impl TryInto<DeleteFieldsParams> for DeleteFieldsPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<DeleteFieldsParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+        if self.field_ids.iter().any(|field_id| field_id.is_empty()) {
+            return Err(ErrorCode::FieldIdIsEmpty);
+        }
+        Ok(DeleteFieldsParams {
+            view_id: view_id.0,
+            field_ids: self.field_ids,
+        })
+    }
+}
+
+/// [ViewFieldVisibilityPayloadPB] shows or hides the field in the view only.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct ViewFieldVisibilityPayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub field_id: String,
+
+    #[pb(index = 3)]
+    pub visibility: bool,
+}
+
+pub struct ViewFieldVisibilityParams {
+    pub view_id: String,
+    pub field_id: String,
+    pub visibility: bool,
+}
+
+impl TryInto<ViewFieldVisibilityParams> for ViewFieldVisibilityPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ViewFieldVisibilityParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+        let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+        Ok(ViewFieldVisibilityParams {
+            view_id: view_id.0,
+            field_id: field_id.0,
+            visibility: self.visibility,
+        })
+    }
+}
+
+/// [ViewFieldWidthPayloadPB] resizes the field in the view only.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct ViewFieldWidthPayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub field_id: String,
+
+    #[pb(index = 3)]
+    pub width: i32,
+}
+
+pub struct ViewFieldWidthParams {
+    pub view_id: String,
+    pub field_id: String,
+    pub width: i32,
+}
+
+impl TryInto<ViewFieldWidthParams> for ViewFieldWidthPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ViewFieldWidthParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+        let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+        Ok(ViewFieldWidthParams {
+            view_id: view_id.0,
+            field_id: field_id.0,
+            width: self.width,
+        })
+    }
+}
+
+/// [ViewFrozenFieldCountPayloadPB] freezes the first `count` visible fields of the view.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct ViewFrozenFieldCountPayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub count: i32,
+}
+
+pub struct ViewFrozenFieldCountParams {
+    pub view_id: String,
+    pub count: usize,
+}
+
+impl TryInto<ViewFrozenFieldCountParams> for ViewFrozenFieldCountPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ViewFrozenFieldCountParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+        Ok(ViewFrozenFieldCountParams {
+            view_id: view_id.0,
+            count: self.count.max(0) as usize,
+        })
+    }
+}
+
+/// [MoveRowPayloadPB] moves the row to the place of the row with the `to_row_id`, or to the
+/// `to_index` of all the rows if it's set. The `to_row_id` can be empty if the `to_index` is set.
 #[derive(Debug, Clone, Default, ProtoBuf)]
 pub struct MoveRowPayloadPB {
     #[pb(index = 1)]
@@ -80,14 +281,34 @@ pub struct MoveRowPayloadPB {
     #[pb(index = 2)]
     pub from_row_id: String,
 
+    #[pb(index = 3, one_of)]
+    pub to_index: Option<i32>,
+
     #[pb(index = 4)]
     pub to_row_id: String,
+
+    /// Removes the sorts of the sorted view, so the row stays where it's moved to. Otherwise, the
+    /// row is moved in the manual order of the view, which is used once the sorts are removed.
+    #[pb(index = 5)]
+    pub remove_sorts: bool,
+}
+
+/// The position that the row is moved to.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum MoveRowTarget {
+    /// The row takes the place of the row with the id, it's after the row if it's moved down and
+    /// before the row if it's moved up.
+    RowId(String),
+    /// The index of the row among all the rows once it's moved. The row is moved to the end if the
+    /// index is out of bounds.
+    Index(usize),
 }
 
 pub struct MoveRowParams {
     pub view_id: String,
     pub from_row_id: String,
-    pub to_row_id: String,
+    pub to: MoveRowTarget,
+    pub remove_sorts: bool,
 }
 
 impl TryInto<MoveRowParams> for MoveRowPayloadPB {
@@ -96,15 +317,24 @@ impl TryInto<MoveRowParams> for MoveRowPayloadPB {
     fn try_into(self) -> Result<MoveRowParams, Self::Error> {
         let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
         let from_row_id = NotEmptyStr::parse(self.from_row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?;
-        let to_row_id = NotEmptyStr::parse(self.to_row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?;
+        let to = match self.to_index {
+            Some(to_index) if to_index < 0 => return Err(ErrorCode::OutOfBounds),
+            Some(to_index) => MoveRowTarget::Index(to_index as usize),
+            None => {
+                let to_row_id = NotEmptyStr::parse(self.to_row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?;
+                MoveRowTarget::RowId(to_row_id.0)
+            }
+        };
 
         Ok(MoveRowParams {
             view_id: view_id.0,
             from_row_id: from_row_id.0,
-            to_row_id: to_row_id.0,
+            to,
+            remove_sorts: self.remove_sorts,
         })
     }
 }
+
 #[derive(Debug, Clone, Default, ProtoBuf)]
 pub struct MoveGroupRowPayloadPB {
     #[pb(index = 1)]
@@ -118,6 +348,11 @@ pub struct MoveGroupRowPayloadPB {
 
     #[pb(index = 4, one_of)]
     pub to_row_id: Option<String>,
+
+    /// The group that the row is dragged out of. The row of the multi-select cell might be in
+    /// many groups, only the option of this group is replaced by the option of the `to_group_id`.
+    #[pb(index = 5, one_of)]
+    pub from_group_id: Option<String>,
 }
 
 pub struct MoveGroupRowParams {
@@ -125,6 +360,7 @@ pub struct MoveGroupRowParams {
     pub from_row_id: String,
     pub to_group_id: String,
     pub to_row_id: Option<String>,
+    pub from_group_id: Option<String>,
 }
 
 impl TryInto<MoveGroupRowParams> for MoveGroupRowPayloadPB {
@@ -139,12 +375,21 @@ impl TryInto<MoveGroupRowParams> for MoveGroupRowPayloadPB {
             None => None,
             Some(to_row_id) => Some(NotEmptyStr::parse(to_row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?.0),
         };
+        let from_group_id = match self.from_group_id {
+            None => None,
+            Some(from_group_id) => Some(
+                NotEmptyStr::parse(from_group_id)
+                    .map_err(|_| ErrorCode::GroupIdIsEmpty)?
+                    .0,
+            ),
+        };
 
         Ok(MoveGroupRowParams {
             view_id: view_id.0,
             from_row_id: from_row_id.0,
             to_group_id: to_group_id.0,
             to_row_id,
+            from_group_id,
         })
     }
 }
@@ -1,5 +1,9 @@
+use crate::entities::parser::NotEmptyStr;
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
-use grid_model::{GroupRevision, SelectOptionGroupConfigurationRevision};
+use flowy_error::ErrorCode;
+use grid_model::{GroupRevision, NumberGroupConfigurationRevision, SelectOptionGroupConfigurationRevision};
+use rust_decimal::Decimal;
+use std::str::FromStr;
 
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
 pub struct UrlGroupConfigurationPB {
@@ -49,6 +53,93 @@ impl std::convert::From<GroupRevision> for GroupRecordPB {
 pub struct NumberGroupConfigurationPB {
     #[pb(index = 1)]
     hide_empty: bool,
+
+    #[pb(index = 2)]
+    pub start: String,
+
+    #[pb(index = 3)]
+    pub width: String,
+
+    /// The ranges are between the breakpoints instead of the fixed width if it's not empty.
+    #[pb(index = 4)]
+    pub breakpoints: Vec<String>,
+}
+
+impl std::convert::From<NumberGroupConfigurationRevision> for NumberGroupConfigurationPB {
+    fn from(rev: NumberGroupConfigurationRevision) -> Self {
+        Self {
+            hide_empty: rev.hide_empty,
+            start: rev.start,
+            width: rev.width,
+            breakpoints: rev.breakpoints,
+        }
+    }
+}
+
+/// [UpdateNumberGroupRangesPayloadPB] changes the ranges of the numbers in the groups of the view
+/// that is grouped by the number field. The numbers are decimal strings, the empty start is `0`
+/// and the empty width is `10`.
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct UpdateNumberGroupRangesPayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub start: String,
+
+    #[pb(index = 3)]
+    pub width: String,
+
+    #[pb(index = 4)]
+    pub breakpoints: Vec<String>,
+}
+
+impl TryInto<UpdateNumberGroupRangesParams> for UpdateNumberGroupRangesPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateNumberGroupRangesParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id)
+            .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+            .0;
+        let parse_decimal = |s: &str| Decimal::from_str(s.trim()).map_err(|_| ErrorCode::NumberGroupRangeInvalid);
+        let start = match self.start.trim().is_empty() {
+            true => Decimal::ZERO,
+            false => parse_decimal(&self.start)?,
+        };
+        let mut breakpoints = self
+            .breakpoints
+            .iter()
+            .map(|breakpoint| parse_decimal(breakpoint))
+            .collect::<Result<Vec<Decimal>, ErrorCode>>()?;
+        breakpoints.sort();
+        breakpoints.dedup();
+
+        let width = match self.width.trim().is_empty() {
+            true => Decimal::TEN,
+            false => parse_decimal(&self.width)?,
+        };
+        if width <= Decimal::ZERO {
+            return Err(ErrorCode::NumberGroupRangeInvalid);
+        }
+
+        Ok(UpdateNumberGroupRangesParams {
+            view_id,
+            start: start.normalize().to_string(),
+            width: width.normalize().to_string(),
+            breakpoints: breakpoints
+                .into_iter()
+                .map(|breakpoint| breakpoint.normalize().to_string())
+                .collect(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateNumberGroupRangesParams {
+    pub view_id: String,
+    pub start: String,
+    pub width: String,
+    pub breakpoints: Vec<String>,
 }
 
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
@@ -76,6 +167,61 @@ impl std::default::Default for DateCondition {
     }
 }
 
+impl std::convert::From<grid_model::DateCondition> for DateCondition {
+    fn from(condition: grid_model::DateCondition) -> Self {
+        match condition {
+            grid_model::DateCondition::Relative => DateCondition::Relative,
+            grid_model::DateCondition::Day => DateCondition::Day,
+            grid_model::DateCondition::Week => DateCondition::Week,
+            grid_model::DateCondition::Month => DateCondition::Month,
+            grid_model::DateCondition::Year => DateCondition::Year,
+        }
+    }
+}
+
+impl std::convert::From<DateCondition> for grid_model::DateCondition {
+    fn from(condition: DateCondition) -> Self {
+        match condition {
+            DateCondition::Relative => grid_model::DateCondition::Relative,
+            DateCondition::Day => grid_model::DateCondition::Day,
+            DateCondition::Week => grid_model::DateCondition::Week,
+            DateCondition::Month => grid_model::DateCondition::Month,
+            DateCondition::Year => grid_model::DateCondition::Year,
+        }
+    }
+}
+
+/// [UpdateDateGroupConditionPayloadPB] changes the range of the dates in each group of the view
+/// that is grouped by the date field.
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct UpdateDateGroupConditionPayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub condition: DateCondition,
+}
+
+impl TryInto<UpdateDateGroupConditionParams> for UpdateDateGroupConditionPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateDateGroupConditionParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id)
+            .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+            .0;
+        Ok(UpdateDateGroupConditionParams {
+            view_id,
+            condition: self.condition,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateDateGroupConditionParams {
+    pub view_id: String,
+    pub condition: DateCondition,
+}
+
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
 pub struct CheckboxGroupConfigurationPB {
     #[pb(index = 1)]
@@ -38,6 +38,34 @@ impl TryInto<CreateRowParams> for CreateBoardCardPayloadPB {
     }
 }
 
+/// [LoadGroupsPayloadPB] loads the groups of the database view, the hidden groups are skipped
+/// unless `include_hidden` is set.
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct LoadGroupsPayloadPB {
+    #[pb(index = 1)]
+    pub database_id: String,
+
+    #[pb(index = 2)]
+    pub include_hidden: bool,
+}
+
+pub struct LoadGroupsParams {
+    pub database_id: String,
+    pub include_hidden: bool,
+}
+
+impl TryInto<LoadGroupsParams> for LoadGroupsPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<LoadGroupsParams, Self::Error> {
+        let database_id = NotEmptyStr::parse(self.database_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+        Ok(LoadGroupsParams {
+            database_id: database_id.0,
+            include_hidden: self.include_hidden,
+        })
+    }
+}
+
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
 pub struct GroupConfigurationPB {
     #[pb(index = 1)]
@@ -94,6 +122,9 @@ pub struct GroupPB {
 
     #[pb(index = 6)]
     pub is_visible: bool,
+
+    #[pb(index = 7)]
+    pub is_collapsed: bool,
 }
 
 impl std::convert::From<Group> for GroupPB {
@@ -105,6 +136,7 @@ impl std::convert::From<Group> for GroupPB {
             rows: group.rows,
             is_default: group.is_default,
             is_visible: group.is_visible,
+            is_collapsed: group.is_collapsed,
         }
     }
 }
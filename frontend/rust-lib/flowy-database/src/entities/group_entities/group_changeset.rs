@@ -126,6 +126,180 @@ impl TryInto<MoveGroupParams> for MoveGroupPayloadPB {
     }
 }
 
+/// [CreateGroupPayloadPB] creates a new group in the view that is grouped by the select field, the
+/// group is created by adding a new option to the field.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct CreateGroupPayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+}
+
+#[derive(Debug)]
+pub struct CreateGroupParams {
+    pub view_id: String,
+    pub name: String,
+}
+
+impl TryInto<CreateGroupParams> for CreateGroupPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CreateGroupParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id)
+            .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+            .0;
+        let name = NotEmptyStr::parse(self.name.trim().to_owned())
+            .map_err(|_| ErrorCode::SelectOptionNameIsEmpty)?
+            .0;
+        Ok(CreateGroupParams { view_id, name })
+    }
+}
+
+/// [MoveGroupToIndexPayloadPB] moves the group to the index in the visible groups of the view. If
+/// `reorder_options` is set, the options of the select field are reordered as the groups, so the
+/// options are shown in the same order in the grid view.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct MoveGroupToIndexPayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub from_group_id: String,
+
+    #[pb(index = 3)]
+    pub to_index: i32,
+
+    #[pb(index = 4)]
+    pub reorder_options: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct MoveGroupToIndexParams {
+    pub view_id: String,
+    pub from_group_id: String,
+    pub to_index: usize,
+    pub reorder_options: bool,
+}
+
+impl TryInto<MoveGroupToIndexParams> for MoveGroupToIndexPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<MoveGroupToIndexParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id)
+            .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+            .0;
+        let from_group_id = NotEmptyStr::parse(self.from_group_id)
+            .map_err(|_| ErrorCode::GroupIdIsEmpty)?
+            .0;
+        if self.to_index < 0 {
+            return Err(ErrorCode::OutOfBounds);
+        }
+        Ok(MoveGroupToIndexParams {
+            view_id,
+            from_group_id,
+            to_index: self.to_index as usize,
+            reorder_options: self.reorder_options,
+        })
+    }
+}
+
+/// [GroupMovedNotificationPB] is sent with the `DidMoveGroup` notification, the indexes are the
+/// indexes in the visible groups of the view.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct GroupMovedNotificationPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub group_id: String,
+
+    #[pb(index = 3)]
+    pub from_index: i32,
+
+    #[pb(index = 4)]
+    pub to_index: i32,
+}
+
+/// [SetGroupCollapsedPayloadPB] collapses or expands the group of the view, the state is saved in
+/// the view, so the group is still collapsed when the view is opened again.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct SetGroupCollapsedPayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub group_id: String,
+
+    #[pb(index = 3)]
+    pub collapsed: bool,
+}
+
+#[derive(Debug)]
+pub struct SetGroupCollapsedParams {
+    pub view_id: String,
+    pub group_id: String,
+    pub collapsed: bool,
+}
+
+impl TryInto<SetGroupCollapsedParams> for SetGroupCollapsedPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<SetGroupCollapsedParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id)
+            .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+            .0;
+        let group_id = NotEmptyStr::parse(self.group_id)
+            .map_err(|_| ErrorCode::GroupIdIsEmpty)?
+            .0;
+        Ok(SetGroupCollapsedParams {
+            view_id,
+            group_id,
+            collapsed: self.collapsed,
+        })
+    }
+}
+
+/// [SetGroupVisibilityPayloadPB] hides or shows the group of the view. The rows of the hidden
+/// group are still in the group, but the group isn't shown on the board.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct SetGroupVisibilityPayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub group_id: String,
+
+    #[pb(index = 3)]
+    pub visible: bool,
+}
+
+#[derive(Debug)]
+pub struct SetGroupVisibilityParams {
+    pub view_id: String,
+    pub group_id: String,
+    pub visible: bool,
+}
+
+impl TryInto<SetGroupVisibilityParams> for SetGroupVisibilityPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<SetGroupVisibilityParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id)
+            .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+            .0;
+        let group_id = NotEmptyStr::parse(self.group_id)
+            .map_err(|_| ErrorCode::GroupIdIsEmpty)?
+            .0;
+        Ok(SetGroupVisibilityParams {
+            view_id,
+            group_id,
+            visible: self.visible,
+        })
+    }
+}
+
 #[derive(Debug, Default, ProtoBuf)]
 pub struct GroupViewChangesetPB {
     #[pb(index = 1)]
@@ -4,6 +4,7 @@ pub mod filter_entities;
 mod grid_entities;
 mod group_entities;
 pub mod parser;
+mod revision_diff_entities;
 mod row_entities;
 pub mod setting_entities;
 mod sort_entities;
@@ -14,6 +15,7 @@ pub use field_entities::*;
 pub use filter_entities::*;
 pub use grid_entities::*;
 pub use group_entities::*;
+pub use revision_diff_entities::*;
 pub use row_entities::*;
 pub use setting_entities::*;
 pub use sort_entities::*;
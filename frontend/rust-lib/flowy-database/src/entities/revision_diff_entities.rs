@@ -0,0 +1,107 @@
+use crate::entities::parser::NotEmptyStr;
+use crate::entities::FieldPB;
+use flowy_derive::ProtoBuf;
+use flowy_error::ErrorCode;
+
+/// [DatabaseDiffPB] describes the changes of the database between two revisions.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct DatabaseDiffPB {
+    #[pb(index = 1)]
+    pub database_id: String,
+
+    #[pb(index = 2)]
+    pub from_rev_id: i64,
+
+    #[pb(index = 3)]
+    pub to_rev_id: i64,
+
+    /// The database can't be reconstructed exactly at one of the revisions, because the revisions
+    /// were merged or compacted. The nearest available state is used instead.
+    #[pb(index = 4)]
+    pub is_approximate: bool,
+
+    #[pb(index = 5)]
+    pub inserted_fields: Vec<FieldPB>,
+
+    #[pb(index = 6)]
+    pub deleted_fields: Vec<FieldPB>,
+
+    #[pb(index = 7)]
+    pub updated_fields: Vec<FieldDiffPB>,
+
+    /// The changes of the rows of each block. The blocks whose rows were not changed are omitted.
+    #[pb(index = 8)]
+    pub blocks: Vec<BlockDiffPB>,
+}
+
+/// [FieldDiffPB] describes a field that exists in both revisions but was changed.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct FieldDiffPB {
+    /// The field at the `to_rev_id`.
+    #[pb(index = 1)]
+    pub field: FieldPB,
+
+    /// The names of the changed properties of the field, for example, "name" or "width".
+    #[pb(index = 2)]
+    pub changed_properties: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct BlockDiffPB {
+    #[pb(index = 1)]
+    pub block_id: String,
+
+    #[pb(index = 2)]
+    pub inserted_row_ids: Vec<String>,
+
+    #[pb(index = 3)]
+    pub deleted_row_ids: Vec<String>,
+
+    #[pb(index = 4)]
+    pub updated_rows: Vec<RowDiffPB>,
+}
+
+/// [RowDiffPB] describes a row that exists in both revisions but was changed. The
+/// `changed_field_ids` is empty if only the height or the visibility of the row was changed.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct RowDiffPB {
+    #[pb(index = 1)]
+    pub row_id: String,
+
+    #[pb(index = 2)]
+    pub changed_field_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct DatabaseDiffPayloadPB {
+    #[pb(index = 1)]
+    pub database_id: String,
+
+    #[pb(index = 2)]
+    pub from_rev_id: i64,
+
+    #[pb(index = 3)]
+    pub to_rev_id: i64,
+}
+
+pub struct DatabaseDiffParams {
+    pub database_id: String,
+    pub from_rev_id: i64,
+    pub to_rev_id: i64,
+}
+
+impl TryInto<DatabaseDiffParams> for DatabaseDiffPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<DatabaseDiffParams, Self::Error> {
+        let database_id = NotEmptyStr::parse(self.database_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+        if self.from_rev_id > self.to_rev_id {
+            return Err(ErrorCode::InvalidParams);
+        }
+        Ok(DatabaseDiffParams {
+            database_id: database_id.0,
+            from_rev_id: self.from_rev_id,
+            to_rev_id: self.to_rev_id,
+        })
+    }
+}
@@ -1,8 +1,9 @@
 use crate::entities::parser::NotEmptyStr;
-use crate::entities::DatabaseViewLayout;
+use crate::entities::{CellChangePB, DatabaseViewLayout};
 use flowy_derive::ProtoBuf;
 use flowy_error::ErrorCode;
-use grid_model::RowRevision;
+use grid_model::{CellRevision, RowMetaChangeset, RowMetaRevision, RowRevision, TrashedRowRevision};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 /// [RowPB] Describes a row. Has the id of the parent Block. Has the metadata of the row.
@@ -16,6 +17,13 @@ pub struct RowPB {
 
     #[pb(index = 3)]
     pub height: i32,
+
+    #[pb(index = 4)]
+    pub meta: RowMetaPB,
+
+    /// The id of the document that the row opens into.
+    #[pb(index = 5, one_of)]
+    pub document_id: Option<String>,
 }
 
 impl RowPB {
@@ -34,6 +42,8 @@ impl std::convert::From<&RowRevision> for RowPB {
             block_id: rev.block_id.clone(),
             id: rev.id.clone(),
             height: rev.height,
+            meta: RowMetaPB::from(&rev.meta),
+            document_id: rev.document_id.clone(),
         }
     }
 }
@@ -44,6 +54,8 @@ impl std::convert::From<&mut RowRevision> for RowPB {
             block_id: rev.block_id.clone(),
             id: rev.id.clone(),
             height: rev.height,
+            meta: RowMetaPB::from(&rev.meta),
+            document_id: rev.document_id.clone(),
         }
     }
 }
@@ -54,10 +66,80 @@ impl std::convert::From<&Arc<RowRevision>> for RowPB {
             block_id: rev.block_id.clone(),
             id: rev.id.clone(),
             height: rev.height,
+            meta: RowMetaPB::from(&rev.meta),
+            document_id: rev.document_id.clone(),
+        }
+    }
+}
+
+/// [RowMetaPB] describes the icon, cover and color of the row that are shown on the cards. The
+/// value is empty if it's not set.
+#[derive(Debug, Default, Clone, ProtoBuf, Eq, PartialEq)]
+pub struct RowMetaPB {
+    #[pb(index = 1)]
+    pub icon: String,
+
+    #[pb(index = 2)]
+    pub cover: String,
+
+    #[pb(index = 3)]
+    pub color: String,
+}
+
+impl std::convert::From<&RowMetaRevision> for RowMetaPB {
+    fn from(meta: &RowMetaRevision) -> Self {
+        Self {
+            icon: meta.icon.clone().unwrap_or_default(),
+            cover: meta.cover.clone().unwrap_or_default(),
+            color: meta.color.clone().unwrap_or_default(),
         }
     }
 }
 
+/// [UpdateRowMetaPayloadPB] is used to update the meta of the row. The value that is not set is
+/// left unchanged, and the value that is empty is cleared.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct UpdateRowMetaPayloadPB {
+    #[pb(index = 1)]
+    pub database_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+
+    #[pb(index = 3, one_of)]
+    pub icon: Option<String>,
+
+    #[pb(index = 4, one_of)]
+    pub cover: Option<String>,
+
+    #[pb(index = 5, one_of)]
+    pub color: Option<String>,
+}
+
+pub struct UpdateRowMetaParams {
+    pub database_id: String,
+    pub row_id: String,
+    pub changeset: RowMetaChangeset,
+}
+
+impl TryInto<UpdateRowMetaParams> for UpdateRowMetaPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateRowMetaParams, Self::Error> {
+        let database_id = NotEmptyStr::parse(self.database_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+        let row_id = NotEmptyStr::parse(self.row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?;
+        Ok(UpdateRowMetaParams {
+            database_id: database_id.0,
+            row_id: row_id.0,
+            changeset: RowMetaChangeset {
+                icon: self.icon,
+                cover: self.cover,
+                color: self.color,
+            },
+        })
+    }
+}
+
 #[derive(Debug, Default, ProtoBuf)]
 pub struct OptionalRowPB {
     #[pb(index = 1, one_of)]
@@ -131,6 +213,12 @@ pub struct UpdatedRowPB {
     // represents as the cells that were updated in this row.
     #[pb(index = 2)]
     pub field_ids: Vec<String>,
+
+    // The old and new values of the updated cells. It's empty unless the cell values are enabled
+    // in the notifications, and it's always empty for the cells that are changed by the field
+    // operations, for example, clearing the cells of a field.
+    #[pb(index = 3)]
+    pub cell_changes: Vec<CellChangePB>,
 }
 
 #[derive(Debug, Default, Clone, ProtoBuf)]
@@ -161,6 +249,274 @@ impl TryInto<RowIdParams> for RowIdPB {
     }
 }
 
+/// [ViewRowIdPB] identifies a row in a view, for example, the row that is pinned to the top of
+/// the view.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct ViewRowIdPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+}
+
+pub struct ViewRowIdParams {
+    pub view_id: String,
+    pub row_id: String,
+}
+
+impl TryInto<ViewRowIdParams> for ViewRowIdPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ViewRowIdParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+        let row_id = NotEmptyStr::parse(self.row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?;
+        Ok(ViewRowIdParams {
+            view_id: view_id.0,
+            row_id: row_id.0,
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct DuplicateRowPayloadPB {
+    #[pb(index = 1)]
+    pub database_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+
+    /// Copies the comments of the cells to the duplicated row if it's true.
+    #[pb(index = 3)]
+    pub include_comments: bool,
+
+    /// Returns the document of the row as the `source_document_id` of [DuplicatedRowPB] if it's
+    /// true, so the caller can duplicate the document for the duplicated row.
+    #[pb(index = 4)]
+    pub duplicate_document: bool,
+}
+
+pub struct DuplicateRowParams {
+    pub database_id: String,
+    pub row_id: String,
+    pub include_comments: bool,
+    pub duplicate_document: bool,
+}
+
+impl TryInto<DuplicateRowParams> for DuplicateRowPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<DuplicateRowParams, Self::Error> {
+        let database_id = NotEmptyStr::parse(self.database_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+        let row_id = NotEmptyStr::parse(self.row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?;
+
+        Ok(DuplicateRowParams {
+            database_id: database_id.0,
+            row_id: row_id.0,
+            include_comments: self.include_comments,
+            duplicate_document: self.duplicate_document,
+        })
+    }
+}
+
+/// [DuplicatedRowPB] is the result of duplicating a row. The duplicated row never shares the
+/// document of the row. The `source_document_id` is the document of the row that needs to be
+/// duplicated for the duplicated row, it's None unless the duplication of the document is asked.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct DuplicatedRowPB {
+    #[pb(index = 1)]
+    pub row: RowPB,
+
+    #[pb(index = 2, one_of)]
+    pub source_document_id: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct SetRowDocumentPayloadPB {
+    #[pb(index = 1)]
+    pub database_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+
+    /// Clears the document of the row if it's None.
+    #[pb(index = 3, one_of)]
+    pub document_id: Option<String>,
+}
+
+pub struct SetRowDocumentParams {
+    pub database_id: String,
+    pub row_id: String,
+    pub document_id: Option<String>,
+}
+
+impl TryInto<SetRowDocumentParams> for SetRowDocumentPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<SetRowDocumentParams, Self::Error> {
+        let database_id = NotEmptyStr::parse(self.database_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+        let row_id = NotEmptyStr::parse(self.row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?;
+        Ok(SetRowDocumentParams {
+            database_id: database_id.0,
+            row_id: row_id.0,
+            document_id: self.document_id.filter(|document_id| !document_id.is_empty()),
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct DeleteRowsPayloadPB {
+    #[pb(index = 1)]
+    pub database_id: String,
+
+    #[pb(index = 2)]
+    pub row_ids: Vec<String>,
+}
+
+pub struct DeleteRowsParams {
+    pub database_id: String,
+    pub row_ids: Vec<String>,
+}
+
+impl TryInto<DeleteRowsParams> for DeleteRowsPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<DeleteRowsParams, Self::Error> {
+        let database_id = NotEmptyStr::parse(self.database_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+        Ok(DeleteRowsParams {
+            database_id: database_id.0,
+            row_ids: self.row_ids,
+        })
+    }
+}
+
+/// [DeletedRowPB] describes the position of the row before it was deleted. The `index` is the index
+/// of the row among all the rows of the database.
+#[derive(Debug, Default, Clone, ProtoBuf, Eq, PartialEq)]
+pub struct DeletedRowPB {
+    #[pb(index = 1)]
+    pub row_id: String,
+
+    #[pb(index = 2)]
+    pub block_id: String,
+
+    #[pb(index = 3)]
+    pub index: i32,
+
+    /// The id of the document of the row, so the caller can clean up the document.
+    #[pb(index = 4, one_of)]
+    pub document_id: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedDeletedRowPB {
+    #[pb(index = 1)]
+    pub items: Vec<DeletedRowPB>,
+}
+
+impl std::convert::From<Vec<DeletedRowPB>> for RepeatedDeletedRowPB {
+    fn from(items: Vec<DeletedRowPB>) -> Self {
+        Self { items }
+    }
+}
+
+/// [DeleteRowsResultPB] is the result of deleting the rows in a batch. The ids of the rows that
+/// can't be found are skipped instead of failing the batch.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct DeleteRowsResultPB {
+    #[pb(index = 1)]
+    pub deleted_rows: Vec<DeletedRowPB>,
+
+    #[pb(index = 2)]
+    pub skipped_row_ids: Vec<String>,
+}
+
+/// [TrashedRowPB] describes a deleted row that can be restored until it's purged from the trash.
+#[derive(Debug, Default, Clone, ProtoBuf, Eq, PartialEq)]
+pub struct TrashedRowPB {
+    #[pb(index = 1)]
+    pub row: RowPB,
+
+    /// The time in seconds when the row was moved to the trash.
+    #[pb(index = 2)]
+    pub deleted_at: i64,
+}
+
+impl std::convert::From<&TrashedRowRevision> for TrashedRowPB {
+    fn from(trashed_row: &TrashedRowRevision) -> Self {
+        Self {
+            row: RowPB::from(&trashed_row.row),
+            deleted_at: trashed_row.deleted_at,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedTrashedRowPB {
+    #[pb(index = 1)]
+    pub items: Vec<TrashedRowPB>,
+}
+
+impl std::convert::From<Vec<TrashedRowPB>> for RepeatedTrashedRowPB {
+    fn from(items: Vec<TrashedRowPB>) -> Self {
+        Self { items }
+    }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RestoreRowsPayloadPB {
+    #[pb(index = 1)]
+    pub database_id: String,
+
+    #[pb(index = 2)]
+    pub row_ids: Vec<String>,
+}
+
+pub struct RestoreRowsParams {
+    pub database_id: String,
+    pub row_ids: Vec<String>,
+}
+
+impl TryInto<RestoreRowsParams> for RestoreRowsPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<RestoreRowsParams, Self::Error> {
+        let database_id = NotEmptyStr::parse(self.database_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+        Ok(RestoreRowsParams {
+            database_id: database_id.0,
+            row_ids: self.row_ids,
+        })
+    }
+}
+
+/// [PurgeTrashPayloadPB] is used to permanently delete the rows that were moved to the trash at or
+/// before the `older_than`, in seconds.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct PurgeTrashPayloadPB {
+    #[pb(index = 1)]
+    pub database_id: String,
+
+    #[pb(index = 2)]
+    pub older_than: i64,
+}
+
+pub struct PurgeTrashParams {
+    pub database_id: String,
+    pub older_than: i64,
+}
+
+impl TryInto<PurgeTrashParams> for PurgeTrashPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<PurgeTrashParams, Self::Error> {
+        let database_id = NotEmptyStr::parse(self.database_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+        Ok(PurgeTrashParams {
+            database_id: database_id.0,
+            older_than: self.older_than,
+        })
+    }
+}
+
 #[derive(Debug, Default, Clone, ProtoBuf)]
 pub struct BlockRowIdPB {
     #[pb(index = 1)]
@@ -201,3 +557,179 @@ impl TryInto<CreateRowParams> for CreateTableRowPayloadPB {
         })
     }
 }
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct CreateRowAtIndexPayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    /// The index in the order the view displays its rows.
+    #[pb(index = 2)]
+    pub index: i32,
+}
+
+pub struct CreateRowAtIndexParams {
+    pub view_id: String,
+    pub index: usize,
+}
+
+impl TryInto<CreateRowAtIndexParams> for CreateRowAtIndexPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<CreateRowAtIndexParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+        if self.index < 0 {
+            return Err(ErrorCode::OutOfBounds);
+        }
+        Ok(CreateRowAtIndexParams {
+            view_id: view_id.0,
+            index: self.index as usize,
+        })
+    }
+}
+
+/// [CreatedRowAtIndexPB] is the row that is created at an index of the view, with the index it
+/// ends up at once the sorts of the view are applied. The `index` is None if the row is hidden by
+/// the filters of the view.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct CreatedRowAtIndexPB {
+    #[pb(index = 1)]
+    pub row: RowPB,
+
+    #[pb(index = 2, one_of)]
+    pub index: Option<i32>,
+}
+
+/// [RowDataPayload] describes the cells of a row that is created in a batch, for example, by an
+/// importer. The value of each cell is the changeset string that is applied to an empty cell of
+/// the field. The cells of the other fields are filled with the default values of the fields.
+#[derive(Debug, Clone, Default)]
+pub struct RowDataPayload {
+    pub cell_by_field_id: HashMap<String, String>,
+    /// The cells that are copied to the row as they are, for example, the cells of a row
+    /// template. The changesets of `cell_by_field_id` are applied over them.
+    pub cell_revs: HashMap<String, CellRevision>,
+}
+
+impl RowDataPayload {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_cell(mut self, field_id: &str, cell_changeset: &str) -> Self {
+        self.cell_by_field_id
+            .insert(field_id.to_owned(), cell_changeset.to_owned());
+        self
+    }
+}
+
+/// [CellChangePB] describes the data of the cell before and after the change. The data is empty
+/// if the cell doesn't exist.
+#[derive(Debug, Clone, Default, ProtoBuf, Eq, PartialEq)]
+pub struct CellChangePB {
+    #[pb(index = 1)]
+    pub field_id: String,
+
+    #[pb(index = 2)]
+    pub before: String,
+
+    #[pb(index = 3)]
+    pub after: String,
+}
+
+/// [MovedRowPB] describes the positions of the row before and after it's moved. The indexes are
+/// the indexes among all the rows of the database.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct MovedRowPB {
+    #[pb(index = 1)]
+    pub row_id: String,
+
+    #[pb(index = 2)]
+    pub from_block_id: String,
+
+    #[pb(index = 3)]
+    pub from_index: i32,
+
+    #[pb(index = 4)]
+    pub to_block_id: String,
+
+    #[pb(index = 5)]
+    pub to_index: i32,
+}
+
+/// [RowChangePB] describes how a revision changed the row.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct RowChangePB {
+    #[pb(index = 1)]
+    pub rev_id: i64,
+
+    /// The timestamp, in seconds, when the revision was written to disk.
+    #[pb(index = 2)]
+    pub timestamp: i64,
+
+    #[pb(index = 3)]
+    pub changed_field_ids: Vec<String>,
+
+    #[pb(index = 4)]
+    pub cells: Vec<CellChangePB>,
+
+    /// The row was created by the revision.
+    #[pb(index = 5)]
+    pub is_created: bool,
+
+    /// The row was deleted by the revision.
+    #[pb(index = 6)]
+    pub is_deleted: bool,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct RepeatedRowChangePB {
+    #[pb(index = 1)]
+    pub items: Vec<RowChangePB>,
+}
+
+impl std::convert::From<Vec<RowChangePB>> for RepeatedRowChangePB {
+    fn from(items: Vec<RowChangePB>) -> Self {
+        Self { items }
+    }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowHistoryPayloadPB {
+    #[pb(index = 1)]
+    pub database_id: String,
+
+    #[pb(index = 2)]
+    pub row_id: String,
+
+    /// Only the changes that were made before the revision are returned. The latest changes are
+    /// returned if it's None.
+    #[pb(index = 3, one_of)]
+    pub before_rev_id: Option<i64>,
+
+    #[pb(index = 4)]
+    pub limit: i32,
+}
+
+pub struct RowHistoryParams {
+    pub database_id: String,
+    pub row_id: String,
+    pub before_rev_id: Option<i64>,
+    pub limit: usize,
+}
+
+impl TryInto<RowHistoryParams> for RowHistoryPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<RowHistoryParams, Self::Error> {
+        let database_id = NotEmptyStr::parse(self.database_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+        let row_id = NotEmptyStr::parse(self.row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?;
+
+        Ok(RowHistoryParams {
+            database_id: database_id.0,
+            row_id: row_id.0,
+            before_rev_id: self.before_rev_id,
+            limit: self.limit.max(0) as usize,
+        })
+    }
+}
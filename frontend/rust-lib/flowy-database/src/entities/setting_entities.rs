@@ -2,7 +2,8 @@ use crate::entities::parser::NotEmptyStr;
 use crate::entities::{
     AlterFilterParams, AlterFilterPayloadPB, AlterSortParams, AlterSortPayloadPB, DeleteFilterParams,
     DeleteFilterPayloadPB, DeleteGroupParams, DeleteGroupPayloadPB, DeleteSortParams, DeleteSortPayloadPB,
-    InsertGroupParams, InsertGroupPayloadPB, RepeatedFilterPB, RepeatedGroupConfigurationPB, RepeatedSortPB,
+    FilterGroupPB, InsertGroupParams, InsertGroupPayloadPB, RepeatedFilterPB, RepeatedGroupConfigurationPB,
+    RepeatedSortPB, SortModePB,
 };
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::ErrorCode;
@@ -28,6 +29,22 @@ pub struct DatabaseViewSettingPB {
 
     #[pb(index = 5)]
     pub sorts: RepeatedSortPB,
+
+    /// The number of the leading visible fields that are frozen.
+    #[pb(index = 6)]
+    pub frozen_field_count: i32,
+
+    /// The ids of the rows that are pinned to the top of the view, in the order they were pinned.
+    #[pb(index = 7)]
+    pub pinned_row_ids: Vec<String>,
+
+    /// The groups that combine the filters.
+    #[pb(index = 8)]
+    pub filter_tree: FilterGroupPB,
+
+    /// The special order of the rows that replaces the sorts.
+    #[pb(index = 9)]
+    pub sort_mode: SortModePB,
 }
 
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
@@ -5,7 +5,7 @@ use std::sync::Arc;
 
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::ErrorCode;
-use grid_model::{FieldTypeRevision, SortCondition, SortRevision};
+use grid_model::{FieldTypeRevision, SortCondition, SortEmptyOrder, SortModeRevision, SortRevision};
 
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
 pub struct SortPB {
@@ -20,6 +20,20 @@ pub struct SortPB {
 
     #[pb(index = 4)]
     pub condition: SortConditionPB,
+
+    /// Compares the numbers in the texts by their values, so `item2` is before `item10`. It's true
+    /// by default.
+    #[pb(index = 5)]
+    pub natural: bool,
+
+    /// Sorts the select options by their names instead of their order in the type option.
+    #[pb(index = 6)]
+    pub alphabetical: bool,
+
+    /// Places the rows whose cells are empty before or after the other rows. They're placed
+    /// after the other rows by default.
+    #[pb(index = 7)]
+    pub empty_order: SortEmptyOrderPB,
 }
 
 impl std::convert::From<&SortRevision> for SortPB {
@@ -29,6 +43,9 @@ impl std::convert::From<&SortRevision> for SortPB {
             field_id: sort_rev.field_id.clone(),
             field_type: sort_rev.field_type.into(),
             condition: sort_rev.condition.clone().into(),
+            natural: sort_rev.natural,
+            alphabetical: sort_rev.alphabetical,
+            empty_order: sort_rev.empty_order.into(),
         }
     }
 }
@@ -74,6 +91,110 @@ impl std::convert::From<SortCondition> for SortConditionPB {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum SortEmptyOrderPB {
+    First = 0,
+    Last = 1,
+}
+impl std::default::Default for SortEmptyOrderPB {
+    fn default() -> Self {
+        Self::Last
+    }
+}
+
+impl std::convert::From<SortEmptyOrder> for SortEmptyOrderPB {
+    fn from(empty_order: SortEmptyOrder) -> Self {
+        match empty_order {
+            SortEmptyOrder::First => SortEmptyOrderPB::First,
+            SortEmptyOrder::Last => SortEmptyOrderPB::Last,
+        }
+    }
+}
+
+/// [SortModePB] is the special order of the rows of the view. It's not one of the sorts, so it
+/// doesn't reference a field. The sorts are not used while the mode is `Shuffle` or `LastModified`.
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum SortModePB {
+    /// The rows are sorted by the sorts of the fields.
+    Field = 0,
+    /// The rows are in a random order that is kept until they're shuffled again.
+    Shuffle = 1,
+    /// The rows that were modified most recently are the first.
+    LastModified = 2,
+}
+impl std::default::Default for SortModePB {
+    fn default() -> Self {
+        Self::Field
+    }
+}
+
+impl std::convert::From<Option<SortModeRevision>> for SortModePB {
+    fn from(sort_mode: Option<SortModeRevision>) -> Self {
+        match sort_mode {
+            None => SortModePB::Field,
+            Some(SortModeRevision::Shuffle { .. }) => SortModePB::Shuffle,
+            Some(SortModeRevision::LastModified) => SortModePB::LastModified,
+        }
+    }
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct UpdateSortModePayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub sort_mode: SortModePB,
+}
+
+impl TryInto<UpdateSortModeParams> for UpdateSortModePayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<UpdateSortModeParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id)
+            .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+            .0;
+        Ok(UpdateSortModeParams {
+            view_id,
+            sort_mode: self.sort_mode,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateSortModeParams {
+    pub view_id: String,
+    pub sort_mode: SortModePB,
+}
+
+/// [RowOrderSourcePB] is where the order of the rows of the view comes from.
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum RowOrderSourcePB {
+    /// The rows are in the order of their blocks.
+    Block = 0,
+    /// The rows are in the order they were placed by hand while the view was sorted.
+    Manual = 1,
+    /// The rows are sorted by the sorts of the view.
+    Sort = 2,
+}
+impl std::default::Default for RowOrderSourcePB {
+    fn default() -> Self {
+        Self::Block
+    }
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct EffectiveRowOrderPB {
+    #[pb(index = 1)]
+    pub source: RowOrderSourcePB,
+
+    #[pb(index = 2)]
+    pub row_ids: Vec<String>,
+}
+
 #[derive(ProtoBuf, Debug, Default, Clone)]
 pub struct AlterSortPayloadPB {
     #[pb(index = 1)]
@@ -91,6 +212,21 @@ pub struct AlterSortPayloadPB {
 
     #[pb(index = 5)]
     pub condition: SortConditionPB,
+
+    /// Whether the numbers in the texts are compared by their values. The new sort is natural if
+    /// it's None, and the existing sort keeps its setting.
+    #[pb(index = 6, one_of)]
+    pub natural: Option<bool>,
+
+    /// Whether the select options are sorted by their names. The new sort uses the order of the
+    /// options if it's None, and the existing sort keeps its setting.
+    #[pb(index = 7, one_of)]
+    pub alphabetical: Option<bool>,
+
+    /// Where the rows whose cells are empty are placed. The new sort places them after the other
+    /// rows if it's None, and the existing sort keeps its setting.
+    #[pb(index = 8, one_of)]
+    pub empty_order: Option<SortEmptyOrderPB>,
 }
 
 impl TryInto<AlterSortParams> for AlterSortPayloadPB {
@@ -116,6 +252,9 @@ impl TryInto<AlterSortParams> for AlterSortPayloadPB {
             sort_id,
             field_type: self.field_type.into(),
             condition: self.condition as u8,
+            natural: self.natural,
+            alphabetical: self.alphabetical,
+            empty_order: self.empty_order.map(|empty_order| empty_order as u8),
         })
     }
 }
@@ -128,6 +267,9 @@ pub struct AlterSortParams {
     pub sort_id: Option<String>,
     pub field_type: FieldTypeRevision,
     pub condition: u8,
+    pub natural: Option<bool>,
+    pub alphabetical: Option<bool>,
+    pub empty_order: Option<u8>,
 }
 
 #[derive(ProtoBuf, Debug, Default, Clone)]
@@ -180,6 +322,49 @@ pub struct DeleteSortParams {
     pub sort_id: String,
 }
 
+/// [ReorderSortPayloadPB] moves the sort to the index of the sorts of the view. The rows are
+/// sorted by the sorts in their order, so the sort at the first index has the highest precedence.
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct ReorderSortPayloadPB {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub sort_id: String,
+
+    #[pb(index = 3)]
+    pub new_index: i32,
+}
+
+impl TryInto<ReorderSortParams> for ReorderSortPayloadPB {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<ReorderSortParams, Self::Error> {
+        let view_id = NotEmptyStr::parse(self.view_id)
+            .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+            .0;
+        let sort_id = NotEmptyStr::parse(self.sort_id)
+            .map_err(|_| ErrorCode::SortIdIsEmpty)?
+            .0;
+        if self.new_index < 0 {
+            return Err(ErrorCode::OutOfBounds);
+        }
+
+        Ok(ReorderSortParams {
+            view_id,
+            sort_id,
+            new_index: self.new_index as usize,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReorderSortParams {
+    pub view_id: String,
+    pub sort_id: String,
+    pub new_index: usize,
+}
+
 #[derive(Debug, Default, ProtoBuf)]
 pub struct SortChangesetNotificationPB {
     #[pb(index = 1)]
@@ -193,6 +378,11 @@ pub struct SortChangesetNotificationPB {
 
     #[pb(index = 4)]
     pub update_sorts: Vec<SortPB>,
+
+    /// The ids of all the sorts in the order of their precedence. It's not empty only if the
+    /// sorts were reordered.
+    #[pb(index = 5)]
+    pub ordered_sort_ids: Vec<String>,
 }
 
 impl SortChangesetNotificationPB {
@@ -202,6 +392,7 @@ impl SortChangesetNotificationPB {
             insert_sorts: vec![],
             delete_sorts: vec![],
             update_sorts: vec![],
+            ordered_sort_ids: vec![],
         }
     }
 
@@ -209,10 +400,16 @@ impl SortChangesetNotificationPB {
         self.insert_sorts.extend(other.insert_sorts);
         self.delete_sorts.extend(other.delete_sorts);
         self.update_sorts.extend(other.update_sorts);
+        if !other.ordered_sort_ids.is_empty() {
+            self.ordered_sort_ids = other.ordered_sort_ids;
+        }
     }
 
     pub fn is_empty(&self) -> bool {
-        self.insert_sorts.is_empty() && self.delete_sorts.is_empty() && self.update_sorts.is_empty()
+        self.insert_sorts.is_empty()
+            && self.delete_sorts.is_empty()
+            && self.update_sorts.is_empty()
+            && self.ordered_sort_ids.is_empty()
     }
 }
 
@@ -233,3 +430,11 @@ pub struct ReorderSingleRowPB {
     #[pb(index = 3)]
     pub new_index: i32,
 }
+
+/// [ReorderRowsPB] is the rows that were moved after the sorts of the view were changed. The rows
+/// that are not in the list keep their indexes.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct ReorderRowsPB {
+    #[pb(index = 1)]
+    pub items: Vec<ReorderSingleRowPB>,
+}
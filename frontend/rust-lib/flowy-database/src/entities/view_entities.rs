@@ -26,6 +26,11 @@ pub struct ViewRowsChangesetPB {
 
     #[pb(index = 4)]
     pub updated_rows: Vec<UpdatedRowPB>,
+
+    /// The ids of the rows that are pinned to the top of the view after the change, in the order
+    /// they were pinned.
+    #[pb(index = 5)]
+    pub pinned_row_ids: Vec<String>,
 }
 
 impl ViewRowsChangesetPB {
@@ -61,4 +66,12 @@ impl ViewRowsChangesetPB {
             ..Default::default()
         }
     }
+
+    pub fn from_pinned_rows(view_id: String, pinned_row_ids: Vec<String>) -> Self {
+        Self {
+            view_id,
+            pinned_row_ids,
+            ..Default::default()
+        }
+    }
 }
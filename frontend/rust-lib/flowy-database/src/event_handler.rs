@@ -1,9 +1,12 @@
 use crate::entities::*;
 use crate::manager::DatabaseManager;
-use crate::services::cell::{FromCellString, ToCellChangesetString, TypeCellData};
+use crate::services::cell::{CellDataDecoder, FromCellString, ToCellChangesetString, TypeCellData};
 use crate::services::field::{
     default_type_option_builder_from_type, select_type_option_from_field_rev, type_option_builder_from_json_str,
-    DateCellChangeset, DateChangesetPB, SelectOptionCellChangeset, SelectOptionCellChangesetPB,
+    AttachmentCellChangeset, AttachmentCellChangesetPB, AttachmentPB, ChecklistCellChangeset, ChecklistCellChangesetPB,
+    ChecklistTypeOptionPB, DateCellChangeset, DateChangesetPB, ImportAttachmentPayloadPB, MemberResolver,
+    PersonCellChangeset, PersonCellChangesetPB, RelationCellChangeset, RelationCellChangesetPB, RelationCellDataPB,
+    RepeatedMemberPB, RepeatedRelatedRowPB, SelectOptionCellChangeset, SelectOptionCellChangesetPB,
     SelectOptionCellChangesetParams, SelectOptionCellDataPB, SelectOptionChangeset, SelectOptionChangesetPB,
     SelectOptionIds, SelectOptionPB,
 };
@@ -24,13 +27,32 @@ pub(crate) async fn get_database_data_handler(
     data_result(database)
 }
 
+#[tracing::instrument(level = "trace", skip(manager), err)]
+pub(crate) async fn get_opened_databases_handler(
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedDatabaseInfoPB, FlowyError> {
+    let databases = manager.opened_databases().await;
+    data_result(databases.into())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn diff_database_revisions_handler(
+    data: AFPluginData<DatabaseDiffPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<DatabaseDiffPB, FlowyError> {
+    let params: DatabaseDiffParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    let diff = editor.diff_revisions(params.from_rev_id, params.to_rev_id).await?;
+    data_result(diff)
+}
+
 #[tracing::instrument(level = "trace", skip(data, manager), err)]
 pub(crate) async fn get_database_setting_handler(
     data: AFPluginData<DatabaseIdPB>,
     manager: AFPluginState<Arc<DatabaseManager>>,
 ) -> DataResult<DatabaseViewSettingPB, FlowyError> {
     let database_id: DatabaseIdPB = data.into_inner();
-    let editor = manager.open_database(database_id).await?;
+    let editor = manager.get_database_editor(database_id.as_ref()).await?;
     let database_setting = editor.get_setting().await?;
     data_result(database_setting)
 }
@@ -74,20 +96,99 @@ pub(crate) async fn get_all_filters_handler(
     manager: AFPluginState<Arc<DatabaseManager>>,
 ) -> DataResult<RepeatedFilterPB, FlowyError> {
     let database_id: DatabaseIdPB = data.into_inner();
-    let editor = manager.open_database(database_id).await?;
+    let editor = manager.get_database_editor(database_id.as_ref()).await?;
     let filters = RepeatedFilterPB {
         items: editor.get_all_filters().await?,
     };
     data_result(filters)
 }
 
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_filterable_fields_handler(
+    data: AFPluginData<DatabaseIdPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedFilterableFieldPB, FlowyError> {
+    let database_id: DatabaseIdPB = data.into_inner();
+    let editor = manager.get_database_editor(database_id.as_ref()).await?;
+    let fields = RepeatedFilterableFieldPB {
+        items: editor.get_filterable_fields().await?,
+    };
+    data_result(fields)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn set_search_query_handler(
+    data: AFPluginData<SearchQueryPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: SearchQueryParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    editor.set_search_query(&params.view_id, params.query).await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn update_filter_tree_handler(
+    data: AFPluginData<UpdateFilterTreePayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: UpdateFilterTreeParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    editor.update_filter_tree(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_filter_sets_handler(
+    data: AFPluginData<DatabaseIdPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedFilterSetPB, FlowyError> {
+    let view_id: DatabaseIdPB = data.into_inner();
+    let editor = manager.get_database_editor(view_id.as_ref()).await?;
+    let filter_sets = editor.get_filter_sets(view_id.as_ref()).await?;
+    data_result(filter_sets)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn save_filter_set_handler(
+    data: AFPluginData<FilterSetPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: FilterSetParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    editor.save_filter_set(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn apply_filter_set_handler(
+    data: AFPluginData<FilterSetPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<AppliedFilterSetPB, FlowyError> {
+    let params: FilterSetParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    let applied_filter_set = editor.apply_filter_set(params).await?;
+    data_result(applied_filter_set)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn delete_filter_set_handler(
+    data: AFPluginData<FilterSetPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: FilterSetParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    editor.delete_filter_set(params).await?;
+    Ok(())
+}
+
 #[tracing::instrument(level = "trace", skip(data, manager), err)]
 pub(crate) async fn get_all_sorts_handler(
     data: AFPluginData<DatabaseIdPB>,
     manager: AFPluginState<Arc<DatabaseManager>>,
 ) -> DataResult<RepeatedSortPB, FlowyError> {
     let database_id: DatabaseIdPB = data.into_inner();
-    let editor = manager.open_database(database_id.as_ref()).await?;
+    let editor = manager.get_database_editor(database_id.as_ref()).await?;
     let sorts = RepeatedSortPB {
         items: editor.get_all_sorts(database_id.as_ref()).await?,
     };
@@ -100,11 +201,66 @@ pub(crate) async fn delete_all_sorts_handler(
     manager: AFPluginState<Arc<DatabaseManager>>,
 ) -> Result<(), FlowyError> {
     let database_id: DatabaseIdPB = data.into_inner();
-    let editor = manager.open_database(database_id.as_ref()).await?;
+    let editor = manager.get_database_editor(database_id.as_ref()).await?;
     editor.delete_all_sorts(database_id.as_ref()).await?;
     Ok(())
 }
 
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn reorder_sort_handler(
+    data: AFPluginData<ReorderSortPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: ReorderSortParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    editor.reorder_sort(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn update_sort_mode_handler(
+    data: AFPluginData<UpdateSortModePayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: UpdateSortModeParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    editor.update_sort_mode(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn resort_shuffle_handler(
+    data: AFPluginData<DatabaseIdPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let database_id: DatabaseIdPB = data.into_inner();
+    let editor = manager.get_database_editor(database_id.as_ref()).await?;
+    editor.resort_shuffle(database_id.as_ref()).await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_sortable_fields_handler(
+    data: AFPluginData<DatabaseIdPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedFieldPB, FlowyError> {
+    let database_id: DatabaseIdPB = data.into_inner();
+    let editor = manager.get_database_editor(database_id.as_ref()).await?;
+    let fields: RepeatedFieldPB = editor.get_sortable_fields().await?.into();
+    data_result(fields)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_effective_row_order_handler(
+    data: AFPluginData<DatabaseIdPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<EffectiveRowOrderPB, FlowyError> {
+    let database_id: DatabaseIdPB = data.into_inner();
+    let editor = manager.get_database_editor(database_id.as_ref()).await?;
+    let row_order = editor.get_effective_row_order(database_id.as_ref()).await?;
+    data_result(row_order)
+}
+
 #[tracing::instrument(level = "trace", skip(data, manager), err)]
 pub(crate) async fn get_fields_handler(
     data: AFPluginData<GetFieldPayloadPB>,
@@ -112,7 +268,9 @@ pub(crate) async fn get_fields_handler(
 ) -> DataResult<RepeatedFieldPB, FlowyError> {
     let params: GetFieldParams = data.into_inner().try_into()?;
     let editor = manager.get_database_editor(&params.database_id).await?;
-    let field_revs = editor.get_field_revs(params.field_ids).await?;
+    let field_revs = editor
+        .get_view_field_revs(&params.database_id, params.field_ids)
+        .await?;
     let repeated_field: RepeatedFieldPB = field_revs.into_iter().map(FieldPB::from).collect::<Vec<_>>().into();
     data_result(repeated_field)
 }
@@ -158,6 +316,41 @@ pub(crate) async fn delete_field_handler(
     Ok(())
 }
 
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn delete_fields_handler(
+    data: AFPluginData<DeleteFieldsPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: DeleteFieldsParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    editor.delete_fields(params.field_ids).await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn set_field_locked_handler(
+    data: AFPluginData<FieldLockedPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: FieldLockedParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    editor.set_field_locked(&params.field_id, params.locked).await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn set_field_default_value_handler(
+    data: AFPluginData<FieldDefaultValuePayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: FieldDefaultValueParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    editor
+        .set_field_default_value(&params.field_id, params.default_value)
+        .await?;
+    Ok(())
+}
+
 #[tracing::instrument(level = "trace", skip(data, manager), err)]
 pub(crate) async fn switch_to_field_handler(
     data: AFPluginData<EditFieldChangesetPB>,
@@ -196,6 +389,31 @@ pub(crate) async fn duplicate_field_handler(
     Ok(())
 }
 
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn clear_field_cells_handler(
+    data: AFPluginData<GridFieldIdentifierPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: FieldIdParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    editor.clear_field_cells(&params.field_id).await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn find_invalid_cells_handler(
+    data: AFPluginData<GridFieldIdentifierPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<InvalidCellsPB, FlowyError> {
+    let params: FieldIdParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    let row_ids = editor.find_invalid_cells(&params.field_id).await?;
+    data_result(InvalidCellsPB {
+        field_id: params.field_id,
+        row_ids,
+    })
+}
+
 /// Return the FieldTypeOptionData if the Field exists otherwise return record not found error.
 #[tracing::instrument(level = "trace", skip(data, manager), err)]
 pub(crate) async fn get_field_type_option_data_handler(
@@ -240,6 +458,17 @@ pub(crate) async fn create_field_type_option_data_handler(
     })
 }
 
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_field_history_handler(
+    data: AFPluginData<GridFieldIdentifierPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedFieldChangePB, FlowyError> {
+    let params: FieldIdParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    let field_changes = editor.get_field_history(&params.field_id).await?;
+    data_result(field_changes.into())
+}
+
 #[tracing::instrument(level = "trace", skip(data, manager), err)]
 pub(crate) async fn move_field_handler(
     data: AFPluginData<MoveFieldPayloadPB>,
@@ -251,6 +480,76 @@ pub(crate) async fn move_field_handler(
     Ok(())
 }
 
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn reorder_fields_handler(
+    data: AFPluginData<ReorderFieldsPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: ReorderFieldsParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    editor.reorder_fields(params.field_ids).await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn set_primary_field_handler(
+    data: AFPluginData<GridFieldIdentifierPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: FieldIdParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    editor.set_primary_field(&params.field_id).await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn update_view_field_visibility_handler(
+    data: AFPluginData<ViewFieldVisibilityPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: ViewFieldVisibilityParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    editor
+        .set_view_field_visibility(&params.view_id, &params.field_id, params.visibility)
+        .await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn update_view_field_width_handler(
+    data: AFPluginData<ViewFieldWidthPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: ViewFieldWidthParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    editor
+        .set_view_field_width(&params.view_id, &params.field_id, params.width)
+        .await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn set_frozen_field_count_handler(
+    data: AFPluginData<ViewFrozenFieldCountPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: ViewFrozenFieldCountParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    editor.set_frozen_field_count(&params.view_id, params.count).await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn reorder_view_fields_handler(
+    data: AFPluginData<ReorderFieldsPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: ReorderFieldsParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    editor.set_view_field_order(&params.view_id, params.field_ids).await?;
+    Ok(())
+}
+
 /// The [FieldRevision] contains multiple data, each of them belongs to a specific FieldType.
 async fn get_type_option_data(field_rev: &FieldRevision, field_type: &FieldType) -> FlowyResult<Vec<u8>> {
     let s = field_rev
@@ -280,6 +579,19 @@ pub(crate) async fn get_row_handler(
     data_result(OptionalRowPB { row })
 }
 
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_row_history_handler(
+    data: AFPluginData<RowHistoryPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedRowChangePB, FlowyError> {
+    let params: RowHistoryParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    let row_changes = editor
+        .get_row_history(&params.row_id, params.before_rev_id, params.limit)
+        .await?;
+    data_result(row_changes.into())
+}
+
 #[tracing::instrument(level = "debug", skip(data, manager), err)]
 pub(crate) async fn delete_row_handler(
     data: AFPluginData<RowIdPB>,
@@ -291,17 +603,131 @@ pub(crate) async fn delete_row_handler(
     Ok(())
 }
 
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn delete_rows_handler(
+    data: AFPluginData<DeleteRowsPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<DeleteRowsResultPB, FlowyError> {
+    let params: DeleteRowsParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    let result = editor.delete_rows(params.row_ids).await?;
+    data_result(result)
+}
+
 #[tracing::instrument(level = "debug", skip(data, manager), err)]
 pub(crate) async fn duplicate_row_handler(
+    data: AFPluginData<DuplicateRowPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<DuplicatedRowPB, FlowyError> {
+    let params: DuplicateRowParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    let duplicated_row = editor
+        .duplicate_row(&params.row_id, params.include_comments, params.duplicate_document)
+        .await?;
+    data_result(duplicated_row)
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn restore_row_handler(
     data: AFPluginData<RowIdPB>,
     manager: AFPluginState<Arc<DatabaseManager>>,
-) -> Result<(), FlowyError> {
+) -> DataResult<RowPB, FlowyError> {
     let params: RowIdParams = data.into_inner().try_into()?;
     let editor = manager.get_database_editor(&params.database_id).await?;
-    editor.duplicate_row(&params.row_id).await?;
+    let row = editor.restore_row(&params.row_id).await?;
+    data_result(row)
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_trashed_rows_handler(
+    data: AFPluginData<DatabaseIdPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedTrashedRowPB, FlowyError> {
+    let database_id: DatabaseIdPB = data.into_inner();
+    let editor = manager.get_database_editor(database_id.as_ref()).await?;
+    let trashed_rows = editor.list_trashed_rows().await;
+    data_result(RepeatedTrashedRowPB::from(trashed_rows))
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn restore_rows_handler(
+    data: AFPluginData<RestoreRowsPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedRowPB, FlowyError> {
+    let params: RestoreRowsParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    let rows = editor.restore_rows(params.row_ids).await?;
+    data_result(RepeatedRowPB::from(rows))
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn purge_trash_handler(
+    data: AFPluginData<PurgeTrashPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedTrashedRowPB, FlowyError> {
+    let params: PurgeTrashParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    let purged_rows = editor.purge_trash(params.older_than).await?;
+    data_result(RepeatedTrashedRowPB::from(purged_rows))
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn pin_row_handler(
+    data: AFPluginData<ViewRowIdPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: ViewRowIdParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    editor.pin_row(&params.view_id, &params.row_id).await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn unpin_row_handler(
+    data: AFPluginData<ViewRowIdPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: ViewRowIdParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    editor.unpin_row(&params.view_id, &params.row_id).await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn update_row_meta_handler(
+    data: AFPluginData<UpdateRowMetaPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: UpdateRowMetaParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    editor.update_row_meta(&params.row_id, params.changeset).await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn set_row_document_handler(
+    data: AFPluginData<SetRowDocumentPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: SetRowDocumentParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    editor.set_row_document(&params.row_id, params.document_id).await?;
     Ok(())
 }
 
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn create_row_at_index_handler(
+    data: AFPluginData<CreateRowAtIndexPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<CreatedRowAtIndexPB, FlowyError> {
+    let params: CreateRowAtIndexParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    let row = editor
+        .create_row_at_index(&params.view_id, params.index, RowDataPayload::new())
+        .await?;
+    data_result(row)
+}
+
 #[tracing::instrument(level = "debug", skip(data, manager), err)]
 pub(crate) async fn move_row_handler(
     data: AFPluginData<MoveRowPayloadPB>,
@@ -350,6 +776,87 @@ pub(crate) async fn update_cell_handler(
     Ok(())
 }
 
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_cells_handler(
+    data: AFPluginData<UpdateCellsPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedCellUpdateErrorPB, FlowyError> {
+    let params: UpdateCellsParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    let errors = editor.update_cells(params.changesets, params.strict).await?;
+    data_result(errors.into())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn copy_range_handler(
+    data: AFPluginData<CopyRangePayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RangeClipboardPB, FlowyError> {
+    let params: CopyRangeParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    let clipboard = editor.copy_range(params).await?;
+    data_result(clipboard)
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn paste_range_handler(
+    data: AFPluginData<PasteRangePayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedCellUpdateErrorPB, FlowyError> {
+    let params: PasteRangeParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    let errors = editor.paste_range(params).await?;
+    data_result(errors.into())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn add_cell_comment_handler(
+    data: AFPluginData<AddCellCommentPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<CellCommentPB, FlowyError> {
+    let params: AddCellCommentParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.cell_path.database_id).await?;
+    let comment = editor
+        .add_cell_comment(&params.cell_path.row_id, &params.cell_path.field_id, &params.content)
+        .await?;
+    data_result(CellCommentPB::from(&comment))
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn delete_cell_comment_handler(
+    data: AFPluginData<DeleteCellCommentPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: DeleteCellCommentParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.cell_path.database_id).await?;
+    editor
+        .delete_cell_comment(&params.cell_path.row_id, &params.cell_path.field_id, &params.comment_id)
+        .await
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn append_to_cell_handler(
+    data: AFPluginData<AppendCellPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: AppendCellParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.cell_path.database_id).await?;
+    editor
+        .append_to_cell(&params.cell_path.row_id, &params.cell_path.field_id, &params.text)
+        .await
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_cell_comments_handler(
+    data: AFPluginData<CellPathPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedCellCommentPB, FlowyError> {
+    let params: CellPathParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    let comments = editor.get_cell_comments(&params.row_id, &params.field_id).await?;
+    data_result(RepeatedCellCommentPB::from(comments.as_slice()))
+}
+
 #[tracing::instrument(level = "trace", skip_all, err)]
 pub(crate) async fn new_select_option_handler(
     data: AFPluginData<CreateSelectOptionPayloadPB>,
@@ -440,7 +947,6 @@ pub(crate) async fn get_select_option_handler(
         Some(field_rev) => {
             //
             let cell_rev = editor.get_cell_rev(&params.row_id, &params.field_id).await?;
-            let type_option = select_type_option_from_field_rev(&field_rev)?;
             let type_cell_data: TypeCellData = match cell_rev {
                 None => TypeCellData {
                     cell_str: "".to_string(),
@@ -448,6 +954,15 @@ pub(crate) async fn get_select_option_handler(
                 },
                 Some(cell_rev) => cell_rev.try_into()?,
             };
+            // The checklist cells were read as the select option cells before the items were
+            // stored in the cells.
+            if FieldType::from(field_rev.ty).is_check_list() {
+                let type_option = ChecklistTypeOptionPB::from(&field_rev);
+                let cell_data =
+                    type_option.decode_cell_str(type_cell_data.cell_str, &type_cell_data.field_type, &field_rev)?;
+                return data_result(cell_data.to_select_option_cell_data());
+            }
+            let type_option = select_type_option_from_field_rev(&field_rev)?;
             let ids = SelectOptionIds::from_cell_str(&type_cell_data.cell_str)?;
             let selected_options = type_option.get_selected_options(ids);
             data_result(selected_options)
@@ -497,6 +1012,156 @@ pub(crate) async fn update_date_cell_handler(
     Ok(())
 }
 
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_checklist_cell_handler(
+    data: AFPluginData<ChecklistCellChangesetPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let data = data.into_inner();
+    let cell_path: CellPathParams = data.cell_path.clone().try_into()?;
+    let cell_changeset = ChecklistCellChangeset::from(data);
+
+    let editor = manager.get_database_editor(&cell_path.database_id).await?;
+    editor
+        .update_cell(cell_path.row_id, cell_path.field_id, cell_changeset)
+        .await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_relation_cell_data_handler(
+    data: AFPluginData<CellPathPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RelationCellDataPB, FlowyError> {
+    let params: CellPathParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    let cell_data = editor
+        .get_relation_cell_data(&params.row_id, &params.field_id, manager.as_ref().as_ref())
+        .await?;
+    data_result(cell_data)
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_relation_cell_handler(
+    data: AFPluginData<RelationCellChangesetPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let data = data.into_inner();
+    let cell_path: CellPathParams = data.cell_path.clone().try_into()?;
+    let cell_changeset = RelationCellChangeset::from(data);
+
+    let editor = manager.get_database_editor(&cell_path.database_id).await?;
+    editor
+        .update_cell(cell_path.row_id, cell_path.field_id, cell_changeset)
+        .await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_relation_candidate_rows_handler(
+    data: AFPluginData<GridFieldIdentifierPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedRelatedRowPB, FlowyError> {
+    let params: FieldIdParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    let rows = editor
+        .get_relation_candidate_rows(&params.field_id, manager.as_ref().as_ref())
+        .await?;
+    data_result(rows.into())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_attachment_cell_handler(
+    data: AFPluginData<AttachmentCellChangesetPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let data = data.into_inner();
+    let cell_path: CellPathParams = data.cell_path.clone().try_into()?;
+    let cell_changeset = AttachmentCellChangeset::from(data);
+
+    let editor = manager.get_database_editor(&cell_path.database_id).await?;
+    editor
+        .update_cell(cell_path.row_id, cell_path.field_id, cell_changeset)
+        .await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn import_attachment_handler(
+    data: AFPluginData<ImportAttachmentPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<AttachmentPB, FlowyError> {
+    let data = data.into_inner();
+    let cell_path: CellPathParams = data.cell_path.try_into()?;
+    let editor = manager.get_database_editor(&cell_path.database_id).await?;
+    let attachment = editor
+        .import_attachment(
+            &cell_path.row_id,
+            &cell_path.field_id,
+            &data.source_path,
+            &data.name,
+            &data.mime_type,
+        )
+        .await?;
+    data_result(attachment)
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_person_cell_handler(
+    data: AFPluginData<PersonCellChangesetPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let data = data.into_inner();
+    let cell_path: CellPathParams = data.cell_path.clone().try_into()?;
+    let cell_changeset = PersonCellChangeset::from(data);
+
+    let editor = manager.get_database_editor(&cell_path.database_id).await?;
+    let resolver = manager.get_member_resolver();
+    editor
+        .update_person_cell(
+            &cell_path.row_id,
+            &cell_path.field_id,
+            cell_changeset,
+            resolver.as_deref(),
+        )
+        .await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_person_candidates_handler(
+    data: AFPluginData<GridFieldIdentifierPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedMemberPB, FlowyError> {
+    let params: FieldIdParams = data.into_inner().try_into()?;
+    let resolver = get_member_resolver(&manager)?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    let members = editor
+        .get_person_candidates(&params.field_id, resolver.as_ref())
+        .await?;
+    data_result(members.into())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn refresh_person_members_handler(
+    data: AFPluginData<GridFieldIdentifierPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+    let params: FieldIdParams = data.into_inner().try_into()?;
+    let resolver = get_member_resolver(&manager)?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    editor
+        .refresh_person_members(&params.field_id, resolver.as_ref())
+        .await?;
+    Ok(())
+}
+
+fn get_member_resolver(manager: &DatabaseManager) -> FlowyResult<Arc<dyn MemberResolver>> {
+    manager
+        .get_member_resolver()
+        .ok_or_else(|| FlowyError::internal().context("The member resolver is not set"))
+}
+
 #[tracing::instrument(level = "trace", skip_all, err)]
 pub(crate) async fn get_groups_handler(
     data: AFPluginData<DatabaseIdPB>,
@@ -508,6 +1173,21 @@ pub(crate) async fn get_groups_handler(
     data_result(group)
 }
 
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn load_groups_handler(
+    data: AFPluginData<LoadGroupsPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedGroupPB, FlowyError> {
+    let params: LoadGroupsParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.database_id).await?;
+    let groups = if params.include_hidden {
+        editor.load_all_groups().await?
+    } else {
+        editor.load_groups().await?
+    };
+    data_result(groups)
+}
+
 #[tracing::instrument(level = "debug", skip(data, manager), err)]
 pub(crate) async fn create_board_card_handler(
     data: AFPluginData<CreateBoardCardPayloadPB>,
@@ -530,6 +1210,72 @@ pub(crate) async fn move_group_handler(
     Ok(())
 }
 
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn create_group_handler(
+    data: AFPluginData<CreateGroupPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<GroupPB, FlowyError> {
+    let params: CreateGroupParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    let group = editor.create_group(params).await?;
+    data_result(group)
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn move_group_to_index_handler(
+    data: AFPluginData<MoveGroupToIndexPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> FlowyResult<()> {
+    let params: MoveGroupToIndexParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    editor.move_group_to_index(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn set_group_visibility_handler(
+    data: AFPluginData<SetGroupVisibilityPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> FlowyResult<()> {
+    let params: SetGroupVisibilityParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    editor.set_group_visibility(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn set_group_collapsed_handler(
+    data: AFPluginData<SetGroupCollapsedPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> FlowyResult<()> {
+    let params: SetGroupCollapsedParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    editor.set_group_collapsed(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn update_date_group_condition_handler(
+    data: AFPluginData<UpdateDateGroupConditionPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> FlowyResult<()> {
+    let params: UpdateDateGroupConditionParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    editor.update_date_group_condition(params).await?;
+    Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn update_number_group_ranges_handler(
+    data: AFPluginData<UpdateNumberGroupRangesPayloadPB>,
+    manager: AFPluginState<Arc<DatabaseManager>>,
+) -> FlowyResult<()> {
+    let params: UpdateNumberGroupRangesParams = data.into_inner().try_into()?;
+    let editor = manager.get_database_editor(&params.view_id).await?;
+    editor.update_number_group_ranges(params).await?;
+    Ok(())
+}
+
 #[tracing::instrument(level = "debug", skip(data, manager), err)]
 pub(crate) async fn move_group_row_handler(
     data: AFPluginData<MoveGroupRowPayloadPB>,
@@ -13,42 +13,118 @@ pub fn init(database_manager: Arc<DatabaseManager>) -> AFPlugin {
         .event(DatabaseEvent::GetDatabaseSetting, get_database_setting_handler)
         .event(DatabaseEvent::UpdateDatabaseSetting, update_database_setting_handler)
         .event(DatabaseEvent::GetAllFilters, get_all_filters_handler)
+        .event(DatabaseEvent::UpdateFilterTree, update_filter_tree_handler)
+        .event(DatabaseEvent::GetFilterableFields, get_filterable_fields_handler)
+        .event(DatabaseEvent::SetSearchQuery, set_search_query_handler)
+        .event(DatabaseEvent::GetFilterSets, get_filter_sets_handler)
+        .event(DatabaseEvent::SaveFilterSet, save_filter_set_handler)
+        .event(DatabaseEvent::ApplyFilterSet, apply_filter_set_handler)
+        .event(DatabaseEvent::DeleteFilterSet, delete_filter_set_handler)
         .event(DatabaseEvent::GetAllSorts, get_all_sorts_handler)
         .event(DatabaseEvent::DeleteAllSorts, delete_all_sorts_handler)
+        .event(DatabaseEvent::ReorderSort, reorder_sort_handler)
+        .event(DatabaseEvent::GetEffectiveRowOrder, get_effective_row_order_handler)
+        .event(DatabaseEvent::GetSortableFields, get_sortable_fields_handler)
+        .event(DatabaseEvent::UpdateSortMode, update_sort_mode_handler)
+        .event(DatabaseEvent::ResortShuffle, resort_shuffle_handler)
+        .event(DatabaseEvent::GetOpenedDatabases, get_opened_databases_handler)
+        .event(DatabaseEvent::DiffDatabaseRevisions, diff_database_revisions_handler)
         // Field
         .event(DatabaseEvent::GetFields, get_fields_handler)
         .event(DatabaseEvent::UpdateField, update_field_handler)
         .event(DatabaseEvent::UpdateFieldTypeOption, update_field_type_option_handler)
         .event(DatabaseEvent::DeleteField, delete_field_handler)
+        .event(DatabaseEvent::DeleteFields, delete_fields_handler)
+        .event(DatabaseEvent::SetFieldLocked, set_field_locked_handler)
+        .event(DatabaseEvent::SetFieldDefaultValue, set_field_default_value_handler)
+        .event(DatabaseEvent::ClearFieldCells, clear_field_cells_handler)
+        .event(DatabaseEvent::FindInvalidCells, find_invalid_cells_handler)
         .event(DatabaseEvent::SwitchToField, switch_to_field_handler)
         .event(DatabaseEvent::DuplicateField, duplicate_field_handler)
         .event(DatabaseEvent::MoveField, move_field_handler)
+        .event(DatabaseEvent::ReorderFields, reorder_fields_handler)
+        .event(DatabaseEvent::SetPrimaryField, set_primary_field_handler)
+        .event(
+            DatabaseEvent::UpdateViewFieldVisibility,
+            update_view_field_visibility_handler,
+        )
+        .event(DatabaseEvent::ReorderViewFields, reorder_view_fields_handler)
+        .event(DatabaseEvent::UpdateViewFieldWidth, update_view_field_width_handler)
+        .event(DatabaseEvent::SetFrozenFieldCount, set_frozen_field_count_handler)
         .event(DatabaseEvent::GetFieldTypeOption, get_field_type_option_data_handler)
         .event(
             DatabaseEvent::CreateFieldTypeOption,
             create_field_type_option_data_handler,
         )
+        .event(DatabaseEvent::GetFieldHistory, get_field_history_handler)
         // Row
         .event(DatabaseEvent::CreateTableRow, create_table_row_handler)
         .event(DatabaseEvent::GetRow, get_row_handler)
         .event(DatabaseEvent::DeleteRow, delete_row_handler)
+        .event(DatabaseEvent::DeleteRows, delete_rows_handler)
         .event(DatabaseEvent::DuplicateRow, duplicate_row_handler)
         .event(DatabaseEvent::MoveRow, move_row_handler)
+        .event(DatabaseEvent::GetRowHistory, get_row_history_handler)
+        .event(DatabaseEvent::RestoreRow, restore_row_handler)
+        .event(DatabaseEvent::GetTrashedRows, get_trashed_rows_handler)
+        .event(DatabaseEvent::RestoreRows, restore_rows_handler)
+        .event(DatabaseEvent::PurgeTrash, purge_trash_handler)
+        .event(DatabaseEvent::PinRow, pin_row_handler)
+        .event(DatabaseEvent::UnpinRow, unpin_row_handler)
+        .event(DatabaseEvent::UpdateRowMeta, update_row_meta_handler)
+        .event(DatabaseEvent::SetRowDocument, set_row_document_handler)
+        .event(DatabaseEvent::CreateRowAtIndex, create_row_at_index_handler)
         // Cell
         .event(DatabaseEvent::GetCell, get_cell_handler)
         .event(DatabaseEvent::UpdateCell, update_cell_handler)
+        .event(DatabaseEvent::UpdateCells, update_cells_handler)
+        .event(DatabaseEvent::CopyRange, copy_range_handler)
+        .event(DatabaseEvent::PasteRange, paste_range_handler)
+        .event(DatabaseEvent::AddCellComment, add_cell_comment_handler)
+        .event(DatabaseEvent::DeleteCellComment, delete_cell_comment_handler)
+        .event(DatabaseEvent::GetCellComments, get_cell_comments_handler)
+        .event(DatabaseEvent::AppendToCell, append_to_cell_handler)
         // SelectOption
         .event(DatabaseEvent::NewSelectOption, new_select_option_handler)
         .event(DatabaseEvent::UpdateSelectOption, update_select_option_handler)
         .event(DatabaseEvent::GetSelectOptionCellData, get_select_option_handler)
         .event(DatabaseEvent::UpdateSelectOptionCell, update_select_option_cell_handler)
+        // Checklist
+        .event(DatabaseEvent::UpdateChecklistCell, update_checklist_cell_handler)
         // Date
         .event(DatabaseEvent::UpdateDateCell, update_date_cell_handler)
+        // Relation
+        .event(DatabaseEvent::GetRelationCellData, get_relation_cell_data_handler)
+        .event(DatabaseEvent::UpdateRelationCell, update_relation_cell_handler)
+        .event(
+            DatabaseEvent::GetRelationCandidateRows,
+            get_relation_candidate_rows_handler,
+        )
+        // Attachment
+        .event(DatabaseEvent::UpdateAttachmentCell, update_attachment_cell_handler)
+        .event(DatabaseEvent::ImportAttachment, import_attachment_handler)
+        // Person
+        .event(DatabaseEvent::UpdatePersonCell, update_person_cell_handler)
+        .event(DatabaseEvent::GetPersonCandidates, get_person_candidates_handler)
+        .event(DatabaseEvent::RefreshPersonMembers, refresh_person_members_handler)
         // Group
         .event(DatabaseEvent::CreateBoardCard, create_board_card_handler)
         .event(DatabaseEvent::MoveGroup, move_group_handler)
         .event(DatabaseEvent::MoveGroupRow, move_group_row_handler)
-        .event(DatabaseEvent::GetGroup, get_groups_handler);
+        .event(DatabaseEvent::GetGroup, get_groups_handler)
+        .event(
+            DatabaseEvent::UpdateDateGroupCondition,
+            update_date_group_condition_handler,
+        )
+        .event(
+            DatabaseEvent::UpdateNumberGroupRanges,
+            update_number_group_ranges_handler,
+        )
+        .event(DatabaseEvent::SetGroupCollapsed, set_group_collapsed_handler)
+        .event(DatabaseEvent::SetGroupVisibility, set_group_visibility_handler)
+        .event(DatabaseEvent::LoadGroups, load_groups_handler)
+        .event(DatabaseEvent::MoveGroupToIndex, move_group_to_index_handler)
+        .event(DatabaseEvent::CreateGroup, create_group_handler);
 
     plugin
 }
@@ -80,12 +156,89 @@ pub enum DatabaseEvent {
     #[event(input = "DatabaseIdPB", output = "RepeatedFilterPB")]
     GetAllFilters = 4,
 
+    /// [UpdateFilterTree] event is used to combine the filters of the view with the nested groups
+    /// of the `And` and `Or` operators. The groups can be nested up to the depth of 3.
+    #[event(input = "UpdateFilterTreePayloadPB")]
+    UpdateFilterTree = 1,
+
+    /// [GetFilterableFields] event is used to get the fields of the database, each of them with
+    /// the conditions that its filter accepts.
+    ///
+    /// The event handler accepts a [DatabaseIdPB] and returns a [RepeatedFilterableFieldPB].
+    #[event(input = "DatabaseIdPB", output = "RepeatedFilterableFieldPB")]
+    GetFilterableFields = 33,
+
+    /// [SetSearchQuery] event is used to search the rows of the view by the text of their cells.
+    /// The query is not saved with the view, and the rows are shown again after it's cleared.
+    #[event(input = "SearchQueryPayloadPB")]
+    SetSearchQuery = 34,
+
+    /// [GetFilterSets] event is used to get the named filter sets of the view.
+    #[event(input = "DatabaseIdPB", output = "RepeatedFilterSetPB")]
+    GetFilterSets = 35,
+
+    /// [SaveFilterSet] event is used to save the current filters of the view as the filter set
+    /// of the name. The filter set with the same name is replaced.
+    #[event(input = "FilterSetPayloadPB")]
+    SaveFilterSet = 36,
+
+    /// [ApplyFilterSet] event is used to replace the filters of the view with the filters of the
+    /// filter set. The event handler returns the filters that were skipped because their fields
+    /// were deleted or changed.
+    #[event(input = "FilterSetPayloadPB", output = "AppliedFilterSetPB")]
+    ApplyFilterSet = 37,
+
+    #[event(input = "FilterSetPayloadPB")]
+    DeleteFilterSet = 38,
+
     #[event(input = "DatabaseIdPB", output = "RepeatedSortPB")]
     GetAllSorts = 5,
 
     #[event(input = "DatabaseIdPB")]
     DeleteAllSorts = 6,
 
+    /// [ReorderSort] event is used to move the sort to the index of the sorts of the view, which
+    /// changes the precedence of the sort. The first sort has the highest precedence.
+    #[event(input = "ReorderSortPayloadPB")]
+    ReorderSort = 39,
+
+    /// [GetEffectiveRowOrder] event is used to get the ids of the rows of the view in the order
+    /// they're displayed, and whether the order comes from the sorts, the rows that were moved by
+    /// hand while the view was sorted, or the blocks of the rows.
+    #[event(input = "DatabaseIdPB", output = "EffectiveRowOrderPB")]
+    GetEffectiveRowOrder = 40,
+
+    /// [GetSortableFields] event is used to get the fields of the database that can be used as
+    /// the keys of the sorts.
+    ///
+    /// The event handler accepts a [DatabaseIdPB] and returns a [RepeatedFieldPB].
+    #[event(input = "DatabaseIdPB", output = "RepeatedFieldPB")]
+    GetSortableFields = 41,
+
+    /// [UpdateSortMode] event is used to order the rows of the view randomly or by the time they
+    /// were last modified. The sorts of the fields are not used while the mode is set.
+    #[event(input = "UpdateSortModePayloadPB")]
+    UpdateSortMode = 42,
+
+    /// [ResortShuffle] event is used to shuffle the rows of the view again with a new seed.
+    #[event(input = "DatabaseIdPB")]
+    ResortShuffle = 43,
+
+    /// [GetOpenedDatabases] event is used to get the databases that are currently opened.
+    ///
+    /// The event handler returns a [RepeatedDatabaseInfoPB]. Each [DatabaseInfoPB] contains the
+    /// reference count and the last modified time of the database.
+    #[event(output = "RepeatedDatabaseInfoPB")]
+    GetOpenedDatabases = 7,
+
+    /// [DiffDatabaseRevisions] event is used to get the changes of the database between two
+    /// revisions.
+    ///
+    /// The event handler accepts a [DatabaseDiffPayloadPB] and returns a [DatabaseDiffPB]. The diff
+    /// is flagged as approximate if the database can't be reconstructed exactly at the revisions.
+    #[event(input = "DatabaseDiffPayloadPB", output = "DatabaseDiffPB")]
+    DiffDatabaseRevisions = 8,
+
     /// [GetFields] event is used to get the database's settings.
     ///
     /// The event handler accepts a [GetFieldPayloadPB] and returns a [RepeatedFieldPB]
@@ -118,6 +271,31 @@ pub enum DatabaseEvent {
     #[event(input = "DeleteFieldPayloadPB")]
     DeleteField = 14,
 
+    /// [DeleteFields] event is used to delete the fields at once. The cells, filters, sorts and
+    /// groups of the deleted fields are removed too.
+    #[event(input = "DeleteFieldsPayloadPB")]
+    DeleteFields = 13,
+
+    /// [SetFieldLocked] event is used to lock or unlock a field. The cells, the field type and the
+    /// type-option of a locked field can't be edited, and it can't be deleted.
+    #[event(input = "FieldLockedPayloadPB")]
+    SetFieldLocked = 17,
+
+    /// [SetFieldDefaultValue] event is used to set or clear the value that the cells of the field
+    /// are filled with when the rows are created. The date field accepts `today` as the value.
+    #[event(input = "FieldDefaultValuePayloadPB")]
+    SetFieldDefaultValue = 9,
+
+    /// [ClearFieldCells] event is used to remove the cells of a field from all the rows. The cells
+    /// are restored by one undo. Returns an error if the field is the primary field or locked.
+    #[event(input = "GridFieldIdentifierPayloadPB")]
+    ClearFieldCells = 18,
+
+    /// [FindInvalidCells] event is used to find the rows whose cells violate the validation rules
+    /// of the field. The cells are not changed.
+    #[event(input = "GridFieldIdentifierPayloadPB", output = "InvalidCellsPB")]
+    FindInvalidCells = 19,
+
     /// [SwitchToField] event is used to update the current Field's type.
     /// It will insert a new FieldTypeOptionData if the new FieldType doesn't exist before, otherwise
     /// reuse the existing FieldTypeOptionData. You could check the [DatabaseRevisionPad] for more details.
@@ -138,6 +316,40 @@ pub enum DatabaseEvent {
     #[event(input = "MoveFieldPayloadPB")]
     MoveField = 22,
 
+    /// [ReorderFields] event is used to move all the fields at once. The passed in
+    /// [ReorderFieldsPayloadPB] must contain the ids of all the fields in their new order.
+    ///
+    /// Return errors if the ids are not the same as the ids of the existing fields.
+    #[event(input = "ReorderFieldsPayloadPB")]
+    ReorderFields = 26,
+
+    /// [SetPrimaryField] event is used to make the field the primary field of the database. The
+    /// old primary field becomes a normal field.
+    ///
+    /// Return errors if the field can't be the primary field, for example, a checkbox field.
+    #[event(input = "GridFieldIdentifierPayloadPB")]
+    SetPrimaryField = 27,
+
+    /// [UpdateViewFieldVisibility] event is used to show or hide the field in a view. The other
+    /// views of the database keep their own visibilities.
+    #[event(input = "ViewFieldVisibilityPayloadPB")]
+    UpdateViewFieldVisibility = 28,
+
+    /// [ReorderViewFields] event is used to reorder the fields in a view. The passed in
+    /// [ReorderFieldsPayloadPB] must contain the ids of all the fields in their new order.
+    #[event(input = "ReorderFieldsPayloadPB")]
+    ReorderViewFields = 29,
+
+    /// [UpdateViewFieldWidth] event is used to resize the field in a view. The width is clamped to
+    /// [MIN_FIELD_WIDTH].
+    #[event(input = "ViewFieldWidthPayloadPB")]
+    UpdateViewFieldWidth = 15,
+
+    /// [SetFrozenFieldCount] event is used to freeze the first fields of a view. The count is
+    /// clamped to the number of the visible fields.
+    #[event(input = "ViewFrozenFieldCountPayloadPB")]
+    SetFrozenFieldCount = 16,
+
     /// [TypeOptionPathPB] event is used to get the FieldTypeOption data for a specific field type.
     ///
     /// Check out the [TypeOptionPB] for more details. If the [FieldTypeOptionData] does exist
@@ -151,6 +363,13 @@ pub enum DatabaseEvent {
     #[event(input = "CreateFieldPayloadPB", output = "TypeOptionPB")]
     CreateFieldTypeOption = 24,
 
+    /// [GetFieldHistory] event is used to get the changes of a field, starting from the oldest one.
+    ///
+    /// The event handler accepts a [GridFieldIdentifierPayloadPB] and returns a [RepeatedFieldChangePB].
+    /// The changes of a deleted field are returned as long as its revisions are kept.
+    #[event(input = "GridFieldIdentifierPayloadPB", output = "RepeatedFieldChangePB")]
+    GetFieldHistory = 25,
+
     /// [NewSelectOption] event is used to create a new select option. Returns a [SelectOptionPB] if
     /// there are no errors.
     #[event(input = "CreateSelectOptionPayloadPB", output = "SelectOptionPB")]
@@ -182,12 +401,71 @@ pub enum DatabaseEvent {
     #[event(input = "RowIdPB")]
     DeleteRow = 52,
 
-    #[event(input = "RowIdPB")]
+    /// [DuplicateRow] event is used to insert a copy of the row right after it. The comments of the
+    /// cells are copied if the `include_comments` of [DuplicateRowPayloadPB] is true. The copy
+    /// doesn't share the document of the row, see [DuplicatedRowPB].
+    #[event(input = "DuplicateRowPayloadPB", output = "DuplicatedRowPB")]
     DuplicateRow = 53,
 
     #[event(input = "MoveRowPayloadPB")]
     MoveRow = 54,
 
+    /// [GetRowHistory] event is used to get the changes of a row, starting from the latest one.
+    ///
+    /// The event handler accepts a [RowHistoryPayloadPB] and returns a [RepeatedRowChangePB]. Pass
+    /// the smallest rev_id of the returned changes as the `before_rev_id` to load the older changes.
+    #[event(input = "RowHistoryPayloadPB", output = "RepeatedRowChangePB")]
+    GetRowHistory = 55,
+
+    /// [RestoreRow] event is used to restore a deleted row from the revisions of its block.
+    ///
+    /// Returns the restored [RowPB], or a `RecordNotFound` error if the row can't be found in the
+    /// revisions.
+    #[event(input = "RowIdPB", output = "RowPB")]
+    RestoreRow = 56,
+
+    /// [DeleteRows] event is used to delete the rows in a batch. The ids of the rows that can't be
+    /// found are skipped and returned in the [DeleteRowsResultPB].
+    #[event(input = "DeleteRowsPayloadPB", output = "DeleteRowsResultPB")]
+    DeleteRows = 57,
+
+    /// [GetTrashedRows] event is used to get the deleted rows that are in the trash of the
+    /// database, in the order they were deleted.
+    #[event(input = "DatabaseIdPB", output = "RepeatedTrashedRowPB")]
+    GetTrashedRows = 58,
+
+    /// [RestoreRows] event is used to restore the rows from the trash. The ids of the rows that are
+    /// not in the trash are skipped.
+    #[event(input = "RestoreRowsPayloadPB", output = "RepeatedRowPB")]
+    RestoreRows = 59,
+
+    /// [PurgeTrash] event is used to permanently delete the rows that were moved to the trash at or
+    /// before the given time. Returns the purged rows.
+    #[event(input = "PurgeTrashPayloadPB", output = "RepeatedTrashedRowPB")]
+    PurgeTrash = 60,
+
+    /// [PinRow] event is used to pin the row to the top of the view. The pinned rows are placed
+    /// before the other rows in the order they were pinned, regardless of the sorts of the view.
+    #[event(input = "ViewRowIdPB")]
+    PinRow = 61,
+
+    #[event(input = "ViewRowIdPB")]
+    UnpinRow = 62,
+
+    /// [UpdateRowMeta] event is used to update the icon, the cover and the color of the row.
+    #[event(input = "UpdateRowMetaPayloadPB")]
+    UpdateRowMeta = 63,
+
+    /// [SetRowDocument] event is used to set the document that the row opens into. The document
+    /// of the row is cleared if the `document_id` is not set.
+    #[event(input = "SetRowDocumentPayloadPB")]
+    SetRowDocument = 64,
+
+    /// [CreateRowAtIndex] event is used to create a row at an index of the view, in the order the
+    /// view displays its rows. The row is reordered by the sorts of the view once it's created.
+    #[event(input = "CreateRowAtIndexPayloadPB", output = "CreatedRowAtIndexPB")]
+    CreateRowAtIndex = 65,
+
     #[event(input = "CellPathPB", output = "CellPB")]
     GetCell = 70,
 
@@ -210,12 +488,104 @@ pub enum DatabaseEvent {
     #[event(input = "SelectOptionCellChangesetPB")]
     UpdateSelectOptionCell = 72,
 
+    /// [UpdateCells] event is used to update many cells at once, for example, when pasting a range
+    /// of cells. The changesets are applied in one revision per block, and the changed rows are
+    /// notified once.
+    ///
+    /// Returns the errors of the changesets that can't be applied. None of the changesets is
+    /// applied if any of them is invalid and the `strict` of [UpdateCellsPayloadPB] is true.
+    #[event(input = "UpdateCellsPayloadPB", output = "RepeatedCellUpdateErrorPB")]
+    UpdateCells = 74,
+
+    /// [CopyRange] event is used to copy a range of cells of a view. Returns the tsv of the display
+    /// strings of the cells, and the lossless json payload that is used by [PasteRange].
+    #[event(input = "CopyRangePayloadPB", output = "RangeClipboardPB")]
+    CopyRange = 75,
+
+    /// [PasteRange] event is used to paste a clipboard to the cells of a view, starting from the
+    /// anchor cell. The values are converted to the field types of the target cells. The rows are
+    /// created for the values that go past the last row if `create_rows` is true.
+    ///
+    /// Returns the errors of the cells that can't be pasted.
+    #[event(input = "PasteRangePayloadPB", output = "RepeatedCellUpdateErrorPB")]
+    PasteRange = 76,
+
+    /// [AddCellComment] event is used to append a comment written by the current user to the
+    /// comments of the cell. Returns the added [CellCommentPB].
+    #[event(input = "AddCellCommentPayloadPB", output = "CellCommentPB")]
+    AddCellComment = 77,
+
+    /// [DeleteCellComment] event is used to delete a comment of the cell. Returns a `RecordNotFound`
+    /// error if the cell doesn't have the comment.
+    #[event(input = "DeleteCellCommentPayloadPB")]
+    DeleteCellComment = 78,
+
+    /// [GetCellComments] event is used to get the comments of the cell in the order they were added.
+    #[event(input = "CellPathPB", output = "RepeatedCellCommentPB")]
+    GetCellComments = 79,
+
+    /// [UpdateChecklistCell] event is used to update the items of a checklist cell. [ChecklistCellChangesetPB]
+    /// contains the items that will be inserted, renamed, toggled, deleted or moved. It can be cast to
+    /// [CellChangesetPB] that will be used by the `update_cell` function.
+    #[event(input = "ChecklistCellChangesetPB")]
+    UpdateChecklistCell = 73,
+
     /// [UpdateDateCell] event is used to update a date cell's data. [DateChangesetPB]
     /// contains the date and the time string. It can be cast to [CellChangesetPB] that
     /// will be used by the `update_cell` function.
     #[event(input = "DateChangesetPB")]
     UpdateDateCell = 80,
 
+    /// [AppendToCell] event is used to append a text to a cell without reading it first. The text
+    /// is appended to the text cell, added as an option to the multi-select cell or added as an
+    /// item to the checklist cell. The cells of the other field types can't be appended to.
+    #[event(input = "AppendCellPayloadPB")]
+    AppendToCell = 81,
+
+    /// [GetRelationCellData] event is used to get the rows that a relation cell links to. The rows
+    /// are resolved from the linked database, each of them carries the display text of its primary
+    /// cell. The references to the rows that were deleted are dropped from the cell.
+    #[event(input = "CellPathPB", output = "RelationCellDataPB")]
+    GetRelationCellData = 90,
+
+    /// [UpdateRelationCell] event is used to add or remove the references to the rows of the linked
+    /// database. [RelationCellChangesetPB] can be cast to [CellChangesetPB] that will be used by the
+    /// `update_cell` function.
+    #[event(input = "RelationCellChangesetPB")]
+    UpdateRelationCell = 91,
+
+    /// [GetRelationCandidateRows] event is used to get the rows of the linked database that can be
+    /// linked by the cells of the relation field.
+    #[event(input = "GridFieldIdentifierPayloadPB", output = "RepeatedRelatedRowPB")]
+    GetRelationCandidateRows = 92,
+
+    /// [UpdateAttachmentCell] event is used to add, remove or reorder the attachments of a cell.
+    /// The stored files of the removed attachments are deleted if no other cell references them.
+    #[event(input = "AttachmentCellChangesetPB")]
+    UpdateAttachmentCell = 93,
+
+    /// [ImportAttachment] event is used to copy a local file into the app's storage directory and
+    /// append it to the attachment cell. It fails if no `AttachmentStore` is set.
+    #[event(input = "ImportAttachmentPayloadPB", output = "AttachmentPB")]
+    ImportAttachment = 94,
+
+    /// [UpdatePersonCell] event is used to add or remove the members of a person cell. The members
+    /// that are added for the first time are resolved by the `MemberResolver` and cached in the
+    /// type option of the field.
+    #[event(input = "PersonCellChangesetPB")]
+    UpdatePersonCell = 95,
+
+    /// [GetPersonCandidates] event is used to get the members of the workspace that can be
+    /// referenced by the cells of the person field. It fails if no `MemberResolver` is set.
+    #[event(input = "GridFieldIdentifierPayloadPB", output = "RepeatedMemberPB")]
+    GetPersonCandidates = 96,
+
+    /// [RefreshPersonMembers] event is used to resolve the members of the person field again, for
+    /// example, after the members of the workspace were changed. The cells of the members that
+    /// can't be resolved anymore are decoded with a placeholder.
+    #[event(input = "GridFieldIdentifierPayloadPB")]
+    RefreshPersonMembers = 97,
+
     #[event(input = "DatabaseIdPB", output = "RepeatedGroupPB")]
     GetGroup = 100,
 
@@ -230,4 +600,39 @@ pub enum DatabaseEvent {
 
     #[event(input = "MoveGroupRowPayloadPB")]
     GroupByField = 113,
+
+    /// [UpdateDateGroupCondition] event is used to change the range of the dates in each group of
+    /// the view that is grouped by the date field, for example, by the months of the dates.
+    #[event(input = "UpdateDateGroupConditionPayloadPB")]
+    UpdateDateGroupCondition = 114,
+
+    /// [UpdateNumberGroupRanges] event is used to change the ranges of the numbers in the groups
+    /// of the view that is grouped by the number field.
+    #[event(input = "UpdateNumberGroupRangesPayloadPB")]
+    UpdateNumberGroupRanges = 115,
+
+    /// [SetGroupCollapsed] event is used to collapse or expand the group of the view.
+    #[event(input = "SetGroupCollapsedPayloadPB")]
+    SetGroupCollapsed = 116,
+
+    /// [SetGroupVisibility] event is used to hide or show the group of the view, the no status
+    /// group can be hidden too.
+    #[event(input = "SetGroupVisibilityPayloadPB")]
+    SetGroupVisibility = 117,
+
+    /// [LoadGroups] event is the same as [GetGroup] event, but the hidden groups are returned too
+    /// if `include_hidden` is set.
+    #[event(input = "LoadGroupsPayloadPB", output = "RepeatedGroupPB")]
+    LoadGroups = 118,
+
+    /// [MoveGroupToIndex] event is used to move the group to the index in the visible groups, the
+    /// order of the groups is saved in the view. The options of the select field are reordered as
+    /// the groups if `reorder_options` is set.
+    #[event(input = "MoveGroupToIndexPayloadPB")]
+    MoveGroupToIndex = 119,
+
+    /// [CreateGroup] event is used to create a new group in the view that is grouped by the select
+    /// field, a new option is added to the field for the group.
+    #[event(input = "CreateGroupPayloadPB", output = "GroupPB")]
+    CreateGroup = 120,
 }
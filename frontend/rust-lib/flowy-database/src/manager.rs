@@ -1,67 +1,294 @@
-use crate::entities::DatabaseViewLayout;
-use crate::services::grid_editor::{DatabaseRevisionEditor, GridRevisionMergeable};
+use crate::entities::{DatabaseInfoPB, DatabaseViewLayout};
+use crate::services::backup::DatabaseBackup;
+use crate::services::block_editor::DatabaseBlockRevisionEditor;
+use crate::services::field::{
+    referenced_attachments, AttachmentPB, AttachmentStore, AttachmentStoreRef, FieldTemplate, FieldTemplateStore,
+    MemberResolver, RelatedRowPB, RelatedRowResolver,
+};
+use crate::services::filter::FilterResultStore;
+use crate::services::grid_editor::{DatabaseRevisionEditor, GridRevisionMergeable, GridRevisionSerde};
+use crate::services::health::{check_database_health, BlockState, DatabaseHealthIssue, DatabaseHealthReport};
 use crate::services::persistence::block_index::BlockIndexCache;
-use crate::services::persistence::kv::DatabaseKVPersistence;
+use crate::services::persistence::kv::{DatabaseKVPersistence, KVTransaction, KeyValue};
 use crate::services::persistence::migration::DatabaseMigration;
-use crate::services::persistence::rev_sqlite::{
-    SQLiteDatabaseRevisionPersistence, SQLiteDatabaseRevisionSnapshotPersistence,
+use crate::services::persistence::rev_sqlite::{verify_database_revision_records, RevisionCipher, LOCAL_DEVICE_ID};
+use crate::services::persistence::rev_store::{
+    GridRevisionObject, GridRevisionStore, GridRevisionStoreFactory, GridRevisionStores,
 };
-use crate::services::persistence::GridDatabase;
-use crate::services::view_editor::make_database_view_rev_manager;
+use crate::services::persistence::DatabaseStorage;
+use crate::services::repair::REPAIR_KEEP_LAST_REVISIONS;
+use crate::services::revision_stats::{read_database_revision_stats, DatabaseStorageReport};
+use crate::services::row::RowTemplateStore;
+use crate::services::snapshot::DatabaseSnapshotPolicy;
+use crate::services::undo::DEFAULT_UNDO_DEPTH;
 use bytes::Bytes;
 use flowy_client_sync::client_database::{
-    make_database_block_operations, make_database_operations, make_grid_view_operations,
+    make_database_block_operations, make_database_operations, make_grid_view_operations, DatabaseRevisionPad,
+    GridBlockRevisionPad, GridViewRevisionPad,
+};
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use flowy_revision::{
+    RevisionManager, RevisionPersistence, RevisionPersistenceConfiguration, RevisionSnapshotDiskCache,
+    RevisionWebSocket,
 };
-use flowy_error::{FlowyError, FlowyResult};
-use flowy_revision::{RevisionManager, RevisionPersistence, RevisionPersistenceConfiguration, RevisionWebSocket};
+use flowy_revision_persistence::RevisionDiskCache;
 use flowy_sqlite::ConnectionPool;
+use futures::StreamExt;
 use grid_model::{BuildDatabaseContext, DatabaseRevision, DatabaseViewRevision};
 use lib_infra::async_trait::async_trait;
 use lib_infra::ref_map::{RefCountHashMap, RefCountValue};
 use revision_model::Revision;
+use std::collections::{HashMap, HashSet};
 
 use crate::services::block_manager::make_database_block_rev_manager;
 use flowy_task::TaskDispatcher;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
 use tokio::sync::RwLock;
 
 pub trait DatabaseUser: Send + Sync {
     fn user_id(&self) -> Result<String, FlowyError>;
     fn token(&self) -> Result<String, FlowyError>;
     fn db_pool(&self) -> Result<Arc<ConnectionPool>, FlowyError>;
+
+    /// Returns the id of the device that edits the databases. It's written along with each
+    /// revision, so the changes made on different devices can be told apart.
+    fn device_id(&self) -> Result<String, FlowyError> {
+        Ok(LOCAL_DEVICE_ID.to_owned())
+    }
 }
 
+/// Controls how the revisions of the database, its blocks and its views are merged before being
+/// written to disk. Merging more revisions into one means fewer writes, but each revision carries
+/// more changes.
+#[derive(Debug, Clone)]
+pub struct RevisionCompressConfig {
+    /// The number of the database's revisions that will be merged into one. 1 means the revisions
+    /// won't be merged.
+    pub database_merge_threshold: usize,
+
+    /// Same as the `database_merge_threshold`, but for each block of the database.
+    pub block_merge_threshold: usize,
+
+    /// Same as the `database_merge_threshold`, but for each view of the database.
+    pub view_merge_threshold: usize,
+
+    /// Merges the revisions that didn't sync to the server into one when closing the editor, even
+    /// if their number doesn't reach the threshold.
+    pub merge_lagging: bool,
+
+    /// Decides when the snapshots are generated automatically.
+    pub snapshot_policy: DatabaseSnapshotPolicy,
+
+    /// Buffers the revisions in memory and writes them to disk in batches. Each revision is
+    /// written as soon as it's applied if it's false.
+    ///
+    /// It's true by default, because writing each cell edit to SQLite synchronously makes typing
+    /// lag on large databases. The buffered revisions are written when the editor is flushed,
+    /// closed or dropped, so turn it off only if every revision must be on disk as soon as it's
+    /// applied.
+    pub buffered_writes: bool,
+
+    /// The buffered revisions are written to disk once no revision has been applied for the
+    /// `write_interval`.
+    pub write_interval: Duration,
+
+    /// The buffered revisions are written to disk immediately once the number of them reaches
+    /// `max_pending_writes`.
+    pub max_pending_writes: usize,
+
+    /// A warning is logged if the number of the stored revisions of a database exceeds the soft
+    /// limit when opening it, and the revisions are compacted before the database is opened.
+    pub revision_soft_limit: usize,
+
+    /// The database is opened from its latest snapshot if the number of its stored revisions
+    /// exceeds the hard limit, and all of its revisions are replayed in the background.
+    pub revision_hard_limit: usize,
+}
+
+impl RevisionCompressConfig {
+    /// Uses the same merge threshold for the database, its blocks and its views.
+    pub fn with_merge_threshold(merge_threshold: usize) -> Self {
+        Self {
+            database_merge_threshold: merge_threshold,
+            block_merge_threshold: merge_threshold,
+            view_merge_threshold: merge_threshold,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn database_configuration(&self) -> RevisionPersistenceConfiguration {
+        self.write_configuration(self.database_merge_threshold)
+    }
+
+    pub(crate) fn block_configuration(&self) -> RevisionPersistenceConfiguration {
+        self.write_configuration(self.block_merge_threshold)
+    }
+
+    pub(crate) fn view_configuration(&self) -> RevisionPersistenceConfiguration {
+        self.write_configuration(self.view_merge_threshold)
+    }
+
+    fn write_configuration(&self, merge_threshold: usize) -> RevisionPersistenceConfiguration {
+        let configuration = RevisionPersistenceConfiguration::new(merge_threshold, self.merge_lagging);
+        if self.buffered_writes {
+            configuration.with_write_buffer(self.write_interval, self.max_pending_writes)
+        } else {
+            configuration.without_write_buffer()
+        }
+    }
+}
+
+impl std::default::Default for RevisionCompressConfig {
+    fn default() -> Self {
+        Self {
+            database_merge_threshold: 6,
+            block_merge_threshold: 4,
+            view_merge_threshold: 2,
+            merge_lagging: false,
+            snapshot_policy: DatabaseSnapshotPolicy::default(),
+            buffered_writes: true,
+            write_interval: Duration::from_millis(200),
+            max_pending_writes: 20,
+            revision_soft_limit: 10_000,
+            revision_hard_limit: 50_000,
+        }
+    }
+}
+
+/// Controls how many [DatabaseRevisionEditor]s the [DatabaseManager] keeps in memory.
+#[derive(Debug, Clone)]
+pub struct DatabaseManagerConfig {
+    /// The maximum number of the opened editors. The least recently used idle editor will be
+    /// closed if opening a new editor exceeds the limit.
+    pub max_open_editors: usize,
+
+    /// The editor that has been idle longer than the `idle_ttl` will be closed.
+    pub idle_ttl: Duration,
+
+    /// A warning will be logged if the reference count of an editor goes above the threshold. It
+    /// usually means some callers open the database without closing it.
+    pub ref_count_warning_threshold: usize,
+
+    /// The initial [RevisionCompressConfig]. It can be changed by calling
+    /// `DatabaseManager::set_rev_compress_config` later.
+    pub rev_compress: RevisionCompressConfig,
+
+    /// The maximum number of the steps that each editor can undo.
+    pub undo_depth: usize,
+
+    /// Includes the old and new values of the updated cells in the notifications of the rows and
+    /// the cells. It's off by default because the values make the notifications much larger.
+    pub notify_cell_values: bool,
+}
+
+impl std::default::Default for DatabaseManagerConfig {
+    fn default() -> Self {
+        Self {
+            max_open_editors: 20,
+            idle_ttl: Duration::from_secs(10 * 60),
+            ref_count_warning_threshold: 10,
+            rev_compress: RevisionCompressConfig::default(),
+            undo_depth: DEFAULT_UNDO_DEPTH,
+            notify_cell_values: false,
+        }
+    }
+}
+
+type DatabaseEditorMap = RwLock<RefCountHashMap<Arc<DatabaseRevisionEditor>>>;
+
 pub struct DatabaseManager {
-    database_editors: RwLock<RefCountHashMap<Arc<DatabaseRevisionEditor>>>,
+    database_editors: Arc<DatabaseEditorMap>,
     database_user: Arc<dyn DatabaseUser>,
     block_index_cache: Arc<BlockIndexCache>,
-    #[allow(dead_code)]
     kv_persistence: Arc<DatabaseKVPersistence>,
+    field_templates: Arc<FieldTemplateStore>,
+    row_templates: Arc<RowTemplateStore>,
+    filter_results: Arc<FilterResultStore>,
     task_scheduler: Arc<RwLock<TaskDispatcher>>,
     migration: DatabaseMigration,
+    config: DatabaseManagerConfig,
+    rev_compress: parking_lot::RwLock<RevisionCompressConfig>,
+    cipher: Option<Arc<dyn RevisionCipher>>,
+    rev_store_factory: Arc<dyn GridRevisionStoreFactory<Arc<ConnectionPool>>>,
+    attachment_store: AttachmentStoreRef,
+    member_resolver: parking_lot::RwLock<Option<Arc<dyn MemberResolver>>>,
 }
 
 impl DatabaseManager {
+    /// The revisions and the snapshots of the databases, their blocks and their views are encrypted
+    /// before they are written to sqlite if the `cipher` is not None.
+    ///
+    /// The databases are stored in the `storage`, see [DatabaseStorage]. No sqlite handle is
+    /// required if it's [DatabaseStorage::memory].
     pub fn new(
         grid_user: Arc<dyn DatabaseUser>,
         _rev_web_socket: Arc<dyn RevisionWebSocket>,
         task_scheduler: Arc<RwLock<TaskDispatcher>>,
-        database: Arc<dyn GridDatabase>,
+        storage: DatabaseStorage,
+        config: DatabaseManagerConfig,
+        cipher: Option<Arc<dyn RevisionCipher>>,
     ) -> Self {
-        let grid_editors = RwLock::new(RefCountHashMap::new());
-        let kv_persistence = Arc::new(DatabaseKVPersistence::new(database.clone()));
-        let block_index_cache = Arc::new(BlockIndexCache::new(database.clone()));
-        let migration = DatabaseMigration::new(grid_user.clone(), database);
+        let grid_editors = Arc::new(RwLock::new(RefCountHashMap::new()));
+        spawn_idle_editors_eviction(Arc::downgrade(&grid_editors), config.idle_ttl);
+        let DatabaseStorage {
+            rev_store_factory,
+            kv_persistence,
+            block_index_cache,
+        } = storage;
+        let field_templates = Arc::new(FieldTemplateStore::new(kv_persistence.clone()));
+        let row_templates = Arc::new(RowTemplateStore::new(kv_persistence.clone()));
+        let filter_results = Arc::new(FilterResultStore::new(kv_persistence.clone()));
+        let migration = DatabaseMigration::new(grid_user.clone());
+        let rev_compress = parking_lot::RwLock::new(config.rev_compress.clone());
         Self {
             database_editors: grid_editors,
             database_user: grid_user,
             kv_persistence,
+            field_templates,
+            row_templates,
+            filter_results,
             block_index_cache,
             task_scheduler,
             migration,
+            config,
+            rev_compress,
+            cipher,
+            rev_store_factory,
+            attachment_store: AttachmentStoreRef::default(),
+            member_resolver: parking_lot::RwLock::new(None),
         }
     }
 
+    pub fn rev_compress_config(&self) -> RevisionCompressConfig {
+        self.rev_compress.read().clone()
+    }
+
+    /// Changes the [RevisionCompressConfig]. It only affects the editors that are opened after
+    /// calling this method. The editors that are already opened keep using the old config until
+    /// they are closed.
+    pub fn set_rev_compress_config(&self, config: RevisionCompressConfig) {
+        *self.rev_compress.write() = config;
+    }
+
+    /// Sets the [AttachmentStore] that manages the files of the attachment cells. It's shared by
+    /// all the editors, including the ones that are already opened. The files can't be imported
+    /// if it's None, and the files of the removed attachments are not deleted.
+    pub fn set_attachment_store(&self, store: Option<Arc<dyn AttachmentStore>>) {
+        self.attachment_store.set(store);
+    }
+
+    /// Sets the [MemberResolver] that resolves the members of the person cells, it's usually
+    /// provided by the user crate. The members can't be resolved if it's None, the person cells
+    /// are decoded with the members that were cached in their type options.
+    pub fn set_member_resolver(&self, resolver: Option<Arc<dyn MemberResolver>>) {
+        *self.member_resolver.write() = resolver;
+    }
+
+    pub fn get_member_resolver(&self) -> Option<Arc<dyn MemberResolver>> {
+        self.member_resolver.read().clone()
+    }
+
     pub async fn initialize_with_new_user(&self, _user_id: &str, _token: &str) -> FlowyResult<()> {
         Ok(())
     }
@@ -73,43 +300,439 @@ impl DatabaseManager {
     #[tracing::instrument(level = "debug", skip_all, err)]
     pub async fn create_database<T: AsRef<str>>(&self, database_id: T, revisions: Vec<Revision>) -> FlowyResult<()> {
         let database_id = database_id.as_ref();
-        let db_pool = self.database_user.db_pool()?;
-        let rev_manager = self.make_database_rev_manager(database_id, db_pool)?;
+        let rev_manager = self.make_database_rev_manager(database_id)?;
         rev_manager.reset_object(revisions).await?;
 
         Ok(())
     }
 
-    #[tracing::instrument(level = "debug", skip_all, err)]
-    async fn create_database_view<T: AsRef<str>>(&self, view_id: T, revisions: Vec<Revision>) -> FlowyResult<()> {
-        let view_id = view_id.as_ref();
-        let rev_manager = make_database_view_rev_manager(&self.database_user, view_id).await?;
-        rev_manager.reset_object(revisions).await?;
-        Ok(())
-    }
-
     #[tracing::instrument(level = "debug", skip_all, err)]
     pub async fn create_database_block<T: AsRef<str>>(&self, block_id: T, revisions: Vec<Revision>) -> FlowyResult<()> {
         let block_id = block_id.as_ref();
-        let rev_manager = make_database_block_rev_manager(&self.database_user, block_id)?;
+        let rev_manager = make_database_block_rev_manager(&self.rev_stores(), block_id)?;
         rev_manager.reset_object(revisions).await?;
         Ok(())
     }
 
+    /// Opens the database and increases the reference count of its editor. Each call should be
+    /// paired with a `close_database` call.
     pub async fn open_database<T: AsRef<str>>(&self, database_id: T) -> FlowyResult<Arc<DatabaseRevisionEditor>> {
+        self.open_database_with_options(database_id, false).await
+    }
+
+    /// Opens the database. Returns a `DatabaseArchived` error if the database is archived, unless
+    /// the `allow_archived` is true.
+    pub async fn open_database_with_options<T: AsRef<str>>(
+        &self,
+        database_id: T,
+        allow_archived: bool,
+    ) -> FlowyResult<Arc<DatabaseRevisionEditor>> {
+        self.open_database_editor(database_id.as_ref(), allow_archived, true)
+            .await
+    }
+
+    async fn open_database_editor(
+        &self,
+        database_id: &str,
+        allow_archived: bool,
+        is_referenced: bool,
+    ) -> FlowyResult<Arc<DatabaseRevisionEditor>> {
+        if !allow_archived && self.is_archived(database_id)? {
+            return Err(FlowyError::database_archived().context(format!("The database:{} is archived", database_id)));
+        }
+        let rev_store = self.database_rev_store(database_id)?;
+        let _ = self
+            .migration
+            .run_v1_migration(database_id, rev_store.revision_disk_cache())
+            .await;
+        self.get_or_create_database_editor(database_id, is_referenced).await
+    }
+
+    /// Archives the database. The revisions of the archived database are kept on disk, but it
+    /// can't be opened by `open_database` until it's unarchived.
+    ///
+    /// The database will be closed if it's opened.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn archive_database(&self, database_id: &str) -> FlowyResult<()> {
+        self.kv_persistence
+            .set(KeyValue::new(archived_flag_key(database_id), vec![1]))?;
+        let editor = self.database_editors.write().await.take(database_id);
+        if let Some(editor) = editor {
+            editor.close().await;
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn unarchive_database(&self, database_id: &str) -> FlowyResult<()> {
+        self.kv_persistence.remove(&archived_flag_key(database_id))
+    }
+
+    pub fn is_archived(&self, database_id: &str) -> FlowyResult<bool> {
+        self.kv_persistence.contains(&archived_flag_key(database_id))
+    }
+
+    /// Decreases the reference count of the database's editor. The editor will be closed if the
+    /// reference count drops to 0.
+    ///
+    /// Returns the remaining reference count.
+    #[tracing::instrument(level = "debug", skip_all, fields(database_id), err)]
+    pub async fn close_database<T: AsRef<str>>(&self, database_id: T) -> FlowyResult<usize> {
         let database_id = database_id.as_ref();
-        let _ = self.migration.run_v1_migration(database_id).await;
-        self.get_or_create_database_editor(database_id).await
+        tracing::Span::current().record("database_id", database_id);
+        let mut database_editors = self.database_editors.write().await;
+        let editor = database_editors.get(database_id);
+        let ref_count = database_editors.remove(database_id).await;
+        drop(database_editors);
+
+        // The editor stays opened if it's still referenced, so write its buffered revisions to disk
+        // when one of its callers closes it. Otherwise, they are written when the editor is closed.
+        if let Some(editor) = editor.filter(|_| ref_count > 0) {
+            editor.flush().await?;
+        }
+        tracing::trace!("The reference count of the database: {} is {}", database_id, ref_count);
+        Ok(ref_count)
+    }
+
+    /// Returns the reference count of each opened database's editor.
+    pub async fn editor_ref_counts(&self) -> HashMap<String, usize> {
+        self.database_editors.read().await.ref_counts()
+    }
+
+    /// Renames the database. The database will be opened if it's not opened yet.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn rename_database(&self, database_id: &str, name: &str) -> FlowyResult<()> {
+        let editor = self.get_database_editor(database_id).await?;
+        editor.update_database_name(name).await
+    }
+
+    /// Saves the configuration of the database's field as a [FieldTemplate] with the name, so it
+    /// can be applied by `DatabaseRevisionEditor::create_field_from_template` in any database. The
+    /// existing template with the same name is replaced.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn save_field_template(&self, name: &str, field_id: &str, database_id: &str) -> FlowyResult<()> {
+        let editor = self.get_database_editor(database_id).await?;
+        let field_rev = editor.get_field_rev(field_id).await.ok_or_else(|| {
+            FlowyError::field_record_not_found().context(format!("Can't find the field: {}", field_id))
+        })?;
+        let template = FieldTemplate::from_field_rev(name, &field_rev);
+        self.field_templates.save_templates(vec![template])
+    }
+
+    pub fn list_field_templates(&self) -> FlowyResult<Vec<FieldTemplate>> {
+        self.field_templates.get_templates()
+    }
+
+    pub fn delete_field_template(&self, name: &str) -> FlowyResult<()> {
+        self.field_templates.delete_template(name)
+    }
+
+    /// Returns the JSON of all the field templates, which can be imported on another device by
+    /// `import_field_templates`.
+    pub fn export_field_templates(&self) -> FlowyResult<String> {
+        let templates = self.field_templates.get_templates()?;
+        Ok(serde_json::to_string(&templates)?)
     }
 
+    /// Imports the field templates from the JSON that is returned by `export_field_templates`. The
+    /// existing templates with the same names are replaced.
+    pub fn import_field_templates(&self, json: &str) -> FlowyResult<()> {
+        let templates: Vec<FieldTemplate> = serde_json::from_str(json)?;
+        self.field_templates.save_templates(templates)
+    }
+
+    /// Returns the information of the databases that are currently opened, including the reference
+    /// count and the last modified time of each database.
+    pub async fn opened_databases(&self) -> Vec<DatabaseInfoPB> {
+        let now = chrono::Utc::now().timestamp();
+        let database_editors = self.database_editors.read().await;
+        database_editors
+            .values()
+            .into_iter()
+            .map(|editor| DatabaseInfoPB {
+                id: editor.database_id.clone(),
+                ref_count: database_editors.ref_count(&editor.database_id) as i64,
+                last_modified: now - editor.last_modified().elapsed().as_secs() as i64,
+                is_archived: self.is_archived(&editor.database_id).unwrap_or(false),
+            })
+            .collect()
+    }
+
+    /// Closes all the opened databases regardless of their reference counts. It's used when the
+    /// user logs out or switches to another workspace.
+    ///
+    /// Each editor is flushed and closed before returning, so the pending revisions are persisted
+    /// and the tasks that were queued by the editor are cancelled. Returns the ids of the closed
+    /// databases.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub async fn close_all(&self) -> Vec<String> {
+        // Release the lock before closing the editors. The databases opened after draining will
+        // be kept.
+        let editors = self.database_editors.write().await.drain();
+        let mut database_ids = Vec::with_capacity(editors.len());
+        for (database_id, editor) in editors {
+            tracing::trace!("Close database: {}", database_id);
+            // Close the editor the same way as it's closed when its last reference is removed.
+            editor.did_remove().await;
+            database_ids.push(database_id);
+        }
+        database_ids
+    }
+
+    /// Deletes the database and all of its persisted state, including the revisions of the database,
+    /// its blocks and its view, the snapshots and the row index.
+    ///
+    /// The editor will be closed first if the database is opened. Deleting a database that was
+    /// already deleted does nothing.
     #[tracing::instrument(level = "debug", skip_all, fields(database_id), err)]
-    pub async fn close_database<T: AsRef<str>>(&self, database_id: T) -> FlowyResult<()> {
+    pub async fn delete_database<T: AsRef<str>>(&self, database_id: T) -> FlowyResult<()> {
         let database_id = database_id.as_ref();
         tracing::Span::current().record("database_id", database_id);
-        self.database_editors.write().await.remove(database_id).await;
+
+        // Close the editor before deleting its revisions. Otherwise, the revisions might be written
+        // back to disk while closing.
+        let rev_stores = self.rev_stores();
+        let editor = self.database_editors.write().await.take(database_id);
+        let block_ids = match editor {
+            Some(editor) => {
+                let block_ids = editor
+                    .get_block_meta_revs()
+                    .await?
+                    .iter()
+                    .map(|block_meta_rev| block_meta_rev.block_id.clone())
+                    .collect::<Vec<String>>();
+                editor.close().await;
+                block_ids
+            }
+            None => read_database_block_ids(&rev_stores, database_id)?,
+        };
+
+        for block_id in block_ids {
+            rev_stores.make_store(GridRevisionObject::Block, &block_id)?.delete()?;
+            self.block_index_cache.remove_block(&block_id)?;
+        }
+        rev_stores.make_store(GridRevisionObject::View, database_id)?.delete()?;
+        rev_stores
+            .make_store(GridRevisionObject::Database, database_id)?
+            .delete()?;
+
+        self.kv_persistence.remove(database_id)?;
+        self.kv_persistence.remove(&archived_flag_key(database_id))?;
+        self.row_templates.delete_templates(database_id)?;
+        self.filter_results.delete_cache(database_id)?;
         Ok(())
     }
 
+    /// Checks the persisted data of the database, and reports the inconsistencies between the
+    /// database, its blocks and the row index. The database doesn't need to be opened.
+    ///
+    /// Returns an error if the revisions of the database don't exist or can't be deserialized.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn check_database(&self, database_id: &str) -> FlowyResult<DatabaseHealthReport> {
+        let database_pad = self.read_database_pad(database_id)?;
+        let blocks = self.read_database_blocks(&database_pad)?;
+        check_database_health(database_id, &database_pad, &blocks, &self.block_index_cache)
+    }
+
+    /// Fixes the repairable issues reported by `check_database`:
+    /// * Removes the block metas that have no backing block.
+    /// * Drops the cells that reference the deleted fields.
+    /// * Rebuilds the row index of the database's blocks.
+    ///
+    /// The database will be closed if it's opened. Returns the report of the issues that remain
+    /// after repairing.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn repair_database(&self, database_id: &str) -> FlowyResult<DatabaseHealthReport> {
+        let editor = self.database_editors.write().await.take(database_id);
+        if let Some(editor) = editor {
+            editor.close().await;
+        }
+
+        let report = self.check_database(database_id).await?;
+        if report.is_healthy() {
+            return Ok(report);
+        }
+
+        let mut missing_block_ids = vec![];
+        let mut block_ids_with_deleted_fields = vec![];
+        for issue in report.issues.iter() {
+            match issue {
+                DatabaseHealthIssue::MissingBlock { block_id } => missing_block_ids.push(block_id.clone()),
+                DatabaseHealthIssue::CellOfDeletedField { block_id, .. } => {
+                    if !block_ids_with_deleted_fields.contains(block_id) {
+                        block_ids_with_deleted_fields.push(block_id.clone());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Remove the block metas that have no backing block
+        let mut rev_manager = self.make_database_rev_manager(database_id)?;
+        let mut database_pad = rev_manager.initialize::<GridRevisionSerde>(None).await?;
+        if let Some(changeset) = database_pad.delete_block_meta_revs(&missing_block_ids)? {
+            let _ = rev_manager
+                .add_local_revision(changeset.operations.json_bytes(), changeset.md5)
+                .await?;
+        }
+        rev_manager.flush().await?;
+        rev_manager.close().await;
+
+        // Drop the cells that reference the deleted fields
+        let field_ids = database_pad
+            .get_fields()
+            .iter()
+            .map(|field_rev| field_rev.id.clone())
+            .collect::<Vec<String>>();
+        let user_id = self.database_user.user_id()?;
+        let token = self.database_user.token()?;
+        let rev_stores = self.rev_stores();
+        for block_id in block_ids_with_deleted_fields {
+            let rev_manager = make_database_block_rev_manager(&rev_stores, &block_id)?;
+            let block_editor = DatabaseBlockRevisionEditor::new(&user_id, &token, &block_id, rev_manager).await?;
+            block_editor.retain_cells(&field_ids).await?;
+            block_editor.close().await;
+        }
+
+        // Rebuild the row index
+        for block_id in missing_block_ids {
+            self.block_index_cache.remove_block(&block_id)?;
+        }
+        for (block_id, block) in self.read_database_blocks(&database_pad)? {
+            self.block_index_cache.remove_block(&block_id)?;
+            for row_rev in block.row_revs() {
+                self.block_index_cache.insert(&block_id, &row_rev.id)?;
+            }
+        }
+
+        self.check_database(database_id).await
+    }
+
+    fn read_database_pad(&self, database_id: &str) -> FlowyResult<DatabaseRevisionPad> {
+        let disk_cache = self.database_rev_store(database_id)?.revision_disk_cache();
+        let revisions = read_revisions(disk_cache.as_ref(), database_id)?;
+        if revisions.is_empty() {
+            return Err(FlowyError::record_not_found().context(format!("Can't find the database: {}", database_id)));
+        }
+        let database_pad = DatabaseRevisionPad::from_revisions(revisions)?;
+        Ok(database_pad)
+    }
+
+    /// Returns the attachments of the database that is not opened.
+    fn read_database_attachments(&self, database_id: &str) -> FlowyResult<Vec<AttachmentPB>> {
+        let database_pad = self.read_database_pad(database_id)?;
+        let field_revs = database_pad.get_field_revs(None)?;
+        let row_revs = self
+            .read_database_blocks(&database_pad)?
+            .values()
+            .flat_map(|block_state| block_state.row_revs())
+            .chain(
+                database_pad
+                    .get_trashed_rows()
+                    .iter()
+                    .map(|trashed_row| Arc::new(trashed_row.row.clone())),
+            )
+            .collect::<Vec<_>>();
+        Ok(referenced_attachments(&field_revs, &row_revs))
+    }
+
+    fn read_database_blocks(&self, database_pad: &DatabaseRevisionPad) -> FlowyResult<HashMap<String, BlockState>> {
+        let rev_stores = self.rev_stores();
+        let mut blocks = HashMap::new();
+        for block_meta_rev in database_pad.get_block_meta_revs() {
+            let block_id = &block_meta_rev.block_id;
+            let block_disk_cache = rev_stores
+                .make_store(GridRevisionObject::Block, block_id)?
+                .revision_disk_cache();
+            let revisions = read_revisions(block_disk_cache.as_ref(), block_id)?;
+            let state = if revisions.is_empty() {
+                BlockState::Missing
+            } else {
+                match GridBlockRevisionPad::from_revisions(block_id, revisions) {
+                    Ok(block_pad) => BlockState::Loaded(block_pad),
+                    Err(e) => {
+                        tracing::error!("Deserialize block:{} failed: {:?}", block_id, e);
+                        BlockState::Corrupted
+                    }
+                }
+            };
+            blocks.insert(block_id.clone(), state);
+        }
+        Ok(blocks)
+    }
+
+    /// Compacts the revision history of each database that is not opened. See
+    /// `DatabaseRevisionEditor::compact_history` for more details. Returns the total number of
+    /// bytes that are reclaimed.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn compact_all(&self, keep_last: usize) -> FlowyResult<usize> {
+        let database_ids = self.rev_store_factory.read_database_ids()?;
+        let mut reclaimed_size = 0;
+        for database_id in database_ids {
+            // Compacting the opened databases is skipped, because their revisions are still in use.
+            if self.database_editors.read().await.get(&database_id).is_some() {
+                continue;
+            }
+
+            let rev_manager = self.make_database_rev_manager(&database_id)?;
+            match rev_manager.compact_history(keep_last).await {
+                Ok(size) => reclaimed_size += size,
+                Err(e) => tracing::error!("Compact database:{} failed: {:?}", database_id, e),
+            }
+        }
+        Ok(reclaimed_size)
+    }
+
+    /// Returns the stats of the revisions and the snapshots of all the databases, including the
+    /// ones that are not opened. The stats are counted by the revision stores without loading the
+    /// payloads.
+    ///
+    /// The size of the stored files of the attachments is counted as well. The attachments of the
+    /// databases that are not opened are read from their revisions, so it costs O(revisions) for
+    /// those databases.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn storage_report(&self) -> FlowyResult<DatabaseStorageReport> {
+        let rev_stores = self.rev_stores();
+        let database_ids = self.rev_store_factory.read_database_ids()?;
+        let mut report = DatabaseStorageReport::default();
+        let mut attachment_ids = HashSet::new();
+        for database_id in database_ids {
+            let editor = self.database_editors.read().await.get(&database_id);
+            let (stats, attachments) = match editor {
+                Some(editor) => (editor.revision_stats().await?, editor.get_attachments().await?),
+                None => {
+                    let stats = read_database_revision_stats(&rev_stores, &database_id, &[])?;
+                    let attachments = match self.read_database_attachments(&database_id) {
+                        Ok(attachments) => attachments,
+                        Err(e) => {
+                            tracing::error!("Read the attachments of database:{} failed: {:?}", database_id, e);
+                            vec![]
+                        }
+                    };
+                    (stats, attachments)
+                }
+            };
+            // The attachment that is copied to another database along with its row is stored once.
+            for attachment in attachments {
+                if attachment.is_local() && attachment_ids.insert(attachment.id) {
+                    report.attachment_bytes += attachment.size.max(0) as usize;
+                }
+            }
+            report.number_of_revisions += stats.number_of_revisions;
+            report.payload_bytes += stats.payload_bytes;
+            report.number_of_snapshots += stats.number_of_snapshots;
+            report.databases.push(stats);
+        }
+
+        let block_stats = self.rev_store_factory.read_total_block_stats()?;
+        report.number_of_revisions += block_stats.number_of_revisions;
+        report.payload_bytes += block_stats.payload_bytes;
+        Ok(report)
+    }
+
+    /// Returns the editor of the database, the database will be opened if it's not opened yet.
+    /// Unlike `open_database`, it doesn't increase the reference count of the editor, so it doesn't
+    /// need to be paired with a `close_database` call. The archived databases are opened too.
     // #[tracing::instrument(level = "debug", skip(self), err)]
     pub async fn get_database_editor(&self, database_id: &str) -> FlowyResult<Arc<DatabaseRevisionEditor>> {
         let read_guard = self.database_editors.read().await;
@@ -118,60 +741,387 @@ impl DatabaseManager {
             None => {
                 // Drop the read_guard ASAP in case of the following read/write lock
                 drop(read_guard);
-                self.open_database(database_id).await
+                // Only `open_database` excludes the archived databases. The internal callers, for
+                // example, renaming the database or resolving the relations, still work on them.
+                self.open_database_editor(database_id, true, false).await
+            }
+            Some(editor) => {
+                editor.did_access();
+                Ok(editor)
             }
-            Some(editor) => Ok(editor),
         }
     }
 
-    async fn get_or_create_database_editor(&self, database_id: &str) -> FlowyResult<Arc<DatabaseRevisionEditor>> {
-        if let Some(editor) = self.database_editors.read().await.get(database_id) {
-            return Ok(editor);
+    /// Returns the editor of the database, the editor will be created if it doesn't exist. The
+    /// reference count of the editor is increased only if `is_referenced` is true.
+    async fn get_or_create_database_editor(
+        &self,
+        database_id: &str,
+        is_referenced: bool,
+    ) -> FlowyResult<Arc<DatabaseRevisionEditor>> {
+        let mut database_editors = self.database_editors.write().await;
+        let editor = match database_editors.get(database_id) {
+            Some(editor) => {
+                editor.did_access();
+                editor
+            }
+            None => {
+                let editor = self.make_database_rev_editor(database_id).await?;
+                tracing::trace!("Open database: {}", database_id);
+                editor
+            }
+        };
+        if is_referenced {
+            // Increase the reference count if the editor already exists.
+            database_editors.insert(database_id.to_string(), editor.clone());
+        } else {
+            database_editors.insert_without_ref(database_id.to_string(), editor.clone());
         }
+        trace_ref_count(
+            database_id,
+            database_editors.ref_count(database_id),
+            self.config.ref_count_warning_threshold,
+        );
 
-        let mut database_editors = self.database_editors.write().await;
-        let db_pool = self.database_user.db_pool()?;
-        let editor = self.make_database_rev_editor(database_id, db_pool).await?;
-        tracing::trace!("Open database: {}", database_id);
-        database_editors.insert(database_id.to_string(), editor.clone());
+        // Close the least recently used editors if there are too many opened editors.
+        let number_of_exceeded = database_editors
+            .values()
+            .len()
+            .saturating_sub(self.config.max_open_editors);
+        let evicted_editors = take_idle_editors(&mut database_editors, Duration::ZERO, number_of_exceeded);
+        drop(database_editors);
+        for evicted_editor in evicted_editors {
+            tracing::trace!("Evict database: {}", evicted_editor.database_id);
+            evicted_editor.did_remove().await;
+        }
         Ok(editor)
     }
 
-    #[tracing::instrument(level = "trace", skip(self, pool), err)]
-    async fn make_database_rev_editor(
+    /// Closes the editors that have been idle longer than the `idle_ttl` and are not held by
+    /// anyone except the [DatabaseManager]. Returns the ids of the closed databases.
+    ///
+    /// The closed databases will be reopened when calling `get_database_editor` next time.
+    pub async fn close_idle_databases(&self, idle_ttl: Duration) -> Vec<String> {
+        close_idle_editors(&self.database_editors, idle_ttl).await
+    }
+
+    /// Reads the rows of the linked database. The linked database is opened for the read and
+    /// closed afterwards, so resolving the relations doesn't keep the linked databases opened. The
+    /// database that is opened by others stays opened, because only the reference taken here is
+    /// released.
+    async fn read_related_rows(&self, database_id: &str, row_ids: Option<&[String]>) -> FlowyResult<Vec<RelatedRowPB>> {
+        let editor = self.open_database_with_options(database_id, true).await?;
+        let related_rows = editor.get_related_rows(row_ids).await;
+        drop(editor);
+        self.close_database(database_id).await?;
+        related_rows
+    }
+
+    /// Duplicates the database, including its fields, view settings, blocks, rows and row
+    /// templates, to a new database with id `new_database_id`.
+    ///
+    /// The ids of the blocks and rows are regenerated, so the duplicated database is independent
+    /// of the original one. The ids of the fields are kept, so the filters, sorts and groups of
+    /// the view still work after duplication.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn duplicate_database(&self, database_id: &str, new_database_id: &str) -> FlowyResult<()> {
+        // Take a reference of the editor while duplicating, so the database that is opened by
+        // others stays opened after releasing it.
+        let editor = self.open_database_with_options(database_id, true).await?;
+        let build_context = editor.duplicate_grid().await;
+        drop(editor);
+        self.close_database(database_id).await?;
+
+        let mut build_context = build_context?;
+        let mut view_rev = DatabaseViewRevision::from_json(build_context.grid_view_revision_data)?;
+        view_rev.view_id = new_database_id.to_owned();
+        view_rev.grid_id = new_database_id.to_owned();
+        let layout = view_rev.layout.clone().into();
+        build_context.grid_view_revision_data = serde_json::to_string(&view_rev)?;
+
+        let _ = self
+            .create_database_from_build_context(new_database_id, layout, build_context)
+            .await?;
+        self.row_templates.copy_templates(database_id, new_database_id)?;
+        Ok(())
+    }
+
+    /// Exports the raw revisions of the database, its blocks and its view, and the indexes of its
+    /// rows. The pending revisions of the opened database are written to disk first, so the
+    /// backup contains the latest changes.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn export_database_raw(&self, database_id: &str) -> FlowyResult<DatabaseBackup> {
+        let editor = self.database_editors.read().await.get(database_id);
+        if let Some(editor) = editor {
+            editor.flush().await?;
+        }
+
+        let rev_stores = self.rev_stores();
+        let database_disk_cache = self.database_rev_store(database_id)?.revision_disk_cache();
+        let database_revisions = read_revisions(database_disk_cache.as_ref(), database_id)?;
+        if database_revisions.is_empty() {
+            return Err(FlowyError::record_not_found().context(format!("Can't find the database: {}", database_id)));
+        }
+
+        let mut block_revisions = HashMap::new();
+        for block_id in read_database_block_ids(&rev_stores, database_id)? {
+            let block_disk_cache = rev_stores
+                .make_store(GridRevisionObject::Block, &block_id)?
+                .revision_disk_cache();
+            let revisions = read_revisions(block_disk_cache.as_ref(), &block_id)?;
+            block_revisions.insert(block_id, revisions);
+        }
+
+        let view_disk_cache = rev_stores
+            .make_store(GridRevisionObject::View, database_id)?
+            .revision_disk_cache();
+        let view_revisions = read_revisions(view_disk_cache.as_ref(), database_id)?;
+
+        Ok(DatabaseBackup {
+            database_id: database_id.to_owned(),
+            database_revisions,
+            block_revisions,
+            view_revisions,
+        })
+    }
+
+    /// Imports the [DatabaseBackup] as a new database with id `new_database_id`.
+    ///
+    /// The revisions of the backup are replayed to rebuild the database, its blocks and its view.
+    /// Then the ids of the blocks and rows are regenerated, like `duplicate_database` does, so the
+    /// imported database won't conflict with the original one if it still exists. The imported
+    /// database starts with a single revision for each object, and its rows are re-indexed.
+    #[tracing::instrument(level = "debug", skip(self, backup), err)]
+    pub async fn import_database_raw(&self, backup: DatabaseBackup, new_database_id: &str) -> FlowyResult<()> {
+        let DatabaseBackup {
+            database_id,
+            database_revisions,
+            mut block_revisions,
+            view_revisions,
+        } = backup;
+
+        let database_pad = DatabaseRevisionPad::from_revisions(database_revisions)?;
+        let (field_revs, duplicated_block_metas) = database_pad.duplicate_grid_block_meta().await;
+        let mut blocks = vec![];
+        for (block_meta_rev, duplicated_block_meta) in database_pad
+            .get_block_meta_revs()
+            .iter()
+            .zip(duplicated_block_metas.iter())
+        {
+            let block_id = &block_meta_rev.block_id;
+            let revisions = block_revisions.remove(block_id).unwrap_or_default();
+            if revisions.is_empty() {
+                return Err(FlowyError::record_not_found()
+                    .context(format!("The backup doesn't contain the block: {}", block_id)));
+            }
+            let block_pad = GridBlockRevisionPad::from_revisions(block_id, revisions)?;
+            blocks.push(block_pad.duplicate_data(&duplicated_block_meta.block_id));
+        }
+
+        let view_pad = GridViewRevisionPad::from_revisions(&database_id, view_revisions)?;
+        let mut view_rev = DatabaseViewRevision::clone(&view_pad);
+        view_rev.view_id = new_database_id.to_owned();
+        view_rev.grid_id = new_database_id.to_owned();
+        let layout = view_rev.layout.clone().into();
+
+        let build_context = BuildDatabaseContext {
+            field_revs: field_revs.into_iter().map(Arc::new).collect(),
+            block_metas: duplicated_block_metas,
+            blocks,
+            grid_view_revision_data: serde_json::to_string(&view_rev)?,
+        };
+        let _ = self
+            .create_database_from_build_context(new_database_id, layout, build_context)
+            .await?;
+        Ok(())
+    }
+
+    /// Creates the revisions of the blocks, the database and the view from the [BuildDatabaseContext].
+    /// The rows of the blocks will be indexed in the [BlockIndexCache].
+    ///
+    /// The revisions are written all or nothing, so a failure in the middle doesn't leave a
+    /// database whose blocks exist but whose view is missing.
+    ///
+    /// Returns the bytes of the database's initial revision.
+    async fn create_database_from_build_context(
         &self,
-        database_id: &str,
-        pool: Arc<ConnectionPool>,
-    ) -> Result<Arc<DatabaseRevisionEditor>, FlowyError> {
-        let user = self.database_user.clone();
-        let rev_manager = self.make_database_rev_manager(database_id, pool.clone())?;
+        view_id: &str,
+        layout: DatabaseViewLayout,
+        build_context: BuildDatabaseContext,
+    ) -> FlowyResult<Bytes> {
+        let revisions = make_database_initial_revisions(view_id, layout, build_context)?;
+        let rev_stores = self.rev_stores();
+        let block_index_cache = self.block_index_cache.clone();
+        tokio::task::spawn_blocking(move || {
+            write_database_initial_revisions(revisions, &rev_stores, &block_index_cache)
+        })
+        .await
+        .map_err(internal_error)?
+    }
+
+    /// Creates multiple databases concurrently, at most [CREATE_DATABASES_CONCURRENCY] databases at
+    /// the same time. It's used when importing a template that contains many databases.
+    ///
+    /// The revisions of each database are written all or nothing, so a database is either
+    /// created completely or not created at all. The failure of one database doesn't abort the
+    /// others. Returns a result for each database, in the same order as the `contexts`.
+    #[tracing::instrument(level = "debug", skip_all)]
+    pub async fn create_databases_batch(
+        &self,
+        contexts: Vec<(String, DatabaseViewLayout, BuildDatabaseContext)>,
+    ) -> Vec<FlowyResult<Bytes>> {
+        let rev_stores = self.rev_stores();
+        futures::stream::iter(contexts)
+            .map(|(view_id, layout, build_context)| {
+                let rev_stores = rev_stores.clone();
+                let block_index_cache = self.block_index_cache.clone();
+                async move {
+                    let result = tokio::task::spawn_blocking(move || {
+                        let revisions = make_database_initial_revisions(&view_id, layout, build_context)?;
+                        write_database_initial_revisions(revisions, &rev_stores, &block_index_cache)
+                    })
+                    .await
+                    .map_err(internal_error)
+                    .and_then(|result| result);
+                    if let Err(e) = &result {
+                        tracing::error!("Create database failed: {:?}", e);
+                    }
+                    result
+                }
+            })
+            .buffered(CREATE_DATABASES_CONCURRENCY)
+            .collect::<Vec<FlowyResult<Bytes>>>()
+            .await
+    }
+
+    /// Opens the editor of the database. If the database can't be opened, its revisions are
+    /// verified. If they are corrupted, the database will be recovered from its latest valid
+    /// snapshot and opened again.
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    async fn make_database_rev_editor(&self, database_id: &str) -> Result<Arc<DatabaseRevisionEditor>, FlowyError> {
+        match self.try_make_database_rev_editor(database_id).await {
+            Ok(editor) => Ok(editor),
+            Err(e) if e.is_record_not_found() || e.is_database_encrypted() => Err(e),
+            Err(e) => {
+                let records = self
+                    .database_rev_store(database_id)?
+                    .revision_disk_cache()
+                    .read_revision_records(database_id, None)?;
+                let report = verify_database_revision_records(database_id, records);
+                tracing::error!("Open database:{} failed: {:?}, {:?}", database_id, e, report);
+                if !report.is_corrupted() {
+                    return Err(e);
+                }
+
+                self.recover_database_from_snapshot(database_id)
+                    .await
+                    .map_err(|_| e.context(format!("The revisions are corrupted: {:?}", report.issues)))?;
+                self.try_make_database_rev_editor(database_id).await
+            }
+        }
+    }
+
+    /// Resets the database to its latest snapshot that can be deserialized.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    async fn recover_database_from_snapshot(&self, database_id: &str) -> FlowyResult<()> {
+        let rev_store = self.database_rev_store(database_id)?;
+        let disk_cache = rev_store.revision_disk_cache();
+        let snapshots = rev_store.snapshot_disk_cache().read_snapshots(usize::MAX)?;
+
+        // The restored revision's rev_id must be greater than the existing ones, so the snapshots
+        // generated later won't collide with the existing snapshots.
+        let max_rev_id = disk_cache
+            .read_revision_records(database_id, None)?
+            .iter()
+            .map(|record| record.revision.rev_id)
+            .chain(snapshots.iter().map(|snapshot| snapshot.rev_id))
+            .max()
+            .unwrap_or(0);
+        for snapshot in snapshots {
+            let revision = Revision::new(database_id, max_rev_id, max_rev_id + 1, snapshot.data, "".to_owned());
+            match DatabaseRevisionPad::from_revisions(vec![revision.clone()]) {
+                Ok(_) => {
+                    tracing::info!("Recover database:{} from snapshot:{}", database_id, snapshot.rev_id);
+                    let rev_manager = self.make_database_rev_manager(database_id)?;
+                    rev_manager.reset_object(vec![revision]).await?;
+                    return Ok(());
+                }
+                Err(e) => tracing::warn!("Snapshot:{} of {} is invalid: {:?}", snapshot.rev_id, database_id, e),
+            }
+        }
+        Err(FlowyError::record_not_found().context(format!("Can't find a valid snapshot of {}", database_id)))
+    }
+
+    async fn try_make_database_rev_editor(&self, database_id: &str) -> Result<Arc<DatabaseRevisionEditor>, FlowyError> {
+        let rev_stores = self.rev_stores();
+        let number_of_revisions = rev_stores
+            .make_store(GridRevisionObject::Database, database_id)?
+            .read_stats()?
+            .number_of_revisions;
+        let rev_compress = rev_stores.rev_compress().clone();
+        let exceeds_soft_limit = number_of_revisions > rev_compress.revision_soft_limit;
+        let open_from_snapshot = number_of_revisions > rev_compress.revision_hard_limit;
+        if exceeds_soft_limit {
+            tracing::warn!(
+                "The database:{} has {} revisions, which exceeds the soft limit:{}",
+                database_id,
+                number_of_revisions,
+                rev_compress.revision_soft_limit
+            );
+        }
+
+        // The revisions are compacted before the editor is opened, so the editor doesn't load all
+        // of them. The revisions of the database that is opened from its snapshot are compacted
+        // after they are replayed in the background.
+        if exceeds_soft_limit && !open_from_snapshot {
+            if let Err(e) = self.compact_database_history(database_id).await {
+                tracing::error!("Compact database:{} failed: {:?}", database_id, e);
+            }
+        }
+
+        let rev_manager = self.make_database_rev_manager(database_id)?;
         let database_editor = DatabaseRevisionEditor::new(
             database_id,
-            user,
+            self.database_user.clone(),
             rev_manager,
             self.block_index_cache.clone(),
             self.task_scheduler.clone(),
+            rev_stores,
+            self.attachment_store.clone(),
+            self.field_templates.clone(),
+            self.row_templates.clone(),
+            self.filter_results.clone(),
+            self.config.undo_depth,
+            self.config.notify_cell_values,
+            open_from_snapshot,
         )
         .await?;
         Ok(database_editor)
     }
 
-    #[tracing::instrument(level = "trace", skip(self, pool), err)]
-    pub fn make_database_rev_manager(
-        &self,
-        database_id: &str,
-        pool: Arc<ConnectionPool>,
-    ) -> FlowyResult<RevisionManager<Arc<ConnectionPool>>> {
+    /// Generates a snapshot of the database that is not opened, then compacts its revisions that
+    /// are covered by the snapshot. Returns the number of bytes that are reclaimed.
+    async fn compact_database_history(&self, database_id: &str) -> FlowyResult<usize> {
+        let mut rev_manager = self.make_database_rev_manager(database_id)?;
+        let _ = rev_manager.initialize::<GridRevisionSerde>(None).await?;
+        rev_manager.generate_snapshot().await;
+        let reclaimed_size = rev_manager.compact_history(REPAIR_KEEP_LAST_REVISIONS).await;
+        rev_manager.close().await;
+        reclaimed_size
+    }
+
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    pub fn make_database_rev_manager(&self, database_id: &str) -> FlowyResult<RevisionManager<Arc<ConnectionPool>>> {
         let user_id = self.database_user.user_id()?;
+        let rev_store = self.database_rev_store(database_id)?;
 
         // Create revision persistence
-        let disk_cache = SQLiteDatabaseRevisionPersistence::new(&user_id, pool.clone());
-        let configuration = RevisionPersistenceConfiguration::new(6, false);
-        let rev_persistence = RevisionPersistence::new(&user_id, database_id, disk_cache, configuration);
+        let configuration = self.rev_compress_config().database_configuration();
+        let rev_persistence =
+            RevisionPersistence::from_disk_cache(&user_id, database_id, rev_store.revision_disk_cache(), configuration);
 
         // Create snapshot persistence
-        let snapshot_object_id = format!("grid:{}", database_id);
-        let snapshot_persistence = SQLiteDatabaseRevisionSnapshotPersistence::new(&snapshot_object_id, pool);
+        let snapshot_persistence = rev_store.snapshot_disk_cache();
 
         let rev_compress = GridRevisionMergeable();
         let rev_manager = RevisionManager::new(
@@ -183,6 +1133,59 @@ impl DatabaseManager {
         );
         Ok(rev_manager)
     }
+
+    /// Rewrites the revisions and the snapshots of the database, its blocks and its view that are
+    /// encrypted with the `old` cipher with the `new` cipher. None means the data is not encrypted. The database must be
+    /// closed, and the [DatabaseManager] should be recreated with the `new` cipher afterwards.
+    #[tracing::instrument(level = "debug", skip(self, old, new), err)]
+    pub async fn reencrypt_database(
+        &self,
+        database_id: &str,
+        old: Option<Arc<dyn RevisionCipher>>,
+        new: Option<Arc<dyn RevisionCipher>>,
+    ) -> FlowyResult<()> {
+        if self.database_editors.read().await.get(database_id).is_some() {
+            return Err(FlowyError::internal().context(format!(
+                "The database:{} must be closed before re-encrypting it",
+                database_id
+            )));
+        }
+
+        let rev_stores = GridRevisionStores::new(
+            self.database_user.clone(),
+            self.rev_store_factory.clone(),
+            old,
+            self.rev_compress_config(),
+        );
+        // The database is re-encrypted last, so the ids of its blocks can still be read with the
+        // `old` cipher if re-encrypting one of the blocks fails.
+        for block_id in read_database_block_ids(&rev_stores, database_id)? {
+            rev_stores
+                .make_store(GridRevisionObject::Block, &block_id)?
+                .reencrypt(new.as_ref())?;
+        }
+        rev_stores
+            .make_store(GridRevisionObject::View, database_id)?
+            .reencrypt(new.as_ref())?;
+        rev_stores
+            .make_store(GridRevisionObject::Database, database_id)?
+            .reencrypt(new.as_ref())
+    }
+
+    /// Makes the stores of the revisions and the snapshots of the databases, their blocks and their
+    /// views with the `rev_store_factory`.
+    fn rev_stores(&self) -> GridRevisionStores {
+        GridRevisionStores::new(
+            self.database_user.clone(),
+            self.rev_store_factory.clone(),
+            self.cipher.clone(),
+            self.rev_compress_config(),
+        )
+    }
+
+    fn database_rev_store(&self, database_id: &str) -> FlowyResult<Arc<dyn GridRevisionStore<Arc<ConnectionPool>>>> {
+        self.rev_stores().make_store(GridRevisionObject::Database, database_id)
+    }
 }
 
 pub async fn make_database_view_data(
@@ -192,6 +1195,50 @@ pub async fn make_database_view_data(
     database_manager: Arc<DatabaseManager>,
     build_context: BuildDatabaseContext,
 ) -> FlowyResult<Bytes> {
+    database_manager
+        .create_database_from_build_context(view_id, layout, build_context)
+        .await
+}
+
+#[async_trait]
+impl RefCountValue for DatabaseRevisionEditor {
+    async fn did_remove(&self) {
+        if let Err(e) = self.flush().await {
+            tracing::error!("Flush the database {} failed: {:?}", self.database_id, e);
+        }
+        self.close().await;
+    }
+}
+
+#[async_trait]
+impl RelatedRowResolver for DatabaseManager {
+    async fn get_related_rows(&self, database_id: &str, row_ids: &[String]) -> FlowyResult<Vec<RelatedRowPB>> {
+        self.read_related_rows(database_id, Some(row_ids)).await
+    }
+
+    async fn get_candidate_rows(&self, database_id: &str) -> FlowyResult<Vec<RelatedRowPB>> {
+        self.read_related_rows(database_id, None).await
+    }
+}
+
+/// The maximum number of the databases that are created at the same time by `create_databases_batch`.
+const CREATE_DATABASES_CONCURRENCY: usize = 4;
+
+/// The initial revisions of a database that are built from the [BuildDatabaseContext].
+struct DatabaseInitialRevisions {
+    database_revision: Revision,
+    block_revisions: Vec<Revision>,
+    view_revision: Revision,
+    /// The (block_id, row_id) pairs of the rows in the blocks.
+    row_indexes: Vec<(String, String)>,
+    database_bytes: Bytes,
+}
+
+fn make_database_initial_revisions(
+    view_id: &str,
+    layout: DatabaseViewLayout,
+    build_context: BuildDatabaseContext,
+) -> FlowyResult<DatabaseInitialRevisions> {
     let BuildDatabaseContext {
         field_revs,
         block_metas,
@@ -199,49 +1246,201 @@ pub async fn make_database_view_data(
         grid_view_revision_data,
     } = build_context;
 
+    let mut row_indexes = vec![];
+    let mut block_revisions = vec![];
     for block_meta_data in &blocks {
-        let block_id = &block_meta_data.block_id;
-        // Indexing the block's rows
         block_meta_data.rows.iter().for_each(|row| {
-            let _ = database_manager.block_index_cache.insert(&row.block_id, &row.id);
+            row_indexes.push((row.block_id.clone(), row.id.clone()));
         });
 
-        // Create grid's block
         let grid_block_delta = make_database_block_operations(block_meta_data);
         let block_delta_data = grid_block_delta.json_bytes();
-        let revision = Revision::initial_revision(block_id, block_delta_data);
-        database_manager
-            .create_database_block(&block_id, vec![revision])
-            .await?;
+        block_revisions.push(Revision::initial_revision(&block_meta_data.block_id, block_delta_data));
     }
 
     // Will replace the grid_id with the value returned by the gen_grid_id()
     let grid_id = view_id.to_owned();
     let grid_rev = DatabaseRevision::from_build_context(&grid_id, field_revs, block_metas);
-
-    // Create grid
     let grid_rev_delta = make_database_operations(&grid_rev);
-    let grid_rev_delta_bytes = grid_rev_delta.json_bytes();
-    let revision = Revision::initial_revision(&grid_id, grid_rev_delta_bytes.clone());
-    database_manager.create_database(&grid_id, vec![revision]).await?;
+    let database_bytes = grid_rev_delta.json_bytes();
+    let database_revision = Revision::initial_revision(&grid_id, database_bytes.clone());
 
-    // Create grid view
     let grid_view = if grid_view_revision_data.is_empty() {
         DatabaseViewRevision::new(grid_id, view_id.to_owned(), layout.into())
     } else {
         DatabaseViewRevision::from_json(grid_view_revision_data)?
     };
     let grid_view_delta = make_grid_view_operations(&grid_view);
-    let grid_view_delta_bytes = grid_view_delta.json_bytes();
-    let revision = Revision::initial_revision(view_id, grid_view_delta_bytes);
-    database_manager.create_database_view(view_id, vec![revision]).await?;
+    let view_revision = Revision::initial_revision(view_id, grid_view_delta.json_bytes());
 
-    Ok(grid_rev_delta_bytes)
+    Ok(DatabaseInitialRevisions {
+        database_revision,
+        block_revisions,
+        view_revision,
+        row_indexes,
+        database_bytes,
+    })
 }
 
-#[async_trait]
-impl RefCountValue for DatabaseRevisionEditor {
-    async fn did_remove(&self) {
-        self.close().await;
+/// Writes the initial revisions of the database, its blocks and its view, then indexes its rows.
+/// The rows are indexed in a separate store, so the revisions are deleted if indexing fails.
+fn write_database_initial_revisions(
+    revisions: DatabaseInitialRevisions,
+    rev_stores: &GridRevisionStores,
+    block_index_cache: &BlockIndexCache,
+) -> FlowyResult<Bytes> {
+    let DatabaseInitialRevisions {
+        database_revision,
+        block_revisions,
+        view_revision,
+        row_indexes,
+        database_bytes,
+    } = revisions;
+
+    let mut objects = block_revisions
+        .into_iter()
+        .map(|revision| (GridRevisionObject::Block, revision))
+        .collect::<Vec<(GridRevisionObject, Revision)>>();
+    objects.push((GridRevisionObject::Database, database_revision));
+    objects.push((GridRevisionObject::View, view_revision));
+    let object_ids = objects
+        .iter()
+        .map(|(object, revision)| (*object, revision.object_id.clone()))
+        .collect::<Vec<(GridRevisionObject, String)>>();
+
+    let user_id = rev_stores.user().user_id()?;
+    rev_stores
+        .factory()
+        .reset_objects(&user_id, objects, rev_stores.options()?)?;
+
+    let mut row_ids_by_block: HashMap<String, Vec<String>> = HashMap::new();
+    for (block_id, row_id) in row_indexes {
+        row_ids_by_block.entry(block_id).or_default().push(row_id);
+    }
+    for (block_id, row_ids) in row_ids_by_block {
+        if let Err(e) = block_index_cache.insert_rows(&block_id, &row_ids) {
+            for (object, object_id) in object_ids {
+                if let Err(e) = rev_stores
+                    .make_store(object, &object_id)
+                    .and_then(|store| store.delete())
+                {
+                    tracing::error!("Delete the revisions of {} failed: {:?}", object_id, e);
+                }
+            }
+            return Err(e);
+        }
+    }
+    Ok(database_bytes)
+}
+
+fn trace_ref_count(database_id: &str, ref_count: usize, threshold: usize) {
+    if ref_count > threshold {
+        let span = tracing::warn_span!("database_ref_count", database_id, ref_count);
+        let _enter = span.enter();
+        tracing::warn!(
+            "The reference count exceeds the threshold: {}, check if the database is closed after opening",
+            threshold
+        );
+    }
+}
+
+fn read_revisions(
+    disk_cache: &dyn RevisionDiskCache<Arc<ConnectionPool>, Error = FlowyError>,
+    object_id: &str,
+) -> FlowyResult<Vec<Revision>> {
+    let revisions = disk_cache
+        .read_revision_records(object_id, None)?
+        .into_iter()
+        .map(|record| record.revision)
+        .collect::<Vec<Revision>>();
+    Ok(revisions)
+}
+
+/// Returns the ids of the database's blocks by reading the revisions from disk. It returns
+/// an empty list if the database doesn't exist.
+fn read_database_block_ids(rev_stores: &GridRevisionStores, database_id: &str) -> FlowyResult<Vec<String>> {
+    let disk_cache = rev_stores
+        .make_store(GridRevisionObject::Database, database_id)?
+        .revision_disk_cache();
+    let revisions = read_revisions(disk_cache.as_ref(), database_id)?;
+    if revisions.is_empty() {
+        return Ok(vec![]);
+    }
+
+    match DatabaseRevisionPad::from_revisions(revisions) {
+        Ok(pad) => Ok(pad
+            .get_block_meta_revs()
+            .iter()
+            .map(|block_meta_rev| block_meta_rev.block_id.clone())
+            .collect()),
+        Err(e) => {
+            tracing::error!("Deserialize database:{} failed: {:?}", database_id, e);
+            Ok(vec![])
+        }
+    }
+}
+
+fn archived_flag_key(database_id: &str) -> String {
+    format!("archived:{}", database_id)
+}
+
+/// Spawns a task that closes the idle editors periodically. The task stops when the editors are
+/// dropped.
+fn spawn_idle_editors_eviction(database_editors: Weak<DatabaseEditorMap>, idle_ttl: Duration) {
+    let period = std::cmp::max(std::cmp::min(idle_ttl, Duration::from_secs(30)), Duration::from_secs(1));
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        loop {
+            interval.tick().await;
+            match database_editors.upgrade() {
+                None => break,
+                Some(database_editors) => {
+                    let database_ids = close_idle_editors(&database_editors, idle_ttl).await;
+                    if !database_ids.is_empty() {
+                        tracing::trace!("Close idle databases: {:?}", database_ids);
+                    }
+                }
+            }
+        }
+    });
+}
+
+async fn close_idle_editors(database_editors: &DatabaseEditorMap, idle_ttl: Duration) -> Vec<String> {
+    let idle_editors = take_idle_editors(&mut *database_editors.write().await, idle_ttl, usize::MAX);
+    let mut database_ids = Vec::with_capacity(idle_editors.len());
+    for editor in idle_editors {
+        editor.did_remove().await;
+        database_ids.push(editor.database_id.clone());
     }
+    database_ids
+}
+
+/// Takes at most `limit` editors, that have been idle longer than the `idle_ttl`, out of the map.
+/// The editors that were idle the longest are taken first.
+///
+/// An editor is taken only if no one has opened it by `open_database` without closing it, and
+/// there is no one outside the map holding it, so closing it won't affect the callers.
+fn take_idle_editors(
+    database_editors: &mut RefCountHashMap<Arc<DatabaseRevisionEditor>>,
+    idle_ttl: Duration,
+    limit: usize,
+) -> Vec<Arc<DatabaseRevisionEditor>> {
+    if limit == 0 {
+        return vec![];
+    }
+
+    let mut idle_editors = database_editors
+        .values()
+        .into_iter()
+        // One is held by the map and the other is held by the returned values.
+        .filter(|editor| database_editors.ref_count(&editor.database_id) == 0 && Arc::strong_count(editor) == 2)
+        .filter(|editor| editor.idle_duration() >= idle_ttl)
+        .map(|editor| (editor.idle_duration(), editor.database_id.clone()))
+        .collect::<Vec<(Duration, String)>>();
+    idle_editors.sort_by(|a, b| b.0.cmp(&a.0));
+    idle_editors
+        .into_iter()
+        .take(limit)
+        .flat_map(|(_, database_id)| database_editors.take(&database_id))
+        .collect()
 }
@@ -5,13 +5,24 @@ const OBSERVABLE_CATEGORY: &str = "Grid";
 #[derive(ProtoBuf_Enum, Debug)]
 pub enum DatabaseNotification {
     Unknown = 0,
+    DidUpdateDatabase = 10,
     DidCreateBlock = 11,
+    DidReloadDatabase = 12,
+    DidFinishRepairingDatabase = 13,
     DidUpdateDatabaseViewRows = 20,
     DidUpdateDatabaseViewRowsVisibility = 21,
     DidUpdateDatabaseFields = 22,
     DidUpdateRow = 30,
+    DidMoveRow = 31,
+    DidDeleteRows = 32,
+    DidTrashRows = 33,
+    DidRestoreRows = 34,
+    DidUpdateRowMeta = 35,
     DidUpdateCell = 40,
+    DidUpdateCellComments = 41,
     DidUpdateField = 50,
+    DidUpdateDuplicateFieldProgress = 51,
+    DidUpdateConvertFieldProgress = 52,
     DidUpdateGroupView = 60,
     DidUpdateGroup = 61,
     DidGroupByNewField = 62,
@@ -19,6 +30,8 @@ pub enum DatabaseNotification {
     DidUpdateSort = 64,
     DidReorderRows = 65,
     DidReorderSingleRow = 66,
+    DidReorderMovedRows = 67,
+    DidMoveGroup = 68,
     DidUpdateDatabaseSetting = 70,
 }
 
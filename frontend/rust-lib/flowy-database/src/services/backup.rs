@@ -0,0 +1,31 @@
+use flowy_error::FlowyResult;
+use revision_model::Revision;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// [DatabaseBackup] contains the raw revisions that are needed to reconstruct a database. It's
+/// generated by `DatabaseManager::export_database_raw` and can be imported by calling
+/// `DatabaseManager::import_database_raw`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DatabaseBackup {
+    pub database_id: String,
+    pub database_revisions: Vec<Revision>,
+
+    /// The revisions of each block, keyed by the block id.
+    pub block_revisions: HashMap<String, Vec<Revision>>,
+
+    /// The revisions of the database's view. The view shares the same id with the database.
+    pub view_revisions: Vec<Revision>,
+}
+
+impl DatabaseBackup {
+    pub fn to_json(&self) -> FlowyResult<String> {
+        let s = serde_json::to_string(self)?;
+        Ok(s)
+    }
+
+    pub fn from_json(s: &str) -> FlowyResult<Self> {
+        let backup = serde_json::from_str(s)?;
+        Ok(backup)
+    }
+}
@@ -5,9 +5,10 @@ use flowy_client_sync::make_operations_from_revisions;
 use flowy_error::{FlowyError, FlowyResult};
 use flowy_revision::{
     RevisionCloudService, RevisionManager, RevisionMergeable, RevisionObjectDeserializer, RevisionObjectSerializer,
+    RevisionSnapshot,
 };
 use flowy_sqlite::ConnectionPool;
-use grid_model::{CellRevision, DatabaseBlockRevision, RowChangeset, RowRevision};
+use grid_model::{CellCommentRevision, CellRevision, DatabaseBlockRevision, RowChangeset, RowRevision};
 use lib_infra::future::FutureResult;
 use lib_infra::retry::spawn_retry;
 use lib_ot::core::EmptyAttributes;
@@ -56,6 +57,44 @@ impl DatabaseBlockRevisionEditor {
         self.rev_manager.close().await;
     }
 
+    pub async fn generate_snapshot(&self) {
+        self.rev_manager.generate_snapshot().await;
+    }
+
+    pub async fn flush(&self) -> FlowyResult<()> {
+        self.rev_manager.flush().await
+    }
+
+    /// Returns the id of the latest revision of the block.
+    pub fn rev_id(&self) -> i64 {
+        self.rev_manager.rev_id()
+    }
+
+    /// Returns at most `limit` snapshots of the block, the latest one first.
+    pub async fn read_snapshots(&self, limit: usize) -> FlowyResult<Vec<RevisionSnapshot>> {
+        self.rev_manager.read_snapshots(limit).await
+    }
+
+    /// Generates a snapshot of the block with the name. Returns false if the block has no revision.
+    pub async fn generate_named_snapshot(&self, name: &str) -> FlowyResult<bool> {
+        self.rev_manager.generate_named_snapshot(name).await
+    }
+
+    pub async fn read_named_snapshot(&self, name: &str) -> FlowyResult<Option<RevisionSnapshot>> {
+        let named_snapshot = self.rev_manager.read_named_snapshot(name).await?;
+        Ok(named_snapshot.map(|named_snapshot| named_snapshot.snapshot))
+    }
+
+    /// Resets the rows of the block to the content of the snapshot.
+    pub async fn restore_snapshot(&self, snapshot: RevisionSnapshot) -> FlowyResult<()> {
+        let block_pad = self
+            .rev_manager
+            .restore_snapshot::<DatabaseBlockRevisionSerde>(snapshot)
+            .await?;
+        *self.pad.write() = block_pad;
+        Ok(())
+    }
+
     pub async fn duplicate_block(&self, duplicated_block_id: &str) -> DatabaseBlockRevision {
         self.pad.read().duplicate_data(duplicated_block_id)
     }
@@ -89,6 +128,23 @@ impl DatabaseBlockRevisionEditor {
         Ok((row_count, row_index))
     }
 
+    /// Inserts the rows at the index in one revision. The rows are appended to the list if the
+    /// index is None. Returns the number of rows of the block and the index of the first row.
+    pub(crate) async fn create_rows(&self, rows: Vec<RowRevision>, index: Option<usize>) -> FlowyResult<(i32, i32)> {
+        let mut row_count = 0;
+        let mut row_index = 0;
+        self.modify(|block_pad| {
+            let number_of_rows = block_pad.number_of_rows() as usize;
+            row_index = index.filter(|index| *index <= number_of_rows).unwrap_or(number_of_rows) as i32;
+            let change = block_pad.add_row_revs(rows, index)?;
+            row_count = block_pad.number_of_rows();
+            Ok(change)
+        })
+        .await?;
+
+        Ok((row_count, row_index))
+    }
+
     pub async fn delete_rows(&self, ids: Vec<Cow<'_, String>>) -> FlowyResult<i32> {
         let mut row_count = 0;
         self.modify(|block_pad| {
@@ -105,6 +161,81 @@ impl DatabaseBlockRevisionEditor {
         Ok(())
     }
 
+    /// Applies the changesets of the rows in one revision.
+    pub async fn update_rows(&self, changesets: Vec<RowChangeset>) -> FlowyResult<()> {
+        self.modify(|block_pad| Ok(block_pad.update_rows(changesets)?)).await?;
+        Ok(())
+    }
+
+    pub async fn retain_cells(&self, field_ids: &[String]) -> FlowyResult<()> {
+        self.modify(|block_pad| Ok(block_pad.retain_cells(field_ids)?)).await?;
+        Ok(())
+    }
+
+    /// Copies the cells of the field to another field in one revision. Returns the ids of the rows
+    /// whose cells are copied.
+    pub async fn copy_cells(&self, from_field_id: &str, to_field_id: &str) -> FlowyResult<Vec<String>> {
+        let mut row_ids = vec![];
+        self.modify(|block_pad| {
+            row_ids = block_pad
+                .rows
+                .iter()
+                .filter(|row_rev| row_rev.cells.contains_key(from_field_id))
+                .map(|row_rev| row_rev.id.clone())
+                .collect();
+            Ok(block_pad.copy_cells(from_field_id, to_field_id)?)
+        })
+        .await?;
+        Ok(row_ids)
+    }
+
+    /// Removes the cells of the field from all the rows in one revision. Returns the ids of the rows
+    /// whose cells are removed.
+    pub async fn clear_cells(&self, field_id: &str) -> FlowyResult<Vec<String>> {
+        let mut row_ids = vec![];
+        self.modify(|block_pad| {
+            row_ids = block_pad
+                .rows
+                .iter()
+                .filter(|row_rev| row_rev.cells.contains_key(field_id))
+                .map(|row_rev| row_rev.id.clone())
+                .collect();
+            Ok(block_pad.delete_cells(&[field_id.to_owned()])?)
+        })
+        .await?;
+        Ok(row_ids)
+    }
+
+    /// Removes the cells and the comments of the fields from all the rows.
+    pub async fn delete_cells(&self, field_ids: &[String]) -> FlowyResult<()> {
+        self.modify(|block_pad| Ok(block_pad.delete_cells(field_ids)?)).await?;
+        self.delete_comments(field_ids).await
+    }
+
+    /// Removes the comments of the fields from all the rows in one revision.
+    pub async fn delete_comments(&self, field_ids: &[String]) -> FlowyResult<()> {
+        self.modify(|block_pad| Ok(block_pad.delete_comments(field_ids)?))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn add_cell_comment(
+        &self,
+        row_id: &str,
+        field_id: &str,
+        comment: CellCommentRevision,
+    ) -> FlowyResult<()> {
+        self.modify(|block_pad| Ok(block_pad.add_cell_comment(row_id, field_id, comment)?))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn delete_cell_comment(&self, row_id: &str, field_id: &str, comment_id: &str) -> FlowyResult<()> {
+        self.modify(|block_pad| Ok(block_pad.delete_cell_comment(row_id, field_id, comment_id)?))
+            .await?;
+        Ok(())
+    }
+
     pub async fn move_row(&self, row_id: &str, from: usize, to: usize) -> FlowyResult<()> {
         self.modify(|block_pad| Ok(block_pad.move_row(row_id, from, to)?))
             .await?;
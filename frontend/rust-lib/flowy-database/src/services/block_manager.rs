@@ -1,17 +1,22 @@
-use crate::entities::{CellChangesetPB, InsertedRowPB, UpdatedRowPB};
-use crate::manager::DatabaseUser;
+use crate::entities::{CellChangePB, CellChangesetPB, InsertedRowPB, RepeatedCellCommentPB, UpdatedRowPB};
 use crate::notification::{send_notification, DatabaseNotification};
 use crate::services::block_editor::{DatabaseBlockRevisionEditor, GridBlockRevisionMergeable};
 use crate::services::persistence::block_index::BlockIndexCache;
-use crate::services::persistence::rev_sqlite::{
-    SQLiteDatabaseBlockRevisionPersistence, SQLiteDatabaseRevisionSnapshotPersistence,
-};
-use crate::services::row::{make_row_from_row_rev, DatabaseBlockRow, DatabaseBlockRowRevision};
+use crate::services::persistence::rev_store::{GridRevisionObject, GridRevisionStores, TimestampedRevision};
+use crate::services::row::{make_row_from_row_rev, DatabaseBlockRowRevision};
 use dashmap::DashMap;
+use flowy_client_sync::client_database::{make_database_block_operations, GridBlockOperations, GridBlockRevisionPad};
 use flowy_error::FlowyResult;
-use flowy_revision::{RevisionManager, RevisionPersistence, RevisionPersistenceConfiguration};
+use flowy_revision::{RevisionManager, RevisionPersistence, RevisionSnapshot};
+use flowy_revision_persistence::{RevisionDiskCache, RevisionState, SyncRecord};
 use flowy_sqlite::ConnectionPool;
-use grid_model::{GridBlockMetaRevision, GridBlockMetaRevisionChangeset, RowChangeset, RowRevision};
+use grid_model::{
+    CellCommentRevision, CellRevision, DatabaseBlockRevision, GridBlockMetaRevision, GridBlockMetaRevisionChangeset,
+    RowChangeset, RowRevision,
+};
+use lib_infra::util::timestamp;
+use lib_ot::core::OperationTransform;
+use revision_model::Revision;
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -27,10 +32,23 @@ pub enum DatabaseBlockEvent {
         block_id: String,
         row: UpdatedRowPB,
     },
+    /// The rows of the block that are updated in one revision.
+    UpdateRows {
+        block_id: String,
+        rows: Vec<UpdatedRowPB>,
+    },
     DeleteRow {
         block_id: String,
         row_id: String,
     },
+    /// The rows that are created by one batch, they may be in different blocks.
+    InsertRows {
+        rows: Vec<InsertedRowPB>,
+    },
+    /// The rows that are deleted by one batch, they may be in different blocks.
+    DeleteRows {
+        row_ids: Vec<String>,
+    },
     Move {
         block_id: String,
         deleted_row_id: String,
@@ -38,9 +56,35 @@ pub enum DatabaseBlockEvent {
     },
 }
 
+/// [RowRevisionChange] describes how a revision of the block changed the row. The row is None if
+/// it doesn't exist before or after the revision.
+#[derive(Debug, Clone)]
+pub(crate) struct RowRevisionChange {
+    pub(crate) rev_id: i64,
+    pub(crate) timestamp: i64,
+    pub(crate) before: Option<Arc<RowRevision>>,
+    pub(crate) after: Option<Arc<RowRevision>>,
+}
+
+/// The rows of the block that are deleted in one revision.
+pub(crate) struct DeletedBlockRows {
+    pub(crate) block_id: String,
+    /// The number of rows of the block after the rows are deleted.
+    pub(crate) row_count: i32,
+    /// The deleted rows and their indexes in the block before they were deleted.
+    pub(crate) rows: Vec<(usize, Arc<RowRevision>)>,
+}
+
+/// The number of rows whose converted cells are written in one revision of the block.
+pub(crate) const CONVERT_CELLS_BATCH_SIZE: usize = 100;
+
+/// The number of rows that a block holds at most when the rows are created in a batch. The rest of
+/// the rows are put into new blocks.
+pub const MAX_ROWS_PER_BLOCK: usize = 1000;
+
 type BlockId = String;
 pub(crate) struct DatabaseBlockManager {
-    user: Arc<dyn DatabaseUser>,
+    rev_stores: GridRevisionStores,
     persistence: Arc<BlockIndexCache>,
     block_editors: DashMap<BlockId, Arc<DatabaseBlockRevisionEditor>>,
     event_notifier: broadcast::Sender<DatabaseBlockEvent>,
@@ -48,15 +92,14 @@ pub(crate) struct DatabaseBlockManager {
 
 impl DatabaseBlockManager {
     pub(crate) async fn new(
-        user: &Arc<dyn DatabaseUser>,
+        rev_stores: GridRevisionStores,
         block_meta_revs: Vec<Arc<GridBlockMetaRevision>>,
         persistence: Arc<BlockIndexCache>,
         event_notifier: broadcast::Sender<DatabaseBlockEvent>,
     ) -> FlowyResult<Self> {
-        let block_editors = make_block_editors(user, block_meta_revs).await?;
-        let user = user.clone();
+        let block_editors = make_block_editors(&rev_stores, block_meta_revs).await?;
         let manager = Self {
-            user,
+            rev_stores,
             block_editors,
             persistence,
             event_notifier,
@@ -64,19 +107,86 @@ impl DatabaseBlockManager {
         Ok(manager)
     }
 
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<DatabaseBlockEvent> {
+        self.event_notifier.subscribe()
+    }
+
     pub async fn close(&self) {
         for block_editor in self.block_editors.iter() {
             block_editor.close().await;
         }
     }
 
+    pub async fn generate_snapshot(&self) {
+        for block_editor in self.block_editors.iter() {
+            block_editor.generate_snapshot().await;
+        }
+    }
+
+    pub async fn flush(&self) -> FlowyResult<()> {
+        for block_editor in self.block_editors.iter() {
+            block_editor.flush().await?;
+        }
+        Ok(())
+    }
+
+    /// Generates a snapshot with the name for each block.
+    pub async fn generate_named_snapshot(&self, name: &str) -> FlowyResult<()> {
+        for block_editor in self.block_editors.iter() {
+            block_editor.generate_named_snapshot(name).await?;
+        }
+        Ok(())
+    }
+
+    /// Restores the block from its latest snapshot that was generated no later than the `timestamp`,
+    /// and re-indexes the rows of the block. The block is kept as it is if there is no such snapshot.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn restore_block_from_snapshot(&self, block_id: &str, timestamp: i64) -> FlowyResult<()> {
+        let editor = self.get_block_editor(block_id).await?;
+        let snapshot = editor
+            .read_snapshots(usize::MAX)
+            .await?
+            .into_iter()
+            .find(|snapshot| snapshot.timestamp <= timestamp);
+        match snapshot {
+            None => tracing::warn!("Can't find the snapshot of the block:{} before {}", block_id, timestamp),
+            Some(snapshot) => self.restore_block(&editor, snapshot).await?,
+        }
+        Ok(())
+    }
+
+    /// Same as `restore_block_from_snapshot`, but the block is restored from its snapshot with the
+    /// name if there is one.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub(crate) async fn restore_block_from_named_snapshot(
+        &self,
+        block_id: &str,
+        name: &str,
+        timestamp: i64,
+    ) -> FlowyResult<()> {
+        let editor = self.get_block_editor(block_id).await?;
+        match editor.read_named_snapshot(name).await? {
+            None => self.restore_block_from_snapshot(block_id, timestamp).await,
+            Some(snapshot) => self.restore_block(&editor, snapshot).await,
+        }
+    }
+
+    async fn restore_block(&self, editor: &DatabaseBlockRevisionEditor, snapshot: RevisionSnapshot) -> FlowyResult<()> {
+        editor.restore_snapshot(snapshot).await?;
+        self.persistence.remove_block(&editor.block_id)?;
+        for row_rev in editor.get_row_revs::<&str>(None).await? {
+            self.persistence.insert(&editor.block_id, &row_rev.id)?;
+        }
+        Ok(())
+    }
+
     // #[tracing::instrument(level = "trace", skip(self))]
     pub(crate) async fn get_block_editor(&self, block_id: &str) -> FlowyResult<Arc<DatabaseBlockRevisionEditor>> {
         debug_assert!(!block_id.is_empty());
         match self.block_editors.get(block_id) {
             None => {
                 tracing::error!("This is a fatal error, block with id:{} is not exist", block_id);
-                let editor = Arc::new(make_database_block_editor(&self.user, block_id).await?);
+                let editor = Arc::new(make_database_block_editor(&self.rev_stores, block_id).await?);
                 self.block_editors.insert(block_id.to_owned(), editor.clone());
                 Ok(editor)
             }
@@ -130,7 +240,92 @@ impl DatabaseBlockManager {
         Ok(changesets)
     }
 
+    /// Inserts the rows into the block at the index in one revision. The rows are appended to the
+    /// block if the index is None. Returns the number of rows of the block and the inserted rows,
+    /// the [DatabaseBlockEvent] is sent by the caller once all the rows of the batch are inserted.
+    pub(crate) async fn create_rows_in_block(
+        &self,
+        block_id: &str,
+        row_revs: Vec<RowRevision>,
+        index: Option<usize>,
+    ) -> FlowyResult<(i32, Vec<InsertedRowPB>)> {
+        let row_ids = row_revs
+            .iter()
+            .map(|row_rev| row_rev.id.clone())
+            .collect::<Vec<String>>();
+        self.persistence.insert_rows(block_id, &row_ids)?;
+        let editor = self.get_block_editor(block_id).await?;
+
+        let mut rows = row_revs.iter().map(InsertedRowPB::from).collect::<Vec<InsertedRowPB>>();
+        let (number_of_rows, start_index) = editor.create_rows(row_revs, index).await?;
+        rows.iter_mut().zip(start_index..).for_each(|(row, index)| {
+            row.index = Some(index);
+        });
+        Ok((number_of_rows, rows))
+    }
+
+    /// Creates the block that contains the rows. The rows are written in the initial revision of
+    /// the block, and the caller adds the block to the metas of the database.
+    pub(crate) async fn create_block_with_rows(
+        &self,
+        block_id: &str,
+        row_revs: Vec<RowRevision>,
+    ) -> FlowyResult<Vec<InsertedRowPB>> {
+        let row_ids = row_revs
+            .iter()
+            .map(|row_rev| row_rev.id.clone())
+            .collect::<Vec<String>>();
+        let rows = row_revs
+            .iter()
+            .enumerate()
+            .map(|(index, row_rev)| {
+                let mut row = InsertedRowPB::from(row_rev);
+                row.index = Some(index as i32);
+                row
+            })
+            .collect::<Vec<InsertedRowPB>>();
+
+        let block_rev = DatabaseBlockRevision {
+            block_id: block_id.to_owned(),
+            rows: row_revs.into_iter().map(Arc::new).collect(),
+        };
+        let bytes = make_database_block_operations(&block_rev).json_bytes();
+        let disk_cache = self
+            .rev_stores
+            .make_store(GridRevisionObject::Block, block_id)?
+            .revision_disk_cache();
+        disk_cache.create_revision_records(vec![SyncRecord {
+            revision: Revision::initial_revision(block_id, bytes),
+            state: RevisionState::Sync,
+            write_to_disk: false,
+        }])?;
+        self.persistence.insert_rows(block_id, &row_ids)?;
+
+        let editor = Arc::new(make_database_block_editor(&self.rev_stores, block_id).await?);
+        self.block_editors.insert(block_id.to_owned(), editor);
+        Ok(rows)
+    }
+
+    pub(crate) fn notify_did_insert_rows(&self, rows: Vec<InsertedRowPB>) {
+        if !rows.is_empty() {
+            let _ = self.event_notifier.send(DatabaseBlockEvent::InsertRows { rows });
+        }
+    }
+
     pub async fn update_row(&self, changeset: RowChangeset) -> FlowyResult<()> {
+        self.update_row_with_cell_changes(changeset, vec![]).await
+    }
+
+    /// Same as `update_row`, but the notified row carries the old and new values of its cells.
+    async fn update_row_with_cell_changes(
+        &self,
+        mut changeset: RowChangeset,
+        cell_changes: Vec<CellChangePB>,
+    ) -> FlowyResult<()> {
+        // The row is modified whenever any of its cells is changed.
+        if !changeset.cell_by_field_id.is_empty() && changeset.modified_at.is_none() {
+            changeset.modified_at = Some(timestamp());
+        }
         let editor = self.get_editor_from_row_id(&changeset.row_id).await?;
         editor.update_row(changeset.clone()).await?;
         match editor.get_row_rev(&changeset.row_id).await? {
@@ -140,6 +335,7 @@ impl DatabaseBlockManager {
                 let row = UpdatedRowPB {
                     row: make_row_from_row_rev(row_rev),
                     field_ids: changed_field_ids,
+                    cell_changes,
                 };
 
                 let _ = self.event_notifier.send(DatabaseBlockEvent::UpdateRow {
@@ -170,25 +366,180 @@ impl DatabaseBlockManager {
         }
     }
 
-    pub(crate) async fn delete_rows(
+    /// Deletes the rows with one revision per block and removes their indexes. The ids of the rows
+    /// that can't be found are skipped and returned. The deleted rows are notified in one event.
+    pub(crate) async fn delete_rows(&self, row_ids: Vec<String>) -> FlowyResult<(Vec<DeletedBlockRows>, Vec<String>)> {
+        let mut skipped_row_ids = vec![];
+        let mut row_ids_by_block_id: Vec<(String, Vec<String>)> = vec![];
+        for row_id in row_ids {
+            match self.persistence.get_block_id(&row_id) {
+                Err(_) => skipped_row_ids.push(row_id),
+                Ok(block_id) => match row_ids_by_block_id.iter_mut().find(|(id, _)| id == &block_id) {
+                    None => row_ids_by_block_id.push((block_id, vec![row_id])),
+                    Some((_, row_ids)) => row_ids.push(row_id),
+                },
+            }
+        }
+
+        let mut deleted_blocks = vec![];
+        for (block_id, row_ids) in row_ids_by_block_id {
+            let editor = self.get_block_editor(&block_id).await?;
+            let mut rows: Vec<(usize, Arc<RowRevision>)> = vec![];
+            for row_id in row_ids {
+                match editor.get_row_rev(&row_id).await? {
+                    None => skipped_row_ids.push(row_id),
+                    Some(row) => {
+                        if !rows.iter().any(|(_, row_rev)| row_rev.id == row.1.id) {
+                            rows.push(row);
+                        }
+                    }
+                }
+            }
+            if rows.is_empty() {
+                continue;
+            }
+
+            let deleted_row_ids = rows
+                .iter()
+                .map(|(_, row_rev)| row_rev.id.clone())
+                .collect::<Vec<String>>();
+            let row_count = editor
+                .delete_rows(deleted_row_ids.iter().cloned().map(Cow::Owned).collect())
+                .await?;
+            self.persistence.remove_rows(&deleted_row_ids)?;
+            deleted_blocks.push(DeletedBlockRows {
+                block_id,
+                row_count,
+                rows,
+            });
+        }
+
+        let row_ids = deleted_blocks
+            .iter()
+            .flat_map(|block| block.rows.iter().map(|(_, row_rev)| row_rev.id.clone()))
+            .collect::<Vec<String>>();
+        if !row_ids.is_empty() {
+            let _ = self.event_notifier.send(DatabaseBlockEvent::DeleteRows { row_ids });
+        }
+        Ok((deleted_blocks, skipped_row_ids))
+    }
+
+    /// Copies the cells of the field to another field in the rows of the block, and notifies the
+    /// rows that are changed. The cells are copied in one revision of the block.
+    pub(crate) async fn copy_cells(&self, block_id: &str, from_field_id: &str, to_field_id: &str) -> FlowyResult<()> {
+        let editor = self.get_block_editor(block_id).await?;
+        let row_ids = editor.copy_cells(from_field_id, to_field_id).await?;
+        if row_ids.is_empty() {
+            return Ok(());
+        }
+
+        let row_ids = row_ids.into_iter().map(Cow::Owned).collect::<Vec<Cow<String>>>();
+        for row_rev in editor.get_row_revs(Some(row_ids)).await? {
+            let row = UpdatedRowPB {
+                row: make_row_from_row_rev(row_rev),
+                field_ids: vec![to_field_id.to_owned()],
+                cell_changes: vec![],
+            };
+            let _ = self.event_notifier.send(DatabaseBlockEvent::UpdateRow {
+                block_id: block_id.to_owned(),
+                row,
+            });
+        }
+        Ok(())
+    }
+
+    /// Applies the converted cells of the field to the rows of the block. The changesets are written
+    /// in batches of [CONVERT_CELLS_BATCH_SIZE] rows, one revision and one event per batch, so a
+    /// block with lots of rows doesn't produce a huge revision.
+    pub(crate) async fn update_converted_cells(
         &self,
-        block_rows: Vec<DatabaseBlockRow>,
-    ) -> FlowyResult<Vec<GridBlockMetaRevisionChangeset>> {
-        let mut changesets = vec![];
-        for block_row in block_rows {
-            let editor = self.get_block_editor(&block_row.block_id).await?;
-            let row_ids = block_row
-                .row_ids
-                .into_iter()
-                .map(Cow::Owned)
+        block_id: &str,
+        field_id: &str,
+        row_changesets: Vec<RowChangeset>,
+    ) -> FlowyResult<()> {
+        let editor = self.get_block_editor(block_id).await?;
+        for row_changesets in row_changesets.chunks(CONVERT_CELLS_BATCH_SIZE) {
+            let row_ids = row_changesets
+                .iter()
+                .map(|row_changeset| Cow::Owned(row_changeset.row_id.clone()))
                 .collect::<Vec<Cow<String>>>();
-            let row_count = editor.delete_rows(row_ids).await?;
-            let changeset = GridBlockMetaRevisionChangeset::from_row_count(block_row.block_id, row_count);
-            changesets.push(changeset);
+            editor.update_rows(row_changesets.to_vec()).await?;
+            let rows = editor
+                .get_row_revs(Some(row_ids))
+                .await?
+                .into_iter()
+                .map(|row_rev| UpdatedRowPB {
+                    row: make_row_from_row_rev(row_rev),
+                    field_ids: vec![field_id.to_owned()],
+                    cell_changes: vec![],
+                })
+                .collect::<Vec<UpdatedRowPB>>();
+            let _ = self.event_notifier.send(DatabaseBlockEvent::UpdateRows {
+                block_id: block_id.to_owned(),
+                rows,
+            });
         }
+        Ok(())
+    }
 
-        Ok(changesets)
+    /// Removes the cells of the field from the rows of the block in one revision of the block, and
+    /// notifies the rows that are changed in one event.
+    pub(crate) async fn clear_cells(&self, block_id: &str, field_id: &str) -> FlowyResult<()> {
+        let editor = self.get_block_editor(block_id).await?;
+        let row_ids = editor.clear_cells(field_id).await?;
+        if row_ids.is_empty() {
+            return Ok(());
+        }
+
+        let row_ids = row_ids.into_iter().map(Cow::Owned).collect::<Vec<Cow<String>>>();
+        let rows = editor
+            .get_row_revs(Some(row_ids))
+            .await?
+            .into_iter()
+            .map(|row_rev| UpdatedRowPB {
+                row: make_row_from_row_rev(row_rev),
+                field_ids: vec![field_id.to_owned()],
+                cell_changes: vec![],
+            })
+            .collect::<Vec<UpdatedRowPB>>();
+        let _ = self.event_notifier.send(DatabaseBlockEvent::UpdateRows {
+            block_id: block_id.to_owned(),
+            rows,
+        });
+        Ok(())
     }
+
+    /// Removes the cells and the comments of the deleted fields from the rows of the block. The
+    /// rows are not notified because the fields are gone.
+    pub(crate) async fn delete_cells(&self, block_id: &str, field_ids: &[String]) -> FlowyResult<()> {
+        let editor = self.get_block_editor(block_id).await?;
+        editor.delete_cells(field_ids).await
+    }
+
+    /// Removes the comments of the deleted fields from the rows of the block in one revision of
+    /// the block.
+    pub(crate) async fn delete_comments(&self, block_id: &str, field_ids: &[String]) -> FlowyResult<()> {
+        let editor = self.get_block_editor(block_id).await?;
+        editor.delete_comments(field_ids).await
+    }
+
+    pub(crate) async fn add_cell_comment(
+        &self,
+        row_id: &str,
+        field_id: &str,
+        comment: CellCommentRevision,
+    ) -> FlowyResult<()> {
+        let editor = self.get_editor_from_row_id(row_id).await?;
+        editor.add_cell_comment(row_id, field_id, comment).await?;
+        self.notify_did_update_cell_comments(row_id, field_id).await
+    }
+
+    pub(crate) async fn delete_cell_comment(&self, row_id: &str, field_id: &str, comment_id: &str) -> FlowyResult<()> {
+        let editor = self.get_editor_from_row_id(row_id).await?;
+        editor.delete_cell_comment(row_id, field_id, comment_id).await?;
+        self.notify_did_update_cell_comments(row_id, field_id).await
+    }
+
     // This function will be moved to GridViewRevisionEditor
     pub(crate) async fn move_row(&self, row_rev: Arc<RowRevision>, from: usize, to: usize) -> FlowyResult<()> {
         let editor = self.get_editor_from_row_id(&row_rev.id).await?;
@@ -210,6 +561,56 @@ impl DatabaseBlockManager {
         Ok(())
     }
 
+    /// Moves the row to the index of another block. Returns the number of rows of the source block
+    /// and the destination block.
+    ///
+    /// The row is inserted into the destination block and indexed to it before it's deleted from
+    /// the source block. If any step fails, the previous steps are reverted, so the row is never
+    /// missing from both blocks.
+    pub(crate) async fn move_row_to_block(
+        &self,
+        row_rev: Arc<RowRevision>,
+        to_block_id: &str,
+        to_index: usize,
+    ) -> FlowyResult<(i32, i32)> {
+        let from_editor = self.get_block_editor(&row_rev.block_id).await?;
+        let to_editor = self.get_block_editor(to_block_id).await?;
+        let mut moved_row_rev = row_rev.as_ref().clone();
+        moved_row_rev.block_id = to_block_id.to_owned();
+        let mut inserted_row = InsertedRowPB::from(&moved_row_rev);
+
+        let (to_row_count, index) = to_editor.create_rows(vec![moved_row_rev], Some(to_index)).await?;
+        inserted_row.index = Some(index);
+        if let Err(err) = self.persistence.insert(to_block_id, &row_rev.id) {
+            self.revert_moved_row(&to_editor, &row_rev.id).await;
+            return Err(err);
+        }
+        let from_row_count = match from_editor.delete_rows(vec![Cow::Borrowed(&row_rev.id)]).await {
+            Ok(row_count) => row_count,
+            Err(err) => {
+                if let Err(e) = self.persistence.insert(&row_rev.block_id, &row_rev.id) {
+                    tracing::error!("Restore the index of the row:{} failed: {:?}", row_rev.id, e);
+                }
+                self.revert_moved_row(&to_editor, &row_rev.id).await;
+                return Err(err);
+            }
+        };
+
+        let _ = self.event_notifier.send(DatabaseBlockEvent::Move {
+            block_id: to_block_id.to_owned(),
+            deleted_row_id: row_rev.id.clone(),
+            inserted_row,
+        });
+        Ok((from_row_count, to_row_count))
+    }
+
+    /// Deletes the row that was inserted into the destination block of the move.
+    async fn revert_moved_row(&self, to_editor: &DatabaseBlockRevisionEditor, row_id: &str) {
+        if let Err(e) = to_editor.delete_rows(vec![Cow::Owned(row_id.to_owned())]).await {
+            tracing::error!("Revert the moved row:{} failed: {:?}", row_id, e);
+        }
+    }
+
     // This function will be moved to GridViewRevisionEditor.
     pub async fn index_of_row(&self, row_id: &str) -> Option<usize> {
         match self.get_editor_from_row_id(row_id).await {
@@ -218,10 +619,82 @@ impl DatabaseBlockManager {
         }
     }
 
-    pub async fn update_cell(&self, changeset: CellChangesetPB) -> FlowyResult<()> {
+    /// Updates the cell. The `cell_change` carries the old and new values of the cell, which are
+    /// notified together with the row and the cell if it's not None.
+    pub async fn update_cell(&self, changeset: CellChangesetPB, cell_change: Option<CellChangePB>) -> FlowyResult<()> {
         let row_changeset: RowChangeset = changeset.clone().into();
-        self.update_row(row_changeset).await?;
-        self.notify_did_update_cell(changeset).await?;
+        let cell_changes = cell_change.iter().cloned().collect::<Vec<CellChangePB>>();
+        self.update_row_with_cell_changes(row_changeset, cell_changes).await?;
+        self.notify_did_update_cell(changeset, cell_change).await?;
+        Ok(())
+    }
+
+    /// Applies the changesets of the cells in one revision per block. The changed rows of each
+    /// block are notified in one event, which carries the ids of the changed fields of each row,
+    /// and the old and new values of the cells in the `cell_changes` of the row.
+    pub(crate) async fn update_cells(
+        &self,
+        changesets: Vec<CellChangesetPB>,
+        cell_changes: Vec<CellChangePB>,
+    ) -> FlowyResult<()> {
+        let mut row_changesets_by_block_id: HashMap<String, Vec<RowChangeset>> = HashMap::new();
+        for changeset in changesets {
+            let block_id = self.persistence.get_block_id(&changeset.row_id)?;
+            let row_changesets = row_changesets_by_block_id.entry(block_id).or_default();
+            match row_changesets
+                .iter_mut()
+                .find(|row_changeset| row_changeset.row_id == changeset.row_id)
+            {
+                None => row_changesets.push(changeset.into()),
+                Some(row_changeset) => {
+                    row_changeset
+                        .cell_by_field_id
+                        .insert(changeset.field_id, CellRevision::new(changeset.type_cell_data));
+                }
+            }
+        }
+
+        let modified_at = timestamp();
+        for (block_id, mut row_changesets) in row_changesets_by_block_id {
+            row_changesets
+                .iter_mut()
+                .for_each(|row_changeset| row_changeset.modified_at = Some(modified_at));
+            let changed_field_ids_by_row_id = row_changesets
+                .iter()
+                .map(|row_changeset| {
+                    let field_ids = row_changeset.cell_by_field_id.keys().cloned().collect::<Vec<String>>();
+                    (row_changeset.row_id.clone(), field_ids)
+                })
+                .collect::<HashMap<String, Vec<String>>>();
+
+            let editor = self.get_block_editor(&block_id).await?;
+            editor.update_rows(row_changesets).await?;
+            let row_ids = changed_field_ids_by_row_id
+                .keys()
+                .cloned()
+                .map(Cow::Owned)
+                .collect::<Vec<Cow<String>>>();
+            let rows = editor
+                .get_row_revs(Some(row_ids))
+                .await?
+                .into_iter()
+                .map(|row_rev| UpdatedRowPB {
+                    field_ids: changed_field_ids_by_row_id
+                        .get(&row_rev.id)
+                        .cloned()
+                        .unwrap_or_default(),
+                    cell_changes: cell_changes
+                        .iter()
+                        .filter(|cell_change| cell_change.row_id == row_rev.id)
+                        .cloned()
+                        .collect(),
+                    row: make_row_from_row_rev(row_rev),
+                })
+                .collect::<Vec<UpdatedRowPB>>();
+            let _ = self
+                .event_notifier
+                .send(DatabaseBlockEvent::UpdateRows { block_id, rows });
+        }
         Ok(())
     }
 
@@ -230,7 +703,6 @@ impl DatabaseBlockManager {
         editor.get_row_rev(row_id).await
     }
 
-    #[allow(dead_code)]
     pub async fn get_row_revs(&self) -> FlowyResult<Vec<Arc<RowRevision>>> {
         let mut row_revs = vec![];
         for iter in self.block_editors.iter() {
@@ -240,6 +712,14 @@ impl DatabaseBlockManager {
         Ok(row_revs)
     }
 
+    /// Returns the id of the latest revision of each block, keyed by the block id.
+    pub(crate) fn get_block_rev_ids(&self) -> HashMap<String, i64> {
+        self.block_editors
+            .iter()
+            .map(|iter| (iter.key().clone(), iter.value().rev_id()))
+            .collect()
+    }
+
     pub(crate) async fn get_blocks(
         &self,
         block_ids: Option<Vec<String>>,
@@ -265,21 +745,118 @@ impl DatabaseBlockManager {
         Ok(blocks)
     }
 
-    async fn notify_did_update_cell(&self, changeset: CellChangesetPB) -> FlowyResult<()> {
+    /// Returns the changes of the row in rev_id ascending order. The changes are read from the
+    /// revisions of the block that contains the row, so the rows that were deleted can be found
+    /// as long as their revisions exist. The pending revisions are written to disk first.
+    pub(crate) async fn get_row_changes(&self, row_id: &str) -> FlowyResult<Vec<RowRevisionChange>> {
+        self.flush().await?;
+        let block_ids = self
+            .block_editors
+            .iter()
+            .map(|editor| editor.key().clone())
+            .collect::<Vec<String>>();
+        for block_id in block_ids {
+            let revisions = self
+                .rev_stores
+                .make_store(GridRevisionObject::Block, &block_id)?
+                .read_timestamped_revisions()?;
+            let row_changes = fold_row_changes(row_id, revisions)?;
+            if !row_changes.is_empty() {
+                return Ok(row_changes);
+            }
+        }
+        Ok(vec![])
+    }
+
+    /// Returns the rows of the block after applying its revisions that were written to disk no
+    /// later than the `timestamp`, in seconds. The block can be read even if it was deleted, as long
+    /// as its revisions exist. The pending revisions are written to disk first.
+    pub(crate) async fn get_block_rows_at(&self, block_id: &str, timestamp: i64) -> FlowyResult<Vec<Arc<RowRevision>>> {
+        self.flush().await?;
+        let rev_store = self.rev_stores.make_store(GridRevisionObject::Block, block_id)?;
+        let mut operations = GridBlockOperations::new();
+        let mut is_empty = true;
+        for TimestampedRevision { revision, .. } in rev_store
+            .read_timestamped_revisions()?
+            .into_iter()
+            .filter(|revision| revision.timestamp <= timestamp)
+        {
+            let revision_operations = GridBlockOperations::from_bytes(revision.bytes)?;
+            operations = operations.compose(&revision_operations)?;
+            is_empty = false;
+        }
+        if is_empty {
+            return Ok(vec![]);
+        }
+        let block_pad = GridBlockRevisionPad::from_operations(operations)?;
+        Ok(block_pad.get_row_revs::<&str>(None)?)
+    }
+
+    async fn notify_did_update_cell(
+        &self,
+        changeset: CellChangesetPB,
+        cell_change: Option<CellChangePB>,
+    ) -> FlowyResult<()> {
         let id = format!("{}:{}", changeset.row_id, changeset.field_id);
-        send_notification(&id, DatabaseNotification::DidUpdateCell).send();
+        match cell_change {
+            None => send_notification(&id, DatabaseNotification::DidUpdateCell).send(),
+            Some(cell_change) => send_notification(&id, DatabaseNotification::DidUpdateCell)
+                .payload(cell_change)
+                .send(),
+        }
+        Ok(())
+    }
+
+    async fn notify_did_update_cell_comments(&self, row_id: &str, field_id: &str) -> FlowyResult<()> {
+        let comments = match self.get_row_rev(row_id).await? {
+            None => RepeatedCellCommentPB::default(),
+            Some((_, row_rev)) => row_rev
+                .comments
+                .get(field_id)
+                .map(|comments| RepeatedCellCommentPB::from(comments.as_slice()))
+                .unwrap_or_default(),
+        };
+        let id = format!("{}:{}", row_id, field_id);
+        send_notification(&id, DatabaseNotification::DidUpdateCellComments)
+            .payload(comments)
+            .send();
         Ok(())
     }
 }
 
+/// Applies the revisions one by one and compares the states of the row before and after applying
+/// each revision. Returns a [RowRevisionChange] for each revision that changed the row.
+fn fold_row_changes(row_id: &str, revisions: Vec<TimestampedRevision>) -> FlowyResult<Vec<RowRevisionChange>> {
+    let mut operations = GridBlockOperations::new();
+    let mut row_rev: Option<Arc<RowRevision>> = None;
+    let mut row_changes = vec![];
+    for TimestampedRevision { revision, timestamp } in revisions {
+        let rev_id = revision.rev_id;
+        let revision_operations = GridBlockOperations::from_bytes(revision.bytes)?;
+        operations = operations.compose(&revision_operations)?;
+        let block_pad = GridBlockRevisionPad::from_operations(operations.clone())?;
+        let new_row_rev = block_pad.get_row_rev(row_id).map(|(_, row_rev)| row_rev);
+        if new_row_rev != row_rev {
+            row_changes.push(RowRevisionChange {
+                rev_id,
+                timestamp,
+                before: row_rev,
+                after: new_row_rev.clone(),
+            });
+            row_rev = new_row_rev;
+        }
+    }
+    Ok(row_changes)
+}
+
 /// Initialize each block editor
 async fn make_block_editors(
-    user: &Arc<dyn DatabaseUser>,
+    rev_stores: &GridRevisionStores,
     block_meta_revs: Vec<Arc<GridBlockMetaRevision>>,
 ) -> FlowyResult<DashMap<String, Arc<DatabaseBlockRevisionEditor>>> {
     let editor_map = DashMap::new();
     for block_meta_rev in block_meta_revs {
-        let editor = make_database_block_editor(user, &block_meta_rev.block_id).await?;
+        let editor = make_database_block_editor(rev_stores, &block_meta_rev.block_id).await?;
         editor_map.insert(block_meta_rev.block_id.clone(), Arc::new(editor));
     }
 
@@ -287,31 +864,30 @@ async fn make_block_editors(
 }
 
 async fn make_database_block_editor(
-    user: &Arc<dyn DatabaseUser>,
+    rev_stores: &GridRevisionStores,
     block_id: &str,
 ) -> FlowyResult<DatabaseBlockRevisionEditor> {
     tracing::trace!("Open block:{} editor", block_id);
-    let token = user.token()?;
-    let user_id = user.user_id()?;
-    let rev_manager = make_database_block_rev_manager(user, block_id)?;
+    let token = rev_stores.user().token()?;
+    let user_id = rev_stores.user().user_id()?;
+    let rev_manager = make_database_block_rev_manager(rev_stores, block_id)?;
     DatabaseBlockRevisionEditor::new(&user_id, &token, block_id, rev_manager).await
 }
 
 pub fn make_database_block_rev_manager(
-    user: &Arc<dyn DatabaseUser>,
+    rev_stores: &GridRevisionStores,
     block_id: &str,
 ) -> FlowyResult<RevisionManager<Arc<ConnectionPool>>> {
-    let user_id = user.user_id()?;
+    let user_id = rev_stores.user().user_id()?;
+    let rev_store = rev_stores.make_store(GridRevisionObject::Block, block_id)?;
 
     // Create revision persistence
-    let pool = user.db_pool()?;
-    let disk_cache = SQLiteDatabaseBlockRevisionPersistence::new(&user_id, pool.clone());
-    let configuration = RevisionPersistenceConfiguration::new(4, false);
-    let rev_persistence = RevisionPersistence::new(&user_id, block_id, disk_cache, configuration);
+    let configuration = rev_stores.rev_compress().block_configuration();
+    let rev_persistence =
+        RevisionPersistence::from_disk_cache(&user_id, block_id, rev_store.revision_disk_cache(), configuration);
 
     // Create snapshot persistence
-    let snapshot_object_id = format!("grid_block:{}", block_id);
-    let snapshot_persistence = SQLiteDatabaseRevisionSnapshotPersistence::new(&snapshot_object_id, pool);
+    let snapshot_persistence = rev_store.snapshot_disk_cache();
 
     let rev_compress = GridBlockRevisionMergeable();
     let rev_manager = RevisionManager::new(&user_id, block_id, rev_persistence, rev_compress, snapshot_persistence);
@@ -3,7 +3,7 @@ use crate::services::cell::{AtomicCellDataCache, CellProtobufBlob, TypeCellData}
 use crate::services::field::*;
 
 use flowy_error::{ErrorCode, FlowyError, FlowyResult};
-use grid_model::{CellRevision, FieldRevision};
+use grid_model::{CellRevision, FieldRevision, RowRevision};
 
 use std::fmt::Debug;
 
@@ -44,6 +44,13 @@ pub trait CellDataChangeset: TypeOption {
         changeset: <Self as TypeOption>::CellChangeset,
         type_cell_data: Option<TypeCellData>,
     ) -> FlowyResult<(String, <Self as TypeOption>::CellData)>;
+
+    /// Returns the validation rule of the type option that the cell data violates. It's checked
+    /// before the changeset is applied to the cell. The type options without validation rules
+    /// accept any cell data.
+    fn validate_cell_data(&self, _cell_data: &<Self as TypeOption>::CellData) -> Option<CellValidationViolation> {
+        None
+    }
 }
 
 /// changeset: It will be deserialized into specific data base on the FieldType.
@@ -76,6 +83,34 @@ pub fn apply_cell_data_changeset<C: ToCellChangesetString, T: AsRef<FieldRevisio
     Ok(TypeCellData::new(cell_str, field_type).to_json())
 }
 
+/// Returns the cell of the field in the row. The cells of the [FieldType::CreatedTime] and the
+/// [FieldType::LastEditedTime] fields are not stored in the row, they are derived from the
+/// timestamps of the row. It returns None if the row doesn't record the timestamp. The cells of
+/// the [FieldType::Progress] field that is derived from a checklist are computed from the
+/// checklist cell of the row.
+pub fn get_cell_rev_from_row(row_rev: &RowRevision, field_rev: &FieldRevision) -> Option<CellRevision> {
+    let field_type: FieldType = field_rev.ty.into();
+    let timestamp = match field_type {
+        FieldType::CreatedTime => row_rev.created_at,
+        FieldType::LastEditedTime => row_rev.modified_at,
+        FieldType::Progress => {
+            let type_option = ProgressTypeOptionPB::from(field_rev);
+            if !type_option.is_derived() {
+                return row_rev.cells.get(&field_rev.id).cloned();
+            }
+            let cell_data = type_option.derive_cell_data(row_rev);
+            let type_cell_data = TypeCellData::new(cell_data.to_string(), field_type);
+            return Some(CellRevision::new(type_cell_data.to_json()));
+        }
+        _ => return row_rev.cells.get(&field_rev.id).cloned(),
+    };
+    if timestamp == 0 {
+        return None;
+    }
+    let type_cell_data = TypeCellData::new(timestamp.to_string(), field_type);
+    Some(CellRevision::new(type_cell_data.to_json()))
+}
+
 pub fn get_type_cell_protobuf<T: TryInto<TypeCellData, Error = FlowyError> + Debug>(
     data: T,
     field_rev: &FieldRevision,
@@ -127,6 +162,29 @@ where
     }
 }
 
+/// Returns the [TypedCellData] of the cell, it's decoded by the `TypeOption` of the field. The
+/// missing cell is decoded as the empty cell of the field.
+pub fn get_typed_cell_data(
+    cell_rev: Option<CellRevision>,
+    field_rev: &FieldRevision,
+    cell_data_cache: Option<AtomicCellDataCache>,
+) -> FlowyResult<TypedCellData> {
+    let to_field_type: FieldType = field_rev.ty.into();
+    let (cell_str, from_field_type) = match cell_rev.map(TypeCellData::try_from) {
+        Some(Ok(type_cell_data)) => (type_cell_data.cell_str, type_cell_data.field_type),
+        _ => ("".to_owned(), to_field_type.clone()),
+    };
+    match TypeOptionCellExt::new_with_cell_data_cache(field_rev, cell_data_cache)
+        .get_type_option_cell_data_handler(&to_field_type)
+    {
+        None => {
+            let msg = format!("Can't find the type option of the {} field", to_field_type);
+            Err(FlowyError::internal().context(msg))
+        }
+        Some(handler) => handler.get_typed_cell_data(cell_str, &from_field_type, field_rev),
+    }
+}
+
 /// Decode the opaque cell data from one field type to another using the corresponding `TypeOption`
 ///
 /// The cell data might become an empty string depends on the to_field_type's `TypeOption`   
@@ -223,6 +281,11 @@ pub fn insert_checkbox_cell(is_check: bool, field_rev: &FieldRevision) -> CellRe
     CellRevision::new(data)
 }
 
+pub fn insert_rating_cell(rating: i64, field_rev: &FieldRevision) -> CellRevision {
+    let data = apply_cell_data_changeset(rating.to_string(), None, field_rev, None).unwrap();
+    CellRevision::new(data)
+}
+
 pub fn insert_date_cell(timestamp: i64, field_rev: &FieldRevision) -> CellRevision {
     let cell_data = serde_json::to_string(&DateCellChangeset {
         date: Some(timestamp.to_string()),
@@ -240,6 +303,33 @@ pub fn insert_select_option_cell(option_ids: Vec<String>, field_rev: &FieldRevis
     CellRevision::new(data)
 }
 
+pub fn insert_checklist_cell(changeset: ChecklistCellChangeset, field_rev: &FieldRevision) -> CellRevision {
+    let data = apply_cell_data_changeset(changeset, None, field_rev, None).unwrap();
+    CellRevision::new(data)
+}
+
+pub fn insert_relation_cell(row_ids: Vec<String>, field_rev: &FieldRevision) -> CellRevision {
+    let data = apply_cell_data_changeset(
+        RelationCellChangeset::from_insert_row_ids(row_ids),
+        None,
+        field_rev,
+        None,
+    )
+    .unwrap();
+    CellRevision::new(data)
+}
+
+pub fn insert_person_cell(member_ids: Vec<String>, field_rev: &FieldRevision) -> CellRevision {
+    let data = apply_cell_data_changeset(
+        PersonCellChangeset::from_insert_member_ids(member_ids),
+        None,
+        field_rev,
+        None,
+    )
+    .unwrap();
+    CellRevision::new(data)
+}
+
 pub fn delete_select_option_cell(option_ids: Vec<String>, field_rev: &FieldRevision) -> CellRevision {
     let changeset = SelectOptionCellChangeset::from_delete_options(option_ids).to_cell_changeset_str();
     let data = apply_cell_data_changeset(changeset, None, field_rev, None).unwrap();
@@ -113,6 +113,38 @@ impl TypeCellData {
         self.field_type == FieldType::URL
     }
 
+    pub fn is_relation(&self) -> bool {
+        self.field_type == FieldType::Relation
+    }
+
+    pub fn is_formula(&self) -> bool {
+        self.field_type == FieldType::Formula
+    }
+
+    pub fn is_rating(&self) -> bool {
+        self.field_type == FieldType::Rating
+    }
+
+    pub fn is_progress(&self) -> bool {
+        self.field_type == FieldType::Progress
+    }
+
+    pub fn is_email(&self) -> bool {
+        self.field_type == FieldType::Email
+    }
+
+    pub fn is_phone_number(&self) -> bool {
+        self.field_type == FieldType::PhoneNumber
+    }
+
+    pub fn is_attachment(&self) -> bool {
+        self.field_type == FieldType::Attachment
+    }
+
+    pub fn is_person(&self) -> bool {
+        self.field_type == FieldType::Person
+    }
+
     pub fn is_select_option(&self) -> bool {
         self.field_type == FieldType::MultiSelect || self.field_type == FieldType::SingleSelect
     }
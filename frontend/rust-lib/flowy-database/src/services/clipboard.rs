@@ -0,0 +1,274 @@
+use crate::entities::{CellChangesetPB, CellUpdateErrorPB, FieldType, RangeClipboardPB};
+use crate::services::cell::TypeCellData;
+use crate::services::field::{
+    MultiSelectTypeOptionPB, SelectOptionPB, SingleSelectTypeOptionPB, TypeOptionCellDataHandler,
+    SELECTION_IDS_SEPARATOR,
+};
+use flowy_error::{FlowyError, FlowyResult};
+use grid_model::{FieldRevision, FieldTypeRevision, TypeOptionDataDeserializer};
+use serde::{Deserialize, Serialize};
+
+const TSV_COLUMN_SEPARATOR: &str = "\t";
+const TSV_ROW_SEPARATOR: &str = "\n";
+
+/// [RangeClipboard] is the lossless payload of the copied cells. Unlike the tsv, it keeps the
+/// cell data of each cell with its field type, so the cells can be pasted without being parsed
+/// from their display strings.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RangeClipboard {
+    pub fields: Vec<ClipboardField>,
+
+    /// The cells of each row in the order of the `fields`. The cell is None if it's empty.
+    pub rows: Vec<Vec<Option<TypeCellData>>>,
+}
+
+/// The field that the cells of the column were copied from.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ClipboardField {
+    pub field_id: String,
+    pub field_type: FieldType,
+
+    /// The json string of the type-option of the `field_type`. It's used to find the names of the
+    /// select options when the cells are pasted to another select field.
+    pub type_option: String,
+}
+
+impl RangeClipboard {
+    pub fn from_json_str(s: &str) -> FlowyResult<Self> {
+        let clipboard = serde_json::from_str(s)
+            .map_err(|err| FlowyError::invalid_data().context(format!("Invalid clipboard payload: {}", err)))?;
+        Ok(clipboard)
+    }
+
+    pub fn to_json_str(&self) -> FlowyResult<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Makes the clipboard from the tsv that was copied from somewhere else, each value is
+    /// treated as a text cell.
+    pub fn from_tsv(tsv: &str) -> Self {
+        let rows = rows_from_tsv(tsv)
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|value| {
+                        if value.is_empty() {
+                            None
+                        } else {
+                            Some(TypeCellData::new(value, FieldType::RichText))
+                        }
+                    })
+                    .collect::<Vec<Option<TypeCellData>>>()
+            })
+            .collect::<Vec<Vec<Option<TypeCellData>>>>();
+        let number_of_columns = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        let fields = (0..number_of_columns)
+            .map(|_| ClipboardField {
+                field_type: FieldType::RichText,
+                ..Default::default()
+            })
+            .collect();
+        Self { fields, rows }
+    }
+}
+
+impl std::convert::TryFrom<&RangeClipboardPB> for RangeClipboard {
+    type Error = FlowyError;
+
+    /// The json payload is preferred, the tsv is only used if the payload is empty.
+    fn try_from(clipboard: &RangeClipboardPB) -> Result<Self, Self::Error> {
+        if clipboard.json.is_empty() {
+            Ok(RangeClipboard::from_tsv(&clipboard.tsv))
+        } else {
+            RangeClipboard::from_json_str(&clipboard.json)
+        }
+    }
+}
+
+/// [RangePaste] is the cells that the clipboard is pasted to. The `type_cell_data` of each
+/// changeset is the converted cell data instead of the changeset of the cell.
+#[derive(Debug, Default)]
+pub struct RangePaste {
+    pub cells: Vec<CellChangesetPB>,
+
+    /// The cells of the values that go past the last row of the view, keyed by the field ids. A
+    /// new row is created for each of them if the rows are allowed to be created.
+    pub new_rows: Vec<Vec<(String, String)>>,
+
+    /// The errors of the values that can't be converted to the cell data of the target fields.
+    pub errors: Vec<CellUpdateErrorPB>,
+}
+
+/// Joins the values with tabs and the rows with newlines. The value that contains a tab, a
+/// newline or a quote is quoted, and its quotes are doubled, the same as the spreadsheets do.
+pub fn tsv_from_rows(rows: &[Vec<String>]) -> String {
+    rows.iter()
+        .map(|row| {
+            row.iter()
+                .map(|value| escape_tsv_value(value))
+                .collect::<Vec<String>>()
+                .join(TSV_COLUMN_SEPARATOR)
+        })
+        .collect::<Vec<String>>()
+        .join(TSV_ROW_SEPARATOR)
+}
+
+fn escape_tsv_value(value: &str) -> String {
+    if value.contains(|c: char| c == '\t' || c == '\n' || c == '\r' || c == '"') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+/// Splits the tsv into the rows of values. It's the reverse of the [tsv_from_rows]. The trailing
+/// newline and the `\r` of the `\r\n` are ignored.
+pub fn rows_from_tsv(tsv: &str) -> Vec<Vec<String>> {
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut value = String::new();
+    let mut in_quotes = false;
+    let mut chars = tsv.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    value.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                value.push(c);
+            }
+            continue;
+        }
+
+        match c {
+            '"' if value.is_empty() => in_quotes = true,
+            '\t' => row.push(std::mem::take(&mut value)),
+            '\n' => {
+                row.push(std::mem::take(&mut value));
+                rows.push(std::mem::take(&mut row));
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            _ => value.push(c),
+        }
+    }
+    if !value.is_empty() || !row.is_empty() {
+        row.push(value);
+        rows.push(row);
+    }
+    rows
+}
+
+/// Converts the copied cell to the cell data of the target field. The cell data is converted by
+/// the type option of the target field if their field types are different, the same as decoding
+/// the cell after switching the field type. The select options are
+/// matched by their ids first, and then by their names, because the option ids of different
+/// fields are different.
+pub fn convert_clipboard_cell(
+    cell: &TypeCellData,
+    source_field: &ClipboardField,
+    field_rev: &FieldRevision,
+    handler: &dyn TypeOptionCellDataHandler,
+) -> FlowyResult<String> {
+    let field_type: FieldType = field_rev.ty.into();
+    let mut cell_str = cell.cell_str.clone();
+    let mut decoded_field_type = cell.field_type.clone();
+    if field_type.is_select_option() && (cell.is_select_option() || cell.is_text()) {
+        let target_options = select_options(&field_type, field_rev.get_type_option_str(field_rev.ty).unwrap_or(""));
+        let names = if cell.is_text() {
+            cell_str
+                .split(SELECTION_IDS_SEPARATOR)
+                .map(|name| name.trim().to_owned())
+                .collect::<Vec<String>>()
+        } else {
+            let source_options = select_options(&cell.field_type, &source_field.type_option);
+            cell_str
+                .split(SELECTION_IDS_SEPARATOR)
+                .map(|id| match source_options.iter().find(|option| option.id == id) {
+                    None => id.to_owned(),
+                    Some(option) => option.name.clone(),
+                })
+                .collect::<Vec<String>>()
+        };
+        let option_ids = cell_str
+            .split(SELECTION_IDS_SEPARATOR)
+            .zip(names.iter())
+            .flat_map(|(id, name)| {
+                target_options
+                    .iter()
+                    .find(|option| option.id == id)
+                    .or_else(|| target_options.iter().find(|option| &option.name == name))
+            })
+            .map(|option| option.id.clone())
+            .collect::<Vec<String>>();
+        // The single select keeps the first option only.
+        let number_of_options = if field_type.is_single_select() {
+            1
+        } else {
+            option_ids.len()
+        };
+        cell_str = option_ids[..number_of_options.min(option_ids.len())].join(SELECTION_IDS_SEPARATOR);
+        decoded_field_type = field_type.clone();
+    }
+
+    if decoded_field_type != field_type {
+        // The target field is treated as if it was switched from the copied field, so the cell
+        // data is decoded with the type option of the copied field.
+        let mut field_rev = field_rev.clone();
+        let decoded_field_type_rev: FieldTypeRevision = (&decoded_field_type).into();
+        if decoded_field_type == source_field.field_type
+            && !source_field.type_option.is_empty()
+            && field_rev.get_type_option_str(decoded_field_type_rev).is_none()
+        {
+            field_rev.insert_type_option_str(&decoded_field_type_rev, source_field.type_option.clone());
+        }
+        cell_str = handler.convert_cell_str(cell_str, &decoded_field_type, &field_rev)?;
+    }
+    Ok(TypeCellData::new(cell_str, field_type).to_json())
+}
+
+fn select_options(field_type: &FieldType, type_option: &str) -> Vec<SelectOptionPB> {
+    if type_option.is_empty() {
+        return vec![];
+    }
+    match field_type {
+        FieldType::SingleSelect => SingleSelectTypeOptionPB::from_json_str(type_option).options,
+        FieldType::MultiSelect => MultiSelectTypeOptionPB::from_json_str(type_option).options,
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::clipboard::{rows_from_tsv, tsv_from_rows};
+
+    #[test]
+    fn tsv_round_trip_test() {
+        let rows = vec![
+            vec!["A".to_owned(), "".to_owned(), "1".to_owned()],
+            vec![
+                "tab\there".to_owned(),
+                "line\nbreak".to_owned(),
+                "\"quoted\"".to_owned(),
+            ],
+        ];
+        let tsv = tsv_from_rows(&rows);
+        assert_eq!(tsv, "A\t\t1\n\"tab\there\"\t\"line\nbreak\"\t\"\"\"quoted\"\"\"");
+        assert_eq!(rows_from_tsv(&tsv), rows);
+    }
+
+    #[test]
+    fn tsv_from_spreadsheet_test() {
+        assert_eq!(
+            rows_from_tsv("a\tb\r\nc\td\r\n"),
+            vec![
+                vec!["a".to_owned(), "b".to_owned()],
+                vec!["c".to_owned(), "d".to_owned()]
+            ]
+        );
+        assert!(rows_from_tsv("").is_empty());
+    }
+}
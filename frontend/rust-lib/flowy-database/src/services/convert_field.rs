@@ -0,0 +1,150 @@
+use crate::entities::ConvertFieldProgressPB;
+use crate::notification::{send_notification, DatabaseNotification};
+use crate::services::block_manager::DatabaseBlockManager;
+use crate::services::field::convert_cell_rev;
+use flowy_client_sync::client_database::DatabaseRevisionPad;
+use flowy_error::FlowyResult;
+use flowy_task::{TaskContent, TaskHandler};
+use grid_model::{FieldTypeRevision, RowChangeset};
+use lib_infra::future::BoxResultFuture;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Weak};
+use tokio::sync::RwLock;
+
+pub(crate) fn convert_field_handler_id(database_id: &str) -> String {
+    format!("{}:convert_field", database_id)
+}
+
+/// [ConvertCellsTask] converts the cells of the field in one block to the field type that the
+/// field was switched to. The cells of each block are converted by a separate task.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ConvertCellsTask {
+    pub field_id: String,
+    pub field_type: FieldTypeRevision,
+    pub block_id: String,
+    pub block_index: usize,
+    pub block_count: usize,
+}
+
+impl ToString for ConvertCellsTask {
+    fn to_string(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+impl FromStr for ConvertCellsTask {
+    type Err = serde_json::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
+/// [ConvertFieldTaskHandler] runs the [ConvertCellsTask]s that are scheduled when the field type
+/// of a field is switched. The progress is sent after each block.
+pub(crate) struct ConvertFieldTaskHandler {
+    handler_id: String,
+    database_id: String,
+    database_pad: Weak<RwLock<DatabaseRevisionPad>>,
+    block_manager: Weak<DatabaseBlockManager>,
+    converting_block_count: Arc<AtomicUsize>,
+}
+
+impl ConvertFieldTaskHandler {
+    pub(crate) fn new(
+        database_id: &str,
+        database_pad: Weak<RwLock<DatabaseRevisionPad>>,
+        block_manager: Weak<DatabaseBlockManager>,
+        converting_block_count: Arc<AtomicUsize>,
+    ) -> Self {
+        Self {
+            handler_id: convert_field_handler_id(database_id),
+            database_id: database_id.to_owned(),
+            database_pad,
+            block_manager,
+            converting_block_count,
+        }
+    }
+}
+
+impl TaskHandler for ConvertFieldTaskHandler {
+    fn handler_id(&self) -> &str {
+        &self.handler_id
+    }
+
+    fn handler_name(&self) -> &str {
+        "ConvertFieldTaskHandler"
+    }
+
+    fn run(&self, content: TaskContent) -> BoxResultFuture<(), anyhow::Error> {
+        let database_id = self.database_id.clone();
+        let database_pad = self.database_pad.upgrade();
+        let block_manager = self.block_manager.upgrade();
+        let converting_block_count = self.converting_block_count.clone();
+        Box::pin(async move {
+            let task = match content {
+                TaskContent::Text(s) => ConvertCellsTask::from_str(&s),
+                _ => return Ok(()),
+            };
+            let result = match (task, database_pad, block_manager) {
+                (Ok(task), Some(database_pad), Some(block_manager)) => {
+                    convert_cells(&database_id, &database_pad, &block_manager, task).await
+                }
+                // The editor was closed before the task runs.
+                _ => Ok(()),
+            };
+            converting_block_count.fetch_sub(1, Ordering::SeqCst);
+            result.map_err(anyhow::Error::from)
+        })
+    }
+}
+
+async fn convert_cells(
+    database_id: &str,
+    database_pad: &RwLock<DatabaseRevisionPad>,
+    block_manager: &DatabaseBlockManager,
+    task: ConvertCellsTask,
+) -> FlowyResult<()> {
+    let field_rev = database_pad
+        .read()
+        .await
+        .get_field_rev(&task.field_id)
+        .map(|(_, field_rev)| field_rev.clone());
+    // The field was deleted or switched to another field type before its cells are converted. The
+    // task that is scheduled by the latest switch converts the cells instead.
+    let field_rev = match field_rev {
+        Some(field_rev) if field_rev.ty == task.field_type => field_rev,
+        _ => return Ok(()),
+    };
+
+    let editor = block_manager.get_block_editor(&task.block_id).await?;
+    let row_changesets = editor
+        .get_row_revs::<&str>(None)
+        .await?
+        .iter()
+        .filter_map(|row_rev| {
+            let cell_rev = row_rev.cells.get(&task.field_id)?;
+            let converted_cell_rev = convert_cell_rev(cell_rev, &field_rev)?;
+            let mut row_changeset = RowChangeset::new(row_rev.id.clone());
+            row_changeset
+                .cell_by_field_id
+                .insert(task.field_id.clone(), converted_cell_rev);
+            Some(row_changeset)
+        })
+        .collect::<Vec<RowChangeset>>();
+    if !row_changesets.is_empty() {
+        block_manager
+            .update_converted_cells(&task.block_id, &task.field_id, row_changesets)
+            .await?;
+    }
+
+    send_notification(database_id, DatabaseNotification::DidUpdateConvertFieldProgress)
+        .payload(ConvertFieldProgressPB {
+            field_id: task.field_id.clone(),
+            converted_block_count: (task.block_index + 1) as i32,
+            block_count: task.block_count as i32,
+        })
+        .send();
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use crate::entities::{DatabaseFieldChangesetPB, DuplicateFieldProgressPB, FieldPB};
+use crate::notification::{send_notification, DatabaseNotification};
+use crate::services::block_manager::DatabaseBlockManager;
+use flowy_client_sync::client_database::DatabaseRevisionPad;
+use flowy_error::FlowyResult;
+use flowy_task::{TaskContent, TaskHandler};
+use lib_infra::future::BoxResultFuture;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Weak};
+use tokio::sync::RwLock;
+
+pub(crate) fn duplicate_field_handler_id(database_id: &str) -> String {
+    format!("{}:duplicate_field", database_id)
+}
+
+/// [CopyCellsTask] copies the cells of the field to the duplicated field in one block. The cells
+/// of each block are copied by a separate task, so the database stays usable while a field with
+/// lots of rows is duplicated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CopyCellsTask {
+    pub field_id: String,
+    pub duplicated_field_id: String,
+    pub block_id: String,
+    pub block_index: usize,
+    pub block_count: usize,
+}
+
+impl ToString for CopyCellsTask {
+    fn to_string(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+impl FromStr for CopyCellsTask {
+    type Err = serde_json::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
+/// [DuplicateFieldTaskHandler] runs the [CopyCellsTask]s that are scheduled when a field is
+/// duplicated. The progress is sent after each block, and the duplicated field is notified as
+/// updated once the cells of the last block are copied.
+pub(crate) struct DuplicateFieldTaskHandler {
+    handler_id: String,
+    database_id: String,
+    database_pad: Weak<RwLock<DatabaseRevisionPad>>,
+    block_manager: Weak<DatabaseBlockManager>,
+    copying_block_count: Arc<AtomicUsize>,
+}
+
+impl DuplicateFieldTaskHandler {
+    pub(crate) fn new(
+        database_id: &str,
+        database_pad: Weak<RwLock<DatabaseRevisionPad>>,
+        block_manager: Weak<DatabaseBlockManager>,
+        copying_block_count: Arc<AtomicUsize>,
+    ) -> Self {
+        Self {
+            handler_id: duplicate_field_handler_id(database_id),
+            database_id: database_id.to_owned(),
+            database_pad,
+            block_manager,
+            copying_block_count,
+        }
+    }
+}
+
+impl TaskHandler for DuplicateFieldTaskHandler {
+    fn handler_id(&self) -> &str {
+        &self.handler_id
+    }
+
+    fn handler_name(&self) -> &str {
+        "DuplicateFieldTaskHandler"
+    }
+
+    fn run(&self, content: TaskContent) -> BoxResultFuture<(), anyhow::Error> {
+        let database_id = self.database_id.clone();
+        let database_pad = self.database_pad.upgrade();
+        let block_manager = self.block_manager.upgrade();
+        let copying_block_count = self.copying_block_count.clone();
+        Box::pin(async move {
+            let task = match content {
+                TaskContent::Text(s) => CopyCellsTask::from_str(&s),
+                _ => return Ok(()),
+            };
+            let result = match (task, database_pad, block_manager) {
+                (Ok(task), Some(database_pad), Some(block_manager)) => {
+                    copy_cells(&database_id, &database_pad, &block_manager, task).await
+                }
+                // The editor was closed before the task runs.
+                _ => Ok(()),
+            };
+            copying_block_count.fetch_sub(1, Ordering::SeqCst);
+            result.map_err(anyhow::Error::from)
+        })
+    }
+}
+
+async fn copy_cells(
+    database_id: &str,
+    database_pad: &RwLock<DatabaseRevisionPad>,
+    block_manager: &DatabaseBlockManager,
+    task: CopyCellsTask,
+) -> FlowyResult<()> {
+    // The duplicated field was deleted before its cells are copied.
+    if !database_pad.read().await.contain_field(&task.duplicated_field_id) {
+        return Ok(());
+    }
+
+    block_manager
+        .copy_cells(&task.block_id, &task.field_id, &task.duplicated_field_id)
+        .await?;
+
+    let progress = DuplicateFieldProgressPB {
+        field_id: task.field_id.clone(),
+        duplicated_field_id: task.duplicated_field_id.clone(),
+        copied_block_count: (task.block_index + 1) as i32,
+        block_count: task.block_count as i32,
+    };
+    let is_finished = progress.is_finished();
+    send_notification(database_id, DatabaseNotification::DidUpdateDuplicateFieldProgress)
+        .payload(progress)
+        .send();
+
+    if is_finished {
+        let field_rev = database_pad
+            .read()
+            .await
+            .get_field_rev(&task.duplicated_field_id)
+            .map(|(_, field_rev)| field_rev.clone());
+        if let Some(field_rev) = field_rev {
+            let updated_field = FieldPB::from(field_rev);
+            let notified_changeset = DatabaseFieldChangesetPB::update(database_id, vec![updated_field.clone()]);
+            send_notification(database_id, DatabaseNotification::DidUpdateDatabaseFields)
+                .payload(notified_changeset)
+                .send();
+            send_notification(&task.duplicated_field_id, DatabaseNotification::DidUpdateField)
+                .payload(updated_field)
+                .send();
+        }
+    }
+    Ok(())
+}
@@ -40,6 +40,9 @@ impl FieldBuilder {
             width: field.width,
             type_options: IndexMap::default(),
             is_primary: field.is_primary,
+            description: field.description,
+            icon: field.icon,
+            locked: field.locked,
         };
         Self {
             field_rev,
@@ -57,6 +60,16 @@ impl FieldBuilder {
         self
     }
 
+    pub fn description(mut self, description: &str) -> Self {
+        self.field_rev.description = description.to_owned();
+        self
+    }
+
+    pub fn icon(mut self, icon: &str) -> Self {
+        self.field_rev.icon = icon.to_owned();
+        self
+    }
+
     pub fn primary(mut self, is_primary: bool) -> Self {
         self.field_rev.is_primary = is_primary;
         self
@@ -0,0 +1,221 @@
+use crate::entities::FieldType;
+use crate::services::cell::{stringify_cell_data, TypeCellData};
+use crate::services::field::{select_type_option_from_field_rev, CHECK, SELECTION_IDS_SEPARATOR, UNCHECK};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use grid_model::{CellRevision, FieldRevision};
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+const DATE_TIME_FORMATS: [&str; 5] = [
+    "%Y-%m-%d %H:%M",
+    "%Y/%m/%d %H:%M",
+    "%Y-%m-%dT%H:%M:%S",
+    "%m/%d/%Y %H:%M",
+    "%b %d,%Y %H:%M",
+];
+const DATE_FORMATS: [&str; 6] = ["%Y-%m-%d", "%Y/%m/%d", "%m/%d/%Y", "%b %d,%Y", "%b %d, %Y", "%d %b %Y"];
+
+/// Returns true if the existing cells are converted when the field is switched to the field type.
+/// The cells of the other field types are transformed when they are read.
+pub fn support_cell_conversion(field_type: &FieldType) -> bool {
+    matches!(
+        field_type,
+        FieldType::RichText
+            | FieldType::Number
+            | FieldType::Checkbox
+            | FieldType::DateTime
+            | FieldType::SingleSelect
+            | FieldType::MultiSelect
+    )
+}
+
+/// Converts the cell to the field type of the field. The text of the cell is interpreted as the
+/// value of the new field type, for example, `yes` is a checked checkbox and `a,b` are the names
+/// of two options.
+///
+/// The cell that can't be converted becomes empty, and its data is kept in the shadow of the cell.
+/// The shadow is restored instead when the field is switched back to the field type of the shadow.
+///
+/// Returns None if the cell doesn't need to be converted.
+pub fn convert_cell_rev(cell_rev: &CellRevision, field_rev: &FieldRevision) -> Option<CellRevision> {
+    let to_field_type: FieldType = field_rev.ty.into();
+    if !support_cell_conversion(&to_field_type) {
+        return None;
+    }
+    let type_cell_data = TypeCellData::try_from(cell_rev).ok()?;
+    if type_cell_data.field_type == to_field_type {
+        return None;
+    }
+    if let Some(shadow) = restorable_shadow(cell_rev, &to_field_type) {
+        return Some(CellRevision::new(shadow));
+    }
+
+    let converted_cell_str = convert_cell_text(&cell_text(type_cell_data, field_rev), &to_field_type, field_rev);
+    let shadow = match converted_cell_str {
+        Some(_) => cell_rev.shadow.clone(),
+        None => Some(cell_rev.type_cell_data.clone()),
+    };
+    let type_cell_data = TypeCellData::new(converted_cell_str.unwrap_or_default(), to_field_type);
+    Some(CellRevision {
+        type_cell_data: type_cell_data.to_json(),
+        shadow,
+    })
+}
+
+/// Returns the names of the options that the cell refers to once it's converted to the select
+/// option field. The options that don't exist are created before the cells are converted.
+pub fn option_names_of_cell(cell_rev: &CellRevision, field_rev: &FieldRevision) -> Vec<String> {
+    let to_field_type: FieldType = field_rev.ty.into();
+    if !to_field_type.is_select_option() {
+        return vec![];
+    }
+    let type_cell_data = match TypeCellData::try_from(cell_rev) {
+        Ok(type_cell_data) => type_cell_data,
+        Err(_) => return vec![],
+    };
+    if type_cell_data.field_type == to_field_type || restorable_shadow(cell_rev, &to_field_type).is_some() {
+        return vec![];
+    }
+
+    let names = split_option_names(&cell_text(type_cell_data, field_rev));
+    // The single select cell can't refer to more than one option.
+    if to_field_type.is_single_select() && names.len() > 1 {
+        return vec![];
+    }
+    names
+}
+
+fn restorable_shadow(cell_rev: &CellRevision, field_type: &FieldType) -> Option<String> {
+    let shadow = cell_rev.shadow.as_ref()?;
+    let type_cell_data = TypeCellData::from_json_str(shadow).ok()?;
+    if &type_cell_data.field_type == field_type {
+        Some(shadow.clone())
+    } else {
+        None
+    }
+}
+
+/// Returns the text of the cell, it's decoded by the type option of the field type of the cell.
+fn cell_text(type_cell_data: TypeCellData, field_rev: &FieldRevision) -> String {
+    let TypeCellData { cell_str, field_type } = type_cell_data;
+    stringify_cell_data(cell_str, &field_type, &field_type, field_rev)
+}
+
+/// Returns the cell string of the field type that the text is interpreted as, None if the text
+/// can't be interpreted.
+fn convert_cell_text(text: &str, field_type: &FieldType, field_rev: &FieldRevision) -> Option<String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Some("".to_owned());
+    }
+
+    match field_type {
+        FieldType::RichText => Some(text.to_owned()),
+        FieldType::Number => parse_number(text).map(|decimal| decimal.to_string()),
+        FieldType::Checkbox => match text.to_lowercase().as_str() {
+            "yes" | "true" | "1" => Some(CHECK.to_owned()),
+            "no" | "false" | "0" => Some(UNCHECK.to_owned()),
+            _ => None,
+        },
+        FieldType::DateTime => parse_timestamp(text).map(|timestamp| timestamp.to_string()),
+        FieldType::SingleSelect | FieldType::MultiSelect => {
+            let names = split_option_names(text);
+            if field_type.is_single_select() && names.len() > 1 {
+                return None;
+            }
+            let type_option = select_type_option_from_field_rev(field_rev).ok()?;
+            let option_ids = names
+                .iter()
+                .map(|name| {
+                    type_option
+                        .options()
+                        .iter()
+                        .find(|option| &option.name == name)
+                        .map(|option| option.id.clone())
+                })
+                .collect::<Option<Vec<String>>>()?;
+            Some(option_ids.join(SELECTION_IDS_SEPARATOR))
+        }
+        _ => None,
+    }
+}
+
+fn split_option_names(text: &str) -> Vec<String> {
+    let mut names: Vec<String> = vec![];
+    for name in text.split(SELECTION_IDS_SEPARATOR).map(|name| name.trim()) {
+        if !name.is_empty() && !names.iter().any(|existing| existing == name) {
+            names.push(name.to_owned());
+        }
+    }
+    names
+}
+
+/// Extracts the first number of the text, the thousands separators are ignored. For example,
+/// `$1,234.5 in total` is `1234.5`.
+fn parse_number(text: &str) -> Option<Decimal> {
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+    let is_negative = text[..start].ends_with('-');
+    let number = text[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '.' || *c == ',')
+        .filter(|c| *c != ',')
+        .collect::<String>();
+    let decimal = Decimal::from_str(number.trim_end_matches('.')).ok()?;
+    Some(if is_negative { -decimal } else { decimal })
+}
+
+/// Parses the text as a timestamp in seconds, a RFC3339 date time, or one of the common date
+/// formats. The dates without the time are at midnight in UTC.
+fn parse_timestamp(text: &str) -> Option<i64> {
+    if let Ok(timestamp) = text.parse::<i64>() {
+        return Some(timestamp);
+    }
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(text) {
+        return Some(date_time.timestamp());
+    }
+    if let Some(date_time) = DATE_TIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+    {
+        return Some(date_time.timestamp());
+    }
+    DATE_FORMATS
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(text, format).ok())
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date_time| date_time.timestamp())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::field::field_cell_conversion::{parse_number, parse_timestamp, split_option_names};
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    #[test]
+    fn parse_number_from_text_test() {
+        assert_eq!(parse_number("12"), Some(Decimal::from(12)));
+        assert_eq!(parse_number("$1,234.5 in total"), Decimal::from_str("1234.5").ok());
+        assert_eq!(parse_number("-3 degrees"), Some(Decimal::from(-3)));
+        assert_eq!(parse_number("version 2."), Some(Decimal::from(2)));
+        assert_eq!(parse_number("1.2.3"), None);
+        assert_eq!(parse_number("hello"), None);
+    }
+
+    #[test]
+    fn parse_timestamp_from_text_test() {
+        assert_eq!(parse_timestamp("1647251762"), Some(1647251762));
+        assert_eq!(parse_timestamp("2022/03/14"), Some(1647216000));
+        assert_eq!(parse_timestamp("2022-03-14"), Some(1647216000));
+        assert_eq!(parse_timestamp("Mar 14,2022"), Some(1647216000));
+        assert_eq!(parse_timestamp("2022-03-14 09:30"), Some(1647250200));
+        assert_eq!(parse_timestamp("2022-03-14T09:30:00+00:00"), Some(1647250200));
+        assert_eq!(parse_timestamp("tomorrow"), None);
+    }
+
+    #[test]
+    fn split_option_names_test() {
+        assert_eq!(split_option_names("tag1, tag2,,tag1 "), vec!["tag1", "tag2"]);
+        assert!(split_option_names(" , ").is_empty());
+    }
+}
@@ -0,0 +1,149 @@
+use crate::entities::FieldType;
+use crate::services::cell::{apply_cell_data_changeset, ToCellChangesetString, TypeCellData};
+use crate::services::field::{
+    CheckboxTypeOptionPB, DateCellChangeset, DateTypeOptionPB, MultiSelectTypeOptionPB, NumberTypeOptionPB,
+    RichTextTypeOptionPB, SelectOptionCellChangeset, SingleSelectTypeOptionPB, SELECTION_IDS_SEPARATOR,
+};
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use grid_model::{CellRevision, FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use lib_infra::util::timestamp;
+
+/// The default value of the date field that is replaced with the date of the day when the row
+/// is created.
+pub const TODAY_DEFAULT_VALUE: &str = "today";
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+pub fn support_default_value(field_type: &FieldType) -> bool {
+    matches!(
+        field_type,
+        FieldType::RichText
+            | FieldType::Number
+            | FieldType::Checkbox
+            | FieldType::DateTime
+            | FieldType::SingleSelect
+            | FieldType::MultiSelect
+    )
+}
+
+/// Returns the default value that is stored in the type-option of the field, None if the field
+/// doesn't have one.
+pub fn get_field_default_value(field_rev: &FieldRevision) -> Option<String> {
+    let field_type: FieldType = field_rev.ty.into();
+    match field_type {
+        FieldType::RichText => {
+            field_rev
+                .get_type_option::<RichTextTypeOptionPB>(field_rev.ty)?
+                .default_value
+        }
+        FieldType::Number => {
+            field_rev
+                .get_type_option::<NumberTypeOptionPB>(field_rev.ty)?
+                .default_value
+        }
+        FieldType::Checkbox => {
+            field_rev
+                .get_type_option::<CheckboxTypeOptionPB>(field_rev.ty)?
+                .default_value
+        }
+        FieldType::DateTime => {
+            field_rev
+                .get_type_option::<DateTypeOptionPB>(field_rev.ty)?
+                .default_value
+        }
+        FieldType::SingleSelect => {
+            field_rev
+                .get_type_option::<SingleSelectTypeOptionPB>(field_rev.ty)?
+                .default_value
+        }
+        FieldType::MultiSelect => {
+            field_rev
+                .get_type_option::<MultiSelectTypeOptionPB>(field_rev.ty)?
+                .default_value
+        }
+        _ => None,
+    }
+}
+
+/// Stores the default value in the type-option of the field, or removes it if the `default_value`
+/// is None. Returns an error if the field type doesn't support the default value, or the value
+/// can't be converted to a cell of the field.
+pub fn set_field_default_value(field_rev: &mut FieldRevision, default_value: Option<String>) -> FlowyResult<()> {
+    let field_type: FieldType = field_rev.ty.into();
+    if !support_default_value(&field_type) {
+        let msg = format!("The {:?} field can't have a default value", field_type);
+        return Err(FlowyError::new(ErrorCode::FieldInvalidOperation, &msg));
+    }
+
+    let value = default_value.clone();
+    match field_type {
+        FieldType::RichText => update_type_option::<RichTextTypeOptionPB>(field_rev, |t| t.default_value = value),
+        FieldType::Number => update_type_option::<NumberTypeOptionPB>(field_rev, |t| t.default_value = value),
+        FieldType::Checkbox => update_type_option::<CheckboxTypeOptionPB>(field_rev, |t| t.default_value = value),
+        FieldType::DateTime => update_type_option::<DateTypeOptionPB>(field_rev, |t| t.default_value = value),
+        FieldType::SingleSelect => {
+            update_type_option::<SingleSelectTypeOptionPB>(field_rev, |t| t.default_value = value)
+        }
+        FieldType::MultiSelect => update_type_option::<MultiSelectTypeOptionPB>(field_rev, |t| t.default_value = value),
+        _ => {}
+    }
+
+    // The value is rejected if it's converted to an empty cell, for example, the id of the option
+    // that doesn't exist.
+    if let Some(default_value) = default_value {
+        let is_empty = make_default_cell_rev(field_rev)
+            .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+            .map(|type_cell_data| type_cell_data.cell_str.is_empty())
+            .unwrap_or(true);
+        if is_empty {
+            return Err(FlowyError::invalid_params().context(format!(
+                "Invalid default value of the {:?} field: {}",
+                field_type, default_value
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Returns the cell that is filled with the default value of the field when a row is created,
+/// None if the field doesn't have a default value.
+pub fn make_default_cell_rev(field_rev: &FieldRevision) -> Option<CellRevision> {
+    let default_value = get_field_default_value(field_rev)?;
+    let field_type: FieldType = field_rev.ty.into();
+    let changeset = match field_type {
+        FieldType::DateTime => {
+            let date_timestamp = if default_value == TODAY_DEFAULT_VALUE {
+                let now = timestamp();
+                now - now.rem_euclid(SECONDS_PER_DAY)
+            } else {
+                default_value.trim().parse::<i64>().ok()?
+            };
+            serde_json::to_string(&DateCellChangeset {
+                date: Some(date_timestamp.to_string()),
+                time: None,
+                is_utc: true,
+            })
+            .ok()?
+        }
+        FieldType::SingleSelect | FieldType::MultiSelect => {
+            let option_ids = default_value
+                .split(SELECTION_IDS_SEPARATOR)
+                .map(|option_id| option_id.trim().to_owned())
+                .filter(|option_id| !option_id.is_empty())
+                .collect::<Vec<String>>();
+            SelectOptionCellChangeset::from_insert_options(option_ids).to_cell_changeset_str()
+        }
+        _ => default_value,
+    };
+    let type_cell_data = apply_cell_data_changeset(changeset, None, field_rev, None).ok()?;
+    Some(CellRevision::new(type_cell_data))
+}
+
+fn update_type_option<T>(field_rev: &mut FieldRevision, f: impl FnOnce(&mut T))
+where
+    T: Default + TypeOptionDataDeserializer + TypeOptionDataSerializer,
+{
+    let mut type_option = field_rev.get_type_option::<T>(field_rev.ty).unwrap_or_default();
+    f(&mut type_option);
+    field_rev.insert_type_option(&type_option);
+}
@@ -0,0 +1,96 @@
+use crate::entities::{FieldChangePB, FieldPB};
+use crate::services::persistence::rev_store::TimestampedRevision;
+use flowy_client_sync::client_database::{DatabaseOperations, DatabaseRevisionPad};
+use flowy_error::FlowyResult;
+use grid_model::FieldRevision;
+use lib_ot::core::OperationTransform;
+use std::sync::Arc;
+
+/// [FieldRevisionChange] is the state of the field before and after a revision of the database.
+/// The state is None if the field doesn't exist.
+pub(crate) struct FieldRevisionChange {
+    pub rev_id: i64,
+    pub timestamp: i64,
+    pub before: Option<Arc<FieldRevision>>,
+    pub after: Option<Arc<FieldRevision>>,
+}
+
+/// Applies the revisions one by one and compares the states of the field before and after applying
+/// each revision. Returns a [FieldRevisionChange] for each revision that changed the field.
+pub(crate) fn fold_field_changes(
+    field_id: &str,
+    revisions: Vec<TimestampedRevision>,
+) -> FlowyResult<Vec<FieldRevisionChange>> {
+    let mut operations = DatabaseOperations::new();
+    let mut field_rev: Option<Arc<FieldRevision>> = None;
+    let mut field_changes = vec![];
+    for TimestampedRevision { revision, timestamp } in revisions {
+        let rev_id = revision.rev_id;
+        let revision_operations = DatabaseOperations::from_bytes(revision.bytes)?;
+        operations = operations.compose(&revision_operations)?;
+        let database_pad = DatabaseRevisionPad::from_operations(operations.clone())?;
+        let new_field_rev = database_pad
+            .get_field_rev(field_id)
+            .map(|(_, field_rev)| field_rev.clone());
+        if new_field_rev != field_rev {
+            field_changes.push(FieldRevisionChange {
+                rev_id,
+                timestamp,
+                before: field_rev,
+                after: new_field_rev.clone(),
+            });
+            field_rev = new_field_rev;
+        }
+    }
+    Ok(field_changes)
+}
+
+/// Makes a [FieldChangePB] from the [FieldRevisionChange]. The field of the [FieldChangePB] is the
+/// state of the field before it was deleted if the revision deleted the field.
+pub(crate) fn make_field_change_pb(field_change: &FieldRevisionChange) -> FieldChangePB {
+    let field_rev = field_change
+        .after
+        .as_ref()
+        .or(field_change.before.as_ref())
+        .cloned()
+        .unwrap_or_default();
+    let changed_properties = match (&field_change.before, &field_change.after) {
+        (Some(before), Some(after)) => changed_field_properties(before, after),
+        _ => vec![],
+    };
+    let type_option = field_rev
+        .get_type_option_str(field_rev.ty)
+        .unwrap_or_default()
+        .to_owned();
+
+    FieldChangePB {
+        rev_id: field_change.rev_id,
+        timestamp: field_change.timestamp,
+        field: FieldPB::from(field_rev.as_ref().clone()),
+        type_option,
+        changed_properties,
+        is_created: field_change.before.is_none(),
+        is_deleted: field_change.after.is_none(),
+    }
+}
+
+pub(crate) fn changed_field_properties(before: &FieldRevision, after: &FieldRevision) -> Vec<String> {
+    let mut changed_properties = vec![];
+    let mut check = |name: &str, is_changed: bool| {
+        if is_changed {
+            changed_properties.push(name.to_owned());
+        }
+    };
+    check("name", before.name != after.name);
+    check("desc", before.desc != after.desc);
+    check("field_type", before.ty != after.ty);
+    check("frozen", before.frozen != after.frozen);
+    check("visibility", before.visibility != after.visibility);
+    check("width", before.width != after.width);
+    check("type_options", before.type_options != after.type_options);
+    check("is_primary", before.is_primary != after.is_primary);
+    check("description", before.description != after.description);
+    check("icon", before.icon != after.icon);
+    check("locked", before.locked != after.locked);
+    changed_properties
+}
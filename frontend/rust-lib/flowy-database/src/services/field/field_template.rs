@@ -0,0 +1,116 @@
+use crate::entities::FieldType;
+use crate::services::field::{
+    gen_option_id, ChecklistTypeOptionPB, MultiSelectTypeOptionPB, SelectOptionPB, SingleSelectTypeOptionPB,
+};
+use crate::services::persistence::kv::{DatabaseKVPersistence, KVTransaction, KeyValue};
+use flowy_error::{FlowyError, FlowyResult};
+use grid_model::{FieldRevision, FieldTypeRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+const FIELD_TEMPLATES_KEY: &str = "field_templates";
+
+/// [FieldTemplate] is the saved configuration of a field, including its field type and its
+/// type-option. The select options are part of the type-option of the select fields.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldTemplate {
+    pub name: String,
+    pub field_name: String,
+    pub field_type: FieldTypeRevision,
+    /// The json string of the type-option of the `field_type`.
+    pub type_option: String,
+}
+
+impl FieldTemplate {
+    pub fn from_field_rev(name: &str, field_rev: &FieldRevision) -> Self {
+        Self {
+            name: name.to_owned(),
+            field_name: field_rev.name.clone(),
+            field_type: field_rev.ty,
+            type_option: field_rev
+                .get_type_option_str(field_rev.ty)
+                .unwrap_or_default()
+                .to_owned(),
+        }
+    }
+
+    /// Returns the json string of the type-option whose select options are assigned new ids, so
+    /// the fields that are created from the same template don't share the option ids.
+    pub fn type_option_with_fresh_option_ids(&self) -> String {
+        let field_type: FieldType = self.field_type.into();
+        match field_type {
+            FieldType::SingleSelect => {
+                let mut type_option = SingleSelectTypeOptionPB::from_json_str(&self.type_option);
+                refresh_option_ids(&mut type_option.options);
+                type_option.json_str()
+            }
+            FieldType::MultiSelect => {
+                let mut type_option = MultiSelectTypeOptionPB::from_json_str(&self.type_option);
+                refresh_option_ids(&mut type_option.options);
+                type_option.json_str()
+            }
+            FieldType::Checklist => {
+                let mut type_option = ChecklistTypeOptionPB::from_json_str(&self.type_option);
+                refresh_option_ids(&mut type_option.options);
+                type_option.json_str()
+            }
+            _ => self.type_option.clone(),
+        }
+    }
+}
+
+fn refresh_option_ids(options: &mut [SelectOptionPB]) {
+    for option in options.iter_mut() {
+        option.id = gen_option_id();
+    }
+}
+
+/// [FieldTemplateStore] persists the [FieldTemplate]s of the user. The templates are shared by
+/// all the databases, and they are identified by their names.
+pub struct FieldTemplateStore {
+    kv_persistence: Arc<DatabaseKVPersistence>,
+}
+
+impl FieldTemplateStore {
+    pub fn new(kv_persistence: Arc<DatabaseKVPersistence>) -> Self {
+        Self { kv_persistence }
+    }
+
+    /// Returns the templates in the order they were first saved.
+    pub fn get_templates(&self) -> FlowyResult<Vec<FieldTemplate>> {
+        match self.kv_persistence.get_bytes(FIELD_TEMPLATES_KEY)? {
+            None => Ok(vec![]),
+            Some(bytes) => Ok(serde_json::from_slice(&bytes)?),
+        }
+    }
+
+    pub fn get_template(&self, name: &str) -> FlowyResult<Option<FieldTemplate>> {
+        let template = self.get_templates()?.into_iter().find(|template| template.name == name);
+        Ok(template)
+    }
+
+    /// Saves the templates. The existing template with the same name is replaced.
+    pub fn save_templates(&self, templates: Vec<FieldTemplate>) -> FlowyResult<()> {
+        let mut saved_templates = self.get_templates()?;
+        for template in templates {
+            if template.name.is_empty() {
+                return Err(FlowyError::invalid_data().context("The name of the field template is empty"));
+            }
+            match saved_templates.iter_mut().find(|saved| saved.name == template.name) {
+                None => saved_templates.push(template),
+                Some(saved_template) => *saved_template = template,
+            }
+        }
+        let bytes = serde_json::to_vec(&saved_templates)?;
+        self.kv_persistence
+            .set(KeyValue::new(FIELD_TEMPLATES_KEY.to_owned(), bytes))
+    }
+
+    pub fn delete_template(&self, name: &str) -> FlowyResult<()> {
+        let mut templates = self.get_templates()?;
+        templates.retain(|template| template.name != name);
+        let bytes = serde_json::to_vec(&templates)?;
+        self.kv_persistence
+            .set(KeyValue::new(FIELD_TEMPLATES_KEY.to_owned(), bytes))
+    }
+}
@@ -1,9 +1,17 @@
 mod field_builder;
+mod field_cell_conversion;
+mod field_default_value;
+mod field_history;
 mod field_operation;
+mod field_template;
 mod type_option_builder;
 pub(crate) mod type_options;
 
 pub use field_builder::*;
+pub use field_cell_conversion::*;
+pub use field_default_value::*;
+pub(crate) use field_history::*;
 pub use field_operation::*;
+pub use field_template::*;
 pub use type_option_builder::*;
 pub use type_options::*;
@@ -21,6 +21,15 @@ pub fn default_type_option_builder_from_type(field_type: &FieldType) -> Box<dyn
         FieldType::Checkbox => CheckboxTypeOptionPB::default().into(),
         FieldType::URL => URLTypeOptionPB::default().into(),
         FieldType::Checklist => ChecklistTypeOptionPB::default().into(),
+        FieldType::CreatedTime | FieldType::LastEditedTime => TimestampTypeOptionPB::default().into(),
+        FieldType::Relation => RelationTypeOptionPB::default().into(),
+        FieldType::Formula => FormulaTypeOptionPB::default().into(),
+        FieldType::Rating => RatingTypeOptionPB::default().into(),
+        FieldType::Progress => ProgressTypeOptionPB::default().into(),
+        FieldType::Email => EmailTypeOptionPB::default().into(),
+        FieldType::PhoneNumber => PhoneNumberTypeOptionPB::default().into(),
+        FieldType::Attachment => AttachmentTypeOptionPB::default().into(),
+        FieldType::Person => PersonTypeOptionPB::default().into(),
     };
 
     type_option_builder_from_json_str(&s, field_type)
@@ -36,6 +45,17 @@ pub fn type_option_builder_from_json_str(s: &str, field_type: &FieldType) -> Box
         FieldType::Checkbox => Box::new(CheckboxTypeOptionBuilder::from_json_str(s)),
         FieldType::URL => Box::new(URLTypeOptionBuilder::from_json_str(s)),
         FieldType::Checklist => Box::new(ChecklistTypeOptionBuilder::from_json_str(s)),
+        FieldType::CreatedTime | FieldType::LastEditedTime => {
+            Box::new(TimestampTypeOptionBuilder::from_json_str(s, field_type))
+        }
+        FieldType::Relation => Box::new(RelationTypeOptionBuilder::from_json_str(s)),
+        FieldType::Formula => Box::new(FormulaTypeOptionBuilder::from_json_str(s)),
+        FieldType::Rating => Box::new(RatingTypeOptionBuilder::from_json_str(s)),
+        FieldType::Progress => Box::new(ProgressTypeOptionBuilder::from_json_str(s)),
+        FieldType::Email => Box::new(EmailTypeOptionBuilder::from_json_str(s)),
+        FieldType::PhoneNumber => Box::new(PhoneNumberTypeOptionBuilder::from_json_str(s)),
+        FieldType::Attachment => Box::new(AttachmentTypeOptionBuilder::from_json_str(s)),
+        FieldType::Person => Box::new(PersonTypeOptionBuilder::from_json_str(s)),
     }
 }
 
@@ -50,5 +70,16 @@ pub fn type_option_builder_from_bytes<T: Into<Bytes>>(bytes: T, field_type: &Fie
         FieldType::Checkbox => Box::new(CheckboxTypeOptionBuilder::from_protobuf_bytes(bytes)),
         FieldType::URL => Box::new(URLTypeOptionBuilder::from_protobuf_bytes(bytes)),
         FieldType::Checklist => Box::new(ChecklistTypeOptionBuilder::from_protobuf_bytes(bytes)),
+        FieldType::CreatedTime | FieldType::LastEditedTime => {
+            Box::new(TimestampTypeOptionBuilder::from_protobuf_bytes(bytes, field_type))
+        }
+        FieldType::Relation => Box::new(RelationTypeOptionBuilder::from_protobuf_bytes(bytes)),
+        FieldType::Formula => Box::new(FormulaTypeOptionBuilder::from_protobuf_bytes(bytes)),
+        FieldType::Rating => Box::new(RatingTypeOptionBuilder::from_protobuf_bytes(bytes)),
+        FieldType::Progress => Box::new(ProgressTypeOptionBuilder::from_protobuf_bytes(bytes)),
+        FieldType::Email => Box::new(EmailTypeOptionBuilder::from_protobuf_bytes(bytes)),
+        FieldType::PhoneNumber => Box::new(PhoneNumberTypeOptionBuilder::from_protobuf_bytes(bytes)),
+        FieldType::Attachment => Box::new(AttachmentTypeOptionBuilder::from_protobuf_bytes(bytes)),
+        FieldType::Person => Box::new(PersonTypeOptionBuilder::from_protobuf_bytes(bytes)),
     }
 }
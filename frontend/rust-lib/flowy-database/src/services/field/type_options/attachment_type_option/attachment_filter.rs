@@ -0,0 +1,74 @@
+use crate::entities::{AttachmentFilterConditionPB, AttachmentFilterPB};
+use crate::services::field::AttachmentCellData;
+
+impl AttachmentFilterPB {
+    pub fn is_visible(&self, cell_data: &AttachmentCellData) -> bool {
+        match self.condition {
+            AttachmentFilterConditionPB::AttachmentIsEmpty => cell_data.attachments.is_empty(),
+            AttachmentFilterConditionPB::AttachmentIsNotEmpty => !cell_data.attachments.is_empty(),
+            AttachmentFilterConditionPB::FileTypeIs => cell_data
+                .attachments
+                .iter()
+                .any(|attachment| attachment.is_file_type(&self.content)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::{AttachmentFilterConditionPB, AttachmentFilterPB};
+    use crate::services::field::{AttachmentCellData, AttachmentPB};
+
+    fn cell_data(mime_types: &[&str]) -> AttachmentCellData {
+        let attachments = mime_types
+            .iter()
+            .map(|mime_type| AttachmentPB::from_url("file", "https://appflowy.io/file", mime_type))
+            .collect();
+        AttachmentCellData { attachments }
+    }
+
+    #[test]
+    fn attachment_filter_is_empty_test() {
+        let attachment_filter = AttachmentFilterPB {
+            condition: AttachmentFilterConditionPB::AttachmentIsEmpty,
+            content: "".to_owned(),
+        };
+        assert!(attachment_filter.is_visible(&cell_data(&[])));
+        assert!(!attachment_filter.is_visible(&cell_data(&["image/png"])));
+    }
+
+    #[test]
+    fn attachment_filter_is_not_empty_test() {
+        let attachment_filter = AttachmentFilterPB {
+            condition: AttachmentFilterConditionPB::AttachmentIsNotEmpty,
+            content: "".to_owned(),
+        };
+        assert!(!attachment_filter.is_visible(&cell_data(&[])));
+        assert!(attachment_filter.is_visible(&cell_data(&["image/png"])));
+    }
+
+    #[test]
+    fn attachment_filter_file_type_is_test() {
+        let attachment_filter = AttachmentFilterPB {
+            condition: AttachmentFilterConditionPB::FileTypeIs,
+            content: "Image".to_owned(),
+        };
+        assert!(attachment_filter.is_visible(&cell_data(&["application/pdf", "image/png"])));
+        assert!(!attachment_filter.is_visible(&cell_data(&["application/pdf"])));
+        assert!(!attachment_filter.is_visible(&cell_data(&[])));
+
+        let attachment_filter = AttachmentFilterPB {
+            condition: AttachmentFilterConditionPB::FileTypeIs,
+            content: "application/pdf".to_owned(),
+        };
+        assert!(attachment_filter.is_visible(&cell_data(&["application/pdf"])));
+        assert!(!attachment_filter.is_visible(&cell_data(&["application/zip"])));
+
+        // The empty file type matches nothing.
+        let attachment_filter = AttachmentFilterPB {
+            condition: AttachmentFilterConditionPB::FileTypeIs,
+            content: "".to_owned(),
+        };
+        assert!(!attachment_filter.is_visible(&cell_data(&["image/png"])));
+    }
+}
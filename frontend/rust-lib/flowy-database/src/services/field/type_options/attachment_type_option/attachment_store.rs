@@ -0,0 +1,89 @@
+use crate::services::cell::{FromCellString, TypeCellData};
+use crate::services::field::{AttachmentCellData, AttachmentPB};
+use flowy_error::FlowyResult;
+use grid_model::{CellRevision, FieldRevision, RowRevision};
+use lib_infra::async_trait::async_trait;
+use parking_lot::RwLock;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// [AttachmentStore] manages the files of the attachment cells. The files are copied into the
+/// app's storage directory when they are imported, and they are deleted when the last cell that
+/// references them is removed.
+///
+/// The deletion can't be undone: undoing the removal of a cell, a row or a field restores the
+/// attachments but not their files.
+#[async_trait]
+pub trait AttachmentStore: Send + Sync {
+    /// Copies the file of the `source_path` into the app's storage directory.
+    async fn import_file(&self, attachment_id: &str, source_path: &str) -> FlowyResult<StoredFile>;
+
+    /// Deletes the stored file of the attachment.
+    async fn delete_file(&self, attachment: &AttachmentPB) -> FlowyResult<()>;
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct StoredFile {
+    /// The path of the file in the app's storage directory.
+    pub path: String,
+
+    /// The size of the file in bytes.
+    pub size: i64,
+}
+
+/// [AttachmentStoreRef] is shared by the `DatabaseManager` and all the opened databases, so the
+/// store that is set after the databases are opened is used by them as well.
+#[derive(Clone, Default)]
+pub struct AttachmentStoreRef(Arc<RwLock<Option<Arc<dyn AttachmentStore>>>>);
+
+impl AttachmentStoreRef {
+    pub fn set(&self, store: Option<Arc<dyn AttachmentStore>>) {
+        *self.0.write() = store;
+    }
+
+    pub fn get(&self) -> Option<Arc<dyn AttachmentStore>> {
+        self.0.read().clone()
+    }
+}
+
+/// Returns the attachments of the cell. The cell that is not an attachment cell has no attachments.
+pub fn attachments_of_cell(cell_rev: &CellRevision) -> Vec<AttachmentPB> {
+    match TypeCellData::try_from(cell_rev) {
+        Ok(type_cell_data) if type_cell_data.is_attachment() => {
+            AttachmentCellData::from_cell_str(&type_cell_data.cell_str)
+                .map(|cell_data| cell_data.attachments)
+                .unwrap_or_default()
+        }
+        _ => vec![],
+    }
+}
+
+/// Returns the attachments of the row. Only the attachments of the field are returned if the
+/// `field_id` is not None.
+pub fn attachments_of_row(row_rev: &RowRevision, field_id: Option<&str>) -> Vec<AttachmentPB> {
+    row_rev
+        .cells
+        .iter()
+        .filter(|(cell_field_id, _)| field_id.map_or(true, |field_id| cell_field_id.as_str() == field_id))
+        .flat_map(|(_, cell_rev)| attachments_of_cell(cell_rev))
+        .collect()
+}
+
+/// Returns the attachments that are referenced by the cells of the fields, including the fields
+/// that were switched to another field type. The cells of the deleted fields are kept in the rows
+/// so the fields can be restored, but their attachments are not referenced anymore. The attachment
+/// that is referenced by multiple cells is returned once.
+pub fn referenced_attachments(field_revs: &[Arc<FieldRevision>], row_revs: &[Arc<RowRevision>]) -> Vec<AttachmentPB> {
+    let field_ids = field_revs
+        .iter()
+        .map(|field_rev| field_rev.id.as_str())
+        .collect::<HashSet<&str>>();
+    let mut attachment_ids = HashSet::new();
+    row_revs
+        .iter()
+        .flat_map(|row_rev| row_rev.cells.iter())
+        .filter(|(field_id, _)| field_ids.contains(field_id.as_str()))
+        .flat_map(|(_, cell_rev)| attachments_of_cell(cell_rev))
+        .filter(|attachment| attachment_ids.insert(attachment.id.clone()))
+        .collect()
+}
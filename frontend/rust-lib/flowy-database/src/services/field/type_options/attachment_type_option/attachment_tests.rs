@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use crate::entities::FieldType;
+    use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+    use crate::services::field::*;
+    use grid_model::CellRevision;
+    use std::cmp::Ordering;
+
+    fn attachment(id: &str, mime_type: &str) -> AttachmentPB {
+        AttachmentPB {
+            id: id.to_owned(),
+            name: format!("{}.file", id),
+            size: 10,
+            mime_type: mime_type.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    fn attachment_ids(cell_data: &AttachmentCellData) -> Vec<&str> {
+        cell_data
+            .attachments
+            .iter()
+            .map(|attachment| attachment.id.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn attachment_changeset_insert_test() {
+        let type_option = AttachmentTypeOptionPB::default();
+        let changeset = AttachmentCellChangeset::from_insert_attachments(vec![
+            attachment("a", "image/png"),
+            attachment("b", "application/pdf"),
+            // The attachment with the same id is only inserted once.
+            attachment("a", "image/png"),
+        ]);
+        let (cell_str, cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+        assert_eq!(attachment_ids(&cell_data), vec!["a", "b"]);
+        assert_eq!(cell_data.total_size(), 20);
+        assert_eq!(AttachmentCellData::from_cell_str(&cell_str).unwrap(), cell_data);
+    }
+
+    #[test]
+    fn attachment_changeset_delete_and_move_test() {
+        let type_option = AttachmentTypeOptionPB::default();
+        let changeset = AttachmentCellChangeset::from_insert_attachments(vec![
+            attachment("a", "image/png"),
+            attachment("b", "application/pdf"),
+            attachment("c", "text/plain"),
+        ]);
+        let (cell_str, _) = type_option.apply_changeset(changeset, None).unwrap();
+        let type_cell_data = || Some(TypeCellData::new(cell_str.clone(), FieldType::Attachment));
+
+        let changeset = AttachmentCellChangeset::from_delete_attachment_ids(vec!["b".to_owned()]);
+        let (_, cell_data) = type_option.apply_changeset(changeset, type_cell_data()).unwrap();
+        assert_eq!(attachment_ids(&cell_data), vec!["a", "c"]);
+
+        let changeset = AttachmentCellChangeset::from_move_attachment("a", 2);
+        let (_, cell_data) = type_option.apply_changeset(changeset, type_cell_data()).unwrap();
+        assert_eq!(attachment_ids(&cell_data), vec!["b", "c", "a"]);
+
+        // The index is clamped to the number of the attachments.
+        let changeset = AttachmentCellChangeset::from_move_attachment("b", 100);
+        let (_, cell_data) = type_option.apply_changeset(changeset, type_cell_data()).unwrap();
+        assert_eq!(attachment_ids(&cell_data), vec!["a", "c", "b"]);
+    }
+
+    #[test]
+    fn attachment_changeset_ignore_other_cell_test() {
+        let type_option = AttachmentTypeOptionPB::default();
+        let type_cell_data = TypeCellData::new("hello".to_owned(), FieldType::RichText);
+        let changeset = AttachmentCellChangeset::from_insert_attachments(vec![attachment("a", "image/png")]);
+        let (_, cell_data) = type_option.apply_changeset(changeset, Some(type_cell_data)).unwrap();
+        assert_eq!(attachment_ids(&cell_data), vec!["a"]);
+    }
+
+    #[test]
+    fn attachment_decode_test() {
+        let type_option = AttachmentTypeOptionPB::default();
+        let field_rev = FieldBuilder::from_field_type(&FieldType::Attachment).build();
+        let cell_data = AttachmentCellData {
+            attachments: vec![attachment("a", "image/png"), attachment("b", "application/pdf")],
+        };
+        let decoded = type_option
+            .decode_cell_str(cell_data.to_string(), &FieldType::Attachment, &field_rev)
+            .unwrap();
+        assert_eq!(type_option.decode_cell_data_to_str(decoded), "a.file,b.file");
+
+        let decoded = type_option
+            .decode_cell_str(cell_data.to_string(), &FieldType::RichText, &field_rev)
+            .unwrap();
+        assert!(decoded.attachments.is_empty());
+    }
+
+    #[test]
+    fn attachments_of_cell_test() {
+        let cell_data = AttachmentCellData {
+            attachments: vec![attachment("a", "image/png")],
+        };
+        let cell_rev = CellRevision::new(TypeCellData::new(cell_data.to_string(), FieldType::Attachment).to_json());
+        assert_eq!(attachments_of_cell(&cell_rev), cell_data.attachments);
+
+        let cell_rev = CellRevision::new(TypeCellData::new(cell_data.to_string(), FieldType::RichText).to_json());
+        assert!(attachments_of_cell(&cell_rev).is_empty());
+    }
+
+    #[test]
+    fn attachment_compare_test() {
+        let type_option = AttachmentTypeOptionPB::default();
+        let left = AttachmentCellData {
+            attachments: vec![attachment("a", "image/png")],
+        };
+        let right = AttachmentCellData::default();
+        assert_eq!(type_option.apply_cmp(&left, &right), Ordering::Greater);
+        assert_eq!(type_option.apply_cmp(&right, &right), Ordering::Equal);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::entities::{AttachmentFilterPB, FieldType};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+    AttachmentCellChangeset, AttachmentCellData, AttachmentCellDataPB, BoxTypeOptionBuilder, TypeOption,
+    TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+    TypedCellData, SELECTION_IDS_SEPARATOR,
+};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::FlowyResult;
+use grid_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+#[derive(Default)]
+pub struct AttachmentTypeOptionBuilder(AttachmentTypeOptionPB);
+impl_into_box_type_option_builder!(AttachmentTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(AttachmentTypeOptionBuilder, AttachmentTypeOptionPB);
+
+impl TypeOptionBuilder for AttachmentTypeOptionBuilder {
+    fn field_type(&self) -> FieldType {
+        FieldType::Attachment
+    }
+
+    fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+        &self.0
+    }
+}
+
+// Attachment
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct AttachmentTypeOptionPB {
+    #[pb(index = 1)]
+    #[serde(default)]
+    data: String,
+}
+impl_type_option!(AttachmentTypeOptionPB, FieldType::Attachment);
+
+impl TypeOption for AttachmentTypeOptionPB {
+    type CellData = AttachmentCellData;
+    type CellChangeset = AttachmentCellChangeset;
+    type CellProtobufType = AttachmentCellDataPB;
+    type CellFilter = AttachmentFilterPB;
+}
+
+impl TypeOptionTransform for AttachmentTypeOptionPB {}
+
+impl TypeOptionCellData for AttachmentTypeOptionPB {
+    fn convert_to_protobuf(&self, cell_data: <Self as TypeOption>::CellData) -> <Self as TypeOption>::CellProtobufType {
+        cell_data.into()
+    }
+
+    fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
+        AttachmentCellData::from_cell_str(&cell_str)
+    }
+
+    fn convert_to_typed_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> TypedCellData {
+        TypedCellData::Attachment(cell_data.attachments)
+    }
+}
+
+impl CellDataDecoder for AttachmentTypeOptionPB {
+    fn decode_cell_str(
+        &self,
+        cell_str: String,
+        decoded_field_type: &FieldType,
+        _field_rev: &FieldRevision,
+    ) -> FlowyResult<<Self as TypeOption>::CellData> {
+        if !decoded_field_type.is_attachment() {
+            return Ok(Default::default());
+        }
+
+        self.decode_type_option_cell_str(cell_str)
+    }
+
+    fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+        cell_data
+            .attachments
+            .into_iter()
+            .map(|attachment| attachment.name)
+            .collect::<Vec<_>>()
+            .join(SELECTION_IDS_SEPARATOR)
+    }
+}
+
+impl CellDataChangeset for AttachmentTypeOptionPB {
+    fn apply_changeset(
+        &self,
+        changeset: <Self as TypeOption>::CellChangeset,
+        type_cell_data: Option<TypeCellData>,
+    ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+        let mut cell_data = match type_cell_data {
+            Some(type_cell_data) if type_cell_data.is_attachment() => {
+                AttachmentCellData::from_cell_str(&type_cell_data.cell_str).unwrap_or_default()
+            }
+            _ => AttachmentCellData::default(),
+        };
+        changeset.apply(&mut cell_data);
+        Ok((cell_data.to_json()?, cell_data))
+    }
+}
+
+impl TypeOptionCellDataFilter for AttachmentTypeOptionPB {
+    fn apply_filter(
+        &self,
+        filter: &<Self as TypeOption>::CellFilter,
+        field_type: &FieldType,
+        cell_data: &<Self as TypeOption>::CellData,
+    ) -> bool {
+        if !field_type.is_attachment() {
+            return true;
+        }
+
+        filter.is_visible(cell_data)
+    }
+}
+
+impl TypeOptionCellDataCompare for AttachmentTypeOptionPB {
+    fn apply_cmp(
+        &self,
+        cell_data: &<Self as TypeOption>::CellData,
+        other_cell_data: &<Self as TypeOption>::CellData,
+    ) -> Ordering {
+        cell_data.attachments.len().cmp(&other_cell_data.attachments.len())
+    }
+}
@@ -0,0 +1,301 @@
+use crate::entities::CellPathPB;
+use crate::services::cell::{
+    CellProtobufBlobParser, DecodedCellData, FromCellChangesetString, FromCellString, ToCellChangesetString,
+};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::{internal_error, FlowyResult};
+use nanoid::nanoid;
+use serde::{Deserialize, Serialize};
+
+pub fn gen_attachment_id() -> String {
+    nanoid!(10)
+}
+
+/// [AttachmentPB] describes a file of the attachment cell. The file is either stored in the app's
+/// storage directory, in which case the `path` is not empty, or it's a remote file with the `url`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ProtoBuf)]
+pub struct AttachmentPB {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    /// The size of the file in bytes. It's 0 if the size of the remote file is unknown.
+    #[pb(index = 3)]
+    #[serde(default)]
+    pub size: i64,
+
+    #[pb(index = 4)]
+    #[serde(default)]
+    pub mime_type: String,
+
+    /// The path of the file that is copied into the app's storage directory by the
+    /// [AttachmentStore](crate::services::field::AttachmentStore).
+    #[pb(index = 5)]
+    #[serde(default)]
+    pub path: String,
+
+    #[pb(index = 6)]
+    #[serde(default)]
+    pub url: String,
+}
+
+impl AttachmentPB {
+    pub fn from_url(name: &str, url: &str, mime_type: &str) -> Self {
+        Self {
+            id: gen_attachment_id(),
+            name: name.to_owned(),
+            mime_type: mime_type.to_owned(),
+            url: url.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns true if the file is stored in the app's storage directory.
+    pub fn is_local(&self) -> bool {
+        !self.path.is_empty()
+    }
+
+    /// The `file_type` is either a full mime type, for example: `image/png`, or only the top-level
+    /// type, for example: `image`. The comparison is case-insensitive.
+    pub fn is_file_type(&self, file_type: &str) -> bool {
+        let file_type = file_type.trim().to_lowercase();
+        if file_type.is_empty() {
+            return false;
+        }
+
+        let mime_type = self.mime_type.trim().to_lowercase();
+        if file_type.contains('/') {
+            mime_type == file_type
+        } else {
+            mime_type.split('/').next() == Some(file_type.as_str())
+        }
+    }
+}
+
+/// [AttachmentCellData] contains the attachments of a cell in the order they are displayed. It's
+/// stored as a json string.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttachmentCellData {
+    pub attachments: Vec<AttachmentPB>,
+}
+
+impl AttachmentCellData {
+    /// Returns the total size of the attachments in bytes.
+    pub fn total_size(&self) -> i64 {
+        self.attachments.iter().map(|attachment| attachment.size.max(0)).sum()
+    }
+
+    pub fn to_json(&self) -> FlowyResult<String> {
+        serde_json::to_string(self).map_err(internal_error)
+    }
+}
+
+impl FromCellString for AttachmentCellData {
+    fn from_cell_str(s: &str) -> FlowyResult<Self> {
+        if s.is_empty() {
+            return Ok(Self::default());
+        }
+        serde_json::from_str::<AttachmentCellData>(s).map_err(internal_error)
+    }
+}
+
+impl ToString for AttachmentCellData {
+    fn to_string(&self) -> String {
+        self.to_json().unwrap()
+    }
+}
+
+impl DecodedCellData for AttachmentCellData {
+    type Object = AttachmentCellData;
+
+    fn is_empty(&self) -> bool {
+        self.attachments.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct AttachmentCellDataPB {
+    #[pb(index = 1)]
+    pub attachments: Vec<AttachmentPB>,
+
+    /// The total size of the attachments in bytes.
+    #[pb(index = 2)]
+    pub total_size: i64,
+}
+
+impl From<AttachmentCellData> for AttachmentCellDataPB {
+    fn from(data: AttachmentCellData) -> Self {
+        let total_size = data.total_size();
+        Self {
+            attachments: data.attachments,
+            total_size,
+        }
+    }
+}
+
+impl DecodedCellData for AttachmentCellDataPB {
+    type Object = AttachmentCellDataPB;
+
+    fn is_empty(&self) -> bool {
+        self.attachments.is_empty()
+    }
+}
+
+pub struct AttachmentCellDataParser();
+impl CellProtobufBlobParser for AttachmentCellDataParser {
+    type Object = AttachmentCellDataPB;
+
+    fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+        AttachmentCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+    }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct AttachmentMovePB {
+    #[pb(index = 1)]
+    pub attachment_id: String,
+
+    #[pb(index = 2)]
+    pub to_index: i32,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct AttachmentCellChangesetPB {
+    #[pb(index = 1)]
+    pub cell_path: CellPathPB,
+
+    /// The attachments that will be appended to the cell. Use the [ImportAttachment] event to
+    /// add the local files, it copies the files into the app's storage directory first.
+    #[pb(index = 2)]
+    pub insert_attachments: Vec<AttachmentPB>,
+
+    #[pb(index = 3)]
+    pub delete_attachment_ids: Vec<String>,
+
+    #[pb(index = 4, one_of)]
+    pub move_attachment: Option<AttachmentMovePB>,
+}
+
+impl From<AttachmentCellChangesetPB> for AttachmentCellChangeset {
+    fn from(changeset: AttachmentCellChangesetPB) -> Self {
+        Self {
+            insert_attachments: changeset.insert_attachments,
+            delete_attachment_ids: changeset.delete_attachment_ids,
+            move_attachment: changeset.move_attachment.map(|move_attachment| AttachmentMove {
+                attachment_id: move_attachment.attachment_id,
+                to_index: move_attachment.to_index.max(0) as usize,
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct ImportAttachmentPayloadPB {
+    #[pb(index = 1)]
+    pub cell_path: CellPathPB,
+
+    /// The path of the file that will be copied into the app's storage directory.
+    #[pb(index = 2)]
+    pub source_path: String,
+
+    /// The name of the attachment. The file name of the `source_path` is used if it's empty.
+    #[pb(index = 3)]
+    pub name: String,
+
+    #[pb(index = 4)]
+    pub mime_type: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AttachmentMove {
+    pub attachment_id: String,
+    pub to_index: usize,
+}
+
+/// [AttachmentCellChangeset] changes the attachments of a cell. The changes are applied in the
+/// order: delete, insert and then move.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AttachmentCellChangeset {
+    #[serde(default)]
+    pub insert_attachments: Vec<AttachmentPB>,
+
+    #[serde(default)]
+    pub delete_attachment_ids: Vec<String>,
+
+    /// Moves the attachment to the index. The index is clamped to the number of the attachments.
+    #[serde(default)]
+    pub move_attachment: Option<AttachmentMove>,
+}
+
+impl AttachmentCellChangeset {
+    pub fn from_insert_attachments(attachments: Vec<AttachmentPB>) -> Self {
+        Self {
+            insert_attachments: attachments,
+            ..Default::default()
+        }
+    }
+
+    pub fn from_delete_attachment_ids(attachment_ids: Vec<String>) -> Self {
+        Self {
+            delete_attachment_ids: attachment_ids,
+            ..Default::default()
+        }
+    }
+
+    pub fn from_move_attachment(attachment_id: &str, to_index: usize) -> Self {
+        Self {
+            move_attachment: Some(AttachmentMove {
+                attachment_id: attachment_id.to_owned(),
+                to_index,
+            }),
+            ..Default::default()
+        }
+    }
+
+    pub fn apply(self, cell_data: &mut AttachmentCellData) {
+        cell_data
+            .attachments
+            .retain(|attachment| !self.delete_attachment_ids.contains(&attachment.id));
+
+        for insert_attachment in self.insert_attachments {
+            if !insert_attachment.id.is_empty()
+                && !cell_data
+                    .attachments
+                    .iter()
+                    .any(|attachment| attachment.id == insert_attachment.id)
+            {
+                cell_data.attachments.push(insert_attachment);
+            }
+        }
+
+        if let Some(move_attachment) = self.move_attachment {
+            if let Some(index) = cell_data
+                .attachments
+                .iter()
+                .position(|attachment| attachment.id == move_attachment.attachment_id)
+            {
+                let attachment = cell_data.attachments.remove(index);
+                let to_index = move_attachment.to_index.min(cell_data.attachments.len());
+                cell_data.attachments.insert(to_index, attachment);
+            }
+        }
+    }
+}
+
+impl FromCellChangesetString for AttachmentCellChangeset {
+    fn from_changeset(changeset: String) -> FlowyResult<Self>
+    where
+        Self: Sized,
+    {
+        serde_json::from_str::<AttachmentCellChangeset>(&changeset).map_err(internal_error)
+    }
+}
+
+impl ToCellChangesetString for AttachmentCellChangeset {
+    fn to_cell_changeset_str(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
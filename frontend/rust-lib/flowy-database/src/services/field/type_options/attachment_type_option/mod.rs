@@ -0,0 +1,10 @@
+#![allow(clippy::module_inception)]
+mod attachment_filter;
+mod attachment_store;
+mod attachment_tests;
+mod attachment_type_option;
+mod attachment_type_option_entities;
+
+pub use attachment_store::*;
+pub use attachment_type_option::*;
+pub use attachment_type_option_entities::*;
@@ -0,0 +1,91 @@
+use bytes::Bytes;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::{ErrorCode, FlowyError};
+
+/// [CellValidationRulePB] is the rule of the type option that the cell violates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ProtoBuf_Enum)]
+pub enum CellValidationRulePB {
+    NumberMin = 0,
+    NumberMax = 1,
+    TextMaxLength = 2,
+    TextPattern = 3,
+    DateMin = 4,
+    DateMax = 5,
+}
+
+impl std::default::Default for CellValidationRulePB {
+    fn default() -> Self {
+        CellValidationRulePB::NumberMin
+    }
+}
+
+/// [CellValidationErrorPB] is the payload of the error that is returned when the new data of the
+/// cell violates a validation rule of its type option. The UI can display it inline.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct CellValidationErrorPB {
+    #[pb(index = 1)]
+    pub field_id: String,
+
+    /// It's empty if the row is unknown, for example, when the cell of a new row is validated.
+    #[pb(index = 2)]
+    pub row_id: String,
+
+    #[pb(index = 3)]
+    pub rule: CellValidationRulePB,
+
+    #[pb(index = 4)]
+    pub msg: String,
+}
+
+impl CellValidationErrorPB {
+    /// Returns the payload of the error if it's a validation error.
+    pub fn from_error(error: &FlowyError) -> Option<Self> {
+        if error.code != ErrorCode::InvalidParams.value() || error.payload.is_empty() {
+            return None;
+        }
+        CellValidationErrorPB::try_from(Bytes::from(error.payload.clone())).ok()
+    }
+
+    pub fn into_error(self) -> FlowyError {
+        let error = FlowyError::new(ErrorCode::InvalidParams, &self.msg);
+        match Bytes::try_from(self) {
+            Ok(bytes) => error.with_payload(bytes.to_vec()),
+            Err(_) => error,
+        }
+    }
+}
+
+/// [CellValidationViolation] is the rule that the cell data violates, it's returned by the
+/// `validate_cell_data` of the type option.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellValidationViolation {
+    pub rule: CellValidationRulePB,
+    pub msg: String,
+}
+
+impl CellValidationViolation {
+    pub fn new(rule: CellValidationRulePB, msg: String) -> Self {
+        Self { rule, msg }
+    }
+
+    pub fn into_error(self, field_id: &str) -> FlowyError {
+        CellValidationErrorPB {
+            field_id: field_id.to_owned(),
+            row_id: "".to_owned(),
+            rule: self.rule,
+            msg: self.msg,
+        }
+        .into_error()
+    }
+}
+
+/// Fills in the row of the validation error, the other errors are returned as they are.
+pub fn validation_error_with_row_id(error: FlowyError, row_id: &str) -> FlowyError {
+    match CellValidationErrorPB::from_error(&error) {
+        None => error,
+        Some(mut validation_error) => {
+            validation_error.row_id = row_id.to_owned();
+            validation_error.into_error()
+        }
+    }
+}
@@ -5,8 +5,8 @@ impl CheckboxFilterPB {
     pub fn is_visible(&self, cell_data: &CheckboxCellData) -> bool {
         let is_check = cell_data.is_check();
         match self.condition {
-            CheckboxFilterConditionPB::IsChecked => is_check,
-            CheckboxFilterConditionPB::IsUnChecked => !is_check,
+            CheckboxFilterConditionPB::IsChecked | CheckboxFilterConditionPB::CheckboxIsNotEmpty => is_check,
+            CheckboxFilterConditionPB::IsUnChecked | CheckboxFilterConditionPB::CheckboxIsEmpty => !is_check,
         }
     }
 }
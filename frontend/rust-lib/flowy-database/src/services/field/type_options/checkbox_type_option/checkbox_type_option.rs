@@ -3,7 +3,7 @@ use crate::impl_type_option;
 use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
 use crate::services::field::{
     default_order, BoxTypeOptionBuilder, CheckboxCellData, TypeOption, TypeOptionBuilder, TypeOptionCellData,
-    TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+    TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform, TypedCellData,
 };
 use bytes::Bytes;
 use flowy_derive::ProtoBuf;
@@ -39,6 +39,11 @@ impl TypeOptionBuilder for CheckboxTypeOptionBuilder {
 pub struct CheckboxTypeOptionPB {
     #[pb(index = 1)]
     pub is_selected: bool,
+
+    /// Whether the cells of the new rows are checked, for example: `Yes` or `No`.
+    #[pb(index = 2, one_of)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<String>,
 }
 impl_type_option!(CheckboxTypeOptionPB, FieldType::Checkbox);
 
@@ -81,6 +86,10 @@ impl TypeOptionCellData for CheckboxTypeOptionPB {
     fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
         CheckboxCellData::from_cell_str(&cell_str)
     }
+
+    fn convert_to_typed_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> TypedCellData {
+        TypedCellData::Checkbox(cell_data.is_check())
+    }
 }
 
 impl CellDataDecoder for CheckboxTypeOptionPB {
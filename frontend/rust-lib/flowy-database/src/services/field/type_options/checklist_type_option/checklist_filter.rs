@@ -0,0 +1,103 @@
+use crate::entities::{ChecklistFilterConditionPB, ChecklistFilterPB};
+use crate::services::field::ChecklistCellData;
+
+impl ChecklistFilterPB {
+    pub fn is_visible(&self, cell_data: &ChecklistCellData) -> bool {
+        let is_complete = cell_data.is_complete();
+        match self.condition {
+            ChecklistFilterConditionPB::IsComplete => is_complete,
+            ChecklistFilterConditionPB::IsIncomplete => !is_complete,
+            // Rounds to the whole percentage, so 2 of the 3 items done is 67%.
+            ChecklistFilterConditionPB::CompletionAtLeast => {
+                (cell_data.percentage() * 100.0).round() as i32 >= self.percent
+            }
+            ChecklistFilterConditionPB::ChecklistIsEmpty => cell_data.items.is_empty(),
+            ChecklistFilterConditionPB::ChecklistIsNotEmpty => !cell_data.items.is_empty(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::{ChecklistFilterConditionPB, ChecklistFilterPB};
+    use crate::services::field::{ChecklistCellData, ChecklistItem};
+
+    fn checklist_cell_data(done_flags: &[bool]) -> ChecklistCellData {
+        let items = done_flags
+            .iter()
+            .enumerate()
+            .map(|(index, done)| ChecklistItem {
+                done: *done,
+                ..ChecklistItem::new(&format!("item {}", index))
+            })
+            .collect();
+        ChecklistCellData { items }
+    }
+
+    #[test]
+    fn checklist_filter_is_complete_test() {
+        let checklist_filter = ChecklistFilterPB {
+            condition: ChecklistFilterConditionPB::IsComplete,
+            ..Default::default()
+        };
+        for (done_flags, visible) in [
+            (vec![], false),
+            (vec![true, false], false),
+            (vec![false, false], false),
+            (vec![true, true], true),
+        ] {
+            assert_eq!(checklist_filter.is_visible(&checklist_cell_data(&done_flags)), visible);
+        }
+    }
+
+    #[test]
+    fn checklist_filter_is_incomplete_test() {
+        let checklist_filter = ChecklistFilterPB {
+            condition: ChecklistFilterConditionPB::IsIncomplete,
+            ..Default::default()
+        };
+        for (done_flags, visible) in [
+            (vec![], true),
+            (vec![true, false], true),
+            (vec![false, false], true),
+            (vec![true, true], false),
+        ] {
+            assert_eq!(checklist_filter.is_visible(&checklist_cell_data(&done_flags)), visible);
+        }
+    }
+
+    #[test]
+    fn checklist_filter_completion_at_least_test() {
+        let checklist_filter = ChecklistFilterPB {
+            condition: ChecklistFilterConditionPB::CompletionAtLeast,
+            percent: 50,
+        };
+        for (done_flags, visible) in [
+            (vec![], false),
+            (vec![true, false], true),
+            (vec![true, false, false], false),
+            (vec![true, true, false], true),
+            (vec![true, true], true),
+        ] {
+            assert_eq!(checklist_filter.is_visible(&checklist_cell_data(&done_flags)), visible);
+        }
+
+        // The checklist without any item is 0% done.
+        let checklist_filter = ChecklistFilterPB {
+            condition: ChecklistFilterConditionPB::CompletionAtLeast,
+            percent: 0,
+        };
+        assert!(checklist_filter.is_visible(&checklist_cell_data(&[])));
+    }
+
+    #[test]
+    fn checklist_filter_is_empty_test() {
+        let checklist_filter = ChecklistFilterPB {
+            condition: ChecklistFilterConditionPB::ChecklistIsEmpty,
+            ..Default::default()
+        };
+        for (done_flags, visible) in [(vec![], true), (vec![false], false), (vec![true], false)] {
+            assert_eq!(checklist_filter.is_visible(&checklist_cell_data(&done_flags)), visible);
+        }
+    }
+}
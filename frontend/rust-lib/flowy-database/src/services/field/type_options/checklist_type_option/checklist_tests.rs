@@ -0,0 +1,214 @@
+#[cfg(test)]
+mod tests {
+    use crate::entities::FieldType;
+    use crate::services::cell::{
+        CellDataChangeset, CellDataDecoder, FromCellChangesetString, ToCellChangesetString, TypeCellData,
+    };
+    use crate::services::field::type_options::checklist_type_option::*;
+    use crate::services::field::{
+        FieldBuilder, MultiSelectTypeOptionBuilder, MultiSelectTypeOptionPB, SelectOptionCellChangeset, SelectOptionPB,
+        TypeOptionBuilder, TypeOptionCellDataCompare, TypeOptionTransform,
+    };
+    use std::cmp::Ordering;
+
+    #[test]
+    fn checklist_insert_and_toggle_items_test() {
+        let type_option = ChecklistTypeOptionPB::default();
+        let first_item = ChecklistItem::new("first");
+        let second_item = ChecklistItem::new("second");
+        let changeset = ChecklistCellChangeset::from_insert_items(vec![first_item.clone(), second_item.clone()]);
+        let (cell_str, cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+        assert_eq!(cell_data.items.len(), 2);
+        assert_eq!(cell_data.percentage(), 0.0);
+
+        let changeset = ChecklistCellChangeset::from_toggle_item_ids(vec![first_item.id.clone()]);
+        let cell_data = apply_changeset(&type_option, changeset, cell_str);
+        assert!(cell_data.items[0].done);
+        assert!(!cell_data.items[1].done);
+        assert_eq!(cell_data.percentage(), 0.5);
+        assert!(!cell_data.is_complete());
+
+        let changeset = ChecklistCellChangeset::from_toggle_item_ids(vec![first_item.id, second_item.id]);
+        let cell_data = apply_changeset(&type_option, changeset, cell_data.to_string());
+        assert!(!cell_data.items[0].done);
+        assert!(cell_data.items[1].done);
+    }
+
+    #[test]
+    fn checklist_rename_move_and_delete_items_test() {
+        let type_option = ChecklistTypeOptionPB::default();
+        let items = vec![
+            ChecklistItem::new("first"),
+            ChecklistItem::new("second"),
+            ChecklistItem::new("third"),
+        ];
+        let changeset = ChecklistCellChangeset::from_insert_items(items.clone());
+        let (cell_str, _) = type_option.apply_changeset(changeset, None).unwrap();
+
+        let changeset = ChecklistCellChangeset::from_rename_item(&items[1].id, "renamed");
+        let cell_data = apply_changeset(&type_option, changeset, cell_str);
+        assert_eq!(
+            type_option.decode_cell_data_to_str(cell_data.clone()),
+            "first,renamed,third"
+        );
+
+        let changeset = ChecklistCellChangeset::from_move_item(&items[2].id, 0);
+        let cell_data = apply_changeset(&type_option, changeset, cell_data.to_string());
+        assert_eq!(
+            type_option.decode_cell_data_to_str(cell_data.clone()),
+            "third,first,renamed"
+        );
+
+        // The index is clamped to the number of the items.
+        let changeset = ChecklistCellChangeset::from_move_item(&items[2].id, 10);
+        let cell_data = apply_changeset(&type_option, changeset, cell_data.to_string());
+        assert_eq!(
+            type_option.decode_cell_data_to_str(cell_data.clone()),
+            "first,renamed,third"
+        );
+
+        let changeset = ChecklistCellChangeset::from_delete_item_ids(vec![items[0].id.clone()]);
+        let cell_data = apply_changeset(&type_option, changeset, cell_data.to_string());
+        assert_eq!(type_option.decode_cell_data_to_str(cell_data), "renamed,third");
+    }
+
+    #[test]
+    fn checklist_decode_option_ids_test() {
+        let first = SelectOptionPB::new("first");
+        let second = SelectOptionPB::new("second");
+        let third = SelectOptionPB::new("third");
+        let type_option = ChecklistTypeOptionPB {
+            options: vec![first.clone(), second, third.clone()],
+            disable_color: false,
+        };
+        let field_rev = FieldBuilder::from_field_type(&FieldType::Checklist).build();
+
+        // The cells that were created before the items were stored in the cells contain the ids
+        // of the done options.
+        let cell_str = format!("{},{}", first.id, third.id);
+        let cell_data = type_option
+            .decode_cell_str(cell_str, &FieldType::Checklist, &field_rev)
+            .unwrap();
+        let done_flags = cell_data.items.iter().map(|item| item.done).collect::<Vec<bool>>();
+        assert_eq!(done_flags, vec![true, false, true]);
+        assert!((cell_data.percentage() - 2.0 / 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn checklist_select_option_changeset_test() {
+        let type_option = ChecklistTypeOptionPB::default();
+        let items = vec![ChecklistItem::new("first"), ChecklistItem::new("second")];
+        let changeset = ChecklistCellChangeset::from_insert_items(items.clone());
+        let (cell_str, _) = type_option.apply_changeset(changeset, None).unwrap();
+
+        // The inserted options of the select option changeset are the done items.
+        let changeset_str =
+            SelectOptionCellChangeset::from_insert_options(vec![items[0].id.clone(), items[1].id.clone()])
+                .to_cell_changeset_str();
+        let changeset = ChecklistCellChangeset::from_changeset(changeset_str).unwrap();
+        let cell_data = apply_changeset(&type_option, changeset, cell_str);
+        assert!(cell_data.is_complete());
+
+        // Inserting the done item again doesn't toggle it.
+        let changeset_str = SelectOptionCellChangeset::from_insert_option_id(&items[0].id).to_cell_changeset_str();
+        let changeset = ChecklistCellChangeset::from_changeset(changeset_str).unwrap();
+        let cell_data = apply_changeset(&type_option, changeset, cell_data.to_string());
+        assert!(cell_data.items[0].done);
+
+        let changeset_str = SelectOptionCellChangeset::from_delete_option_id(&items[1].id).to_cell_changeset_str();
+        let changeset = ChecklistCellChangeset::from_changeset(changeset_str).unwrap();
+        let cell_data = apply_changeset(&type_option, changeset, cell_data.to_string());
+        assert_eq!(cell_data.percentage(), 0.5);
+
+        // The changesets of the other cells are rejected instead of being ignored.
+        assert!(ChecklistCellChangeset::from_changeset(r#"{"url":"https://appflowy.io"}"#.to_owned()).is_err());
+    }
+
+    #[test]
+    fn checklist_transform_from_multi_select_test() {
+        let google = SelectOptionPB::new("Google");
+        let facebook = SelectOptionPB::new("Facebook");
+        let multi_select_builder = MultiSelectTypeOptionBuilder::default()
+            .add_option(google)
+            .add_option(facebook.clone());
+        let multi_select_data = multi_select_builder.serializer().json_str();
+
+        let mut type_option = ChecklistTypeOptionPB::default();
+        type_option.transform_type_option(FieldType::MultiSelect, multi_select_data.clone());
+        assert_eq!(type_option.options.len(), 2);
+
+        // Already contain the options. It doesn't need to insert new options
+        type_option.transform_type_option(FieldType::MultiSelect, multi_select_data);
+        assert_eq!(type_option.options.len(), 2);
+
+        // The selected options become the items
+        let field_rev = FieldBuilder::from_field_type(&FieldType::Checklist).build();
+        let cell_data = type_option
+            .transform_type_option_cell_str(&facebook.id, &FieldType::MultiSelect, &field_rev)
+            .unwrap();
+        assert_eq!(cell_data.items.len(), 1);
+        assert_eq!(cell_data.items[0].name, facebook.name);
+        assert!(!cell_data.items[0].done);
+    }
+
+    #[test]
+    fn checklist_transform_to_multi_select_test() {
+        let google = SelectOptionPB::new("Google");
+        let checklist_builder = ChecklistTypeOptionBuilder::default().add_option(google.clone());
+        let checklist_data = checklist_builder.serializer().json_str();
+        let field_rev = FieldBuilder::new(checklist_builder).build();
+
+        let mut multi_select = MultiSelectTypeOptionPB::default();
+        multi_select.transform_type_option(FieldType::Checklist, checklist_data);
+        assert_eq!(multi_select.options.len(), 1);
+
+        let cell_data = ChecklistCellData {
+            items: vec![
+                ChecklistItem {
+                    id: google.id.clone(),
+                    name: google.name.clone(),
+                    done: true,
+                },
+                ChecklistItem::new("Unknown"),
+            ],
+        };
+
+        // The items without the matched option are dropped.
+        let option_ids = multi_select
+            .transform_type_option_cell_str(&cell_data.to_string(), &FieldType::Checklist, &field_rev)
+            .unwrap();
+        assert_eq!(&*option_ids, &vec![google.id]);
+    }
+
+    #[test]
+    fn checklist_compare_by_percentage_test() {
+        let type_option = ChecklistTypeOptionPB::default();
+        let half_done = ChecklistCellData {
+            items: vec![
+                ChecklistItem {
+                    done: true,
+                    ..ChecklistItem::new("first")
+                },
+                ChecklistItem::new("second"),
+            ],
+        };
+        let undone = ChecklistCellData {
+            items: vec![ChecklistItem::new("first")],
+        };
+        assert_eq!(type_option.apply_cmp(&undone, &half_done), Ordering::Less);
+        assert_eq!(type_option.apply_cmp(&half_done, &undone), Ordering::Greater);
+        assert_eq!(
+            type_option.apply_cmp(&ChecklistCellData::default(), &undone),
+            Ordering::Equal
+        );
+    }
+
+    fn apply_changeset(
+        type_option: &ChecklistTypeOptionPB,
+        changeset: ChecklistCellChangeset,
+        cell_str: String,
+    ) -> ChecklistCellData {
+        let type_cell_data = TypeCellData::new(cell_str, FieldType::Checklist);
+        type_option.apply_changeset(changeset, Some(type_cell_data)).unwrap().1
+    }
+}
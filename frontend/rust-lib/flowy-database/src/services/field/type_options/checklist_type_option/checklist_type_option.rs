@@ -0,0 +1,205 @@
+use crate::entities::{ChecklistFilterPB, FieldType};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+    BoxTypeOptionBuilder, ChecklistCellChangeset, ChecklistCellData, ChecklistCellDataPB, ChecklistItem,
+    MultiSelectTypeOptionPB, SelectOptionIds, SelectOptionPB, SingleSelectTypeOptionPB, TypeOption, TypeOptionBuilder,
+    TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform, TypedCellData,
+    SELECTION_IDS_SEPARATOR,
+};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::FlowyResult;
+use grid_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+// Checklist
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ProtoBuf)]
+pub struct ChecklistTypeOptionPB {
+    /// The items of the checklists that were created before the items were stored in the cells.
+    /// The options are also copied from the select options when switching the field type from
+    /// single select or multi select, so they can be used to name the items.
+    #[pb(index = 1)]
+    pub options: Vec<SelectOptionPB>,
+
+    #[pb(index = 2)]
+    pub disable_color: bool,
+}
+impl_type_option!(ChecklistTypeOptionPB, FieldType::Checklist);
+
+impl ChecklistTypeOptionPB {
+    /// Each selected option becomes an undone item. The option is looked up in the options of
+    /// this type option to get its name.
+    fn items_from_option_ids(&self, option_ids: &SelectOptionIds) -> ChecklistCellData {
+        let items = option_ids
+            .iter()
+            .flat_map(|option_id| self.options.iter().find(|option| &option.id == option_id))
+            .map(|option| ChecklistItem {
+                id: option.id.clone(),
+                name: option.name.clone(),
+                done: false,
+            })
+            .collect();
+        ChecklistCellData { items }
+    }
+}
+
+impl TypeOption for ChecklistTypeOptionPB {
+    type CellData = ChecklistCellData;
+    type CellChangeset = ChecklistCellChangeset;
+    type CellProtobufType = ChecklistCellDataPB;
+    type CellFilter = ChecklistFilterPB;
+}
+
+impl TypeOptionTransform for ChecklistTypeOptionPB {
+    fn transformable(&self) -> bool {
+        true
+    }
+
+    fn transform_type_option(&mut self, old_type_option_field_type: FieldType, old_type_option_data: String) {
+        let old_options = match old_type_option_field_type {
+            FieldType::SingleSelect => SingleSelectTypeOptionPB::from_json_str(&old_type_option_data).options,
+            FieldType::MultiSelect => MultiSelectTypeOptionPB::from_json_str(&old_type_option_data).options,
+            _ => return,
+        };
+        for old_option in old_options {
+            if !self.options.iter().any(|option| option.name == old_option.name) {
+                self.options.push(old_option);
+            }
+        }
+    }
+
+    fn transform_type_option_cell_str(
+        &self,
+        cell_str: &str,
+        decoded_field_type: &FieldType,
+        _field_rev: &FieldRevision,
+    ) -> Option<<Self as TypeOption>::CellData> {
+        match decoded_field_type {
+            FieldType::SingleSelect | FieldType::MultiSelect => {
+                let option_ids = SelectOptionIds::from_cell_str(cell_str).ok()?;
+                Some(self.items_from_option_ids(&option_ids))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl TypeOptionCellData for ChecklistTypeOptionPB {
+    fn convert_to_protobuf(&self, cell_data: <Self as TypeOption>::CellData) -> <Self as TypeOption>::CellProtobufType {
+        cell_data.into()
+    }
+
+    fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
+        match ChecklistCellData::from_cell_str(&cell_str) {
+            Ok(cell_data) => Ok(cell_data),
+            Err(_) => {
+                let option_ids = SelectOptionIds::from_cell_str(&cell_str)?;
+                Ok(ChecklistCellData::from_option_ids(&self.options, &option_ids))
+            }
+        }
+    }
+
+    fn convert_to_typed_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> TypedCellData {
+        TypedCellData::Checklist(cell_data.items)
+    }
+}
+
+impl CellDataDecoder for ChecklistTypeOptionPB {
+    fn decode_cell_str(
+        &self,
+        cell_str: String,
+        decoded_field_type: &FieldType,
+        _field_rev: &FieldRevision,
+    ) -> FlowyResult<<Self as TypeOption>::CellData> {
+        if !decoded_field_type.is_check_list() {
+            return Ok(Default::default());
+        }
+
+        self.decode_type_option_cell_str(cell_str)
+    }
+
+    fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+        cell_data
+            .items
+            .into_iter()
+            .map(|item| item.name)
+            .collect::<Vec<String>>()
+            .join(SELECTION_IDS_SEPARATOR)
+    }
+}
+
+impl CellDataChangeset for ChecklistTypeOptionPB {
+    fn apply_changeset(
+        &self,
+        changeset: <Self as TypeOption>::CellChangeset,
+        type_cell_data: Option<TypeCellData>,
+    ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+        // The cell that doesn't exist contains the options of the type option, the same as it's
+        // displayed.
+        let mut cell_data = match type_cell_data {
+            None => self.decode_type_option_cell_str("".to_owned())?,
+            Some(type_cell_data) => match type_cell_data.field_type {
+                FieldType::Checklist => self.decode_type_option_cell_str(type_cell_data.cell_str)?,
+                FieldType::SingleSelect | FieldType::MultiSelect => {
+                    let option_ids = SelectOptionIds::from_cell_str(&type_cell_data.cell_str)?;
+                    self.items_from_option_ids(&option_ids)
+                }
+                _ => ChecklistCellData::default(),
+            },
+        };
+        changeset.apply(&mut cell_data);
+        Ok((cell_data.to_string(), cell_data))
+    }
+}
+
+impl TypeOptionCellDataFilter for ChecklistTypeOptionPB {
+    fn apply_filter(
+        &self,
+        filter: &<Self as TypeOption>::CellFilter,
+        field_type: &FieldType,
+        cell_data: &<Self as TypeOption>::CellData,
+    ) -> bool {
+        if !field_type.is_check_list() {
+            return true;
+        }
+        filter.is_visible(cell_data)
+    }
+}
+
+impl TypeOptionCellDataCompare for ChecklistTypeOptionPB {
+    fn apply_cmp(
+        &self,
+        cell_data: &<Self as TypeOption>::CellData,
+        other_cell_data: &<Self as TypeOption>::CellData,
+    ) -> Ordering {
+        // The checklists that are done equally are ordered by the number of their items.
+        cell_data
+            .percentage()
+            .partial_cmp(&other_cell_data.percentage())
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| cell_data.items.len().cmp(&other_cell_data.items.len()))
+    }
+}
+
+#[derive(Default)]
+pub struct ChecklistTypeOptionBuilder(ChecklistTypeOptionPB);
+impl_into_box_type_option_builder!(ChecklistTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(ChecklistTypeOptionBuilder, ChecklistTypeOptionPB);
+impl ChecklistTypeOptionBuilder {
+    pub fn add_option(mut self, opt: SelectOptionPB) -> Self {
+        self.0.options.push(opt);
+        self
+    }
+}
+
+impl TypeOptionBuilder for ChecklistTypeOptionBuilder {
+    fn field_type(&self) -> FieldType {
+        FieldType::Checklist
+    }
+
+    fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+        &self.0
+    }
+}
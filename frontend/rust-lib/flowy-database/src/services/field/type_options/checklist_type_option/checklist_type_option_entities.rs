@@ -0,0 +1,369 @@
+use crate::entities::CellPathPB;
+use crate::services::cell::{
+    CellProtobufBlobParser, DecodedCellData, FromCellChangesetString, FromCellString, ToCellChangesetString,
+};
+use crate::services::field::{
+    gen_option_id, SelectOptionCellChangeset, SelectOptionCellDataPB, SelectOptionIds, SelectOptionPB,
+};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::{internal_error, FlowyResult};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChecklistItem {
+    pub id: String,
+    pub name: String,
+    pub done: bool,
+}
+
+impl ChecklistItem {
+    pub fn new(name: &str) -> Self {
+        Self {
+            id: gen_option_id(),
+            name: name.to_owned(),
+            done: false,
+        }
+    }
+}
+
+/// [ChecklistCellData] contains the items of a checklist cell. Each cell owns its items, so the
+/// cells of the same field can have different items. It's stored as a json string.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChecklistCellData {
+    pub items: Vec<ChecklistItem>,
+}
+
+impl ChecklistCellData {
+    /// The cells of the checklists that were created before the items were stored in the cells only
+    /// contain the ids of the done options. All the options of the type option are the items.
+    pub fn from_option_ids(options: &[SelectOptionPB], option_ids: &SelectOptionIds) -> Self {
+        let items = options
+            .iter()
+            .map(|option| ChecklistItem {
+                id: option.id.clone(),
+                name: option.name.clone(),
+                done: option_ids.contains(&option.id),
+            })
+            .collect();
+        Self { items }
+    }
+
+    /// Returns the percentage of the done items, from 0 to 1. It's 0 if there are no items.
+    pub fn percentage(&self) -> f64 {
+        if self.items.is_empty() {
+            return 0.0;
+        }
+        let done_count = self.items.iter().filter(|item| item.done).count();
+        done_count as f64 / self.items.len() as f64
+    }
+
+    /// Returns true if there is at least one item and all the items are done.
+    pub fn is_complete(&self) -> bool {
+        !self.items.is_empty() && self.items.iter().all(|item| item.done)
+    }
+
+    /// The checklist cell that is read as a select option cell. The items are the options, and the
+    /// done items are the selected options.
+    pub fn to_select_option_cell_data(&self) -> SelectOptionCellDataPB {
+        let to_option = |item: &ChecklistItem| SelectOptionPB {
+            id: item.id.clone(),
+            name: item.name.clone(),
+            color: Default::default(),
+        };
+        SelectOptionCellDataPB {
+            options: self.items.iter().map(to_option).collect(),
+            select_options: self.items.iter().filter(|item| item.done).map(to_option).collect(),
+        }
+    }
+
+    pub fn to_json(&self) -> FlowyResult<String> {
+        serde_json::to_string(self).map_err(internal_error)
+    }
+}
+
+impl FromCellString for ChecklistCellData {
+    fn from_cell_str(s: &str) -> FlowyResult<Self> {
+        serde_json::from_str::<ChecklistCellData>(s).map_err(internal_error)
+    }
+}
+
+impl ToString for ChecklistCellData {
+    fn to_string(&self) -> String {
+        self.to_json().unwrap()
+    }
+}
+
+impl DecodedCellData for ChecklistCellData {
+    type Object = ChecklistCellData;
+
+    fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct ChecklistItemPB {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    pub done: bool,
+}
+
+impl From<ChecklistItem> for ChecklistItemPB {
+    fn from(item: ChecklistItem) -> Self {
+        Self {
+            id: item.id,
+            name: item.name,
+            done: item.done,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct ChecklistCellDataPB {
+    #[pb(index = 1)]
+    pub items: Vec<ChecklistItemPB>,
+
+    /// The percentage of the done items, from 0 to 1.
+    #[pb(index = 2)]
+    pub percentage: f64,
+}
+
+impl From<ChecklistCellData> for ChecklistCellDataPB {
+    fn from(data: ChecklistCellData) -> Self {
+        let percentage = data.percentage();
+        Self {
+            items: data.items.into_iter().map(ChecklistItemPB::from).collect(),
+            percentage,
+        }
+    }
+}
+
+impl DecodedCellData for ChecklistCellDataPB {
+    type Object = ChecklistCellDataPB;
+
+    fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+pub struct ChecklistCellDataParser();
+impl CellProtobufBlobParser for ChecklistCellDataParser {
+    type Object = ChecklistCellDataPB;
+
+    fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+        ChecklistCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+    }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct ChecklistItemMovePB {
+    #[pb(index = 1)]
+    pub item_id: String,
+
+    #[pb(index = 2)]
+    pub to_index: i32,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct ChecklistCellChangesetPB {
+    #[pb(index = 1)]
+    pub cell_path: CellPathPB,
+
+    /// The names of the items that will be appended to the checklist.
+    #[pb(index = 2)]
+    pub insert_item_names: Vec<String>,
+
+    /// The items that will be renamed. Only the id and the name of each item are used.
+    #[pb(index = 3)]
+    pub rename_items: Vec<ChecklistItemPB>,
+
+    #[pb(index = 4)]
+    pub toggle_item_ids: Vec<String>,
+
+    #[pb(index = 5)]
+    pub delete_item_ids: Vec<String>,
+
+    #[pb(index = 6, one_of)]
+    pub move_item: Option<ChecklistItemMovePB>,
+}
+
+impl From<ChecklistCellChangesetPB> for ChecklistCellChangeset {
+    fn from(changeset: ChecklistCellChangesetPB) -> Self {
+        Self {
+            insert_items: changeset
+                .insert_item_names
+                .iter()
+                .map(|name| ChecklistItem::new(name))
+                .collect(),
+            rename_items: changeset
+                .rename_items
+                .into_iter()
+                .map(|item| ChecklistItemRename {
+                    item_id: item.id,
+                    name: item.name,
+                })
+                .collect(),
+            toggle_item_ids: changeset.toggle_item_ids,
+            delete_item_ids: changeset.delete_item_ids,
+            done_item_ids: vec![],
+            undone_item_ids: vec![],
+            move_item: changeset.move_item.map(|move_item| ChecklistItemMove {
+                item_id: move_item.item_id,
+                to_index: move_item.to_index.max(0) as usize,
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChecklistItemRename {
+    pub item_id: String,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChecklistItemMove {
+    pub item_id: String,
+    pub to_index: usize,
+}
+
+/// [ChecklistCellChangeset] changes the items of a checklist cell. The changes are applied in the
+/// order: delete, rename, toggle, done, undone, insert and then move.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChecklistCellChangeset {
+    #[serde(default)]
+    pub insert_items: Vec<ChecklistItem>,
+
+    #[serde(default)]
+    pub rename_items: Vec<ChecklistItemRename>,
+
+    #[serde(default)]
+    pub toggle_item_ids: Vec<String>,
+
+    #[serde(default)]
+    pub delete_item_ids: Vec<String>,
+
+    #[serde(default)]
+    pub done_item_ids: Vec<String>,
+
+    #[serde(default)]
+    pub undone_item_ids: Vec<String>,
+
+    /// Moves the item to the index. The index is clamped to the number of the items.
+    #[serde(default)]
+    pub move_item: Option<ChecklistItemMove>,
+}
+
+impl ChecklistCellChangeset {
+    pub fn from_insert_items(items: Vec<ChecklistItem>) -> Self {
+        Self {
+            insert_items: items,
+            ..Default::default()
+        }
+    }
+
+    pub fn from_rename_item(item_id: &str, name: &str) -> Self {
+        Self {
+            rename_items: vec![ChecklistItemRename {
+                item_id: item_id.to_owned(),
+                name: name.to_owned(),
+            }],
+            ..Default::default()
+        }
+    }
+
+    pub fn from_toggle_item_ids(item_ids: Vec<String>) -> Self {
+        Self {
+            toggle_item_ids: item_ids,
+            ..Default::default()
+        }
+    }
+
+    pub fn from_delete_item_ids(item_ids: Vec<String>) -> Self {
+        Self {
+            delete_item_ids: item_ids,
+            ..Default::default()
+        }
+    }
+
+    pub fn from_move_item(item_id: &str, to_index: usize) -> Self {
+        Self {
+            move_item: Some(ChecklistItemMove {
+                item_id: item_id.to_owned(),
+                to_index,
+            }),
+            ..Default::default()
+        }
+    }
+
+    pub fn apply(self, cell_data: &mut ChecklistCellData) {
+        cell_data.items.retain(|item| !self.delete_item_ids.contains(&item.id));
+
+        for rename_item in self.rename_items {
+            if let Some(item) = cell_data.items.iter_mut().find(|item| item.id == rename_item.item_id) {
+                item.name = rename_item.name;
+            }
+        }
+
+        for item in cell_data.items.iter_mut() {
+            if self.toggle_item_ids.contains(&item.id) {
+                item.done = !item.done;
+            }
+        }
+
+        for item in cell_data.items.iter_mut() {
+            if self.done_item_ids.contains(&item.id) {
+                item.done = true;
+            }
+            if self.undone_item_ids.contains(&item.id) {
+                item.done = false;
+            }
+        }
+
+        for insert_item in self.insert_items {
+            if !cell_data.items.iter().any(|item| item.id == insert_item.id) {
+                cell_data.items.push(insert_item);
+            }
+        }
+
+        if let Some(move_item) = self.move_item {
+            if let Some(index) = cell_data.items.iter().position(|item| item.id == move_item.item_id) {
+                let item = cell_data.items.remove(index);
+                let to_index = move_item.to_index.min(cell_data.items.len());
+                cell_data.items.insert(to_index, item);
+            }
+        }
+    }
+}
+
+impl FromCellChangesetString for ChecklistCellChangeset {
+    fn from_changeset(changeset: String) -> FlowyResult<Self>
+    where
+        Self: Sized,
+    {
+        // The checklist cells were edited with the select option changesets before the items were
+        // stored in the cells. The inserted options are the done items, and the deleted options are
+        // the undone items.
+        if let Ok(select_option_changeset) = serde_json::from_str::<SelectOptionCellChangeset>(&changeset) {
+            return Ok(Self {
+                done_item_ids: select_option_changeset.insert_option_ids,
+                undone_item_ids: select_option_changeset.delete_option_ids,
+                ..Default::default()
+            });
+        }
+        serde_json::from_str::<ChecklistCellChangeset>(&changeset).map_err(internal_error)
+    }
+}
+
+impl ToCellChangesetString for ChecklistCellChangeset {
+    fn to_cell_changeset_str(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
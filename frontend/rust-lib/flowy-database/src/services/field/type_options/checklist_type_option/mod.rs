@@ -0,0 +1,8 @@
+#![allow(clippy::module_inception)]
+mod checklist_filter;
+mod checklist_tests;
+mod checklist_type_option;
+mod checklist_type_option_entities;
+
+pub use checklist_type_option::*;
+pub use checklist_type_option_entities::*;
@@ -1,8 +1,17 @@
 use crate::entities::{DateFilterConditionPB, DateFilterPB};
-use chrono::NaiveDateTime;
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime};
 
 impl DateFilterPB {
+    /// The conditions that are relative to today are evaluated against the date of today in
+    /// local time.
     pub fn is_visible<T: Into<Option<i64>>>(&self, cell_timestamp: T) -> bool {
+        self.is_visible_at(cell_timestamp, Local::now().naive_local().date())
+    }
+
+    /// Same as [Self::is_visible], but the conditions that are relative to today are evaluated
+    /// against `today`. The date of the cell is its date in UTC, which is the date that the date
+    /// fields display.
+    pub fn is_visible_at<T: Into<Option<i64>>>(&self, cell_timestamp: T, today: NaiveDate) -> bool {
         match cell_timestamp.into() {
             None => DateFilterConditionPB::DateIsEmpty == self.condition,
             Some(timestamp) => {
@@ -18,6 +27,15 @@ impl DateFilterPB {
 
                 let cell_time = NaiveDateTime::from_timestamp_opt(timestamp, 0);
                 let cell_date = cell_time.map(|time| time.date());
+                if self.condition.is_relative() {
+                    return match relative_date_range(&self.condition, self.days, today) {
+                        None => true,
+                        Some((start_date, end_date)) => {
+                            cell_date.map_or(false, |cell_date| cell_date >= start_date && cell_date <= end_date)
+                        }
+                    };
+                }
+
                 match self.timestamp {
                     None => {
                         if self.start.is_none() {
@@ -56,10 +74,62 @@ impl DateFilterPB {
     }
 }
 
+/// Returns the first and the last date of the range of the condition that is relative to
+/// `today`, or None if the range is unknown, for example, the number of the days is not set.
+fn relative_date_range(
+    condition: &DateFilterConditionPB,
+    days: Option<i64>,
+    today: NaiveDate,
+) -> Option<(NaiveDate, NaiveDate)> {
+    match condition {
+        DateFilterConditionPB::DateIsToday => Some((today, today)),
+        DateFilterConditionPB::DateIsThisWeek => {
+            let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+            Some((monday, monday + Duration::days(6)))
+        }
+        DateFilterConditionPB::DateIsThisMonth => {
+            let first_date = today.with_day(1)?;
+            let first_date_of_next_month = match today.month() {
+                12 => NaiveDate::from_ymd_opt(today.year() + 1, 1, 1)?,
+                month => NaiveDate::from_ymd_opt(today.year(), month + 1, 1)?,
+            };
+            Some((first_date, first_date_of_next_month.pred_opt()?))
+        }
+        DateFilterConditionPB::DateIsInLastDays => {
+            let days = Duration::days(days?.clamp(0, i32::MAX as i64));
+            Some((today.checked_sub_signed(days).unwrap_or(NaiveDate::MIN), today))
+        }
+        DateFilterConditionPB::DateIsInNextDays => {
+            let days = Duration::days(days?.clamp(0, i32::MAX as i64));
+            Some((today, today.checked_add_signed(days).unwrap_or(NaiveDate::MAX)))
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::all)]
     use crate::entities::{DateFilterConditionPB, DateFilterPB};
+    use chrono::NaiveDate;
+
+    fn timestamp(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> i64 {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .and_hms_opt(hour, min, sec)
+            .unwrap()
+            .timestamp()
+    }
+
+    fn relative_filter(condition: DateFilterConditionPB, days: Option<i64>) -> DateFilterPB {
+        DateFilterPB {
+            condition,
+            start: None,
+            end: None,
+            timestamp: None,
+            days,
+        }
+    }
 
     #[test]
     fn date_filter_is_test() {
@@ -68,6 +138,7 @@ mod tests {
             timestamp: Some(1668387885),
             end: None,
             start: None,
+            days: None,
         };
 
         for (val, visible) in vec![(1668387885, true), (1647251762, false)] {
@@ -81,6 +152,7 @@ mod tests {
             timestamp: Some(1668387885),
             start: None,
             end: None,
+            days: None,
         };
 
         for (val, visible, msg) in vec![(1668387884, false, "1"), (1647251762, true, "2")] {
@@ -95,6 +167,7 @@ mod tests {
             timestamp: Some(1668387885),
             start: None,
             end: None,
+            days: None,
         };
 
         for (val, visible) in vec![(1668387884, true), (1668387885, true)] {
@@ -108,6 +181,7 @@ mod tests {
             timestamp: Some(1668387885),
             start: None,
             end: None,
+            days: None,
         };
 
         for (val, visible) in vec![(1668387888, false), (1668531885, true), (0, false)] {
@@ -122,6 +196,7 @@ mod tests {
             start: Some(1668272685), // 11/13
             end: Some(1668618285),   // 11/17
             timestamp: None,
+            days: None,
         };
 
         for (val, visible, _msg) in vec![
@@ -140,10 +215,106 @@ mod tests {
             start: None,
             end: None,
             timestamp: None,
+            days: None,
         };
 
         for (val, visible) in vec![(None, true), (Some(123), false)] {
             assert_eq!(filter.is_visible(val), visible);
         }
     }
+
+    #[test]
+    fn date_filter_is_today_across_midnight_test() {
+        let filter = relative_filter(DateFilterConditionPB::DateIsToday, None);
+        let today = NaiveDate::from_ymd_opt(2022, 11, 14).unwrap();
+        for (val, visible, msg) in vec![
+            (
+                timestamp(2022, 11, 13, 23, 59, 59),
+                false,
+                "the last second of yesterday",
+            ),
+            (timestamp(2022, 11, 14, 0, 0, 0), true, "midnight"),
+            (timestamp(2022, 11, 14, 23, 59, 59), true, "the last second of today"),
+            (timestamp(2022, 11, 15, 0, 0, 0), false, "the next midnight"),
+        ] {
+            assert_eq!(filter.is_visible_at(val, today), visible, "{}", msg);
+        }
+        assert!(!filter.is_visible_at(None, today));
+
+        // The row of today is hidden once the date changes.
+        let tomorrow = today.succ_opt().unwrap();
+        assert!(!filter.is_visible_at(timestamp(2022, 11, 14, 12, 0, 0), tomorrow));
+        assert!(filter.is_visible_at(timestamp(2022, 11, 15, 0, 0, 0), tomorrow));
+    }
+
+    #[test]
+    fn date_filter_is_this_week_test() {
+        let filter = relative_filter(DateFilterConditionPB::DateIsThisWeek, None);
+        // Wednesday
+        let today = NaiveDate::from_ymd_opt(2022, 11, 16).unwrap();
+        for (val, visible, msg) in vec![
+            (timestamp(2022, 11, 13, 23, 59, 59), false, "the last Sunday"),
+            (timestamp(2022, 11, 14, 0, 0, 0), true, "Monday"),
+            (timestamp(2022, 11, 20, 23, 59, 59), true, "Sunday"),
+            (timestamp(2022, 11, 21, 0, 0, 0), false, "the next Monday"),
+        ] {
+            assert_eq!(filter.is_visible_at(val, today), visible, "{}", msg);
+        }
+
+        // The week starts on Monday, even if today is Sunday.
+        let sunday = NaiveDate::from_ymd_opt(2022, 11, 20).unwrap();
+        assert!(filter.is_visible_at(timestamp(2022, 11, 14, 0, 0, 0), sunday));
+        assert!(!filter.is_visible_at(timestamp(2022, 11, 21, 0, 0, 0), sunday));
+    }
+
+    #[test]
+    fn date_filter_is_this_month_test() {
+        let filter = relative_filter(DateFilterConditionPB::DateIsThisMonth, None);
+        let today = NaiveDate::from_ymd_opt(2022, 12, 31).unwrap();
+        for (val, visible, msg) in vec![
+            (timestamp(2022, 11, 30, 23, 59, 59), false, "the last month"),
+            (timestamp(2022, 12, 1, 0, 0, 0), true, "the first day"),
+            (timestamp(2022, 12, 31, 23, 59, 59), true, "the last day"),
+            (timestamp(2023, 1, 1, 0, 0, 0), false, "the next year"),
+            (
+                timestamp(2021, 12, 15, 0, 0, 0),
+                false,
+                "the same month of the last year",
+            ),
+        ] {
+            assert_eq!(filter.is_visible_at(val, today), visible, "{}", msg);
+        }
+    }
+
+    #[test]
+    fn date_filter_is_in_last_days_test() {
+        let filter = relative_filter(DateFilterConditionPB::DateIsInLastDays, Some(7));
+        let today = NaiveDate::from_ymd_opt(2022, 11, 14).unwrap();
+        for (val, visible, msg) in vec![
+            (timestamp(2022, 11, 6, 23, 59, 59), false, "8 days ago"),
+            (timestamp(2022, 11, 7, 0, 0, 0), true, "7 days ago"),
+            (timestamp(2022, 11, 14, 23, 59, 59), true, "today"),
+            (timestamp(2022, 11, 15, 0, 0, 0), false, "tomorrow"),
+        ] {
+            assert_eq!(filter.is_visible_at(val, today), visible, "{}", msg);
+        }
+    }
+
+    #[test]
+    fn date_filter_is_in_next_days_test() {
+        let filter = relative_filter(DateFilterConditionPB::DateIsInNextDays, Some(30));
+        let today = NaiveDate::from_ymd_opt(2022, 11, 14).unwrap();
+        for (val, visible, msg) in vec![
+            (timestamp(2022, 11, 13, 23, 59, 59), false, "yesterday"),
+            (timestamp(2022, 11, 14, 0, 0, 0), true, "today"),
+            (timestamp(2022, 12, 14, 23, 59, 59), true, "30 days later"),
+            (timestamp(2022, 12, 15, 0, 0, 0), false, "31 days later"),
+        ] {
+            assert_eq!(filter.is_visible_at(val, today), visible, "{}", msg);
+        }
+
+        // The condition is ignored if the number of the days is not set.
+        let filter = relative_filter(DateFilterConditionPB::DateIsInNextDays, None);
+        assert!(filter.is_visible_at(timestamp(2021, 1, 1, 0, 0, 0), today));
+    }
 }
@@ -2,9 +2,9 @@ use crate::entities::{DateFilterPB, FieldType};
 use crate::impl_type_option;
 use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
 use crate::services::field::{
-    default_order, BoxTypeOptionBuilder, DateCellChangeset, DateCellData, DateCellDataPB, DateFormat, TimeFormat,
-    TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
-    TypeOptionTransform,
+    default_order, BoxTypeOptionBuilder, CellValidationRulePB, CellValidationViolation, DateCellChangeset,
+    DateCellData, DateCellDataPB, DateFormat, TimeFormat, TypeOption, TypeOptionBuilder, TypeOptionCellData,
+    TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform, TypedCellData,
 };
 use bytes::Bytes;
 use chrono::format::strftime::StrftimeItems;
@@ -26,6 +26,22 @@ pub struct DateTypeOptionPB {
 
     #[pb(index = 3)]
     pub include_time: bool,
+
+    /// The earliest timestamp that the cell accepts.
+    #[pb(index = 4, one_of)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_date: Option<i64>,
+
+    /// The latest timestamp that the cell accepts.
+    #[pb(index = 5, one_of)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_date: Option<i64>,
+
+    /// The timestamp of the cells of the new rows. The `today` is replaced with the date of the
+    /// day when the row is created.
+    #[pb(index = 6, one_of)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<String>,
 }
 impl_type_option!(DateTypeOptionPB, FieldType::DateTime);
 
@@ -44,6 +60,13 @@ impl TypeOptionCellData for DateTypeOptionPB {
     fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
         DateCellData::from_cell_str(&cell_str)
     }
+
+    fn convert_to_typed_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> TypedCellData {
+        TypedCellData::Date {
+            timestamp: cell_data.0,
+            include_time: self.include_time,
+        }
+    }
 }
 
 impl DateTypeOptionPB {
@@ -52,7 +75,7 @@ impl DateTypeOptionPB {
         Self::default()
     }
 
-    fn today_desc_from_timestamp<T: Into<i64>>(&self, timestamp: T) -> DateCellDataPB {
+    pub(crate) fn today_desc_from_timestamp<T: Into<i64>>(&self, timestamp: T) -> DateCellDataPB {
         let timestamp = timestamp.into();
         let native = chrono::NaiveDateTime::from_timestamp_opt(timestamp, 0);
         if native.is_none() {
@@ -181,6 +204,30 @@ impl CellDataChangeset for DateTypeOptionPB {
         let date_cell_data = DateCellData(Some(cell_data));
         Ok((date_cell_data.to_string(), date_cell_data))
     }
+
+    fn validate_cell_data(&self, cell_data: &<Self as TypeOption>::CellData) -> Option<CellValidationViolation> {
+        // The timestamp 0 is the empty date.
+        let timestamp = cell_data.0.filter(|timestamp| *timestamp != 0)?;
+        if let Some(min_date) = self.min_date {
+            if timestamp < min_date {
+                let msg = format!(
+                    "The date can't be earlier than {}",
+                    self.today_desc_from_timestamp(min_date).date
+                );
+                return Some(CellValidationViolation::new(CellValidationRulePB::DateMin, msg));
+            }
+        }
+        if let Some(max_date) = self.max_date {
+            if timestamp > max_date {
+                let msg = format!(
+                    "The date can't be later than {}",
+                    self.today_desc_from_timestamp(max_date).date
+                );
+                return Some(CellValidationViolation::new(CellValidationRulePB::DateMax, msg));
+            }
+        }
+        None
+    }
 }
 
 impl TypeOptionCellDataFilter for DateTypeOptionPB {
@@ -228,6 +275,16 @@ impl DateTypeOptionBuilder {
         self.0.time_format = time_format;
         self
     }
+
+    pub fn min_date(mut self, timestamp: i64) -> Self {
+        self.0.min_date = Some(timestamp);
+        self
+    }
+
+    pub fn max_date(mut self, timestamp: i64) -> Self {
+        self.0.max_date = Some(timestamp);
+        self
+    }
 }
 impl TypeOptionBuilder for DateTypeOptionBuilder {
     fn field_type(&self) -> FieldType {
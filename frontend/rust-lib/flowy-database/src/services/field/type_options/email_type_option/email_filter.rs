@@ -0,0 +1,57 @@
+use crate::entities::{EmailFilterConditionPB, EmailFilterPB};
+use crate::services::field::EmailCellData;
+
+impl EmailFilterPB {
+    pub fn is_visible(&self, cell_data: &EmailCellData) -> bool {
+        let email = cell_data.normalized();
+        let content = self.content.trim().to_lowercase();
+        match self.condition {
+            EmailFilterConditionPB::Is => email == content,
+            EmailFilterConditionPB::IsNot => email != content,
+            EmailFilterConditionPB::Contains => email.contains(&content),
+            EmailFilterConditionPB::DoesNotContain => !email.contains(&content),
+            EmailFilterConditionPB::EmailIsEmpty => email.is_empty(),
+            EmailFilterConditionPB::EmailIsNotEmpty => !email.is_empty(),
+            EmailFilterConditionPB::EmailIsInvalid => !email.is_empty() && !cell_data.is_valid(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::{EmailFilterConditionPB, EmailFilterPB};
+    use crate::services::field::EmailCellData;
+
+    #[test]
+    fn email_filter_is_test() {
+        let email_filter = EmailFilterPB {
+            condition: EmailFilterConditionPB::Is,
+            content: "Lucas@AppFlowy.io".to_owned(),
+        };
+        assert!(email_filter.is_visible(&EmailCellData::new("lucas@appflowy.io")));
+        assert!(!email_filter.is_visible(&EmailCellData::new("nathan@appflowy.io")));
+        assert!(!email_filter.is_visible(&EmailCellData::new("")));
+    }
+
+    #[test]
+    fn email_filter_contains_test() {
+        let email_filter = EmailFilterPB {
+            condition: EmailFilterConditionPB::Contains,
+            content: "appflowy".to_owned(),
+        };
+        assert!(email_filter.is_visible(&EmailCellData::new("Lucas@AppFlowy.io")));
+        assert!(!email_filter.is_visible(&EmailCellData::new("annie@example.com")));
+    }
+
+    #[test]
+    fn email_filter_is_invalid_test() {
+        let email_filter = EmailFilterPB {
+            condition: EmailFilterConditionPB::EmailIsInvalid,
+            content: "".to_owned(),
+        };
+        assert!(email_filter.is_visible(&EmailCellData::new("lucas")));
+        assert!(!email_filter.is_visible(&EmailCellData::new("lucas@appflowy.io")));
+        // The empty cells are not invalid.
+        assert!(!email_filter.is_visible(&EmailCellData::new("")));
+    }
+}
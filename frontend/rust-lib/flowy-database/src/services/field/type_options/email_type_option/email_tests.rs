@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use crate::entities::FieldType;
+    use crate::services::cell::CellDataChangeset;
+    use crate::services::field::{
+        EmailCellData, EmailTypeOptionPB, FieldBuilder, TypeOptionCellDataCompare, TypeOptionTransform, URLCellData,
+        URLTypeOptionPB,
+    };
+    use std::cmp::Ordering;
+
+    #[test]
+    fn email_type_option_changeset_test() {
+        let type_option = EmailTypeOptionPB::default();
+        let (cell_str, cell_data) = type_option
+            .apply_changeset(" Lucas@AppFlowy.io ".to_owned(), None)
+            .unwrap();
+        assert_eq!(cell_str, "Lucas@AppFlowy.io");
+        assert_eq!(cell_data.normalized(), "lucas@appflowy.io");
+
+        let (cell_str, _) = type_option.apply_changeset("".to_owned(), None).unwrap();
+        assert_eq!(cell_str, "");
+
+        assert!(type_option.apply_changeset("lucas".to_owned(), None).is_err());
+        assert!(type_option.apply_changeset("lucas@".to_owned(), None).is_err());
+        assert!(type_option.apply_changeset("lucas@appflowy".to_owned(), None).is_err());
+    }
+
+    #[test]
+    fn email_type_option_transform_test() {
+        let type_option = EmailTypeOptionPB::default();
+        let field_rev = FieldBuilder::from_field_type(&FieldType::Email).build();
+
+        // The text that is not a valid email is kept.
+        let cell_data = type_option
+            .transform_type_option_cell_str("hello world", &FieldType::RichText, &field_rev)
+            .unwrap();
+        assert_eq!(cell_data.0, "hello world");
+        assert!(!cell_data.is_valid());
+
+        let url_cell_data = URLCellData {
+            url: "mailto:lucas@appflowy.io".to_owned(),
+            content: "mailto:lucas@appflowy.io".to_owned(),
+        };
+        let cell_data = type_option
+            .transform_type_option_cell_str(&url_cell_data.to_string(), &FieldType::URL, &field_rev)
+            .unwrap();
+        assert_eq!(cell_data.0, "lucas@appflowy.io");
+        assert!(cell_data.is_valid());
+    }
+
+    #[test]
+    fn url_type_option_transform_from_email_test() {
+        let type_option = URLTypeOptionPB::default();
+        let field_rev = FieldBuilder::from_field_type(&FieldType::URL).build();
+        let cell_data = type_option
+            .transform_type_option_cell_str("lucas@appflowy.io", &FieldType::Email, &field_rev)
+            .unwrap();
+        assert_eq!(cell_data.url, "mailto:lucas@appflowy.io");
+        assert_eq!(cell_data.content, "lucas@appflowy.io");
+    }
+
+    #[test]
+    fn email_type_option_compare_test() {
+        let type_option = EmailTypeOptionPB::default();
+        let left = EmailCellData::new("Lucas@AppFlowy.io");
+        let right = EmailCellData::new("lucas@appflowy.io");
+        assert_eq!(type_option.apply_cmp(&left, &right), Ordering::Equal);
+        assert_eq!(
+            type_option.apply_cmp(&EmailCellData::new("annie@example.com"), &right),
+            Ordering::Less
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use crate::entities::{EmailFilterPB, FieldType};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+    BoxTypeOptionBuilder, EmailCellChangeset, EmailCellData, EmailCellDataPB, TypeOption, TypeOptionBuilder,
+    TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform, TypedCellData,
+    URLCellData,
+};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::{FlowyError, FlowyResult};
+use grid_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// The prefix of the url that is converted from the email.
+pub const MAILTO_SCHEME: &str = "mailto:";
+
+#[derive(Default)]
+pub struct EmailTypeOptionBuilder(EmailTypeOptionPB);
+impl_into_box_type_option_builder!(EmailTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(EmailTypeOptionBuilder, EmailTypeOptionPB);
+
+impl TypeOptionBuilder for EmailTypeOptionBuilder {
+    fn field_type(&self) -> FieldType {
+        FieldType::Email
+    }
+
+    fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+        &self.0
+    }
+}
+
+// Email
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct EmailTypeOptionPB {
+    #[pb(index = 1)]
+    #[serde(default)]
+    data: String,
+}
+impl_type_option!(EmailTypeOptionPB, FieldType::Email);
+
+impl TypeOption for EmailTypeOptionPB {
+    type CellData = EmailCellData;
+    type CellChangeset = EmailCellChangeset;
+    type CellProtobufType = EmailCellDataPB;
+    type CellFilter = EmailFilterPB;
+}
+
+impl TypeOptionTransform for EmailTypeOptionPB {
+    fn transformable(&self) -> bool {
+        true
+    }
+
+    fn transform_type_option_cell_str(
+        &self,
+        cell_str: &str,
+        decoded_field_type: &FieldType,
+        _field_rev: &FieldRevision,
+    ) -> Option<<Self as TypeOption>::CellData> {
+        if decoded_field_type.is_text() {
+            // The text is kept even if it's not a valid email.
+            EmailCellData::from_cell_str(cell_str).ok()
+        } else if decoded_field_type.is_url() {
+            let content = URLCellData::from_cell_str(cell_str).ok()?.content;
+            let content = content.trim();
+            Some(EmailCellData::new(
+                content.strip_prefix(MAILTO_SCHEME).unwrap_or(content),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+impl TypeOptionCellData for EmailTypeOptionPB {
+    fn convert_to_protobuf(&self, cell_data: <Self as TypeOption>::CellData) -> <Self as TypeOption>::CellProtobufType {
+        cell_data.into()
+    }
+
+    fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
+        EmailCellData::from_cell_str(&cell_str)
+    }
+
+    fn convert_to_typed_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> TypedCellData {
+        TypedCellData::Email(cell_data.0)
+    }
+}
+
+impl CellDataDecoder for EmailTypeOptionPB {
+    fn decode_cell_str(
+        &self,
+        cell_str: String,
+        decoded_field_type: &FieldType,
+        _field_rev: &FieldRevision,
+    ) -> FlowyResult<<Self as TypeOption>::CellData> {
+        if !decoded_field_type.is_email() {
+            return Ok(Default::default());
+        }
+
+        self.decode_type_option_cell_str(cell_str)
+    }
+
+    fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+        cell_data.0
+    }
+}
+
+impl CellDataChangeset for EmailTypeOptionPB {
+    fn apply_changeset(
+        &self,
+        changeset: <Self as TypeOption>::CellChangeset,
+        _type_cell_data: Option<TypeCellData>,
+    ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+        // The empty changeset clears the cell.
+        let cell_data = EmailCellData::new(&changeset);
+        if !cell_data.0.is_empty() && !cell_data.is_valid() {
+            return Err(FlowyError::invalid_params().context(format!("Invalid email: {}", changeset)));
+        }
+        Ok((cell_data.to_string(), cell_data))
+    }
+}
+
+impl TypeOptionCellDataFilter for EmailTypeOptionPB {
+    fn apply_filter(
+        &self,
+        filter: &<Self as TypeOption>::CellFilter,
+        field_type: &FieldType,
+        cell_data: &<Self as TypeOption>::CellData,
+    ) -> bool {
+        if !field_type.is_email() {
+            return true;
+        }
+
+        filter.is_visible(cell_data)
+    }
+}
+
+impl TypeOptionCellDataCompare for EmailTypeOptionPB {
+    fn apply_cmp(
+        &self,
+        cell_data: &<Self as TypeOption>::CellData,
+        other_cell_data: &<Self as TypeOption>::CellData,
+    ) -> Ordering {
+        cell_data.normalized().cmp(&other_cell_data.normalized())
+    }
+}
@@ -0,0 +1,110 @@
+use crate::services::cell::{CellProtobufBlobParser, DecodedCellData, FromCellString};
+use bytes::Bytes;
+use fancy_regex::Regex;
+use flowy_derive::ProtoBuf;
+use flowy_error::{internal_error, FlowyResult};
+use lazy_static::lazy_static;
+
+/// [EmailCellData] is the email of the cell. It's stored as a plain string, so the text cells can
+/// be read as the email cells directly. The cells that were converted from other fields may not be
+/// valid emails.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EmailCellData(pub String);
+
+impl EmailCellData {
+    pub fn new(s: &str) -> Self {
+        Self(s.trim().to_owned())
+    }
+
+    /// Returns the lowercase form of the email, which is used to sort the cells and to detect the
+    /// duplicate emails.
+    pub fn normalized(&self) -> String {
+        self.0.to_lowercase()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        is_valid_email(&self.0)
+    }
+}
+
+impl FromCellString for EmailCellData {
+    fn from_cell_str(s: &str) -> FlowyResult<Self>
+    where
+        Self: Sized,
+    {
+        Ok(Self::new(s))
+    }
+}
+
+impl ToString for EmailCellData {
+    fn to_string(&self) -> String {
+        self.0.clone()
+    }
+}
+
+impl AsRef<str> for EmailCellData {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl DecodedCellData for EmailCellData {
+    type Object = EmailCellData;
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct EmailCellDataPB {
+    #[pb(index = 1)]
+    pub email: String,
+
+    /// The lowercase form of the email.
+    #[pb(index = 2)]
+    pub normalized: String,
+
+    #[pb(index = 3)]
+    pub is_valid: bool,
+}
+
+impl From<EmailCellData> for EmailCellDataPB {
+    fn from(data: EmailCellData) -> Self {
+        Self {
+            normalized: data.normalized(),
+            is_valid: data.is_valid(),
+            email: data.0,
+        }
+    }
+}
+
+impl DecodedCellData for EmailCellDataPB {
+    type Object = EmailCellDataPB;
+
+    fn is_empty(&self) -> bool {
+        self.email.is_empty()
+    }
+}
+
+pub struct EmailCellDataParser();
+impl CellProtobufBlobParser for EmailCellDataParser {
+    type Object = EmailCellDataPB;
+
+    fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+        EmailCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+    }
+}
+
+pub type EmailCellChangeset = String;
+
+pub fn is_valid_email(s: &str) -> bool {
+    EMAIL_REGEX.is_match(s).unwrap_or(false)
+}
+
+lazy_static! {
+    static ref EMAIL_REGEX: Regex = Regex::new(
+        r"^[a-zA-Z0-9.!#$%&'*+/=?^_`{|}~-]+@[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)+$"
+    )
+    .unwrap();
+}
@@ -0,0 +1,8 @@
+#![allow(clippy::module_inception)]
+mod email_filter;
+mod email_tests;
+mod email_type_option;
+mod email_type_option_entities;
+
+pub use email_type_option::*;
+pub use email_type_option_entities::*;
@@ -0,0 +1,529 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+/// [FormulaValue] is the result of evaluating a formula expression.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum FormulaValue {
+    Number(f64),
+    Text(String),
+    Bool(bool),
+}
+
+impl FormulaValue {
+    fn type_name(&self) -> &'static str {
+        match self {
+            FormulaValue::Number(_) => "number",
+            FormulaValue::Text(_) => "text",
+            FormulaValue::Bool(_) => "boolean",
+        }
+    }
+
+    fn as_number(&self) -> FormulaResult<f64> {
+        match self {
+            FormulaValue::Number(num) => Ok(*num),
+            _ => Err(FormulaError::new(format!(
+                "Expected a number, got a {}",
+                self.type_name()
+            ))),
+        }
+    }
+
+    fn as_bool(&self) -> FormulaResult<bool> {
+        match self {
+            FormulaValue::Bool(value) => Ok(*value),
+            _ => Err(FormulaError::new(format!(
+                "Expected a boolean, got a {}",
+                self.type_name()
+            ))),
+        }
+    }
+
+    /// Compares two values of the same type. The numbers come first, then the texts and the
+    /// booleans if the types are different.
+    pub fn cmp_value(&self, other: &FormulaValue) -> Ordering {
+        match (self, other) {
+            (FormulaValue::Number(left), FormulaValue::Number(right)) => {
+                left.partial_cmp(right).unwrap_or(Ordering::Equal)
+            }
+            (FormulaValue::Text(left), FormulaValue::Text(right)) => left.cmp(right),
+            (FormulaValue::Bool(left), FormulaValue::Bool(right)) => left.cmp(right),
+            _ => self.type_order().cmp(&other.type_order()),
+        }
+    }
+
+    fn type_order(&self) -> u8 {
+        match self {
+            FormulaValue::Number(_) => 0,
+            FormulaValue::Text(_) => 1,
+            FormulaValue::Bool(_) => 2,
+        }
+    }
+}
+
+impl Display for FormulaValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormulaValue::Number(num) => {
+                if num.fract() == 0.0 && num.abs() < 1e15 {
+                    write!(f, "{}", *num as i64)
+                } else {
+                    write!(f, "{}", num)
+                }
+            }
+            FormulaValue::Text(s) => write!(f, "{}", s),
+            FormulaValue::Bool(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormulaError {
+    pub msg: String,
+}
+
+impl FormulaError {
+    pub fn new<T: Into<String>>(msg: T) -> Self {
+        Self { msg: msg.into() }
+    }
+}
+
+impl Display for FormulaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.msg)
+    }
+}
+
+pub type FormulaResult<T> = Result<T, FormulaError>;
+
+/// Provides the values of the fields referenced by `prop("name")` in the expression.
+pub trait FormulaContext {
+    fn prop(&self, field_name: &str) -> FormulaResult<FormulaValue>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FormulaOperator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum FormulaExpr {
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    /// The value of the field with the name, `prop("name")`.
+    Prop(String),
+    Neg(Box<FormulaExpr>),
+    Binary {
+        op: FormulaOperator,
+        left: Box<FormulaExpr>,
+        right: Box<FormulaExpr>,
+    },
+    Call {
+        name: String,
+        args: Vec<FormulaExpr>,
+    },
+}
+
+impl FormulaExpr {
+    /// Parses the expression. It supports the number, string and boolean literals, the arithmetic
+    /// operators `+ - * / %`, the comparison operators `== != < <= > >=`, the parentheses and the
+    /// functions `prop`, `if`, `round`, `concat` and `length`.
+    pub fn parse(s: &str) -> FormulaResult<FormulaExpr> {
+        let tokens = tokenize(s)?;
+        let mut parser = FormulaParser { tokens, pos: 0 };
+        let expr = parser.parse_comparison()?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some(token) => Err(FormulaError::new(format!("Unexpected token: {:?}", token))),
+        }
+    }
+
+    /// Returns the names of the fields referenced by the expression.
+    pub fn referenced_field_names(&self) -> Vec<String> {
+        let mut names = vec![];
+        self.collect_field_names(&mut names);
+        names
+    }
+
+    fn collect_field_names(&self, names: &mut Vec<String>) {
+        match self {
+            FormulaExpr::Prop(name) => {
+                if !names.contains(name) {
+                    names.push(name.clone());
+                }
+            }
+            FormulaExpr::Neg(expr) => expr.collect_field_names(names),
+            FormulaExpr::Binary { left, right, .. } => {
+                left.collect_field_names(names);
+                right.collect_field_names(names);
+            }
+            FormulaExpr::Call { args, .. } => args.iter().for_each(|arg| arg.collect_field_names(names)),
+            FormulaExpr::Number(_) | FormulaExpr::Text(_) | FormulaExpr::Bool(_) => {}
+        }
+    }
+
+    pub fn evaluate(&self, context: &dyn FormulaContext) -> FormulaResult<FormulaValue> {
+        match self {
+            FormulaExpr::Number(num) => Ok(FormulaValue::Number(*num)),
+            FormulaExpr::Text(s) => Ok(FormulaValue::Text(s.clone())),
+            FormulaExpr::Bool(value) => Ok(FormulaValue::Bool(*value)),
+            FormulaExpr::Prop(name) => context.prop(name),
+            FormulaExpr::Neg(expr) => Ok(FormulaValue::Number(-expr.evaluate(context)?.as_number()?)),
+            FormulaExpr::Binary { op, left, right } => {
+                let left = left.evaluate(context)?;
+                let right = right.evaluate(context)?;
+                evaluate_binary(*op, left, right)
+            }
+            FormulaExpr::Call { name, args } => evaluate_call(name, args, context),
+        }
+    }
+}
+
+fn evaluate_binary(op: FormulaOperator, left: FormulaValue, right: FormulaValue) -> FormulaResult<FormulaValue> {
+    match op {
+        FormulaOperator::Add => match (&left, &right) {
+            (FormulaValue::Number(left), FormulaValue::Number(right)) => Ok(FormulaValue::Number(left + right)),
+            (FormulaValue::Text(_), _) | (_, FormulaValue::Text(_)) => {
+                Ok(FormulaValue::Text(format!("{}{}", left, right)))
+            }
+            _ => Err(FormulaError::new(format!(
+                "Can't add a {} to a {}",
+                right.type_name(),
+                left.type_name()
+            ))),
+        },
+        FormulaOperator::Sub => Ok(FormulaValue::Number(left.as_number()? - right.as_number()?)),
+        FormulaOperator::Mul => Ok(FormulaValue::Number(left.as_number()? * right.as_number()?)),
+        FormulaOperator::Div | FormulaOperator::Rem => {
+            let (left, right) = (left.as_number()?, right.as_number()?);
+            if right == 0.0 {
+                return Err(FormulaError::new("Division by zero"));
+            }
+            if op == FormulaOperator::Div {
+                Ok(FormulaValue::Number(left / right))
+            } else {
+                Ok(FormulaValue::Number(left % right))
+            }
+        }
+        FormulaOperator::Eq => Ok(FormulaValue::Bool(left == right)),
+        FormulaOperator::NotEq => Ok(FormulaValue::Bool(left != right)),
+        FormulaOperator::Lt | FormulaOperator::LtEq | FormulaOperator::Gt | FormulaOperator::GtEq => {
+            if left.type_order() != right.type_order() {
+                return Err(FormulaError::new(format!(
+                    "Can't compare a {} with a {}",
+                    left.type_name(),
+                    right.type_name()
+                )));
+            }
+            let ordering = left.cmp_value(&right);
+            let value = match op {
+                FormulaOperator::Lt => ordering == Ordering::Less,
+                FormulaOperator::LtEq => ordering != Ordering::Greater,
+                FormulaOperator::Gt => ordering == Ordering::Greater,
+                _ => ordering != Ordering::Less,
+            };
+            Ok(FormulaValue::Bool(value))
+        }
+    }
+}
+
+fn evaluate_call(name: &str, args: &[FormulaExpr], context: &dyn FormulaContext) -> FormulaResult<FormulaValue> {
+    let check_args_len = |min: usize, max: usize| {
+        if !(min..=max).contains(&args.len()) {
+            Err(FormulaError::new(format!(
+                "The function {} takes {} arguments, got {}",
+                name,
+                if min == max {
+                    min.to_string()
+                } else {
+                    format!("{} to {}", min, max)
+                },
+                args.len()
+            )))
+        } else {
+            Ok(())
+        }
+    };
+
+    match name {
+        "if" => {
+            check_args_len(3, 3)?;
+            // Only the chosen branch is evaluated.
+            if args[0].evaluate(context)?.as_bool()? {
+                args[1].evaluate(context)
+            } else {
+                args[2].evaluate(context)
+            }
+        }
+        "round" => {
+            check_args_len(1, 2)?;
+            let num = args[0].evaluate(context)?.as_number()?;
+            let digits = match args.get(1) {
+                None => 0,
+                Some(arg) => arg.evaluate(context)?.as_number()? as i32,
+            };
+            let factor = 10_f64.powi(digits);
+            Ok(FormulaValue::Number((num * factor).round() / factor))
+        }
+        "concat" => {
+            let mut s = String::new();
+            for arg in args {
+                s.push_str(&arg.evaluate(context)?.to_string());
+            }
+            Ok(FormulaValue::Text(s))
+        }
+        "length" => {
+            check_args_len(1, 1)?;
+            let s = args[0].evaluate(context)?.to_string();
+            Ok(FormulaValue::Number(s.chars().count() as f64))
+        }
+        _ => Err(FormulaError::new(format!("Unknown function: {}", name))),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum FormulaToken {
+    Number(f64),
+    Text(String),
+    Ident(String),
+    Operator(FormulaOperator),
+    LeftParen,
+    RightParen,
+    Comma,
+}
+
+fn tokenize(s: &str) -> FormulaResult<Vec<FormulaToken>> {
+    let mut tokens = vec![];
+    let mut chars = s.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '0'..='9' | '.' => {
+                let mut num = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_ascii_digit() || c == '.' {
+                        num.push(c);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                let num = num
+                    .parse::<f64>()
+                    .map_err(|_| FormulaError::new(format!("Invalid number: {}", num)))?;
+                tokens.push(FormulaToken::Number(num));
+            }
+            '"' => {
+                chars.next();
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        None => return Err(FormulaError::new("Unterminated string")),
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            None => return Err(FormulaError::new("Unterminated string")),
+                            Some(c) => text.push(c),
+                        },
+                        Some(c) => text.push(c),
+                    }
+                }
+                tokens.push(FormulaToken::Text(text));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut ident = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_alphanumeric() || c == '_' {
+                        ident.push(c);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(FormulaToken::Ident(ident));
+            }
+            _ => {
+                chars.next();
+                let token = match (c, chars.peek()) {
+                    ('=', Some('=')) => Some(FormulaOperator::Eq),
+                    ('!', Some('=')) => Some(FormulaOperator::NotEq),
+                    ('<', Some('=')) => Some(FormulaOperator::LtEq),
+                    ('>', Some('=')) => Some(FormulaOperator::GtEq),
+                    _ => None,
+                };
+                let token = match token {
+                    Some(op) => {
+                        chars.next();
+                        FormulaToken::Operator(op)
+                    }
+                    None => match c {
+                        '+' => FormulaToken::Operator(FormulaOperator::Add),
+                        '-' => FormulaToken::Operator(FormulaOperator::Sub),
+                        '*' => FormulaToken::Operator(FormulaOperator::Mul),
+                        '/' => FormulaToken::Operator(FormulaOperator::Div),
+                        '%' => FormulaToken::Operator(FormulaOperator::Rem),
+                        '<' => FormulaToken::Operator(FormulaOperator::Lt),
+                        '>' => FormulaToken::Operator(FormulaOperator::Gt),
+                        '(' => FormulaToken::LeftParen,
+                        ')' => FormulaToken::RightParen,
+                        ',' => FormulaToken::Comma,
+                        _ => return Err(FormulaError::new(format!("Unexpected character: {}", c))),
+                    },
+                };
+                tokens.push(token);
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct FormulaParser {
+    tokens: Vec<FormulaToken>,
+    pos: usize,
+}
+
+impl FormulaParser {
+    fn peek(&self) -> Option<&FormulaToken> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<FormulaToken> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: FormulaToken) -> FormulaResult<()> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            Some(token) => Err(FormulaError::new(format!("Expected {:?}, got {:?}", expected, token))),
+            None => Err(FormulaError::new(format!("Expected {:?}", expected))),
+        }
+    }
+
+    fn peek_operator(&self, operators: &[FormulaOperator]) -> Option<FormulaOperator> {
+        match self.peek() {
+            Some(FormulaToken::Operator(op)) if operators.contains(op) => Some(*op),
+            _ => None,
+        }
+    }
+
+    fn parse_comparison(&mut self) -> FormulaResult<FormulaExpr> {
+        let left = self.parse_additive()?;
+        let operators = [
+            FormulaOperator::Eq,
+            FormulaOperator::NotEq,
+            FormulaOperator::Lt,
+            FormulaOperator::LtEq,
+            FormulaOperator::Gt,
+            FormulaOperator::GtEq,
+        ];
+        match self.peek_operator(&operators) {
+            None => Ok(left),
+            Some(op) => {
+                self.next();
+                let right = self.parse_additive()?;
+                Ok(FormulaExpr::Binary {
+                    op,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                })
+            }
+        }
+    }
+
+    fn parse_additive(&mut self) -> FormulaResult<FormulaExpr> {
+        let mut left = self.parse_multiplicative()?;
+        while let Some(op) = self.peek_operator(&[FormulaOperator::Add, FormulaOperator::Sub]) {
+            self.next();
+            let right = self.parse_multiplicative()?;
+            left = FormulaExpr::Binary {
+                op,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+        Ok(left)
+    }
+
+    fn parse_multiplicative(&mut self) -> FormulaResult<FormulaExpr> {
+        let mut left = self.parse_unary()?;
+        while let Some(op) = self.peek_operator(&[FormulaOperator::Mul, FormulaOperator::Div, FormulaOperator::Rem]) {
+            self.next();
+            let right = self.parse_unary()?;
+            left = FormulaExpr::Binary {
+                op,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> FormulaResult<FormulaExpr> {
+        if self.peek_operator(&[FormulaOperator::Sub]).is_some() {
+            self.next();
+            return Ok(FormulaExpr::Neg(Box::new(self.parse_unary()?)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> FormulaResult<FormulaExpr> {
+        match self.next() {
+            Some(FormulaToken::Number(num)) => Ok(FormulaExpr::Number(num)),
+            Some(FormulaToken::Text(s)) => Ok(FormulaExpr::Text(s)),
+            Some(FormulaToken::LeftParen) => {
+                let expr = self.parse_comparison()?;
+                self.expect(FormulaToken::RightParen)?;
+                Ok(expr)
+            }
+            Some(FormulaToken::Ident(ident)) => match ident.as_str() {
+                "true" => Ok(FormulaExpr::Bool(true)),
+                "false" => Ok(FormulaExpr::Bool(false)),
+                _ => {
+                    let args = self.parse_args()?;
+                    if ident == "prop" {
+                        return match args.as_slice() {
+                            [FormulaExpr::Text(name)] => Ok(FormulaExpr::Prop(name.clone())),
+                            _ => Err(FormulaError::new("The function prop takes the name of a field")),
+                        };
+                    }
+                    Ok(FormulaExpr::Call { name: ident, args })
+                }
+            },
+            Some(token) => Err(FormulaError::new(format!("Unexpected token: {:?}", token))),
+            None => Err(FormulaError::new("Unexpected end of the formula")),
+        }
+    }
+
+    fn parse_args(&mut self) -> FormulaResult<Vec<FormulaExpr>> {
+        self.expect(FormulaToken::LeftParen)?;
+        let mut args = vec![];
+        if self.peek() == Some(&FormulaToken::RightParen) {
+            self.next();
+            return Ok(args);
+        }
+        loop {
+            args.push(self.parse_comparison()?);
+            match self.next() {
+                Some(FormulaToken::Comma) => continue,
+                Some(FormulaToken::RightParen) => return Ok(args),
+                Some(token) => return Err(FormulaError::new(format!("Unexpected token: {:?}", token))),
+                None => return Err(FormulaError::new("Expected )")),
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+#[cfg(test)]
+mod tests {
+    use crate::entities::FieldType;
+    use crate::services::cell::{insert_number_cell, insert_text_cell, CellDataChangeset, FromCellString};
+    use crate::services::field::*;
+    use grid_model::{FieldRevision, RowRevision};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    struct MockFormulaContext(HashMap<&'static str, FormulaValue>);
+
+    impl FormulaContext for MockFormulaContext {
+        fn prop(&self, field_name: &str) -> FormulaResult<FormulaValue> {
+            self.0
+                .get(field_name)
+                .cloned()
+                .ok_or_else(|| FormulaError::new(format!("Can't find the field: {}", field_name)))
+        }
+    }
+
+    fn evaluate(s: &str) -> FormulaResult<FormulaValue> {
+        let context = MockFormulaContext(HashMap::from([
+            ("Price", FormulaValue::Number(2.5)),
+            ("Qty", FormulaValue::Number(4.0)),
+            ("Name", FormulaValue::Text("Apple".to_owned())),
+            ("Done", FormulaValue::Bool(true)),
+        ]));
+        FormulaExpr::parse(s)?.evaluate(&context)
+    }
+
+    #[test]
+    fn formula_arithmetic_test() {
+        assert_eq!(evaluate("1 + 2 * 3").unwrap(), FormulaValue::Number(7.0));
+        assert_eq!(evaluate("(1 + 2) * 3").unwrap(), FormulaValue::Number(9.0));
+        assert_eq!(evaluate("-2 + 7 % 4").unwrap(), FormulaValue::Number(1.0));
+        assert_eq!(
+            evaluate(r#"prop("Price") * prop("Qty")"#).unwrap(),
+            FormulaValue::Number(10.0)
+        );
+        assert!(evaluate("1 / 0").is_err());
+        assert!(evaluate("true * 2").is_err());
+    }
+
+    #[test]
+    fn formula_comparison_test() {
+        assert_eq!(evaluate(r#"prop("Price") > 2"#).unwrap(), FormulaValue::Bool(true));
+        assert_eq!(evaluate(r#""a" < "b""#).unwrap(), FormulaValue::Bool(true));
+        assert_eq!(evaluate(r#"prop("Done") == true"#).unwrap(), FormulaValue::Bool(true));
+        assert_eq!(evaluate(r#"1 != "1""#).unwrap(), FormulaValue::Bool(true));
+        assert!(evaluate(r#"1 < "1""#).is_err());
+    }
+
+    #[test]
+    fn formula_text_test() {
+        assert_eq!(
+            evaluate(r#"prop("Name") + " x" + prop("Qty")"#).unwrap(),
+            FormulaValue::Text("Apple x4".to_owned())
+        );
+        assert_eq!(
+            evaluate(r#"concat(prop("Name"), "-", 1, true)"#).unwrap(),
+            FormulaValue::Text("Apple-1true".to_owned())
+        );
+        assert_eq!(evaluate(r#"length(prop("Name"))"#).unwrap(), FormulaValue::Number(5.0));
+    }
+
+    #[test]
+    fn formula_function_test() {
+        assert_eq!(
+            evaluate(r#"if(prop("Done"), "yes", 1 / 0)"#).unwrap(),
+            FormulaValue::Text("yes".to_owned())
+        );
+        assert_eq!(evaluate("round(2.567, 2)").unwrap(), FormulaValue::Number(2.57));
+        assert_eq!(evaluate("round(2.5)").unwrap(), FormulaValue::Number(3.0));
+        assert!(evaluate("if(1, 2, 3)").is_err());
+        assert!(evaluate("round()").is_err());
+        assert!(evaluate("unknown(1)").is_err());
+    }
+
+    #[test]
+    fn formula_parse_error_test() {
+        assert!(FormulaExpr::parse("1 +").is_err());
+        assert!(FormulaExpr::parse("(1 + 2").is_err());
+        assert!(FormulaExpr::parse(r#""abc"#).is_err());
+        assert!(FormulaExpr::parse("prop(Price)").is_err());
+        assert!(FormulaExpr::parse("1 2").is_err());
+    }
+
+    #[test]
+    fn formula_referenced_field_names_test() {
+        let expr = FormulaExpr::parse(r#"if(prop("A") > 1, prop("B"), prop("A") + prop("C"))"#).unwrap();
+        assert_eq!(expr.referenced_field_names(), vec!["A", "B", "C"]);
+    }
+
+    #[test]
+    fn formula_cell_data_test() {
+        let cell_data = FormulaCellData::from_result(Ok(FormulaValue::Number(10.0)));
+        assert_eq!(cell_data.content(), "10");
+        let cell_str = cell_data.to_string();
+        assert_eq!(FormulaCellData::from_cell_str(&cell_str).unwrap(), cell_data);
+
+        let cell_data = FormulaCellData::from_result(Err(FormulaError::new("Division by zero")));
+        assert_eq!(cell_data.content(), "");
+        assert_eq!(cell_data.error.as_deref(), Some("Division by zero"));
+    }
+
+    #[test]
+    fn formula_type_option_reject_changeset_test() {
+        let type_option = FormulaTypeOptionPB::default();
+        assert!(type_option.apply_changeset("1".to_owned(), None).is_err());
+    }
+
+    fn formula_field_rev(name: &str, expression: &str) -> Arc<FieldRevision> {
+        let builder = FormulaTypeOptionBuilder::default().expression(expression);
+        Arc::new(FieldBuilder::new(builder).name(name).build())
+    }
+
+    #[test]
+    fn formula_evaluator_test() {
+        let price_field = Arc::new(FieldBuilder::from_field_type(&FieldType::Number).name("Price").build());
+        let name_field = Arc::new(FieldBuilder::from_field_type(&FieldType::RichText).name("Name").build());
+        let total_field = formula_field_rev("Total", r#"prop("Price") * 2"#);
+        // The formula that references another formula is evaluated after it.
+        let label_field = formula_field_rev("Label", r#"prop("Name") + ": " + prop("Total")"#);
+        let field_revs = vec![
+            price_field.clone(),
+            name_field.clone(),
+            label_field.clone(),
+            total_field.clone(),
+        ];
+
+        let mut row_rev = RowRevision::new("");
+        row_rev
+            .cells
+            .insert(price_field.id.clone(), insert_number_cell(3, &price_field));
+        row_rev
+            .cells
+            .insert(name_field.id.clone(), insert_text_cell("Apple".to_owned(), &name_field));
+
+        let evaluator = FormulaEvaluator::new(field_revs);
+        let results = evaluator
+            .evaluate_row(&row_rev, None)
+            .into_iter()
+            .collect::<HashMap<String, FormulaCellData>>();
+        assert_eq!(results[&total_field.id].content(), "6");
+        assert_eq!(results[&label_field.id].content(), "Apple: 6");
+
+        // Only the formulas that reference the changed field are evaluated.
+        let results = evaluator.evaluate_row(&row_rev, Some(vec![name_field.id.clone()]));
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, label_field.id);
+    }
+
+    #[test]
+    fn formula_circular_reference_test() {
+        let a_field = formula_field_rev("A", r#"prop("B") + 1"#);
+        let b_field = formula_field_rev("B", "1");
+        let field_revs = vec![a_field.clone(), b_field.clone()];
+        assert!(check_formula_circular_reference(&b_field.id, "2", &field_revs).is_ok());
+        assert!(check_formula_circular_reference(&b_field.id, r#"prop("A")"#, &field_revs).is_err());
+        assert!(check_formula_circular_reference(&a_field.id, r#"prop("A")"#, &field_revs).is_err());
+
+        // The formulas of a circular reference are evaluated to an error.
+        let b_field = formula_field_rev("B", r#"prop("A")"#);
+        let evaluator = FormulaEvaluator::new(vec![a_field.clone(), b_field]);
+        let results = evaluator.evaluate_row(&RowRevision::new(""), None);
+        assert!(results.iter().all(|(_, cell_data)| cell_data.error.is_some()));
+    }
+}
@@ -0,0 +1,402 @@
+use crate::entities::{FieldType, TextFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{
+    get_cell_rev_from_row, stringify_cell_data, CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData,
+};
+use crate::services::field::{
+    BoxTypeOptionBuilder, CheckboxCellData, DateCellData, FormulaCellChangeset, FormulaCellData, FormulaCellDataPB,
+    FormulaContext, FormulaError, FormulaExpr, FormulaResult, FormulaValue, NumberTypeOptionPB, ProgressCellData,
+    TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+    TypeOptionTransform, TypedCellData,
+};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use grid_model::{FieldRevision, RowRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+// Formula
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ProtoBuf)]
+pub struct FormulaTypeOptionPB {
+    /// The expression that is evaluated for each row, for example: `prop("Price") * prop("Qty")`.
+    /// The other fields are referenced by their names.
+    #[pb(index = 1)]
+    pub expression: String,
+}
+impl_type_option!(FormulaTypeOptionPB, FieldType::Formula);
+
+impl TypeOption for FormulaTypeOptionPB {
+    type CellData = FormulaCellData;
+    type CellChangeset = FormulaCellChangeset;
+    type CellProtobufType = FormulaCellDataPB;
+    type CellFilter = TextFilterPB;
+}
+
+impl TypeOptionTransform for FormulaTypeOptionPB {}
+
+impl TypeOptionCellData for FormulaTypeOptionPB {
+    fn convert_to_protobuf(&self, cell_data: <Self as TypeOption>::CellData) -> <Self as TypeOption>::CellProtobufType {
+        cell_data.into()
+    }
+
+    fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
+        FormulaCellData::from_cell_str(&cell_str)
+    }
+
+    fn convert_to_typed_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> TypedCellData {
+        TypedCellData::Formula {
+            value: cell_data.value,
+            error: cell_data.error,
+        }
+    }
+}
+
+impl CellDataDecoder for FormulaTypeOptionPB {
+    fn decode_cell_str(
+        &self,
+        cell_str: String,
+        decoded_field_type: &FieldType,
+        _field_rev: &FieldRevision,
+    ) -> FlowyResult<<Self as TypeOption>::CellData> {
+        if !decoded_field_type.is_formula() {
+            return Ok(Default::default());
+        }
+
+        self.decode_type_option_cell_str(cell_str)
+    }
+
+    fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+        cell_data.content()
+    }
+}
+
+impl CellDataChangeset for FormulaTypeOptionPB {
+    fn apply_changeset(
+        &self,
+        _changeset: <Self as TypeOption>::CellChangeset,
+        _type_cell_data: Option<TypeCellData>,
+    ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+        Err(FlowyError::new(
+            ErrorCode::FieldInvalidOperation,
+            "The cells of the formula fields are read-only",
+        ))
+    }
+}
+
+impl TypeOptionCellDataFilter for FormulaTypeOptionPB {
+    fn apply_filter(
+        &self,
+        filter: &<Self as TypeOption>::CellFilter,
+        field_type: &FieldType,
+        cell_data: &<Self as TypeOption>::CellData,
+    ) -> bool {
+        if !field_type.is_formula() {
+            return true;
+        }
+        filter.is_visible(cell_data.content())
+    }
+}
+
+impl TypeOptionCellDataCompare for FormulaTypeOptionPB {
+    fn apply_cmp(
+        &self,
+        cell_data: &<Self as TypeOption>::CellData,
+        other_cell_data: &<Self as TypeOption>::CellData,
+    ) -> Ordering {
+        match (&cell_data.value, &other_cell_data.value) {
+            (Some(left), Some(right)) => left.cmp_value(right),
+            (Some(_), None) => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (None, None) => Ordering::Equal,
+        }
+    }
+}
+
+/// Reads the values of the fields referenced by the formula from the row.
+struct RowFormulaContext<'a> {
+    row_rev: &'a RowRevision,
+    field_revs: &'a [Arc<FieldRevision>],
+    /// The results of the formulas that were evaluated for the row, by field id.
+    formula_results: &'a HashMap<String, FormulaCellData>,
+}
+
+impl<'a> FormulaContext for RowFormulaContext<'a> {
+    /// The empty cells of the number, the date and the progress fields are treated as 0.
+    fn prop(&self, field_name: &str) -> FormulaResult<FormulaValue> {
+        let field_rev = find_field_rev_by_name(self.field_revs, field_name)
+            .ok_or_else(|| FormulaError::new(format!("Can't find the field: {}", field_name)))?;
+        let field_type: FieldType = field_rev.ty.into();
+        if let Some(cell_data) = self.formula_results.get(&field_rev.id) {
+            return cell_data.to_result();
+        }
+
+        let cell_str = get_cell_rev_from_row(self.row_rev, field_rev)
+            .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+            .filter(|type_cell_data| type_cell_data.field_type == field_type)
+            .map(|type_cell_data| type_cell_data.cell_str)
+            .unwrap_or_default();
+        let value = match field_type {
+            FieldType::Number => {
+                let num = NumberTypeOptionPB::from(field_rev)
+                    .format_cell_data(&cell_str)
+                    .ok()
+                    .and_then(|cell_data| (*cell_data.decimal()).and_then(|decimal| decimal.to_f64()))
+                    .unwrap_or_default();
+                FormulaValue::Number(num)
+            }
+            FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => {
+                let timestamp = DateCellData::from_cell_str(&cell_str)
+                    .ok()
+                    .and_then(|cell_data| cell_data.0)
+                    .unwrap_or_default();
+                FormulaValue::Number(timestamp as f64)
+            }
+            FieldType::Progress => {
+                let value = ProgressCellData::from_cell_str(&cell_str)
+                    .ok()
+                    .and_then(|cell_data| cell_data.0)
+                    .unwrap_or_default();
+                FormulaValue::Number(value)
+            }
+            FieldType::Checkbox => {
+                let is_check = CheckboxCellData::from_cell_str(&cell_str)
+                    .map(|cell_data| cell_data.is_check())
+                    .unwrap_or(false);
+                FormulaValue::Bool(is_check)
+            }
+            FieldType::Formula => FormulaCellData::from_cell_str(&cell_str)
+                .unwrap_or_default()
+                .to_result()?,
+            _ => FormulaValue::Text(stringify_cell_data(cell_str, &field_type, &field_type, field_rev)),
+        };
+        Ok(value)
+    }
+}
+
+fn find_field_rev_by_name<'a>(field_revs: &'a [Arc<FieldRevision>], name: &str) -> Option<&'a Arc<FieldRevision>> {
+    field_revs.iter().find(|field_rev| field_rev.name == name)
+}
+
+/// Returns the ids of the fields referenced by the expression. The names that can't be found are
+/// skipped.
+fn referenced_field_ids(expr: &FormulaExpr, field_revs: &[Arc<FieldRevision>]) -> Vec<String> {
+    expr.referenced_field_names()
+        .iter()
+        .flat_map(|name| find_field_rev_by_name(field_revs, name))
+        .map(|field_rev| field_rev.id.clone())
+        .collect()
+}
+
+struct FormulaNode {
+    field_rev: Arc<FieldRevision>,
+    /// None if the expression is empty.
+    expr: Option<FormulaResult<FormulaExpr>>,
+    referenced_field_ids: Vec<String>,
+}
+
+/// [FormulaEvaluator] evaluates the formula fields of the rows. The formulas are evaluated in the
+/// order of their references, so a formula is evaluated after the formulas it references.
+pub struct FormulaEvaluator {
+    field_revs: Vec<Arc<FieldRevision>>,
+    nodes: Vec<FormulaNode>,
+    /// The ids of the formula fields that are part of a circular reference, or reference one.
+    circular_field_ids: HashSet<String>,
+}
+
+impl FormulaEvaluator {
+    pub fn new(field_revs: Vec<Arc<FieldRevision>>) -> Self {
+        let mut pending_nodes = field_revs
+            .iter()
+            .filter(|field_rev| FieldType::from(field_rev.ty).is_formula())
+            .map(|field_rev| {
+                let type_option = FormulaTypeOptionPB::from(field_rev);
+                let expr = if type_option.expression.trim().is_empty() {
+                    None
+                } else {
+                    Some(FormulaExpr::parse(&type_option.expression))
+                };
+                let referenced_field_ids = match &expr {
+                    Some(Ok(expr)) => referenced_field_ids(expr, &field_revs),
+                    _ => vec![],
+                };
+                FormulaNode {
+                    field_rev: field_rev.clone(),
+                    expr,
+                    referenced_field_ids,
+                }
+            })
+            .collect::<Vec<FormulaNode>>();
+
+        // Moves the formulas whose referenced formulas are all sorted, until nothing can be moved.
+        // The remaining formulas are part of a circular reference.
+        let formula_field_ids = pending_nodes
+            .iter()
+            .map(|node| node.field_rev.id.clone())
+            .collect::<HashSet<String>>();
+        let mut sorted_field_ids = HashSet::new();
+        let mut nodes = vec![];
+        loop {
+            let (ready_nodes, remaining_nodes): (Vec<FormulaNode>, Vec<FormulaNode>) =
+                pending_nodes.into_iter().partition(|node| {
+                    node.referenced_field_ids
+                        .iter()
+                        .all(|field_id| !formula_field_ids.contains(field_id) || sorted_field_ids.contains(field_id))
+                });
+            pending_nodes = remaining_nodes;
+            if ready_nodes.is_empty() {
+                break;
+            }
+            for node in ready_nodes {
+                sorted_field_ids.insert(node.field_rev.id.clone());
+                nodes.push(node);
+            }
+        }
+
+        let circular_field_ids = pending_nodes
+            .iter()
+            .map(|node| node.field_rev.id.clone())
+            .collect::<HashSet<String>>();
+        nodes.extend(pending_nodes);
+        Self {
+            field_revs,
+            nodes,
+            circular_field_ids,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Evaluates the formulas of the row that reference the changed fields, directly or through
+    /// other formulas. All the formulas are evaluated if the `changed_field_ids` is None.
+    ///
+    /// Returns the results of the formulas that are different from the cells of the row, by field id.
+    pub fn evaluate_row(
+        &self,
+        row_rev: &RowRevision,
+        changed_field_ids: Option<Vec<String>>,
+    ) -> Vec<(String, FormulaCellData)> {
+        let mut changed_field_ids =
+            changed_field_ids.map(|field_ids| field_ids.into_iter().collect::<HashSet<String>>());
+        let mut formula_results = HashMap::new();
+        let mut updated_cells = vec![];
+        for node in &self.nodes {
+            let field_id = &node.field_rev.id;
+            if let Some(changed_field_ids) = changed_field_ids.as_ref() {
+                if !node
+                    .referenced_field_ids
+                    .iter()
+                    .any(|referenced_field_id| changed_field_ids.contains(referenced_field_id))
+                {
+                    continue;
+                }
+            }
+
+            let cell_data = if self.circular_field_ids.contains(field_id) {
+                FormulaCellData::from_result(Err(FormulaError::new("The formula contains a circular reference")))
+            } else {
+                let context = RowFormulaContext {
+                    row_rev,
+                    field_revs: &self.field_revs,
+                    formula_results: &formula_results,
+                };
+                match &node.expr {
+                    None => FormulaCellData::default(),
+                    Some(Ok(expr)) => FormulaCellData::from_result(expr.evaluate(&context)),
+                    Some(Err(e)) => FormulaCellData::from_result(Err(e.clone())),
+                }
+            };
+
+            let old_cell_data = get_cell_rev_from_row(row_rev, &node.field_rev)
+                .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+                .and_then(|type_cell_data| FormulaCellData::from_cell_str(&type_cell_data.cell_str).ok());
+            if old_cell_data.as_ref() != Some(&cell_data) {
+                updated_cells.push((field_id.clone(), cell_data.clone()));
+                if let Some(changed_field_ids) = changed_field_ids.as_mut() {
+                    changed_field_ids.insert(field_id.clone());
+                }
+            }
+            formula_results.insert(field_id.clone(), cell_data);
+        }
+        updated_cells
+    }
+}
+
+/// Returns a `FormulaCircularReference` error if the formula field with the expression references
+/// itself, directly or through other formula fields.
+pub fn check_formula_circular_reference(
+    field_id: &str,
+    expression: &str,
+    field_revs: &[Arc<FieldRevision>],
+) -> FlowyResult<()> {
+    let references_of = |formula_field_id: &str| -> Vec<String> {
+        let expression = if formula_field_id == field_id {
+            expression.to_owned()
+        } else {
+            match field_revs.iter().find(|field_rev| field_rev.id == formula_field_id) {
+                Some(field_rev) if FieldType::from(field_rev.ty).is_formula() => {
+                    FormulaTypeOptionPB::from(field_rev).expression
+                }
+                _ => return vec![],
+            }
+        };
+        FormulaExpr::parse(&expression)
+            .map(|expr| referenced_field_ids(&expr, field_revs))
+            .unwrap_or_default()
+    };
+
+    let mut visited = HashSet::new();
+    let mut pending_field_ids = references_of(field_id);
+    while let Some(referenced_field_id) = pending_field_ids.pop() {
+        if referenced_field_id == field_id {
+            return Err(FlowyError::new(
+                ErrorCode::FormulaCircularReference,
+                "The formula references itself",
+            ));
+        }
+        if visited.insert(referenced_field_id.clone()) {
+            pending_field_ids.extend(references_of(&referenced_field_id));
+        }
+    }
+    Ok(())
+}
+
+/// Returns a `FormulaCircularReference` error if any formula field of the `field_revs` references
+/// itself. The formulas reference the fields by name, so the fields are checked again after a
+/// field is created or renamed.
+pub fn check_formula_fields_circular_reference(field_revs: &[Arc<FieldRevision>]) -> FlowyResult<()> {
+    for field_rev in field_revs {
+        if FieldType::from(field_rev.ty).is_formula() {
+            let expression = FormulaTypeOptionPB::from(field_rev).expression;
+            check_formula_circular_reference(&field_rev.id, &expression, field_revs)?;
+        }
+    }
+    Ok(())
+}
+
+#[derive(Default)]
+pub struct FormulaTypeOptionBuilder(FormulaTypeOptionPB);
+impl_into_box_type_option_builder!(FormulaTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(FormulaTypeOptionBuilder, FormulaTypeOptionPB);
+
+impl FormulaTypeOptionBuilder {
+    pub fn expression(mut self, expression: &str) -> Self {
+        self.0.expression = expression.to_owned();
+        self
+    }
+}
+
+impl TypeOptionBuilder for FormulaTypeOptionBuilder {
+    fn field_type(&self) -> FieldType {
+        FieldType::Formula
+    }
+
+    fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+        &self.0
+    }
+}
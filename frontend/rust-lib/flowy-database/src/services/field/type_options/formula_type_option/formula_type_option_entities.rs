@@ -0,0 +1,110 @@
+use crate::services::cell::{CellProtobufBlobParser, DecodedCellData, FromCellString};
+use crate::services::field::{FormulaError, FormulaResult, FormulaValue};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::{internal_error, FlowyResult};
+use serde::{Deserialize, Serialize};
+
+/// [FormulaCellData] is the result of evaluating the formula for the row. It's computed by the
+/// editor and cached in the cell, because evaluating the formula needs the other cells of the row.
+/// It's stored as a json string.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FormulaCellData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<FormulaValue>,
+
+    /// The reason why the formula can't be evaluated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl FormulaCellData {
+    pub fn from_result(result: FormulaResult<FormulaValue>) -> Self {
+        match result {
+            Ok(value) => Self {
+                value: Some(value),
+                error: None,
+            },
+            Err(e) => Self {
+                value: None,
+                error: Some(e.msg),
+            },
+        }
+    }
+
+    /// Returns the value or the error of the cell, so it can be referenced by the other formulas.
+    pub fn to_result(&self) -> FormulaResult<FormulaValue> {
+        match (&self.value, &self.error) {
+            (Some(value), _) => Ok(value.clone()),
+            (None, Some(error)) => Err(FormulaError::new(error.clone())),
+            (None, None) => Ok(FormulaValue::Text("".to_owned())),
+        }
+    }
+
+    pub fn content(&self) -> String {
+        self.value.as_ref().map(|value| value.to_string()).unwrap_or_default()
+    }
+}
+
+impl FromCellString for FormulaCellData {
+    fn from_cell_str(s: &str) -> FlowyResult<Self> {
+        if s.is_empty() {
+            return Ok(Self::default());
+        }
+        serde_json::from_str::<FormulaCellData>(s).map_err(internal_error)
+    }
+}
+
+impl ToString for FormulaCellData {
+    fn to_string(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+impl DecodedCellData for FormulaCellData {
+    type Object = FormulaCellData;
+
+    fn is_empty(&self) -> bool {
+        self.value.is_none()
+    }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct FormulaCellDataPB {
+    /// The display text of the value.
+    #[pb(index = 1)]
+    pub content: String,
+
+    /// Not empty if the formula can't be evaluated for the row.
+    #[pb(index = 2)]
+    pub error: String,
+}
+
+impl From<FormulaCellData> for FormulaCellDataPB {
+    fn from(data: FormulaCellData) -> Self {
+        Self {
+            content: data.content(),
+            error: data.error.unwrap_or_default(),
+        }
+    }
+}
+
+impl DecodedCellData for FormulaCellDataPB {
+    type Object = FormulaCellDataPB;
+
+    fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+}
+
+pub struct FormulaCellDataParser();
+impl CellProtobufBlobParser for FormulaCellDataParser {
+    type Object = FormulaCellDataPB;
+
+    fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+        FormulaCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+    }
+}
+
+/// The cells of the formula fields are read-only, the changeset is always rejected.
+pub type FormulaCellChangeset = String;
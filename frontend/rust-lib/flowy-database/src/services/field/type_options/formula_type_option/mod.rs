@@ -0,0 +1,9 @@
+#![allow(clippy::module_inception)]
+mod formula_expr;
+mod formula_tests;
+mod formula_type_option;
+mod formula_type_option_entities;
+
+pub use formula_expr::*;
+pub use formula_type_option::*;
+pub use formula_type_option_entities::*;
@@ -1,17 +1,41 @@
+pub mod attachment_type_option;
+mod cell_validation;
 pub mod checkbox_type_option;
+pub mod checklist_type_option;
 pub mod date_type_option;
+pub mod email_type_option;
+pub mod formula_type_option;
 pub mod number_type_option;
+pub mod person_type_option;
+pub mod phone_number_type_option;
+pub mod progress_type_option;
+pub mod rating_type_option;
+pub mod relation_type_option;
 pub mod selection_type_option;
 pub mod text_type_option;
+pub mod timestamp_type_option;
 mod type_option;
 mod type_option_cell;
+mod typed_cell_data;
 pub mod url_type_option;
 
+pub use attachment_type_option::*;
+pub use cell_validation::*;
 pub use checkbox_type_option::*;
+pub use checklist_type_option::*;
 pub use date_type_option::*;
+pub use email_type_option::*;
+pub use formula_type_option::*;
 pub use number_type_option::*;
+pub use person_type_option::*;
+pub use phone_number_type_option::*;
+pub use progress_type_option::*;
+pub use rating_type_option::*;
+pub use relation_type_option::*;
 pub use selection_type_option::*;
 pub use text_type_option::*;
+pub use timestamp_type_option::*;
 pub use type_option::*;
 pub use type_option_cell::*;
+pub use typed_cell_data::*;
 pub use url_type_option::*;
@@ -1,8 +1,10 @@
 use flowy_derive::ProtoBuf_Enum;
 use lazy_static::lazy_static;
 
-use rusty_money::define_currency_set;
+use rust_decimal::Decimal;
+use rusty_money::{define_currency_set, Locale};
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
@@ -451,4 +453,117 @@ impl NumberFormat {
     pub fn symbol(&self) -> String {
         self.currency().symbol.to_string()
     }
+
+    /// Returns true if the format represents a currency, the [NumberFormat::Num] and the
+    /// [NumberFormat::Percent] are not currencies.
+    pub fn is_currency(&self) -> bool {
+        !matches!(self, NumberFormat::Num | NumberFormat::Percent)
+    }
+
+    /// Returns true if the symbol is placed before the amount, for example: `$1,234.50`.
+    pub fn symbol_first(&self) -> bool {
+        self.currency().symbol_first
+    }
+
+    /// The number of the digits after the decimal separator, for example: `2` for `$1,234.50`.
+    pub fn decimal_digits(&self) -> u32 {
+        self.currency().exponent
+    }
+
+    pub fn thousands_separator(&self) -> char {
+        self.separators().0
+    }
+
+    pub fn decimal_separator(&self) -> char {
+        self.separators().1
+    }
+
+    /// Returns the thousands separator and the decimal separator of the format's locale.
+    fn separators(&self) -> (char, char) {
+        if *self == NumberFormat::Num {
+            return (',', '.');
+        }
+
+        match self.currency().locale {
+            Locale::EnEu => ('.', ','),
+            Locale::EnBy => (' ', ','),
+            _ => (',', '.'),
+        }
+    }
+
+    /// Parses the input into a decimal with the separators of the format's locale. The input may
+    /// contain a currency symbol before or after the amount, for example: `$1,234.50` or
+    /// `1.234,50 €`. Returns None if the input is not a number.
+    ///
+    /// If the input contains only one kind of separator, it's treated as the decimal separator
+    /// when it's the locale's decimal separator, or when it appears once and isn't followed by
+    /// exactly three digits. So `1,234` is `1234` for [NumberFormat::USD] but `1.234` for
+    /// [NumberFormat::EUR].
+    pub fn parse_decimal(&self, s: &str) -> Option<Decimal> {
+        let (is_negative, s) = strip_sign(s.trim());
+        let (is_negative_after_symbol, s) = strip_sign(strip_symbol(s).trim());
+        let num_str = s.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+        if !num_str.chars().any(|c| c.is_ascii_digit())
+            || !num_str.chars().all(|c| c.is_ascii_digit() || c == '.' || c == ',')
+        {
+            return None;
+        }
+
+        let decimal_separator = match (num_str.rfind('.'), num_str.rfind(',')) {
+            (Some(dot), Some(comma)) => Some(if dot > comma { '.' } else { ',' }),
+            (Some(_), None) => self.guess_decimal_separator(&num_str, '.'),
+            (None, Some(_)) => self.guess_decimal_separator(&num_str, ','),
+            (None, None) => None,
+        };
+        let (integer, fraction) = decimal_separator
+            .and_then(|separator| num_str.rsplit_once(separator))
+            .unwrap_or((num_str.as_str(), ""));
+        let mut integer = integer.replace(&['.', ','][..], "");
+        if integer.is_empty() {
+            integer = "0".to_owned();
+        }
+        let mut decimal = if fraction.is_empty() {
+            Decimal::from_str(&integer).ok()?
+        } else {
+            Decimal::from_str(&format!("{}.{}", integer, fraction)).ok()?
+        };
+        decimal.set_sign_negative(is_negative || is_negative_after_symbol);
+        Some(decimal)
+    }
+
+    fn guess_decimal_separator(&self, num_str: &str, separator: char) -> Option<char> {
+        if num_str.matches(separator).count() > 1 {
+            return None;
+        }
+
+        if separator == self.decimal_separator() {
+            return Some(separator);
+        }
+
+        let fraction_len = num_str.rsplit(separator).next().map(|fraction| fraction.len());
+        if fraction_len == Some(3) {
+            None
+        } else {
+            Some(separator)
+        }
+    }
+}
+
+fn strip_sign(s: &str) -> (bool, &str) {
+    match s.strip_prefix('-') {
+        None => (false, s),
+        Some(s) => (true, s),
+    }
+}
+
+/// Strips the longest currency symbol that the input starts or ends with.
+fn strip_symbol(s: &str) -> &str {
+    CURRENCY_SYMBOL
+        .iter()
+        .filter_map(|symbol| {
+            s.strip_prefix(symbol.as_str())
+                .or_else(|| s.strip_suffix(symbol.as_str()))
+        })
+        .min_by_key(|stripped| stripped.len())
+        .unwrap_or(s)
 }
@@ -8,44 +8,74 @@ use std::str::FromStr;
 
 impl NumberFilterPB {
     pub fn is_visible(&self, num_cell_data: &NumberCellData) -> bool {
-        if self.content.is_empty() {
-            match self.condition {
-                NumberFilterConditionPB::NumberIsEmpty => {
-                    return num_cell_data.is_empty();
-                }
-                NumberFilterConditionPB::NumberIsNotEmpty => {
-                    return !num_cell_data.is_empty();
-                }
-                _ => {}
-            }
+        match self.condition {
+            NumberFilterConditionPB::NumberIsEmpty => return num_cell_data.is_empty(),
+            NumberFilterConditionPB::NumberIsNotEmpty => return !num_cell_data.is_empty(),
+            _ => {}
         }
-        match num_cell_data.decimal().as_ref() {
-            None => false,
-            Some(cell_decimal) => {
-                let decimal = Decimal::from_str(&self.content).unwrap_or_else(|_| Decimal::zero());
-                match self.condition {
-                    NumberFilterConditionPB::Equal => cell_decimal == &decimal,
-                    NumberFilterConditionPB::NotEqual => cell_decimal != &decimal,
-                    NumberFilterConditionPB::GreaterThan => cell_decimal > &decimal,
-                    NumberFilterConditionPB::LessThan => cell_decimal < &decimal,
-                    NumberFilterConditionPB::GreaterThanOrEqualTo => cell_decimal >= &decimal,
-                    NumberFilterConditionPB::LessThanOrEqualTo => cell_decimal <= &decimal,
-                    _ => true,
-                }
-            }
+
+        // The empty cells are hidden by the comparisons unless they are treated as zero.
+        let cell_decimal = match num_cell_data.decimal() {
+            Some(decimal) => *decimal,
+            None if self.treat_empty_as_zero => Decimal::zero(),
+            None => return false,
+        };
+
+        if self.condition == NumberFilterConditionPB::Between {
+            return if self.start.is_some() || self.end.is_some() {
+                is_bounded_by(&cell_decimal, self.start.as_deref().unwrap_or(""), |cell, start| {
+                    cell >= start
+                }) && is_bounded_by(&cell_decimal, self.end.as_deref().unwrap_or(""), |cell, end| {
+                    cell <= end
+                })
+            } else {
+                is_between(&cell_decimal, &self.content)
+            };
+        }
+
+        let decimal = Decimal::from_str(&self.content).unwrap_or_else(|_| Decimal::zero());
+        match self.condition {
+            NumberFilterConditionPB::Equal => cell_decimal == decimal,
+            NumberFilterConditionPB::NotEqual => cell_decimal != decimal,
+            NumberFilterConditionPB::GreaterThan => cell_decimal > decimal,
+            NumberFilterConditionPB::LessThan => cell_decimal < decimal,
+            NumberFilterConditionPB::GreaterThanOrEqualTo => cell_decimal >= decimal,
+            NumberFilterConditionPB::LessThanOrEqualTo => cell_decimal <= decimal,
+            _ => true,
         }
     }
 }
 
+/// The legacy content of the [NumberFilterConditionPB::Between] condition is the start and the
+/// end separated by a comma, for example: `10,20`. The filters that set the `start` or the `end`
+/// don't use the content.
+fn is_between(cell_decimal: &Decimal, content: &str) -> bool {
+    let (start, end) = content.split_once(',').unwrap_or((content, ""));
+    is_bounded_by(cell_decimal, start, |cell, start| cell >= start)
+        && is_bounded_by(cell_decimal, end, |cell, end| cell <= end)
+}
+
+/// Both of the bounds are inclusive, and the empty one is unbounded.
+fn is_bounded_by(cell_decimal: &Decimal, bound: &str, f: fn(&Decimal, &Decimal) -> bool) -> bool {
+    let bound = bound.trim();
+    bound.is_empty()
+        || Decimal::from_str(bound)
+            .map(|bound| f(cell_decimal, &bound))
+            .unwrap_or(false)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::entities::{NumberFilterConditionPB, NumberFilterPB};
     use crate::services::field::{NumberCellData, NumberFormat};
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
     #[test]
     fn number_filter_equal_test() {
         let number_filter = NumberFilterPB {
             condition: NumberFilterConditionPB::Equal,
             content: "123".to_owned(),
+            ..Default::default()
         };
 
         for (num_str, visible) in [("123", true), ("1234", false), ("", false)] {
@@ -64,6 +94,7 @@ mod tests {
         let number_filter = NumberFilterPB {
             condition: NumberFilterConditionPB::GreaterThan,
             content: "12".to_owned(),
+            ..Default::default()
         };
         for (num_str, visible) in [("123", true), ("10", false), ("30", true), ("", false)] {
             let data = NumberCellData::from_format_str(num_str, true, &NumberFormat::Num).unwrap();
@@ -76,10 +107,129 @@ mod tests {
         let number_filter = NumberFilterPB {
             condition: NumberFilterConditionPB::LessThan,
             content: "100".to_owned(),
+            ..Default::default()
         };
         for (num_str, visible) in [("12", true), ("1234", false), ("30", true), ("", false)] {
             let data = NumberCellData::from_format_str(num_str, true, &NumberFormat::Num).unwrap();
             assert_eq!(number_filter.is_visible(&data), visible);
         }
     }
+
+    #[test]
+    fn number_filter_between_test() {
+        let number_filter = NumberFilterPB {
+            condition: NumberFilterConditionPB::Between,
+            content: "10,20".to_owned(),
+            ..Default::default()
+        };
+        for (num_str, visible) in [("10", true), ("15", true), ("20", true), ("21", false), ("", false)] {
+            let data = NumberCellData::from_format_str(num_str, true, &NumberFormat::Num).unwrap();
+            assert_eq!(number_filter.is_visible(&data), visible);
+        }
+
+        // The empty bound is unbounded.
+        let number_filter = NumberFilterPB {
+            condition: NumberFilterConditionPB::Between,
+            content: "10,".to_owned(),
+            ..Default::default()
+        };
+        for (num_str, visible) in [("9", false), ("1234", true)] {
+            let data = NumberCellData::from_format_str(num_str, true, &NumberFormat::Num).unwrap();
+            assert_eq!(number_filter.is_visible(&data), visible);
+        }
+    }
+
+    #[test]
+    fn number_filter_between_bounds_test() {
+        let number_filter = NumberFilterPB {
+            condition: NumberFilterConditionPB::Between,
+            start: Some("-10.5".to_owned()),
+            end: Some("0".to_owned()),
+            ..Default::default()
+        };
+        for (num_str, visible) in [
+            ("-10.5", true),
+            ("-10.6", false),
+            ("-3", true),
+            ("0", true),
+            ("0.01", false),
+        ] {
+            let data = NumberCellData::from_decimal(Decimal::from_str(num_str).unwrap());
+            assert_eq!(number_filter.is_visible(&data), visible, "{}", num_str);
+        }
+
+        // The bound that is not set is unbounded.
+        let number_filter = NumberFilterPB {
+            condition: NumberFilterConditionPB::Between,
+            end: Some("1000.25".to_owned()),
+            ..Default::default()
+        };
+        let format = NumberFormat::USD;
+        for (num_str, visible) in [("$1,000.25", true), ("$1,000.26", false), ("$5", true), ("", false)] {
+            let data = NumberCellData::from_format_str(num_str, true, &format).unwrap();
+            assert_eq!(number_filter.is_visible(&data), visible, "{}", num_str);
+        }
+    }
+
+    #[test]
+    fn number_filter_negative_number_test() {
+        let number_filter = NumberFilterPB {
+            condition: NumberFilterConditionPB::LessThan,
+            content: "-1.5".to_owned(),
+            ..Default::default()
+        };
+        for (num_str, visible) in [("-2", true), ("-1.5", false), ("0", false)] {
+            let data = NumberCellData::from_decimal(Decimal::from_str(num_str).unwrap());
+            assert_eq!(number_filter.is_visible(&data), visible, "{}", num_str);
+        }
+        assert!(!number_filter.is_visible(&NumberCellData::new()));
+    }
+
+    #[test]
+    fn number_filter_empty_cell_test() {
+        let empty = NumberCellData::from_format_str("", true, &NumberFormat::Num).unwrap();
+        for condition in [
+            NumberFilterConditionPB::Equal,
+            NumberFilterConditionPB::NotEqual,
+            NumberFilterConditionPB::LessThan,
+            NumberFilterConditionPB::GreaterThanOrEqualTo,
+            NumberFilterConditionPB::Between,
+        ] {
+            let number_filter = NumberFilterPB {
+                condition,
+                content: "5".to_owned(),
+                ..Default::default()
+            };
+            assert!(!number_filter.is_visible(&empty));
+        }
+
+        // The content of the empty conditions is ignored.
+        let number_filter = NumberFilterPB {
+            condition: NumberFilterConditionPB::NumberIsEmpty,
+            content: "5".to_owned(),
+            ..Default::default()
+        };
+        assert!(number_filter.is_visible(&empty));
+    }
+
+    #[test]
+    fn number_filter_treat_empty_as_zero_test() {
+        let empty = NumberCellData::from_format_str("", true, &NumberFormat::Num).unwrap();
+        for (condition, content, visible) in [
+            (NumberFilterConditionPB::Equal, "0", true),
+            (NumberFilterConditionPB::LessThan, "1", true),
+            (NumberFilterConditionPB::GreaterThan, "0", false),
+            (NumberFilterConditionPB::Between, "-1,1", true),
+            (NumberFilterConditionPB::NumberIsEmpty, "", true),
+            (NumberFilterConditionPB::NumberIsNotEmpty, "", false),
+        ] {
+            let number_filter = NumberFilterPB {
+                condition,
+                content: content.to_owned(),
+                treat_empty_as_zero: true,
+                ..Default::default()
+            };
+            assert_eq!(number_filter.is_visible(&empty), visible);
+        }
+    }
 }
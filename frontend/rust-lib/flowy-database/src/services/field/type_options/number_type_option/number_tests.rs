@@ -1,11 +1,16 @@
 #[cfg(test)]
 mod tests {
-    use crate::entities::FieldType;
-    use crate::services::cell::CellDataDecoder;
-    use crate::services::field::FieldBuilder;
+    use crate::entities::{FieldType, NumberFilterConditionPB, NumberFilterPB};
+    use crate::services::cell::{CellDataChangeset, CellDataDecoder, TypeCellData};
+    use crate::services::field::{
+        FieldBuilder, StrCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionCellDataHandler,
+        TypeOptionCellExt,
+    };
 
     use crate::services::field::{strip_currency_symbol, NumberFormat, NumberTypeOptionPB};
-    use grid_model::FieldRevision;
+    use flowy_error::ErrorCode;
+    use grid_model::{CellRevision, FieldRevision};
+    use std::cmp::Ordering;
     use strum::IntoEnumIterator;
 
     /// Testing when the input is not a number.
@@ -19,7 +24,27 @@ mod tests {
         assert_number(&type_option, "", "", &field_type, &field_rev);
 
         // Input is letter
-        assert_number(&type_option, "abc", "", &field_type, &field_rev);
+        assert_invalid_number(&type_option, "abc", &field_type, &field_rev);
+    }
+
+    /// The number cell that contains only whitespace has no number, so it's empty.
+    #[test]
+    fn number_cell_with_only_whitespace_is_empty_test() {
+        let field_type = FieldType::Number;
+        let field_rev = FieldBuilder::from_field_type(&field_type).build();
+        let handler = TypeOptionCellExt::new(&field_rev, None, None)
+            .get_type_option_cell_data_handler(&field_type)
+            .unwrap();
+        for (cell_str, is_empty) in [("   ", true), ("", true), (" 0 ", false), ("12", false)] {
+            let cell_rev = CellRevision::new(TypeCellData::new(cell_str.to_owned(), field_type.clone()).to_json());
+            assert_eq!(
+                handler.is_cell_empty(Some(&cell_rev), &field_rev),
+                is_empty,
+                "{:?}",
+                cell_str
+            );
+        }
+        assert!(handler.is_cell_empty(None, &field_rev));
     }
 
     /// Testing the strip_currency_symbol function. It should return the string without the input symbol.
@@ -125,7 +150,7 @@ mod tests {
                     assert_number(&type_option, "18443", "18443", &field_type, &field_rev);
                     assert_number(&type_option, "0.2", "0.2", &field_type, &field_rev);
                     assert_number(&type_option, "", "", &field_type, &field_rev);
-                    assert_number(&type_option, "abc", "", &field_type, &field_rev);
+                    assert_invalid_number(&type_option, "abc", &field_type, &field_rev);
                 }
                 NumberFormat::USD => {
                     assert_number(&type_option, "$18,44", "$1,844", &field_type, &field_rev);
@@ -445,4 +470,108 @@ mod tests {
             expected_str.to_owned()
         );
     }
+
+    fn assert_invalid_number(
+        type_option: &NumberTypeOptionPB,
+        input_str: &str,
+        field_type: &FieldType,
+        field_rev: &FieldRevision,
+    ) {
+        let error = type_option
+            .decode_cell_str(input_str.to_owned(), field_type, field_rev)
+            .unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidParams.value(), "input: {}", input_str);
+    }
+
+    /// Parse the input with the separators of the format's locale, the canonical decimal is stored.
+    #[test]
+    fn number_type_option_parse_currency_test() {
+        let mut type_option = NumberTypeOptionPB::default();
+        type_option.set_format(NumberFormat::USD);
+        assert_cell_str(&type_option, "$1,234.50", "1234.50");
+        assert_cell_str(&type_option, "1234.5", "1234.5");
+        assert_cell_str(&type_option, "1,234", "1234");
+        assert_cell_str(&type_option, "-$12", "-12");
+        assert_cell_str(&type_option, "1.234,50 €", "1234.50");
+        assert_invalid_cell_str(&type_option, "abc");
+        assert_invalid_cell_str(&type_option, "12 apples");
+        assert_cell_str(&type_option, "", "");
+
+        type_option.set_format(NumberFormat::Rupee);
+        assert_cell_str(&type_option, "₹1,00,000", "100000");
+    }
+
+    /// The locales such as [NumberFormat::EUR] use the comma as the decimal separator.
+    #[test]
+    fn number_type_option_parse_comma_decimal_separator_test() {
+        let mut type_option = NumberTypeOptionPB::default();
+        type_option.set_format(NumberFormat::EUR);
+        assert_eq!(NumberFormat::EUR.decimal_separator(), ',');
+        assert_eq!(NumberFormat::EUR.thousands_separator(), '.');
+        assert_cell_str(&type_option, "1.234,50 €", "1234.50");
+        assert_cell_str(&type_option, "€1.234", "1234");
+        assert_cell_str(&type_option, "12,5", "12.5");
+        // The dot is the decimal separator if it isn't followed by three digits.
+        assert_cell_str(&type_option, "12.5", "12.5");
+        assert_cell_str(&type_option, "1.234.567", "1234567");
+
+        type_option.set_format(NumberFormat::Krona);
+        assert_eq!(NumberFormat::Krona.thousands_separator(), ' ');
+        assert_cell_str(&type_option, "1 234,50 SEK", "1234.50");
+        assert_cell_str(&type_option, "1234,5", "1234.5");
+    }
+
+    /// Switching the format only changes the display string of the stored number.
+    #[test]
+    fn number_type_option_switch_currency_format_test() {
+        let field_type = FieldType::Number;
+        let field_rev = FieldBuilder::from_field_type(&field_type).build();
+        let mut type_option = NumberTypeOptionPB::default();
+        type_option.set_format(NumberFormat::USD);
+        let (cell_str, _) = type_option.apply_changeset("$1,234.5".to_owned(), None).unwrap();
+        assert_eq!(cell_str, "1234.5");
+        assert_number(&type_option, &cell_str, "$1,234.5", &field_type, &field_rev);
+
+        type_option.set_format(NumberFormat::EUR);
+        let cell_data = type_option
+            .decode_cell_str(cell_str.clone(), &field_type, &field_rev)
+            .unwrap();
+        assert_eq!(cell_data.as_str(), "€1.234,5");
+        assert_number(&type_option, &cell_str, "€1.234,5", &field_type, &field_rev);
+    }
+
+    /// The cells are compared and filtered by their decimals rather than the display strings.
+    #[test]
+    fn number_type_option_compare_and_filter_decimal_test() {
+        let mut type_option = NumberTypeOptionPB::default();
+        type_option.set_format(NumberFormat::USD);
+        let nine = StrCellData("9".to_owned());
+        let ten = StrCellData("10".to_owned());
+        let empty = StrCellData::default();
+        assert_eq!(type_option.apply_cmp(&nine, &ten), Ordering::Less);
+        assert_eq!(type_option.apply_cmp(&empty, &nine), Ordering::Less);
+
+        let filter = NumberFilterPB {
+            condition: NumberFilterConditionPB::GreaterThan,
+            content: "9".to_owned(),
+            ..Default::default()
+        };
+        assert!(type_option.apply_filter(&filter, &FieldType::Number, &ten));
+        assert!(!type_option.apply_filter(&filter, &FieldType::Number, &nine));
+
+        // The cells that were stored before contain the formatted input.
+        let formatted = StrCellData("$1,844".to_owned());
+        assert_eq!(type_option.apply_cmp(&nine, &formatted), Ordering::Less);
+        assert!(type_option.apply_filter(&filter, &FieldType::Number, &formatted));
+    }
+
+    fn assert_cell_str(type_option: &NumberTypeOptionPB, input_str: &str, expected_cell_str: &str) {
+        let (cell_str, _) = type_option.apply_changeset(input_str.to_owned(), None).unwrap();
+        assert_eq!(cell_str, expected_cell_str, "input: {}", input_str);
+    }
+
+    fn assert_invalid_cell_str(type_option: &NumberTypeOptionPB, input_str: &str) {
+        let error = type_option.apply_changeset(input_str.to_owned(), None).unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidParams.value(), "input: {}", input_str);
+    }
 }
@@ -1,15 +1,16 @@
 use crate::entities::{FieldType, NumberFilterPB};
 use crate::impl_type_option;
-use crate::services::cell::{CellDataChangeset, CellDataDecoder, TypeCellData};
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
 use crate::services::field::type_options::number_type_option::format::*;
 use crate::services::field::{
-    BoxTypeOptionBuilder, NumberCellData, StrCellData, TypeOption, TypeOptionBuilder, TypeOptionCellData,
-    TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+    BoxTypeOptionBuilder, CellValidationRulePB, CellValidationViolation, NumberCellData, ProgressCellData, StrCellData,
+    TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+    TypeOptionTransform, TypedCellData, TypedNumber,
 };
 use bytes::Bytes;
 use fancy_regex::Regex;
 use flowy_derive::ProtoBuf;
-use flowy_error::FlowyResult;
+use flowy_error::{FlowyError, FlowyResult};
 use grid_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
 use lazy_static::lazy_static;
 use rust_decimal::Decimal;
@@ -43,6 +44,16 @@ impl NumberTypeOptionBuilder {
         self.0.sign_positive = positive;
         self
     }
+
+    pub fn min(mut self, min: Decimal) -> Self {
+        self.0.min = Some(min.to_string());
+        self
+    }
+
+    pub fn max(mut self, max: Decimal) -> Self {
+        self.0.max = Some(max.to_string());
+        self
+    }
 }
 
 impl TypeOptionBuilder for NumberTypeOptionBuilder {
@@ -72,6 +83,21 @@ pub struct NumberTypeOptionPB {
 
     #[pb(index = 5)]
     pub name: String,
+
+    /// The smallest number that the cell accepts, it's stored as a decimal string.
+    #[pb(index = 6, one_of)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<String>,
+
+    /// The largest number that the cell accepts, it's stored as a decimal string.
+    #[pb(index = 7, one_of)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<String>,
+
+    /// The number of the cells of the new rows, it's parsed with the format of the type option.
+    #[pb(index = 8, one_of)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<String>,
 }
 impl_type_option!(NumberTypeOptionPB, FieldType::Number);
 
@@ -90,6 +116,10 @@ impl TypeOptionCellData for NumberTypeOptionPB {
     fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
         Ok(cell_str.into())
     }
+
+    fn convert_to_typed_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> TypedCellData {
+        TypedCellData::Number(self.decimal_from_cell_str(&cell_data).map(TypedNumber::from))
+    }
 }
 
 impl NumberTypeOptionPB {
@@ -131,6 +161,39 @@ impl NumberTypeOptionPB {
         self.format = format;
         self.symbol = format.symbol();
     }
+
+    /// Parses the input of the cell into a decimal, the currency formats accept the separators of
+    /// their locales, for example: `1.234,50 €`.
+    fn parse_number(&self, s: &str) -> Option<Decimal> {
+        match self.format {
+            NumberFormat::Num => self.format_cell_data(s).ok().and_then(|cell_data| *cell_data.decimal()),
+            _ => self.format.parse_decimal(s),
+        }
+    }
+
+    /// Returns the decimal of the cell. The cells store the canonical decimal string, which doesn't
+    /// depend on the format. The cells that were stored before and the decoded cell data contain
+    /// the formatted string, for example: `$1,844`.
+    pub(crate) fn decimal_from_cell_str(&self, cell_str: &str) -> Option<Decimal> {
+        if let Ok(decimal) = Decimal::from_str(cell_str) {
+            return Some(decimal);
+        }
+
+        let cell_data = match self.format {
+            NumberFormat::Num => self.format_cell_data(cell_str),
+            _ => NumberCellData::from_format_str(cell_str, true, &self.format),
+        };
+        // The negative numbers are formatted with the sign before the symbol, for example: `-$12`.
+        cell_data
+            .ok()
+            .and_then(|cell_data| *cell_data.decimal())
+            .or_else(|| self.parse_number(cell_str))
+    }
+
+    /// Formats the decimal with the format of the type option, for example: `1844` is `$1,844`.
+    fn format_decimal(&self, decimal: Decimal) -> FlowyResult<String> {
+        Ok(self.format_cell_data(&decimal.to_string())?.to_string())
+    }
 }
 
 pub(crate) fn strip_currency_symbol<T: ToString>(s: T) -> String {
@@ -144,7 +207,33 @@ pub(crate) fn strip_currency_symbol<T: ToString>(s: T) -> String {
     s
 }
 
-impl TypeOptionTransform for NumberTypeOptionPB {}
+impl TypeOptionTransform for NumberTypeOptionPB {
+    fn transformable(&self) -> bool {
+        true
+    }
+
+    fn transform_type_option_cell_str(
+        &self,
+        cell_str: &str,
+        decoded_field_type: &FieldType,
+        _field_rev: &FieldRevision,
+    ) -> Option<<Self as TypeOption>::CellData> {
+        if decoded_field_type.is_progress() {
+            if cell_str.trim().is_empty() {
+                return Some(StrCellData::default());
+            }
+            // The progress is converted into the percentage, so `0.45` is `45`. The invalid progress
+            // isn't transformed, it's rejected by `decode_cell_str`.
+            ProgressCellData::from_cell_str(cell_str)
+                .ok()
+                .and_then(|cell_data| cell_data.percent_decimal())
+                .and_then(|percent| self.format_decimal(percent).ok())
+                .map(StrCellData)
+        } else {
+            None
+        }
+    }
+}
 
 impl CellDataDecoder for NumberTypeOptionPB {
     fn decode_cell_str(
@@ -157,16 +246,19 @@ impl CellDataDecoder for NumberTypeOptionPB {
             return Ok(Default::default());
         }
 
-        let str_cell_data = self.decode_type_option_cell_str(cell_str)?;
-        let s = self.format_cell_data(&str_cell_data)?.to_string();
-        Ok(s.into())
+        if cell_str.trim().is_empty() {
+            return Ok(Default::default());
+        }
+        match self.decimal_from_cell_str(&cell_str) {
+            Some(decimal) => Ok(self.format_decimal(decimal)?.into()),
+            None => Err(FlowyError::invalid_params().context(format!("Invalid number: {}", cell_str))),
+        }
     }
 
     fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
-        match self.format_cell_data(&cell_data) {
-            Ok(cell_data) => cell_data.to_string(),
-            Err(_) => "".to_string(),
-        }
+        self.decimal_from_cell_str(&cell_data)
+            .and_then(|decimal| self.format_decimal(decimal).ok())
+            .unwrap_or_default()
     }
 }
 
@@ -178,13 +270,32 @@ impl CellDataChangeset for NumberTypeOptionPB {
         changeset: <Self as TypeOption>::CellChangeset,
         _type_cell_data: Option<TypeCellData>,
     ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
-        let data = changeset.trim().to_string();
-        let number_cell_data = self.format_cell_data(&data)?;
+        let changeset = changeset.trim();
+        if changeset.is_empty() {
+            return Ok((String::new(), Default::default()));
+        }
+        // The canonical decimal is stored, so switching the format doesn't change the number.
+        let decimal = self
+            .parse_number(changeset)
+            .ok_or_else(|| FlowyError::invalid_params().context(format!("Invalid number: {}", changeset)))?;
+        Ok((decimal.to_string(), self.format_decimal(decimal)?.into()))
+    }
 
-        match self.format {
-            NumberFormat::Num => Ok((number_cell_data.to_string(), number_cell_data.to_string().into())),
-            _ => Ok((data, number_cell_data.to_string().into())),
+    fn validate_cell_data(&self, cell_data: &<Self as TypeOption>::CellData) -> Option<CellValidationViolation> {
+        let number = self.decimal_from_cell_str(cell_data)?;
+        if let Some(min) = self.min.as_ref().and_then(|min| Decimal::from_str(min).ok()) {
+            if number < min {
+                let msg = format!("The number must be greater than or equal to {}", min);
+                return Some(CellValidationViolation::new(CellValidationRulePB::NumberMin, msg));
+            }
         }
+        if let Some(max) = self.max.as_ref().and_then(|max| Decimal::from_str(max).ok()) {
+            if number > max {
+                let msg = format!("The number must be less than or equal to {}", max);
+                return Some(CellValidationViolation::new(CellValidationRulePB::NumberMax, msg));
+            }
+        }
+        None
     }
 }
 
@@ -198,10 +309,11 @@ impl TypeOptionCellDataFilter for NumberTypeOptionPB {
         if !field_type.is_number() {
             return true;
         }
-        match self.format_cell_data(cell_data) {
-            Ok(cell_data) => filter.is_visible(&cell_data),
-            Err(_) => true,
-        }
+        let cell_data = self
+            .decimal_from_cell_str(cell_data)
+            .map(NumberCellData::from_decimal)
+            .unwrap_or_default();
+        filter.is_visible(&cell_data)
     }
 }
 
@@ -211,7 +323,9 @@ impl TypeOptionCellDataCompare for NumberTypeOptionPB {
         cell_data: &<Self as TypeOption>::CellData,
         other_cell_data: &<Self as TypeOption>::CellData,
     ) -> Ordering {
-        cell_data.0.cmp(&other_cell_data.0)
+        let decimal = self.decimal_from_cell_str(cell_data);
+        let other_decimal = self.decimal_from_cell_str(other_cell_data);
+        decimal.cmp(&other_decimal)
     }
 }
 impl std::default::Default for NumberTypeOptionPB {
@@ -224,6 +338,9 @@ impl std::default::Default for NumberTypeOptionPB {
             symbol,
             sign_positive: true,
             name: "Number".to_string(),
+            min: None,
+            max: None,
+            default_value: None,
         }
     }
 }
@@ -0,0 +1,8 @@
+#![allow(clippy::module_inception)]
+mod person_filter;
+mod person_tests;
+mod person_type_option;
+mod person_type_option_entities;
+
+pub use person_type_option::*;
+pub use person_type_option_entities::*;
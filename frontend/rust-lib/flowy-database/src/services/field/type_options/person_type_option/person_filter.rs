@@ -0,0 +1,78 @@
+use crate::entities::{PersonFilterConditionPB, PersonFilterPB};
+use crate::services::field::PersonCellData;
+
+impl PersonFilterPB {
+    pub fn is_visible(&self, cell_data: &PersonCellData) -> bool {
+        match self.condition {
+            PersonFilterConditionPB::PersonIs => {
+                if self.member_ids.is_empty() {
+                    return true;
+                }
+                cell_data
+                    .member_ids
+                    .iter()
+                    .any(|member_id| self.member_ids.contains(member_id))
+            }
+            PersonFilterConditionPB::PersonIsNot => {
+                if self.member_ids.is_empty() {
+                    return true;
+                }
+                !cell_data
+                    .member_ids
+                    .iter()
+                    .any(|member_id| self.member_ids.contains(member_id))
+            }
+            PersonFilterConditionPB::PersonIsEmpty => cell_data.member_ids.is_empty(),
+            PersonFilterConditionPB::PersonIsNotEmpty => !cell_data.member_ids.is_empty(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::{PersonFilterConditionPB, PersonFilterPB};
+    use crate::services::field::PersonCellData;
+
+    fn cell_data(member_ids: &[&str]) -> PersonCellData {
+        PersonCellData::new(member_ids.iter().map(|member_id| member_id.to_string()).collect())
+    }
+
+    fn person_filter(condition: PersonFilterConditionPB, member_ids: &[&str]) -> PersonFilterPB {
+        PersonFilterPB {
+            condition,
+            member_ids: member_ids.iter().map(|member_id| member_id.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn person_filter_is_test() {
+        let filter = person_filter(PersonFilterConditionPB::PersonIs, &["a", "b"]);
+        assert!(filter.is_visible(&cell_data(&["a"])));
+        assert!(filter.is_visible(&cell_data(&["c", "b"])));
+        assert!(!filter.is_visible(&cell_data(&["c"])));
+        assert!(!filter.is_visible(&cell_data(&[])));
+
+        // The filter without members shows all the cells.
+        let filter = person_filter(PersonFilterConditionPB::PersonIs, &[]);
+        assert!(filter.is_visible(&cell_data(&["c"])));
+    }
+
+    #[test]
+    fn person_filter_is_not_test() {
+        let filter = person_filter(PersonFilterConditionPB::PersonIsNot, &["a"]);
+        assert!(!filter.is_visible(&cell_data(&["a", "b"])));
+        assert!(filter.is_visible(&cell_data(&["b"])));
+        assert!(filter.is_visible(&cell_data(&[])));
+    }
+
+    #[test]
+    fn person_filter_is_empty_test() {
+        let filter = person_filter(PersonFilterConditionPB::PersonIsEmpty, &[]);
+        assert!(filter.is_visible(&cell_data(&[])));
+        assert!(!filter.is_visible(&cell_data(&["a"])));
+
+        let filter = person_filter(PersonFilterConditionPB::PersonIsNotEmpty, &[]);
+        assert!(!filter.is_visible(&cell_data(&[])));
+        assert!(filter.is_visible(&cell_data(&["a"])));
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use crate::entities::FieldType;
+    use crate::services::cell::{CellDataChangeset, CellDataDecoder, TypeCellData};
+    use crate::services::field::*;
+    use std::cmp::Ordering;
+
+    fn type_option(is_single: bool) -> PersonTypeOptionPB {
+        PersonTypeOptionPB {
+            is_single,
+            members: vec![MemberPB::new("a", "Lucas"), MemberPB::new("b", "Ada")],
+        }
+    }
+
+    fn cell_data(member_ids: &[&str]) -> PersonCellData {
+        PersonCellData::new(member_ids.iter().map(|member_id| member_id.to_string()).collect())
+    }
+
+    #[test]
+    fn person_type_option_insert_and_delete_members_test() {
+        let type_option = type_option(false);
+        let changeset =
+            PersonCellChangeset::from_insert_member_ids(vec!["a".to_owned(), "b".to_owned(), "a".to_owned()]);
+        let (cell_str, cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+        assert_eq!(cell_data.member_ids, vec!["a".to_owned(), "b".to_owned()]);
+        assert_eq!(cell_str, "a,b");
+
+        let type_cell_data = TypeCellData::new(cell_str, FieldType::Person);
+        let changeset = PersonCellChangeset::from_delete_member_ids(vec!["a".to_owned()]);
+        let (cell_str, _) = type_option.apply_changeset(changeset, Some(type_cell_data)).unwrap();
+        assert_eq!(cell_str, "b");
+    }
+
+    #[test]
+    fn person_type_option_single_member_test() {
+        let type_option = type_option(true);
+        let type_cell_data = TypeCellData::new("a".to_owned(), FieldType::Person);
+        let changeset = PersonCellChangeset::from_insert_member_ids(vec!["b".to_owned()]);
+        let (cell_str, _) = type_option.apply_changeset(changeset, Some(type_cell_data)).unwrap();
+        assert_eq!(cell_str, "b");
+    }
+
+    #[test]
+    fn person_type_option_decode_test() {
+        let field_rev = FieldBuilder::new(
+            PersonTypeOptionBuilder::default()
+                .add_member(MemberPB::new("a", "Lucas"))
+                .add_member(MemberPB::new("b", "Ada")),
+        )
+        .build();
+        let type_option = PersonTypeOptionPB::from(&field_rev);
+        let cell_data = type_option
+            .decode_cell_str("b,a".to_owned(), &FieldType::Person, &field_rev)
+            .unwrap();
+        assert_eq!(type_option.decode_cell_data_to_str(cell_data), "Ada,Lucas");
+
+        // The cell of other field types doesn't reference any member.
+        let cell_data = type_option
+            .decode_cell_str("a".to_owned(), &FieldType::RichText, &field_rev)
+            .unwrap();
+        assert!(cell_data.member_ids.is_empty());
+    }
+
+    #[test]
+    fn person_type_option_decode_unresolved_member_test() {
+        let type_option = type_option(false);
+        let cell_data_pb = type_option.convert_to_protobuf(cell_data(&["a", "removed"]));
+        assert_eq!(cell_data_pb.members.len(), 2);
+        assert!(!cell_data_pb.members[0].is_placeholder);
+        assert_eq!(cell_data_pb.members[1].id, "removed");
+        assert_eq!(cell_data_pb.members[1].name, UNKNOWN_MEMBER_NAME);
+        assert!(cell_data_pb.members[1].is_placeholder);
+    }
+
+    #[test]
+    fn person_type_option_insert_members_test() {
+        let mut type_option = type_option(false);
+        assert!(!type_option.insert_members(vec![MemberPB::new("a", "Lucas")]));
+        assert!(!type_option.insert_members(vec![MemberPB::placeholder("c")]));
+        assert!(type_option.insert_members(vec![MemberPB::new("a", "Luke"), MemberPB::new("c", "Nathan")]));
+        assert_eq!(type_option.get_member("a").name, "Luke");
+        assert_eq!(type_option.members.len(), 3);
+    }
+
+    #[test]
+    fn person_type_option_compare_test() {
+        let type_option = type_option(false);
+        // Ada < Lucas
+        assert_eq!(
+            type_option.apply_cmp(&cell_data(&["b"]), &cell_data(&["a"])),
+            Ordering::Less
+        );
+        assert_eq!(
+            type_option.apply_cmp(&cell_data(&["a", "b"]), &cell_data(&["a"])),
+            Ordering::Greater
+        );
+        assert_eq!(
+            type_option.apply_cmp(&cell_data(&[]), &cell_data(&["a"])),
+            Ordering::Less
+        );
+        assert_eq!(
+            type_option.apply_cmp(&cell_data(&["a"]), &cell_data(&["a"])),
+            Ordering::Equal
+        );
+    }
+}
@@ -0,0 +1,217 @@
+use crate::entities::{FieldType, PersonFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+    BoxTypeOptionBuilder, MemberPB, PersonCellChangeset, PersonCellData, PersonCellDataPB, TypeOption,
+    TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+    TypedCellData, SELECTION_IDS_SEPARATOR,
+};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::FlowyResult;
+use grid_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use lib_infra::async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// [MemberResolver] resolves the ids of the members into their names and avatars. The members are
+/// owned by the user's workspace, so the resolver is provided to the `DatabaseManager` in order to
+/// keep the database independent of the user crate.
+#[async_trait]
+pub trait MemberResolver: Send + Sync {
+    /// Returns the members in the order of the `member_ids`. The members that can't be resolved
+    /// anymore are skipped.
+    async fn resolve_members(&self, member_ids: &[String]) -> FlowyResult<Vec<MemberPB>>;
+
+    /// Returns all the members of the workspace. Each of them can be referenced by the person cells.
+    async fn get_members(&self) -> FlowyResult<Vec<MemberPB>>;
+}
+
+// Person
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ProtoBuf)]
+pub struct PersonTypeOptionPB {
+    /// Each cell references one member at most if it's true.
+    #[pb(index = 1)]
+    #[serde(default)]
+    pub is_single: bool,
+
+    /// The members that were resolved by the [MemberResolver]. The cells are decoded, sorted and
+    /// grouped with them because the resolver can't be called synchronously. The members are
+    /// refreshed when the cells are updated or the [RefreshPersonMembers] event is sent.
+    #[pb(index = 2)]
+    #[serde(default)]
+    pub members: Vec<MemberPB>,
+}
+impl_type_option!(PersonTypeOptionPB, FieldType::Person);
+
+impl PersonTypeOptionPB {
+    /// Returns the member with the id, or a placeholder if the member can't be resolved.
+    pub fn get_member(&self, member_id: &str) -> MemberPB {
+        self.members
+            .iter()
+            .find(|member| member.id == member_id)
+            .cloned()
+            .unwrap_or_else(|| MemberPB::placeholder(member_id))
+    }
+
+    /// Replaces the cached members that have the same ids and appends the others. Returns true if
+    /// the members are changed.
+    pub fn insert_members(&mut self, members: Vec<MemberPB>) -> bool {
+        let mut is_changed = false;
+        for member in members.into_iter().filter(|member| !member.is_placeholder) {
+            match self.members.iter_mut().find(|cached| cached.id == member.id) {
+                None => {
+                    self.members.push(member);
+                    is_changed = true;
+                }
+                Some(cached) => {
+                    if cached != &member {
+                        *cached = member;
+                        is_changed = true;
+                    }
+                }
+            }
+        }
+        is_changed
+    }
+
+    fn member_names(&self, cell_data: &PersonCellData) -> Vec<String> {
+        cell_data
+            .member_ids
+            .iter()
+            .map(|member_id| self.get_member(member_id).name)
+            .collect()
+    }
+}
+
+impl TypeOption for PersonTypeOptionPB {
+    type CellData = PersonCellData;
+    type CellChangeset = PersonCellChangeset;
+    type CellProtobufType = PersonCellDataPB;
+    type CellFilter = PersonFilterPB;
+}
+
+impl TypeOptionTransform for PersonTypeOptionPB {}
+
+impl TypeOptionCellData for PersonTypeOptionPB {
+    fn convert_to_protobuf(&self, cell_data: <Self as TypeOption>::CellData) -> <Self as TypeOption>::CellProtobufType {
+        PersonCellDataPB {
+            members: cell_data
+                .member_ids
+                .iter()
+                .map(|member_id| self.get_member(member_id))
+                .collect(),
+        }
+    }
+
+    fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
+        PersonCellData::from_cell_str(&cell_str)
+    }
+
+    fn convert_to_typed_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> TypedCellData {
+        TypedCellData::Person(
+            cell_data
+                .member_ids
+                .iter()
+                .map(|member_id| self.get_member(member_id))
+                .collect(),
+        )
+    }
+}
+
+impl CellDataDecoder for PersonTypeOptionPB {
+    fn decode_cell_str(
+        &self,
+        cell_str: String,
+        decoded_field_type: &FieldType,
+        _field_rev: &FieldRevision,
+    ) -> FlowyResult<<Self as TypeOption>::CellData> {
+        if !decoded_field_type.is_person() {
+            return Ok(Default::default());
+        }
+
+        self.decode_type_option_cell_str(cell_str)
+    }
+
+    fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+        self.member_names(&cell_data).join(SELECTION_IDS_SEPARATOR)
+    }
+}
+
+impl CellDataChangeset for PersonTypeOptionPB {
+    fn apply_changeset(
+        &self,
+        changeset: <Self as TypeOption>::CellChangeset,
+        type_cell_data: Option<TypeCellData>,
+    ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+        let mut cell_data = match type_cell_data {
+            Some(type_cell_data) if type_cell_data.is_person() => {
+                self.decode_type_option_cell_str(type_cell_data.cell_str)?
+            }
+            _ => PersonCellData::default(),
+        };
+        changeset.apply(&mut cell_data, self.is_single);
+        Ok((cell_data.to_string(), cell_data))
+    }
+}
+
+impl TypeOptionCellDataFilter for PersonTypeOptionPB {
+    fn apply_filter(
+        &self,
+        filter: &<Self as TypeOption>::CellFilter,
+        field_type: &FieldType,
+        cell_data: &<Self as TypeOption>::CellData,
+    ) -> bool {
+        if !field_type.is_person() {
+            return true;
+        }
+        filter.is_visible(cell_data)
+    }
+}
+
+impl TypeOptionCellDataCompare for PersonTypeOptionPB {
+    /// The cells are compared by the names of their members one by one, the cell that has fewer
+    /// members is less if all of its members are equal to the other's.
+    fn apply_cmp(
+        &self,
+        cell_data: &<Self as TypeOption>::CellData,
+        other_cell_data: &<Self as TypeOption>::CellData,
+    ) -> Ordering {
+        let names = self.member_names(cell_data);
+        let other_names = self.member_names(other_cell_data);
+        for (name, other_name) in names.iter().zip(other_names.iter()) {
+            let order = name.to_lowercase().cmp(&other_name.to_lowercase());
+            if order.is_ne() {
+                return order;
+            }
+        }
+        names.len().cmp(&other_names.len())
+    }
+}
+
+#[derive(Default)]
+pub struct PersonTypeOptionBuilder(PersonTypeOptionPB);
+impl_into_box_type_option_builder!(PersonTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(PersonTypeOptionBuilder, PersonTypeOptionPB);
+
+impl PersonTypeOptionBuilder {
+    pub fn is_single(mut self, is_single: bool) -> Self {
+        self.0.is_single = is_single;
+        self
+    }
+
+    pub fn add_member(mut self, member: MemberPB) -> Self {
+        self.0.members.push(member);
+        self
+    }
+}
+
+impl TypeOptionBuilder for PersonTypeOptionBuilder {
+    fn field_type(&self) -> FieldType {
+        FieldType::Person
+    }
+
+    fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+        &self.0
+    }
+}
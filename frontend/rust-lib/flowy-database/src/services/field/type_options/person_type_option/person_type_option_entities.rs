@@ -0,0 +1,216 @@
+use crate::entities::CellPathPB;
+use crate::services::cell::{
+    CellProtobufBlobParser, DecodedCellData, FromCellChangesetString, FromCellString, ToCellChangesetString,
+};
+use crate::services::field::SELECTION_IDS_SEPARATOR;
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::{internal_error, FlowyResult};
+use serde::{Deserialize, Serialize};
+
+/// The name of the member that can't be resolved anymore, for example, the member was removed
+/// from the workspace.
+pub const UNKNOWN_MEMBER_NAME: &str = "Unknown member";
+
+/// [MemberPB] is a member of the workspace that can be referenced by the person cells.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ProtoBuf)]
+pub struct MemberPB {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+
+    #[pb(index = 3)]
+    #[serde(default)]
+    pub avatar_url: String,
+
+    /// It's true if the member can't be resolved, the name of the placeholder is
+    /// [UNKNOWN_MEMBER_NAME]. The placeholders are never stored in the type option.
+    #[pb(index = 4)]
+    #[serde(skip)]
+    pub is_placeholder: bool,
+}
+
+impl MemberPB {
+    pub fn new(id: &str, name: &str) -> Self {
+        Self {
+            id: id.to_owned(),
+            name: name.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn placeholder(id: &str) -> Self {
+        Self {
+            id: id.to_owned(),
+            name: UNKNOWN_MEMBER_NAME.to_owned(),
+            avatar_url: "".to_owned(),
+            is_placeholder: true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RepeatedMemberPB {
+    #[pb(index = 1)]
+    pub items: Vec<MemberPB>,
+}
+
+impl std::convert::From<Vec<MemberPB>> for RepeatedMemberPB {
+    fn from(items: Vec<MemberPB>) -> Self {
+        Self { items }
+    }
+}
+
+/// [PersonCellData] contains the ids of the members that the cell references. It's stored as a
+/// list of ids separated by commas.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PersonCellData {
+    pub member_ids: Vec<String>,
+}
+
+impl PersonCellData {
+    pub fn new(member_ids: Vec<String>) -> Self {
+        Self { member_ids }
+    }
+}
+
+impl FromCellString for PersonCellData {
+    fn from_cell_str(s: &str) -> FlowyResult<Self> {
+        let member_ids = s
+            .split(SELECTION_IDS_SEPARATOR)
+            .filter(|member_id| !member_id.is_empty())
+            .map(|member_id| member_id.to_owned())
+            .collect::<Vec<String>>();
+        Ok(Self { member_ids })
+    }
+}
+
+impl ToString for PersonCellData {
+    fn to_string(&self) -> String {
+        self.member_ids.join(SELECTION_IDS_SEPARATOR)
+    }
+}
+
+impl DecodedCellData for PersonCellData {
+    type Object = PersonCellData;
+
+    fn is_empty(&self) -> bool {
+        self.member_ids.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct PersonCellDataPB {
+    /// The members in the order they were added to the cell. The member that can't be resolved is
+    /// a placeholder.
+    #[pb(index = 1)]
+    pub members: Vec<MemberPB>,
+}
+
+impl DecodedCellData for PersonCellDataPB {
+    type Object = PersonCellDataPB;
+
+    fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+}
+
+pub struct PersonCellDataParser();
+impl CellProtobufBlobParser for PersonCellDataParser {
+    type Object = PersonCellDataPB;
+
+    fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+        PersonCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+    }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct PersonCellChangesetPB {
+    #[pb(index = 1)]
+    pub cell_path: CellPathPB,
+
+    #[pb(index = 2)]
+    pub insert_member_ids: Vec<String>,
+
+    #[pb(index = 3)]
+    pub delete_member_ids: Vec<String>,
+}
+
+impl From<PersonCellChangesetPB> for PersonCellChangeset {
+    fn from(changeset: PersonCellChangesetPB) -> Self {
+        Self {
+            insert_member_ids: changeset.insert_member_ids,
+            delete_member_ids: changeset.delete_member_ids,
+        }
+    }
+}
+
+/// [PersonCellChangeset] adds or removes the members of the cell. The members are removed first
+/// and then added.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PersonCellChangeset {
+    #[serde(default)]
+    pub insert_member_ids: Vec<String>,
+
+    #[serde(default)]
+    pub delete_member_ids: Vec<String>,
+}
+
+impl PersonCellChangeset {
+    pub fn from_insert_member_ids(member_ids: Vec<String>) -> Self {
+        Self {
+            insert_member_ids: member_ids,
+            ..Default::default()
+        }
+    }
+
+    pub fn from_delete_member_ids(member_ids: Vec<String>) -> Self {
+        Self {
+            delete_member_ids: member_ids,
+            ..Default::default()
+        }
+    }
+
+    /// Only the last inserted member is kept if `is_single` is true, it replaces the member of the
+    /// cell.
+    pub fn apply(self, cell_data: &mut PersonCellData, is_single: bool) {
+        cell_data
+            .member_ids
+            .retain(|member_id| !self.delete_member_ids.contains(member_id));
+
+        if is_single {
+            if let Some(member_id) = self
+                .insert_member_ids
+                .into_iter()
+                .filter(|member_id| !member_id.is_empty())
+                .last()
+            {
+                cell_data.member_ids = vec![member_id];
+            }
+            return;
+        }
+
+        for member_id in self.insert_member_ids {
+            if !member_id.is_empty() && !cell_data.member_ids.contains(&member_id) {
+                cell_data.member_ids.push(member_id);
+            }
+        }
+    }
+}
+
+impl FromCellChangesetString for PersonCellChangeset {
+    fn from_changeset(changeset: String) -> FlowyResult<Self>
+    where
+        Self: Sized,
+    {
+        serde_json::from_str::<PersonCellChangeset>(&changeset).map_err(internal_error)
+    }
+}
+
+impl ToCellChangesetString for PersonCellChangeset {
+    fn to_cell_changeset_str(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
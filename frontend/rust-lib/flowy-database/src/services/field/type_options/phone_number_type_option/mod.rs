@@ -0,0 +1,7 @@
+#![allow(clippy::module_inception)]
+mod phone_number_tests;
+mod phone_number_type_option;
+mod phone_number_type_option_entities;
+
+pub use phone_number_type_option::*;
+pub use phone_number_type_option_entities::*;
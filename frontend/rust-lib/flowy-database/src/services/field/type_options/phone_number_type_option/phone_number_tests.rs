@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use crate::entities::{FieldType, TextFilterConditionPB, TextFilterPB};
+    use crate::services::cell::CellDataChangeset;
+    use crate::services::field::{
+        FieldBuilder, PhoneNumberCellData, PhoneNumberFormatPB, PhoneNumberTypeOptionPB, TypeOptionCellData,
+        TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+    };
+    use std::cmp::Ordering;
+
+    fn type_option(format: PhoneNumberFormatPB) -> PhoneNumberTypeOptionPB {
+        PhoneNumberTypeOptionPB {
+            format,
+            country_code: "1".to_owned(),
+        }
+    }
+
+    #[test]
+    fn phone_number_type_option_changeset_test() {
+        let type_option = type_option(PhoneNumberFormatPB::International);
+        let (cell_str, _) = type_option
+            .apply_changeset("+1 (555) 123-4567".to_owned(), None)
+            .unwrap();
+        assert_eq!(cell_str, "+15551234567");
+
+        let (cell_str, _) = type_option.apply_changeset("555.123.4567".to_owned(), None).unwrap();
+        assert_eq!(cell_str, "5551234567");
+
+        let (cell_str, _) = type_option.apply_changeset("".to_owned(), None).unwrap();
+        assert_eq!(cell_str, "");
+
+        assert!(type_option.apply_changeset("555-CALL-NOW".to_owned(), None).is_err());
+        assert!(type_option.apply_changeset("1+555".to_owned(), None).is_err());
+        assert!(type_option.apply_changeset("+".to_owned(), None).is_err());
+    }
+
+    #[test]
+    fn phone_number_type_option_format_test() {
+        let international = type_option(PhoneNumberFormatPB::International);
+        let national = type_option(PhoneNumberFormatPB::National);
+        let raw = type_option(PhoneNumberFormatPB::Raw);
+
+        let cell_data = PhoneNumberCellData::new("+15551234567");
+        assert_eq!(international.format(&cell_data), "+1 555 123 4567");
+        assert_eq!(national.format(&cell_data), "555 123 4567");
+        assert_eq!(raw.format(&cell_data), "+15551234567");
+
+        // The default country code is used if the phone number doesn't start with a `+`.
+        let cell_data = PhoneNumberCellData::new("5551234567");
+        assert_eq!(international.format(&cell_data), "+1 555 123 4567");
+        assert_eq!(national.format(&cell_data), "555 123 4567");
+
+        // The phone number of other countries can't be formatted as a national number.
+        let cell_data = PhoneNumberCellData::new("+442079460958");
+        assert_eq!(international.format(&cell_data), "+44 207 946 0958");
+        assert_eq!(national.format(&cell_data), "+44 207 946 0958");
+
+        let cell_data = PhoneNumberCellData::new("1234");
+        assert_eq!(PhoneNumberTypeOptionPB::default().format(&cell_data), "1234");
+    }
+
+    #[test]
+    fn phone_number_type_option_transform_from_text_test() {
+        let type_option = type_option(PhoneNumberFormatPB::International);
+        let field_rev = FieldBuilder::from_field_type(&FieldType::PhoneNumber).build();
+
+        let cell_data = type_option
+            .transform_type_option_cell_str("call me", &FieldType::RichText, &field_rev)
+            .unwrap();
+        let cell_data_pb = type_option.convert_to_protobuf(cell_data);
+        assert_eq!(cell_data_pb.phone_number, "call me");
+        assert_eq!(cell_data_pb.formatted, "call me");
+        assert!(!cell_data_pb.is_valid);
+
+        let cell_data = type_option
+            .transform_type_option_cell_str("(555) 123-4567", &FieldType::RichText, &field_rev)
+            .unwrap();
+        let cell_data_pb = type_option.convert_to_protobuf(cell_data);
+        assert_eq!(cell_data_pb.formatted, "+1 555 123 4567");
+        assert!(cell_data_pb.is_valid);
+    }
+
+    #[test]
+    fn phone_number_type_option_filter_test() {
+        let type_option = type_option(PhoneNumberFormatPB::International);
+        let field_type = FieldType::PhoneNumber;
+        let filter = TextFilterPB {
+            condition: TextFilterConditionPB::Contains,
+            content: "123-45".to_owned(),
+        };
+        assert!(type_option.apply_filter(&filter, &field_type, &PhoneNumberCellData::new("+15551234567")));
+        assert!(!type_option.apply_filter(&filter, &field_type, &PhoneNumberCellData::new("+442079460958")));
+
+        let filter = TextFilterPB {
+            condition: TextFilterConditionPB::Is,
+            content: "+1 (555) 123-4567".to_owned(),
+        };
+        assert!(type_option.apply_filter(&filter, &field_type, &PhoneNumberCellData::new("+15551234567")));
+        assert!(!type_option.apply_filter(&filter, &field_type, &PhoneNumberCellData::new("5551234567")));
+
+        let filter = TextFilterPB {
+            condition: TextFilterConditionPB::TextIsEmpty,
+            content: "".to_owned(),
+        };
+        assert!(type_option.apply_filter(&filter, &field_type, &PhoneNumberCellData::new("")));
+        assert!(!type_option.apply_filter(&filter, &field_type, &PhoneNumberCellData::new("call me")));
+    }
+
+    #[test]
+    fn phone_number_type_option_compare_test() {
+        let type_option = type_option(PhoneNumberFormatPB::International);
+        assert_eq!(
+            type_option.apply_cmp(
+                &PhoneNumberCellData::new("+1 555 123 4567"),
+                &PhoneNumberCellData::new("+15551234567")
+            ),
+            Ordering::Equal
+        );
+        assert_eq!(
+            type_option.apply_cmp(
+                &PhoneNumberCellData::new("+15551234567"),
+                &PhoneNumberCellData::new("+442079460958")
+            ),
+            Ordering::Less
+        );
+    }
+}
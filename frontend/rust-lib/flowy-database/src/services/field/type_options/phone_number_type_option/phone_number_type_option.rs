@@ -0,0 +1,205 @@
+use crate::entities::{FieldType, TextFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+    group_phone_number_digits, normalize_phone_number, phone_number_digits, BoxTypeOptionBuilder,
+    PhoneNumberCellChangeset, PhoneNumberCellData, PhoneNumberCellDataPB, PhoneNumberFormatPB, TypeOption,
+    TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+    TypedCellData,
+};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::FlowyResult;
+use grid_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+#[derive(Default)]
+pub struct PhoneNumberTypeOptionBuilder(PhoneNumberTypeOptionPB);
+impl_into_box_type_option_builder!(PhoneNumberTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(PhoneNumberTypeOptionBuilder, PhoneNumberTypeOptionPB);
+
+impl PhoneNumberTypeOptionBuilder {
+    pub fn format(mut self, format: PhoneNumberFormatPB) -> Self {
+        self.0.format = format;
+        self
+    }
+
+    pub fn country_code(mut self, country_code: &str) -> Self {
+        self.0.country_code = country_code.to_owned();
+        self
+    }
+}
+
+impl TypeOptionBuilder for PhoneNumberTypeOptionBuilder {
+    fn field_type(&self) -> FieldType {
+        FieldType::PhoneNumber
+    }
+
+    fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+        &self.0
+    }
+}
+
+// Phone Number
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ProtoBuf)]
+pub struct PhoneNumberTypeOptionPB {
+    #[pb(index = 1)]
+    #[serde(default)]
+    pub format: PhoneNumberFormatPB,
+
+    /// The country code of the phone numbers that don't start with a `+`, for example: `1`.
+    #[pb(index = 2)]
+    #[serde(default)]
+    pub country_code: String,
+}
+impl_type_option!(PhoneNumberTypeOptionPB, FieldType::PhoneNumber);
+
+impl PhoneNumberTypeOptionPB {
+    fn default_country_code(&self) -> &str {
+        self.country_code.trim().trim_start_matches('+')
+    }
+
+    /// Formats the phone number with the format of the type option. The cells that are not valid
+    /// are returned as they are.
+    pub fn format(&self, cell_data: &PhoneNumberCellData) -> String {
+        let phone_number = match cell_data.normalized() {
+            None => return cell_data.0.clone(),
+            Some(phone_number) => phone_number,
+        };
+        if phone_number.is_empty() || self.format == PhoneNumberFormatPB::Raw {
+            return phone_number;
+        }
+
+        let country_code = self.default_country_code();
+        // Splits the phone number into the country code and the national number. The country
+        // code of the phone number that starts with a `+` is only known if it's the default one.
+        let (country_code, national_number) = match phone_number.strip_prefix('+') {
+            None => (country_code, phone_number.as_str()),
+            Some(number) => match number.strip_prefix(country_code) {
+                Some(national_number) if !country_code.is_empty() && !national_number.is_empty() => {
+                    (country_code, national_number)
+                }
+                _ => return format!("+{}", group_phone_number_digits(number)),
+            },
+        };
+
+        let national_number = group_phone_number_digits(national_number);
+        if self.format == PhoneNumberFormatPB::National || country_code.is_empty() {
+            national_number
+        } else {
+            format!("+{} {}", country_code, national_number)
+        }
+    }
+}
+
+impl TypeOption for PhoneNumberTypeOptionPB {
+    type CellData = PhoneNumberCellData;
+    type CellChangeset = PhoneNumberCellChangeset;
+    type CellProtobufType = PhoneNumberCellDataPB;
+    type CellFilter = TextFilterPB;
+}
+
+impl TypeOptionTransform for PhoneNumberTypeOptionPB {
+    fn transformable(&self) -> bool {
+        true
+    }
+
+    fn transform_type_option_cell_str(
+        &self,
+        cell_str: &str,
+        decoded_field_type: &FieldType,
+        _field_rev: &FieldRevision,
+    ) -> Option<<Self as TypeOption>::CellData> {
+        if decoded_field_type.is_text() {
+            // The text is kept even if it's not a phone number, the cell is flagged as not valid.
+            PhoneNumberCellData::from_cell_str(cell_str).ok()
+        } else {
+            None
+        }
+    }
+}
+
+impl TypeOptionCellData for PhoneNumberTypeOptionPB {
+    fn convert_to_protobuf(&self, cell_data: <Self as TypeOption>::CellData) -> <Self as TypeOption>::CellProtobufType {
+        PhoneNumberCellDataPB {
+            formatted: self.format(&cell_data),
+            is_valid: cell_data.is_valid(),
+            phone_number: cell_data.0,
+        }
+    }
+
+    fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
+        PhoneNumberCellData::from_cell_str(&cell_str)
+    }
+
+    fn convert_to_typed_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> TypedCellData {
+        TypedCellData::PhoneNumber(cell_data.0)
+    }
+}
+
+impl CellDataDecoder for PhoneNumberTypeOptionPB {
+    fn decode_cell_str(
+        &self,
+        cell_str: String,
+        decoded_field_type: &FieldType,
+        _field_rev: &FieldRevision,
+    ) -> FlowyResult<<Self as TypeOption>::CellData> {
+        if !decoded_field_type.is_phone_number() {
+            return Ok(Default::default());
+        }
+
+        self.decode_type_option_cell_str(cell_str)
+    }
+
+    fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+        self.format(&cell_data)
+    }
+}
+
+impl CellDataChangeset for PhoneNumberTypeOptionPB {
+    fn apply_changeset(
+        &self,
+        changeset: <Self as TypeOption>::CellChangeset,
+        _type_cell_data: Option<TypeCellData>,
+    ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+        let phone_number = normalize_phone_number(&changeset)?;
+        let cell_data = PhoneNumberCellData(phone_number);
+        Ok((cell_data.to_string(), cell_data))
+    }
+}
+
+impl TypeOptionCellDataFilter for PhoneNumberTypeOptionPB {
+    fn apply_filter(
+        &self,
+        filter: &<Self as TypeOption>::CellFilter,
+        field_type: &FieldType,
+        cell_data: &<Self as TypeOption>::CellData,
+    ) -> bool {
+        if !field_type.is_phone_number() {
+            return true;
+        }
+
+        // The formatting characters are ignored, so `555-1234` matches `(555) 1234`. The filter
+        // that doesn't contain any digits is applied to the text of the cell.
+        let content = phone_number_digits(&filter.content);
+        if content.is_empty() {
+            return filter.is_visible(cell_data);
+        }
+        let filter = TextFilterPB {
+            condition: filter.condition.clone(),
+            content,
+        };
+        filter.is_visible(cell_data.digits())
+    }
+}
+
+impl TypeOptionCellDataCompare for PhoneNumberTypeOptionPB {
+    fn apply_cmp(
+        &self,
+        cell_data: &<Self as TypeOption>::CellData,
+        other_cell_data: &<Self as TypeOption>::CellData,
+    ) -> Ordering {
+        cell_data.digits().cmp(&other_cell_data.digits())
+    }
+}
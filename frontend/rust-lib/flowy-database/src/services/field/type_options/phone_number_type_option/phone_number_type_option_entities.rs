@@ -0,0 +1,165 @@
+use crate::services::cell::{CellProtobufBlobParser, DecodedCellData, FromCellString};
+use bytes::Bytes;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::{internal_error, ErrorCode, FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+
+/// The characters that are used to format the phone numbers. They are removed before the phone
+/// numbers are stored.
+const FORMATTING_CHARS: [char; 6] = [' ', '-', '(', ')', '.', '/'];
+
+/// [PhoneNumberCellData] is the phone number of the cell. The phone numbers that are entered by the
+/// user are stored without the formatting characters, for example: `+15551234567`. The cells that
+/// were converted from the text cells keep the text, even if it's not a phone number.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PhoneNumberCellData(pub String);
+
+impl PhoneNumberCellData {
+    pub fn new(s: &str) -> Self {
+        Self(s.trim().to_owned())
+    }
+
+    /// Returns the phone number without the formatting characters, or None if the cell doesn't
+    /// contain a phone number.
+    pub fn normalized(&self) -> Option<String> {
+        normalize_phone_number(&self.0).ok()
+    }
+
+    /// Returns false if the cell contains the text that isn't a phone number. The empty cell is
+    /// valid.
+    pub fn is_valid(&self) -> bool {
+        self.normalized().is_some()
+    }
+
+    /// Returns the digits of the cell, which are used to sort and filter the cells.
+    pub fn digits(&self) -> String {
+        phone_number_digits(&self.0)
+    }
+}
+
+impl FromCellString for PhoneNumberCellData {
+    fn from_cell_str(s: &str) -> FlowyResult<Self>
+    where
+        Self: Sized,
+    {
+        Ok(Self::new(s))
+    }
+}
+
+impl ToString for PhoneNumberCellData {
+    fn to_string(&self) -> String {
+        self.0.clone()
+    }
+}
+
+impl AsRef<str> for PhoneNumberCellData {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl DecodedCellData for PhoneNumberCellData {
+    type Object = PhoneNumberCellData;
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct PhoneNumberCellDataPB {
+    /// The phone number that is stored in the cell.
+    #[pb(index = 1)]
+    pub phone_number: String,
+
+    /// The phone number that is formatted with the format of the type option. It's the same as
+    /// the `phone_number` if the cell is not valid.
+    #[pb(index = 2)]
+    pub formatted: String,
+
+    /// The UI shows a warning if the cell is not valid.
+    #[pb(index = 3)]
+    pub is_valid: bool,
+}
+
+impl DecodedCellData for PhoneNumberCellDataPB {
+    type Object = PhoneNumberCellDataPB;
+
+    fn is_empty(&self) -> bool {
+        self.phone_number.is_empty()
+    }
+}
+
+pub struct PhoneNumberCellDataParser();
+impl CellProtobufBlobParser for PhoneNumberCellDataParser {
+    type Object = PhoneNumberCellDataPB;
+
+    fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+        PhoneNumberCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+    }
+}
+
+/// The changeset of the phone number cell may contain the formatting characters, for example:
+/// `+1 (555) 123-4567`. The empty string clears the cell.
+pub type PhoneNumberCellChangeset = String;
+
+/// Removes the formatting characters of the phone number. The phone number may start with a `+`
+/// and the other characters must be digits, otherwise it returns an error.
+pub fn normalize_phone_number(s: &str) -> FlowyResult<String> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Ok("".to_owned());
+    }
+
+    let (prefix, number) = match s.strip_prefix('+') {
+        Some(number) => ("+", number),
+        None => ("", s),
+    };
+    let digits = number
+        .chars()
+        .filter(|c| !FORMATTING_CHARS.contains(c))
+        .collect::<String>();
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        let msg = format!("Invalid phone number: {}", s);
+        return Err(FlowyError::new(ErrorCode::InvalidData, &msg));
+    }
+    Ok(format!("{}{}", prefix, digits))
+}
+
+pub fn phone_number_digits(s: &str) -> String {
+    s.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+/// Groups the digits by three, and the last four digits are kept together, for example:
+/// `555 123 4567`.
+pub(crate) fn group_phone_number_digits(digits: &str) -> String {
+    if digits.len() <= 4 {
+        return digits.to_owned();
+    }
+
+    let (head, tail) = digits.split_at(digits.len() - 4);
+    let head = head.chars().collect::<Vec<char>>();
+    let mut groups = head
+        .rchunks(3)
+        .rev()
+        .map(|chunk| chunk.iter().collect::<String>())
+        .collect::<Vec<String>>();
+    groups.push(tail.to_owned());
+    groups.join(" ")
+}
+
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Serialize, Deserialize, ProtoBuf_Enum)]
+pub enum PhoneNumberFormatPB {
+    /// The phone number is prefixed with the country code, for example: `+1 555 123 4567`.
+    International = 0,
+    /// The country code is omitted if it's the default country code, for example: `555 123 4567`.
+    National = 1,
+    /// The phone number is displayed as it's stored, for example: `+15551234567`.
+    Raw = 2,
+}
+
+impl std::default::Default for PhoneNumberFormatPB {
+    fn default() -> Self {
+        PhoneNumberFormatPB::International
+    }
+}
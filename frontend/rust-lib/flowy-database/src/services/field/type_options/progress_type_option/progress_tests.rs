@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod tests {
+    use crate::entities::{FieldType, NumberFilterConditionPB, NumberFilterPB};
+    use crate::services::cell::{CellDataChangeset, CellDataDecoder, TypeCellData};
+    use crate::services::field::*;
+    use grid_model::{CellRevision, RowRevision};
+    use std::cmp::Ordering;
+
+    #[test]
+    fn progress_type_option_changeset_test() {
+        let type_option = ProgressTypeOptionPB::default();
+        for (changeset, expected) in [
+            ("45%", "0.45"),
+            (" 45 % ", "0.45"),
+            ("0.45", "0.45"),
+            ("45", "0.45"),
+            ("1", "1"),
+            ("150%", "1"),
+            ("-5%", "0"),
+            ("", ""),
+        ] {
+            let (cell_str, _) = type_option.apply_changeset(changeset.to_owned(), None).unwrap();
+            assert_eq!(cell_str, expected, "changeset: {}", changeset);
+        }
+        assert!(type_option.apply_changeset("half".to_owned(), None).is_err());
+    }
+
+    #[test]
+    fn progress_type_option_format_test() {
+        let field_rev = FieldBuilder::from_field_type(&FieldType::Progress).build();
+        let type_option = ProgressTypeOptionPB {
+            precision: 1,
+            ..Default::default()
+        };
+        let cell_data = type_option
+            .decode_cell_str("0.4567".to_owned(), &FieldType::Progress, &field_rev)
+            .unwrap();
+        assert_eq!(type_option.decode_cell_data_to_str(cell_data), "45.7%");
+        assert_eq!(type_option.decode_cell_data_to_str(ProgressCellData(None)), "");
+    }
+
+    #[test]
+    fn progress_type_option_derive_from_checklist_test() {
+        let checklist_field = FieldBuilder::from_field_type(&FieldType::Checklist).build();
+        let builder = ProgressTypeOptionBuilder::default().checklist_field_id(&checklist_field.id);
+        let field_rev = FieldBuilder::new(builder).build();
+        let type_option = ProgressTypeOptionPB::from(&field_rev);
+        assert!(type_option.is_derived());
+        assert!(type_option.apply_changeset("45%".to_owned(), None).is_err());
+
+        let mut row_rev = RowRevision::new("");
+        assert_eq!(type_option.derive_cell_data(&row_rev), ProgressCellData(None));
+
+        let items = vec![
+            ChecklistItem {
+                done: true,
+                ..ChecklistItem::new("a")
+            },
+            ChecklistItem::new("b"),
+            ChecklistItem::new("c"),
+            ChecklistItem {
+                done: true,
+                ..ChecklistItem::new("d")
+            },
+        ];
+        let type_cell_data = TypeCellData::new(ChecklistCellData { items }.to_string(), FieldType::Checklist);
+        row_rev
+            .cells
+            .insert(checklist_field.id.clone(), CellRevision::new(type_cell_data.to_json()));
+        assert_eq!(type_option.derive_cell_data(&row_rev), ProgressCellData::new(0.5));
+    }
+
+    #[test]
+    fn progress_type_option_transform_number_test() {
+        let mut field_rev = FieldBuilder::new(NumberTypeOptionBuilder::default()).build();
+        field_rev.ty = FieldType::Progress.into();
+        let type_option = ProgressTypeOptionPB::default();
+        for (cell_str, expected) in [("45", Some(0.45)), ("250", Some(1.0)), ("", None)] {
+            let cell_data = type_option
+                .transform_type_option_cell_str(cell_str, &FieldType::Number, &field_rev)
+                .unwrap();
+            assert_eq!(cell_data, ProgressCellData(expected), "cell_str: {}", cell_str);
+        }
+
+        // Converting the progress back to the number multiplies it by 100.
+        let number_type_option = NumberTypeOptionPB::default();
+        let cell_data = number_type_option
+            .transform_type_option_cell_str("0.45", &FieldType::Progress, &field_rev)
+            .unwrap();
+        assert_eq!(cell_data.as_str(), "45");
+    }
+
+    #[test]
+    fn progress_type_option_filter_and_compare_test() {
+        let type_option = ProgressTypeOptionPB::default();
+        let filter = NumberFilterPB {
+            condition: NumberFilterConditionPB::GreaterThan,
+            content: "50".to_owned(),
+            ..Default::default()
+        };
+        assert!(type_option.apply_filter(&filter, &FieldType::Progress, &ProgressCellData::new(0.75)));
+        assert!(!type_option.apply_filter(&filter, &FieldType::Progress, &ProgressCellData::new(0.5)));
+        assert!(!type_option.apply_filter(&filter, &FieldType::Progress, &ProgressCellData(None)));
+
+        let filter = NumberFilterPB {
+            condition: NumberFilterConditionPB::Between,
+            content: "20,45".to_owned(),
+            ..Default::default()
+        };
+        assert!(type_option.apply_filter(&filter, &FieldType::Progress, &ProgressCellData::new(0.45)));
+        assert!(!type_option.apply_filter(&filter, &FieldType::Progress, &ProgressCellData::new(0.46)));
+
+        assert_eq!(
+            type_option.apply_cmp(&ProgressCellData::new(0.1), &ProgressCellData::new(0.4)),
+            Ordering::Less
+        );
+        assert_eq!(
+            type_option.apply_cmp(&ProgressCellData::new(0.1), &ProgressCellData(None)),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn progress_average_test() {
+        let cell_datas = [
+            ProgressCellData::new(0.2),
+            ProgressCellData(None),
+            ProgressCellData::new(0.6),
+        ];
+        let average = average_progress(&cell_datas).unwrap();
+        assert!((average - 0.4).abs() < f64::EPSILON);
+        assert_eq!(average_progress(&[ProgressCellData(None)]), None);
+    }
+}
@@ -0,0 +1,220 @@
+use crate::entities::{FieldType, NumberFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+    default_order, parse_progress_changeset, BoxTypeOptionBuilder, ChecklistCellData, NumberCellData,
+    NumberTypeOptionPB, ProgressCellChangeset, ProgressCellData, ProgressCellDataPB, ProgressSourcePB, TypeOption,
+    TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+    TypedCellData,
+};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use grid_model::{FieldRevision, RowRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+#[derive(Default)]
+pub struct ProgressTypeOptionBuilder(ProgressTypeOptionPB);
+impl_into_box_type_option_builder!(ProgressTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(ProgressTypeOptionBuilder, ProgressTypeOptionPB);
+
+impl ProgressTypeOptionBuilder {
+    pub fn precision(mut self, precision: u32) -> Self {
+        self.0.precision = precision;
+        self
+    }
+
+    /// Derives the progress from the checklist field with id.
+    pub fn checklist_field_id(mut self, field_id: &str) -> Self {
+        self.0.source = ProgressSourcePB::Checklist;
+        self.0.checklist_field_id = field_id.to_owned();
+        self
+    }
+}
+
+impl TypeOptionBuilder for ProgressTypeOptionBuilder {
+    fn field_type(&self) -> FieldType {
+        FieldType::Progress
+    }
+
+    fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+        &self.0
+    }
+}
+
+// Progress
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ProtoBuf)]
+pub struct ProgressTypeOptionPB {
+    /// The number of the decimal places of the percentage.
+    #[pb(index = 1)]
+    #[serde(default)]
+    pub precision: u32,
+
+    #[pb(index = 2)]
+    #[serde(default)]
+    pub source: ProgressSourcePB,
+
+    /// The id of the checklist field that the progress is derived from. It's only used if the
+    /// source is [ProgressSourcePB::Checklist].
+    #[pb(index = 3)]
+    #[serde(default)]
+    pub checklist_field_id: String,
+}
+impl_type_option!(ProgressTypeOptionPB, FieldType::Progress);
+
+impl ProgressTypeOptionPB {
+    /// Returns true if the cells are derived from the checklist cells instead of being stored in
+    /// the row. The cells can't be edited in this case.
+    pub fn is_derived(&self) -> bool {
+        self.source == ProgressSourcePB::Checklist
+    }
+
+    /// Returns the percentage of the done items of the checklist cell of the row. It's empty if the
+    /// row doesn't contain the checklist cell or the checklist doesn't have any items. The cells
+    /// that only contain the ids of the done options are treated as empty, because their items
+    /// are stored in the type option of the checklist field.
+    pub fn derive_cell_data(&self, row_rev: &RowRevision) -> ProgressCellData {
+        row_rev
+            .cells
+            .get(&self.checklist_field_id)
+            .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+            .filter(|type_cell_data| type_cell_data.is_checklist())
+            .and_then(|type_cell_data| ChecklistCellData::from_cell_str(&type_cell_data.cell_str).ok())
+            .filter(|cell_data| !cell_data.items.is_empty())
+            .map(|cell_data| ProgressCellData::new(cell_data.percentage()))
+            .unwrap_or_default()
+    }
+
+    /// Formats the cell as a percentage, for example: `45%`.
+    pub fn format(&self, cell_data: &ProgressCellData) -> String {
+        match cell_data.percent() {
+            None => "".to_string(),
+            Some(percent) => format!("{:.*}%", self.precision as usize, percent),
+        }
+    }
+}
+
+impl TypeOption for ProgressTypeOptionPB {
+    type CellData = ProgressCellData;
+    type CellChangeset = ProgressCellChangeset;
+    type CellProtobufType = ProgressCellDataPB;
+    type CellFilter = NumberFilterPB;
+}
+
+impl TypeOptionTransform for ProgressTypeOptionPB {
+    fn transformable(&self) -> bool {
+        true
+    }
+
+    fn transform_type_option_cell_str(
+        &self,
+        cell_str: &str,
+        decoded_field_type: &FieldType,
+        field_rev: &FieldRevision,
+    ) -> Option<<Self as TypeOption>::CellData> {
+        if decoded_field_type.is_number() {
+            // The numbers are treated as percentages, so `45` is 45%.
+            let percent = NumberTypeOptionPB::from(field_rev)
+                .format_cell_data(cell_str)
+                .ok()
+                .and_then(|cell_data| (*cell_data.decimal()).and_then(|decimal| decimal.to_f64()));
+            match percent {
+                None => Some(ProgressCellData(None)),
+                Some(percent) => Some(ProgressCellData::new(percent / 100.0)),
+            }
+        } else {
+            None
+        }
+    }
+}
+
+impl TypeOptionCellData for ProgressTypeOptionPB {
+    fn convert_to_protobuf(&self, cell_data: <Self as TypeOption>::CellData) -> <Self as TypeOption>::CellProtobufType {
+        ProgressCellDataPB {
+            value: cell_data.0.unwrap_or_default(),
+            content: self.format(&cell_data),
+        }
+    }
+
+    fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
+        ProgressCellData::from_cell_str(&cell_str)
+    }
+
+    fn convert_to_typed_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> TypedCellData {
+        TypedCellData::Progress(cell_data.0)
+    }
+}
+
+impl CellDataDecoder for ProgressTypeOptionPB {
+    fn decode_cell_str(
+        &self,
+        cell_str: String,
+        decoded_field_type: &FieldType,
+        _field_rev: &FieldRevision,
+    ) -> FlowyResult<<Self as TypeOption>::CellData> {
+        if !decoded_field_type.is_progress() {
+            return Ok(Default::default());
+        }
+
+        self.decode_type_option_cell_str(cell_str)
+    }
+
+    fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+        self.format(&cell_data)
+    }
+}
+
+impl CellDataChangeset for ProgressTypeOptionPB {
+    fn apply_changeset(
+        &self,
+        changeset: <Self as TypeOption>::CellChangeset,
+        _type_cell_data: Option<TypeCellData>,
+    ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+        if self.is_derived() {
+            return Err(FlowyError::new(
+                ErrorCode::FieldInvalidOperation,
+                "The cells of the progress field that is derived from a checklist are read-only",
+            ));
+        }
+
+        let cell_data = parse_progress_changeset(&changeset)?;
+        Ok((cell_data.to_string(), cell_data))
+    }
+}
+
+impl TypeOptionCellDataFilter for ProgressTypeOptionPB {
+    fn apply_filter(
+        &self,
+        filter: &<Self as TypeOption>::CellFilter,
+        field_type: &FieldType,
+        cell_data: &<Self as TypeOption>::CellData,
+    ) -> bool {
+        if !field_type.is_progress() {
+            return true;
+        }
+
+        // The content of the filter is a percentage, so `50` is 50%.
+        let num_cell_data = match cell_data.percent_decimal() {
+            None => NumberCellData::new(),
+            Some(percent) => NumberCellData::from_decimal(percent),
+        };
+        filter.is_visible(&num_cell_data)
+    }
+}
+
+impl TypeOptionCellDataCompare for ProgressTypeOptionPB {
+    fn apply_cmp(
+        &self,
+        cell_data: &<Self as TypeOption>::CellData,
+        other_cell_data: &<Self as TypeOption>::CellData,
+    ) -> Ordering {
+        match (cell_data.0, other_cell_data.0) {
+            (Some(left), Some(right)) => left.partial_cmp(&right).unwrap_or(Ordering::Equal),
+            (Some(_), None) => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (None, None) => default_order(),
+        }
+    }
+}
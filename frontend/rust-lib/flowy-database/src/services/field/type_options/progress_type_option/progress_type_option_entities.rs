@@ -0,0 +1,155 @@
+use crate::services::cell::{CellProtobufBlobParser, DecodedCellData, FromCellString};
+use bytes::Bytes;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::{internal_error, ErrorCode, FlowyError, FlowyResult};
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+const PERCENT_DECIMAL_PLACES: u32 = 8;
+
+/// [ProgressCellData] is the completion of the cell, from 0 to 1. It's stored as a float string, and
+/// the empty cell is stored as an empty string.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ProgressCellData(pub Option<f64>);
+
+impl ProgressCellData {
+    /// The value is clamped between 0 and 1.
+    pub fn new(value: f64) -> Self {
+        if value.is_finite() {
+            Self(Some(value.clamp(0.0, 1.0)))
+        } else {
+            Self(None)
+        }
+    }
+
+    /// Returns the completion from 0 to 100.
+    pub fn percent(&self) -> Option<f64> {
+        self.0.map(|value| value * 100.0)
+    }
+
+    /// Same as `percent` but avoids the rounding errors of the float, so `0.45` is exactly `45`.
+    pub fn percent_decimal(&self) -> Option<Decimal> {
+        let value = Decimal::from_f64(self.0?)?;
+        Some(
+            (value * Decimal::from(100))
+                .round_dp(PERCENT_DECIMAL_PLACES)
+                .normalize(),
+        )
+    }
+}
+
+impl FromCellString for ProgressCellData {
+    fn from_cell_str(s: &str) -> FlowyResult<Self>
+    where
+        Self: Sized,
+    {
+        match s.trim().parse::<f64>() {
+            Ok(value) => Ok(Self::new(value)),
+            Err(_) => Ok(Self(None)),
+        }
+    }
+}
+
+impl ToString for ProgressCellData {
+    fn to_string(&self) -> String {
+        match self.0 {
+            None => "".to_string(),
+            Some(value) => value.to_string(),
+        }
+    }
+}
+
+impl DecodedCellData for ProgressCellData {
+    type Object = ProgressCellData;
+
+    fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+/// Returns the average of the cells that are not empty, or None if all of them are empty.
+pub fn average_progress<'a>(cell_datas: impl IntoIterator<Item = &'a ProgressCellData>) -> Option<f64> {
+    let values = cell_datas
+        .into_iter()
+        .flat_map(|cell_data| cell_data.0)
+        .collect::<Vec<f64>>();
+    if values.is_empty() {
+        return None;
+    }
+    Some(values.iter().sum::<f64>() / values.len() as f64)
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct ProgressCellDataPB {
+    /// The completion from 0 to 1.
+    #[pb(index = 1)]
+    pub value: f64,
+
+    /// The percentage that is formatted with the precision of the type option, for example: `45%`.
+    /// It's empty if the cell is empty.
+    #[pb(index = 2)]
+    pub content: String,
+}
+
+impl DecodedCellData for ProgressCellDataPB {
+    type Object = ProgressCellDataPB;
+
+    fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+}
+
+pub struct ProgressCellDataParser();
+impl CellProtobufBlobParser for ProgressCellDataParser {
+    type Object = ProgressCellDataPB;
+
+    fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+        ProgressCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+    }
+}
+
+/// The changeset of the progress cell is a percentage, for example: `45%`, or a fraction, for
+/// example: `0.45`. The numbers that are greater than 1 are treated as percentages too, so `45`
+/// is the same as `45%`. The empty string clears the cell.
+pub type ProgressCellChangeset = String;
+
+pub(crate) fn parse_progress_changeset(changeset: &str) -> FlowyResult<ProgressCellData> {
+    let s = changeset.trim();
+    if s.is_empty() {
+        return Ok(ProgressCellData(None));
+    }
+
+    let (num_str, is_percent) = match s.strip_suffix('%') {
+        Some(num_str) => (num_str.trim(), true),
+        None => (s, false),
+    };
+    match num_str.parse::<f64>() {
+        Ok(num) if num.is_finite() => {
+            if is_percent || num > 1.0 {
+                Ok(ProgressCellData::new(num / 100.0))
+            } else {
+                Ok(ProgressCellData::new(num))
+            }
+        }
+        _ => {
+            let msg = format!("Invalid progress: {}", changeset);
+            Err(FlowyError::new(ErrorCode::InvalidData, &msg))
+        }
+    }
+}
+
+/// Where the progress of the cells comes from.
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Serialize, Deserialize, ProtoBuf_Enum)]
+pub enum ProgressSourcePB {
+    /// The progress is entered by the user.
+    Manual = 0,
+    /// The progress is the percentage of the done items of the checklist cell in the same row.
+    Checklist = 1,
+}
+
+impl std::default::Default for ProgressSourcePB {
+    fn default() -> Self {
+        ProgressSourcePB::Manual
+    }
+}
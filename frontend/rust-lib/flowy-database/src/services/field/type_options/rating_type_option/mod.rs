@@ -0,0 +1,7 @@
+#![allow(clippy::module_inception)]
+mod rating_tests;
+mod rating_type_option;
+mod rating_type_option_entities;
+
+pub use rating_type_option::*;
+pub use rating_type_option_entities::*;
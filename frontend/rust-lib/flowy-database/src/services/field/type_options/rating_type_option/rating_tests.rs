@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use crate::entities::{FieldType, NumberFilterConditionPB, NumberFilterPB};
+    use crate::services::cell::{CellDataChangeset, CellDataDecoder};
+    use crate::services::field::*;
+    use grid_model::TypeOptionDataDeserializer;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn rating_type_option_default_test() {
+        let field_rev = FieldBuilder::from_field_type(&FieldType::Rating).build();
+        let type_option = RatingTypeOptionPB::from(&field_rev);
+        assert_eq!(type_option.max, DEFAULT_MAX_RATING);
+        assert_eq!(type_option.symbol, RatingSymbolPB::Star);
+
+        // The missing properties of the json are filled with the default values.
+        let type_option = RatingTypeOptionPB::from_json_str(r#"{"symbol":"Heart"}"#);
+        assert_eq!(type_option.max, DEFAULT_MAX_RATING);
+        assert_eq!(type_option.symbol, RatingSymbolPB::Heart);
+    }
+
+    #[test]
+    fn rating_type_option_changeset_test() {
+        let type_option = RatingTypeOptionPB {
+            max: 5,
+            ..Default::default()
+        };
+        for (changeset, expected) in [
+            ("3", "3"),
+            (r#""4""#, "4"),
+            (" 2 ", "2"),
+            ("2.6", "3"),
+            ("9", "5"),
+            ("-1", ""),
+            ("", ""),
+            (r#""""#, ""),
+        ] {
+            let (cell_str, _) = type_option.apply_changeset(changeset.to_owned(), None).unwrap();
+            assert_eq!(cell_str, expected, "changeset: {}", changeset);
+        }
+        assert!(type_option.apply_changeset("three".to_owned(), None).is_err());
+    }
+
+    #[test]
+    fn rating_type_option_decode_test() {
+        let field_rev = FieldBuilder::from_field_type(&FieldType::Rating).build();
+        let type_option = RatingTypeOptionPB {
+            max: 3,
+            ..Default::default()
+        };
+        // The ratings that are out of the range are clamped when the max is lowered.
+        let cell_data = type_option
+            .decode_cell_str("5".to_owned(), &FieldType::Rating, &field_rev)
+            .unwrap();
+        assert_eq!(cell_data, RatingCellData(3));
+        assert_eq!(type_option.decode_cell_data_to_str(cell_data), "3");
+    }
+
+    #[test]
+    fn rating_type_option_transform_number_test() {
+        let number_type_option = NumberTypeOptionBuilder::default().set_format(NumberFormat::USD);
+        let mut field_rev = FieldBuilder::new(number_type_option).build();
+        field_rev.ty = FieldType::Rating.into();
+        let type_option = RatingTypeOptionPB::default();
+        for (cell_str, expected) in [("$2", 2), ("$2.6", 3), ("$12", 5), ("", 0)] {
+            let cell_data = type_option
+                .transform_type_option_cell_str(cell_str, &FieldType::Number, &field_rev)
+                .unwrap();
+            assert_eq!(cell_data, RatingCellData(expected), "cell_str: {}", cell_str);
+        }
+    }
+
+    #[test]
+    fn rating_type_option_filter_and_compare_test() {
+        let type_option = RatingTypeOptionPB::default();
+        let filter = NumberFilterPB {
+            condition: NumberFilterConditionPB::GreaterThan,
+            content: "2".to_owned(),
+            ..Default::default()
+        };
+        assert!(type_option.apply_filter(&filter, &FieldType::Rating, &RatingCellData(3)));
+        assert!(!type_option.apply_filter(&filter, &FieldType::Rating, &RatingCellData(2)));
+        assert!(!type_option.apply_filter(&filter, &FieldType::Rating, &RatingCellData(0)));
+
+        let filter = NumberFilterPB {
+            condition: NumberFilterConditionPB::NumberIsEmpty,
+            content: "".to_owned(),
+            ..Default::default()
+        };
+        assert!(type_option.apply_filter(&filter, &FieldType::Rating, &RatingCellData(0)));
+
+        assert_eq!(
+            type_option.apply_cmp(&RatingCellData(1), &RatingCellData(4)),
+            Ordering::Less
+        );
+        assert_eq!(
+            type_option.apply_cmp(&RatingCellData(0), &RatingCellData(0)),
+            Ordering::Equal
+        );
+    }
+}
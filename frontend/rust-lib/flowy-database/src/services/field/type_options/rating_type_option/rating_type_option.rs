@@ -0,0 +1,186 @@
+use crate::entities::{FieldType, NumberFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+    parse_rating_changeset, BoxTypeOptionBuilder, NumberCellData, NumberTypeOptionPB, RatingCellChangeset,
+    RatingCellData, RatingSymbolPB, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
+    TypeOptionCellDataFilter, TypeOptionTransform, TypedCellData,
+};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::FlowyResult;
+use grid_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+pub const DEFAULT_MAX_RATING: i64 = 5;
+
+#[derive(Default)]
+pub struct RatingTypeOptionBuilder(RatingTypeOptionPB);
+impl_into_box_type_option_builder!(RatingTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(RatingTypeOptionBuilder, RatingTypeOptionPB);
+
+impl RatingTypeOptionBuilder {
+    pub fn max(mut self, max: i64) -> Self {
+        self.0.max = max;
+        self
+    }
+
+    pub fn symbol(mut self, symbol: RatingSymbolPB) -> Self {
+        self.0.symbol = symbol;
+        self
+    }
+}
+
+impl TypeOptionBuilder for RatingTypeOptionBuilder {
+    fn field_type(&self) -> FieldType {
+        FieldType::Rating
+    }
+
+    fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+        &self.0
+    }
+}
+
+// Rating
+#[derive(Clone, Debug, Serialize, Deserialize, ProtoBuf)]
+pub struct RatingTypeOptionPB {
+    /// The cells are clamped between 0 and the max rating.
+    #[pb(index = 1)]
+    #[serde(default = "default_max_rating")]
+    pub max: i64,
+
+    #[pb(index = 2)]
+    #[serde(default)]
+    pub symbol: RatingSymbolPB,
+}
+impl_type_option!(RatingTypeOptionPB, FieldType::Rating);
+
+fn default_max_rating() -> i64 {
+    DEFAULT_MAX_RATING
+}
+
+impl std::default::Default for RatingTypeOptionPB {
+    fn default() -> Self {
+        Self {
+            max: DEFAULT_MAX_RATING,
+            symbol: RatingSymbolPB::default(),
+        }
+    }
+}
+
+impl RatingTypeOptionPB {
+    pub fn clamp(&self, rating: i64) -> RatingCellData {
+        RatingCellData(rating.clamp(0, self.max.max(0)))
+    }
+}
+
+impl TypeOption for RatingTypeOptionPB {
+    type CellData = RatingCellData;
+    type CellChangeset = RatingCellChangeset;
+    type CellProtobufType = RatingCellData;
+    type CellFilter = NumberFilterPB;
+}
+
+impl TypeOptionTransform for RatingTypeOptionPB {
+    fn transformable(&self) -> bool {
+        true
+    }
+
+    fn transform_type_option_cell_str(
+        &self,
+        cell_str: &str,
+        decoded_field_type: &FieldType,
+        field_rev: &FieldRevision,
+    ) -> Option<<Self as TypeOption>::CellData> {
+        if decoded_field_type.is_number() {
+            // The numbers are rounded and clamped into the range of the rating.
+            let rating = NumberTypeOptionPB::from(field_rev)
+                .format_cell_data(cell_str)
+                .ok()
+                .and_then(|cell_data| (*cell_data.decimal()).and_then(|decimal| decimal.round().to_i64()))
+                .unwrap_or_default();
+            Some(self.clamp(rating))
+        } else {
+            None
+        }
+    }
+}
+
+impl TypeOptionCellData for RatingTypeOptionPB {
+    fn convert_to_protobuf(&self, cell_data: <Self as TypeOption>::CellData) -> <Self as TypeOption>::CellProtobufType {
+        cell_data
+    }
+
+    fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
+        let cell_data = RatingCellData::from_cell_str(&cell_str)?;
+        Ok(self.clamp(cell_data.0))
+    }
+
+    fn convert_to_typed_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> TypedCellData {
+        TypedCellData::Rating(cell_data.0)
+    }
+}
+
+impl CellDataDecoder for RatingTypeOptionPB {
+    fn decode_cell_str(
+        &self,
+        cell_str: String,
+        decoded_field_type: &FieldType,
+        _field_rev: &FieldRevision,
+    ) -> FlowyResult<<Self as TypeOption>::CellData> {
+        if !decoded_field_type.is_rating() {
+            return Ok(Default::default());
+        }
+
+        self.decode_type_option_cell_str(cell_str)
+    }
+
+    fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+        cell_data.to_string()
+    }
+}
+
+impl CellDataChangeset for RatingTypeOptionPB {
+    fn apply_changeset(
+        &self,
+        changeset: <Self as TypeOption>::CellChangeset,
+        _type_cell_data: Option<TypeCellData>,
+    ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+        let cell_data = self.clamp(parse_rating_changeset(&changeset)?);
+        Ok((cell_data.to_string(), cell_data))
+    }
+}
+
+impl TypeOptionCellDataFilter for RatingTypeOptionPB {
+    fn apply_filter(
+        &self,
+        filter: &<Self as TypeOption>::CellFilter,
+        field_type: &FieldType,
+        cell_data: &<Self as TypeOption>::CellData,
+    ) -> bool {
+        if !field_type.is_rating() {
+            return true;
+        }
+
+        // The cells that are not rated are treated as empty numbers.
+        let num_cell_data = if cell_data.is_rated() {
+            NumberCellData::from_decimal(Decimal::from(cell_data.0))
+        } else {
+            NumberCellData::new()
+        };
+        filter.is_visible(&num_cell_data)
+    }
+}
+
+impl TypeOptionCellDataCompare for RatingTypeOptionPB {
+    fn apply_cmp(
+        &self,
+        cell_data: &<Self as TypeOption>::CellData,
+        other_cell_data: &<Self as TypeOption>::CellData,
+    ) -> Ordering {
+        cell_data.0.cmp(&other_cell_data.0)
+    }
+}
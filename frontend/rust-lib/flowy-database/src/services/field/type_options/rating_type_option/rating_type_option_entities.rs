@@ -0,0 +1,106 @@
+use crate::services::cell::{CellProtobufBlobParser, DecodedCellData, FromCellString};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf_Enum;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use protobuf::ProtobufError;
+use serde::{Deserialize, Serialize};
+
+/// [RatingCellData] is the number of the stars of the cell. The cell is not rated if it's 0, and
+/// it's stored as an empty string.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RatingCellData(pub i64);
+
+impl RatingCellData {
+    pub fn is_rated(&self) -> bool {
+        self.0 > 0
+    }
+}
+
+impl FromCellString for RatingCellData {
+    fn from_cell_str(s: &str) -> FlowyResult<Self>
+    where
+        Self: Sized,
+    {
+        Ok(Self(s.trim().parse::<i64>().unwrap_or_default()))
+    }
+}
+
+impl ToString for RatingCellData {
+    fn to_string(&self) -> String {
+        if self.is_rated() {
+            self.0.to_string()
+        } else {
+            "".to_string()
+        }
+    }
+}
+
+impl std::convert::TryFrom<RatingCellData> for Bytes {
+    type Error = ProtobufError;
+
+    fn try_from(value: RatingCellData) -> Result<Self, Self::Error> {
+        Ok(Bytes::from(value.to_string()))
+    }
+}
+
+impl DecodedCellData for RatingCellData {
+    type Object = RatingCellData;
+
+    fn is_empty(&self) -> bool {
+        !self.is_rated()
+    }
+}
+
+pub struct RatingCellDataParser();
+impl CellProtobufBlobParser for RatingCellDataParser {
+    type Object = RatingCellData;
+    fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+        match String::from_utf8(bytes.to_vec()) {
+            Ok(s) => RatingCellData::from_cell_str(&s),
+            Err(_) => Ok(RatingCellData::default()),
+        }
+    }
+}
+
+/// The changeset of the rating cell is an integer, or an integer in a json string. For example:
+/// `3` or `"3"`. The empty string clears the rating.
+pub type RatingCellChangeset = String;
+
+pub(crate) fn parse_rating_changeset(changeset: &str) -> FlowyResult<i64> {
+    let s = changeset.trim();
+    if s.is_empty() {
+        return Ok(0);
+    }
+
+    let s = match serde_json::from_str::<serde_json::Value>(s) {
+        Ok(serde_json::Value::String(inner)) => inner,
+        _ => s.to_owned(),
+    };
+    let s = s.trim();
+    if s.is_empty() {
+        return Ok(0);
+    }
+    match s.parse::<i64>() {
+        Ok(rating) => Ok(rating),
+        Err(_) => match s.parse::<f64>() {
+            Ok(rating) if rating.is_finite() => Ok(rating.round() as i64),
+            _ => {
+                let msg = format!("Invalid rating: {}", changeset);
+                Err(FlowyError::new(ErrorCode::InvalidData, &msg))
+            }
+        },
+    }
+}
+
+/// The symbol that the UI uses to render the rating.
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Serialize, Deserialize, ProtoBuf_Enum)]
+pub enum RatingSymbolPB {
+    Star = 0,
+    Heart = 1,
+}
+
+impl std::default::Default for RatingSymbolPB {
+    fn default() -> Self {
+        RatingSymbolPB::Star
+    }
+}
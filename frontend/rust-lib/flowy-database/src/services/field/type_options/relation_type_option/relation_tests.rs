@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use crate::entities::{FieldType, TextFilterConditionPB, TextFilterPB};
+    use crate::services::cell::{CellDataChangeset, CellDataDecoder, TypeCellData};
+    use crate::services::field::*;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn relation_type_option_insert_and_delete_rows_test() {
+        let type_option = RelationTypeOptionPB::default();
+        let changeset =
+            RelationCellChangeset::from_insert_row_ids(vec!["a".to_owned(), "b".to_owned(), "a".to_owned()]);
+        let (cell_str, cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+        assert_eq!(cell_data.row_ids, vec!["a".to_owned(), "b".to_owned()]);
+        assert_eq!(cell_str, "a,b");
+
+        let type_cell_data = TypeCellData::new(cell_str, FieldType::Relation);
+        let changeset = RelationCellChangeset::from_delete_row_ids(vec!["a".to_owned()]);
+        let (cell_str, _) = type_option.apply_changeset(changeset, Some(type_cell_data)).unwrap();
+        assert_eq!(cell_str, "b");
+    }
+
+    #[test]
+    fn relation_type_option_single_row_test() {
+        let type_option = RelationTypeOptionPB {
+            database_id: "database".to_owned(),
+            is_single: true,
+        };
+        let type_cell_data = TypeCellData::new("a".to_owned(), FieldType::Relation);
+        let changeset = RelationCellChangeset::from_insert_row_ids(vec!["b".to_owned(), "c".to_owned()]);
+        let (cell_str, _) = type_option.apply_changeset(changeset, Some(type_cell_data)).unwrap();
+        assert_eq!(cell_str, "c");
+    }
+
+    #[test]
+    fn relation_type_option_decode_test() {
+        let field_rev = FieldBuilder::from_field_type(&FieldType::Relation).build();
+        let type_option = RelationTypeOptionPB::from(&field_rev);
+        let cell_data = type_option
+            .decode_cell_str("a,b".to_owned(), &FieldType::Relation, &field_rev)
+            .unwrap();
+        assert_eq!(cell_data.row_ids.len(), 2);
+
+        // The cell of other field types doesn't link to any row.
+        let cell_data = type_option
+            .decode_cell_str("a,b".to_owned(), &FieldType::RichText, &field_rev)
+            .unwrap();
+        assert!(cell_data.row_ids.is_empty());
+    }
+
+    #[test]
+    fn relation_type_option_compare_test() {
+        let type_option = RelationTypeOptionPB::default();
+        let one_row = RelationCellData::new(vec!["a".to_owned()]);
+        let two_rows = RelationCellData::new(vec!["a".to_owned(), "b".to_owned()]);
+        assert_eq!(type_option.apply_cmp(&one_row, &two_rows), Ordering::Less);
+        assert_eq!(
+            type_option.apply_cmp(&two_rows, &RelationCellData::default()),
+            Ordering::Greater
+        );
+    }
+
+    /// The row ids are compared one by one, so the id `a` doesn't match the id `ab`.
+    #[test]
+    fn relation_type_option_filter_row_ids_test() {
+        let type_option = RelationTypeOptionPB::default();
+        let cell_data = RelationCellData::new(vec!["ab".to_owned(), "c".to_owned()]);
+        let is_visible = |condition: TextFilterConditionPB, content: &str| {
+            let filter = TextFilterPB {
+                condition,
+                content: content.to_owned(),
+            };
+            type_option.apply_filter(&filter, &FieldType::Relation, &cell_data)
+        };
+        assert!(!is_visible(TextFilterConditionPB::Contains, "a"));
+        assert!(is_visible(TextFilterConditionPB::Contains, "ab"));
+        assert!(is_visible(TextFilterConditionPB::Contains, "c,ab"));
+        assert!(is_visible(TextFilterConditionPB::DoesNotContain, "b"));
+        assert!(!is_visible(TextFilterConditionPB::DoesNotContain, "c"));
+        assert!(is_visible(TextFilterConditionPB::Is, "ab,c"));
+        assert!(!is_visible(TextFilterConditionPB::Is, "ab"));
+        assert!(is_visible(TextFilterConditionPB::IsNot, "ab"));
+        assert!(is_visible(TextFilterConditionPB::StartsWith, "ab"));
+        assert!(!is_visible(TextFilterConditionPB::StartsWith, "a"));
+        assert!(is_visible(TextFilterConditionPB::EndsWith, "c"));
+        assert!(!is_visible(TextFilterConditionPB::EndsWith, "b,c"));
+        assert!(is_visible(TextFilterConditionPB::TextIsNotEmpty, ""));
+        assert!(!is_visible(TextFilterConditionPB::TextIsEmpty, ""));
+    }
+}
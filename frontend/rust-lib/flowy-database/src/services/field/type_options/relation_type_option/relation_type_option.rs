@@ -0,0 +1,216 @@
+use crate::entities::{FieldType, TextFilterConditionPB, TextFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+    BoxTypeOptionBuilder, RelatedRowPB, RelationCellChangeset, RelationCellData, RelationCellDataPB, TypeOption,
+    TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+    TypedCellData, SELECTION_IDS_SEPARATOR,
+};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::FlowyResult;
+use grid_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use lib_infra::async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// [RelatedRowResolver] reads the rows of the other databases. The type option only knows the ids
+/// of the linked rows, so the rows are resolved through the `DatabaseManager` that owns all the
+/// opened databases.
+#[async_trait]
+pub trait RelatedRowResolver: Send + Sync {
+    /// Returns the rows of the database in the order of the `row_ids`. The rows that don't exist
+    /// anymore are skipped.
+    async fn get_related_rows(&self, database_id: &str, row_ids: &[String]) -> FlowyResult<Vec<RelatedRowPB>>;
+
+    /// Returns all the rows of the database. Each of them can be linked by the relation cells.
+    async fn get_candidate_rows(&self, database_id: &str) -> FlowyResult<Vec<RelatedRowPB>>;
+}
+
+// Relation
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ProtoBuf)]
+pub struct RelationTypeOptionPB {
+    /// The id of the database that the rows of the cells belong to.
+    #[pb(index = 1)]
+    pub database_id: String,
+
+    /// Each cell links to one row at most if it's true.
+    #[pb(index = 2)]
+    pub is_single: bool,
+}
+impl_type_option!(RelationTypeOptionPB, FieldType::Relation);
+
+impl RelationTypeOptionPB {
+    /// Resolves the linked rows of the cell into the rows of the linked database. The rows that
+    /// were deleted from the linked database are not included.
+    pub async fn resolve_cell_data(
+        &self,
+        cell_data: &RelationCellData,
+        resolver: &dyn RelatedRowResolver,
+    ) -> FlowyResult<RelationCellDataPB> {
+        let rows = if self.database_id.is_empty() || cell_data.row_ids.is_empty() {
+            vec![]
+        } else {
+            resolver.get_related_rows(&self.database_id, &cell_data.row_ids).await?
+        };
+        Ok(RelationCellDataPB {
+            database_id: self.database_id.clone(),
+            rows,
+        })
+    }
+}
+
+impl TypeOption for RelationTypeOptionPB {
+    type CellData = RelationCellData;
+    type CellChangeset = RelationCellChangeset;
+    type CellProtobufType = RelationCellDataPB;
+    type CellFilter = TextFilterPB;
+}
+
+impl TypeOptionTransform for RelationTypeOptionPB {}
+
+impl TypeOptionCellData for RelationTypeOptionPB {
+    /// The rows only contain the ids, the names are resolved by calling `resolve_cell_data`.
+    fn convert_to_protobuf(&self, cell_data: <Self as TypeOption>::CellData) -> <Self as TypeOption>::CellProtobufType {
+        RelationCellDataPB {
+            database_id: self.database_id.clone(),
+            rows: cell_data
+                .row_ids
+                .into_iter()
+                .map(|row_id| RelatedRowPB {
+                    row_id,
+                    name: "".to_owned(),
+                })
+                .collect(),
+        }
+    }
+
+    fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
+        RelationCellData::from_cell_str(&cell_str)
+    }
+
+    fn convert_to_typed_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> TypedCellData {
+        TypedCellData::Relation(cell_data.row_ids)
+    }
+}
+
+impl CellDataDecoder for RelationTypeOptionPB {
+    fn decode_cell_str(
+        &self,
+        cell_str: String,
+        decoded_field_type: &FieldType,
+        _field_rev: &FieldRevision,
+    ) -> FlowyResult<<Self as TypeOption>::CellData> {
+        if !decoded_field_type.is_relation() {
+            return Ok(Default::default());
+        }
+
+        self.decode_type_option_cell_str(cell_str)
+    }
+
+    fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+        cell_data.to_string()
+    }
+}
+
+impl CellDataChangeset for RelationTypeOptionPB {
+    fn apply_changeset(
+        &self,
+        changeset: <Self as TypeOption>::CellChangeset,
+        type_cell_data: Option<TypeCellData>,
+    ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+        let mut cell_data = match type_cell_data {
+            Some(type_cell_data) if type_cell_data.is_relation() => {
+                self.decode_type_option_cell_str(type_cell_data.cell_str)?
+            }
+            _ => RelationCellData::default(),
+        };
+        changeset.apply(&mut cell_data, self.is_single);
+        Ok((cell_data.to_string(), cell_data))
+    }
+}
+
+impl TypeOptionCellDataFilter for RelationTypeOptionPB {
+    /// The names of the linked rows can't be read here, so the filter is applied to the ids of the
+    /// rows. It's mostly used to check whether the cell links to any row.
+    fn apply_filter(
+        &self,
+        filter: &<Self as TypeOption>::CellFilter,
+        field_type: &FieldType,
+        cell_data: &<Self as TypeOption>::CellData,
+    ) -> bool {
+        if !field_type.is_relation() {
+            return true;
+        }
+        is_visible_by_row_ids(filter, &cell_data.row_ids)
+    }
+}
+
+/// Applies the filter to the ids of the linked rows. The content of the filter is a list of row
+/// ids separated by commas, and each of them is compared with the linked row ids as a whole, so
+/// an id doesn't match another id that contains it.
+fn is_visible_by_row_ids(filter: &TextFilterPB, row_ids: &[String]) -> bool {
+    let filter_row_ids = filter
+        .content
+        .split(SELECTION_IDS_SEPARATOR)
+        .map(|row_id| row_id.trim())
+        .filter(|row_id| !row_id.is_empty())
+        .collect::<Vec<&str>>();
+    let is_linked = |row_id: &&str| row_ids.iter().any(|linked_row_id| linked_row_id == row_id);
+    match filter.condition {
+        TextFilterConditionPB::Is => row_ids.iter().map(|row_id| row_id.as_str()).eq(filter_row_ids),
+        TextFilterConditionPB::IsNot => !row_ids.iter().map(|row_id| row_id.as_str()).eq(filter_row_ids),
+        TextFilterConditionPB::Contains => filter_row_ids.iter().all(is_linked),
+        TextFilterConditionPB::DoesNotContain => !filter_row_ids.iter().any(is_linked),
+        TextFilterConditionPB::StartsWith => row_ids
+            .iter()
+            .map(|row_id| row_id.as_str())
+            .take(filter_row_ids.len())
+            .eq(filter_row_ids),
+        TextFilterConditionPB::EndsWith => row_ids
+            .iter()
+            .rev()
+            .map(|row_id| row_id.as_str())
+            .take(filter_row_ids.len())
+            .eq(filter_row_ids.into_iter().rev()),
+        TextFilterConditionPB::TextIsEmpty => row_ids.is_empty(),
+        TextFilterConditionPB::TextIsNotEmpty => !row_ids.is_empty(),
+    }
+}
+
+impl TypeOptionCellDataCompare for RelationTypeOptionPB {
+    fn apply_cmp(
+        &self,
+        cell_data: &<Self as TypeOption>::CellData,
+        other_cell_data: &<Self as TypeOption>::CellData,
+    ) -> Ordering {
+        cell_data.row_ids.len().cmp(&other_cell_data.row_ids.len())
+    }
+}
+
+#[derive(Default)]
+pub struct RelationTypeOptionBuilder(RelationTypeOptionPB);
+impl_into_box_type_option_builder!(RelationTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(RelationTypeOptionBuilder, RelationTypeOptionPB);
+
+impl RelationTypeOptionBuilder {
+    pub fn database_id(mut self, database_id: &str) -> Self {
+        self.0.database_id = database_id.to_owned();
+        self
+    }
+
+    pub fn is_single(mut self, is_single: bool) -> Self {
+        self.0.is_single = is_single;
+        self
+    }
+}
+
+impl TypeOptionBuilder for RelationTypeOptionBuilder {
+    fn field_type(&self) -> FieldType {
+        FieldType::Relation
+    }
+
+    fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+        &self.0
+    }
+}
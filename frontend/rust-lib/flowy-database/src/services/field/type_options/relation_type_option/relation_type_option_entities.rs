@@ -0,0 +1,184 @@
+use crate::entities::CellPathPB;
+use crate::services::cell::{
+    CellProtobufBlobParser, DecodedCellData, FromCellChangesetString, FromCellString, ToCellChangesetString,
+};
+use crate::services::field::SELECTION_IDS_SEPARATOR;
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::{internal_error, FlowyResult};
+use serde::{Deserialize, Serialize};
+
+/// [RelationCellData] contains the ids of the rows that the cell links to. The rows belong to the
+/// database configured in the type option. It's stored as a list of ids separated by commas.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RelationCellData {
+    pub row_ids: Vec<String>,
+}
+
+impl RelationCellData {
+    pub fn new(row_ids: Vec<String>) -> Self {
+        Self { row_ids }
+    }
+}
+
+impl FromCellString for RelationCellData {
+    fn from_cell_str(s: &str) -> FlowyResult<Self> {
+        let row_ids = s
+            .split(SELECTION_IDS_SEPARATOR)
+            .filter(|row_id| !row_id.is_empty())
+            .map(|row_id| row_id.to_owned())
+            .collect::<Vec<String>>();
+        Ok(Self { row_ids })
+    }
+}
+
+impl ToString for RelationCellData {
+    fn to_string(&self) -> String {
+        self.row_ids.join(SELECTION_IDS_SEPARATOR)
+    }
+}
+
+impl DecodedCellData for RelationCellData {
+    type Object = RelationCellData;
+
+    fn is_empty(&self) -> bool {
+        self.row_ids.is_empty()
+    }
+}
+
+/// [RelatedRowPB] is a row of the linked database. The name is the display text of the row's
+/// primary cell.
+#[derive(Clone, Debug, Default, PartialEq, Eq, ProtoBuf)]
+pub struct RelatedRowPB {
+    #[pb(index = 1)]
+    pub row_id: String,
+
+    #[pb(index = 2)]
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RelationCellDataPB {
+    #[pb(index = 1)]
+    pub database_id: String,
+
+    /// The linked rows. The names of the rows are empty if the cell data was read without resolving
+    /// the rows, use the [GetRelationCellData] event to get the names.
+    #[pb(index = 2)]
+    pub rows: Vec<RelatedRowPB>,
+}
+
+impl DecodedCellData for RelationCellDataPB {
+    type Object = RelationCellDataPB;
+
+    fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+}
+
+pub struct RelationCellDataParser();
+impl CellProtobufBlobParser for RelationCellDataParser {
+    type Object = RelationCellDataPB;
+
+    fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+        RelationCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+    }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RepeatedRelatedRowPB {
+    #[pb(index = 1)]
+    pub items: Vec<RelatedRowPB>,
+}
+
+impl std::convert::From<Vec<RelatedRowPB>> for RepeatedRelatedRowPB {
+    fn from(items: Vec<RelatedRowPB>) -> Self {
+        Self { items }
+    }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RelationCellChangesetPB {
+    #[pb(index = 1)]
+    pub cell_path: CellPathPB,
+
+    #[pb(index = 2)]
+    pub insert_row_ids: Vec<String>,
+
+    #[pb(index = 3)]
+    pub delete_row_ids: Vec<String>,
+}
+
+impl From<RelationCellChangesetPB> for RelationCellChangeset {
+    fn from(changeset: RelationCellChangesetPB) -> Self {
+        Self {
+            insert_row_ids: changeset.insert_row_ids,
+            delete_row_ids: changeset.delete_row_ids,
+        }
+    }
+}
+
+/// [RelationCellChangeset] adds or removes the references to the rows of the linked database. The
+/// references are removed first and then added.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RelationCellChangeset {
+    #[serde(default)]
+    pub insert_row_ids: Vec<String>,
+
+    #[serde(default)]
+    pub delete_row_ids: Vec<String>,
+}
+
+impl RelationCellChangeset {
+    pub fn from_insert_row_ids(row_ids: Vec<String>) -> Self {
+        Self {
+            insert_row_ids: row_ids,
+            ..Default::default()
+        }
+    }
+
+    pub fn from_delete_row_ids(row_ids: Vec<String>) -> Self {
+        Self {
+            delete_row_ids: row_ids,
+            ..Default::default()
+        }
+    }
+
+    /// Only the last inserted row is kept if `is_single` is true, it replaces the linked row.
+    pub fn apply(self, cell_data: &mut RelationCellData, is_single: bool) {
+        cell_data.row_ids.retain(|row_id| !self.delete_row_ids.contains(row_id));
+
+        if is_single {
+            if let Some(row_id) = self
+                .insert_row_ids
+                .into_iter()
+                .filter(|row_id| !row_id.is_empty())
+                .last()
+            {
+                cell_data.row_ids = vec![row_id];
+            }
+            return;
+        }
+
+        for row_id in self.insert_row_ids {
+            if !row_id.is_empty() && !cell_data.row_ids.contains(&row_id) {
+                cell_data.row_ids.push(row_id);
+            }
+        }
+    }
+}
+
+impl FromCellChangesetString for RelationCellChangeset {
+    fn from_changeset(changeset: String) -> FlowyResult<Self>
+    where
+        Self: Sized,
+    {
+        serde_json::from_str::<RelationCellChangeset>(&changeset).map_err(internal_error)
+    }
+}
+
+impl ToCellChangesetString for RelationCellChangeset {
+    fn to_cell_changeset_str(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
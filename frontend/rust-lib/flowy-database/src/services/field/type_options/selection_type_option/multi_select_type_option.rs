@@ -6,8 +6,9 @@ use std::cmp::{min, Ordering};
 use crate::services::field::{
     default_order, BoxTypeOptionBuilder, SelectOptionCellChangeset, SelectOptionCellDataPB, SelectOptionIds,
     SelectOptionPB, SelectTypeOptionSharedAction, SelectedSelectOptions, TypeOption, TypeOptionBuilder,
-    TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+    TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypedCellData,
 };
+use crate::services::sort::Collation;
 use bytes::Bytes;
 use flowy_derive::ProtoBuf;
 use flowy_error::FlowyResult;
@@ -22,6 +23,11 @@ pub struct MultiSelectTypeOptionPB {
 
     #[pb(index = 2)]
     pub disable_color: bool,
+
+    /// The ids of the options of the cells of the new rows, separated by commas.
+    #[pb(index = 3, one_of)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<String>,
 }
 impl_type_option!(MultiSelectTypeOptionPB, FieldType::MultiSelect);
 
@@ -40,6 +46,10 @@ impl TypeOptionCellData for MultiSelectTypeOptionPB {
     fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
         SelectOptionIds::from_cell_str(&cell_str)
     }
+
+    fn convert_to_typed_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> TypedCellData {
+        TypedCellData::MultiSelect(self.get_selected_options(cell_data).select_options)
+    }
 }
 
 impl SelectTypeOptionSharedAction for MultiSelectTypeOptionPB {
@@ -110,6 +120,47 @@ impl TypeOptionCellDataCompare for MultiSelectTypeOptionPB {
         &self,
         cell_data: &<Self as TypeOption>::CellData,
         other_cell_data: &<Self as TypeOption>::CellData,
+    ) -> Ordering {
+        self.apply_cmp_with_collation(cell_data, other_cell_data, &Collation::default())
+    }
+
+    fn apply_cmp_with_collation(
+        &self,
+        cell_data: &<Self as TypeOption>::CellData,
+        other_cell_data: &<Self as TypeOption>::CellData,
+        collation: &Collation,
+    ) -> Ordering {
+        if collation.alphabetical {
+            return self.cmp_option_names(cell_data, other_cell_data, collation);
+        }
+
+        // The cells are compared by the first of their options in the order of the type option,
+        // and then the cell with fewer options is before the other.
+        let option_indexes = |cell_data: &SelectOptionIds| {
+            cell_data
+                .iter()
+                .flat_map(|id| self.options.iter().position(|option| &option.id == id))
+                .collect::<Vec<usize>>()
+        };
+        let (left, right) = (option_indexes(cell_data), option_indexes(other_cell_data));
+        match (left.iter().min(), right.iter().min()) {
+            (Some(left_index), Some(right_index)) => {
+                left_index.cmp(right_index).then_with(|| left.len().cmp(&right.len()))
+            }
+            (Some(_), None) => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (None, None) => default_order(),
+        }
+    }
+}
+
+impl MultiSelectTypeOptionPB {
+    /// Compares the names of the options of the cells one by one.
+    fn cmp_option_names(
+        &self,
+        cell_data: &SelectOptionIds,
+        other_cell_data: &SelectOptionIds,
+        collation: &Collation,
     ) -> Ordering {
         for i in 0..min(cell_data.len(), other_cell_data.len()) {
             let order = match (
@@ -120,7 +171,7 @@ impl TypeOptionCellDataCompare for MultiSelectTypeOptionPB {
                     .get(i)
                     .and_then(|id| self.options.iter().find(|option| &option.id == id)),
             ) {
-                (Some(left), Some(right)) => left.name.cmp(&right.name),
+                (Some(left), Some(right)) => collation.compare(&left.name, &right.name),
                 (Some(_), None) => Ordering::Greater,
                 (None, Some(_)) => Ordering::Less,
                 (None, None) => default_order(),
@@ -157,10 +208,16 @@ impl TypeOptionBuilder for MultiSelectTypeOptionBuilder {
 #[cfg(test)]
 mod tests {
     use crate::entities::FieldType;
-    use crate::services::cell::CellDataChangeset;
+    use crate::services::cell::{CellDataChangeset, TypeCellData};
     use crate::services::field::type_options::selection_type_option::*;
-    use crate::services::field::{CheckboxTypeOptionBuilder, FieldBuilder, TypeOptionBuilder, TypeOptionTransform};
-    use crate::services::field::{MultiSelectTypeOptionBuilder, MultiSelectTypeOptionPB};
+    use crate::services::field::{
+        CheckboxTypeOptionBuilder, FieldBuilder, TypeOptionBuilder, TypeOptionCellDataHandler, TypeOptionCellExt,
+        TypeOptionTransform,
+    };
+    use crate::services::field::{MultiSelectTypeOptionBuilder, MultiSelectTypeOptionPB, TypeOptionCellDataCompare};
+    use crate::services::sort::Collation;
+    use grid_model::CellRevision;
+    use std::cmp::Ordering;
 
     #[test]
     fn multi_select_transform_with_checkbox_type_option_test() {
@@ -197,6 +254,69 @@ mod tests {
         debug_assert_eq!(multi_select.options.len(), 2);
     }
 
+    /// The multi-select cell is empty if none of its options exist, for example, all of its options
+    /// are deleted from the field.
+    #[test]
+    fn multi_select_cell_with_zero_options_is_empty_test() {
+        let google = SelectOptionPB::new("Google");
+        let facebook = SelectOptionPB::new("Facebook");
+        let multi_select = MultiSelectTypeOptionBuilder::default().add_option(google.clone());
+        let field_rev = FieldBuilder::new(multi_select).name("Platform").build();
+        let handler = TypeOptionCellExt::new(&field_rev, None, None)
+            .get_type_option_cell_data_handler(&FieldType::MultiSelect)
+            .unwrap();
+        for (option_ids, is_empty) in [
+            (vec![], true),
+            (vec![facebook.id.clone()], true),
+            (vec![google.id.clone(), facebook.id.clone()], false),
+        ] {
+            let cell_str = SelectOptionIds::from(option_ids).to_string();
+            let cell_rev = CellRevision::new(TypeCellData::new(cell_str, FieldType::MultiSelect).to_json());
+            assert_eq!(handler.is_cell_empty(Some(&cell_rev), &field_rev), is_empty);
+        }
+    }
+
+    #[test]
+    fn multi_select_compare_by_option_order_test() {
+        let (todo, doing, done) = (
+            SelectOptionPB::new("Todo"),
+            SelectOptionPB::new("Doing"),
+            SelectOptionPB::new("Done"),
+        );
+        let type_option = MultiSelectTypeOptionBuilder::default()
+            .add_option(todo.clone())
+            .add_option(doing.clone())
+            .add_option(done.clone())
+            .0;
+        let cell_data = |options: &[&SelectOptionPB]| {
+            SelectOptionIds::from(options.iter().map(|option| option.id.clone()).collect::<Vec<String>>())
+        };
+
+        // The first option of the type option is before the others, even though its name isn't.
+        assert_eq!(
+            type_option.apply_cmp(&cell_data(&[&todo]), &cell_data(&[&doing, &done])),
+            Ordering::Less
+        );
+        // The cell with fewer options is before the other if their first options are the same.
+        assert_eq!(
+            type_option.apply_cmp(&cell_data(&[&doing, &done]), &cell_data(&[&doing])),
+            Ordering::Greater
+        );
+        assert_eq!(
+            type_option.apply_cmp(&cell_data(&[]), &cell_data(&[&done])),
+            Ordering::Less
+        );
+
+        let alphabetical = Collation {
+            alphabetical: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            type_option.apply_cmp_with_collation(&cell_data(&[&todo]), &cell_data(&[&doing, &done]), &alphabetical),
+            Ordering::Greater
+        );
+    }
+
     // #[test]
 
     #[test]
@@ -291,4 +411,16 @@ mod tests {
         let select_option_ids = type_option.apply_changeset(changeset, None).unwrap().1;
         assert!(select_option_ids.is_empty());
     }
+
+    #[test]
+    fn multi_select_create_option_with_unique_name_test() {
+        let mut type_option = MultiSelectTypeOptionBuilder::default().0;
+        assert_eq!(type_option.create_option_with_unique_name("Done").name, "Done");
+
+        type_option.insert_option(SelectOptionPB::new("Done"));
+        type_option.insert_option(SelectOptionPB::new("Done (2)"));
+        assert_eq!(type_option.create_option_with_unique_name("Done").name, "Done (3)");
+        // The names are compared case-sensitively
+        assert_eq!(type_option.create_option_with_unique_name("done").name, "done");
+    }
 }
@@ -2,6 +2,7 @@
 
 use crate::entities::{FieldType, SelectOptionConditionPB, SelectOptionFilterPB};
 use crate::services::field::SelectedSelectOptions;
+use std::collections::HashSet;
 
 impl SelectOptionFilterPB {
     pub fn is_visible(&self, selected_options: &SelectedSelectOptions, field_type: FieldType) -> bool {
@@ -71,6 +72,22 @@ impl SelectOptionFilterPB {
             },
             SelectOptionConditionPB::OptionIsEmpty => selected_option_ids.is_empty(),
             SelectOptionConditionPB::OptionIsNotEmpty => !selected_option_ids.is_empty(),
+            // The conditions below compare the set of the option ids in the cell with the option
+            // ids of the filter, the filter without options shows all the rows.
+            _ if self.option_ids.is_empty() => true,
+            SelectOptionConditionPB::OptionContainsAll => {
+                self.option_ids.iter().all(|id| selected_option_ids.contains(&id))
+            }
+            SelectOptionConditionPB::OptionContainsAny => {
+                self.option_ids.iter().any(|id| selected_option_ids.contains(&id))
+            }
+            SelectOptionConditionPB::OptionIsExactly => {
+                let option_ids = self.option_ids.iter().collect::<HashSet<&String>>();
+                option_ids == selected_option_ids.into_iter().collect::<HashSet<&String>>()
+            }
+            SelectOptionConditionPB::OptionDoesNotContain => {
+                !self.option_ids.iter().any(|id| selected_option_ids.contains(&id))
+            }
         }
     }
 }
@@ -281,4 +298,97 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn multi_select_option_filter_contains_all_test() {
+        let option_1 = SelectOptionPB::new("A");
+        let option_2 = SelectOptionPB::new("B");
+        let option_3 = SelectOptionPB::new("C");
+
+        let filter = SelectOptionFilterPB {
+            condition: SelectOptionConditionPB::OptionContainsAll,
+            option_ids: vec![option_1.id.clone(), option_2.id.clone()],
+        };
+        for (options, is_visible) in vec![
+            (vec![], false),
+            (vec![option_1.clone()], false),
+            (vec![option_2.clone(), option_1.clone()], true),
+            (vec![option_1.clone(), option_2.clone(), option_3.clone()], true),
+            (vec![option_1.clone(), option_3.clone()], false),
+        ] {
+            assert_eq!(
+                filter.is_visible(&SelectedSelectOptions { options }, FieldType::MultiSelect),
+                is_visible
+            );
+        }
+    }
+
+    #[test]
+    fn multi_select_option_filter_contains_any_test() {
+        let option_1 = SelectOptionPB::new("A");
+        let option_2 = SelectOptionPB::new("B");
+        let option_3 = SelectOptionPB::new("C");
+
+        let filter = SelectOptionFilterPB {
+            condition: SelectOptionConditionPB::OptionContainsAny,
+            option_ids: vec![option_1.id.clone(), option_2.id.clone()],
+        };
+        for (options, is_visible) in vec![
+            (vec![], false),
+            (vec![option_2.clone()], true),
+            (vec![option_3.clone()], false),
+            (vec![option_1.clone(), option_3.clone()], true),
+        ] {
+            assert_eq!(
+                filter.is_visible(&SelectedSelectOptions { options }, FieldType::MultiSelect),
+                is_visible
+            );
+        }
+    }
+
+    #[test]
+    fn multi_select_option_filter_is_exactly_test() {
+        let option_1 = SelectOptionPB::new("A");
+        let option_2 = SelectOptionPB::new("B");
+        let option_3 = SelectOptionPB::new("C");
+
+        let filter = SelectOptionFilterPB {
+            condition: SelectOptionConditionPB::OptionIsExactly,
+            option_ids: vec![option_1.id.clone(), option_2.id.clone()],
+        };
+        for (options, is_visible) in vec![
+            (vec![], false),
+            (vec![option_1.clone()], false),
+            (vec![option_2.clone(), option_1.clone()], true),
+            (vec![option_1.clone(), option_2.clone(), option_3.clone()], false),
+        ] {
+            assert_eq!(
+                filter.is_visible(&SelectedSelectOptions { options }, FieldType::MultiSelect),
+                is_visible
+            );
+        }
+    }
+
+    #[test]
+    fn multi_select_option_filter_does_not_contain_test() {
+        let option_1 = SelectOptionPB::new("A");
+        let option_2 = SelectOptionPB::new("B");
+        let option_3 = SelectOptionPB::new("C");
+
+        let filter = SelectOptionFilterPB {
+            condition: SelectOptionConditionPB::OptionDoesNotContain,
+            option_ids: vec![option_1.id.clone(), option_2.id.clone()],
+        };
+        for (options, is_visible) in vec![
+            (vec![], true),
+            (vec![option_3.clone()], true),
+            (vec![option_2.clone()], false),
+            (vec![option_1.clone(), option_3.clone()], false),
+        ] {
+            assert_eq!(
+                filter.is_visible(&SelectedSelectOptions { options }, FieldType::MultiSelect),
+                is_visible
+            );
+        }
+    }
 }
@@ -12,7 +12,7 @@ use crate::services::field::{
 };
 use bytes::Bytes;
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
-use flowy_error::{internal_error, ErrorCode, FlowyResult};
+use flowy_error::{internal_error, ErrorCode, FlowyError, FlowyResult};
 use grid_model::{FieldRevision, TypeOptionDataSerializer};
 use nanoid::nanoid;
 use serde::{Deserialize, Serialize};
@@ -110,6 +110,19 @@ pub trait SelectTypeOptionSharedAction: TypeOptionDataSerializer + Send + Sync {
         SelectOptionPB::with_color(name, color)
     }
 
+    /// Same as `create_option`, but a suffix is appended to the name if another option has the
+    /// same name, for example, `Done (2)`.
+    fn create_option_with_unique_name(&self, name: &str) -> SelectOptionPB {
+        let has_name = |name: &str| self.options().iter().any(|option| option.name == name);
+        let mut unique_name = name.to_owned();
+        let mut suffix = 2;
+        while has_name(&unique_name) {
+            unique_name = format!("{} ({})", name, suffix);
+            suffix += 1;
+        }
+        self.create_option(&unique_name)
+    }
+
     /// Return a list of options that are selected by user
     fn get_selected_options(&self, ids: SelectOptionIds) -> SelectOptionCellDataPB {
         let mut select_options = make_selected_options(ids, self.options());
@@ -153,10 +166,28 @@ where
         &self,
         cell_str: &str,
         decoded_field_type: &FieldType,
-        _field_rev: &FieldRevision,
+        field_rev: &FieldRevision,
     ) -> Option<<Self as TypeOption>::CellData> {
         match decoded_field_type {
-            FieldType::SingleSelect | FieldType::MultiSelect | FieldType::Checklist => None,
+            FieldType::SingleSelect | FieldType::MultiSelect => None,
+            FieldType::Checklist => {
+                // Each item of the checklist is transformed to the option with the same id or name.
+                let checklist_cell_data = ChecklistTypeOptionPB::from(field_rev)
+                    .decode_type_option_cell_str(cell_str.to_owned())
+                    .ok()?;
+                let options = self.options();
+                let transformed_ids = checklist_cell_data
+                    .items
+                    .iter()
+                    .flat_map(|item| {
+                        options
+                            .iter()
+                            .find(|option| option.id == item.id || option.name == item.name)
+                    })
+                    .map(|option| option.id.clone())
+                    .collect::<Vec<String>>();
+                Some(SelectOptionIds::from(transformed_ids))
+            }
             FieldType::Checkbox => match CheckboxCellData::from_cell_str(cell_str) {
                 Ok(checkbox_cell_data) => {
                     let cell_content = checkbox_cell_data.to_string();
@@ -211,10 +242,10 @@ pub fn select_type_option_from_field_rev(
             let type_option = MultiSelectTypeOptionPB::from(field_rev);
             Ok(Box::new(type_option))
         }
-        FieldType::Checklist => {
-            let type_option = ChecklistTypeOptionPB::from(field_rev);
-            Ok(Box::new(type_option))
-        }
+        FieldType::Checklist => Err(FlowyError::new(
+            ErrorCode::FieldInvalidOperation,
+            "The items of the checklist are stored in the cells, update them with the checklist changeset",
+        )),
         ty => {
             tracing::error!("Unsupported field type: {:?} for this handler", ty);
             Err(ErrorCode::FieldInvalidOperation.into())
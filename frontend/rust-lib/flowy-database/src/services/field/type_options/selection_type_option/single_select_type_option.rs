@@ -5,11 +5,12 @@ use std::cmp::Ordering;
 
 use crate::services::field::{
     default_order, BoxTypeOptionBuilder, SelectOptionCellDataPB, SelectedSelectOptions, TypeOption, TypeOptionBuilder,
-    TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+    TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypedCellData,
 };
 use crate::services::field::{
     SelectOptionCellChangeset, SelectOptionIds, SelectOptionPB, SelectTypeOptionSharedAction,
 };
+use crate::services::sort::Collation;
 use bytes::Bytes;
 use flowy_derive::ProtoBuf;
 use flowy_error::FlowyResult;
@@ -24,6 +25,11 @@ pub struct SingleSelectTypeOptionPB {
 
     #[pb(index = 2)]
     pub disable_color: bool,
+
+    /// The ids of the options of the cells of the new rows, separated by commas.
+    #[pb(index = 3, one_of)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<String>,
 }
 impl_type_option!(SingleSelectTypeOptionPB, FieldType::SingleSelect);
 
@@ -42,6 +48,10 @@ impl TypeOptionCellData for SingleSelectTypeOptionPB {
     fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
         SelectOptionIds::from_cell_str(&cell_str)
     }
+
+    fn convert_to_typed_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> TypedCellData {
+        TypedCellData::SingleSelect(self.get_selected_options(cell_data).select_options.into_iter().next())
+    }
 }
 
 impl SelectTypeOptionSharedAction for SingleSelectTypeOptionPB {
@@ -105,15 +115,27 @@ impl TypeOptionCellDataCompare for SingleSelectTypeOptionPB {
         cell_data: &<Self as TypeOption>::CellData,
         other_cell_data: &<Self as TypeOption>::CellData,
     ) -> Ordering {
-        match (
+        self.apply_cmp_with_collation(cell_data, other_cell_data, &Collation::default())
+    }
+
+    fn apply_cmp_with_collation(
+        &self,
+        cell_data: &<Self as TypeOption>::CellData,
+        other_cell_data: &<Self as TypeOption>::CellData,
+        collation: &Collation,
+    ) -> Ordering {
+        // The options are compared by their order in the type option, unless the sort compares
+        // them by their names.
+        let option_index = |cell_data: &SelectOptionIds| {
             cell_data
                 .first()
-                .and_then(|id| self.options.iter().find(|option| &option.id == id)),
-            other_cell_data
-                .first()
-                .and_then(|id| self.options.iter().find(|option| &option.id == id)),
-        ) {
-            (Some(left), Some(right)) => left.name.cmp(&right.name),
+                .and_then(|id| self.options.iter().position(|option| &option.id == id))
+        };
+        match (option_index(cell_data), option_index(other_cell_data)) {
+            (Some(left), Some(right)) if collation.alphabetical => {
+                collation.compare(&self.options[left].name, &self.options[right].name)
+            }
+            (Some(left), Some(right)) => left.cmp(&right),
             (Some(_), None) => Ordering::Greater,
             (None, Some(_)) => Ordering::Less,
             (None, None) => default_order(),
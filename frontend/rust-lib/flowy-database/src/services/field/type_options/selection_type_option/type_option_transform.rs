@@ -1,8 +1,8 @@
 use crate::entities::FieldType;
 
 use crate::services::field::{
-    MultiSelectTypeOptionPB, SelectOptionColorPB, SelectOptionIds, SelectOptionPB, SelectTypeOptionSharedAction,
-    SingleSelectTypeOptionPB, TypeOption, CHECK, UNCHECK,
+    ChecklistTypeOptionPB, MultiSelectTypeOptionPB, SelectOptionColorPB, SelectOptionIds, SelectOptionPB,
+    SelectTypeOptionSharedAction, SingleSelectTypeOptionPB, TypeOption, CHECK, UNCHECK,
 };
 
 use grid_model::TypeOptionDataDeserializer;
@@ -49,6 +49,14 @@ impl SelectOptionTypeOptionTransformHelper {
                     }
                 })
             }
+            FieldType::Checklist => {
+                let options = ChecklistTypeOptionPB::from_json_str(&old_type_option_data).options;
+                options.iter().for_each(|new_option| {
+                    if !shared.options().iter().any(|option| option.name == new_option.name) {
+                        shared.mut_options().push(new_option.clone());
+                    }
+                })
+            }
             _ => {}
         }
     }
@@ -64,4 +64,17 @@ mod tests {
             format!("{},{}", france.name, argentina.name)
         );
     }
+
+    #[test]
+    fn append_text_with_separator_test() {
+        let type_option = RichTextTypeOptionPB::default();
+        assert_eq!(type_option.append_text("", "milk"), "milk");
+        assert_eq!(type_option.append_text("eggs", "milk"), "eggs\nmilk");
+
+        let type_option = RichTextTypeOptionPB {
+            append_separator: Some("; ".to_owned()),
+            ..Default::default()
+        };
+        assert_eq!(type_option.append_text("eggs", "milk"), "eggs; milk");
+    }
 }
@@ -5,10 +5,12 @@ use crate::services::cell::{
     TypeCellData,
 };
 use crate::services::field::{
-    BoxTypeOptionBuilder, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
-    TypeOptionCellDataFilter, TypeOptionTransform,
+    BoxTypeOptionBuilder, CellValidationRulePB, CellValidationViolation, TypeOption, TypeOptionBuilder,
+    TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform, TypedCellData,
 };
+use crate::services::sort::Collation;
 use bytes::Bytes;
+use fancy_regex::Regex;
 use flowy_derive::ProtoBuf;
 use flowy_error::{FlowyError, FlowyResult};
 use grid_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
@@ -16,11 +18,32 @@ use protobuf::ProtobufError;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 
+/// The separator between the existing text of the cell and the appended text if the type option
+/// doesn't specify one.
+pub const DEFAULT_APPEND_SEPARATOR: &str = "\n";
+
 #[derive(Default)]
 pub struct RichTextTypeOptionBuilder(RichTextTypeOptionPB);
 impl_into_box_type_option_builder!(RichTextTypeOptionBuilder);
 impl_builder_from_json_str_and_from_bytes!(RichTextTypeOptionBuilder, RichTextTypeOptionPB);
 
+impl RichTextTypeOptionBuilder {
+    pub fn max_length(mut self, max_length: i64) -> Self {
+        self.0.max_length = Some(max_length);
+        self
+    }
+
+    pub fn pattern(mut self, pattern: &str) -> Self {
+        self.0.pattern = Some(pattern.to_owned());
+        self
+    }
+
+    pub fn append_separator(mut self, separator: &str) -> Self {
+        self.0.append_separator = Some(separator.to_owned());
+        self
+    }
+}
+
 impl TypeOptionBuilder for RichTextTypeOptionBuilder {
     fn field_type(&self) -> FieldType {
         FieldType::RichText
@@ -31,16 +54,46 @@ impl TypeOptionBuilder for RichTextTypeOptionBuilder {
     }
 }
 
-/// For the moment, the `RichTextTypeOptionPB` is empty. The `data` property is not
-/// used yet.
+/// The `data` property of the `RichTextTypeOptionPB` is not used yet.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, ProtoBuf)]
 pub struct RichTextTypeOptionPB {
     #[pb(index = 1)]
     #[serde(default)]
     data: String,
+
+    /// The maximum number of characters that the cell accepts.
+    #[pb(index = 2, one_of)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<i64>,
+
+    /// The regular expression that the non-empty text of the cell must match.
+    #[pb(index = 3, one_of)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+
+    /// The text of the cells of the new rows.
+    #[pb(index = 4, one_of)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<String>,
+
+    /// The separator that is inserted before the text appended to a non-empty cell.
+    #[pb(index = 5, one_of)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub append_separator: Option<String>,
 }
 impl_type_option!(RichTextTypeOptionPB, FieldType::RichText);
 
+impl RichTextTypeOptionPB {
+    /// Returns the text of the cell after the `text` is appended to it.
+    pub fn append_text(&self, cell_text: &str, text: &str) -> String {
+        if cell_text.is_empty() {
+            return text.to_owned();
+        }
+        let separator = self.append_separator.as_deref().unwrap_or(DEFAULT_APPEND_SEPARATOR);
+        format!("{}{}{}", cell_text, separator, text)
+    }
+}
+
 impl TypeOption for RichTextTypeOptionPB {
     type CellData = StrCellData;
     type CellChangeset = String;
@@ -82,6 +135,10 @@ impl TypeOptionCellData for RichTextTypeOptionPB {
     fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
         StrCellData::from_cell_str(&cell_str)
     }
+
+    fn convert_to_typed_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> TypedCellData {
+        TypedCellData::Text(cell_data.0)
+    }
 }
 
 impl CellDataDecoder for RichTextTypeOptionPB {
@@ -112,6 +169,26 @@ impl CellDataChangeset for RichTextTypeOptionPB {
             Ok((text_cell_data.to_string(), text_cell_data))
         }
     }
+
+    fn validate_cell_data(&self, cell_data: &<Self as TypeOption>::CellData) -> Option<CellValidationViolation> {
+        if let Some(max_length) = self.max_length {
+            if cell_data.chars().count() as i64 > max_length {
+                let msg = format!("The text should not be longer than {} characters", max_length);
+                return Some(CellValidationViolation::new(CellValidationRulePB::TextMaxLength, msg));
+            }
+        }
+        if let Some(pattern) = self.pattern.as_ref() {
+            // The invalid pattern is ignored, otherwise none of the cells could be edited.
+            let is_match = Regex::new(pattern)
+                .and_then(|regex| regex.is_match(cell_data))
+                .unwrap_or(true);
+            if !cell_data.is_empty() && !is_match {
+                let msg = format!("The text should match the pattern: {}", pattern);
+                return Some(CellValidationViolation::new(CellValidationRulePB::TextPattern, msg));
+            }
+        }
+        None
+    }
 }
 
 impl TypeOptionCellDataFilter for RichTextTypeOptionPB {
@@ -135,7 +212,16 @@ impl TypeOptionCellDataCompare for RichTextTypeOptionPB {
         cell_data: &<Self as TypeOption>::CellData,
         other_cell_data: &<Self as TypeOption>::CellData,
     ) -> Ordering {
-        cell_data.0.cmp(&other_cell_data.0)
+        self.apply_cmp_with_collation(cell_data, other_cell_data, &Collation::default())
+    }
+
+    fn apply_cmp_with_collation(
+        &self,
+        cell_data: &<Self as TypeOption>::CellData,
+        other_cell_data: &<Self as TypeOption>::CellData,
+        collation: &Collation,
+    ) -> Ordering {
+        collation.compare(&cell_data.0, &other_cell_data.0)
     }
 }
 
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use crate::entities::{DateFilterConditionPB, DateFilterPB, FieldType};
+    use crate::services::cell::{CellDataChangeset, CellDataDecoder};
+    use crate::services::field::*;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn timestamp_type_option_format_test() {
+        let field_rev = FieldBuilder::from_field_type(&FieldType::CreatedTime).build();
+        let mut type_option = TimestampTypeOptionPB::from(&field_rev);
+        let cell_data = type_option
+            .decode_cell_str("1653609600".to_owned(), &FieldType::CreatedTime, &field_rev)
+            .unwrap();
+        assert_eq!(type_option.decode_cell_data_to_str(cell_data), "May 27,2022");
+
+        type_option.date_format = DateFormat::ISO;
+        type_option.include_time = true;
+        type_option.time_format = TimeFormat::TwentyFourHour;
+        let cell_data = type_option
+            .decode_cell_str("1653616800".to_owned(), &FieldType::CreatedTime, &field_rev)
+            .unwrap();
+        assert_eq!(type_option.decode_cell_data_to_str(cell_data), "2022-05-27 02:00");
+    }
+
+    #[test]
+    fn timestamp_type_option_read_by_field_type_test() {
+        let builder = TimestampTypeOptionBuilder::new(FieldType::LastEditedTime).date_format(DateFormat::US);
+        let field_rev = FieldBuilder::new(builder).build();
+        let field_type: FieldType = field_rev.ty.into();
+        assert_eq!(field_type, FieldType::LastEditedTime);
+
+        let type_option = TimestampTypeOptionPB::from(&field_rev);
+        let cell_data = type_option
+            .decode_cell_str("1653609600".to_owned(), &FieldType::LastEditedTime, &field_rev)
+            .unwrap();
+        assert_eq!(type_option.decode_cell_data_to_str(cell_data), "2022/05/27");
+    }
+
+    #[test]
+    fn timestamp_type_option_reject_changeset_test() {
+        let type_option = TimestampTypeOptionPB::default();
+        let changeset = DateCellChangeset {
+            date: Some("1653609600".to_owned()),
+            time: None,
+            is_utc: true,
+        };
+        assert!(type_option.apply_changeset(changeset, None).is_err());
+    }
+
+    #[test]
+    fn timestamp_type_option_filter_and_compare_test() {
+        let type_option = TimestampTypeOptionPB::default();
+        let filter = DateFilterPB {
+            condition: DateFilterConditionPB::DateAfter,
+            start: None,
+            end: None,
+            timestamp: Some(1653609600),
+            days: None,
+        };
+        let earlier = DateCellData(Some(1653609600));
+        let later = DateCellData(Some(1653782400));
+        assert!(!type_option.apply_filter(&filter, &FieldType::CreatedTime, &earlier));
+        assert!(type_option.apply_filter(&filter, &FieldType::CreatedTime, &later));
+
+        assert_eq!(type_option.apply_cmp(&earlier, &later), Ordering::Less);
+        assert_eq!(type_option.apply_cmp(&later, &DateCellData(None)), Ordering::Greater);
+    }
+}
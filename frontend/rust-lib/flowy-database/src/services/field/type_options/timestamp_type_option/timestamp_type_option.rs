@@ -0,0 +1,244 @@
+use crate::entities::{DateFilterPB, FieldType};
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+    default_order, BoxTypeOptionBuilder, DateCellChangeset, DateCellData, DateCellDataPB, DateFormat, DateTypeOptionPB,
+    TimeFormat, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+    TypeOptionTransform, TypedCellData,
+};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use grid_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// [TimestampTypeOptionPB] is the type option of the [FieldType::CreatedTime] and the
+/// [FieldType::LastEditedTime] fields. The cells of these fields are not stored in the row, they
+/// are derived from the `created_at` and the `modified_at` of the row. So the cells can't be edited.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ProtoBuf)]
+pub struct TimestampTypeOptionPB {
+    #[pb(index = 1)]
+    pub date_format: DateFormat,
+
+    #[pb(index = 2)]
+    pub time_format: TimeFormat,
+
+    #[pb(index = 3)]
+    pub include_time: bool,
+}
+
+impl TimestampTypeOptionPB {
+    fn date_type_option(&self) -> DateTypeOptionPB {
+        DateTypeOptionPB {
+            date_format: self.date_format,
+            time_format: self.time_format,
+            include_time: self.include_time,
+            ..Default::default()
+        }
+    }
+}
+
+// The same type option is used by two field types, so it's read by the field type of the field.
+impl std::convert::From<&FieldRevision> for TimestampTypeOptionPB {
+    fn from(field_rev: &FieldRevision) -> TimestampTypeOptionPB {
+        field_rev
+            .get_type_option::<TimestampTypeOptionPB>(field_rev.ty)
+            .unwrap_or_default()
+    }
+}
+
+impl std::convert::From<&Arc<FieldRevision>> for TimestampTypeOptionPB {
+    fn from(field_rev: &Arc<FieldRevision>) -> TimestampTypeOptionPB {
+        TimestampTypeOptionPB::from(field_rev.as_ref())
+    }
+}
+
+impl std::convert::From<TimestampTypeOptionPB> for String {
+    fn from(type_option: TimestampTypeOptionPB) -> String {
+        type_option.json_str()
+    }
+}
+
+impl TypeOptionDataSerializer for TimestampTypeOptionPB {
+    fn json_str(&self) -> String {
+        match serde_json::to_string(&self) {
+            Ok(s) => s,
+            Err(e) => {
+                tracing::error!("Field type data serialize to json fail, error: {:?}", e);
+                serde_json::to_string(&TimestampTypeOptionPB::default()).unwrap()
+            }
+        }
+    }
+
+    fn protobuf_bytes(&self) -> Bytes {
+        self.clone().try_into().unwrap()
+    }
+}
+
+impl TypeOptionDataDeserializer for TimestampTypeOptionPB {
+    fn from_json_str(s: &str) -> TimestampTypeOptionPB {
+        match serde_json::from_str(s) {
+            Ok(obj) => obj,
+            Err(err) => {
+                tracing::error!(
+                    "TimestampTypeOptionPB type option deserialize from {} failed, {:?}",
+                    s,
+                    err
+                );
+                TimestampTypeOptionPB::default()
+            }
+        }
+    }
+
+    fn from_protobuf_bytes(bytes: Bytes) -> TimestampTypeOptionPB {
+        TimestampTypeOptionPB::try_from(bytes).unwrap_or_default()
+    }
+}
+
+impl TypeOption for TimestampTypeOptionPB {
+    type CellData = DateCellData;
+    type CellChangeset = DateCellChangeset;
+    type CellProtobufType = DateCellDataPB;
+    type CellFilter = DateFilterPB;
+}
+
+impl TypeOptionCellData for TimestampTypeOptionPB {
+    fn convert_to_protobuf(&self, cell_data: <Self as TypeOption>::CellData) -> <Self as TypeOption>::CellProtobufType {
+        self.date_type_option().today_desc_from_timestamp(cell_data)
+    }
+
+    fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
+        DateCellData::from_cell_str(&cell_str)
+    }
+
+    fn convert_to_typed_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> TypedCellData {
+        TypedCellData::Date {
+            timestamp: cell_data.0,
+            include_time: self.include_time,
+        }
+    }
+}
+
+impl TypeOptionTransform for TimestampTypeOptionPB {}
+
+impl CellDataDecoder for TimestampTypeOptionPB {
+    fn decode_cell_str(
+        &self,
+        cell_str: String,
+        decoded_field_type: &FieldType,
+        _field_rev: &FieldRevision,
+    ) -> FlowyResult<<Self as TypeOption>::CellData> {
+        if !decoded_field_type.is_timestamp() {
+            return Ok(Default::default());
+        }
+
+        self.decode_type_option_cell_str(cell_str)
+    }
+
+    fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+        let cell_data_pb = self.date_type_option().today_desc_from_timestamp(cell_data);
+        if cell_data_pb.time.is_empty() {
+            cell_data_pb.date
+        } else {
+            format!("{} {}", cell_data_pb.date, cell_data_pb.time)
+        }
+    }
+}
+
+impl CellDataChangeset for TimestampTypeOptionPB {
+    fn apply_changeset(
+        &self,
+        _changeset: <Self as TypeOption>::CellChangeset,
+        _type_cell_data: Option<TypeCellData>,
+    ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+        Err(FlowyError::new(
+            ErrorCode::FieldInvalidOperation,
+            "The cells of the created time and the last edited time fields are read-only",
+        ))
+    }
+}
+
+impl TypeOptionCellDataFilter for TimestampTypeOptionPB {
+    fn apply_filter(
+        &self,
+        filter: &<Self as TypeOption>::CellFilter,
+        field_type: &FieldType,
+        cell_data: &<Self as TypeOption>::CellData,
+    ) -> bool {
+        if !field_type.is_timestamp() {
+            return true;
+        }
+
+        filter.is_visible(cell_data.0)
+    }
+}
+
+impl TypeOptionCellDataCompare for TimestampTypeOptionPB {
+    fn apply_cmp(
+        &self,
+        cell_data: &<Self as TypeOption>::CellData,
+        other_cell_data: &<Self as TypeOption>::CellData,
+    ) -> Ordering {
+        match (cell_data.0, other_cell_data.0) {
+            (Some(left), Some(right)) => left.cmp(&right),
+            (Some(_), None) => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (None, None) => default_order(),
+        }
+    }
+}
+
+pub struct TimestampTypeOptionBuilder {
+    field_type: FieldType,
+    type_option: TimestampTypeOptionPB,
+}
+impl_into_box_type_option_builder!(TimestampTypeOptionBuilder);
+
+impl TimestampTypeOptionBuilder {
+    pub fn new(field_type: FieldType) -> Self {
+        Self {
+            field_type,
+            type_option: TimestampTypeOptionPB::default(),
+        }
+    }
+
+    pub fn from_protobuf_bytes(bytes: Bytes, field_type: &FieldType) -> Self {
+        Self {
+            field_type: field_type.clone(),
+            type_option: TimestampTypeOptionPB::from_protobuf_bytes(bytes),
+        }
+    }
+
+    pub fn from_json_str(s: &str, field_type: &FieldType) -> Self {
+        Self {
+            field_type: field_type.clone(),
+            type_option: TimestampTypeOptionPB::from_json_str(s),
+        }
+    }
+
+    pub fn date_format(mut self, date_format: DateFormat) -> Self {
+        self.type_option.date_format = date_format;
+        self
+    }
+
+    pub fn time_format(mut self, time_format: TimeFormat) -> Self {
+        self.type_option.time_format = time_format;
+        self
+    }
+
+    pub fn include_time(mut self, include_time: bool) -> Self {
+        self.type_option.include_time = include_time;
+        self
+    }
+}
+
+impl TypeOptionBuilder for TimestampTypeOptionBuilder {
+    fn field_type(&self) -> FieldType {
+        self.field_type.clone()
+    }
+
+    fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+        &self.type_option
+    }
+}
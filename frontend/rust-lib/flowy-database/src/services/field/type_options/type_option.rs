@@ -1,7 +1,9 @@
 use crate::entities::FieldType;
 use crate::services::cell::{CellDataDecoder, FromCellChangesetString, FromCellString, ToCellChangesetString};
+use crate::services::field::TypedCellData;
 
 use crate::services::filter::FromFilterString;
+use crate::services::sort::Collation;
 use bytes::Bytes;
 use flowy_error::FlowyResult;
 use grid_model::FieldRevision;
@@ -53,6 +55,13 @@ pub trait TypeOptionCellData: TypeOption {
     // data can not directly show to user. So it needs to be encode as the date string with custom
     // format setting. Encode `1647251762` to `"Mar 14,2022`
     fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData>;
+
+    /// Convert the decoded cell data into the [TypedCellData] that doesn't need to be decoded
+    /// again by the consumers.
+    /// For example:
+    ///    FieldType::Number => TypedCellData::Number
+    ///    FieldType::Date => TypedCellData::Date
+    fn convert_to_typed_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> TypedCellData;
 }
 
 pub trait TypeOptionTransform: TypeOption {
@@ -113,4 +122,15 @@ pub trait TypeOptionCellDataCompare: TypeOption {
         cell_data: &<Self as TypeOption>::CellData,
         other_cell_data: &<Self as TypeOption>::CellData,
     ) -> Ordering;
+
+    /// Compares the cell data with the [Collation] of the sort. Only the type options whose cells
+    /// are compared by their texts override it, the others ignore the collation.
+    fn apply_cmp_with_collation(
+        &self,
+        cell_data: &<Self as TypeOption>::CellData,
+        other_cell_data: &<Self as TypeOption>::CellData,
+        _collation: &Collation,
+    ) -> Ordering {
+        self.apply_cmp(cell_data, other_cell_data)
+    }
 }
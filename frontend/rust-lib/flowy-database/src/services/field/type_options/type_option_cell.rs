@@ -4,13 +4,16 @@ use crate::services::cell::{
     FromCellChangesetString, FromCellString, TypeCellData,
 };
 use crate::services::field::{
-    CheckboxTypeOptionPB, ChecklistTypeOptionPB, DateTypeOptionPB, MultiSelectTypeOptionPB, NumberTypeOptionPB,
-    RichTextTypeOptionPB, SingleSelectTypeOptionPB, TypeOption, TypeOptionCellData, TypeOptionCellDataCompare,
-    TypeOptionCellDataFilter, TypeOptionTransform, URLTypeOptionPB,
+    AttachmentTypeOptionPB, CellValidationViolation, CheckboxTypeOptionPB, ChecklistTypeOptionPB, DateTypeOptionPB,
+    EmailTypeOptionPB, FormulaTypeOptionPB, MultiSelectTypeOptionPB, NumberTypeOptionPB, PersonTypeOptionPB,
+    PhoneNumberTypeOptionPB, ProgressTypeOptionPB, RatingTypeOptionPB, RelationTypeOptionPB, RichTextTypeOptionPB,
+    SingleSelectTypeOptionPB, TimestampTypeOptionPB, TypeOption, TypeOptionCellData, TypeOptionCellDataCompare,
+    TypeOptionCellDataFilter, TypeOptionTransform, TypedCellData, URLTypeOptionPB,
 };
 use crate::services::filter::FilterType;
+use crate::services::sort::Collation;
 use flowy_error::FlowyResult;
-use grid_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use grid_model::{CellRevision, FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
 use std::any::Any;
 use std::cmp::Ordering;
 use std::collections::hash_map::DefaultHasher;
@@ -37,7 +40,13 @@ pub trait TypeOptionCellDataHandler {
         field_rev: &FieldRevision,
     ) -> FlowyResult<String>;
 
-    fn handle_cell_compare(&self, left_cell_data: &str, right_cell_data: &str, field_rev: &FieldRevision) -> Ordering;
+    fn handle_cell_compare(
+        &self,
+        left_cell_data: &str,
+        right_cell_data: &str,
+        field_rev: &FieldRevision,
+        collation: &Collation,
+    ) -> Ordering;
 
     fn handle_cell_filter(
         &self,
@@ -57,6 +66,39 @@ pub trait TypeOptionCellDataHandler {
         decoded_field_type: &FieldType,
         field_rev: &FieldRevision,
     ) -> FlowyResult<BoxCellData>;
+
+    /// Decode the cell_str to the [TypedCellData] of this type option.
+    fn get_typed_cell_data(
+        &self,
+        cell_str: String,
+        decoded_field_type: &FieldType,
+        field_rev: &FieldRevision,
+    ) -> FlowyResult<TypedCellData>;
+
+    /// Converts the cell_str of the `decoded_field_type` to the cell_str of this type option, using
+    /// the same transformation as decoding the cell after switching the field type.
+    fn convert_cell_str(
+        &self,
+        cell_str: String,
+        decoded_field_type: &FieldType,
+        field_rev: &FieldRevision,
+    ) -> FlowyResult<String>;
+
+    /// Returns the validation rule that the stored cell violates. The stored cells are not
+    /// validated when the rules change, so they may violate the rules that are added later.
+    fn validate_cell_str(
+        &self,
+        cell_str: String,
+        decoded_field_type: &FieldType,
+        field_rev: &FieldRevision,
+    ) -> Option<CellValidationViolation>;
+
+    /// Returns true if the cell is empty, the missing cell is empty too. This is the emptiness
+    /// that the `IsEmpty` and `IsNotEmpty` conditions of all the filters check, see
+    /// [TypedCellData::is_empty] for each field type. For example, the multi-select cell whose
+    /// options are all removed, the number cell that contains only whitespace, and the unchecked
+    /// checkbox are empty, but the URL cell that has the content without the URL is not.
+    fn is_cell_empty(&self, cell_rev: Option<&CellRevision>, field_rev: &FieldRevision) -> bool;
 }
 
 struct CellDataCacheKey(u64);
@@ -215,11 +257,20 @@ where
     ) -> FlowyResult<String> {
         let changeset = <Self as TypeOption>::CellChangeset::from_changeset(cell_changeset)?;
         let (cell_str, cell_data) = self.apply_changeset(changeset, old_type_cell_data)?;
+        if let Some(violation) = self.validate_cell_data(&cell_data) {
+            return Err(violation.into_error(&field_rev.id));
+        }
         self.set_decoded_cell_data(&cell_str, cell_data, field_rev);
         Ok(cell_str)
     }
 
-    fn handle_cell_compare(&self, left_cell_data: &str, right_cell_data: &str, field_rev: &FieldRevision) -> Ordering {
+    fn handle_cell_compare(
+        &self,
+        left_cell_data: &str,
+        right_cell_data: &str,
+        field_rev: &FieldRevision,
+        collation: &Collation,
+    ) -> Ordering {
         let field_type: FieldType = field_rev.ty.into();
         let left = self
             .get_decoded_cell_data(left_cell_data.to_owned(), &field_type, field_rev)
@@ -227,7 +278,7 @@ where
         let right = self
             .get_decoded_cell_data(right_cell_data.to_owned(), &field_type, field_rev)
             .unwrap_or_default();
-        self.apply_cmp(&left, &right)
+        self.apply_cmp_with_collation(&left, &right, collation)
     }
 
     fn handle_cell_filter(
@@ -282,6 +333,53 @@ where
         };
         Ok(BoxCellData::new(cell_data))
     }
+
+    fn get_typed_cell_data(
+        &self,
+        cell_str: String,
+        decoded_field_type: &FieldType,
+        field_rev: &FieldRevision,
+    ) -> FlowyResult<TypedCellData> {
+        let cell_data = self
+            .get_cell_data(cell_str, decoded_field_type, field_rev)?
+            .unbox_or_default::<<Self as TypeOption>::CellData>();
+        Ok(self.convert_to_typed_cell_data(cell_data))
+    }
+
+    fn convert_cell_str(
+        &self,
+        cell_str: String,
+        decoded_field_type: &FieldType,
+        field_rev: &FieldRevision,
+    ) -> FlowyResult<String> {
+        let cell_data = self
+            .get_cell_data(cell_str, decoded_field_type, field_rev)?
+            .unbox_or_default::<<Self as TypeOption>::CellData>();
+        Ok(cell_data.to_string())
+    }
+
+    fn validate_cell_str(
+        &self,
+        cell_str: String,
+        decoded_field_type: &FieldType,
+        field_rev: &FieldRevision,
+    ) -> Option<CellValidationViolation> {
+        let cell_data = self
+            .get_cell_data(cell_str, decoded_field_type, field_rev)
+            .ok()?
+            .unbox_or_default::<<Self as TypeOption>::CellData>();
+        self.validate_cell_data(&cell_data)
+    }
+
+    fn is_cell_empty(&self, cell_rev: Option<&CellRevision>, field_rev: &FieldRevision) -> bool {
+        let type_cell_data = match cell_rev.map(TypeCellData::try_from) {
+            Some(Ok(type_cell_data)) => type_cell_data,
+            _ => return true,
+        };
+        self.get_typed_cell_data(type_cell_data.cell_str, &type_cell_data.field_type, field_rev)
+            .map(|typed_cell_data| typed_cell_data.is_empty())
+            .unwrap_or(true)
+    }
 }
 
 pub struct TypeOptionCellExt<'a> {
@@ -407,6 +505,96 @@ impl<'a> TypeOptionCellExt<'a> {
                         self.cell_data_cache.clone(),
                     )
                 }),
+            FieldType::CreatedTime | FieldType::LastEditedTime => self
+                .field_rev
+                .get_type_option::<TimestampTypeOptionPB>(field_type.into())
+                .map(|type_option| {
+                    TypeOptionCellDataHandlerImpl::new_with_boxed(
+                        type_option,
+                        self.cell_filter_cache.clone(),
+                        self.cell_data_cache.clone(),
+                    )
+                }),
+            FieldType::Relation => self
+                .field_rev
+                .get_type_option::<RelationTypeOptionPB>(field_type.into())
+                .map(|type_option| {
+                    TypeOptionCellDataHandlerImpl::new_with_boxed(
+                        type_option,
+                        self.cell_filter_cache.clone(),
+                        self.cell_data_cache.clone(),
+                    )
+                }),
+            FieldType::Formula => self
+                .field_rev
+                .get_type_option::<FormulaTypeOptionPB>(field_type.into())
+                .map(|type_option| {
+                    TypeOptionCellDataHandlerImpl::new_with_boxed(
+                        type_option,
+                        self.cell_filter_cache.clone(),
+                        self.cell_data_cache.clone(),
+                    )
+                }),
+            FieldType::Rating => self
+                .field_rev
+                .get_type_option::<RatingTypeOptionPB>(field_type.into())
+                .map(|type_option| {
+                    TypeOptionCellDataHandlerImpl::new_with_boxed(
+                        type_option,
+                        self.cell_filter_cache.clone(),
+                        self.cell_data_cache.clone(),
+                    )
+                }),
+            FieldType::Progress => self
+                .field_rev
+                .get_type_option::<ProgressTypeOptionPB>(field_type.into())
+                .map(|type_option| {
+                    TypeOptionCellDataHandlerImpl::new_with_boxed(
+                        type_option,
+                        self.cell_filter_cache.clone(),
+                        self.cell_data_cache.clone(),
+                    )
+                }),
+            FieldType::Email => self
+                .field_rev
+                .get_type_option::<EmailTypeOptionPB>(field_type.into())
+                .map(|type_option| {
+                    TypeOptionCellDataHandlerImpl::new_with_boxed(
+                        type_option,
+                        self.cell_filter_cache.clone(),
+                        self.cell_data_cache.clone(),
+                    )
+                }),
+            FieldType::PhoneNumber => self
+                .field_rev
+                .get_type_option::<PhoneNumberTypeOptionPB>(field_type.into())
+                .map(|type_option| {
+                    TypeOptionCellDataHandlerImpl::new_with_boxed(
+                        type_option,
+                        self.cell_filter_cache.clone(),
+                        self.cell_data_cache.clone(),
+                    )
+                }),
+            FieldType::Attachment => self
+                .field_rev
+                .get_type_option::<AttachmentTypeOptionPB>(field_type.into())
+                .map(|type_option| {
+                    TypeOptionCellDataHandlerImpl::new_with_boxed(
+                        type_option,
+                        self.cell_filter_cache.clone(),
+                        self.cell_data_cache.clone(),
+                    )
+                }),
+            FieldType::Person => self
+                .field_rev
+                .get_type_option::<PersonTypeOptionPB>(field_type.into())
+                .map(|type_option| {
+                    TypeOptionCellDataHandlerImpl::new_with_boxed(
+                        type_option,
+                        self.cell_filter_cache.clone(),
+                        self.cell_data_cache.clone(),
+                    )
+                }),
         }
     }
 }
@@ -474,6 +662,33 @@ fn get_type_option_transform_handler(
         FieldType::Checklist => {
             Box::new(ChecklistTypeOptionPB::from_json_str(type_option_data)) as Box<dyn TypeOptionTransformHandler>
         }
+        FieldType::CreatedTime | FieldType::LastEditedTime => {
+            Box::new(TimestampTypeOptionPB::from_json_str(type_option_data)) as Box<dyn TypeOptionTransformHandler>
+        }
+        FieldType::Relation => {
+            Box::new(RelationTypeOptionPB::from_json_str(type_option_data)) as Box<dyn TypeOptionTransformHandler>
+        }
+        FieldType::Formula => {
+            Box::new(FormulaTypeOptionPB::from_json_str(type_option_data)) as Box<dyn TypeOptionTransformHandler>
+        }
+        FieldType::Rating => {
+            Box::new(RatingTypeOptionPB::from_json_str(type_option_data)) as Box<dyn TypeOptionTransformHandler>
+        }
+        FieldType::Progress => {
+            Box::new(ProgressTypeOptionPB::from_json_str(type_option_data)) as Box<dyn TypeOptionTransformHandler>
+        }
+        FieldType::Email => {
+            Box::new(EmailTypeOptionPB::from_json_str(type_option_data)) as Box<dyn TypeOptionTransformHandler>
+        }
+        FieldType::PhoneNumber => {
+            Box::new(PhoneNumberTypeOptionPB::from_json_str(type_option_data)) as Box<dyn TypeOptionTransformHandler>
+        }
+        FieldType::Attachment => {
+            Box::new(AttachmentTypeOptionPB::from_json_str(type_option_data)) as Box<dyn TypeOptionTransformHandler>
+        }
+        FieldType::Person => {
+            Box::new(PersonTypeOptionPB::from_json_str(type_option_data)) as Box<dyn TypeOptionTransformHandler>
+        }
     }
 }
 
@@ -549,7 +764,44 @@ impl RowSingleCellData {
     );
     into_cell_data!(into_date_field_cell_data, <DateTypeOptionPB as TypeOption>::CellData);
     into_cell_data!(
-        into_check_list_field_cell_data,
+        into_checkbox_field_cell_data,
         <CheckboxTypeOptionPB as TypeOption>::CellData
     );
+    into_cell_data!(
+        into_check_list_field_cell_data,
+        <ChecklistTypeOptionPB as TypeOption>::CellData
+    );
+    into_cell_data!(
+        into_timestamp_field_cell_data,
+        <TimestampTypeOptionPB as TypeOption>::CellData
+    );
+    into_cell_data!(
+        into_relation_field_cell_data,
+        <RelationTypeOptionPB as TypeOption>::CellData
+    );
+    into_cell_data!(
+        into_formula_field_cell_data,
+        <FormulaTypeOptionPB as TypeOption>::CellData
+    );
+    into_cell_data!(
+        into_rating_field_cell_data,
+        <RatingTypeOptionPB as TypeOption>::CellData
+    );
+    into_cell_data!(
+        into_progress_field_cell_data,
+        <ProgressTypeOptionPB as TypeOption>::CellData
+    );
+    into_cell_data!(into_email_field_cell_data, <EmailTypeOptionPB as TypeOption>::CellData);
+    into_cell_data!(
+        into_phone_number_field_cell_data,
+        <PhoneNumberTypeOptionPB as TypeOption>::CellData
+    );
+    into_cell_data!(
+        into_attachment_field_cell_data,
+        <AttachmentTypeOptionPB as TypeOption>::CellData
+    );
+    into_cell_data!(
+        into_person_field_cell_data,
+        <PersonTypeOptionPB as TypeOption>::CellData
+    );
 }
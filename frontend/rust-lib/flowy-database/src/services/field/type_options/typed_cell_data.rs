@@ -0,0 +1,78 @@
+use crate::services::field::{AttachmentPB, ChecklistItem, FormulaValue, MemberPB, SelectOptionPB};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+/// [TypedCellData] is the decoded data of the cell, it's produced by the type option of the field.
+/// Unlike the protobuf of the cell, the consumers don't need to know which struct to decode for
+/// each field type.
+///
+/// The empty cell is represented by the empty value of the variant, for example, `Number(None)`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypedCellData {
+    Text(String),
+    Number(Option<TypedNumber>),
+    /// The cells of the created time and the last edited time fields are dates too.
+    Date {
+        timestamp: Option<i64>,
+        include_time: bool,
+    },
+    SingleSelect(Option<SelectOptionPB>),
+    MultiSelect(Vec<SelectOptionPB>),
+    Checkbox(bool),
+    Url {
+        url: String,
+        content: String,
+    },
+    Checklist(Vec<ChecklistItem>),
+    /// The ids of the related rows.
+    Relation(Vec<String>),
+    Formula {
+        value: Option<FormulaValue>,
+        error: Option<String>,
+    },
+    Rating(i64),
+    /// The progress is between 0 and 1.
+    Progress(Option<f64>),
+    Email(String),
+    PhoneNumber(String),
+    Attachment(Vec<AttachmentPB>),
+    Person(Vec<MemberPB>),
+}
+
+impl TypedCellData {
+    pub fn is_empty(&self) -> bool {
+        match self {
+            TypedCellData::Text(s) | TypedCellData::Email(s) | TypedCellData::PhoneNumber(s) => s.is_empty(),
+            TypedCellData::Number(number) => number.is_none(),
+            TypedCellData::Date { timestamp, .. } => timestamp.is_none(),
+            TypedCellData::SingleSelect(option) => option.is_none(),
+            TypedCellData::MultiSelect(options) => options.is_empty(),
+            TypedCellData::Checkbox(is_check) => !is_check,
+            TypedCellData::Url { url, content } => url.is_empty() && content.is_empty(),
+            TypedCellData::Checklist(items) => items.is_empty(),
+            TypedCellData::Relation(row_ids) => row_ids.is_empty(),
+            TypedCellData::Formula { value, .. } => value.is_none(),
+            TypedCellData::Rating(rating) => *rating <= 0,
+            TypedCellData::Progress(progress) => progress.is_none(),
+            TypedCellData::Attachment(attachments) => attachments.is_empty(),
+            TypedCellData::Person(members) => members.is_empty(),
+        }
+    }
+}
+
+/// [TypedNumber] keeps the exact decimal of the number cell, the `value` is its lossy
+/// approximation that is convenient for calculations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypedNumber {
+    pub decimal: String,
+    pub value: f64,
+}
+
+impl std::convert::From<Decimal> for TypedNumber {
+    fn from(decimal: Decimal) -> Self {
+        Self {
+            value: decimal.to_f64().unwrap_or_default(),
+            decimal: decimal.to_string(),
+        }
+    }
+}
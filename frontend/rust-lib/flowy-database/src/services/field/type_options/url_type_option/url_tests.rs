@@ -1,11 +1,11 @@
 #[cfg(test)]
 mod tests {
     use crate::entities::FieldType;
-    use crate::services::cell::CellDataChangeset;
+    use crate::services::cell::{CellDataChangeset, TypeCellData};
 
     use crate::services::field::FieldBuilder;
-    use crate::services::field::URLTypeOptionPB;
-    use grid_model::FieldRevision;
+    use crate::services::field::{TypeOptionCellDataHandler, TypeOptionCellExt, URLTypeOptionPB};
+    use grid_model::{CellRevision, FieldRevision};
 
     /// The expected_str will equal to the input string, but the expected_url will be empty if there's no
     /// http url in the input string.
@@ -150,6 +150,27 @@ mod tests {
         );
     }
 
+    /// The URL cell that has the content without any URL shows the content, so it's not empty.
+    #[test]
+    fn url_cell_with_content_but_no_url_is_not_empty_test() {
+        let type_option = URLTypeOptionPB::default();
+        let field_type = FieldType::URL;
+        let field_rev = FieldBuilder::from_field_type(&field_type).build();
+        let handler = TypeOptionCellExt::new(&field_rev, None, None)
+            .get_type_option_cell_data_handler(&field_type)
+            .unwrap();
+        for (input_str, is_empty) in [("123", false), ("https://www.appflowy.io", false), ("", true)] {
+            let cell_str = type_option.apply_changeset(input_str.to_owned(), None).unwrap().0;
+            let cell_rev = CellRevision::new(TypeCellData::new(cell_str, field_type.clone()).to_json());
+            assert_eq!(
+                handler.is_cell_empty(Some(&cell_rev), &field_rev),
+                is_empty,
+                "{:?}",
+                input_str
+            );
+        }
+    }
+
     fn assert_url(
         type_option: &URLTypeOptionPB,
         input_str: &str,
@@ -2,9 +2,10 @@ use crate::entities::{FieldType, TextFilterPB};
 use crate::impl_type_option;
 use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
 use crate::services::field::{
-    BoxTypeOptionBuilder, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
-    TypeOptionCellDataFilter, TypeOptionTransform, URLCellData, URLCellDataPB,
+    BoxTypeOptionBuilder, EmailCellData, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
+    TypeOptionCellDataFilter, TypeOptionTransform, TypedCellData, URLCellData, URLCellDataPB, MAILTO_SCHEME,
 };
+use crate::services::sort::Collation;
 use bytes::Bytes;
 use fancy_regex::Regex;
 use flowy_derive::ProtoBuf;
@@ -46,7 +47,32 @@ impl TypeOption for URLTypeOptionPB {
     type CellFilter = TextFilterPB;
 }
 
-impl TypeOptionTransform for URLTypeOptionPB {}
+impl TypeOptionTransform for URLTypeOptionPB {
+    fn transformable(&self) -> bool {
+        true
+    }
+
+    fn transform_type_option_cell_str(
+        &self,
+        cell_str: &str,
+        decoded_field_type: &FieldType,
+        _field_rev: &FieldRevision,
+    ) -> Option<<Self as TypeOption>::CellData> {
+        if decoded_field_type.is_email() {
+            // The emails are converted into the mailto links.
+            let email = EmailCellData::from_cell_str(cell_str).ok()?;
+            if email.0.is_empty() {
+                return Some(URLCellData::default());
+            }
+            Some(URLCellData {
+                url: format!("{}{}", MAILTO_SCHEME, email.0),
+                content: email.0,
+            })
+        } else {
+            None
+        }
+    }
+}
 
 impl TypeOptionCellData for URLTypeOptionPB {
     fn convert_to_protobuf(&self, cell_data: <Self as TypeOption>::CellData) -> <Self as TypeOption>::CellProtobufType {
@@ -56,6 +82,13 @@ impl TypeOptionCellData for URLTypeOptionPB {
     fn decode_type_option_cell_str(&self, cell_str: String) -> FlowyResult<<Self as TypeOption>::CellData> {
         URLCellData::from_cell_str(&cell_str)
     }
+
+    fn convert_to_typed_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> TypedCellData {
+        TypedCellData::Url {
+            url: cell_data.url,
+            content: cell_data.content,
+        }
+    }
 }
 
 impl CellDataDecoder for URLTypeOptionPB {
@@ -118,7 +151,16 @@ impl TypeOptionCellDataCompare for URLTypeOptionPB {
         cell_data: &<Self as TypeOption>::CellData,
         other_cell_data: &<Self as TypeOption>::CellData,
     ) -> Ordering {
-        cell_data.content.cmp(&other_cell_data.content)
+        self.apply_cmp_with_collation(cell_data, other_cell_data, &Collation::default())
+    }
+
+    fn apply_cmp_with_collation(
+        &self,
+        cell_data: &<Self as TypeOption>::CellData,
+        other_cell_data: &<Self as TypeOption>::CellData,
+        collation: &Collation,
+    ) -> Ordering {
+        collation.compare(&cell_data.content, &other_cell_data.content)
     }
 }
 fn auto_append_scheme(s: &str) -> String {
@@ -1,23 +1,35 @@
 use crate::entities::filter_entities::*;
 use crate::entities::{FieldType, InsertedRowPB, RowPB};
-use crate::services::cell::{AnyTypeCache, AtomicCellDataCache, AtomicCellFilterCache, TypeCellData};
+use crate::services::cell::{
+    get_cell_rev_from_row, AnyTypeCache, AtomicCellDataCache, AtomicCellFilterCache, TypeCellData,
+};
 use crate::services::field::*;
-use crate::services::filter::{FilterChangeset, FilterResult, FilterResultNotification, FilterType};
+use crate::services::filter::{FilterChangeset, FilterResult, FilterResultNotification, FilterTree, FilterType};
 use crate::services::row::DatabaseBlockRowRevision;
 use crate::services::view_editor::{GridViewChanged, GridViewChangedNotifier};
+use chrono::Local;
 use flowy_error::FlowyResult;
 use flowy_task::{QualityOfService, Task, TaskContent, TaskDispatcher};
-use grid_model::{CellRevision, FieldId, FieldRevision, FilterRevision, RowRevision};
+use grid_model::{CellRevision, FieldId, FieldRevision, FilterGroupRevision, FilterRevision, RowRevision};
 use lib_infra::future::Fut;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
 
 type RowId = String;
+
+/// The rows are searched after the query stops changing for this duration, so typing the query
+/// doesn't search all the rows for each keystroke.
+const SEARCH_DEBOUNCE_DURATION: Duration = Duration::from_millis(300);
+
 pub trait FilterDelegate: Send + Sync + 'static {
     fn get_filter_rev(&self, filter_type: FilterType) -> Fut<Option<Arc<FilterRevision>>>;
+    fn get_filter_tree(&self) -> Fut<FilterGroupRevision>;
     fn get_field_rev(&self, field_id: &str) -> Fut<Option<Arc<FieldRevision>>>;
     fn get_field_revs(&self, field_ids: Option<Vec<String>>) -> Fut<Vec<Arc<FieldRevision>>>;
     fn get_blocks(&self) -> Fut<Vec<DatabaseBlockRowRevision>>;
@@ -37,8 +49,31 @@ pub struct FilterController {
     result_by_row_id: HashMap<RowId, FilterResult>,
     cell_data_cache: AtomicCellDataCache,
     cell_filter_cache: AtomicCellFilterCache,
+    filter_tree: FilterTree,
     task_scheduler: Arc<RwLock<TaskDispatcher>>,
     notifier: GridViewChangedNotifier,
+    // The date filters whose conditions are relative to today.
+    relative_date_filter_types: HashSet<FilterType>,
+    // The filters whose conditions check whether the cells are empty, the value is true for the
+    // `IsEmpty` conditions and false for the `IsNotEmpty` conditions.
+    emptiness_by_filter_type: HashMap<FilterType, bool>,
+    midnight_refresh: Option<JoinHandle<()>>,
+    // The lowercase search query of the view. It's not persisted.
+    search_query: Option<String>,
+    pending_search: Option<JoinHandle<()>>,
+    // The number of the times that a filter evaluated a cell.
+    evaluation_count: AtomicUsize,
+}
+
+impl Drop for FilterController {
+    fn drop(&mut self) {
+        if let Some(midnight_refresh) = self.midnight_refresh.take() {
+            midnight_refresh.abort();
+        }
+        if let Some(pending_search) = self.pending_search.take() {
+            pending_search.abort();
+        }
+    }
 }
 
 impl FilterController {
@@ -61,19 +96,26 @@ impl FilterController {
             result_by_row_id: HashMap::default(),
             cell_data_cache,
             cell_filter_cache: AnyTypeCache::<FilterType>::new(),
+            filter_tree: FilterTree::default(),
             task_scheduler,
             notifier,
+            relative_date_filter_types: HashSet::new(),
+            emptiness_by_filter_type: HashMap::new(),
+            midnight_refresh: None,
+            search_query: None,
+            pending_search: None,
+            evaluation_count: AtomicUsize::new(0),
         };
         this.refresh_filters(filter_revs).await;
+        this.filter_tree.set_root(this.delegate.get_filter_tree().await);
+        this.update_midnight_refresh();
         this
     }
 
     pub async fn close(&self) {
-        self.task_scheduler
-            .write()
-            .await
-            .unregister_handler(&self.handler_id)
-            .await;
+        let mut task_scheduler = self.task_scheduler.write().await;
+        task_scheduler.cancel_tasks_of_handler(&self.handler_id);
+        task_scheduler.unregister_handler(&self.handler_id).await;
     }
 
     #[tracing::instrument(name = "schedule_filter_task", level = "trace", skip(self))]
@@ -84,28 +126,77 @@ impl FilterController {
     }
 
     pub async fn filter_row_revs(&mut self, row_revs: &mut Vec<Arc<RowRevision>>) {
-        if self.cell_filter_cache.read().is_empty() {
+        if self.cell_filter_cache.read().is_empty() && self.search_query.is_none() {
             return;
         }
         let field_rev_by_field_id = self.get_filter_revs_map().await;
+        let context = RowFilterContext {
+            field_rev_by_field_id: &field_rev_by_field_id,
+            cell_data_cache: &self.cell_data_cache,
+            cell_filter_cache: &self.cell_filter_cache,
+            filter_tree: &self.filter_tree,
+            search_query: self.search_query.as_deref(),
+            emptiness_by_filter_type: &self.emptiness_by_filter_type,
+            evaluation_count: &self.evaluation_count,
+        };
+        // The restored rows are shown with their cached visibility until they are revalidated.
+        let is_searching = self.search_query.is_some();
         row_revs.iter().for_each(|row_rev| {
-            let _ = filter_row(
-                row_rev,
-                &mut self.result_by_row_id,
-                &field_rev_by_field_id,
-                &self.cell_data_cache,
-                &self.cell_filter_cache,
-            );
+            let is_restored = self
+                .result_by_row_id
+                .get(&row_rev.id)
+                .map(|result| result.is_restored)
+                .unwrap_or(false);
+            if !is_restored || is_searching {
+                let _ = filter_row(row_rev, &mut self.result_by_row_id, &context, None);
+            }
         });
 
         row_revs.retain(|row_rev| {
             self.result_by_row_id
                 .get(&row_rev.id)
-                .map(|result| result.is_visible())
+                .map(|result| result.is_visible)
                 .unwrap_or(false)
         });
     }
 
+    /// Returns the number of the rows that are visible after applying the filters. The cached
+    /// results of the rows are used, only the rows that were never filtered, like the rows that
+    /// were just created, are filtered and cached.
+    pub async fn visible_row_count(&mut self, row_revs: &[Arc<RowRevision>]) -> usize {
+        if self.cell_filter_cache.read().is_empty() && self.search_query.is_none() {
+            return row_revs.len();
+        }
+        let unfiltered_row_revs = row_revs
+            .iter()
+            .filter(|row_rev| !self.result_by_row_id.contains_key(&row_rev.id))
+            .collect::<Vec<&Arc<RowRevision>>>();
+        if !unfiltered_row_revs.is_empty() {
+            let field_rev_by_field_id = self.get_filter_revs_map().await;
+            let context = RowFilterContext {
+                field_rev_by_field_id: &field_rev_by_field_id,
+                cell_data_cache: &self.cell_data_cache,
+                cell_filter_cache: &self.cell_filter_cache,
+                filter_tree: &self.filter_tree,
+                search_query: self.search_query.as_deref(),
+                emptiness_by_filter_type: &self.emptiness_by_filter_type,
+                evaluation_count: &self.evaluation_count,
+            };
+            for row_rev in unfiltered_row_revs {
+                let _ = filter_row(row_rev, &mut self.result_by_row_id, &context, None);
+            }
+        }
+        row_revs
+            .iter()
+            .filter(|row_rev| {
+                self.result_by_row_id
+                    .get(&row_rev.id)
+                    .map(|result| result.is_visible)
+                    .unwrap_or(false)
+            })
+            .count()
+    }
+
     async fn get_filter_revs_map(&self) -> HashMap<String, Arc<FieldRevision>> {
         self.delegate
             .get_field_revs(None)
@@ -119,23 +210,33 @@ impl FilterController {
     pub async fn process(&mut self, predicate: &str) -> FlowyResult<()> {
         let event_type = FilterEvent::from_str(predicate).unwrap();
         match event_type {
-            FilterEvent::FilterDidChanged => self.filter_all_rows().await?,
-            FilterEvent::RowDidChanged(row_id) => self.filter_row(row_id).await?,
+            FilterEvent::FilterDidChanged => self.filter_all_rows(false).await?,
+            FilterEvent::RowDidChanged(row_id) => self.filter_row(row_id, None).await?,
+            FilterEvent::CellsDidChanged { row_id, field_ids } => self.filter_row(row_id, Some(field_ids)).await?,
+            FilterEvent::SearchDidChanged => self.filter_all_rows(true).await?,
         }
         Ok(())
     }
 
-    async fn filter_row(&mut self, row_id: String) -> FlowyResult<()> {
+    /// Filters the row and notifies the row if its visibility changed. If the `field_ids` is not
+    /// None, only the filters of the fields are evaluated, the cached results of the other filters
+    /// of the row are reused.
+    async fn filter_row(&mut self, row_id: String, field_ids: Option<Vec<String>>) -> FlowyResult<()> {
         if let Some((_, row_rev)) = self.delegate.get_row_rev(&row_id).await {
             let field_rev_by_field_id = self.get_filter_revs_map().await;
+            let context = RowFilterContext {
+                field_rev_by_field_id: &field_rev_by_field_id,
+                cell_data_cache: &self.cell_data_cache,
+                cell_filter_cache: &self.cell_filter_cache,
+                filter_tree: &self.filter_tree,
+                search_query: self.search_query.as_deref(),
+                emptiness_by_filter_type: &self.emptiness_by_filter_type,
+                evaluation_count: &self.evaluation_count,
+            };
             let mut notification = FilterResultNotification::new(self.view_id.clone(), row_rev.block_id.clone());
-            if let Some((row_id, is_visible)) = filter_row(
-                &row_rev,
-                &mut self.result_by_row_id,
-                &field_rev_by_field_id,
-                &self.cell_data_cache,
-                &self.cell_filter_cache,
-            ) {
+            if let Some((row_id, is_visible)) =
+                filter_row(&row_rev, &mut self.result_by_row_id, &context, field_ids.as_deref())
+            {
                 if is_visible {
                     if let Some((index, row_rev)) = self.delegate.get_row_rev(&row_id).await {
                         let row_pb = RowPB::from(row_rev.as_ref());
@@ -146,28 +247,43 @@ impl FilterController {
                 } else {
                     notification.invisible_rows.push(row_id);
                 }
+                let _ = self.notifier.send(GridViewChanged::FilterNotification(notification));
             }
-
-            let _ = self.notifier.send(GridViewChanged::FilterNotification(notification));
         }
         Ok(())
     }
 
-    async fn filter_all_rows(&mut self) -> FlowyResult<()> {
+    /// Filters all the rows and notifies the rows whose visibility changed. If `search_only` is
+    /// true, only the search query is matched again, and the cached results of the filters are
+    /// reused, so clearing the query restores the rows without running the filters.
+    async fn filter_all_rows(&mut self, search_only: bool) -> FlowyResult<()> {
         let field_rev_by_field_id = self.get_filter_revs_map().await;
+        let context = RowFilterContext {
+            field_rev_by_field_id: &field_rev_by_field_id,
+            cell_data_cache: &self.cell_data_cache,
+            cell_filter_cache: &self.cell_filter_cache,
+            filter_tree: &self.filter_tree,
+            search_query: self.search_query.as_deref(),
+            emptiness_by_filter_type: &self.emptiness_by_filter_type,
+            evaluation_count: &self.evaluation_count,
+        };
         for block in self.delegate.get_blocks().await.into_iter() {
             // The row_ids contains the row that its visibility was changed.
             let mut visible_rows = vec![];
             let mut invisible_rows = vec![];
 
             for (index, row_rev) in block.row_revs.iter().enumerate() {
-                if let Some((row_id, is_visible)) = filter_row(
-                    row_rev,
-                    &mut self.result_by_row_id,
-                    &field_rev_by_field_id,
-                    &self.cell_data_cache,
-                    &self.cell_filter_cache,
-                ) {
+                let has_filter_results = self
+                    .result_by_row_id
+                    .get(&row_rev.id)
+                    .map(|result| !result.is_restored)
+                    .unwrap_or(false);
+                let changed = if search_only && has_filter_results {
+                    search_row(row_rev, &mut self.result_by_row_id, &context)
+                } else {
+                    filter_row(row_rev, &mut self.result_by_row_id, &context, None)
+                };
+                if let Some((row_id, is_visible)) = changed {
                     if is_visible {
                         let row_pb = RowPB::from(row_rev.as_ref());
                         visible_rows.push(InsertedRowPB::with_index(row_pb, index as i32))
@@ -189,17 +305,56 @@ impl FilterController {
         Ok(())
     }
 
-    pub async fn did_receive_row_changed(&self, row_id: &str) {
-        self.gen_task(
-            FilterEvent::RowDidChanged(row_id.to_string()),
-            QualityOfService::UserInteractive,
-        )
-        .await
+    /// Filters the row again after it's changed. If the `changed_field_ids` is not None, only the
+    /// filters of the fields whose cells changed are evaluated.
+    pub async fn did_receive_row_changed(&self, row_id: &str, changed_field_ids: Option<Vec<String>>) {
+        let event = match changed_field_ids {
+            None => FilterEvent::RowDidChanged(row_id.to_string()),
+            Some(field_ids) => FilterEvent::CellsDidChanged {
+                row_id: row_id.to_string(),
+                field_ids,
+            },
+        };
+        self.gen_task(event, QualityOfService::UserInteractive).await
+    }
+
+    /// Returns the visibility of the filtered rows after applying the filters, the search query is
+    /// ignored. It's empty if the view has no filters.
+    pub fn visibility_by_row_id(&self) -> HashMap<String, bool> {
+        if self.cell_filter_cache.read().is_empty() {
+            return HashMap::new();
+        }
+        self.result_by_row_id
+            .iter()
+            .map(|(row_id, result)| (row_id.clone(), result.is_visible_by_filters(&self.filter_tree)))
+            .collect()
+    }
+
+    /// Restores the visibility of the rows that was saved when the view was closed, so the rows
+    /// are shown without running the filters. The rows are revalidated in the background, and the
+    /// rows whose visibility changed are notified.
+    pub async fn restore_visibility(&mut self, visible_by_row_id: HashMap<String, bool>) {
+        if self.cell_filter_cache.read().is_empty() || visible_by_row_id.is_empty() {
+            return;
+        }
+        for (row_id, is_visible) in visible_by_row_id {
+            self.result_by_row_id
+                .entry(row_id)
+                .or_insert_with(|| FilterResult::restored(is_visible));
+        }
+        self.gen_task(FilterEvent::FilterDidChanged, QualityOfService::Background)
+            .await;
+    }
+
+    /// Returns the number of the times that a filter evaluated a cell of the view.
+    pub fn evaluation_count(&self) -> usize {
+        self.evaluation_count.load(Ordering::SeqCst)
     }
 
     #[tracing::instrument(level = "trace", skip(self))]
     pub async fn did_receive_changes(&mut self, changeset: FilterChangeset) -> Option<FilterChangesetNotificationPB> {
         let mut notification: Option<FilterChangesetNotificationPB> = None;
+        self.filter_tree.set_root(self.delegate.get_filter_tree().await);
         if let Some(filter_type) = &changeset.insert_filter {
             if let Some(filter) = self.filter_from_filter_type(filter_type).await {
                 notification = Some(FilterChangesetNotificationPB::from_insert(&self.view_id, vec![filter]));
@@ -221,6 +376,8 @@ impl FilterController {
                 }
 
                 // Update the corresponding filter in the cache
+                self.relative_date_filter_types.remove(&old_filter_type);
+                self.emptiness_by_filter_type.remove(&old_filter_type);
                 if let Some(filter_rev) = self.delegate.get_filter_rev(updated_filter_type.new.clone()).await {
                     self.refresh_filters(vec![filter_rev]).await;
                 }
@@ -239,17 +396,125 @@ impl FilterController {
 
         if let Some(filter_type) = &changeset.delete_filter {
             if let Some(filter) = self.filter_from_filter_type(filter_type).await {
+                // The filter is removed from the view after the change is received.
+                self.filter_tree.remove_filter(&filter.id);
                 notification = Some(FilterChangesetNotificationPB::from_delete(&self.view_id, vec![filter]));
             }
             self.cell_filter_cache.write().remove(filter_type);
+            self.relative_date_filter_types.remove(filter_type);
+            self.emptiness_by_filter_type.remove(filter_type);
         }
 
+        if let Some(notification) = notification.as_mut() {
+            notification.filter_tree = Some(self.filter_tree.root().into());
+        }
+        self.update_midnight_refresh();
         self.gen_task(FilterEvent::FilterDidChanged, QualityOfService::Background)
             .await;
         tracing::trace!("{:?}", notification);
         notification
     }
 
+    /// Sets the search query of the view, which hides the rows that don't contain the query in
+    /// the display text of any of their cells, ignoring the case. The rows are searched after the
+    /// query stops changing for a while.
+    pub fn set_search_query(&mut self, query: Option<String>) {
+        let query = query
+            .map(|query| query.trim().to_lowercase())
+            .filter(|query| !query.is_empty());
+        if query == self.search_query {
+            return;
+        }
+        self.search_query = query;
+
+        if let Some(pending_search) = self.pending_search.take() {
+            pending_search.abort();
+        }
+        let handler_id = self.handler_id.clone();
+        let task_scheduler = self.task_scheduler.clone();
+        self.pending_search = Some(tokio::spawn(async move {
+            tokio::time::sleep(SEARCH_DEBOUNCE_DURATION).await;
+            let mut task_scheduler = task_scheduler.write().await;
+            let task_id = task_scheduler.next_task_id();
+            let content = TaskContent::Text(FilterEvent::SearchDidChanged.to_string());
+            task_scheduler.add_task(Task::new(
+                &handler_id,
+                task_id,
+                content,
+                QualityOfService::UserInteractive,
+            ));
+        }));
+    }
+
+    /// Replaces the filters with the new filters of the view, for example, after a filter set is
+    /// applied. The rows are filtered again once for all the changed filters.
+    pub async fn did_replace_filters(
+        &mut self,
+        old_filter_revs: Vec<Arc<FilterRevision>>,
+        filter_revs: Vec<Arc<FilterRevision>>,
+    ) -> FilterChangesetNotificationPB {
+        for old_filter_rev in old_filter_revs.iter() {
+            if let Some(field_rev) = self.delegate.get_field_rev(&old_filter_rev.field_id).await {
+                let filter_type = FilterType::from(&field_rev);
+                self.cell_filter_cache.write().remove(&filter_type);
+                self.relative_date_filter_types.remove(&filter_type);
+                self.emptiness_by_filter_type.remove(&filter_type);
+            }
+            self.filter_tree.remove_filter(&old_filter_rev.id);
+        }
+        self.filter_tree.set_root(self.delegate.get_filter_tree().await);
+        let insert_filters = filter_revs
+            .iter()
+            .map(|filter_rev| FilterPB::from(filter_rev.as_ref()))
+            .collect();
+        self.refresh_filters(filter_revs).await;
+        self.update_midnight_refresh();
+        self.gen_task(FilterEvent::FilterDidChanged, QualityOfService::Background)
+            .await;
+
+        let mut notification = FilterChangesetNotificationPB::from_insert(&self.view_id, insert_filters);
+        notification.delete_filters = old_filter_revs
+            .iter()
+            .map(|filter_rev| FilterPB::from(filter_rev.as_ref()))
+            .collect();
+        notification.filter_tree = Some(self.filter_tree.root().into());
+        notification
+    }
+
+    /// Re-runs the filters after the groups that combine the filters are changed.
+    pub async fn did_receive_filter_tree_changed(&mut self) -> FilterChangesetNotificationPB {
+        self.filter_tree.set_root(self.delegate.get_filter_tree().await);
+        self.gen_task(FilterEvent::FilterDidChanged, QualityOfService::Background)
+            .await;
+        FilterChangesetNotificationPB::from_filter_tree(&self.view_id, self.filter_tree.root().into())
+    }
+
+    /// Re-runs the filters at every local midnight while there are date filters relative to
+    /// today, so the rows they show stay correct in the long-running sessions.
+    fn update_midnight_refresh(&mut self) {
+        if self.relative_date_filter_types.is_empty() {
+            if let Some(midnight_refresh) = self.midnight_refresh.take() {
+                midnight_refresh.abort();
+            }
+            return;
+        }
+        if self.midnight_refresh.is_some() {
+            return;
+        }
+
+        let handler_id = self.handler_id.clone();
+        let task_scheduler = self.task_scheduler.clone();
+        self.midnight_refresh = Some(tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(duration_until_next_local_midnight()).await;
+                let mut task_scheduler = task_scheduler.write().await;
+                let task_id = task_scheduler.next_task_id();
+                let content = TaskContent::Text(FilterEvent::FilterDidChanged.to_string());
+                task_scheduler.add_task(Task::new(&handler_id, task_id, content, QualityOfService::Background));
+            }
+        }));
+    }
+
     async fn filter_from_filter_type(&self, filter_type: &FilterType) -> Option<FilterPB> {
         self.delegate
             .get_filter_rev(filter_type.clone())
@@ -263,21 +528,30 @@ impl FilterController {
             if let Some(field_rev) = self.delegate.get_field_rev(&filter_rev.field_id).await {
                 let filter_type = FilterType::from(&field_rev);
                 tracing::trace!("Create filter with type: {:?}", filter_type);
+                self.filter_tree.insert_filter(&filter_rev.id, filter_type.clone());
+                match emptiness_of_filter_condition(&filter_type.field_type, filter_rev.condition) {
+                    None => self.emptiness_by_filter_type.remove(&filter_type),
+                    Some(is_empty) => self.emptiness_by_filter_type.insert(filter_type.clone(), is_empty),
+                };
                 match &filter_type.field_type {
                     FieldType::RichText => {
                         self.cell_filter_cache
                             .write()
                             .insert(&filter_type, TextFilterPB::from_filter_rev(filter_rev.as_ref()));
                     }
-                    FieldType::Number => {
+                    FieldType::Number | FieldType::Rating | FieldType::Progress => {
                         self.cell_filter_cache
                             .write()
                             .insert(&filter_type, NumberFilterPB::from_filter_rev(filter_rev.as_ref()));
                     }
-                    FieldType::DateTime => {
-                        self.cell_filter_cache
-                            .write()
-                            .insert(&filter_type, DateFilterPB::from_filter_rev(filter_rev.as_ref()));
+                    FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => {
+                        let date_filter = DateFilterPB::from_filter_rev(filter_rev.as_ref());
+                        if date_filter.condition.is_relative() {
+                            self.relative_date_filter_types.insert(filter_type.clone());
+                        } else {
+                            self.relative_date_filter_types.remove(&filter_type);
+                        }
+                        self.cell_filter_cache.write().insert(&filter_type, date_filter);
                     }
                     FieldType::SingleSelect | FieldType::MultiSelect => {
                         self.cell_filter_cache
@@ -289,7 +563,7 @@ impl FilterController {
                             .write()
                             .insert(&filter_type, CheckboxFilterPB::from_filter_rev(filter_rev.as_ref()));
                     }
-                    FieldType::URL => {
+                    FieldType::URL | FieldType::Relation | FieldType::Formula | FieldType::PhoneNumber => {
                         self.cell_filter_cache
                             .write()
                             .insert(&filter_type, TextFilterPB::from_filter_rev(filter_rev.as_ref()));
@@ -299,44 +573,97 @@ impl FilterController {
                             .write()
                             .insert(&filter_type, ChecklistFilterPB::from_filter_rev(filter_rev.as_ref()));
                     }
+                    FieldType::Email => {
+                        self.cell_filter_cache
+                            .write()
+                            .insert(&filter_type, EmailFilterPB::from_filter_rev(filter_rev.as_ref()));
+                    }
+                    FieldType::Attachment => {
+                        self.cell_filter_cache
+                            .write()
+                            .insert(&filter_type, AttachmentFilterPB::from_filter_rev(filter_rev.as_ref()));
+                    }
+                    FieldType::Person => {
+                        self.cell_filter_cache
+                            .write()
+                            .insert(&filter_type, PersonFilterPB::from_filter_rev(filter_rev.as_ref()));
+                    }
                 }
             }
         }
     }
 }
 
-/// Returns None if there is no change in this row after applying the filter
+/// The duration is computed from the local date and time, so it's recomputed after each midnight
+/// in case the offset of the local time zone changes.
+fn duration_until_next_local_midnight() -> Duration {
+    let now = Local::now().naive_local();
+    match now.date().succ_opt().and_then(|tomorrow| tomorrow.and_hms_opt(0, 0, 0)) {
+        None => Duration::from_secs(24 * 60 * 60),
+        Some(midnight) => (midnight - now).to_std().unwrap_or_default() + Duration::from_secs(1),
+    }
+}
+
+/// The states that are used to filter the rows.
+struct RowFilterContext<'a> {
+    field_rev_by_field_id: &'a HashMap<FieldId, Arc<FieldRevision>>,
+    cell_data_cache: &'a AtomicCellDataCache,
+    cell_filter_cache: &'a AtomicCellFilterCache,
+    filter_tree: &'a FilterTree,
+    search_query: Option<&'a str>,
+    emptiness_by_filter_type: &'a HashMap<FilterType, bool>,
+    evaluation_count: &'a AtomicUsize,
+}
+
+/// Returns None if there is no change in this row after applying the filter. If the `field_ids`
+/// is not None, only the filters of the fields, and the filters of the fields whose cells are
+/// computed from the row, are evaluated. All the filters are evaluated for the row that was never
+/// filtered.
 #[tracing::instrument(level = "trace", skip_all)]
 fn filter_row(
     row_rev: &Arc<RowRevision>,
     result_by_row_id: &mut HashMap<RowId, FilterResult>,
-    field_rev_by_field_id: &HashMap<FieldId, Arc<FieldRevision>>,
-    cell_data_cache: &AtomicCellDataCache,
-    cell_filter_cache: &AtomicCellFilterCache,
+    context: &RowFilterContext,
+    field_ids: Option<&[String]>,
 ) -> Option<(String, bool)> {
+    let field_ids = match result_by_row_id.get(&row_rev.id) {
+        Some(filter_result) if !filter_result.is_restored => field_ids,
+        _ => None,
+    };
     // Create a filter result cache if it's not exist
     let filter_result = result_by_row_id
         .entry(row_rev.id.clone())
         .or_insert_with(FilterResult::default);
-    let old_is_visible = filter_result.is_visible();
+    let old_is_visible = filter_result.is_visible;
 
     // Iterate each cell of the row to check its visibility
-    for (field_id, field_rev) in field_rev_by_field_id {
+    for field_rev in context.field_rev_by_field_id.values() {
         let filter_type = FilterType::from(field_rev);
-        if !cell_filter_cache.read().contains(&filter_type) {
+        if !context.cell_filter_cache.read().contains(&filter_type) {
             filter_result.visible_by_filter_id.remove(&filter_type);
             continue;
         }
+        if let Some(field_ids) = field_ids {
+            let is_computed = filter_type.field_type.is_timestamp() || filter_type.field_type.is_formula();
+            if !is_computed && !field_ids.contains(&field_rev.id) {
+                continue;
+            }
+        }
 
-        let cell_rev = row_rev.cells.get(field_id);
+        let cell_rev = get_cell_rev_from_row(row_rev, field_rev);
         // if the visibility of the cell_rew is changed, which means the visibility of the
         // row is changed too.
-        if let Some(is_visible) = filter_cell(&filter_type, field_rev, cell_rev, cell_data_cache, cell_filter_cache) {
+        if let Some(is_visible) = filter_cell(&filter_type, field_rev, cell_rev.as_ref(), context) {
             filter_result.visible_by_filter_id.insert(filter_type, is_visible);
         }
     }
 
-    let is_visible = filter_result.is_visible();
+    filter_result.is_restored = false;
+    filter_result.matches_search = context
+        .search_query
+        .map(|search_query| row_matches_search(row_rev, context, search_query))
+        .unwrap_or(true);
+    let is_visible = filter_result.update_visibility(context.filter_tree);
     if old_is_visible != is_visible {
         Some((row_rev.id.clone(), is_visible))
     } else {
@@ -344,6 +671,51 @@ fn filter_row(
     }
 }
 
+/// Matches the row against the search query and combines it with the cached results of the
+/// filters. Returns None if the visibility of the row is not changed.
+fn search_row(
+    row_rev: &Arc<RowRevision>,
+    result_by_row_id: &mut HashMap<RowId, FilterResult>,
+    context: &RowFilterContext,
+) -> Option<(String, bool)> {
+    let filter_result = result_by_row_id
+        .entry(row_rev.id.clone())
+        .or_insert_with(FilterResult::default);
+    let old_is_visible = filter_result.is_visible;
+    filter_result.matches_search = context
+        .search_query
+        .map(|search_query| row_matches_search(row_rev, context, search_query))
+        .unwrap_or(true);
+    let is_visible = filter_result.update_visibility(context.filter_tree);
+    if old_is_visible != is_visible {
+        Some((row_rev.id.clone(), is_visible))
+    } else {
+        None
+    }
+}
+
+/// Returns true if the display text of any cell of the row contains the lowercase query.
+fn row_matches_search(row_rev: &RowRevision, context: &RowFilterContext, search_query: &str) -> bool {
+    context.field_rev_by_field_id.values().any(|field_rev| {
+        let type_cell_data = match get_cell_rev_from_row(row_rev, field_rev)
+            .and_then(|cell_rev| TypeCellData::try_from(&cell_rev).ok())
+        {
+            None => return false,
+            Some(type_cell_data) => type_cell_data,
+        };
+        let field_type: FieldType = field_rev.ty.into();
+        TypeOptionCellExt::new_with_cell_data_cache(field_rev.as_ref(), Some(context.cell_data_cache.clone()))
+            .get_type_option_cell_data_handler(&field_type)
+            .map(|handler| {
+                handler
+                    .stringify_cell_str(type_cell_data.cell_str, &type_cell_data.field_type, field_rev.as_ref())
+                    .to_lowercase()
+                    .contains(search_query)
+            })
+            .unwrap_or(false)
+    })
+}
+
 // Returns None if there is no change in this cell after applying the filter
 // Returns Some if the visibility of the cell is changed
 
@@ -352,9 +724,22 @@ fn filter_cell(
     filter_type: &FilterType,
     field_rev: &Arc<FieldRevision>,
     cell_rev: Option<&CellRevision>,
-    cell_data_cache: &AtomicCellDataCache,
-    cell_filter_cache: &AtomicCellFilterCache,
+    context: &RowFilterContext,
 ) -> Option<bool> {
+    let handler = TypeOptionCellExt::new(
+        field_rev.as_ref(),
+        Some(context.cell_data_cache.clone()),
+        Some(context.cell_filter_cache.clone()),
+    )
+    .get_type_option_cell_data_handler(&filter_type.field_type)?;
+
+    context.evaluation_count.fetch_add(1, Ordering::SeqCst);
+
+    // The emptiness of the cells is checked in the same way for all the field types.
+    if let Some(is_empty) = context.emptiness_by_filter_type.get(filter_type) {
+        return Some(handler.is_cell_empty(cell_rev, field_rev.as_ref()) == *is_empty);
+    }
+
     let type_cell_data = match cell_rev {
         None => TypeCellData::from_field_type(&filter_type.field_type),
         Some(cell_rev) => match TypeCellData::try_from(cell_rev) {
@@ -365,14 +750,6 @@ fn filter_cell(
             }
         },
     };
-
-    let handler = TypeOptionCellExt::new(
-        field_rev.as_ref(),
-        Some(cell_data_cache.clone()),
-        Some(cell_filter_cache.clone()),
-    )
-    .get_type_option_cell_data_handler(&filter_type.field_type)?;
-
     let is_visible = handler.handle_cell_filter(filter_type, field_rev.as_ref(), type_cell_data);
     Some(is_visible)
 }
@@ -381,6 +758,8 @@ fn filter_cell(
 enum FilterEvent {
     FilterDidChanged,
     RowDidChanged(String),
+    CellsDidChanged { row_id: String, field_ids: Vec<String> },
+    SearchDidChanged,
 }
 
 impl ToString for FilterEvent {
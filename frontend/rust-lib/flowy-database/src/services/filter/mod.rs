@@ -1,7 +1,11 @@
 mod controller;
 mod entities;
+mod result_cache;
 mod task;
+mod tree;
 
 pub use controller::*;
 pub use entities::*;
+pub use result_cache::*;
 pub(crate) use task::*;
+pub(crate) use tree::*;
@@ -0,0 +1,85 @@
+use crate::services::persistence::kv::{DatabaseKVPersistence, KVTransaction, KeyValue};
+use flowy_error::FlowyResult;
+use grid_model::{FieldRevision, FilterGroupRevision, FilterRevision};
+use lib_infra::util::md5;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+fn filter_results_key(view_id: &str) -> String {
+    format!("filter_results:{}", view_id)
+}
+
+/// [FilterResultCache] is the visibility of the rows of the view after applying the filters, it's
+/// saved when the view is closed, so the rows can be shown without running the filters when the
+/// view is opened again.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilterResultCache {
+    /// The hash of the filters, the filter tree and the filtered fields of the view.
+    pub filter_hash: String,
+    /// The revision id of each block when the cache was saved.
+    pub block_rev_ids: HashMap<String, i64>,
+    pub visible_by_row_id: HashMap<String, bool>,
+}
+
+impl FilterResultCache {
+    /// Returns true if the cache was saved with the same filters and the blocks were not changed
+    /// since then.
+    pub fn is_valid(&self, filter_hash: &str, block_rev_ids: &HashMap<String, i64>) -> bool {
+        self.filter_hash == filter_hash && &self.block_rev_ids == block_rev_ids
+    }
+}
+
+/// Returns the hash of the filter configuration of the view. Changing any filter, the way the
+/// filters are combined, or the type option of a filtered field changes the hash.
+pub fn filter_config_hash(
+    filter_revs: &[Arc<FilterRevision>],
+    filter_tree: &FilterGroupRevision,
+    field_revs: &[Arc<FieldRevision>],
+) -> String {
+    let mut filter_revs = filter_revs
+        .iter()
+        .map(|filter_rev| filter_rev.as_ref())
+        .collect::<Vec<_>>();
+    filter_revs.sort_by(|a, b| a.id.cmp(&b.id));
+    let mut field_revs = field_revs
+        .iter()
+        .filter(|field_rev| filter_revs.iter().any(|filter_rev| filter_rev.field_id == field_rev.id))
+        .map(|field_rev| field_rev.as_ref())
+        .collect::<Vec<_>>();
+    field_revs.sort_by(|a, b| a.id.cmp(&b.id));
+    let config = serde_json::json!({
+        "filters": filter_revs,
+        "filter_tree": filter_tree,
+        "fields": field_revs,
+    });
+    md5(config.to_string())
+}
+
+/// [FilterResultStore] persists the [FilterResultCache] of each view.
+pub struct FilterResultStore {
+    kv_persistence: Arc<DatabaseKVPersistence>,
+}
+
+impl FilterResultStore {
+    pub fn new(kv_persistence: Arc<DatabaseKVPersistence>) -> Self {
+        Self { kv_persistence }
+    }
+
+    pub fn get_cache(&self, view_id: &str) -> FlowyResult<Option<FilterResultCache>> {
+        match self.kv_persistence.get_bytes(&filter_results_key(view_id))? {
+            None => Ok(None),
+            Some(bytes) => Ok(serde_json::from_slice(&bytes)?),
+        }
+    }
+
+    pub fn save_cache(&self, view_id: &str, cache: &FilterResultCache) -> FlowyResult<()> {
+        let bytes = serde_json::to_vec(cache)?;
+        self.kv_persistence
+            .set(KeyValue::new(filter_results_key(view_id), bytes))
+    }
+
+    pub fn delete_cache(&self, view_id: &str) -> FlowyResult<()> {
+        self.kv_persistence.remove(&filter_results_key(view_id))
+    }
+}
@@ -1,4 +1,4 @@
-use crate::services::filter::{FilterController, FilterType};
+use crate::services::filter::{FilterController, FilterTree, FilterType};
 use flowy_task::{TaskContent, TaskHandler};
 use lib_infra::future::BoxResultFuture;
 use std::collections::HashMap;
@@ -44,20 +44,48 @@ impl TaskHandler for FilterTaskHandler {
     }
 }
 /// Refresh the filter according to the field id.
-#[derive(Default)]
 pub(crate) struct FilterResult {
     pub(crate) visible_by_filter_id: HashMap<FilterType, bool>,
+    /// Whether the row matches the search query of the view. It's true if there is no query.
+    pub(crate) matches_search: bool,
+    /// The visibility of the row that was combined by the [FilterTree] last time.
+    pub(crate) is_visible: bool,
+    /// Whether the visibility is restored from the [FilterResultCache](crate::services::filter::FilterResultCache).
+    /// The results of the filters are unknown until the row is filtered again.
+    pub(crate) is_restored: bool,
+}
+
+impl std::default::Default for FilterResult {
+    fn default() -> Self {
+        Self {
+            visible_by_filter_id: HashMap::new(),
+            matches_search: true,
+            is_visible: true,
+            is_restored: false,
+        }
+    }
 }
 
 impl FilterResult {
-    pub(crate) fn is_visible(&self) -> bool {
-        let mut is_visible = true;
-        for visible in self.visible_by_filter_id.values() {
-            if !is_visible {
-                break;
-            }
-            is_visible = *visible;
+    pub(crate) fn restored(is_visible: bool) -> Self {
+        Self {
+            is_visible,
+            is_restored: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the visibility of the row after applying the filters, regardless of the search query.
+    pub(crate) fn is_visible_by_filters(&self, filter_tree: &FilterTree) -> bool {
+        if self.is_restored {
+            self.is_visible
+        } else {
+            filter_tree.is_visible(&self.visible_by_filter_id)
         }
-        is_visible
+    }
+
+    pub(crate) fn update_visibility(&mut self, filter_tree: &FilterTree) -> bool {
+        self.is_visible = self.matches_search && filter_tree.is_visible(&self.visible_by_filter_id);
+        self.is_visible
     }
 }
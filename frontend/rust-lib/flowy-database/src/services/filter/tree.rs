@@ -0,0 +1,150 @@
+use crate::services::filter::FilterType;
+use grid_model::{FilterGroupRevision, FilterNodeRevision, FilterOperatorRevision};
+use std::collections::{HashMap, HashSet};
+
+/// [FilterTree] combines the visibilities of a row that are computed by each filter with the
+/// groups of the view.
+#[derive(Debug, Default)]
+pub(crate) struct FilterTree {
+    root: FilterGroupRevision,
+    filter_type_by_filter_id: HashMap<String, FilterType>,
+}
+
+impl FilterTree {
+    pub(crate) fn root(&self) -> &FilterGroupRevision {
+        &self.root
+    }
+
+    pub(crate) fn set_root(&mut self, root: FilterGroupRevision) {
+        self.root = root;
+    }
+
+    pub(crate) fn insert_filter(&mut self, filter_id: &str, filter_type: FilterType) {
+        self.filter_type_by_filter_id.insert(filter_id.to_owned(), filter_type);
+    }
+
+    pub(crate) fn remove_filter(&mut self, filter_id: &str) {
+        self.filter_type_by_filter_id.remove(filter_id);
+        self.root.remove_filter(filter_id);
+    }
+
+    /// Returns the visibility of the row. The filters that were not computed for the row are
+    /// skipped, and so are the groups whose filters were all skipped. The filters that are not in
+    /// any group are combined with the root group by the `And` operator.
+    pub(crate) fn is_visible(&self, visible_by_filter_type: &HashMap<FilterType, bool>) -> bool {
+        let mut grouped_filter_types = HashSet::new();
+        let is_visible = self
+            .is_group_visible(&self.root, visible_by_filter_type, &mut grouped_filter_types)
+            .unwrap_or(true);
+        is_visible
+            && visible_by_filter_type
+                .iter()
+                .filter(|(filter_type, _)| !grouped_filter_types.contains(filter_type))
+                .all(|(_, is_visible)| *is_visible)
+    }
+
+    fn is_group_visible<'a>(
+        &'a self,
+        group: &'a FilterGroupRevision,
+        visible_by_filter_type: &HashMap<FilterType, bool>,
+        grouped_filter_types: &mut HashSet<&'a FilterType>,
+    ) -> Option<bool> {
+        let mut visibilities = vec![];
+        for child in group.children.iter() {
+            let is_visible = match child {
+                FilterNodeRevision::Filter(filter_id) => {
+                    self.filter_type_by_filter_id.get(filter_id).and_then(|filter_type| {
+                        grouped_filter_types.insert(filter_type);
+                        visible_by_filter_type.get(filter_type).copied()
+                    })
+                }
+                FilterNodeRevision::Group(group) => {
+                    self.is_group_visible(group, visible_by_filter_type, grouped_filter_types)
+                }
+            };
+            visibilities.extend(is_visible);
+        }
+
+        if visibilities.is_empty() {
+            return None;
+        }
+        match group.operator {
+            FilterOperatorRevision::And => Some(visibilities.into_iter().all(|is_visible| is_visible)),
+            FilterOperatorRevision::Or => Some(visibilities.into_iter().any(|is_visible| is_visible)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entities::FieldType;
+    use crate::services::filter::{FilterTree, FilterType};
+    use grid_model::{FilterGroupRevision, FilterNodeRevision, FilterOperatorRevision};
+    use std::collections::HashMap;
+
+    fn filter_type(field_id: &str) -> FilterType {
+        FilterType {
+            field_id: field_id.to_owned(),
+            field_type: FieldType::RichText,
+        }
+    }
+
+    fn make_tree(operator: FilterOperatorRevision, filter_ids: &[&str]) -> FilterTree {
+        let mut tree = FilterTree::default();
+        let mut root = FilterGroupRevision::with_filters("root", vec![]);
+        root.operator = operator;
+        for filter_id in filter_ids {
+            root.push_filter(filter_id);
+            tree.insert_filter(filter_id, filter_type(filter_id));
+        }
+        tree.set_root(root);
+        tree
+    }
+
+    #[test]
+    fn filter_tree_or_group_test() {
+        let tree = make_tree(FilterOperatorRevision::Or, &["a", "b"]);
+        let mut visible_by_filter_type = HashMap::new();
+        visible_by_filter_type.insert(filter_type("a"), false);
+        visible_by_filter_type.insert(filter_type("b"), true);
+        assert!(tree.is_visible(&visible_by_filter_type));
+
+        visible_by_filter_type.insert(filter_type("b"), false);
+        assert!(!tree.is_visible(&visible_by_filter_type));
+    }
+
+    #[test]
+    fn filter_tree_nested_group_test() {
+        let mut tree = make_tree(FilterOperatorRevision::And, &["c"]);
+        tree.insert_filter("a", filter_type("a"));
+        tree.insert_filter("b", filter_type("b"));
+        let mut root = tree.root().clone();
+        let mut nested = FilterGroupRevision::with_filters("nested", vec!["a".to_owned(), "b".to_owned()]);
+        nested.operator = FilterOperatorRevision::Or;
+        root.children.push(FilterNodeRevision::Group(nested));
+        tree.set_root(root);
+
+        let mut visible_by_filter_type = HashMap::new();
+        visible_by_filter_type.insert(filter_type("a"), true);
+        visible_by_filter_type.insert(filter_type("b"), false);
+        visible_by_filter_type.insert(filter_type("c"), true);
+        assert!(tree.is_visible(&visible_by_filter_type));
+
+        visible_by_filter_type.insert(filter_type("c"), false);
+        assert!(!tree.is_visible(&visible_by_filter_type));
+    }
+
+    #[test]
+    fn filter_tree_ungrouped_filter_test() {
+        let tree = make_tree(FilterOperatorRevision::Or, &["a"]);
+        let mut visible_by_filter_type = HashMap::new();
+        visible_by_filter_type.insert(filter_type("a"), true);
+        visible_by_filter_type.insert(filter_type("b"), false);
+        assert!(!tree.is_visible(&visible_by_filter_type));
+
+        // The filters that were not computed are skipped.
+        let tree = make_tree(FilterOperatorRevision::And, &["a", "b"]);
+        visible_by_filter_type.remove(&filter_type("b"));
+        assert!(tree.is_visible(&visible_by_filter_type));
+    }
+}
@@ -2,49 +2,90 @@ use crate::entities::CellPathParams;
 use crate::entities::*;
 use crate::manager::DatabaseUser;
 use crate::notification::{send_notification, DatabaseNotification};
-use crate::services::block_manager::DatabaseBlockManager;
+use crate::services::block_manager::{DatabaseBlockEvent, DatabaseBlockManager, MAX_ROWS_PER_BLOCK};
 use crate::services::cell::{
-    apply_cell_data_changeset, get_type_cell_protobuf, stringify_cell_data, AnyTypeCache, AtomicCellDataCache,
-    CellProtobufBlob, ToCellChangesetString, TypeCellData,
+    apply_cell_data_changeset, get_cell_rev_from_row, get_type_cell_protobuf, get_typed_cell_data, stringify_cell_data,
+    AnyTypeCache, AtomicCellDataCache, CellDataDecoder, CellProtobufBlob, FromCellString, ToCellChangesetString,
+    TypeCellData,
 };
 use crate::services::field::{
-    default_type_option_builder_from_type, transform_type_option, type_option_builder_from_bytes, FieldBuilder,
-    RowSingleCellData,
+    attachments_of_cell, attachments_of_row, check_formula_circular_reference, check_formula_fields_circular_reference,
+    default_type_option_builder_from_type, fold_field_changes, gen_attachment_id, make_field_change_pb,
+    option_names_of_cell, referenced_attachments, select_type_option_from_field_rev, set_field_default_value,
+    support_cell_conversion, transform_type_option, type_option_builder_from_bytes, validation_error_with_row_id,
+    AttachmentCellChangeset, AttachmentPB, AttachmentStoreRef, ChecklistCellChangeset, ChecklistItem, FieldBuilder,
+    FieldTemplateStore, FormulaEvaluator, FormulaTypeOptionPB, MemberPB, MemberResolver, MultiSelectTypeOptionPB,
+    PersonCellChangeset, PersonCellData, PersonTypeOptionPB, RelatedRowPB, RelatedRowResolver, RelationCellData,
+    RelationCellDataPB, RelationTypeOptionPB, RichTextTypeOptionPB, RowSingleCellData, SelectOptionCellChangeset,
+    SelectTypeOptionSharedAction, SingleSelectTypeOptionPB, TypeOptionCellExt, TypedCellData,
 };
 
-use crate::services::filter::FilterType;
+use crate::services::convert_field::{convert_field_handler_id, ConvertCellsTask, ConvertFieldTaskHandler};
+use crate::services::duplicate_field::{duplicate_field_handler_id, CopyCellsTask, DuplicateFieldTaskHandler};
+use crate::services::filter::{FilterResultStore, FilterType};
 use crate::services::grid_editor_trait_impl::GridViewEditorDelegateImpl;
 use crate::services::persistence::block_index::BlockIndexCache;
-use crate::services::row::{DatabaseBlockRow, DatabaseBlockRowRevision, RowRevisionBuilder};
+use crate::services::persistence::rev_sqlite::RevisionAuthor;
+use crate::services::persistence::rev_store::{GridRevisionObject, GridRevisionStores, TimestampedRevision};
+use crate::services::repair::{repair_handler_id, DatabaseRepairTaskHandler};
+use crate::services::revision_diff::{make_block_diff, make_database_state, make_field_diffs};
+use crate::services::revision_preview::{make_revision_preview, RevisionPreview};
+use crate::services::revision_stats::{read_database_revision_stats, DatabaseRevisionStats};
+use crate::services::row::{
+    make_row_change_pb, DatabaseBlockRowRevision, RowRevisionBuilder, RowTemplate, RowTemplateStore,
+};
+use crate::services::snapshot::{pre_rollback_checkpoint_name, DatabaseCheckpointMeta, DatabaseSnapshotMeta};
+use crate::services::trash::{purge_trash_handler_id, trash_expired_before, PurgeTrashTaskHandler};
+use crate::services::undo::{DatabaseEditAction, DatabaseEditStep, DatabaseUndoStack};
 use crate::services::view_editor::{DatabaseViewManager, GridViewChanged};
 use bytes::Bytes;
 use flowy_client_sync::client_database::{DatabaseRevisionChangeset, DatabaseRevisionPad, JsonDeserializer};
 use flowy_client_sync::errors::{SyncError, SyncResult};
-use flowy_error::{FlowyError, FlowyResult};
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 use flowy_revision::{
     RevisionCloudService, RevisionManager, RevisionMergeable, RevisionObjectDeserializer, RevisionObjectSerializer,
+    RevisionSnapshot,
 };
 use flowy_sqlite::ConnectionPool;
-use flowy_task::TaskDispatcher;
+use flowy_task::{Task, TaskContent, TaskDispatcher};
 use grid_model::*;
 use lib_infra::future::{to_fut, FutureResult};
+use lib_infra::util::timestamp;
 use lib_ot::core::EmptyAttributes;
-use revision_model::Revision;
-use std::collections::HashMap;
+use revision_model::{Revision, RevisionRange};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use flowy_client_sync::make_operations_from_revisions;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::{broadcast, RwLock};
 
 pub struct DatabaseRevisionEditor {
     pub database_id: String,
-    #[allow(dead_code)]
     user: Arc<dyn DatabaseUser>,
+    rev_stores: GridRevisionStores,
     database_pad: Arc<RwLock<DatabaseRevisionPad>>,
     view_manager: Arc<DatabaseViewManager>,
     rev_manager: Arc<RevisionManager<Arc<ConnectionPool>>>,
     block_manager: Arc<DatabaseBlockManager>,
     cell_data_cache: AtomicCellDataCache,
+    last_modified: Arc<parking_lot::RwLock<Instant>>,
+    last_accessed: parking_lot::RwLock<Instant>,
+    undo_stack: parking_lot::Mutex<DatabaseUndoStack>,
+    // Makes sure the steps are undone or redone one by one.
+    undo_lock: tokio::sync::Mutex<()>,
+    task_scheduler: Arc<RwLock<TaskDispatcher>>,
+    is_repairing: Arc<AtomicBool>,
+    // The number of the blocks whose cells are not yet copied to the duplicated fields.
+    copying_block_count: Arc<AtomicUsize>,
+    // The number of the blocks whose cells are not yet converted to the switched field types.
+    converting_block_count: Arc<AtomicUsize>,
+    // Includes the old and new values of the updated cells in the notifications.
+    notify_cell_values: AtomicBool,
+    attachment_store: AttachmentStoreRef,
+    field_templates: Arc<FieldTemplateStore>,
+    row_templates: Arc<RowTemplateStore>,
 }
 
 impl Drop for DatabaseRevisionEditor {
@@ -54,29 +95,57 @@ impl Drop for DatabaseRevisionEditor {
 }
 
 impl DatabaseRevisionEditor {
+    #[allow(clippy::too_many_arguments)]
     pub async fn new(
         database_id: &str,
         user: Arc<dyn DatabaseUser>,
         mut rev_manager: RevisionManager<Arc<ConnectionPool>>,
         persistence: Arc<BlockIndexCache>,
         task_scheduler: Arc<RwLock<TaskDispatcher>>,
+        rev_stores: GridRevisionStores,
+        attachment_store: AttachmentStoreRef,
+        field_templates: Arc<FieldTemplateStore>,
+        row_templates: Arc<RowTemplateStore>,
+        filter_results: Arc<FilterResultStore>,
+        undo_depth: usize,
+        notify_cell_values: bool,
+        open_from_snapshot: bool,
     ) -> FlowyResult<Arc<Self>> {
         let token = user.token()?;
         let cloud = Arc::new(GridRevisionCloudService { token });
-        let database_pad = rev_manager.initialize::<GridRevisionSerde>(Some(cloud)).await?;
+        // The database that is opened from its latest snapshot is marked as repairing until all
+        // of its revisions are replayed in the background.
+        let snapshot_pad = match open_from_snapshot {
+            false => None,
+            true => match rev_manager.initialize_from_snapshot::<GridRevisionSerde>().await {
+                Ok(database_pad) => database_pad,
+                Err(e) => {
+                    tracing::error!("Open database:{} from snapshot failed: {:?}", database_id, e);
+                    None
+                }
+            },
+        };
+        let is_repairing = Arc::new(AtomicBool::new(snapshot_pad.is_some()));
+        let database_pad = match snapshot_pad {
+            Some(database_pad) => database_pad,
+            None => rev_manager.initialize::<GridRevisionSerde>(Some(cloud)).await?,
+        };
         let rev_manager = Arc::new(rev_manager);
         let database_pad = Arc::new(RwLock::new(database_pad));
         let cell_data_cache = AnyTypeCache::<u64>::new();
 
         // Block manager
         let (block_event_tx, block_event_rx) = broadcast::channel(100);
+        let last_modified = Arc::new(parking_lot::RwLock::new(Instant::now()));
+        listen_on_database_block_event(block_event_tx.subscribe(), last_modified.clone());
         let block_meta_revs = database_pad.read().await.get_block_meta_revs();
-        let block_manager =
-            Arc::new(DatabaseBlockManager::new(&user, block_meta_revs, persistence, block_event_tx).await?);
+        let block_manager = Arc::new(
+            DatabaseBlockManager::new(rev_stores.clone(), block_meta_revs, persistence, block_event_tx).await?,
+        );
         let delegate = Arc::new(GridViewEditorDelegateImpl {
             pad: database_pad.clone(),
             block_manager: block_manager.clone(),
-            task_scheduler,
+            task_scheduler: task_scheduler.clone(),
             cell_data_cache: cell_data_cache.clone(),
         });
 
@@ -84,10 +153,11 @@ impl DatabaseRevisionEditor {
         let view_manager = Arc::new(
             DatabaseViewManager::new(
                 database_id.to_owned(),
-                user.clone(),
+                rev_stores.clone(),
                 delegate,
                 cell_data_cache.clone(),
                 block_event_rx,
+                filter_results,
             )
             .await?,
         );
@@ -95,22 +165,221 @@ impl DatabaseRevisionEditor {
         let editor = Arc::new(Self {
             database_id: database_id.to_owned(),
             user,
+            rev_stores,
             database_pad,
             rev_manager,
             block_manager,
             view_manager,
             cell_data_cache,
+            last_modified,
+            last_accessed: parking_lot::RwLock::new(Instant::now()),
+            undo_stack: parking_lot::Mutex::new(DatabaseUndoStack::new(undo_depth)),
+            undo_lock: tokio::sync::Mutex::new(()),
+            task_scheduler,
+            is_repairing,
+            copying_block_count: Arc::new(AtomicUsize::new(0)),
+            converting_block_count: Arc::new(AtomicUsize::new(0)),
+            notify_cell_values: AtomicBool::new(notify_cell_values),
+            attachment_store,
+            field_templates,
+            row_templates,
         });
 
+        let handler = DuplicateFieldTaskHandler::new(
+            database_id,
+            Arc::downgrade(&editor.database_pad),
+            Arc::downgrade(&editor.block_manager),
+            editor.copying_block_count.clone(),
+        );
+        editor.task_scheduler.write().await.register_handler(handler);
+
+        let handler = ConvertFieldTaskHandler::new(
+            database_id,
+            Arc::downgrade(&editor.database_pad),
+            Arc::downgrade(&editor.block_manager),
+            editor.converting_block_count.clone(),
+        );
+        editor.task_scheduler.write().await.register_handler(handler);
+
+        let handler = PurgeTrashTaskHandler::new(database_id, Arc::downgrade(&editor));
+        let mut task_scheduler = editor.task_scheduler.write().await;
+        task_scheduler.register_handler(handler);
+        let expired_before = trash_expired_before();
+        if editor
+            .database_pad
+            .read()
+            .await
+            .get_trashed_rows()
+            .iter()
+            .any(|trashed_row| trashed_row.deleted_at <= expired_before)
+        {
+            let task_id = task_scheduler.next_task_id();
+            let task = Task::background(
+                &purge_trash_handler_id(database_id),
+                task_id,
+                TaskContent::Text(database_id.to_owned()),
+            );
+            task_scheduler.add_task(task);
+        }
+        drop(task_scheduler);
+
+        if editor.is_repairing() {
+            let handler = DatabaseRepairTaskHandler::new(
+                database_id,
+                Arc::downgrade(&editor.database_pad),
+                Arc::downgrade(&editor.rev_manager),
+                editor.is_repairing.clone(),
+            );
+            let mut task_scheduler = editor.task_scheduler.write().await;
+            task_scheduler.register_handler(handler);
+            let task_id = task_scheduler.next_task_id();
+            let task = Task::background(
+                &repair_handler_id(database_id),
+                task_id,
+                TaskContent::Text(database_id.to_owned()),
+            );
+            task_scheduler.add_task(task);
+        }
         Ok(editor)
     }
 
+    /// Returns true if the database was opened from its latest snapshot because it has too many
+    /// revisions, and the revisions are still being replayed in the background.
+    pub fn is_repairing(&self) -> bool {
+        self.is_repairing.load(Ordering::SeqCst)
+    }
+
+    /// Returns true if the cells of the duplicated fields are still being copied in the background.
+    pub fn is_copying_cells(&self) -> bool {
+        self.copying_block_count.load(Ordering::SeqCst) > 0
+    }
+
+    /// Returns true if the cells of the switched fields are still being converted in the background.
+    pub fn is_converting_cells(&self) -> bool {
+        self.converting_block_count.load(Ordering::SeqCst) > 0
+    }
+
+    /// Includes the old and new values of the updated cells in the notifications of the rows and
+    /// the cells, or excludes them.
+    pub fn set_notify_cell_values(&self, enabled: bool) {
+        self.notify_cell_values.store(enabled, Ordering::SeqCst);
+    }
+
+    /// Subscribes the changes of the rows of the database's blocks.
+    pub fn subscribe_block_event(&self) -> broadcast::Receiver<DatabaseBlockEvent> {
+        self.block_manager.subscribe()
+    }
+
+    /// Returns the time when the last revision was applied to the database or one of its blocks.
+    pub fn last_modified(&self) -> Instant {
+        *self.last_modified.read()
+    }
+
+    /// Marks the editor as accessed. The [DatabaseManager] calls it whenever the editor is
+    /// handed out, so the editor won't be treated as idle while it's in use.
+    pub(crate) fn did_access(&self) {
+        *self.last_accessed.write() = Instant::now();
+    }
+
+    /// Returns how long the editor has been neither accessed nor modified.
+    pub fn idle_duration(&self) -> Duration {
+        let last_active = std::cmp::max(*self.last_accessed.read(), self.last_modified());
+        last_active.elapsed()
+    }
+
     #[tracing::instrument(name = "close grid editor", level = "trace", skip_all)]
     pub async fn close(&self) {
         self.block_manager.close().await;
         self.rev_manager.generate_snapshot().await;
         self.rev_manager.close().await;
         self.view_manager.close(&self.database_id).await;
+
+        // The tasks that are still queued would run against the closed editor, cancel them before
+        // their handlers are gone.
+        let mut task_scheduler = self.task_scheduler.write().await;
+        for handler_id in [
+            repair_handler_id(&self.database_id),
+            duplicate_field_handler_id(&self.database_id),
+            convert_field_handler_id(&self.database_id),
+            purge_trash_handler_id(&self.database_id),
+        ] {
+            task_scheduler.cancel_tasks_of_handler(&handler_id);
+            task_scheduler.unregister_handler(handler_id).await;
+        }
+    }
+
+    /// Writes the pending revisions of the database, its blocks and its views to disk.
+    pub async fn flush(&self) -> FlowyResult<()> {
+        self.block_manager.flush().await?;
+        self.rev_manager.flush().await?;
+        self.view_manager.flush().await?;
+        Ok(())
+    }
+
+    /// Returns the stats of the revisions and the snapshots of the database that are stored on
+    /// disk. The pending revisions are written to disk first, so they are counted.
+    pub async fn revision_stats(&self) -> FlowyResult<DatabaseRevisionStats> {
+        self.flush().await?;
+        let block_ids = self
+            .database_pad
+            .read()
+            .await
+            .get_block_meta_revs()
+            .iter()
+            .map(|block_meta_rev| block_meta_rev.block_id.clone())
+            .collect::<Vec<String>>();
+        read_database_revision_stats(&self.rev_stores, &self.database_id, &block_ids)
+    }
+
+    /// Reverts the last step of the edits. The reverted step can be redone by calling `redo`.
+    /// Returns false if there is nothing to undo.
+    ///
+    /// Undoing generates the revisions and sends the notifications just like a normal edit. If
+    /// undoing fails, the applied actions of the step are reverted and the step is kept.
+    pub async fn undo(&self) -> FlowyResult<bool> {
+        let _guard = self.undo_lock.lock().await;
+        let step = match self.undo_stack.lock().pop_undo() {
+            None => return Ok(false),
+            Some(step) => step,
+        };
+        match self.apply_edit_step(step.clone()).await {
+            Ok(redo_step) => {
+                self.undo_stack.lock().push_redo(redo_step);
+                Ok(true)
+            }
+            Err(err) => {
+                self.undo_stack.lock().push_undo(step);
+                Err(err)
+            }
+        }
+    }
+
+    /// Applies the last undone step again. Returns false if there is nothing to redo. The redo
+    /// steps are cleared once a new edit is made.
+    pub async fn redo(&self) -> FlowyResult<bool> {
+        let _guard = self.undo_lock.lock().await;
+        let step = match self.undo_stack.lock().pop_redo() {
+            None => return Ok(false),
+            Some(step) => step,
+        };
+        match self.apply_edit_step(step.clone()).await {
+            Ok(undo_step) => {
+                self.undo_stack.lock().push_undo(undo_step);
+                Ok(true)
+            }
+            Err(err) => {
+                self.undo_stack.lock().push_redo(step);
+                Err(err)
+            }
+        }
+    }
+
+    pub fn number_of_undo_steps(&self) -> usize {
+        self.undo_stack.lock().number_of_undo_steps()
+    }
+
+    pub fn number_of_redo_steps(&self) -> usize {
+        self.undo_stack.lock().number_of_redo_steps()
     }
 
     /// Save the type-option data to disk and send a `DatabaseNotification::DidUpdateField` notification
@@ -137,6 +406,14 @@ impl DatabaseRevisionEditor {
             return Ok(());
         }
         let field_rev = result.unwrap();
+        check_field_unlocked(&field_rev)?;
+        let field_type: FieldType = field_rev.ty.into();
+        if field_type.is_formula() {
+            let type_option = FormulaTypeOptionPB::from_protobuf_bytes(Bytes::from(type_option_data.clone()));
+            let field_revs = self.get_field_revs(None).await?;
+            check_formula_circular_reference(field_id, &type_option.expression, &field_revs)?;
+        }
+
         self.modify(|grid| {
             let changeset = grid.modify_field(field_id, |field| {
                 let deserializer = TypeOptionJsonDeserializer(field_rev.ty.into());
@@ -159,6 +436,10 @@ impl DatabaseRevisionEditor {
             .did_update_view_field_type_option(field_id, old_field_rev)
             .await?;
         self.notify_did_update_grid_field(field_id).await?;
+        self.record_update_field(field_rev).await;
+        if field_type.is_formula() {
+            self.recompute_formulas(None, None).await?;
+        }
         Ok(())
     }
 
@@ -170,9 +451,11 @@ impl DatabaseRevisionEditor {
 
     pub async fn create_new_field_rev(&self, field_rev: FieldRevision) -> FlowyResult<()> {
         let field_id = field_rev.id.clone();
-        self.modify(|grid| Ok(grid.create_field_rev(field_rev, None)?)).await?;
-        self.notify_did_insert_grid_field(&field_id).await?;
-
+        self.check_formula_fields_with(&field_rev).await?;
+        if self.insert_field_rev(field_rev, None).await? {
+            self.record_edit(vec![DatabaseEditAction::DeleteField { field_id }]);
+        }
+        self.recompute_formulas(None, None).await?;
         Ok(())
     }
 
@@ -186,10 +469,32 @@ impl DatabaseRevisionEditor {
             let type_option_builder = type_option_builder_from_bytes(type_option_data, field_type);
             field_rev.insert_type_option(type_option_builder.serializer());
         }
-        self.modify(|grid| Ok(grid.create_field_rev(field_rev.clone(), None)?))
-            .await?;
-        self.notify_did_insert_grid_field(&field_rev.id).await?;
+        self.check_formula_fields_with(&field_rev).await?;
+        if self.insert_field_rev(field_rev.clone(), None).await? {
+            let field_id = field_rev.id.clone();
+            self.record_edit(vec![DatabaseEditAction::DeleteField { field_id }]);
+        }
+        self.recompute_formulas(None, None).await?;
+        Ok(field_rev)
+    }
 
+    /// Creates a new field from the saved [FieldTemplate](crate::services::field::FieldTemplate)
+    /// with the name. The select options of the new field are assigned new ids.
+    pub async fn create_field_from_template(&self, template_name: &str) -> FlowyResult<FieldRevision> {
+        let template = self.field_templates.get_template(template_name)?.ok_or_else(|| {
+            FlowyError::record_not_found().context(format!("Can't find the field template: {}", template_name))
+        })?;
+        let field_type: FieldType = template.field_type.into();
+        let mut field_rev = FieldBuilder::from_field_type(&field_type)
+            .name(&template.field_name)
+            .build();
+        field_rev.insert_type_option_str(&template.field_type, template.type_option_with_fresh_option_ids());
+        self.check_formula_fields_with(&field_rev).await?;
+        if self.insert_field_rev(field_rev.clone(), None).await? {
+            let field_id = field_rev.id.clone();
+            self.record_edit(vec![DatabaseEditAction::DeleteField { field_id }]);
+        }
+        self.recompute_formulas(None, None).await?;
         Ok(field_rev)
     }
 
@@ -199,6 +504,29 @@ impl DatabaseRevisionEditor {
 
     pub async fn update_field(&self, params: FieldChangesetParams) -> FlowyResult<()> {
         let field_id = params.field_id.clone();
+        let old_field_rev = self.get_field_rev(&field_id).await;
+        if let Some(old_field_rev) = old_field_rev.as_ref() {
+            if params
+                .field_type
+                .map_or(false, |field_type| field_type != old_field_rev.ty)
+            {
+                check_field_unlocked(old_field_rev)?;
+            }
+        }
+        // The formulas reference the fields by name, so renaming a field might change them.
+        let is_formula_changed = params.name.is_some() || params.field_type.is_some();
+        if is_formula_changed {
+            if let Some(old_field_rev) = old_field_rev.as_ref() {
+                let mut field_rev = old_field_rev.as_ref().clone();
+                if let Some(name) = params.name.as_ref() {
+                    field_rev.name = name.clone();
+                }
+                if let Some(field_type) = params.field_type {
+                    field_rev.ty = field_type;
+                }
+                self.check_formula_fields_with(&field_rev).await?;
+            }
+        }
         self.modify(|grid| {
             let changeset = grid.modify_field(&params.field_id, |field| {
                 if let Some(name) = params.name {
@@ -219,21 +547,73 @@ impl DatabaseRevisionEditor {
                 if let Some(width) = params.width {
                     field.width = width;
                 }
+                if let Some(description) = params.description {
+                    field.description = description;
+                }
+                if let Some(icon) = params.icon {
+                    field.icon = icon;
+                }
                 Ok(Some(()))
             })?;
             Ok(changeset)
         })
         .await?;
         self.notify_did_update_grid_field(&field_id).await?;
+        if let Some(old_field_rev) = old_field_rev {
+            self.record_update_field(old_field_rev).await;
+        }
+        if is_formula_changed {
+            self.recompute_formulas(None, None).await?;
+        }
         Ok(())
     }
 
+    /// Updates the description that is shown as the tooltip of the field.
+    pub async fn update_field_description(&self, field_id: &str, description: &str) -> FlowyResult<()> {
+        self.update_field(FieldChangesetParams {
+            field_id: field_id.to_owned(),
+            database_id: self.database_id.clone(),
+            description: Some(description.to_owned()),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Updates the icon of the field. The field uses the icon of its field type if the icon is
+    /// empty.
+    pub async fn update_field_icon(&self, field_id: &str, icon: &str) -> FlowyResult<()> {
+        self.update_field(FieldChangesetParams {
+            field_id: field_id.to_owned(),
+            database_id: self.database_id.clone(),
+            icon: Some(icon.to_owned()),
+            ..Default::default()
+        })
+        .await
+    }
+
     pub async fn modify_field_rev<F>(&self, field_id: &str, f: F) -> FlowyResult<()>
+    where
+        F: for<'a> FnOnce(&'a mut FieldRevision) -> FlowyResult<Option<()>>,
+    {
+        if let Some(old_field_rev) = self.modify_field_rev_without_undo(field_id, f).await? {
+            self.record_update_field(old_field_rev).await;
+        }
+        Ok(())
+    }
+
+    /// Modifies the field like [Self::modify_field_rev] but leaves recording the undo step to the
+    /// caller. Returns the field before the change if the field was changed. Returns a `FieldLocked`
+    /// error if the field is locked, for example, the select options of a locked field can't be
+    /// inserted, renamed or deleted.
+    async fn modify_field_rev_without_undo<F>(&self, field_id: &str, f: F) -> FlowyResult<Option<Arc<FieldRevision>>>
     where
         F: for<'a> FnOnce(&'a mut FieldRevision) -> FlowyResult<Option<()>>,
     {
         let mut is_changed = false;
         let old_field_rev = self.get_field_rev(field_id).await;
+        if let Some(old_field_rev) = old_field_rev.as_ref() {
+            check_field_unlocked(old_field_rev)?;
+        }
         self.modify(|grid| {
             let changeset = grid.modify_field(field_id, |field_rev| {
                 f(field_rev).map_err(|e| SyncError::internal().context(e))
@@ -243,33 +623,230 @@ impl DatabaseRevisionEditor {
         })
         .await?;
 
-        if is_changed {
-            match self
-                .view_manager
-                .did_update_view_field_type_option(field_id, old_field_rev)
-                .await
-            {
-                Ok(_) => {}
-                Err(e) => tracing::error!("View manager update field failed: {:?}", e),
-            }
-            self.notify_did_update_grid_field(field_id).await?;
+        if !is_changed {
+            return Ok(None);
+        }
+        match self
+            .view_manager
+            .did_update_view_field_type_option(field_id, old_field_rev.clone())
+            .await
+        {
+            Ok(_) => {}
+            Err(e) => tracing::error!("View manager update field failed: {:?}", e),
         }
+        self.notify_did_update_grid_field(field_id).await?;
+        Ok(old_field_rev)
+    }
+
+    /// Locks the field against the edits of its cells, its field type and its type-option, or
+    /// unlocks it. A locked field can still be read, filtered, sorted and grouped, but it must be
+    /// unlocked before it can be deleted.
+    pub async fn set_field_locked(&self, field_id: &str, locked: bool) -> FlowyResult<()> {
+        let old_field_rev = self
+            .get_field_rev(field_id)
+            .await
+            .ok_or_else(|| FlowyError::field_record_not_found().context(format!("field:{}", field_id)))?;
+        self.modify(|grid| {
+            Ok(grid.modify_field(field_id, |field| {
+                if field.locked == locked {
+                    return Ok(None);
+                }
+                field.locked = locked;
+                Ok(Some(()))
+            })?)
+        })
+        .await?;
+        self.notify_did_update_grid_field(field_id).await?;
+        self.record_update_field(old_field_rev).await;
         Ok(())
     }
 
+    /// Sets the value that the cells of the field are filled with when the rows are created, or
+    /// clears it if the `default_value` is None. The cells of the existing rows are not changed.
+    pub async fn set_field_default_value(&self, field_id: &str, default_value: Option<String>) -> FlowyResult<()> {
+        let mut field_rev = self
+            .get_field_rev(field_id)
+            .await
+            .ok_or_else(|| FlowyError::field_record_not_found().context(format!("field:{}", field_id)))?
+            .as_ref()
+            .clone();
+        check_field_unlocked(&field_rev)?;
+        set_field_default_value(&mut field_rev, default_value)?;
+        let type_option_str = field_rev
+            .get_type_option_str(field_rev.ty)
+            .unwrap_or_default()
+            .to_owned();
+        self.modify_field_rev(field_id, |field| {
+            if field.get_type_option_str(field.ty) == Some(type_option_str.as_str()) {
+                return Ok(None);
+            }
+            let field_type = field.ty;
+            field.insert_type_option_str(&field_type, type_option_str);
+            Ok(Some(()))
+        })
+        .await
+    }
+
     pub async fn delete_field(&self, field_id: &str) -> FlowyResult<()> {
-        self.modify(|grid_pad| Ok(grid_pad.delete_field_rev(field_id)?)).await?;
-        let field_order = FieldIdPB::from(field_id);
-        let notified_changeset = DatabaseFieldChangesetPB::delete(&self.database_id, vec![field_order]);
+        if let Some(field_rev) = self.get_field_rev(field_id).await {
+            check_field_unlocked(&field_rev)?;
+        }
+        let create_field_action = self.make_create_field_action(field_id).await;
+        let attachments = self
+            .block_manager
+            .get_row_revs()
+            .await?
+            .iter()
+            .flat_map(|row_rev| attachments_of_row(row_rev, Some(field_id)))
+            .collect::<Vec<AttachmentPB>>();
+        self.remove_field(field_id).await?;
+        // The cells are kept for undoing, but the comments are gone with the field.
+        let block_ids = self
+            .database_pad
+            .read()
+            .await
+            .get_block_meta_revs()
+            .iter()
+            .map(|block_meta_rev| block_meta_rev.block_id.clone())
+            .collect::<Vec<String>>();
+        for block_id in block_ids {
+            self.block_manager
+                .delete_comments(&block_id, &[field_id.to_owned()])
+                .await?;
+        }
+        if let Some(action) = create_field_action {
+            self.record_edit(vec![action]);
+        }
+        self.delete_orphaned_attachments(attachments).await;
+        Ok(())
+    }
+
+    /// Deletes the fields in one revision of the database. Their cells are removed in one revision
+    /// per block, and the filters, sorts, groups and settings that reference them are removed in
+    /// one revision per opened view.
+    ///
+    /// None of the fields is deleted if one of them is the primary field. The ids of the fields
+    /// that don't exist are ignored.
+    pub async fn delete_fields(&self, field_ids: Vec<String>) -> FlowyResult<()> {
+        let deleted_field_revs = self
+            .database_pad
+            .read()
+            .await
+            .get_fields()
+            .iter()
+            .filter(|field_rev| field_ids.contains(&field_rev.id))
+            .cloned()
+            .collect::<Vec<Arc<FieldRevision>>>();
+        if deleted_field_revs.is_empty() {
+            return Ok(());
+        }
+        for field_rev in &deleted_field_revs {
+            check_field_unlocked(field_rev)?;
+        }
+
+        let deleted_field_ids = deleted_field_revs
+            .iter()
+            .map(|field_rev| field_rev.id.clone())
+            .collect::<Vec<String>>();
+        let mut create_field_actions = vec![];
+        for field_id in &deleted_field_ids {
+            if let Some(action) = self.make_create_field_action(field_id).await {
+                create_field_actions.push(action);
+            }
+        }
+
+        // The cells are restored after the fields are inserted back when undoing.
+        let mut cell_actions = vec![];
+        let mut attachments = vec![];
+        for row_rev in self.block_manager.get_row_revs().await? {
+            for field_id in &deleted_field_ids {
+                if let Some(cell_rev) = row_rev.cells.get(field_id) {
+                    let action = DatabaseEditAction::UpdateCell {
+                        row_id: row_rev.id.clone(),
+                        field_id: field_id.clone(),
+                        cell_rev: cell_rev.clone(),
+                    };
+                    cell_actions.push((row_rev.block_id.clone(), action));
+                    attachments.extend(attachments_of_row(&row_rev, Some(field_id)));
+                }
+            }
+        }
+
+        // Remove the cells before the fields, so the fields are kept if removing the cells of a
+        // block fails. The cells that were removed are put back in that case.
+        let block_ids = self
+            .database_pad
+            .read()
+            .await
+            .get_block_meta_revs()
+            .iter()
+            .map(|block_meta_rev| block_meta_rev.block_id.clone())
+            .collect::<Vec<String>>();
+        let mut stripped_block_ids = vec![];
+        for block_id in block_ids {
+            // The cells of the block might be removed even if removing its comments fails.
+            stripped_block_ids.push(block_id.clone());
+            if let Err(err) = self.block_manager.delete_cells(&block_id, &deleted_field_ids).await {
+                self.restore_deleted_cells(&cell_actions, &stripped_block_ids).await;
+                return Err(err);
+            }
+        }
+        if let Err(err) = self
+            .modify(|grid_pad| Ok(grid_pad.delete_field_revs(&deleted_field_ids)?))
+            .await
+        {
+            self.restore_deleted_cells(&cell_actions, &stripped_block_ids).await;
+            return Err(err);
+        }
+        self.view_manager.did_delete_fields(&deleted_field_revs).await;
+
+        let deleted_fields = deleted_field_ids
+            .iter()
+            .map(|field_id| FieldIdPB::from(field_id.as_str()))
+            .collect();
+        let notified_changeset = DatabaseFieldChangesetPB::delete(&self.database_id, deleted_fields);
         self.notify_did_update_grid(notified_changeset).await?;
+
+        let mut edit_step = cell_actions
+            .into_iter()
+            .map(|(_, action)| action)
+            .collect::<DatabaseEditStep>();
+        edit_step.extend(create_field_actions);
+        self.record_edit(edit_step);
+        self.delete_orphaned_attachments(attachments).await;
         Ok(())
     }
 
+    /// Puts back the cells of the deleted fields that were removed from the blocks.
+    async fn restore_deleted_cells(&self, cell_actions: &[(String, DatabaseEditAction)], block_ids: &[String]) {
+        let restore_step = cell_actions
+            .iter()
+            .filter(|(block_id, _)| block_ids.contains(block_id))
+            .map(|(_, action)| action.clone())
+            .collect::<DatabaseEditStep>();
+        if let Err(e) = self.apply_edit_step(restore_step).await {
+            tracing::error!("Restore the cells of the deleted fields failed: {:?}", e);
+        }
+    }
+
     pub async fn group_by_field(&self, field_id: &str) -> FlowyResult<()> {
         self.view_manager.group_by_field(field_id).await?;
         Ok(())
     }
 
+    /// Changes the range of the dates in each group of the view, for example, the rows are grouped
+    /// by their months instead of the days relative to today. The view must be grouped by the
+    /// date field.
+    pub async fn update_date_group_condition(&self, params: UpdateDateGroupConditionParams) -> FlowyResult<()> {
+        self.view_manager.update_date_group_condition(params).await
+    }
+
+    /// Changes the ranges of the numbers in the groups of the view, for example, the ranges
+    /// between the breakpoints `0`, `10` and `100`. The view must be grouped by the number field.
+    pub async fn update_number_group_ranges(&self, params: UpdateNumberGroupRangesParams) -> FlowyResult<()> {
+        self.view_manager.update_number_group_ranges(params).await
+    }
+
     /// Switch the field with id to a new field type.  
     ///
     /// If the field type is not exist before, the default type-option data will be created.
@@ -295,6 +872,10 @@ impl DatabaseRevisionEditor {
                 transform_type_option(&new_type_option, new_field_type, old_type_option, old_field_type)
             };
 
+        let old_field_rev = self.get_field_rev(field_id).await;
+        if let Some(old_field_rev) = old_field_rev.as_ref() {
+            check_field_unlocked(old_field_rev)?;
+        }
         self.modify(|grid| {
             Ok(grid.switch_to_field(
                 field_id,
@@ -304,26 +885,205 @@ impl DatabaseRevisionEditor {
             )?)
         })
         .await?;
+        self.create_options_of_cells(field_id).await?;
+        self.view_manager
+            .did_update_view_field_type_option(field_id, old_field_rev.clone())
+            .await?;
 
         self.notify_did_update_grid_field(field_id).await?;
+        if let Some(old_field_rev) = old_field_rev {
+            self.record_update_field(old_field_rev).await;
+        }
+        self.schedule_convert_cells(field_id).await;
+
+        Ok(())
+    }
+
+    /// Creates the options that the cells refer to after they're converted to the select option
+    /// field, so the names of the options in the cells are kept instead of being dropped.
+    async fn create_options_of_cells(&self, field_id: &str) -> FlowyResult<()> {
+        let field_rev = match self.get_field_rev(field_id).await {
+            Some(field_rev) => field_rev,
+            None => return Ok(()),
+        };
+        let field_type: FieldType = field_rev.ty.into();
+        if !field_type.is_select_option() {
+            return Ok(());
+        }
+
+        let type_option = select_type_option_from_field_rev(&field_rev)?;
+        let mut option_names: Vec<String> = vec![];
+        for row_rev in self.block_manager.get_row_revs().await? {
+            if let Some(cell_rev) = row_rev.cells.get(field_id) {
+                for name in option_names_of_cell(cell_rev, &field_rev) {
+                    if !option_names.contains(&name) && !type_option.options().iter().any(|option| option.name == name)
+                    {
+                        option_names.push(name);
+                    }
+                }
+            }
+        }
+        if option_names.is_empty() {
+            return Ok(());
+        }
 
+        self.modify(|grid| {
+            Ok(grid.modify_field(field_id, |field_rev| {
+                let mut type_option =
+                    select_type_option_from_field_rev(field_rev).map_err(|e| SyncError::internal().context(e))?;
+                for name in &option_names {
+                    let option = type_option.create_option(name);
+                    type_option.insert_option(option);
+                }
+                field_rev.insert_type_option(&*type_option);
+                Ok(Some(()))
+            })?)
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Converts the cells of the field to its current field type block by block in the
+    /// background. The progress is sent by the
+    /// [DatabaseNotification::DidUpdateConvertFieldProgress] notification.
+    async fn schedule_convert_cells(&self, field_id: &str) {
+        let field_rev = match self.get_field_rev(field_id).await {
+            Some(field_rev) => field_rev,
+            None => return,
+        };
+        if !support_cell_conversion(&field_rev.ty.into()) {
+            return;
+        }
+
+        let block_meta_revs = self.database_pad.read().await.get_block_meta_revs();
+        let block_count = block_meta_revs.len();
+        let handler_id = convert_field_handler_id(&self.database_id);
+        let mut task_scheduler = self.task_scheduler.write().await;
+        for (block_index, block_meta_rev) in block_meta_revs.iter().enumerate() {
+            let task = ConvertCellsTask {
+                field_id: field_id.to_owned(),
+                field_type: field_rev.ty,
+                block_id: block_meta_rev.block_id.clone(),
+                block_index,
+                block_count,
+            };
+            self.converting_block_count.fetch_add(1, Ordering::SeqCst);
+            let task_id = task_scheduler.next_task_id();
+            task_scheduler.add_task(Task::background(
+                &handler_id,
+                task_id,
+                TaskContent::Text(task.to_string()),
+            ));
+        }
+    }
+
+    /// Makes the field the primary field of the database, the old primary field becomes a normal
+    /// field. Returns an error if the field can't be the primary field, for example, a checkbox
+    /// field.
+    pub async fn set_primary_field(&self, field_id: &str) -> FlowyResult<()> {
+        let field_rev = self
+            .get_field_rev(field_id)
+            .await
+            .ok_or_else(|| FlowyError::field_record_not_found().context(format!("field:{}", field_id)))?;
+        if field_rev.is_primary {
+            return Ok(());
+        }
+
+        let field_type: FieldType = field_rev.ty.into();
+        if !field_type.can_be_primary() {
+            let msg = format!("The {:?} field can't be the primary field", field_type);
+            return Err(FlowyError::invalid_data().context(msg));
+        }
+
+        let old_primary_field_rev = self
+            .database_pad
+            .read()
+            .await
+            .get_fields()
+            .iter()
+            .find(|field_rev| field_rev.is_primary)
+            .cloned();
+        self.modify(|grid| Ok(grid.set_primary_field(field_id)?)).await?;
+
+        let mut actions = vec![DatabaseEditAction::UpdateField { field_rev }];
+        let mut updated_field_ids = vec![field_id.to_owned()];
+        if let Some(old_primary_field_rev) = old_primary_field_rev {
+            updated_field_ids.push(old_primary_field_rev.id.clone());
+            actions.push(DatabaseEditAction::UpdateField {
+                field_rev: old_primary_field_rev,
+            });
+        }
+        self.notify_did_update_grid_fields(&updated_field_ids).await?;
+        self.view_manager.did_update_primary_field().await;
+        self.record_edit(actions);
         Ok(())
     }
 
+    /// Duplicates the field and its cells. The duplicated field is inserted right away, but its
+    /// cells are copied block by block in the background. The progress is sent by the
+    /// [DatabaseNotification::DidUpdateDuplicateFieldProgress] notification.
     pub async fn duplicate_field(&self, field_id: &str) -> FlowyResult<()> {
         let duplicated_field_id = gen_field_id();
-        self.modify(|grid| Ok(grid.duplicate_field_rev(field_id, &duplicated_field_id)?))
-            .await?;
+        let mut is_duplicated = false;
+        self.modify(|grid| {
+            let changeset = grid.duplicate_field_rev(field_id, &duplicated_field_id)?;
+            is_duplicated = changeset.is_some();
+            Ok(changeset)
+        })
+        .await?;
 
-        self.notify_did_insert_grid_field(&duplicated_field_id).await?;
+        if is_duplicated {
+            self.notify_did_insert_grid_field(&duplicated_field_id).await?;
+            self.record_edit(vec![DatabaseEditAction::DeleteField {
+                field_id: duplicated_field_id.clone(),
+            }]);
+            self.schedule_copy_cells(field_id, &duplicated_field_id).await;
+        }
         Ok(())
     }
 
+    async fn schedule_copy_cells(&self, field_id: &str, duplicated_field_id: &str) {
+        let block_meta_revs = self.database_pad.read().await.get_block_meta_revs();
+        let block_count = block_meta_revs.len();
+        let handler_id = duplicate_field_handler_id(&self.database_id);
+        let mut task_scheduler = self.task_scheduler.write().await;
+        for (block_index, block_meta_rev) in block_meta_revs.iter().enumerate() {
+            let task = CopyCellsTask {
+                field_id: field_id.to_owned(),
+                duplicated_field_id: duplicated_field_id.to_owned(),
+                block_id: block_meta_rev.block_id.clone(),
+                block_index,
+                block_count,
+            };
+            self.copying_block_count.fetch_add(1, Ordering::SeqCst);
+            let task_id = task_scheduler.next_task_id();
+            task_scheduler.add_task(Task::background(
+                &handler_id,
+                task_id,
+                TaskContent::Text(task.to_string()),
+            ));
+        }
+    }
+
     pub async fn get_field_rev(&self, field_id: &str) -> Option<Arc<FieldRevision>> {
         let field_rev = self.database_pad.read().await.get_field_rev(field_id)?.1.clone();
         Some(field_rev)
     }
 
+    /// Returns the changes of the field, starting from the oldest one. The field of each change is
+    /// the state of the field after the change. A deleted field can be queried as long as the
+    /// revisions that contain it are kept.
+    ///
+    /// The edits whose revisions were merged into one are reported as one change.
+    pub async fn get_field_history(&self, field_id: &str) -> FlowyResult<Vec<FieldChangePB>> {
+        let revisions = self.load_timestamped_revisions().await?;
+        let field_changes = fold_field_changes(field_id, revisions)?
+            .iter()
+            .map(make_field_change_pb)
+            .collect::<Vec<FieldChangePB>>();
+        Ok(field_changes)
+    }
+
     pub async fn get_field_revs(&self, field_ids: Option<Vec<String>>) -> FlowyResult<Vec<Arc<FieldRevision>>> {
         if field_ids.is_none() {
             let field_revs = self.database_pad.read().await.get_field_revs(None)?;
@@ -343,6 +1103,59 @@ impl DatabaseRevisionEditor {
         Ok(field_revs)
     }
 
+    /// Returns the fields in the order of the view, and their visibilities are overridden by the
+    /// view. The fields that the view doesn't override fall back to their own settings.
+    pub async fn get_view_field_revs(
+        &self,
+        view_id: &str,
+        field_ids: Option<Vec<String>>,
+    ) -> FlowyResult<Vec<Arc<FieldRevision>>> {
+        let field_revs = self.get_field_revs(field_ids).await?;
+        self.view_manager.get_view_field_revs(view_id, field_revs).await
+    }
+
+    /// Shows or hides the field in the view only. Use [Self::update_field] to change the
+    /// visibility of the field in all the views that don't override it.
+    pub async fn set_view_field_visibility(&self, view_id: &str, field_id: &str, visibility: bool) -> FlowyResult<()> {
+        self.view_manager
+            .set_field_visibility(view_id, field_id, visibility)
+            .await
+    }
+
+    /// Resizes the field in the view only. The field's own width is used by the views that don't
+    /// override it.
+    pub async fn set_view_field_width(&self, view_id: &str, field_id: &str, width: i32) -> FlowyResult<()> {
+        self.view_manager.set_field_width(view_id, field_id, width).await
+    }
+
+    /// Freezes the first `count` visible fields of the view. The count is kept when the fields
+    /// are reordered, so the fields that are moved into the first `count` positions get frozen.
+    pub async fn set_frozen_field_count(&self, view_id: &str, count: usize) -> FlowyResult<()> {
+        self.view_manager.set_frozen_field_count(view_id, count).await
+    }
+
+    /// Pins the row to the top of the view. The pinned rows are placed before the other rows of the
+    /// view in the order they were pinned, regardless of the sorts of the view.
+    /// Returns the ids of the rows of the view in the order they're displayed, and whether the
+    /// order comes from the sorts, the manual order of the view, or the blocks.
+    pub async fn get_effective_row_order(&self, view_id: &str) -> FlowyResult<EffectiveRowOrderPB> {
+        self.view_manager.get_effective_row_order(view_id).await
+    }
+
+    pub async fn pin_row(&self, view_id: &str, row_id: &str) -> FlowyResult<()> {
+        self.view_manager.pin_row(view_id, row_id).await
+    }
+
+    pub async fn unpin_row(&self, view_id: &str, row_id: &str) -> FlowyResult<()> {
+        self.view_manager.unpin_row(view_id, row_id).await
+    }
+
+    /// Reorders the fields in the view only. Use [Self::reorder_fields] to change the order of the
+    /// fields in all the views that don't override it.
+    pub async fn set_view_field_order(&self, view_id: &str, field_ids: Vec<String>) -> FlowyResult<()> {
+        self.view_manager.set_view_field_order(view_id, field_ids).await
+    }
+
     pub async fn create_block(&self, block_meta_rev: GridBlockMetaRevision) -> FlowyResult<()> {
         self.modify(|grid_pad| Ok(grid_pad.create_block_meta_rev(block_meta_rev)?))
             .await?;
@@ -361,8 +1174,16 @@ impl DatabaseRevisionEditor {
         self.view_manager.will_create_row(&mut row_rev, &params).await;
 
         let row_pb = self.create_row_pb(row_rev, params.start_row_id.clone()).await?;
+        self.recompute_formulas(Some(vec![row_pb.id.clone()]), None).await?;
 
         self.view_manager.did_create_row(&row_pb, &params).await;
+        // The filters and the sorts of the views evaluate the row that isn't created in a group.
+        if params.group_id.is_none() {
+            self.view_manager.did_update_row(None, &row_pb.id).await;
+        }
+        self.record_edit(vec![DatabaseEditAction::DeleteRow {
+            row_id: row_pb.id.clone(),
+        }]);
         Ok(row_pb)
     }
 
@@ -372,8 +1193,333 @@ impl DatabaseRevisionEditor {
         Ok(())
     }
 
-    pub async fn insert_rows(&self, row_revs: Vec<RowRevision>) -> FlowyResult<Vec<RowPB>> {
-        let block_id = self.block_id().await?;
+    /// Creates a new group in the view that is grouped by the select field, by adding a new option
+    /// to the field. The name gets a suffix if another option has the same name. The groups of the
+    /// other field types can't be created, the [ErrorCode::UnsupportedGroupOperation] is returned.
+    #[tracing::instrument(level = "trace", skip_all, err)]
+    pub async fn create_group(&self, params: CreateGroupParams) -> FlowyResult<GroupPB> {
+        let field_id = self.view_manager.group_field_id(&params.view_id).await?;
+        let field_rev = self
+            .get_field_rev(&field_id)
+            .await
+            .ok_or_else(|| FlowyError::record_not_found().context("Can't find the group field"))?;
+        let field_type: FieldType = field_rev.ty.into();
+        let option_id = match field_type {
+            FieldType::SingleSelect => {
+                self.insert_group_option::<SingleSelectTypeOptionPB>(field_rev, &params.name)
+                    .await?
+            }
+            FieldType::MultiSelect => {
+                self.insert_group_option::<MultiSelectTypeOptionPB>(field_rev, &params.name)
+                    .await?
+            }
+            _ => {
+                let msg = format!("Can't create the group of the {:?} field", field_type);
+                return Err(FlowyError::new(ErrorCode::UnsupportedGroupOperation, &msg));
+            }
+        };
+        self.view_manager.did_create_group(&params.view_id, &option_id).await
+    }
+
+    /// Inserts the option with the name into the select field, the rows are grouped again after the
+    /// field is updated, so the group of the option is generated. Returns the id of the option.
+    async fn insert_group_option<T>(&self, field_rev: Arc<FieldRevision>, name: &str) -> FlowyResult<String>
+    where
+        T: SelectTypeOptionSharedAction + TypeOptionDataDeserializer,
+    {
+        let mut type_option = field_rev.get_type_option::<T>(field_rev.ty).ok_or_else(|| {
+            FlowyError::record_not_found().context(format!("Can't find the type option of the field: {}", field_rev.id))
+        })?;
+        let option = type_option.create_option_with_unique_name(name);
+        let option_id = option.id.clone();
+        type_option.insert_option(option);
+        let bytes = type_option.protobuf_bytes().to_vec();
+        self.update_field_type_option(&self.database_id, &field_rev.id, bytes, Some(field_rev.clone()))
+            .await?;
+        Ok(option_id)
+    }
+
+    /// Moves the group to the index in the visible groups of the view, the order of the groups is
+    /// saved in the view. If `reorder_options` is set and the view is grouped by the select field,
+    /// the options of the field are reordered as the groups.
+    #[tracing::instrument(level = "trace", skip_all, err)]
+    pub async fn move_group_to_index(&self, params: MoveGroupToIndexParams) -> FlowyResult<()> {
+        let (field_id, group_ids) = self.view_manager.move_group_to_index(&params).await?;
+        if !params.reorder_options {
+            return Ok(());
+        }
+
+        if let Some(field_rev) = self.get_field_rev(&field_id).await {
+            let field_type: FieldType = field_rev.ty.into();
+            match field_type {
+                FieldType::SingleSelect => {
+                    self.reorder_select_options::<SingleSelectTypeOptionPB>(field_rev, &group_ids)
+                        .await?
+                }
+                FieldType::MultiSelect => {
+                    self.reorder_select_options::<MultiSelectTypeOptionPB>(field_rev, &group_ids)
+                        .await?
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Reorders the options of the select field as the groups, the options without groups keep
+    /// their relative order and are put at the end.
+    async fn reorder_select_options<T>(&self, field_rev: Arc<FieldRevision>, group_ids: &[String]) -> FlowyResult<()>
+    where
+        T: SelectTypeOptionSharedAction + TypeOptionDataDeserializer,
+    {
+        let mut type_option = match field_rev.get_type_option::<T>(field_rev.ty) {
+            None => return Ok(()),
+            Some(type_option) => type_option,
+        };
+        let old_option_ids = type_option
+            .options()
+            .iter()
+            .map(|option| option.id.clone())
+            .collect::<Vec<String>>();
+        type_option.mut_options().sort_by_key(|option| {
+            group_ids
+                .iter()
+                .position(|group_id| group_id == &option.id)
+                .unwrap_or(usize::MAX)
+        });
+        let is_changed = type_option
+            .options()
+            .iter()
+            .map(|option| &option.id)
+            .ne(old_option_ids.iter());
+        if is_changed {
+            let bytes = type_option.protobuf_bytes().to_vec();
+            self.update_field_type_option(&self.database_id, &field_rev.id, bytes, Some(field_rev.clone()))
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Hides or shows the group of the view, including the no status group. The rows are still
+    /// grouped into the hidden group, but the group and its rows are not sent to the board.
+    #[tracing::instrument(level = "trace", skip_all, err)]
+    pub async fn set_group_visibility(&self, params: SetGroupVisibilityParams) -> FlowyResult<()> {
+        self.view_manager.set_group_visibility(params).await
+    }
+
+    /// Collapses or expands the group of the view. The state is kept while the id of the group is
+    /// not changed, for example, the id of the select option.
+    #[tracing::instrument(level = "trace", skip_all, err)]
+    pub async fn set_group_collapsed(&self, params: SetGroupCollapsedParams) -> FlowyResult<()> {
+        self.view_manager.set_group_collapsed(params).await
+    }
+
+    pub async fn insert_rows(&self, row_revs: Vec<RowRevision>) -> FlowyResult<Vec<RowPB>> {
+        let row_orders = self.insert_row_revs(row_revs).await?;
+
+        // The inserted rows are deleted together when undoing.
+        let delete_row_actions = row_orders
+            .iter()
+            .map(|row| DatabaseEditAction::DeleteRow { row_id: row.id.clone() })
+            .collect::<DatabaseEditStep>();
+        self.record_edit(delete_row_actions);
+        Ok(row_orders)
+    }
+
+    /// Creates the rows in a batch, for example, when importing. The rows are inserted at the
+    /// `start_index` of the database in their order, or appended to the database if it's None.
+    ///
+    /// The rows that are appended to a block fill it up to [MAX_ROWS_PER_BLOCK] rows, and the
+    /// rest of the rows are put into new blocks after it. The rows that are inserted in the middle
+    /// of a block are kept in that block, so the order of the rows is kept. Each touched block is
+    /// written in one revision and the metas of the blocks are updated in one revision of the
+    /// database, then the views are notified of all the new rows at once.
+    pub async fn create_rows(&self, rows: Vec<RowDataPayload>, start_index: Option<usize>) -> FlowyResult<Vec<RowPB>> {
+        if rows.is_empty() {
+            return Ok(vec![]);
+        }
+        let field_revs = self.database_pad.read().await.get_field_revs(None)?;
+        let block_meta_revs = self.database_pad.read().await.get_block_meta_revs();
+        let (block_meta_rev, index_in_block) = locate_block_of_row_index(&block_meta_revs, start_index)
+            .ok_or_else(|| FlowyError::internal().context("There is no grid block in this grid"))?;
+
+        let mut row_revs = Vec::with_capacity(rows.len());
+        for row in rows {
+            row_revs.push(self.make_row_rev_from_data(&block_meta_rev.block_id, &field_revs, row)?);
+        }
+        let number_of_rows_in_block = match index_in_block {
+            Some(_) => row_revs.len(),
+            None => MAX_ROWS_PER_BLOCK
+                .saturating_sub(block_meta_rev.row_count.max(0) as usize)
+                .min(row_revs.len()),
+        };
+        let mut overflow_row_revs = row_revs.split_off(number_of_rows_in_block);
+
+        let mut inserted_rows = vec![];
+        let mut changesets = vec![];
+        if !row_revs.is_empty() {
+            let (row_count, rows) = self
+                .block_manager
+                .create_rows_in_block(&block_meta_rev.block_id, row_revs, index_in_block)
+                .await?;
+            changesets.push(GridBlockMetaRevisionChangeset::from_row_count(
+                block_meta_rev.block_id.clone(),
+                row_count,
+            ));
+            inserted_rows.extend(rows);
+        }
+
+        let mut new_block_meta_revs = vec![];
+        while !overflow_row_revs.is_empty() {
+            let rest_row_revs = overflow_row_revs.split_off(MAX_ROWS_PER_BLOCK.min(overflow_row_revs.len()));
+            let mut new_block_meta_rev = GridBlockMetaRevision::new();
+            new_block_meta_rev.row_count = overflow_row_revs.len() as i32;
+            overflow_row_revs
+                .iter_mut()
+                .for_each(|row_rev| row_rev.block_id = new_block_meta_rev.block_id.clone());
+            let rows = self
+                .block_manager
+                .create_block_with_rows(&new_block_meta_rev.block_id, overflow_row_revs)
+                .await?;
+            inserted_rows.extend(rows);
+            new_block_meta_revs.push(new_block_meta_rev);
+            overflow_row_revs = rest_row_revs;
+        }
+
+        self.modify(|grid_pad| {
+            Ok(grid_pad.update_block_revs(changesets, new_block_meta_revs, Some(&block_meta_rev.block_id))?)
+        })
+        .await?;
+
+        let row_pbs = inserted_rows
+            .iter()
+            .map(|inserted_row| inserted_row.row.clone())
+            .collect::<Vec<RowPB>>();
+        self.block_manager.notify_did_insert_rows(inserted_rows);
+        let row_ids = row_pbs.iter().map(|row| row.id.clone()).collect::<Vec<String>>();
+        self.recompute_formulas(Some(row_ids), None).await?;
+
+        // The created rows are deleted together when undoing.
+        let delete_row_actions = row_pbs
+            .iter()
+            .map(|row| DatabaseEditAction::DeleteRow { row_id: row.id.clone() })
+            .collect::<DatabaseEditStep>();
+        self.record_edit(delete_row_actions);
+        Ok(row_pbs)
+    }
+
+    /// Creates the row at the visual `index` of the view, that is the index in the order the view
+    /// displays its rows after applying its filters and sorts. The row is inserted right after the
+    /// row at `index - 1` of the view, or before the first row of the view if the `index` is 0.
+    ///
+    /// The sorts of the view are not suspended, so the row is inserted and then reordered by the
+    /// sorts like the other rows. Returns the row together with the index it ends up at, which is
+    /// None if the row is hidden by the filters of the view.
+    pub async fn create_row_at_index(
+        &self,
+        view_id: &str,
+        index: usize,
+        data: RowDataPayload,
+    ) -> FlowyResult<CreatedRowAtIndexPB> {
+        let view_editor = self.view_manager.get_view_editor(view_id).await?;
+        let start_index = view_editor.database_index_of_visual_index(index).await;
+        let row = self
+            .create_rows(vec![data], start_index)
+            .await?
+            .pop()
+            .ok_or_else(|| FlowyError::internal().context("Create the row failed"))?;
+        let index = view_editor.visual_index_of_row(&row.id).await.map(|index| index as i32);
+        Ok(CreatedRowAtIndexPB { row, index })
+    }
+
+    /// Saves the cells of the row as a [RowTemplate] of the database with the name. The existing
+    /// template with the same name is replaced. The cell of the primary field is saved only if
+    /// `include_primary` is true.
+    pub async fn save_row_template(&self, name: &str, row_id: &str, include_primary: bool) -> FlowyResult<RowTemplate> {
+        let row_rev = self
+            .get_row_rev(row_id)
+            .await?
+            .ok_or_else(|| FlowyError::record_not_found().context(format!("Can't find the row: {}", row_id)))?;
+        let field_revs = self.get_field_revs(None).await?;
+        let template = RowTemplate::from_row_rev(name, &row_rev, &field_revs, include_primary);
+        self.row_templates
+            .save_templates(&self.database_id, vec![template.clone()])?;
+        Ok(template)
+    }
+
+    pub fn list_row_templates(&self) -> FlowyResult<Vec<RowTemplate>> {
+        self.row_templates.get_templates(&self.database_id)
+    }
+
+    pub fn delete_row_template(&self, name: &str) -> FlowyResult<()> {
+        self.row_templates.delete_template(&self.database_id, name)
+    }
+
+    /// Returns the JSON of the row templates of the database, which can be imported by
+    /// `import_row_templates`.
+    pub fn export_row_templates(&self) -> FlowyResult<String> {
+        let templates = self.row_templates.get_templates(&self.database_id)?;
+        Ok(serde_json::to_string(&templates)?)
+    }
+
+    /// Imports the row templates from the JSON that is returned by `export_row_templates`. The
+    /// existing templates with the same names are replaced.
+    pub fn import_row_templates(&self, json: &str) -> FlowyResult<()> {
+        let templates: Vec<RowTemplate> = serde_json::from_str(json)?;
+        self.row_templates.save_templates(&self.database_id, templates)
+    }
+
+    /// Creates the row from the [RowTemplate] with the name at the visual `index` of the view. The
+    /// cells of the template that are no longer compatible with the fields, because their fields
+    /// were deleted or switched to another field type, are skipped.
+    pub async fn create_row_from_template(
+        &self,
+        view_id: &str,
+        name: &str,
+        index: usize,
+    ) -> FlowyResult<CreatedRowAtIndexPB> {
+        let template = self
+            .row_templates
+            .get_template(&self.database_id, name)?
+            .ok_or_else(|| FlowyError::record_not_found().context(format!("Can't find the row template: {}", name)))?;
+        let field_revs = self.get_field_revs(None).await?;
+        let data = RowDataPayload {
+            cell_revs: template.make_cell_revs(&field_revs),
+            ..Default::default()
+        };
+        self.create_row_at_index(view_id, index, data).await
+    }
+
+    /// Builds the row from its cells and the changesets of its cells, the other cells are filled
+    /// with the default values of their fields.
+    fn make_row_rev_from_data(
+        &self,
+        block_id: &str,
+        field_revs: &[Arc<FieldRevision>],
+        row: RowDataPayload,
+    ) -> FlowyResult<RowRevision> {
+        let mut builder = RowRevisionBuilder::new(block_id, field_revs);
+        for (field_id, cell_rev) in row.cell_revs {
+            builder.insert_cell_rev(&field_id, cell_rev);
+        }
+        for (field_id, cell_changeset) in row.cell_by_field_id {
+            let field_rev = field_revs
+                .iter()
+                .find(|field_rev| field_rev.id == field_id)
+                .ok_or_else(|| FlowyError::record_not_found().context(format!("Can't find the field: {}", field_id)))?;
+            check_field_unlocked(field_rev)?;
+            let type_cell_data =
+                apply_cell_data_changeset(cell_changeset, None, field_rev, Some(self.cell_data_cache.clone()))
+                    .map_err(|err| validation_error_with_row_id(err, builder.row_id()))?;
+            builder.insert_cell_rev(&field_id, CellRevision::new(type_cell_data));
+        }
+        builder.insert_default_cells();
+        Ok(builder.build())
+    }
+
+    /// Inserts the rows at the end of the block without recording the edit.
+    async fn insert_row_revs(&self, row_revs: Vec<RowRevision>) -> FlowyResult<Vec<RowPB>> {
+        let block_id = self.block_id().await?;
         let mut rows_by_block_id: HashMap<String, Vec<RowRevision>> = HashMap::new();
         let mut row_orders = vec![];
         for row_rev in row_revs {
@@ -387,17 +1533,54 @@ impl DatabaseRevisionEditor {
         for changeset in changesets {
             self.update_block(changeset).await?;
         }
+        let row_ids = row_orders.iter().map(|row| row.id.clone()).collect();
+        self.recompute_formulas(Some(row_ids), None).await?;
         Ok(row_orders)
     }
 
     pub async fn update_row(&self, changeset: RowChangeset) -> FlowyResult<()> {
-        let row_id = changeset.row_id.clone();
-        let old_row = self.get_row_rev(&row_id).await?;
-        self.block_manager.update_row(changeset).await?;
-        self.view_manager.did_update_row(old_row, &row_id).await;
+        let update_row_action = self.make_update_row_action(&changeset).await?;
+        self.apply_row_changeset(changeset).await?;
+        if let Some(action) = update_row_action {
+            self.record_edit(vec![action]);
+        }
         Ok(())
     }
 
+    /// Updates the icon, the cover and the color of the row. The value that is None is left
+    /// unchanged, and the value that is empty is cleared.
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    pub async fn update_row_meta(&self, row_id: &str, changeset: RowMetaChangeset) -> FlowyResult<()> {
+        if self.get_row_rev(row_id).await?.is_none() {
+            return Err(FlowyError::record_not_found().context(format!("Can't find the row: {}", row_id)));
+        }
+        if changeset.is_empty() {
+            return Ok(());
+        }
+        let mut row_changeset = RowChangeset::new(row_id.to_owned());
+        row_changeset.meta = Some(changeset);
+        self.update_row(row_changeset).await
+    }
+
+    /// Sets the document that the row opens into. The document of the row is cleared if the
+    /// `document_id` is None.
+    pub async fn set_row_document(&self, row_id: &str, document_id: Option<String>) -> FlowyResult<()> {
+        if self.get_row_rev(row_id).await?.is_none() {
+            return Err(FlowyError::record_not_found().context(format!("Can't find the row: {}", row_id)));
+        }
+        let mut row_changeset = RowChangeset::new(row_id.to_owned());
+        row_changeset.document_id = Some(document_id.unwrap_or_default());
+        self.update_row(row_changeset).await
+    }
+
+    /// Returns the id of the document that the row opens into.
+    pub async fn get_row_document(&self, row_id: &str) -> FlowyResult<Option<String>> {
+        match self.get_row_rev(row_id).await? {
+            None => Err(FlowyError::record_not_found().context(format!("Can't find the row: {}", row_id))),
+            Some(row_rev) => Ok(row_rev.document_id.clone()),
+        }
+    }
+
     /// Returns all the rows in this block.
     pub async fn get_row_pbs(&self, view_id: &str, block_id: &str) -> FlowyResult<Vec<RowPB>> {
         let rows = self.view_manager.get_row_revs(view_id, block_id).await?;
@@ -407,11 +1590,19 @@ impl DatabaseRevisionEditor {
 
     pub async fn get_all_row_revs(&self, view_id: &str) -> FlowyResult<Vec<Arc<RowRevision>>> {
         let mut all_rows = vec![];
-        let blocks = self.block_manager.get_blocks(None).await?;
-        for block in blocks {
-            let rows = self.view_manager.get_row_revs(view_id, &block.block_id).await?;
+        // The rows are in the order of the blocks of the database.
+        let block_meta_revs = self.database_pad.read().await.get_block_meta_revs();
+        for block_meta_rev in block_meta_revs {
+            let rows = self
+                .view_manager
+                .get_row_revs(view_id, &block_meta_rev.block_id)
+                .await?;
             all_rows.extend(rows);
         }
+        // The rows are sorted block by block, so the pinned rows are moved before the rows of the
+        // previous blocks.
+        let pinned_row_ids = self.view_manager.get_pinned_row_ids(view_id).await.unwrap_or_default();
+        move_pinned_rows_first(&pinned_row_ids, &mut all_rows, |row_rev| row_rev.id.as_str());
         Ok(all_rows)
     }
 
@@ -422,21 +1613,235 @@ impl DatabaseRevisionEditor {
         }
     }
 
+    /// Returns at most `limit` changes of the row, starting from the latest one. Only the changes
+    /// made by the revisions before the `before_rev_id` are returned if it's not None, so the older
+    /// changes can be loaded page by page.
+    ///
+    /// The edits whose revisions were merged into one are reported as one change.
+    pub async fn get_row_history(
+        &self,
+        row_id: &str,
+        before_rev_id: Option<i64>,
+        limit: usize,
+    ) -> FlowyResult<Vec<RowChangePB>> {
+        let field_rev_by_id = self
+            .database_pad
+            .read()
+            .await
+            .get_fields()
+            .iter()
+            .map(|field_rev| (field_rev.id.clone(), field_rev.clone()))
+            .collect::<HashMap<String, Arc<FieldRevision>>>();
+        let row_changes = self
+            .block_manager
+            .get_row_changes(row_id)
+            .await?
+            .iter()
+            .rev()
+            .filter(|row_change| before_rev_id.map(|rev_id| row_change.rev_id < rev_id).unwrap_or(true))
+            .take(limit)
+            .map(|row_change| make_row_change_pb(row_change, &field_rev_by_id))
+            .collect::<Vec<RowChangePB>>();
+        Ok(row_changes)
+    }
+
+    /// Moves the row to the trash. See [Self::delete_rows].
     pub async fn delete_row(&self, row_id: &str) -> FlowyResult<()> {
-        let row_rev = self.block_manager.delete_row(row_id).await?;
-        tracing::trace!("Did delete row:{:?}", row_rev);
-        if let Some(row_rev) = row_rev {
-            self.view_manager.did_delete_row(row_rev).await;
-        }
+        self.delete_rows(vec![row_id.to_owned()]).await?;
         Ok(())
     }
 
+    /// Restores the deleted row from the trash, or from the last revision of its block in which
+    /// the row existed if it was purged from the trash. The row is inserted back into its block
+    /// with all its cells, or into the first block if its block doesn't exist anymore. The views
+    /// are notified just like the row was created.
+    ///
+    /// Returns a `RecordNotFound` error if the row can't be found in the revisions. The row is
+    /// returned as it is if it wasn't deleted.
+    pub async fn restore_row(&self, row_id: &str) -> FlowyResult<RowPB> {
+        if let Ok(Some(row_rev)) = self.get_row_rev(row_id).await {
+            return Ok(RowPB::from(&row_rev));
+        }
+        if let Some(row_pb) = self.restore_rows(vec![row_id.to_owned()]).await?.pop() {
+            return Ok(row_pb);
+        }
+
+        let row_rev = self
+            .block_manager
+            .get_row_changes(row_id)
+            .await?
+            .into_iter()
+            .rev()
+            .find_map(|row_change| row_change.after)
+            .ok_or_else(|| FlowyError::record_not_found().context(format!("Can't find the row with id: {}", row_id)))?;
+
+        let mut row_rev = row_rev.as_ref().clone();
+        let block_meta_revs = self.database_pad.read().await.get_block_meta_revs();
+        if !block_meta_revs
+            .iter()
+            .any(|block_meta_rev| block_meta_rev.block_id == row_rev.block_id)
+        {
+            match block_meta_revs.first() {
+                None => return Err(FlowyError::internal().context("There is no grid block in this grid")),
+                Some(block_meta_rev) => row_rev.block_id = block_meta_rev.block_id.clone(),
+            }
+        }
+
+        let row_pb = self.create_row_pb(row_rev, None).await?;
+        self.did_restore_row(&row_pb, None).await;
+        self.record_edit(vec![DatabaseEditAction::DeleteRow {
+            row_id: row_id.to_owned(),
+        }]);
+        Ok(row_pb)
+    }
+
+    /// Returns the deleted rows that are in the trash, in the order they were deleted.
+    pub async fn list_trashed_rows(&self) -> Vec<TrashedRowPB> {
+        self.database_pad
+            .read()
+            .await
+            .get_trashed_rows()
+            .iter()
+            .map(|trashed_row| TrashedRowPB::from(trashed_row.as_ref()))
+            .collect()
+    }
+
+    /// Restores the rows from the trash. Each row is inserted back after the row that was before it
+    /// when it was deleted, or at the end of its block if that row doesn't exist anymore. The row
+    /// is inserted into the first block if its block doesn't exist anymore.
+    ///
+    /// The ids of the rows that are not in the trash are skipped. Returns the restored rows.
+    pub async fn restore_rows(&self, row_ids: Vec<String>) -> FlowyResult<Vec<RowPB>> {
+        let trashed_rows = self
+            .database_pad
+            .read()
+            .await
+            .get_trashed_rows()
+            .into_iter()
+            .filter(|trashed_row| row_ids.contains(&trashed_row.row.id))
+            .collect::<Vec<Arc<TrashedRowRevision>>>();
+        if trashed_rows.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let block_meta_revs = self.database_pad.read().await.get_block_meta_revs();
+        let first_block_id = match block_meta_revs.first() {
+            None => return Err(FlowyError::internal().context("There is no grid block in this grid")),
+            Some(block_meta_rev) => block_meta_rev.block_id.clone(),
+        };
+
+        // The rows are restored in the reverse order they were deleted, because the previous row
+        // of a row that was deleted together with its previous row is the row before both of them.
+        let mut row_pbs = vec![];
+        for trashed_row in trashed_rows.iter().rev() {
+            let mut row_rev = trashed_row.row.clone();
+            if !block_meta_revs
+                .iter()
+                .any(|block_meta_rev| block_meta_rev.block_id == row_rev.block_id)
+            {
+                row_rev.block_id = first_block_id.clone();
+            }
+            row_pbs.push(self.insert_row_after(row_rev, trashed_row.prev_row_id.clone()).await?);
+        }
+        row_pbs.reverse();
+
+        let restored_row_ids = row_pbs.iter().map(|row_pb| row_pb.id.clone()).collect::<Vec<String>>();
+        self.modify(|grid_pad| Ok(grid_pad.remove_trashed_rows(&restored_row_ids)?))
+            .await?;
+        for (row_pb, trashed_row) in row_pbs.iter().zip(trashed_rows.iter()) {
+            self.did_restore_row(row_pb, trashed_row.prev_row_id.clone()).await;
+        }
+        send_notification(&self.database_id, DatabaseNotification::DidRestoreRows)
+            .payload(RepeatedRowPB::from(row_pbs.clone()))
+            .send();
+        Ok(row_pbs)
+    }
+
+    /// Permanently deletes the rows that were moved to the trash at or before `older_than`, in
+    /// seconds. The stored files of their attachments are deleted if they are not referenced by
+    /// any other cell. Returns the purged rows.
+    pub async fn purge_trash(&self, older_than: i64) -> FlowyResult<Vec<TrashedRowPB>> {
+        let purged_rows = self
+            .database_pad
+            .read()
+            .await
+            .get_trashed_rows()
+            .into_iter()
+            .filter(|trashed_row| trashed_row.deleted_at <= older_than)
+            .collect::<Vec<Arc<TrashedRowRevision>>>();
+        if purged_rows.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let row_ids = purged_rows
+            .iter()
+            .map(|trashed_row| trashed_row.row.id.clone())
+            .collect::<Vec<String>>();
+        self.modify(|grid_pad| Ok(grid_pad.remove_trashed_rows(&row_ids)?))
+            .await?;
+
+        let attachments = purged_rows
+            .iter()
+            .flat_map(|trashed_row| attachments_of_row(&trashed_row.row, None))
+            .collect::<Vec<AttachmentPB>>();
+        let purged_rows = purged_rows
+            .iter()
+            .map(|trashed_row| TrashedRowPB::from(trashed_row.as_ref()))
+            .collect::<Vec<TrashedRowPB>>();
+        send_notification(&self.database_id, DatabaseNotification::DidDeleteRows)
+            .payload(RepeatedTrashedRowPB::from(purged_rows.clone()))
+            .send();
+        self.delete_orphaned_attachments(attachments).await;
+        Ok(purged_rows)
+    }
+
     pub async fn subscribe_view_changed(&self, view_id: &str) -> FlowyResult<broadcast::Receiver<GridViewChanged>> {
         self.view_manager.subscribe_view_changed(view_id).await
     }
 
-    pub async fn duplicate_row(&self, _row_id: &str) -> FlowyResult<()> {
-        Ok(())
+    /// Inserts a copy of the row right after it in the same block. The copy has a copy of each
+    /// cell of the row, the height and the visibility of the row, and the comments of its cells if
+    /// `include_comments` is true. The cells of the created time and the last edited time fields
+    /// are the times of the copy.
+    ///
+    /// The copy never shares the document of the row. If `duplicate_document` is true, the id of
+    /// the document of the row is returned as the `source_document_id` of the result, so the caller
+    /// can duplicate the document and attach it to the copy with [Self::set_row_document].
+    /// Otherwise, the copy has no document.
+    ///
+    /// The inserted row is sent with its index, so the views insert it next to the row.
+    pub async fn duplicate_row(
+        &self,
+        row_id: &str,
+        include_comments: bool,
+        duplicate_document: bool,
+    ) -> FlowyResult<DuplicatedRowPB> {
+        let row_rev = self.get_typed_row_rev(row_id).await?;
+        let mut duplicated_row_rev = self.create_row_rev().await?;
+        duplicated_row_rev.block_id = row_rev.block_id.clone();
+        duplicated_row_rev.cells = row_rev.cells.clone();
+        duplicated_row_rev.height = row_rev.height;
+        duplicated_row_rev.visibility = row_rev.visibility;
+        duplicated_row_rev.meta = row_rev.meta.clone();
+        if include_comments {
+            duplicated_row_rev.comments = row_rev.comments.clone();
+        }
+
+        let row_pb = self.create_row_pb(duplicated_row_rev, Some(row_id.to_owned())).await?;
+        self.recompute_formulas(Some(vec![row_pb.id.clone()]), None).await?;
+        self.view_manager.did_update_row(None, &row_pb.id).await;
+        self.record_edit(vec![DatabaseEditAction::DeleteRow {
+            row_id: row_pb.id.clone(),
+        }]);
+        let source_document_id = if duplicate_document {
+            row_rev.document_id.clone()
+        } else {
+            None
+        };
+        Ok(DuplicatedRowPB {
+            row: row_pb,
+            source_document_id,
+        })
     }
 
     /// Returns the cell data that encoded in protobuf.
@@ -460,7 +1865,8 @@ impl DatabaseRevisionEditor {
         let display_str = || async {
             let field_rev = self.get_field_rev(&params.field_id).await?;
             let field_type: FieldType = field_rev.ty.into();
-            let cell_rev = self.get_cell_rev(&params.row_id, &params.field_id).await.ok()??;
+            let (_, row_rev) = self.block_manager.get_row_rev(&params.row_id).await.ok()??;
+            let cell_rev = get_cell_rev_from_row(&row_rev, &field_rev)?;
             let type_cell_data: TypeCellData = cell_rev.try_into().ok()?;
             Some(stringify_cell_data(
                 type_cell_data.cell_str,
@@ -481,7 +1887,7 @@ impl DatabaseRevisionEditor {
     async fn get_type_cell_protobuf(&self, params: &CellPathParams) -> Option<(FieldType, CellProtobufBlob)> {
         let field_rev = self.get_field_rev(&params.field_id).await?;
         let (_, row_rev) = self.block_manager.get_row_rev(&params.row_id).await.ok()??;
-        let cell_rev = row_rev.cells.get(&params.field_id)?.clone();
+        let cell_rev = get_cell_rev_from_row(&row_rev, &field_rev)?;
         Some(get_type_cell_protobuf(
             cell_rev.type_cell_data,
             &field_rev,
@@ -505,6 +1911,87 @@ impl DatabaseRevisionEditor {
         view_editor.get_cells_for_field(field_id).await
     }
 
+    /// Returns the decoded data of the cell, so the consumers don't need to know which protobuf
+    /// to decode for each field type. The cell that the row doesn't have is returned as the empty
+    /// cell of the field.
+    pub async fn get_typed_cell(&self, row_id: &str, field_id: &str) -> FlowyResult<TypedCellData> {
+        let field_rev = self.get_field_rev(field_id).await.ok_or_else(|| {
+            FlowyError::field_record_not_found().context(format!("Can't find the field: {}", field_id))
+        })?;
+        let row_rev = self.get_typed_row_rev(row_id).await?;
+        get_typed_cell_data(
+            get_cell_rev_from_row(&row_rev, &field_rev),
+            &field_rev,
+            Some(self.cell_data_cache.clone()),
+        )
+    }
+
+    /// Returns the decoded data of all the cells of the row, keyed by the id of their fields.
+    pub async fn get_row_typed(&self, row_id: &str) -> FlowyResult<HashMap<String, TypedCellData>> {
+        let row_rev = self.get_typed_row_rev(row_id).await?;
+        let field_revs = self.database_pad.read().await.get_field_revs(None)?;
+        let mut typed_cells = HashMap::with_capacity(field_revs.len());
+        for field_rev in field_revs {
+            let typed_cell_data = get_typed_cell_data(
+                get_cell_rev_from_row(&row_rev, &field_rev),
+                &field_rev,
+                Some(self.cell_data_cache.clone()),
+            )?;
+            typed_cells.insert(field_rev.id.clone(), typed_cell_data);
+        }
+        Ok(typed_cells)
+    }
+
+    /// Appends a comment written by the current user to the comments of the cell. The comments are
+    /// stored next to the cells of the row, so they are kept when the field type is switched.
+    pub async fn add_cell_comment(
+        &self,
+        row_id: &str,
+        field_id: &str,
+        content: &str,
+    ) -> FlowyResult<CellCommentRevision> {
+        if content.trim().is_empty() {
+            return Err(ErrorCode::CellCommentIsEmpty.into());
+        }
+        if self.get_field_rev(field_id).await.is_none() {
+            return Err(FlowyError::field_record_not_found().context(format!("Can't find the field: {}", field_id)));
+        }
+        let _ = self.get_typed_row_rev(row_id).await?;
+
+        let comment = CellCommentRevision::new(self.user.user_id()?, content.to_owned(), timestamp());
+        self.block_manager
+            .add_cell_comment(row_id, field_id, comment.clone())
+            .await?;
+        Ok(comment)
+    }
+
+    /// Returns a `RecordNotFound` error if the cell doesn't have the comment.
+    pub async fn delete_cell_comment(&self, row_id: &str, field_id: &str, comment_id: &str) -> FlowyResult<()> {
+        if !self
+            .get_cell_comments(row_id, field_id)
+            .await?
+            .iter()
+            .any(|comment| comment.id == comment_id)
+        {
+            return Err(FlowyError::record_not_found().context(format!("Can't find the comment: {}", comment_id)));
+        }
+        self.block_manager
+            .delete_cell_comment(row_id, field_id, comment_id)
+            .await
+    }
+
+    /// Returns the comments of the cell in the order they were added.
+    pub async fn get_cell_comments(&self, row_id: &str, field_id: &str) -> FlowyResult<Vec<CellCommentRevision>> {
+        let row_rev = self.get_typed_row_rev(row_id).await?;
+        Ok(row_rev.comments.get(field_id).cloned().unwrap_or_default())
+    }
+
+    async fn get_typed_row_rev(&self, row_id: &str) -> FlowyResult<Arc<RowRevision>> {
+        self.get_row_rev(row_id)
+            .await?
+            .ok_or_else(|| FlowyError::record_not_found().context(format!("Can't find the row: {}", row_id)))
+    }
+
     #[tracing::instrument(level = "trace", skip_all, err)]
     pub async fn update_cell_with_changeset<T: ToCellChangesetString>(
         &self,
@@ -512,48 +1999,809 @@ impl DatabaseRevisionEditor {
         field_id: &str,
         cell_changeset: T,
     ) -> FlowyResult<()> {
-        match self.database_pad.read().await.get_field_rev(field_id) {
+        self.update_cell_with_undo_step(row_id, field_id, cell_changeset, vec![])
+            .await
+    }
+
+    /// Updates the cell and records the update in the `step`, so the actions that were applied
+    /// before the update are undone together with it.
+    async fn update_cell_with_undo_step<T: ToCellChangesetString>(
+        &self,
+        row_id: &str,
+        field_id: &str,
+        cell_changeset: T,
+        mut step: DatabaseEditStep,
+    ) -> FlowyResult<()> {
+        let removed_attachments = match self.database_pad.read().await.get_field_rev(field_id) {
             None => {
                 let msg = format!("Field with id:{} not found", &field_id);
                 Err(FlowyError::internal().context(msg))
             }
             Some((_, field_rev)) => {
+                check_field_unlocked(field_rev)?;
                 tracing::trace!("Cell changeset: id:{} / value:{:?}", &field_id, cell_changeset);
-                let old_row_rev = self.get_row_rev(row_id).await?.clone();
                 let cell_rev = self.get_cell_rev(row_id, field_id).await?;
+                let old_cell_rev = cell_rev.clone().unwrap_or_else(|| make_empty_cell_rev(field_rev));
                 // Update the changeset.data property with the return value.
                 let type_cell_data =
-                    apply_cell_data_changeset(cell_changeset, cell_rev, field_rev, Some(self.cell_data_cache.clone()))?;
-                let cell_changeset = CellChangesetPB {
-                    database_id: self.database_id.clone(),
+                    apply_cell_data_changeset(cell_changeset, cell_rev, field_rev, Some(self.cell_data_cache.clone()))
+                        .map_err(|err| validation_error_with_row_id(err, row_id))?;
+                let mut removed_attachments = attachments_of_cell(&old_cell_rev);
+                if !removed_attachments.is_empty() {
+                    let attachments = attachments_of_cell(&CellRevision::new(type_cell_data.clone()));
+                    removed_attachments
+                        .retain(|removed| !attachments.iter().any(|attachment| attachment.id == removed.id));
+                }
+                self.apply_cell_data(row_id, field_id, type_cell_data).await?;
+                step.push(DatabaseEditAction::UpdateCell {
                     row_id: row_id.to_owned(),
                     field_id: field_id.to_owned(),
-                    type_cell_data,
-                };
-                self.block_manager.update_cell(cell_changeset).await?;
-                self.view_manager.did_update_row(old_row_rev, row_id).await;
-                Ok(())
+                    cell_rev: old_cell_rev,
+                });
+                self.record_edit(step);
+                Ok(removed_attachments)
             }
-        }
-    }
+        }?;
+        self.delete_orphaned_attachments(removed_attachments).await;
 
-    #[tracing::instrument(level = "trace", skip_all, err)]
-    pub async fn update_cell<T: ToCellChangesetString>(
-        &self,
-        row_id: String,
-        field_id: String,
-        cell_changeset: T,
-    ) -> FlowyResult<()> {
-        self.update_cell_with_changeset(&row_id, &field_id, cell_changeset)
+        // The formulas of the row that reference the cell are evaluated again.
+        self.recompute_formulas(Some(vec![row_id.to_owned()]), Some(vec![field_id.to_owned()]))
             .await
     }
 
-    pub async fn get_block_meta_revs(&self) -> FlowyResult<Vec<Arc<GridBlockMetaRevision>>> {
-        let block_meta_revs = self.database_pad.read().await.get_block_meta_revs();
-        Ok(block_meta_revs)
+    /// Appends the `text` to the cell instead of replacing its content:
+    /// * the text cell gets the text after the append separator of its type option.
+    /// * the multi-select cell gets the option named `text`, the option is created if the field
+    /// doesn't have it yet.
+    /// * the checklist cell gets a new unchecked item named `text`.
+    ///
+    /// Creating the option and updating the cell are undone in one step. Returns an error for the
+    /// other field types, for example, the number, date and checkbox fields.
+    pub async fn append_to_cell(&self, row_id: &str, field_id: &str, text: &str) -> FlowyResult<()> {
+        let field_rev = self
+            .get_field_rev(field_id)
+            .await
+            .ok_or_else(|| FlowyError::record_not_found().context(format!("Field with id:{} not found", field_id)))?;
+        check_field_unlocked(&field_rev)?;
+        let field_type: FieldType = field_rev.ty.into();
+        if !matches!(
+            field_type,
+            FieldType::RichText | FieldType::MultiSelect | FieldType::Checklist
+        ) {
+            let msg = format!("Can't append the text to the cell of the {:?} field", field_type);
+            return Err(FlowyError::new(ErrorCode::FieldInvalidOperation, &msg));
+        }
+        if self.get_row_rev(row_id).await?.is_none() {
+            return Err(FlowyError::record_not_found().context(format!("Row with id:{} not found", row_id)));
+        }
+        if text.trim().is_empty() {
+            return Ok(());
+        }
+
+        match field_type {
+            FieldType::RichText => {
+                let type_option = field_rev
+                    .get_type_option::<RichTextTypeOptionPB>(field_rev.ty)
+                    .unwrap_or_default();
+                let cell_text = self
+                    .get_cell_rev(row_id, field_id)
+                    .await?
+                    .and_then(|cell_rev| TypeCellData::try_from(&cell_rev).ok())
+                    .map(|type_cell_data| {
+                        stringify_cell_data(
+                            type_cell_data.cell_str,
+                            &type_cell_data.field_type,
+                            &field_type,
+                            &field_rev,
+                        )
+                    })
+                    .unwrap_or_default();
+                self.update_cell_with_changeset(row_id, field_id, type_option.append_text(&cell_text, text))
+                    .await
+            }
+            FieldType::MultiSelect => {
+                let name = text.trim();
+                let type_option = select_type_option_from_field_rev(&field_rev)?;
+                let existing_option_id = type_option
+                    .options()
+                    .iter()
+                    .find(|option| option.name == name)
+                    .map(|option| option.id.clone());
+                let mut step = vec![];
+                let option_id = match existing_option_id {
+                    Some(option_id) => option_id,
+                    None => {
+                        let option = type_option.create_option(name);
+                        let option_id = option.id.clone();
+                        let old_field_rev = self
+                            .modify_field_rev_without_undo(field_id, |field_rev| {
+                                let mut type_option = select_type_option_from_field_rev(field_rev)?;
+                                type_option.insert_option(option);
+                                field_rev.insert_type_option(&*type_option);
+                                Ok(Some(()))
+                            })
+                            .await?;
+                        if let Some(old_field_rev) = old_field_rev {
+                            step.push(DatabaseEditAction::UpdateField {
+                                field_rev: old_field_rev,
+                            });
+                        }
+                        option_id
+                    }
+                };
+                let changeset = SelectOptionCellChangeset::from_insert_option_id(&option_id);
+                self.update_cell_with_undo_step(row_id, field_id, changeset, step).await
+            }
+            _ => {
+                let changeset = ChecklistCellChangeset {
+                    insert_items: vec![ChecklistItem::new(text.trim())],
+                    ..Default::default()
+                };
+                self.update_cell_with_changeset(row_id, field_id, changeset).await
+            }
+        }
     }
 
-    pub async fn get_blocks(&self, block_ids: Option<Vec<String>>) -> FlowyResult<Vec<DatabaseBlockRowRevision>> {
+    /// Returns the ids of the rows whose cells violate the validation rules of the field. The
+    /// rules are only checked when the cells are edited, so the cells that were stored before the
+    /// rules were added are kept as they are until the user cleans them up.
+    pub async fn find_invalid_cells(&self, field_id: &str) -> FlowyResult<Vec<String>> {
+        let field_rev = self.get_field_rev(field_id).await.ok_or_else(|| {
+            FlowyError::field_record_not_found().context(format!("Can't find the field: {}", field_id))
+        })?;
+        let field_type: FieldType = field_rev.ty.into();
+        let handler = match TypeOptionCellExt::new_with_cell_data_cache(&field_rev, Some(self.cell_data_cache.clone()))
+            .get_type_option_cell_data_handler(&field_type)
+        {
+            None => return Ok(vec![]),
+            Some(handler) => handler,
+        };
+
+        let mut row_ids = vec![];
+        for block in self.get_blocks(None).await? {
+            for row_rev in block.row_revs {
+                let type_cell_data = get_cell_rev_from_row(&row_rev, &field_rev)
+                    .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok());
+                if let Some(type_cell_data) = type_cell_data {
+                    if handler
+                        .validate_cell_str(type_cell_data.cell_str, &type_cell_data.field_type, &field_rev)
+                        .is_some()
+                    {
+                        row_ids.push(row_rev.id.clone());
+                    }
+                }
+            }
+        }
+        Ok(row_ids)
+    }
+
+    /// Removes the cells of the field from all the rows in one revision per block. The cells are
+    /// restored by one undo. The cells of the primary field and the locked fields can't be cleared.
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    pub async fn clear_field_cells(&self, field_id: &str) -> FlowyResult<()> {
+        let field_rev = self.get_field_rev(field_id).await.ok_or_else(|| {
+            FlowyError::field_record_not_found().context(format!("Can't find the field: {}", field_id))
+        })?;
+        check_field_unlocked(&field_rev)?;
+        if field_rev.is_primary {
+            return Err(FlowyError::new(
+                ErrorCode::FieldInvalidOperation,
+                "The cells of the primary field can't be cleared",
+            ));
+        }
+
+        let mut edit_step = vec![];
+        let mut attachments = vec![];
+        let mut old_row_revs = vec![];
+        let mut block_ids = vec![];
+        for block in self.get_blocks(None).await? {
+            for row_rev in block.row_revs {
+                if let Some(cell_rev) = row_rev.cells.get(field_id) {
+                    edit_step.push(DatabaseEditAction::UpdateCell {
+                        row_id: row_rev.id.clone(),
+                        field_id: field_id.to_owned(),
+                        cell_rev: cell_rev.clone(),
+                    });
+                    attachments.extend(attachments_of_row(&row_rev, Some(field_id)));
+                    old_row_revs.push(row_rev);
+                    if !block_ids.contains(&block.block_id) {
+                        block_ids.push(block.block_id.clone());
+                    }
+                }
+            }
+        }
+        if edit_step.is_empty() {
+            return Ok(());
+        }
+
+        for block_id in block_ids {
+            self.block_manager.clear_cells(&block_id, field_id).await?;
+        }
+        let row_ids = old_row_revs
+            .iter()
+            .map(|row_rev| row_rev.id.clone())
+            .collect::<Vec<String>>();
+        // The rows are regrouped, filtered and sorted again.
+        for old_row_rev in old_row_revs {
+            let row_id = old_row_rev.id.clone();
+            self.view_manager.did_update_row(Some(old_row_rev), &row_id).await;
+        }
+        self.record_edit(edit_step);
+        self.delete_orphaned_attachments(attachments).await;
+
+        // The formulas that reference the field are evaluated again.
+        self.recompute_formulas(Some(row_ids), Some(vec![field_id.to_owned()]))
+            .await
+    }
+
+    /// Applies the changesets of the cells in one revision per block, for example, when pasting a
+    /// range of cells. The changed rows of each block are notified once.
+    ///
+    /// Each changeset is validated by the cell data handler of its field. Returns the errors of the
+    /// invalid changesets, the valid ones are still applied unless `strict` is true, in which case
+    /// none of the changesets is applied if any of them is invalid.
+    #[tracing::instrument(level = "trace", skip_all, err)]
+    pub async fn update_cells(
+        &self,
+        changesets: Vec<CellChangesetPB>,
+        strict: bool,
+    ) -> FlowyResult<Vec<CellUpdateErrorPB>> {
+        self.apply_cells(changesets, strict, false, vec![]).await
+    }
+
+    /// Copies the cells at the intersection of the rows and the fields of the view.
+    pub async fn copy_range(&self, params: CopyRangeParams) -> FlowyResult<RangeClipboardPB> {
+        let view_editor = self.view_manager.get_view_editor(&params.view_id).await?;
+        view_editor.copy_range(&params.row_ids, &params.field_ids).await
+    }
+
+    /// Pastes the clipboard to the cells of the view, starting from the anchor cell. The rows for
+    /// the values that go past the last row are created if `create_rows` is true. The pasted cells
+    /// and the created rows are restored by one undo.
+    ///
+    /// Returns the errors of the cells that can't be pasted, the other cells are still pasted.
+    #[tracing::instrument(level = "trace", skip_all, err)]
+    pub async fn paste_range(&self, params: PasteRangeParams) -> FlowyResult<Vec<CellUpdateErrorPB>> {
+        let view_editor = self.view_manager.get_view_editor(&params.view_id).await?;
+        let range_paste = view_editor
+            .paste_range(&params.anchor_row_id, &params.anchor_field_id, &params.clipboard)
+            .await?;
+        let mut errors = range_paste.errors;
+        let mut cells = range_paste.cells;
+        let mut undo_step = vec![];
+        if params.create_rows && !range_paste.new_rows.is_empty() {
+            let mut row_revs = vec![];
+            for new_row in range_paste.new_rows {
+                let row_rev = self.create_row_rev().await?;
+                for (field_id, type_cell_data) in new_row {
+                    cells.push(CellChangesetPB {
+                        database_id: self.database_id.clone(),
+                        row_id: row_rev.id.clone(),
+                        field_id,
+                        type_cell_data,
+                    });
+                }
+                row_revs.push(row_rev);
+            }
+            let row_pbs = self.insert_row_revs(row_revs).await?;
+            undo_step.extend(
+                row_pbs
+                    .iter()
+                    .map(|row| DatabaseEditAction::DeleteRow { row_id: row.id.clone() }),
+            );
+        }
+        errors.extend(self.apply_cells(cells, false, true, undo_step).await?);
+        Ok(errors)
+    }
+
+    /// Applies the cells in one revision per block. The `type_cell_data` of each changeset is the
+    /// new cell data if `is_cell_data` is true, otherwise it's the changeset of the cell. The
+    /// `undo_step` contains the actions of the same edit that were made before the cells.
+    async fn apply_cells(
+        &self,
+        changesets: Vec<CellChangesetPB>,
+        strict: bool,
+        is_cell_data: bool,
+        mut undo_step: DatabaseEditStep,
+    ) -> FlowyResult<Vec<CellUpdateErrorPB>> {
+        let mut errors = vec![];
+        let mut applied_changesets = vec![];
+        let mut removed_attachments = vec![];
+        let mut old_row_revs: Vec<Arc<RowRevision>> = vec![];
+        // The cells that are changed by the previous changesets, keyed by (row_id, field_id).
+        let mut changed_cells: HashMap<(String, String), String> = HashMap::new();
+        for changeset in changesets {
+            let cell_key = (changeset.row_id.clone(), changeset.field_id.clone());
+            let old_row_rev = old_row_revs
+                .iter()
+                .find(|row_rev| row_rev.id == changeset.row_id)
+                .cloned();
+            let result = self
+                .make_cell_data(
+                    &changeset,
+                    old_row_rev,
+                    changed_cells.get(&cell_key).cloned(),
+                    is_cell_data,
+                )
+                .await;
+            match result {
+                Err(e) => errors.push(CellUpdateErrorPB::new(&changeset, e)),
+                Ok((row_rev, old_cell_rev, type_cell_data)) => {
+                    let mut cell_removed_attachments = attachments_of_cell(&old_cell_rev);
+                    if !cell_removed_attachments.is_empty() {
+                        let attachments = attachments_of_cell(&CellRevision::new(type_cell_data.clone()));
+                        cell_removed_attachments
+                            .retain(|removed| !attachments.iter().any(|attachment| attachment.id == removed.id));
+                        removed_attachments.extend(cell_removed_attachments);
+                    }
+                    if !old_row_revs.iter().any(|old_row_rev| old_row_rev.id == row_rev.id) {
+                        old_row_revs.push(row_rev);
+                    }
+                    undo_step.push(DatabaseEditAction::UpdateCell {
+                        row_id: changeset.row_id.clone(),
+                        field_id: changeset.field_id.clone(),
+                        cell_rev: old_cell_rev,
+                    });
+                    changed_cells.insert(cell_key, type_cell_data.clone());
+                    applied_changesets.push(CellChangesetPB {
+                        database_id: self.database_id.clone(),
+                        type_cell_data,
+                        ..changeset
+                    });
+                }
+            }
+        }
+        if applied_changesets.is_empty() || (strict && !errors.is_empty()) {
+            undo_step.retain(|action| !matches!(action, DatabaseEditAction::UpdateCell { .. }));
+            if !undo_step.is_empty() {
+                self.record_edit(undo_step);
+            }
+            return Ok(errors);
+        }
+
+        let mut changed_field_ids = vec![];
+        for changeset in applied_changesets.iter() {
+            if !changed_field_ids.contains(&changeset.field_id) {
+                changed_field_ids.push(changeset.field_id.clone());
+            }
+        }
+        let cell_changes = self.make_cell_changes(old_row_revs.clone(), &applied_changesets).await;
+        self.block_manager
+            .update_cells(applied_changesets, cell_changes)
+            .await?;
+        let row_ids = old_row_revs
+            .iter()
+            .map(|row_rev| row_rev.id.clone())
+            .collect::<Vec<String>>();
+        for old_row_rev in old_row_revs {
+            let row_id = old_row_rev.id.clone();
+            self.view_manager.did_update_row(Some(old_row_rev), &row_id).await;
+        }
+        self.record_edit(undo_step);
+        self.delete_orphaned_attachments(removed_attachments).await;
+
+        // The formulas of the rows that reference the cells are evaluated again.
+        self.recompute_formulas(Some(row_ids), Some(changed_field_ids)).await?;
+        Ok(errors)
+    }
+
+    /// Applies the changeset to the cell, which is the `changed_cell` if it was changed by the
+    /// previous changesets of the same batch. The cell is replaced if `is_cell_data` is true.
+    /// Returns the row before the batch is applied, the old cell and the new cell data.
+    async fn make_cell_data(
+        &self,
+        changeset: &CellChangesetPB,
+        old_row_rev: Option<Arc<RowRevision>>,
+        changed_cell: Option<String>,
+        is_cell_data: bool,
+    ) -> FlowyResult<(Arc<RowRevision>, CellRevision, String)> {
+        let field_rev = self.get_field_rev(&changeset.field_id).await.ok_or_else(|| {
+            FlowyError::field_record_not_found().context(format!("Can't find the field: {}", changeset.field_id))
+        })?;
+        check_field_unlocked(&field_rev)?;
+        let row_rev = match old_row_rev {
+            Some(row_rev) => row_rev,
+            // The row that isn't indexed fails to be read, it's treated as not found as well.
+            None => self
+                .get_row_rev(&changeset.row_id)
+                .await
+                .ok()
+                .flatten()
+                .ok_or_else(|| {
+                    FlowyError::record_not_found().context(format!("Can't find the row: {}", changeset.row_id))
+                })?,
+        };
+        let cell_rev = match changed_cell {
+            Some(type_cell_data) => Some(CellRevision::new(type_cell_data)),
+            None => row_rev.cells.get(&changeset.field_id).cloned(),
+        };
+        let old_cell_rev = cell_rev.clone().unwrap_or_else(|| make_empty_cell_rev(&field_rev));
+        if is_cell_data {
+            return Ok((row_rev, old_cell_rev, changeset.type_cell_data.clone()));
+        }
+        let type_cell_data = apply_cell_data_changeset(
+            changeset.type_cell_data.clone(),
+            cell_rev,
+            &field_rev,
+            Some(self.cell_data_cache.clone()),
+        )
+        .map_err(|err| validation_error_with_row_id(err, &changeset.row_id))?;
+        Ok((row_rev, old_cell_rev, type_cell_data))
+    }
+
+    #[tracing::instrument(level = "trace", skip_all, err)]
+    pub async fn update_cell<T: ToCellChangesetString>(
+        &self,
+        row_id: String,
+        field_id: String,
+        cell_changeset: T,
+    ) -> FlowyResult<()> {
+        self.update_cell_with_changeset(&row_id, &field_id, cell_changeset)
+            .await
+    }
+
+    /// Returns the rows that the relation cell links to, along with the display text of their
+    /// primary cells. The references to the rows that were deleted from the linked database are
+    /// dropped from the cell.
+    pub async fn get_relation_cell_data(
+        &self,
+        row_id: &str,
+        field_id: &str,
+        resolver: &dyn RelatedRowResolver,
+    ) -> FlowyResult<RelationCellDataPB> {
+        let field_rev = self.get_relation_field_rev(field_id).await?;
+        let type_option = RelationTypeOptionPB::from(&field_rev);
+        let cell_data = match self.get_cell_rev(row_id, field_id).await? {
+            None => RelationCellData::default(),
+            Some(cell_rev) => {
+                let type_cell_data: TypeCellData = cell_rev.try_into()?;
+                type_option.decode_cell_str(type_cell_data.cell_str, &type_cell_data.field_type, &field_rev)?
+            }
+        };
+
+        let cell_data_pb = type_option.resolve_cell_data(&cell_data, resolver).await?;
+        if !type_option.database_id.is_empty() && cell_data_pb.rows.len() != cell_data.row_ids.len() {
+            let row_ids = cell_data_pb.rows.iter().map(|row| row.row_id.clone()).collect();
+            tracing::trace!("Drop the dangling references of the relation cell in row: {}", row_id);
+            let type_cell_data = TypeCellData::new(RelationCellData::new(row_ids).to_string(), FieldType::Relation);
+            self.apply_cell_data(row_id, field_id, type_cell_data.to_json()).await?;
+        }
+        Ok(cell_data_pb)
+    }
+
+    /// Returns all the rows of the database that the relation field links to. Each of them can be
+    /// linked by the cells of the field.
+    pub async fn get_relation_candidate_rows(
+        &self,
+        field_id: &str,
+        resolver: &dyn RelatedRowResolver,
+    ) -> FlowyResult<Vec<RelatedRowPB>> {
+        let field_rev = self.get_relation_field_rev(field_id).await?;
+        let type_option = RelationTypeOptionPB::from(&field_rev);
+        if type_option.database_id.is_empty() {
+            return Ok(vec![]);
+        }
+        resolver.get_candidate_rows(&type_option.database_id).await
+    }
+
+    /// Adds or removes the members of the person cell. The members that are not cached by the type
+    /// option yet are resolved by the `resolver` first, so the cell can be decoded, sorted and
+    /// grouped without calling the resolver. The members that can't be resolved are still added to
+    /// the cell, they are decoded with a placeholder.
+    pub async fn update_person_cell(
+        &self,
+        row_id: &str,
+        field_id: &str,
+        changeset: PersonCellChangeset,
+        resolver: Option<&dyn MemberResolver>,
+    ) -> FlowyResult<()> {
+        let field_rev = self.get_person_field_rev(field_id).await?;
+        if let Some(resolver) = resolver {
+            let type_option = PersonTypeOptionPB::from(&field_rev);
+            let uncached_member_ids = changeset
+                .insert_member_ids
+                .iter()
+                .filter(|member_id| !type_option.members.iter().any(|member| &member.id == *member_id))
+                .cloned()
+                .collect::<Vec<String>>();
+            if !uncached_member_ids.is_empty() {
+                let members = resolver.resolve_members(&uncached_member_ids).await?;
+                self.cache_person_members(field_id, members, false).await?;
+            }
+        }
+        self.update_cell_with_changeset(row_id, field_id, changeset).await
+    }
+
+    /// Resolves the members that are referenced by the cells of the person field again, and caches
+    /// them in the type option. The members that can't be resolved anymore are removed from the
+    /// type option, so their cells are decoded with a placeholder.
+    pub async fn refresh_person_members(&self, field_id: &str, resolver: &dyn MemberResolver) -> FlowyResult<()> {
+        let field_rev = self.get_person_field_rev(field_id).await?;
+        let type_option = PersonTypeOptionPB::from(&field_rev);
+        let mut member_ids = type_option
+            .members
+            .iter()
+            .map(|member| member.id.clone())
+            .collect::<Vec<String>>();
+        for row_rev in self.block_manager.get_row_revs().await? {
+            let cell_data = match row_rev.cells.get(field_id).map(TypeCellData::try_from) {
+                Some(Ok(type_cell_data)) if type_cell_data.is_person() => {
+                    PersonCellData::from_cell_str(&type_cell_data.cell_str)?
+                }
+                _ => continue,
+            };
+            for member_id in cell_data.member_ids {
+                if !member_ids.contains(&member_id) {
+                    member_ids.push(member_id);
+                }
+            }
+        }
+
+        let members = if member_ids.is_empty() {
+            vec![]
+        } else {
+            resolver.resolve_members(&member_ids).await?
+        };
+        self.cache_person_members(field_id, members, true).await
+    }
+
+    /// Returns all the members of the workspace. Each of them can be referenced by the cells of the
+    /// person field.
+    pub async fn get_person_candidates(
+        &self,
+        field_id: &str,
+        resolver: &dyn MemberResolver,
+    ) -> FlowyResult<Vec<MemberPB>> {
+        let _ = self.get_person_field_rev(field_id).await?;
+        resolver.get_members().await
+    }
+
+    /// Caches the members in the type option of the person field. The cached members are replaced
+    /// with the `members` if `replace` is true, otherwise the `members` are merged into them.
+    async fn cache_person_members(&self, field_id: &str, members: Vec<MemberPB>, replace: bool) -> FlowyResult<()> {
+        self.modify_field_rev(field_id, |field_rev| {
+            let mut type_option = PersonTypeOptionPB::from(&*field_rev);
+            let is_changed = if replace {
+                let is_changed = type_option.members != members;
+                type_option.members = members;
+                is_changed
+            } else {
+                type_option.insert_members(members)
+            };
+            if !is_changed {
+                return Ok(None);
+            }
+            field_rev.insert_type_option(&type_option);
+            Ok(Some(()))
+        })
+        .await
+    }
+
+    /// Returns the rows of this database with the display text of their primary cells. All the
+    /// rows are returned if the `row_ids` is None. Otherwise, the rows are returned in the order of
+    /// the ids, and the ids of the rows that don't exist are skipped.
+    pub async fn get_related_rows(&self, row_ids: Option<&[String]>) -> FlowyResult<Vec<RelatedRowPB>> {
+        let primary_field_rev = self
+            .database_pad
+            .read()
+            .await
+            .get_fields()
+            .iter()
+            .find(|field_rev| field_rev.is_primary)
+            .cloned();
+        let row_revs = self
+            .get_blocks(None)
+            .await?
+            .into_iter()
+            .flat_map(|block| block.row_revs)
+            .collect::<Vec<Arc<RowRevision>>>();
+        let row_revs = match row_ids {
+            None => row_revs,
+            Some(row_ids) => {
+                let row_rev_by_id = row_revs
+                    .into_iter()
+                    .map(|row_rev| (row_rev.id.clone(), row_rev))
+                    .collect::<HashMap<String, Arc<RowRevision>>>();
+                row_ids
+                    .iter()
+                    .flat_map(|row_id| row_rev_by_id.get(row_id).cloned())
+                    .collect()
+            }
+        };
+
+        let related_rows = row_revs
+            .iter()
+            .map(|row_rev| {
+                let name = primary_field_rev
+                    .as_ref()
+                    .and_then(|field_rev| {
+                        let cell_rev = get_cell_rev_from_row(row_rev, field_rev)?;
+                        let type_cell_data: TypeCellData = cell_rev.try_into().ok()?;
+                        let field_type: FieldType = field_rev.ty.into();
+                        Some(stringify_cell_data(
+                            type_cell_data.cell_str,
+                            &field_type,
+                            &field_type,
+                            field_rev,
+                        ))
+                    })
+                    .unwrap_or_default();
+                RelatedRowPB {
+                    row_id: row_rev.id.clone(),
+                    name,
+                }
+            })
+            .collect();
+        Ok(related_rows)
+    }
+
+    /// Copies the file into the app's storage directory by the `AttachmentStore`, and appends it to
+    /// the attachment cell. The copied file is deleted if the cell can't be updated.
+    ///
+    /// The file name of the `source_path` is used if the `name` is empty.
+    pub async fn import_attachment(
+        &self,
+        row_id: &str,
+        field_id: &str,
+        source_path: &str,
+        name: &str,
+        mime_type: &str,
+    ) -> FlowyResult<AttachmentPB> {
+        let field_rev = self
+            .get_field_rev(field_id)
+            .await
+            .ok_or_else(|| FlowyError::record_not_found().context(format!("Field with id:{} not found", field_id)))?;
+        let field_type: FieldType = field_rev.ty.into();
+        if !field_type.is_attachment() {
+            let msg = format!("The field with id:{} is not an attachment field", field_id);
+            return Err(FlowyError::new(ErrorCode::FieldInvalidOperation, &msg));
+        }
+        if self.get_row_rev(row_id).await?.is_none() {
+            return Err(FlowyError::record_not_found().context(format!("Row with id:{} not found", row_id)));
+        }
+        let store = self
+            .attachment_store
+            .get()
+            .ok_or_else(|| FlowyError::internal().context("The attachment store is not set"))?;
+
+        let attachment_id = gen_attachment_id();
+        let stored_file = store.import_file(&attachment_id, source_path).await?;
+        let name = match name.trim() {
+            "" => std::path::Path::new(source_path)
+                .file_name()
+                .map(|file_name| file_name.to_string_lossy().to_string())
+                .unwrap_or_else(|| source_path.to_owned()),
+            name => name.to_owned(),
+        };
+        let attachment = AttachmentPB {
+            id: attachment_id,
+            name,
+            size: stored_file.size,
+            mime_type: mime_type.to_owned(),
+            path: stored_file.path,
+            url: "".to_owned(),
+        };
+        let changeset = AttachmentCellChangeset::from_insert_attachments(vec![attachment.clone()]);
+        if let Err(e) = self.update_cell_with_changeset(row_id, field_id, changeset).await {
+            if let Err(err) = store.delete_file(&attachment).await {
+                tracing::error!("Delete the file of attachment:{} failed: {:?}", attachment.id, err);
+            }
+            return Err(e);
+        }
+        Ok(attachment)
+    }
+
+    /// Returns the attachments of the database, including the attachments of the rows in the
+    /// trash. The attachment that is referenced by multiple cells is returned once.
+    pub async fn get_attachments(&self) -> FlowyResult<Vec<AttachmentPB>> {
+        let (field_revs, trashed_rows) = {
+            let database_pad = self.database_pad.read().await;
+            (database_pad.get_field_revs(None)?, database_pad.get_trashed_rows())
+        };
+        let mut row_revs = self.block_manager.get_row_revs().await?;
+        row_revs.extend(trashed_rows.iter().map(|trashed_row| Arc::new(trashed_row.row.clone())));
+        Ok(referenced_attachments(&field_revs, &row_revs))
+    }
+
+    /// Deletes the stored files of the attachments that are not referenced by any cell anymore.
+    /// The failures are only logged because the cells were updated already.
+    async fn delete_orphaned_attachments(&self, attachments: Vec<AttachmentPB>) {
+        if !attachments.iter().any(|attachment| attachment.is_local()) {
+            return;
+        }
+        let store = match self.attachment_store.get() {
+            None => return,
+            Some(store) => store,
+        };
+        let referenced_ids = match self.get_attachments().await {
+            Ok(referenced_attachments) => referenced_attachments
+                .into_iter()
+                .map(|attachment| attachment.id)
+                .collect::<HashSet<String>>(),
+            Err(e) => {
+                tracing::error!("Get the attachments of database:{} failed: {:?}", self.database_id, e);
+                return;
+            }
+        };
+
+        let mut deleted_ids = HashSet::new();
+        for attachment in attachments {
+            if !attachment.is_local()
+                || referenced_ids.contains(&attachment.id)
+                || !deleted_ids.insert(attachment.id.clone())
+            {
+                continue;
+            }
+            if let Err(e) = store.delete_file(&attachment).await {
+                tracing::error!("Delete the file of attachment:{} failed: {:?}", attachment.id, e);
+            }
+        }
+    }
+
+    async fn get_relation_field_rev(&self, field_id: &str) -> FlowyResult<Arc<FieldRevision>> {
+        let field_rev = self
+            .get_field_rev(field_id)
+            .await
+            .ok_or_else(|| FlowyError::record_not_found().context(format!("Field with id:{} not found", field_id)))?;
+        let field_type: FieldType = field_rev.ty.into();
+        if !field_type.is_relation() {
+            let msg = format!("The field with id:{} is not a relation field", field_id);
+            return Err(FlowyError::new(ErrorCode::FieldInvalidOperation, &msg));
+        }
+        Ok(field_rev)
+    }
+
+    async fn get_person_field_rev(&self, field_id: &str) -> FlowyResult<Arc<FieldRevision>> {
+        let field_rev = self
+            .get_field_rev(field_id)
+            .await
+            .ok_or_else(|| FlowyError::record_not_found().context(format!("Field with id:{} not found", field_id)))?;
+        let field_type: FieldType = field_rev.ty.into();
+        if !field_type.is_person() {
+            let msg = format!("The field with id:{} is not a person field", field_id);
+            return Err(FlowyError::new(ErrorCode::FieldInvalidOperation, &msg));
+        }
+        Ok(field_rev)
+    }
+
+    pub async fn get_block_meta_revs(&self) -> FlowyResult<Vec<Arc<GridBlockMetaRevision>>> {
+        let block_meta_revs = self.database_pad.read().await.get_block_meta_revs();
+        Ok(block_meta_revs)
+    }
+
+    /// Returns the number of the rows of the database. It's answered from the row counts of the
+    /// blocks, so the rows are not loaded.
+    pub async fn row_count(&self) -> usize {
+        self.row_counts_per_block()
+            .await
+            .iter()
+            .map(|(_, row_count)| row_count)
+            .sum()
+    }
+
+    /// Returns the number of the rows of each block, together with the id of the block, in the
+    /// order of the blocks.
+    pub async fn row_counts_per_block(&self) -> Vec<(String, usize)> {
+        self.database_pad
+            .read()
+            .await
+            .get_block_meta_revs()
+            .iter()
+            .map(|block_meta_rev| {
+                (
+                    block_meta_rev.block_id.clone(),
+                    block_meta_rev.row_count.max(0) as usize,
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the number of the rows that the view displays after applying its filters.
+    pub async fn visible_row_count(&self, view_id: &str) -> FlowyResult<usize> {
+        self.view_manager.visible_row_count(view_id).await
+    }
+
+    pub async fn get_blocks(&self, block_ids: Option<Vec<String>>) -> FlowyResult<Vec<DatabaseBlockRowRevision>> {
         let block_ids = match block_ids {
             None => self
                 .database_pad
@@ -569,28 +2817,94 @@ impl DatabaseRevisionEditor {
         Ok(blocks)
     }
 
-    pub async fn delete_rows(&self, block_rows: Vec<DatabaseBlockRow>) -> FlowyResult<()> {
-        let changesets = self.block_manager.delete_rows(block_rows).await?;
-        for changeset in changesets {
-            self.update_block(changeset).await?;
+    /// Moves the rows to the trash in a batch with one revision per block. The rows are removed
+    /// from their blocks, so they're excluded from the views, and the row counts of the blocks are
+    /// updated together with the trash in one revision of the database. The ids of the rows that
+    /// can't be found are skipped and returned in the result.
+    ///
+    /// The trashed rows are notified once, with their positions before they were deleted, and
+    /// they're restored together when undoing. They're permanently deleted by [Self::purge_trash].
+    pub async fn delete_rows(&self, row_ids: Vec<String>) -> FlowyResult<DeleteRowsResultPB> {
+        let insert_row_actions = self.make_insert_row_actions(&row_ids).await;
+        let mut prev_row_ids = insert_row_actions
+            .iter()
+            .filter_map(|action| match action {
+                DatabaseEditAction::InsertRow { row_rev, prev_row_id } => {
+                    Some((row_rev.id.clone(), prev_row_id.clone()))
+                }
+                _ => None,
+            })
+            .collect::<HashMap<String, Option<String>>>();
+        let block_meta_revs = self.database_pad.read().await.get_block_meta_revs();
+        let (deleted_blocks, skipped_row_ids) = self.block_manager.delete_rows(row_ids).await?;
+
+        let mut changesets = vec![];
+        let mut deleted_rows = vec![];
+        let mut deleted_row_revs = vec![];
+        for deleted_block in deleted_blocks {
+            let start_row_index = start_row_index_of_block(&block_meta_revs, &deleted_block.block_id);
+            for (index, row_rev) in deleted_block.rows {
+                deleted_rows.push(DeletedRowPB {
+                    row_id: row_rev.id.clone(),
+                    block_id: deleted_block.block_id.clone(),
+                    index: (start_row_index + index) as i32,
+                    document_id: row_rev.document_id.clone(),
+                });
+                deleted_row_revs.push(row_rev);
+            }
+            changesets.push(GridBlockMetaRevisionChangeset::from_row_count(
+                deleted_block.block_id,
+                deleted_block.row_count,
+            ));
         }
-        Ok(())
+        deleted_rows.sort_by_key(|deleted_row| deleted_row.index);
+        if !deleted_rows.is_empty() {
+            let deleted_at = timestamp();
+            let trashed_rows = deleted_rows
+                .iter()
+                .flat_map(|deleted_row| {
+                    deleted_row_revs
+                        .iter()
+                        .find(|row_rev| row_rev.id == deleted_row.row_id)
+                        .map(|row_rev| TrashedRowRevision {
+                            row: row_rev.as_ref().clone(),
+                            prev_row_id: prev_row_ids.remove(&row_rev.id).flatten(),
+                            deleted_at,
+                        })
+                })
+                .collect::<Vec<TrashedRowRevision>>();
+            self.modify(|grid_pad| Ok(grid_pad.trash_rows(changesets, trashed_rows)?))
+                .await?;
+            self.view_manager.did_delete_rows(&deleted_row_revs).await;
+            send_notification(&self.database_id, DatabaseNotification::DidTrashRows)
+                .payload(RepeatedDeletedRowPB::from(deleted_rows.clone()))
+                .send();
+        }
+        self.record_edit(insert_row_actions);
+        Ok(DeleteRowsResultPB {
+            deleted_rows,
+            skipped_row_ids,
+        })
     }
 
     pub async fn get_database(&self, view_id: &str) -> FlowyResult<DatabasePB> {
+        let field_revs = self.get_view_field_revs(view_id, None).await?;
+        let fields = field_revs.iter().map(FieldIdPB::from).collect();
         let pad = self.database_pad.read().await;
-        let fields = pad.get_field_revs(None)?.iter().map(FieldIdPB::from).collect();
         let mut all_rows = vec![];
         for block_rev in pad.get_block_meta_revs() {
             if let Ok(rows) = self.get_row_pbs(view_id, &block_rev.block_id).await {
                 all_rows.extend(rows);
             }
         }
+        let pinned_row_ids = self.view_manager.get_pinned_row_ids(view_id).await.unwrap_or_default();
+        move_pinned_rows_first(&pinned_row_ids, &mut all_rows, |row| row.id.as_str());
 
         Ok(DatabasePB {
             id: self.database_id.clone(),
             fields,
             rows: all_rows,
+            is_repairing: self.is_repairing(),
         })
     }
 
@@ -608,6 +2922,15 @@ impl DatabaseRevisionEditor {
             .collect())
     }
 
+    /// Returns the fields of the database with the conditions that their filters accept.
+    pub async fn get_filterable_fields(&self) -> FlowyResult<Vec<FilterableFieldPB>> {
+        let field_revs = self.get_field_revs(None).await?;
+        Ok(field_revs
+            .iter()
+            .map(|field_rev| FilterableFieldPB::from(field_rev.as_ref()))
+            .collect())
+    }
+
     pub async fn get_filters(&self, filter_id: FilterType) -> FlowyResult<Vec<Arc<FilterRevision>>> {
         self.view_manager.get_filters(&filter_id).await
     }
@@ -622,6 +2945,71 @@ impl DatabaseRevisionEditor {
         Ok(())
     }
 
+    /// Returns the groups that combine the filters of the view.
+    pub async fn get_filter_tree(&self, view_id: &str) -> FlowyResult<FilterGroupPB> {
+        let filter_tree = self.view_manager.get_filter_tree(view_id).await?;
+        Ok(FilterGroupPB::from(&filter_tree))
+    }
+
+    pub async fn update_filter_tree(&self, params: UpdateFilterTreeParams) -> FlowyResult<()> {
+        self.view_manager.update_filter_tree(params).await
+    }
+
+    /// Returns the filter sets of the view in the order they were first saved.
+    pub async fn get_filter_sets(&self, view_id: &str) -> FlowyResult<RepeatedFilterSetPB> {
+        let filter_sets = self.view_manager.get_filter_sets(view_id).await?;
+        Ok(RepeatedFilterSetPB::from(filter_sets))
+    }
+
+    /// Saves the current filters of the view as the filter set, the filter set with the same name
+    /// is replaced.
+    pub async fn save_filter_set(&self, params: FilterSetParams) -> FlowyResult<()> {
+        self.view_manager.save_filter_set(params).await
+    }
+
+    /// Replaces the filters of the view with the filters of the filter set. Returns the filters
+    /// that were skipped because their fields were deleted or changed.
+    pub async fn apply_filter_set(&self, params: FilterSetParams) -> FlowyResult<AppliedFilterSetPB> {
+        let skipped_filter_revs = self.view_manager.apply_filter_set(params).await?;
+        Ok(AppliedFilterSetPB {
+            skipped_filters: skipped_filter_revs.iter().map(FilterPB::from).collect(),
+        })
+    }
+
+    pub async fn delete_filter_set(&self, params: FilterSetParams) -> FlowyResult<()> {
+        self.view_manager.delete_filter_set(params).await
+    }
+
+    /// Sets the search query of the view, the query is not saved with the view.
+    pub async fn set_search_query(&self, view_id: &str, query: Option<String>) -> FlowyResult<()> {
+        self.view_manager.set_search_query(view_id, query).await
+    }
+
+    /// Closes the view, the view is opened again when it's used next time.
+    pub async fn close_view(&self, view_id: &str) {
+        self.view_manager.close(view_id).await;
+    }
+
+    /// Returns the number of the times that a filter of the view evaluated a cell.
+    pub async fn get_filter_evaluation_count(&self, view_id: &str) -> FlowyResult<usize> {
+        self.view_manager.get_filter_evaluation_count(view_id).await
+    }
+
+    /// Returns the number of the times that two rows of the view were compared by the sorts.
+    pub async fn get_sort_comparison_count(&self, view_id: &str) -> FlowyResult<usize> {
+        self.view_manager.get_sort_comparison_count(view_id).await
+    }
+
+    /// Returns the fields of the database that can be used as the keys of the sorts.
+    pub async fn get_sortable_fields(&self) -> FlowyResult<Vec<FieldPB>> {
+        let field_revs = self.get_field_revs(None).await?;
+        Ok(field_revs
+            .into_iter()
+            .filter(|field_rev| FieldType::from(field_rev.ty).can_be_sorted())
+            .map(FieldPB::from)
+            .collect())
+    }
+
     pub async fn get_all_sorts(&self, view_id: &str) -> FlowyResult<Vec<SortPB>> {
         Ok(self
             .view_manager
@@ -636,114 +3024,519 @@ impl DatabaseRevisionEditor {
         self.view_manager.delete_all_sorts(view_id).await
     }
 
-    pub async fn delete_sort(&self, params: DeleteSortParams) -> FlowyResult<()> {
-        self.view_manager.delete_sort(params).await?;
-        Ok(())
+    pub async fn delete_sort(&self, params: DeleteSortParams) -> FlowyResult<()> {
+        self.view_manager.delete_sort(params).await?;
+        Ok(())
+    }
+
+    /// Moves the sort to the index of the sorts of the view. The rows are sorted by the sorts in
+    /// their order, the next sort is used only if the rows are equal in the previous sorts.
+    pub async fn get_sort_mode(&self, view_id: &str) -> FlowyResult<Option<SortModeRevision>> {
+        self.view_manager.get_sort_mode(view_id).await
+    }
+
+    /// Sets the special order of the rows of the view. The sorts are kept, but they're not used
+    /// until the mode is set back to `Field`.
+    pub async fn update_sort_mode(&self, params: UpdateSortModeParams) -> FlowyResult<()> {
+        self.view_manager.update_sort_mode(params).await
+    }
+
+    /// Shuffles the rows of the view again with a new seed, the view is switched to the shuffle
+    /// mode if it's not.
+    pub async fn resort_shuffle(&self, view_id: &str) -> FlowyResult<()> {
+        self.view_manager.resort_shuffle(view_id).await
+    }
+
+    pub async fn reorder_sort(&self, params: ReorderSortParams) -> FlowyResult<()> {
+        self.view_manager.reorder_sort(params).await
+    }
+
+    pub async fn create_or_update_sort(&self, params: AlterSortParams) -> FlowyResult<SortRevision> {
+        let sort_rev = self.view_manager.create_or_update_sort(params).await?;
+        Ok(sort_rev)
+    }
+
+    pub async fn insert_group(&self, params: InsertGroupParams) -> FlowyResult<()> {
+        self.view_manager.insert_or_update_group(params).await
+    }
+
+    pub async fn delete_group(&self, params: DeleteGroupParams) -> FlowyResult<()> {
+        self.view_manager.delete_group(params).await
+    }
+
+    /// Moves the row to the place of another row or to an index of all the rows. The row that is
+    /// moved to another block is deleted from its block once it's inserted into the other block,
+    /// and the row counts of both blocks are updated. The row of the sorted view is moved in the
+    /// manual order of the view instead, see `move_row_in_manual_order` of the view editor.
+    pub async fn move_row(&self, params: MoveRowParams) -> FlowyResult<()> {
+        if self.view_manager.move_row_in_manual_order(&params).await? {
+            return Ok(());
+        }
+        let MoveRowParams {
+            view_id: _,
+            from_row_id,
+            to,
+            remove_sorts: _,
+        } = params;
+
+        let (from_index_in_block, row_rev) = match self.block_manager.get_row_rev(&from_row_id).await? {
+            None => {
+                tracing::warn!("Move row failed, can not find the row:{}", from_row_id);
+                return Ok(());
+            }
+            Some(value) => value,
+        };
+        let block_meta_revs = self.database_pad.read().await.get_block_meta_revs();
+        let from_index = start_row_index_of_block(&block_meta_revs, &row_rev.block_id) + from_index_in_block;
+        let to_index = match to {
+            MoveRowTarget::RowId(to_row_id) => match self.block_manager.get_row_rev(&to_row_id).await.ok().flatten() {
+                None => {
+                    tracing::warn!("Can not find the to row id: {}", to_row_id);
+                    return Ok(());
+                }
+                Some((index_in_block, to_row_rev)) => {
+                    start_row_index_of_block(&block_meta_revs, &to_row_rev.block_id) + index_in_block
+                }
+            },
+            MoveRowTarget::Index(index) => {
+                let number_of_rows = block_meta_revs
+                    .iter()
+                    .map(|block_meta_rev| block_meta_rev.row_count.max(0) as usize)
+                    .sum::<usize>();
+                index.min(number_of_rows.saturating_sub(1))
+            }
+        };
+        if from_index == to_index {
+            return Ok(());
+        }
+
+        let from_block_id = row_rev.block_id.clone();
+        let (to_block_id, to_index_in_block) = locate_moved_row(&block_meta_revs, &from_block_id, to_index);
+        tracing::trace!("Move row from {} to {}", from_index, to_index);
+        if to_block_id == from_block_id {
+            self.block_manager
+                .move_row(row_rev, from_index_in_block, to_index_in_block)
+                .await?;
+        } else {
+            let (from_row_count, to_row_count) = self
+                .block_manager
+                .move_row_to_block(row_rev, &to_block_id, to_index_in_block)
+                .await?;
+            let changesets = vec![
+                GridBlockMetaRevisionChangeset::from_row_count(from_block_id.clone(), from_row_count),
+                GridBlockMetaRevisionChangeset::from_row_count(to_block_id.clone(), to_row_count),
+            ];
+            self.modify(|grid_pad| Ok(grid_pad.update_block_revs(changesets, vec![], None)?))
+                .await?;
+        }
+
+        send_notification(&self.database_id, DatabaseNotification::DidMoveRow)
+            .payload(MovedRowPB {
+                row_id: from_row_id,
+                from_block_id,
+                from_index: from_index as i32,
+                to_block_id,
+                to_index: to_index as i32,
+            })
+            .send();
+        Ok(())
+    }
+
+    pub async fn move_group_row(&self, params: MoveGroupRowParams) -> FlowyResult<()> {
+        let MoveGroupRowParams {
+            view_id,
+            from_row_id,
+            to_group_id,
+            to_row_id,
+            from_group_id,
+        } = params;
+
+        match self.block_manager.get_row_rev(&from_row_id).await? {
+            None => tracing::warn!("Move row failed, can not find the row:{}", from_row_id),
+            Some((_, row_rev)) => {
+                let block_manager = self.block_manager.clone();
+                let old_row_revs = vec![row_rev.clone()];
+                let field_revs = match self.notify_cell_values.load(Ordering::SeqCst) {
+                    true => self.get_field_revs(None).await?,
+                    false => vec![],
+                };
+                let cell_data_cache = self.cell_data_cache.clone();
+                self.view_manager
+                    .move_group_row(
+                        row_rev,
+                        from_group_id,
+                        to_group_id,
+                        to_row_id.clone(),
+                        |row_changeset| {
+                            to_fut(async move {
+                                tracing::trace!("Row data changed: {:?}", row_changeset);
+                                let cell_changesets = row_changeset
+                                    .cell_by_field_id
+                                    .into_iter()
+                                    .map(|(field_id, cell_rev)| CellChangesetPB {
+                                        database_id: view_id.clone(),
+                                        row_id: row_changeset.row_id.clone(),
+                                        field_id,
+                                        type_cell_data: cell_rev.type_cell_data,
+                                    })
+                                    .collect::<Vec<CellChangesetPB>>();
+
+                                for cell_changeset in cell_changesets {
+                                    let cell_change = make_cell_changes(
+                                        &old_row_revs,
+                                        &[cell_changeset.clone()],
+                                        &field_revs,
+                                        &cell_data_cache,
+                                    )
+                                    .pop();
+                                    match block_manager.update_cell(cell_changeset, cell_change).await {
+                                        Ok(_) => {}
+                                        Err(e) => tracing::error!("Apply cell changeset error:{:?}", e),
+                                    }
+                                }
+                            })
+                        },
+                    )
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn move_field(&self, params: MoveFieldParams) -> FlowyResult<()> {
+        let MoveFieldParams {
+            view_id: _,
+            field_id,
+            from_index,
+            to_index,
+        } = params;
+
+        self.modify(|grid_pad| Ok(grid_pad.move_field(&field_id, from_index as usize, to_index as usize)?))
+            .await?;
+        if let Some((index, field_rev)) = self.database_pad.read().await.get_field_rev(&field_id) {
+            let delete_field_order = FieldIdPB::from(field_id);
+            let insert_field = IndexFieldPB::from_field_rev(field_rev, index);
+            let notified_changeset = DatabaseFieldChangesetPB {
+                database_id: self.database_id.clone(),
+                inserted_fields: vec![insert_field],
+                deleted_fields: vec![delete_field_order],
+                updated_fields: vec![],
+            };
+
+            self.notify_did_update_grid(notified_changeset).await?;
+        }
+        Ok(())
+    }
+
+    /// Moves all the fields to the positions of their ids in the `ordered_field_ids` in one
+    /// revision. The `ordered_field_ids` must contain each id of the existing fields exactly once,
+    /// otherwise none of the fields is moved.
+    pub async fn reorder_fields(&self, ordered_field_ids: Vec<String>) -> FlowyResult<()> {
+        let old_field_ids = self
+            .database_pad
+            .read()
+            .await
+            .get_field_revs(None)?
+            .into_iter()
+            .map(|field_rev| field_rev.id.clone())
+            .collect::<Vec<String>>();
+        self.modify(|grid_pad| Ok(grid_pad.reorder_fields(&ordered_field_ids)?))
+            .await?;
+
+        // Only the fields whose positions are changed are notified.
+        let mut deleted_fields = vec![];
+        let mut inserted_fields = vec![];
+        let database_pad = self.database_pad.read().await;
+        for (index, field_id) in ordered_field_ids.iter().enumerate() {
+            if old_field_ids.get(index) == Some(field_id) {
+                continue;
+            }
+            if let Some((index, field_rev)) = database_pad.get_field_rev(field_id) {
+                deleted_fields.push(FieldIdPB::from(field_id.as_str()));
+                inserted_fields.push(IndexFieldPB::from_field_rev(field_rev, index));
+            }
+        }
+        drop(database_pad);
+
+        if !inserted_fields.is_empty() {
+            let notified_changeset = DatabaseFieldChangesetPB {
+                database_id: self.database_id.clone(),
+                inserted_fields,
+                deleted_fields,
+                updated_fields: vec![],
+            };
+            self.notify_did_update_grid(notified_changeset).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn get_database_name(&self) -> String {
+        self.database_pad.read().await.get_database_name().to_owned()
+    }
+
+    /// Renames the database and sends a `DatabaseNotification::DidUpdateDatabase` notification to
+    /// the views of the database. Returns an error if the name is empty.
+    pub async fn update_database_name(&self, name: &str) -> FlowyResult<()> {
+        if name.trim().is_empty() {
+            return Err(FlowyError::invalid_params().context("The name of the database should not be empty"));
+        }
+
+        let mut is_changed = false;
+        self.modify(|grid_pad| {
+            let changeset = grid_pad.update_database_name(name)?;
+            is_changed = changeset.is_some();
+            Ok(changeset)
+        })
+        .await?;
+
+        if is_changed {
+            send_notification(&self.database_id, DatabaseNotification::DidUpdateDatabase)
+                .payload(DatabaseNamePB {
+                    database_id: self.database_id.clone(),
+                    name: name.to_owned(),
+                })
+                .send();
+        }
+        Ok(())
+    }
+
+    /// Generates the snapshots of the database and its blocks.
+    ///
+    /// The snapshots of the blocks are generated first, so they are not newer than the snapshot of
+    /// the database. Check out the `restore_from_snapshot` for more details.
+    pub async fn generate_snapshot(&self) {
+        self.block_manager.generate_snapshot().await;
+        self.rev_manager.generate_snapshot().await;
+    }
+
+    /// Compacts the revisions of the database that are older than its latest snapshot. The latest
+    /// `keep_last` revisions and the revisions that are not synced yet are kept. Returns the number
+    /// of bytes that are reclaimed.
+    pub async fn compact_history(&self, keep_last: usize) -> FlowyResult<usize> {
+        self.rev_manager.compact_history(keep_last).await
+    }
+
+    /// Returns at most `limit` snapshots of the database, the latest one first.
+    pub async fn get_snapshots(&self, limit: usize) -> FlowyResult<Vec<DatabaseSnapshotMeta>> {
+        let snapshots = self.rev_manager.read_snapshots(limit).await?;
+        Ok(snapshots.iter().map(DatabaseSnapshotMeta::from).collect())
+    }
+
+    /// Rolls the database back to the snapshot with `rev_id`.
+    ///
+    /// Each block of the database is restored from its latest snapshot that was generated no later
+    /// than the database's snapshot, so the rows match the fields and the block metas of the snapshot.
+    /// The views are reloaded and a `DatabaseNotification::DidReloadDatabase` notification will be
+    /// sent to each view.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn restore_from_snapshot(&self, rev_id: i64) -> FlowyResult<()> {
+        let snapshot = self.rev_manager.read_snapshot(Some(rev_id)).await?.ok_or_else(|| {
+            FlowyError::record_not_found().context(format!("Can't find the snapshot with rev_id: {}", rev_id))
+        })?;
+        self.restore_database(snapshot, None).await
+    }
+
+    /// Returns the changes of the database from the revision with `from_rev_id` to the revision
+    /// with `to_rev_id`, including the changes of the fields and the rows of each block.
+    ///
+    /// The rows at a revision are read from the block revisions that were written no later than
+    /// the revision, with the precision of one second. The diff is flagged as approximate if the
+    /// database can't be reconstructed exactly at one of the revisions, for example, the
+    /// `from_rev_id` predates the oldest revision, in which case the nearest snapshot is used.
+    pub async fn diff_revisions(&self, from_rev_id: i64, to_rev_id: i64) -> FlowyResult<DatabaseDiffPB> {
+        if from_rev_id > to_rev_id {
+            return Err(FlowyError::invalid_params().context("The from_rev_id can't be greater than the to_rev_id"));
+        }
+        self.flush().await?;
+        let revisions = self.load_timestamped_revisions().await?;
+        let snapshots = self.rev_manager.read_snapshots(usize::MAX).await?;
+        let from = make_database_state(from_rev_id, &revisions, &snapshots)?;
+        let to = make_database_state(to_rev_id, &revisions, &snapshots)?;
+
+        let mut block_ids = from
+            .database_pad
+            .get_block_meta_revs()
+            .iter()
+            .map(|block_meta_rev| block_meta_rev.block_id.clone())
+            .collect::<Vec<String>>();
+        for block_meta_rev in to.database_pad.get_block_meta_revs() {
+            if !block_ids.contains(&block_meta_rev.block_id) {
+                block_ids.push(block_meta_rev.block_id.clone());
+            }
+        }
+        let mut blocks = vec![];
+        for block_id in block_ids {
+            let from_rows = self.block_manager.get_block_rows_at(&block_id, from.timestamp).await?;
+            let to_rows = self.block_manager.get_block_rows_at(&block_id, to.timestamp).await?;
+            blocks.extend(make_block_diff(&block_id, &from_rows, &to_rows));
+        }
+
+        let (inserted_fields, deleted_fields, updated_fields) = make_field_diffs(&from.database_pad, &to.database_pad);
+        Ok(DatabaseDiffPB {
+            database_id: self.database_id.clone(),
+            from_rev_id,
+            to_rev_id,
+            is_approximate: from.is_approximate || to.is_approximate,
+            inserted_fields,
+            deleted_fields,
+            updated_fields,
+            blocks,
+        })
     }
 
-    pub async fn create_or_update_sort(&self, params: AlterSortParams) -> FlowyResult<SortRevision> {
-        let sort_rev = self.view_manager.create_or_update_sort(params).await?;
-        Ok(sort_rev)
+    /// Creates a checkpoint with the name, which contains the current fields and rows of the
+    /// database. The checkpoint replaces the existing one with the same name.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn create_checkpoint(&self, name: &str) -> FlowyResult<DatabaseCheckpointMeta> {
+        if name.is_empty() {
+            return Err(FlowyError::invalid_data().context("The name of the checkpoint can't be empty"));
+        }
+        // The snapshots of the blocks are generated first, so they are not newer than the snapshot
+        // of the database.
+        self.block_manager.generate_named_snapshot(name).await?;
+        self.rev_manager.generate_named_snapshot(name).await?;
+        let checkpoint = self
+            .rev_manager
+            .read_named_snapshot(name)
+            .await?
+            .ok_or_else(|| FlowyError::internal().context(format!("Failed to create the checkpoint: {}", name)))?;
+        Ok(DatabaseCheckpointMeta::from(&checkpoint))
     }
 
-    pub async fn insert_group(&self, params: InsertGroupParams) -> FlowyResult<()> {
-        self.view_manager.insert_or_update_group(params).await
+    /// Returns all the checkpoints of the database, the latest one first.
+    pub async fn list_checkpoints(&self) -> FlowyResult<Vec<DatabaseCheckpointMeta>> {
+        let checkpoints = self.rev_manager.read_named_snapshots().await?;
+        Ok(checkpoints.iter().map(DatabaseCheckpointMeta::from).collect())
     }
 
-    pub async fn delete_group(&self, params: DeleteGroupParams) -> FlowyResult<()> {
-        self.view_manager.delete_group(params).await
+    /// Rolls the database back to the checkpoint with the name. It works just like the
+    /// `restore_from_snapshot`, except the blocks are restored from the checkpoint too.
+    ///
+    /// A checkpoint of the current state is created before rolling back, which is named by the
+    /// `pre_rollback_checkpoint_name`. So the rollback can be reverted by rolling back to it.
+    #[tracing::instrument(level = "debug", skip(self), err)]
+    pub async fn rollback_to_checkpoint(&self, name: &str) -> FlowyResult<()> {
+        let checkpoint =
+            self.rev_manager.read_named_snapshot(name).await?.ok_or_else(|| {
+                FlowyError::record_not_found().context(format!("Can't find the checkpoint: {}", name))
+            })?;
+        self.create_checkpoint(&pre_rollback_checkpoint_name(name)).await?;
+        self.restore_database(checkpoint.snapshot, Some(name)).await
     }
 
-    pub async fn move_row(&self, params: MoveRowParams) -> FlowyResult<()> {
-        let MoveRowParams {
-            view_id: _,
-            from_row_id,
-            to_row_id,
-        } = params;
-
-        match self.block_manager.get_row_rev(&from_row_id).await? {
-            None => tracing::warn!("Move row failed, can not find the row:{}", from_row_id),
-            Some((_, row_rev)) => {
-                match (
-                    self.block_manager.index_of_row(&from_row_id).await,
-                    self.block_manager.index_of_row(&to_row_id).await,
-                ) {
-                    (Some(from_index), Some(to_index)) => {
-                        tracing::trace!("Move row from {} to {}", from_index, to_index);
-                        self.block_manager
-                            .move_row(row_rev.clone(), from_index, to_index)
-                            .await?;
-                    }
-                    (_, None) => tracing::warn!("Can not find the from row id: {}", from_row_id),
-                    (None, _) => tracing::warn!("Can not find the to row id: {}", to_row_id),
-                }
+    /// Applies the `revisions` to a copy of the database and reports whether they apply cleanly,
+    /// the conflicts and the changes they would make. Nothing is written and no notification is
+    /// sent, so the database is left untouched even if the revisions conflict with it.
+    pub async fn preview_revisions(&self, revisions: Vec<Revision>) -> FlowyResult<RevisionPreview> {
+        let mut local_revisions = HashMap::new();
+        for revision in &revisions {
+            if let Some(local_revision) = self.rev_manager.get_revision(revision.rev_id).await {
+                local_revisions.insert(revision.rev_id, local_revision);
             }
         }
-        Ok(())
+        let read_guard = self.database_pad.read().await;
+        let database_pad = read_guard.clone();
+        let latest_rev_id = self.rev_manager.rev_id();
+        drop(read_guard);
+        Ok(make_revision_preview(
+            database_pad,
+            latest_rev_id,
+            revisions,
+            &local_revisions,
+        ))
     }
 
-    pub async fn move_group_row(&self, params: MoveGroupRowParams) -> FlowyResult<()> {
-        let MoveGroupRowParams {
-            view_id,
-            from_row_id,
-            to_group_id,
-            to_row_id,
-        } = params;
-
-        match self.block_manager.get_row_rev(&from_row_id).await? {
-            None => tracing::warn!("Move row failed, can not find the row:{}", from_row_id),
-            Some((_, row_rev)) => {
-                let block_manager = self.block_manager.clone();
-                self.view_manager
-                    .move_group_row(row_rev, to_group_id, to_row_id.clone(), |row_changeset| {
-                        to_fut(async move {
-                            tracing::trace!("Row data changed: {:?}", row_changeset);
-                            let cell_changesets = row_changeset
-                                .cell_by_field_id
-                                .into_iter()
-                                .map(|(field_id, cell_rev)| CellChangesetPB {
-                                    database_id: view_id.clone(),
-                                    row_id: row_changeset.row_id.clone(),
-                                    field_id,
-                                    type_cell_data: cell_rev.type_cell_data,
-                                })
-                                .collect::<Vec<CellChangesetPB>>();
-
-                            for cell_changeset in cell_changesets {
-                                match block_manager.update_cell(cell_changeset).await {
-                                    Ok(_) => {}
-                                    Err(e) => tracing::error!("Apply cell changeset error:{:?}", e),
-                                }
-                            }
-                        })
-                    })
-                    .await?;
-            }
+    /// Returns the authors of the revisions of the object whose rev_ids are in the `range`, keyed
+    /// by rev_id. The `object_id` is the id of the database, one of its blocks or one of its views.
+    /// The revisions of the database are read if a view has the same id as the database. The
+    /// pending revisions are written to disk first.
+    pub async fn get_revision_authors(
+        &self,
+        object_id: &str,
+        range: RevisionRange,
+    ) -> FlowyResult<BTreeMap<i64, RevisionAuthor>> {
+        self.flush().await?;
+        if object_id == self.database_id {
+            return self
+                .rev_stores
+                .make_store(GridRevisionObject::Database, object_id)?
+                .read_revision_authors(&range);
         }
-        Ok(())
+
+        let is_block = self
+            .database_pad
+            .read()
+            .await
+            .get_block_meta_revs()
+            .iter()
+            .any(|block_meta_rev| block_meta_rev.block_id == object_id);
+        let object = if is_block {
+            GridRevisionObject::Block
+        } else {
+            GridRevisionObject::View
+        };
+        self.rev_stores
+            .make_store(object, object_id)?
+            .read_revision_authors(&range)
     }
 
-    pub async fn move_field(&self, params: MoveFieldParams) -> FlowyResult<()> {
-        let MoveFieldParams {
-            view_id: _,
-            field_id,
-            from_index,
-            to_index,
-        } = params;
+    /// Returns the revisions of the database that are stored on disk, with the time when each of
+    /// them was written. The pending revisions are written to disk first.
+    async fn load_timestamped_revisions(&self) -> FlowyResult<Vec<TimestampedRevision>> {
+        self.rev_manager.flush().await?;
+        let range = RevisionRange {
+            start: 0,
+            end: i64::MAX,
+        };
+        let authors = self
+            .rev_stores
+            .make_store(GridRevisionObject::Database, &self.database_id)?
+            .read_revision_authors(&range)?;
+        let revisions = self
+            .rev_manager
+            .load_revisions()
+            .await?
+            .into_iter()
+            .map(|revision| TimestampedRevision {
+                timestamp: authors
+                    .get(&revision.rev_id)
+                    .map(|author| author.timestamp)
+                    .unwrap_or(0),
+                revision,
+            })
+            .collect::<Vec<TimestampedRevision>>();
+        Ok(revisions)
+    }
 
-        self.modify(|grid_pad| Ok(grid_pad.move_field(&field_id, from_index as usize, to_index as usize)?))
-            .await?;
-        if let Some((index, field_rev)) = self.database_pad.read().await.get_field_rev(&field_id) {
-            let delete_field_order = FieldIdPB::from(field_id);
-            let insert_field = IndexFieldPB::from_field_rev(field_rev, index);
-            let notified_changeset = DatabaseFieldChangesetPB {
-                database_id: self.database_id.clone(),
-                inserted_fields: vec![insert_field],
-                deleted_fields: vec![delete_field_order],
-                updated_fields: vec![],
-            };
+    /// Resets the database to the snapshot. The blocks are restored from their snapshots with the
+    /// `checkpoint_name` if it's not None.
+    async fn restore_database(&self, snapshot: RevisionSnapshot, checkpoint_name: Option<&str>) -> FlowyResult<()> {
+        let timestamp = snapshot.timestamp;
+        let mut database_pad = self.database_pad.write().await;
+        *database_pad = self.rev_manager.restore_snapshot::<GridRevisionSerde>(snapshot).await?;
+        for block_meta_rev in database_pad.get_block_meta_revs() {
+            match checkpoint_name {
+                None => {
+                    self.block_manager
+                        .restore_block_from_snapshot(&block_meta_rev.block_id, timestamp)
+                        .await?
+                }
+                Some(name) => {
+                    self.block_manager
+                        .restore_block_from_named_snapshot(&block_meta_rev.block_id, name, timestamp)
+                        .await?
+                }
+            }
+        }
+        drop(database_pad);
+        *self.last_modified.write() = Instant::now();
 
-            self.notify_did_update_grid(notified_changeset).await?;
+        let mut view_ids = self.view_manager.reload_views().await;
+        if !view_ids.contains(&self.database_id) {
+            view_ids.push(self.database_id.clone());
+        }
+        for view_id in view_ids {
+            send_notification(&view_id, DatabaseNotification::DidReloadDatabase).send();
         }
         Ok(())
     }
@@ -782,16 +3575,22 @@ impl DatabaseRevisionEditor {
 
     #[tracing::instrument(level = "trace", skip_all, err)]
     pub async fn load_groups(&self) -> FlowyResult<RepeatedGroupPB> {
-        self.view_manager.load_groups().await
+        self.view_manager.load_groups(false).await
+    }
+
+    /// Same as `load_groups`, but the hidden groups are returned too.
+    pub async fn load_all_groups(&self) -> FlowyResult<RepeatedGroupPB> {
+        self.view_manager.load_groups(true).await
     }
 
     async fn create_row_rev(&self) -> FlowyResult<RowRevision> {
         let field_revs = self.database_pad.read().await.get_field_revs(None)?;
         let block_id = self.block_id().await?;
 
-        // insert empty row below the row whose id is upper_row_id
-        let row_rev = RowRevisionBuilder::new(&block_id, &field_revs).build();
-        Ok(row_rev)
+        // The cells of the new row are filled with the default values of the fields.
+        let mut builder = RowRevisionBuilder::new(&block_id, &field_revs);
+        builder.insert_default_cells();
+        Ok(builder.build())
     }
 
     async fn create_row_pb(&self, row_rev: RowRevision, start_row_id: Option<String>) -> FlowyResult<RowPB> {
@@ -807,6 +3606,373 @@ impl DatabaseRevisionEditor {
         Ok(row_pb)
     }
 
+    /// Inserts the row after the row with `prev_row_id` in its block, or at the beginning of its
+    /// block if `prev_row_id` is None.
+    async fn insert_row_after(&self, row_rev: RowRevision, prev_row_id: Option<String>) -> FlowyResult<RowPB> {
+        let row_id = row_rev.id.clone();
+        let is_first_row = prev_row_id.is_none();
+        let row_pb = self.create_row_pb(row_rev, prev_row_id).await?;
+        if is_first_row {
+            if let Some((index, row_rev)) = self.block_manager.get_row_rev(&row_id).await? {
+                if index != 0 {
+                    self.block_manager.move_row(row_rev, index, 0).await?;
+                }
+            }
+        }
+        Ok(row_pb)
+    }
+
+    /// Notifies the views that the row is inserted back after the row with `prev_row_id`. The row
+    /// is put into the groups that match its cells like the row that is created.
+    async fn did_restore_row(&self, row_pb: &RowPB, prev_row_id: Option<String>) {
+        let params = CreateRowParams {
+            database_id: self.database_id.clone(),
+            start_row_id: prev_row_id,
+            group_id: None,
+            layout: DatabaseViewLayout::Grid,
+        };
+        self.view_manager.did_create_row(row_pb, &params).await;
+        self.view_manager.did_update_row(None, &row_pb.id).await;
+    }
+
+    fn record_edit(&self, step: DatabaseEditStep) {
+        self.undo_stack.lock().record(step);
+    }
+
+    /// Records the `old_field_rev` if the field was changed.
+    async fn record_update_field(&self, old_field_rev: Arc<FieldRevision>) {
+        if let Some(field_rev) = self.get_field_rev(&old_field_rev.id).await {
+            if field_rev != old_field_rev {
+                self.record_edit(vec![DatabaseEditAction::UpdateField {
+                    field_rev: old_field_rev,
+                }]);
+            }
+        }
+    }
+
+    /// Applies the actions of the step in reverse order, and returns the step that reverts the
+    /// applied actions.
+    async fn apply_edit_step(&self, step: DatabaseEditStep) -> FlowyResult<DatabaseEditStep> {
+        let mut inverse_step = vec![];
+        for action in step.into_iter().rev() {
+            match self.apply_edit_action(action).await {
+                Ok(Some(inverse_action)) => inverse_step.push(inverse_action),
+                Ok(None) => {}
+                Err(err) => {
+                    // Revert the actions that were applied, so the step is applied entirely or
+                    // not at all.
+                    for inverse_action in inverse_step.into_iter().rev() {
+                        if let Err(e) = self.apply_edit_action(inverse_action).await {
+                            tracing::error!("Revert the applied action failed: {:?}", e);
+                        }
+                    }
+                    return Err(err);
+                }
+            }
+        }
+        Ok(inverse_step)
+    }
+
+    /// Returns None if the action changes nothing. For example, deleting a row that doesn't exist.
+    async fn apply_edit_action(&self, action: DatabaseEditAction) -> FlowyResult<Option<DatabaseEditAction>> {
+        match action {
+            DatabaseEditAction::InsertRow { row_rev, prev_row_id } => {
+                let row_id = row_rev.id.clone();
+                let row_pb = self
+                    .insert_row_after(row_rev.as_ref().clone(), prev_row_id.clone())
+                    .await?;
+                self.modify(|grid_pad| Ok(grid_pad.remove_trashed_rows(&[row_id.clone()])?))
+                    .await?;
+                self.did_restore_row(&row_pb, prev_row_id).await;
+                Ok(Some(DatabaseEditAction::DeleteRow { row_id }))
+            }
+            DatabaseEditAction::DeleteRow { row_id } => {
+                let inverse_action = self.make_insert_row_actions(&[row_id.clone()]).await.pop();
+                if inverse_action.is_some() {
+                    self.remove_row(&row_id).await?;
+                }
+                Ok(inverse_action)
+            }
+            DatabaseEditAction::UpdateRow { changeset } => {
+                let inverse_action = self.make_update_row_action(&changeset).await?;
+                if inverse_action.is_some() {
+                    self.apply_row_changeset(changeset).await?;
+                }
+                Ok(inverse_action)
+            }
+            DatabaseEditAction::UpdateCell {
+                row_id,
+                field_id,
+                cell_rev,
+            } => {
+                let old_cell_rev = match self.get_cell_rev(&row_id, &field_id).await? {
+                    Some(old_cell_rev) => old_cell_rev,
+                    None => match self.get_field_rev(&field_id).await {
+                        None => return Ok(None),
+                        Some(field_rev) => make_empty_cell_rev(&field_rev),
+                    },
+                };
+                self.apply_cell_data(&row_id, &field_id, cell_rev.type_cell_data)
+                    .await?;
+                Ok(Some(DatabaseEditAction::UpdateCell {
+                    row_id,
+                    field_id,
+                    cell_rev: old_cell_rev,
+                }))
+            }
+            DatabaseEditAction::CreateField {
+                field_rev,
+                next_field_id,
+            } => {
+                let field_id = field_rev.id.clone();
+                if self.insert_field_rev(field_rev.as_ref().clone(), next_field_id).await? {
+                    Ok(Some(DatabaseEditAction::DeleteField { field_id }))
+                } else {
+                    Ok(None)
+                }
+            }
+            DatabaseEditAction::DeleteField { field_id } => {
+                let inverse_action = self.make_create_field_action(&field_id).await;
+                if inverse_action.is_some() {
+                    self.remove_field(&field_id).await?;
+                }
+                Ok(inverse_action)
+            }
+            DatabaseEditAction::UpdateField { field_rev } => {
+                let old_field_rev = match self.get_field_rev(&field_rev.id).await {
+                    None => return Ok(None),
+                    Some(old_field_rev) => old_field_rev,
+                };
+                let field_id = field_rev.id.clone();
+                self.modify(|grid| {
+                    Ok(grid.modify_field(&field_id, |field| {
+                        *field = field_rev.as_ref().clone();
+                        Ok(Some(()))
+                    })?)
+                })
+                .await?;
+                self.view_manager
+                    .did_update_view_field_type_option(&field_id, Some(old_field_rev.clone()))
+                    .await?;
+                self.notify_did_update_grid_field(&field_id).await?;
+                if old_field_rev.ty != field_rev.ty {
+                    self.schedule_convert_cells(&field_id).await;
+                }
+                Ok(Some(DatabaseEditAction::UpdateField {
+                    field_rev: old_field_rev,
+                }))
+            }
+        }
+    }
+
+    /// Returns the actions that insert the rows back to their current positions. The actions are
+    /// applied in reverse order, so the preceding rows that are going to be deleted together are
+    /// skipped when computing the position of a row.
+    async fn make_insert_row_actions(&self, row_ids: &[String]) -> DatabaseEditStep {
+        let mut actions = vec![];
+        for row_id in row_ids {
+            let row_rev = match self.block_manager.get_row_rev(row_id).await {
+                Ok(Some((_, row_rev))) => row_rev,
+                _ => continue,
+            };
+            let block_row_revs = match self.block_manager.get_block_editor(&row_rev.block_id).await {
+                Ok(editor) => editor.get_row_revs::<&str>(None).await.unwrap_or_default(),
+                Err(_) => continue,
+            };
+            let prev_row_id = block_row_revs
+                .iter()
+                .take_while(|block_row_rev| block_row_rev.id != row_rev.id)
+                .filter(|block_row_rev| !row_ids.contains(&block_row_rev.id))
+                .last()
+                .map(|block_row_rev| block_row_rev.id.clone());
+            actions.push(DatabaseEditAction::InsertRow { row_rev, prev_row_id });
+        }
+        actions
+    }
+
+    /// Returns the action that reverts the changeset. Returns None if the row doesn't exist.
+    async fn make_update_row_action(&self, changeset: &RowChangeset) -> FlowyResult<Option<DatabaseEditAction>> {
+        let row_rev = match self.get_row_rev(&changeset.row_id).await? {
+            None => return Ok(None),
+            Some(row_rev) => row_rev,
+        };
+        let mut inverse_changeset = RowChangeset::new(changeset.row_id.clone());
+        inverse_changeset.height = changeset.height.map(|_| row_rev.height);
+        inverse_changeset.visibility = changeset.visibility.map(|_| row_rev.visibility);
+        inverse_changeset.document_id = changeset
+            .document_id
+            .as_ref()
+            .map(|_| row_rev.document_id.clone().unwrap_or_default());
+        for field_id in changeset.cell_by_field_id.keys() {
+            let cell_rev = match row_rev.cells.get(field_id) {
+                Some(cell_rev) => cell_rev.clone(),
+                None => match self.get_field_rev(field_id).await {
+                    None => continue,
+                    Some(field_rev) => make_empty_cell_rev(&field_rev),
+                },
+            };
+            inverse_changeset.cell_by_field_id.insert(field_id.clone(), cell_rev);
+        }
+        if let Some(meta_changeset) = changeset.meta.clone() {
+            inverse_changeset.meta = row_rev.meta.clone().apply_changeset(meta_changeset);
+        }
+        Ok(Some(DatabaseEditAction::UpdateRow {
+            changeset: inverse_changeset,
+        }))
+    }
+
+    async fn make_create_field_action(&self, field_id: &str) -> Option<DatabaseEditAction> {
+        let database_pad = self.database_pad.read().await;
+        let (index, field_rev) = database_pad.get_field_rev(field_id)?;
+        let next_field_id = database_pad
+            .get_fields()
+            .get(index + 1)
+            .map(|field_rev| field_rev.id.clone());
+        Some(DatabaseEditAction::CreateField {
+            field_rev: field_rev.clone(),
+            next_field_id,
+        })
+    }
+
+    /// Inserts the field before the field with id `next_field_id`. Returns false if the field
+    /// already exists.
+    async fn insert_field_rev(&self, field_rev: FieldRevision, next_field_id: Option<String>) -> FlowyResult<bool> {
+        let field_id = field_rev.id.clone();
+        let mut is_inserted = false;
+        self.modify(|grid| {
+            let changeset = grid.create_field_rev(field_rev, next_field_id)?;
+            is_inserted = changeset.is_some();
+            Ok(changeset)
+        })
+        .await?;
+        if is_inserted {
+            self.notify_did_insert_grid_field(&field_id).await?;
+        }
+        Ok(is_inserted)
+    }
+
+    async fn remove_field(&self, field_id: &str) -> FlowyResult<()> {
+        self.modify(|grid_pad| Ok(grid_pad.delete_field_rev(field_id)?)).await?;
+        self.view_manager.did_delete_field(field_id).await;
+        let field_order = FieldIdPB::from(field_id);
+        let notified_changeset = DatabaseFieldChangesetPB::delete(&self.database_id, vec![field_order]);
+        self.notify_did_update_grid(notified_changeset).await?;
+        Ok(())
+    }
+
+    async fn remove_row(&self, row_id: &str) -> FlowyResult<()> {
+        let row_rev = self.block_manager.delete_row(row_id).await?;
+        tracing::trace!("Did delete row:{:?}", row_rev);
+        if let Some(row_rev) = row_rev {
+            self.view_manager.did_delete_row(row_rev).await;
+        }
+        Ok(())
+    }
+
+    async fn apply_row_changeset(&self, changeset: RowChangeset) -> FlowyResult<()> {
+        let row_id = changeset.row_id.clone();
+        let old_row = self.get_row_rev(&row_id).await?;
+        let is_meta_changed = changeset.meta.is_some();
+        self.block_manager.update_row(changeset).await?;
+        if is_meta_changed {
+            if let Some(row_rev) = self.get_row_rev(&row_id).await? {
+                if old_row.as_ref().map(|old_row| &old_row.meta) != Some(&row_rev.meta) {
+                    send_notification(&row_id, DatabaseNotification::DidUpdateRowMeta)
+                        .payload(RowMetaPB::from(&row_rev.meta))
+                        .send();
+                }
+            }
+        }
+        self.view_manager.did_update_row(old_row, &row_id).await;
+        Ok(())
+    }
+
+    async fn apply_cell_data(&self, row_id: &str, field_id: &str, type_cell_data: String) -> FlowyResult<()> {
+        let old_row_rev = self.get_row_rev(row_id).await?;
+        let cell_changeset = CellChangesetPB {
+            database_id: self.database_id.clone(),
+            row_id: row_id.to_owned(),
+            field_id: field_id.to_owned(),
+            type_cell_data,
+        };
+        let cell_change = self
+            .make_cell_changes(old_row_rev.iter().cloned().collect(), &[cell_changeset.clone()])
+            .await
+            .pop();
+        self.block_manager.update_cell(cell_changeset, cell_change).await?;
+        self.view_manager.did_update_row(old_row_rev, row_id).await;
+        Ok(())
+    }
+
+    /// Returns the old and new values of the cells that are changed by the changesets if the values
+    /// are included in the notifications, otherwise returns an empty list.
+    async fn make_cell_changes(
+        &self,
+        old_row_revs: Vec<Arc<RowRevision>>,
+        changesets: &[CellChangesetPB],
+    ) -> Vec<CellChangePB> {
+        if !self.notify_cell_values.load(Ordering::SeqCst) {
+            return vec![];
+        }
+        let field_revs = self.get_field_revs(None).await.unwrap_or_default();
+        make_cell_changes(&old_row_revs, changesets, &field_revs, &self.cell_data_cache)
+    }
+
+    /// Evaluates the formula fields of the rows, and writes the results that are different from the
+    /// cells. All the rows are evaluated if the `row_ids` is None. Only the formulas that reference
+    /// the changed fields are evaluated if the `changed_field_ids` is not None.
+    async fn recompute_formulas(
+        &self,
+        row_ids: Option<Vec<String>>,
+        changed_field_ids: Option<Vec<String>>,
+    ) -> FlowyResult<()> {
+        let evaluator = FormulaEvaluator::new(self.get_field_revs(None).await?);
+        if evaluator.is_empty() {
+            return Ok(());
+        }
+
+        let row_revs = match row_ids {
+            None => self
+                .get_blocks(None)
+                .await?
+                .into_iter()
+                .flat_map(|block| block.row_revs)
+                .collect::<Vec<Arc<RowRevision>>>(),
+            Some(row_ids) => {
+                let mut row_revs = vec![];
+                for row_id in row_ids {
+                    if let Some(row_rev) = self.get_row_rev(&row_id).await? {
+                        row_revs.push(row_rev);
+                    }
+                }
+                row_revs
+            }
+        };
+
+        for row_rev in row_revs {
+            for (field_id, cell_data) in evaluator.evaluate_row(&row_rev, changed_field_ids.clone()) {
+                tracing::trace!("Recompute the formula cell: {}:{}", row_rev.id, field_id);
+                let type_cell_data = TypeCellData::new(cell_data.to_string(), FieldType::Formula);
+                self.apply_cell_data(&row_rev.id, &field_id, type_cell_data.to_json())
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns a `FormulaCircularReference` error if the formulas reference themselves after the
+    /// field is inserted, or the field with the same id is replaced by it.
+    async fn check_formula_fields_with(&self, field_rev: &FieldRevision) -> FlowyResult<()> {
+        let mut field_revs = self
+            .get_field_revs(None)
+            .await?
+            .into_iter()
+            .filter(|other| other.id != field_rev.id)
+            .collect::<Vec<Arc<FieldRevision>>>();
+        field_revs.push(Arc::new(field_rev.clone()));
+        check_formula_fields_circular_reference(&field_revs)
+    }
+
     async fn modify<F>(&self, f: F) -> FlowyResult<()>
     where
         F: for<'a> FnOnce(&'a mut DatabaseRevisionPad) -> FlowyResult<Option<DatabaseRevisionChangeset>>,
@@ -822,6 +3988,7 @@ impl DatabaseRevisionEditor {
         let DatabaseRevisionChangeset { operations: delta, md5 } = change;
         let data = delta.json_bytes();
         let _ = self.rev_manager.add_local_revision(data, md5).await?;
+        *self.last_modified.write() = Instant::now();
         Ok(())
     }
 
@@ -842,6 +4009,29 @@ impl DatabaseRevisionEditor {
         Ok(())
     }
 
+    /// Notifies the fields are updated in one [DatabaseFieldChangesetPB], and then notifies each
+    /// of them.
+    #[tracing::instrument(level = "trace", skip_all, err)]
+    async fn notify_did_update_grid_fields(&self, field_ids: &[String]) -> FlowyResult<()> {
+        let updated_fields = self
+            .database_pad
+            .read()
+            .await
+            .get_field_revs(Some(field_ids.to_vec()))?
+            .into_iter()
+            .map(FieldPB::from)
+            .collect::<Vec<FieldPB>>();
+        let notified_changeset = DatabaseFieldChangesetPB::update(&self.database_id, updated_fields.clone());
+        self.notify_did_update_grid(notified_changeset).await?;
+
+        for updated_field in updated_fields {
+            send_notification(&updated_field.id, DatabaseNotification::DidUpdateField)
+                .payload(updated_field)
+                .send();
+        }
+        Ok(())
+    }
+
     #[tracing::instrument(level = "trace", skip_all, err)]
     async fn notify_did_update_grid_field(&self, field_id: &str) -> FlowyResult<()> {
         if let Some((_, field_rev)) = self
@@ -931,3 +4121,142 @@ impl JsonDeserializer for TypeOptionJsonDeserializer {
         Ok(json)
     }
 }
+
+/// Updates the `last_modified` whenever a row of the database's blocks was changed.
+fn listen_on_database_block_event(
+    mut block_event_rx: broadcast::Receiver<DatabaseBlockEvent>,
+    last_modified: Arc<parking_lot::RwLock<Instant>>,
+) {
+    tokio::spawn(async move {
+        loop {
+            match block_event_rx.recv().await {
+                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {
+                    *last_modified.write() = Instant::now();
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
+/// Returns the old and new values of the cells that are changed by the changesets, one for each
+/// cell. The changesets of the same cell are merged, only the last one is applied. The cells of
+/// the fields that aren't in the `field_revs` are skipped.
+fn make_cell_changes(
+    old_row_revs: &[Arc<RowRevision>],
+    changesets: &[CellChangesetPB],
+    field_revs: &[Arc<FieldRevision>],
+    cell_data_cache: &AtomicCellDataCache,
+) -> Vec<CellChangePB> {
+    let mut cell_changes: Vec<CellChangePB> = vec![];
+    for changeset in changesets {
+        let field_rev = match field_revs.iter().find(|field_rev| field_rev.id == changeset.field_id) {
+            None => continue,
+            Some(field_rev) => field_rev,
+        };
+        let (_, new_data) = get_type_cell_protobuf(
+            changeset.type_cell_data.clone(),
+            field_rev,
+            Some(cell_data_cache.clone()),
+        );
+        if let Some(cell_change) = cell_changes
+            .iter_mut()
+            .find(|cell_change| cell_change.row_id == changeset.row_id && cell_change.field_id == changeset.field_id)
+        {
+            cell_change.new_data = new_data.to_vec();
+            continue;
+        }
+
+        let old_data = old_row_revs
+            .iter()
+            .find(|row_rev| row_rev.id == changeset.row_id)
+            .and_then(|row_rev| get_cell_rev_from_row(row_rev, field_rev))
+            .map(|cell_rev| {
+                let (_, old_data) =
+                    get_type_cell_protobuf(cell_rev.type_cell_data, field_rev, Some(cell_data_cache.clone()));
+                old_data.to_vec()
+            })
+            .unwrap_or_default();
+        cell_changes.push(CellChangePB {
+            row_id: changeset.row_id.clone(),
+            field_id: changeset.field_id.clone(),
+            field_type: field_rev.ty.into(),
+            old_data,
+            new_data: new_data.to_vec(),
+        });
+    }
+    cell_changes
+}
+
+/// Returns the cell that restores a cell which didn't exist before the edit.
+fn make_empty_cell_rev(field_rev: &FieldRevision) -> CellRevision {
+    let field_type: FieldType = field_rev.ty.into();
+    CellRevision::new(TypeCellData::new("".to_owned(), field_type).to_json())
+}
+
+/// Returns a `FieldLocked` error if the field is locked.
+fn check_field_unlocked(field_rev: &FieldRevision) -> FlowyResult<()> {
+    if field_rev.locked {
+        let msg = format!("The field:{} is locked", field_rev.id);
+        return Err(FlowyError::field_locked().context(msg));
+    }
+    Ok(())
+}
+
+/// Returns the block that the rows inserted at the `start_index` of the database go to, and the
+/// index of the rows in the block. The index is None if the rows are appended to the block, which
+/// is the last block if the `start_index` is None or out of bounds.
+fn locate_block_of_row_index(
+    block_meta_revs: &[Arc<GridBlockMetaRevision>],
+    start_index: Option<usize>,
+) -> Option<(Arc<GridBlockMetaRevision>, Option<usize>)> {
+    let last_block_meta_rev = block_meta_revs.last()?;
+    if let Some(start_index) = start_index {
+        let mut start_row_index = 0;
+        for block_meta_rev in block_meta_revs {
+            let row_count = block_meta_rev.row_count.max(0) as usize;
+            if start_index <= start_row_index + row_count {
+                let index_in_block = start_index - start_row_index;
+                return Some((
+                    block_meta_rev.clone(),
+                    (index_in_block < row_count).then_some(index_in_block),
+                ));
+            }
+            start_row_index += row_count;
+        }
+    }
+    Some((last_block_meta_rev.clone(), None))
+}
+
+/// Returns the index of the first row of the block among all the rows of the database.
+fn start_row_index_of_block(block_meta_revs: &[Arc<GridBlockMetaRevision>], block_id: &str) -> usize {
+    block_meta_revs
+        .iter()
+        .take_while(|block_meta_rev| block_meta_rev.block_id != block_id)
+        .map(|block_meta_rev| block_meta_rev.row_count.max(0) as usize)
+        .sum()
+}
+
+/// Returns the block that the row is moved to and the index of the row in the block, so the row is
+/// at the `to_index` of all the rows once it's moved. The index in the block doesn't count the row
+/// itself if it's moved in its block.
+fn locate_moved_row(
+    block_meta_revs: &[Arc<GridBlockMetaRevision>],
+    from_block_id: &str,
+    to_index: usize,
+) -> (String, usize) {
+    let mut start_row_index = 0;
+    let mut last_block = (from_block_id.to_owned(), 0);
+    for block_meta_rev in block_meta_revs {
+        let mut row_count = block_meta_rev.row_count.max(0) as usize;
+        if block_meta_rev.block_id == from_block_id {
+            row_count = row_count.saturating_sub(1);
+        }
+        if to_index <= start_row_index + row_count {
+            return (block_meta_rev.block_id.clone(), to_index - start_row_index);
+        }
+        start_row_index += row_count;
+        last_block = (block_meta_rev.block_id.clone(), row_count);
+    }
+    last_block
+}
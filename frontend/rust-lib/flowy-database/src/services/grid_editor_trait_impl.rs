@@ -9,6 +9,7 @@ use flowy_client_sync::client_database::DatabaseRevisionPad;
 use flowy_task::TaskDispatcher;
 use grid_model::{FieldRevision, RowRevision};
 use lib_infra::future::{to_fut, Fut};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -76,6 +77,11 @@ impl DatabaseViewEditorDelegate for GridViewEditorDelegateImpl {
         to_fut(async move { block_manager.get_blocks(None).await.unwrap_or_default() })
     }
 
+    fn get_block_rev_ids(&self) -> Fut<HashMap<String, i64>> {
+        let block_manager = self.block_manager.clone();
+        to_fut(async move { block_manager.get_block_rev_ids() })
+    }
+
     fn get_task_scheduler(&self) -> Arc<RwLock<TaskDispatcher>> {
         self.task_scheduler.clone()
     }
@@ -25,6 +25,12 @@ pub trait GroupCustomize: Send + Sync {
     /// Returns a bool value to determine whether the group should contain this cell or not.
     fn can_group(&self, content: &str, cell_data: &Self::CellData) -> bool;
 
+    /// Returns true if the groups are decided by the date of today, for example, the `Today` group
+    /// of the date field.
+    fn is_relative_to_today(&self) -> bool {
+        false
+    }
+
     fn create_or_delete_group_when_cell_changed(
         &mut self,
         _row_rev: &RowRevision,
@@ -72,6 +78,12 @@ pub trait GroupControllerActions: Send + Sync {
     /// Remove the group with from_group_id and insert it to the index with to_group_id
     fn move_group(&mut self, from_group_id: &str, to_group_id: &str) -> FlowyResult<()>;
 
+    /// Collapses or expands the group, the state is kept until the group is deleted
+    fn set_group_collapsed(&mut self, group_id: &str, collapsed: bool) -> FlowyResult<()>;
+
+    /// Hides or shows the group, the rows are still grouped into the hidden group
+    fn set_group_visibility(&mut self, group_id: &str, visible: bool) -> FlowyResult<()>;
+
     /// Insert/Remove the row to the group if the corresponding cell data is changed
     fn did_update_group_row(
         &mut self,
@@ -92,6 +104,12 @@ pub trait GroupControllerActions: Send + Sync {
 
     /// Update the group if the corresponding field is changed
     fn did_update_group_field(&mut self, field_rev: &FieldRevision) -> FlowyResult<Option<GroupViewChangesetPB>>;
+
+    /// Returns true if the groups are decided by the date of today and the rows were grouped on
+    /// another day, so the rows should be grouped again.
+    fn is_outdated(&self) -> bool {
+        false
+    }
 }
 
 #[derive(Debug)]
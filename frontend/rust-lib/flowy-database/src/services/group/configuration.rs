@@ -1,6 +1,7 @@
 use crate::entities::{GroupPB, GroupViewChangesetPB, InsertedGroupPB};
 use crate::services::field::RowSingleCellData;
-use crate::services::group::{default_group_configuration, GeneratedGroupContext, Group};
+use crate::services::group::{default_group_configuration, local_today, GeneratedGroupContext, Group};
+use chrono::NaiveDate;
 use flowy_error::{FlowyError, FlowyResult};
 use grid_model::{
     FieldRevision, FieldTypeRevision, GroupConfigurationContentSerde, GroupConfigurationRevision, GroupRevision,
@@ -55,6 +56,9 @@ pub struct GroupContext<C> {
     /// Cache all the groups
     groups_map: IndexMap<String, Group>,
 
+    /// The date of today in local time when the rows were grouped
+    grouped_date: NaiveDate,
+
     /// A reader that implement the [GroupConfigurationReader] trait
     ///
     #[allow(dead_code)]
@@ -92,6 +96,7 @@ where
             view_id,
             field_rev,
             groups_map: IndexMap::new(),
+            grouped_date: local_today(),
             reader,
             writer,
             configuration,
@@ -99,6 +104,16 @@ where
         })
     }
 
+    /// Returns the content of the configuration, or None if the content was saved by the groups
+    /// of another field type and can't be read as the content of these groups.
+    pub(crate) fn get_content(&self) -> Option<C> {
+        C::from_json(&self.configuration.content).ok()
+    }
+
+    pub(crate) fn grouped_date(&self) -> NaiveDate {
+        self.grouped_date
+    }
+
     /// Returns the no `status` group
     ///
     /// We take the `id` of the `field` as the no status group id
@@ -142,12 +157,14 @@ where
     }
     #[tracing::instrument(level = "trace", skip(self), err)]
     pub(crate) fn add_new_group(&mut self, group_rev: GroupRevision) -> FlowyResult<InsertedGroupPB> {
-        let group = Group::new(
+        let mut group = Group::new(
             group_rev.id.clone(),
             self.field_rev.id.clone(),
             group_rev.name.clone(),
             group_rev.id.clone(),
         );
+        group.is_visible = group_rev.visible;
+        group.is_collapsed = group_rev.collapsed;
         self.groups_map.insert(group_rev.id.clone(), group);
         let (index, group) = self.get_group(&group_rev.id).unwrap();
         let insert_group = InsertedGroupPB {
@@ -294,7 +311,9 @@ where
                 .get(&group_rev.id)
                 .cloned()
                 .unwrap_or_else(|| "".to_owned());
-            let group = Group::new(group_rev.id, self.field_rev.id.clone(), group_rev.name, filter_content);
+            let mut group = Group::new(group_rev.id, self.field_rev.id.clone(), group_rev.name, filter_content);
+            group.is_visible = group_rev.visible;
+            group.is_collapsed = group_rev.collapsed;
             self.groups_map.insert(group.id.clone(), group);
         });
 
@@ -327,20 +346,30 @@ where
         }
     }
 
-    #[allow(dead_code)]
-    pub(crate) async fn hide_group(&mut self, group_id: &str) -> FlowyResult<()> {
-        self.mut_group_rev(group_id, |group_rev| {
-            group_rev.visible = false;
-        })?;
-        Ok(())
+    /// Collapses or expands the group, the state is saved in the [GroupRevision] of the group.
+    pub(crate) fn set_group_collapsed(&mut self, group_id: &str, collapsed: bool) -> FlowyResult<()> {
+        match self.groups_map.get_mut(group_id) {
+            None => Err(FlowyError::record_not_found().context(format!("Can not find the group: {}", group_id))),
+            Some(group) => {
+                group.is_collapsed = collapsed;
+                self.mut_group_rev(group_id, |group_rev| {
+                    group_rev.collapsed = collapsed;
+                })
+            }
+        }
     }
 
-    #[allow(dead_code)]
-    pub(crate) async fn show_group(&mut self, group_id: &str) -> FlowyResult<()> {
-        self.mut_group_rev(group_id, |group_rev| {
-            group_rev.visible = true;
-        })?;
-        Ok(())
+    /// Hides or shows the group, the rows of the hidden group are still kept in the group.
+    pub(crate) fn set_group_visibility(&mut self, group_id: &str, visible: bool) -> FlowyResult<()> {
+        match self.groups_map.get_mut(group_id) {
+            None => Err(FlowyError::record_not_found().context(format!("Can not find the group: {}", group_id))),
+            Some(group) => {
+                group.is_visible = visible;
+                self.mut_group_rev(group_id, |group_rev| {
+                    group_rev.visible = visible;
+                })
+            }
+        }
     }
 
     pub(crate) async fn get_all_cells(&self) -> Vec<RowSingleCellData> {
@@ -386,10 +415,11 @@ where
     }
 }
 
-/// Merge the new groups into old groups while keeping the order in the old groups
+/// Merge the new groups into old groups while keeping the order in the old groups, including the
+/// position of the `No status` group. The new groups are appended to the end.
 ///
 fn merge_groups(
-    no_status_group: Option<GroupRevision>,
+    mut no_status_group: Option<GroupRevision>,
     old_groups: Vec<GroupRevision>,
     new_groups: Vec<GroupRevision>,
 ) -> MergeGroupResult {
@@ -402,7 +432,13 @@ fn merge_groups(
 
     // The group is ordered in old groups. Add them before adding the new groups
     for old in old_groups {
-        if let Some(new) = new_group_map.remove(&old.id) {
+        if no_status_group
+            .as_ref()
+            .map(|group| group.id == old.id)
+            .unwrap_or(false)
+        {
+            merge_result.all_group_revs.push(no_status_group.take().unwrap());
+        } else if let Some(new) = new_group_map.remove(&old.id) {
             merge_result.all_group_revs.push(new.clone());
         } else {
             merge_result.deleted_group_revs.push(old);
@@ -416,7 +452,7 @@ fn merge_groups(
         merge_result.new_group_revs.push(group);
     }
 
-    // The `No status` group index is initialized to 0
+    // The `No status` group index is initialized to 0 if it's not in the old groups
     if let Some(no_status_group) = no_status_group {
         merge_result.all_group_revs.insert(0, no_status_group);
     }
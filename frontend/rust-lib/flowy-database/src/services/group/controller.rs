@@ -1,11 +1,14 @@
 use crate::entities::{GroupRowsNotificationPB, GroupViewChangesetPB, InsertedRowPB, RowPB};
-use crate::services::cell::{get_type_cell_protobuf, CellProtobufBlobParser, DecodedCellData};
+use crate::services::cell::{
+    get_cell_rev_from_row, get_type_cell_protobuf, CellProtobufBlobParser, DecodedCellData, TypeCellData,
+};
 
 use crate::services::group::action::{
     DidMoveGroupRowResult, DidUpdateGroupRowResult, GroupControllerActions, GroupCustomize,
 };
 use crate::services::group::configuration::GroupContext;
 use crate::services::group::entities::Group;
+use crate::services::group::local_today;
 use flowy_error::FlowyResult;
 use grid_model::{
     CellRevision, FieldRevision, GroupConfigurationContentSerde, GroupRevision, RowChangeset, RowRevision,
@@ -53,6 +56,9 @@ pub struct MoveGroupRowContext<'a> {
     pub row_rev: &'a RowRevision,
     pub row_changeset: &'a mut RowChangeset,
     pub field_rev: &'a FieldRevision,
+    /// The group that the row is dragged out of. The row of the multi-select cell might be in many
+    /// groups, only the option of this group is replaced. None if the client doesn't tell.
+    pub from_group_id: Option<&'a str>,
     pub to_group_id: &'a str,
     pub to_row_id: Option<String>,
 }
@@ -88,70 +94,29 @@ where
         })
     }
 
-    // https://stackoverflow.com/questions/69413164/how-to-fix-this-clippy-warning-needless-collect
-    #[allow(clippy::needless_collect)]
-    fn update_no_status_group(
-        &mut self,
-        row_rev: &RowRevision,
-        other_group_changesets: &[GroupRowsNotificationPB],
-    ) -> Option<GroupRowsNotificationPB> {
+    /// Puts the row into the no status group if no other group contains it, and removes it from
+    /// the no status group otherwise. The groups are checked after they are updated instead of
+    /// being inferred from the changesets, because the row might stay in one group while it's
+    /// removed from the others, for example, the row of the multi-select cell.
+    fn update_no_status_group(&mut self, row_rev: &RowRevision) -> Option<GroupRowsNotificationPB> {
+        let no_status_group_id = self.group_ctx.get_no_status_group()?.id.clone();
+        let is_grouped = self
+            .group_ctx
+            .groups()
+            .into_iter()
+            .any(|group| group.id != no_status_group_id && group.contains_row(&row_rev.id));
         let no_status_group = self.group_ctx.get_mut_no_status_group()?;
 
-        // [other_group_inserted_row] contains all the inserted rows except the default group.
-        let other_group_inserted_row = other_group_changesets
-            .iter()
-            .flat_map(|changeset| &changeset.inserted_rows)
-            .collect::<Vec<&InsertedRowPB>>();
-
-        // Calculate the inserted_rows of the default_group
-        let no_status_group_rows = other_group_changesets
-            .iter()
-            .flat_map(|changeset| &changeset.deleted_rows)
-            .cloned()
-            .filter(|row_id| {
-                // if the [other_group_inserted_row] contains the row_id of the row
-                // which means the row should not move to the default group.
-                !other_group_inserted_row
-                    .iter()
-                    .any(|inserted_row| &inserted_row.row.id == row_id)
-            })
-            .collect::<Vec<String>>();
-
         let mut changeset = GroupRowsNotificationPB::new(no_status_group.id.clone());
-        if !no_status_group_rows.is_empty() {
+        if is_grouped {
+            if no_status_group.contains_row(&row_rev.id) {
+                no_status_group.remove_row(&row_rev.id);
+                changeset.deleted_rows.push(row_rev.id.clone());
+            }
+        } else if !no_status_group.contains_row(&row_rev.id) {
             changeset.inserted_rows.push(InsertedRowPB::new(row_rev.into()));
             no_status_group.add_row(row_rev.into());
         }
-
-        // [other_group_delete_rows] contains all the deleted rows except the default group.
-        let other_group_delete_rows: Vec<String> = other_group_changesets
-            .iter()
-            .flat_map(|changeset| &changeset.deleted_rows)
-            .cloned()
-            .collect();
-
-        let default_group_deleted_rows = other_group_changesets
-            .iter()
-            .flat_map(|changeset| &changeset.inserted_rows)
-            .filter(|inserted_row| {
-                // if the [other_group_delete_rows] contain the inserted_row, which means this row should move
-                // out from the default_group.
-                let inserted_row_id = &inserted_row.row.id;
-                !other_group_delete_rows.iter().any(|row_id| inserted_row_id == row_id)
-            })
-            .collect::<Vec<&InsertedRowPB>>();
-
-        let mut deleted_row_ids = vec![];
-        for row in &no_status_group.rows {
-            if default_group_deleted_rows
-                .iter()
-                .any(|deleted_row| deleted_row.row.id == row.id)
-            {
-                deleted_row_ids.push(row.id.clone());
-            }
-        }
-        no_status_group.rows.retain(|row| !deleted_row_ids.contains(&row.id));
-        changeset.deleted_rows.extend(deleted_row_ids);
         Some(changeset)
     }
 }
@@ -181,9 +146,9 @@ where
     #[tracing::instrument(level = "trace", skip_all, fields(row_count=%row_revs.len(), group_result))]
     fn fill_groups(&mut self, row_revs: &[Arc<RowRevision>], field_rev: &FieldRevision) -> FlowyResult<()> {
         for row_rev in row_revs {
-            let cell_rev = match row_rev.cells.get(&self.field_id) {
+            let cell_rev = match get_cell_rev_from_row(row_rev, field_rev) {
                 None => self.placeholder_cell(),
-                Some(cell_rev) => Some(cell_rev.clone()),
+                Some(cell_rev) => Some(cell_rev),
             };
 
             if let Some(cell_rev) = cell_rev {
@@ -222,6 +187,14 @@ where
         self.group_ctx.move_group(from_group_id, to_group_id)
     }
 
+    fn set_group_collapsed(&mut self, group_id: &str, collapsed: bool) -> FlowyResult<()> {
+        self.group_ctx.set_group_collapsed(group_id, collapsed)
+    }
+
+    fn set_group_visibility(&mut self, group_id: &str, visible: bool) -> FlowyResult<()> {
+        self.group_ctx.set_group_visibility(group_id, visible)
+    }
+
     fn did_update_group_row(
         &mut self,
         old_row_rev: &Option<Arc<RowRevision>>,
@@ -238,7 +211,11 @@ where
             row_changesets: vec![],
         };
 
-        if let Some(cell_data) = get_cell_data_from_row_rev::<P>(Some(row_rev), field_rev) {
+        // The row whose cell was removed is treated as the row with an empty cell, so it falls into
+        // the no status group.
+        let cell_data =
+            get_cell_data_from_row_rev::<P>(Some(row_rev), field_rev).or_else(|| get_empty_cell_data::<P>(field_rev));
+        if let Some(cell_data) = cell_data {
             let old_row_rev = old_row_rev.as_ref().map(|old| old.as_ref());
             let old_cell_data = get_cell_data_from_row_rev::<P>(old_row_rev, field_rev);
             if let Ok((insert, delete)) =
@@ -249,7 +226,7 @@ where
             }
 
             let mut changesets = self.add_or_remove_row_when_cell_changed(row_rev, &cell_data);
-            if let Some(changeset) = self.update_no_status_group(row_rev, &changesets) {
+            if let Some(changeset) = self.update_no_status_group(row_rev) {
                 if !changeset.is_empty() {
                     changesets.push(changeset);
                 }
@@ -270,7 +247,7 @@ where
             deleted_group: None,
             row_changesets: vec![],
         };
-        if let Some(cell_rev) = row_rev.cells.get(&self.field_id) {
+        if let Some(cell_rev) = get_cell_rev_from_row(row_rev, field_rev) {
             let cell_bytes = get_type_cell_protobuf(cell_rev.type_cell_data.clone(), field_rev, None).1;
             let cell_data = cell_bytes.parser::<P>()?;
             if !cell_data.is_empty() {
@@ -303,8 +280,8 @@ where
             deleted_group: None,
             row_changesets: vec![],
         };
-        let cell_rev = match context.row_rev.cells.get(&self.field_id) {
-            Some(cell_rev) => Some(cell_rev.clone()),
+        let cell_rev = match get_cell_rev_from_row(context.row_rev, context.field_rev) {
+            Some(cell_rev) => Some(cell_rev),
             None => self.placeholder_cell(),
         };
 
@@ -322,6 +299,10 @@ where
     fn did_update_group_field(&mut self, _field_rev: &FieldRevision) -> FlowyResult<Option<GroupViewChangesetPB>> {
         Ok(None)
     }
+
+    fn is_outdated(&self) -> bool {
+        self.is_relative_to_today() && self.group_ctx.grouped_date() != local_today()
+    }
 }
 
 struct GroupedRow {
@@ -333,7 +314,13 @@ fn get_cell_data_from_row_rev<P: CellProtobufBlobParser>(
     row_rev: Option<&RowRevision>,
     field_rev: &FieldRevision,
 ) -> Option<P::Object> {
-    let cell_rev: &CellRevision = row_rev.and_then(|row_rev| row_rev.cells.get(&field_rev.id))?;
-    let cell_bytes = get_type_cell_protobuf(cell_rev.type_cell_data.clone(), field_rev, None).1;
+    let cell_rev: CellRevision = row_rev.and_then(|row_rev| get_cell_rev_from_row(row_rev, field_rev))?;
+    let cell_bytes = get_type_cell_protobuf(cell_rev.type_cell_data, field_rev, None).1;
+    cell_bytes.parser::<P>().ok()
+}
+
+fn get_empty_cell_data<P: CellProtobufBlobParser>(field_rev: &FieldRevision) -> Option<P::Object> {
+    let type_cell_data = TypeCellData::new("".to_owned(), field_rev.ty.into()).to_json();
+    let cell_bytes = get_type_cell_protobuf(type_cell_data, field_rev, None).1;
     cell_bytes.parser::<P>().ok()
 }
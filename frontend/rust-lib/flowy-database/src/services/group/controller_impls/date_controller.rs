@@ -0,0 +1,450 @@
+use crate::entities::{FieldType, GroupPB, GroupRowsNotificationPB, InsertedGroupPB, InsertedRowPB, RowPB};
+use crate::services::cell::insert_date_cell;
+use crate::services::field::{DateCellDataPB, DateCellDataParser, DateFormat, DateTypeOptionPB};
+use crate::services::group::action::GroupCustomize;
+use crate::services::group::configuration::GroupContext;
+use crate::services::group::controller::{
+    GenericGroupController, GroupController, GroupGenerator, MoveGroupRowContext,
+};
+use crate::services::group::{make_no_status_group, move_group_row, GeneratedGroupConfig, GeneratedGroupContext};
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, Weekday};
+use flowy_error::FlowyResult;
+use grid_model::{DateCondition, DateGroupConfigurationRevision, FieldRevision, GroupRevision, RowRevision};
+
+const TODAY: &str = "today";
+const YESTERDAY: &str = "yesterday";
+const THIS_WEEK: &str = "this_week";
+const THIS_MONTH: &str = "this_month";
+const EARLIER: &str = "earlier";
+const LATER: &str = "later";
+
+pub type DateGroupController =
+    GenericGroupController<DateGroupConfigurationRevision, DateTypeOptionPB, DateGroupGenerator, DateCellDataParser>;
+
+pub type DateGroupContext = GroupContext<DateGroupConfigurationRevision>;
+
+impl DateGroupController {
+    fn condition(&self) -> DateCondition {
+        self.group_ctx
+            .get_content()
+            .map(|content| content.condition)
+            .unwrap_or_default()
+    }
+
+    fn group_id_of_cell(&self, cell_data: &DateCellDataPB) -> Option<String> {
+        let date = date_of_timestamp(cell_data.timestamp)?;
+        Some(date_group_id(&self.condition(), date, self.group_ctx.grouped_date()))
+    }
+}
+
+impl GroupCustomize for DateGroupController {
+    type CellData = DateCellDataPB;
+
+    fn can_group(&self, content: &str, cell_data: &Self::CellData) -> bool {
+        self.group_id_of_cell(cell_data).as_deref() == Some(content)
+    }
+
+    fn is_relative_to_today(&self) -> bool {
+        self.condition() == DateCondition::Relative
+    }
+
+    fn create_or_delete_group_when_cell_changed(
+        &mut self,
+        row_rev: &RowRevision,
+        old_cell_data: Option<&Self::CellData>,
+        cell_data: &Self::CellData,
+    ) -> FlowyResult<(Option<InsertedGroupPB>, Option<GroupPB>)> {
+        let condition = self.condition();
+        let today = self.group_ctx.grouped_date();
+        let date = date_of_timestamp(cell_data.timestamp);
+        let group_id = date.map(|date| date_group_id(&condition, date, today));
+
+        // Creates the group of the date if the row is the first row in it
+        let mut inserted_group = None;
+        if let (Some(date), Some(group_id)) = (date, group_id.as_ref()) {
+            if self.group_ctx.get_group(group_id).is_none() {
+                let group_rev = make_date_group(&condition, date, today, &self.type_option);
+                let mut new_group = self.group_ctx.add_new_group(group_rev)?;
+                new_group.group.rows.push(RowPB::from(row_rev));
+                inserted_group = Some(new_group);
+            }
+        }
+
+        // Deletes the old group of the row if the row was the last row in it
+        let old_group_id = old_cell_data.and_then(|old_cell_data| self.group_id_of_cell(old_cell_data));
+        let deleted_group = match old_group_id.filter(|old_group_id| Some(old_group_id) != group_id.as_ref()) {
+            None => None,
+            Some(old_group_id) => match self.group_ctx.get_group(&old_group_id) {
+                Some((_, group)) if group.rows.len() == 1 => Some(group.clone()),
+                _ => None,
+            },
+        };
+        let deleted_group = match deleted_group {
+            None => None,
+            Some(group) => {
+                self.group_ctx.delete_group(&group.id)?;
+                Some(GroupPB::from(group))
+            }
+        };
+
+        Ok((inserted_group, deleted_group))
+    }
+
+    fn add_or_remove_row_when_cell_changed(
+        &mut self,
+        row_rev: &RowRevision,
+        cell_data: &Self::CellData,
+    ) -> Vec<GroupRowsNotificationPB> {
+        let mut changesets = vec![];
+        let group_id = self.group_id_of_cell(cell_data);
+        self.group_ctx.iter_mut_status_groups(|group| {
+            let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+            if Some(&group.id) == group_id.as_ref() {
+                if !group.contains_row(&row_rev.id) {
+                    let row_pb = RowPB::from(row_rev);
+                    changeset.inserted_rows.push(InsertedRowPB::new(row_pb.clone()));
+                    group.add_row(row_pb);
+                }
+            } else if group.contains_row(&row_rev.id) {
+                changeset.deleted_rows.push(row_rev.id.clone());
+                group.remove_row(&row_rev.id);
+            }
+
+            if !changeset.is_empty() {
+                changesets.push(changeset);
+            }
+        });
+        changesets
+    }
+
+    fn delete_row(&mut self, row_rev: &RowRevision, _cell_data: &Self::CellData) -> Vec<GroupRowsNotificationPB> {
+        let mut changesets = vec![];
+        self.group_ctx.iter_mut_groups(|group| {
+            let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+            if group.contains_row(&row_rev.id) {
+                changeset.deleted_rows.push(row_rev.id.clone());
+                group.remove_row(&row_rev.id);
+            }
+
+            if !changeset.is_empty() {
+                changesets.push(changeset);
+            }
+        });
+        changesets
+    }
+
+    fn move_row(
+        &mut self,
+        _cell_data: &Self::CellData,
+        mut context: MoveGroupRowContext,
+    ) -> Vec<GroupRowsNotificationPB> {
+        let mut group_changeset = vec![];
+        self.group_ctx.iter_mut_groups(|group| {
+            if let Some(changeset) = move_group_row(group, &mut context) {
+                group_changeset.push(changeset);
+            }
+        });
+        group_changeset
+    }
+
+    fn delete_group_when_move_row(&mut self, _row_rev: &RowRevision, cell_data: &Self::CellData) -> Option<GroupPB> {
+        let group_id = self.group_id_of_cell(cell_data)?;
+        let deleted_group = match self.group_ctx.get_group(&group_id) {
+            Some((_, group)) if group.rows.len() == 1 => GroupPB::from(group.clone()),
+            _ => return None,
+        };
+        let _ = self.group_ctx.delete_group(&deleted_group.group_id);
+        Some(deleted_group)
+    }
+}
+
+impl GroupController for DateGroupController {
+    fn will_create_row(&mut self, row_rev: &mut RowRevision, field_rev: &FieldRevision, group_id: &str) {
+        // The timestamps of the row are not stored in the cells.
+        if FieldType::from(field_rev.ty).is_timestamp() {
+            return;
+        }
+        let condition = self.condition();
+        let today = self.group_ctx.grouped_date();
+        match self.group_ctx.get_group(group_id) {
+            None => tracing::warn!("Can not find the group: {}", group_id),
+            Some((_, group)) => {
+                // The rows created in the no status group have no date.
+                if let Some(timestamp) = date_of_group(&condition, &group.id, today)
+                    .and_then(|date| date.and_hms_opt(0, 0, 0))
+                    .map(|time| time.timestamp())
+                {
+                    let cell_rev = insert_date_cell(timestamp, field_rev);
+                    row_rev.cells.insert(field_rev.id.clone(), cell_rev);
+                }
+            }
+        }
+    }
+
+    fn did_create_row(&mut self, row_pb: &RowPB, group_id: &str) {
+        if let Some(group) = self.group_ctx.get_mut_group(group_id) {
+            group.add_row(row_pb.clone())
+        }
+    }
+}
+
+pub struct DateGroupGenerator();
+impl GroupGenerator for DateGroupGenerator {
+    type Context = DateGroupContext;
+    type TypeOptionType = DateTypeOptionPB;
+
+    fn generate_groups(
+        field_rev: &FieldRevision,
+        group_ctx: &Self::Context,
+        type_option: &Option<Self::TypeOptionType>,
+    ) -> GeneratedGroupContext {
+        let condition = group_ctx
+            .get_content()
+            .map(|content| content.condition)
+            .unwrap_or_default();
+        let today = group_ctx.grouped_date();
+
+        // Only the dates of the cells have their groups, the groups are in the order of the dates.
+        let cells = futures::executor::block_on(group_ctx.get_all_cells());
+        let mut dates = cells
+            .into_iter()
+            .flat_map(|value| value.into_date_field_cell_data())
+            .flat_map(|cell_data| cell_data.0.and_then(date_of_timestamp))
+            .collect::<Vec<NaiveDate>>();
+        dates.sort();
+
+        let mut group_configs: Vec<GeneratedGroupConfig> = vec![];
+        for date in dates {
+            let group_id = date_group_id(&condition, date, today);
+            if group_configs.last().map(|config| &config.group_rev.id) != Some(&group_id) {
+                group_configs.push(GeneratedGroupConfig {
+                    group_rev: make_date_group(&condition, date, today, type_option),
+                    filter_content: group_id,
+                });
+            }
+        }
+
+        GeneratedGroupContext {
+            no_status_group: Some(make_no_status_group(field_rev)),
+            group_configs,
+        }
+    }
+}
+
+/// Returns the date of today in local time, the groups that are relative to today are generated
+/// against it.
+pub fn local_today() -> NaiveDate {
+    Local::now().naive_local().date()
+}
+
+/// Returns the date of the timestamp in UTC, which is the date that the date fields display. The
+/// timestamp 0 is the empty cell.
+fn date_of_timestamp(timestamp: i64) -> Option<NaiveDate> {
+    if timestamp == 0 {
+        return None;
+    }
+    NaiveDateTime::from_timestamp_opt(timestamp, 0).map(|time| time.date())
+}
+
+/// Returns the id of the group that contains the date. The groups of the relative condition are
+/// decided by `today`, the others by the date itself, for example, `2023-W02` is the second ISO
+/// week of 2023.
+fn date_group_id(condition: &DateCondition, date: NaiveDate, today: NaiveDate) -> String {
+    match condition {
+        DateCondition::Relative => {
+            let group_id = if date > today {
+                LATER
+            } else if date == today {
+                TODAY
+            } else if Some(date) == today.pred_opt() {
+                YESTERDAY
+            } else if date.iso_week() == today.iso_week() {
+                THIS_WEEK
+            } else if date.year() == today.year() && date.month() == today.month() {
+                THIS_MONTH
+            } else {
+                EARLIER
+            };
+            group_id.to_owned()
+        }
+        DateCondition::Day => date.format("%Y-%m-%d").to_string(),
+        DateCondition::Week => {
+            let week = date.iso_week();
+            format!("{}-W{:02}", week.year(), week.week())
+        }
+        DateCondition::Month => date.format("%Y-%m").to_string(),
+        DateCondition::Year => date.year().to_string(),
+    }
+}
+
+fn make_date_group(
+    condition: &DateCondition,
+    date: NaiveDate,
+    today: NaiveDate,
+    type_option: &Option<DateTypeOptionPB>,
+) -> GroupRevision {
+    let group_id = date_group_id(condition, date, today);
+    let date_format = type_option
+        .as_ref()
+        .map(|type_option| type_option.date_format.format_str())
+        .unwrap_or_else(|| DateFormat::default().format_str());
+    let group_name = match condition {
+        DateCondition::Relative => match group_id.as_str() {
+            TODAY => "Today",
+            YESTERDAY => "Yesterday",
+            THIS_WEEK => "This week",
+            THIS_MONTH => "This month",
+            LATER => "Later",
+            _ => "Earlier",
+        }
+        .to_owned(),
+        DateCondition::Day => date.format(date_format).to_string(),
+        DateCondition::Week => {
+            let monday = date - Duration::days(date.weekday().num_days_from_monday() as i64);
+            format!("Week of {}", monday.format(date_format))
+        }
+        DateCondition::Month => date.format("%B %Y").to_string(),
+        DateCondition::Year => date.year().to_string(),
+    };
+    GroupRevision::new(group_id, group_name)
+}
+
+/// Returns the date of the cells of the rows that are created in the group, or None if the group
+/// has no such date, for example, the `This week` group has no days other than today and
+/// yesterday on Tuesday.
+fn date_of_group(condition: &DateCondition, group_id: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let date = match condition {
+        DateCondition::Relative => match group_id {
+            TODAY => Some(today),
+            YESTERDAY => today.pred_opt(),
+            THIS_WEEK => today.checked_sub_signed(Duration::days(2)),
+            THIS_MONTH => today.with_day(1),
+            // A week before the first day of the month is neither in this week nor in this month.
+            EARLIER => today
+                .with_day(1)
+                .and_then(|date| date.checked_sub_signed(Duration::days(7))),
+            LATER => today.succ_opt(),
+            _ => None,
+        },
+        DateCondition::Day => NaiveDate::parse_from_str(group_id, "%Y-%m-%d").ok(),
+        DateCondition::Week => {
+            let (year, week) = group_id.split_once("-W")?;
+            NaiveDate::from_isoywd_opt(year.parse().ok()?, week.parse().ok()?, Weekday::Mon)
+        }
+        DateCondition::Month => NaiveDate::parse_from_str(&format!("{}-01", group_id), "%Y-%m-%d").ok(),
+        DateCondition::Year => NaiveDate::from_ymd_opt(group_id.parse().ok()?, 1, 1),
+    }?;
+    (date_group_id(condition, date, today) == group_id).then_some(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::group::controller_impls::date_controller::{date_group_id, date_of_group, date_of_timestamp};
+    use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone, Utc};
+    use grid_model::DateCondition;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn timestamp(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> i64 {
+        date(year, month, day).and_hms_opt(hour, min, sec).unwrap().timestamp()
+    }
+
+    fn relative_group_id(timestamp: i64, today: NaiveDate) -> String {
+        date_group_id(&DateCondition::Relative, date_of_timestamp(timestamp).unwrap(), today)
+    }
+
+    /// The date of today in the time zone, like the date of today in local time.
+    fn today_in<Tz: TimeZone>(now: DateTime<Utc>, time_zone: &Tz) -> NaiveDate {
+        now.with_timezone(time_zone).naive_local().date()
+    }
+
+    #[test]
+    fn date_group_relative_test() {
+        // Wednesday
+        let today = date(2023, 1, 11);
+        for (timestamp, group_id) in [
+            (timestamp(2023, 1, 12, 8, 0, 0), "later"),
+            (timestamp(2023, 1, 11, 8, 0, 0), "today"),
+            (timestamp(2023, 1, 10, 8, 0, 0), "yesterday"),
+            (timestamp(2023, 1, 9, 8, 0, 0), "this_week"),
+            (timestamp(2023, 1, 8, 8, 0, 0), "this_month"),
+            (timestamp(2022, 12, 31, 8, 0, 0), "earlier"),
+        ] {
+            assert_eq!(relative_group_id(timestamp, today), group_id);
+        }
+    }
+
+    #[test]
+    fn date_group_relative_day_boundary_test() {
+        let today = date(2023, 1, 11);
+        // The date of the cell is its date in UTC, so the last second of the day is still yesterday.
+        assert_eq!(
+            relative_group_id(timestamp(2023, 1, 10, 23, 59, 59), today),
+            "yesterday"
+        );
+        assert_eq!(relative_group_id(timestamp(2023, 1, 11, 0, 0, 0), today), "today");
+        assert_eq!(relative_group_id(timestamp(2023, 1, 11, 23, 59, 59), today), "today");
+        assert_eq!(relative_group_id(timestamp(2023, 1, 12, 0, 0, 0), today), "later");
+    }
+
+    #[test]
+    fn date_group_relative_time_zone_test() {
+        // 20:00 in UTC is the next day in UTC+8, and the same day in UTC-5.
+        let now = Utc.from_utc_datetime(&date(2023, 1, 10).and_hms_opt(20, 0, 0).unwrap());
+        let east = FixedOffset::east_opt(8 * 3600).unwrap();
+        let west = FixedOffset::west_opt(5 * 3600).unwrap();
+        assert_eq!(today_in(now, &east), date(2023, 1, 11));
+        assert_eq!(today_in(now, &west), date(2023, 1, 10));
+
+        let cell_timestamp = timestamp(2023, 1, 11, 0, 0, 0);
+        assert_eq!(relative_group_id(cell_timestamp, today_in(now, &east)), "today");
+        assert_eq!(relative_group_id(cell_timestamp, today_in(now, &west)), "later");
+
+        let cell_timestamp = timestamp(2023, 1, 10, 12, 0, 0);
+        assert_eq!(relative_group_id(cell_timestamp, today_in(now, &east)), "yesterday");
+        assert_eq!(relative_group_id(cell_timestamp, today_in(now, &west)), "today");
+    }
+
+    #[test]
+    fn date_group_relative_week_across_months_test() {
+        // Wednesday, the Monday of this week is in the last month.
+        let today = date(2023, 2, 1);
+        assert_eq!(relative_group_id(timestamp(2023, 1, 30, 8, 0, 0), today), "this_week");
+        assert_eq!(relative_group_id(timestamp(2023, 1, 29, 8, 0, 0), today), "earlier");
+    }
+
+    #[test]
+    fn date_group_fixed_ranges_test() {
+        let today = date(2023, 1, 11);
+        // 2021-01-01 is in the last ISO week of 2020.
+        let new_year = date(2021, 1, 1);
+        assert_eq!(date_group_id(&DateCondition::Day, new_year, today), "2021-01-01");
+        assert_eq!(date_group_id(&DateCondition::Week, new_year, today), "2020-W53");
+        assert_eq!(date_group_id(&DateCondition::Month, new_year, today), "2021-01");
+        assert_eq!(date_group_id(&DateCondition::Year, new_year, today), "2021");
+    }
+
+    #[test]
+    fn date_of_group_test() {
+        let today = date(2023, 1, 11);
+        for (condition, group_id, expected) in [
+            (DateCondition::Day, "2021-01-01", Some(date(2021, 1, 1))),
+            (DateCondition::Week, "2020-W53", Some(date(2020, 12, 28))),
+            (DateCondition::Month, "2021-01", Some(date(2021, 1, 1))),
+            (DateCondition::Year, "2021", Some(date(2021, 1, 1))),
+            (DateCondition::Relative, "today", Some(today)),
+            (DateCondition::Relative, "this_week", Some(date(2023, 1, 9))),
+            (DateCondition::Relative, "earlier", Some(date(2022, 12, 25))),
+        ] {
+            assert_eq!(date_of_group(&condition, group_id, today), expected, "{}", group_id);
+        }
+
+        // There is no day of this week other than today and yesterday on Tuesday.
+        assert_eq!(
+            date_of_group(&DateCondition::Relative, "this_week", date(2023, 1, 10)),
+            None
+        );
+    }
+}
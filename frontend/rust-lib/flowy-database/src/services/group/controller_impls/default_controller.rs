@@ -55,6 +55,14 @@ impl GroupControllerActions for DefaultGroupController {
         Ok(())
     }
 
+    fn set_group_collapsed(&mut self, _group_id: &str, _collapsed: bool) -> FlowyResult<()> {
+        Ok(())
+    }
+
+    fn set_group_visibility(&mut self, _group_id: &str, _visible: bool) -> FlowyResult<()> {
+        Ok(())
+    }
+
     fn did_update_group_row(
         &mut self,
         _old_row_rev: &Option<Arc<RowRevision>>,
@@ -1,9 +1,17 @@
 mod checkbox_controller;
+mod date_controller;
 mod default_controller;
+mod number_controller;
+mod person_controller;
+mod rating_controller;
 mod select_option_controller;
 mod url_controller;
 
 pub use checkbox_controller::*;
+pub use date_controller::*;
 pub use default_controller::*;
+pub use number_controller::*;
+pub use person_controller::*;
+pub use rating_controller::*;
 pub use select_option_controller::*;
 pub use url_controller::*;
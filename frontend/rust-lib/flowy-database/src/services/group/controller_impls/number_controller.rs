@@ -0,0 +1,467 @@
+use crate::entities::{GroupPB, GroupRowsNotificationPB, InsertedGroupPB, InsertedRowPB, RowPB};
+use crate::services::cell::apply_cell_data_changeset;
+use crate::services::field::{NumberTypeOptionPB, TextCellData, TextCellDataParser};
+use crate::services::group::action::GroupCustomize;
+use crate::services::group::configuration::GroupContext;
+use crate::services::group::controller::{
+    GenericGroupController, GroupController, GroupGenerator, MoveGroupRowContext,
+};
+use crate::services::group::{make_no_status_group, move_group_row, GeneratedGroupConfig, GeneratedGroupContext};
+use flowy_error::FlowyResult;
+use grid_model::{CellRevision, FieldRevision, GroupRevision, NumberGroupConfigurationRevision, RowRevision};
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+// The cells are parsed from the texts that the number fields display, for example: `$1,844`. The
+// type option parses them back into the numbers in the same format.
+pub type NumberGroupController = GenericGroupController<
+    NumberGroupConfigurationRevision,
+    NumberTypeOptionPB,
+    NumberGroupGenerator,
+    TextCellDataParser,
+>;
+
+pub type NumberGroupContext = GroupContext<NumberGroupConfigurationRevision>;
+
+impl NumberGroupController {
+    fn ranges(&self) -> NumberRanges {
+        NumberRanges::from_configuration(&self.group_ctx.get_content().unwrap_or_default())
+    }
+
+    /// Returns None if the cell is empty or can't be parsed into a number, the row of the cell is
+    /// put into the no status group.
+    fn range_of_cell(&self, cell_data: &TextCellData) -> Option<NumberRange> {
+        let number = match self.type_option.as_ref() {
+            None => Decimal::from_str(&cell_data.0).ok(),
+            Some(type_option) => type_option.decimal_from_cell_str(&cell_data.0),
+        }?;
+        self.ranges().range_of(number)
+    }
+}
+
+impl GroupCustomize for NumberGroupController {
+    type CellData = TextCellData;
+
+    fn can_group(&self, content: &str, cell_data: &Self::CellData) -> bool {
+        self.range_of_cell(cell_data).map(|range| range.id()).as_deref() == Some(content)
+    }
+
+    fn create_or_delete_group_when_cell_changed(
+        &mut self,
+        row_rev: &RowRevision,
+        old_cell_data: Option<&Self::CellData>,
+        cell_data: &Self::CellData,
+    ) -> FlowyResult<(Option<InsertedGroupPB>, Option<GroupPB>)> {
+        let range = self.range_of_cell(cell_data);
+        let group_id = range.as_ref().map(|range| range.id());
+
+        // Creates the group of the range if the row is the first row in it
+        let mut inserted_group = None;
+        if let (Some(range), Some(group_id)) = (range.as_ref(), group_id.as_ref()) {
+            if self.group_ctx.get_group(group_id).is_none() {
+                let mut new_group = self.group_ctx.add_new_group(range.group_rev())?;
+                new_group.group.rows.push(RowPB::from(row_rev));
+                inserted_group = Some(new_group);
+            }
+        }
+
+        // Deletes the old group of the row if the row was the last row in it
+        let old_group_id = old_cell_data
+            .and_then(|old_cell_data| self.range_of_cell(old_cell_data))
+            .map(|range| range.id());
+        let deleted_group = match old_group_id.filter(|old_group_id| Some(old_group_id) != group_id.as_ref()) {
+            None => None,
+            Some(old_group_id) => match self.group_ctx.get_group(&old_group_id) {
+                Some((_, group)) if group.rows.len() == 1 => Some(group.clone()),
+                _ => None,
+            },
+        };
+        let deleted_group = match deleted_group {
+            None => None,
+            Some(group) => {
+                self.group_ctx.delete_group(&group.id)?;
+                Some(GroupPB::from(group))
+            }
+        };
+
+        Ok((inserted_group, deleted_group))
+    }
+
+    fn add_or_remove_row_when_cell_changed(
+        &mut self,
+        row_rev: &RowRevision,
+        cell_data: &Self::CellData,
+    ) -> Vec<GroupRowsNotificationPB> {
+        let mut changesets = vec![];
+        let group_id = self.range_of_cell(cell_data).map(|range| range.id());
+        self.group_ctx.iter_mut_status_groups(|group| {
+            let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+            if Some(&group.id) == group_id.as_ref() {
+                if !group.contains_row(&row_rev.id) {
+                    let row_pb = RowPB::from(row_rev);
+                    changeset.inserted_rows.push(InsertedRowPB::new(row_pb.clone()));
+                    group.add_row(row_pb);
+                }
+            } else if group.contains_row(&row_rev.id) {
+                changeset.deleted_rows.push(row_rev.id.clone());
+                group.remove_row(&row_rev.id);
+            }
+
+            if !changeset.is_empty() {
+                changesets.push(changeset);
+            }
+        });
+        changesets
+    }
+
+    fn delete_row(&mut self, row_rev: &RowRevision, _cell_data: &Self::CellData) -> Vec<GroupRowsNotificationPB> {
+        let mut changesets = vec![];
+        self.group_ctx.iter_mut_groups(|group| {
+            let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+            if group.contains_row(&row_rev.id) {
+                changeset.deleted_rows.push(row_rev.id.clone());
+                group.remove_row(&row_rev.id);
+            }
+
+            if !changeset.is_empty() {
+                changesets.push(changeset);
+            }
+        });
+        changesets
+    }
+
+    fn move_row(
+        &mut self,
+        _cell_data: &Self::CellData,
+        mut context: MoveGroupRowContext,
+    ) -> Vec<GroupRowsNotificationPB> {
+        let mut group_changeset = vec![];
+        self.group_ctx.iter_mut_groups(|group| {
+            if let Some(changeset) = move_group_row(group, &mut context) {
+                group_changeset.push(changeset);
+            }
+        });
+        group_changeset
+    }
+
+    fn delete_group_when_move_row(&mut self, _row_rev: &RowRevision, cell_data: &Self::CellData) -> Option<GroupPB> {
+        let group_id = self.range_of_cell(cell_data)?.id();
+        let deleted_group = match self.group_ctx.get_group(&group_id) {
+            Some((_, group)) if group.rows.len() == 1 => GroupPB::from(group.clone()),
+            _ => return None,
+        };
+        let _ = self.group_ctx.delete_group(&deleted_group.group_id);
+        Some(deleted_group)
+    }
+}
+
+impl GroupController for NumberGroupController {
+    fn will_create_row(&mut self, row_rev: &mut RowRevision, field_rev: &FieldRevision, group_id: &str) {
+        match self.group_ctx.get_group(group_id) {
+            None => tracing::warn!("Can not find the group: {}", group_id),
+            Some((_, group)) => {
+                // The rows created in the no status group have no number.
+                let number = NumberRange::from_id(&group.id).and_then(|range| range.number_in_range());
+                if let Some(number) = number {
+                    match apply_cell_data_changeset(number.to_string(), None, field_rev, None) {
+                        Ok(data) => {
+                            row_rev.cells.insert(field_rev.id.clone(), CellRevision::new(data));
+                        }
+                        Err(e) => tracing::error!("Insert the number of the group failed: {:?}", e),
+                    }
+                }
+            }
+        }
+    }
+
+    fn did_create_row(&mut self, row_pb: &RowPB, group_id: &str) {
+        if let Some(group) = self.group_ctx.get_mut_group(group_id) {
+            group.add_row(row_pb.clone())
+        }
+    }
+}
+
+pub struct NumberGroupGenerator();
+impl GroupGenerator for NumberGroupGenerator {
+    type Context = NumberGroupContext;
+    type TypeOptionType = NumberTypeOptionPB;
+
+    fn generate_groups(
+        field_rev: &FieldRevision,
+        group_ctx: &Self::Context,
+        _type_option: &Option<Self::TypeOptionType>,
+    ) -> GeneratedGroupContext {
+        let ranges = NumberRanges::from_configuration(&group_ctx.get_content().unwrap_or_default());
+
+        // Only the ranges of the numbers in the cells have their groups, the groups are in the
+        // order of the ranges. The cells keep the numbers as decimal strings.
+        let cells = futures::executor::block_on(group_ctx.get_all_cells());
+        let mut number_ranges = cells
+            .into_iter()
+            .flat_map(|value| value.into_number_field_cell_data())
+            .flat_map(|cell_data| Decimal::from_str(&cell_data.0).ok())
+            .flat_map(|number| ranges.range_of(number))
+            .collect::<Vec<NumberRange>>();
+        number_ranges.sort_by(|left, right| left.lower.cmp(&right.lower));
+        number_ranges.dedup();
+
+        let group_configs = number_ranges
+            .into_iter()
+            .map(|range| GeneratedGroupConfig {
+                group_rev: range.group_rev(),
+                filter_content: range.id(),
+            })
+            .collect();
+
+        GeneratedGroupContext {
+            no_status_group: Some(make_no_status_group(field_rev)),
+            group_configs,
+        }
+    }
+}
+
+/// The ranges that the numbers are grouped into, see [NumberGroupConfigurationRevision].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum NumberRanges {
+    FixedWidth {
+        start: Decimal,
+        width: Decimal,
+    },
+    /// The breakpoints are sorted without duplicates. The numbers below the first breakpoint and
+    /// from the last breakpoint on are in the two open ranges.
+    Breakpoints(Vec<Decimal>),
+}
+
+impl NumberRanges {
+    /// The invalid numbers of the configuration are ignored, the default ranges are 10 wide and
+    /// start from 0.
+    fn from_configuration(configuration: &NumberGroupConfigurationRevision) -> Self {
+        let mut breakpoints = configuration
+            .breakpoints
+            .iter()
+            .flat_map(|breakpoint| Decimal::from_str(breakpoint).ok())
+            .collect::<Vec<Decimal>>();
+        breakpoints.sort();
+        breakpoints.dedup();
+        if !breakpoints.is_empty() {
+            return NumberRanges::Breakpoints(breakpoints);
+        }
+
+        let start = Decimal::from_str(&configuration.start).unwrap_or(Decimal::ZERO);
+        let width = Decimal::from_str(&configuration.width)
+            .ok()
+            .filter(|width| width.is_sign_positive() && !width.is_zero())
+            .unwrap_or(Decimal::TEN);
+        NumberRanges::FixedWidth { start, width }
+    }
+
+    /// Returns None if the bounds of the range overflow, which only happens to the huge numbers.
+    fn range_of(&self, number: Decimal) -> Option<NumberRange> {
+        match self {
+            NumberRanges::FixedWidth { start, width } => {
+                let index = number.checked_sub(*start)?.checked_div(*width)?.floor();
+                let mut lower = index.checked_mul(*width)?.checked_add(*start)?;
+                // The quotient is rounded if it has too many digits, which might put the number
+                // into the next range when it's just below the bound.
+                if number < lower {
+                    lower = lower.checked_sub(*width)?;
+                }
+                let upper = lower.checked_add(*width)?;
+                Some(NumberRange::new(Some(lower), Some(upper)))
+            }
+            NumberRanges::Breakpoints(breakpoints) => {
+                let index = breakpoints.partition_point(|breakpoint| *breakpoint <= number);
+                let lower = index.checked_sub(1).map(|index| breakpoints[index]);
+                let upper = breakpoints.get(index).copied();
+                Some(NumberRange::new(lower, upper))
+            }
+        }
+    }
+}
+
+/// The range contains its lower bound but not its upper bound. The range without the lower bound
+/// contains all the numbers below the upper bound, and vice versa.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NumberRange {
+    lower: Option<Decimal>,
+    upper: Option<Decimal>,
+}
+
+impl NumberRange {
+    fn new(lower: Option<Decimal>, upper: Option<Decimal>) -> Self {
+        Self {
+            lower: lower.map(|lower| lower.normalize()),
+            upper: upper.map(|upper| upper.normalize()),
+        }
+    }
+
+    /// The id of the range is its bounds, for example: `10..20`, `..0` and `100..`.
+    fn id(&self) -> String {
+        let bound = |bound: &Option<Decimal>| bound.map(|bound| bound.to_string()).unwrap_or_default();
+        format!("{}..{}", bound(&self.lower), bound(&self.upper))
+    }
+
+    fn from_id(id: &str) -> Option<Self> {
+        let (lower, upper) = id.split_once("..")?;
+        let parse_bound = |bound: &str| -> Option<Option<Decimal>> {
+            match bound.is_empty() {
+                true => Some(None),
+                false => Decimal::from_str(bound).ok().map(Some),
+            }
+        };
+        let range = NumberRange::new(parse_bound(lower)?, parse_bound(upper)?);
+        if range.lower.is_none() && range.upper.is_none() {
+            return None;
+        }
+        Some(range)
+    }
+
+    /// The range between the whole numbers is named by the first and the last whole numbers in it,
+    /// for example, `[0, 10)` is `0–9`, which contains `9.5` too. The other ranges are named by
+    /// their bounds, for example: `0.5–<1.5`, `< 0` and `≥ 100`.
+    fn name(&self) -> String {
+        match (self.lower, self.upper) {
+            (None, None) => "".to_owned(),
+            (None, Some(upper)) => format!("< {}", upper),
+            (Some(lower), None) => format!("≥ {}", lower),
+            (Some(lower), Some(upper)) => {
+                if !lower.fract().is_zero() || !upper.fract().is_zero() {
+                    return format!("{}–<{}", lower, upper);
+                }
+                match upper.checked_sub(Decimal::ONE) {
+                    Some(last) if last > lower => format!("{}–{}", lower, last),
+                    _ => lower.to_string(),
+                }
+            }
+        }
+    }
+
+    fn group_rev(&self) -> GroupRevision {
+        GroupRevision::new(self.id(), self.name())
+    }
+
+    /// The number that the rows created in the group of the range have.
+    fn number_in_range(&self) -> Option<Decimal> {
+        match (self.lower, self.upper) {
+            (Some(lower), _) => Some(lower),
+            (None, Some(upper)) => upper.checked_sub(Decimal::ONE),
+            (None, None) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::group::controller_impls::number_controller::{NumberRange, NumberRanges};
+    use grid_model::NumberGroupConfigurationRevision;
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    fn decimal(s: &str) -> Decimal {
+        Decimal::from_str(s).unwrap()
+    }
+
+    fn group_name(ranges: &NumberRanges, number: &str) -> String {
+        ranges.range_of(decimal(number)).unwrap().name()
+    }
+
+    fn breakpoints(breakpoints: &[&str]) -> NumberRanges {
+        NumberRanges::from_configuration(&NumberGroupConfigurationRevision {
+            breakpoints: breakpoints.iter().map(|breakpoint| breakpoint.to_string()).collect(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn number_fixed_width_ranges_test() {
+        let ranges = NumberRanges::from_configuration(&NumberGroupConfigurationRevision::default());
+        assert_eq!(group_name(&ranges, "0"), "0–9");
+        assert_eq!(group_name(&ranges, "9.99"), "0–9");
+        assert_eq!(group_name(&ranges, "10"), "10–19");
+        assert_eq!(group_name(&ranges, "123"), "120–129");
+        assert_eq!(ranges.range_of(decimal("15")).unwrap().id(), "10..20");
+    }
+
+    #[test]
+    fn number_fixed_width_negative_ranges_test() {
+        let ranges = NumberRanges::from_configuration(&NumberGroupConfigurationRevision::default());
+        assert_eq!(group_name(&ranges, "-1"), "-10–-1");
+        assert_eq!(group_name(&ranges, "-10"), "-10–-1");
+        assert_eq!(group_name(&ranges, "-10.5"), "-20–-11");
+    }
+
+    #[test]
+    fn number_fixed_width_decimal_ranges_test() {
+        let ranges = NumberRanges::from_configuration(&NumberGroupConfigurationRevision {
+            start: "0.5".to_owned(),
+            width: "1".to_owned(),
+            ..Default::default()
+        });
+        // The lower bound is in the range, the upper bound is in the next range.
+        assert_eq!(group_name(&ranges, "0.5"), "0.5–<1.5");
+        assert_eq!(group_name(&ranges, "1.4999"), "0.5–<1.5");
+        assert_eq!(group_name(&ranges, "1.5"), "1.5–<2.5");
+
+        let ranges = NumberRanges::from_configuration(&NumberGroupConfigurationRevision {
+            width: "0.1".to_owned(),
+            ..Default::default()
+        });
+        assert_eq!(ranges.range_of(decimal("0.3")).unwrap().id(), "0.3..0.4");
+        assert_eq!(ranges.range_of(decimal("0.29")).unwrap().id(), "0.2..0.3");
+    }
+
+    #[test]
+    fn number_fixed_width_rounded_quotient_test() {
+        let ranges = NumberRanges::from_configuration(&NumberGroupConfigurationRevision {
+            width: "3".to_owned(),
+            ..Default::default()
+        });
+        let number = decimal("2.9999999999999999999999999999");
+        let range = ranges.range_of(number).unwrap();
+        assert_eq!(range.id(), "0..3");
+    }
+
+    #[test]
+    fn number_breakpoint_ranges_test() {
+        let ranges = breakpoints(&["100", "0", "10", "10"]);
+        assert_eq!(group_name(&ranges, "-5"), "< 0");
+        assert_eq!(group_name(&ranges, "0"), "0–9");
+        assert_eq!(group_name(&ranges, "99.5"), "10–99");
+        assert_eq!(group_name(&ranges, "100"), "≥ 100");
+        assert_eq!(ranges.range_of(decimal("-5")).unwrap().id(), "..0");
+        assert_eq!(ranges.range_of(decimal("100")).unwrap().id(), "100..");
+    }
+
+    #[test]
+    fn number_invalid_configuration_test() {
+        let ranges = NumberRanges::from_configuration(&NumberGroupConfigurationRevision {
+            width: "-1".to_owned(),
+            breakpoints: vec!["abc".to_owned()],
+            ..Default::default()
+        });
+        assert_eq!(
+            ranges,
+            NumberRanges::FixedWidth {
+                start: Decimal::ZERO,
+                width: Decimal::TEN
+            }
+        );
+    }
+
+    #[test]
+    fn number_range_from_id_test() {
+        for id in ["10..20", "..0", "100..", "0.5..1.5"] {
+            assert_eq!(NumberRange::from_id(id).unwrap().id(), id);
+        }
+        assert_eq!(
+            NumberRange::from_id("..0").unwrap().number_in_range(),
+            Some(decimal("-1"))
+        );
+        assert_eq!(
+            NumberRange::from_id("10..20").unwrap().number_in_range(),
+            Some(decimal("10"))
+        );
+        assert!(NumberRange::from_id("..").is_none());
+        assert!(NumberRange::from_id("field_id").is_none());
+    }
+}
@@ -0,0 +1,135 @@
+use crate::entities::{GroupRowsNotificationPB, InsertedRowPB, RowPB};
+use crate::services::cell::insert_person_cell;
+use crate::services::field::{PersonCellDataPB, PersonCellDataParser, PersonTypeOptionPB};
+use crate::services::group::action::GroupCustomize;
+use crate::services::group::configuration::GroupContext;
+use crate::services::group::controller::{
+    GenericGroupController, GroupController, GroupGenerator, MoveGroupRowContext,
+};
+use crate::services::group::{make_no_status_group, move_group_row, GeneratedGroupConfig, GeneratedGroupContext};
+use grid_model::{FieldRevision, GroupRevision, PersonGroupConfigurationRevision, RowRevision};
+
+pub type PersonGroupController = GenericGroupController<
+    PersonGroupConfigurationRevision,
+    PersonTypeOptionPB,
+    PersonGroupGenerator,
+    PersonCellDataParser,
+>;
+
+pub type PersonGroupContext = GroupContext<PersonGroupConfigurationRevision>;
+
+impl GroupCustomize for PersonGroupController {
+    type CellData = PersonCellDataPB;
+
+    fn can_group(&self, content: &str, cell_data: &Self::CellData) -> bool {
+        cell_data.members.iter().any(|member| member.id == content)
+    }
+
+    fn add_or_remove_row_when_cell_changed(
+        &mut self,
+        row_rev: &RowRevision,
+        cell_data: &Self::CellData,
+    ) -> Vec<GroupRowsNotificationPB> {
+        let mut changesets = vec![];
+        self.group_ctx.iter_mut_status_groups(|group| {
+            let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+            if cell_data.members.iter().any(|member| member.id == group.id) {
+                if !group.contains_row(&row_rev.id) {
+                    let row_pb = RowPB::from(row_rev);
+                    changeset.inserted_rows.push(InsertedRowPB::new(row_pb.clone()));
+                    group.add_row(row_pb);
+                }
+            } else if group.contains_row(&row_rev.id) {
+                changeset.deleted_rows.push(row_rev.id.clone());
+                group.remove_row(&row_rev.id);
+            }
+
+            if !changeset.is_empty() {
+                changesets.push(changeset);
+            }
+        });
+        changesets
+    }
+
+    fn delete_row(&mut self, row_rev: &RowRevision, _cell_data: &Self::CellData) -> Vec<GroupRowsNotificationPB> {
+        let mut changesets = vec![];
+        self.group_ctx.iter_mut_groups(|group| {
+            let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+            if group.contains_row(&row_rev.id) {
+                changeset.deleted_rows.push(row_rev.id.clone());
+                group.remove_row(&row_rev.id);
+            }
+
+            if !changeset.is_empty() {
+                changesets.push(changeset);
+            }
+        });
+        changesets
+    }
+
+    fn move_row(
+        &mut self,
+        _cell_data: &Self::CellData,
+        mut context: MoveGroupRowContext,
+    ) -> Vec<GroupRowsNotificationPB> {
+        let mut group_changeset = vec![];
+        self.group_ctx.iter_mut_groups(|group| {
+            if let Some(changeset) = move_group_row(group, &mut context) {
+                group_changeset.push(changeset);
+            }
+        });
+        group_changeset
+    }
+}
+
+impl GroupController for PersonGroupController {
+    fn will_create_row(&mut self, row_rev: &mut RowRevision, field_rev: &FieldRevision, group_id: &str) {
+        match self.group_ctx.get_group(group_id) {
+            None => tracing::warn!("Can not find the group: {}", group_id),
+            Some((_, group)) => {
+                // The rows created in the no status group don't reference any member.
+                if group.id != field_rev.id {
+                    let cell_rev = insert_person_cell(vec![group.id.clone()], field_rev);
+                    row_rev.cells.insert(field_rev.id.clone(), cell_rev);
+                }
+            }
+        }
+    }
+
+    fn did_create_row(&mut self, row_pb: &RowPB, group_id: &str) {
+        if let Some(group) = self.group_ctx.get_mut_group(group_id) {
+            group.add_row(row_pb.clone())
+        }
+    }
+}
+
+pub struct PersonGroupGenerator();
+impl GroupGenerator for PersonGroupGenerator {
+    type Context = PersonGroupContext;
+    type TypeOptionType = PersonTypeOptionPB;
+
+    fn generate_groups(
+        field_rev: &FieldRevision,
+        _group_ctx: &Self::Context,
+        type_option: &Option<Self::TypeOptionType>,
+    ) -> GeneratedGroupContext {
+        // Each resolved member has its own group. The rows that don't reference any resolved
+        // member are put into the no status group.
+        let group_configs = match type_option {
+            None => vec![],
+            Some(type_option) => type_option
+                .members
+                .iter()
+                .map(|member| GeneratedGroupConfig {
+                    group_rev: GroupRevision::new(member.id.clone(), member.name.clone()),
+                    filter_content: member.id.clone(),
+                })
+                .collect(),
+        };
+
+        GeneratedGroupContext {
+            no_status_group: Some(make_no_status_group(field_rev)),
+            group_configs,
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use crate::entities::{GroupRowsNotificationPB, InsertedRowPB, RowPB};
+use crate::services::cell::insert_rating_cell;
+use crate::services::field::{RatingCellData, RatingCellDataParser, RatingTypeOptionPB, DEFAULT_MAX_RATING};
+use crate::services::group::action::GroupCustomize;
+use crate::services::group::configuration::GroupContext;
+use crate::services::group::controller::{
+    GenericGroupController, GroupController, GroupGenerator, MoveGroupRowContext,
+};
+use crate::services::group::{make_no_status_group, move_group_row, GeneratedGroupConfig, GeneratedGroupContext};
+use grid_model::{FieldRevision, GroupRevision, RatingGroupConfigurationRevision, RowRevision};
+
+pub type RatingGroupController = GenericGroupController<
+    RatingGroupConfigurationRevision,
+    RatingTypeOptionPB,
+    RatingGroupGenerator,
+    RatingCellDataParser,
+>;
+
+pub type RatingGroupContext = GroupContext<RatingGroupConfigurationRevision>;
+
+impl GroupCustomize for RatingGroupController {
+    type CellData = RatingCellData;
+
+    fn can_group(&self, content: &str, cell_data: &Self::CellData) -> bool {
+        cell_data.is_rated() && cell_data.0.to_string() == content
+    }
+
+    fn add_or_remove_row_when_cell_changed(
+        &mut self,
+        row_rev: &RowRevision,
+        cell_data: &Self::CellData,
+    ) -> Vec<GroupRowsNotificationPB> {
+        let mut changesets = vec![];
+        let rating = cell_data.to_string();
+        self.group_ctx.iter_mut_status_groups(|group| {
+            let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+            if group.id == rating {
+                if !group.contains_row(&row_rev.id) {
+                    let row_pb = RowPB::from(row_rev);
+                    changeset.inserted_rows.push(InsertedRowPB::new(row_pb.clone()));
+                    group.add_row(row_pb);
+                }
+            } else if group.contains_row(&row_rev.id) {
+                changeset.deleted_rows.push(row_rev.id.clone());
+                group.remove_row(&row_rev.id);
+            }
+
+            if !changeset.is_empty() {
+                changesets.push(changeset);
+            }
+        });
+        changesets
+    }
+
+    fn delete_row(&mut self, row_rev: &RowRevision, _cell_data: &Self::CellData) -> Vec<GroupRowsNotificationPB> {
+        let mut changesets = vec![];
+        self.group_ctx.iter_mut_groups(|group| {
+            let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+            if group.contains_row(&row_rev.id) {
+                changeset.deleted_rows.push(row_rev.id.clone());
+                group.remove_row(&row_rev.id);
+            }
+
+            if !changeset.is_empty() {
+                changesets.push(changeset);
+            }
+        });
+        changesets
+    }
+
+    fn move_row(
+        &mut self,
+        _cell_data: &Self::CellData,
+        mut context: MoveGroupRowContext,
+    ) -> Vec<GroupRowsNotificationPB> {
+        let mut group_changeset = vec![];
+        self.group_ctx.iter_mut_groups(|group| {
+            if let Some(changeset) = move_group_row(group, &mut context) {
+                group_changeset.push(changeset);
+            }
+        });
+        group_changeset
+    }
+}
+
+impl GroupController for RatingGroupController {
+    fn will_create_row(&mut self, row_rev: &mut RowRevision, field_rev: &FieldRevision, group_id: &str) {
+        match self.group_ctx.get_group(group_id) {
+            None => tracing::warn!("Can not find the group: {}", group_id),
+            Some((_, group)) => {
+                // The rows created in the no status group are not rated.
+                let rating = group.id.parse::<i64>().unwrap_or_default();
+                let cell_rev = insert_rating_cell(rating, field_rev);
+                row_rev.cells.insert(field_rev.id.clone(), cell_rev);
+            }
+        }
+    }
+
+    fn did_create_row(&mut self, row_pb: &RowPB, group_id: &str) {
+        if let Some(group) = self.group_ctx.get_mut_group(group_id) {
+            group.add_row(row_pb.clone())
+        }
+    }
+}
+
+pub struct RatingGroupGenerator();
+impl GroupGenerator for RatingGroupGenerator {
+    type Context = RatingGroupContext;
+    type TypeOptionType = RatingTypeOptionPB;
+
+    fn generate_groups(
+        field_rev: &FieldRevision,
+        _group_ctx: &Self::Context,
+        type_option: &Option<Self::TypeOptionType>,
+    ) -> GeneratedGroupContext {
+        // Each rating has its own group, the rows that are not rated are put into the no status group.
+        let max = type_option
+            .as_ref()
+            .map(|type_option| type_option.max)
+            .unwrap_or(DEFAULT_MAX_RATING);
+        let group_configs = (1..=max)
+            .map(|rating| GeneratedGroupConfig {
+                group_rev: GroupRevision::new(rating.to_string(), rating.to_string()),
+                filter_content: rating.to_string(),
+            })
+            .collect();
+
+        GeneratedGroupContext {
+            no_status_group: Some(make_no_status_group(field_rev)),
+            group_configs,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::entities::{GroupRowsNotificationPB, RowPB};
+use crate::entities::{GroupRowsNotificationPB, InsertedRowPB, RowPB};
 use crate::services::cell::insert_select_option_cell;
 use crate::services::field::{MultiSelectTypeOptionPB, SelectOptionCellDataPB, SelectOptionCellDataParser};
 use crate::services::group::action::GroupCustomize;
@@ -40,11 +40,17 @@ impl GroupCustomize for MultiSelectGroupController {
         changesets
     }
 
-    fn delete_row(&mut self, row_rev: &RowRevision, cell_data: &Self::CellData) -> Vec<GroupRowsNotificationPB> {
+    fn delete_row(&mut self, row_rev: &RowRevision, _cell_data: &Self::CellData) -> Vec<GroupRowsNotificationPB> {
+        // Removes the row from all the groups it's in, even if the options of the groups were
+        // removed from the cell before.
         let mut changesets = vec![];
         self.group_ctx.iter_mut_status_groups(|group| {
-            if let Some(changeset) = remove_select_option_row(group, cell_data, row_rev) {
-                changesets.push(changeset);
+            if group.contains_row(&row_rev.id) {
+                group.remove_row(&row_rev.id);
+                changesets.push(GroupRowsNotificationPB::delete(
+                    group.id.clone(),
+                    vec![row_rev.id.clone()],
+                ));
             }
         });
         changesets
@@ -52,16 +58,106 @@ impl GroupCustomize for MultiSelectGroupController {
 
     fn move_row(
         &mut self,
-        _cell_data: &Self::CellData,
+        cell_data: &Self::CellData,
         mut context: MoveGroupRowContext,
     ) -> Vec<GroupRowsNotificationPB> {
         let mut group_changeset = vec![];
+        match context.from_group_id.map(|from_group_id| from_group_id.to_owned()) {
+            // The row is only in the group that it's dropped into if the client doesn't tell
+            // which group it's dragged out of.
+            None => {
+                self.group_ctx.iter_mut_groups(|group| {
+                    if let Some(changeset) = move_group_row(group, &mut context) {
+                        group_changeset.push(changeset);
+                    }
+                });
+            }
+            // Reorders the row in the group, the row stays in the groups of the other options.
+            Some(from_group_id) if from_group_id == context.to_group_id => {
+                if let Some(group) = self.group_ctx.get_mut_group(&from_group_id) {
+                    if let Some(changeset) = move_group_row(group, &mut context) {
+                        group_changeset.push(changeset);
+                    }
+                }
+            }
+            Some(from_group_id) => {
+                group_changeset = self.move_row_between_groups(cell_data, &from_group_id, &mut context);
+            }
+        }
+        group_changeset
+    }
+}
+
+impl MultiSelectGroupController {
+    /// Replaces the option of the group that the row is dragged out of with the option of the
+    /// group it's dropped into, so the row stays in the groups of the other options. Dropping the
+    /// row into the no status group removes all the options of the cell.
+    fn move_row_between_groups(
+        &mut self,
+        cell_data: &SelectOptionCellDataPB,
+        from_group_id: &str,
+        context: &mut MoveGroupRowContext,
+    ) -> Vec<GroupRowsNotificationPB> {
+        let no_status_group_id = self.field_id.clone();
+        let to_group_id = context.to_group_id.to_owned();
+        let mut option_ids = cell_data
+            .select_options
+            .iter()
+            .map(|option| option.id.clone())
+            .collect::<Vec<String>>();
+        if to_group_id == no_status_group_id {
+            option_ids.clear();
+        } else {
+            option_ids.retain(|option_id| option_id != from_group_id);
+            if !option_ids.contains(&to_group_id) {
+                option_ids.push(to_group_id.clone());
+            }
+        }
+        let cell_rev = insert_select_option_cell(option_ids.clone(), context.field_rev);
+        context
+            .row_changeset
+            .cell_by_field_id
+            .insert(context.field_rev.id.clone(), cell_rev);
+
+        let row_pb = RowPB::from(context.row_rev);
+        let to_row_id = context.to_row_id.clone();
+        let mut changesets = vec![];
         self.group_ctx.iter_mut_groups(|group| {
-            if let Some(changeset) = move_group_row(group, &mut context) {
-                group_changeset.push(changeset);
+            let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+            if group.id == to_group_id {
+                let to_index = to_row_id.as_ref().and_then(|to_row_id| group.index_of_row(to_row_id));
+                if group.contains_row(&row_pb.id) {
+                    changeset.deleted_rows.push(row_pb.id.clone());
+                    group.remove_row(&row_pb.id);
+                }
+                let mut inserted_row = InsertedRowPB::new(row_pb.clone());
+                match to_index {
+                    Some(to_index) if to_index < group.number_of_row() => {
+                        inserted_row.index = Some(to_index as i32);
+                        group.insert_row(to_index, row_pb.clone());
+                    }
+                    _ => group.add_row(row_pb.clone()),
+                }
+                changeset.inserted_rows.push(inserted_row);
+            } else if group.contains_row(&row_pb.id) {
+                let is_selected = match group.id == no_status_group_id {
+                    true => option_ids.is_empty(),
+                    false => option_ids.contains(&group.id),
+                };
+                if is_selected {
+                    // The row stays in the group, but its card shows the new options.
+                    changeset.updated_rows.push(row_pb.clone());
+                } else {
+                    changeset.deleted_rows.push(row_pb.id.clone());
+                    group.remove_row(&row_pb.id);
+                }
+            }
+
+            if !changeset.is_empty() {
+                changesets.push(changeset);
             }
         });
-        group_changeset
+        changesets
     }
 }
 
@@ -1,5 +1,5 @@
 use crate::entities::{FieldType, GroupRowsNotificationPB, InsertedRowPB, RowPB};
-use crate::services::cell::{insert_checkbox_cell, insert_select_option_cell, insert_url_cell};
+use crate::services::cell::{insert_checkbox_cell, insert_person_cell, insert_select_option_cell, insert_url_cell};
 use crate::services::field::{SelectOptionCellDataPB, SelectOptionPB, CHECK};
 use crate::services::group::configuration::GroupContext;
 use crate::services::group::controller::MoveGroupRowContext;
@@ -13,25 +13,18 @@ pub fn add_or_remove_select_option_row(
     cell_data: &SelectOptionCellDataPB,
     row_rev: &RowRevision,
 ) -> Option<GroupRowsNotificationPB> {
+    // The row of the multi-select cell is in the group of every option of the cell.
     let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
-    if cell_data.select_options.is_empty() {
-        if group.contains_row(&row_rev.id) {
-            changeset.deleted_rows.push(row_rev.id.clone());
-            group.remove_row(&row_rev.id);
+    let is_selected = cell_data.select_options.iter().any(|option| option.id == group.id);
+    if is_selected {
+        if !group.contains_row(&row_rev.id) {
+            let row_pb = RowPB::from(row_rev);
+            changeset.inserted_rows.push(InsertedRowPB::new(row_pb.clone()));
+            group.add_row(row_pb);
         }
-    } else {
-        cell_data.select_options.iter().for_each(|option| {
-            if option.id == group.id {
-                if !group.contains_row(&row_rev.id) {
-                    let row_pb = RowPB::from(row_rev);
-                    changeset.inserted_rows.push(InsertedRowPB::new(row_pb.clone()));
-                    group.add_row(row_pb);
-                }
-            } else if group.contains_row(&row_rev.id) {
-                changeset.deleted_rows.push(row_rev.id.clone());
-                group.remove_row(&row_rev.id);
-            }
-        });
+    } else if group.contains_row(&row_rev.id) {
+        changeset.deleted_rows.push(row_rev.id.clone());
+        group.remove_row(&row_rev.id);
     }
 
     if changeset.is_empty() {
@@ -69,6 +62,7 @@ pub fn move_group_row(group: &mut Group, context: &mut MoveGroupRowContext) -> O
         field_rev,
         to_group_id,
         to_row_id,
+        ..
     } = context;
 
     let from_index = group.index_of_row(&row_rev.id);
@@ -147,6 +141,17 @@ pub fn make_inserted_cell_rev(group_id: &str, field_rev: &FieldRevision) -> Opti
             let cell_rev = insert_url_cell(group_id.to_owned(), field_rev);
             Some(cell_rev)
         }
+        FieldType::Person => {
+            // The id of the no status group is the id of the field, moving the row into it removes
+            // the members of the cell.
+            let member_ids = if group_id == field_rev.id {
+                vec![]
+            } else {
+                vec![group_id.to_owned()]
+            };
+            let cell_rev = insert_person_cell(member_ids, field_rev);
+            Some(cell_rev)
+        }
         _ => {
             tracing::warn!("Unknown field type: {:?}", field_type);
             None
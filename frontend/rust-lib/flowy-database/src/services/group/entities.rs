@@ -7,6 +7,7 @@ pub struct Group {
     pub name: String,
     pub is_default: bool,
     pub is_visible: bool,
+    pub is_collapsed: bool,
     pub(crate) rows: Vec<RowPB>,
 
     /// [filter_content] is used to determine which group the cell belongs to.
@@ -21,6 +22,7 @@ impl Group {
             field_id,
             is_default,
             is_visible: true,
+            is_collapsed: false,
             name,
             rows: vec![],
             filter_content,
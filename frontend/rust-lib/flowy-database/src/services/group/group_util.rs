@@ -2,15 +2,17 @@ use crate::entities::FieldType;
 use crate::services::group::configuration::GroupConfigurationReader;
 use crate::services::group::controller::GroupController;
 use crate::services::group::{
-    CheckboxGroupContext, CheckboxGroupController, DefaultGroupController, GroupConfigurationWriter,
-    MultiSelectGroupController, SelectOptionGroupContext, SingleSelectGroupController, URLGroupContext,
-    URLGroupController,
+    CheckboxGroupContext, CheckboxGroupController, DateGroupContext, DateGroupController, DefaultGroupController,
+    GroupConfigurationWriter, MultiSelectGroupController, NumberGroupContext, NumberGroupController,
+    PersonGroupContext, PersonGroupController, RatingGroupContext, RatingGroupController, SelectOptionGroupContext,
+    SingleSelectGroupController, URLGroupContext, URLGroupController,
 };
 use flowy_error::FlowyResult;
 use grid_model::{
     CheckboxGroupConfigurationRevision, DateGroupConfigurationRevision, FieldRevision, GroupConfigurationRevision,
-    GroupRevision, LayoutRevision, NumberGroupConfigurationRevision, RowRevision,
-    SelectOptionGroupConfigurationRevision, TextGroupConfigurationRevision, URLGroupConfigurationRevision,
+    GroupRevision, LayoutRevision, NumberGroupConfigurationRevision, PersonGroupConfigurationRevision,
+    RatingGroupConfigurationRevision, RowRevision, SelectOptionGroupConfigurationRevision,
+    TextGroupConfigurationRevision, URLGroupConfigurationRevision,
 };
 use std::sync::Arc;
 
@@ -71,6 +73,31 @@ where
             let controller = URLGroupController::new(&field_rev, configuration).await?;
             group_controller = Box::new(controller);
         }
+        FieldType::Rating => {
+            let configuration =
+                RatingGroupContext::new(view_id, field_rev.clone(), configuration_reader, configuration_writer).await?;
+            let controller = RatingGroupController::new(&field_rev, configuration).await?;
+            group_controller = Box::new(controller);
+        }
+        FieldType::Person => {
+            let configuration =
+                PersonGroupContext::new(view_id, field_rev.clone(), configuration_reader, configuration_writer).await?;
+            let controller = PersonGroupController::new(&field_rev, configuration).await?;
+            group_controller = Box::new(controller);
+        }
+        // The rows are grouped by their timestamps, which are read as the date cells.
+        FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => {
+            let configuration =
+                DateGroupContext::new(view_id, field_rev.clone(), configuration_reader, configuration_writer).await?;
+            let controller = DateGroupController::new(&field_rev, configuration).await?;
+            group_controller = Box::new(controller);
+        }
+        FieldType::Number => {
+            let configuration =
+                NumberGroupContext::new(view_id, field_rev.clone(), configuration_reader, configuration_writer).await?;
+            let controller = NumberGroupController::new(&field_rev, configuration).await?;
+            group_controller = Box::new(controller);
+        }
         _ => {
             group_controller = Box::new(DefaultGroupController::new(&field_rev));
         }
@@ -110,7 +137,7 @@ pub fn default_group_configuration(field_rev: &FieldRevision) -> GroupConfigurat
             GroupConfigurationRevision::new(field_id, field_type_rev, NumberGroupConfigurationRevision::default())
                 .unwrap()
         }
-        FieldType::DateTime => {
+        FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => {
             GroupConfigurationRevision::new(field_id, field_type_rev, DateGroupConfigurationRevision::default())
                 .unwrap()
         }
@@ -140,6 +167,23 @@ pub fn default_group_configuration(field_rev: &FieldRevision) -> GroupConfigurat
         FieldType::URL => {
             GroupConfigurationRevision::new(field_id, field_type_rev, URLGroupConfigurationRevision::default()).unwrap()
         }
+        FieldType::Rating => {
+            GroupConfigurationRevision::new(field_id, field_type_rev, RatingGroupConfigurationRevision::default())
+                .unwrap()
+        }
+        FieldType::Person => {
+            GroupConfigurationRevision::new(field_id, field_type_rev, PersonGroupConfigurationRevision::default())
+                .unwrap()
+        }
+        FieldType::Relation
+        | FieldType::Formula
+        | FieldType::Progress
+        | FieldType::Email
+        | FieldType::PhoneNumber
+        | FieldType::Attachment => {
+            GroupConfigurationRevision::new(field_id, field_type_rev, TextGroupConfigurationRevision::default())
+                .unwrap()
+        }
     }
 }
 
@@ -148,5 +192,6 @@ pub fn make_no_status_group(field_rev: &FieldRevision) -> GroupRevision {
         id: field_rev.id.clone(),
         name: format!("No {}", field_rev.name),
         visible: true,
+        collapsed: false,
     }
 }
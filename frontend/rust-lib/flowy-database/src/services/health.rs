@@ -0,0 +1,140 @@
+use crate::services::persistence::block_index::BlockIndexCache;
+use flowy_client_sync::client_database::{DatabaseRevisionPad, GridBlockRevisionPad};
+use flowy_error::FlowyResult;
+use grid_model::RowRevision;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// [DatabaseHealthIssue] describes an inconsistency found in the persisted data of a database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DatabaseHealthIssue {
+    /// The database references the block, but there are no revisions of the block.
+    MissingBlock { block_id: String },
+
+    /// The revisions of the block exist but can't be deserialized.
+    CorruptedBlock { block_id: String },
+
+    /// The row isn't indexed in the [BlockIndexCache], or it's indexed to another block.
+    MissingRowIndex { block_id: String, row_id: String },
+
+    /// The [BlockIndexCache] indexes a row to the block, but the block doesn't contain the row.
+    DanglingRowIndex { block_id: String, row_id: String },
+
+    /// The cell of the row references a field that doesn't exist.
+    CellOfDeletedField {
+        block_id: String,
+        row_id: String,
+        field_id: String,
+    },
+}
+
+impl DatabaseHealthIssue {
+    /// Returns true if `DatabaseManager::repair_database` is able to fix the issue.
+    pub fn is_repairable(&self) -> bool {
+        !matches!(self, DatabaseHealthIssue::CorruptedBlock { .. })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DatabaseHealthReport {
+    pub database_id: String,
+    pub issues: Vec<DatabaseHealthIssue>,
+}
+
+impl DatabaseHealthReport {
+    pub fn is_healthy(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// The state of a block that is loaded from its persisted revisions.
+pub(crate) enum BlockState {
+    Missing,
+    Corrupted,
+    Loaded(GridBlockRevisionPad),
+}
+
+impl BlockState {
+    pub(crate) fn row_revs(&self) -> Vec<Arc<RowRevision>> {
+        match self {
+            BlockState::Loaded(block_pad) => block_pad.get_row_revs::<String>(None).unwrap_or_default(),
+            _ => vec![],
+        }
+    }
+}
+
+/// Cross-checks the blocks of the database against the block metas, the fields and the
+/// [BlockIndexCache].
+pub(crate) fn check_database_health(
+    database_id: &str,
+    database_pad: &DatabaseRevisionPad,
+    blocks: &HashMap<String, BlockState>,
+    block_index_cache: &BlockIndexCache,
+) -> FlowyResult<DatabaseHealthReport> {
+    let field_ids = database_pad
+        .get_fields()
+        .iter()
+        .map(|field_rev| field_rev.id.clone())
+        .collect::<HashSet<String>>();
+
+    let mut issues = vec![];
+    for block_meta_rev in database_pad.get_block_meta_revs() {
+        let block_id = &block_meta_rev.block_id;
+        let row_revs = match blocks.get(block_id) {
+            None | Some(BlockState::Missing) => {
+                issues.push(DatabaseHealthIssue::MissingBlock {
+                    block_id: block_id.clone(),
+                });
+                vec![]
+            }
+            Some(BlockState::Corrupted) => {
+                issues.push(DatabaseHealthIssue::CorruptedBlock {
+                    block_id: block_id.clone(),
+                });
+                continue;
+            }
+            Some(state) => state.row_revs(),
+        };
+
+        let row_ids = row_revs
+            .iter()
+            .map(|row_rev| row_rev.id.clone())
+            .collect::<HashSet<String>>();
+        for row_rev in row_revs.iter() {
+            let is_indexed = block_index_cache
+                .get_block_id(&row_rev.id)
+                .map(|indexed_block_id| &indexed_block_id == block_id)
+                .unwrap_or(false);
+            if !is_indexed {
+                issues.push(DatabaseHealthIssue::MissingRowIndex {
+                    block_id: block_id.clone(),
+                    row_id: row_rev.id.clone(),
+                });
+            }
+
+            for field_id in row_rev.cells.keys() {
+                if !field_ids.contains(field_id) {
+                    issues.push(DatabaseHealthIssue::CellOfDeletedField {
+                        block_id: block_id.clone(),
+                        row_id: row_rev.id.clone(),
+                        field_id: field_id.clone(),
+                    });
+                }
+            }
+        }
+
+        for row_id in block_index_cache.get_row_ids(block_id)? {
+            if !row_ids.contains(&row_id) {
+                issues.push(DatabaseHealthIssue::DanglingRowIndex {
+                    block_id: block_id.clone(),
+                    row_id,
+                });
+            }
+        }
+    }
+
+    Ok(DatabaseHealthReport {
+        database_id: database_id.to_owned(),
+        issues,
+    })
+}
@@ -1,16 +1,28 @@
 mod util;
 
+pub mod backup;
 pub mod block_editor;
 pub mod block_manager;
 pub mod cell;
+pub mod clipboard;
+mod convert_field;
+mod duplicate_field;
 pub mod field;
 pub mod filter;
 pub mod grid_editor;
 mod grid_editor_trait_impl;
 pub mod group;
+pub mod health;
 pub mod persistence;
+mod repair;
 mod retry;
+mod revision_diff;
+pub mod revision_preview;
+pub mod revision_stats;
 pub mod row;
 pub mod setting;
+pub mod snapshot;
 pub mod sort;
+pub mod trash;
+pub mod undo;
 pub mod view_editor;
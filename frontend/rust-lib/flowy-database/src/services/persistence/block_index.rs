@@ -1,43 +1,160 @@
 use crate::services::persistence::GridDatabase;
 use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
-use flowy_error::FlowyResult;
+use flowy_error::{FlowyError, FlowyResult};
 use flowy_sqlite::{
     prelude::*,
     schema::{grid_block_index_table, grid_block_index_table::dsl},
 };
+use parking_lot::RwLock;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 /// Allow getting the block id from row id.
 pub struct BlockIndexCache {
-    database: Arc<dyn GridDatabase>,
+    backend: BlockIndexBackend,
+}
+
+enum BlockIndexBackend {
+    SQLite(Arc<dyn GridDatabase>),
+    /// The block ids keyed by row id.
+    Memory(RwLock<HashMap<String, String>>),
 }
 
 impl BlockIndexCache {
     pub fn new(database: Arc<dyn GridDatabase>) -> Self {
-        Self { database }
+        Self {
+            backend: BlockIndexBackend::SQLite(database),
+        }
+    }
+
+    /// Keeps the index in memory instead of sqlite. It's lost when the cache is dropped.
+    pub fn new_memory() -> Self {
+        Self {
+            backend: BlockIndexBackend::Memory(RwLock::new(HashMap::new())),
+        }
     }
 
     pub fn get_block_id(&self, row_id: &str) -> FlowyResult<String> {
-        let conn = self.database.db_connection()?;
-        let block_id = dsl::grid_block_index_table
-            .filter(grid_block_index_table::row_id.eq(row_id))
-            .select(grid_block_index_table::block_id)
-            .first::<String>(&*conn)?;
+        match &self.backend {
+            BlockIndexBackend::SQLite(database) => {
+                let conn = database.db_connection()?;
+                let block_id = dsl::grid_block_index_table
+                    .filter(grid_block_index_table::row_id.eq(row_id))
+                    .select(grid_block_index_table::block_id)
+                    .first::<String>(&*conn)?;
 
-        Ok(block_id)
+                Ok(block_id)
+            }
+            BlockIndexBackend::Memory(index) => index
+                .read()
+                .get(row_id)
+                .cloned()
+                .ok_or_else(|| FlowyError::record_not_found().context(format!("Row {} is not indexed", row_id))),
+        }
     }
 
     pub fn insert(&self, block_id: &str, row_id: &str) -> FlowyResult<()> {
-        let conn = self.database.db_connection()?;
+        match &self.backend {
+            BlockIndexBackend::SQLite(database) => {
+                let conn = database.db_connection()?;
+                Self::insert_with_conn(block_id, row_id, &conn)
+            }
+            BlockIndexBackend::Memory(index) => {
+                index.write().insert(row_id.to_owned(), block_id.to_owned());
+                Ok(())
+            }
+        }
+    }
+
+    /// Indexes the rows to the block in one transaction.
+    pub fn insert_rows(&self, block_id: &str, row_ids: &[String]) -> FlowyResult<()> {
+        match &self.backend {
+            BlockIndexBackend::SQLite(database) => {
+                let conn = database.db_connection()?;
+                conn.immediate_transaction::<_, FlowyError, _>(|| {
+                    for row_id in row_ids {
+                        Self::insert_with_conn(block_id, row_id, &conn)?;
+                    }
+                    Ok(())
+                })
+            }
+            BlockIndexBackend::Memory(index) => {
+                let mut index = index.write();
+                for row_id in row_ids {
+                    index.insert(row_id.clone(), block_id.to_owned());
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Same as the `insert` method, but uses the passed-in connection. So it can be wrapped in the
+    /// caller's transaction.
+    pub(crate) fn insert_with_conn(block_id: &str, row_id: &str, conn: &SqliteConnection) -> FlowyResult<()> {
         let item = IndexItem {
             row_id: row_id.to_string(),
             block_id: block_id.to_string(),
         };
         let _ = diesel::replace_into(grid_block_index_table::table)
             .values(item)
-            .execute(&*conn)?;
+            .execute(conn)?;
         Ok(())
     }
+
+    /// Returns the ids of the rows that are indexed to the block.
+    pub fn get_row_ids(&self, block_id: &str) -> FlowyResult<Vec<String>> {
+        match &self.backend {
+            BlockIndexBackend::SQLite(database) => {
+                let conn = database.db_connection()?;
+                let row_ids = dsl::grid_block_index_table
+                    .filter(grid_block_index_table::block_id.eq(block_id))
+                    .select(grid_block_index_table::row_id)
+                    .load::<String>(&*conn)?;
+                Ok(row_ids)
+            }
+            BlockIndexBackend::Memory(index) => Ok(index
+                .read()
+                .iter()
+                .filter(|(_, id)| id.as_str() == block_id)
+                .map(|(row_id, _)| row_id.clone())
+                .collect()),
+        }
+    }
+
+    /// Removes the index of the rows.
+    pub fn remove_rows(&self, row_ids: &[String]) -> FlowyResult<()> {
+        match &self.backend {
+            BlockIndexBackend::SQLite(database) => {
+                let conn = database.db_connection()?;
+                let sql = dsl::grid_block_index_table.filter(grid_block_index_table::row_id.eq_any(row_ids));
+                let _ = diesel::delete(sql).execute(&*conn)?;
+                Ok(())
+            }
+            BlockIndexBackend::Memory(index) => {
+                let mut index = index.write();
+                for row_id in row_ids {
+                    index.remove(row_id);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Removes the index of all the rows that belong to the block.
+    pub fn remove_block(&self, block_id: &str) -> FlowyResult<()> {
+        match &self.backend {
+            BlockIndexBackend::SQLite(database) => {
+                let conn = database.db_connection()?;
+                let sql = dsl::grid_block_index_table.filter(grid_block_index_table::block_id.eq(block_id));
+                let _ = diesel::delete(sql).execute(&*conn)?;
+                Ok(())
+            }
+            BlockIndexBackend::Memory(index) => {
+                index.write().retain(|_, id| id.as_str() != block_id);
+                Ok(())
+            }
+        }
+    }
 }
 
 #[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
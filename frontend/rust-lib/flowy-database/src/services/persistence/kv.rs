@@ -1,12 +1,15 @@
 use crate::services::persistence::GridDatabase;
 use ::diesel::{query_dsl::*, ExpressionMethods};
 use bytes::Bytes;
+use diesel::result::OptionalExtension;
 use diesel::SqliteConnection;
-use flowy_error::{FlowyError, FlowyResult};
+use flowy_error::{internal_error, FlowyError, FlowyResult};
 use flowy_sqlite::{
     prelude::*,
     schema::{kv_table, kv_table::dsl},
 };
+use parking_lot::RwLock;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 #[derive(PartialEq, Eq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
@@ -17,6 +20,12 @@ pub struct KeyValue {
     value: Vec<u8>,
 }
 
+impl KeyValue {
+    pub fn new(key: String, value: Vec<u8>) -> Self {
+        Self { key, value }
+    }
+}
+
 pub trait KVTransaction {
     fn get<T: TryFrom<Bytes, Error = ::protobuf::ProtobufError>>(&self, key: &str) -> FlowyResult<Option<T>>;
     fn set<T: Into<KeyValue>>(&self, value: T) -> FlowyResult<()>;
@@ -29,52 +38,176 @@ pub trait KVTransaction {
 }
 
 pub struct DatabaseKVPersistence {
-    database: Arc<dyn GridDatabase>,
+    backend: KVBackend,
+}
+
+enum KVBackend {
+    SQLite(Arc<dyn GridDatabase>),
+    Memory(MemoryKV),
 }
 
 impl DatabaseKVPersistence {
     pub fn new(database: Arc<dyn GridDatabase>) -> Self {
-        Self { database }
+        Self {
+            backend: KVBackend::SQLite(database),
+        }
     }
 
+    /// Keeps the values in memory instead of sqlite. They are lost when the persistence is dropped.
+    pub fn new_memory() -> Self {
+        Self {
+            backend: KVBackend::Memory(MemoryKV::default()),
+        }
+    }
+
+    /// Runs the `f` in a sqlite transaction. Returns an error if the values are kept in memory.
     pub fn begin_transaction<F, O>(&self, f: F) -> FlowyResult<O>
     where
         F: for<'a> FnOnce(SqliteTransaction<'a>) -> FlowyResult<O>,
     {
-        let conn = self.database.db_connection()?;
+        let conn = match &self.backend {
+            KVBackend::SQLite(database) => database.db_connection()?,
+            KVBackend::Memory(_) => {
+                return Err(FlowyError::internal().context("The key-values are kept in memory, not in sqlite"));
+            }
+        };
         conn.immediate_transaction::<_, FlowyError, _>(|| {
             let sql_transaction = SqliteTransaction { conn: &conn };
             f(sql_transaction)
         })
     }
+
+    /// Returns true if there is a value with the key.
+    pub fn contains(&self, key: &str) -> FlowyResult<bool> {
+        let database = match &self.backend {
+            KVBackend::SQLite(database) => database,
+            KVBackend::Memory(kv) => return Ok(kv.values.read().contains_key(key)),
+        };
+        let conn = database.db_connection()?;
+        let count = dsl::kv_table
+            .filter(kv_table::key.eq(key))
+            .count()
+            .get_result::<i64>(&*conn)?;
+        Ok(count > 0)
+    }
+
+    /// Returns the raw bytes of the value with the key, or None if there is no such value.
+    pub fn get_bytes(&self, key: &str) -> FlowyResult<Option<Vec<u8>>> {
+        let database = match &self.backend {
+            KVBackend::SQLite(database) => database,
+            KVBackend::Memory(kv) => return Ok(kv.values.read().get(key).cloned()),
+        };
+        let conn = database.db_connection()?;
+        let item = dsl::kv_table
+            .filter(kv_table::key.eq(key))
+            .first::<KeyValue>(&*conn)
+            .optional()?;
+        Ok(item.map(|item| item.value))
+    }
 }
 
 impl KVTransaction for DatabaseKVPersistence {
     fn get<T: TryFrom<Bytes, Error = ::protobuf::ProtobufError>>(&self, key: &str) -> FlowyResult<Option<T>> {
-        self.begin_transaction(|transaction| transaction.get(key))
+        match &self.backend {
+            KVBackend::SQLite(_) => self.begin_transaction(|transaction| transaction.get(key)),
+            KVBackend::Memory(kv) => kv.get(key),
+        }
     }
 
     fn set<T: Into<KeyValue>>(&self, value: T) -> FlowyResult<()> {
-        self.begin_transaction(|transaction| transaction.set(value))
+        match &self.backend {
+            KVBackend::SQLite(_) => self.begin_transaction(|transaction| transaction.set(value)),
+            KVBackend::Memory(kv) => kv.set(value),
+        }
     }
 
     fn remove(&self, key: &str) -> FlowyResult<()> {
-        self.begin_transaction(|transaction| transaction.remove(key))
+        match &self.backend {
+            KVBackend::SQLite(_) => self.begin_transaction(|transaction| transaction.remove(key)),
+            KVBackend::Memory(kv) => kv.remove(key),
+        }
     }
 
     fn batch_get<T: TryFrom<Bytes, Error = ::protobuf::ProtobufError>>(
         &self,
         keys: Vec<String>,
     ) -> FlowyResult<Vec<T>> {
-        self.begin_transaction(|transaction| transaction.batch_get(keys))
+        match &self.backend {
+            KVBackend::SQLite(_) => self.begin_transaction(|transaction| transaction.batch_get(keys)),
+            KVBackend::Memory(kv) => kv.batch_get(keys),
+        }
     }
 
     fn batch_set<T: Into<KeyValue>>(&self, values: Vec<T>) -> FlowyResult<()> {
-        self.begin_transaction(|transaction| transaction.batch_set(values))
+        match &self.backend {
+            KVBackend::SQLite(_) => self.begin_transaction(|transaction| transaction.batch_set(values)),
+            KVBackend::Memory(kv) => kv.batch_set(values),
+        }
     }
 
     fn batch_remove(&self, keys: Vec<String>) -> FlowyResult<()> {
-        self.begin_transaction(|transaction| transaction.batch_remove(keys))
+        match &self.backend {
+            KVBackend::SQLite(_) => self.begin_transaction(|transaction| transaction.batch_remove(keys)),
+            KVBackend::Memory(kv) => kv.batch_remove(keys),
+        }
+    }
+}
+
+#[derive(Default)]
+struct MemoryKV {
+    values: RwLock<HashMap<String, Vec<u8>>>,
+}
+
+impl KVTransaction for MemoryKV {
+    fn get<T: TryFrom<Bytes, Error = ::protobuf::ProtobufError>>(&self, key: &str) -> FlowyResult<Option<T>> {
+        match self.values.read().get(key) {
+            None => Ok(None),
+            Some(value) => {
+                let value = T::try_from(Bytes::from(value.clone())).map_err(internal_error)?;
+                Ok(Some(value))
+            }
+        }
+    }
+
+    fn set<T: Into<KeyValue>>(&self, value: T) -> FlowyResult<()> {
+        let item: KeyValue = value.into();
+        self.values.write().insert(item.key, item.value);
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> FlowyResult<()> {
+        self.values.write().remove(key);
+        Ok(())
+    }
+
+    fn batch_get<T: TryFrom<Bytes, Error = ::protobuf::ProtobufError>>(
+        &self,
+        keys: Vec<String>,
+    ) -> FlowyResult<Vec<T>> {
+        let mut values = vec![];
+        for key in keys {
+            if let Some(value) = self.get(&key)? {
+                values.push(value);
+            }
+        }
+        Ok(values)
+    }
+
+    fn batch_set<T: Into<KeyValue>>(&self, values: Vec<T>) -> FlowyResult<()> {
+        let mut map = self.values.write();
+        for value in values {
+            let item: KeyValue = value.into();
+            map.insert(item.key, item.value);
+        }
+        Ok(())
+    }
+
+    fn batch_remove(&self, keys: Vec<String>) -> FlowyResult<()> {
+        let mut map = self.values.write();
+        for key in keys {
+            map.remove(&key);
+        }
+        Ok(())
     }
 }
 
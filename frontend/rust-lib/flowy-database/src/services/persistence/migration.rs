@@ -1,11 +1,11 @@
 use crate::manager::DatabaseUser;
-use crate::services::persistence::rev_sqlite::SQLiteDatabaseRevisionPersistence;
-use crate::services::persistence::GridDatabase;
+use crate::services::persistence::rev_store::GridRevisionDiskCache;
 use bytes::Bytes;
 use flowy_client_sync::client_database::{make_database_rev_json_str, DatabaseOperationsBuilder, DatabaseRevisionPad};
 use flowy_error::FlowyResult;
 use flowy_revision::reset::{RevisionResettable, RevisionStructReset};
 use flowy_sqlite::kv::KV;
+use flowy_sqlite::ConnectionPool;
 use grid_model::DatabaseRevision;
 use lib_infra::util::md5;
 use revision_model::Revision;
@@ -15,34 +15,40 @@ const V1_MIGRATION: &str = "GRID_V1_MIGRATION";
 
 pub(crate) struct DatabaseMigration {
     user: Arc<dyn DatabaseUser>,
-    database: Arc<dyn GridDatabase>,
 }
 
 impl DatabaseMigration {
-    pub fn new(user: Arc<dyn DatabaseUser>, database: Arc<dyn GridDatabase>) -> Self {
-        Self { user, database }
+    pub fn new(user: Arc<dyn DatabaseUser>) -> Self {
+        Self { user }
     }
 
-    pub async fn run_v1_migration(&self, grid_id: &str) -> FlowyResult<()> {
+    /// Migrates the revisions of the database that are stored in the `disk_cache`.
+    pub async fn run_v1_migration(
+        &self,
+        grid_id: &str,
+        disk_cache: GridRevisionDiskCache<Arc<ConnectionPool>>,
+    ) -> FlowyResult<()> {
         let user_id = self.user.user_id()?;
         let key = migration_flag_key(&user_id, V1_MIGRATION, grid_id);
         if KV::get_bool(&key) {
             return Ok(());
         }
-        self.migration_grid_rev_struct(grid_id).await?;
+        self.migration_grid_rev_struct(grid_id, disk_cache).await?;
         tracing::trace!("Run grid:{} v1 migration", grid_id);
         KV::set_bool(&key, true);
         Ok(())
     }
 
-    pub async fn migration_grid_rev_struct(&self, grid_id: &str) -> FlowyResult<()> {
+    pub async fn migration_grid_rev_struct(
+        &self,
+        grid_id: &str,
+        disk_cache: GridRevisionDiskCache<Arc<ConnectionPool>>,
+    ) -> FlowyResult<()> {
         let object = GridRevisionResettable {
             grid_id: grid_id.to_owned(),
         };
         let user_id = self.user.user_id()?;
-        let pool = self.database.db_pool()?;
-        let disk_cache = SQLiteDatabaseRevisionPersistence::new(&user_id, pool);
-        let reset = RevisionStructReset::new(&user_id, object, Arc::new(disk_cache));
+        let reset = RevisionStructReset::new(&user_id, object, disk_cache);
         reset.run().await
     }
 }
@@ -1,3 +1,7 @@
+use crate::services::persistence::block_index::BlockIndexCache;
+use crate::services::persistence::kv::DatabaseKVPersistence;
+use crate::services::persistence::rev_memory::MemoryGridRevisionStoreFactory;
+use crate::services::persistence::rev_store::{GridRevisionStoreFactory, SQLiteGridRevisionStoreFactory};
 use flowy_error::FlowyError;
 use flowy_sqlite::{ConnectionPool, DBConnection};
 use std::sync::Arc;
@@ -5,7 +9,9 @@ use std::sync::Arc;
 pub mod block_index;
 pub mod kv;
 pub mod migration;
+pub mod rev_memory;
 pub mod rev_sqlite;
+pub mod rev_store;
 
 pub trait GridDatabase: Send + Sync {
     fn db_pool(&self) -> Result<Arc<ConnectionPool>, FlowyError>;
@@ -16,3 +22,39 @@ pub trait GridDatabase: Send + Sync {
         Ok(conn)
     }
 }
+
+/// Where the [DatabaseManager](crate::manager::DatabaseManager) stores the databases. The revisions
+/// and the snapshots are stored with the stores made by the [GridRevisionStoreFactory], the
+/// key-values and the block index are stored in sqlite or in memory.
+pub struct DatabaseStorage {
+    pub(crate) rev_store_factory: Arc<dyn GridRevisionStoreFactory<Arc<ConnectionPool>>>,
+    pub(crate) kv_persistence: Arc<DatabaseKVPersistence>,
+    pub(crate) block_index_cache: Arc<BlockIndexCache>,
+}
+
+impl DatabaseStorage {
+    /// Stores everything in the sqlite database.
+    pub fn sqlite(database: Arc<dyn GridDatabase>) -> Self {
+        Self {
+            rev_store_factory: Arc::new(SQLiteGridRevisionStoreFactory::new(database.clone())),
+            kv_persistence: Arc::new(DatabaseKVPersistence::new(database.clone())),
+            block_index_cache: Arc::new(BlockIndexCache::new(database)),
+        }
+    }
+
+    /// Keeps everything in memory, so no sqlite handle is required. Everything is lost when the
+    /// manager that owns the storage is dropped.
+    pub fn memory() -> Self {
+        Self::with_rev_store_factory(Arc::new(MemoryGridRevisionStoreFactory::new()))
+    }
+
+    /// Stores the revisions and the snapshots with the stores made by the `rev_store_factory`, the
+    /// key-values and the block index are kept in memory.
+    pub fn with_rev_store_factory(rev_store_factory: Arc<dyn GridRevisionStoreFactory<Arc<ConnectionPool>>>) -> Self {
+        Self {
+            rev_store_factory,
+            kv_persistence: Arc::new(DatabaseKVPersistence::new_memory()),
+            block_index_cache: Arc::new(BlockIndexCache::new_memory()),
+        }
+    }
+}
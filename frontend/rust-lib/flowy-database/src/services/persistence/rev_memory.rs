@@ -0,0 +1,436 @@
+use crate::services::persistence::rev_sqlite::{RevisionAuthor, RevisionCipher, RevisionTableStats};
+use crate::services::persistence::rev_store::{
+    GridRevisionDiskCache, GridRevisionObject, GridRevisionStore, GridRevisionStoreFactory, GridRevisionStoreOptions,
+};
+use crate::services::snapshot::DatabaseSnapshotPolicy;
+use bytes::Bytes;
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_revision::{NamedRevisionSnapshot, RevisionSnapshot, RevisionSnapshotDiskCache};
+use flowy_revision_persistence::{RevisionChangeset, RevisionDiskCache, RevisionState, SyncRecord};
+use parking_lot::RwLock;
+use revision_model::{Revision, RevisionRange};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+
+/// Keeps the revisions and the snapshots of the databases, their blocks and their views in memory.
+/// They are lost when the factory is dropped. The objects that are made by the same factory share
+/// the stores, so reopening a database reads the revisions that were written before.
+///
+/// The cipher of the [GridRevisionStoreOptions] is ignored because nothing is written to disk.
+#[derive(Default)]
+pub struct MemoryGridRevisionStoreFactory {
+    records: RwLock<HashMap<GridRevisionObject, Arc<MemoryRevisionRecords>>>,
+    snapshot_disk_caches: RwLock<HashMap<String, Arc<MemoryGridRevisionSnapshotDiskCache>>>,
+}
+
+impl MemoryGridRevisionStoreFactory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn records(&self, object: GridRevisionObject) -> Arc<MemoryRevisionRecords> {
+        self.records.write().entry(object).or_default().clone()
+    }
+}
+
+impl<C: 'static> GridRevisionStoreFactory<C> for MemoryGridRevisionStoreFactory {
+    fn make_store(
+        &self,
+        user_id: &str,
+        object: GridRevisionObject,
+        object_id: &str,
+        options: GridRevisionStoreOptions,
+    ) -> FlowyResult<Arc<dyn GridRevisionStore<C>>> {
+        let snapshot_disk_cache = self
+            .snapshot_disk_caches
+            .write()
+            .entry(object.snapshot_object_id(object_id))
+            .or_insert_with(|| Arc::new(MemoryGridRevisionSnapshotDiskCache::new()))
+            .clone();
+        *snapshot_disk_cache.policy.write() = options.snapshot_policy;
+        let disk_cache = MemoryGridRevisionDiskCache {
+            records: self.records(object),
+            user_id: user_id.to_owned(),
+            device_id: options.device_id,
+        };
+        Ok(Arc::new(MemoryGridRevisionStore {
+            object_id: object_id.to_owned(),
+            disk_cache: Arc::new(disk_cache),
+            snapshot_disk_cache,
+        }))
+    }
+
+    fn read_database_ids(&self) -> FlowyResult<Vec<String>> {
+        let records = self.records(GridRevisionObject::Database);
+        let database_ids = records
+            .read()
+            .iter()
+            .filter(|(_, object_records)| !object_records.is_empty())
+            .map(|(database_id, _)| database_id.clone())
+            .collect();
+        Ok(database_ids)
+    }
+
+    fn read_view_ids(&self) -> FlowyResult<Vec<String>> {
+        let records = self.records(GridRevisionObject::View);
+        let view_ids = records
+            .read()
+            .iter()
+            .filter(|(_, object_records)| !object_records.is_empty())
+            .map(|(view_id, _)| view_id.clone())
+            .collect();
+        Ok(view_ids)
+    }
+
+    fn read_total_block_stats(&self) -> FlowyResult<RevisionTableStats> {
+        let records = self.records(GridRevisionObject::Block);
+        let stats = records
+            .read()
+            .values()
+            .flat_map(|object_records| object_records.values())
+            .fold(RevisionTableStats::default(), |mut stats, record| {
+                stats.number_of_revisions += 1;
+                stats.payload_bytes += record.record.revision.bytes.len();
+                stats
+            });
+        Ok(stats)
+    }
+
+    fn reset_objects(
+        &self,
+        user_id: &str,
+        revisions: Vec<(GridRevisionObject, Revision)>,
+        options: GridRevisionStoreOptions,
+    ) -> FlowyResult<()> {
+        let author = RevisionAuthor::now(user_id, &options.device_id);
+        for (object, revision) in revisions {
+            let records = self.records(object);
+            let mut records = records.write();
+            let object_records = records.entry(revision.object_id.clone()).or_default();
+            object_records.clear();
+            object_records.insert(
+                revision.rev_id,
+                MemoryRevisionRecord {
+                    record: SyncRecord {
+                        revision,
+                        state: RevisionState::Sync,
+                        write_to_disk: false,
+                    },
+                    author: author.clone(),
+                },
+            );
+        }
+        Ok(())
+    }
+}
+
+struct MemoryGridRevisionStore {
+    object_id: String,
+    disk_cache: Arc<MemoryGridRevisionDiskCache>,
+    snapshot_disk_cache: Arc<MemoryGridRevisionSnapshotDiskCache>,
+}
+
+impl<C: 'static> GridRevisionStore<C> for MemoryGridRevisionStore {
+    fn object_id(&self) -> &str {
+        &self.object_id
+    }
+
+    fn revision_disk_cache(&self) -> GridRevisionDiskCache<C> {
+        self.disk_cache.clone()
+    }
+
+    fn snapshot_disk_cache(&self) -> Arc<dyn RevisionSnapshotDiskCache> {
+        self.snapshot_disk_cache.clone()
+    }
+
+    fn read_revision_authors(&self, range: &RevisionRange) -> FlowyResult<BTreeMap<i64, RevisionAuthor>> {
+        if range.end < range.start {
+            return Ok(BTreeMap::new());
+        }
+        let authors = self
+            .disk_cache
+            .records
+            .read()
+            .get(&self.object_id)
+            .map(|object_records| {
+                object_records
+                    .range(range.start..=range.end)
+                    .map(|(rev_id, record)| (*rev_id, record.author.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(authors)
+    }
+
+    fn read_stats(&self) -> FlowyResult<RevisionTableStats> {
+        let records = self.disk_cache.records.read();
+        let mut stats = RevisionTableStats::default();
+        if let Some(object_records) = records.get(&self.object_id) {
+            stats.number_of_revisions = object_records.len();
+            stats.payload_bytes = object_records
+                .values()
+                .map(|record| record.record.revision.bytes.len())
+                .sum();
+        }
+        Ok(stats)
+    }
+
+    fn read_snapshot_stats(&self) -> FlowyResult<(usize, Option<i64>)> {
+        let snapshots = self.snapshot_disk_cache.snapshots.read();
+        let last_snapshot_at = snapshots.iter().map(|snapshot| snapshot.timestamp).max();
+        Ok((snapshots.len(), last_snapshot_at))
+    }
+
+    fn delete(&self) -> FlowyResult<()> {
+        self.disk_cache.records.write().remove(&self.object_id);
+        self.snapshot_disk_cache.snapshots.write().clear();
+        self.snapshot_disk_cache.named_snapshots.write().clear();
+        Ok(())
+    }
+
+    fn reencrypt(&self, _new_cipher: Option<&Arc<dyn RevisionCipher>>) -> FlowyResult<()> {
+        Ok(())
+    }
+}
+
+/// A [SyncRecord] with the author who wrote it.
+struct MemoryRevisionRecord {
+    record: SyncRecord,
+    author: RevisionAuthor,
+}
+
+/// The records of each object, ordered by their rev_ids.
+type MemoryRevisionRecords = RwLock<HashMap<String, BTreeMap<i64, MemoryRevisionRecord>>>;
+
+/// The records of the objects of the same [GridRevisionObject], the records are written by the
+/// user with the `user_id` on the device with the `device_id`.
+struct MemoryGridRevisionDiskCache {
+    records: Arc<MemoryRevisionRecords>,
+    user_id: String,
+    device_id: String,
+}
+
+impl MemoryGridRevisionDiskCache {
+    fn insert_records(
+        &self,
+        records: &mut HashMap<String, BTreeMap<i64, MemoryRevisionRecord>>,
+        revision_records: Vec<SyncRecord>,
+    ) {
+        let author = RevisionAuthor::now(&self.user_id, &self.device_id);
+        for mut record in revision_records {
+            record.write_to_disk = false;
+            records.entry(record.revision.object_id.clone()).or_default().insert(
+                record.revision.rev_id,
+                MemoryRevisionRecord {
+                    record,
+                    author: author.clone(),
+                },
+            );
+        }
+    }
+
+    fn delete_records(
+        records: &mut HashMap<String, BTreeMap<i64, MemoryRevisionRecord>>,
+        object_id: &str,
+        rev_ids: Option<Vec<i64>>,
+    ) {
+        match rev_ids {
+            None => {
+                records.remove(object_id);
+            }
+            Some(rev_ids) => {
+                if let Some(object_records) = records.get_mut(object_id) {
+                    for rev_id in rev_ids {
+                        object_records.remove(&rev_id);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<C> RevisionDiskCache<C> for MemoryGridRevisionDiskCache {
+    type Error = FlowyError;
+
+    fn create_revision_records(&self, revision_records: Vec<SyncRecord>) -> Result<(), Self::Error> {
+        self.insert_records(&mut self.records.write(), revision_records);
+        Ok(())
+    }
+
+    fn get_connection(&self) -> Result<C, Self::Error> {
+        Err(FlowyError::internal().context("The revisions in memory don't have a connection"))
+    }
+
+    fn read_revision_records(
+        &self,
+        object_id: &str,
+        rev_ids: Option<Vec<i64>>,
+    ) -> Result<Vec<SyncRecord>, Self::Error> {
+        let records = self.records.read();
+        let object_records = match records.get(object_id) {
+            None => return Ok(vec![]),
+            Some(object_records) => object_records,
+        };
+        let records = object_records
+            .values()
+            .filter(|record| match &rev_ids {
+                None => true,
+                Some(rev_ids) => rev_ids.contains(&record.record.revision.rev_id),
+            })
+            .map(|record| record.record.clone())
+            .collect();
+        Ok(records)
+    }
+
+    fn read_revision_records_with_range(
+        &self,
+        object_id: &str,
+        range: &RevisionRange,
+    ) -> Result<Vec<SyncRecord>, Self::Error> {
+        if range.end < range.start {
+            return Ok(vec![]);
+        }
+        let records = self
+            .records
+            .read()
+            .get(object_id)
+            .map(|object_records| {
+                object_records
+                    .range(range.start..=range.end)
+                    .map(|(_, record)| record.record.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(records)
+    }
+
+    fn update_revision_record(&self, changesets: Vec<RevisionChangeset>) -> FlowyResult<()> {
+        let mut records = self.records.write();
+        for changeset in changesets {
+            if let Some(record) = records
+                .get_mut(&changeset.object_id)
+                .and_then(|object_records| object_records.get_mut(&changeset.rev_id))
+            {
+                record.record.state = changeset.state;
+            }
+        }
+        Ok(())
+    }
+
+    fn delete_revision_records(&self, object_id: &str, rev_ids: Option<Vec<i64>>) -> Result<(), Self::Error> {
+        Self::delete_records(&mut self.records.write(), object_id, rev_ids);
+        Ok(())
+    }
+
+    fn delete_and_insert_records(
+        &self,
+        object_id: &str,
+        deleted_rev_ids: Option<Vec<i64>>,
+        inserted_records: Vec<SyncRecord>,
+    ) -> Result<(), Self::Error> {
+        let mut records = self.records.write();
+        Self::delete_records(&mut records, object_id, deleted_rev_ids);
+        self.insert_records(&mut records, inserted_records);
+        Ok(())
+    }
+}
+
+/// The snapshots of a database, the latest one last.
+struct MemoryGridRevisionSnapshotDiskCache {
+    snapshots: RwLock<Vec<RevisionSnapshot>>,
+    named_snapshots: RwLock<Vec<NamedRevisionSnapshot>>,
+    policy: RwLock<DatabaseSnapshotPolicy>,
+    // The time, in milliseconds, when the last snapshot was written or this cache was created.
+    last_snapshot_at: AtomicI64,
+}
+
+impl MemoryGridRevisionSnapshotDiskCache {
+    fn new() -> Self {
+        Self {
+            snapshots: RwLock::new(vec![]),
+            named_snapshots: RwLock::new(vec![]),
+            policy: RwLock::new(DatabaseSnapshotPolicy::default()),
+            last_snapshot_at: AtomicI64::new(chrono::Utc::now().timestamp_millis()),
+        }
+    }
+}
+
+impl RevisionSnapshotDiskCache for MemoryGridRevisionSnapshotDiskCache {
+    fn should_generate_snapshot_from_range(&self, start_rev_id: i64, current_rev_id: i64) -> bool {
+        let elapsed_millis = chrono::Utc::now().timestamp_millis() - self.last_snapshot_at.load(Ordering::SeqCst);
+        self.policy
+            .read()
+            .should_generate_snapshot(current_rev_id - start_rev_id, elapsed_millis)
+    }
+
+    fn write_snapshot(&self, rev_id: i64, data: Vec<u8>) -> FlowyResult<()> {
+        let timestamp = chrono::Utc::now().timestamp_millis();
+        let retention = self.policy.read().retention;
+        let mut snapshots = self.snapshots.write();
+        if snapshots.iter().any(|snapshot| snapshot.rev_id == rev_id) {
+            return Ok(());
+        }
+        snapshots.push(RevisionSnapshot {
+            rev_id,
+            base_rev_id: rev_id,
+            timestamp,
+            data: Bytes::from(data),
+        });
+        if snapshots.len() > retention {
+            let pruned = snapshots.len() - retention;
+            snapshots.drain(..pruned);
+        }
+        self.last_snapshot_at.store(timestamp, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn read_snapshot(&self, rev_id: i64) -> FlowyResult<Option<RevisionSnapshot>> {
+        Ok(self
+            .snapshots
+            .read()
+            .iter()
+            .find(|snapshot| snapshot.rev_id == rev_id)
+            .cloned())
+    }
+
+    fn read_last_snapshot(&self) -> FlowyResult<Option<RevisionSnapshot>> {
+        Ok(self.snapshots.read().last().cloned())
+    }
+
+    fn read_snapshots(&self, limit: usize) -> FlowyResult<Vec<RevisionSnapshot>> {
+        Ok(self.snapshots.read().iter().rev().take(limit).cloned().collect())
+    }
+
+    fn write_named_snapshot(&self, name: &str, rev_id: i64, data: Vec<u8>) -> FlowyResult<()> {
+        if name.is_empty() {
+            return Err(FlowyError::invalid_data().context("The name of the snapshot can't be empty"));
+        }
+        let named_snapshot = NamedRevisionSnapshot {
+            name: name.to_owned(),
+            snapshot: RevisionSnapshot {
+                rev_id,
+                base_rev_id: rev_id,
+                timestamp: chrono::Utc::now().timestamp_millis(),
+                data: Bytes::from(data),
+            },
+        };
+        let mut named_snapshots = self.named_snapshots.write();
+        named_snapshots.retain(|named_snapshot| named_snapshot.name != name);
+        named_snapshots.push(named_snapshot);
+        Ok(())
+    }
+
+    fn read_named_snapshot(&self, name: &str) -> FlowyResult<Option<NamedRevisionSnapshot>> {
+        Ok(self
+            .named_snapshots
+            .read()
+            .iter()
+            .find(|named_snapshot| named_snapshot.name == name)
+            .cloned())
+    }
+
+    fn read_named_snapshots(&self) -> FlowyResult<Vec<NamedRevisionSnapshot>> {
+        Ok(self.named_snapshots.read().iter().rev().cloned().collect())
+    }
+}
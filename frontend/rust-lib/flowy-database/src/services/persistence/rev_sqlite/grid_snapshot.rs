@@ -1,18 +1,27 @@
 #![allow(clippy::unused_unit)]
+use crate::services::persistence::rev_sqlite::{
+    decrypt_revision_data, encrypt_revision_data, snapshot_stats_sql, RevisionCipher,
+};
+use crate::services::snapshot::DatabaseSnapshotPolicy;
 use bytes::Bytes;
-use flowy_error::{internal_error, FlowyResult};
-use flowy_revision::{RevisionSnapshot, RevisionSnapshotDiskCache};
+use diesel::result::OptionalExtension;
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use flowy_revision::{NamedRevisionSnapshot, RevisionSnapshot, RevisionSnapshotDiskCache};
 use flowy_sqlite::{
     prelude::*,
     schema::{grid_rev_snapshot, grid_rev_snapshot::dsl},
     ConnectionPool,
 };
-use lib_infra::util::timestamp;
+use std::sync::atomic::{AtomicI64, Ordering};
 use std::sync::Arc;
 
 pub struct SQLiteDatabaseRevisionSnapshotPersistence {
     object_id: String,
     pool: Arc<ConnectionPool>,
+    policy: DatabaseSnapshotPolicy,
+    // The time, in milliseconds, when the last snapshot was written or this persistence was created.
+    last_snapshot_at: AtomicI64,
+    cipher: Option<Arc<dyn RevisionCipher>>,
 }
 
 impl SQLiteDatabaseRevisionSnapshotPersistence {
@@ -20,19 +29,123 @@ impl SQLiteDatabaseRevisionSnapshotPersistence {
         Self {
             object_id: object_id.to_string(),
             pool,
+            policy: DatabaseSnapshotPolicy::default(),
+            last_snapshot_at: AtomicI64::new(chrono::Utc::now().timestamp_millis()),
+            cipher: None,
+        }
+    }
+
+    pub fn with_policy(mut self, policy: DatabaseSnapshotPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// The data of the snapshots is encrypted before it's written and decrypted after it's read
+    /// if the cipher is not None.
+    pub fn with_cipher(mut self, cipher: Option<Arc<dyn RevisionCipher>>) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
+    /// Rewrites the data of the object's snapshots with the `new_cipher`. The data is read with the
+    /// current cipher.
+    pub fn reencrypt(&self, new_cipher: Option<&Arc<dyn RevisionCipher>>) -> FlowyResult<()> {
+        let conn = self.pool.get().map_err(internal_error)?;
+        let records = dsl::grid_rev_snapshot
+            .filter(dsl::object_id.eq(&self.object_id))
+            .load::<GridSnapshotRecord>(&*conn)?;
+        conn.immediate_transaction::<_, FlowyError, _>(|| {
+            for record in records {
+                let data = decrypt_revision_data(self.cipher.as_ref(), record.data)?;
+                let data = encrypt_revision_data(new_cipher, data)?;
+                let sql = dsl::grid_rev_snapshot.filter(dsl::snapshot_id.eq(&record.snapshot_id));
+                let _ = diesel::update(sql).set(dsl::data.eq(data)).execute(&*conn)?;
+            }
+            Ok(())
+        })
+    }
+
+    fn mk_snapshot(&self, record: GridSnapshotRecord) -> FlowyResult<RevisionSnapshot> {
+        let data = decrypt_revision_data(self.cipher.as_ref(), record.data)?;
+        Ok(RevisionSnapshot {
+            rev_id: record.rev_id,
+            base_rev_id: record.base_rev_id,
+            timestamp: record.timestamp,
+            data: Bytes::from(data),
+        })
+    }
+
+    /// Returns the number of the object's snapshots and the time, in milliseconds, when the latest
+    /// one was written.
+    pub fn read_stats(&self) -> FlowyResult<(usize, Option<i64>)> {
+        let conn = self.pool.get().map_err(internal_error)?;
+        let (number_of_snapshots, last_snapshot_at) = dsl::grid_rev_snapshot
+            .filter(dsl::object_id.eq(&self.object_id))
+            .select(snapshot_stats_sql())
+            .get_result::<(i64, Option<i64>)>(&*conn)?;
+        Ok((number_of_snapshots.max(0) as usize, last_snapshot_at))
+    }
+
+    /// Deletes all the snapshots of the object.
+    pub fn delete_snapshots(&self) -> FlowyResult<()> {
+        let conn = self.pool.get().map_err(internal_error)?;
+        let sql = dsl::grid_rev_snapshot.filter(dsl::object_id.eq(&self.object_id));
+        let affected_row = diesel::delete(sql).execute(&*conn)?;
+        tracing::trace!("[GridSnapshot] Delete {} snapshots of {}", affected_row, self.object_id);
+        Ok(())
+    }
+
+    /// Deletes the snapshots of the object except the latest `retention` ones. The named snapshots
+    /// are not deleted.
+    fn prune_snapshots(&self, conn: &SqliteConnection) -> FlowyResult<()> {
+        let retained_ids = dsl::grid_rev_snapshot
+            .filter(dsl::object_id.eq(&self.object_id))
+            .filter(dsl::name.eq(""))
+            .order((dsl::timestamp.desc(), dsl::rev_id.desc()))
+            .limit(std::cmp::min(self.policy.retention, i64::MAX as usize) as i64)
+            .select(dsl::snapshot_id)
+            .load::<String>(conn)?;
+
+        let sql = dsl::grid_rev_snapshot
+            .filter(dsl::object_id.eq(&self.object_id))
+            .filter(dsl::name.eq(""))
+            .filter(dsl::snapshot_id.ne_all(retained_ids));
+        let affected_row = diesel::delete(sql).execute(conn)?;
+        if affected_row > 0 {
+            tracing::trace!("[GridSnapshot] Prune {} snapshots of {}", affected_row, self.object_id);
         }
+        Ok(())
     }
 
     fn gen_snapshot_id(&self, rev_id: i64) -> String {
         format!("{}:{}", self.object_id, rev_id)
     }
+
+    fn gen_named_snapshot_id(&self, name: &str) -> String {
+        format!("{}:named:{}", self.object_id, name)
+    }
+
+    fn mk_named_snapshot(&self, record: GridSnapshotRecord) -> FlowyResult<NamedRevisionSnapshot> {
+        let name = record.name.clone();
+        let snapshot = self.mk_snapshot(record)?;
+        Ok(NamedRevisionSnapshot { name, snapshot })
+    }
 }
 
 impl RevisionSnapshotDiskCache for SQLiteDatabaseRevisionSnapshotPersistence {
+    fn should_generate_snapshot_from_range(&self, start_rev_id: i64, current_rev_id: i64) -> bool {
+        let elapsed_millis = chrono::Utc::now().timestamp_millis() - self.last_snapshot_at.load(Ordering::SeqCst);
+        self.policy
+            .should_generate_snapshot(current_rev_id - start_rev_id, elapsed_millis)
+    }
+
     fn write_snapshot(&self, rev_id: i64, data: Vec<u8>) -> FlowyResult<()> {
+        let data = encrypt_revision_data(self.cipher.as_ref(), data)?;
         let conn = self.pool.get().map_err(internal_error)?;
         let snapshot_id = self.gen_snapshot_id(rev_id);
-        let timestamp = timestamp();
+        // Use milliseconds, so the snapshots of the database and its blocks that are generated
+        // one after another can be told apart.
+        let timestamp = chrono::Utc::now().timestamp_millis();
         let record = (
             dsl::snapshot_id.eq(&snapshot_id),
             dsl::object_id.eq(&self.object_id),
@@ -44,6 +157,8 @@ impl RevisionSnapshotDiskCache for SQLiteDatabaseRevisionSnapshotPersistence {
         let _ = insert_or_ignore_into(dsl::grid_rev_snapshot)
             .values(record)
             .execute(&*conn)?;
+        self.last_snapshot_at.store(timestamp, Ordering::SeqCst);
+        self.prune_snapshots(&*conn)?;
         Ok(())
 
         // conn.immediate_transaction::<_, FlowyError, _>(|| {
@@ -77,20 +192,76 @@ impl RevisionSnapshotDiskCache for SQLiteDatabaseRevisionSnapshotPersistence {
         let snapshot_id = self.gen_snapshot_id(rev_id);
         let record = dsl::grid_rev_snapshot
             .filter(dsl::snapshot_id.eq(&snapshot_id))
-            .first::<GridSnapshotRecord>(&*conn)?;
+            .first::<GridSnapshotRecord>(&*conn)
+            .optional()?;
 
-        Ok(Some(record.into()))
+        record.map(|record| self.mk_snapshot(record)).transpose()
     }
 
     fn read_last_snapshot(&self) -> FlowyResult<Option<RevisionSnapshot>> {
         let conn = self.pool.get().map_err(internal_error)?;
         let latest_record = dsl::grid_rev_snapshot
             .filter(dsl::object_id.eq(&self.object_id))
+            .filter(dsl::name.eq(""))
             .order(dsl::timestamp.desc())
             // .select(max(dsl::rev_id))
             // .select((dsl::id, dsl::object_id, dsl::rev_id, dsl::data))
             .first::<GridSnapshotRecord>(&*conn)?;
-        Ok(Some(latest_record.into()))
+        Ok(Some(self.mk_snapshot(latest_record)?))
+    }
+
+    fn read_snapshots(&self, limit: usize) -> FlowyResult<Vec<RevisionSnapshot>> {
+        let conn = self.pool.get().map_err(internal_error)?;
+        let records = dsl::grid_rev_snapshot
+            .filter(dsl::object_id.eq(&self.object_id))
+            .filter(dsl::name.eq(""))
+            .order((dsl::timestamp.desc(), dsl::rev_id.desc()))
+            .limit(std::cmp::min(limit, i64::MAX as usize) as i64)
+            .load::<GridSnapshotRecord>(&*conn)?;
+        records.into_iter().map(|record| self.mk_snapshot(record)).collect()
+    }
+
+    fn write_named_snapshot(&self, name: &str, rev_id: i64, data: Vec<u8>) -> FlowyResult<()> {
+        if name.is_empty() {
+            return Err(FlowyError::invalid_data().context("The name of the snapshot can't be empty"));
+        }
+        let data = encrypt_revision_data(self.cipher.as_ref(), data)?;
+        let conn = self.pool.get().map_err(internal_error)?;
+        let record = (
+            dsl::snapshot_id.eq(self.gen_named_snapshot_id(name)),
+            dsl::object_id.eq(&self.object_id),
+            dsl::rev_id.eq(rev_id),
+            dsl::base_rev_id.eq(rev_id),
+            dsl::timestamp.eq(chrono::Utc::now().timestamp_millis()),
+            dsl::data.eq(data),
+            dsl::name.eq(name),
+        );
+        let _ = diesel::replace_into(dsl::grid_rev_snapshot)
+            .values(record)
+            .execute(&*conn)?;
+        Ok(())
+    }
+
+    fn read_named_snapshot(&self, name: &str) -> FlowyResult<Option<NamedRevisionSnapshot>> {
+        let conn = self.pool.get().map_err(internal_error)?;
+        let record = dsl::grid_rev_snapshot
+            .filter(dsl::snapshot_id.eq(self.gen_named_snapshot_id(name)))
+            .first::<GridSnapshotRecord>(&*conn)
+            .optional()?;
+        record.map(|record| self.mk_named_snapshot(record)).transpose()
+    }
+
+    fn read_named_snapshots(&self) -> FlowyResult<Vec<NamedRevisionSnapshot>> {
+        let conn = self.pool.get().map_err(internal_error)?;
+        let records = dsl::grid_rev_snapshot
+            .filter(dsl::object_id.eq(&self.object_id))
+            .filter(dsl::name.ne(""))
+            .order((dsl::timestamp.desc(), dsl::rev_id.desc()))
+            .load::<GridSnapshotRecord>(&*conn)?;
+        records
+            .into_iter()
+            .map(|record| self.mk_named_snapshot(record))
+            .collect()
     }
 }
 
@@ -104,15 +275,5 @@ struct GridSnapshotRecord {
     base_rev_id: i64,
     timestamp: i64,
     data: Vec<u8>,
-}
-
-impl std::convert::From<GridSnapshotRecord> for RevisionSnapshot {
-    fn from(record: GridSnapshotRecord) -> Self {
-        RevisionSnapshot {
-            rev_id: record.rev_id,
-            base_rev_id: record.base_rev_id,
-            timestamp: record.timestamp,
-            data: Bytes::from(record.data),
-        }
-    }
+    name: String,
 }
@@ -1,3 +1,7 @@
+use crate::services::persistence::rev_sqlite::{
+    decrypt_revision_data, encrypt_revision_data, read_revision_authors, revision_stats_sql, RevisionAuthor,
+    RevisionCipher, RevisionTableStats, LOCAL_DEVICE_ID,
+};
 use bytes::Bytes;
 use diesel::{sql_types::Integer, update, SqliteConnection};
 use flowy_error::{internal_error, FlowyError, FlowyResult};
@@ -10,20 +14,110 @@ use flowy_sqlite::{
 };
 use lib_infra::util::md5;
 use revision_model::{Revision, RevisionRange};
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 pub struct SQLiteGridViewRevisionPersistence {
     user_id: String,
+    device_id: String,
     pub(crate) pool: Arc<ConnectionPool>,
+    cipher: Option<Arc<dyn RevisionCipher>>,
 }
 
 impl SQLiteGridViewRevisionPersistence {
     pub fn new(user_id: &str, pool: Arc<ConnectionPool>) -> Self {
         Self {
             user_id: user_id.to_owned(),
+            device_id: LOCAL_DEVICE_ID.to_owned(),
             pool,
+            cipher: None,
         }
     }
+
+    /// The payloads of the revisions are encrypted before they are written and decrypted after
+    /// they are read if the cipher is not None.
+    pub fn with_cipher(mut self, cipher: Option<Arc<dyn RevisionCipher>>) -> Self {
+        self.cipher = cipher;
+        self
+    }
+
+    fn cipher(&self) -> Option<&Arc<dyn RevisionCipher>> {
+        self.cipher.as_ref()
+    }
+
+    /// The `device_id` is written along with each revision. It's [LOCAL_DEVICE_ID] by default.
+    pub fn with_device_id(mut self, device_id: &str) -> Self {
+        self.device_id = device_id.to_owned();
+        self
+    }
+
+    fn author(&self) -> RevisionAuthor {
+        RevisionAuthor::now(&self.user_id, &self.device_id)
+    }
+
+    /// Rewrites the payloads of the view's revisions with the `new_cipher`. The payloads are
+    /// decrypted with the current cipher. The other columns of the revisions are kept as they are.
+    pub fn reencrypt(&self, object_id: &str, new_cipher: Option<&Arc<dyn RevisionCipher>>) -> FlowyResult<()> {
+        let conn = self.pool.get().map_err(internal_error)?;
+        let rows = dsl::grid_view_rev_table
+            .filter(dsl::object_id.eq(object_id))
+            .load::<GridViewRevisionTable>(&*conn)?;
+        conn.immediate_transaction::<_, FlowyError, _>(|| {
+            for row in rows {
+                let data = decrypt_revision_data(self.cipher(), row.data)?;
+                let data = encrypt_revision_data(new_cipher, data)?;
+                let _ = update(dsl::grid_view_rev_table.filter(dsl::id.eq(row.id)))
+                    .set(dsl::data.eq(data))
+                    .execute(&*conn)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the authors of the object's revisions whose rev_ids are in the `range`, keyed by
+    /// rev_id.
+    pub fn read_revision_authors(
+        &self,
+        object_id: &str,
+        range: &RevisionRange,
+    ) -> FlowyResult<BTreeMap<i64, RevisionAuthor>> {
+        let conn = self.pool.get().map_err(internal_error)?;
+        read_revision_authors("grid_view_rev_table", object_id, range, &conn)
+    }
+
+    /// Returns the number of the view's revisions and the total size of their payloads.
+    pub fn read_stats(&self, object_id: &str) -> FlowyResult<RevisionTableStats> {
+        let conn = self.pool.get().map_err(internal_error)?;
+        let row = dsl::grid_view_rev_table
+            .filter(dsl::object_id.eq(object_id))
+            .select(revision_stats_sql())
+            .get_result::<(i64, i64)>(&*conn)?;
+        Ok(RevisionTableStats::from_row(row))
+    }
+
+    /// Returns the ids of all the views that have revisions.
+    pub fn read_object_ids(&self) -> FlowyResult<Vec<String>> {
+        let conn = self.pool.get().map_err(internal_error)?;
+        let object_ids = dsl::grid_view_rev_table
+            .select(dsl::object_id)
+            .distinct()
+            .load::<String>(&*conn)?;
+        Ok(object_ids)
+    }
+
+    /// Replaces the revisions of the object with the `records` using the passed-in connection.
+    /// It's used when the caller wants to wrap the writes of multiple objects in one transaction.
+    pub(crate) fn reset_with_conn(
+        object_id: &str,
+        records: Vec<SyncRecord>,
+        cipher: Option<&Arc<dyn RevisionCipher>>,
+        author: &RevisionAuthor,
+        conn: &SqliteConnection,
+    ) -> Result<(), FlowyError> {
+        GridViewRevisionSql::delete(object_id, None, conn)?;
+        GridViewRevisionSql::create(records, cipher, author, conn)?;
+        Ok(())
+    }
 }
 
 impl RevisionDiskCache<Arc<ConnectionPool>> for SQLiteGridViewRevisionPersistence {
@@ -31,7 +125,7 @@ impl RevisionDiskCache<Arc<ConnectionPool>> for SQLiteGridViewRevisionPersistenc
 
     fn create_revision_records(&self, revision_records: Vec<SyncRecord>) -> Result<(), Self::Error> {
         let conn = self.pool.get().map_err(internal_error)?;
-        GridViewRevisionSql::create(revision_records, &conn)?;
+        GridViewRevisionSql::create(revision_records, self.cipher(), &self.author(), &conn)?;
         Ok(())
     }
 
@@ -45,7 +139,7 @@ impl RevisionDiskCache<Arc<ConnectionPool>> for SQLiteGridViewRevisionPersistenc
         rev_ids: Option<Vec<i64>>,
     ) -> Result<Vec<SyncRecord>, Self::Error> {
         let conn = self.pool.get().map_err(internal_error)?;
-        let records = GridViewRevisionSql::read(&self.user_id, object_id, rev_ids, &conn)?;
+        let records = GridViewRevisionSql::read(&self.user_id, object_id, rev_ids, self.cipher(), &conn)?;
         Ok(records)
     }
 
@@ -55,7 +149,8 @@ impl RevisionDiskCache<Arc<ConnectionPool>> for SQLiteGridViewRevisionPersistenc
         range: &RevisionRange,
     ) -> Result<Vec<SyncRecord>, Self::Error> {
         let conn = &*self.pool.get().map_err(internal_error)?;
-        let revisions = GridViewRevisionSql::read_with_range(&self.user_id, object_id, range.clone(), conn)?;
+        let revisions =
+            GridViewRevisionSql::read_with_range(&self.user_id, object_id, range.clone(), self.cipher(), conn)?;
         Ok(revisions)
     }
 
@@ -85,7 +180,7 @@ impl RevisionDiskCache<Arc<ConnectionPool>> for SQLiteGridViewRevisionPersistenc
         let conn = self.pool.get().map_err(internal_error)?;
         conn.immediate_transaction::<_, FlowyError, _>(|| {
             GridViewRevisionSql::delete(object_id, deleted_rev_ids, &conn)?;
-            GridViewRevisionSql::create(inserted_records, &conn)?;
+            GridViewRevisionSql::create(inserted_records, self.cipher(), &self.author(), &conn)?;
             Ok(())
         })
     }
@@ -93,7 +188,12 @@ impl RevisionDiskCache<Arc<ConnectionPool>> for SQLiteGridViewRevisionPersistenc
 
 struct GridViewRevisionSql();
 impl GridViewRevisionSql {
-    fn create(revision_records: Vec<SyncRecord>, conn: &SqliteConnection) -> Result<(), FlowyError> {
+    fn create(
+        revision_records: Vec<SyncRecord>,
+        cipher: Option<&Arc<dyn RevisionCipher>>,
+        author: &RevisionAuthor,
+        conn: &SqliteConnection,
+    ) -> Result<(), FlowyError> {
         // Batch insert: https://diesel.rs/guides/all-about-inserts.html
         let records = revision_records
             .into_iter()
@@ -104,15 +204,19 @@ impl GridViewRevisionSql {
                     record.revision.rev_id
                 );
                 let rev_state: GridViewRevisionState = record.state.into();
-                (
+                let data = encrypt_revision_data(cipher, record.revision.bytes)?;
+                Ok((
                     dsl::object_id.eq(record.revision.object_id),
                     dsl::base_rev_id.eq(record.revision.base_rev_id),
                     dsl::rev_id.eq(record.revision.rev_id),
-                    dsl::data.eq(record.revision.bytes),
+                    dsl::data.eq(data),
                     dsl::state.eq(rev_state),
-                )
+                    dsl::timestamp.eq(author.timestamp),
+                    dsl::user_id.eq(author.user_id.clone()),
+                    dsl::device_id.eq(author.device_id.clone()),
+                ))
             })
-            .collect::<Vec<_>>();
+            .collect::<FlowyResult<Vec<_>>>()?;
 
         let _ = insert_or_ignore_into(dsl::grid_view_rev_table)
             .values(&records)
@@ -138,6 +242,7 @@ impl GridViewRevisionSql {
         user_id: &str,
         object_id: &str,
         rev_ids: Option<Vec<i64>>,
+        cipher: Option<&Arc<dyn RevisionCipher>>,
         conn: &SqliteConnection,
     ) -> Result<Vec<SyncRecord>, FlowyError> {
         let mut sql = dsl::grid_view_rev_table
@@ -149,8 +254,8 @@ impl GridViewRevisionSql {
         let rows = sql.order(dsl::rev_id.asc()).load::<GridViewRevisionTable>(conn)?;
         let records = rows
             .into_iter()
-            .map(|row| mk_revision_record_from_table(user_id, row))
-            .collect::<Vec<_>>();
+            .map(|row| mk_revision_record_from_table(user_id, row, cipher))
+            .collect::<FlowyResult<Vec<_>>>()?;
 
         Ok(records)
     }
@@ -159,6 +264,7 @@ impl GridViewRevisionSql {
         user_id: &str,
         object_id: &str,
         range: RevisionRange,
+        cipher: Option<&Arc<dyn RevisionCipher>>,
         conn: &SqliteConnection,
     ) -> Result<Vec<SyncRecord>, FlowyError> {
         let rev_tables = dsl::grid_view_rev_table
@@ -170,8 +276,8 @@ impl GridViewRevisionSql {
 
         let revisions = rev_tables
             .into_iter()
-            .map(|table| mk_revision_record_from_table(user_id, table))
-            .collect::<Vec<_>>();
+            .map(|table| mk_revision_record_from_table(user_id, table, cipher))
+            .collect::<FlowyResult<Vec<_>>>()?;
         Ok(revisions)
     }
 
@@ -199,6 +305,9 @@ struct GridViewRevisionTable {
     rev_id: i64,
     data: Vec<u8>,
     state: GridViewRevisionState,
+    timestamp: i64,
+    user_id: String,
+    device_id: String,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, FromSqlRow, AsExpression)]
@@ -217,18 +326,23 @@ impl std::default::Default for GridViewRevisionState {
     }
 }
 
-fn mk_revision_record_from_table(_user_id: &str, table: GridViewRevisionTable) -> SyncRecord {
-    let md5 = md5(&table.data);
+fn mk_revision_record_from_table(
+    _user_id: &str,
+    table: GridViewRevisionTable,
+    cipher: Option<&Arc<dyn RevisionCipher>>,
+) -> FlowyResult<SyncRecord> {
+    let data = decrypt_revision_data(cipher, table.data)?;
+    let md5 = md5(&data);
     let revision = Revision::new(
         &table.object_id,
         table.base_rev_id,
         table.rev_id,
-        Bytes::from(table.data),
+        Bytes::from(data),
         md5,
     );
-    SyncRecord {
+    Ok(SyncRecord {
         revision,
         state: table.state.into(),
         write_to_disk: false,
-    }
+    })
 }
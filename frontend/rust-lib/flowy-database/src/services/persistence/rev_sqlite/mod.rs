@@ -2,8 +2,16 @@ mod grid_block_sqlite_impl;
 mod grid_snapshot;
 mod grid_sqlite_impl;
 mod grid_view_sqlite_impl;
+mod revision_author;
+mod revision_cipher;
+mod revision_stats;
+mod revision_verification;
 
 pub use grid_block_sqlite_impl::*;
 pub use grid_snapshot::*;
 pub use grid_sqlite_impl::*;
 pub use grid_view_sqlite_impl::*;
+pub use revision_author::*;
+pub use revision_cipher::*;
+pub use revision_stats::*;
+pub use revision_verification::*;
@@ -0,0 +1,78 @@
+use diesel::sql_types::{BigInt, Text};
+use flowy_error::FlowyResult;
+use flowy_sqlite::prelude::*;
+use revision_model::RevisionRange;
+use std::collections::BTreeMap;
+
+/// The device id of the revisions that are written by a [DatabaseUser](crate::manager::DatabaseUser)
+/// that doesn't provide one.
+pub const LOCAL_DEVICE_ID: &str = "local";
+
+/// [RevisionAuthor] is stored along with the payload of each revision, so the changes made by
+/// different collaborators or on different devices can be told apart.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RevisionAuthor {
+    pub user_id: String,
+    pub device_id: String,
+
+    /// The time, in seconds, when the revision was written to disk.
+    pub timestamp: i64,
+}
+
+impl RevisionAuthor {
+    /// Creates the author of the revisions that are written now.
+    pub fn now(user_id: &str, device_id: &str) -> Self {
+        Self {
+            user_id: user_id.to_owned(),
+            device_id: device_id.to_owned(),
+            timestamp: chrono::Utc::now().timestamp(),
+        }
+    }
+}
+
+#[derive(QueryableByName)]
+struct RevisionAuthorRow {
+    #[sql_type = "BigInt"]
+    rev_id: i64,
+    #[sql_type = "Text"]
+    user_id: String,
+    #[sql_type = "Text"]
+    device_id: String,
+    #[sql_type = "BigInt"]
+    timestamp: i64,
+}
+
+/// Returns the authors of the object's revisions, in the revision table with the `table_name`,
+/// whose rev_ids are in the `range`, keyed by rev_id. The ids of the author are empty if the
+/// revision was written before they were recorded.
+///
+/// The revision tables of the databases, the blocks and the views share the same columns, so
+/// they are read by the same query.
+pub(crate) fn read_revision_authors(
+    table_name: &str,
+    object_id: &str,
+    range: &RevisionRange,
+    conn: &SqliteConnection,
+) -> FlowyResult<BTreeMap<i64, RevisionAuthor>> {
+    let sql = format!(
+        "SELECT rev_id, user_id, device_id, timestamp FROM {} WHERE object_id = ? AND rev_id >= ? AND rev_id <= ?",
+        table_name
+    );
+    let rows = diesel::sql_query(sql)
+        .bind::<Text, _>(object_id)
+        .bind::<BigInt, _>(range.start)
+        .bind::<BigInt, _>(range.end)
+        .load::<RevisionAuthorRow>(conn)?;
+    let authors = rows
+        .into_iter()
+        .map(|row| {
+            let author = RevisionAuthor {
+                user_id: row.user_id,
+                device_id: row.device_id,
+                timestamp: row.timestamp,
+            };
+            (row.rev_id, author)
+        })
+        .collect();
+    Ok(authors)
+}
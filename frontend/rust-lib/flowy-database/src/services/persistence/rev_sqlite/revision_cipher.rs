@@ -0,0 +1,44 @@
+use bytes::Bytes;
+use flowy_error::{FlowyError, FlowyResult};
+use std::sync::Arc;
+
+/// [RevisionCipher] encrypts the payloads of the revisions and the snapshots before they are
+/// written to sqlite, and decrypts them after they are read.
+pub trait RevisionCipher: Send + Sync {
+    fn encrypt(&self, data: Bytes) -> FlowyResult<Bytes>;
+    fn decrypt(&self, data: Bytes) -> FlowyResult<Bytes>;
+}
+
+/// The encrypted payloads start with the prefix, so they can be told apart from the plaintext
+/// payloads that were written without a cipher.
+const ENCRYPTED_DATA_PREFIX: &[u8] = b"AF_ENCRYPTED:";
+
+/// Returns the data as is if the cipher is None.
+pub(crate) fn encrypt_revision_data(cipher: Option<&Arc<dyn RevisionCipher>>, data: Vec<u8>) -> FlowyResult<Vec<u8>> {
+    match cipher {
+        None => Ok(data),
+        Some(cipher) => {
+            let encrypted_data = cipher.encrypt(Bytes::from(data))?;
+            let mut bytes = Vec::with_capacity(ENCRYPTED_DATA_PREFIX.len() + encrypted_data.len());
+            bytes.extend_from_slice(ENCRYPTED_DATA_PREFIX);
+            bytes.extend_from_slice(&encrypted_data);
+            Ok(bytes)
+        }
+    }
+}
+
+/// Returns the plaintext data as is. Returns a `DatabaseEncrypted` error if the data is encrypted
+/// but the cipher is None.
+pub(crate) fn decrypt_revision_data(cipher: Option<&Arc<dyn RevisionCipher>>, data: Vec<u8>) -> FlowyResult<Vec<u8>> {
+    if !data.starts_with(ENCRYPTED_DATA_PREFIX) {
+        return Ok(data);
+    }
+
+    match cipher {
+        None => Err(FlowyError::database_encrypted()),
+        Some(cipher) => {
+            let encrypted_data = Bytes::from(data).slice(ENCRYPTED_DATA_PREFIX.len()..);
+            Ok(cipher.decrypt(encrypted_data)?.to_vec())
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use diesel::dsl::{sql, SqlLiteral};
+use diesel::sql_types::{BigInt, Nullable};
+
+/// The number of the revisions of an object and the total size of their payloads. They are
+/// counted by sql instead of loading the payloads.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RevisionTableStats {
+    pub number_of_revisions: usize,
+    pub payload_bytes: usize,
+}
+
+impl RevisionTableStats {
+    pub(crate) fn from_row((number_of_revisions, payload_bytes): (i64, i64)) -> Self {
+        Self {
+            number_of_revisions: number_of_revisions.max(0) as usize,
+            payload_bytes: payload_bytes.max(0) as usize,
+        }
+    }
+}
+
+/// Selects the number of the rows and the total length of their `data` column.
+pub(crate) fn revision_stats_sql() -> SqlLiteral<(BigInt, BigInt)> {
+    sql::<(BigInt, BigInt)>("COUNT(*), COALESCE(SUM(LENGTH(data)), 0)")
+}
+
+/// Selects the number of the rows and the latest `timestamp`.
+pub(crate) fn snapshot_stats_sql() -> SqlLiteral<(BigInt, Nullable<BigInt>)> {
+    sql::<(BigInt, Nullable<BigInt>)>("COUNT(*), MAX(timestamp)")
+}
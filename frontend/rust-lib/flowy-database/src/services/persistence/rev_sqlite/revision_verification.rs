@@ -0,0 +1,92 @@
+use flowy_client_sync::client_database::{DatabaseOperations, DatabaseRevisionPad};
+use flowy_revision_persistence::SyncRecord;
+
+/// [RevisionIntegrityIssue] describes a problem found in the persisted revisions of an object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RevisionIntegrityIssue {
+    /// The revision doesn't follow the previous one. Merging revisions also leaves gaps in the
+    /// rev_ids, so a gap alone doesn't mean the revisions are corrupted.
+    Gap { prev_rev_id: i64, rev_id: i64 },
+
+    /// More than one revision has the same rev_id.
+    DuplicateRevId { rev_id: i64 },
+
+    /// The bytes of the revision can't be deserialized.
+    CorruptedPayload { rev_id: i64 },
+
+    /// Each revision can be deserialized, but they can't be composed into the object.
+    Uncomposable,
+}
+
+#[derive(Debug, Clone)]
+pub struct RevisionIntegrityReport {
+    pub object_id: String,
+    pub number_of_revisions: usize,
+    pub issues: Vec<RevisionIntegrityIssue>,
+}
+
+impl RevisionIntegrityReport {
+    /// Returns true if the object can't be built from its revisions.
+    pub fn is_corrupted(&self) -> bool {
+        self.issues
+            .iter()
+            .any(|issue| !matches!(issue, RevisionIntegrityIssue::Gap { .. }))
+    }
+}
+
+/// Checks the `records` that are sorted by rev_id. The `is_valid_payload` is used to check if the
+/// bytes of each revision can be deserialized.
+pub(crate) fn verify_revision_records<F>(
+    object_id: &str,
+    records: &[SyncRecord],
+    is_valid_payload: F,
+) -> RevisionIntegrityReport
+where
+    F: Fn(&[u8]) -> bool,
+{
+    let mut issues = vec![];
+    let mut prev_rev_id = None;
+    for record in records {
+        let revision = &record.revision;
+        if let Some(prev_rev_id) = prev_rev_id {
+            if revision.rev_id == prev_rev_id {
+                issues.push(RevisionIntegrityIssue::DuplicateRevId {
+                    rev_id: revision.rev_id,
+                });
+            } else if revision.rev_id != prev_rev_id + 1 && revision.base_rev_id != prev_rev_id {
+                issues.push(RevisionIntegrityIssue::Gap {
+                    prev_rev_id,
+                    rev_id: revision.rev_id,
+                });
+            }
+        }
+
+        if !is_valid_payload(&revision.bytes) {
+            issues.push(RevisionIntegrityIssue::CorruptedPayload {
+                rev_id: revision.rev_id,
+            });
+        }
+        prev_rev_id = Some(revision.rev_id);
+    }
+
+    RevisionIntegrityReport {
+        object_id: object_id.to_owned(),
+        number_of_revisions: records.len(),
+        issues,
+    }
+}
+
+/// Checks the revisions of the database for gaps, duplicate rev_ids and the payloads that can't be
+/// deserialized. The `records` must be sorted by rev_id.
+pub(crate) fn verify_database_revision_records(object_id: &str, records: Vec<SyncRecord>) -> RevisionIntegrityReport {
+    let mut report = verify_revision_records(object_id, &records, |bytes| {
+        DatabaseOperations::from_bytes(bytes).is_ok()
+    });
+    if !report.is_corrupted() && !records.is_empty() {
+        let revisions = records.into_iter().map(|record| record.revision).collect();
+        if DatabaseRevisionPad::from_revisions(revisions).is_err() {
+            report.issues.push(RevisionIntegrityIssue::Uncomposable);
+        }
+    }
+    report
+}
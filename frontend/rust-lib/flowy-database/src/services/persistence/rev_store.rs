@@ -0,0 +1,383 @@
+use crate::manager::{DatabaseUser, RevisionCompressConfig};
+use crate::services::persistence::rev_sqlite::{
+    RevisionAuthor, RevisionCipher, RevisionTableStats, SQLiteDatabaseBlockRevisionPersistence,
+    SQLiteDatabaseRevisionPersistence, SQLiteDatabaseRevisionSnapshotPersistence, SQLiteGridViewRevisionPersistence,
+    LOCAL_DEVICE_ID,
+};
+use crate::services::persistence::GridDatabase;
+use crate::services::snapshot::DatabaseSnapshotPolicy;
+use diesel::Connection;
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use flowy_revision::RevisionSnapshotDiskCache;
+use flowy_revision_persistence::{RevisionDiskCache, RevisionState, SyncRecord};
+use flowy_sqlite::ConnectionPool;
+use revision_model::{Revision, RevisionRange};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+pub type GridRevisionDiskCache<C> = Arc<dyn RevisionDiskCache<C, Error = FlowyError>>;
+
+/// The objects whose revisions are stored separately. The database, its blocks and its views
+/// each have their own revisions and snapshots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GridRevisionObject {
+    Database,
+    Block,
+    View,
+}
+
+impl GridRevisionObject {
+    /// Returns the id that the snapshots of the object are stored with. The id of a view is the
+    /// same as the id of its database, so the ids of the snapshots are prefixed with the object.
+    pub fn snapshot_object_id(&self, object_id: &str) -> String {
+        match self {
+            GridRevisionObject::Database => format!("grid:{}", object_id),
+            GridRevisionObject::Block => format!("grid_block:{}", object_id),
+            GridRevisionObject::View => format!("grid_view:{}", object_id),
+        }
+    }
+}
+
+/// [TimestampedRevision] is a revision with the time, in seconds, when it was written to disk. The
+/// time is 0 if the revision was written before the time was recorded.
+#[derive(Debug, Clone)]
+pub struct TimestampedRevision {
+    pub revision: Revision,
+    pub timestamp: i64,
+}
+
+/// [GridRevisionStore] persists the revisions and the snapshots of a database, a block or a view.
+/// The revisions are read and written by the [RevisionPersistence](flowy_revision::RevisionPersistence)
+/// of the object, and the snapshots by its snapshot controller.
+///
+/// `C` is the connection type of the [RevisionDiskCache].
+pub trait GridRevisionStore<C>: Send + Sync {
+    fn object_id(&self) -> &str;
+
+    fn revision_disk_cache(&self) -> GridRevisionDiskCache<C>;
+
+    fn snapshot_disk_cache(&self) -> Arc<dyn RevisionSnapshotDiskCache>;
+
+    /// Returns the authors of the object's revisions whose rev_ids are in the `range`, keyed by
+    /// rev_id.
+    fn read_revision_authors(&self, range: &RevisionRange) -> FlowyResult<BTreeMap<i64, RevisionAuthor>>;
+
+    /// Returns the number of the object's revisions and the total size of their payloads.
+    fn read_stats(&self) -> FlowyResult<RevisionTableStats>;
+
+    /// Returns the number of the object's snapshots and the time, in milliseconds, when the latest
+    /// one was written.
+    fn read_snapshot_stats(&self) -> FlowyResult<(usize, Option<i64>)>;
+
+    /// Deletes all the revisions and the snapshots of the object.
+    fn delete(&self) -> FlowyResult<()>;
+
+    /// Rewrites the revisions and the snapshots of the object with the `new_cipher`. They are
+    /// decrypted with the cipher of the store.
+    fn reencrypt(&self, new_cipher: Option<&Arc<dyn RevisionCipher>>) -> FlowyResult<()>;
+
+    /// Returns the object's revisions in rev_id ascending order, along with the time when each of
+    /// them was written to disk.
+    fn read_timestamped_revisions(&self) -> FlowyResult<Vec<TimestampedRevision>> {
+        let authors = self.read_revision_authors(&RevisionRange {
+            start: 0,
+            end: i64::MAX,
+        })?;
+        let mut revisions = self
+            .revision_disk_cache()
+            .read_revision_records(self.object_id(), None)?
+            .into_iter()
+            .map(|record| TimestampedRevision {
+                timestamp: authors
+                    .get(&record.revision.rev_id)
+                    .map(|author| author.timestamp)
+                    .unwrap_or(0),
+                revision: record.revision,
+            })
+            .collect::<Vec<TimestampedRevision>>();
+        revisions.sort_by_key(|revision| revision.revision.rev_id);
+        Ok(revisions)
+    }
+}
+
+/// The options of the [GridRevisionStore] that are decided by the
+/// [DatabaseManager](crate::manager::DatabaseManager).
+#[derive(Clone)]
+pub struct GridRevisionStoreOptions {
+    /// The id of the device that is written along with each revision.
+    pub device_id: String,
+
+    /// The revisions and the snapshots are encrypted with the cipher if it's not None.
+    pub cipher: Option<Arc<dyn RevisionCipher>>,
+
+    pub snapshot_policy: DatabaseSnapshotPolicy,
+}
+
+impl std::default::Default for GridRevisionStoreOptions {
+    fn default() -> Self {
+        Self {
+            device_id: LOCAL_DEVICE_ID.to_owned(),
+            cipher: None,
+            snapshot_policy: DatabaseSnapshotPolicy::default(),
+        }
+    }
+}
+
+/// [GridRevisionStoreFactory] makes the [GridRevisionStore] of each database, block and view, so
+/// the storage of the databases can be swapped, for example in tests or on the platforms without
+/// sqlite.
+pub trait GridRevisionStoreFactory<C>: Send + Sync {
+    fn make_store(
+        &self,
+        user_id: &str,
+        object: GridRevisionObject,
+        object_id: &str,
+        options: GridRevisionStoreOptions,
+    ) -> FlowyResult<Arc<dyn GridRevisionStore<C>>>;
+
+    /// Returns the ids of all the databases that have revisions.
+    fn read_database_ids(&self) -> FlowyResult<Vec<String>>;
+
+    /// Returns the ids of all the views that have revisions. The views of all the databases are
+    /// returned, the database of a view is recorded in its revisions.
+    fn read_view_ids(&self) -> FlowyResult<Vec<String>>;
+
+    /// Returns the number of the revisions of all the blocks and the total size of their payloads.
+    fn read_total_block_stats(&self) -> FlowyResult<RevisionTableStats>;
+
+    /// Replaces the revisions of each object with its revision in the `revisions`. Either all the
+    /// objects are written or none of them is.
+    fn reset_objects(
+        &self,
+        user_id: &str,
+        revisions: Vec<(GridRevisionObject, Revision)>,
+        options: GridRevisionStoreOptions,
+    ) -> FlowyResult<()>;
+}
+
+/// Makes the [GridRevisionStore]s of a database, its blocks and its views with the options
+/// decided by the [DatabaseManager](crate::manager::DatabaseManager). It's cheap to clone.
+#[derive(Clone)]
+pub struct GridRevisionStores {
+    user: Arc<dyn DatabaseUser>,
+    factory: Arc<dyn GridRevisionStoreFactory<Arc<ConnectionPool>>>,
+    cipher: Option<Arc<dyn RevisionCipher>>,
+    rev_compress: RevisionCompressConfig,
+}
+
+impl GridRevisionStores {
+    pub(crate) fn new(
+        user: Arc<dyn DatabaseUser>,
+        factory: Arc<dyn GridRevisionStoreFactory<Arc<ConnectionPool>>>,
+        cipher: Option<Arc<dyn RevisionCipher>>,
+        rev_compress: RevisionCompressConfig,
+    ) -> Self {
+        Self {
+            user,
+            factory,
+            cipher,
+            rev_compress,
+        }
+    }
+
+    pub(crate) fn user(&self) -> &Arc<dyn DatabaseUser> {
+        &self.user
+    }
+
+    pub(crate) fn factory(&self) -> &Arc<dyn GridRevisionStoreFactory<Arc<ConnectionPool>>> {
+        &self.factory
+    }
+
+    pub(crate) fn rev_compress(&self) -> &RevisionCompressConfig {
+        &self.rev_compress
+    }
+
+    pub(crate) fn options(&self) -> FlowyResult<GridRevisionStoreOptions> {
+        Ok(GridRevisionStoreOptions {
+            device_id: self.user.device_id()?,
+            cipher: self.cipher.clone(),
+            snapshot_policy: self.rev_compress.snapshot_policy.clone(),
+        })
+    }
+
+    pub(crate) fn make_store(
+        &self,
+        object: GridRevisionObject,
+        object_id: &str,
+    ) -> FlowyResult<Arc<dyn GridRevisionStore<Arc<ConnectionPool>>>> {
+        let user_id = self.user.user_id()?;
+        self.factory.make_store(&user_id, object, object_id, self.options()?)
+    }
+}
+
+/// Stores the revisions and the snapshots of the databases in sqlite. It's used by
+/// [DatabaseStorage::sqlite](crate::services::persistence::DatabaseStorage::sqlite).
+pub struct SQLiteGridRevisionStoreFactory {
+    database: Arc<dyn GridDatabase>,
+}
+
+impl SQLiteGridRevisionStoreFactory {
+    pub fn new(database: Arc<dyn GridDatabase>) -> Self {
+        Self { database }
+    }
+}
+
+impl GridRevisionStoreFactory<Arc<ConnectionPool>> for SQLiteGridRevisionStoreFactory {
+    fn make_store(
+        &self,
+        user_id: &str,
+        object: GridRevisionObject,
+        object_id: &str,
+        options: GridRevisionStoreOptions,
+    ) -> FlowyResult<Arc<dyn GridRevisionStore<Arc<ConnectionPool>>>> {
+        let pool = self.database.db_pool()?;
+        let disk_cache = match object {
+            GridRevisionObject::Database => SQLiteRevisionDiskCache::Database(Arc::new(
+                SQLiteDatabaseRevisionPersistence::new(user_id, pool.clone())
+                    .with_cipher(options.cipher.clone())
+                    .with_device_id(&options.device_id),
+            )),
+            GridRevisionObject::Block => SQLiteRevisionDiskCache::Block(Arc::new(
+                SQLiteDatabaseBlockRevisionPersistence::new(user_id, pool.clone())
+                    .with_cipher(options.cipher.clone())
+                    .with_device_id(&options.device_id),
+            )),
+            GridRevisionObject::View => SQLiteRevisionDiskCache::View(Arc::new(
+                SQLiteGridViewRevisionPersistence::new(user_id, pool.clone())
+                    .with_cipher(options.cipher.clone())
+                    .with_device_id(&options.device_id),
+            )),
+        };
+        let snapshot_object_id = object.snapshot_object_id(object_id);
+        let snapshot_disk_cache = SQLiteDatabaseRevisionSnapshotPersistence::new(&snapshot_object_id, pool)
+            .with_cipher(options.cipher)
+            .with_policy(options.snapshot_policy);
+        Ok(Arc::new(SQLiteGridRevisionStore {
+            object_id: object_id.to_owned(),
+            disk_cache,
+            snapshot_disk_cache: Arc::new(snapshot_disk_cache),
+        }))
+    }
+
+    fn read_database_ids(&self) -> FlowyResult<Vec<String>> {
+        SQLiteDatabaseRevisionPersistence::new("", self.database.db_pool()?).read_object_ids()
+    }
+
+    fn read_view_ids(&self) -> FlowyResult<Vec<String>> {
+        SQLiteGridViewRevisionPersistence::new("", self.database.db_pool()?).read_object_ids()
+    }
+
+    fn read_total_block_stats(&self) -> FlowyResult<RevisionTableStats> {
+        SQLiteDatabaseBlockRevisionPersistence::new("", self.database.db_pool()?).read_total_stats()
+    }
+
+    fn reset_objects(
+        &self,
+        user_id: &str,
+        revisions: Vec<(GridRevisionObject, Revision)>,
+        options: GridRevisionStoreOptions,
+    ) -> FlowyResult<()> {
+        let author = RevisionAuthor::now(user_id, &options.device_id);
+        let pool = self.database.db_pool()?;
+        let conn = pool.get().map_err(internal_error)?;
+        conn.immediate_transaction::<_, FlowyError, _>(|| {
+            for (object, revision) in revisions {
+                let object_id = revision.object_id.clone();
+                let records = vec![SyncRecord {
+                    revision,
+                    state: RevisionState::Sync,
+                    write_to_disk: false,
+                }];
+                match object {
+                    GridRevisionObject::Database => SQLiteDatabaseRevisionPersistence::reset_with_conn(
+                        &object_id,
+                        records,
+                        options.cipher.as_ref(),
+                        &author,
+                        &conn,
+                    )?,
+                    GridRevisionObject::Block => SQLiteDatabaseBlockRevisionPersistence::reset_with_conn(
+                        &object_id,
+                        records,
+                        options.cipher.as_ref(),
+                        &author,
+                        &conn,
+                    )?,
+                    GridRevisionObject::View => SQLiteGridViewRevisionPersistence::reset_with_conn(
+                        &object_id,
+                        records,
+                        options.cipher.as_ref(),
+                        &author,
+                        &conn,
+                    )?,
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+enum SQLiteRevisionDiskCache {
+    Database(Arc<SQLiteDatabaseRevisionPersistence>),
+    Block(Arc<SQLiteDatabaseBlockRevisionPersistence>),
+    View(Arc<SQLiteGridViewRevisionPersistence>),
+}
+
+struct SQLiteGridRevisionStore {
+    object_id: String,
+    disk_cache: SQLiteRevisionDiskCache,
+    snapshot_disk_cache: Arc<SQLiteDatabaseRevisionSnapshotPersistence>,
+}
+
+impl GridRevisionStore<Arc<ConnectionPool>> for SQLiteGridRevisionStore {
+    fn object_id(&self) -> &str {
+        &self.object_id
+    }
+
+    fn revision_disk_cache(&self) -> GridRevisionDiskCache<Arc<ConnectionPool>> {
+        match &self.disk_cache {
+            SQLiteRevisionDiskCache::Database(disk_cache) => disk_cache.clone(),
+            SQLiteRevisionDiskCache::Block(disk_cache) => disk_cache.clone(),
+            SQLiteRevisionDiskCache::View(disk_cache) => disk_cache.clone(),
+        }
+    }
+
+    fn snapshot_disk_cache(&self) -> Arc<dyn RevisionSnapshotDiskCache> {
+        self.snapshot_disk_cache.clone()
+    }
+
+    fn read_revision_authors(&self, range: &RevisionRange) -> FlowyResult<BTreeMap<i64, RevisionAuthor>> {
+        match &self.disk_cache {
+            SQLiteRevisionDiskCache::Database(disk_cache) => disk_cache.read_revision_authors(&self.object_id, range),
+            SQLiteRevisionDiskCache::Block(disk_cache) => disk_cache.read_revision_authors(&self.object_id, range),
+            SQLiteRevisionDiskCache::View(disk_cache) => disk_cache.read_revision_authors(&self.object_id, range),
+        }
+    }
+
+    fn read_stats(&self) -> FlowyResult<RevisionTableStats> {
+        match &self.disk_cache {
+            SQLiteRevisionDiskCache::Database(disk_cache) => disk_cache.read_stats(&self.object_id),
+            SQLiteRevisionDiskCache::Block(disk_cache) => disk_cache.read_stats(&self.object_id),
+            SQLiteRevisionDiskCache::View(disk_cache) => disk_cache.read_stats(&self.object_id),
+        }
+    }
+
+    fn read_snapshot_stats(&self) -> FlowyResult<(usize, Option<i64>)> {
+        self.snapshot_disk_cache.read_stats()
+    }
+
+    fn delete(&self) -> FlowyResult<()> {
+        self.revision_disk_cache()
+            .delete_revision_records(&self.object_id, None)?;
+        self.snapshot_disk_cache.delete_snapshots()
+    }
+
+    fn reencrypt(&self, new_cipher: Option<&Arc<dyn RevisionCipher>>) -> FlowyResult<()> {
+        match &self.disk_cache {
+            SQLiteRevisionDiskCache::Database(disk_cache) => disk_cache.reencrypt(&self.object_id, new_cipher)?,
+            SQLiteRevisionDiskCache::Block(disk_cache) => disk_cache.reencrypt(&self.object_id, new_cipher)?,
+            SQLiteRevisionDiskCache::View(disk_cache) => disk_cache.reencrypt(&self.object_id, new_cipher)?,
+        }
+        self.snapshot_disk_cache.reencrypt(new_cipher)
+    }
+}
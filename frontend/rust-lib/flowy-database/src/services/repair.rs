@@ -0,0 +1,116 @@
+use crate::notification::{send_notification, DatabaseNotification};
+use flowy_client_sync::client_database::DatabaseRevisionPad;
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use flowy_revision::RevisionManager;
+use flowy_sqlite::ConnectionPool;
+use flowy_task::{TaskContent, TaskHandler};
+use lib_infra::future::BoxResultFuture;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Weak};
+use tokio::sync::RwLock;
+
+/// The number of the latest revisions that are kept when compacting the revisions of a database
+/// that has too many revisions.
+pub const REPAIR_KEEP_LAST_REVISIONS: usize = 100;
+
+pub(crate) fn repair_handler_id(database_id: &str) -> String {
+    format!("{}:repair", database_id)
+}
+
+/// [DatabaseRepairTaskHandler] replays all the revisions of a database that was opened from its
+/// latest snapshot. If the replayed database is the same as the opened one, the revisions are
+/// compacted and the database is no longer marked as repairing.
+pub(crate) struct DatabaseRepairTaskHandler {
+    handler_id: String,
+    database_id: String,
+    database_pad: Weak<RwLock<DatabaseRevisionPad>>,
+    rev_manager: Weak<RevisionManager<Arc<ConnectionPool>>>,
+    is_repairing: Arc<AtomicBool>,
+}
+
+impl DatabaseRepairTaskHandler {
+    pub(crate) fn new(
+        database_id: &str,
+        database_pad: Weak<RwLock<DatabaseRevisionPad>>,
+        rev_manager: Weak<RevisionManager<Arc<ConnectionPool>>>,
+        is_repairing: Arc<AtomicBool>,
+    ) -> Self {
+        Self {
+            handler_id: repair_handler_id(database_id),
+            database_id: database_id.to_owned(),
+            database_pad,
+            rev_manager,
+            is_repairing,
+        }
+    }
+}
+
+impl TaskHandler for DatabaseRepairTaskHandler {
+    fn handler_id(&self) -> &str {
+        &self.handler_id
+    }
+
+    fn handler_name(&self) -> &str {
+        "DatabaseRepairTaskHandler"
+    }
+
+    fn run(&self, _content: TaskContent) -> BoxResultFuture<(), anyhow::Error> {
+        let database_id = self.database_id.clone();
+        let database_pad = self.database_pad.upgrade();
+        let rev_manager = self.rev_manager.upgrade();
+        let is_repairing = self.is_repairing.clone();
+        Box::pin(async move {
+            // The editor was closed before the task runs.
+            let (database_pad, rev_manager) = match (database_pad, rev_manager) {
+                (Some(database_pad), Some(rev_manager)) => (database_pad, rev_manager),
+                _ => return Ok(()),
+            };
+            replay_revisions(&database_id, &database_pad, &rev_manager)
+                .await
+                .map_err(anyhow::Error::from)?;
+            is_repairing.store(false, Ordering::SeqCst);
+            send_notification(&database_id, DatabaseNotification::DidFinishRepairingDatabase).send();
+            Ok(())
+        })
+    }
+}
+
+async fn replay_revisions(
+    database_id: &str,
+    database_pad: &RwLock<DatabaseRevisionPad>,
+    rev_manager: &RevisionManager<Arc<ConnectionPool>>,
+) -> FlowyResult<()> {
+    // Copy the database and the rev_id of its latest revision, so the database can still be
+    // edited while replaying its revisions.
+    let (json_str, rev_id) = {
+        let read_guard = database_pad.read().await;
+        (read_guard.json_str()?, rev_manager.rev_id())
+    };
+    rev_manager.flush().await?;
+    let revisions = rev_manager
+        .load_revisions()
+        .await?
+        .into_iter()
+        .filter(|revision| revision.rev_id <= rev_id)
+        .collect::<Vec<_>>();
+    // Replaying the revisions is CPU bound, so it doesn't block the runtime.
+    let is_same = tokio::task::spawn_blocking(move || -> FlowyResult<bool> {
+        let replayed_pad = DatabaseRevisionPad::from_revisions(revisions)?;
+        Ok(replayed_pad.json_str()? == json_str)
+    })
+    .await
+    .map_err(internal_error)??;
+    if !is_same {
+        let msg = format!(
+            "The database:{} replayed from its revisions is different from its latest snapshot",
+            database_id
+        );
+        tracing::error!("{}", msg);
+        return Err(FlowyError::internal().context(msg));
+    }
+
+    rev_manager.generate_snapshot().await;
+    let reclaimed_size = rev_manager.compact_history(REPAIR_KEEP_LAST_REVISIONS).await?;
+    tracing::info!("Repair database:{}, reclaimed {} bytes", database_id, reclaimed_size);
+    Ok(())
+}
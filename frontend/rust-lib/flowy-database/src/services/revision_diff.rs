@@ -0,0 +1,172 @@
+use crate::entities::{BlockDiffPB, FieldDiffPB, FieldPB, RowDiffPB};
+use crate::services::field::changed_field_properties;
+use crate::services::persistence::rev_store::TimestampedRevision;
+use flowy_client_sync::client_database::{DatabaseOperations, DatabaseRevisionPad};
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_revision::RevisionSnapshot;
+use grid_model::RowRevision;
+use lib_ot::core::OperationTransform;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+/// [DatabaseRevisionState] is the database reconstructed at a revision.
+pub(crate) struct DatabaseRevisionState {
+    pub(crate) database_pad: DatabaseRevisionPad,
+
+    /// The time, in seconds, when the revision was written to disk. The rows of the blocks at the
+    /// revision are read from the block revisions that were written no later than it. It's
+    /// i64::MAX if the state is the latest one, so all the block revisions are applied.
+    pub(crate) timestamp: i64,
+
+    /// The state is not exactly the database at the revision, because the revision was merged
+    /// with others or compacted away.
+    pub(crate) is_approximate: bool,
+}
+
+/// Reconstructs the database at the `rev_id` from the `revisions` that are sorted by rev_id.
+///
+/// A merged revision takes the rev_id of the first revision it contains, so the state is
+/// approximate if the `rev_id` falls in the middle of a merged revision. The state falls back to
+/// the nearest snapshot if the `rev_id` predates the oldest revision.
+pub(crate) fn make_database_state(
+    rev_id: i64,
+    revisions: &[TimestampedRevision],
+    snapshots: &[RevisionSnapshot],
+) -> FlowyResult<DatabaseRevisionState> {
+    let first_rev_id = match revisions.first() {
+        None => return Err(FlowyError::record_not_found().context("The database has no revision")),
+        Some(timestamped_revision) => timestamped_revision.revision.rev_id,
+    };
+
+    let is_before_first_revision = rev_id < first_rev_id;
+    if is_before_first_revision {
+        let snapshot = snapshots
+            .iter()
+            .filter(|snapshot| snapshot.rev_id <= rev_id)
+            .max_by_key(|snapshot| snapshot.rev_id)
+            .or_else(|| snapshots.iter().min_by_key(|snapshot| snapshot.rev_id));
+        if let Some(snapshot) = snapshot {
+            let operations = DatabaseOperations::from_bytes(snapshot.data.clone())?;
+            return Ok(DatabaseRevisionState {
+                database_pad: DatabaseRevisionPad::from_operations(operations)?,
+                timestamp: snapshot.timestamp / 1000,
+                is_approximate: true,
+            });
+        }
+    }
+
+    // The first revision is always applied, so the state is the oldest one if there is no snapshot.
+    let mut operations = DatabaseOperations::new();
+    let mut timestamp = 0;
+    let mut next_rev_id = None;
+    for (index, timestamped_revision) in revisions.iter().enumerate() {
+        if index > 0 && timestamped_revision.revision.rev_id > rev_id {
+            next_rev_id = Some(timestamped_revision.revision.rev_id);
+            break;
+        }
+        let revision_operations = DatabaseOperations::from_bytes(timestamped_revision.revision.bytes.clone())?;
+        operations = operations.compose(&revision_operations)?;
+        timestamp = timestamped_revision.timestamp;
+    }
+
+    let (timestamp, is_approximate) = match next_rev_id {
+        None => (i64::MAX, is_before_first_revision),
+        Some(next_rev_id) => (timestamp, is_before_first_revision || next_rev_id != rev_id + 1),
+    };
+    Ok(DatabaseRevisionState {
+        database_pad: DatabaseRevisionPad::from_operations(operations)?,
+        timestamp,
+        is_approximate,
+    })
+}
+
+/// Returns the inserted, the deleted and the updated fields from the `from` to the `to`.
+pub(crate) fn make_field_diffs(
+    from: &DatabaseRevisionPad,
+    to: &DatabaseRevisionPad,
+) -> (Vec<FieldPB>, Vec<FieldPB>, Vec<FieldDiffPB>) {
+    let mut inserted_fields = vec![];
+    let mut updated_fields = vec![];
+    for to_field_rev in to.get_fields() {
+        match from.get_field_rev(&to_field_rev.id) {
+            None => inserted_fields.push(FieldPB::from(to_field_rev.as_ref().clone())),
+            Some((_, from_field_rev)) => {
+                let changed_properties = changed_field_properties(from_field_rev, to_field_rev);
+                if !changed_properties.is_empty() {
+                    updated_fields.push(FieldDiffPB {
+                        field: FieldPB::from(to_field_rev.as_ref().clone()),
+                        changed_properties,
+                    });
+                }
+            }
+        }
+    }
+
+    let deleted_fields = from
+        .get_fields()
+        .iter()
+        .filter(|from_field_rev| !to.contain_field(&from_field_rev.id))
+        .map(|from_field_rev| FieldPB::from(from_field_rev.as_ref().clone()))
+        .collect::<Vec<FieldPB>>();
+    (inserted_fields, deleted_fields, updated_fields)
+}
+
+/// Returns the changes of the rows of the block from the `from_rows` to the `to_rows`. Returns
+/// None if the rows were not changed.
+pub(crate) fn make_block_diff(
+    block_id: &str,
+    from_rows: &[Arc<RowRevision>],
+    to_rows: &[Arc<RowRevision>],
+) -> Option<BlockDiffPB> {
+    let from_row_by_id = from_rows
+        .iter()
+        .map(|row_rev| (row_rev.id.as_str(), row_rev))
+        .collect::<HashMap<&str, &Arc<RowRevision>>>();
+    let to_row_by_id = to_rows
+        .iter()
+        .map(|row_rev| (row_rev.id.as_str(), row_rev))
+        .collect::<HashMap<&str, &Arc<RowRevision>>>();
+
+    let mut inserted_row_ids = vec![];
+    let mut updated_rows = vec![];
+    for to_row_rev in to_rows {
+        match from_row_by_id.get(to_row_rev.id.as_str()) {
+            None => inserted_row_ids.push(to_row_rev.id.clone()),
+            Some(from_row_rev) => {
+                if *from_row_rev != to_row_rev {
+                    updated_rows.push(RowDiffPB {
+                        row_id: to_row_rev.id.clone(),
+                        changed_field_ids: changed_cell_field_ids(from_row_rev, to_row_rev),
+                    });
+                }
+            }
+        }
+    }
+
+    let deleted_row_ids = from_rows
+        .iter()
+        .filter(|from_row_rev| !to_row_by_id.contains_key(from_row_rev.id.as_str()))
+        .map(|from_row_rev| from_row_rev.id.clone())
+        .collect::<Vec<String>>();
+
+    if inserted_row_ids.is_empty() && deleted_row_ids.is_empty() && updated_rows.is_empty() {
+        return None;
+    }
+    Some(BlockDiffPB {
+        block_id: block_id.to_owned(),
+        inserted_row_ids,
+        deleted_row_ids,
+        updated_rows,
+    })
+}
+
+fn changed_cell_field_ids(from: &RowRevision, to: &RowRevision) -> Vec<String> {
+    from.cells
+        .keys()
+        .chain(to.cells.keys())
+        .collect::<BTreeSet<&String>>()
+        .into_iter()
+        .filter(|field_id| from.cells.get(*field_id) != to.cells.get(*field_id))
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,141 @@
+use crate::entities::{FieldDiffPB, FieldPB};
+use crate::services::revision_diff::make_field_diffs;
+use flowy_client_sync::client_database::{DatabaseOperations, DatabaseRevisionPad};
+use revision_model::Revision;
+use std::collections::HashMap;
+
+/// [RevisionPreview] is the result of applying a batch of revisions to a copy of the database.
+/// The changes are summarized from the revisions that were applied before the first conflict.
+#[derive(Debug, Clone, Default)]
+pub struct RevisionPreview {
+    /// The rev_ids of the revisions that were applied, in rev_id ascending order.
+    pub applied_rev_ids: Vec<i64>,
+
+    /// The rev_ids of the revisions that are equal to the local ones, so they were skipped.
+    pub skipped_rev_ids: Vec<i64>,
+
+    /// The revisions after the first conflict are not applied, so there is at most one conflict.
+    pub conflicts: Vec<RevisionConflict>,
+
+    pub inserted_fields: Vec<FieldPB>,
+    pub deleted_fields: Vec<FieldPB>,
+    pub updated_fields: Vec<FieldDiffPB>,
+    pub inserted_block_ids: Vec<String>,
+    pub deleted_block_ids: Vec<String>,
+}
+
+impl RevisionPreview {
+    pub fn applies_cleanly(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevisionConflict {
+    pub rev_id: i64,
+    pub kind: RevisionConflictKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RevisionConflictKind {
+    /// A local revision with the same rev_id exists, but its content is different. The revision
+    /// was made on top of a stale state of the database.
+    Stale,
+
+    /// The revision is not based on the latest revision, so some of the revisions in between are
+    /// missing.
+    BaseMismatch { expected_base_rev_id: i64 },
+
+    /// The operations of the revision can't be deserialized or composed with the database.
+    Uncomposable(String),
+
+    /// The operations were composed, but the resulting database is different from the one that
+    /// the revision was made with.
+    Md5Mismatch,
+}
+
+/// Applies the `revisions` one by one to the `database_pad`, which is a copy of the database whose
+/// latest revision is the `latest_rev_id`. The `local_revisions` are the local revisions that have
+/// the same rev_ids as some of the `revisions`. Stops at the first conflict.
+pub(crate) fn make_revision_preview(
+    mut database_pad: DatabaseRevisionPad,
+    latest_rev_id: i64,
+    mut revisions: Vec<Revision>,
+    local_revisions: &HashMap<i64, Revision>,
+) -> RevisionPreview {
+    revisions.sort_by_key(|revision| revision.rev_id);
+    let original_pad = database_pad.clone();
+    let mut preview = RevisionPreview::default();
+    let mut expected_base_rev_id = latest_rev_id;
+    for revision in revisions {
+        let conflict = |kind: RevisionConflictKind| RevisionConflict {
+            rev_id: revision.rev_id,
+            kind,
+        };
+        if let Some(local_revision) = local_revisions.get(&revision.rev_id) {
+            if local_revision.md5 == revision.md5 {
+                preview.skipped_rev_ids.push(revision.rev_id);
+                continue;
+            }
+            preview.conflicts.push(conflict(RevisionConflictKind::Stale));
+            break;
+        }
+
+        if revision.base_rev_id != expected_base_rev_id {
+            preview
+                .conflicts
+                .push(conflict(RevisionConflictKind::BaseMismatch { expected_base_rev_id }));
+            break;
+        }
+
+        let operations = match DatabaseOperations::from_bytes(&revision.bytes) {
+            Ok(operations) => operations,
+            Err(e) => {
+                let kind = RevisionConflictKind::Uncomposable(e.to_string());
+                preview.conflicts.push(conflict(kind));
+                break;
+            }
+        };
+        let mut composed_pad = database_pad.clone();
+        let md5 = match composed_pad.compose_remote_operations(operations) {
+            Ok(md5) => md5,
+            Err(e) => {
+                let kind = RevisionConflictKind::Uncomposable(e.to_string());
+                preview.conflicts.push(conflict(kind));
+                break;
+            }
+        };
+        if !revision.md5.is_empty() && revision.md5 != md5 {
+            preview.conflicts.push(conflict(RevisionConflictKind::Md5Mismatch));
+            break;
+        }
+        database_pad = composed_pad;
+        preview.applied_rev_ids.push(revision.rev_id);
+        expected_base_rev_id = revision.rev_id;
+    }
+
+    let (inserted_fields, deleted_fields, updated_fields) = make_field_diffs(&original_pad, &database_pad);
+    let original_block_ids = block_ids(&original_pad);
+    let block_ids = block_ids(&database_pad);
+    preview.inserted_fields = inserted_fields;
+    preview.deleted_fields = deleted_fields;
+    preview.updated_fields = updated_fields;
+    preview.inserted_block_ids = block_ids
+        .iter()
+        .filter(|block_id| !original_block_ids.contains(block_id))
+        .cloned()
+        .collect();
+    preview.deleted_block_ids = original_block_ids
+        .into_iter()
+        .filter(|block_id| !block_ids.contains(block_id))
+        .collect();
+    preview
+}
+
+fn block_ids(database_pad: &DatabaseRevisionPad) -> Vec<String> {
+    database_pad
+        .get_block_meta_revs()
+        .iter()
+        .map(|block_meta_rev| block_meta_rev.block_id.clone())
+        .collect()
+}
@@ -0,0 +1,80 @@
+use crate::services::persistence::rev_store::{GridRevisionObject, GridRevisionStores};
+use flowy_error::FlowyResult;
+use std::collections::HashMap;
+
+/// The revisions and the snapshots of a database that are stored on disk.
+#[derive(Debug, Clone, Default)]
+pub struct DatabaseRevisionStats {
+    pub database_id: String,
+
+    /// The number of the database's revisions, not including the revisions of its blocks.
+    pub number_of_revisions: usize,
+
+    /// The total size, in bytes, of the payloads of the database's revisions.
+    pub payload_bytes: usize,
+
+    pub number_of_snapshots: usize,
+
+    /// The time, in milliseconds, when the latest snapshot of the database was written.
+    pub last_snapshot_at: Option<i64>,
+
+    /// The number of the revisions of each block of the database.
+    pub block_revisions: HashMap<String, usize>,
+
+    /// The total size, in bytes, of the payloads of the revisions of the database's blocks.
+    pub block_payload_bytes: usize,
+}
+
+/// The revisions and the snapshots of all the databases that are stored on disk.
+#[derive(Debug, Clone, Default)]
+pub struct DatabaseStorageReport {
+    /// The blocks of the databases that are not opened are not listed in their `block_revisions`,
+    /// because it requires loading the databases. They are still counted in the totals below.
+    pub databases: Vec<DatabaseRevisionStats>,
+
+    /// The number of the revisions of the databases and all the blocks.
+    pub number_of_revisions: usize,
+
+    /// The total size, in bytes, of the payloads of the revisions of the databases and all the
+    /// blocks.
+    pub payload_bytes: usize,
+
+    /// The number of the snapshots of the databases.
+    pub number_of_snapshots: usize,
+
+    /// The total size, in bytes, of the files of the attachments that are stored in the app's
+    /// storage directory.
+    pub attachment_bytes: usize,
+}
+
+/// Reads the stats of the database and the blocks with the `block_ids` from the revision stores,
+/// so the payloads are not loaded.
+pub(crate) fn read_database_revision_stats(
+    rev_stores: &GridRevisionStores,
+    database_id: &str,
+    block_ids: &[String],
+) -> FlowyResult<DatabaseRevisionStats> {
+    let database_store = rev_stores.make_store(GridRevisionObject::Database, database_id)?;
+    let database_stats = database_store.read_stats()?;
+    let (number_of_snapshots, last_snapshot_at) = database_store.read_snapshot_stats()?;
+
+    let mut block_revisions = HashMap::new();
+    let mut block_payload_bytes = 0;
+    for block_id in block_ids {
+        let block_stats = rev_stores
+            .make_store(GridRevisionObject::Block, block_id)?
+            .read_stats()?;
+        block_revisions.insert(block_id.clone(), block_stats.number_of_revisions);
+        block_payload_bytes += block_stats.payload_bytes;
+    }
+
+    Ok(DatabaseRevisionStats {
+        database_id: database_id.to_owned(),
+        number_of_revisions: database_stats.number_of_revisions,
+        payload_bytes: database_stats.payload_bytes,
+        number_of_snapshots,
+        last_snapshot_at,
+        block_revisions,
+        block_payload_bytes,
+    })
+}
@@ -1,5 +1,9 @@
 mod row_builder;
+mod row_history;
 mod row_loader;
+mod row_template;
 
 pub use row_builder::*;
+pub(crate) use row_history::*;
 pub use row_loader::*;
+pub use row_template::*;
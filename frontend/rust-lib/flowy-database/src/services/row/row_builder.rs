@@ -1,15 +1,18 @@
 use crate::services::cell::{
-    insert_checkbox_cell, insert_date_cell, insert_number_cell, insert_select_option_cell, insert_text_cell,
-    insert_url_cell,
+    insert_checkbox_cell, insert_checklist_cell, insert_date_cell, insert_number_cell, insert_person_cell,
+    insert_rating_cell, insert_relation_cell, insert_select_option_cell, insert_text_cell, insert_url_cell,
 };
 
+use crate::services::field::{make_default_cell_rev, ChecklistCellChangeset};
 use grid_model::{gen_row_id, CellRevision, FieldRevision, RowRevision, DEFAULT_ROW_HEIGHT};
 use indexmap::IndexMap;
+use lib_infra::util::timestamp;
 use std::collections::HashMap;
 use std::sync::Arc;
 
 pub struct RowRevisionBuilder<'a> {
     block_id: String,
+    fields: &'a [Arc<FieldRevision>],
     field_rev_map: HashMap<&'a String, Arc<FieldRevision>>,
     payload: CreateRowRevisionPayload,
 }
@@ -32,11 +35,22 @@ impl<'a> RowRevisionBuilder<'a> {
 
         Self {
             block_id,
+            fields,
             field_rev_map,
             payload,
         }
     }
 
+    pub fn row_id(&self) -> &str {
+        &self.payload.row_id
+    }
+
+    /// Inserts the cell whose data is already serialized, for example, the return value of
+    /// `apply_cell_data_changeset`.
+    pub fn insert_cell_rev(&mut self, field_id: &str, cell_rev: CellRevision) {
+        self.payload.cell_by_field_id.insert(field_id.to_owned(), cell_rev);
+    }
+
     pub fn insert_text_cell(&mut self, field_id: &str, data: String) {
         match self.field_rev_map.get(&field_id.to_owned()) {
             None => tracing::warn!("Can't find the text field with id: {}", field_id),
@@ -103,6 +117,62 @@ impl<'a> RowRevisionBuilder<'a> {
         }
     }
 
+    pub fn insert_checklist_cell(&mut self, field_id: &str, changeset: ChecklistCellChangeset) {
+        match self.field_rev_map.get(&field_id.to_owned()) {
+            None => tracing::warn!("Can't find the checklist field with id: {}", field_id),
+            Some(field_rev) => {
+                self.payload
+                    .cell_by_field_id
+                    .insert(field_id.to_owned(), insert_checklist_cell(changeset, field_rev));
+            }
+        }
+    }
+
+    pub fn insert_rating_cell(&mut self, field_id: &str, rating: i64) {
+        match self.field_rev_map.get(&field_id.to_owned()) {
+            None => tracing::warn!("Can't find the rating field with id: {}", field_id),
+            Some(field_rev) => {
+                self.payload
+                    .cell_by_field_id
+                    .insert(field_id.to_owned(), insert_rating_cell(rating, field_rev));
+            }
+        }
+    }
+
+    pub fn insert_relation_cell(&mut self, field_id: &str, row_ids: Vec<String>) {
+        match self.field_rev_map.get(&field_id.to_owned()) {
+            None => tracing::warn!("Can't find the relation field with id: {}", field_id),
+            Some(field_rev) => {
+                self.payload
+                    .cell_by_field_id
+                    .insert(field_id.to_owned(), insert_relation_cell(row_ids, field_rev));
+            }
+        }
+    }
+
+    pub fn insert_person_cell(&mut self, field_id: &str, member_ids: Vec<String>) {
+        match self.field_rev_map.get(&field_id.to_owned()) {
+            None => tracing::warn!("Can't find the person field with id: {}", field_id),
+            Some(field_rev) => {
+                self.payload
+                    .cell_by_field_id
+                    .insert(field_id.to_owned(), insert_person_cell(member_ids, field_rev));
+            }
+        }
+    }
+
+    /// Fills the cells that are not inserted with the default values of their fields.
+    pub fn insert_default_cells(&mut self) {
+        for field_rev in self.fields {
+            if self.payload.cell_by_field_id.contains_key(&field_rev.id) {
+                continue;
+            }
+            if let Some(cell_rev) = make_default_cell_rev(field_rev) {
+                self.payload.cell_by_field_id.insert(field_rev.id.clone(), cell_rev);
+            }
+        }
+    }
+
     #[allow(dead_code)]
     pub fn height(mut self, height: i32) -> Self {
         self.payload.height = height;
@@ -116,12 +186,18 @@ impl<'a> RowRevisionBuilder<'a> {
     }
 
     pub fn build(self) -> RowRevision {
+        let created_at = timestamp();
         RowRevision {
             id: self.payload.row_id,
             block_id: self.block_id,
             cells: self.payload.cell_by_field_id,
             height: self.payload.height,
             visibility: self.payload.visibility,
+            created_at,
+            modified_at: created_at,
+            comments: Default::default(),
+            meta: Default::default(),
+            document_id: None,
         }
     }
 }
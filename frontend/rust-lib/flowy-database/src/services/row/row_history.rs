@@ -0,0 +1,70 @@
+use crate::entities::{CellChangePB, FieldType, RowChangePB};
+use crate::services::block_manager::RowRevisionChange;
+use crate::services::cell::{stringify_cell_data, TypeCellData};
+use grid_model::{CellRevision, FieldRevision};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+/// Makes a [RowChangePB] from the [RowRevisionChange]. The cells are stringified with the current
+/// fields, and the raw data of the cell is used if its field was deleted.
+pub(crate) fn make_row_change_pb(
+    row_change: &RowRevisionChange,
+    field_rev_by_id: &HashMap<String, Arc<FieldRevision>>,
+) -> RowChangePB {
+    let before_cells = row_change.before.as_ref().map(|row_rev| &row_rev.cells);
+    let after_cells = row_change.after.as_ref().map(|row_rev| &row_rev.cells);
+    let field_ids = before_cells
+        .into_iter()
+        .chain(after_cells)
+        .flat_map(|cells| cells.keys().cloned())
+        .collect::<BTreeSet<String>>();
+
+    let mut changed_field_ids = vec![];
+    let mut cells = vec![];
+    for field_id in field_ids {
+        let before = before_cells.and_then(|cells| cells.get(&field_id));
+        let after = after_cells.and_then(|cells| cells.get(&field_id));
+        if before == after {
+            continue;
+        }
+        let field_rev = field_rev_by_id.get(&field_id);
+        cells.push(CellChangePB {
+            field_id: field_id.clone(),
+            before: stringify_cell_rev(before, field_rev),
+            after: stringify_cell_rev(after, field_rev),
+        });
+        changed_field_ids.push(field_id);
+    }
+
+    RowChangePB {
+        rev_id: row_change.rev_id,
+        timestamp: row_change.timestamp,
+        changed_field_ids,
+        cells,
+        is_created: row_change.before.is_none(),
+        is_deleted: row_change.after.is_none(),
+    }
+}
+
+fn stringify_cell_rev(cell_rev: Option<&CellRevision>, field_rev: Option<&Arc<FieldRevision>>) -> String {
+    let cell_rev = match cell_rev {
+        None => return "".to_owned(),
+        Some(cell_rev) => cell_rev,
+    };
+    let type_cell_data = match TypeCellData::try_from(cell_rev) {
+        Ok(type_cell_data) => type_cell_data,
+        Err(_) => return cell_rev.type_cell_data.clone(),
+    };
+    match field_rev {
+        None => type_cell_data.cell_str,
+        Some(field_rev) => {
+            let field_type: FieldType = field_rev.ty.into();
+            stringify_cell_data(
+                type_cell_data.cell_str,
+                &type_cell_data.field_type,
+                &field_type,
+                field_rev,
+            )
+        }
+    }
+}
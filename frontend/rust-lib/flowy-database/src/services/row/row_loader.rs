@@ -1,4 +1,4 @@
-use crate::entities::RowPB;
+use crate::entities::{RowMetaPB, RowPB};
 use grid_model::RowRevision;
 
 use std::sync::Arc;
@@ -28,6 +28,8 @@ pub(crate) fn make_rows_from_row_revs(row_revs: &[Arc<RowRevision>]) -> Vec<RowP
         block_id: row_rev.block_id.clone(),
         id: row_rev.id.clone(),
         height: row_rev.height,
+        meta: RowMetaPB::from(&row_rev.meta),
+        document_id: row_rev.document_id.clone(),
     };
 
     row_revs.iter().map(make_row).collect::<Vec<_>>()
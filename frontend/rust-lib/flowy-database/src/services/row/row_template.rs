@@ -0,0 +1,169 @@
+use crate::entities::FieldType;
+use crate::services::cell::TypeCellData;
+use crate::services::field::{select_type_option_from_field_rev, SelectOptionIds};
+use crate::services::persistence::kv::{DatabaseKVPersistence, KVTransaction, KeyValue};
+use flowy_error::{FlowyError, FlowyResult};
+use grid_model::{CellRevision, FieldRevision, FieldTypeRevision, RowRevision};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+fn row_templates_key(database_id: &str) -> String {
+    format!("row_templates:{}", database_id)
+}
+
+/// [RowTemplate] is the saved cells of a row, which are copied to the rows that are created from
+/// the template. The templates belong to a database, and they are identified by their names.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RowTemplate {
+    pub name: String,
+    pub cells: Vec<RowTemplateCell>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RowTemplateCell {
+    pub field_id: String,
+    /// The field type of the field when the template was saved.
+    pub field_type: FieldTypeRevision,
+    /// The json string of the [TypeCellData] of the cell.
+    pub data: String,
+}
+
+impl RowTemplate {
+    /// Captures the cells of the row. The empty cells and the cells of the fields whose data is
+    /// computed or refers to the files of the row, like the timestamp, the formula and the
+    /// attachment fields, are not captured. The cell of the primary field is captured only if
+    /// `include_primary` is true.
+    pub fn from_row_rev(
+        name: &str,
+        row_rev: &RowRevision,
+        field_revs: &[Arc<FieldRevision>],
+        include_primary: bool,
+    ) -> Self {
+        let cells = field_revs
+            .iter()
+            .filter(|field_rev| include_primary || !field_rev.is_primary)
+            .filter(|field_rev| {
+                let field_type: FieldType = field_rev.ty.into();
+                !field_type.is_timestamp() && !field_type.is_formula() && field_type != FieldType::Attachment
+            })
+            .flat_map(|field_rev| {
+                let cell_rev = row_rev.cells.get(&field_rev.id)?;
+                let type_cell_data = TypeCellData::try_from(cell_rev).ok()?;
+                if type_cell_data.cell_str.is_empty() {
+                    return None;
+                }
+                Some(RowTemplateCell {
+                    field_id: field_rev.id.clone(),
+                    field_type: field_rev.ty,
+                    data: cell_rev.type_cell_data.clone(),
+                })
+            })
+            .collect();
+        Self {
+            name: name.to_owned(),
+            cells,
+        }
+    }
+
+    /// Returns the cells of the template that are still compatible with the fields, keyed by the
+    /// field id. The cells of the fields that were deleted or switched to another field type since
+    /// the template was saved are skipped, and so are the options of the select cells that were
+    /// deleted from their fields.
+    pub fn make_cell_revs(&self, field_revs: &[Arc<FieldRevision>]) -> HashMap<String, CellRevision> {
+        let mut cell_revs = HashMap::new();
+        for cell in self.cells.iter() {
+            let field_rev = match field_revs.iter().find(|field_rev| field_rev.id == cell.field_id) {
+                Some(field_rev) if field_rev.ty == cell.field_type && !field_rev.locked => field_rev,
+                _ => continue,
+            };
+            let mut type_cell_data = match TypeCellData::from_json_str(&cell.data) {
+                Ok(type_cell_data) => type_cell_data,
+                Err(_) => continue,
+            };
+            if type_cell_data.is_single_select() || type_cell_data.is_multi_select() {
+                let options = match select_type_option_from_field_rev(field_rev) {
+                    Ok(type_option) => type_option.options().clone(),
+                    Err(_) => continue,
+                };
+                let mut option_ids = SelectOptionIds::from(type_cell_data.cell_str);
+                option_ids.retain(|option_id| options.iter().any(|option| &option.id == option_id));
+                if option_ids.is_empty() {
+                    continue;
+                }
+                type_cell_data.cell_str = option_ids.to_string();
+            }
+            cell_revs.insert(cell.field_id.clone(), CellRevision::new(type_cell_data.to_json()));
+        }
+        cell_revs
+    }
+}
+
+/// [RowTemplateStore] persists the [RowTemplate]s of each database.
+pub struct RowTemplateStore {
+    kv_persistence: Arc<DatabaseKVPersistence>,
+}
+
+impl RowTemplateStore {
+    pub fn new(kv_persistence: Arc<DatabaseKVPersistence>) -> Self {
+        Self { kv_persistence }
+    }
+
+    /// Returns the templates of the database in the order they were first saved.
+    pub fn get_templates(&self, database_id: &str) -> FlowyResult<Vec<RowTemplate>> {
+        match self.kv_persistence.get_bytes(&row_templates_key(database_id))? {
+            None => Ok(vec![]),
+            Some(bytes) => Ok(serde_json::from_slice(&bytes)?),
+        }
+    }
+
+    pub fn get_template(&self, database_id: &str, name: &str) -> FlowyResult<Option<RowTemplate>> {
+        let template = self
+            .get_templates(database_id)?
+            .into_iter()
+            .find(|template| template.name == name);
+        Ok(template)
+    }
+
+    /// Saves the templates of the database. The existing template with the same name is replaced.
+    pub fn save_templates(&self, database_id: &str, templates: Vec<RowTemplate>) -> FlowyResult<()> {
+        let mut saved_templates = self.get_templates(database_id)?;
+        for template in templates {
+            if template.name.is_empty() {
+                return Err(FlowyError::invalid_data().context("The name of the row template is empty"));
+            }
+            match saved_templates.iter_mut().find(|saved| saved.name == template.name) {
+                None => saved_templates.push(template),
+                Some(saved_template) => *saved_template = template,
+            }
+        }
+        self.set_templates(database_id, &saved_templates)
+    }
+
+    pub fn delete_template(&self, database_id: &str, name: &str) -> FlowyResult<()> {
+        let mut templates = self.get_templates(database_id)?;
+        templates.retain(|template| template.name != name);
+        self.set_templates(database_id, &templates)
+    }
+
+    /// Deletes all the templates of the database.
+    pub fn delete_templates(&self, database_id: &str) -> FlowyResult<()> {
+        self.kv_persistence.remove(&row_templates_key(database_id))
+    }
+
+    /// Copies the templates of the database to another database, for example, the duplicated
+    /// database whose fields have the same ids.
+    pub fn copy_templates(&self, database_id: &str, to_database_id: &str) -> FlowyResult<()> {
+        let templates = self.get_templates(database_id)?;
+        if templates.is_empty() {
+            return Ok(());
+        }
+        self.set_templates(to_database_id, &templates)
+    }
+
+    fn set_templates(&self, database_id: &str, templates: &[RowTemplate]) -> FlowyResult<()> {
+        let bytes = serde_json::to_vec(templates)?;
+        self.kv_persistence
+            .set(KeyValue::new(row_templates_key(database_id), bytes))
+    }
+}
@@ -0,0 +1,90 @@
+use flowy_revision::{NamedRevisionSnapshot, RevisionSnapshot};
+use std::time::Duration;
+
+/// [DatabaseSnapshotMeta] describes a snapshot of the database without carrying its content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatabaseSnapshotMeta {
+    /// The rev_id of the database when the snapshot was generated. It's used to restore the
+    /// database from the snapshot.
+    pub rev_id: i64,
+
+    /// The unix timestamp, in milliseconds, when the snapshot was generated.
+    pub created_at: i64,
+
+    /// The size of the snapshot's content in bytes.
+    pub size: usize,
+}
+
+impl std::convert::From<&RevisionSnapshot> for DatabaseSnapshotMeta {
+    fn from(snapshot: &RevisionSnapshot) -> Self {
+        Self {
+            rev_id: snapshot.rev_id,
+            created_at: snapshot.timestamp,
+            size: snapshot.data.len(),
+        }
+    }
+}
+
+/// [DatabaseCheckpointMeta] describes a checkpoint of the database, which is a snapshot with a
+/// name that the user created. The checkpoints are not deleted by the retention of the snapshots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatabaseCheckpointMeta {
+    pub name: String,
+
+    /// The rev_id of the database when the checkpoint was created.
+    pub rev_id: i64,
+
+    /// The unix timestamp, in milliseconds, when the checkpoint was created.
+    pub created_at: i64,
+}
+
+impl std::convert::From<&NamedRevisionSnapshot> for DatabaseCheckpointMeta {
+    fn from(named_snapshot: &NamedRevisionSnapshot) -> Self {
+        Self {
+            name: named_snapshot.name.clone(),
+            rev_id: named_snapshot.snapshot.rev_id,
+            created_at: named_snapshot.snapshot.timestamp,
+        }
+    }
+}
+
+/// Returns the name of the checkpoint that is created automatically before rolling back to the
+/// checkpoint with the `name`. Rolling back to it reverts the rollback.
+pub fn pre_rollback_checkpoint_name(name: &str) -> String {
+    format!("Before rolling back to {}", name)
+}
+
+/// [DatabaseSnapshotPolicy] decides when the snapshots of the database, its blocks and its views
+/// are generated automatically, and how many of them are kept.
+#[derive(Debug, Clone)]
+pub struct DatabaseSnapshotPolicy {
+    /// Generates a snapshot after every `per_revisions` revisions.
+    pub per_revisions: i64,
+
+    /// Generates a snapshot if the object was edited and the last snapshot is older than the
+    /// interval, even if the number of revisions doesn't reach `per_revisions`.
+    pub interval: Duration,
+
+    /// The number of the latest snapshots that are kept for each object. The older ones are
+    /// deleted after writing a new snapshot.
+    pub retention: usize,
+}
+
+impl DatabaseSnapshotPolicy {
+    pub(crate) fn should_generate_snapshot(&self, num_of_revisions: i64, elapsed_millis: i64) -> bool {
+        if num_of_revisions <= 0 {
+            return false;
+        }
+        num_of_revisions >= self.per_revisions || elapsed_millis >= self.interval.as_millis() as i64
+    }
+}
+
+impl std::default::Default for DatabaseSnapshotPolicy {
+    fn default() -> Self {
+        Self {
+            per_revisions: 50,
+            interval: Duration::from_secs(10 * 60),
+            retention: 10,
+        }
+    }
+}
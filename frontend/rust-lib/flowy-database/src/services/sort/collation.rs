@@ -0,0 +1,156 @@
+use grid_model::SortRevision;
+use std::cmp::Ordering;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// [Collation] is the way the texts are compared when the rows are sorted. The texts are compared
+/// by their letters regardless of the accents and the cases first, so `é` is next to `e` instead
+/// of after `z`. The accents and then the cases only break the ties.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Collation {
+    /// Compares the runs of the digits by their numbers, so `item2` is before `item10`.
+    pub natural: bool,
+    /// Compares the select options by their names instead of their order in the type option.
+    pub alphabetical: bool,
+}
+
+impl std::default::Default for Collation {
+    fn default() -> Self {
+        Self {
+            natural: true,
+            alphabetical: false,
+        }
+    }
+}
+
+impl std::convert::From<&SortRevision> for Collation {
+    fn from(sort_rev: &SortRevision) -> Self {
+        Self {
+            natural: sort_rev.natural,
+            alphabetical: sort_rev.alphabetical,
+        }
+    }
+}
+
+impl Collation {
+    pub fn compare(&self, left: &str, right: &str) -> Ordering {
+        self.compare_chars(&base_chars(left), &base_chars(right))
+            .then_with(|| self.compare_chars(&lowercase_chars(left), &lowercase_chars(right)))
+            .then_with(|| self.compare_chars(&left.nfc().collect::<Vec<_>>(), &right.nfc().collect::<Vec<_>>()))
+    }
+
+    fn compare_chars(&self, left: &[char], right: &[char]) -> Ordering {
+        if !self.natural {
+            return left.cmp(right);
+        }
+
+        let (mut left_index, mut right_index) = (0, 0);
+        while left_index < left.len() && right_index < right.len() {
+            let (left_char, right_char) = (left[left_index], right[right_index]);
+            if left_char.is_ascii_digit() && right_char.is_ascii_digit() {
+                let left_digits = digit_run(&left[left_index..]);
+                let right_digits = digit_run(&right[right_index..]);
+                let order = compare_numbers(left_digits, right_digits);
+                if order.is_ne() {
+                    return order;
+                }
+                left_index += left_digits.len();
+                right_index += right_digits.len();
+            } else {
+                let order = left_char.cmp(&right_char);
+                if order.is_ne() {
+                    return order;
+                }
+                left_index += 1;
+                right_index += 1;
+            }
+        }
+        (left.len() - left_index).cmp(&(right.len() - right_index))
+    }
+}
+
+/// The letters of the text without the accents, in lowercase.
+fn base_chars(s: &str) -> Vec<char> {
+    s.nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn lowercase_chars(s: &str) -> Vec<char> {
+    s.nfc().flat_map(char::to_lowercase).collect()
+}
+
+fn digit_run(chars: &[char]) -> &[char] {
+    let len = chars.iter().take_while(|c| c.is_ascii_digit()).count();
+    &chars[..len]
+}
+
+/// Compares the digits by their numbers without parsing them, so the long runs don't overflow.
+/// The number with more leading zeros is after the equal number, for example, `01` after `1`.
+fn compare_numbers(left: &[char], right: &[char]) -> Ordering {
+    let trim_zeros = |digits: &[char]| -> usize { digits.iter().take_while(|c| **c == '0').count() };
+    let (left_zeros, right_zeros) = (trim_zeros(left), trim_zeros(right));
+    let (left_number, right_number) = (&left[left_zeros..], &right[right_zeros..]);
+    left_number
+        .len()
+        .cmp(&right_number.len())
+        .then_with(|| left_number.cmp(right_number))
+        .then_with(|| left_zeros.cmp(&right_zeros))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::sort::Collation;
+
+    fn sorted(texts: &[&str], collation: Collation) -> Vec<String> {
+        let mut texts = texts.iter().map(|text| text.to_string()).collect::<Vec<String>>();
+        texts.sort_by(|left, right| collation.compare(left, right));
+        texts
+    }
+
+    #[test]
+    fn collation_ignore_case_test() {
+        assert_eq!(
+            sorted(&["banana", "Cherry", "apple", "Banana"], Collation::default()),
+            vec!["apple", "Banana", "banana", "Cherry"]
+        );
+    }
+
+    #[test]
+    fn collation_accented_characters_test() {
+        assert_eq!(
+            sorted(&["zebra", "école", "ecole", "Eclair", "faux"], Collation::default()),
+            vec!["Eclair", "ecole", "école", "faux", "zebra"]
+        );
+        // The decomposed `é` is equal to the composed one.
+        let collation = Collation::default();
+        assert!(collation.compare("e\u{301}cole", "\u{e9}cole").is_eq());
+    }
+
+    #[test]
+    fn collation_natural_numbers_test() {
+        assert_eq!(
+            sorted(&["item10", "item2", "Item1", "item02", "item"], Collation::default()),
+            vec!["item", "Item1", "item2", "item02", "item10"]
+        );
+        assert_eq!(
+            sorted(
+                &["item10", "item2", "item1"],
+                Collation {
+                    natural: false,
+                    ..Default::default()
+                }
+            ),
+            vec!["item1", "item10", "item2"]
+        );
+    }
+
+    #[test]
+    fn collation_long_digit_runs_test() {
+        let collation = Collation::default();
+        assert!(collation
+            .compare("v99999999999999999999999", "v100000000000000000000000")
+            .is_lt());
+    }
+}
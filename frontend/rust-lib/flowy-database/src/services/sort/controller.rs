@@ -1,27 +1,38 @@
 use crate::entities::FieldType;
 use crate::entities::SortChangesetNotificationPB;
-use crate::services::cell::{AtomicCellDataCache, TypeCellData};
-use crate::services::field::{default_order, TypeOptionCellExt};
-use crate::services::sort::{ReorderAllRowsResult, ReorderSingleRowResult, SortChangeset, SortType};
+use crate::services::cell::{get_cell_rev_from_row, AtomicCellDataCache, TypeCellData};
+use crate::services::field::{default_order, TypeOptionCellDataHandler, TypeOptionCellExt};
+use crate::services::sort::{
+    Collation, ReorderAllRowsResult, ReorderRowsResult, ReorderSingleRowResult, SortChangeset, SortType,
+};
 use crate::services::view_editor::{GridViewChanged, GridViewChangedNotifier};
 use flowy_error::FlowyResult;
-use flowy_task::{QualityOfService, Task, TaskContent, TaskDispatcher};
-use grid_model::{CellRevision, FieldRevision, RowRevision, SortCondition, SortRevision};
+use flowy_task::{QualityOfService, Task, TaskContent, TaskDispatcher, TaskId};
+use grid_model::{
+    move_pinned_rows_first, CellRevision, FieldRevision, RowRevision, SortCondition, SortEmptyOrder, SortModeRevision,
+    SortRevision,
+};
 use lib_infra::future::Fut;
+use parking_lot::Mutex;
 use rayon::prelude::ParallelSliceMut;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::atomic::{self, AtomicBool, AtomicU64, AtomicUsize};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 pub trait SortDelegate: Send + Sync {
     fn get_sort_rev(&self, sort_type: SortType) -> Fut<Option<Arc<SortRevision>>>;
-    /// Returns all the rows after applying grid's filter
+    /// Returns all the rows after applying grid's filter, in the manual order of the view
     fn get_row_revs(&self) -> Fut<Vec<Arc<RowRevision>>>;
     fn get_field_rev(&self, field_id: &str) -> Fut<Option<Arc<FieldRevision>>>;
     fn get_field_revs(&self, field_ids: Option<Vec<String>>) -> Fut<Vec<Arc<FieldRevision>>>;
+    /// Returns the ids of the rows that are pinned to the top of the view
+    fn get_pinned_row_ids(&self) -> Fut<Vec<String>>;
+    /// Returns the special order of the rows that replaces the sorts, if it's set
+    fn get_sort_mode(&self) -> Fut<Option<SortModeRevision>>;
 }
 
 pub struct SortController {
@@ -33,6 +44,15 @@ pub struct SortController {
     cell_data_cache: AtomicCellDataCache,
     row_index_cache: HashMap<String, usize>,
     notifier: GridViewChangedNotifier,
+    comparison_count: Arc<AtomicUsize>,
+    /// It's increased whenever all the rows are going to be sorted again, so the result of the
+    /// task that was scheduled before is discarded.
+    generation: AtomicU64,
+    /// The task that sorts all the rows. It's cancelled if all the rows are going to be sorted
+    /// again before it's processed.
+    sort_all_task_id: Mutex<Option<TaskId>>,
+    /// It's true while all the rows are sorted without locking the controller.
+    is_sorting_all_rows: AtomicBool,
 }
 
 impl SortController {
@@ -57,34 +77,136 @@ impl SortController {
             cell_data_cache,
             row_index_cache: Default::default(),
             notifier,
+            comparison_count: Arc::new(AtomicUsize::new(0)),
+            generation: AtomicU64::new(0),
+            sort_all_task_id: Mutex::new(None),
+            is_sorting_all_rows: AtomicBool::new(false),
         }
     }
 
     pub async fn close(&self) {
-        self.task_scheduler
-            .write()
-            .await
-            .unregister_handler(&self.handler_id)
-            .await;
+        let mut task_scheduler = self.task_scheduler.write().await;
+        task_scheduler.cancel_tasks_of_handler(&self.handler_id);
+        task_scheduler.unregister_handler(&self.handler_id).await;
     }
 
-    pub async fn did_receive_row_changed(&self, row_id: &str) {
+    /// Moves the row to its new position if the cells of the sorted fields were changed. The
+    /// `changed_field_ids` is None if the changed cells are unknown.
+    pub async fn did_receive_row_changed(&self, row_id: &str, changed_field_ids: Option<Vec<String>>) {
+        match self.delegate.get_sort_mode().await {
+            // The shuffled rows don't move until they're shuffled again.
+            Some(SortModeRevision::Shuffle { .. }) => return,
+            // The changed row is the last modified one.
+            Some(SortModeRevision::LastModified) => {
+                let task_type = SortEvent::RowDidChanged(row_id.to_string());
+                self.gen_task(task_type, QualityOfService::Background).await;
+                return;
+            }
+            None => {}
+        }
+        if let Some(changed_field_ids) = changed_field_ids {
+            // The timestamps and the formulas are calculated from the other cells of the row.
+            let is_affected = self.sorts.iter().any(|sort| {
+                let field_type: FieldType = sort.field_type.into();
+                field_type.is_timestamp() || field_type.is_formula() || changed_field_ids.contains(&sort.field_id)
+            });
+            if !is_affected {
+                return;
+            }
+        }
         let task_type = SortEvent::RowDidChanged(row_id.to_string());
         self.gen_task(task_type, QualityOfService::Background).await;
     }
 
-    #[tracing::instrument(name = "process_sort_task", level = "trace", skip_all, err)]
-    pub async fn process(&mut self, predicate: &str) -> FlowyResult<()> {
-        let event_type = SortEvent::from_str(predicate).unwrap();
+    /// Moves the changed row to its new position. All the rows are sorted again if the other rows
+    /// are not in the sorted order.
+    async fn process_row_changed(&mut self, row_id: String) -> FlowyResult<()> {
         let mut row_revs = self.delegate.get_row_revs().await;
-        match event_type {
-            SortEvent::SortDidChanged => {
+        // Only the changed row is moved if the other rows are still in the sorted order.
+        // Otherwise, all the rows are sorted again.
+        let row_index = match self.reinsert_row(&row_id, &row_revs).await {
+            Some((old_row_index, new_row_index)) => (Some(old_row_index), Some(new_row_index)),
+            None => {
+                let old_row_index = self.row_index_cache.get(&row_id).cloned();
                 self.sort_rows(&mut row_revs).await;
-                let row_orders = row_revs
-                    .iter()
-                    .map(|row_rev| row_rev.id.clone())
-                    .collect::<Vec<String>>();
+                (old_row_index, self.row_index_cache.get(&row_id).cloned())
+            }
+        };
+        match row_index {
+            (Some(old_row_index), Some(new_row_index)) => {
+                if old_row_index == new_row_index {
+                    return Ok(());
+                }
+                let notification = ReorderSingleRowResult {
+                    row_id,
+                    view_id: self.view_id.clone(),
+                    old_index: old_row_index,
+                    new_index: new_row_index,
+                };
+                let _ = self
+                    .notifier
+                    .send(GridViewChanged::ReorderSingleRowNotification(notification));
+            }
+            _ => tracing::trace!("The row index cache is outdated"),
+        }
+        Ok(())
+    }
 
+    /// Returns the rows and what's needed to sort them if the task of the `generation` is the
+    /// last one, so the rows can be sorted without locking the controller.
+    async fn start_sorting_all_rows(&self, generation: u64) -> Option<(RowSorter, Vec<Arc<RowRevision>>)> {
+        if generation != self.generation.load(atomic::Ordering::SeqCst) {
+            return None;
+        }
+        let row_revs = self.delegate.get_row_revs().await;
+        let row_sorter = self.make_row_sorter().await;
+        self.is_sorting_all_rows.store(true, atomic::Ordering::SeqCst);
+        Some((row_sorter, row_revs))
+    }
+
+    /// Applies the rows that were sorted by the task of the `generation`, and notifies the rows that
+    /// were moved. The rows are discarded if all the rows are going to be sorted again, since the
+    /// sorts were changed while the rows were sorted.
+    fn did_sort_all_rows(&mut self, generation: u64, row_revs: Vec<Arc<RowRevision>>, is_sorted: bool) {
+        self.is_sorting_all_rows.store(false, atomic::Ordering::SeqCst);
+        if generation != self.generation.load(atomic::Ordering::SeqCst) {
+            tracing::trace!("Discard the sorted rows of the outdated sort task");
+            return;
+        }
+
+        let old_row_index_cache = std::mem::take(&mut self.row_index_cache);
+        if is_sorted {
+            self.cache_row_indexes(&row_revs);
+        }
+        let row_orders = row_revs
+            .iter()
+            .map(|row_rev| row_rev.id.clone())
+            .collect::<Vec<String>>();
+
+        // Only the moved rows are sent if the rows were sorted before, for example, after
+        // adding the secondary sort. Otherwise, all the rows are reordered.
+        match moved_rows(&old_row_index_cache, &row_orders) {
+            Some(moved_rows) => {
+                if moved_rows.is_empty() {
+                    return;
+                }
+                let notification = ReorderRowsResult {
+                    view_id: self.view_id.clone(),
+                    moved_rows: moved_rows
+                        .into_iter()
+                        .map(|(row_id, old_index, new_index)| ReorderSingleRowResult {
+                            view_id: self.view_id.clone(),
+                            row_id,
+                            old_index,
+                            new_index,
+                        })
+                        .collect(),
+                };
+                let _ = self
+                    .notifier
+                    .send(GridViewChanged::ReorderRowsNotification(notification));
+            }
+            None => {
                 let notification = ReorderAllRowsResult {
                     view_id: self.view_id.clone(),
                     row_orders,
@@ -94,30 +216,7 @@ impl SortController {
                     .notifier
                     .send(GridViewChanged::ReorderAllRowsNotification(notification));
             }
-            SortEvent::RowDidChanged(row_id) => {
-                let old_row_index = self.row_index_cache.get(&row_id).cloned();
-                self.sort_rows(&mut row_revs).await;
-                let new_row_index = self.row_index_cache.get(&row_id).cloned();
-                match (old_row_index, new_row_index) {
-                    (Some(old_row_index), Some(new_row_index)) => {
-                        if old_row_index == new_row_index {
-                            return Ok(());
-                        }
-                        let notification = ReorderSingleRowResult {
-                            row_id,
-                            view_id: self.view_id.clone(),
-                            old_index: old_row_index,
-                            new_index: new_row_index,
-                        };
-                        let _ = self
-                            .notifier
-                            .send(GridViewChanged::ReorderSingleRowNotification(notification));
-                    }
-                    _ => tracing::trace!("The row index cache is outdated"),
-                }
-            }
         }
-        Ok(())
     }
 
     #[tracing::instrument(name = "schedule_sort_task", level = "trace", skip(self))]
@@ -127,28 +226,157 @@ impl SortController {
         self.task_scheduler.write().await.add_task(task);
     }
 
+    /// Schedules the task that sorts all the rows, and cancels the previous one if it's not
+    /// processed yet. The result of the previous one is discarded if it's being processed.
+    #[tracing::instrument(name = "schedule_sort_all_task", level = "trace", skip(self))]
+    async fn gen_sort_all_task(&self, qos: QualityOfService) {
+        let generation = self.generation.fetch_add(1, atomic::Ordering::SeqCst) + 1;
+        let mut task_scheduler = self.task_scheduler.write().await;
+        let previous_task_id = self.sort_all_task_id.lock().take();
+        if let Some(task_id) = previous_task_id {
+            task_scheduler.cancel_task(task_id);
+        }
+        let task_id = task_scheduler.next_task_id();
+        let task_type = SortEvent::SortDidChanged(generation);
+        let task = Task::new(&self.handler_id, task_id, TaskContent::Text(task_type.to_string()), qos);
+        task_scheduler.add_task(task);
+        *self.sort_all_task_id.lock() = Some(task_id);
+    }
+
+    /// Sorts the rows by the sorts in the order of their precedence, and then places the pinned
+    /// rows before the other rows regardless of the sorts. The sort is stable, so the rows that are
+    /// equal in all the sorts keep their order. The rows are ordered by the sort mode instead of the
+    /// sorts if it's set.
+    ///
+    /// The rows are placed in the previous order while all the rows are sorted in the background,
+    /// so the rows are not sorted twice and the view is not blocked by the sort.
     pub async fn sort_rows(&mut self, rows: &mut Vec<Arc<RowRevision>>) {
-        if self.sorts.is_empty() {
+        if self.is_sorting_all_rows.load(atomic::Ordering::SeqCst) && !self.row_index_cache.is_empty() {
+            rows.sort_by_key(|row| self.row_index_cache.get(&row.id).cloned().unwrap_or(usize::MAX));
             return;
         }
 
-        let field_revs = self.delegate.get_field_revs(None).await;
-        for sort in self.sorts.iter() {
-            rows.par_sort_by(|left, right| cmp_row(left, right, sort, &field_revs, &self.cell_data_cache));
+        let row_sorter = self.make_row_sorter().await;
+        if row_sorter.is_empty() {
+            return;
         }
+        row_sorter.sort(rows);
+        self.cache_row_indexes(rows);
+    }
+
+    fn cache_row_indexes(&mut self, rows: &[Arc<RowRevision>]) {
         rows.iter().enumerate().for_each(|(index, row)| {
             self.row_index_cache.insert(row.id.to_string(), index);
         });
     }
 
+    async fn make_row_sorter(&self) -> RowSorter {
+        let field_revs = if self.sorts.is_empty() {
+            vec![]
+        } else {
+            self.delegate.get_field_revs(None).await
+        };
+        RowSorter {
+            sorts: self.sorts.clone(),
+            sort_mode: self.delegate.get_sort_mode().await,
+            pinned_row_ids: self.delegate.get_pinned_row_ids().await,
+            field_revs,
+            cell_data_cache: self.cell_data_cache.clone(),
+            comparison_count: self.comparison_count.clone(),
+        }
+    }
+
+    /// Removes the changed row from the sorted rows and inserts it back at the position found by
+    /// the binary search, so only O(log n) rows are compared with it. Returns the old and the new
+    /// index of the row, or None if the rows were changed since they were sorted.
+    async fn reinsert_row(&mut self, row_id: &str, row_revs: &[Arc<RowRevision>]) -> Option<(usize, usize)> {
+        if self.sorts.is_empty() || self.row_index_cache.len() != row_revs.len() {
+            return None;
+        }
+        if self.delegate.get_sort_mode().await.is_some() {
+            return None;
+        }
+
+        // The rows in the sorted order, with their positions before sorting. The rows that are
+        // equal in all the sorts are ordered by their positions, the same as the stable sort.
+        let mut sorted_rows: Vec<Option<(usize, &Arc<RowRevision>)>> = vec![None; row_revs.len()];
+        for (position, row_rev) in row_revs.iter().enumerate() {
+            let index = *self.row_index_cache.get(&row_rev.id)?;
+            if sorted_rows.get(index)?.is_some() {
+                return None;
+            }
+            sorted_rows[index] = Some((position, row_rev));
+        }
+        let mut sorted_rows = sorted_rows.into_iter().collect::<Option<Vec<_>>>()?;
+
+        let old_index = *self.row_index_cache.get(row_id)?;
+        let pinned_row_ids = self.delegate.get_pinned_row_ids().await;
+        if pinned_row_ids.iter().any(|pinned_row_id| pinned_row_id == row_id) {
+            return Some((old_index, old_index));
+        }
+
+        let (position, row_rev) = sorted_rows.remove(old_index);
+        let pinned_count = sorted_rows
+            .iter()
+            .take_while(|(_, sorted_row)| pinned_row_ids.contains(&sorted_row.id))
+            .count();
+        let field_revs = self.delegate.get_field_revs(None).await;
+        let new_index = pinned_count
+            + sorted_rows[pinned_count..].partition_point(|(sorted_position, sorted_row)| {
+                match cmp_row_by_sorts(
+                    sorted_row,
+                    row_rev,
+                    &self.sorts,
+                    &field_revs,
+                    &self.cell_data_cache,
+                    &self.comparison_count,
+                ) {
+                    Ordering::Less => true,
+                    Ordering::Greater => false,
+                    Ordering::Equal => *sorted_position < position,
+                }
+            });
+        sorted_rows.insert(new_index, (position, row_rev));
+
+        let (start, end) = (old_index.min(new_index), old_index.max(new_index));
+        for (index, (_, sorted_row)) in sorted_rows.iter().enumerate().take(end + 1).skip(start) {
+            self.row_index_cache.insert(sorted_row.id.clone(), index);
+        }
+        Some((old_index, new_index))
+    }
+
+    /// Returns the number of the times that two rows of the view were compared by the sorts.
+    pub fn comparison_count(&self) -> usize {
+        self.comparison_count.load(atomic::Ordering::SeqCst)
+    }
+
+    /// Reorders all the rows after the rows were pinned or unpinned.
+    pub async fn did_update_pinned_rows(&self) {
+        self.gen_sort_all_task(QualityOfService::UserInteractive).await;
+    }
+
+    /// Reorders all the rows after the manual order of the rows was changed. The sorted rows only
+    /// move if they're equal in the sorts, since the manual order breaks the ties.
+    pub async fn did_update_manual_row_order(&self) {
+        self.gen_sort_all_task(QualityOfService::UserInteractive).await;
+    }
+
+    /// Reorders all the rows after the sort mode was set, removed, or shuffled again.
+    pub async fn did_update_sort_mode(&self) {
+        self.gen_sort_all_task(QualityOfService::UserInteractive).await;
+    }
+
     pub async fn delete_all_sorts(&mut self) {
         self.sorts.clear();
-        self.gen_task(SortEvent::SortDidChanged, QualityOfService::Background)
-            .await;
+        self.gen_sort_all_task(QualityOfService::Background).await;
     }
 
-    pub async fn did_update_view_field_type_option(&self, _field_rev: &FieldRevision) {
-        //
+    /// Sorts the rows again if the rows are sorted by the field, for example, after the options of
+    /// the select field were reordered.
+    pub async fn did_update_view_field_type_option(&self, field_rev: &FieldRevision) {
+        if self.sorts.iter().any(|sort| sort.field_id == field_rev.id) {
+            self.gen_sort_all_task(QualityOfService::UserInteractive).await;
+        }
     }
 
     #[tracing::instrument(level = "trace", skip(self))]
@@ -177,15 +405,141 @@ impl SortController {
             }
         }
 
+        if let Some(reorder_sort) = changeset.reorder_sort {
+            if let Some(old_index) = self.sorts.iter().position(|sort| sort.id == reorder_sort.sort_id) {
+                let new_index = reorder_sort.new_index.min(self.sorts.len() - 1);
+                if old_index != new_index {
+                    let sort = self.sorts.remove(old_index);
+                    self.sorts.insert(new_index, sort);
+                    notification.ordered_sort_ids = self.sorts.iter().map(|sort| sort.id.clone()).collect();
+                }
+            }
+        }
+
         if !notification.is_empty() {
-            self.gen_task(SortEvent::SortDidChanged, QualityOfService::UserInteractive)
-                .await;
+            self.gen_sort_all_task(QualityOfService::UserInteractive).await;
         }
         tracing::trace!("sort notification: {:?}", notification);
         notification
     }
 }
 
+/// Processes the task of the [SortController]. All the rows are sorted without locking the
+/// controller, so the view keeps serving the previous order until the sorted rows are applied.
+#[tracing::instrument(name = "process_sort_task", level = "trace", skip_all, err)]
+pub async fn process_sort_task(sort_controller: Arc<RwLock<SortController>>, predicate: &str) -> FlowyResult<()> {
+    let event_type = SortEvent::from_str(predicate).unwrap();
+    match event_type {
+        SortEvent::SortDidChanged(generation) => {
+            let sorting = sort_controller.read().await.start_sorting_all_rows(generation).await;
+            if let Some((row_sorter, mut row_revs)) = sorting {
+                let is_sorted = !row_sorter.is_empty();
+                if is_sorted {
+                    row_sorter.sort(&mut row_revs);
+                }
+                sort_controller
+                    .write()
+                    .await
+                    .did_sort_all_rows(generation, row_revs, is_sorted);
+            }
+            Ok(())
+        }
+        SortEvent::RowDidChanged(row_id) => sort_controller.write().await.process_row_changed(row_id).await,
+    }
+}
+
+/// [RowSorter] holds what's needed to sort the rows, so the rows can be sorted without locking the
+/// [SortController].
+struct RowSorter {
+    sorts: Vec<Arc<SortRevision>>,
+    sort_mode: Option<SortModeRevision>,
+    pinned_row_ids: Vec<String>,
+    field_revs: Vec<Arc<FieldRevision>>,
+    cell_data_cache: AtomicCellDataCache,
+    comparison_count: Arc<AtomicUsize>,
+}
+
+impl RowSorter {
+    /// Returns true if there is nothing to sort the rows by.
+    fn is_empty(&self) -> bool {
+        self.sorts.is_empty() && self.sort_mode.is_none() && self.pinned_row_ids.is_empty()
+    }
+
+    fn sort(&self, rows: &mut [Arc<RowRevision>]) {
+        if let Some(sort_mode) = &self.sort_mode {
+            sort_rows_by_mode(sort_mode, rows);
+        } else if !self.sorts.is_empty() {
+            rows.par_sort_by(|left, right| {
+                cmp_row_by_sorts(
+                    left,
+                    right,
+                    &self.sorts,
+                    &self.field_revs,
+                    &self.cell_data_cache,
+                    &self.comparison_count,
+                )
+            });
+        }
+        move_pinned_rows_first(&self.pinned_row_ids, rows, |row| row.id.as_str());
+    }
+}
+
+/// Returns the rows whose indexes were changed, with their old and new indexes. Returns None if
+/// the rows were not sorted before or the rows were changed since then.
+fn moved_rows(
+    old_row_index_cache: &HashMap<String, usize>,
+    row_orders: &[String],
+) -> Option<Vec<(String, usize, usize)>> {
+    if old_row_index_cache.len() != row_orders.len() {
+        return None;
+    }
+    let mut moved_rows = vec![];
+    for (new_index, row_id) in row_orders.iter().enumerate() {
+        let old_index = *old_row_index_cache.get(row_id)?;
+        if old_index != new_index {
+            moved_rows.push((row_id.clone(), old_index, new_index));
+        }
+    }
+    Some(moved_rows)
+}
+
+fn cmp_row_by_sorts(
+    left: &Arc<RowRevision>,
+    right: &Arc<RowRevision>,
+    sorts: &[Arc<SortRevision>],
+    field_revs: &[Arc<FieldRevision>],
+    cell_data_cache: &AtomicCellDataCache,
+    comparison_count: &AtomicUsize,
+) -> Ordering {
+    comparison_count.fetch_add(1, atomic::Ordering::SeqCst);
+    sorts.iter().fold(Ordering::Equal, |order, sort| {
+        order.then_with(|| cmp_row(left, right, sort, field_revs, cell_data_cache))
+    })
+}
+
+/// Orders the rows by the sort mode. The shuffled order of the rows is decided by their own ids, so
+/// the rows keep their order when other rows are inserted or removed.
+fn sort_rows_by_mode(sort_mode: &SortModeRevision, rows: &mut [Arc<RowRevision>]) {
+    match sort_mode {
+        SortModeRevision::Shuffle { seed } => rows.sort_by_cached_key(|row| shuffle_key(*seed, &row.id)),
+        SortModeRevision::LastModified => rows.sort_by(|left, right| right.modified_at.cmp(&left.modified_at)),
+    }
+}
+
+/// Hashes the row id with the seed by FNV-1a and then mixes the bits by SplitMix64, so the key
+/// of the row is the same on every platform and every run.
+fn shuffle_key(seed: u64, row_id: &str) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325_u64 ^ seed;
+    for byte in row_id.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash = hash.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    hash ^ (hash >> 31)
+}
+
 fn cmp_row(
     left: &Arc<RowRevision>,
     right: &Arc<RowRevision>,
@@ -193,13 +547,56 @@ fn cmp_row(
     field_revs: &[Arc<FieldRevision>],
     cell_data_cache: &AtomicCellDataCache,
 ) -> Ordering {
-    let order = match (left.cells.get(&sort.field_id), right.cells.get(&sort.field_id)) {
+    let field_rev = match field_revs.iter().find(|field_rev| field_rev.id == sort.field_id) {
+        None => return default_order(),
+        Some(field_rev) => field_rev,
+    };
+    let field_type: FieldType = sort.field_type.into();
+    let handler = match TypeOptionCellExt::new_with_cell_data_cache(field_rev.as_ref(), Some(cell_data_cache.clone()))
+        .get_type_option_cell_data_handler(&field_type)
+    {
+        None => return default_order(),
+        Some(handler) => handler,
+    };
+    let left_cell = get_cell_rev_from_row(left, field_rev);
+    let right_cell = get_cell_rev_from_row(right, field_rev);
+
+    // The empty cells are placed by the empty order of the sort instead of its condition. The
+    // unchecked checkbox is not placed as the empty cell, it's the value that is before the
+    // checked one.
+    if !field_type.is_checkbox() {
+        let is_left_empty = handler.is_cell_empty(left_cell.as_ref(), field_rev);
+        let is_right_empty = handler.is_cell_empty(right_cell.as_ref(), field_rev);
+        let empty_order = match sort.empty_order {
+            SortEmptyOrder::First => Ordering::Less,
+            SortEmptyOrder::Last => Ordering::Greater,
+        };
+        match (is_left_empty, is_right_empty) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return empty_order,
+            (false, true) => return empty_order.reverse(),
+            (false, false) => {}
+        }
+    }
+
+    let order = match (left_cell, right_cell) {
         (Some(left_cell), Some(right_cell)) => {
-            let field_type: FieldType = sort.field_type.into();
-            match field_revs.iter().find(|field_rev| field_rev.id == sort.field_id) {
-                None => default_order(),
-                Some(field_rev) => cmp_cell(left_cell, right_cell, field_rev, field_type, cell_data_cache),
-            }
+            let collation = Collation::from(sort.as_ref());
+            cmp_cell(&left_cell, &right_cell, field_rev, handler.as_ref(), &collation)
+        }
+        // The checkbox without the cell is unchecked.
+        (left_cell, right_cell) if field_type.is_checkbox() => {
+            let cell_str = |cell: Option<CellRevision>| {
+                cell.and_then(|cell| TypeCellData::try_from(cell).ok())
+                    .map(|type_cell_data| type_cell_data.into_inner())
+                    .unwrap_or_default()
+            };
+            handler.handle_cell_compare(
+                &cell_str(left_cell),
+                &cell_str(right_cell),
+                field_rev.as_ref(),
+                &Collation::default(),
+            )
         }
         (Some(_), None) => Ordering::Greater,
         (None, Some(_)) => Ordering::Less,
@@ -217,28 +614,24 @@ fn cmp_cell(
     left_cell: &CellRevision,
     right_cell: &CellRevision,
     field_rev: &Arc<FieldRevision>,
-    field_type: FieldType,
-    cell_data_cache: &AtomicCellDataCache,
+    handler: &dyn TypeOptionCellDataHandler,
+    collation: &Collation,
 ) -> Ordering {
-    match TypeOptionCellExt::new_with_cell_data_cache(field_rev.as_ref(), Some(cell_data_cache.clone()))
-        .get_type_option_cell_data_handler(&field_type)
-    {
-        None => default_order(),
-        Some(handler) => {
-            let cal_order = || {
-                let left_cell_str = TypeCellData::try_from(left_cell).ok()?.into_inner();
-                let right_cell_str = TypeCellData::try_from(right_cell).ok()?.into_inner();
-                let order = handler.handle_cell_compare(&left_cell_str, &right_cell_str, field_rev.as_ref());
-                Option::<Ordering>::Some(order)
-            };
+    let cal_order = || {
+        let left_cell_str = TypeCellData::try_from(left_cell).ok()?.into_inner();
+        let right_cell_str = TypeCellData::try_from(right_cell).ok()?.into_inner();
+        let order = handler.handle_cell_compare(&left_cell_str, &right_cell_str, field_rev.as_ref(), collation);
+        Option::<Ordering>::Some(order)
+    };
 
-            cal_order().unwrap_or_else(default_order)
-        }
-    }
+    cal_order().unwrap_or_else(default_order)
 }
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 enum SortEvent {
-    SortDidChanged,
+    /// Sorts all the rows. The generation of the task is compared with the generation of the
+    /// controller, so only the last task is applied.
+    SortDidChanged(u64),
     RowDidChanged(String),
 }
 
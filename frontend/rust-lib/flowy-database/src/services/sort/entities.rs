@@ -1,4 +1,4 @@
-use crate::entities::{AlterSortParams, DeleteSortParams, FieldType};
+use crate::entities::{AlterSortParams, DeleteSortParams, FieldType, ReorderSortParams};
 use grid_model::{FieldRevision, FieldTypeRevision};
 use std::sync::Arc;
 
@@ -52,11 +52,20 @@ pub struct ReorderSingleRowResult {
     pub new_index: usize,
 }
 
+/// [ReorderRowsResult] is the rows that were moved after the sorts were changed. The rows that are
+/// not in the list keep their indexes.
+#[derive(Clone)]
+pub struct ReorderRowsResult {
+    pub view_id: String,
+    pub moved_rows: Vec<ReorderSingleRowResult>,
+}
+
 #[derive(Debug)]
 pub struct SortChangeset {
     pub(crate) insert_sort: Option<SortType>,
     pub(crate) update_sort: Option<SortType>,
     pub(crate) delete_sort: Option<DeletedSortType>,
+    pub(crate) reorder_sort: Option<ReorderedSortType>,
 }
 
 impl SortChangeset {
@@ -65,6 +74,7 @@ impl SortChangeset {
             insert_sort: Some(sort),
             update_sort: None,
             delete_sort: None,
+            reorder_sort: None,
         }
     }
 
@@ -73,6 +83,7 @@ impl SortChangeset {
             insert_sort: None,
             update_sort: Some(sort),
             delete_sort: None,
+            reorder_sort: None,
         }
     }
 
@@ -81,6 +92,16 @@ impl SortChangeset {
             insert_sort: None,
             update_sort: None,
             delete_sort: Some(deleted_sort),
+            reorder_sort: None,
+        }
+    }
+
+    pub fn from_reorder(reordered_sort: ReorderedSortType) -> Self {
+        Self {
+            insert_sort: None,
+            update_sort: None,
+            delete_sort: None,
+            reorder_sort: Some(reordered_sort),
         }
     }
 }
@@ -99,3 +120,18 @@ impl std::convert::From<DeleteSortParams> for DeletedSortType {
         }
     }
 }
+
+#[derive(Debug)]
+pub struct ReorderedSortType {
+    pub sort_id: String,
+    pub new_index: usize,
+}
+
+impl std::convert::From<&ReorderSortParams> for ReorderedSortType {
+    fn from(params: &ReorderSortParams) -> Self {
+        Self {
+            sort_id: params.sort_id.clone(),
+            new_index: params.new_index,
+        }
+    }
+}
@@ -1,7 +1,9 @@
+mod collation;
 mod controller;
 mod entities;
 mod task;
 
+pub use collation::*;
 pub use controller::*;
 pub use entities::*;
 pub use task::*;
@@ -1,4 +1,4 @@
-use crate::services::sort::SortController;
+use crate::services::sort::{process_sort_task, SortController};
 use flowy_task::{TaskContent, TaskHandler};
 use lib_infra::future::BoxResultFuture;
 use std::sync::Arc;
@@ -32,10 +32,7 @@ impl TaskHandler for SortTaskHandler {
         let sort_controller = self.sort_controller.clone();
         Box::pin(async move {
             if let TaskContent::Text(predicate) = content {
-                sort_controller
-                    .write()
-                    .await
-                    .process(&predicate)
+                process_sort_task(sort_controller, &predicate)
                     .await
                     .map_err(anyhow::Error::from)?;
             }
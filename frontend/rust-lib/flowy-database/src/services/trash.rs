@@ -0,0 +1,62 @@
+use crate::services::grid_editor::DatabaseRevisionEditor;
+use flowy_task::{TaskContent, TaskHandler};
+use lib_infra::future::BoxResultFuture;
+use lib_infra::util::timestamp;
+use std::sync::Weak;
+
+/// The number of days that the deleted rows are kept in the trash before they are purged.
+pub const TRASH_RETENTION_DAYS: i64 = 30;
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+pub(crate) fn purge_trash_handler_id(database_id: &str) -> String {
+    format!("{}:purge_trash", database_id)
+}
+
+/// Returns the time in seconds at or before which the trashed rows are expired.
+pub(crate) fn trash_expired_before() -> i64 {
+    timestamp() - TRASH_RETENTION_DAYS * SECONDS_PER_DAY
+}
+
+/// [PurgeTrashTaskHandler] permanently deletes the rows that were moved to the trash more than
+/// [TRASH_RETENTION_DAYS] days ago.
+pub(crate) struct PurgeTrashTaskHandler {
+    handler_id: String,
+    editor: Weak<DatabaseRevisionEditor>,
+}
+
+impl PurgeTrashTaskHandler {
+    pub(crate) fn new(database_id: &str, editor: Weak<DatabaseRevisionEditor>) -> Self {
+        Self {
+            handler_id: purge_trash_handler_id(database_id),
+            editor,
+        }
+    }
+}
+
+impl TaskHandler for PurgeTrashTaskHandler {
+    fn handler_id(&self) -> &str {
+        &self.handler_id
+    }
+
+    fn handler_name(&self) -> &str {
+        "PurgeTrashTaskHandler"
+    }
+
+    fn run(&self, _content: TaskContent) -> BoxResultFuture<(), anyhow::Error> {
+        let editor = self.editor.upgrade();
+        Box::pin(async move {
+            // The editor was closed before the task runs.
+            let editor = match editor {
+                None => return Ok(()),
+                Some(editor) => editor,
+            };
+            let purged_rows = editor
+                .purge_trash(trash_expired_before())
+                .await
+                .map_err(anyhow::Error::from)?;
+            tracing::trace!("Purge {} expired rows from the trash", purged_rows.len());
+            Ok(())
+        })
+    }
+}
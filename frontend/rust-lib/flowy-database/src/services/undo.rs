@@ -0,0 +1,111 @@
+use grid_model::{CellRevision, FieldRevision, RowChangeset, RowRevision};
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+pub const DEFAULT_UNDO_DEPTH: usize = 50;
+
+/// [DatabaseEditAction] is the inverse of an edit that was applied to the database. Applying the
+/// action reverts the edit, and returns the action that reverts the applied action.
+#[derive(Debug, Clone)]
+pub(crate) enum DatabaseEditAction {
+    /// Inserts the row after the `prev_row_id`. The row will be the first row of its block if the
+    /// `prev_row_id` is None.
+    InsertRow {
+        row_rev: Arc<RowRevision>,
+        prev_row_id: Option<String>,
+    },
+
+    DeleteRow {
+        row_id: String,
+    },
+
+    UpdateRow {
+        changeset: RowChangeset,
+    },
+
+    UpdateCell {
+        row_id: String,
+        field_id: String,
+        cell_rev: CellRevision,
+    },
+
+    /// Inserts the field before the `next_field_id`. The field will be the last field if the
+    /// `next_field_id` is None.
+    CreateField {
+        field_rev: Arc<FieldRevision>,
+        next_field_id: Option<String>,
+    },
+
+    DeleteField {
+        field_id: String,
+    },
+
+    /// Replaces the field that has the same id with the `field_rev`.
+    UpdateField {
+        field_rev: Arc<FieldRevision>,
+    },
+}
+
+/// A step contains the actions that are undone or redone together. The actions are applied in
+/// the reverse order of the step.
+pub(crate) type DatabaseEditStep = Vec<DatabaseEditAction>;
+
+/// [DatabaseUndoStack] keeps the last `depth` steps that can be undone, and the steps that were
+/// undone and can be redone. Recording a new step clears the redo steps.
+pub(crate) struct DatabaseUndoStack {
+    depth: usize,
+    undo_steps: VecDeque<DatabaseEditStep>,
+    redo_steps: VecDeque<DatabaseEditStep>,
+}
+
+impl DatabaseUndoStack {
+    pub(crate) fn new(depth: usize) -> Self {
+        Self {
+            depth,
+            undo_steps: VecDeque::new(),
+            redo_steps: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn record(&mut self, step: DatabaseEditStep) {
+        if step.is_empty() {
+            return;
+        }
+        self.redo_steps.clear();
+        push_step(&mut self.undo_steps, step, self.depth);
+    }
+
+    pub(crate) fn pop_undo(&mut self) -> Option<DatabaseEditStep> {
+        self.undo_steps.pop_back()
+    }
+
+    pub(crate) fn push_undo(&mut self, step: DatabaseEditStep) {
+        push_step(&mut self.undo_steps, step, self.depth);
+    }
+
+    pub(crate) fn pop_redo(&mut self) -> Option<DatabaseEditStep> {
+        self.redo_steps.pop_back()
+    }
+
+    pub(crate) fn push_redo(&mut self, step: DatabaseEditStep) {
+        push_step(&mut self.redo_steps, step, self.depth);
+    }
+
+    pub(crate) fn number_of_undo_steps(&self) -> usize {
+        self.undo_steps.len()
+    }
+
+    pub(crate) fn number_of_redo_steps(&self) -> usize {
+        self.redo_steps.len()
+    }
+}
+
+fn push_step(steps: &mut VecDeque<DatabaseEditStep>, step: DatabaseEditStep, depth: usize) {
+    if step.is_empty() || depth == 0 {
+        return;
+    }
+    steps.push_back(step);
+    while steps.len() > depth {
+        steps.pop_front();
+    }
+}
@@ -1,7 +1,7 @@
-use crate::entities::{ReorderAllRowsPB, ReorderSingleRowPB, ViewRowsVisibilityChangesetPB};
+use crate::entities::{ReorderAllRowsPB, ReorderRowsPB, ReorderSingleRowPB, ViewRowsVisibilityChangesetPB};
 use crate::notification::{send_notification, DatabaseNotification};
 use crate::services::filter::FilterResultNotification;
-use crate::services::sort::{ReorderAllRowsResult, ReorderSingleRowResult};
+use crate::services::sort::{ReorderAllRowsResult, ReorderRowsResult, ReorderSingleRowResult};
 use async_stream::stream;
 use futures::stream::StreamExt;
 use tokio::sync::broadcast;
@@ -11,6 +11,7 @@ pub enum GridViewChanged {
     FilterNotification(FilterResultNotification),
     ReorderAllRowsNotification(ReorderAllRowsResult),
     ReorderSingleRowNotification(ReorderSingleRowResult),
+    ReorderRowsNotification(ReorderRowsResult),
 }
 
 pub type GridViewChangedNotifier = broadcast::Sender<GridViewChanged>;
@@ -62,6 +63,22 @@ impl GridViewChangedReceiverRunner {
                             .payload(reorder_row)
                             .send()
                     }
+                    GridViewChanged::ReorderRowsNotification(notification) => {
+                        let reorder_rows = ReorderRowsPB {
+                            items: notification
+                                .moved_rows
+                                .into_iter()
+                                .map(|row| ReorderSingleRowPB {
+                                    row_id: row.row_id,
+                                    old_index: row.old_index as i32,
+                                    new_index: row.new_index as i32,
+                                })
+                                .collect(),
+                        };
+                        send_notification(&notification.view_id, DatabaseNotification::DidReorderMovedRows)
+                            .payload(reorder_rows)
+                            .send()
+                    }
                 }
             })
             .await;
@@ -1,26 +1,36 @@
 use crate::entities::*;
 use crate::notification::{send_notification, DatabaseNotification};
 use crate::services::block_manager::DatabaseBlockEvent;
-use crate::services::cell::{AtomicCellDataCache, TypeCellData};
-use crate::services::field::{RowSingleCellData, TypeOptionCellDataHandler};
-use crate::services::filter::{FilterChangeset, FilterController, FilterTaskHandler, FilterType, UpdatedFilterType};
+use crate::services::cell::{get_cell_rev_from_row, AtomicCellDataCache, TypeCellData};
+use crate::services::clipboard::{convert_clipboard_cell, tsv_from_rows, ClipboardField, RangeClipboard, RangePaste};
+use crate::services::field::{
+    select_type_option_from_field_rev, RowSingleCellData, SelectOptionIds, TypeOptionCellDataHandler,
+};
+use crate::services::filter::{
+    filter_config_hash, FilterChangeset, FilterController, FilterResultCache, FilterResultStore, FilterTaskHandler,
+    FilterType, UpdatedFilterType,
+};
 use crate::services::group::{
     default_group_configuration, find_group_field, make_group_controller, Group, GroupConfigurationReader,
     GroupController, MoveGroupRowContext,
 };
 use crate::services::row::DatabaseBlockRowRevision;
-use crate::services::sort::{DeletedSortType, SortChangeset, SortController, SortTaskHandler, SortType};
+use crate::services::sort::{
+    DeletedSortType, ReorderedSortType, SortChangeset, SortController, SortTaskHandler, SortType,
+};
 use crate::services::view_editor::changed_notifier::GridViewChangedNotifier;
 use crate::services::view_editor::trait_impl::*;
 use crate::services::view_editor::GridViewChangedReceiverRunner;
 use flowy_client_sync::client_database::{make_grid_view_operations, GridViewRevisionChangeset, GridViewRevisionPad};
-use flowy_error::FlowyResult;
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 use flowy_revision::RevisionManager;
 use flowy_sqlite::ConnectionPool;
 use flowy_task::TaskDispatcher;
 use grid_model::{
-    gen_grid_filter_id, gen_grid_sort_id, FieldRevision, FieldTypeRevision, FilterRevision, LayoutRevision,
-    RowChangeset, RowRevision, SortRevision,
+    apply_manual_row_order, gen_grid_filter_id, gen_grid_sort_id, gen_shuffle_seed, DateGroupConfigurationRevision,
+    FieldRevision, FieldTypeRevision, FilterGroupRevision, FilterRevision, FilterSetRevision,
+    GroupConfigurationContentSerde, LayoutRevision, NumberGroupConfigurationRevision, RowChangeset, RowRevision,
+    SortEmptyOrder, SortModeRevision, SortRevision, MAX_FILTER_GROUP_DEPTH,
 };
 use lib_infra::async_trait::async_trait;
 use lib_infra::future::Fut;
@@ -28,6 +38,7 @@ use lib_infra::ref_map::RefCountValue;
 use nanoid::nanoid;
 use revision_model::Revision;
 use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::future::Future;
 use std::sync::Arc;
 use tokio::sync::{broadcast, RwLock};
@@ -57,6 +68,9 @@ pub trait DatabaseViewEditorDelegate: Send + Sync + 'static {
     /// One grid has a list of blocks
     fn get_blocks(&self) -> Fut<Vec<DatabaseBlockRowRevision>>;
 
+    /// Returns the id of the latest revision of each block, keyed by the block id.
+    fn get_block_rev_ids(&self) -> Fut<HashMap<String, i64>>;
+
     /// Returns a `TaskDispatcher` used to poll a `Task`
     fn get_task_scheduler(&self) -> Arc<RwLock<TaskDispatcher>>;
 
@@ -76,6 +90,7 @@ pub struct DatabaseViewRevisionEditor {
     group_controller: Arc<RwLock<Box<dyn GroupController>>>,
     filter_controller: Arc<RwLock<FilterController>>,
     sort_controller: Arc<RwLock<SortController>>,
+    filter_results: Arc<FilterResultStore>,
     pub notifier: GridViewChangedNotifier,
 }
 
@@ -88,6 +103,7 @@ impl DatabaseViewRevisionEditor {
         delegate: Arc<dyn DatabaseViewEditorDelegate>,
         cell_data_cache: AtomicCellDataCache,
         mut rev_manager: RevisionManager<Arc<ConnectionPool>>,
+        filter_results: Arc<FilterResultStore>,
     ) -> FlowyResult<Self> {
         let (notifier, _) = broadcast::channel(100);
         tokio::spawn(GridViewChangedReceiverRunner(Some(notifier.subscribe())).run());
@@ -110,6 +126,8 @@ impl DatabaseViewRevisionEditor {
 
         let view_rev_pad = Arc::new(RwLock::new(view_rev_pad));
         let rev_manager = Arc::new(rev_manager);
+        initialize_field_settings(user_id, &view_rev_pad, &rev_manager, &delegate).await?;
+        initialize_pinned_rows(user_id, &view_rev_pad, &rev_manager, &delegate).await?;
         let group_controller = new_group_controller(
             user_id.to_owned(),
             view_id.clone(),
@@ -129,6 +147,7 @@ impl DatabaseViewRevisionEditor {
             view_rev_pad.clone(),
         )
         .await;
+        restore_filter_results(&view_id, &delegate, &view_rev_pad, &filter_controller, &filter_results).await;
 
         let sort_controller = make_sort_controller(
             &view_id,
@@ -148,20 +167,43 @@ impl DatabaseViewRevisionEditor {
             group_controller,
             filter_controller,
             sort_controller,
+            filter_results,
             notifier,
         })
     }
 
+    /// Saves the visibility of the rows after applying the filters, it's restored when the view
+    /// is opened again. The saved results are deleted if the view has no filters.
+    async fn save_filter_results(&self) -> FlowyResult<()> {
+        let visible_by_row_id = self.filter_controller.read().await.visibility_by_row_id();
+        if visible_by_row_id.is_empty() {
+            return self.filter_results.delete_cache(&self.view_id);
+        }
+        let cache = FilterResultCache {
+            filter_hash: get_filter_config_hash(&self.delegate, &self.pad).await,
+            block_rev_ids: self.delegate.get_block_rev_ids().await,
+            visible_by_row_id,
+        };
+        self.filter_results.save_cache(&self.view_id, &cache)
+    }
+
+    pub async fn flush(&self) -> FlowyResult<()> {
+        self.rev_manager.flush().await
+    }
+
     #[tracing::instrument(name = "close grid view editor", level = "trace", skip_all)]
     pub async fn close(&self) {
         self.rev_manager.generate_snapshot().await;
         self.rev_manager.close().await;
+        if let Err(e) = self.save_filter_results().await {
+            tracing::error!("Save the filter results of the view:{} failed: {:?}", self.view_id, e);
+        }
         self.filter_controller.read().await.close().await;
         self.sort_controller.read().await.close().await;
     }
 
     pub async fn handle_block_event(&self, event: Cow<'_, DatabaseBlockEvent>) {
-        let changeset = match event.into_owned() {
+        let mut changeset = match event.into_owned() {
             DatabaseBlockEvent::InsertRow { block_id: _, row } => {
                 //
                 ViewRowsChangesetPB::from_insert(self.view_id.clone(), vec![row])
@@ -170,10 +212,22 @@ impl DatabaseViewRevisionEditor {
                 //
                 ViewRowsChangesetPB::from_update(self.view_id.clone(), vec![row])
             }
+            DatabaseBlockEvent::UpdateRows { block_id: _, rows } => {
+                //
+                ViewRowsChangesetPB::from_update(self.view_id.clone(), rows)
+            }
             DatabaseBlockEvent::DeleteRow { block_id: _, row_id } => {
                 //
                 ViewRowsChangesetPB::from_delete(self.view_id.clone(), vec![row_id])
             }
+            DatabaseBlockEvent::InsertRows { rows } => {
+                //
+                ViewRowsChangesetPB::from_insert(self.view_id.clone(), rows)
+            }
+            DatabaseBlockEvent::DeleteRows { row_ids } => {
+                //
+                ViewRowsChangesetPB::from_delete(self.view_id.clone(), row_ids)
+            }
             DatabaseBlockEvent::Move {
                 block_id: _,
                 deleted_row_id,
@@ -183,6 +237,7 @@ impl DatabaseViewRevisionEditor {
                 ViewRowsChangesetPB::from_move(self.view_id.clone(), vec![deleted_row_id], vec![inserted_row])
             }
         };
+        changeset.pinned_row_ids = self.get_pinned_row_ids().await;
 
         send_notification(&self.view_id, DatabaseNotification::DidUpdateDatabaseViewRows)
             .payload(changeset)
@@ -206,6 +261,7 @@ impl DatabaseViewRevisionEditor {
         if params.group_id.is_none() {
             return;
         }
+        self.regroup_if_outdated().await;
         let group_id = params.group_id.as_ref().unwrap();
         let _ = self
             .mut_group_controller(|group_controller, field_rev| {
@@ -218,7 +274,13 @@ impl DatabaseViewRevisionEditor {
     pub async fn did_create_view_row(&self, row_pb: &RowPB, params: &CreateRowParams) {
         // Send the group notification if the current view has groups
         match params.group_id.as_ref() {
-            None => {}
+            None => {
+                // The row that isn't created in a group is put into the groups that match its
+                // cells, for example, the group of the default option of the single select field.
+                if let Some((_, row_rev)) = self.delegate.get_row_rev(&row_pb.id).await {
+                    self.update_groups_of_row(&None, &row_rev, true).await;
+                }
+            }
             Some(group_id) => {
                 let index = match params.start_row_id {
                     None => Some(0),
@@ -237,8 +299,15 @@ impl DatabaseViewRevisionEditor {
         }
     }
 
+    /// The setting of the view is sent again, so the groups and the cards of the view are
+    /// re-rendered with the new primary field.
+    pub async fn did_update_primary_field(&self) {
+        self.notify_did_update_setting().await;
+    }
+
     #[tracing::instrument(level = "trace", skip_all)]
     pub async fn did_delete_view_row(&self, row_rev: &RowRevision) {
+        self.unpin_deleted_rows(&[row_rev.id.clone()]).await;
         // Send the group notification if the current view has groups;
         let result = self
             .mut_group_controller(|group_controller, field_rev| {
@@ -254,10 +323,83 @@ impl DatabaseViewRevisionEditor {
         }
     }
 
+    /// Same as `did_delete_view_row`, but the changes of each group are merged into one
+    /// notification.
+    #[tracing::instrument(level = "trace", skip_all)]
+    pub async fn did_delete_view_rows(&self, row_revs: &[Arc<RowRevision>]) {
+        let row_ids = row_revs
+            .iter()
+            .map(|row_rev| row_rev.id.clone())
+            .collect::<Vec<String>>();
+        self.unpin_deleted_rows(&row_ids).await;
+        let result = self
+            .mut_group_controller(|group_controller, field_rev| {
+                let mut row_changesets: Vec<GroupRowsNotificationPB> = vec![];
+                for row_rev in row_revs {
+                    let result = match group_controller.did_delete_delete_row(row_rev, &field_rev) {
+                        Ok(result) => result,
+                        Err(e) => {
+                            tracing::error!("Delete the row:{} in the groups failed: {:?}", row_rev.id, e);
+                            continue;
+                        }
+                    };
+                    for changeset in result.row_changesets {
+                        match row_changesets
+                            .iter_mut()
+                            .find(|row_changeset| row_changeset.group_id == changeset.group_id)
+                        {
+                            None => row_changesets.push(changeset),
+                            Some(row_changeset) => {
+                                row_changeset.inserted_rows.extend(changeset.inserted_rows);
+                                row_changeset.deleted_rows.extend(changeset.deleted_rows);
+                                row_changeset.updated_rows.extend(changeset.updated_rows);
+                            }
+                        }
+                    }
+                }
+                Ok(row_changesets)
+            })
+            .await;
+
+        if let Some(row_changesets) = result {
+            tracing::trace!("Delete rows in view changesets: {:?}", row_changesets);
+            for changeset in row_changesets {
+                self.notify_did_update_group_rows(changeset).await;
+            }
+        }
+    }
+
     pub async fn did_update_view_row(&self, old_row_rev: Option<Arc<RowRevision>>, row_rev: &RowRevision) {
+        self.update_groups_of_row(&old_row_rev, row_rev, false).await;
+
+        // Only the filters of the fields whose cells changed need to be evaluated again.
+        let changed_field_ids = old_row_rev
+            .as_ref()
+            .map(|old_row_rev| changed_field_ids(old_row_rev, row_rev));
+        let filter_controller = self.filter_controller.clone();
+        let sort_controller = self.sort_controller.clone();
+        let row_id = row_rev.id.clone();
+        tokio::spawn(async move {
+            filter_controller
+                .read()
+                .await
+                .did_receive_row_changed(&row_id, changed_field_ids.clone())
+                .await;
+            sort_controller
+                .read()
+                .await
+                .did_receive_row_changed(&row_id, changed_field_ids)
+                .await;
+        });
+    }
+
+    /// Moves the row into the groups that match its cells. The rows that are put into the groups
+    /// are marked as new if `is_new` is true, as the rows created in a group are.
+    async fn update_groups_of_row(&self, old_row_rev: &Option<Arc<RowRevision>>, row_rev: &RowRevision, is_new: bool) {
+        self.regroup_if_outdated().await;
         let result = self
             .mut_group_controller(|group_controller, field_rev| {
-                Ok(group_controller.did_update_group_row(&old_row_rev, row_rev, &field_rev))
+                Ok(group_controller.did_update_group_row(old_row_rev, row_rev, &field_rev))
             })
             .await;
 
@@ -277,33 +419,32 @@ impl DatabaseViewRevisionEditor {
             self.notify_did_update_view(changeset).await;
 
             tracing::trace!("Group changesets after editing the row: {:?}", result.row_changesets);
-            for changeset in result.row_changesets {
+            for mut changeset in result.row_changesets {
+                changeset
+                    .inserted_rows
+                    .iter_mut()
+                    .for_each(|inserted_row| inserted_row.is_new = is_new);
                 self.notify_did_update_group_rows(changeset).await;
             }
         }
-
-        let filter_controller = self.filter_controller.clone();
-        let sort_controller = self.sort_controller.clone();
-        let row_id = row_rev.id.clone();
-        tokio::spawn(async move {
-            filter_controller.read().await.did_receive_row_changed(&row_id).await;
-            sort_controller.read().await.did_receive_row_changed(&row_id).await;
-        });
     }
 
     pub async fn move_view_group_row(
         &self,
         row_rev: &RowRevision,
         row_changeset: &mut RowChangeset,
+        from_group_id: Option<&str>,
         to_group_id: &str,
         to_row_id: Option<String>,
     ) {
+        self.regroup_if_outdated().await;
         let result = self
             .mut_group_controller(|group_controller, field_rev| {
                 let move_row_context = MoveGroupRowContext {
                     row_rev,
                     row_changeset,
                     field_rev: field_rev.as_ref(),
+                    from_group_id,
                     to_group_id,
                     to_row_id,
                 };
@@ -327,15 +468,19 @@ impl DatabaseViewRevisionEditor {
             }
         }
     }
+    /// Returns the groups of the view, the hidden groups are skipped unless `include_hidden` is true.
+    ///
     /// Only call once after grid view editor initialized
     #[tracing::instrument(level = "trace", skip(self))]
-    pub async fn load_view_groups(&self) -> FlowyResult<Vec<GroupPB>> {
+    pub async fn load_view_groups(&self, include_hidden: bool) -> FlowyResult<Vec<GroupPB>> {
+        self.regroup_if_outdated().await;
         let groups = self
             .group_controller
             .read()
             .await
             .groups()
             .into_iter()
+            .filter(|group| include_hidden || group.is_visible)
             .cloned()
             .collect::<Vec<Group>>();
         tracing::trace!("Number of groups: {}", groups.len());
@@ -348,7 +493,7 @@ impl DatabaseViewRevisionEditor {
             .write()
             .await
             .move_group(&params.from_group_id, &params.to_group_id)?;
-        match self.group_controller.read().await.get_group(&params.from_group_id) {
+        match self.get_visible_group(&params.from_group_id).await {
             None => tracing::warn!("Can not find the group with id: {}", params.from_group_id),
             Some((index, group)) => {
                 let inserted_group = InsertedGroupPB {
@@ -370,6 +515,244 @@ impl DatabaseViewRevisionEditor {
         Ok(())
     }
 
+    /// Collapses or expands the group, the group with the new state is notified as the updated
+    /// group of the view.
+    pub async fn set_group_collapsed(&self, group_id: &str, collapsed: bool) -> FlowyResult<()> {
+        self.regroup_if_outdated().await;
+        self.group_controller
+            .write()
+            .await
+            .set_group_collapsed(group_id, collapsed)?;
+        if let Some((_, group)) = self.group_controller.read().await.get_group(group_id) {
+            let changeset = GroupViewChangesetPB {
+                view_id: self.view_id.clone(),
+                update_groups: vec![GroupPB::from(group)],
+                ..Default::default()
+            };
+            self.notify_did_update_view(changeset).await;
+        }
+        Ok(())
+    }
+
+    /// Moves the group to the index in the visible groups, the index beyond the visible groups
+    /// moves the group to the end. The order of the groups is written into the view revision
+    /// before returning, so the order is kept when the rows are grouped again.
+    ///
+    /// Returns the ids of all the groups in the new order, including the hidden groups.
+    pub async fn move_view_group_to_index(&self, from_group_id: &str, to_index: usize) -> FlowyResult<Vec<String>> {
+        self.regroup_if_outdated().await;
+        let visible_group_ids = self
+            .group_controller
+            .read()
+            .await
+            .groups()
+            .into_iter()
+            .filter(|group| group.is_visible)
+            .map(|group| group.id.clone())
+            .collect::<Vec<String>>();
+        let from_index = visible_group_ids
+            .iter()
+            .position(|group_id| group_id == from_group_id)
+            .ok_or_else(|| {
+                FlowyError::record_not_found().context(format!("Can not find the group: {}", from_group_id))
+            })?;
+        let to_index = std::cmp::min(to_index, visible_group_ids.len() - 1);
+        if from_index == to_index {
+            return Ok(self.all_group_ids().await);
+        }
+        self.group_controller
+            .write()
+            .await
+            .move_group(from_group_id, &visible_group_ids[to_index])?;
+        let group_ids = self.save_group_order().await?;
+
+        let notification = GroupMovedNotificationPB {
+            view_id: self.view_id.clone(),
+            group_id: from_group_id.to_owned(),
+            from_index: from_index as i32,
+            to_index: to_index as i32,
+        };
+        send_notification(&self.view_id, DatabaseNotification::DidMoveGroup)
+            .payload(notification)
+            .send();
+        Ok(group_ids)
+    }
+
+    /// Notifies the group that was generated for the new option of the grouping field as the
+    /// inserted group, and saves the order of the groups with the new group at the end.
+    pub async fn did_create_group(&self, group_id: &str) -> FlowyResult<GroupPB> {
+        self.save_group_order().await?;
+        let (index, group) = self
+            .get_visible_group(group_id)
+            .await
+            .ok_or_else(|| FlowyError::record_not_found().context(format!("Can not find the group: {}", group_id)))?;
+        let group = GroupPB::from(group);
+        let changeset = GroupViewChangesetPB {
+            view_id: self.view_id.clone(),
+            inserted_groups: vec![InsertedGroupPB {
+                group: group.clone(),
+                index: index as i32,
+            }],
+            ..Default::default()
+        };
+        self.notify_did_update_view(changeset).await;
+        Ok(group)
+    }
+
+    /// Returns the ids of all the groups in order, including the hidden groups.
+    async fn all_group_ids(&self) -> Vec<String> {
+        self.group_controller
+            .read()
+            .await
+            .groups()
+            .into_iter()
+            .map(|group| group.id.clone())
+            .collect()
+    }
+
+    /// Writes the order of the groups into the view revision at once instead of waiting for the
+    /// group controller to save it, so the order is read when the rows are grouped again right
+    /// after. Returns the ids of the groups in order.
+    async fn save_group_order(&self) -> FlowyResult<Vec<String>> {
+        let group_ids = self.all_group_ids().await;
+        let configuration = self.pad.read().await.get_all_groups().pop();
+        if let Some(configuration) = configuration {
+            let mut configuration = configuration.as_ref().clone();
+            configuration.groups.sort_by_key(|group_rev| {
+                group_ids
+                    .iter()
+                    .position(|group_id| group_id == &group_rev.id)
+                    .unwrap_or(usize::MAX)
+            });
+            let field_id = configuration.field_id.clone();
+            let field_type = configuration.field_type_rev;
+            self.modify(|pad| Ok(pad.insert_or_update_group_configuration(&field_id, &field_type, configuration)?))
+                .await?;
+        }
+        Ok(group_ids)
+    }
+
+    /// Hides or shows the group. The hidden group is notified as the deleted group of the view, and
+    /// the shown group is notified as the inserted group at its index in the visible groups.
+    pub async fn set_group_visibility(&self, group_id: &str, visible: bool) -> FlowyResult<()> {
+        self.regroup_if_outdated().await;
+        if let Some((_, group)) = self.group_controller.read().await.get_group(group_id) {
+            if group.is_visible == visible {
+                return Ok(());
+            }
+        }
+        self.group_controller
+            .write()
+            .await
+            .set_group_visibility(group_id, visible)?;
+
+        let mut changeset = GroupViewChangesetPB {
+            view_id: self.view_id.clone(),
+            ..Default::default()
+        };
+        if visible {
+            if let Some((index, group)) = self.get_visible_group(group_id).await {
+                changeset.inserted_groups.push(InsertedGroupPB {
+                    group: GroupPB::from(group),
+                    index: index as i32,
+                });
+            }
+        } else {
+            changeset.deleted_groups.push(group_id.to_owned());
+        }
+        self.notify_did_update_view(changeset).await;
+        Ok(())
+    }
+
+    /// Returns the index of the group in the visible groups, and the group. Returns None if the
+    /// group is hidden.
+    async fn get_visible_group(&self, group_id: &str) -> Option<(usize, Group)> {
+        self.group_controller
+            .read()
+            .await
+            .groups()
+            .into_iter()
+            .filter(|group| group.is_visible)
+            .enumerate()
+            .find(|(_, group)| group.id == group_id)
+            .map(|(index, group)| (index, group.clone()))
+    }
+
+    /// Groups the rows again if the groups are decided by the date of today and the rows were
+    /// grouped on another day, for example, the rows of `Today` became the rows of `Yesterday`.
+    async fn regroup_if_outdated(&self) {
+        if !self.group_controller.read().await.is_outdated() {
+            return;
+        }
+        let field_id = self.group_controller.read().await.field_id().to_owned();
+        if let Err(e) = self.group_by_view_field(&field_id).await {
+            tracing::error!("Group the rows of the view:{} again failed: {:?}", self.view_id, e);
+        }
+    }
+
+    /// Changes the range of the dates in each group of the view that is grouped by the date field,
+    /// or the created time and the last edited time fields, and groups the rows again.
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    pub async fn update_date_group_condition(&self, condition: DateCondition) -> FlowyResult<()> {
+        let field_types = [FieldType::DateTime, FieldType::CreatedTime, FieldType::LastEditedTime];
+        self.update_group_configuration_content(&field_types, |content: &mut DateGroupConfigurationRevision| {
+            content.condition = condition.into();
+        })
+        .await
+    }
+
+    /// Changes the ranges of the numbers in the groups of the view that is grouped by the number
+    /// field, and groups the rows again.
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    pub async fn update_number_group_ranges(&self, params: UpdateNumberGroupRangesParams) -> FlowyResult<()> {
+        self.update_group_configuration_content(
+            &[FieldType::Number],
+            |content: &mut NumberGroupConfigurationRevision| {
+                content.start = params.start;
+                content.width = params.width;
+                content.breakpoints = params.breakpoints;
+            },
+        )
+        .await
+    }
+
+    /// Updates the content of the group configuration, and groups the rows again with it, so the
+    /// groups are generated from the new content. The view must be grouped by the field of one of
+    /// the field types.
+    async fn update_group_configuration_content<C, F>(&self, field_types: &[FieldType], f: F) -> FlowyResult<()>
+    where
+        C: GroupConfigurationContentSerde + Default,
+        F: FnOnce(&mut C),
+    {
+        let field_id = self.group_controller.read().await.field_id().to_owned();
+        let field_rev = self
+            .delegate
+            .get_field_rev(&field_id)
+            .await
+            .ok_or_else(|| FlowyError::record_not_found().context("Can't find the group field"))?;
+        let group_field_type: FieldType = field_rev.ty.into();
+        if !field_types.contains(&group_field_type) {
+            let msg = format!(
+                "The view is grouped by the {:?} field instead of the {:?} field",
+                group_field_type, field_types
+            );
+            return Err(FlowyError::new(ErrorCode::FieldInvalidOperation, &msg));
+        }
+
+        let mut configuration = match self.pad.read().await.get_all_groups().pop() {
+            None => default_group_configuration(&field_rev),
+            Some(configuration) => configuration.as_ref().clone(),
+        };
+        let mut content = C::from_json(&configuration.content).unwrap_or_default();
+        f(&mut content);
+        configuration.field_id = field_rev.id.clone();
+        configuration.field_type_rev = field_rev.ty;
+        configuration.content = content.to_json()?;
+        self.modify(|pad| Ok(pad.insert_or_update_group_configuration(&field_rev.id, &field_rev.ty, configuration)?))
+            .await?;
+        self.group_by_view_field(&field_rev.id).await
+    }
+
     pub async fn group_id(&self) -> String {
         self.group_controller.read().await.field_id().to_string()
     }
@@ -420,11 +803,25 @@ impl DatabaseViewRevisionEditor {
             Some(sort_id) => sort_id,
         };
 
+        // The settings that are not given are kept, or the default settings for the new sort.
+        let old_sort_rev = self
+            .get_all_view_sorts()
+            .await
+            .into_iter()
+            .find(|sort| sort.id == sort_id)
+            .map(|sort| sort.as_ref().clone())
+            .unwrap_or_default();
         let sort_rev = SortRevision {
             id: sort_id,
             field_id: params.field_id.clone(),
             field_type: params.field_type,
             condition: params.condition.into(),
+            natural: params.natural.unwrap_or(old_sort_rev.natural),
+            alphabetical: params.alphabetical.unwrap_or(old_sort_rev.alphabetical),
+            empty_order: params
+                .empty_order
+                .map(SortEmptyOrder::from)
+                .unwrap_or(old_sort_rev.empty_order),
         };
 
         let mut sort_controller = self.sort_controller.write().await;
@@ -471,6 +868,66 @@ impl DatabaseViewRevisionEditor {
         Ok(())
     }
 
+    pub async fn get_sort_mode(&self) -> Option<SortModeRevision> {
+        self.pad.read().await.get_sort_mode()
+    }
+
+    /// Sets the special order of the rows that replaces the sorts, or removes it if the mode is
+    /// `Field`. The rows that were shuffled keep their order if they're shuffled again by this.
+    pub async fn update_sort_mode(&self, sort_mode: SortModePB) -> FlowyResult<()> {
+        let sort_mode = match sort_mode {
+            SortModePB::Field => None,
+            SortModePB::Shuffle => match self.get_sort_mode().await {
+                Some(SortModeRevision::Shuffle { seed }) => Some(SortModeRevision::Shuffle { seed }),
+                _ => Some(SortModeRevision::Shuffle {
+                    seed: gen_shuffle_seed(),
+                }),
+            },
+            SortModePB::LastModified => Some(SortModeRevision::LastModified),
+        };
+        self.set_sort_mode(sort_mode).await
+    }
+
+    /// Shuffles the rows again with a new seed.
+    pub async fn resort_shuffle(&self) -> FlowyResult<()> {
+        let sort_mode = SortModeRevision::Shuffle {
+            seed: gen_shuffle_seed(),
+        };
+        self.set_sort_mode(Some(sort_mode)).await
+    }
+
+    async fn set_sort_mode(&self, sort_mode: Option<SortModeRevision>) -> FlowyResult<()> {
+        self.modify(|pad| Ok(pad.set_sort_mode(sort_mode)?)).await?;
+        self.sort_controller.read().await.did_update_sort_mode().await;
+        Ok(())
+    }
+
+    /// Moves the sort to the index of the sorts of the view, which changes the precedence of the
+    /// sort. The rows are sorted again in the background.
+    pub async fn reorder_view_sort(&self, params: ReorderSortParams) -> FlowyResult<()> {
+        let is_exist = self
+            .get_all_view_sorts()
+            .await
+            .iter()
+            .any(|sort| sort.id == params.sort_id);
+        if !is_exist {
+            return Err(FlowyError::record_not_found().context(format!("Can't find the sort: {}", params.sort_id)));
+        }
+
+        let mut sort_controller = self.sort_controller.write().await;
+        self.modify(|pad| {
+            let changeset = pad.reorder_sort(&params.sort_id, params.new_index)?;
+            Ok(changeset)
+        })
+        .await?;
+        let notification = sort_controller
+            .did_receive_changes(SortChangeset::from_reorder(ReorderedSortType::from(&params)))
+            .await;
+        drop(sort_controller);
+        self.notify_did_update_sort(notification).await;
+        Ok(())
+    }
+
     pub async fn delete_all_view_sorts(&self) -> FlowyResult<()> {
         let all_sorts = self.get_all_view_sorts().await;
         self.sort_controller.write().await.delete_all_sorts().await;
@@ -572,6 +1029,134 @@ impl DatabaseViewRevisionEditor {
         Ok(())
     }
 
+    pub async fn get_view_filter_tree(&self) -> FilterGroupRevision {
+        self.pad.read().await.get_filter_tree()
+    }
+
+    /// Replaces the groups that combine the filters. Each filter of the view can be referenced
+    /// once, and the filters that are not referenced are combined with the root group by the
+    /// `And` operator.
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    pub async fn update_view_filter_tree(&self, filter_tree: FilterGroupRevision) -> FlowyResult<()> {
+        if filter_tree.depth() > MAX_FILTER_GROUP_DEPTH {
+            return Err(FlowyError::new(
+                ErrorCode::FilterGroupTooDeep,
+                &format!(
+                    "The filter groups can be nested up to the depth of {}",
+                    MAX_FILTER_GROUP_DEPTH
+                ),
+            ));
+        }
+        let filter_ids = self
+            .get_all_view_filters()
+            .await
+            .into_iter()
+            .map(|filter| filter.id.clone())
+            .collect::<Vec<String>>();
+        let mut grouped_filter_ids = HashSet::new();
+        for filter_id in filter_tree.filter_ids() {
+            if !filter_ids.iter().any(|id| id == filter_id) {
+                return Err(FlowyError::record_not_found().context(format!("The filter {} is not found", filter_id)));
+            }
+            if !grouped_filter_ids.insert(filter_id) {
+                return Err(FlowyError::invalid_data().context(format!("The filter {} is grouped twice", filter_id)));
+            }
+        }
+
+        self.modify(|pad| Ok(pad.set_filter_tree(filter_tree)?)).await?;
+        let notification = self
+            .filter_controller
+            .write()
+            .await
+            .did_receive_filter_tree_changed()
+            .await;
+        self.notify_did_update_filter(notification).await;
+        Ok(())
+    }
+
+    pub async fn get_view_filter_sets(&self) -> Vec<FilterSetRevision> {
+        self.pad.read().await.get_filter_sets().to_vec()
+    }
+
+    /// Saves the current filters of the view as the filter set of the name.
+    pub async fn save_view_filter_set(&self, name: &str) -> FlowyResult<()> {
+        self.modify(|pad| Ok(pad.save_filter_set(name)?)).await
+    }
+
+    pub async fn delete_view_filter_set(&self, name: &str) -> FlowyResult<()> {
+        self.modify(|pad| Ok(pad.delete_filter_set(name)?)).await
+    }
+
+    /// Replaces the filters of the view with the filters of the filter set in one revision. The
+    /// filters of the deleted fields and the filters that can't be converted to the new field
+    /// types of their fields are skipped and returned.
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    pub async fn apply_view_filter_set(&self, name: &str) -> FlowyResult<Vec<FilterRevision>> {
+        let filter_set =
+            self.pad.read().await.get_filter_set(name).cloned().ok_or_else(|| {
+                FlowyError::record_not_found().context(format!("The filter set {} is not found", name))
+            })?;
+        let field_revs = self.delegate.get_field_revs(None).await;
+        let mut filter_revs: Vec<FilterRevision> = vec![];
+        let mut skipped_filter_revs = vec![];
+        for filter_rev in filter_set.filters {
+            let converted_filter_rev = field_revs
+                .iter()
+                .find(|field_rev| field_rev.id == filter_rev.field_id)
+                .and_then(|field_rev| {
+                    if field_rev.ty == filter_rev.field_type {
+                        return Some(filter_rev.clone());
+                    }
+                    convert_filter_rev(&filter_rev, &field_rev.ty.into())
+                })
+                // A field type of the field has one filter at most.
+                .filter(|converted| {
+                    !filter_revs
+                        .iter()
+                        .any(|kept| kept.field_id == converted.field_id && kept.field_type == converted.field_type)
+                });
+            match converted_filter_rev {
+                None => skipped_filter_revs.push(filter_rev),
+                Some(converted_filter_rev) => filter_revs.push(converted_filter_rev),
+            }
+        }
+        let filter_tree = filter_set.filter_tree.map(|mut filter_tree| {
+            for skipped_filter_rev in skipped_filter_revs.iter() {
+                filter_tree.remove_filter(&skipped_filter_rev.id);
+            }
+            filter_tree
+        });
+
+        let old_filter_revs = self.get_all_view_filters().await;
+        self.modify(|pad| Ok(pad.replace_filters(filter_revs.clone(), filter_tree)?))
+            .await?;
+        let notification = self
+            .filter_controller
+            .write()
+            .await
+            .did_replace_filters(old_filter_revs, filter_revs.into_iter().map(Arc::new).collect())
+            .await;
+        self.notify_did_update_filter(notification).await;
+        Ok(skipped_filter_revs)
+    }
+
+    /// Sets the search query that hides the rows that don't contain it in any of their cells. The
+    /// query is kept in memory, it isn't saved to the [DatabaseViewRevision], and the rows are
+    /// searched after the query stops changing for a while.
+    pub async fn set_search_query(&self, query: Option<String>) {
+        self.filter_controller.write().await.set_search_query(query);
+    }
+
+    /// Returns the number of the times that a filter of the view evaluated a cell.
+    pub async fn get_filter_evaluation_count(&self) -> usize {
+        self.filter_controller.read().await.evaluation_count()
+    }
+
+    /// Returns the number of the times that two rows of the view were compared by the sorts.
+    pub async fn get_sort_comparison_count(&self) -> usize {
+        self.sort_controller.read().await.comparison_count()
+    }
+
     #[tracing::instrument(level = "trace", skip_all, err)]
     pub async fn did_update_view_field_type_option(
         &self,
@@ -579,6 +1164,10 @@ impl DatabaseViewRevisionEditor {
         old_field_rev: Option<Arc<FieldRevision>>,
     ) -> FlowyResult<()> {
         if let Some(field_rev) = self.delegate.get_field_rev(field_id).await {
+            if let Some(old_field_rev) = old_field_rev.as_ref().filter(|old| old.ty != field_rev.ty) {
+                self.convert_filters_of_retyped_field(old_field_rev, &field_rev).await?;
+            }
+            self.remove_deleted_options_from_filters(&field_rev).await?;
             let old = old_field_rev.map(|old_field_rev| FilterType::from(&old_field_rev));
             let new = FilterType::from(&field_rev);
             let filter_type = UpdatedFilterType::new(old, new);
@@ -603,6 +1192,87 @@ impl DatabaseViewRevisionEditor {
         Ok(())
     }
 
+    /// Converts the filters of the field to the filters of its new field type after the field type
+    /// is changed. The filters whose conditions can't be converted are deleted.
+    async fn convert_filters_of_retyped_field(
+        &self,
+        old_field_rev: &Arc<FieldRevision>,
+        field_rev: &Arc<FieldRevision>,
+    ) -> FlowyResult<()> {
+        let field_type: FieldType = field_rev.ty.into();
+        let filter_revs = self.pad.read().await.get_filters(&field_rev.id, &old_field_rev.ty);
+        for filter_rev in filter_revs {
+            let params = DeleteFilterParams {
+                view_id: self.view_id.clone(),
+                filter_type: FilterType::from(old_field_rev),
+                filter_id: filter_rev.id.clone(),
+            };
+            self.delete_view_filter(params).await?;
+
+            // A field type of the field has one filter at most.
+            let has_filter = !self
+                .pad
+                .read()
+                .await
+                .get_filters(&field_rev.id, &field_rev.ty)
+                .is_empty();
+            if let Some(converted_filter_rev) = convert_filter_rev(&filter_rev, &field_type).filter(|_| !has_filter) {
+                let params = AlterFilterParams {
+                    view_id: self.view_id.clone(),
+                    field_id: field_rev.id.clone(),
+                    filter_id: None,
+                    field_type: field_rev.ty,
+                    condition: converted_filter_rev.condition,
+                    content: converted_filter_rev.content,
+                };
+                self.insert_view_filter(params).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the options that were deleted from the select field from the filters of the field.
+    /// The filter is deleted if none of its options is left.
+    async fn remove_deleted_options_from_filters(&self, field_rev: &Arc<FieldRevision>) -> FlowyResult<()> {
+        let field_type: FieldType = field_rev.ty.into();
+        if !field_type.is_select_option() {
+            return Ok(());
+        }
+        let options = match select_type_option_from_field_rev(field_rev) {
+            Ok(type_option) => type_option.options().clone(),
+            Err(_) => return Ok(()),
+        };
+        let filter_revs = self.pad.read().await.get_filters(&field_rev.id, &field_rev.ty);
+        for filter_rev in filter_revs {
+            let mut option_ids = SelectOptionIds::from(filter_rev.content.clone()).into_inner();
+            let option_count = option_ids.len();
+            option_ids.retain(|option_id| options.iter().any(|option| &option.id == option_id));
+            if option_ids.len() == option_count {
+                continue;
+            }
+
+            if option_ids.is_empty() {
+                let params = DeleteFilterParams {
+                    view_id: self.view_id.clone(),
+                    filter_type: FilterType::from(field_rev),
+                    filter_id: filter_rev.id.clone(),
+                };
+                self.delete_view_filter(params).await?;
+            } else {
+                let params = AlterFilterParams {
+                    view_id: self.view_id.clone(),
+                    field_id: field_rev.id.clone(),
+                    filter_id: Some(filter_rev.id.clone()),
+                    field_type: field_rev.ty,
+                    condition: filter_rev.condition,
+                    content: SelectOptionIds::from(option_ids).to_string(),
+                };
+                self.insert_view_filter(params).await?;
+            }
+        }
+        Ok(())
+    }
+
     ///
     ///
     /// # Arguments
@@ -631,6 +1301,7 @@ impl DatabaseViewRevisionEditor {
             let new_groups = new_group_controller
                 .groups()
                 .into_iter()
+                .filter(|group| group.is_visible)
                 .map(|group| GroupPB::from(group.clone()))
                 .collect();
 
@@ -651,10 +1322,581 @@ impl DatabaseViewRevisionEditor {
         Ok(())
     }
 
+    /// Applies the field settings of the view to the fields. The fields are returned in the order
+    /// of the view, and their visibilities and widths are overridden by the view.
+    pub async fn get_view_field_revs(&self, field_revs: Vec<Arc<FieldRevision>>) -> Vec<Arc<FieldRevision>> {
+        let read_guard = self.pad.read().await;
+        let field_settings = match read_guard.get_field_settings() {
+            None => return field_revs,
+            Some(field_settings) => field_settings,
+        };
+
+        let mut field_revs = field_revs
+            .into_iter()
+            .map(|mut field_rev| {
+                let visibility = field_settings.is_visible(&field_rev.id, field_rev.visibility);
+                let width = field_settings.width(&field_rev.id, field_rev.width);
+                if visibility != field_rev.visibility || width != field_rev.width {
+                    let field_rev = Arc::make_mut(&mut field_rev);
+                    field_rev.visibility = visibility;
+                    field_rev.width = width;
+                }
+                field_rev
+            })
+            .collect::<Vec<Arc<FieldRevision>>>();
+        field_settings.sort_fields(&mut field_revs, |field_rev| field_rev.id.as_str());
+        field_revs
+    }
+
+    /// Shows or hides the field in this view only. The other views keep their own visibilities.
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    pub async fn set_field_visibility(&self, field_id: &str, visibility: bool) -> FlowyResult<()> {
+        if self.delegate.get_field_rev(field_id).await.is_none() {
+            return Err(FlowyError::field_record_not_found().context(format!("field:{}", field_id)));
+        }
+        self.modify(|pad| Ok(pad.set_field_visibility(field_id, visibility)?))
+            .await?;
+
+        self.notify_did_update_view_field(field_id).await;
+        Ok(())
+    }
+
+    /// Resizes the field in this view only. The width is clamped to [MIN_FIELD_WIDTH].
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    pub async fn set_field_width(&self, field_id: &str, width: i32) -> FlowyResult<()> {
+        if self.delegate.get_field_rev(field_id).await.is_none() {
+            return Err(FlowyError::field_record_not_found().context(format!("field:{}", field_id)));
+        }
+        let width = std::cmp::max(width, MIN_FIELD_WIDTH);
+        self.modify(|pad| Ok(pad.set_field_width(field_id, width)?)).await?;
+        self.notify_did_update_view_field(field_id).await;
+        Ok(())
+    }
+
+    /// Freezes the first `count` visible fields of the view. The count is clamped to the number of
+    /// the visible fields.
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    pub async fn set_frozen_field_count(&self, count: usize) -> FlowyResult<()> {
+        let visible_field_count = self
+            .get_view_field_revs(self.delegate.get_field_revs(None).await)
+            .await
+            .iter()
+            .filter(|field_rev| field_rev.visibility)
+            .count();
+        let count = std::cmp::min(count, visible_field_count);
+        self.modify(|pad| Ok(pad.set_frozen_field_count(count)?)).await?;
+        self.notify_did_update_setting().await;
+        Ok(())
+    }
+
+    /// Removes the filters, the sorts and the settings of the deleted field from the view in one
+    /// revision, and notifies the deleted filters and sorts. The rows are sorted by the remaining
+    /// sorts, or are back in their manual order if no sort remains.
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    pub async fn did_delete_field(&self, field_id: &str) -> FlowyResult<()> {
+        let filter_revs = self.pad.read().await.get_filters_of_field(field_id);
+        let sort_revs = self.pad.read().await.get_sorts_of_field(field_id);
+        let mut filter_notifications = vec![];
+        for filter_rev in filter_revs.iter() {
+            let filter_type = FilterType {
+                field_id: field_id.to_owned(),
+                field_type: filter_rev.field_type.into(),
+            };
+            if let Some(notification) = self
+                .filter_controller
+                .write()
+                .await
+                .did_receive_changes(FilterChangeset::from_delete(filter_type))
+                .await
+            {
+                filter_notifications.push(notification);
+            }
+        }
+        self.modify(|pad| Ok(pad.delete_field_rules(field_id)?)).await?;
+
+        let mut sort_notification = SortChangesetNotificationPB::new(self.view_id.clone());
+        for sort_rev in sort_revs {
+            let deleted_sort_type = DeletedSortType {
+                sort_type: SortType {
+                    field_id: field_id.to_owned(),
+                    field_type: sort_rev.field_type.into(),
+                },
+                sort_id: sort_rev.id.clone(),
+            };
+            let notification = self
+                .sort_controller
+                .write()
+                .await
+                .did_receive_changes(SortChangeset::from_delete(deleted_sort_type))
+                .await;
+            sort_notification.extend(notification);
+        }
+
+        for notification in filter_notifications {
+            self.notify_did_update_filter(notification).await;
+        }
+        if !sort_notification.is_empty() {
+            self.notify_did_update_sort(sort_notification).await;
+        }
+        Ok(())
+    }
+
+    /// Removes the filters, sorts, groups and settings of the deleted fields from the view in one
+    /// revision. The view is grouped by another field if its group field was deleted.
+    #[tracing::instrument(level = "trace", skip_all, err)]
+    pub async fn did_delete_fields(&self, field_revs: &[Arc<FieldRevision>]) -> FlowyResult<()> {
+        let mut filter_notifications = vec![];
+        let mut sort_notifications = vec![];
+        for field_rev in field_revs {
+            let filter_type = FilterType::from(field_rev);
+            if let Some(notification) = self
+                .filter_controller
+                .write()
+                .await
+                .did_receive_changes(FilterChangeset::from_delete(filter_type))
+                .await
+            {
+                filter_notifications.push(notification);
+            }
+
+            let sort_revs = self.pad.read().await.get_sorts_of_field(&field_rev.id);
+            for sort_rev in sort_revs {
+                let deleted_sort_type = DeletedSortType {
+                    sort_type: SortType {
+                        field_id: field_rev.id.clone(),
+                        field_type: sort_rev.field_type.into(),
+                    },
+                    sort_id: sort_rev.id.clone(),
+                };
+                let notification = self
+                    .sort_controller
+                    .write()
+                    .await
+                    .did_receive_changes(SortChangeset::from_delete(deleted_sort_type))
+                    .await;
+                sort_notifications.push(notification);
+            }
+        }
+
+        let field_ids = field_revs
+            .iter()
+            .map(|field_rev| field_rev.id.clone())
+            .collect::<Vec<String>>();
+        self.modify(|pad| Ok(pad.delete_field_objects(&field_ids)?)).await?;
+
+        for notification in filter_notifications {
+            self.notify_did_update_filter(notification).await;
+        }
+        for notification in sort_notifications {
+            self.notify_did_update_sort(notification).await;
+        }
+
+        let group_field_id = self.group_controller.read().await.field_id().to_owned();
+        if field_ids.contains(&group_field_id) {
+            let remaining_field_revs = self.delegate.get_field_revs(None).await;
+            let layout = self.pad.read().await.layout();
+            if let Some(field_rev) = find_group_field(&remaining_field_revs, &layout) {
+                self.group_by_view_field(&field_rev.id).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reorders the fields in this view only. The `field_ids` must contain each id of the fields
+    /// exactly once.
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    pub async fn set_view_field_order(&self, field_ids: Vec<String>) -> FlowyResult<()> {
+        let old_field_revs = self.get_view_field_revs(self.delegate.get_field_revs(None).await).await;
+        let mut sorted_field_ids = field_ids.clone();
+        sorted_field_ids.sort();
+        let mut existing_field_ids = old_field_revs
+            .iter()
+            .map(|field_rev| field_rev.id.clone())
+            .collect::<Vec<String>>();
+        existing_field_ids.sort();
+        if sorted_field_ids != existing_field_ids {
+            let msg = "The field ids should be the same as the ids of the fields of the view";
+            return Err(FlowyError::invalid_data().context(msg));
+        }
+
+        self.modify(|pad| Ok(pad.set_field_order(field_ids.clone())?)).await?;
+
+        // Only the fields whose positions are changed are notified.
+        let mut deleted_fields = vec![];
+        let mut inserted_fields = vec![];
+        for (index, field_id) in field_ids.iter().enumerate() {
+            if old_field_revs.get(index).map(|field_rev| &field_rev.id) == Some(field_id) {
+                continue;
+            }
+            if let Some(field_rev) = old_field_revs.iter().find(|field_rev| &field_rev.id == field_id) {
+                deleted_fields.push(FieldIdPB::from(field_id.as_str()));
+                inserted_fields.push(IndexFieldPB::from_field_rev(field_rev, index));
+            }
+        }
+        if !inserted_fields.is_empty() {
+            let notified_changeset = DatabaseFieldChangesetPB {
+                database_id: self.view_id.clone(),
+                inserted_fields,
+                deleted_fields,
+                updated_fields: vec![],
+            };
+            self.notify_did_update_fields(notified_changeset).await;
+        }
+        Ok(())
+    }
+
+    pub async fn get_pinned_row_ids(&self) -> Vec<String> {
+        self.pad.read().await.get_pinned_row_ids().to_vec()
+    }
+
+    /// Pins the row to the top of this view. The pinned rows are placed before the other rows in
+    /// the order they were pinned, regardless of the sorts. They are still filtered.
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    pub async fn pin_row(&self, row_id: &str) -> FlowyResult<()> {
+        if self.delegate.get_row_rev(row_id).await.is_none() {
+            return Err(FlowyError::record_not_found().context(format!("Can't find the row with id: {}", row_id)));
+        }
+        if self.pad.read().await.get_pinned_row_ids().iter().any(|id| id == row_id) {
+            return Ok(());
+        }
+        self.modify(|pad| Ok(pad.pin_row(row_id)?)).await?;
+        self.did_update_pinned_rows().await;
+        Ok(())
+    }
+
+    /// Unpins the row, so it's placed by the sorts of this view again.
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    pub async fn unpin_row(&self, row_id: &str) -> FlowyResult<()> {
+        if !self.pad.read().await.get_pinned_row_ids().iter().any(|id| id == row_id) {
+            return Ok(());
+        }
+        self.modify(|pad| Ok(pad.unpin_rows(&[row_id.to_owned()])?)).await?;
+        self.did_update_pinned_rows().await;
+        Ok(())
+    }
+
+    /// Removes the deleted rows from the pinned rows. The rows don't need to be reordered.
+    async fn unpin_deleted_rows(&self, row_ids: &[String]) {
+        if !self
+            .pad
+            .read()
+            .await
+            .get_pinned_row_ids()
+            .iter()
+            .any(|pinned_row_id| row_ids.contains(pinned_row_id))
+        {
+            return;
+        }
+        match self.modify(|pad| Ok(pad.unpin_rows(row_ids)?)).await {
+            Ok(_) => self.notify_did_update_pinned_rows().await,
+            Err(e) => tracing::error!("Unpin the deleted rows in view:{} failed: {:?}", self.view_id, e),
+        }
+    }
+
+    async fn did_update_pinned_rows(&self) {
+        self.sort_controller.read().await.did_update_pinned_rows().await;
+        self.notify_did_update_pinned_rows().await;
+    }
+
+    /// Copies the cells at the intersection of the rows and the fields, in the order of the
+    /// passed-in ids. Returns the display strings of the cells as tsv, along with the lossless
+    /// json payload that is used when the cells are pasted.
+    pub async fn copy_range(&self, row_ids: &[String], field_ids: &[String]) -> FlowyResult<RangeClipboardPB> {
+        let mut field_revs = vec![];
+        for field_id in field_ids {
+            let field_rev = self.delegate.get_field_rev(field_id).await.ok_or_else(|| {
+                FlowyError::field_record_not_found().context(format!("Can't find the field: {}", field_id))
+            })?;
+            field_revs.push(field_rev);
+        }
+
+        let mut clipboard = RangeClipboard {
+            fields: field_revs
+                .iter()
+                .map(|field_rev| ClipboardField {
+                    field_id: field_rev.id.clone(),
+                    field_type: field_rev.ty.into(),
+                    type_option: field_rev
+                        .get_type_option_str(field_rev.ty)
+                        .unwrap_or_default()
+                        .to_owned(),
+                })
+                .collect(),
+            rows: vec![],
+        };
+        let mut display_rows = vec![];
+        for row_id in row_ids {
+            let (_, row_rev) = self
+                .delegate
+                .get_row_rev(row_id)
+                .await
+                .ok_or_else(|| FlowyError::record_not_found().context(format!("Can't find the row: {}", row_id)))?;
+            let mut cells = vec![];
+            let mut display_strs = vec![];
+            for field_rev in field_revs.iter() {
+                let field_type: FieldType = field_rev.ty.into();
+                let type_cell_data = get_cell_rev_from_row(&row_rev, field_rev)
+                    .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+                    .filter(|type_cell_data| !type_cell_data.cell_str.is_empty());
+                let handler = self.delegate.get_type_option_cell_handler(field_rev, &field_type);
+                let display_str = match (&type_cell_data, handler) {
+                    (Some(type_cell_data), Some(handler)) => handler.stringify_cell_str(
+                        type_cell_data.cell_str.clone(),
+                        &type_cell_data.field_type,
+                        field_rev,
+                    ),
+                    _ => "".to_owned(),
+                };
+                cells.push(type_cell_data);
+                display_strs.push(display_str);
+            }
+            clipboard.rows.push(cells);
+            display_rows.push(display_strs);
+        }
+
+        Ok(RangeClipboardPB {
+            tsv: tsv_from_rows(&display_rows),
+            json: clipboard.to_json_str()?,
+        })
+    }
+
+    /// Maps the values of the clipboard onto the cells, starting from the cell of the anchor row
+    /// and the anchor field. The rows and the visible fields are taken in the order of this view.
+    /// The values that go past the last field are dropped, and the cells of the timestamp and the
+    /// formula fields are skipped because they're computed.
+    pub async fn paste_range(
+        &self,
+        anchor_row_id: &str,
+        anchor_field_id: &str,
+        clipboard: &RangeClipboardPB,
+    ) -> FlowyResult<RangePaste> {
+        let clipboard = RangeClipboard::try_from(clipboard)?;
+        let row_ids = self
+            .get_view_row_revs()
+            .await
+            .into_iter()
+            .map(|row_rev| row_rev.id.clone())
+            .collect::<Vec<String>>();
+        let row_index = row_ids
+            .iter()
+            .position(|row_id| row_id == anchor_row_id)
+            .ok_or_else(|| FlowyError::record_not_found().context(format!("Can't find the row: {}", anchor_row_id)))?;
+        let field_revs = self
+            .get_view_field_revs(self.delegate.get_field_revs(None).await)
+            .await
+            .into_iter()
+            .filter(|field_rev| field_rev.visibility || field_rev.id == anchor_field_id)
+            .collect::<Vec<Arc<FieldRevision>>>();
+        let field_index = field_revs
+            .iter()
+            .position(|field_rev| field_rev.id == anchor_field_id)
+            .ok_or_else(|| {
+                FlowyError::field_record_not_found().context(format!("Can't find the field: {}", anchor_field_id))
+            })?;
+
+        let mut range_paste = RangePaste::default();
+        for (row_offset, values) in clipboard.rows.iter().enumerate() {
+            let row_id = row_ids.get(row_index + row_offset);
+            let mut new_row = vec![];
+            for (column, value) in values.iter().enumerate() {
+                let (field_rev, source_field) =
+                    match (field_revs.get(field_index + column), clipboard.fields.get(column)) {
+                        (Some(field_rev), Some(source_field)) => (field_rev, source_field),
+                        _ => break,
+                    };
+                let field_type: FieldType = field_rev.ty.into();
+                if field_type.is_timestamp() || field_type.is_formula() {
+                    continue;
+                }
+                // The empty value clears the cell of the existing row.
+                let type_cell_data = match value {
+                    None => Ok(TypeCellData::from_field_type(&field_type).to_json()),
+                    Some(value) => match self.delegate.get_type_option_cell_handler(field_rev, &field_type) {
+                        None => Ok(TypeCellData::from_field_type(&field_type).to_json()),
+                        Some(handler) => convert_clipboard_cell(value, source_field, field_rev, handler.as_ref()),
+                    },
+                };
+                let changeset = CellChangesetPB {
+                    database_id: self.view_id.clone(),
+                    row_id: row_id.cloned().unwrap_or_default(),
+                    field_id: field_rev.id.clone(),
+                    type_cell_data: "".to_owned(),
+                };
+                match type_cell_data {
+                    Err(e) => range_paste.errors.push(CellUpdateErrorPB::new(&changeset, e)),
+                    Ok(type_cell_data) => match row_id {
+                        Some(_) => range_paste.cells.push(CellChangesetPB {
+                            type_cell_data,
+                            ..changeset
+                        }),
+                        // The empty values of the new rows are skipped.
+                        None if value.is_some() => new_row.push((changeset.field_id, type_cell_data)),
+                        None => {}
+                    },
+                }
+            }
+            if row_id.is_none() {
+                range_paste.new_rows.push(new_row);
+            }
+        }
+        Ok(range_paste)
+    }
+
+    /// Maps the visual `index` of this view to the index among all the rows of the database where
+    /// a new row is inserted, so the new row goes right after the row at `index - 1` of this view.
+    /// The new row goes before the first row of this view if the `index` is 0, and after the last
+    /// row of this view if the `index` is out of bounds. Returns None if this view has no rows,
+    /// then the new row is appended to the database.
+    pub async fn database_index_of_visual_index(&self, index: usize) -> Option<usize> {
+        let view_row_revs = self.get_view_row_revs().await;
+        let (row_id, offset) = match index.min(view_row_revs.len()) {
+            0 => (&view_row_revs.first()?.id, 0),
+            index => (&view_row_revs[index - 1].id, 1),
+        };
+        let mut database_index = 0;
+        for block in self.delegate.get_blocks().await {
+            match block.row_revs.iter().position(|row_rev| &row_rev.id == row_id) {
+                None => database_index += block.row_revs.len(),
+                Some(index_in_block) => return Some(database_index + index_in_block + offset),
+            }
+        }
+        None
+    }
+
+    /// Returns the index of the row in the order this view displays the rows, or None if the row is
+    /// hidden by the filters of this view.
+    pub async fn visual_index_of_row(&self, row_id: &str) -> Option<usize> {
+        self.get_view_row_revs()
+            .await
+            .iter()
+            .position(|row_rev| row_rev.id == row_id)
+    }
+
+    /// Returns the number of the rows that this view displays after applying its filters. The
+    /// cached filter results are used rather than filtering all the rows again.
+    pub async fn visible_row_count(&self) -> usize {
+        let blocks = self.delegate.get_blocks().await;
+        let mut row_count = 0;
+        let mut filter_controller = self.filter_controller.write().await;
+        for block in blocks {
+            row_count += filter_controller.visible_row_count(&block.row_revs).await;
+        }
+        row_count
+    }
+
+    /// Returns the rows of this view in the order they're displayed. The rows are placed in the
+    /// manual order first, so the sorts keep the manual order of the rows that are equal in them.
+    async fn get_view_row_revs(&self) -> Vec<Arc<RowRevision>> {
+        let mut view_row_revs = vec![];
+        for block in self.delegate.get_blocks().await {
+            let mut row_revs = block.row_revs;
+            self.filter_rows(&block.block_id, &mut row_revs).await;
+            view_row_revs.extend(row_revs);
+        }
+        let manual_row_order = self.get_manual_row_order().await;
+        apply_manual_row_order(&manual_row_order, &mut view_row_revs, |row_rev| row_rev.id.as_str());
+        self.sort_rows(&mut view_row_revs).await;
+        view_row_revs
+    }
+
+    pub async fn get_manual_row_order(&self) -> Vec<String> {
+        self.pad.read().await.get_manual_row_order().to_vec()
+    }
+
+    /// Returns true if the rows are ordered by the sorts or the sort mode.
+    async fn is_sorted(&self) -> bool {
+        !self.get_all_view_sorts().await.is_empty() || self.pad.read().await.get_sort_mode().is_some()
+    }
+
+    /// Returns the ids of the rows in the order they're displayed, and where the order comes from.
+    pub async fn get_effective_row_order(&self) -> EffectiveRowOrderPB {
+        let source = if self.is_sorted().await {
+            RowOrderSourcePB::Sort
+        } else if !self.pad.read().await.get_manual_row_order().is_empty() {
+            RowOrderSourcePB::Manual
+        } else {
+            RowOrderSourcePB::Block
+        };
+        let row_ids = self
+            .get_view_row_revs()
+            .await
+            .into_iter()
+            .map(|row_rev| row_rev.id.clone())
+            .collect();
+        EffectiveRowOrderPB { source, row_ids }
+    }
+
+    /// Moves the row in the manual order of this view if the view is sorted or the rows were moved
+    /// while it was sorted. The sorted rows keep their places until the sorts are removed, unless
+    /// `remove_sorts` is true, in which case the sorted order becomes the manual order and the
+    /// sorts are removed. Returns false if the rows are in the order of their blocks, so the row
+    /// should be moved in its block.
+    #[tracing::instrument(level = "trace", skip(self), err)]
+    pub async fn move_row_in_manual_order(
+        &self,
+        row_id: &str,
+        to: &MoveRowTarget,
+        remove_sorts: bool,
+    ) -> FlowyResult<bool> {
+        let is_sorted = self.is_sorted().await;
+        let manual_row_order = self.get_manual_row_order().await;
+        if !is_sorted && manual_row_order.is_empty() {
+            return Ok(false);
+        }
+
+        let mut row_revs = if remove_sorts && is_sorted {
+            self.get_view_row_revs().await
+        } else {
+            let mut row_revs = self.delegate.get_row_revs(None).await;
+            apply_manual_row_order(&manual_row_order, &mut row_revs, |row_rev| row_rev.id.as_str());
+            row_revs
+        };
+        let from_index = row_revs
+            .iter()
+            .position(|row_rev| row_rev.id == row_id)
+            .ok_or_else(|| FlowyError::record_not_found().context(format!("Can't find the row with id: {}", row_id)))?;
+        let to_index = match to {
+            MoveRowTarget::RowId(to_row_id) => match row_revs.iter().position(|row_rev| &row_rev.id == to_row_id) {
+                None => {
+                    tracing::warn!("Can not find the to row id: {}", to_row_id);
+                    return Ok(true);
+                }
+                Some(to_index) => to_index,
+            },
+            MoveRowTarget::Index(index) => (*index).min(row_revs.len() - 1),
+        };
+        let row_rev = row_revs.remove(from_index);
+        row_revs.insert(to_index, row_rev);
+        let row_ids = row_revs.into_iter().map(|row_rev| row_rev.id.clone()).collect();
+        self.modify(|pad| Ok(pad.set_manual_row_order(row_ids)?)).await?;
+
+        if remove_sorts && is_sorted {
+            self.delete_all_view_sorts().await?;
+            self.set_sort_mode(None).await?;
+        } else {
+            self.sort_controller.read().await.did_update_manual_row_order().await;
+        }
+        Ok(true)
+    }
+
     pub(crate) async fn get_cells_for_field(&self, field_id: &str) -> FlowyResult<Vec<RowSingleCellData>> {
         get_cells_for_field(self.delegate.clone(), field_id).await
     }
 
+    async fn notify_did_update_view_field(&self, field_id: &str) {
+        let field_revs = self.get_view_field_revs(self.delegate.get_field_revs(None).await).await;
+        if let Some(field_rev) = field_revs.into_iter().find(|field_rev| field_rev.id == field_id) {
+            let notified_changeset = DatabaseFieldChangesetPB::update(&self.view_id, vec![FieldPB::from(field_rev)]);
+            self.notify_did_update_fields(notified_changeset).await;
+        }
+    }
+
+    async fn notify_did_update_fields(&self, changeset: DatabaseFieldChangesetPB) {
+        send_notification(&self.view_id, DatabaseNotification::DidUpdateDatabaseFields)
+            .payload(changeset)
+            .send();
+    }
+
     async fn notify_did_update_setting(&self) {
         let setting = self.get_view_setting().await;
         send_notification(&self.view_id, DatabaseNotification::DidUpdateDatabaseSetting)
@@ -662,7 +1904,26 @@ impl DatabaseViewRevisionEditor {
             .send();
     }
 
+    async fn notify_did_update_pinned_rows(&self) {
+        let changeset = ViewRowsChangesetPB::from_pinned_rows(self.view_id.clone(), self.get_pinned_row_ids().await);
+        send_notification(&self.view_id, DatabaseNotification::DidUpdateDatabaseViewRows)
+            .payload(changeset)
+            .send();
+    }
+
+    /// The rows of the hidden group are not notified, so the row that is moved into the hidden group
+    /// is only notified as the deleted row of its old group.
     pub async fn notify_did_update_group_rows(&self, payload: GroupRowsNotificationPB) {
+        let is_hidden = self
+            .group_controller
+            .read()
+            .await
+            .groups()
+            .iter()
+            .any(|group| group.id == payload.group_id && !group.is_visible);
+        if is_hidden {
+            return;
+        }
         send_notification(&payload.group_id, DatabaseNotification::DidUpdateGroup)
             .payload(payload)
             .send();
@@ -743,7 +2004,7 @@ pub(crate) async fn get_cells_for_field(
     let mut cells = vec![];
     if let Some(handler) = delegate.get_type_option_cell_handler(&field_rev, &field_type) {
         for row_rev in row_revs {
-            if let Some(cell_rev) = row_rev.cells.get(field_id) {
+            if let Some(cell_rev) = get_cell_rev_from_row(&row_rev, &field_rev) {
                 if let Ok(type_cell_data) = TypeCellData::try_from(cell_rev) {
                     if let Ok(cell_data) = handler.get_cell_data(type_cell_data.cell_str, &field_type, &field_rev) {
                         cells.push(RowSingleCellData {
@@ -857,6 +2118,47 @@ async fn make_filter_controller(
     filter_controller
 }
 
+async fn get_filter_config_hash(
+    delegate: &Arc<dyn DatabaseViewEditorDelegate>,
+    pad: &Arc<RwLock<GridViewRevisionPad>>,
+) -> String {
+    let field_revs = delegate.get_field_revs(None).await;
+    let pad = pad.read().await;
+    let filter_revs = pad.get_all_filters(&field_revs);
+    filter_config_hash(&filter_revs, &pad.get_filter_tree(), &field_revs)
+}
+
+/// Restores the filter results that were saved when the view was closed. The results are
+/// discarded if the filters or the blocks were changed since then.
+async fn restore_filter_results(
+    view_id: &str,
+    delegate: &Arc<dyn DatabaseViewEditorDelegate>,
+    pad: &Arc<RwLock<GridViewRevisionPad>>,
+    filter_controller: &Arc<RwLock<FilterController>>,
+    filter_results: &FilterResultStore,
+) {
+    let cache = match filter_results.get_cache(view_id) {
+        Ok(Some(cache)) => cache,
+        Ok(None) => return,
+        Err(e) => {
+            tracing::error!("Read the filter results of the view:{} failed: {:?}", view_id, e);
+            return;
+        }
+    };
+    let filter_hash = get_filter_config_hash(delegate, pad).await;
+    let block_rev_ids = delegate.get_block_rev_ids().await;
+    if !cache.is_valid(&filter_hash, &block_rev_ids) {
+        tracing::trace!("The filter results of the view:{} are outdated", view_id);
+        let _ = filter_results.delete_cache(view_id);
+        return;
+    }
+    filter_controller
+        .write()
+        .await
+        .restore_visibility(cache.visible_by_row_id)
+        .await;
+}
+
 async fn make_sort_controller(
     view_id: &str,
     delegate: Arc<dyn DatabaseViewEditorDelegate>,
@@ -891,10 +2193,96 @@ async fn make_sort_controller(
     sort_controller
 }
 
+/// The views that were created before the fields could be overridden per view don't have the
+/// field settings. The fields that are hidden in the database are copied into the field settings
+/// of the view when it's opened for the first time.
+///
+/// The settings of the fields that were deleted while the view was closed are removed.
+async fn initialize_field_settings(
+    user_id: &str,
+    pad: &Arc<RwLock<GridViewRevisionPad>>,
+    rev_manager: &Arc<RevisionManager<Arc<ConnectionPool>>>,
+    delegate: &Arc<dyn DatabaseViewEditorDelegate>,
+) -> FlowyResult<()> {
+    let field_revs = delegate.get_field_revs(None).await;
+    let field_settings = pad.read().await.get_field_settings().cloned();
+    let changeset = match field_settings {
+        None => {
+            let hidden_field_ids = field_revs
+                .iter()
+                .filter(|field_rev| !field_rev.visibility)
+                .map(|field_rev| field_rev.id.clone())
+                .collect::<Vec<String>>();
+            pad.write().await.initialize_field_settings(hidden_field_ids)?
+        }
+        Some(field_settings) => {
+            let deleted_field_ids = field_settings
+                .field_ids()
+                .into_iter()
+                .filter(|field_id| !field_revs.iter().any(|field_rev| &field_rev.id == field_id))
+                .collect::<Vec<String>>();
+            if deleted_field_ids.is_empty() {
+                return Ok(());
+            }
+            pad.write().await.delete_field_settings(&deleted_field_ids)?
+        }
+    };
+    if let Some(changeset) = changeset {
+        apply_change(user_id, rev_manager.clone(), changeset).await?;
+    }
+    Ok(())
+}
+
+/// Unpins the rows that were deleted while the view was closed.
+async fn initialize_pinned_rows(
+    user_id: &str,
+    pad: &Arc<RwLock<GridViewRevisionPad>>,
+    rev_manager: &Arc<RevisionManager<Arc<ConnectionPool>>>,
+    delegate: &Arc<dyn DatabaseViewEditorDelegate>,
+) -> FlowyResult<()> {
+    let pinned_row_ids = pad.read().await.get_pinned_row_ids().to_vec();
+    if pinned_row_ids.is_empty() {
+        return Ok(());
+    }
+    let mut deleted_row_ids = vec![];
+    for row_id in pinned_row_ids {
+        if delegate.get_row_rev(&row_id).await.is_none() {
+            deleted_row_ids.push(row_id);
+        }
+    }
+    if deleted_row_ids.is_empty() {
+        return Ok(());
+    }
+    let changeset = pad.write().await.unpin_rows(&deleted_row_ids)?;
+    if let Some(changeset) = changeset {
+        apply_change(user_id, rev_manager.clone(), changeset).await?;
+    }
+    Ok(())
+}
+
 fn gen_handler_id() -> String {
     nanoid!(10)
 }
 
+/// Returns the ids of the fields whose cells are different between the two revisions of the row,
+/// including the cells that were removed from the row.
+fn changed_field_ids(old_row_rev: &RowRevision, row_rev: &RowRevision) -> Vec<String> {
+    let mut field_ids = row_rev
+        .cells
+        .iter()
+        .filter(|(field_id, cell_rev)| old_row_rev.cells.get(*field_id) != Some(*cell_rev))
+        .map(|(field_id, _)| field_id.clone())
+        .collect::<Vec<String>>();
+    field_ids.extend(
+        old_row_rev
+            .cells
+            .keys()
+            .filter(|field_id| !row_rev.cells.contains_key(*field_id))
+            .cloned(),
+    );
+    field_ids
+}
+
 #[cfg(test)]
 mod tests {
     use flowy_client_sync::client_database::DatabaseOperations;
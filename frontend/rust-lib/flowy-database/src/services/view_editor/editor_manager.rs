@@ -1,56 +1,88 @@
 use crate::entities::{
     AlterFilterParams, AlterSortParams, CreateRowParams, DatabaseViewSettingPB, DeleteFilterParams, DeleteGroupParams,
-    DeleteSortParams, InsertGroupParams, MoveGroupParams, RepeatedGroupPB, RowPB,
+    DeleteSortParams, EffectiveRowOrderPB, FilterSetParams, GroupPB, InsertGroupParams, MoveGroupParams,
+    MoveGroupToIndexParams, MoveRowParams, ReorderSortParams, RepeatedGroupPB, RowPB, SetGroupCollapsedParams,
+    SetGroupVisibilityParams, UpdateDateGroupConditionParams, UpdateFilterTreeParams, UpdateNumberGroupRangesParams,
+    UpdateSortModeParams,
 };
-use crate::manager::DatabaseUser;
 use crate::services::block_manager::DatabaseBlockEvent;
 use crate::services::cell::AtomicCellDataCache;
-use crate::services::filter::FilterType;
-use crate::services::persistence::rev_sqlite::{
-    SQLiteDatabaseRevisionSnapshotPersistence, SQLiteGridViewRevisionPersistence,
-};
+use crate::services::filter::{FilterResultStore, FilterType};
+use crate::services::persistence::rev_store::{GridRevisionObject, GridRevisionStores};
 use crate::services::view_editor::changed_notifier::*;
-use crate::services::view_editor::trait_impl::GridViewRevisionMergeable;
+use crate::services::view_editor::trait_impl::{apply_change, GridViewRevisionMergeable, GridViewRevisionSerde};
 use crate::services::view_editor::{DatabaseViewEditorDelegate, DatabaseViewRevisionEditor};
+use flowy_client_sync::client_database::{make_grid_view_operations, GridViewRevisionPad};
 use flowy_error::FlowyResult;
-use flowy_revision::{RevisionManager, RevisionPersistence, RevisionPersistenceConfiguration};
+use flowy_revision::{RevisionManager, RevisionPersistence};
 use flowy_sqlite::ConnectionPool;
-use grid_model::{FieldRevision, FilterRevision, RowChangeset, RowRevision, SortRevision};
+use grid_model::{
+    FieldRevision, FilterGroupRevision, FilterRevision, FilterSetRevision, LayoutRevision, RowChangeset, RowRevision,
+    SortModeRevision, SortRevision,
+};
 use lib_infra::future::Fut;
 use lib_infra::ref_map::RefCountHashMap;
+use revision_model::Revision;
 use std::borrow::Cow;
 use std::sync::Arc;
 use tokio::sync::{broadcast, RwLock};
 
 pub struct DatabaseViewManager {
     view_id: String,
-    user: Arc<dyn DatabaseUser>,
+    rev_stores: GridRevisionStores,
     delegate: Arc<dyn DatabaseViewEditorDelegate>,
     view_editors: Arc<RwLock<RefCountHashMap<Arc<DatabaseViewRevisionEditor>>>>,
     cell_data_cache: AtomicCellDataCache,
+    filter_results: Arc<FilterResultStore>,
 }
 
 impl DatabaseViewManager {
     pub async fn new(
         view_id: String,
-        user: Arc<dyn DatabaseUser>,
+        rev_stores: GridRevisionStores,
         delegate: Arc<dyn DatabaseViewEditorDelegate>,
         cell_data_cache: AtomicCellDataCache,
         block_event_rx: broadcast::Receiver<DatabaseBlockEvent>,
+        filter_results: Arc<FilterResultStore>,
     ) -> FlowyResult<Self> {
         let view_editors = Arc::new(RwLock::new(RefCountHashMap::default()));
         listen_on_database_block_event(block_event_rx, view_editors.clone());
         Ok(Self {
             view_id,
-            user,
+            rev_stores,
             delegate,
             cell_data_cache,
             view_editors,
+            filter_results,
         })
     }
 
+    /// Closes the view editor and waits until its pending revisions are persisted.
     pub async fn close(&self, view_id: &str) {
-        self.view_editors.write().await.remove(view_id).await;
+        let view_editor = self.view_editors.write().await.take(view_id);
+        if let Some(view_editor) = view_editor {
+            view_editor.close().await;
+        }
+    }
+
+    pub async fn flush(&self) -> FlowyResult<()> {
+        let view_editors = self.view_editors.read().await.values();
+        for view_editor in view_editors {
+            view_editor.flush().await?;
+        }
+        Ok(())
+    }
+
+    /// Closes all the view editors, so the filters, sorts and groups of the views will be rebuilt
+    /// from the latest data of the database next time. Returns the ids of the closed views.
+    pub async fn reload_views(&self) -> Vec<String> {
+        let view_editors = self.view_editors.write().await.drain();
+        let mut view_ids = Vec::with_capacity(view_editors.len());
+        for (view_id, view_editor) in view_editors {
+            view_editor.close().await;
+            view_ids.push(view_id);
+        }
+        view_ids
     }
 
     pub async fn subscribe_view_changed(&self, view_id: &str) -> FlowyResult<broadcast::Receiver<GridViewChanged>> {
@@ -101,12 +133,37 @@ impl DatabaseViewManager {
         }
     }
 
+    /// Notifies the views that the primary field is changed. The cards of the board views use the
+    /// primary field as their titles.
+    pub async fn did_update_primary_field(&self) {
+        for view_editor in self.view_editors.read().await.values() {
+            view_editor.did_update_primary_field().await;
+        }
+    }
+
     pub async fn group_by_field(&self, field_id: &str) -> FlowyResult<()> {
         let view_editor = self.get_default_view_editor().await?;
         view_editor.group_by_view_field(field_id).await?;
         Ok(())
     }
 
+    pub async fn update_date_group_condition(&self, params: UpdateDateGroupConditionParams) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(&params.view_id).await?;
+        view_editor.update_date_group_condition(params.condition).await
+    }
+
+    pub async fn update_number_group_ranges(&self, params: UpdateNumberGroupRangesParams) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(&params.view_id).await?;
+        view_editor.update_number_group_ranges(params).await
+    }
+
+    /// Removes the deleted rows from the groups, the changes of each group are notified at once.
+    pub async fn did_delete_rows(&self, row_revs: &[Arc<RowRevision>]) {
+        for view_editor in self.view_editors.read().await.values() {
+            view_editor.did_delete_view_rows(row_revs).await;
+        }
+    }
+
     pub async fn did_delete_row(&self, row_rev: Arc<RowRevision>) {
         for view_editor in self.view_editors.read().await.values() {
             view_editor.did_delete_view_row(&row_rev).await;
@@ -138,6 +195,52 @@ impl DatabaseViewManager {
         view_editor.delete_view_filter(params).await
     }
 
+    pub async fn get_filter_tree(&self, view_id: &str) -> FlowyResult<FilterGroupRevision> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        Ok(view_editor.get_view_filter_tree().await)
+    }
+
+    pub async fn update_filter_tree(&self, params: UpdateFilterTreeParams) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(&params.view_id).await?;
+        view_editor.update_view_filter_tree(params.filter_tree).await
+    }
+
+    pub async fn get_filter_sets(&self, view_id: &str) -> FlowyResult<Vec<FilterSetRevision>> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        Ok(view_editor.get_view_filter_sets().await)
+    }
+
+    pub async fn save_filter_set(&self, params: FilterSetParams) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(&params.view_id).await?;
+        view_editor.save_view_filter_set(&params.name).await
+    }
+
+    pub async fn apply_filter_set(&self, params: FilterSetParams) -> FlowyResult<Vec<FilterRevision>> {
+        let view_editor = self.get_view_editor(&params.view_id).await?;
+        view_editor.apply_view_filter_set(&params.name).await
+    }
+
+    pub async fn delete_filter_set(&self, params: FilterSetParams) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(&params.view_id).await?;
+        view_editor.delete_view_filter_set(&params.name).await
+    }
+
+    pub async fn set_search_query(&self, view_id: &str, query: Option<String>) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        view_editor.set_search_query(query).await;
+        Ok(())
+    }
+
+    pub async fn get_filter_evaluation_count(&self, view_id: &str) -> FlowyResult<usize> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        Ok(view_editor.get_filter_evaluation_count().await)
+    }
+
+    pub async fn get_sort_comparison_count(&self, view_id: &str) -> FlowyResult<usize> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        Ok(view_editor.get_sort_comparison_count().await)
+    }
+
     pub async fn get_all_sorts(&self, view_id: &str) -> FlowyResult<Vec<Arc<SortRevision>>> {
         let view_editor = self.get_view_editor(view_id).await?;
         Ok(view_editor.get_all_view_sorts().await)
@@ -158,9 +261,29 @@ impl DatabaseViewManager {
         view_editor.delete_view_sort(params).await
     }
 
-    pub async fn load_groups(&self) -> FlowyResult<RepeatedGroupPB> {
+    pub async fn get_sort_mode(&self, view_id: &str) -> FlowyResult<Option<SortModeRevision>> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        Ok(view_editor.get_sort_mode().await)
+    }
+
+    pub async fn update_sort_mode(&self, params: UpdateSortModeParams) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(&params.view_id).await?;
+        view_editor.update_sort_mode(params.sort_mode).await
+    }
+
+    pub async fn resort_shuffle(&self, view_id: &str) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        view_editor.resort_shuffle().await
+    }
+
+    pub async fn reorder_sort(&self, params: ReorderSortParams) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(&params.view_id).await?;
+        view_editor.reorder_view_sort(params).await
+    }
+
+    pub async fn load_groups(&self, include_hidden: bool) -> FlowyResult<RepeatedGroupPB> {
         let view_editor = self.get_default_view_editor().await?;
-        let groups = view_editor.load_view_groups().await?;
+        let groups = view_editor.load_view_groups(include_hidden).await?;
         Ok(RepeatedGroupPB { items: groups })
     }
 
@@ -180,12 +303,46 @@ impl DatabaseViewManager {
         Ok(())
     }
 
+    /// Moves the group to the index in the visible groups of the view. Returns the id of the
+    /// grouping field and the ids of all the groups in the new order.
+    pub async fn move_group_to_index(&self, params: &MoveGroupToIndexParams) -> FlowyResult<(String, Vec<String>)> {
+        let view_editor = self.get_view_editor(&params.view_id).await?;
+        let group_ids = view_editor
+            .move_view_group_to_index(&params.from_group_id, params.to_index)
+            .await?;
+        Ok((view_editor.group_id().await, group_ids))
+    }
+
+    /// Returns the id of the field that the rows of the view are grouped by.
+    pub async fn group_field_id(&self, view_id: &str) -> FlowyResult<String> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        Ok(view_editor.group_id().await)
+    }
+
+    pub async fn did_create_group(&self, view_id: &str, group_id: &str) -> FlowyResult<GroupPB> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        view_editor.did_create_group(group_id).await
+    }
+
+    pub async fn set_group_visibility(&self, params: SetGroupVisibilityParams) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(&params.view_id).await?;
+        view_editor.set_group_visibility(&params.group_id, params.visible).await
+    }
+
+    pub async fn set_group_collapsed(&self, params: SetGroupCollapsedParams) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(&params.view_id).await?;
+        view_editor
+            .set_group_collapsed(&params.group_id, params.collapsed)
+            .await
+    }
+
     /// It may generate a RowChangeset when the Row was moved from one group to another.
     /// The return value, [RowChangeset], contains the changes made by the groups.
     ///
     pub async fn move_group_row(
         &self,
         row_rev: Arc<RowRevision>,
+        from_group_id: Option<String>,
         to_group_id: String,
         to_row_id: Option<String>,
         recv_row_changeset: impl FnOnce(RowChangeset) -> Fut<()>,
@@ -193,7 +350,13 @@ impl DatabaseViewManager {
         let mut row_changeset = RowChangeset::new(row_rev.id.clone());
         let view_editor = self.get_default_view_editor().await?;
         view_editor
-            .move_view_group_row(&row_rev, &mut row_changeset, &to_group_id, to_row_id.clone())
+            .move_view_group_row(
+                &row_rev,
+                &mut row_changeset,
+                from_group_id.as_deref(),
+                &to_group_id,
+                to_row_id.clone(),
+            )
             .await;
 
         if !row_changeset.is_empty() {
@@ -216,17 +379,148 @@ impl DatabaseViewManager {
         field_id: &str,
         old_field_rev: Option<Arc<FieldRevision>>,
     ) -> FlowyResult<()> {
-        let view_editor = self.get_default_view_editor().await?;
-        if view_editor.group_id().await == field_id {
-            view_editor.group_by_view_field(field_id).await?;
+        // Every opened view that is grouped by the field is regrouped, so the groups of the options
+        // that are inserted into the field can be found in any of them.
+        let _ = self.get_default_view_editor().await?;
+        let view_editors = self.view_editors.read().await.values();
+        for view_editor in view_editors {
+            if view_editor.group_id().await == field_id {
+                view_editor.group_by_view_field(field_id).await?;
+            }
+
+            view_editor
+                .did_update_view_field_type_option(field_id, old_field_rev.clone())
+                .await?;
         }
+        Ok(())
+    }
 
-        view_editor
-            .did_update_view_field_type_option(field_id, old_field_rev)
-            .await?;
+    /// Returns the fields with the field settings of the view applied.
+    pub async fn get_view_field_revs(
+        &self,
+        view_id: &str,
+        field_revs: Vec<Arc<FieldRevision>>,
+    ) -> FlowyResult<Vec<Arc<FieldRevision>>> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        Ok(view_editor.get_view_field_revs(field_revs).await)
+    }
+
+    pub async fn set_field_visibility(&self, view_id: &str, field_id: &str, visibility: bool) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        view_editor.set_field_visibility(field_id, visibility).await
+    }
+
+    pub async fn set_field_width(&self, view_id: &str, field_id: &str, width: i32) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        view_editor.set_field_width(field_id, width).await
+    }
+
+    /// Removes the filters, sorts and settings of the deleted field from the opened views. The other
+    /// views remove them when they are opened.
+    pub async fn did_delete_field(&self, field_id: &str) {
+        for view_editor in self.view_editors.read().await.values() {
+            if let Err(e) = view_editor.did_delete_field(field_id).await {
+                tracing::error!("Remove the settings of the field:{} failed: {:?}", field_id, e);
+            }
+        }
+    }
+
+    /// Removes the filters, sorts, groups and settings of the deleted fields from every view of the
+    /// database. The views that are not opened are changed in their revisions directly.
+    pub async fn did_delete_fields(&self, field_revs: &[Arc<FieldRevision>]) {
+        let (view_editors, opened_view_ids) = {
+            let view_editors = self.view_editors.read().await;
+            (view_editors.values(), view_editors.ref_counts())
+        };
+        for view_editor in view_editors {
+            if let Err(e) = view_editor.did_delete_fields(field_revs).await {
+                tracing::error!("Remove the objects of the deleted fields failed: {:?}", e);
+            }
+        }
+
+        let view_ids = match self.rev_stores.factory().read_view_ids() {
+            Ok(view_ids) => view_ids,
+            Err(e) => {
+                tracing::error!("Read the views of the database failed: {:?}", e);
+                return;
+            }
+        };
+        let field_ids = field_revs
+            .iter()
+            .map(|field_rev| field_rev.id.clone())
+            .collect::<Vec<String>>();
+        for view_id in view_ids {
+            if opened_view_ids.contains_key(&view_id) {
+                continue;
+            }
+            if let Err(e) = self.delete_field_objects_of_closed_view(&view_id, &field_ids).await {
+                tracing::error!(
+                    "Remove the objects of the deleted fields from {} failed: {:?}",
+                    view_id,
+                    e
+                );
+            }
+        }
+    }
+
+    /// Removes the objects of the fields from the revisions of the view that is not opened. The
+    /// views of the other databases are skipped.
+    async fn delete_field_objects_of_closed_view(&self, view_id: &str, field_ids: &[String]) -> FlowyResult<()> {
+        let mut rev_manager = make_database_view_rev_manager(&self.rev_stores, view_id).await?;
+        let mut pad = rev_manager.initialize::<GridViewRevisionSerde>(None).await?;
+        let rev_manager = Arc::new(rev_manager);
+        if pad.grid_id == self.view_id {
+            if let Some(changeset) = pad.delete_field_objects(field_ids)? {
+                let user_id = self.rev_stores.user().user_id()?;
+                apply_change(&user_id, rev_manager.clone(), changeset).await?;
+            }
+        }
+        rev_manager.close().await;
         Ok(())
     }
 
+    pub async fn get_effective_row_order(&self, view_id: &str) -> FlowyResult<EffectiveRowOrderPB> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        Ok(view_editor.get_effective_row_order().await)
+    }
+
+    pub async fn move_row_in_manual_order(&self, params: &MoveRowParams) -> FlowyResult<bool> {
+        let view_editor = self.get_view_editor(&params.view_id).await?;
+        view_editor
+            .move_row_in_manual_order(&params.from_row_id, &params.to, params.remove_sorts)
+            .await
+    }
+
+    pub async fn pin_row(&self, view_id: &str, row_id: &str) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        view_editor.pin_row(row_id).await
+    }
+
+    pub async fn unpin_row(&self, view_id: &str, row_id: &str) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        view_editor.unpin_row(row_id).await
+    }
+
+    pub async fn get_pinned_row_ids(&self, view_id: &str) -> FlowyResult<Vec<String>> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        Ok(view_editor.get_pinned_row_ids().await)
+    }
+
+    pub async fn visible_row_count(&self, view_id: &str) -> FlowyResult<usize> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        Ok(view_editor.visible_row_count().await)
+    }
+
+    pub async fn set_frozen_field_count(&self, view_id: &str, count: usize) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        view_editor.set_frozen_field_count(count).await
+    }
+
+    pub async fn set_view_field_order(&self, view_id: &str, field_ids: Vec<String>) -> FlowyResult<()> {
+        let view_editor = self.get_view_editor(view_id).await?;
+        view_editor.set_view_field_order(field_ids).await
+    }
+
     pub async fn get_view_editor(&self, view_id: &str) -> FlowyResult<Arc<DatabaseViewRevisionEditor>> {
         debug_assert!(!view_id.is_empty());
         if let Some(editor) = self.view_editors.read().await.get(view_id) {
@@ -244,9 +538,22 @@ impl DatabaseViewManager {
     }
 
     async fn make_view_editor(&self, view_id: &str) -> FlowyResult<DatabaseViewRevisionEditor> {
-        let rev_manager = make_database_view_rev_manager(&self.user, view_id).await?;
-        let user_id = self.user.user_id()?;
-        let token = self.user.token()?;
+        let user_id = self.rev_stores.user().user_id()?;
+        // The view that is opened for the first time records the database it belongs to, so the
+        // view can be found when the database changes while the view is not opened.
+        let rev_store = self.rev_stores.make_store(GridRevisionObject::View, view_id)?;
+        if rev_store.read_stats()?.number_of_revisions == 0 {
+            let view = GridViewRevisionPad::new(self.view_id.clone(), view_id.to_owned(), LayoutRevision::Grid);
+            let revision = Revision::initial_revision(view_id, make_grid_view_operations(&view).json_bytes());
+            self.rev_stores.factory().reset_objects(
+                &user_id,
+                vec![(GridRevisionObject::View, revision)],
+                self.rev_stores.options()?,
+            )?;
+        }
+
+        let rev_manager = make_database_view_rev_manager(&self.rev_stores, view_id).await?;
+        let token = self.rev_stores.user().token()?;
         let view_id = view_id.to_owned();
 
         DatabaseViewRevisionEditor::new(
@@ -256,6 +563,7 @@ impl DatabaseViewManager {
             self.delegate.clone(),
             self.cell_data_cache.clone(),
             rev_manager,
+            self.filter_results.clone(),
         )
         .await
     }
@@ -283,20 +591,19 @@ fn listen_on_database_block_event(
     });
 }
 pub async fn make_database_view_rev_manager(
-    user: &Arc<dyn DatabaseUser>,
+    rev_stores: &GridRevisionStores,
     view_id: &str,
 ) -> FlowyResult<RevisionManager<Arc<ConnectionPool>>> {
-    let user_id = user.user_id()?;
+    let user_id = rev_stores.user().user_id()?;
+    let rev_store = rev_stores.make_store(GridRevisionObject::View, view_id)?;
 
     // Create revision persistence
-    let pool = user.db_pool()?;
-    let disk_cache = SQLiteGridViewRevisionPersistence::new(&user_id, pool.clone());
-    let configuration = RevisionPersistenceConfiguration::new(2, false);
-    let rev_persistence = RevisionPersistence::new(&user_id, view_id, disk_cache, configuration);
+    let configuration = rev_stores.rev_compress().view_configuration();
+    let rev_persistence =
+        RevisionPersistence::from_disk_cache(&user_id, view_id, rev_store.revision_disk_cache(), configuration);
 
     // Create snapshot persistence
-    let snapshot_object_id = format!("grid_view:{}", view_id);
-    let snapshot_persistence = SQLiteDatabaseRevisionSnapshotPersistence::new(&snapshot_object_id, pool);
+    let snapshot_persistence = rev_store.snapshot_disk_cache();
 
     let rev_compress = GridViewRevisionMergeable();
     Ok(RevisionManager::new(
@@ -14,7 +14,8 @@ use flowy_revision::{
 };
 use flowy_sqlite::ConnectionPool;
 use grid_model::{
-    FieldRevision, FieldTypeRevision, FilterRevision, GroupConfigurationRevision, RowRevision, SortRevision,
+    apply_manual_row_order, FieldRevision, FieldTypeRevision, FilterGroupRevision, FilterRevision,
+    GroupConfigurationRevision, RowRevision, SortModeRevision, SortRevision,
 };
 use lib_infra::future::{to_fut, Fut, FutureResult};
 use lib_ot::core::EmptyAttributes;
@@ -136,12 +137,27 @@ pub fn make_grid_setting(view_pad: &GridViewRevisionPad, field_revs: &[Arc<Field
     let filters = view_pad.get_all_filters(field_revs);
     let group_configurations = view_pad.get_groups_by_field_revs(field_revs);
     let sorts = view_pad.get_all_sorts(field_revs);
+    // The fields may be hidden or deleted after they are frozen.
+    let frozen_field_count = match view_pad.get_field_settings() {
+        None => 0,
+        Some(field_settings) => {
+            let visible_field_count = field_revs
+                .iter()
+                .filter(|field_rev| field_settings.is_visible(&field_rev.id, field_rev.visibility))
+                .count();
+            std::cmp::min(field_settings.frozen_field_count, visible_field_count)
+        }
+    };
     DatabaseViewSettingPB {
         layouts: ViewLayoutConfigPB::all(),
         layout_type,
         filters: filters.into(),
         sorts: sorts.into(),
         group_configurations: group_configurations.into(),
+        frozen_field_count: frozen_field_count as i32,
+        pinned_row_ids: view_pad.get_pinned_row_ids().to_vec(),
+        filter_tree: (&view_pad.get_filter_tree()).into(),
+        sort_mode: view_pad.get_sort_mode().into(),
     }
 }
 
@@ -165,6 +181,11 @@ impl FilterDelegate for GridViewFilterDelegateImpl {
         })
     }
 
+    fn get_filter_tree(&self) -> Fut<FilterGroupRevision> {
+        let pad = self.view_revision_pad.clone();
+        to_fut(async move { pad.read().await.get_filter_tree() })
+    }
+
     fn get_field_rev(&self, field_id: &str) -> Fut<Option<Arc<FieldRevision>>> {
         self.editor_delegate.get_field_rev(field_id)
     }
@@ -207,13 +228,27 @@ impl SortDelegate for GridViewSortDelegateImpl {
     fn get_row_revs(&self) -> Fut<Vec<Arc<RowRevision>>> {
         let filter_controller = self.filter_controller.clone();
         let editor_delegate = self.editor_delegate.clone();
+        let pad = self.view_revision_pad.clone();
         to_fut(async move {
             let mut row_revs = editor_delegate.get_row_revs(None).await;
             filter_controller.write().await.filter_row_revs(&mut row_revs).await;
+            apply_manual_row_order(pad.read().await.get_manual_row_order(), &mut row_revs, |row_rev| {
+                row_rev.id.as_str()
+            });
             row_revs
         })
     }
 
+    fn get_pinned_row_ids(&self) -> Fut<Vec<String>> {
+        let pad = self.view_revision_pad.clone();
+        to_fut(async move { pad.read().await.get_pinned_row_ids().to_vec() })
+    }
+
+    fn get_sort_mode(&self) -> Fut<Option<SortModeRevision>> {
+        let pad = self.view_revision_pad.clone();
+        to_fut(async move { pad.read().await.get_sort_mode() })
+    }
+
     fn get_field_rev(&self, field_id: &str) -> Fut<Option<Arc<FieldRevision>>> {
         self.editor_delegate.get_field_rev(field_id)
     }
@@ -0,0 +1,224 @@
+use crate::grid::database_editor::DatabaseEditorTest;
+use flowy_database::entities::{CellPathParams, FieldType};
+use flowy_database::services::field::{
+    AttachmentCellChangeset, AttachmentCellDataParser, AttachmentPB, AttachmentStore, StoredFile,
+};
+use flowy_error::{FlowyError, FlowyResult};
+use lib_infra::async_trait::async_trait;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Pretends to copy the files into the app's storage directory. Only the files that are passed to
+/// `new` can be imported.
+pub struct MockAttachmentStore {
+    file_sizes: HashMap<String, i64>,
+    deleted_ids: Mutex<Vec<String>>,
+}
+
+impl MockAttachmentStore {
+    pub fn new(files: &[(&str, i64)]) -> Self {
+        let file_sizes = files
+            .iter()
+            .map(|(source_path, size)| (source_path.to_string(), *size))
+            .collect();
+        Self {
+            file_sizes,
+            deleted_ids: Mutex::new(vec![]),
+        }
+    }
+}
+
+#[async_trait]
+impl AttachmentStore for MockAttachmentStore {
+    async fn import_file(&self, attachment_id: &str, source_path: &str) -> FlowyResult<StoredFile> {
+        match self.file_sizes.get(source_path) {
+            None => Err(FlowyError::record_not_found().context(format!("Can't find the file: {}", source_path))),
+            Some(size) => Ok(StoredFile {
+                path: format!("attachments/{}", attachment_id),
+                size: *size,
+            }),
+        }
+    }
+
+    async fn delete_file(&self, attachment: &AttachmentPB) -> FlowyResult<()> {
+        self.deleted_ids.lock().push(attachment.id.clone());
+        Ok(())
+    }
+}
+
+pub enum AttachmentScript {
+    ImportAttachment {
+        row_index: usize,
+        source_path: String,
+        name: String,
+    },
+    /// Imports the file into the cell of the field with the `field_type`, it's expected to fail.
+    ImportAttachmentError {
+        row_index: usize,
+        field_type: FieldType,
+        source_path: String,
+    },
+    /// Inserts the imported attachment into another cell, so it's referenced by both cells.
+    InsertAttachment {
+        row_index: usize,
+        attachment_index: usize,
+    },
+    RemoveAttachment {
+        row_index: usize,
+        attachment_index: usize,
+    },
+    DeleteRow {
+        row_index: usize,
+    },
+    /// Permanently deletes all the rows in the trash.
+    PurgeTrash,
+    DeleteAttachmentField,
+    AssertAttachmentNames {
+        row_index: usize,
+        expected: Vec<String>,
+    },
+    /// Asserts the files of the imported attachments with the indexes are deleted.
+    AssertDeletedFiles(Vec<usize>),
+    AssertAttachmentBytes(usize),
+}
+
+pub struct DatabaseAttachmentTest {
+    inner: DatabaseEditorTest,
+    store: Arc<MockAttachmentStore>,
+    imported_attachments: Vec<AttachmentPB>,
+}
+
+impl DatabaseAttachmentTest {
+    pub async fn new(files: &[(&str, i64)]) -> Self {
+        let editor_test = DatabaseEditorTest::new_table().await;
+        let store = Arc::new(MockAttachmentStore::new(files));
+        editor_test.sdk.grid_manager.set_attachment_store(Some(store.clone()));
+        Self {
+            inner: editor_test,
+            store,
+            imported_attachments: vec![],
+        }
+    }
+
+    fn cell_path(&self, row_index: usize, field_type: FieldType) -> CellPathParams {
+        CellPathParams {
+            database_id: self.view_id.clone(),
+            field_id: self.get_first_field_rev(field_type).id.clone(),
+            row_id: self.row_revs[row_index].id.clone(),
+        }
+    }
+
+    pub async fn run_scripts(&mut self, scripts: Vec<AttachmentScript>) {
+        for script in scripts {
+            self.run_script(script).await;
+        }
+    }
+
+    pub async fn run_script(&mut self, script: AttachmentScript) {
+        match script {
+            AttachmentScript::ImportAttachment {
+                row_index,
+                source_path,
+                name,
+            } => {
+                let cell_path = self.cell_path(row_index, FieldType::Attachment);
+                let attachment = self
+                    .editor
+                    .import_attachment(&cell_path.row_id, &cell_path.field_id, &source_path, &name, "image/png")
+                    .await
+                    .unwrap();
+                assert!(attachment.is_local());
+                self.imported_attachments.push(attachment);
+            }
+            AttachmentScript::ImportAttachmentError {
+                row_index,
+                field_type,
+                source_path,
+            } => {
+                let cell_path = self.cell_path(row_index, field_type);
+                let result = self
+                    .editor
+                    .import_attachment(&cell_path.row_id, &cell_path.field_id, &source_path, "", "image/png")
+                    .await;
+                assert!(result.is_err());
+            }
+            AttachmentScript::InsertAttachment {
+                row_index,
+                attachment_index,
+            } => {
+                let cell_path = self.cell_path(row_index, FieldType::Attachment);
+                let attachment = self.imported_attachments[attachment_index].clone();
+                let changeset = AttachmentCellChangeset::from_insert_attachments(vec![attachment]);
+                self.editor
+                    .update_cell(cell_path.row_id, cell_path.field_id, changeset)
+                    .await
+                    .unwrap();
+            }
+            AttachmentScript::RemoveAttachment {
+                row_index,
+                attachment_index,
+            } => {
+                let cell_path = self.cell_path(row_index, FieldType::Attachment);
+                let attachment_id = self.imported_attachments[attachment_index].id.clone();
+                let changeset = AttachmentCellChangeset::from_delete_attachment_ids(vec![attachment_id]);
+                self.editor
+                    .update_cell(cell_path.row_id, cell_path.field_id, changeset)
+                    .await
+                    .unwrap();
+            }
+            AttachmentScript::DeleteRow { row_index } => {
+                let row_id = self.row_revs[row_index].id.clone();
+                self.editor.delete_row(&row_id).await.unwrap();
+            }
+            AttachmentScript::PurgeTrash => {
+                self.editor.purge_trash(i64::MAX).await.unwrap();
+            }
+            AttachmentScript::DeleteAttachmentField => {
+                let field_id = self.get_first_field_rev(FieldType::Attachment).id.clone();
+                self.editor.delete_field(&field_id).await.unwrap();
+            }
+            AttachmentScript::AssertAttachmentNames { row_index, expected } => {
+                let cell_path = self.cell_path(row_index, FieldType::Attachment);
+                let cell_data = self
+                    .editor
+                    .get_cell_protobuf(&cell_path)
+                    .await
+                    .unwrap()
+                    .parser::<AttachmentCellDataParser>()
+                    .unwrap();
+                let names = cell_data
+                    .attachments
+                    .into_iter()
+                    .map(|attachment| attachment.name)
+                    .collect::<Vec<String>>();
+                assert_eq!(names, expected);
+            }
+            AttachmentScript::AssertDeletedFiles(attachment_indexes) => {
+                let expected = attachment_indexes
+                    .into_iter()
+                    .map(|attachment_index| self.imported_attachments[attachment_index].id.clone())
+                    .collect::<Vec<String>>();
+                assert_eq!(*self.store.deleted_ids.lock(), expected);
+            }
+            AttachmentScript::AssertAttachmentBytes(expected) => {
+                let report = self.sdk.grid_manager.storage_report().await.unwrap();
+                assert_eq!(report.attachment_bytes, expected);
+            }
+        }
+    }
+}
+
+impl std::ops::Deref for DatabaseAttachmentTest {
+    type Target = DatabaseEditorTest;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl std::ops::DerefMut for DatabaseAttachmentTest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
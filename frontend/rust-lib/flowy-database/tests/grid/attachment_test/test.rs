@@ -0,0 +1,147 @@
+use crate::grid::attachment_test::script::{AttachmentScript::*, DatabaseAttachmentTest};
+use flowy_database::entities::FieldType;
+
+const FILES: [(&str, i64); 2] = [("/tmp/photo.png", 120), ("/tmp/screenshot.png", 300)];
+
+#[tokio::test]
+async fn import_attachment_test() {
+    let mut test = DatabaseAttachmentTest::new(&FILES).await;
+    let scripts = vec![
+        // The file name is used if the name is empty.
+        ImportAttachment {
+            row_index: 2,
+            source_path: "/tmp/photo.png".to_owned(),
+            name: "".to_owned(),
+        },
+        ImportAttachment {
+            row_index: 2,
+            source_path: "/tmp/screenshot.png".to_owned(),
+            name: "Screenshot".to_owned(),
+        },
+        AssertAttachmentNames {
+            row_index: 2,
+            expected: vec!["photo.png".to_owned(), "Screenshot".to_owned()],
+        },
+        AssertAttachmentBytes(420),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn import_attachment_error_test() {
+    let mut test = DatabaseAttachmentTest::new(&FILES).await;
+    let scripts = vec![
+        ImportAttachmentError {
+            row_index: 2,
+            field_type: FieldType::RichText,
+            source_path: "/tmp/photo.png".to_owned(),
+        },
+        ImportAttachmentError {
+            row_index: 2,
+            field_type: FieldType::Attachment,
+            source_path: "/tmp/unknown.png".to_owned(),
+        },
+        AssertAttachmentNames {
+            row_index: 2,
+            expected: vec![],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn remove_attachment_deletes_file_test() {
+    let mut test = DatabaseAttachmentTest::new(&FILES).await;
+    let scripts = vec![
+        ImportAttachment {
+            row_index: 0,
+            source_path: "/tmp/photo.png".to_owned(),
+            name: "".to_owned(),
+        },
+        AssertAttachmentNames {
+            row_index: 0,
+            expected: vec!["logo.png".to_owned(), "guide.pdf".to_owned(), "photo.png".to_owned()],
+        },
+        RemoveAttachment {
+            row_index: 0,
+            attachment_index: 0,
+        },
+        AssertAttachmentNames {
+            row_index: 0,
+            expected: vec!["logo.png".to_owned(), "guide.pdf".to_owned()],
+        },
+        AssertDeletedFiles(vec![0]),
+        AssertAttachmentBytes(0),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn purge_deleted_row_deletes_attachment_file_test() {
+    let mut test = DatabaseAttachmentTest::new(&FILES).await;
+    let scripts = vec![
+        ImportAttachment {
+            row_index: 3,
+            source_path: "/tmp/photo.png".to_owned(),
+            name: "".to_owned(),
+        },
+        DeleteRow { row_index: 0 },
+        AssertDeletedFiles(vec![]),
+        // The file is kept until the deleted row is purged from the trash.
+        DeleteRow { row_index: 3 },
+        AssertDeletedFiles(vec![]),
+        AssertAttachmentBytes(120),
+        PurgeTrash,
+        AssertDeletedFiles(vec![0]),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn shared_attachment_file_deleted_with_last_cell_test() {
+    let mut test = DatabaseAttachmentTest::new(&FILES).await;
+    let scripts = vec![
+        ImportAttachment {
+            row_index: 3,
+            source_path: "/tmp/photo.png".to_owned(),
+            name: "".to_owned(),
+        },
+        InsertAttachment {
+            row_index: 4,
+            attachment_index: 0,
+        },
+        // The file that is referenced by two cells is counted once.
+        AssertAttachmentBytes(120),
+        RemoveAttachment {
+            row_index: 3,
+            attachment_index: 0,
+        },
+        AssertDeletedFiles(vec![]),
+        DeleteRow { row_index: 4 },
+        AssertDeletedFiles(vec![]),
+        PurgeTrash,
+        AssertDeletedFiles(vec![0]),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn delete_field_deletes_attachment_files_test() {
+    let mut test = DatabaseAttachmentTest::new(&FILES).await;
+    let scripts = vec![
+        ImportAttachment {
+            row_index: 3,
+            source_path: "/tmp/photo.png".to_owned(),
+            name: "".to_owned(),
+        },
+        ImportAttachment {
+            row_index: 4,
+            source_path: "/tmp/screenshot.png".to_owned(),
+            name: "".to_owned(),
+        },
+        DeleteAttachmentField,
+        AssertDeletedFiles(vec![0, 1]),
+        AssertAttachmentBytes(0),
+    ];
+    test.run_scripts(scripts).await;
+}
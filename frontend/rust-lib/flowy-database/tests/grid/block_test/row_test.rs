@@ -1,9 +1,10 @@
 use crate::grid::block_test::script::RowScript::*;
-use crate::grid::block_test::script::{CreateRowScriptBuilder, DatabaseRowTest};
+use crate::grid::block_test::script::{CreateRowScriptBuilder, DatabaseRowTest, ExpectedRowChange, RowScript};
 use crate::grid::mock_data::{COMPLETED, FACEBOOK, GOOGLE, PAUSED, TWITTER};
-use flowy_database::entities::FieldType;
-use flowy_database::services::field::{SELECTION_IDS_SEPARATOR, UNCHECK};
-use grid_model::RowChangeset;
+use flowy_database::entities::{CellChangePB, FieldType, MoveRowTarget, RowDataPayload};
+use flowy_database::services::block_manager::MAX_ROWS_PER_BLOCK;
+use flowy_database::services::field::{CHECK, SELECTION_IDS_SEPARATOR, TODAY_DEFAULT_VALUE, UNCHECK};
+use grid_model::{RowChangeset, RowMetaChangeset, RowMetaRevision};
 
 #[tokio::test]
 async fn grid_create_row_count_test() {
@@ -29,6 +30,9 @@ async fn grid_update_row() {
         height: None,
         visibility: None,
         cell_by_field_id: Default::default(),
+        modified_at: None,
+        meta: None,
+        document_id: None,
     };
     let row_count = test.row_revs.len();
     let scripts = vec![CreateRow { row_rev }, UpdateRow { changeset }];
@@ -110,6 +114,45 @@ async fn grid_row_insert_date_test() {
         test.run_scripts(scripts).await;
     }
 }
+
+#[tokio::test]
+async fn grid_row_insert_progress_test() {
+    let mut test = DatabaseRowTest::new().await;
+    for (val, expected) in &[("0.45", "45%"), ("45%", "45%"), ("150%", "100%"), ("", "")] {
+        let mut builder = CreateRowScriptBuilder::new(&test);
+        builder.insert(FieldType::Progress, val, expected);
+        let scripts = builder.build();
+        test.run_scripts(scripts).await;
+    }
+}
+
+#[tokio::test]
+async fn grid_row_insert_email_test() {
+    let mut test = DatabaseRowTest::new().await;
+    for (val, expected) in &[(" Lucas@AppFlowy.io ", "Lucas@AppFlowy.io"), ("", "")] {
+        let mut builder = CreateRowScriptBuilder::new(&test);
+        builder.insert(FieldType::Email, val, expected);
+        let scripts = builder.build();
+        test.run_scripts(scripts).await;
+    }
+}
+
+#[tokio::test]
+async fn grid_row_insert_phone_number_test() {
+    let mut test = DatabaseRowTest::new().await;
+    for (val, expected) in &[
+        ("(555) 123-4567", "+1 555 123 4567"),
+        ("+1 555.123.4567", "+1 555 123 4567"),
+        ("+44 20 7946 0958", "+44 207 946 0958"),
+        ("", ""),
+    ] {
+        let mut builder = CreateRowScriptBuilder::new(&test);
+        builder.insert(FieldType::PhoneNumber, val, expected);
+        let scripts = builder.build();
+        test.run_scripts(scripts).await;
+    }
+}
+
 #[tokio::test]
 async fn grid_row_insert_single_select_test() {
     let mut test = DatabaseRowTest::new().await;
@@ -133,3 +176,894 @@ async fn grid_row_insert_multi_select_test() {
     let scripts = builder.build();
     test.run_scripts(scripts).await;
 }
+
+fn text_cell_change(test: &DatabaseRowTest, before: &str, after: &str) -> CellChangePB {
+    CellChangePB {
+        field_id: test.get_first_field_rev(FieldType::RichText).id.clone(),
+        before: before.to_owned(),
+        after: after.to_owned(),
+    }
+}
+
+#[tokio::test]
+async fn grid_row_history_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let row_rev = test.row_builder().build();
+    let row_id = row_rev.id.clone();
+    let scripts = vec![
+        ReopenWithMergeThreshold(1),
+        CreateRow { row_rev },
+        UpdateTextCell {
+            row_id: row_id.clone(),
+            content: "hello".to_owned(),
+        },
+        UpdateTextCell {
+            row_id: row_id.clone(),
+            content: "world".to_owned(),
+        },
+        AssertRowHistory {
+            row_id,
+            before_rev_id: None,
+            limit: 10,
+            expected: vec![
+                ExpectedRowChange {
+                    is_created: false,
+                    is_deleted: false,
+                    cells: vec![text_cell_change(&test, "hello", "world")],
+                },
+                ExpectedRowChange {
+                    is_created: false,
+                    is_deleted: false,
+                    cells: vec![text_cell_change(&test, "", "hello")],
+                },
+                ExpectedRowChange {
+                    is_created: true,
+                    is_deleted: false,
+                    cells: vec![],
+                },
+            ],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_deleted_row_history_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let mut builder = test.row_builder();
+    builder.insert_text_cell("hello");
+    let row_rev = builder.build();
+    let row_id = row_rev.id.clone();
+    let scripts = vec![
+        ReopenWithMergeThreshold(1),
+        CreateRow { row_rev },
+        DeleteRows {
+            row_ids: vec![row_id.clone()],
+        },
+        AssertRowHistory {
+            row_id,
+            before_rev_id: None,
+            limit: 1,
+            expected: vec![ExpectedRowChange {
+                is_created: false,
+                is_deleted: true,
+                cells: vec![text_cell_change(&test, "hello", "")],
+            }],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_row_history_pagination_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let row_rev = test.row_builder().build();
+    let row_id = row_rev.id.clone();
+    let mut scripts = vec![ReopenWithMergeThreshold(1), CreateRow { row_rev }];
+    for content in ["a", "b", "c", "d"] {
+        scripts.push(UpdateTextCell {
+            row_id: row_id.clone(),
+            content: content.to_owned(),
+        });
+    }
+    test.run_scripts(scripts).await;
+
+    let latest_changes = test.editor.get_row_history(&row_id, None, 2).await.unwrap();
+    assert_eq!(latest_changes.len(), 2);
+    let before_rev_id = latest_changes.last().map(|row_change| row_change.rev_id);
+    let scripts = vec![AssertRowHistory {
+        row_id,
+        before_rev_id,
+        limit: 10,
+        expected: vec![
+            ExpectedRowChange {
+                is_created: false,
+                is_deleted: false,
+                cells: vec![text_cell_change(&test, "a", "b")],
+            },
+            ExpectedRowChange {
+                is_created: false,
+                is_deleted: false,
+                cells: vec![text_cell_change(&test, "", "a")],
+            },
+            ExpectedRowChange {
+                is_created: true,
+                is_deleted: false,
+                cells: vec![],
+            },
+        ],
+    }];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_restore_deleted_row_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let mut builder = test.row_builder();
+    builder.insert_text_cell("hello");
+    let row_rev = builder.build();
+    let row_id = row_rev.id.clone();
+    let row_count = test.row_revs.len();
+    let scripts = vec![
+        ReopenWithMergeThreshold(1),
+        CreateRow { row_rev },
+        DeleteRows {
+            row_ids: vec![row_id.clone()],
+        },
+        AssertRowCount(row_count),
+        RestoreRow { row_id: row_id.clone() },
+        AssertRowCount(row_count + 1),
+        AssertCell {
+            row_id,
+            field_id: test.get_first_field_rev(FieldType::RichText).id.clone(),
+            field_type: FieldType::RichText,
+            expected: "hello".to_owned(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_restore_unknown_row_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let scripts = vec![AssertRestoreRowNotFound {
+        row_id: "unknown row".to_owned(),
+    }];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_create_row_with_default_values_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let date_field_id = test.get_first_field_rev(FieldType::DateTime).id.clone();
+    let checkbox_field_id = test.get_first_field_rev(FieldType::Checkbox).id.clone();
+    let single_select_field_id = test.get_first_field_rev(FieldType::SingleSelect).id.clone();
+    let multi_select_field_id = test.get_first_field_rev(FieldType::MultiSelect).id.clone();
+    let url_field_id = test.get_first_field_rev(FieldType::URL).id.clone();
+    let single_select_option = test.get_single_select_type_option(&single_select_field_id).options[0].clone();
+    let multi_select_option_ids = test
+        .get_multi_select_type_option(&multi_select_field_id)
+        .iter()
+        .take(2)
+        .map(|option| option.id.clone())
+        .collect::<Vec<String>>();
+
+    let set_default_value = |field_id: &str, default_value: &str| SetFieldDefaultValue {
+        field_id: field_id.to_owned(),
+        default_value: Some(default_value.to_owned()),
+        is_err: false,
+    };
+    let scripts = vec![
+        set_default_value(&text_field_id, "hello"),
+        set_default_value(&number_field_id, "5"),
+        set_default_value(&date_field_id, "1647251762"),
+        set_default_value(&checkbox_field_id, "Yes"),
+        set_default_value(&single_select_field_id, &single_select_option.id),
+        set_default_value(
+            &multi_select_field_id,
+            &multi_select_option_ids.join(SELECTION_IDS_SEPARATOR),
+        ),
+        // The value that can't be converted to a cell is rejected.
+        SetFieldDefaultValue {
+            field_id: number_field_id.clone(),
+            default_value: Some("abc".to_owned()),
+            is_err: true,
+        },
+        SetFieldDefaultValue {
+            field_id: single_select_field_id.clone(),
+            default_value: Some("not an option".to_owned()),
+            is_err: true,
+        },
+        // The url field doesn't support the default value.
+        SetFieldDefaultValue {
+            field_id: url_field_id,
+            default_value: Some("https://appflowy.io".to_owned()),
+            is_err: true,
+        },
+        CreateEmptyRow,
+    ];
+    test.run_scripts(scripts).await;
+
+    let row_id = test.last_row().unwrap().id;
+    let scripts = vec![
+        AssertCell {
+            row_id: row_id.clone(),
+            field_id: text_field_id.clone(),
+            field_type: FieldType::RichText,
+            expected: "hello".to_owned(),
+        },
+        AssertCell {
+            row_id: row_id.clone(),
+            field_id: number_field_id,
+            field_type: FieldType::Number,
+            expected: "$5.00".to_owned(),
+        },
+        AssertCell {
+            row_id: row_id.clone(),
+            field_id: date_field_id.clone(),
+            field_type: FieldType::DateTime,
+            expected: "2022/03/14".to_owned(),
+        },
+        AssertCell {
+            row_id: row_id.clone(),
+            field_id: checkbox_field_id,
+            field_type: FieldType::Checkbox,
+            expected: CHECK.to_owned(),
+        },
+        AssertCell {
+            row_id: row_id.clone(),
+            field_id: single_select_field_id,
+            field_type: FieldType::SingleSelect,
+            expected: single_select_option.name,
+        },
+        AssertCell {
+            row_id: row_id.clone(),
+            field_id: multi_select_field_id,
+            field_type: FieldType::MultiSelect,
+            expected: vec![GOOGLE, FACEBOOK].join(SELECTION_IDS_SEPARATOR),
+        },
+        // Clearing the default value doesn't change the existing rows.
+        SetFieldDefaultValue {
+            field_id: text_field_id.clone(),
+            default_value: None,
+            is_err: false,
+        },
+        set_default_value(&date_field_id, TODAY_DEFAULT_VALUE),
+        CreateEmptyRow,
+        AssertCell {
+            row_id,
+            field_id: text_field_id.clone(),
+            field_type: FieldType::RichText,
+            expected: "hello".to_owned(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+
+    let last_row = test.last_row().unwrap();
+    assert!(!last_row.cells.contains_key(&text_field_id));
+    assert!(last_row.cells.contains_key(&date_field_id));
+}
+
+#[tokio::test]
+async fn grid_duplicate_row_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let row_count = test.row_revs.len();
+    // The rows of the test grid cover the cells of all the field types.
+    let scripts = test
+        .row_revs
+        .iter()
+        .map(|row_rev| DuplicateRow {
+            row_id: row_rev.id.clone(),
+        })
+        .collect::<Vec<RowScript>>();
+    test.run_scripts(scripts).await;
+    test.run_scripts(vec![AssertRowCount(row_count * 2)]).await;
+}
+
+#[tokio::test]
+async fn grid_duplicate_hidden_row_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let row_id = test.row_revs[1].id.clone();
+    let scripts = vec![
+        UpdateRow {
+            changeset: RowChangeset {
+                row_id: row_id.clone(),
+                height: Some(120),
+                visibility: Some(false),
+                cell_by_field_id: Default::default(),
+                modified_at: None,
+                meta: None,
+                document_id: None,
+            },
+        },
+        DuplicateRow { row_id },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_create_rows_in_batch_test() {
+    let mut test = DatabaseRowTest::new().await;
+    test.run_scripts(vec![ReopenWithMergeThreshold(1)]).await;
+    let stats = test.editor.revision_stats().await.unwrap();
+    let block_id = test.block_id().to_owned();
+    let row_count = test.row_revs.len();
+
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let rows = (0..1000)
+        .map(|i| RowDataPayload::new().with_cell(&text_field_id, &format!("row {}", i)))
+        .collect::<Vec<RowDataPayload>>();
+    let overflow_row_count = row_count + 1000 - MAX_ROWS_PER_BLOCK;
+    let scripts = vec![
+        CreateRows {
+            rows,
+            start_index: None,
+        },
+        AssertRowCount(row_count + 1000),
+        AssertBlockCount(2),
+        AssertBlock {
+            block_index: 0,
+            row_count: MAX_ROWS_PER_BLOCK as i32,
+            start_row_index: 0,
+        },
+        AssertBlock {
+            block_index: 1,
+            row_count: overflow_row_count as i32,
+            start_row_index: MAX_ROWS_PER_BLOCK as i32,
+        },
+    ];
+    test.run_scripts(scripts).await;
+    let last_row_id = test.row_revs.last().unwrap().id.clone();
+    test.run_scripts(vec![AssertCell {
+        row_id: last_row_id,
+        field_id: text_field_id,
+        field_type: FieldType::RichText,
+        expected: "row 999".to_owned(),
+    }])
+    .await;
+
+    // The existing block and the new block are written in one revision each, and the metas of the
+    // blocks are updated in one revision of the database.
+    let new_stats = test.editor.revision_stats().await.unwrap();
+    assert_eq!(new_stats.number_of_revisions, stats.number_of_revisions + 1);
+    assert_eq!(
+        new_stats.block_revisions[&block_id],
+        stats.block_revisions[&block_id] + 1
+    );
+    let new_block_id = &test.block_meta_revs[1].block_id;
+    assert_eq!(new_stats.block_revisions[new_block_id], 1);
+}
+
+#[tokio::test]
+async fn grid_create_rows_at_index_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let row_count = test.row_revs.len();
+    let first_row_id = test.row_revs[0].id.clone();
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let rows = vec![
+        RowDataPayload::new().with_cell(&text_field_id, "hello"),
+        RowDataPayload::new().with_cell(&text_field_id, "world"),
+    ];
+    let scripts = vec![
+        CreateRows {
+            rows,
+            start_index: Some(1),
+        },
+        AssertRowCount(row_count + 2),
+        AssertBlockCount(1),
+    ];
+    test.run_scripts(scripts).await;
+
+    assert_eq!(test.row_revs[0].id, first_row_id);
+    let scripts = ["hello", "world"]
+        .into_iter()
+        .enumerate()
+        .map(|(i, expected)| AssertCell {
+            row_id: test.row_revs[i + 1].id.clone(),
+            field_id: text_field_id.clone(),
+            field_type: FieldType::RichText,
+            expected: expected.to_owned(),
+        })
+        .collect::<Vec<RowScript>>();
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_move_row_in_block_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let row_id = test.row_revs[0].id.clone();
+    let to_row_id = test.row_revs[2].id.clone();
+    let scripts = vec![
+        MoveRow {
+            row_id: row_id.clone(),
+            to: MoveRowTarget::RowId(to_row_id.clone()),
+        },
+        AssertRowPosition {
+            row_id: row_id.clone(),
+            index: 2,
+            block_index: 0,
+        },
+        AssertRowPosition {
+            row_id: to_row_id,
+            index: 1,
+            block_index: 0,
+        },
+        MoveRow {
+            row_id: row_id.clone(),
+            to: MoveRowTarget::Index(0),
+        },
+        AssertRowPosition {
+            row_id,
+            index: 0,
+            block_index: 0,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_move_row_across_blocks_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let row_count = test.row_revs.len();
+    let rows = (0..MAX_ROWS_PER_BLOCK).map(|_| RowDataPayload::new()).collect();
+    test.run_scripts(vec![CreateRows {
+        rows,
+        start_index: None,
+    }])
+    .await;
+    let number_of_rows = row_count + MAX_ROWS_PER_BLOCK;
+    let overflow_row_count = number_of_rows - MAX_ROWS_PER_BLOCK;
+
+    // The first row has the text "A" in the mock data.
+    let row_id = test.row_revs[0].id.clone();
+    let last_row_id = test.row_revs.last().unwrap().id.clone();
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let scripts = vec![
+        MoveRow {
+            row_id: row_id.clone(),
+            to: MoveRowTarget::RowId(last_row_id.clone()),
+        },
+        AssertRowCount(number_of_rows),
+        AssertRowPosition {
+            row_id: row_id.clone(),
+            index: number_of_rows - 1,
+            block_index: 1,
+        },
+        AssertRowPosition {
+            row_id: last_row_id,
+            index: number_of_rows - 2,
+            block_index: 1,
+        },
+        AssertBlock {
+            block_index: 0,
+            row_count: MAX_ROWS_PER_BLOCK as i32 - 1,
+            start_row_index: 0,
+        },
+        AssertBlock {
+            block_index: 1,
+            row_count: overflow_row_count as i32 + 1,
+            start_row_index: MAX_ROWS_PER_BLOCK as i32 - 1,
+        },
+        AssertCell {
+            row_id: row_id.clone(),
+            field_id: text_field_id,
+            field_type: FieldType::RichText,
+            expected: "A".to_owned(),
+        },
+        MoveRow {
+            row_id: row_id.clone(),
+            to: MoveRowTarget::Index(0),
+        },
+        AssertRowCount(number_of_rows),
+        AssertRowPosition {
+            row_id,
+            index: 0,
+            block_index: 0,
+        },
+        AssertBlock {
+            block_index: 0,
+            row_count: MAX_ROWS_PER_BLOCK as i32,
+            start_row_index: 0,
+        },
+        AssertBlock {
+            block_index: 1,
+            row_count: overflow_row_count as i32,
+            start_row_index: MAX_ROWS_PER_BLOCK as i32,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_delete_rows_in_batch_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let row_count = test.row_revs.len();
+    let row_ids = test
+        .row_revs
+        .iter()
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<String>>();
+    let scripts = vec![
+        DeleteRowsInBatch {
+            row_ids: vec![row_ids[3].clone(), "unknown row".to_owned(), row_ids[1].clone()],
+            expected_deleted_rows: vec![(row_ids[1].clone(), 1), (row_ids[3].clone(), 3)],
+            expected_skipped_row_ids: vec!["unknown row".to_owned()],
+        },
+        AssertRowCount(row_count - 2),
+        AssertBlock {
+            block_index: 0,
+            row_count: row_count as i32 - 2,
+            start_row_index: 0,
+        },
+        // The deleted rows can't be deleted again.
+        DeleteRowsInBatch {
+            row_ids: vec![row_ids[1].clone()],
+            expected_deleted_rows: vec![],
+            expected_skipped_row_ids: vec![row_ids[1].clone()],
+        },
+        AssertRowCount(row_count - 2),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_delete_rows_across_blocks_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let rows = (0..MAX_ROWS_PER_BLOCK).map(|_| RowDataPayload::new()).collect();
+    test.run_scripts(vec![CreateRows {
+        rows,
+        start_index: None,
+    }])
+    .await;
+    let number_of_rows = test.row_revs.len();
+    let first_row_id = test.row_revs[0].id.clone();
+    let last_row_id = test.row_revs.last().unwrap().id.clone();
+    let scripts = vec![
+        DeleteRowsInBatch {
+            row_ids: vec![last_row_id.clone(), first_row_id.clone()],
+            expected_deleted_rows: vec![(first_row_id, 0), (last_row_id, number_of_rows - 1)],
+            expected_skipped_row_ids: vec![],
+        },
+        AssertRowCount(number_of_rows - 2),
+        AssertBlock {
+            block_index: 0,
+            row_count: MAX_ROWS_PER_BLOCK as i32 - 1,
+            start_row_index: 0,
+        },
+        AssertBlock {
+            block_index: 1,
+            row_count: (number_of_rows - MAX_ROWS_PER_BLOCK) as i32 - 1,
+            start_row_index: MAX_ROWS_PER_BLOCK as i32 - 1,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_delete_rows_to_trash_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let row_ids = vec![test.row_revs[1].id.clone(), test.row_revs[2].id.clone()];
+    let row_count = test.row_revs.len();
+    let scripts = vec![
+        DeleteRows {
+            row_ids: row_ids.clone(),
+        },
+        AssertRowCount(row_count - 2),
+        AssertBlock {
+            block_index: 0,
+            row_count: (row_count - 2) as i32,
+            start_row_index: 0,
+        },
+        AssertTrashedRows(row_ids),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_restore_trashed_rows_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let first_row_id = test.row_revs[0].id.clone();
+    let row_ids = vec![test.row_revs[1].id.clone(), test.row_revs[2].id.clone()];
+    let row_count = test.row_revs.len();
+    let scripts = vec![
+        DeleteRows {
+            row_ids: vec![first_row_id.clone()],
+        },
+        DeleteRows {
+            row_ids: row_ids.clone(),
+        },
+        AssertRowCount(row_count - 3),
+        RestoreRows {
+            row_ids: vec![row_ids[0].clone(), row_ids[1].clone(), "unknown row".to_owned()],
+            expected_restored_row_ids: row_ids.clone(),
+        },
+        AssertTrashedRows(vec![first_row_id.clone()]),
+        // The rows are inserted back in their order, even though the row before them is still in
+        // the trash.
+        AssertRowPosition {
+            row_id: row_ids[0].clone(),
+            index: 0,
+            block_index: 0,
+        },
+        AssertRowPosition {
+            row_id: row_ids[1].clone(),
+            index: 1,
+            block_index: 0,
+        },
+        RestoreRow {
+            row_id: first_row_id.clone(),
+        },
+        AssertTrashedRows(vec![]),
+        AssertRowPosition {
+            row_id: first_row_id,
+            index: 0,
+            block_index: 0,
+        },
+        AssertRowCount(row_count),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_purge_trash_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let row_ids = vec![test.row_revs[0].id.clone(), test.row_revs[3].id.clone()];
+    let row_count = test.row_revs.len();
+    let scripts = vec![
+        DeleteRows {
+            row_ids: row_ids.clone(),
+        },
+        // The rows were trashed after the given time.
+        PurgeTrash {
+            older_than: 0,
+            expected_purged_row_ids: vec![],
+        },
+        AssertTrashedRows(row_ids.clone()),
+        PurgeTrash {
+            older_than: i64::MAX,
+            expected_purged_row_ids: row_ids.clone(),
+        },
+        AssertTrashedRows(vec![]),
+        RestoreRows {
+            row_ids,
+            expected_restored_row_ids: vec![],
+        },
+        AssertRowCount(row_count - 2),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_update_row_meta_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let row_id = test.row_revs[0].id.clone();
+    let scripts = vec![
+        UpdateRowMeta {
+            row_id: row_id.clone(),
+            changeset: RowMetaChangeset {
+                icon: Some("🚀".to_owned()),
+                cover: Some("cover.png".to_owned()),
+                color: None,
+            },
+            is_err: false,
+        },
+        AssertRowMeta {
+            row_id: row_id.clone(),
+            expected: RowMetaRevision {
+                icon: Some("🚀".to_owned()),
+                cover: Some("cover.png".to_owned()),
+                color: None,
+            },
+        },
+        // The empty value clears the icon, and the cover is left unchanged.
+        UpdateRowMeta {
+            row_id: row_id.clone(),
+            changeset: RowMetaChangeset {
+                icon: Some("".to_owned()),
+                cover: None,
+                color: Some("#FF8800".to_owned()),
+            },
+            is_err: false,
+        },
+        AssertRowMeta {
+            row_id,
+            expected: RowMetaRevision {
+                icon: None,
+                cover: Some("cover.png".to_owned()),
+                color: Some("#FF8800".to_owned()),
+            },
+        },
+        UpdateRowMeta {
+            row_id: "unknown row".to_owned(),
+            changeset: RowMetaChangeset {
+                icon: Some("🚀".to_owned()),
+                ..Default::default()
+            },
+            is_err: true,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_duplicate_row_with_meta_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let row_id = test.row_revs[2].id.clone();
+    let scripts = vec![
+        UpdateRowMeta {
+            row_id: row_id.clone(),
+            changeset: RowMetaChangeset {
+                icon: Some("📌".to_owned()),
+                cover: Some("cover.png".to_owned()),
+                color: Some("#00FF00".to_owned()),
+            },
+            is_err: false,
+        },
+        DuplicateRow { row_id },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_restore_trashed_row_with_meta_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let row_id = test.row_revs[1].id.clone();
+    let meta = RowMetaRevision {
+        icon: Some("📌".to_owned()),
+        cover: None,
+        color: Some("#00FF00".to_owned()),
+    };
+    let scripts = vec![
+        UpdateRowMeta {
+            row_id: row_id.clone(),
+            changeset: RowMetaChangeset {
+                icon: meta.icon.clone(),
+                cover: None,
+                color: meta.color.clone(),
+            },
+            is_err: false,
+        },
+        DeleteRows {
+            row_ids: vec![row_id.clone()],
+        },
+        // The meta is kept with the row in the trash.
+        RestoreRows {
+            row_ids: vec![row_id.clone()],
+            expected_restored_row_ids: vec![row_id.clone()],
+        },
+        AssertRowMeta { row_id, expected: meta },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_set_row_document_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let row_id = test.row_revs[0].id.clone();
+    let scripts = vec![
+        AssertRowDocument {
+            row_id: row_id.clone(),
+            expected: None,
+        },
+        SetRowDocument {
+            row_id: row_id.clone(),
+            document_id: Some("document 1".to_owned()),
+        },
+        AssertRowDocument {
+            row_id: row_id.clone(),
+            expected: Some("document 1".to_owned()),
+        },
+        SetRowDocument {
+            row_id: row_id.clone(),
+            document_id: None,
+        },
+        AssertRowDocument { row_id, expected: None },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_duplicate_row_with_document_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let row_id = test.row_revs[0].id.clone();
+    let scripts = vec![
+        SetRowDocument {
+            row_id: row_id.clone(),
+            document_id: Some("document 1".to_owned()),
+        },
+        // The copy has no document.
+        DuplicateRowWithDocument {
+            row_id: row_id.clone(),
+            duplicate_document: false,
+            expected_source_document_id: None,
+        },
+        // The document of the row needs to be duplicated for the copy.
+        DuplicateRowWithDocument {
+            row_id: row_id.clone(),
+            duplicate_document: true,
+            expected_source_document_id: Some("document 1".to_owned()),
+        },
+        AssertRowDocument {
+            row_id,
+            expected: Some("document 1".to_owned()),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_delete_rows_with_document_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let row_ids = vec![test.row_revs[0].id.clone(), test.row_revs[1].id.clone()];
+    let scripts = vec![
+        SetRowDocument {
+            row_id: row_ids[1].clone(),
+            document_id: Some("document 2".to_owned()),
+        },
+        DeleteRowsWithDocuments {
+            row_ids,
+            expected_document_ids: vec![None, Some("document 2".to_owned())],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_row_counts_after_batch_operations_test() {
+    let mut test = DatabaseRowTest::new().await;
+    let row_count = test.row_revs.len();
+    let rows = (0..MAX_ROWS_PER_BLOCK).map(|_| RowDataPayload::new()).collect();
+    let scripts = vec![
+        AssertRowCounts {
+            total: row_count,
+            per_block: vec![row_count],
+        },
+        CreateRows {
+            rows,
+            start_index: None,
+        },
+        AssertRowCounts {
+            total: row_count + MAX_ROWS_PER_BLOCK,
+            per_block: vec![MAX_ROWS_PER_BLOCK, row_count],
+        },
+    ];
+    test.run_scripts(scripts).await;
+
+    let first_row_id = test.row_revs[0].id.clone();
+    let second_row_id = test.row_revs[1].id.clone();
+    let last_row_id = test.row_revs.last().unwrap().id.clone();
+    let scripts = vec![
+        DeleteRowsInBatch {
+            row_ids: vec![first_row_id.clone(), last_row_id.clone()],
+            expected_deleted_rows: vec![
+                (first_row_id.clone(), 0),
+                (last_row_id.clone(), row_count + MAX_ROWS_PER_BLOCK - 1),
+            ],
+            expected_skipped_row_ids: vec![],
+        },
+        AssertRowCounts {
+            total: row_count + MAX_ROWS_PER_BLOCK - 2,
+            per_block: vec![MAX_ROWS_PER_BLOCK - 1, row_count - 1],
+        },
+        // Moves the row from the first block to the end of the second block.
+        MoveRow {
+            row_id: second_row_id,
+            to: MoveRowTarget::Index(row_count + MAX_ROWS_PER_BLOCK - 3),
+        },
+        AssertRowCounts {
+            total: row_count + MAX_ROWS_PER_BLOCK - 2,
+            per_block: vec![MAX_ROWS_PER_BLOCK - 2, row_count],
+        },
+        RestoreRows {
+            row_ids: vec![first_row_id.clone(), last_row_id.clone()],
+            expected_restored_row_ids: vec![first_row_id, last_row_id],
+        },
+        AssertRowCounts {
+            total: row_count + MAX_ROWS_PER_BLOCK,
+            per_block: vec![MAX_ROWS_PER_BLOCK - 1, row_count + 1],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
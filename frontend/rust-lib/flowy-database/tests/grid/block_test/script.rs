@@ -1,10 +1,16 @@
 use crate::grid::block_test::script::RowScript::{AssertCell, CreateRow};
 use crate::grid::block_test::util::GridRowTestBuilder;
 use crate::grid::database_editor::DatabaseEditorTest;
-use flowy_database::entities::{CellPathParams, CreateRowParams, DatabaseViewLayout, FieldType, RowPB};
+use flowy_database::entities::{
+    CellChangePB, CellPathParams, CreateRowParams, DatabaseViewLayout, FieldType, MoveRowParams, MoveRowTarget,
+    RowDataPayload, RowMetaPB, RowPB,
+};
+use flowy_database::manager::RevisionCompressConfig;
+use flowy_database::services::block_manager::DatabaseBlockEvent;
 use flowy_database::services::field::*;
-use flowy_database::services::row::DatabaseBlockRow;
-use grid_model::{GridBlockMetaRevision, GridBlockMetaRevisionChangeset, RowChangeset, RowRevision};
+use grid_model::{
+    GridBlockMetaRevision, GridBlockMetaRevisionChangeset, RowChangeset, RowMetaChangeset, RowMetaRevision, RowRevision,
+};
 use std::collections::HashMap;
 use std::sync::Arc;
 use strum::IntoEnumIterator;
@@ -23,6 +29,13 @@ pub enum RowScript {
     DeleteRows {
         row_ids: Vec<String>,
     },
+    /// Deletes the rows in a batch, and asserts the deleted rows with their former indexes, the
+    /// skipped ids, and the deleted rows are notified in one event.
+    DeleteRowsInBatch {
+        row_ids: Vec<String>,
+        expected_deleted_rows: Vec<(String, usize)>,
+        expected_skipped_row_ids: Vec<String>,
+    },
     AssertCell {
         row_id: String,
         field_id: String,
@@ -30,6 +43,12 @@ pub enum RowScript {
         expected: String,
     },
     AssertRowCount(usize),
+    /// Asserts the row counts that are answered from the blocks, which must be the same as the
+    /// number of the loaded rows.
+    AssertRowCounts {
+        total: usize,
+        per_block: Vec<usize>,
+    },
     CreateBlock {
         block: GridBlockMetaRevision,
     },
@@ -46,6 +65,97 @@ pub enum RowScript {
         block_index: usize,
         block: GridBlockMetaRevision,
     },
+    UpdateTextCell {
+        row_id: String,
+        content: String,
+    },
+    SetFieldDefaultValue {
+        field_id: String,
+        default_value: Option<String>,
+        is_err: bool,
+    },
+    /// Reopens the database with the merge threshold, so each edit generates its own revision if
+    /// the threshold is 1.
+    ReopenWithMergeThreshold(usize),
+    /// Asserts the changes of the row, starting from the latest one.
+    AssertRowHistory {
+        row_id: String,
+        before_rev_id: Option<i64>,
+        limit: usize,
+        expected: Vec<ExpectedRowChange>,
+    },
+    RestoreRow {
+        row_id: String,
+    },
+    /// Asserts the row can't be restored because it's not found in the revisions.
+    AssertRestoreRowNotFound {
+        row_id: String,
+    },
+    /// Creates the rows in a batch, and asserts the views are notified of all the rows at once.
+    CreateRows {
+        rows: Vec<RowDataPayload>,
+        start_index: Option<usize>,
+    },
+    MoveRow {
+        row_id: String,
+        to: MoveRowTarget,
+    },
+    /// Asserts the row is at the index of all the rows, and it's in the block and indexed to it.
+    AssertRowPosition {
+        row_id: String,
+        index: usize,
+        block_index: usize,
+    },
+    /// Duplicates the row, and asserts the copy is inserted right after the row with the same
+    /// cells, height, visibility and meta, and the inserted row is sent with its index.
+    DuplicateRow {
+        row_id: String,
+    },
+    /// Asserts the ids of the rows in the trash, in the order they were deleted.
+    AssertTrashedRows(Vec<String>),
+    RestoreRows {
+        row_ids: Vec<String>,
+        expected_restored_row_ids: Vec<String>,
+    },
+    PurgeTrash {
+        older_than: i64,
+        expected_purged_row_ids: Vec<String>,
+    },
+    UpdateRowMeta {
+        row_id: String,
+        changeset: RowMetaChangeset,
+        is_err: bool,
+    },
+    AssertRowMeta {
+        row_id: String,
+        expected: RowMetaRevision,
+    },
+    SetRowDocument {
+        row_id: String,
+        document_id: Option<String>,
+    },
+    AssertRowDocument {
+        row_id: String,
+        expected: Option<String>,
+    },
+    /// Duplicates the row, and asserts the copy has no document and the document that needs to
+    /// be duplicated is returned.
+    DuplicateRowWithDocument {
+        row_id: String,
+        duplicate_document: bool,
+        expected_source_document_id: Option<String>,
+    },
+    /// Deletes the rows, and asserts the deleted rows carry their documents.
+    DeleteRowsWithDocuments {
+        row_ids: Vec<String>,
+        expected_document_ids: Vec<Option<String>>,
+    },
+}
+
+pub struct ExpectedRowChange {
+    pub is_created: bool,
+    pub is_deleted: bool,
+    pub cells: Vec<CellChangePB>,
 }
 
 pub struct DatabaseRowTest {
@@ -96,13 +206,40 @@ impl DatabaseRowTest {
             }
             RowScript::UpdateRow { changeset: change } => self.editor.update_row(change).await.unwrap(),
             RowScript::DeleteRows { row_ids } => {
-                let row_pbs = row_ids
-                    .into_iter()
-                    .map(|row_id| self.row_by_row_id.get(&row_id).unwrap().clone())
-                    .collect::<Vec<RowPB>>();
-
-                let block_rows = block_from_row_pbs(row_pbs);
-                self.editor.delete_rows(block_rows).await.unwrap();
+                let result = self.editor.delete_rows(row_ids).await.unwrap();
+                assert!(result.skipped_row_ids.is_empty());
+                self.row_revs = self.get_row_revs().await;
+                self.block_meta_revs = self.editor.get_block_meta_revs().await.unwrap();
+            }
+            RowScript::DeleteRowsInBatch {
+                row_ids,
+                expected_deleted_rows,
+                expected_skipped_row_ids,
+            } => {
+                let mut block_event_rx = self.editor.subscribe_block_event();
+                let result = self.editor.delete_rows(row_ids).await.unwrap();
+                let deleted_rows = result
+                    .deleted_rows
+                    .iter()
+                    .map(|deleted_row| (deleted_row.row_id.clone(), deleted_row.index as usize))
+                    .collect::<Vec<(String, usize)>>();
+                assert_eq!(deleted_rows, expected_deleted_rows);
+                assert_eq!(result.skipped_row_ids, expected_skipped_row_ids);
+
+                if !expected_deleted_rows.is_empty() {
+                    match block_event_rx.recv().await.unwrap() {
+                        DatabaseBlockEvent::DeleteRows { mut row_ids } => {
+                            row_ids.sort();
+                            let mut expected_row_ids = expected_deleted_rows
+                                .into_iter()
+                                .map(|(row_id, _)| row_id)
+                                .collect::<Vec<String>>();
+                            expected_row_ids.sort();
+                            assert_eq!(row_ids, expected_row_ids);
+                        }
+                        _ => panic!("Expected the deleted rows event"),
+                    }
+                }
                 self.row_revs = self.get_row_revs().await;
                 self.block_meta_revs = self.editor.get_block_meta_revs().await.unwrap();
             }
@@ -131,6 +268,30 @@ impl DatabaseRowTest {
             RowScript::AssertRowCount(expected_row_count) => {
                 assert_eq!(expected_row_count, self.row_revs.len());
             }
+            RowScript::AssertRowCounts { total, per_block } => {
+                assert_eq!(self.editor.row_count().await, total);
+                assert_eq!(self.get_row_revs().await.len(), total);
+
+                let row_counts = self.editor.row_counts_per_block().await;
+                let block_ids = row_counts
+                    .iter()
+                    .map(|(block_id, _)| block_id.clone())
+                    .collect::<Vec<String>>();
+                let expected_block_ids = self
+                    .editor
+                    .get_block_meta_revs()
+                    .await
+                    .unwrap()
+                    .iter()
+                    .map(|block_meta_rev| block_meta_rev.block_id.clone())
+                    .collect::<Vec<String>>();
+                assert_eq!(block_ids, expected_block_ids);
+                let row_counts = row_counts
+                    .into_iter()
+                    .map(|(_, row_count)| row_count)
+                    .collect::<Vec<usize>>();
+                assert_eq!(row_counts, per_block);
+            }
             RowScript::CreateBlock { block } => {
                 self.editor.create_block(block).await.unwrap();
                 self.block_meta_revs = self.editor.get_block_meta_revs().await.unwrap();
@@ -154,6 +315,254 @@ impl DatabaseRowTest {
                 let compared_block = blocks[block_index].clone();
                 assert_eq!(compared_block, Arc::new(block));
             }
+            RowScript::UpdateTextCell { row_id, content } => {
+                self.update_text_cell(row_id, &content).await;
+            }
+            RowScript::SetFieldDefaultValue {
+                field_id,
+                default_value,
+                is_err,
+            } => {
+                let result = self.editor.set_field_default_value(&field_id, default_value).await;
+                assert_eq!(result.is_err(), is_err);
+            }
+            RowScript::ReopenWithMergeThreshold(merge_threshold) => {
+                let database_manager = self.sdk.grid_manager.clone();
+                database_manager.set_rev_compress_config(RevisionCompressConfig::with_merge_threshold(merge_threshold));
+                database_manager.close_all().await;
+                self.editor = database_manager.open_database(&self.view_id).await.unwrap();
+                self.row_revs = self.get_row_revs().await;
+            }
+            RowScript::AssertRowHistory {
+                row_id,
+                before_rev_id,
+                limit,
+                expected,
+            } => {
+                let row_changes = self
+                    .editor
+                    .get_row_history(&row_id, before_rev_id, limit)
+                    .await
+                    .unwrap();
+                assert_eq!(row_changes.len(), expected.len());
+                for (row_change, expected) in row_changes.into_iter().zip(expected) {
+                    assert_eq!(row_change.is_created, expected.is_created);
+                    assert_eq!(row_change.is_deleted, expected.is_deleted);
+                    assert_eq!(row_change.cells, expected.cells);
+                    let changed_field_ids = expected
+                        .cells
+                        .iter()
+                        .map(|cell| cell.field_id.clone())
+                        .collect::<Vec<String>>();
+                    assert_eq!(row_change.changed_field_ids, changed_field_ids);
+                }
+            }
+            RowScript::RestoreRow { row_id } => {
+                let row_pb = self.editor.restore_row(&row_id).await.unwrap();
+                assert_eq!(row_pb.id, row_id);
+                self.row_by_row_id.insert(row_pb.row_id().to_owned(), row_pb);
+                self.row_revs = self.get_row_revs().await;
+                self.block_meta_revs = self.editor.get_block_meta_revs().await.unwrap();
+            }
+            RowScript::AssertRestoreRowNotFound { row_id } => {
+                let error = self.editor.restore_row(&row_id).await.unwrap_err();
+                assert!(error.is_record_not_found());
+            }
+            RowScript::CreateRows { rows, start_index } => {
+                let mut block_event_rx = self.editor.subscribe_block_event();
+                let row_count = rows.len();
+                let row_pbs = self.editor.create_rows(rows, start_index).await.unwrap();
+                assert_eq!(row_pbs.len(), row_count);
+                match block_event_rx.recv().await.unwrap() {
+                    DatabaseBlockEvent::InsertRows { rows } => {
+                        let row_ids = rows.iter().map(|row| row.row.id.as_str()).collect::<Vec<&str>>();
+                        let expected_row_ids = row_pbs.iter().map(|row| row.id.as_str()).collect::<Vec<&str>>();
+                        assert_eq!(row_ids, expected_row_ids);
+                    }
+                    _ => panic!("Expected the inserted rows event"),
+                }
+                for row_pb in row_pbs {
+                    self.row_by_row_id.insert(row_pb.row_id().to_owned(), row_pb);
+                }
+                self.row_revs = self.get_row_revs().await;
+                self.block_meta_revs = self.editor.get_block_meta_revs().await.unwrap();
+            }
+            RowScript::MoveRow { row_id, to } => {
+                let params = MoveRowParams {
+                    view_id: self.view_id.clone(),
+                    from_row_id: row_id,
+                    to,
+                    remove_sorts: false,
+                };
+                self.editor.move_row(params).await.unwrap();
+                self.row_revs = self.get_row_revs().await;
+                self.block_meta_revs = self.editor.get_block_meta_revs().await.unwrap();
+            }
+            RowScript::AssertRowPosition {
+                row_id,
+                index,
+                block_index,
+            } => {
+                let position = self.row_revs.iter().position(|row_rev| row_rev.id == row_id);
+                assert_eq!(position, Some(index));
+                let block_id = &self.block_meta_revs[block_index].block_id;
+                assert_eq!(&self.row_revs[index].block_id, block_id);
+                let row_rev = self.editor.get_row_rev(&row_id).await.unwrap().unwrap();
+                assert_eq!(&row_rev.block_id, block_id);
+            }
+            RowScript::AssertTrashedRows(expected_row_ids) => {
+                let row_ids = self
+                    .editor
+                    .list_trashed_rows()
+                    .await
+                    .into_iter()
+                    .map(|trashed_row| trashed_row.row.id)
+                    .collect::<Vec<String>>();
+                assert_eq!(row_ids, expected_row_ids);
+            }
+            RowScript::RestoreRows {
+                row_ids,
+                expected_restored_row_ids,
+            } => {
+                let row_pbs = self.editor.restore_rows(row_ids).await.unwrap();
+                let row_ids = row_pbs.iter().map(|row_pb| row_pb.id.clone()).collect::<Vec<String>>();
+                assert_eq!(row_ids, expected_restored_row_ids);
+                for row_pb in row_pbs {
+                    self.row_by_row_id.insert(row_pb.row_id().to_owned(), row_pb);
+                }
+                self.row_revs = self.get_row_revs().await;
+                self.block_meta_revs = self.editor.get_block_meta_revs().await.unwrap();
+            }
+            RowScript::PurgeTrash {
+                older_than,
+                expected_purged_row_ids,
+            } => {
+                let row_ids = self
+                    .editor
+                    .purge_trash(older_than)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|trashed_row| trashed_row.row.id)
+                    .collect::<Vec<String>>();
+                assert_eq!(row_ids, expected_purged_row_ids);
+            }
+            RowScript::UpdateRowMeta {
+                row_id,
+                changeset,
+                is_err,
+            } => {
+                let mut block_event_rx = self.editor.subscribe_block_event();
+                let result = self.editor.update_row_meta(&row_id, changeset).await;
+                assert_eq!(result.is_err(), is_err);
+                if !is_err {
+                    // The updated row carries its new meta.
+                    let row_rev = self.editor.get_row_rev(&row_id).await.unwrap().unwrap();
+                    match block_event_rx.recv().await.unwrap() {
+                        DatabaseBlockEvent::UpdateRow { row, .. } => {
+                            assert_eq!(row.row.id, row_id);
+                            assert_eq!(row.row.meta, RowMetaPB::from(&row_rev.meta));
+                        }
+                        _ => panic!("Expected the updated row event"),
+                    }
+                }
+            }
+            RowScript::AssertRowMeta { row_id, expected } => {
+                let row_rev = self.editor.get_row_rev(&row_id).await.unwrap().unwrap();
+                assert_eq!(row_rev.meta, expected);
+            }
+            RowScript::SetRowDocument { row_id, document_id } => {
+                self.editor.set_row_document(&row_id, document_id).await.unwrap();
+            }
+            RowScript::AssertRowDocument { row_id, expected } => {
+                let document_id = self.editor.get_row_document(&row_id).await.unwrap();
+                assert_eq!(document_id, expected);
+                let row_rev = self.editor.get_row_rev(&row_id).await.unwrap().unwrap();
+                assert_eq!(RowPB::from(&row_rev).document_id, expected);
+            }
+            RowScript::DuplicateRowWithDocument {
+                row_id,
+                duplicate_document,
+                expected_source_document_id,
+            } => {
+                let duplicated_row = self
+                    .editor
+                    .duplicate_row(&row_id, false, duplicate_document)
+                    .await
+                    .unwrap();
+                assert_eq!(duplicated_row.source_document_id, expected_source_document_id);
+                assert!(duplicated_row.row.document_id.is_none());
+                self.row_revs = self.get_row_revs().await;
+            }
+            RowScript::DeleteRowsWithDocuments {
+                row_ids,
+                expected_document_ids,
+            } => {
+                let result = self.editor.delete_rows(row_ids).await.unwrap();
+                let document_ids = result
+                    .deleted_rows
+                    .into_iter()
+                    .map(|deleted_row| deleted_row.document_id)
+                    .collect::<Vec<Option<String>>>();
+                assert_eq!(document_ids, expected_document_ids);
+                self.row_revs = self.get_row_revs().await;
+            }
+            RowScript::DuplicateRow { row_id } => {
+                let mut block_event_rx = self.editor.subscribe_block_event();
+                let row_pb = self.editor.duplicate_row(&row_id, false, false).await.unwrap().row;
+                assert_ne!(row_pb.id, row_id);
+                self.row_by_row_id.insert(row_pb.row_id().to_owned(), row_pb.clone());
+                self.row_revs = self.get_row_revs().await;
+                self.block_meta_revs = self.editor.get_block_meta_revs().await.unwrap();
+
+                let row_rev = self
+                    .row_revs
+                    .iter()
+                    .find(|row_rev| row_rev.id == row_id)
+                    .unwrap()
+                    .clone();
+                let block_row_ids = self
+                    .row_revs
+                    .iter()
+                    .filter(|other| other.block_id == row_rev.block_id)
+                    .map(|other| other.id.as_str())
+                    .collect::<Vec<&str>>();
+                let index = block_row_ids.iter().position(|id| *id == row_id).unwrap();
+                assert_eq!(block_row_ids.get(index + 1), Some(&row_pb.id.as_str()));
+
+                let duplicated_row_rev = self.editor.get_row_rev(&row_pb.id).await.unwrap().unwrap();
+                assert_eq!(duplicated_row_rev.block_id, row_rev.block_id);
+                assert_eq!(duplicated_row_rev.cells, row_rev.cells);
+                assert_eq!(duplicated_row_rev.height, row_rev.height);
+                assert_eq!(duplicated_row_rev.visibility, row_rev.visibility);
+                assert_eq!(duplicated_row_rev.meta, row_rev.meta);
+                assert!(duplicated_row_rev.document_id.is_none());
+
+                match block_event_rx.recv().await.unwrap() {
+                    DatabaseBlockEvent::InsertRow { block_id, row } => {
+                        assert_eq!(block_id, row_rev.block_id);
+                        assert_eq!(row.row.id, row_pb.id);
+                        assert_eq!(row.index, Some(index as i32 + 1));
+                    }
+                    _ => panic!("Expected the inserted row event"),
+                }
+
+                let typed_cells = self.editor.get_row_typed(&row_id).await.unwrap();
+                let duplicated_typed_cells = self.editor.get_row_typed(&row_pb.id).await.unwrap();
+                for field_rev in self.field_revs.iter() {
+                    let field_type: FieldType = field_rev.ty.into();
+                    // The times of the copy are the times when the copy was created.
+                    if matches!(field_type, FieldType::CreatedTime | FieldType::LastEditedTime) {
+                        continue;
+                    }
+                    assert_eq!(
+                        duplicated_typed_cells.get(&field_rev.id),
+                        typed_cells.get(&field_rev.id),
+                        "The cell of the {} field is not copied",
+                        field_type
+                    );
+                }
+            }
         }
     }
 
@@ -184,7 +593,7 @@ impl DatabaseRowTest {
                     .unwrap();
                 assert_eq!(cell_data.to_string(), expected);
             }
-            FieldType::DateTime => {
+            FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => {
                 let cell_data = self
                     .editor
                     .get_cell_protobuf(&cell_id)
@@ -231,13 +640,14 @@ impl DatabaseRowTest {
                     .get_cell_protobuf(&cell_id)
                     .await
                     .unwrap()
-                    .parser::<SelectOptionCellDataParser>()
+                    .parser::<ChecklistCellDataParser>()
                     .unwrap();
 
                 let s = cell_data
-                    .select_options
+                    .items
                     .into_iter()
-                    .map(|option| option.name)
+                    .filter(|item| item.done)
+                    .map(|item| item.name)
                     .collect::<Vec<String>>()
                     .join(SELECTION_IDS_SEPARATOR);
 
@@ -265,23 +675,119 @@ impl DatabaseRowTest {
                 assert_eq!(cell_data.content, expected);
                 // assert_eq!(cell_data.url, expected);
             }
+            FieldType::Relation => {
+                let cell_data = self
+                    .editor
+                    .get_cell_protobuf(&cell_id)
+                    .await
+                    .unwrap()
+                    .parser::<RelationCellDataParser>()
+                    .unwrap();
+
+                let s = cell_data
+                    .rows
+                    .into_iter()
+                    .map(|row| row.row_id)
+                    .collect::<Vec<String>>()
+                    .join(SELECTION_IDS_SEPARATOR);
+
+                assert_eq!(s, expected);
+            }
+            FieldType::Formula => {
+                let cell_data = self
+                    .editor
+                    .get_cell_protobuf(&cell_id)
+                    .await
+                    .unwrap()
+                    .parser::<FormulaCellDataParser>()
+                    .unwrap();
+
+                assert_eq!(cell_data.content, expected);
+            }
+            FieldType::Rating => {
+                let cell_data = self
+                    .editor
+                    .get_cell_protobuf(&cell_id)
+                    .await
+                    .unwrap()
+                    .parser::<RatingCellDataParser>()
+                    .unwrap();
+
+                assert_eq!(cell_data.to_string(), expected);
+            }
+            FieldType::Progress => {
+                let cell_data = self
+                    .editor
+                    .get_cell_protobuf(&cell_id)
+                    .await
+                    .unwrap()
+                    .parser::<ProgressCellDataParser>()
+                    .unwrap();
+
+                assert_eq!(cell_data.content, expected);
+            }
+            FieldType::Email => {
+                let cell_data = self
+                    .editor
+                    .get_cell_protobuf(&cell_id)
+                    .await
+                    .unwrap()
+                    .parser::<EmailCellDataParser>()
+                    .unwrap();
+
+                assert_eq!(cell_data.email, expected);
+            }
+            FieldType::PhoneNumber => {
+                let cell_data = self
+                    .editor
+                    .get_cell_protobuf(&cell_id)
+                    .await
+                    .unwrap()
+                    .parser::<PhoneNumberCellDataParser>()
+                    .unwrap();
+
+                assert_eq!(cell_data.formatted, expected);
+            }
+            FieldType::Attachment => {
+                let cell_data = self
+                    .editor
+                    .get_cell_protobuf(&cell_id)
+                    .await
+                    .unwrap()
+                    .parser::<AttachmentCellDataParser>()
+                    .unwrap();
+
+                let s = cell_data
+                    .attachments
+                    .into_iter()
+                    .map(|attachment| attachment.name)
+                    .collect::<Vec<String>>()
+                    .join(SELECTION_IDS_SEPARATOR);
+
+                assert_eq!(s, expected);
+            }
+            FieldType::Person => {
+                let cell_data = self
+                    .editor
+                    .get_cell_protobuf(&cell_id)
+                    .await
+                    .unwrap()
+                    .parser::<PersonCellDataParser>()
+                    .unwrap();
+
+                let s = cell_data
+                    .members
+                    .into_iter()
+                    .map(|member| member.name)
+                    .collect::<Vec<String>>()
+                    .join(SELECTION_IDS_SEPARATOR);
+
+                assert_eq!(s, expected);
+            }
         }
     }
 }
 
-fn block_from_row_pbs(row_orders: Vec<RowPB>) -> Vec<DatabaseBlockRow> {
-    let mut map: HashMap<String, DatabaseBlockRow> = HashMap::new();
-    row_orders.into_iter().for_each(|row_pb| {
-        let block_id = row_pb.block_id().to_owned();
-        let cloned_block_id = block_id.clone();
-        map.entry(block_id)
-            .or_insert_with(|| DatabaseBlockRow::new(cloned_block_id, vec![]))
-            .row_ids
-            .push(row_pb.id);
-    });
-    map.into_values().collect::<Vec<_>>()
-}
-
 impl std::ops::Deref for DatabaseRowTest {
     type Target = DatabaseEditorTest;
 
@@ -362,6 +868,13 @@ impl<'a> CreateRowScriptBuilder<'a> {
                     FieldType::DateTime => self.builder.insert_date_cell(&data.input),
                     FieldType::Checkbox => self.builder.insert_checkbox_cell(&data.input),
                     FieldType::URL => self.builder.insert_url_cell(&data.input),
+                    FieldType::Progress => self.builder.insert_progress_cell(&data.input),
+                    FieldType::Email => self.builder.insert_email_cell(&data.input),
+                    FieldType::PhoneNumber => self.builder.insert_phone_number_cell(&data.input),
+                    FieldType::Person => {
+                        let member_ids = data.input.split(SELECTION_IDS_SEPARATOR).collect::<Vec<&str>>();
+                        self.builder.insert_person_cell(&member_ids)
+                    }
                     _ => "".to_owned(),
                 };
 
@@ -1,6 +1,7 @@
 use flowy_database::entities::FieldType;
 use flowy_database::services::field::{
-    ChecklistTypeOptionPB, DateCellChangeset, MultiSelectTypeOptionPB, SelectOptionPB, SingleSelectTypeOptionPB,
+    AttachmentCellChangeset, AttachmentPB, ChecklistCellChangeset, ChecklistTypeOptionPB, DateCellChangeset,
+    MultiSelectTypeOptionPB, SelectOptionPB, SingleSelectTypeOptionPB,
 };
 use flowy_database::services::row::RowRevisionBuilder;
 use grid_model::{FieldRevision, RowRevision};
@@ -62,6 +63,46 @@ impl<'a> GridRowTestBuilder<'a> {
         url_field.id.clone()
     }
 
+    pub fn insert_rating_cell(&mut self, data: &str) -> String {
+        let rating_field = self.field_rev_with_type(&FieldType::Rating);
+        self.inner_builder.insert_text_cell(&rating_field.id, data.to_string());
+        rating_field.id.clone()
+    }
+
+    pub fn insert_progress_cell(&mut self, data: &str) -> String {
+        let progress_field = self.field_rev_with_type(&FieldType::Progress);
+        self.inner_builder
+            .insert_text_cell(&progress_field.id, data.to_string());
+        progress_field.id.clone()
+    }
+
+    pub fn insert_email_cell(&mut self, data: &str) -> String {
+        let email_field = self.field_rev_with_type(&FieldType::Email);
+        self.inner_builder.insert_text_cell(&email_field.id, data.to_string());
+        email_field.id.clone()
+    }
+
+    pub fn insert_phone_number_cell(&mut self, data: &str) -> String {
+        let phone_number_field = self.field_rev_with_type(&FieldType::PhoneNumber);
+        self.inner_builder
+            .insert_text_cell(&phone_number_field.id, data.to_string());
+        phone_number_field.id.clone()
+    }
+
+    pub fn insert_attachment_cell(&mut self, attachments: Vec<AttachmentPB>) -> String {
+        let value = serde_json::to_string(&AttachmentCellChangeset::from_insert_attachments(attachments)).unwrap();
+        let attachment_field = self.field_rev_with_type(&FieldType::Attachment);
+        self.inner_builder.insert_text_cell(&attachment_field.id, value);
+        attachment_field.id.clone()
+    }
+
+    pub fn insert_person_cell(&mut self, member_ids: &[&str]) -> String {
+        let person_field = self.field_rev_with_type(&FieldType::Person);
+        let member_ids = member_ids.iter().map(|member_id| member_id.to_string()).collect();
+        self.inner_builder.insert_person_cell(&person_field.id, member_ids);
+        person_field.id.clone()
+    }
+
     pub fn insert_single_select_cell<F>(&mut self, f: F) -> String
     where
         F: Fn(Vec<SelectOptionPB>) -> SelectOptionPB,
@@ -95,10 +136,13 @@ impl<'a> GridRowTestBuilder<'a> {
     {
         let checklist_field = self.field_rev_with_type(&FieldType::Checklist);
         let type_option = ChecklistTypeOptionPB::from(&checklist_field);
+        // The options of the type option are the items of the cell. Toggle the items to make them done.
         let options = f(type_option.options);
-        let ops_ids = options.iter().map(|option| option.id.clone()).collect::<Vec<_>>();
-        self.inner_builder
-            .insert_select_option_cell(&checklist_field.id, ops_ids);
+        let item_ids = options.iter().map(|option| option.id.clone()).collect::<Vec<_>>();
+        self.inner_builder.insert_checklist_cell(
+            &checklist_field.id,
+            ChecklistCellChangeset::from_toggle_item_ids(item_ids),
+        );
 
         checklist_field.id.clone()
     }
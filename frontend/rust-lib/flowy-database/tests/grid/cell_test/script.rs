@@ -1,18 +1,141 @@
 use crate::grid::database_editor::DatabaseEditorTest;
-use flowy_database::entities::CellChangesetPB;
+use flowy_database::entities::{
+    CellChangePB, CellChangesetPB, CellPathParams, CopyRangeParams, PasteRangeParams, RangeClipboardPB,
+};
+use flowy_database::services::block_manager::DatabaseBlockEvent;
+use flowy_database::services::field::{
+    select_type_option_from_field_rev, CellValidationErrorPB, CellValidationRulePB, TypedCellData,
+};
+use flowy_error::ErrorCode;
+use std::time::Duration;
+use tokio::sync::broadcast::Receiver;
 
 pub enum CellScript {
-    UpdateCell { changeset: CellChangesetPB, is_err: bool },
+    UpdateCell {
+        changeset: CellChangesetPB,
+        is_err: bool,
+    },
+    SetFieldLocked {
+        field_id: String,
+        locked: bool,
+    },
+    /// Asserts updating the cell fails with the error code.
+    AssertUpdateCellError {
+        changeset: CellChangesetPB,
+        code: ErrorCode,
+    },
+    /// Asserts updating the cell fails with the validation error of the rule.
+    AssertCellValidationError {
+        changeset: CellChangesetPB,
+        rule: CellValidationRulePB,
+    },
+    AssertInvalidCells {
+        field_id: String,
+        expected_row_ids: Vec<String>,
+    },
+    /// Updates the cells at once, and asserts the error codes of the changesets that can't be
+    /// applied.
+    UpdateCells {
+        changesets: Vec<CellChangesetPB>,
+        strict: bool,
+        expected_error_codes: Vec<ErrorCode>,
+    },
+    AssertCellDisplayStr {
+        row_id: String,
+        field_id: String,
+        expected: String,
+    },
+    /// Asserts the display strings of the cells of the field in the two rows are the same.
+    AssertCellsEqual {
+        row_id: String,
+        other_row_id: String,
+        field_id: String,
+    },
+    AssertTypedCell {
+        row_id: String,
+        field_id: String,
+        expected: TypedCellData,
+    },
+    /// Copies the range of cells to the clipboard of the test.
+    CopyRange {
+        row_ids: Vec<String>,
+        field_ids: Vec<String>,
+    },
+    SetClipboard(RangeClipboardPB),
+    /// Asserts the values of the column of the tsv of the clipboard.
+    AssertClipboardTsvColumn {
+        column: usize,
+        expected: Vec<String>,
+    },
+    /// Pastes the clipboard of the test, and asserts the error codes of the cells that can't be
+    /// pasted.
+    PasteRange {
+        anchor_row_id: String,
+        anchor_field_id: String,
+        create_rows: bool,
+        expected_error_codes: Vec<ErrorCode>,
+    },
+    AppendToCell {
+        row_id: String,
+        field_id: String,
+        text: String,
+    },
+    /// Asserts appending the text to the cell fails with the error code.
+    AssertAppendToCellError {
+        row_id: String,
+        field_id: String,
+        text: String,
+        code: ErrorCode,
+    },
+    /// Asserts the names of the options of the select option field.
+    AssertSelectOptionNames {
+        field_id: String,
+        expected: Vec<String>,
+    },
+    AssertRowCount(usize),
+    Undo,
+    SetNotifyCellValues(bool),
+    /// Subscribes the row events of the blocks, the following row updated event is asserted by
+    /// the `AssertNotifiedCellChanges`.
+    SubscribeBlockEvent,
+    /// Asserts the old and new values of the text cells that are carried by the next row updated
+    /// event. The order of the cells doesn't matter.
+    AssertNotifiedCellChanges(Vec<ExpectedCellChange>),
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExpectedCellChange {
+    pub row_id: String,
+    pub field_id: String,
+    pub old_content: String,
+    pub new_content: String,
+}
+
+impl std::convert::From<CellChangePB> for ExpectedCellChange {
+    fn from(cell_change: CellChangePB) -> Self {
+        Self {
+            row_id: cell_change.row_id,
+            field_id: cell_change.field_id,
+            old_content: String::from_utf8(cell_change.old_data).unwrap(),
+            new_content: String::from_utf8(cell_change.new_data).unwrap(),
+        }
+    }
 }
 
 pub struct DatabaseCellTest {
     inner: DatabaseEditorTest,
+    clipboard: RangeClipboardPB,
+    block_event_rx: Option<Receiver<DatabaseBlockEvent>>,
 }
 
 impl DatabaseCellTest {
     pub async fn new() -> Self {
         let inner = DatabaseEditorTest::new_table().await;
-        Self { inner }
+        Self {
+            inner,
+            clipboard: RangeClipboardPB::default(),
+            block_event_rx: None,
+        }
     }
 
     pub async fn run_scripts(&mut self, scripts: Vec<CellScript>) {
@@ -38,6 +161,187 @@ impl DatabaseCellTest {
                 } else {
                     result.unwrap();
                 }
+            }
+            CellScript::SetFieldLocked { field_id, locked } => {
+                self.editor.set_field_locked(&field_id, locked).await.unwrap();
+            }
+            CellScript::AssertUpdateCellError { changeset, code } => {
+                let error = self
+                    .editor
+                    .update_cell_with_changeset(&changeset.row_id, &changeset.field_id, changeset.type_cell_data)
+                    .await
+                    .unwrap_err();
+                assert_eq!(error.code, code.value());
+            }
+            CellScript::AssertCellValidationError { changeset, rule } => {
+                let error = self
+                    .editor
+                    .update_cell_with_changeset(&changeset.row_id, &changeset.field_id, changeset.type_cell_data)
+                    .await
+                    .unwrap_err();
+                assert_eq!(error.code, ErrorCode::InvalidParams.value());
+                let validation_error = CellValidationErrorPB::from_error(&error).unwrap();
+                assert_eq!(validation_error.field_id, changeset.field_id);
+                assert_eq!(validation_error.row_id, changeset.row_id);
+                assert_eq!(validation_error.rule, rule);
+            }
+            CellScript::AssertInvalidCells {
+                field_id,
+                expected_row_ids,
+            } => {
+                let row_ids = self.editor.find_invalid_cells(&field_id).await.unwrap();
+                assert_eq!(row_ids, expected_row_ids);
+            }
+            CellScript::UpdateCells {
+                changesets,
+                strict,
+                expected_error_codes,
+            } => {
+                let errors = self.editor.update_cells(changesets, strict).await.unwrap();
+                let error_codes = errors.iter().map(|error| error.code).collect::<Vec<i32>>();
+                let expected_error_codes = expected_error_codes
+                    .iter()
+                    .map(|code| code.value())
+                    .collect::<Vec<i32>>();
+                assert_eq!(error_codes, expected_error_codes);
+            }
+            CellScript::AssertCellDisplayStr {
+                row_id,
+                field_id,
+                expected,
+            } => {
+                let params = CellPathParams {
+                    database_id: self.view_id.clone(),
+                    field_id,
+                    row_id,
+                };
+                assert_eq!(self.editor.get_cell_display_str(&params).await, expected);
+            }
+            CellScript::AssertCellsEqual {
+                row_id,
+                other_row_id,
+                field_id,
+            } => {
+                let params = CellPathParams {
+                    database_id: self.view_id.clone(),
+                    field_id: field_id.clone(),
+                    row_id,
+                };
+                let other_params = CellPathParams {
+                    database_id: self.view_id.clone(),
+                    field_id,
+                    row_id: other_row_id,
+                };
+                assert_eq!(
+                    self.editor.get_cell_display_str(&params).await,
+                    self.editor.get_cell_display_str(&other_params).await
+                );
+            }
+            CellScript::AssertTypedCell {
+                row_id,
+                field_id,
+                expected,
+            } => {
+                let typed_cell_data = self.editor.get_typed_cell(&row_id, &field_id).await.unwrap();
+                assert_eq!(typed_cell_data, expected);
+            }
+            CellScript::CopyRange { row_ids, field_ids } => {
+                let params = CopyRangeParams {
+                    view_id: self.view_id.clone(),
+                    row_ids,
+                    field_ids,
+                };
+                self.clipboard = self.editor.copy_range(params).await.unwrap();
+            }
+            CellScript::SetClipboard(clipboard) => {
+                self.clipboard = clipboard;
+            }
+            CellScript::AssertClipboardTsvColumn { column, expected } => {
+                let values = self
+                    .clipboard
+                    .tsv
+                    .split('\n')
+                    .map(|line| line.split('\t').nth(column).unwrap_or_default().to_owned())
+                    .collect::<Vec<String>>();
+                assert_eq!(values, expected);
+            }
+            CellScript::PasteRange {
+                anchor_row_id,
+                anchor_field_id,
+                create_rows,
+                expected_error_codes,
+            } => {
+                let params = PasteRangeParams {
+                    view_id: self.view_id.clone(),
+                    anchor_row_id,
+                    anchor_field_id,
+                    clipboard: self.clipboard.clone(),
+                    create_rows,
+                };
+                let errors = self.editor.paste_range(params).await.unwrap();
+                let error_codes = errors.iter().map(|error| error.code).collect::<Vec<i32>>();
+                let expected_error_codes = expected_error_codes
+                    .iter()
+                    .map(|code| code.value())
+                    .collect::<Vec<i32>>();
+                assert_eq!(error_codes, expected_error_codes);
+            }
+            CellScript::AppendToCell { row_id, field_id, text } => {
+                self.editor.append_to_cell(&row_id, &field_id, &text).await.unwrap();
+            }
+            CellScript::AssertAppendToCellError {
+                row_id,
+                field_id,
+                text,
+                code,
+            } => {
+                let error = self.editor.append_to_cell(&row_id, &field_id, &text).await.unwrap_err();
+                assert_eq!(error.code, code.value());
+            }
+            CellScript::AssertSelectOptionNames { field_id, expected } => {
+                let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
+                let type_option = select_type_option_from_field_rev(&field_rev).unwrap();
+                let names = type_option
+                    .options()
+                    .iter()
+                    .map(|option| option.name.clone())
+                    .collect::<Vec<String>>();
+                assert_eq!(names, expected);
+            }
+            CellScript::AssertRowCount(expected) => {
+                let row_revs = self.editor.get_all_row_revs(&self.view_id).await.unwrap();
+                assert_eq!(row_revs.len(), expected);
+            }
+            CellScript::Undo => {
+                assert!(self.editor.undo().await.unwrap());
+            }
+            CellScript::SetNotifyCellValues(enabled) => {
+                self.editor.set_notify_cell_values(enabled);
+            }
+            CellScript::SubscribeBlockEvent => {
+                self.block_event_rx = Some(self.editor.subscribe_block_event());
+            }
+            CellScript::AssertNotifiedCellChanges(mut expected) => {
+                let receiver = self.block_event_rx.as_mut().unwrap();
+                let rows = loop {
+                    let event = tokio::time::timeout(Duration::from_secs(2), receiver.recv())
+                        .await
+                        .expect("No row is updated")
+                        .unwrap();
+                    match event {
+                        DatabaseBlockEvent::UpdateRow { row, .. } => break vec![row],
+                        DatabaseBlockEvent::UpdateRows { rows, .. } => break rows,
+                        _ => {}
+                    }
+                };
+                let mut cell_changes = rows
+                    .into_iter()
+                    .flat_map(|row| row.cell_changes)
+                    .map(ExpectedCellChange::from)
+                    .collect::<Vec<ExpectedCellChange>>();
+                cell_changes.sort();
+                expected.sort();
+                assert_eq!(cell_changes, expected);
             } // CellScript::AssertGridRevisionPad => {
               //     sleep(Duration::from_millis(2 * REVISION_WRITE_INTERVAL_IN_MILLIS)).await;
               //     let mut grid_rev_manager = grid_manager.make_grid_rev_manager(&self.grid_id, pool.clone()).unwrap();
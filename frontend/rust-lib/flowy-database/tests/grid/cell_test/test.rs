@@ -1,10 +1,20 @@
 use crate::grid::cell_test::script::CellScript::*;
-use crate::grid::cell_test::script::DatabaseCellTest;
+use crate::grid::cell_test::script::{CellScript, DatabaseCellTest, ExpectedCellChange};
 use crate::grid::field_test::util::make_date_cell_string;
-use flowy_database::entities::{CellChangesetPB, FieldType};
+use crate::grid::mock_data::{
+    COMPLETED, FACEBOOK, FIRST_THING, GOOGLE, LUCAS_ID, PAUSED, PLANNED, SECOND_THING, THIRD_THING, TWITTER,
+};
+use flowy_database::entities::{CellChangesetPB, FieldChangesetParams, FieldType, RangeClipboardPB};
 use flowy_database::services::cell::ToCellChangesetString;
 use flowy_database::services::field::selection_type_option::SelectOptionCellChangeset;
-use flowy_database::services::field::{ChecklistTypeOptionPB, MultiSelectTypeOptionPB, SingleSelectTypeOptionPB};
+use flowy_database::services::field::{
+    AttachmentCellChangeset, AttachmentPB, CellValidationRulePB, ChecklistCellChangeset, ChecklistItem,
+    DateTypeOptionPB, FormulaTypeOptionPB, FormulaValue, MultiSelectTypeOptionPB, NumberTypeOptionPB,
+    PersonCellChangeset, ProgressSourcePB, ProgressTypeOptionPB, RelationCellChangeset, RelationTypeOptionPB,
+    RichTextTypeOptionPB, SingleSelectTypeOptionPB, TypedCellData, TypedNumber,
+};
+use flowy_error::ErrorCode;
+use grid_model::TypeOptionDataSerializer;
 
 #[tokio::test]
 async fn grid_cell_update() {
@@ -35,12 +45,30 @@ async fn grid_cell_update() {
                         .to_cell_changeset_str()
                 }
                 FieldType::Checklist => {
-                    let type_option = ChecklistTypeOptionPB::from(field_rev);
-                    SelectOptionCellChangeset::from_insert_option_id(&type_option.options.first().unwrap().id)
-                        .to_cell_changeset_str()
+                    ChecklistCellChangeset::from_insert_items(vec![ChecklistItem::new("item")]).to_cell_changeset_str()
                 }
                 FieldType::Checkbox => "1".to_string(),
                 FieldType::URL => "1".to_string(),
+                FieldType::Relation => {
+                    RelationCellChangeset::from_insert_row_ids(vec![row_rev.id.clone()]).to_cell_changeset_str()
+                }
+                FieldType::Rating => "3".to_string(),
+                FieldType::Progress => "45%".to_string(),
+                FieldType::Email => "lucas@appflowy.io".to_string(),
+                FieldType::PhoneNumber => "(555) 123-4567".to_string(),
+                FieldType::Attachment => {
+                    AttachmentCellChangeset::from_insert_attachments(vec![AttachmentPB::from_url(
+                        "logo.png",
+                        "https://appflowy.io/logo.png",
+                        "image/png",
+                    )])
+                    .to_cell_changeset_str()
+                }
+                FieldType::Person => {
+                    PersonCellChangeset::from_insert_member_ids(vec![LUCAS_ID.to_owned()]).to_cell_changeset_str()
+                }
+                // The cells of these fields are read-only.
+                FieldType::CreatedTime | FieldType::LastEditedTime | FieldType::Formula => continue,
             };
 
             scripts.push(UpdateCell {
@@ -99,3 +127,1299 @@ async fn url_cell_date_test() {
         }
     }
 }
+
+#[tokio::test]
+async fn checklist_cell_data_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let checklist_field = test.get_first_field_rev(FieldType::Checklist).clone();
+    let row_id = test.row_revs[1].id.clone();
+    let new_item = ChecklistItem::new("Check the emails");
+    let scripts = vec![UpdateCell {
+        changeset: CellChangesetPB {
+            database_id: test.view_id.clone(),
+            row_id: row_id.clone(),
+            field_id: checklist_field.id.clone(),
+            type_cell_data: ChecklistCellChangeset::from_insert_items(vec![new_item.clone()]).to_cell_changeset_str(),
+        },
+        is_err: false,
+    }];
+    test.run_scripts(scripts).await;
+
+    let cells = test
+        .editor
+        .get_cells_for_field(&test.view_id, &checklist_field.id)
+        .await
+        .unwrap();
+    for (i, cell) in cells.into_iter().enumerate() {
+        let cell_data = cell.into_check_list_field_cell_data().unwrap();
+        match i {
+            // All the items of the first row are done.
+            0 => {
+                assert_eq!(cell_data.items.len(), 3);
+                assert_eq!(cell_data.percentage(), 1.0);
+            }
+            // The item is appended to the items of the type option.
+            1 => {
+                assert_eq!(cell_data.items.len(), 4);
+                assert_eq!(cell_data.items[3].name, new_item.name);
+                assert!(!cell_data.items[3].done);
+                assert_eq!(cell_data.percentage(), 0.0);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[tokio::test]
+async fn timestamp_cell_data_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let created_time_field = test.get_first_field_rev(FieldType::CreatedTime).clone();
+    let last_edited_time_field = test.get_first_field_rev(FieldType::LastEditedTime).clone();
+    let row_id = test.row_revs[0].id.clone();
+    let scripts = vec![
+        // The cells of the created time and the last edited time fields are read-only.
+        UpdateCell {
+            changeset: CellChangesetPB {
+                database_id: test.view_id.clone(),
+                row_id: row_id.clone(),
+                field_id: created_time_field.id.clone(),
+                type_cell_data: make_date_cell_string("123"),
+            },
+            is_err: true,
+        },
+        UpdateCell {
+            changeset: CellChangesetPB {
+                database_id: test.view_id.clone(),
+                row_id,
+                field_id: text_field.id.clone(),
+                type_cell_data: "B".to_string(),
+            },
+            is_err: false,
+        },
+    ];
+    test.run_scripts(scripts).await;
+
+    let created_times = test
+        .editor
+        .get_cells_for_field(&test.view_id, &created_time_field.id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|cell| cell.into_timestamp_field_cell_data().unwrap().0.unwrap())
+        .collect::<Vec<i64>>();
+    let last_edited_times = test
+        .editor
+        .get_cells_for_field(&test.view_id, &last_edited_time_field.id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|cell| cell.into_timestamp_field_cell_data().unwrap().0.unwrap())
+        .collect::<Vec<i64>>();
+
+    assert_eq!(created_times[0], 1653609600);
+    // Only the edited row has a new last edited time.
+    assert!(last_edited_times[0] > created_times[0]);
+    assert_eq!(last_edited_times[1..], created_times[1..]);
+}
+
+#[tokio::test]
+async fn relation_cell_data_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let relation_field = test.get_first_field_rev(FieldType::Relation).clone();
+    // Links the rows of the grid to the rows of itself.
+    let type_option = RelationTypeOptionPB {
+        database_id: test.view_id.clone(),
+        is_single: false,
+    };
+    test.editor
+        .update_field_type_option(
+            &test.view_id,
+            &relation_field.id,
+            type_option.protobuf_bytes().to_vec(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let row_id = test.row_revs[0].id.clone();
+    let linked_row_ids = vec![test.row_revs[2].id.clone(), test.row_revs[3].id.clone()];
+    let scripts = vec![UpdateCell {
+        changeset: CellChangesetPB {
+            database_id: test.view_id.clone(),
+            row_id: row_id.clone(),
+            field_id: relation_field.id.clone(),
+            type_cell_data: RelationCellChangeset::from_insert_row_ids(linked_row_ids.clone()).to_cell_changeset_str(),
+        },
+        is_err: false,
+    }];
+    test.run_scripts(scripts).await;
+
+    let resolver = test.sdk.grid_manager.as_ref();
+    let candidate_rows = test
+        .editor
+        .get_relation_candidate_rows(&relation_field.id, resolver)
+        .await
+        .unwrap();
+    assert_eq!(candidate_rows.len(), test.row_revs.len());
+
+    // The linked rows are resolved into the text of their primary cells.
+    let cell_data = test
+        .editor
+        .get_relation_cell_data(&row_id, &relation_field.id, resolver)
+        .await
+        .unwrap();
+    assert_eq!(cell_data.database_id, test.view_id);
+    assert_eq!(
+        cell_data
+            .rows
+            .iter()
+            .map(|row| row.name.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["C", "DA"]
+    );
+
+    // The reference to the deleted row is dropped when reading the cell.
+    test.editor.delete_row(&linked_row_ids[0]).await.unwrap();
+    let cell_data = test
+        .editor
+        .get_relation_cell_data(&row_id, &relation_field.id, resolver)
+        .await
+        .unwrap();
+    assert_eq!(cell_data.rows.len(), 1);
+    assert_eq!(cell_data.rows[0].row_id, linked_row_ids[1]);
+
+    let cells = test
+        .editor
+        .get_cells_for_field(&test.view_id, &relation_field.id)
+        .await
+        .unwrap();
+    let relation_cell_data = cells
+        .into_iter()
+        .find(|cell| cell.row_id == row_id)
+        .unwrap()
+        .into_relation_field_cell_data()
+        .unwrap();
+    assert_eq!(relation_cell_data.row_ids, vec![linked_row_ids[1].clone()]);
+}
+
+#[tokio::test]
+async fn formula_cell_data_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let number_field = test.get_first_field_rev(FieldType::Number).clone();
+    let formula_field = test.get_first_field_rev(FieldType::Formula).clone();
+    let type_option = FormulaTypeOptionPB {
+        expression: r#"prop("Price") * 2"#.to_owned(),
+    };
+    test.editor
+        .update_field_type_option(
+            &test.view_id,
+            &formula_field.id,
+            type_option.protobuf_bytes().to_vec(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    // The formula references itself.
+    let type_option = FormulaTypeOptionPB {
+        expression: r#"prop("Formula") + 1"#.to_owned(),
+    };
+    assert!(test
+        .editor
+        .update_field_type_option(
+            &test.view_id,
+            &formula_field.id,
+            type_option.protobuf_bytes().to_vec(),
+            None,
+        )
+        .await
+        .is_err());
+
+    let row_id = test.row_revs[0].id.clone();
+    let scripts = vec![
+        // The cells of the formula fields are read-only.
+        UpdateCell {
+            changeset: CellChangesetPB {
+                database_id: test.view_id.clone(),
+                row_id: row_id.clone(),
+                field_id: formula_field.id.clone(),
+                type_cell_data: "1".to_string(),
+            },
+            is_err: true,
+        },
+        UpdateCell {
+            changeset: CellChangesetPB {
+                database_id: test.view_id.clone(),
+                row_id: row_id.clone(),
+                field_id: number_field.id.clone(),
+                type_cell_data: "5".to_string(),
+            },
+            is_err: false,
+        },
+    ];
+    test.run_scripts(scripts).await;
+
+    let contents = test
+        .editor
+        .get_cells_for_field(&test.view_id, &formula_field.id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|cell| cell.into_formula_field_cell_data().unwrap().content())
+        .collect::<Vec<String>>();
+    // The formula of the first row is evaluated again after its price changed.
+    assert_eq!(contents[0], "10");
+    assert_eq!(contents[1], "4");
+    assert_eq!(contents[2], "6");
+}
+
+#[tokio::test]
+async fn formula_rename_field_test() {
+    let test = DatabaseCellTest::new().await;
+    let number_field = test.get_first_field_rev(FieldType::Number).clone();
+    let formula_field = test.get_first_field_rev(FieldType::Formula).clone();
+    let type_option = FormulaTypeOptionPB {
+        expression: r#"prop("Total") + 1"#.to_owned(),
+    };
+    test.editor
+        .update_field_type_option(
+            &test.view_id,
+            &formula_field.id,
+            type_option.protobuf_bytes().to_vec(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    // The formula references itself after the formula field is renamed to "Total".
+    let error = test
+        .editor
+        .update_field(FieldChangesetParams {
+            field_id: formula_field.id.clone(),
+            database_id: test.view_id.clone(),
+            name: Some("Total".to_owned()),
+            ..Default::default()
+        })
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::FormulaCircularReference.value());
+    let field_rev = test.editor.get_field_rev(&formula_field.id).await.unwrap();
+    assert_eq!(field_rev.name, formula_field.name);
+
+    // The formulas are evaluated again after the price field is renamed to "Total".
+    test.editor
+        .update_field(FieldChangesetParams {
+            field_id: number_field.id.clone(),
+            database_id: test.view_id.clone(),
+            name: Some("Total".to_owned()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let contents = test
+        .editor
+        .get_cells_for_field(&test.view_id, &formula_field.id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|cell| cell.into_formula_field_cell_data().unwrap().content())
+        .collect::<Vec<String>>();
+    assert_eq!(contents[0], "2");
+    assert_eq!(contents[1], "3");
+    assert_eq!(contents[2], "4");
+
+    // The new formula field references itself by its default name.
+    let name = format!("Property {}", test.field_revs.len() + 1);
+    let type_option = FormulaTypeOptionPB {
+        expression: format!(r#"prop("{}")"#, name),
+    };
+    let error = test
+        .editor
+        .create_new_field_rev_with_type_option(&FieldType::Formula, Some(type_option.protobuf_bytes().to_vec()))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::FormulaCircularReference.value());
+    assert!(test
+        .editor
+        .get_field_revs(None)
+        .await
+        .unwrap()
+        .iter()
+        .all(|field_rev| field_rev.name != name));
+}
+
+#[tokio::test]
+async fn progress_cell_data_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let checklist_field = test.get_first_field_rev(FieldType::Checklist).clone();
+    let progress_field = test.get_first_field_rev(FieldType::Progress).clone();
+    let type_option = ProgressTypeOptionPB {
+        source: ProgressSourcePB::Checklist,
+        checklist_field_id: checklist_field.id.clone(),
+        ..Default::default()
+    };
+    test.editor
+        .update_field_type_option(
+            &test.view_id,
+            &progress_field.id,
+            type_option.protobuf_bytes().to_vec(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let row_id = test.row_revs[1].id.clone();
+    let scripts = vec![
+        // The cells of the progress field that is derived from a checklist are read-only.
+        UpdateCell {
+            changeset: CellChangesetPB {
+                database_id: test.view_id.clone(),
+                row_id: row_id.clone(),
+                field_id: progress_field.id.clone(),
+                type_cell_data: "45%".to_string(),
+            },
+            is_err: true,
+        },
+        UpdateCell {
+            changeset: CellChangesetPB {
+                database_id: test.view_id.clone(),
+                row_id,
+                field_id: checklist_field.id.clone(),
+                type_cell_data: ChecklistCellChangeset::from_insert_items(vec![ChecklistItem::new("item")])
+                    .to_cell_changeset_str(),
+            },
+            is_err: false,
+        },
+    ];
+    test.run_scripts(scripts).await;
+
+    let values = test
+        .editor
+        .get_cells_for_field(&test.view_id, &progress_field.id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|cell| cell.into_progress_field_cell_data().unwrap().0)
+        .collect::<Vec<Option<f64>>>();
+    // All the items of the first row are done, and none of the items of the second row are done.
+    assert_eq!(values[0], Some(1.0));
+    assert_eq!(values[1], Some(0.0));
+    assert_eq!(values[2], None);
+}
+
+#[tokio::test]
+async fn grid_cell_update_locked_field_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let changeset = |field_id: &str, data: &str| CellChangesetPB {
+        database_id: test.view_id.clone(),
+        row_id: test.row_revs[0].id.clone(),
+        field_id: field_id.to_owned(),
+        type_cell_data: data.to_owned(),
+    };
+    let scripts = vec![
+        SetFieldLocked {
+            field_id: number_field_id.clone(),
+            locked: true,
+        },
+        AssertUpdateCellError {
+            changeset: changeset(&number_field_id, "123"),
+            code: ErrorCode::FieldLocked,
+        },
+        // The other fields are not locked.
+        UpdateCell {
+            changeset: changeset(&text_field_id, "hello"),
+            is_err: false,
+        },
+        SetFieldLocked {
+            field_id: number_field_id.clone(),
+            locked: false,
+        },
+        UpdateCell {
+            changeset: changeset(&number_field_id, "123"),
+            is_err: false,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_update_cells_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let email_field_id = test.get_first_field_rev(FieldType::Email).id.clone();
+    let row_ids = test
+        .row_revs
+        .iter()
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<String>>();
+    let changeset = |row_id: &str, field_id: &str, data: &str| CellChangesetPB {
+        database_id: test.view_id.clone(),
+        row_id: row_id.to_owned(),
+        field_id: field_id.to_owned(),
+        type_cell_data: data.to_owned(),
+    };
+    let scripts = vec![
+        UpdateCells {
+            changesets: vec![
+                changeset(&row_ids[0], &text_field_id, "X"),
+                changeset(&row_ids[0], &email_field_id, "x@appflowy.io"),
+                changeset(&row_ids[1], &text_field_id, "B"),
+                // The later changeset of the same cell wins.
+                changeset(&row_ids[1], &text_field_id, "C"),
+            ],
+            strict: false,
+            expected_error_codes: vec![],
+        },
+        AssertCellDisplayStr {
+            row_id: row_ids[0].clone(),
+            field_id: text_field_id.clone(),
+            expected: "X".to_owned(),
+        },
+        AssertCellDisplayStr {
+            row_id: row_ids[0].clone(),
+            field_id: email_field_id.clone(),
+            expected: "x@appflowy.io".to_owned(),
+        },
+        AssertCellDisplayStr {
+            row_id: row_ids[1].clone(),
+            field_id: text_field_id.clone(),
+            expected: "C".to_owned(),
+        },
+        // The cells updated at once are restored by one undo.
+        Undo,
+        AssertCellDisplayStr {
+            row_id: row_ids[0].clone(),
+            field_id: text_field_id.clone(),
+            expected: "A".to_owned(),
+        },
+        AssertCellDisplayStr {
+            row_id: row_ids[0].clone(),
+            field_id: email_field_id,
+            expected: "Lucas@AppFlowy.io".to_owned(),
+        },
+        AssertCellDisplayStr {
+            row_id: row_ids[1].clone(),
+            field_id: text_field_id,
+            expected: "".to_owned(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_update_cells_with_invalid_changesets_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let date_field_id = test.get_first_field_rev(FieldType::DateTime).id.clone();
+    let row_id = test.row_revs[0].id.clone();
+    let changeset = |row_id: &str, field_id: &str, data: &str| CellChangesetPB {
+        database_id: test.view_id.clone(),
+        row_id: row_id.to_owned(),
+        field_id: field_id.to_owned(),
+        type_cell_data: data.to_owned(),
+    };
+    let invalid_changesets = vec![
+        changeset(&row_id, "unknown field", "A"),
+        changeset("unknown row", &text_field_id, "A"),
+        // The changeset of the date cell must be a json string.
+        changeset(&row_id, &date_field_id, "A"),
+    ];
+    let expected_error_codes = vec![
+        ErrorCode::FieldRecordNotFound,
+        ErrorCode::RecordNotFound,
+        ErrorCode::Internal,
+    ];
+    let mut strict_changesets = invalid_changesets.clone();
+    strict_changesets.push(changeset(&row_id, &text_field_id, "strict"));
+    let mut changesets = invalid_changesets;
+    changesets.push(changeset(&row_id, &text_field_id, "hello"));
+    let scripts = vec![
+        // Nothing is applied in strict mode.
+        UpdateCells {
+            changesets: strict_changesets,
+            strict: true,
+            expected_error_codes: expected_error_codes.clone(),
+        },
+        AssertCellDisplayStr {
+            row_id: row_id.clone(),
+            field_id: text_field_id.clone(),
+            expected: "A".to_owned(),
+        },
+        // The valid changesets are still applied.
+        UpdateCells {
+            changesets,
+            strict: false,
+            expected_error_codes,
+        },
+        AssertCellDisplayStr {
+            row_id,
+            field_id: text_field_id,
+            expected: "hello".to_owned(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_copy_and_paste_range_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let field_ids = [
+        FieldType::RichText,
+        FieldType::Number,
+        FieldType::DateTime,
+        FieldType::SingleSelect,
+    ]
+    .into_iter()
+    .map(|field_type| test.get_first_field_rev(field_type).id.clone())
+    .collect::<Vec<String>>();
+    let row_ids = test
+        .row_revs
+        .iter()
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<String>>();
+    let mut scripts = vec![
+        CopyRange {
+            row_ids: vec![row_ids[2].clone(), row_ids[4].clone()],
+            field_ids: field_ids.clone(),
+        },
+        AssertClipboardTsvColumn {
+            column: 0,
+            expected: vec!["C".to_owned(), "AE".to_owned()],
+        },
+        AssertClipboardTsvColumn {
+            column: 3,
+            expected: vec![COMPLETED.to_owned(), PLANNED.to_owned()],
+        },
+        PasteRange {
+            anchor_row_id: row_ids[0].clone(),
+            anchor_field_id: field_ids[0].clone(),
+            create_rows: false,
+            expected_error_codes: vec![],
+        },
+    ];
+    // The text, number, date and select cells are pasted without loss, the empty number cell
+    // clears the pasted cell.
+    for field_id in field_ids.iter() {
+        scripts.push(AssertCellsEqual {
+            row_id: row_ids[0].clone(),
+            other_row_id: row_ids[2].clone(),
+            field_id: field_id.clone(),
+        });
+        scripts.push(AssertCellsEqual {
+            row_id: row_ids[1].clone(),
+            other_row_id: row_ids[4].clone(),
+            field_id: field_id.clone(),
+        });
+    }
+    // The pasted cells are restored by one undo.
+    scripts.push(Undo);
+    scripts.push(AssertCellDisplayStr {
+        row_id: row_ids[0].clone(),
+        field_id: field_ids[0].clone(),
+        expected: "A".to_owned(),
+    });
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_paste_range_with_different_field_types_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let single_select_field_id = test.get_first_field_rev(FieldType::SingleSelect).id.clone();
+    let multi_select_field_id = test.get_first_field_rev(FieldType::MultiSelect).id.clone();
+    let row_ids = test
+        .row_revs
+        .iter()
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<String>>();
+    let scripts = vec![
+        // The number cell is converted to the text cell.
+        CopyRange {
+            row_ids: vec![row_ids[2].clone()],
+            field_ids: vec![number_field_id],
+        },
+        PasteRange {
+            anchor_row_id: row_ids[0].clone(),
+            anchor_field_id: text_field_id.clone(),
+            create_rows: false,
+            expected_error_codes: vec![],
+        },
+        AssertCellDisplayStr {
+            row_id: row_ids[0].clone(),
+            field_id: text_field_id,
+            expected: "3".to_owned(),
+        },
+        // The tsv that is copied from somewhere else is pasted as text, and the select options
+        // are matched by their names.
+        SetClipboard(RangeClipboardPB {
+            tsv: format!("{}\t{}, unknown", PAUSED, FACEBOOK),
+            json: "".to_owned(),
+        }),
+        PasteRange {
+            anchor_row_id: row_ids[1].clone(),
+            anchor_field_id: single_select_field_id.clone(),
+            create_rows: false,
+            expected_error_codes: vec![],
+        },
+        AssertCellDisplayStr {
+            row_id: row_ids[1].clone(),
+            field_id: single_select_field_id,
+            expected: PAUSED.to_owned(),
+        },
+        AssertCellDisplayStr {
+            row_id: row_ids[1].clone(),
+            field_id: multi_select_field_id,
+            expected: FACEBOOK.to_owned(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_paste_range_past_the_last_row_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let number_of_rows = test.row_revs.len();
+    let last_row_id = test.row_revs[number_of_rows - 1].id.clone();
+    let clipboard = RangeClipboardPB {
+        tsv: "X\nY\nZ".to_owned(),
+        json: "".to_owned(),
+    };
+    let scripts = vec![
+        // The values past the last row are dropped.
+        SetClipboard(clipboard),
+        PasteRange {
+            anchor_row_id: last_row_id.clone(),
+            anchor_field_id: text_field_id.clone(),
+            create_rows: false,
+            expected_error_codes: vec![],
+        },
+        AssertRowCount(number_of_rows),
+        AssertCellDisplayStr {
+            row_id: last_row_id.clone(),
+            field_id: text_field_id.clone(),
+            expected: "X".to_owned(),
+        },
+        Undo,
+        // The rows are created for them.
+        PasteRange {
+            anchor_row_id: last_row_id.clone(),
+            anchor_field_id: text_field_id.clone(),
+            create_rows: true,
+            expected_error_codes: vec![],
+        },
+        AssertRowCount(number_of_rows + 2),
+        // The created rows and the pasted cells are restored by one undo.
+        Undo,
+        AssertRowCount(number_of_rows),
+        AssertCellDisplayStr {
+            row_id: last_row_id,
+            field_id: text_field_id,
+            expected: "AE".to_owned(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_number_validation_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::Number).clone();
+    let mut type_option = field_rev.get_type_option::<NumberTypeOptionPB>(field_rev.ty).unwrap();
+    type_option.min = Some("2".to_owned());
+    type_option.max = Some("4".to_owned());
+    test.editor
+        .update_field_type_option(
+            &test.view_id,
+            &field_rev.id,
+            type_option.protobuf_bytes().to_vec(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let row_ids = test
+        .row_revs
+        .iter()
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<String>>();
+    let changeset = |row_id: &str, type_cell_data: &str| CellChangesetPB {
+        database_id: test.view_id.clone(),
+        row_id: row_id.to_owned(),
+        field_id: field_rev.id.clone(),
+        type_cell_data: type_cell_data.to_owned(),
+    };
+    let scripts = vec![
+        // The stored numbers that violate the new rules are kept.
+        AssertInvalidCells {
+            field_id: field_rev.id.clone(),
+            expected_row_ids: vec![row_ids[0].clone(), row_ids[5].clone()],
+        },
+        AssertCellDisplayStr {
+            row_id: row_ids[0].clone(),
+            field_id: field_rev.id.clone(),
+            expected: "$1".to_owned(),
+        },
+        AssertCellValidationError {
+            changeset: changeset(&row_ids[1], "10"),
+            rule: CellValidationRulePB::NumberMax,
+        },
+        AssertCellValidationError {
+            changeset: changeset(&row_ids[1], "1.5"),
+            rule: CellValidationRulePB::NumberMin,
+        },
+        AssertCellDisplayStr {
+            row_id: row_ids[1].clone(),
+            field_id: field_rev.id.clone(),
+            expected: "$2".to_owned(),
+        },
+        UpdateCell {
+            changeset: changeset(&row_ids[0], "3"),
+            is_err: false,
+        },
+        // The empty cell doesn't violate the rules.
+        UpdateCell {
+            changeset: changeset(&row_ids[5], ""),
+            is_err: false,
+        },
+        AssertInvalidCells {
+            field_id: field_rev.id.clone(),
+            expected_row_ids: vec![],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_text_validation_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::RichText).clone();
+    let mut type_option = field_rev
+        .get_type_option::<RichTextTypeOptionPB>(field_rev.ty)
+        .unwrap_or_default();
+    type_option.max_length = Some(2);
+    type_option.pattern = Some("^[A-Z]{2}$".to_owned());
+    test.editor
+        .update_field_type_option(
+            &test.view_id,
+            &field_rev.id,
+            type_option.protobuf_bytes().to_vec(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let row_ids = test
+        .row_revs
+        .iter()
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<String>>();
+    let changeset = |row_id: &str, type_cell_data: &str| CellChangesetPB {
+        database_id: test.view_id.clone(),
+        row_id: row_id.to_owned(),
+        field_id: field_rev.id.clone(),
+        type_cell_data: type_cell_data.to_owned(),
+    };
+    let scripts = vec![
+        // The empty text of the second row doesn't need to match the pattern.
+        AssertInvalidCells {
+            field_id: field_rev.id.clone(),
+            expected_row_ids: vec![row_ids[0].clone(), row_ids[2].clone()],
+        },
+        AssertCellValidationError {
+            changeset: changeset(&row_ids[3], "ABC"),
+            rule: CellValidationRulePB::TextMaxLength,
+        },
+        AssertCellValidationError {
+            changeset: changeset(&row_ids[3], "ab"),
+            rule: CellValidationRulePB::TextPattern,
+        },
+        AssertCellDisplayStr {
+            row_id: row_ids[3].clone(),
+            field_id: field_rev.id.clone(),
+            expected: "DA".to_owned(),
+        },
+        UpdateCell {
+            changeset: changeset(&row_ids[0], "AB"),
+            is_err: false,
+        },
+        AssertInvalidCells {
+            field_id: field_rev.id.clone(),
+            expected_row_ids: vec![row_ids[2].clone()],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_date_validation_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::DateTime).clone();
+    let mut type_option = field_rev.get_type_option::<DateTypeOptionPB>(field_rev.ty).unwrap();
+    type_option.min_date = Some(1668000000);
+    type_option.max_date = Some(1670000000);
+    test.editor
+        .update_field_type_option(
+            &test.view_id,
+            &field_rev.id,
+            type_option.protobuf_bytes().to_vec(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let row_ids = test
+        .row_revs
+        .iter()
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<String>>();
+    let changeset = |row_id: &str, timestamp: &str| CellChangesetPB {
+        database_id: test.view_id.clone(),
+        row_id: row_id.to_owned(),
+        field_id: field_rev.id.clone(),
+        type_cell_data: make_date_cell_string(timestamp),
+    };
+    let scripts = vec![
+        AssertInvalidCells {
+            field_id: field_rev.id.clone(),
+            expected_row_ids: vec![
+                row_ids[0].clone(),
+                row_ids[1].clone(),
+                row_ids[2].clone(),
+                row_ids[5].clone(),
+            ],
+        },
+        AssertCellValidationError {
+            changeset: changeset(&row_ids[3], "1600000000"),
+            rule: CellValidationRulePB::DateMin,
+        },
+        AssertCellValidationError {
+            changeset: changeset(&row_ids[3], "1700000000"),
+            rule: CellValidationRulePB::DateMax,
+        },
+        UpdateCell {
+            changeset: changeset(&row_ids[0], "1669000000"),
+            is_err: false,
+        },
+        AssertInvalidCells {
+            field_id: field_rev.id.clone(),
+            expected_row_ids: vec![row_ids[1].clone(), row_ids[2].clone(), row_ids[5].clone()],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_get_row_typed_test() {
+    let test = DatabaseCellTest::new().await;
+    let formula_field = test.get_first_field_rev(FieldType::Formula).clone();
+    let type_option = FormulaTypeOptionPB {
+        expression: r#"prop("Price") * 2"#.to_owned(),
+    };
+    test.editor
+        .update_field_type_option(
+            &test.view_id,
+            &formula_field.id,
+            type_option.protobuf_bytes().to_vec(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let row_id = test.row_revs[0].id.clone();
+    let typed_cells = test.editor.get_row_typed(&row_id).await.unwrap();
+    assert_eq!(typed_cells.len(), test.field_revs.len());
+    for field_rev in test.field_revs.iter() {
+        let field_type: FieldType = field_rev.ty.into();
+        let typed_cell_data = typed_cells.get(&field_rev.id).unwrap().clone();
+        match field_type {
+            FieldType::RichText => assert_eq!(typed_cell_data, TypedCellData::Text("A".to_owned())),
+            FieldType::Number => assert_eq!(
+                typed_cell_data,
+                TypedCellData::Number(Some(TypedNumber {
+                    decimal: "1".to_owned(),
+                    value: 1.0,
+                }))
+            ),
+            FieldType::DateTime => assert_eq!(
+                typed_cell_data,
+                TypedCellData::Date {
+                    timestamp: Some(1647251762),
+                    include_time: false,
+                }
+            ),
+            // The first row doesn't have the cell of the single select field.
+            FieldType::SingleSelect => assert_eq!(typed_cell_data, TypedCellData::SingleSelect(None)),
+            FieldType::MultiSelect => match typed_cell_data {
+                TypedCellData::MultiSelect(options) => {
+                    let names = options.iter().map(|option| option.name.as_str()).collect::<Vec<&str>>();
+                    assert_eq!(names, vec![GOOGLE, FACEBOOK]);
+                }
+                _ => panic!("Expected the multi select cell, but got {:?}", typed_cell_data),
+            },
+            FieldType::Checkbox => assert_eq!(typed_cell_data, TypedCellData::Checkbox(true)),
+            FieldType::URL => assert_eq!(
+                typed_cell_data,
+                TypedCellData::Url {
+                    url: "https://www.appflowy.io/".to_owned(),
+                    content: "AppFlowy website - https://www.appflowy.io".to_owned(),
+                }
+            ),
+            FieldType::Checklist => match typed_cell_data {
+                TypedCellData::Checklist(items) => {
+                    assert_eq!(items.len(), 3);
+                    assert!(items.iter().all(|item| item.done));
+                }
+                _ => panic!("Expected the checklist cell, but got {:?}", typed_cell_data),
+            },
+            FieldType::CreatedTime => assert_eq!(
+                typed_cell_data,
+                TypedCellData::Date {
+                    timestamp: Some(1653609600),
+                    include_time: false,
+                }
+            ),
+            FieldType::LastEditedTime => {
+                assert!(matches!(
+                    typed_cell_data,
+                    TypedCellData::Date { timestamp: Some(_), .. }
+                ))
+            }
+            FieldType::Relation => assert_eq!(typed_cell_data, TypedCellData::Relation(vec![])),
+            FieldType::Formula => assert_eq!(
+                typed_cell_data,
+                TypedCellData::Formula {
+                    value: Some(FormulaValue::Number(2.0)),
+                    error: None,
+                }
+            ),
+            FieldType::Rating => assert_eq!(typed_cell_data, TypedCellData::Rating(5)),
+            FieldType::Progress => assert_eq!(typed_cell_data, TypedCellData::Progress(Some(1.0))),
+            FieldType::Email => assert_eq!(typed_cell_data, TypedCellData::Email("Lucas@AppFlowy.io".to_owned())),
+            FieldType::PhoneNumber => assert_eq!(
+                typed_cell_data,
+                TypedCellData::PhoneNumber("+1 (555) 123-4567".to_owned())
+            ),
+            FieldType::Attachment => match typed_cell_data {
+                TypedCellData::Attachment(attachments) => {
+                    let names = attachments
+                        .iter()
+                        .map(|attachment| attachment.name.as_str())
+                        .collect::<Vec<&str>>();
+                    assert_eq!(names, vec!["logo.png", "guide.pdf"]);
+                }
+                _ => panic!("Expected the attachment cell, but got {:?}", typed_cell_data),
+            },
+            FieldType::Person => match typed_cell_data {
+                TypedCellData::Person(members) => {
+                    let member_ids = members.iter().map(|member| member.id.as_str()).collect::<Vec<&str>>();
+                    assert_eq!(member_ids, vec![LUCAS_ID]);
+                }
+                _ => panic!("Expected the person cell, but got {:?}", typed_cell_data),
+            },
+        }
+    }
+}
+
+#[tokio::test]
+async fn grid_cell_get_typed_cell_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let number_field = test.get_first_field_rev(FieldType::Number).clone();
+    let single_select_field = test.get_first_field_rev(FieldType::SingleSelect).clone();
+    let checkbox_field = test.get_first_field_rev(FieldType::Checkbox).clone();
+    let planned_option = SingleSelectTypeOptionPB::from(&single_select_field)
+        .options
+        .into_iter()
+        .find(|option| option.name == PLANNED)
+        .unwrap();
+    let row_id = test.row_revs[1].id.clone();
+    let changeset = |field_id: &str, type_cell_data: String| CellChangesetPB {
+        database_id: test.view_id.clone(),
+        row_id: row_id.clone(),
+        field_id: field_id.to_owned(),
+        type_cell_data,
+    };
+    let scripts = vec![
+        // The text of the second row is empty.
+        AssertTypedCell {
+            row_id: row_id.clone(),
+            field_id: text_field.id.clone(),
+            expected: TypedCellData::Text("".to_owned()),
+        },
+        UpdateCell {
+            changeset: changeset(&text_field.id, "Hello".to_owned()),
+            is_err: false,
+        },
+        UpdateCell {
+            changeset: changeset(&number_field.id, "$12.50".to_owned()),
+            is_err: false,
+        },
+        UpdateCell {
+            changeset: changeset(
+                &single_select_field.id,
+                SelectOptionCellChangeset::from_insert_option_id(&planned_option.id).to_cell_changeset_str(),
+            ),
+            is_err: false,
+        },
+        UpdateCell {
+            changeset: changeset(&checkbox_field.id, "No".to_owned()),
+            is_err: false,
+        },
+        AssertTypedCell {
+            row_id: row_id.clone(),
+            field_id: text_field.id.clone(),
+            expected: TypedCellData::Text("Hello".to_owned()),
+        },
+        AssertTypedCell {
+            row_id: row_id.clone(),
+            field_id: number_field.id.clone(),
+            expected: TypedCellData::Number(Some(TypedNumber {
+                decimal: "12.50".to_owned(),
+                value: 12.5,
+            })),
+        },
+        AssertTypedCell {
+            row_id: row_id.clone(),
+            field_id: single_select_field.id.clone(),
+            expected: TypedCellData::SingleSelect(Some(planned_option)),
+        },
+        AssertTypedCell {
+            row_id: row_id.clone(),
+            field_id: checkbox_field.id.clone(),
+            expected: TypedCellData::Checkbox(false),
+        },
+    ];
+    test.run_scripts(scripts).await;
+
+    let error = test.editor.get_typed_cell("unknown", &text_field.id).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::RecordNotFound.value());
+    let error = test.editor.get_typed_cell(&row_id, "unknown").await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::FieldRecordNotFound.value());
+}
+
+#[tokio::test]
+async fn grid_cell_update_notifies_cell_values_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let row_id = test.row_revs[0].id.clone();
+    let scripts = vec![
+        SetNotifyCellValues(true),
+        SubscribeBlockEvent,
+        UpdateCell {
+            changeset: CellChangesetPB {
+                database_id: test.view_id.clone(),
+                row_id: row_id.clone(),
+                field_id: text_field_id.clone(),
+                type_cell_data: "X".to_owned(),
+            },
+            is_err: false,
+        },
+        AssertNotifiedCellChanges(vec![ExpectedCellChange {
+            row_id,
+            field_id: text_field_id,
+            old_content: "A".to_owned(),
+            new_content: "X".to_owned(),
+        }]),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_update_cells_notifies_cell_values_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let row_ids = test
+        .row_revs
+        .iter()
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<String>>();
+    let changeset = |row_id: &str, data: &str| CellChangesetPB {
+        database_id: test.view_id.clone(),
+        row_id: row_id.to_owned(),
+        field_id: text_field_id.clone(),
+        type_cell_data: data.to_owned(),
+    };
+    let cell_change = |row_id: &str, old_content: &str, new_content: &str| ExpectedCellChange {
+        row_id: row_id.to_owned(),
+        field_id: text_field_id.clone(),
+        old_content: old_content.to_owned(),
+        new_content: new_content.to_owned(),
+    };
+    let scripts = vec![
+        SetNotifyCellValues(true),
+        SubscribeBlockEvent,
+        UpdateCells {
+            changesets: vec![
+                changeset(&row_ids[0], "X"),
+                changeset(&row_ids[1], "B"),
+                changeset(&row_ids[1], "C"),
+            ],
+            strict: false,
+            expected_error_codes: vec![],
+        },
+        // One entry for each changed cell, the cell that's changed twice carries its value before
+        // the first change and its value after the last change.
+        AssertNotifiedCellChanges(vec![
+            cell_change(&row_ids[0], "A", "X"),
+            cell_change(&row_ids[1], "", "C"),
+        ]),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_update_without_cell_values_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let row_id = test.row_revs[0].id.clone();
+    let scripts = vec![
+        SubscribeBlockEvent,
+        UpdateCell {
+            changeset: CellChangesetPB {
+                database_id: test.view_id.clone(),
+                row_id,
+                field_id: text_field_id,
+                type_cell_data: "X".to_owned(),
+            },
+            is_err: false,
+        },
+        AssertNotifiedCellChanges(vec![]),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_append_to_text_cell_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let first_row_id = test.row_revs[0].id.clone();
+    let second_row_id = test.row_revs[1].id.clone();
+    let scripts = vec![
+        AppendToCell {
+            row_id: first_row_id.clone(),
+            field_id: text_field_id.clone(),
+            text: "B".to_owned(),
+        },
+        AssertCellDisplayStr {
+            row_id: first_row_id,
+            field_id: text_field_id.clone(),
+            expected: "A\nB".to_owned(),
+        },
+        // The separator is only inserted after the existing text.
+        AppendToCell {
+            row_id: second_row_id.clone(),
+            field_id: text_field_id.clone(),
+            text: "B".to_owned(),
+        },
+        AssertCellDisplayStr {
+            row_id: second_row_id,
+            field_id: text_field_id,
+            expected: "B".to_owned(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_append_to_multi_select_cell_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let multi_select_field_id = test.get_first_field_rev(FieldType::MultiSelect).id.clone();
+    let row_id = test.row_revs[0].id.clone();
+    let scripts = vec![
+        // The option that's already in the cell isn't added again.
+        AppendToCell {
+            row_id: row_id.clone(),
+            field_id: multi_select_field_id.clone(),
+            text: FACEBOOK.to_owned(),
+        },
+        AssertCellDisplayStr {
+            row_id: row_id.clone(),
+            field_id: multi_select_field_id.clone(),
+            expected: format!("{},{}", GOOGLE, FACEBOOK),
+        },
+        AppendToCell {
+            row_id: row_id.clone(),
+            field_id: multi_select_field_id.clone(),
+            text: "Slack".to_owned(),
+        },
+        AssertCellDisplayStr {
+            row_id: row_id.clone(),
+            field_id: multi_select_field_id.clone(),
+            expected: format!("{},{},Slack", GOOGLE, FACEBOOK),
+        },
+        AssertSelectOptionNames {
+            field_id: multi_select_field_id.clone(),
+            expected: vec![
+                "Slack".to_owned(),
+                GOOGLE.to_owned(),
+                FACEBOOK.to_owned(),
+                TWITTER.to_owned(),
+            ],
+        },
+        // Creating the option and updating the cell are undone together.
+        Undo,
+        AssertCellDisplayStr {
+            row_id,
+            field_id: multi_select_field_id.clone(),
+            expected: format!("{},{}", GOOGLE, FACEBOOK),
+        },
+        AssertSelectOptionNames {
+            field_id: multi_select_field_id,
+            expected: vec![GOOGLE.to_owned(), FACEBOOK.to_owned(), TWITTER.to_owned()],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_append_to_checklist_cell_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let checklist_field_id = test.get_first_field_rev(FieldType::Checklist).id.clone();
+    let row_id = test.row_revs[0].id.clone();
+    let scripts = vec![AppendToCell {
+        row_id: row_id.clone(),
+        field_id: checklist_field_id.clone(),
+        text: "Go home".to_owned(),
+    }];
+    test.run_scripts(scripts).await;
+
+    match test.editor.get_typed_cell(&row_id, &checklist_field_id).await.unwrap() {
+        TypedCellData::Checklist(items) => {
+            let items = items
+                .iter()
+                .map(|item| (item.name.as_str(), item.done))
+                .collect::<Vec<(&str, bool)>>();
+            assert_eq!(
+                items,
+                vec![
+                    (FIRST_THING, true),
+                    (SECOND_THING, true),
+                    (THIRD_THING, true),
+                    ("Go home", false)
+                ]
+            );
+        }
+        typed_cell_data => panic!("Expected the checklist cell, but got {:?}", typed_cell_data),
+    }
+}
+
+#[tokio::test]
+async fn grid_cell_append_to_unsupported_cell_test() {
+    let mut test = DatabaseCellTest::new().await;
+    let row_id = test.row_revs[0].id.clone();
+    let scripts = [FieldType::Number, FieldType::DateTime, FieldType::Checkbox]
+        .iter()
+        .map(|field_type| AssertAppendToCellError {
+            row_id: row_id.clone(),
+            field_id: test.get_first_field_rev(field_type.clone()).id.clone(),
+            text: "1".to_owned(),
+            code: ErrorCode::FieldInvalidOperation,
+        })
+        .collect::<Vec<CellScript>>();
+    test.run_scripts(scripts).await;
+}
@@ -0,0 +1,205 @@
+use crate::grid::database_editor::DatabaseEditorTest;
+use flowy_database::entities::FieldType;
+use flowy_error::ErrorCode;
+
+pub enum CommentScript {
+    AddComment {
+        row_index: usize,
+        field_id: String,
+        content: &'static str,
+    },
+    AssertAddCommentError {
+        row_index: usize,
+        field_id: String,
+        content: &'static str,
+        code: ErrorCode,
+    },
+    /// Deletes the comment at `comment_index` of the comments of the cell.
+    DeleteComment {
+        row_index: usize,
+        field_id: String,
+        comment_index: usize,
+    },
+    AssertDeleteCommentError {
+        row_index: usize,
+        field_id: String,
+        comment_id: String,
+        code: ErrorCode,
+    },
+    /// Asserts the contents of the comments of the cell in the order they were added.
+    AssertComments {
+        row_index: usize,
+        field_id: String,
+        expected: Vec<&'static str>,
+    },
+    SwitchFieldType {
+        field_id: String,
+        field_type: FieldType,
+    },
+    DeleteField {
+        field_id: String,
+    },
+    DeleteFields {
+        field_ids: Vec<String>,
+    },
+    /// Duplicates the row, the duplicated row is inserted right after it.
+    DuplicateRow {
+        row_index: usize,
+        include_comments: bool,
+    },
+    /// Exports the raw backup of the database and imports it as a new database, then asserts the
+    /// comments of the cell in the new database.
+    AssertImportedComments {
+        new_database_id: &'static str,
+        row_index: usize,
+        field_id: String,
+        expected: Vec<&'static str>,
+    },
+}
+
+pub struct DatabaseCommentTest {
+    inner: DatabaseEditorTest,
+}
+
+impl DatabaseCommentTest {
+    pub async fn new() -> Self {
+        let inner = DatabaseEditorTest::new_table().await;
+        Self { inner }
+    }
+
+    pub async fn run_scripts(&mut self, scripts: Vec<CommentScript>) {
+        for script in scripts {
+            self.run_script(script).await;
+        }
+    }
+
+    pub async fn run_script(&mut self, script: CommentScript) {
+        match script {
+            CommentScript::AddComment {
+                row_index,
+                field_id,
+                content,
+            } => {
+                let row_id = self.row_revs[row_index].id.clone();
+                let comment = self.editor.add_cell_comment(&row_id, &field_id, content).await.unwrap();
+                assert_eq!(comment.content, content);
+                assert_eq!(comment.author, self.sdk.user_session.user_id().unwrap());
+            }
+            CommentScript::AssertAddCommentError {
+                row_index,
+                field_id,
+                content,
+                code,
+            } => {
+                let row_id = self.row_revs[row_index].id.clone();
+                let error = self
+                    .editor
+                    .add_cell_comment(&row_id, &field_id, content)
+                    .await
+                    .unwrap_err();
+                assert_eq!(error.code, code.value());
+            }
+            CommentScript::DeleteComment {
+                row_index,
+                field_id,
+                comment_index,
+            } => {
+                let row_id = self.row_revs[row_index].id.clone();
+                let comments = self.editor.get_cell_comments(&row_id, &field_id).await.unwrap();
+                self.editor
+                    .delete_cell_comment(&row_id, &field_id, &comments[comment_index].id)
+                    .await
+                    .unwrap();
+            }
+            CommentScript::AssertDeleteCommentError {
+                row_index,
+                field_id,
+                comment_id,
+                code,
+            } => {
+                let row_id = self.row_revs[row_index].id.clone();
+                let error = self
+                    .editor
+                    .delete_cell_comment(&row_id, &field_id, &comment_id)
+                    .await
+                    .unwrap_err();
+                assert_eq!(error.code, code.value());
+            }
+            CommentScript::AssertComments {
+                row_index,
+                field_id,
+                expected,
+            } => {
+                let row_id = self.row_revs[row_index].id.clone();
+                let comments = self.editor.get_cell_comments(&row_id, &field_id).await.unwrap();
+                let contents = comments
+                    .iter()
+                    .map(|comment| comment.content.as_str())
+                    .collect::<Vec<&str>>();
+                assert_eq!(contents, expected);
+            }
+            CommentScript::SwitchFieldType { field_id, field_type } => {
+                self.editor.switch_to_field_type(&field_id, &field_type).await.unwrap();
+            }
+            CommentScript::DeleteField { field_id } => {
+                self.editor.delete_field(&field_id).await.unwrap();
+            }
+            CommentScript::DeleteFields { field_ids } => {
+                self.editor.delete_fields(field_ids).await.unwrap();
+            }
+            CommentScript::DuplicateRow {
+                row_index,
+                include_comments,
+            } => {
+                let row_id = self.row_revs[row_index].id.clone();
+                let row = self
+                    .editor
+                    .duplicate_row(&row_id, include_comments, false)
+                    .await
+                    .unwrap()
+                    .row;
+                self.row_revs = self.get_row_revs().await;
+                assert_eq!(self.row_revs[row_index + 1].id, row.id);
+                assert_eq!(self.row_revs[row_index + 1].cells, self.row_revs[row_index].cells);
+            }
+            CommentScript::AssertImportedComments {
+                new_database_id,
+                row_index,
+                field_id,
+                expected,
+            } => {
+                let database_manager = self.sdk.grid_manager.clone();
+                let backup = database_manager.export_database_raw(&self.view_id).await.unwrap();
+                database_manager
+                    .import_database_raw(backup, new_database_id)
+                    .await
+                    .unwrap();
+                let editor = database_manager.get_database_editor(new_database_id).await.unwrap();
+                let row_revs = editor.get_all_row_revs(new_database_id).await.unwrap();
+                let comments = editor
+                    .get_cell_comments(&row_revs[row_index].id, &field_id)
+                    .await
+                    .unwrap();
+                let contents = comments
+                    .iter()
+                    .map(|comment| comment.content.as_str())
+                    .collect::<Vec<&str>>();
+                assert_eq!(contents, expected);
+            }
+        }
+    }
+}
+
+impl std::ops::Deref for DatabaseCommentTest {
+    type Target = DatabaseEditorTest;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl std::ops::DerefMut for DatabaseCommentTest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
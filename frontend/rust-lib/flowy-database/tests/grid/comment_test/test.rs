@@ -0,0 +1,183 @@
+use crate::grid::comment_test::script::CommentScript::*;
+use crate::grid::comment_test::script::DatabaseCommentTest;
+use flowy_database::entities::FieldType;
+use flowy_error::ErrorCode;
+
+#[tokio::test]
+async fn grid_add_and_delete_cell_comment_test() {
+    let mut test = DatabaseCommentTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let scripts = vec![
+        AddComment {
+            row_index: 0,
+            field_id: field_id.clone(),
+            content: "first",
+        },
+        AddComment {
+            row_index: 0,
+            field_id: field_id.clone(),
+            content: "second",
+        },
+        AssertComments {
+            row_index: 0,
+            field_id: field_id.clone(),
+            expected: vec!["first", "second"],
+        },
+        // The comments belong to the cell, the other cells of the row don't have them.
+        AssertComments {
+            row_index: 1,
+            field_id: field_id.clone(),
+            expected: vec![],
+        },
+        DeleteComment {
+            row_index: 0,
+            field_id: field_id.clone(),
+            comment_index: 0,
+        },
+        AssertComments {
+            row_index: 0,
+            field_id,
+            expected: vec!["second"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_add_invalid_cell_comment_test() {
+    let mut test = DatabaseCommentTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let scripts = vec![
+        AssertAddCommentError {
+            row_index: 0,
+            field_id: field_id.clone(),
+            content: "  ",
+            code: ErrorCode::CellCommentIsEmpty,
+        },
+        AssertAddCommentError {
+            row_index: 0,
+            field_id: "unknown".to_owned(),
+            content: "hello",
+            code: ErrorCode::FieldRecordNotFound,
+        },
+        AssertDeleteCommentError {
+            row_index: 0,
+            field_id,
+            comment_id: "unknown".to_owned(),
+            code: ErrorCode::RecordNotFound,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cell_comment_survives_switching_field_type_test() {
+    let mut test = DatabaseCommentTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let scripts = vec![
+        AddComment {
+            row_index: 0,
+            field_id: field_id.clone(),
+            content: "check the amount",
+        },
+        SwitchFieldType {
+            field_id: field_id.clone(),
+            field_type: FieldType::Checkbox,
+        },
+        AssertComments {
+            row_index: 0,
+            field_id,
+            expected: vec!["check the amount"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_delete_field_removes_cell_comments_test() {
+    let mut test = DatabaseCommentTest::new().await;
+    let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let url_field_id = test.get_first_field_rev(FieldType::URL).id.clone();
+    let scripts = vec![
+        AddComment {
+            row_index: 0,
+            field_id: number_field_id.clone(),
+            content: "hello",
+        },
+        AddComment {
+            row_index: 1,
+            field_id: url_field_id.clone(),
+            content: "world",
+        },
+        DeleteField {
+            field_id: number_field_id.clone(),
+        },
+        DeleteFields {
+            field_ids: vec![url_field_id.clone()],
+        },
+    ];
+    test.run_scripts(scripts).await;
+
+    for row_rev in test.get_row_revs().await {
+        assert!(!row_rev.comments.contains_key(&number_field_id));
+        assert!(!row_rev.comments.contains_key(&url_field_id));
+    }
+}
+
+#[tokio::test]
+async fn grid_duplicate_row_with_cell_comments_test() {
+    let mut test = DatabaseCommentTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let scripts = vec![
+        AddComment {
+            row_index: 0,
+            field_id: field_id.clone(),
+            content: "hello",
+        },
+        DuplicateRow {
+            row_index: 0,
+            include_comments: true,
+        },
+        AssertComments {
+            row_index: 1,
+            field_id: field_id.clone(),
+            expected: vec!["hello"],
+        },
+        DuplicateRow {
+            row_index: 0,
+            include_comments: false,
+        },
+        AssertComments {
+            row_index: 1,
+            field_id: field_id.clone(),
+            expected: vec![],
+        },
+        // The comments of the original row are kept.
+        AssertComments {
+            row_index: 0,
+            field_id,
+            expected: vec!["hello"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_export_and_import_cell_comments_test() {
+    let mut test = DatabaseCommentTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let scripts = vec![
+        AddComment {
+            row_index: 2,
+            field_id: field_id.clone(),
+            content: "hello",
+        },
+        AssertImportedComments {
+            new_database_id: "imported_database",
+            row_index: 2,
+            field_id,
+            expected: vec!["hello"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
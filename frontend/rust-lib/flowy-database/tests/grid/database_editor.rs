@@ -190,4 +190,18 @@ impl DatabaseEditorTest {
         let cell_changeset = SelectOptionCellChangeset::from_insert_option_id(option_id);
         self.update_cell(&field_rev.id, row_id, cell_changeset).await;
     }
+
+    pub(crate) async fn update_checklist_cell(&mut self, row_id: String, changeset: ChecklistCellChangeset) {
+        let field_rev = self
+            .field_revs
+            .iter()
+            .find(|field_rev| {
+                let field_type: FieldType = field_rev.ty.into();
+                field_type == FieldType::Checklist
+            })
+            .unwrap()
+            .clone();
+
+        self.update_cell(&field_rev.id, row_id, changeset).await;
+    }
 }
@@ -1,7 +1,15 @@
 use crate::grid::database_editor::DatabaseEditorTest;
-use flowy_database::entities::{CreateFieldParams, FieldChangesetParams, FieldType};
+use flowy_database::entities::{
+    AlterFilterParams, AlterSortParams, CreateFieldParams, FieldChangesetParams, FieldType,
+};
+use flowy_database::manager::RevisionCompressConfig;
 use flowy_database::services::cell::{stringify_cell_data, TypeCellData};
+use flowy_database::services::field::{
+    select_type_option_from_field_rev, SelectOptionPB, SelectTypeOptionSharedAction,
+};
+use flowy_error::ErrorCode;
 use grid_model::FieldRevision;
+use std::time::Duration;
 
 pub enum FieldScript {
     CreateField {
@@ -13,6 +21,12 @@ pub enum FieldScript {
     DeleteField {
         field_rev: FieldRevision,
     },
+    /// Deletes the fields at once. None of them is deleted if it fails.
+    DeleteFields {
+        field_ids: Vec<String>,
+        is_ok: bool,
+    },
+    /// Switches the field to the field type, and waits until its cells are converted.
     SwitchToField {
         field_id: String,
         new_field_type: FieldType,
@@ -21,7 +35,111 @@ pub enum FieldScript {
         field_id: String,
         type_option: Vec<u8>,
     },
+    /// Duplicates the field, the duplicated field is inserted right after it.
+    DuplicateField {
+        field_id: String,
+    },
+    /// Waits until the cells of the duplicated fields are copied.
+    WaitForCopyCellsFinished,
+    /// Moves all the fields at once. The fields are left untouched if it fails.
+    ReorderFields {
+        field_ids: Vec<String>,
+        is_ok: bool,
+    },
+    SetPrimaryField {
+        field_id: String,
+        is_ok: bool,
+    },
+    /// Asserts the field is the only primary field.
+    AssertPrimaryField {
+        field_id: String,
+    },
+    /// Shows or hides the field in the view only.
+    SetViewFieldVisibility {
+        field_id: String,
+        visibility: bool,
+    },
+    /// Reorders the fields in the view only.
+    SetViewFieldOrder {
+        field_ids: Vec<String>,
+        is_ok: bool,
+    },
+    /// Asserts the visibility of the field in the view and in the database.
+    AssertFieldVisibility {
+        field_id: String,
+        view_visibility: bool,
+        database_visibility: bool,
+    },
+    AssertViewFieldOrder(Vec<String>),
+    /// Resizes the field in the view only.
+    SetViewFieldWidth {
+        field_id: String,
+        width: i32,
+    },
+    /// Asserts the width of the field in the view and in the database.
+    AssertFieldWidth {
+        field_id: String,
+        view_width: i32,
+        database_width: i32,
+    },
+    SetFieldLocked {
+        field_id: String,
+        locked: bool,
+    },
+    AssertFieldLocked {
+        field_id: String,
+        locked: bool,
+    },
+    /// Asserts the field type, the type-option of the field can't be changed, and the field can't
+    /// be deleted.
+    AssertFieldEditsLocked {
+        field_id: String,
+    },
+    /// Asserts the select options of the locked field can't be inserted, renamed or deleted.
+    AssertSelectOptionEditsLocked {
+        field_id: String,
+    },
+    /// Freezes the first fields of the view.
+    SetFrozenFieldCount(usize),
+    /// Asserts the number of the frozen fields in the setting of the view.
+    AssertFrozenFieldCount(i32),
+    /// Undoes the last edit of the database.
+    Undo,
+    UpdateFieldDescription {
+        field_id: String,
+        description: String,
+    },
+    UpdateFieldIcon {
+        field_id: String,
+        icon: String,
+    },
+    AssertFieldDescriptionAndIcon {
+        field_id: String,
+        description: String,
+        icon: String,
+    },
+    InsertFilter {
+        params: AlterFilterParams,
+    },
+    InsertSort {
+        params: AlterSortParams,
+    },
+    AssertFilterCount(usize),
+    AssertSortCount(usize),
+    /// Asserts none of the rows has the cell of the field.
+    AssertNoCells {
+        field_id: String,
+    },
+    AssertCellCount {
+        field_id: String,
+        count: usize,
+    },
+    ClearFieldCells {
+        field_id: String,
+        error_code: Option<ErrorCode>,
+    },
     AssertFieldCount(usize),
+    AssertFieldOrder(Vec<String>),
     AssertFieldFrozen {
         field_index: usize,
         frozen: bool,
@@ -30,12 +148,45 @@ pub enum FieldScript {
         field_index: usize,
         expected_type_option_data: String,
     },
+    /// Asserts each row has the same cell in both fields.
+    AssertCellsEqual {
+        field_id: String,
+        other_field_id: String,
+    },
+    /// Asserts the content of the cell. The cell is decoded by the type option of the field type
+    /// of the cell, and stringified by the type option of the current field type.
     AssertCellContent {
         field_id: String,
         row_index: usize,
-        from_field_type: FieldType,
         expected_content: String,
     },
+    /// Asserts the field type that the cell of the row is stored as.
+    AssertCellFieldType {
+        field_id: String,
+        row_index: usize,
+        field_type: FieldType,
+    },
+    /// Updates the cell of the row with the cell changeset string of the current field type.
+    UpdateCell {
+        field_id: String,
+        row_index: usize,
+        content: String,
+    },
+    /// Closes the database and opens it again with the merge threshold of the revisions.
+    ReopenWithMergeThreshold(usize),
+    /// Asserts the changes of the field, starting from the oldest one.
+    AssertFieldHistory {
+        field_id: String,
+        expected: Vec<ExpectedFieldChange>,
+    },
+}
+
+pub struct ExpectedFieldChange {
+    pub is_created: bool,
+    pub is_deleted: bool,
+    pub name: String,
+    pub field_type: FieldType,
+    pub changed_properties: Vec<&'static str>,
 }
 
 pub struct DatabaseFieldTest {
@@ -86,6 +237,12 @@ impl DatabaseFieldTest {
                 self.field_revs = self.editor.get_field_revs(None).await.unwrap();
                 assert_eq!(self.field_count, self.field_revs.len());
             }
+            FieldScript::DeleteFields { field_ids, is_ok } => {
+                let result = self.editor.delete_fields(field_ids).await;
+                assert_eq!(result.is_ok(), is_ok);
+                self.field_revs = self.editor.get_field_revs(None).await.unwrap();
+                self.field_count = self.field_revs.len();
+            }
             FieldScript::SwitchToField {
                 field_id,
                 new_field_type,
@@ -95,6 +252,7 @@ impl DatabaseFieldTest {
                     .switch_to_field_type(&field_id, &new_field_type)
                     .await
                     .unwrap();
+                self.wait_for_convert_cells_finished().await;
                 self.field_revs = self.editor.get_field_revs(None).await.unwrap();
             }
             FieldScript::UpdateTypeOption { field_id, type_option } => {
@@ -105,9 +263,235 @@ impl DatabaseFieldTest {
                     .unwrap();
                 self.field_revs = self.editor.get_field_revs(None).await.unwrap();
             }
+            FieldScript::DuplicateField { field_id } => {
+                self.field_count += 1;
+                self.editor.duplicate_field(&field_id).await.unwrap();
+                self.field_revs = self.editor.get_field_revs(None).await.unwrap();
+                assert_eq!(self.field_count, self.field_revs.len());
+            }
+            FieldScript::WaitForCopyCellsFinished => {
+                let mut retry = 0;
+                while self.editor.is_copying_cells() {
+                    assert!(retry < 50, "The cells are still being copied");
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    retry += 1;
+                }
+            }
+            FieldScript::ReorderFields { field_ids, is_ok } => {
+                let result = self.editor.reorder_fields(field_ids).await;
+                assert_eq!(result.is_ok(), is_ok);
+                self.field_revs = self.editor.get_field_revs(None).await.unwrap();
+            }
+            FieldScript::SetPrimaryField { field_id, is_ok } => {
+                let result = self.editor.set_primary_field(&field_id).await;
+                assert_eq!(result.is_ok(), is_ok);
+                self.field_revs = self.editor.get_field_revs(None).await.unwrap();
+            }
+            FieldScript::AssertPrimaryField { field_id } => {
+                let primary_field_ids = self
+                    .editor
+                    .get_field_revs(None)
+                    .await
+                    .unwrap()
+                    .iter()
+                    .filter(|field_rev| field_rev.is_primary)
+                    .map(|field_rev| field_rev.id.clone())
+                    .collect::<Vec<String>>();
+                assert_eq!(primary_field_ids, vec![field_id]);
+            }
+            FieldScript::SetViewFieldVisibility { field_id, visibility } => {
+                self.editor
+                    .set_view_field_visibility(&self.view_id, &field_id, visibility)
+                    .await
+                    .unwrap();
+            }
+            FieldScript::SetViewFieldOrder { field_ids, is_ok } => {
+                let result = self.editor.set_view_field_order(&self.view_id, field_ids).await;
+                assert_eq!(result.is_ok(), is_ok);
+            }
+            FieldScript::AssertFieldVisibility {
+                field_id,
+                view_visibility,
+                database_visibility,
+            } => {
+                let view_field_revs = self
+                    .editor
+                    .get_view_field_revs(&self.view_id, Some(vec![field_id.clone()]))
+                    .await
+                    .unwrap();
+                assert_eq!(view_field_revs[0].visibility, view_visibility);
+                let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
+                assert_eq!(field_rev.visibility, database_visibility);
+            }
+            FieldScript::AssertViewFieldOrder(expected_field_ids) => {
+                let field_ids = self
+                    .editor
+                    .get_view_field_revs(&self.view_id, None)
+                    .await
+                    .unwrap()
+                    .iter()
+                    .map(|field_rev| field_rev.id.clone())
+                    .collect::<Vec<String>>();
+                assert_eq!(field_ids, expected_field_ids);
+            }
+            FieldScript::SetViewFieldWidth { field_id, width } => {
+                self.editor
+                    .set_view_field_width(&self.view_id, &field_id, width)
+                    .await
+                    .unwrap();
+            }
+            FieldScript::AssertFieldWidth {
+                field_id,
+                view_width,
+                database_width,
+            } => {
+                let view_field_revs = self
+                    .editor
+                    .get_view_field_revs(&self.view_id, Some(vec![field_id.clone()]))
+                    .await
+                    .unwrap();
+                assert_eq!(view_field_revs[0].width, view_width);
+                let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
+                assert_eq!(field_rev.width, database_width);
+            }
+            FieldScript::Undo => {
+                assert!(self.editor.undo().await.unwrap());
+                self.wait_for_convert_cells_finished().await;
+                self.field_revs = self.editor.get_field_revs(None).await.unwrap();
+                self.field_count = self.field_revs.len();
+            }
+            FieldScript::UpdateFieldDescription { field_id, description } => {
+                self.editor
+                    .update_field_description(&field_id, &description)
+                    .await
+                    .unwrap();
+                self.field_revs = self.editor.get_field_revs(None).await.unwrap();
+            }
+            FieldScript::UpdateFieldIcon { field_id, icon } => {
+                self.editor.update_field_icon(&field_id, &icon).await.unwrap();
+                self.field_revs = self.editor.get_field_revs(None).await.unwrap();
+            }
+            FieldScript::AssertFieldDescriptionAndIcon {
+                field_id,
+                description,
+                icon,
+            } => {
+                let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
+                assert_eq!(field_rev.description, description);
+                assert_eq!(field_rev.icon, icon);
+            }
+            FieldScript::SetFieldLocked { field_id, locked } => {
+                self.editor.set_field_locked(&field_id, locked).await.unwrap();
+                self.field_revs = self.editor.get_field_revs(None).await.unwrap();
+            }
+            FieldScript::AssertFieldLocked { field_id, locked } => {
+                let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
+                assert_eq!(field_rev.locked, locked);
+            }
+            FieldScript::AssertFieldEditsLocked { field_id } => {
+                let field_locked = ErrorCode::FieldLocked.value();
+                let error = self
+                    .editor
+                    .switch_to_field_type(&field_id, &FieldType::RichText)
+                    .await
+                    .unwrap_err();
+                assert_eq!(error.code, field_locked);
+                let error = self
+                    .editor
+                    .update_field_type_option(&self.view_id, &field_id, vec![], None)
+                    .await
+                    .unwrap_err();
+                assert_eq!(error.code, field_locked);
+                let error = self.editor.delete_field(&field_id).await.unwrap_err();
+                assert_eq!(error.code, field_locked);
+                assert!(self.editor.contain_field(&field_id).await);
+            }
+            FieldScript::AssertSelectOptionEditsLocked { field_id } => {
+                let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
+                let options = select_type_option_from_field_rev(&field_rev).unwrap().options().clone();
+                let mut renamed_option = options[0].clone();
+                renamed_option.name = format!("{} (renamed)", renamed_option.name);
+                let edits = vec![
+                    (Some(SelectOptionPB::new("New option")), None),
+                    (Some(renamed_option), None),
+                    (None, Some(options[0].clone())),
+                ];
+                for (insert_option, delete_option) in edits {
+                    let error = self
+                        .editor
+                        .modify_field_rev(&field_id, |field_rev| {
+                            let mut type_option = select_type_option_from_field_rev(field_rev)?;
+                            if let Some(option) = insert_option {
+                                type_option.insert_option(option);
+                            }
+                            if let Some(option) = delete_option {
+                                type_option.delete_option(option);
+                            }
+                            field_rev.insert_type_option(&*type_option);
+                            Ok(Some(()))
+                        })
+                        .await
+                        .unwrap_err();
+                    assert_eq!(error.code, ErrorCode::FieldLocked.value());
+                }
+                let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
+                assert_eq!(
+                    select_type_option_from_field_rev(&field_rev).unwrap().options(),
+                    &options
+                );
+            }
+            FieldScript::SetFrozenFieldCount(count) => {
+                self.editor.set_frozen_field_count(&self.view_id, count).await.unwrap();
+            }
+            FieldScript::AssertFrozenFieldCount(count) => {
+                let setting = self.editor.get_setting().await.unwrap();
+                assert_eq!(setting.frozen_field_count, count);
+            }
+            FieldScript::InsertFilter { params } => {
+                self.editor.create_or_update_filter(params).await.unwrap();
+            }
+            FieldScript::InsertSort { params } => {
+                self.editor.create_or_update_sort(params).await.unwrap();
+            }
+            FieldScript::AssertFilterCount(count) => {
+                assert_eq!(self.editor.get_all_filters().await.unwrap().len(), count);
+            }
+            FieldScript::AssertSortCount(count) => {
+                assert_eq!(self.editor.get_all_sorts(&self.view_id).await.unwrap().len(), count);
+            }
+            FieldScript::AssertNoCells { field_id } => {
+                let row_revs = self.editor.get_all_row_revs(&self.view_id).await.unwrap();
+                assert!(row_revs.iter().all(|row_rev| !row_rev.cells.contains_key(&field_id)));
+            }
+            FieldScript::AssertCellCount { field_id, count } => {
+                let row_revs = self.editor.get_all_row_revs(&self.view_id).await.unwrap();
+                let cell_count = row_revs
+                    .iter()
+                    .filter(|row_rev| row_rev.cells.contains_key(&field_id))
+                    .count();
+                assert_eq!(cell_count, count);
+            }
+            FieldScript::ClearFieldCells { field_id, error_code } => {
+                let result = self.editor.clear_field_cells(&field_id).await;
+                match error_code {
+                    None => result.unwrap(),
+                    Some(error_code) => assert_eq!(result.unwrap_err().code, error_code.value()),
+                }
+            }
             FieldScript::AssertFieldCount(count) => {
                 assert_eq!(self.editor.get_field_revs(None).await.unwrap().len(), count);
             }
+            FieldScript::AssertFieldOrder(expected_field_ids) => {
+                let field_ids = self
+                    .editor
+                    .get_field_revs(None)
+                    .await
+                    .unwrap()
+                    .iter()
+                    .map(|field_rev| field_rev.id.clone())
+                    .collect::<Vec<String>>();
+                assert_eq!(field_ids, expected_field_ids);
+            }
             FieldScript::AssertFieldFrozen { field_index, frozen } => {
                 let field_revs = self.editor.get_field_revs(None).await.unwrap();
                 let field_rev = field_revs[field_index].as_ref();
@@ -122,28 +506,103 @@ impl DatabaseFieldTest {
                 let type_option_data = field_rev.get_type_option_str(field_rev.ty).unwrap();
                 assert_eq!(type_option_data, expected_type_option_data);
             }
+            FieldScript::AssertCellsEqual {
+                field_id,
+                other_field_id,
+            } => {
+                let row_revs = self.editor.get_all_row_revs(&self.view_id).await.unwrap();
+                assert!(!row_revs.is_empty());
+                for row_rev in row_revs {
+                    let cell = row_rev
+                        .cells
+                        .get(&field_id)
+                        .map(|cell_rev| cell_rev.type_cell_data.clone());
+                    let other_cell = row_rev
+                        .cells
+                        .get(&other_field_id)
+                        .map(|cell_rev| cell_rev.type_cell_data.clone());
+                    assert_eq!(cell, other_cell, "The cells of the row:{} are not equal", row_rev.id);
+                }
+            }
             FieldScript::AssertCellContent {
                 field_id,
                 row_index,
-                from_field_type,
                 expected_content,
             } => {
                 let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
                 let field_type: FieldType = field_rev.ty.into();
 
-                let rows = self.editor.get_database(&self.view_id()).await.unwrap().rows;
-                let row = rows.get(row_index).unwrap();
-                let row_rev = self.editor.get_row_rev(&row.id).await.unwrap().unwrap();
-
-                let cell_rev = row_rev.cells.get(&field_id).unwrap().clone();
-                let type_cell_data: TypeCellData = cell_rev.try_into().unwrap();
-                let content = stringify_cell_data(type_cell_data.cell_str, &from_field_type, &field_type, &field_rev);
+                let type_cell_data = self.get_type_cell_data(&field_id, row_index).await;
+                let content = stringify_cell_data(
+                    type_cell_data.cell_str,
+                    &type_cell_data.field_type,
+                    &field_type,
+                    &field_rev,
+                );
                 assert_eq!(content, expected_content);
             }
+            FieldScript::AssertCellFieldType {
+                field_id,
+                row_index,
+                field_type,
+            } => {
+                let type_cell_data = self.get_type_cell_data(&field_id, row_index).await;
+                assert_eq!(type_cell_data.field_type, field_type);
+            }
+            FieldScript::UpdateCell {
+                field_id,
+                row_index,
+                content,
+            } => {
+                let rows = self.editor.get_database(&self.view_id()).await.unwrap().rows;
+                let row_id = rows.get(row_index).unwrap().id.clone();
+                self.editor
+                    .update_cell_with_changeset(&row_id, &field_id, content)
+                    .await
+                    .unwrap();
+            }
+            FieldScript::ReopenWithMergeThreshold(merge_threshold) => {
+                let database_manager = self.sdk.grid_manager.clone();
+                database_manager.set_rev_compress_config(RevisionCompressConfig::with_merge_threshold(merge_threshold));
+                database_manager.close_all().await;
+                self.editor = database_manager.open_database(&self.view_id).await.unwrap();
+                self.field_revs = self.editor.get_field_revs(None).await.unwrap();
+            }
+            FieldScript::AssertFieldHistory { field_id, expected } => {
+                let field_changes = self.editor.get_field_history(&field_id).await.unwrap();
+                assert_eq!(field_changes.len(), expected.len());
+                for (field_change, expected) in field_changes.into_iter().zip(expected) {
+                    assert_eq!(field_change.field.id, field_id);
+                    assert_eq!(field_change.is_created, expected.is_created);
+                    assert_eq!(field_change.is_deleted, expected.is_deleted);
+                    assert_eq!(field_change.field.name, expected.name);
+                    assert_eq!(field_change.field.field_type, expected.field_type);
+                    assert_eq!(field_change.changed_properties, expected.changed_properties);
+                }
+            }
         }
     }
 }
 
+impl DatabaseFieldTest {
+    async fn wait_for_convert_cells_finished(&self) {
+        let mut retry = 0;
+        while self.editor.is_converting_cells() {
+            assert!(retry < 50, "The cells are still being converted");
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            retry += 1;
+        }
+    }
+
+    async fn get_type_cell_data(&self, field_id: &str, row_index: usize) -> TypeCellData {
+        let rows = self.editor.get_database(&self.view_id()).await.unwrap().rows;
+        let row = rows.get(row_index).unwrap();
+        let row_rev = self.editor.get_row_rev(&row.id).await.unwrap().unwrap();
+        let cell_rev = row_rev.cells.get(field_id).unwrap().clone();
+        cell_rev.try_into().unwrap()
+    }
+}
+
 impl std::ops::Deref for DatabaseFieldTest {
     type Target = DatabaseEditorTest;
 
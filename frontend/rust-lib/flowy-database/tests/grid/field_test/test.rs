@@ -1,10 +1,14 @@
-use crate::grid::field_test::script::DatabaseFieldTest;
 use crate::grid::field_test::script::FieldScript::*;
+use crate::grid::field_test::script::{DatabaseFieldTest, ExpectedFieldChange};
 use crate::grid::field_test::util::*;
 use bytes::Bytes;
-use flowy_database::entities::{FieldChangesetParams, FieldType};
+use flowy_database::entities::{
+    AlterFilterParams, AlterSortParams, FieldChangesetParams, FieldType, NumberFilterConditionPB, MIN_FIELD_WIDTH,
+};
 use flowy_database::services::field::selection_type_option::SelectOptionPB;
-use flowy_database::services::field::{gen_option_id, SingleSelectTypeOptionPB, CHECK, UNCHECK};
+use flowy_database::services::field::{gen_option_id, NumberTypeOptionPB, SingleSelectTypeOptionPB, CHECK, UNCHECK};
+use flowy_error::ErrorCode;
+use grid_model::{SortCondition, TypeOptionDataSerializer};
 
 #[tokio::test]
 async fn grid_create_field() {
@@ -123,6 +127,110 @@ async fn grid_delete_field() {
     test.run_scripts(scripts).await;
 }
 
+#[tokio::test]
+async fn grid_delete_fields_with_filter_and_sort_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let original_field_count = test.field_count();
+    let number_field_rev = test.get_first_field_rev(FieldType::Number).clone();
+    let checkbox_field_id = test.get_first_field_rev(FieldType::Checkbox).id.clone();
+    let scripts = vec![
+        InsertFilter {
+            params: AlterFilterParams {
+                view_id: test.view_id(),
+                field_id: number_field_rev.id.clone(),
+                filter_id: None,
+                field_type: number_field_rev.ty,
+                condition: NumberFilterConditionPB::Equal as u8,
+                content: "1".to_owned(),
+            },
+        },
+        InsertSort {
+            params: AlterSortParams {
+                view_id: test.view_id(),
+                field_id: number_field_rev.id.clone(),
+                sort_id: None,
+                field_type: number_field_rev.ty,
+                condition: SortCondition::Ascending.into(),
+                natural: None,
+                alphabetical: None,
+                empty_order: None,
+            },
+        },
+        AssertFilterCount(1),
+        AssertSortCount(1),
+        DeleteFields {
+            field_ids: vec![number_field_rev.id.clone(), checkbox_field_id.clone()],
+            is_ok: true,
+        },
+        AssertFieldCount(original_field_count - 2),
+        AssertFilterCount(0),
+        AssertSortCount(0),
+        AssertNoCells {
+            field_id: number_field_rev.id.clone(),
+        },
+        AssertNoCells {
+            field_id: checkbox_field_id,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_delete_fields_with_sort_of_closed_view_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let number_field_rev = test.get_first_field_rev(FieldType::Number).clone();
+    let second_view_id = "second_view".to_owned();
+    test.run_scripts(vec![InsertSort {
+        params: AlterSortParams {
+            view_id: second_view_id.clone(),
+            field_id: number_field_rev.id.clone(),
+            sort_id: None,
+            field_type: number_field_rev.ty,
+            condition: SortCondition::Ascending.into(),
+            natural: None,
+            alphabetical: None,
+            empty_order: None,
+        },
+    }])
+    .await;
+    assert_eq!(test.editor.get_all_sorts(&second_view_id).await.unwrap().len(), 1);
+
+    // The sort of the view that is not opened is removed with the field
+    test.editor.close_view(&second_view_id).await;
+    test.run_scripts(vec![DeleteFields {
+        field_ids: vec![number_field_rev.id.clone()],
+        is_ok: true,
+    }])
+    .await;
+    assert!(test.editor.get_all_sorts(&second_view_id).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn grid_delete_fields_with_primary_field_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let original_field_count = test.field_count();
+    let primary_field_id = test
+        .field_revs
+        .iter()
+        .find(|field| field.is_primary)
+        .unwrap()
+        .id
+        .clone();
+    let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let scripts = vec![
+        DeleteFields {
+            field_ids: vec![number_field_id, primary_field_id.clone()],
+            is_ok: false,
+        },
+        // None of the fields is deleted.
+        AssertFieldCount(original_field_count),
+        AssertPrimaryField {
+            field_id: primary_field_id,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
 #[tokio::test]
 async fn grid_switch_from_select_option_to_checkbox_test() {
     let mut test = DatabaseFieldTest::new().await;
@@ -177,8 +285,6 @@ async fn grid_switch_from_checkbox_to_select_option_test() {
             field_id: field_rev.id.clone(),
             // the mock data of the checkbox with row_index one is "true"
             row_index: 1,
-            // the from_field_type represents as the current field type
-            from_field_type: FieldType::Checkbox,
             // The content of the checkbox should transform to the corresponding option name.
             expected_content: CHECK.to_string(),
         },
@@ -218,7 +324,6 @@ async fn grid_switch_from_multi_select_to_text_test() {
     let script_assert_field = vec![AssertCellContent {
         field_id: field_rev.id.clone(),
         row_index: 0,
-        from_field_type: FieldType::MultiSelect,
         expected_content: format!(
             "{},{}",
             multi_select_type_option.get(0).unwrap().name,
@@ -246,13 +351,11 @@ async fn grid_switch_from_checkbox_to_text_test() {
         AssertCellContent {
             field_id: field_rev.id.clone(),
             row_index: 1,
-            from_field_type: FieldType::Checkbox,
             expected_content: "Yes".to_string(),
         },
         AssertCellContent {
             field_id: field_rev.id.clone(),
             row_index: 2,
-            from_field_type: FieldType::Checkbox,
             expected_content: "No".to_string(),
         },
     ];
@@ -276,7 +379,6 @@ async fn grid_switch_from_text_to_checkbox_test() {
         AssertCellContent {
             field_id: field_rev.id.clone(),
             row_index: 0,
-            from_field_type: FieldType::RichText,
             expected_content: "".to_string(),
         },
     ];
@@ -298,13 +400,11 @@ async fn grid_switch_from_date_to_text_test() {
         AssertCellContent {
             field_id: field_rev.id.clone(),
             row_index: 2,
-            from_field_type: FieldType::DateTime,
             expected_content: "2022/03/14".to_string(),
         },
         AssertCellContent {
             field_id: field_rev.id.clone(),
             row_index: 3,
-            from_field_type: FieldType::DateTime,
             expected_content: "2022/11/17".to_string(),
         },
     ];
@@ -327,16 +427,988 @@ async fn grid_switch_from_number_to_text_test() {
         AssertCellContent {
             field_id: field_rev.id.clone(),
             row_index: 0,
-            from_field_type: FieldType::Number,
             expected_content: "$1".to_string(),
         },
         AssertCellContent {
             field_id: field_rev.id.clone(),
             row_index: 4,
-            from_field_type: FieldType::Number,
             expected_content: "".to_string(),
         },
     ];
 
     test.run_scripts(scripts).await;
 }
+
+// Test when switching the current field from Text to Multi-select test
+// input:
+//      "tag1, tag2" -> [tag1, tag2], the options that don't exist are created.
+#[tokio::test]
+async fn grid_switch_from_text_to_multi_select_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::RichText).clone();
+
+    let scripts = vec![
+        UpdateCell {
+            field_id: field_rev.id.clone(),
+            row_index: 0,
+            content: "tag1, tag2".to_string(),
+        },
+        SwitchToField {
+            field_id: field_rev.id.clone(),
+            new_field_type: FieldType::MultiSelect,
+        },
+        AssertCellFieldType {
+            field_id: field_rev.id.clone(),
+            row_index: 0,
+            field_type: FieldType::MultiSelect,
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 0,
+            expected_content: "tag1,tag2".to_string(),
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 3,
+            expected_content: "DA".to_string(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+
+    let mut option_names = test
+        .get_multi_select_type_option(&field_rev.id)
+        .into_iter()
+        .map(|option| option.name)
+        .collect::<Vec<String>>();
+    option_names.sort();
+    assert_eq!(option_names, vec!["AE", "C", "DA", "tag1", "tag2"]);
+}
+
+// Test when switching the current field from Text to Checkbox test
+// input:
+//      "yes" -> check
+//      "0" -> unchecked
+//      "DA" -> "", the text is restored after switching back to Text.
+#[tokio::test]
+async fn grid_switch_from_text_to_checkbox_and_back_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::RichText).clone();
+
+    let scripts = vec![
+        UpdateCell {
+            field_id: field_rev.id.clone(),
+            row_index: 0,
+            content: "yes".to_string(),
+        },
+        UpdateCell {
+            field_id: field_rev.id.clone(),
+            row_index: 2,
+            content: "0".to_string(),
+        },
+        SwitchToField {
+            field_id: field_rev.id.clone(),
+            new_field_type: FieldType::Checkbox,
+        },
+        AssertCellFieldType {
+            field_id: field_rev.id.clone(),
+            row_index: 3,
+            field_type: FieldType::Checkbox,
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 0,
+            expected_content: CHECK.to_string(),
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 2,
+            expected_content: UNCHECK.to_string(),
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 3,
+            expected_content: "".to_string(),
+        },
+        SwitchToField {
+            field_id: field_rev.id.clone(),
+            new_field_type: FieldType::RichText,
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 0,
+            expected_content: CHECK.to_string(),
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 3,
+            expected_content: "DA".to_string(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+// The unconvertible text is kept through the following switches until the field is switched back
+// to Text.
+#[tokio::test]
+async fn grid_switch_text_through_fields_and_back_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::RichText).clone();
+
+    let scripts = vec![
+        SwitchToField {
+            field_id: field_rev.id.clone(),
+            new_field_type: FieldType::Number,
+        },
+        SwitchToField {
+            field_id: field_rev.id.clone(),
+            new_field_type: FieldType::Checkbox,
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 4,
+            expected_content: "".to_string(),
+        },
+        SwitchToField {
+            field_id: field_rev.id.clone(),
+            new_field_type: FieldType::RichText,
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 4,
+            expected_content: "AE".to_string(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+// The shadow of the cell is dropped once the cell is updated, so the updated value is kept after
+// switching back.
+#[tokio::test]
+async fn grid_switch_back_after_updating_converted_cell_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::RichText).clone();
+
+    let scripts = vec![
+        SwitchToField {
+            field_id: field_rev.id.clone(),
+            new_field_type: FieldType::Checkbox,
+        },
+        UpdateCell {
+            field_id: field_rev.id.clone(),
+            row_index: 3,
+            content: CHECK.to_string(),
+        },
+        SwitchToField {
+            field_id: field_rev.id.clone(),
+            new_field_type: FieldType::RichText,
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 3,
+            expected_content: CHECK.to_string(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+// Undoing the switch converts the cells back to the old field type.
+#[tokio::test]
+async fn grid_undo_switch_field_restores_cells_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::RichText).clone();
+
+    let scripts = vec![
+        SwitchToField {
+            field_id: field_rev.id.clone(),
+            new_field_type: FieldType::Checkbox,
+        },
+        Undo,
+        AssertCellFieldType {
+            field_id: field_rev.id.clone(),
+            row_index: 3,
+            field_type: FieldType::RichText,
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 3,
+            expected_content: "DA".to_string(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+// Test when switching the current field from Text to Date test
+// input:
+//      "2022/03/14" -> 1647216000
+#[tokio::test]
+async fn grid_switch_from_text_to_date_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::RichText).clone();
+
+    let scripts = vec![
+        UpdateCell {
+            field_id: field_rev.id.clone(),
+            row_index: 0,
+            content: "2022/03/14".to_string(),
+        },
+        SwitchToField {
+            field_id: field_rev.id.clone(),
+            new_field_type: FieldType::DateTime,
+        },
+        AssertCellFieldType {
+            field_id: field_rev.id.clone(),
+            row_index: 0,
+            field_type: FieldType::DateTime,
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 0,
+            expected_content: "Mar 14,2022".to_string(),
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 3,
+            expected_content: "".to_string(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+// Test when switching the current field from Text to Number test
+// input:
+//      "12 apples" -> 12
+#[tokio::test]
+async fn grid_switch_from_text_to_number_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::RichText).clone();
+
+    let scripts = vec![
+        UpdateCell {
+            field_id: field_rev.id.clone(),
+            row_index: 0,
+            content: "12 apples".to_string(),
+        },
+        SwitchToField {
+            field_id: field_rev.id.clone(),
+            new_field_type: FieldType::Number,
+        },
+        AssertCellFieldType {
+            field_id: field_rev.id.clone(),
+            row_index: 0,
+            field_type: FieldType::Number,
+        },
+        AssertCellContent {
+            field_id: field_rev.id.clone(),
+            row_index: 0,
+            expected_content: "12".to_string(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_field_history_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::Number).clone();
+    let changeset = |name: Option<&str>, width: Option<i32>| FieldChangesetParams {
+        field_id: field_rev.id.clone(),
+        database_id: test.view_id(),
+        name: name.map(|name| name.to_owned()),
+        width,
+        ..Default::default()
+    };
+    let scripts = vec![
+        ReopenWithMergeThreshold(1),
+        UpdateField {
+            changeset: changeset(Some("Price"), None),
+        },
+        UpdateField {
+            changeset: changeset(None, Some(300)),
+        },
+        DeleteField {
+            field_rev: field_rev.clone(),
+        },
+        AssertFieldHistory {
+            field_id: field_rev.id.clone(),
+            expected: vec![
+                ExpectedFieldChange {
+                    is_created: true,
+                    is_deleted: false,
+                    name: field_rev.name.clone(),
+                    field_type: FieldType::Number,
+                    changed_properties: vec![],
+                },
+                ExpectedFieldChange {
+                    is_created: false,
+                    is_deleted: false,
+                    name: "Price".to_owned(),
+                    field_type: FieldType::Number,
+                    changed_properties: vec!["name"],
+                },
+                ExpectedFieldChange {
+                    is_created: false,
+                    is_deleted: false,
+                    name: "Price".to_owned(),
+                    field_type: FieldType::Number,
+                    changed_properties: vec!["width"],
+                },
+                // The deleted field is the state of the field before it was deleted.
+                ExpectedFieldChange {
+                    is_created: false,
+                    is_deleted: true,
+                    name: "Price".to_owned(),
+                    field_type: FieldType::Number,
+                    changed_properties: vec![],
+                },
+            ],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_field_history_switch_field_type_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let (params, _) = create_text_field(&test.view_id());
+    let scripts = vec![ReopenWithMergeThreshold(1), CreateField { params }];
+    test.run_scripts(scripts).await;
+
+    let text_field_rev = (*test.field_revs.clone().pop().unwrap()).clone();
+    let scripts = vec![
+        SwitchToField {
+            field_id: text_field_rev.id.clone(),
+            new_field_type: FieldType::Checkbox,
+        },
+        AssertFieldHistory {
+            field_id: text_field_rev.id.clone(),
+            expected: vec![
+                ExpectedFieldChange {
+                    is_created: true,
+                    is_deleted: false,
+                    name: text_field_rev.name.clone(),
+                    field_type: FieldType::RichText,
+                    changed_properties: vec![],
+                },
+                ExpectedFieldChange {
+                    is_created: false,
+                    is_deleted: false,
+                    name: text_field_rev.name.clone(),
+                    field_type: FieldType::Checkbox,
+                    changed_properties: vec!["field_type", "type_options"],
+                },
+            ],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_duplicate_field_copy_cells_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::Number).clone();
+    let scripts = vec![DuplicateField {
+        field_id: field_rev.id.clone(),
+    }];
+    test.run_scripts(scripts).await;
+
+    let index = test
+        .field_revs
+        .iter()
+        .position(|field| field.id == field_rev.id)
+        .unwrap();
+    let duplicated_field_rev = test.field_revs[index + 1].clone();
+    assert_eq!(duplicated_field_rev.name, format!("{} (copy)", field_rev.name));
+    let scripts = vec![
+        WaitForCopyCellsFinished,
+        AssertCellsEqual {
+            field_id: field_rev.id.clone(),
+            other_field_id: duplicated_field_rev.id.clone(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_duplicate_primary_field_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let primary_field_rev = test.field_revs.iter().find(|field| field.is_primary).unwrap().clone();
+    let scripts = vec![
+        DuplicateField {
+            field_id: primary_field_rev.id.clone(),
+        },
+        WaitForCopyCellsFinished,
+    ];
+    test.run_scripts(scripts).await;
+
+    let primary_field_count = test.field_revs.iter().filter(|field| field.is_primary).count();
+    assert_eq!(primary_field_count, 1);
+    let index = test
+        .field_revs
+        .iter()
+        .position(|field| field.id == primary_field_rev.id)
+        .unwrap();
+    let duplicated_field_rev = test.field_revs[index + 1].clone();
+    assert!(!duplicated_field_rev.is_primary);
+    let scripts = vec![AssertCellsEqual {
+        field_id: primary_field_rev.id.clone(),
+        other_field_id: duplicated_field_rev.id.clone(),
+    }];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_reorder_fields_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let mut field_ids = test
+        .field_revs
+        .iter()
+        .map(|field_rev| field_rev.id.clone())
+        .collect::<Vec<String>>();
+    field_ids.reverse();
+    let scripts = vec![
+        ReorderFields {
+            field_ids: field_ids.clone(),
+            is_ok: true,
+        },
+        AssertFieldOrder(field_ids),
+        AssertFieldCount(test.field_count()),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_reorder_fields_with_invalid_ids_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_ids = test
+        .field_revs
+        .iter()
+        .map(|field_rev| field_rev.id.clone())
+        .collect::<Vec<String>>();
+
+    let mut unknown_field_ids = field_ids.clone();
+    unknown_field_ids.reverse();
+    unknown_field_ids[0] = "unknown".to_owned();
+
+    let mut missing_field_ids = field_ids.clone();
+    missing_field_ids.reverse();
+    missing_field_ids.pop();
+
+    let mut duplicated_field_ids = field_ids.clone();
+    let last_index = duplicated_field_ids.len() - 1;
+    duplicated_field_ids[last_index] = field_ids[0].clone();
+
+    let scripts = vec![
+        ReorderFields {
+            field_ids: unknown_field_ids,
+            is_ok: false,
+        },
+        AssertFieldOrder(field_ids.clone()),
+        ReorderFields {
+            field_ids: missing_field_ids,
+            is_ok: false,
+        },
+        AssertFieldOrder(field_ids.clone()),
+        ReorderFields {
+            field_ids: duplicated_field_ids,
+            is_ok: false,
+        },
+        AssertFieldOrder(field_ids),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_set_primary_field_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let primary_field_id = test
+        .field_revs
+        .iter()
+        .find(|field| field.is_primary)
+        .unwrap()
+        .id
+        .clone();
+    let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let scripts = vec![
+        AssertPrimaryField {
+            field_id: primary_field_id.clone(),
+        },
+        SetPrimaryField {
+            field_id: number_field_id.clone(),
+            is_ok: true,
+        },
+        AssertPrimaryField {
+            field_id: number_field_id,
+        },
+        SetPrimaryField {
+            field_id: primary_field_id.clone(),
+            is_ok: true,
+        },
+        AssertPrimaryField {
+            field_id: primary_field_id,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_set_primary_field_with_invalid_field_type_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let primary_field_id = test
+        .field_revs
+        .iter()
+        .find(|field| field.is_primary)
+        .unwrap()
+        .id
+        .clone();
+    let checkbox_field_id = test.get_first_field_rev(FieldType::Checkbox).id.clone();
+    let checklist_field_id = test.get_first_field_rev(FieldType::Checklist).id.clone();
+    let scripts = vec![
+        SetPrimaryField {
+            field_id: checkbox_field_id,
+            is_ok: false,
+        },
+        AssertPrimaryField {
+            field_id: primary_field_id.clone(),
+        },
+        SetPrimaryField {
+            field_id: checklist_field_id,
+            is_ok: false,
+        },
+        SetPrimaryField {
+            field_id: "unknown".to_owned(),
+            is_ok: false,
+        },
+        AssertPrimaryField {
+            field_id: primary_field_id,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_view_field_visibility_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let date_field_id = test.get_first_field_rev(FieldType::DateTime).id.clone();
+    let scripts = vec![
+        // Hiding the field in the view doesn't hide it in the database.
+        SetViewFieldVisibility {
+            field_id: number_field_id.clone(),
+            visibility: false,
+        },
+        AssertFieldVisibility {
+            field_id: number_field_id.clone(),
+            view_visibility: false,
+            database_visibility: true,
+        },
+        // The view falls back to the visibility of the database if it doesn't override the field.
+        UpdateField {
+            changeset: FieldChangesetParams {
+                field_id: date_field_id.clone(),
+                database_id: test.view_id(),
+                visibility: Some(false),
+                ..Default::default()
+            },
+        },
+        AssertFieldVisibility {
+            field_id: date_field_id.clone(),
+            view_visibility: false,
+            database_visibility: false,
+        },
+        SetViewFieldVisibility {
+            field_id: date_field_id.clone(),
+            visibility: true,
+        },
+        AssertFieldVisibility {
+            field_id: date_field_id,
+            view_visibility: true,
+            database_visibility: false,
+        },
+        // The overrides are kept after reopening the database.
+        ReopenWithMergeThreshold(1),
+        AssertFieldVisibility {
+            field_id: number_field_id,
+            view_visibility: false,
+            database_visibility: true,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_view_field_order_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_ids = test
+        .field_revs
+        .iter()
+        .map(|field_rev| field_rev.id.clone())
+        .collect::<Vec<String>>();
+    let mut view_field_ids = field_ids.clone();
+    view_field_ids.reverse();
+
+    let mut missing_field_ids = view_field_ids.clone();
+    missing_field_ids.pop();
+    let scripts = vec![
+        SetViewFieldOrder {
+            field_ids: view_field_ids.clone(),
+            is_ok: true,
+        },
+        AssertViewFieldOrder(view_field_ids.clone()),
+        // The order of the database is not changed.
+        AssertFieldOrder(field_ids),
+        SetViewFieldOrder {
+            field_ids: missing_field_ids,
+            is_ok: false,
+        },
+        AssertViewFieldOrder(view_field_ids),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_view_field_order_with_new_field_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let mut view_field_ids = test
+        .field_revs
+        .iter()
+        .map(|field_rev| field_rev.id.clone())
+        .collect::<Vec<String>>();
+    view_field_ids.reverse();
+    let scripts = vec![SetViewFieldOrder {
+        field_ids: view_field_ids.clone(),
+        is_ok: true,
+    }];
+    test.run_scripts(scripts).await;
+
+    // The field that is created after the view is reordered is placed after the ordered fields.
+    let (params, _) = create_text_field(&test.view_id());
+    test.run_scripts(vec![CreateField { params }]).await;
+    view_field_ids.push(test.field_revs.last().unwrap().id.clone());
+    test.run_scripts(vec![AssertViewFieldOrder(view_field_ids)]).await;
+}
+
+#[tokio::test]
+async fn grid_view_field_width_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::Number).clone();
+    let scripts = vec![
+        SetViewFieldWidth {
+            field_id: field_rev.id.clone(),
+            width: 300,
+        },
+        AssertFieldWidth {
+            field_id: field_rev.id.clone(),
+            view_width: 300,
+            database_width: field_rev.width,
+        },
+        // The width below the minimum is clamped.
+        SetViewFieldWidth {
+            field_id: field_rev.id.clone(),
+            width: 10,
+        },
+        AssertFieldWidth {
+            field_id: field_rev.id.clone(),
+            view_width: MIN_FIELD_WIDTH,
+            database_width: field_rev.width,
+        },
+        ReopenWithMergeThreshold(1),
+        AssertFieldWidth {
+            field_id: field_rev.id.clone(),
+            view_width: MIN_FIELD_WIDTH,
+            database_width: field_rev.width,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_view_field_width_removed_after_deleting_field_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::Number).as_ref().clone();
+    let scripts = vec![
+        SetViewFieldWidth {
+            field_id: field_rev.id.clone(),
+            width: 300,
+        },
+        DeleteField {
+            field_rev: field_rev.clone(),
+        },
+        // The restored field uses its own width because the width of the view was removed.
+        Undo,
+        AssertFieldWidth {
+            field_id: field_rev.id.clone(),
+            view_width: field_rev.width,
+            database_width: field_rev.width,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_update_field_description_and_icon_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::Number).clone();
+    let scripts = vec![
+        AssertFieldDescriptionAndIcon {
+            field_id: field_rev.id.clone(),
+            description: "".to_owned(),
+            icon: "".to_owned(),
+        },
+        UpdateFieldDescription {
+            field_id: field_rev.id.clone(),
+            description: "The price of the product".to_owned(),
+        },
+        UpdateFieldIcon {
+            field_id: field_rev.id.clone(),
+            icon: "💰".to_owned(),
+        },
+        AssertFieldDescriptionAndIcon {
+            field_id: field_rev.id.clone(),
+            description: "The price of the product".to_owned(),
+            icon: "💰".to_owned(),
+        },
+        ReopenWithMergeThreshold(1),
+        AssertFieldDescriptionAndIcon {
+            field_id: field_rev.id.clone(),
+            description: "The price of the product".to_owned(),
+            icon: "💰".to_owned(),
+        },
+        // The duplicated field copies the description and the icon.
+        DuplicateField {
+            field_id: field_rev.id.clone(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+
+    let index = test
+        .field_revs
+        .iter()
+        .position(|field| field.id == field_rev.id)
+        .unwrap();
+    let duplicated_field_id = test.field_revs[index + 1].id.clone();
+    let scripts = vec![
+        WaitForCopyCellsFinished,
+        AssertFieldDescriptionAndIcon {
+            field_id: duplicated_field_id,
+            description: "The price of the product".to_owned(),
+            icon: "💰".to_owned(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_view_frozen_field_count_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_count = test.field_count() as i32;
+    let hidden_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let scripts = vec![
+        AssertFrozenFieldCount(0),
+        SetFrozenFieldCount(2),
+        AssertFrozenFieldCount(2),
+        // The count is clamped to the number of the visible fields.
+        SetFrozenFieldCount(100),
+        AssertFrozenFieldCount(field_count),
+        SetViewFieldVisibility {
+            field_id: hidden_field_id,
+            visibility: false,
+        },
+        AssertFrozenFieldCount(field_count - 1),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_view_frozen_field_count_after_reorder_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let mut view_field_ids = test
+        .field_revs
+        .iter()
+        .map(|field_rev| field_rev.id.clone())
+        .collect::<Vec<String>>();
+    view_field_ids.reverse();
+    let scripts = vec![
+        SetFrozenFieldCount(1),
+        // The count is kept, so the field that is moved to the first position gets frozen.
+        SetViewFieldOrder {
+            field_ids: view_field_ids,
+            is_ok: true,
+        },
+        AssertFrozenFieldCount(1),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_locked_field_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let scripts = vec![
+        SetFieldLocked {
+            field_id: field_id.clone(),
+            locked: true,
+        },
+        AssertFieldLocked {
+            field_id: field_id.clone(),
+            locked: true,
+        },
+        AssertFieldEditsLocked {
+            field_id: field_id.clone(),
+        },
+        // The field can be deleted after it's unlocked.
+        SetFieldLocked {
+            field_id: field_id.clone(),
+            locked: false,
+        },
+        DeleteFields {
+            field_ids: vec![field_id],
+            is_ok: true,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_locked_field_select_options_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::SingleSelect).id.clone();
+    let scripts = vec![
+        SetFieldLocked {
+            field_id: field_id.clone(),
+            locked: true,
+        },
+        AssertSelectOptionEditsLocked { field_id },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_duplicate_locked_field_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let scripts = vec![
+        SetFieldLocked {
+            field_id: field_id.clone(),
+            locked: true,
+        },
+        DuplicateField {
+            field_id: field_id.clone(),
+        },
+        WaitForCopyCellsFinished,
+    ];
+    test.run_scripts(scripts).await;
+
+    let field_revs = test.editor.get_field_revs(None).await.unwrap();
+    let index = field_revs
+        .iter()
+        .position(|field_rev| field_rev.id == field_id)
+        .unwrap();
+    let duplicated_field_id = field_revs[index + 1].id.clone();
+    let scripts = vec![
+        AssertFieldLocked {
+            field_id: duplicated_field_id.clone(),
+            locked: true,
+        },
+        AssertCellsEqual {
+            field_id,
+            other_field_id: duplicated_field_id,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_clear_field_cells_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let cell_count = test
+        .row_revs
+        .iter()
+        .filter(|row_rev| row_rev.cells.contains_key(&field_id))
+        .count();
+    assert!(cell_count > 0);
+    let scripts = vec![
+        ClearFieldCells {
+            field_id: field_id.clone(),
+            error_code: None,
+        },
+        AssertNoCells {
+            field_id: field_id.clone(),
+        },
+        // The cells of all the rows are restored by one undo.
+        Undo,
+        AssertCellCount {
+            field_id,
+            count: cell_count,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_clear_primary_or_locked_field_cells_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let primary_field_id = test
+        .field_revs
+        .iter()
+        .find(|field_rev| field_rev.is_primary)
+        .unwrap()
+        .id
+        .clone();
+    let field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let cell_count = test
+        .row_revs
+        .iter()
+        .filter(|row_rev| row_rev.cells.contains_key(&field_id))
+        .count();
+    let scripts = vec![
+        ClearFieldCells {
+            field_id: primary_field_id,
+            error_code: Some(ErrorCode::FieldInvalidOperation),
+        },
+        SetFieldLocked {
+            field_id: field_id.clone(),
+            locked: true,
+        },
+        ClearFieldCells {
+            field_id: field_id.clone(),
+            error_code: Some(ErrorCode::FieldLocked),
+        },
+        AssertCellCount {
+            field_id,
+            count: cell_count,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_duplicate_field_keep_validation_rules_test() {
+    let mut test = DatabaseFieldTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::Number).clone();
+    let mut type_option = field_rev.get_type_option::<NumberTypeOptionPB>(field_rev.ty).unwrap();
+    type_option.min = Some("2".to_owned());
+    type_option.max = Some("4".to_owned());
+    let scripts = vec![
+        UpdateTypeOption {
+            field_id: field_rev.id.clone(),
+            type_option: type_option.protobuf_bytes().to_vec(),
+        },
+        DuplicateField {
+            field_id: field_rev.id.clone(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+
+    let index = test
+        .field_revs
+        .iter()
+        .position(|field| field.id == field_rev.id)
+        .unwrap();
+    let duplicated_field_rev = test.field_revs[index + 1].clone();
+    let duplicated_type_option = duplicated_field_rev
+        .get_type_option::<NumberTypeOptionPB>(duplicated_field_rev.ty)
+        .unwrap();
+    assert_eq!(duplicated_type_option.min, type_option.min);
+    assert_eq!(duplicated_type_option.max, type_option.max);
+}
@@ -0,0 +1,69 @@
+use crate::grid::filter_test::script::FilterScript::*;
+use crate::grid::filter_test::script::{DatabaseFilterTest, FilterRowChanged};
+use flowy_database::entities::AttachmentFilterConditionPB;
+
+#[tokio::test]
+async fn grid_filter_attachment_is_not_empty_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let expected = 2;
+    let scripts = vec![
+        CreateAttachmentFilter {
+            condition: AttachmentFilterConditionPB::AttachmentIsNotEmpty,
+            content: "".to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_attachment_is_empty_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let expected = 4;
+    let scripts = vec![
+        CreateAttachmentFilter {
+            condition: AttachmentFilterConditionPB::AttachmentIsEmpty,
+            content: "".to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_attachment_file_type_is_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let scripts = vec![
+        CreateAttachmentFilter {
+            condition: AttachmentFilterConditionPB::FileTypeIs,
+            content: "image".to_string(),
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 1 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_attachment_file_type_is_mime_type_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let scripts = vec![
+        CreateAttachmentFilter {
+            condition: AttachmentFilterConditionPB::FileTypeIs,
+            content: "application/pdf".to_string(),
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 2 },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -35,3 +35,22 @@ async fn grid_filter_checkbox_is_uncheck_test() {
     ];
     test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_filter_checkbox_is_empty_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    // The unchecked checkbox is empty.
+    let expected = 3;
+    let row_count = test.row_revs.len();
+    let scripts = vec![
+        CreateCheckboxFilter {
+            condition: CheckboxFilterConditionPB::CheckboxIsEmpty,
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
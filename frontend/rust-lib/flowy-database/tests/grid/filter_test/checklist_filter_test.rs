@@ -1,6 +1,7 @@
 use crate::grid::filter_test::script::FilterScript::*;
 use crate::grid::filter_test::script::{DatabaseFilterTest, FilterRowChanged};
-use flowy_database::entities::ChecklistFilterConditionPB;
+use flowy_database::entities::{ChecklistFilterConditionPB, FieldType};
+use flowy_database::services::field::ChecklistCellChangeset;
 
 #[tokio::test]
 async fn grid_filter_checklist_is_incomplete_test() {
@@ -37,3 +38,130 @@ async fn grid_filter_checklist_is_complete_test() {
     ];
     test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_filter_checklist_is_empty_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let expected = 5;
+    let row_count = test.row_revs.len();
+    let scripts = vec![
+        CreateChecklistFilter {
+            condition: ChecklistFilterConditionPB::ChecklistIsEmpty,
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_checklist_is_not_empty_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let expected = 1;
+    let row_count = test.row_revs.len();
+    let scripts = vec![
+        CreateChecklistFilter {
+            condition: ChecklistFilterConditionPB::ChecklistIsNotEmpty,
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_checklist_is_complete_after_toggling_items_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_revs = test.get_row_revs().await;
+    let type_option = test.get_checklist_type_option(&test.get_first_field_rev(FieldType::Checklist).id);
+    let item_ids = type_option
+        .options
+        .iter()
+        .map(|option| option.id.clone())
+        .collect::<Vec<String>>();
+    let scripts = vec![
+        CreateChecklistFilter {
+            condition: ChecklistFilterConditionPB::IsComplete,
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 1 },
+        UpdateChecklistCell {
+            row_id: row_revs[1].id.clone(),
+            changeset: ChecklistCellChangeset::from_toggle_item_ids(item_ids),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 1,
+                hiding_num_of_rows: 0,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected: 2 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_checklist_completion_at_least_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_revs = test.get_row_revs().await;
+    let expected = 1;
+    let row_count = test.row_revs.len();
+    let type_option = test.get_checklist_type_option(&test.get_first_field_rev(FieldType::Checklist).id);
+    let first_item_id = type_option.options[0].id.clone();
+    let scripts = vec![
+        CreateChecklistCompletionFilter {
+            percent: 30,
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+        // One of the three items is done, which is 33%.
+        UpdateChecklistCell {
+            row_id: row_revs[1].id.clone(),
+            changeset: ChecklistCellChangeset::from_toggle_item_ids(vec![first_item_id.clone()]),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 1,
+                hiding_num_of_rows: 0,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected: 2 },
+        UpdateChecklistCell {
+            row_id: row_revs[1].id.clone(),
+            changeset: ChecklistCellChangeset::from_toggle_item_ids(vec![first_item_id]),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: 1,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected: 1 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_checklist_filterable_field_conditions_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let scripts = vec![
+        AssertFilterableFieldConditions {
+            field_type: FieldType::Checklist,
+            expected: vec![
+                "IsComplete",
+                "IsIncomplete",
+                "CompletionAtLeast",
+                "ChecklistIsEmpty",
+                "ChecklistIsNotEmpty",
+            ],
+        },
+        AssertFilterableFieldConditions {
+            field_type: FieldType::Checkbox,
+            expected: vec!["IsChecked", "IsUnChecked", "CheckboxIsEmpty", "CheckboxIsNotEmpty"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
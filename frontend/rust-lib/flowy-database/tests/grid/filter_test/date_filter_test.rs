@@ -106,3 +106,119 @@ async fn grid_filter_date_within_test() {
     ];
     test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_filter_date_is_not_empty_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    // Only one row of the initial rows has no date.
+    let expected = 5;
+    let scripts = vec![
+        CreateDateFilter {
+            condition: DateFilterConditionPB::DateIsNotEmpty,
+            start: None,
+            end: None,
+            timestamp: None,
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_date_is_today_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_revs = test.row_revs.clone();
+    let scripts = vec![
+        // The dates of the initial rows are in the past.
+        CreateRelativeDateFilter {
+            condition: DateFilterConditionPB::DateIsToday,
+            days: None,
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_revs.len(),
+            }),
+        },
+        AssertNumberOfVisibleRows { expected: 0 },
+        UpdateDateCellInDays {
+            row_id: row_revs[0].id.clone(),
+            days: 0,
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 1,
+                hiding_num_of_rows: 0,
+            }),
+        },
+        Wait { millisecond: 100 },
+        AssertNumberOfVisibleRows { expected: 1 },
+        UpdateDateCellInDays {
+            row_id: row_revs[0].id.clone(),
+            days: 1,
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: 1,
+            }),
+        },
+        Wait { millisecond: 100 },
+        AssertNumberOfVisibleRows { expected: 0 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_date_is_in_last_and_next_days_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_revs = test.row_revs.clone();
+    let scripts = vec![
+        // The dates of the initial rows are in the last 100 years, except the row without date.
+        CreateRelativeDateFilter {
+            condition: DateFilterConditionPB::DateIsInLastDays,
+            days: Some(36500),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: 1,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected: 5 },
+        // Tomorrow is not in the last days.
+        UpdateDateCellInDays {
+            row_id: row_revs[0].id.clone(),
+            days: 1,
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: 1,
+            }),
+        },
+        Wait { millisecond: 100 },
+        AssertNumberOfVisibleRows { expected: 4 },
+    ];
+    test.run_scripts(scripts).await;
+
+    let mut test = DatabaseFilterTest::new().await;
+    let row_revs = test.row_revs.clone();
+    let scripts = vec![
+        CreateRelativeDateFilter {
+            condition: DateFilterConditionPB::DateIsInNextDays,
+            days: Some(7),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_revs.len(),
+            }),
+        },
+        AssertNumberOfVisibleRows { expected: 0 },
+        UpdateDateCellInDays {
+            row_id: row_revs[0].id.clone(),
+            days: 7,
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 1,
+                hiding_num_of_rows: 0,
+            }),
+        },
+        Wait { millisecond: 100 },
+        AssertNumberOfVisibleRows { expected: 1 },
+    ];
+    test.run_scripts(scripts).await;
+}
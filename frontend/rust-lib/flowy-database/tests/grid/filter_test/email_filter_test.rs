@@ -0,0 +1,82 @@
+use crate::grid::filter_test::script::FilterScript::*;
+use crate::grid::filter_test::script::{DatabaseFilterTest, FilterRowChanged};
+use flowy_database::entities::{EmailFilterConditionPB, FieldType};
+
+#[tokio::test]
+async fn grid_filter_email_contains_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let expected = 2;
+    let scripts = vec![
+        CreateEmailFilter {
+            condition: EmailFilterConditionPB::Contains,
+            content: "AppFlowy".to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_email_is_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let scripts = vec![
+        CreateEmailFilter {
+            condition: EmailFilterConditionPB::Is,
+            content: "lucas@appflowy.io".to_string(),
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 1 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_email_is_empty_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let expected = 3;
+    let scripts = vec![
+        CreateEmailFilter {
+            condition: EmailFilterConditionPB::EmailIsEmpty,
+            content: "".to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_email_is_invalid_after_switching_from_text_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    test.editor
+        .switch_to_field_type(&text_field_id, &FieldType::Email)
+        .await
+        .unwrap();
+    test.field_revs = test.editor.get_field_revs(None).await.unwrap();
+
+    // The text field is converted to an email field, but none of its texts are valid emails.
+    let row_count = test.row_revs.len();
+    let expected = 5;
+    let scripts = vec![
+        CreateEmailFilter {
+            condition: EmailFilterConditionPB::EmailIsInvalid,
+            content: "".to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
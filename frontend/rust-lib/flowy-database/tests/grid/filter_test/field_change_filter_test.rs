@@ -0,0 +1,75 @@
+use crate::grid::filter_test::script::FilterScript::*;
+use crate::grid::filter_test::script::*;
+use flowy_database::entities::{FieldType, NumberFilterConditionPB, SelectOptionConditionPB, TextFilterConditionPB};
+use flowy_database::services::filter::FilterType;
+
+#[tokio::test]
+async fn grid_filter_deleted_after_switching_number_field_to_text_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let scripts = vec![
+        CreateNumberFilter {
+            condition: NumberFilterConditionPB::Equal,
+            content: "1".to_string(),
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 1 },
+        // The text filter has no condition that compares the numbers.
+        SwitchFieldType {
+            from_field_type: FieldType::Number,
+            to_field_type: FieldType::RichText,
+        },
+        AssertFilterCount { count: 0 },
+        Wait { millisecond: 100 },
+        AssertNumberOfVisibleRows { expected: 6 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_converted_after_switching_number_field_to_text_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let scripts = vec![
+        CreateNumberFilter {
+            condition: NumberFilterConditionPB::NumberIsNotEmpty,
+            content: "".to_string(),
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 5 },
+        SwitchFieldType {
+            from_field_type: FieldType::Number,
+            to_field_type: FieldType::RichText,
+        },
+        AssertFilterCount { count: 1 },
+        AssertFilterContent {
+            filter_type: FilterType {
+                field_id,
+                field_type: FieldType::RichText,
+            },
+            condition: TextFilterConditionPB::TextIsNotEmpty as u32,
+            content: "".to_string(),
+        },
+        Wait { millisecond: 100 },
+        AssertNumberOfVisibleRows { expected: 5 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_deleted_after_deleting_select_field_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let scripts = vec![
+        CreateMultiSelectFilter {
+            condition: SelectOptionConditionPB::OptionIsEmpty,
+            option_ids: vec![],
+        },
+        AssertNumberOfVisibleRows { expected: 3 },
+        DeleteField {
+            field_type: FieldType::MultiSelect,
+        },
+        AssertFilterCount { count: 0 },
+        Wait { millisecond: 100 },
+        AssertNumberOfVisibleRows { expected: 6 },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -0,0 +1,195 @@
+use crate::grid::filter_test::script::FilterScript::*;
+use crate::grid::filter_test::script::{DatabaseFilterTest, FilterRowChanged};
+use flowy_database::entities::{
+    CheckboxFilterConditionPB, DateFilterConditionPB, FieldType, FilterGroupPB, FilterNodePB, FilterOperatorPB,
+    TextFilterConditionPB, UpdateFilterTreeParams, UpdateFilterTreePayloadPB,
+};
+use flowy_database::services::filter::FilterType;
+use flowy_error::ErrorCode;
+
+fn filter_node(filter_id: &str) -> FilterNodePB {
+    FilterNodePB {
+        filter_id: Some(filter_id.to_owned()),
+        group: None,
+    }
+}
+
+fn group_node(group: FilterGroupPB) -> FilterNodePB {
+    FilterNodePB {
+        filter_id: None,
+        group: Some(group),
+    }
+}
+
+fn filter_group(id: &str, operator: FilterOperatorPB, children: Vec<FilterNodePB>) -> FilterGroupPB {
+    FilterGroupPB {
+        id: id.to_owned(),
+        operator,
+        children,
+    }
+}
+
+async fn create_text_and_checkbox_filters(test: &mut DatabaseFilterTest) {
+    let scripts = vec![
+        CreateTextFilter {
+            condition: TextFilterConditionPB::Contains,
+            content: "A".to_string(),
+            changed: None,
+        },
+        CreateCheckboxFilter {
+            condition: CheckboxFilterConditionPB::IsChecked,
+            changed: None,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_without_groups_are_combined_by_and_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    create_text_and_checkbox_filters(&mut test).await;
+    let text_filter_id = test.get_filter_id(FieldType::RichText).await;
+    let checkbox_filter_id = test.get_filter_id(FieldType::Checkbox).await;
+    let scripts = vec![
+        AssertNumberOfVisibleRows { expected: 2 },
+        AssertFilterTree {
+            expected: filter_group(
+                &test.view_id(),
+                FilterOperatorPB::And,
+                vec![filter_node(&text_filter_id), filter_node(&checkbox_filter_id)],
+            ),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_or_group_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    create_text_and_checkbox_filters(&mut test).await;
+    let text_filter_id = test.get_filter_id(FieldType::RichText).await;
+    let checkbox_filter_id = test.get_filter_id(FieldType::Checkbox).await;
+    let filter_tree = filter_group(
+        "root",
+        FilterOperatorPB::Or,
+        vec![filter_node(&text_filter_id), filter_node(&checkbox_filter_id)],
+    );
+    let scripts = vec![
+        UpdateFilterTree {
+            filter_tree: filter_tree.clone(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 3,
+                hiding_num_of_rows: 0,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected: 5 },
+        AssertFilterTree { expected: filter_tree },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_or_group_nested_in_and_group_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    create_text_and_checkbox_filters(&mut test).await;
+    test.run_scripts(vec![
+        CreateDateFilter {
+            condition: DateFilterConditionPB::DateWithIn,
+            start: Some(1668359085),
+            end: Some(1668704685),
+            timestamp: None,
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 0 },
+    ])
+    .await;
+
+    // (text contains "A" OR checkbox is checked) AND date is within the range
+    let text_filter_id = test.get_filter_id(FieldType::RichText).await;
+    let checkbox_filter_id = test.get_filter_id(FieldType::Checkbox).await;
+    let date_filter_id = test.get_filter_id(FieldType::DateTime).await;
+    let or_group = filter_group(
+        "or",
+        FilterOperatorPB::Or,
+        vec![filter_node(&text_filter_id), filter_node(&checkbox_filter_id)],
+    );
+    let filter_tree = filter_group(
+        "root",
+        FilterOperatorPB::And,
+        vec![group_node(or_group), filter_node(&date_filter_id)],
+    );
+    let scripts = vec![
+        UpdateFilterTree {
+            filter_tree: filter_tree.clone(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 2,
+                hiding_num_of_rows: 0,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected: 2 },
+        AssertVisibleRowCount { expected: 2 },
+        AssertFilterTree { expected: filter_tree },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_group_delete_filter_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    create_text_and_checkbox_filters(&mut test).await;
+    let text_filter_id = test.get_filter_id(FieldType::RichText).await;
+    let checkbox_filter_id = test.get_filter_id(FieldType::Checkbox).await;
+    let checkbox_filter_type = FilterType {
+        field_id: test.get_first_field_rev(FieldType::Checkbox).id.clone(),
+        field_type: FieldType::Checkbox,
+    };
+    let or_group = filter_group("or", FilterOperatorPB::Or, vec![filter_node(&checkbox_filter_id)]);
+    let scripts = vec![
+        UpdateFilterTree {
+            filter_tree: filter_group(
+                "root",
+                FilterOperatorPB::Or,
+                vec![filter_node(&text_filter_id), group_node(or_group)],
+            ),
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 5 },
+        DeleteFilter {
+            filter_id: checkbox_filter_id,
+            filter_type: checkbox_filter_type,
+            changed: None,
+        },
+        // The nested group is removed after its only filter is deleted.
+        AssertFilterTree {
+            expected: filter_group("root", FilterOperatorPB::Or, vec![filter_node(&text_filter_id)]),
+        },
+        AssertNumberOfVisibleRows { expected: 4 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_group_too_deep_test() {
+    let test = DatabaseFilterTest::new().await;
+    let mut group = filter_group("4", FilterOperatorPB::And, vec![filter_node("filter")]);
+    for id in ["3", "2", "1"] {
+        group = filter_group(id, FilterOperatorPB::Or, vec![group_node(group)]);
+    }
+    let payload = UpdateFilterTreePayloadPB {
+        view_id: test.view_id(),
+        filter_tree: group,
+    };
+    let result: Result<UpdateFilterTreeParams, ErrorCode> = payload.try_into();
+    assert_eq!(result.unwrap_err(), ErrorCode::FilterGroupTooDeep);
+}
+
+#[tokio::test]
+async fn grid_filter_group_unknown_filter_test() {
+    let test = DatabaseFilterTest::new().await;
+    let payload = UpdateFilterTreePayloadPB {
+        view_id: test.view_id(),
+        filter_tree: filter_group("root", FilterOperatorPB::Or, vec![filter_node("unknown")]),
+    };
+    let params: UpdateFilterTreeParams = payload.try_into().unwrap();
+    assert!(test.editor.update_filter_tree(params).await.is_err());
+}
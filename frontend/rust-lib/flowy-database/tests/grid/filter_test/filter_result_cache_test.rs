@@ -0,0 +1,65 @@
+use crate::grid::filter_test::script::FilterScript::*;
+use crate::grid::filter_test::script::*;
+use flowy_database::entities::TextFilterConditionPB;
+
+#[tokio::test]
+async fn grid_filter_results_restored_after_reopen_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let scripts = vec![
+        CreateTextFilter {
+            condition: TextFilterConditionPB::TextIsNotEmpty,
+            content: "".to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: 1,
+            }),
+        },
+        Wait { millisecond: 100 },
+        CloseView,
+        // The rows are served from the saved filter results.
+        AssertNumberOfVisibleRows { expected: 5 },
+        Wait { millisecond: 200 },
+        AssertNumberOfVisibleRows { expected: 5 },
+        AssertVisibleRowCount { expected: 5 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_results_revalidated_after_row_modified_while_closed_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_revs = test.row_revs.clone();
+    let scripts = vec![
+        CreateTextFilter {
+            condition: TextFilterConditionPB::TextIsNotEmpty,
+            content: "".to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: 1,
+            }),
+        },
+        Wait { millisecond: 100 },
+        AssertNumberOfVisibleRows { expected: 5 },
+        // The saved results are outdated, the first row is hidden and the second row is shown.
+        UpdateTextCellWhileViewClosed {
+            row_id: row_revs[0].id.clone(),
+            text: "".to_string(),
+        },
+        UpdateTextCellWhileViewClosed {
+            row_id: row_revs[1].id.clone(),
+            text: "B".to_string(),
+        },
+        Wait { millisecond: 100 },
+        AssertNumberOfVisibleRows { expected: 5 },
+        AssertVisibleRowCount { expected: 5 },
+        UpdateTextCellWhileViewClosed {
+            row_id: row_revs[2].id.clone(),
+            text: "".to_string(),
+        },
+        Wait { millisecond: 100 },
+        AssertNumberOfVisibleRows { expected: 4 },
+        Wait { millisecond: 200 },
+        AssertVisibleRowCount { expected: 4 },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -0,0 +1,130 @@
+use crate::grid::filter_test::script::FilterScript::*;
+use crate::grid::filter_test::script::*;
+use flowy_database::entities::{CheckboxFilterConditionPB, FieldType, TextFilterConditionPB};
+
+#[tokio::test]
+async fn grid_filter_set_apply_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let scripts = vec![
+        SaveFilterSet { name: "All".to_owned() },
+        CreateTextFilter {
+            condition: TextFilterConditionPB::TextIsNotEmpty,
+            content: "".to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: 1,
+            }),
+        },
+        SaveFilterSet {
+            name: "Named".to_owned(),
+        },
+        AssertFilterSetNames {
+            expected: vec!["All".to_owned(), "Named".to_owned()],
+        },
+        ApplyFilterSet {
+            name: "All".to_owned(),
+            skipped_count: 0,
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 1,
+                hiding_num_of_rows: 0,
+            }),
+        },
+        AssertFilterCount { count: 0 },
+        AssertNumberOfVisibleRows { expected: 6 },
+        ApplyFilterSet {
+            name: "Named".to_owned(),
+            skipped_count: 0,
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: 1,
+            }),
+        },
+        AssertFilterCount { count: 1 },
+        AssertNumberOfVisibleRows { expected: 5 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_set_replace_and_delete_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let scripts = vec![
+        SaveFilterSet {
+            name: "Named".to_owned(),
+        },
+        CreateCheckboxFilter {
+            condition: CheckboxFilterConditionPB::IsChecked,
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: 3,
+            }),
+        },
+        SaveFilterSet {
+            name: "Named".to_owned(),
+        },
+        AssertFilterSetNames {
+            expected: vec!["Named".to_owned()],
+        },
+        ApplyFilterSet {
+            name: "Named".to_owned(),
+            skipped_count: 0,
+            changed: None,
+        },
+        AssertFilterCount { count: 1 },
+        AssertNumberOfVisibleRows { expected: 3 },
+        DeleteFilterSet {
+            name: "Named".to_owned(),
+        },
+        AssertFilterSetNames { expected: vec![] },
+        AssertFilterCount { count: 1 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_set_skip_deleted_field_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let scripts = vec![
+        CreateTextFilter {
+            condition: TextFilterConditionPB::TextIsNotEmpty,
+            content: "".to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: 1,
+            }),
+        },
+        CreateCheckboxFilter {
+            condition: CheckboxFilterConditionPB::IsChecked,
+            changed: None,
+        },
+        SaveFilterSet {
+            name: "Named".to_owned(),
+        },
+        DeleteField {
+            field_type: FieldType::Checkbox,
+        },
+        AssertFilterCount { count: 1 },
+        ApplyFilterSet {
+            name: "Named".to_owned(),
+            skipped_count: 1,
+            changed: None,
+        },
+        AssertFilterCount { count: 1 },
+        AssertNumberOfVisibleRows { expected: 5 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_set_duplicate_view_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let scripts = vec![
+        SaveFilterSet {
+            name: "Named".to_owned(),
+        },
+        AssertFilterSetNamesOfDuplicatedView {
+            expected: vec!["Named".to_owned()],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
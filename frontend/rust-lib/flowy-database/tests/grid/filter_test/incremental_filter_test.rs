@@ -0,0 +1,65 @@
+use crate::grid::filter_test::script::FilterScript::*;
+use crate::grid::filter_test::script::*;
+use flowy_database::entities::{CheckboxFilterConditionPB, TextFilterConditionPB};
+
+#[tokio::test]
+async fn grid_filter_only_evaluates_changed_cell_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_revs = test.row_revs.clone();
+    let scripts = vec![
+        CreateRows { texts: vec!["B"; 5000] },
+        // Only one row's text of the initial rows is ""
+        CreateTextFilter {
+            condition: TextFilterConditionPB::TextIsNotEmpty,
+            content: "".to_string(),
+            changed: None,
+        },
+        // The first and the last rows of the initial rows are checked and not empty
+        CreateCheckboxFilter {
+            condition: CheckboxFilterConditionPB::IsChecked,
+            changed: None,
+        },
+        Wait { millisecond: 500 },
+        AssertVisibleRowCount { expected: 2 },
+        MarkFilterEvaluationCount,
+        UpdateTextCell {
+            row_id: row_revs[0].id.clone(),
+            text: "".to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: 1,
+            }),
+        },
+        Wait { millisecond: 200 },
+        // Only the text filter is evaluated, and only for the updated row.
+        AssertFilterEvaluationCount { expected: 1 },
+        AssertVisibleRowCount { expected: 1 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_skips_unfiltered_field_change_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_revs = test.row_revs.clone();
+    let scripts = vec![
+        CreateCheckboxFilter {
+            condition: CheckboxFilterConditionPB::IsChecked,
+            changed: None,
+        },
+        Wait { millisecond: 200 },
+        AssertVisibleRowCount { expected: 3 },
+        MarkFilterEvaluationCount,
+        // The text field has no filter, so the visibility of the row is kept without evaluating
+        // the checkbox filter.
+        UpdateTextCell {
+            row_id: row_revs[0].id.clone(),
+            text: "".to_string(),
+            changed: None,
+        },
+        Wait { millisecond: 200 },
+        AssertFilterEvaluationCount { expected: 0 },
+        AssertVisibleRowCount { expected: 3 },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -1,7 +1,21 @@
+mod attachment_filter_test;
 mod checkbox_filter_test;
 mod checklist_filter_test;
 mod date_filter_test;
+mod email_filter_test;
+mod field_change_filter_test;
+mod filter_group_test;
+mod filter_result_cache_test;
+mod filter_set_test;
+mod incremental_filter_test;
 mod number_filter_test;
+mod person_filter_test;
+mod phone_number_filter_test;
+mod progress_filter_test;
+mod rating_filter_test;
 mod script;
+mod search_test;
 mod select_option_filter_test;
 mod text_filter_test;
+mod timestamp_filter_test;
+mod visible_row_count_test;
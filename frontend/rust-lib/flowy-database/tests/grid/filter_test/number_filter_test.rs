@@ -1,6 +1,6 @@
 use crate::grid::filter_test::script::FilterScript::*;
 use crate::grid::filter_test::script::{DatabaseFilterTest, FilterRowChanged};
-use flowy_database::entities::NumberFilterConditionPB;
+use flowy_database::entities::{NumberFilterConditionPB, NumberFilterPB};
 
 #[tokio::test]
 async fn grid_filter_number_is_equal_test() {
@@ -116,3 +116,151 @@ async fn grid_filter_number_is_not_empty_test() {
     ];
     test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_filter_number_is_between_legacy_content_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let expected = 3;
+    let scripts = vec![
+        CreateNumberFilter {
+            condition: NumberFilterConditionPB::Between,
+            content: "2,4".to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_number_is_between_negative_and_decimal_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let row_revs = test.row_revs.clone();
+    let expected = 3;
+    let scripts = vec![
+        UpdateNumberCell {
+            row_id: row_revs[2].id.clone(),
+            content: "-$5.25".to_string(),
+            changed: None,
+        },
+        UpdateNumberCell {
+            row_id: row_revs[3].id.clone(),
+            content: "$1,234.50".to_string(),
+            changed: None,
+        },
+        CreateNumberFilterWithOptions {
+            condition: NumberFilterConditionPB::Between,
+            content: "".to_string(),
+            start: Some("-5.25".to_string()),
+            end: Some("2.5".to_string()),
+            treat_empty_as_zero: false,
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+        AssertNumberFilter {
+            expected: NumberFilterPB {
+                condition: NumberFilterConditionPB::Between,
+                content: "".to_string(),
+                start: Some("-5.25".to_string()),
+                end: Some("2.5".to_string()),
+                treat_empty_as_zero: false,
+            },
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_number_greater_than_formatted_decimal_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let row_revs = test.row_revs.clone();
+    let expected = 1;
+    let scripts = vec![
+        UpdateNumberCell {
+            row_id: row_revs[3].id.clone(),
+            content: "$1,234.50".to_string(),
+            changed: None,
+        },
+        CreateNumberFilter {
+            condition: NumberFilterConditionPB::GreaterThan,
+            content: "1234.49".to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_number_less_than_hides_empty_cell_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let row_revs = test.row_revs.clone();
+    let expected = 1;
+    let scripts = vec![
+        UpdateNumberCell {
+            row_id: row_revs[2].id.clone(),
+            content: "-$5.25".to_string(),
+            changed: None,
+        },
+        CreateNumberFilter {
+            condition: NumberFilterConditionPB::LessThanOrEqualTo,
+            content: "0".to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_number_treat_empty_as_zero_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let row_revs = test.row_revs.clone();
+    let expected = 2;
+    let scripts = vec![
+        UpdateNumberCell {
+            row_id: row_revs[2].id.clone(),
+            content: "-$5.25".to_string(),
+            changed: None,
+        },
+        CreateNumberFilterWithOptions {
+            condition: NumberFilterConditionPB::LessThanOrEqualTo,
+            content: "0".to_string(),
+            start: None,
+            end: None,
+            treat_empty_as_zero: true,
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+        AssertNumberFilter {
+            expected: NumberFilterPB {
+                condition: NumberFilterConditionPB::LessThanOrEqualTo,
+                content: "0".to_string(),
+                start: None,
+                end: None,
+                treat_empty_as_zero: true,
+            },
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -0,0 +1,75 @@
+use crate::grid::filter_test::script::FilterScript::*;
+use crate::grid::filter_test::script::{DatabaseFilterTest, FilterRowChanged};
+use crate::grid::mock_data::{ANNIE_ID, LUCAS_ID, REMOVED_MEMBER_ID};
+use flowy_database::entities::PersonFilterConditionPB;
+
+#[tokio::test]
+async fn grid_filter_person_is_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let expected = 2;
+    let scripts = vec![
+        CreatePersonFilter {
+            condition: PersonFilterConditionPB::PersonIs,
+            member_ids: vec![LUCAS_ID.to_owned()],
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_person_is_any_of_members_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let scripts = vec![
+        CreatePersonFilter {
+            condition: PersonFilterConditionPB::PersonIs,
+            member_ids: vec![ANNIE_ID.to_owned(), REMOVED_MEMBER_ID.to_owned()],
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 2 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_person_is_not_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let expected = 4;
+    let scripts = vec![
+        CreatePersonFilter {
+            condition: PersonFilterConditionPB::PersonIsNot,
+            member_ids: vec![LUCAS_ID.to_owned()],
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_person_is_empty_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let expected = 2;
+    let scripts = vec![
+        CreatePersonFilter {
+            condition: PersonFilterConditionPB::PersonIsEmpty,
+            member_ids: vec![],
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -0,0 +1,56 @@
+use crate::grid::filter_test::script::FilterScript::*;
+use crate::grid::filter_test::script::{DatabaseFilterTest, FilterRowChanged};
+use flowy_database::entities::TextFilterConditionPB;
+
+#[tokio::test]
+async fn grid_filter_phone_number_contains_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let expected = 2;
+    let scripts = vec![
+        // The formatting characters of the filter are ignored.
+        CreatePhoneNumberFilter {
+            condition: TextFilterConditionPB::Contains,
+            content: "(555)".to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_phone_number_is_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let scripts = vec![
+        CreatePhoneNumberFilter {
+            condition: TextFilterConditionPB::Is,
+            content: "+1 555-123-4567".to_string(),
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 1 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_phone_number_is_empty_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let expected = 3;
+    let scripts = vec![
+        CreatePhoneNumberFilter {
+            condition: TextFilterConditionPB::TextIsEmpty,
+            content: "".to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
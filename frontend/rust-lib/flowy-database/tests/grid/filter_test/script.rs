@@ -7,10 +7,11 @@ use std::time::Duration;
 use bytes::Bytes;
 use futures::TryFutureExt;
 use tokio::sync::broadcast::Receiver;
-use flowy_database::entities::{AlterFilterParams, AlterFilterPayloadPB, DeleteFilterParams, DatabaseViewLayout, DatabaseSettingChangesetParams, DatabaseViewSettingPB, RowPB, TextFilterConditionPB, FieldType, NumberFilterConditionPB, CheckboxFilterConditionPB, DateFilterConditionPB, DateFilterContentPB, SelectOptionConditionPB, TextFilterPB, NumberFilterPB, CheckboxFilterPB, DateFilterPB, SelectOptionFilterPB, CellChangesetPB, FilterPB, ChecklistFilterConditionPB, ChecklistFilterPB};
-use flowy_database::services::field::{SelectOptionCellChangeset, SelectOptionIds};
+use flowy_database::entities::{AlterFilterParams, AlterFilterPayloadPB, DeleteFilterParams, DatabaseViewLayout, DatabaseSettingChangesetParams, DatabaseViewSettingPB, RowPB, TextFilterConditionPB, FieldType, NumberFilterConditionPB, CheckboxFilterConditionPB, DateFilterConditionPB, DateFilterContentPB, SelectOptionConditionPB, TextFilterPB, NumberFilterPB, CheckboxFilterPB, DateFilterPB, SelectOptionFilterPB, CellChangesetPB, FilterPB, ChecklistFilterConditionPB, ChecklistFilterPB, EmailFilterConditionPB, EmailFilterPB, AttachmentFilterConditionPB, AttachmentFilterPB, PersonFilterConditionPB, PersonFilterPB, RowDataPayload, FilterGroupPB, UpdateFilterTreePayloadPB, UpdateFilterTreeParams, FilterSetPayloadPB, FilterSetParams};
+use chrono::Local;
+use flowy_database::services::field::{ChecklistCellChangeset, DateCellChangeset, MultiSelectTypeOptionPB, SelectOptionCellChangeset, SelectOptionIds};
 use flowy_database::services::setting::GridSettingChangesetBuilder;
-use grid_model::{FieldRevision, FieldTypeRevision};
+use grid_model::{gen_grid_id, FieldRevision, FieldTypeRevision, TypeOptionDataSerializer};
 use flowy_sqlite::schema::view_table::dsl::view_table;
 use flowy_database::services::cell::insert_select_option_cell;
 use flowy_database::services::filter::FilterType;
@@ -28,11 +29,22 @@ pub enum FilterScript {
         text: String,
        changed: Option<FilterRowChanged>,
     },
+    /// Updates the cell of the number field with the input, for example: `-$5.25`.
+    UpdateNumberCell {
+        row_id: String,
+        content: String,
+        changed: Option<FilterRowChanged>,
+    },
     UpdateSingleSelectCell {
         row_id: String,
         option_id: String,
         changed: Option<FilterRowChanged>,
     },
+    UpdateChecklistCell {
+        row_id: String,
+        changeset: ChecklistCellChangeset,
+        changed: Option<FilterRowChanged>,
+    },
     InsertFilter {
         payload: AlterFilterPayloadPB,
     },
@@ -52,10 +64,50 @@ pub enum FilterScript {
         content: String,
         changed: Option<FilterRowChanged>,
     },
+    /// Creates the number filter with the bounds of the `Between` condition or the flag that
+    /// treats the empty cells as zero.
+    CreateNumberFilterWithOptions {
+        condition: NumberFilterConditionPB,
+        content: String,
+        start: Option<String>,
+        end: Option<String>,
+        treat_empty_as_zero: bool,
+        changed: Option<FilterRowChanged>,
+    },
+    CreateRatingFilter {
+        condition: NumberFilterConditionPB,
+        content: String,
+        changed: Option<FilterRowChanged>,
+    },
+    CreateProgressFilter {
+        condition: NumberFilterConditionPB,
+        content: String,
+        changed: Option<FilterRowChanged>,
+    },
     CreateCheckboxFilter {
         condition: CheckboxFilterConditionPB,
         changed: Option<FilterRowChanged>,
     },
+    CreateEmailFilter {
+        condition: EmailFilterConditionPB,
+        content: String,
+        changed: Option<FilterRowChanged>,
+    },
+    CreatePhoneNumberFilter {
+        condition: TextFilterConditionPB,
+        content: String,
+        changed: Option<FilterRowChanged>,
+    },
+    CreateAttachmentFilter {
+        condition: AttachmentFilterConditionPB,
+        content: String,
+        changed: Option<FilterRowChanged>,
+    },
+    CreatePersonFilter {
+        condition: PersonFilterConditionPB,
+        member_ids: Vec<String>,
+        changed: Option<FilterRowChanged>,
+    },
     CreateDateFilter{
         condition: DateFilterConditionPB,
         start: Option<i64>,
@@ -63,6 +115,26 @@ pub enum FilterScript {
         timestamp: Option<i64>,
         changed: Option<FilterRowChanged>,
     },
+    /// Creates the filter of the date field with the condition that is relative to today.
+    CreateRelativeDateFilter {
+        condition: DateFilterConditionPB,
+        days: Option<i64>,
+        changed: Option<FilterRowChanged>,
+    },
+    /// Updates the date cell to the timestamp in `days` days.
+    UpdateDateCellInDays {
+        row_id: String,
+        days: i64,
+        changed: Option<FilterRowChanged>,
+    },
+    CreateTimestampFilter{
+        field_type: FieldType,
+        condition: DateFilterConditionPB,
+        start: Option<i64>,
+        end: Option<i64>,
+        timestamp: Option<i64>,
+        changed: Option<FilterRowChanged>,
+    },
     CreateMultiSelectFilter {
         condition: SelectOptionConditionPB,
         option_ids: Vec<String>,
@@ -72,10 +144,25 @@ pub enum FilterScript {
         option_ids: Vec<String>,
         changed: Option<FilterRowChanged>,
     },
+    CreateChecklistCompletionFilter {
+        percent: i32,
+        changed: Option<FilterRowChanged>,
+    },
+    /// Asserts the names of the conditions of the first field of the field type in the
+    /// filterable fields of the database.
+    AssertFilterableFieldConditions {
+        field_type: FieldType,
+        expected: Vec<&'static str>,
+    },
     CreateChecklistFilter {
         condition: ChecklistFilterConditionPB,
         changed: Option<FilterRowChanged>,
     },
+    /// Deletes the option from the type option of the multi-select field.
+    DeleteMultiSelectOption {
+        option_id: String,
+        changed: Option<FilterRowChanged>,
+    },
     AssertFilterCount {
         count: i32,
     },
@@ -89,19 +176,93 @@ pub enum FilterScript {
         condition: u32,
         content: String
     },
+    /// Asserts the number filter that is decoded from the [FilterPB] of the view.
+    AssertNumberFilter {
+        expected: NumberFilterPB,
+    },
     AssertNumberOfVisibleRows {
         expected: usize,
     },
+    /// Asserts the number of the visible rows that is answered from the cached filter results,
+    /// which must be the same as the number of the rows that the view loads.
+    AssertVisibleRowCount {
+        expected: usize,
+    },
+    /// Creates the rows with the texts in a batch.
+    CreateRows {
+        texts: Vec<&'static str>,
+    },
+    DeleteRows {
+        row_ids: Vec<String>,
+    },
+    /// Replaces the groups that combine the filters of the view.
+    UpdateFilterTree {
+        filter_tree: FilterGroupPB,
+        changed: Option<FilterRowChanged>,
+    },
+    /// Asserts the groups that combine the filters in the setting of the view.
+    AssertFilterTree {
+        expected: FilterGroupPB,
+    },
     #[allow(dead_code)]
     AssertGridSetting {
         expected_setting: DatabaseViewSettingPB,
     },
+    /// Sets the search query of the view, the rows are searched after the query stops changing.
+    SetSearchQuery {
+        query: Option<&'static str>,
+        changed: Option<FilterRowChanged>,
+    },
+    /// Switches the field type of the first field of the `from_field_type`.
+    SwitchFieldType {
+        from_field_type: FieldType,
+        to_field_type: FieldType,
+    },
+    /// Deletes the first field of the field type.
+    DeleteField {
+        field_type: FieldType,
+    },
+    /// Closes the view, which saves the filter results of the view. The view is opened again by
+    /// the next script that uses it.
+    CloseView,
+    /// Closes the view and updates the text cell, so the view doesn't receive the change.
+    UpdateTextCellWhileViewClosed {
+        row_id: String,
+        text: String,
+    },
+    /// Remembers the number of the times that the filters evaluated a cell.
+    MarkFilterEvaluationCount,
+    /// Asserts the number of the times that the filters evaluated a cell since it was marked.
+    AssertFilterEvaluationCount {
+        expected: usize,
+    },
+    SaveFilterSet {
+        name: String,
+    },
+    /// Applies the filter set, and asserts the number of its filters that were skipped because
+    /// their fields were deleted.
+    ApplyFilterSet {
+        name: String,
+        skipped_count: usize,
+        changed: Option<FilterRowChanged>,
+    },
+    DeleteFilterSet {
+        name: String,
+    },
+    AssertFilterSetNames {
+        expected: Vec<String>,
+    },
+    /// Duplicates the database, and asserts the names of the filter sets of the duplicated view.
+    AssertFilterSetNamesOfDuplicatedView {
+        expected: Vec<String>,
+    },
     Wait { millisecond: u64 }
 }
 
 pub struct DatabaseFilterTest {
     inner: DatabaseEditorTest,
     recv: Option<Receiver<GridViewChanged>>,
+    marked_evaluation_count: usize,
 }
 
 impl DatabaseFilterTest {
@@ -110,6 +271,7 @@ impl DatabaseFilterTest {
         Self {
             inner: editor_test,
             recv: None,
+            marked_evaluation_count: 0,
         }
     }
 
@@ -121,6 +283,10 @@ impl DatabaseFilterTest {
         self.editor.get_all_filters().await.unwrap()
     }
 
+    pub async fn get_filter_id(&self, field_type: FieldType) -> String {
+        self.get_all_filters().await.into_iter().find(|filter| filter.field_type == field_type).unwrap().id
+    }
+
     pub async fn run_scripts(&mut self, scripts: Vec<FilterScript>) {
         for script in scripts {
             self.run_script(script).await;
@@ -134,11 +300,22 @@ impl DatabaseFilterTest {
                 self.assert_future_changed(changed).await;
                 self.update_text_cell(row_id, &text).await;
             }
+            FilterScript::UpdateNumberCell { row_id, content, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_id = self.get_first_field_rev(FieldType::Number).id.clone();
+                self.update_cell(&field_id, row_id, content).await;
+            }
             FilterScript::UpdateSingleSelectCell { row_id, option_id, changed} => {
                 self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
                 self.assert_future_changed(changed).await;
                 self.update_single_select_cell(row_id, &option_id).await;
             }
+            FilterScript::UpdateChecklistCell { row_id, changeset, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                self.update_checklist_cell(row_id, changeset).await;
+            }
             FilterScript::InsertFilter { payload } => {
                 self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
                 self.insert_filter(payload).await;
@@ -176,7 +353,40 @@ impl DatabaseFilterTest {
                 let field_rev = self.get_first_field_rev(FieldType::Number);
                 let number_filter = NumberFilterPB {
                     condition,
-                    content
+                    content,
+                    ..Default::default()
+                };
+                let payload =
+                    AlterFilterPayloadPB::new(
+                         &self.view_id(),
+                        field_rev, number_filter);
+                self.insert_filter(payload).await;
+            }
+            FilterScript::CreateNumberFilterWithOptions {condition, content, start, end, treat_empty_as_zero, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_rev = self.get_first_field_rev(FieldType::Number);
+                let number_filter = NumberFilterPB {
+                    condition,
+                    content,
+                    start,
+                    end,
+                    treat_empty_as_zero,
+                };
+                let payload =
+                    AlterFilterPayloadPB::new(
+                         &self.view_id(),
+                        field_rev, number_filter);
+                self.insert_filter(payload).await;
+            }
+            FilterScript::CreateRatingFilter {condition, content, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_rev = self.get_first_field_rev(FieldType::Rating);
+                let number_filter = NumberFilterPB {
+                    condition,
+                    content,
+                    ..Default::default()
                 };
                 let payload =
                     AlterFilterPayloadPB::new(
@@ -184,6 +394,77 @@ impl DatabaseFilterTest {
                         field_rev, number_filter);
                 self.insert_filter(payload).await;
             }
+            FilterScript::CreateProgressFilter {condition, content, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_rev = self.get_first_field_rev(FieldType::Progress);
+                let number_filter = NumberFilterPB {
+                    condition,
+                    content,
+                    ..Default::default()
+                };
+                let payload =
+                    AlterFilterPayloadPB::new(
+                         &self.view_id(),
+                        field_rev, number_filter);
+                self.insert_filter(payload).await;
+            }
+            FilterScript::CreateEmailFilter {condition, content, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_rev = self.get_first_field_rev(FieldType::Email);
+                let email_filter = EmailFilterPB {
+                    condition,
+                    content
+                };
+                let payload =
+                    AlterFilterPayloadPB::new(
+                         &self.view_id(),
+                        field_rev, email_filter);
+                self.insert_filter(payload).await;
+            }
+            FilterScript::CreateAttachmentFilter {condition, content, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_rev = self.get_first_field_rev(FieldType::Attachment);
+                let attachment_filter = AttachmentFilterPB {
+                    condition,
+                    content
+                };
+                let payload =
+                    AlterFilterPayloadPB::new(
+                         &self.view_id(),
+                        field_rev, attachment_filter);
+                self.insert_filter(payload).await;
+            }
+            FilterScript::CreatePersonFilter {condition, member_ids, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_rev = self.get_first_field_rev(FieldType::Person);
+                let person_filter = PersonFilterPB {
+                    condition,
+                    member_ids
+                };
+                let payload =
+                    AlterFilterPayloadPB::new(
+                         &self.view_id(),
+                        field_rev, person_filter);
+                self.insert_filter(payload).await;
+            }
+            FilterScript::CreatePhoneNumberFilter {condition, content, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_rev = self.get_first_field_rev(FieldType::PhoneNumber);
+                let text_filter = TextFilterPB {
+                    condition,
+                    content
+                };
+                let payload =
+                    AlterFilterPayloadPB::new(
+                         &self.view_id(),
+                        field_rev, text_filter);
+                self.insert_filter(payload).await;
+            }
             FilterScript::CreateCheckboxFilter {condition, changed} => {
                 self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
                 self.assert_future_changed(changed).await;
@@ -203,7 +484,55 @@ impl DatabaseFilterTest {
                     condition,
                     start,
                     end,
-                    timestamp
+                    timestamp,
+                    days: None,
+                };
+
+                let payload =
+                    AlterFilterPayloadPB::new( &self.view_id(), field_rev, date_filter);
+                self.insert_filter(payload).await;
+            }
+            FilterScript::CreateRelativeDateFilter { condition, days, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_rev = self.get_first_field_rev(FieldType::DateTime);
+                let date_filter = DateFilterPB {
+                    condition,
+                    start: None,
+                    end: None,
+                    timestamp: None,
+                    days,
+                };
+
+                let payload =
+                    AlterFilterPayloadPB::new( &self.view_id(), field_rev, date_filter);
+                self.insert_filter(payload).await;
+            }
+            FilterScript::UpdateDateCellInDays { row_id, days, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                // The date fields display the date of the cell in UTC, so the cell is at the noon
+                // of the local date in UTC.
+                let date = Local::now().naive_local().date() + chrono::Duration::days(days);
+                let timestamp = date.and_hms_opt(12, 0, 0).unwrap().timestamp();
+                let field_id = self.get_first_field_rev(FieldType::DateTime).id.clone();
+                let changeset = DateCellChangeset {
+                    date: Some(timestamp.to_string()),
+                    time: None,
+                    is_utc: true,
+                };
+                self.update_cell(&field_id, row_id, changeset).await;
+            }
+            FilterScript::CreateTimestampFilter { field_type, condition, start, end, timestamp, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_rev = self.get_first_field_rev(field_type);
+                let date_filter = DateFilterPB {
+                    condition,
+                    start,
+                    end,
+                    timestamp,
+                    days: None,
                 };
 
                 let payload =
@@ -232,11 +561,42 @@ impl DatabaseFilterTest {
                 self.assert_future_changed(changed).await;
                 let field_rev = self.get_first_field_rev(FieldType::Checklist);
                 // let type_option = self.get_checklist_type_option(&field_rev.id);
-                let filter = ChecklistFilterPB { condition };
+                let filter = ChecklistFilterPB { condition, ..Default::default() };
+                let payload =
+                    AlterFilterPayloadPB::new(& self.view_id(),field_rev, filter);
+                self.insert_filter(payload).await;
+            }
+            FilterScript::CreateChecklistCompletionFilter { percent, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_rev = self.get_first_field_rev(FieldType::Checklist);
+                let filter = ChecklistFilterPB { condition: ChecklistFilterConditionPB::CompletionAtLeast, percent };
                 let payload =
                     AlterFilterPayloadPB::new(& self.view_id(),field_rev, filter);
                 self.insert_filter(payload).await;
             }
+            FilterScript::AssertFilterableFieldConditions { field_type, expected } => {
+                let field_id = self.get_first_field_rev(field_type.clone()).id.clone();
+                let field = self.editor.get_filterable_fields().await.unwrap()
+                    .into_iter()
+                    .find(|field| field.field_id == field_id)
+                    .unwrap();
+                assert_eq!(field.field_type, field_type);
+                let names = field.conditions.iter().map(|condition| condition.name.as_str()).collect::<Vec<_>>();
+                assert_eq!(names, expected);
+            }
+            FilterScript::DeleteMultiSelectOption { option_id, changed } => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_id = self.get_first_field_rev(FieldType::MultiSelect).id.clone();
+                let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
+                let mut type_option = MultiSelectTypeOptionPB::from(&field_rev);
+                type_option.options.retain(|option| option.id != option_id);
+                self.editor
+                    .update_field_type_option(&self.view_id(), &field_id, type_option.protobuf_bytes().to_vec(), None)
+                    .await
+                    .unwrap();
+            }
             FilterScript::AssertFilterCount { count } => {
                 let filters = self.editor.get_all_filters().await.unwrap();
                 assert_eq!(count as usize, filters.len());
@@ -253,14 +613,109 @@ impl DatabaseFilterTest {
                 let params = DeleteFilterParams { view_id: self.view_id(),filter_type, filter_id };
                 let _ = self.editor.delete_filter(params).await.unwrap();
             }
+            FilterScript::UpdateFilterTree { filter_tree, changed } => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let payload = UpdateFilterTreePayloadPB { view_id: self.view_id(), filter_tree };
+                let params: UpdateFilterTreeParams = payload.try_into().unwrap();
+                self.editor.update_filter_tree(params).await.unwrap();
+            }
+            FilterScript::AssertFilterTree { expected } => {
+                let setting = self.editor.get_setting().await.unwrap();
+                assert_eq!(setting.filter_tree, expected);
+            }
             FilterScript::AssertGridSetting { expected_setting } => {
                 let setting = self.editor.get_setting().await.unwrap();
                 assert_eq!(expected_setting, setting);
             }
+            FilterScript::AssertNumberFilter { expected } => {
+                let filter = self.editor.get_all_filters().await.unwrap()
+                    .into_iter()
+                    .find(|filter| filter.field_type == FieldType::Number)
+                    .unwrap();
+                let number_filter = NumberFilterPB::try_from(Bytes::from(filter.data)).unwrap();
+                assert_eq!(number_filter, expected);
+            }
             FilterScript::AssertNumberOfVisibleRows { expected } => {
                 let grid = self.editor.get_database(&self.view_id()).await.unwrap();
                 assert_eq!(grid.rows.len(), expected);
             }
+            FilterScript::AssertVisibleRowCount { expected } => {
+                let visible_row_count = self.editor.visible_row_count(&self.view_id()).await.unwrap();
+                assert_eq!(visible_row_count, expected);
+                let grid = self.editor.get_database(&self.view_id()).await.unwrap();
+                assert_eq!(grid.rows.len(), expected);
+            }
+            FilterScript::CreateRows { texts } => {
+                let field_id = self.get_first_field_rev(FieldType::RichText).id.clone();
+                let rows = texts
+                    .into_iter()
+                    .map(|text| RowDataPayload::new().with_cell(&field_id, text))
+                    .collect();
+                self.editor.create_rows(rows, None).await.unwrap();
+            }
+            FilterScript::DeleteRows { row_ids } => {
+                self.editor.delete_rows(row_ids).await.unwrap();
+            }
+            FilterScript::SetSearchQuery { query, changed } => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let query = query.map(|query| query.to_owned());
+                self.editor.set_search_query(&self.view_id(), query).await.unwrap();
+            }
+            FilterScript::SwitchFieldType { from_field_type, to_field_type } => {
+                let field_id = self.get_first_field_rev(from_field_type).id.clone();
+                self.editor.switch_to_field_type(&field_id, &to_field_type).await.unwrap();
+                self.field_revs = self.editor.get_field_revs(None).await.unwrap();
+            }
+            FilterScript::DeleteField { field_type } => {
+                let field_id = self.get_first_field_rev(field_type).id.clone();
+                self.editor.delete_field(&field_id).await.unwrap();
+                self.field_revs = self.editor.get_field_revs(None).await.unwrap();
+            }
+            FilterScript::CloseView => {
+                self.editor.close_view(&self.view_id()).await;
+            }
+            FilterScript::UpdateTextCellWhileViewClosed { row_id, text } => {
+                self.editor.close_view(&self.view_id()).await;
+                self.update_text_cell(row_id, &text).await;
+            }
+            FilterScript::MarkFilterEvaluationCount => {
+                self.marked_evaluation_count = self.editor.get_filter_evaluation_count(&self.view_id()).await.unwrap();
+            }
+            FilterScript::AssertFilterEvaluationCount { expected } => {
+                let evaluation_count = self.editor.get_filter_evaluation_count(&self.view_id()).await.unwrap();
+                assert_eq!(evaluation_count - self.marked_evaluation_count, expected);
+            }
+            FilterScript::SaveFilterSet { name } => {
+                let params = FilterSetParams { view_id: self.view_id(), name };
+                self.editor.save_filter_set(params).await.unwrap();
+            }
+            FilterScript::ApplyFilterSet { name, skipped_count, changed } => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let payload = FilterSetPayloadPB { view_id: self.view_id(), name };
+                let params: FilterSetParams = payload.try_into().unwrap();
+                let applied = self.editor.apply_filter_set(params).await.unwrap();
+                assert_eq!(applied.skipped_filters.len(), skipped_count);
+            }
+            FilterScript::DeleteFilterSet { name } => {
+                let params = FilterSetParams { view_id: self.view_id(), name };
+                self.editor.delete_filter_set(params).await.unwrap();
+            }
+            FilterScript::AssertFilterSetNames { expected } => {
+                let filter_sets = self.editor.get_filter_sets(&self.view_id()).await.unwrap();
+                let names = filter_sets.items.into_iter().map(|filter_set| filter_set.name).collect::<Vec<String>>();
+                assert_eq!(names, expected);
+            }
+            FilterScript::AssertFilterSetNamesOfDuplicatedView { expected } => {
+                let new_database_id = gen_grid_id();
+                self.sdk.grid_manager.duplicate_database(&self.view_id(), &new_database_id).await.unwrap();
+                let editor = self.sdk.grid_manager.get_database_editor(&new_database_id).await.unwrap();
+                let filter_sets = editor.get_filter_sets(&new_database_id).await.unwrap();
+                let names = filter_sets.items.into_iter().map(|filter_set| filter_set.name).collect::<Vec<String>>();
+                assert_eq!(names, expected);
+            }
             FilterScript::Wait { millisecond } => {
                 tokio::time::sleep(Duration::from_millis(millisecond)).await;
             }
@@ -0,0 +1,101 @@
+use crate::grid::filter_test::script::FilterScript::*;
+use crate::grid::filter_test::script::{DatabaseFilterTest, FilterRowChanged};
+use flowy_database::entities::CheckboxFilterConditionPB;
+
+#[tokio::test]
+async fn grid_search_ignores_case_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let expected = 2;
+    let scripts = vec![
+        // The url, the email and the attachments of the first two rows contain the query.
+        SetSearchQuery {
+            query: Some("APPFLOWY"),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+        Wait { millisecond: 500 },
+        AssertVisibleRowCount { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_search_select_option_name_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let expected = 2;
+    let scripts = vec![
+        SetSearchQuery {
+            query: Some("facebook"),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_search_debounce_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let expected = 2;
+    let scripts = vec![
+        // Only the last query is searched, so there is only one notification, for `facebook`.
+        SetSearchQuery {
+            query: Some("twitter"),
+            changed: None,
+        },
+        SetSearchQuery {
+            query: Some("facebook"),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        Wait { millisecond: 500 },
+        AssertVisibleRowCount { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_search_with_filter_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let scripts = vec![
+        CreateCheckboxFilter {
+            condition: CheckboxFilterConditionPB::IsChecked,
+            changed: None,
+        },
+        AssertNumberOfVisibleRows { expected: 3 },
+        Wait { millisecond: 500 },
+        // The rows must be checked and contain the query.
+        SetSearchQuery {
+            query: Some("facebook"),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: 2,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected: 1 },
+        Wait { millisecond: 500 },
+        // Clearing the query shows the rows that were hidden by the query again.
+        SetSearchQuery {
+            query: None,
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 2,
+                hiding_num_of_rows: 0,
+            }),
+        },
+        Wait { millisecond: 500 },
+        AssertVisibleRowCount { expected: 3 },
+        AssertNumberOfVisibleRows { expected: 3 },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -1,6 +1,7 @@
 use crate::grid::filter_test::script::FilterScript::*;
 use crate::grid::filter_test::script::{DatabaseFilterTest, FilterRowChanged};
 use flowy_database::entities::{FieldType, SelectOptionConditionPB};
+use flowy_database::services::filter::FilterType;
 
 #[tokio::test]
 async fn grid_filter_multi_select_is_empty_test() {
@@ -15,6 +16,31 @@ async fn grid_filter_multi_select_is_empty_test() {
     test.run_scripts(scripts).await;
 }
 
+#[tokio::test]
+async fn grid_filter_multi_select_is_empty_after_deleting_options_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::MultiSelect).clone();
+    let options = test.get_multi_select_type_option(&field_rev.id);
+    let scripts = vec![
+        CreateMultiSelectFilter {
+            condition: SelectOptionConditionPB::OptionIsEmpty,
+            option_ids: vec![],
+        },
+        AssertNumberOfVisibleRows { expected: 3 },
+        // The third row has zero options after its only option is deleted.
+        DeleteMultiSelectOption {
+            option_id: options[1].id.clone(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 1,
+                hiding_num_of_rows: 0,
+            }),
+        },
+        AssertFilterCount { count: 1 },
+        AssertNumberOfVisibleRows { expected: 4 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
 #[tokio::test]
 async fn grid_filter_multi_select_is_not_empty_test() {
     let mut test = DatabaseFilterTest::new().await;
@@ -135,3 +161,104 @@ async fn grid_filter_single_select_is_test2() {
     ];
     test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_filter_multi_select_contains_all_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::MultiSelect);
+    let options = test.get_multi_select_type_option(&field_rev.id);
+    let scripts = vec![
+        CreateMultiSelectFilter {
+            condition: SelectOptionConditionPB::OptionContainsAll,
+            option_ids: vec![options[0].id.clone(), options[1].id.clone()],
+        },
+        AssertNumberOfVisibleRows { expected: 1 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_multi_select_contains_any_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::MultiSelect);
+    let options = test.get_multi_select_type_option(&field_rev.id);
+    let scripts = vec![
+        CreateMultiSelectFilter {
+            condition: SelectOptionConditionPB::OptionContainsAny,
+            option_ids: vec![options[1].id.clone(), options[2].id.clone()],
+        },
+        AssertNumberOfVisibleRows { expected: 3 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_multi_select_is_exactly_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::MultiSelect);
+    let options = test.get_multi_select_type_option(&field_rev.id);
+    let scripts = vec![
+        // The order of the options doesn't matter.
+        CreateMultiSelectFilter {
+            condition: SelectOptionConditionPB::OptionIsExactly,
+            option_ids: vec![options[1].id.clone(), options[0].id.clone()],
+        },
+        AssertNumberOfVisibleRows { expected: 1 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_multi_select_does_not_contain_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::MultiSelect);
+    let options = test.get_multi_select_type_option(&field_rev.id);
+    let scripts = vec![
+        CreateMultiSelectFilter {
+            condition: SelectOptionConditionPB::OptionDoesNotContain,
+            option_ids: vec![options[1].id.clone(), options[2].id.clone()],
+        },
+        AssertNumberOfVisibleRows { expected: 3 },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_multi_select_delete_option_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::MultiSelect).clone();
+    let options = test.get_multi_select_type_option(&field_rev.id);
+    let filter_type = FilterType::from(&field_rev);
+    let scripts = vec![
+        CreateMultiSelectFilter {
+            condition: SelectOptionConditionPB::OptionContainsAny,
+            option_ids: vec![options[1].id.clone(), options[2].id.clone()],
+        },
+        AssertNumberOfVisibleRows { expected: 3 },
+        // The deleted option is removed from the filter.
+        DeleteMultiSelectOption {
+            option_id: options[2].id.clone(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: 1,
+            }),
+        },
+        AssertFilterContent {
+            filter_type: filter_type.clone(),
+            condition: SelectOptionConditionPB::OptionContainsAny as u32,
+            content: options[1].id.clone(),
+        },
+        AssertNumberOfVisibleRows { expected: 2 },
+        // The filter is deleted after all of its options are deleted.
+        DeleteMultiSelectOption {
+            option_id: options[1].id.clone(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 4,
+                hiding_num_of_rows: 0,
+            }),
+        },
+        AssertFilterCount { count: 0 },
+        AssertNumberOfVisibleRows { expected: 6 },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -0,0 +1,79 @@
+use crate::grid::filter_test::script::FilterScript::*;
+use crate::grid::filter_test::script::{DatabaseFilterTest, FilterRowChanged};
+use flowy_database::entities::{DateFilterConditionPB, FieldType};
+
+#[tokio::test]
+async fn grid_filter_created_time_after_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let expected = 3;
+    let scripts = vec![
+        CreateTimestampFilter {
+            field_type: FieldType::CreatedTime,
+            condition: DateFilterConditionPB::DateAfter,
+            start: None,
+            end: None,
+            timestamp: Some(1653609600),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_created_time_within_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_count = test.row_revs.len();
+    let expected = 3;
+    let scripts = vec![
+        CreateTimestampFilter {
+            field_type: FieldType::CreatedTime,
+            condition: DateFilterConditionPB::DateWithIn,
+            start: Some(1647251762),
+            end: Some(1653609600),
+            timestamp: None,
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count - expected,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_last_edited_time_after_update_cell_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_revs = test.row_revs.clone();
+    let row_count = row_revs.len();
+    let scripts = vec![
+        CreateTimestampFilter {
+            field_type: FieldType::LastEditedTime,
+            condition: DateFilterConditionPB::DateAfter,
+            start: None,
+            end: None,
+            timestamp: Some(1671938394),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: row_count,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected: 0 },
+        // Editing any cell of the row updates its last edited time.
+        UpdateTextCell {
+            row_id: row_revs[0].id.clone(),
+            text: "B".to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 1,
+                hiding_num_of_rows: 0,
+            }),
+        },
+        AssertNumberOfVisibleRows { expected: 1 },
+    ];
+    test.run_scripts(scripts).await;
+}
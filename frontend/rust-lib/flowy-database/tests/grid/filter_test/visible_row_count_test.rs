@@ -0,0 +1,53 @@
+use crate::grid::filter_test::script::FilterScript::*;
+use crate::grid::filter_test::script::*;
+use flowy_database::entities::TextFilterConditionPB;
+
+#[tokio::test]
+async fn grid_visible_row_count_without_filters_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    test.run_scripts(vec![
+        AssertVisibleRowCount { expected: 6 },
+        CreateRows { texts: vec!["", "B"] },
+        AssertVisibleRowCount { expected: 8 },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_visible_row_count_after_batch_operations_test() {
+    let mut test = DatabaseFilterTest::new().await;
+    let row_revs = test.row_revs.clone();
+    let scripts = vec![
+        // Only one row's text of the initial rows is ""
+        CreateTextFilter {
+            condition: TextFilterConditionPB::TextIsNotEmpty,
+            content: "".to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: 1,
+            }),
+        },
+        Wait { millisecond: 100 },
+        AssertVisibleRowCount { expected: 5 },
+        // The created rows are filtered when they're counted for the first time.
+        CreateRows {
+            texts: vec!["", "B", "C"],
+        },
+        AssertVisibleRowCount { expected: 7 },
+        DeleteRows {
+            row_ids: vec![row_revs[0].id.clone(), row_revs[1].id.clone()],
+        },
+        AssertVisibleRowCount { expected: 6 },
+        UpdateTextCell {
+            row_id: row_revs[2].id.clone(),
+            text: "".to_string(),
+            changed: Some(FilterRowChanged {
+                showing_num_of_rows: 0,
+                hiding_num_of_rows: 1,
+            }),
+        },
+        Wait { millisecond: 200 },
+        AssertVisibleRowCount { expected: 5 },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -0,0 +1,146 @@
+use crate::grid::group_test::script::DatabaseGroupTest;
+use crate::grid::group_test::script::GroupScript::*;
+use flowy_database::entities::{SetGroupCollapsedParams, SetGroupCollapsedPayloadPB};
+use flowy_database::services::field::SelectTypeOptionSharedAction;
+use flowy_error::ErrorCode;
+
+#[tokio::test]
+async fn group_collapse_and_expand_group_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let scripts = vec![
+        AssertGroupCollapsed {
+            group_index: 1,
+            collapsed: false,
+        },
+        SetGroupCollapsed {
+            group_index: 1,
+            collapsed: true,
+        },
+        AssertGroupCollapsed {
+            group_index: 1,
+            collapsed: true,
+        },
+        AssertGroupCollapsed {
+            group_index: 2,
+            collapsed: false,
+        },
+        SetGroupCollapsed {
+            group_index: 1,
+            collapsed: false,
+        },
+        AssertGroupCollapsed {
+            group_index: 1,
+            collapsed: false,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_collapsed_state_is_kept_after_regrouping_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let single_select_field = test.get_single_select_field().await;
+    let scripts = vec![
+        // The no status group can be collapsed too
+        SetGroupCollapsed {
+            group_index: 0,
+            collapsed: true,
+        },
+        SetGroupCollapsed {
+            group_index: 2,
+            collapsed: true,
+        },
+        Wait { millis: 100 },
+        // The groups are generated again, the ids of the groups are the same
+        GroupByField {
+            field_id: single_select_field.id.clone(),
+        },
+        AssertGroupCollapsed {
+            group_index: 0,
+            collapsed: true,
+        },
+        AssertGroupCollapsed {
+            group_index: 1,
+            collapsed: false,
+        },
+        AssertGroupCollapsed {
+            group_index: 2,
+            collapsed: true,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_collapsed_state_of_deleted_option_is_removed_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    test.run_scripts(vec![
+        SetGroupCollapsed {
+            group_index: 1,
+            collapsed: true,
+        },
+        Wait { millis: 100 },
+    ])
+    .await;
+
+    let group_id = test.group_at_index(1).await.group_id;
+    let mut deleted_option = None;
+    test.edit_single_select_type_option(|type_option| {
+        let option = type_option
+            .options
+            .iter()
+            .find(|option| option.id == group_id)
+            .cloned()
+            .unwrap();
+        type_option.delete_option(option.clone());
+        deleted_option = Some(option);
+    })
+    .await;
+    test.run_scripts(vec![AssertGroupCount(3), Wait { millis: 100 }]).await;
+
+    // The group of the option that is added back is a new group, so it isn't collapsed.
+    test.run_scripts(vec![
+        UpdateSingleSelectSelectOption {
+            inserted_options: vec![deleted_option.unwrap()],
+        },
+        AssertGroupCount(4),
+        AssertGroupCollapsed {
+            group_index: 3,
+            collapsed: false,
+        },
+    ])
+    .await;
+    assert_eq!(test.group_at_index(3).await.group_id, group_id);
+}
+
+#[tokio::test]
+async fn group_collapsed_state_is_copied_to_duplicated_view_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let scripts = vec![
+        SetGroupCollapsed {
+            group_index: 3,
+            collapsed: true,
+        },
+        Wait { millis: 100 },
+        AssertGroupCollapsedInDuplicatedView {
+            group_index: 3,
+            collapsed: true,
+        },
+        AssertGroupCollapsedInDuplicatedView {
+            group_index: 1,
+            collapsed: false,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[test]
+fn set_group_collapsed_payload_test() {
+    let payload = SetGroupCollapsedPayloadPB {
+        view_id: "view".to_owned(),
+        group_id: "".to_owned(),
+        collapsed: true,
+    };
+    let result: Result<SetGroupCollapsedParams, ErrorCode> = payload.try_into();
+    assert_eq!(result.unwrap_err(), ErrorCode::GroupIdIsEmpty);
+}
@@ -0,0 +1,121 @@
+use crate::grid::group_test::script::DatabaseGroupTest;
+use crate::grid::group_test::script::GroupScript::*;
+use flowy_database::entities::{CreateGroupParams, CreateGroupPayloadPB};
+use flowy_error::ErrorCode;
+
+#[tokio::test]
+async fn group_create_group_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let group_1 = test.group_at_index(1).await;
+    let group_2 = test.group_at_index(2).await;
+    let group_3 = test.group_at_index(3).await;
+    test.run_scripts(vec![
+        CreateGroup {
+            name: "New group".to_owned(),
+        },
+        AssertGroupCount(5),
+        AssertGroupRowCount {
+            group_index: 4,
+            row_count: 0,
+        },
+    ])
+    .await;
+
+    // The option of the group is appended to the options of the field
+    let new_group = test.group_at_index(4).await;
+    assert_eq!(new_group.desc, "New group");
+    test.run_scripts(vec![AssertSingleSelectOptionOrder {
+        option_ids: vec![group_1.group_id, group_2.group_id, group_3.group_id, new_group.group_id],
+    }])
+    .await;
+}
+
+#[tokio::test]
+async fn group_create_group_with_duplicate_name_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let name = test.group_at_index(1).await.desc;
+    test.run_scripts(vec![
+        CreateGroup { name: name.clone() },
+        CreateGroup { name: name.clone() },
+        AssertGroupCount(6),
+    ])
+    .await;
+    assert_eq!(test.group_at_index(4).await.desc, format!("{} (2)", name));
+    assert_eq!(test.group_at_index(5).await.desc, format!("{} (3)", name));
+}
+
+#[tokio::test]
+async fn group_create_group_is_kept_after_regrouping_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let single_select_field = test.get_single_select_field().await;
+    test.run_scripts(vec![
+        MoveGroupToIndex {
+            from_group_index: 3,
+            to_index: 1,
+            reorder_options: false,
+        },
+        CreateGroup {
+            name: "New group".to_owned(),
+        },
+    ])
+    .await;
+    let group_3 = test.group_at_index(1).await;
+    let new_group = test.group_at_index(4).await;
+    test.run_scripts(vec![
+        GroupByField {
+            field_id: single_select_field.id.clone(),
+        },
+        AssertGroupCount(5),
+        AssertGroup {
+            group_index: 1,
+            expected_group: group_3,
+        },
+        AssertGroup {
+            group_index: 4,
+            expected_group: new_group,
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn group_create_group_of_date_field_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let date_field = test.get_date_field().await;
+    test.run_scripts(vec![GroupByField {
+        field_id: date_field.id.clone(),
+    }])
+    .await;
+    let params = CreateGroupParams {
+        view_id: test.view_id.clone(),
+        name: "New group".to_owned(),
+    };
+    let error = test.editor.create_group(params).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UnsupportedGroupOperation.value());
+}
+
+#[tokio::test]
+async fn group_create_group_in_second_view_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    // The second view of the board is grouped by the same field as the first one.
+    let params = CreateGroupParams {
+        view_id: "second_board_view".to_owned(),
+        name: "New group".to_owned(),
+    };
+    let new_group = test.editor.create_group(params).await.unwrap();
+    assert_eq!(new_group.desc, "New group");
+
+    // The first view is regrouped too
+    test.run_scripts(vec![AssertGroupCount(5)]).await;
+    assert_eq!(test.group_at_index(4).await.group_id, new_group.group_id);
+}
+
+#[test]
+fn create_group_payload_test() {
+    let payload = CreateGroupPayloadPB {
+        view_id: "view".to_owned(),
+        name: "  ".to_owned(),
+    };
+    let result: Result<CreateGroupParams, ErrorCode> = payload.try_into();
+    assert_eq!(result.unwrap_err(), ErrorCode::SelectOptionNameIsEmpty);
+}
@@ -0,0 +1,211 @@
+use crate::grid::group_test::script::DatabaseGroupTest;
+use crate::grid::group_test::script::GroupScript::*;
+use flowy_database::entities::{DateCondition, GroupPB, UpdateDateGroupConditionParams};
+
+#[tokio::test]
+async fn group_by_date_relative_to_today_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let date_field = test.get_date_field().await;
+    let scripts = vec![
+        GroupByField {
+            field_id: date_field.id.clone(),
+        },
+        // no status group
+        AssertGroupRowCount {
+            group_index: 0,
+            row_count: 0,
+        },
+        // All the dates of the rows are before this month
+        AssertGroup {
+            group_index: 1,
+            expected_group: GroupPB {
+                group_id: "earlier".to_owned(),
+                desc: "Earlier".to_owned(),
+                ..Default::default()
+            },
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 5,
+        },
+        AssertGroupCount(2),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_by_date_month_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let date_field = test.get_date_field().await;
+    let scripts = vec![
+        GroupByField {
+            field_id: date_field.id.clone(),
+        },
+        UpdateDateGroupCondition {
+            condition: DateCondition::Month,
+        },
+        AssertGroupCount(3),
+        AssertGroup {
+            group_index: 1,
+            expected_group: GroupPB {
+                group_id: "2022-03".to_owned(),
+                desc: "March 2022".to_owned(),
+                ..Default::default()
+            },
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 3,
+        },
+        AssertGroup {
+            group_index: 2,
+            expected_group: GroupPB {
+                group_id: "2022-11".to_owned(),
+                desc: "November 2022".to_owned(),
+                ..Default::default()
+            },
+        },
+        AssertGroupRowCount {
+            group_index: 2,
+            row_count: 2,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_by_date_week_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let date_field = test.get_date_field().await;
+    let scripts = vec![
+        GroupByField {
+            field_id: date_field.id.clone(),
+        },
+        UpdateDateGroupCondition {
+            condition: DateCondition::Week,
+        },
+        // The two dates of November are in different weeks, 2022-11-13 is a Sunday.
+        AssertGroupCount(4),
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 3,
+        },
+        AssertGroupRowCount {
+            group_index: 2,
+            row_count: 1,
+        },
+        AssertGroupRowCount {
+            group_index: 3,
+            row_count: 1,
+        },
+    ];
+    test.run_scripts(scripts).await;
+    assert_eq!(test.group_at_index(2).await.group_id, "2022-W45");
+    assert_eq!(test.group_at_index(3).await.group_id, "2022-W46");
+}
+
+#[tokio::test]
+async fn group_move_date_to_another_month_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let date_field = test.get_date_field().await;
+    let scripts = vec![
+        GroupByField {
+            field_id: date_field.id.clone(),
+        },
+        UpdateDateGroupCondition {
+            condition: DateCondition::Month,
+        },
+        // 2022-03-14
+        UpdateGroupedCellWithData {
+            from_group_index: 2,
+            row_index: 0,
+            cell_data: "1647251762".to_owned(),
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 4,
+        },
+        AssertGroupRowCount {
+            group_index: 2,
+            row_count: 1,
+        },
+        // When moving the last row out of the November group, the group will be removed
+        UpdateGroupedCellWithData {
+            from_group_index: 2,
+            row_index: 0,
+            cell_data: "1647251762".to_owned(),
+        },
+        AssertGroupCount(2),
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 5,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_create_row_in_date_group_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let date_field = test.get_date_field().await;
+    let scripts = vec![
+        GroupByField {
+            field_id: date_field.id.clone(),
+        },
+        UpdateDateGroupCondition {
+            condition: DateCondition::Month,
+        },
+        CreateRow { group_index: 2 },
+        AssertGroupRowCount {
+            group_index: 2,
+            row_count: 3,
+        },
+        CreateRow { group_index: 0 },
+        AssertGroupRowCount {
+            group_index: 0,
+            row_count: 1,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_by_created_time_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let created_time_field = test.get_created_time_field().await;
+    let scripts = vec![
+        GroupByField {
+            field_id: created_time_field.id.clone(),
+        },
+        // All the rows are created at the same time
+        AssertGroupCount(2),
+        AssertGroupRowCount {
+            group_index: 0,
+            row_count: 0,
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 5,
+        },
+        UpdateDateGroupCondition {
+            condition: DateCondition::Year,
+        },
+        AssertGroupCount(2),
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 5,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn update_date_group_condition_of_non_date_field_test() {
+    let test = DatabaseGroupTest::new().await;
+    // The board is grouped by the single select field by default.
+    let params = UpdateDateGroupConditionParams {
+        view_id: test.view_id.clone(),
+        condition: DateCondition::Month,
+    };
+    assert!(test.editor.update_date_group_condition(params).await.is_err());
+}
@@ -0,0 +1,136 @@
+use crate::grid::group_test::script::DatabaseGroupTest;
+use crate::grid::group_test::script::GroupScript::*;
+use flowy_database::entities::{MoveGroupToIndexParams, MoveGroupToIndexPayloadPB};
+use flowy_database::services::field::SelectOptionPB;
+use flowy_error::ErrorCode;
+
+#[tokio::test]
+async fn group_move_group_to_index_is_kept_after_regrouping_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let single_select_field = test.get_single_select_field().await;
+    let group_1 = test.group_at_index(1).await;
+    let group_3 = test.group_at_index(3).await;
+    let scripts = vec![
+        MoveGroupToIndex {
+            from_group_index: 3,
+            to_index: 1,
+            reorder_options: false,
+        },
+        GroupByField {
+            field_id: single_select_field.id.clone(),
+        },
+        AssertGroup {
+            group_index: 1,
+            expected_group: group_3,
+        },
+        AssertGroup {
+            group_index: 2,
+            expected_group: group_1,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_move_no_status_group_to_index_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let single_select_field = test.get_single_select_field().await;
+    let no_status_group = test.group_at_index(0).await;
+    let group_1 = test.group_at_index(1).await;
+    let scripts = vec![
+        // The index beyond the groups moves the group to the end
+        MoveGroupToIndex {
+            from_group_index: 0,
+            to_index: 10,
+            reorder_options: false,
+        },
+        AssertGroup {
+            group_index: 3,
+            expected_group: no_status_group.clone(),
+        },
+        GroupByField {
+            field_id: single_select_field.id.clone(),
+        },
+        AssertGroup {
+            group_index: 0,
+            expected_group: group_1,
+        },
+        AssertGroup {
+            group_index: 3,
+            expected_group: no_status_group,
+        },
+        AssertGroupRowCount {
+            group_index: 3,
+            row_count: 0,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_move_group_to_index_and_reorder_options_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let group_1 = test.group_at_index(1).await;
+    let group_2 = test.group_at_index(2).await;
+    let group_3 = test.group_at_index(3).await;
+    let scripts = vec![
+        MoveGroupToIndex {
+            from_group_index: 3,
+            to_index: 1,
+            reorder_options: true,
+        },
+        AssertSingleSelectOptionOrder {
+            option_ids: vec![
+                group_3.group_id.clone(),
+                group_1.group_id.clone(),
+                group_2.group_id.clone(),
+            ],
+        },
+        // The groups are generated again after the options changed, the order is the same.
+        AssertGroup {
+            group_index: 1,
+            expected_group: group_3,
+        },
+        AssertGroup {
+            group_index: 3,
+            expected_group: group_2,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_new_option_is_appended_after_moving_groups_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let group_3 = test.group_at_index(3).await;
+    let new_option_name = "New option";
+    let scripts = vec![
+        MoveGroupToIndex {
+            from_group_index: 3,
+            to_index: 0,
+            reorder_options: false,
+        },
+        UpdateSingleSelectSelectOption {
+            inserted_options: vec![SelectOptionPB::new(new_option_name)],
+        },
+        AssertGroupCount(5),
+        AssertGroup {
+            group_index: 0,
+            expected_group: group_3,
+        },
+    ];
+    test.run_scripts(scripts).await;
+    assert_eq!(test.group_at_index(4).await.desc, new_option_name);
+}
+
+#[test]
+fn move_group_to_index_payload_test() {
+    let payload = MoveGroupToIndexPayloadPB {
+        view_id: "view".to_owned(),
+        from_group_id: "group".to_owned(),
+        to_index: -1,
+        reorder_options: false,
+    };
+    let result: Result<MoveGroupToIndexParams, ErrorCode> = payload.try_into();
+    assert_eq!(result.unwrap_err(), ErrorCode::OutOfBounds);
+}
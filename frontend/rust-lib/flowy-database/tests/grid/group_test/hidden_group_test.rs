@@ -0,0 +1,108 @@
+use crate::grid::group_test::script::DatabaseGroupTest;
+use crate::grid::group_test::script::GroupScript::*;
+
+#[tokio::test]
+async fn group_hide_and_show_group_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let group = test.group_at_index(1).await;
+    let scripts = vec![
+        SetGroupVisibility {
+            group_id: group.group_id.clone(),
+            visible: false,
+        },
+        AssertGroupCount(3),
+        AssertAllGroupCount(4),
+        // The rows are still in the hidden group
+        AssertGroupRowCountWithId {
+            group_id: group.group_id.clone(),
+            row_count: 2,
+        },
+        SetGroupVisibility {
+            group_id: group.group_id.clone(),
+            visible: true,
+        },
+        AssertGroupCount(4),
+    ];
+    test.run_scripts(scripts).await;
+    assert!(test.group_with_id(&group.group_id).await.is_visible);
+    // The group is shown at its old position
+    assert_eq!(test.group_at_index(1).await.group_id, group.group_id);
+}
+
+#[tokio::test]
+async fn group_hide_no_status_group_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let no_status_group = test.group_at_index(0).await;
+    assert!(no_status_group.is_default);
+    let scripts = vec![
+        SetGroupVisibility {
+            group_id: no_status_group.group_id.clone(),
+            visible: false,
+        },
+        AssertGroupCount(3),
+        // Moves the row of the first visible group into the hidden no status group
+        UpdateGroupedCellToGroupId {
+            from_group_index: 0,
+            row_index: 0,
+            to_group_id: no_status_group.group_id.clone(),
+        },
+        AssertGroupRowCount {
+            group_index: 0,
+            row_count: 1,
+        },
+        AssertGroupRowCountWithId {
+            group_id: no_status_group.group_id.clone(),
+            row_count: 1,
+        },
+    ];
+    test.run_scripts(scripts).await;
+    assert!(!test.group_at_index(0).await.is_default);
+}
+
+#[tokio::test]
+async fn group_move_row_into_hidden_group_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let hidden_group = test.group_at_index(3).await;
+    let scripts = vec![
+        SetGroupVisibility {
+            group_id: hidden_group.group_id.clone(),
+            visible: false,
+        },
+        UpdateGroupedCellToGroupId {
+            from_group_index: 1,
+            row_index: 0,
+            to_group_id: hidden_group.group_id.clone(),
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 1,
+        },
+        AssertGroupRowCountWithId {
+            group_id: hidden_group.group_id.clone(),
+            row_count: 2,
+        },
+        AssertGroupCount(3),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_hidden_state_is_kept_after_regrouping_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let single_select_field = test.get_single_select_field().await;
+    let hidden_group = test.group_at_index(2).await;
+    let scripts = vec![
+        SetGroupVisibility {
+            group_id: hidden_group.group_id.clone(),
+            visible: false,
+        },
+        Wait { millis: 100 },
+        GroupByField {
+            field_id: single_select_field.id.clone(),
+        },
+        AssertGroupCount(3),
+        AssertAllGroupCount(4),
+    ];
+    test.run_scripts(scripts).await;
+    assert!(!test.group_with_id(&hidden_group.group_id).await.is_visible);
+}
@@ -1,3 +1,12 @@
+mod collapsed_group_test;
+mod create_group_test;
+mod date_group_test;
+mod group_order_test;
+mod hidden_group_test;
+mod multi_select_group_test;
+mod number_group_test;
+mod person_group_test;
+mod rating_group_test;
 mod script;
 mod test;
 mod url_group_test;
@@ -0,0 +1,191 @@
+use crate::grid::group_test::script::DatabaseGroupTest;
+use crate::grid::group_test::script::GroupScript::*;
+
+// The board is grouped by the multi-select field, the groups are: no status, Google, Facebook and
+// Twitter. The first two rows are in Google and Facebook, the third row is in Google only.
+async fn new_multi_select_group_test() -> DatabaseGroupTest {
+    let mut test = DatabaseGroupTest::new().await;
+    let multi_select_field = test.get_multi_select_field().await;
+    test.run_scripts(vec![GroupByField {
+        field_id: multi_select_field.id.clone(),
+    }])
+    .await;
+    test
+}
+
+#[tokio::test]
+async fn group_multi_select_row_with_three_options_edited_to_one_option_test() {
+    let mut test = new_multi_select_group_test().await;
+    let row = test.row_at_index(1, 0).await;
+    test.run_scripts(vec![
+        UpdateMultiSelectCell {
+            from_group_index: 1,
+            row_index: 0,
+            option_group_indexes: vec![1, 2, 3],
+        },
+        // The row is in every group of its options
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 3,
+        },
+        AssertGroupRowCount {
+            group_index: 2,
+            row_count: 2,
+        },
+        AssertGroupRowCount {
+            group_index: 3,
+            row_count: 1,
+        },
+        UpdateMultiSelectCell {
+            from_group_index: 3,
+            row_index: 0,
+            option_group_indexes: vec![3],
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 2,
+        },
+        AssertGroupRowCount {
+            group_index: 2,
+            row_count: 1,
+        },
+        AssertGroupRowCount {
+            group_index: 3,
+            row_count: 1,
+        },
+        AssertRow {
+            group_index: 3,
+            row_index: 0,
+            row,
+        },
+        // The row is still grouped, so it isn't in the no status group
+        AssertGroupRowCount {
+            group_index: 0,
+            row_count: 2,
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn group_move_multi_select_row_to_another_group_test() {
+    let mut test = new_multi_select_group_test().await;
+    let row = test.row_at_index(2, 0).await;
+    test.run_scripts(vec![
+        UpdateMultiSelectCell {
+            from_group_index: 1,
+            row_index: 2,
+            option_group_indexes: vec![3],
+        },
+        // Moves the first row out of Facebook into Twitter, the row stays in Google.
+        MoveRow {
+            from_group_index: 2,
+            from_row_index: 0,
+            to_group_index: 3,
+            to_row_index: 0,
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 2,
+        },
+        AssertRow {
+            group_index: 1,
+            row_index: 0,
+            row: row.clone(),
+        },
+        AssertGroupRowCount {
+            group_index: 2,
+            row_count: 1,
+        },
+        AssertGroupRowCount {
+            group_index: 3,
+            row_count: 2,
+        },
+        AssertRow {
+            group_index: 3,
+            row_index: 0,
+            row,
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn group_reorder_multi_select_row_test() {
+    let mut test = new_multi_select_group_test().await;
+    test.run_scripts(vec![
+        MoveRow {
+            from_group_index: 1,
+            from_row_index: 0,
+            to_group_index: 1,
+            to_row_index: 2,
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 3,
+        },
+        // The row is still in the group of its other option
+        AssertGroupRowCount {
+            group_index: 2,
+            row_count: 2,
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn group_move_multi_select_row_to_no_status_group_test() {
+    let mut test = new_multi_select_group_test().await;
+    let row = test.row_at_index(1, 0).await;
+    test.run_scripts(vec![
+        MoveRow {
+            from_group_index: 1,
+            from_row_index: 0,
+            to_group_index: 0,
+            to_row_index: 0,
+        },
+        // All the options of the row are removed
+        AssertGroupRowCount {
+            group_index: 0,
+            row_count: 3,
+        },
+        AssertRow {
+            group_index: 0,
+            row_index: 0,
+            row,
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 2,
+        },
+        AssertGroupRowCount {
+            group_index: 2,
+            row_count: 1,
+        },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn group_delete_multi_select_row_test() {
+    let mut test = new_multi_select_group_test().await;
+    test.run_scripts(vec![
+        DeleteRow {
+            group_index: 1,
+            row_index: 0,
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 2,
+        },
+        AssertGroupRowCount {
+            group_index: 2,
+            row_count: 1,
+        },
+        AssertGroupRowCount {
+            group_index: 0,
+            row_count: 2,
+        },
+    ])
+    .await;
+}
@@ -0,0 +1,209 @@
+use crate::grid::group_test::script::DatabaseGroupTest;
+use crate::grid::group_test::script::GroupScript::*;
+use flowy_database::entities::{GroupPB, UpdateNumberGroupRangesParams, UpdateNumberGroupRangesPayloadPB};
+use flowy_error::ErrorCode;
+
+fn breakpoints(breakpoints: &[&str]) -> Vec<String> {
+    breakpoints.iter().map(|breakpoint| breakpoint.to_string()).collect()
+}
+
+#[tokio::test]
+async fn group_by_number_fixed_width_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let number_field = test.get_number_field().await;
+    let scripts = vec![
+        GroupByField {
+            field_id: number_field.id.clone(),
+        },
+        // no status group
+        AssertGroupRowCount {
+            group_index: 0,
+            row_count: 1,
+        },
+        AssertGroup {
+            group_index: 1,
+            expected_group: GroupPB {
+                group_id: "0..10".to_owned(),
+                desc: "0–9".to_owned(),
+                ..Default::default()
+            },
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 4,
+        },
+        AssertGroupCount(2),
+        UpdateNumberGroupRanges {
+            start: "0".to_owned(),
+            width: "2".to_owned(),
+            breakpoints: vec![],
+        },
+        // 1 | 2, 3 | 4
+        AssertGroupCount(4),
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 1,
+        },
+        AssertGroupRowCount {
+            group_index: 2,
+            row_count: 2,
+        },
+        AssertGroupRowCount {
+            group_index: 3,
+            row_count: 1,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_by_number_breakpoints_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let number_field = test.get_number_field().await;
+    let scripts = vec![
+        GroupByField {
+            field_id: number_field.id.clone(),
+        },
+        UpdateNumberGroupRanges {
+            start: "".to_owned(),
+            width: "".to_owned(),
+            breakpoints: breakpoints(&["2", "4"]),
+        },
+        AssertGroupCount(4),
+        AssertGroup {
+            group_index: 1,
+            expected_group: GroupPB {
+                group_id: "..2".to_owned(),
+                desc: "< 2".to_owned(),
+                ..Default::default()
+            },
+        },
+        AssertGroup {
+            group_index: 2,
+            expected_group: GroupPB {
+                group_id: "2..4".to_owned(),
+                desc: "2–3".to_owned(),
+                ..Default::default()
+            },
+        },
+        AssertGroupRowCount {
+            group_index: 2,
+            row_count: 2,
+        },
+        AssertGroup {
+            group_index: 3,
+            expected_group: GroupPB {
+                group_id: "4..".to_owned(),
+                desc: "≥ 4".to_owned(),
+                ..Default::default()
+            },
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_move_number_to_another_range_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let number_field = test.get_number_field().await;
+    let scripts = vec![
+        GroupByField {
+            field_id: number_field.id.clone(),
+        },
+        UpdateNumberGroupRanges {
+            start: "".to_owned(),
+            width: "".to_owned(),
+            breakpoints: breakpoints(&["2", "4"]),
+        },
+        // The number is still below the first breakpoint
+        UpdateGroupedCellWithData {
+            from_group_index: 1,
+            row_index: 0,
+            cell_data: "0.5".to_owned(),
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 1,
+        },
+        // When moving the last row out of the `≥ 4` group, the group will be removed
+        UpdateGroupedCellWithData {
+            from_group_index: 3,
+            row_index: 0,
+            cell_data: "3.99".to_owned(),
+        },
+        AssertGroupCount(3),
+        AssertGroupRowCount {
+            group_index: 2,
+            row_count: 3,
+        },
+        // The empty cell is put into the no status group
+        UpdateGroupedCellWithData {
+            from_group_index: 1,
+            row_index: 0,
+            cell_data: "".to_owned(),
+        },
+        AssertGroupRowCount {
+            group_index: 0,
+            row_count: 2,
+        },
+        AssertGroupCount(2),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_create_row_in_number_range_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let number_field = test.get_number_field().await;
+    let scripts = vec![
+        GroupByField {
+            field_id: number_field.id.clone(),
+        },
+        UpdateNumberGroupRanges {
+            start: "".to_owned(),
+            width: "".to_owned(),
+            breakpoints: breakpoints(&["2", "4"]),
+        },
+        CreateRow { group_index: 1 },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 2,
+        },
+        CreateRow { group_index: 3 },
+        AssertGroupRowCount {
+            group_index: 3,
+            row_count: 2,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[test]
+fn number_group_ranges_payload_test() {
+    let payload = UpdateNumberGroupRangesPayloadPB {
+        view_id: "view".to_owned(),
+        start: "".to_owned(),
+        width: "0.50".to_owned(),
+        breakpoints: breakpoints(&["100", "10", "10.0"]),
+    };
+    let params: UpdateNumberGroupRangesParams = payload.try_into().unwrap();
+    assert_eq!(params.start, "0");
+    assert_eq!(params.width, "0.5");
+    assert_eq!(params.breakpoints, breakpoints(&["10", "100"]));
+
+    let payload = UpdateNumberGroupRangesPayloadPB {
+        view_id: "view".to_owned(),
+        width: "0".to_owned(),
+        ..Default::default()
+    };
+    let result: Result<UpdateNumberGroupRangesParams, ErrorCode> = payload.try_into();
+    assert_eq!(result.unwrap_err(), ErrorCode::NumberGroupRangeInvalid);
+
+    let payload = UpdateNumberGroupRangesPayloadPB {
+        view_id: "view".to_owned(),
+        breakpoints: breakpoints(&["abc"]),
+        ..Default::default()
+    };
+    let result: Result<UpdateNumberGroupRangesParams, ErrorCode> = payload.try_into();
+    assert_eq!(result.unwrap_err(), ErrorCode::NumberGroupRangeInvalid);
+}
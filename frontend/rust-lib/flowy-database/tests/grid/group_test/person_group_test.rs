@@ -0,0 +1,87 @@
+use crate::grid::group_test::script::DatabaseGroupTest;
+use crate::grid::group_test::script::GroupScript::*;
+
+#[tokio::test]
+async fn group_group_by_person() {
+    let mut test = DatabaseGroupTest::new().await;
+    let person_field = test.get_person_field().await;
+    let scripts = vec![
+        GroupByField {
+            field_id: person_field.id.clone(),
+        },
+        // One group for each member, and the no status group
+        AssertGroupCount(4),
+        // no status group
+        AssertGroupRowCount {
+            group_index: 0,
+            row_count: 2,
+        },
+        // Lucas
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 2,
+        },
+        // Nathan
+        AssertGroupRowCount {
+            group_index: 2,
+            row_count: 1,
+        },
+        // Annie
+        AssertGroupRowCount {
+            group_index: 3,
+            row_count: 1,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_move_person_to_another_group_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let person_field = test.get_person_field().await;
+    let scripts = vec![
+        GroupByField {
+            field_id: person_field.id.clone(),
+        },
+        UpdateGroupedCell {
+            from_group_index: 3,
+            row_index: 0,
+            to_group_index: 1,
+        },
+        // The row references Lucas instead of Annie now.
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 3,
+        },
+        AssertGroupRowCount {
+            group_index: 3,
+            row_count: 1,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_move_person_to_no_status_group_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let person_field = test.get_person_field().await;
+    let scripts = vec![
+        GroupByField {
+            field_id: person_field.id.clone(),
+        },
+        UpdateGroupedCell {
+            from_group_index: 3,
+            row_index: 0,
+            to_group_index: 0,
+        },
+        AssertGroupRowCount {
+            group_index: 0,
+            row_count: 3,
+        },
+        AssertGroupRowCount {
+            group_index: 3,
+            row_count: 0,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
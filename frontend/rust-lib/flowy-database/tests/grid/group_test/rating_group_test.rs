@@ -0,0 +1,63 @@
+use crate::grid::group_test::script::DatabaseGroupTest;
+use crate::grid::group_test::script::GroupScript::*;
+
+#[tokio::test]
+async fn group_group_by_rating() {
+    let mut test = DatabaseGroupTest::new().await;
+    let rating_field = test.get_rating_field().await;
+    let scripts = vec![
+        GroupByField {
+            field_id: rating_field.id.clone(),
+        },
+        // One group for each rating, and the no status group
+        AssertGroupCount(6),
+        // no status group
+        AssertGroupRowCount {
+            group_index: 0,
+            row_count: 2,
+        },
+        // 1
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 0,
+        },
+        // 3
+        AssertGroupRowCount {
+            group_index: 3,
+            row_count: 2,
+        },
+        // 5
+        AssertGroupRowCount {
+            group_index: 5,
+            row_count: 1,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_alter_rating_to_another_group_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let rating_field = test.get_rating_field().await;
+    let scripts = vec![
+        GroupByField {
+            field_id: rating_field.id.clone(),
+        },
+        UpdateGroupedCell {
+            from_group_index: 3,
+            row_index: 0,
+            to_group_index: 5,
+        },
+        AssertGroupRowCount {
+            group_index: 3,
+            row_count: 1,
+        },
+        AssertGroupRowCount {
+            group_index: 5,
+            row_count: 2,
+        },
+        // The groups are kept even if they don't contain any rows.
+        AssertGroupCount(6),
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -1,13 +1,19 @@
 use crate::grid::database_editor::DatabaseEditorTest;
 use flowy_database::entities::{
-    CreateRowParams, DatabaseViewLayout, FieldType, GroupPB, MoveGroupParams, MoveGroupRowParams, RowPB,
+    CreateGroupParams, CreateRowParams, DatabaseViewLayout, DateCondition, FieldType, GroupPB, MoveGroupParams,
+    MoveGroupRowParams, MoveGroupToIndexParams, RowPB, SetGroupCollapsedParams, SetGroupVisibilityParams,
+    UpdateDateGroupConditionParams, UpdateNumberGroupRangesParams,
+};
+use flowy_database::services::cell::{
+    apply_cell_data_changeset, delete_select_option_cell, insert_date_cell, insert_person_cell, insert_rating_cell,
+    insert_select_option_cell, insert_url_cell,
 };
-use flowy_database::services::cell::{delete_select_option_cell, insert_select_option_cell, insert_url_cell};
 use flowy_database::services::field::{
     edit_single_select_type_option, SelectOptionPB, SelectTypeOptionSharedAction, SingleSelectTypeOptionPB,
 };
-use grid_model::{FieldRevision, RowChangeset};
+use grid_model::{gen_grid_id, CellRevision, FieldRevision, RowChangeset};
 use std::sync::Arc;
+use std::time::Duration;
 
 pub enum GroupScript {
     AssertGroupRowCount {
@@ -33,6 +39,8 @@ pub enum GroupScript {
     CreateRow {
         group_index: usize,
     },
+    /// Creates a row that isn't in any group, it's put into the group that matches its cell.
+    CreateUngroupedRow,
     DeleteRow {
         group_index: usize,
         row_index: usize,
@@ -42,21 +50,84 @@ pub enum GroupScript {
         row_index: usize,
         to_group_index: usize,
     },
+    /// Same as `UpdateGroupedCell`, but the group is found by its id, so it can be a hidden group.
+    UpdateGroupedCellToGroupId {
+        from_group_index: usize,
+        row_index: usize,
+        to_group_id: String,
+    },
     UpdateGroupedCellWithData {
         from_group_index: usize,
         row_index: usize,
         cell_data: String,
     },
+    /// Replaces the options of the multi-select cell with the options of the groups.
+    UpdateMultiSelectCell {
+        from_group_index: usize,
+        row_index: usize,
+        option_group_indexes: Vec<usize>,
+    },
     MoveGroup {
         from_group_index: usize,
         to_group_index: usize,
     },
+    MoveGroupToIndex {
+        from_group_index: usize,
+        to_index: usize,
+        reorder_options: bool,
+    },
+    AssertSingleSelectOptionOrder {
+        option_ids: Vec<String>,
+    },
+    CreateGroup {
+        name: String,
+    },
     UpdateSingleSelectSelectOption {
         inserted_options: Vec<SelectOptionPB>,
     },
     GroupByField {
         field_id: String,
     },
+    UpdateDateGroupCondition {
+        condition: DateCondition,
+    },
+    UpdateNumberGroupRanges {
+        start: String,
+        width: String,
+        breakpoints: Vec<String>,
+    },
+    ClearFieldCells {
+        field_id: String,
+    },
+    Undo,
+    SetGroupCollapsed {
+        group_index: usize,
+        collapsed: bool,
+    },
+    AssertGroupCollapsed {
+        group_index: usize,
+        collapsed: bool,
+    },
+    /// Duplicates the database, and asserts the state of the group in the view of the copy.
+    AssertGroupCollapsedInDuplicatedView {
+        group_index: usize,
+        collapsed: bool,
+    },
+    SetGroupVisibility {
+        group_id: String,
+        visible: bool,
+    },
+    /// Asserts the number of the groups, including the hidden groups.
+    AssertAllGroupCount(usize),
+    /// Asserts the number of the rows in the group with the id, the group can be hidden.
+    AssertGroupRowCountWithId {
+        group_id: String,
+        row_count: usize,
+    },
+    /// Waits for the group configuration to be saved in the background.
+    Wait {
+        millis: u64,
+    },
 }
 
 pub struct DatabaseGroupTest {
@@ -91,7 +162,8 @@ impl DatabaseGroupTest {
                 to_row_index,
             } => {
                 let groups: Vec<GroupPB> = self.editor.load_groups().await.unwrap().items;
-                let from_row = groups.get(from_group_index).unwrap().rows.get(from_row_index).unwrap();
+                let from_group = groups.get(from_group_index).unwrap();
+                let from_row = from_group.rows.get(from_row_index).unwrap();
                 let to_group = groups.get(to_group_index).unwrap();
                 let to_row = to_group.rows.get(to_row_index).unwrap();
                 let params = MoveGroupRowParams {
@@ -99,6 +171,7 @@ impl DatabaseGroupTest {
                     from_row_id: from_row.id.clone(),
                     to_group_id: to_group.group_id.clone(),
                     to_row_id: Some(to_row.id.clone()),
+                    from_group_id: Some(from_group.group_id.clone()),
                 };
 
                 self.editor.move_group_row(params).await.unwrap();
@@ -123,6 +196,15 @@ impl DatabaseGroupTest {
                 };
                 let _ = self.editor.create_row(params).await.unwrap();
             }
+            GroupScript::CreateUngroupedRow => {
+                let params = CreateRowParams {
+                    database_id: self.editor.database_id.clone(),
+                    start_row_id: None,
+                    group_id: None,
+                    layout: DatabaseViewLayout::Board,
+                };
+                let _ = self.editor.create_row(params).await.unwrap();
+            }
             GroupScript::DeleteRow { group_index, row_index } => {
                 let row = self.row_at_index(group_index, row_index).await;
                 self.editor.delete_row(&row.id).await.unwrap();
@@ -132,43 +214,16 @@ impl DatabaseGroupTest {
                 row_index,
                 to_group_index,
             } => {
-                let from_group = self.group_at_index(from_group_index).await;
                 let to_group = self.group_at_index(to_group_index).await;
-                let field_id = from_group.field_id;
-                let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
-                let field_type: FieldType = field_rev.ty.into();
-
-                let cell_rev = if to_group.is_default {
-                    match field_type {
-                        FieldType::SingleSelect => {
-                            delete_select_option_cell(vec![to_group.group_id.clone()], &field_rev)
-                        }
-                        FieldType::MultiSelect => {
-                            delete_select_option_cell(vec![to_group.group_id.clone()], &field_rev)
-                        }
-                        _ => {
-                            panic!("Unsupported group field type");
-                        }
-                    }
-                } else {
-                    match field_type {
-                        FieldType::SingleSelect => {
-                            insert_select_option_cell(vec![to_group.group_id.clone()], &field_rev)
-                        }
-                        FieldType::MultiSelect => {
-                            insert_select_option_cell(vec![to_group.group_id.clone()], &field_rev)
-                        }
-                        FieldType::URL => insert_url_cell(to_group.group_id.clone(), &field_rev),
-                        _ => {
-                            panic!("Unsupported group field type");
-                        }
-                    }
-                };
-
-                let row_id = self.row_at_index(from_group_index, row_index).await.id;
-                let mut row_changeset = RowChangeset::new(row_id);
-                row_changeset.cell_by_field_id.insert(field_id, cell_rev);
-                self.editor.update_row(row_changeset).await.unwrap();
+                self.update_grouped_cell(from_group_index, row_index, to_group).await;
+            }
+            GroupScript::UpdateGroupedCellToGroupId {
+                from_group_index,
+                row_index,
+                to_group_id,
+            } => {
+                let to_group = self.group_with_id(&to_group_id).await;
+                self.update_grouped_cell(from_group_index, row_index, to_group).await;
             }
             GroupScript::UpdateGroupedCellWithData {
                 from_group_index,
@@ -181,6 +236,10 @@ impl DatabaseGroupTest {
                 let field_type: FieldType = field_rev.ty.into();
                 let cell_rev = match field_type {
                     FieldType::URL => insert_url_cell(cell_data, &field_rev),
+                    FieldType::DateTime => insert_date_cell(cell_data.parse().unwrap(), &field_rev),
+                    FieldType::Number => {
+                        CellRevision::new(apply_cell_data_changeset(cell_data, None, &field_rev, None).unwrap())
+                    }
                     _ => {
                         panic!("Unsupported group field type");
                     }
@@ -191,6 +250,22 @@ impl DatabaseGroupTest {
                 row_changeset.cell_by_field_id.insert(field_id, cell_rev);
                 self.editor.update_row(row_changeset).await.unwrap();
             }
+            GroupScript::UpdateMultiSelectCell {
+                from_group_index,
+                row_index,
+                option_group_indexes,
+            } => {
+                let field_rev = self.get_multi_select_field().await;
+                let mut option_ids = vec![];
+                for group_index in option_group_indexes {
+                    option_ids.push(self.group_at_index(group_index).await.group_id);
+                }
+                let cell_rev = insert_select_option_cell(option_ids, &field_rev);
+                let row_id = self.row_at_index(from_group_index, row_index).await.id;
+                let mut row_changeset = RowChangeset::new(row_id);
+                row_changeset.cell_by_field_id.insert(field_rev.id.clone(), cell_rev);
+                self.editor.update_row(row_changeset).await.unwrap();
+            }
             GroupScript::MoveGroup {
                 from_group_index,
                 to_group_index,
@@ -205,6 +280,33 @@ impl DatabaseGroupTest {
                 self.editor.move_group(params).await.unwrap();
                 //
             }
+            GroupScript::MoveGroupToIndex {
+                from_group_index,
+                to_index,
+                reorder_options,
+            } => {
+                let from_group = self.group_at_index(from_group_index).await;
+                let params = MoveGroupToIndexParams {
+                    view_id: self.inner.view_id.clone(),
+                    from_group_id: from_group.group_id,
+                    to_index,
+                    reorder_options,
+                };
+                self.editor.move_group_to_index(params).await.unwrap();
+            }
+            GroupScript::AssertSingleSelectOptionOrder { option_ids } => {
+                let single_select = self.get_single_select_field().await;
+                let field_rev = self.editor.get_field_rev(&single_select.id).await.unwrap();
+                let type_option = field_rev
+                    .get_type_option::<SingleSelectTypeOptionPB>(field_rev.ty)
+                    .unwrap();
+                let ids = type_option
+                    .options
+                    .iter()
+                    .map(|option| option.id.clone())
+                    .collect::<Vec<String>>();
+                assert_eq!(ids, option_ids);
+            }
             GroupScript::AssertGroup {
                 group_index,
                 expected_group: group_pb,
@@ -213,6 +315,13 @@ impl DatabaseGroupTest {
                 assert_eq!(group.group_id, group_pb.group_id);
                 assert_eq!(group.desc, group_pb.desc);
             }
+            GroupScript::CreateGroup { name } => {
+                let params = CreateGroupParams {
+                    view_id: self.inner.view_id.clone(),
+                    name,
+                };
+                self.editor.create_group(params).await.unwrap();
+            }
             GroupScript::UpdateSingleSelectSelectOption { inserted_options } => {
                 self.edit_single_select_type_option(|type_option| {
                     for inserted_option in inserted_options {
@@ -224,9 +333,122 @@ impl DatabaseGroupTest {
             GroupScript::GroupByField { field_id } => {
                 self.editor.group_by_field(&field_id).await.unwrap();
             }
+            GroupScript::UpdateDateGroupCondition { condition } => {
+                let params = UpdateDateGroupConditionParams {
+                    view_id: self.inner.view_id.clone(),
+                    condition,
+                };
+                self.editor.update_date_group_condition(params).await.unwrap();
+            }
+            GroupScript::UpdateNumberGroupRanges {
+                start,
+                width,
+                breakpoints,
+            } => {
+                let params = UpdateNumberGroupRangesParams {
+                    view_id: self.inner.view_id.clone(),
+                    start,
+                    width,
+                    breakpoints,
+                };
+                self.editor.update_number_group_ranges(params).await.unwrap();
+            }
+            GroupScript::ClearFieldCells { field_id } => {
+                self.editor.clear_field_cells(&field_id).await.unwrap();
+            }
+            GroupScript::Undo => {
+                assert!(self.editor.undo().await.unwrap());
+            }
+            GroupScript::SetGroupCollapsed { group_index, collapsed } => {
+                let group = self.group_at_index(group_index).await;
+                let params = SetGroupCollapsedParams {
+                    view_id: self.inner.view_id.clone(),
+                    group_id: group.group_id,
+                    collapsed,
+                };
+                self.editor.set_group_collapsed(params).await.unwrap();
+            }
+            GroupScript::AssertGroupCollapsed { group_index, collapsed } => {
+                assert_eq!(self.group_at_index(group_index).await.is_collapsed, collapsed);
+            }
+            GroupScript::AssertGroupCollapsedInDuplicatedView { group_index, collapsed } => {
+                let new_database_id = gen_grid_id();
+                self.sdk
+                    .grid_manager
+                    .duplicate_database(&self.inner.view_id, &new_database_id)
+                    .await
+                    .unwrap();
+                let editor = self
+                    .sdk
+                    .grid_manager
+                    .get_database_editor(&new_database_id)
+                    .await
+                    .unwrap();
+                let groups = editor.load_groups().await.unwrap().items;
+                assert_eq!(groups.get(group_index).unwrap().is_collapsed, collapsed);
+            }
+            GroupScript::SetGroupVisibility { group_id, visible } => {
+                let params = SetGroupVisibilityParams {
+                    view_id: self.inner.view_id.clone(),
+                    group_id,
+                    visible,
+                };
+                self.editor.set_group_visibility(params).await.unwrap();
+            }
+            GroupScript::AssertAllGroupCount(count) => {
+                let groups = self.editor.load_all_groups().await.unwrap();
+                assert_eq!(count, groups.len());
+            }
+            GroupScript::AssertGroupRowCountWithId { group_id, row_count } => {
+                assert_eq!(row_count, self.group_with_id(&group_id).await.rows.len());
+            }
+            GroupScript::Wait { millis } => {
+                tokio::time::sleep(Duration::from_millis(millis)).await;
+            }
         }
     }
 
+    /// Updates the cell of the row, so the row is moved into the `to_group`.
+    async fn update_grouped_cell(&self, from_group_index: usize, row_index: usize, to_group: GroupPB) {
+        let from_group = self.group_at_index(from_group_index).await;
+        let field_id = from_group.field_id;
+        let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
+        let field_type: FieldType = field_rev.ty.into();
+
+        let cell_rev = if to_group.is_default {
+            match field_type {
+                FieldType::SingleSelect => delete_select_option_cell(vec![to_group.group_id.clone()], &field_rev),
+                FieldType::MultiSelect => delete_select_option_cell(vec![to_group.group_id.clone()], &field_rev),
+                FieldType::Person => insert_person_cell(vec![], &field_rev),
+                _ => {
+                    panic!("Unsupported group field type");
+                }
+            }
+        } else {
+            match field_type {
+                FieldType::SingleSelect => insert_select_option_cell(vec![to_group.group_id.clone()], &field_rev),
+                FieldType::MultiSelect => insert_select_option_cell(vec![to_group.group_id.clone()], &field_rev),
+                FieldType::URL => insert_url_cell(to_group.group_id.clone(), &field_rev),
+                FieldType::Rating => insert_rating_cell(to_group.group_id.parse().unwrap(), &field_rev),
+                FieldType::Person => insert_person_cell(vec![to_group.group_id.clone()], &field_rev),
+                _ => {
+                    panic!("Unsupported group field type");
+                }
+            }
+        };
+
+        let row_id = self.row_at_index(from_group_index, row_index).await.id;
+        let mut row_changeset = RowChangeset::new(row_id);
+        row_changeset.cell_by_field_id.insert(field_id, cell_rev);
+        self.editor.update_row(row_changeset).await.unwrap();
+    }
+
+    /// Returns the group with the id, including the hidden group.
+    pub async fn group_with_id(&self, group_id: &str) -> GroupPB {
+        let groups = self.editor.load_all_groups().await.unwrap().items;
+        groups.into_iter().find(|group| group.group_id == group_id).unwrap()
+    }
+
     pub async fn group_at_index(&self, index: usize) -> GroupPB {
         let groups = self.editor.load_groups().await.unwrap().items;
         groups.get(index).unwrap().clone()
@@ -237,7 +459,6 @@ impl DatabaseGroupTest {
         groups.rows.get(row_index).unwrap().clone()
     }
 
-    #[allow(dead_code)]
     pub async fn get_multi_select_field(&self) -> Arc<FieldRevision> {
         let field = self
             .inner
@@ -271,6 +492,66 @@ impl DatabaseGroupTest {
             .unwrap();
     }
 
+    pub async fn get_rating_field(&self) -> Arc<FieldRevision> {
+        self.inner
+            .field_revs
+            .iter()
+            .find(|field_rev| {
+                let field_type: FieldType = field_rev.ty.into();
+                field_type.is_rating()
+            })
+            .unwrap()
+            .clone()
+    }
+
+    pub async fn get_person_field(&self) -> Arc<FieldRevision> {
+        self.inner
+            .field_revs
+            .iter()
+            .find(|field_rev| {
+                let field_type: FieldType = field_rev.ty.into();
+                field_type.is_person()
+            })
+            .unwrap()
+            .clone()
+    }
+
+    pub async fn get_number_field(&self) -> Arc<FieldRevision> {
+        self.inner
+            .field_revs
+            .iter()
+            .find(|field_rev| {
+                let field_type: FieldType = field_rev.ty.into();
+                field_type.is_number()
+            })
+            .unwrap()
+            .clone()
+    }
+
+    pub async fn get_date_field(&self) -> Arc<FieldRevision> {
+        self.inner
+            .field_revs
+            .iter()
+            .find(|field_rev| {
+                let field_type: FieldType = field_rev.ty.into();
+                field_type.is_date()
+            })
+            .unwrap()
+            .clone()
+    }
+
+    pub async fn get_created_time_field(&self) -> Arc<FieldRevision> {
+        self.inner
+            .field_revs
+            .iter()
+            .find(|field_rev| {
+                let field_type: FieldType = field_rev.ty.into();
+                field_type == FieldType::CreatedTime
+            })
+            .unwrap()
+            .clone()
+    }
+
     pub async fn get_url_field(&self) -> Arc<FieldRevision> {
         self.inner
             .field_revs
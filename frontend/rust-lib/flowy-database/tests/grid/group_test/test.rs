@@ -221,6 +221,33 @@ async fn group_create_row_test() {
     test.run_scripts(scripts).await;
 }
 
+#[tokio::test]
+async fn group_create_row_with_default_option_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let single_select_field = test.get_single_select_field().await;
+    let group = test.group_at_index(2).await;
+    test.editor
+        .set_field_default_value(&single_select_field.id, Some(group.group_id.clone()))
+        .await
+        .unwrap();
+    let scripts = vec![
+        CreateUngroupedRow,
+        AssertGroupRowCount {
+            group_index: 2,
+            row_count: 3,
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 2,
+        },
+        AssertGroupRowCount {
+            group_index: 0,
+            row_count: 0,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
 #[tokio::test]
 async fn group_delete_row_test() {
     let mut test = DatabaseGroupTest::new().await;
@@ -237,6 +264,48 @@ async fn group_delete_row_test() {
     test.run_scripts(scripts).await;
 }
 
+#[tokio::test]
+async fn group_undo_delete_row_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let row = test.row_at_index(1, 0).await;
+    test.run_scripts(vec![
+        DeleteRow {
+            group_index: 1,
+            row_index: 0,
+        },
+        Undo,
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 2,
+        },
+    ])
+    .await;
+    let group = test.group_at_index(1).await;
+    assert!(group.rows.iter().any(|group_row| group_row.id == row.id));
+}
+
+#[tokio::test]
+async fn group_restore_row_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let row = test.row_at_index(1, 0).await;
+    test.run_scripts(vec![DeleteRow {
+        group_index: 1,
+        row_index: 0,
+    }])
+    .await;
+
+    // The restored row is put back into the group of its cell
+    let restored_row = test.editor.restore_row(&row.id).await.unwrap();
+    assert_eq!(restored_row.id, row.id);
+    test.run_scripts(vec![AssertGroupRowCount {
+        group_index: 1,
+        row_count: 2,
+    }])
+    .await;
+    let group = test.group_at_index(1).await;
+    assert!(group.rows.iter().any(|group_row| group_row.id == row.id));
+}
+
 #[tokio::test]
 async fn group_delete_all_row_test() {
     let mut test = DatabaseGroupTest::new().await;
@@ -486,3 +555,44 @@ async fn group_group_by_other_field() {
     ];
     test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn group_clear_grouped_field_cells_test() {
+    let mut test = DatabaseGroupTest::new().await;
+    let field_id = test.get_single_select_field().await.id.clone();
+    let scripts = vec![
+        ClearFieldCells { field_id },
+        // All the rows fall into the no status group.
+        AssertGroupCount(4),
+        AssertGroupRowCount {
+            group_index: 0,
+            row_count: 5,
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 0,
+        },
+        AssertGroupRowCount {
+            group_index: 2,
+            row_count: 0,
+        },
+        AssertGroupRowCount {
+            group_index: 3,
+            row_count: 0,
+        },
+        Undo,
+        AssertGroupRowCount {
+            group_index: 0,
+            row_count: 0,
+        },
+        AssertGroupRowCount {
+            group_index: 1,
+            row_count: 2,
+        },
+        AssertGroupRowCount {
+            group_index: 3,
+            row_count: 1,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
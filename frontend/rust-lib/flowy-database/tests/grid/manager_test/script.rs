@@ -0,0 +1,961 @@
+use crate::grid::database_editor::DatabaseEditorTest;
+use crate::grid::mock_data::make_test_grid;
+use bytes::Bytes;
+use diesel::RunQueryDsl;
+use flowy_database::entities::{CreateRowParams, DatabaseViewLayout};
+use flowy_database::manager::{
+    make_database_view_data, DatabaseManager, DatabaseManagerConfig, DatabaseUser, RevisionCompressConfig,
+};
+use flowy_database::services::backup::DatabaseBackup;
+use flowy_database::services::field::{RelatedRowResolver, SingleSelectTypeOptionPB};
+use flowy_database::services::grid_editor::DatabaseRevisionEditor;
+use flowy_database::services::health::DatabaseHealthIssue;
+use flowy_database::services::persistence::rev_memory::MemoryGridRevisionStoreFactory;
+use flowy_database::services::persistence::rev_sqlite::{
+    RevisionCipher, RevisionIntegrityIssue, SQLiteDatabaseBlockRevisionPersistence, SQLiteDatabaseRevisionPersistence,
+    SQLiteDatabaseRevisionSnapshotPersistence, SQLiteGridViewRevisionPersistence,
+};
+use flowy_database::services::persistence::rev_store::{
+    GridRevisionObject, GridRevisionStoreFactory, GridRevisionStoreOptions,
+};
+use flowy_database::services::persistence::DatabaseStorage;
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_revision::{
+    RevisionSnapshotDiskCache, RevisionWebSocket, WSStateReceiver, REVISION_WRITE_INTERVAL_IN_MILLIS,
+};
+use flowy_revision_persistence::RevisionDiskCache;
+use flowy_sqlite::ConnectionPool;
+use futures::future::BoxFuture;
+use grid_model::{gen_grid_id, RowMetaChangeset};
+use lib_infra::future::BoxResultFuture;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use ws_model::ws_revision::ClientRevisionWSData;
+
+pub enum DatabaseManagerScript {
+    DeleteDatabase,
+    AssertCanOpenDatabase(bool),
+    DuplicateDatabase {
+        new_database_id: String,
+    },
+    /// Asserts the duplicated database has the same fields and the same number of rows as the
+    /// original database, but the ids of the rows are different.
+    AssertDuplicatedDatabase {
+        database_id: String,
+    },
+    SetFrozenFieldCount {
+        count: usize,
+    },
+    SetFieldLocked {
+        field_id: String,
+        locked: bool,
+    },
+    AssertFrozenFieldCount {
+        database_id: String,
+        expected: i32,
+    },
+    SaveFieldTemplate {
+        name: String,
+        field_id: String,
+    },
+    DeleteFieldTemplate {
+        name: String,
+    },
+    AssertFieldTemplateNames(Vec<String>),
+    /// Exports the field templates to JSON, deletes them and imports them from the JSON.
+    ExportAndImportFieldTemplates,
+    CreateFieldFromTemplate {
+        database_id: String,
+        template_name: String,
+        is_ok: bool,
+    },
+    /// Asserts the last field of the database has the same name, field type and select options
+    /// as the source field, but the ids of the select options are different.
+    AssertLastFieldFromTemplate {
+        database_id: String,
+        source_field_id: String,
+    },
+    CloseAllDatabases {
+        expected_database_ids: Vec<String>,
+    },
+    AssertNumberOfRows {
+        expected: usize,
+    },
+    AssertOpenedDatabases {
+        expected_database_ids: Vec<String>,
+    },
+    CloseIdleDatabases {
+        idle_ttl: Duration,
+        expected_database_ids: Vec<String>,
+    },
+    RenameDatabase {
+        name: String,
+        is_err: bool,
+    },
+    AssertDatabaseName(String),
+    DeleteField {
+        field_id: String,
+    },
+    /// Asserts the issues reported by `check_database`. Only the variants of the issues are compared.
+    CheckDatabase {
+        expected_issues: Vec<DatabaseHealthIssue>,
+    },
+    RepairDatabase {
+        is_healthy: bool,
+    },
+    ArchiveDatabase,
+    UnarchiveDatabase,
+    AssertIsArchived(bool),
+    OpenDatabase {
+        allow_archived: bool,
+        is_ok: bool,
+    },
+    /// Creates the databases with the test grid's build context. The build context of the
+    /// database whose `is_ok` is false will be corrupted, so it fails to be created.
+    CreateDatabasesBatch {
+        databases: Vec<(String, bool)>,
+    },
+    AssertNumberOfRowsInDatabase {
+        database_id: String,
+        expected: usize,
+    },
+    /// Reads the candidate rows of the relation that links to the database.
+    AssertNumberOfCandidateRows {
+        database_id: String,
+        expected: usize,
+    },
+    CloseDatabase {
+        expected_ref_count: usize,
+    },
+    /// Asserts the reference count of the database's editor. 0 means the editor is not opened.
+    AssertEditorRefCount(usize),
+    /// Sets the config and reopens the database, so the config takes effect.
+    SetRevisionCompressConfig(RevisionCompressConfig),
+    UpdateTextCell {
+        count: usize,
+    },
+    UpdateRowMeta {
+        row_index: usize,
+        changeset: RowMetaChangeset,
+    },
+    /// Asserts the number of the block revisions that were written to disk since the
+    /// `SetRevisionCompressConfig` script.
+    AssertNumberOfNewBlockRevisions(usize),
+    AssertNumberOfBlockSnapshots(usize),
+    /// Acks all the revisions of the database as if they were synced to the server.
+    AckAllDatabaseRevisions,
+    WriteDatabaseSnapshot,
+    CompactDatabaseHistory {
+        keep_last: usize,
+        is_reclaimed: bool,
+    },
+    CompactAllDatabases {
+        keep_last: usize,
+        is_reclaimed: bool,
+    },
+    AssertNumberOfDatabaseRevisions(usize),
+    AssertIsRepairing(bool),
+    /// Waits until the revisions of the database, which was opened from its latest snapshot, are
+    /// replayed in the background.
+    WaitForRepairFinished,
+    /// Exports the database to JSON and imports it as a new database.
+    ExportAndImportDatabase {
+        new_database_id: String,
+    },
+    /// Asserts the fields and the rows of the database are the same as the current database's,
+    /// except the ids of the rows.
+    AssertDatabaseDataEqual {
+        database_id: String,
+    },
+    /// Overwrites the payload of the database's latest revision with invalid bytes.
+    CorruptLatestDatabaseRevision,
+    /// Overwrites the payload of the database's latest snapshot with invalid bytes.
+    CorruptLatestDatabaseSnapshot,
+    AssertDatabaseRevisionsCorrupted(bool),
+    /// Closes all the databases and re-encrypts the revisions and the snapshots of the database.
+    ReencryptDatabase {
+        old: Option<Arc<dyn RevisionCipher>>,
+        new: Option<Arc<dyn RevisionCipher>>,
+    },
+    /// Asserts the revisions of the database can't be read without a cipher.
+    AssertDatabaseEncrypted(bool),
+    /// Asserts the revisions of the database's blocks and its view can't be read without a cipher.
+    AssertBlocksAndViewEncrypted(bool),
+    /// Asserts the revisions of the database that are read with the cipher are valid.
+    AssertDatabaseReadable {
+        cipher: Option<Arc<dyn RevisionCipher>>,
+        is_readable: bool,
+    },
+    AssertOpenEncryptedDatabaseFailed,
+    /// Creates the database while the table of the view revisions is missing, so the creation
+    /// fails after the blocks and the database are written. Asserts none of them is left on disk.
+    CreateDatabaseFailedAfterBlocks {
+        database_id: String,
+    },
+    /// Asserts the stats returned by `revision_stats` match the revisions that are read from disk.
+    AssertRevisionStats,
+    /// Asserts the storage report contains the databases, and the opened ones list their blocks.
+    AssertStorageReport {
+        database_ids: Vec<String>,
+        opened_database_ids: Vec<String>,
+    },
+    /// Copies the revisions of the database to an in-memory store, and asserts the revisions and
+    /// the snapshot are read back after the store is made again.
+    AssertMemoryRevisionStore,
+    /// Runs a [DatabaseManager] whose revisions are stored in memory. Creates, edits, reopens,
+    /// exports and deletes a database, and asserts none of its revisions is written to sqlite.
+    AssertMemoryDatabaseManager,
+}
+
+/// Encrypts the data by XOR-ing each byte with the key.
+pub struct XorRevisionCipher(pub u8);
+
+impl RevisionCipher for XorRevisionCipher {
+    fn encrypt(&self, data: Bytes) -> FlowyResult<Bytes> {
+        Ok(data.iter().map(|byte| byte ^ self.0).collect::<Vec<u8>>().into())
+    }
+
+    fn decrypt(&self, data: Bytes) -> FlowyResult<Bytes> {
+        self.encrypt(data)
+    }
+}
+
+/// Provides the user and the sqlite of the test sdk to the [DatabaseManager] that is made by the
+/// test.
+struct TestDatabaseUser {
+    user_id: String,
+    token: String,
+    pool: Arc<ConnectionPool>,
+}
+
+impl DatabaseUser for TestDatabaseUser {
+    fn user_id(&self) -> Result<String, FlowyError> {
+        Ok(self.user_id.clone())
+    }
+
+    fn token(&self) -> Result<String, FlowyError> {
+        Ok(self.token.clone())
+    }
+
+    fn db_pool(&self) -> Result<Arc<ConnectionPool>, FlowyError> {
+        Ok(self.pool.clone())
+    }
+}
+
+struct TestRevisionWebSocket();
+
+impl RevisionWebSocket for TestRevisionWebSocket {
+    fn send(&self, _data: ClientRevisionWSData) -> BoxResultFuture<(), FlowyError> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn subscribe_state_changed(&self) -> BoxFuture<WSStateReceiver> {
+        let (_, rx) = tokio::sync::broadcast::channel(1);
+        Box::pin(async { rx })
+    }
+}
+
+pub struct DatabaseManagerTest {
+    inner: DatabaseEditorTest,
+    number_of_block_revisions: usize,
+}
+
+impl DatabaseManagerTest {
+    pub async fn new() -> Self {
+        let editor_test = DatabaseEditorTest::new_table().await;
+        Self {
+            inner: editor_test,
+            number_of_block_revisions: 0,
+        }
+    }
+
+    fn number_of_block_revisions(&self) -> usize {
+        let user_id = self.sdk.user_session.user_id().unwrap();
+        let pool = self.sdk.user_session.db_pool().unwrap();
+        let disk_cache = SQLiteDatabaseBlockRevisionPersistence::new(&user_id, pool);
+        self.block_meta_revs
+            .iter()
+            .map(|block_meta_rev| {
+                disk_cache
+                    .read_revision_records(&block_meta_rev.block_id, None)
+                    .unwrap()
+                    .len()
+            })
+            .sum()
+    }
+
+    pub async fn run_scripts(&mut self, scripts: Vec<DatabaseManagerScript>) {
+        for script in scripts {
+            self.run_script(script).await;
+        }
+    }
+
+    pub async fn run_script(&mut self, script: DatabaseManagerScript) {
+        let database_manager = self.sdk.grid_manager.clone();
+        match script {
+            DatabaseManagerScript::DeleteDatabase => {
+                database_manager.delete_database(&self.view_id).await.unwrap();
+            }
+            DatabaseManagerScript::AssertCanOpenDatabase(expected) => {
+                let result = database_manager.open_database(&self.view_id).await;
+                assert_eq!(result.is_ok(), expected);
+            }
+            DatabaseManagerScript::DuplicateDatabase { new_database_id } => {
+                database_manager
+                    .duplicate_database(&self.view_id, &new_database_id)
+                    .await
+                    .unwrap();
+            }
+            DatabaseManagerScript::SetFrozenFieldCount { count } => {
+                let editor = database_manager.get_database_editor(&self.view_id).await.unwrap();
+                editor.set_frozen_field_count(&self.view_id, count).await.unwrap();
+            }
+            DatabaseManagerScript::SetFieldLocked { field_id, locked } => {
+                let editor = database_manager.get_database_editor(&self.view_id).await.unwrap();
+                editor.set_field_locked(&field_id, locked).await.unwrap();
+            }
+            DatabaseManagerScript::SaveFieldTemplate { name, field_id } => {
+                database_manager
+                    .save_field_template(&name, &field_id, &self.view_id)
+                    .await
+                    .unwrap();
+            }
+            DatabaseManagerScript::DeleteFieldTemplate { name } => {
+                database_manager.delete_field_template(&name).unwrap();
+            }
+            DatabaseManagerScript::AssertFieldTemplateNames(expected_names) => {
+                let names = database_manager
+                    .list_field_templates()
+                    .unwrap()
+                    .into_iter()
+                    .map(|template| template.name)
+                    .collect::<Vec<String>>();
+                assert_eq!(names, expected_names);
+            }
+            DatabaseManagerScript::ExportAndImportFieldTemplates => {
+                let templates = database_manager.list_field_templates().unwrap();
+                let json = database_manager.export_field_templates().unwrap();
+                for template in templates.iter() {
+                    database_manager.delete_field_template(&template.name).unwrap();
+                }
+                assert!(database_manager.list_field_templates().unwrap().is_empty());
+
+                database_manager.import_field_templates(&json).unwrap();
+                assert_eq!(database_manager.list_field_templates().unwrap(), templates);
+            }
+            DatabaseManagerScript::CreateFieldFromTemplate {
+                database_id,
+                template_name,
+                is_ok,
+            } => {
+                let editor = database_manager.get_database_editor(&database_id).await.unwrap();
+                let result = editor.create_field_from_template(&template_name).await;
+                assert_eq!(result.is_ok(), is_ok);
+            }
+            DatabaseManagerScript::AssertLastFieldFromTemplate {
+                database_id,
+                source_field_id,
+            } => {
+                let source_field_rev = self
+                    .field_revs
+                    .iter()
+                    .find(|field_rev| field_rev.id == source_field_id)
+                    .unwrap()
+                    .clone();
+                let editor = database_manager.get_database_editor(&database_id).await.unwrap();
+                let field_rev = editor.get_field_revs(None).await.unwrap().pop().unwrap();
+                assert_ne!(field_rev.id, source_field_rev.id);
+                assert_eq!(field_rev.name, source_field_rev.name);
+                assert_eq!(field_rev.ty, source_field_rev.ty);
+
+                let source_options = SingleSelectTypeOptionPB::from(&source_field_rev).options;
+                let options = SingleSelectTypeOptionPB::from(&field_rev).options;
+                assert!(!options.is_empty());
+                assert_eq!(options.len(), source_options.len());
+                for (option, source_option) in options.iter().zip(source_options.iter()) {
+                    assert_eq!(option.name, source_option.name);
+                    assert_eq!(option.color, source_option.color);
+                    assert_ne!(option.id, source_option.id);
+                }
+            }
+            DatabaseManagerScript::AssertFrozenFieldCount { database_id, expected } => {
+                let editor = database_manager.get_database_editor(&database_id).await.unwrap();
+                let setting = editor.get_setting().await.unwrap();
+                assert_eq!(setting.frozen_field_count, expected);
+            }
+            DatabaseManagerScript::AssertDuplicatedDatabase { database_id } => {
+                let editor = database_manager.get_database_editor(&database_id).await.unwrap();
+                let field_ids = editor
+                    .get_field_revs(None)
+                    .await
+                    .unwrap()
+                    .iter()
+                    .map(|field_rev| field_rev.id.clone())
+                    .collect::<Vec<String>>();
+                let expected_field_ids = self
+                    .field_revs
+                    .iter()
+                    .map(|field_rev| field_rev.id.clone())
+                    .collect::<Vec<String>>();
+                assert_eq!(field_ids, expected_field_ids);
+
+                let row_revs = editor.get_all_row_revs(&database_id).await.unwrap();
+                assert_eq!(row_revs.len(), self.row_revs.len());
+                let original_row_ids = self
+                    .row_revs
+                    .iter()
+                    .map(|row_rev| row_rev.id.clone())
+                    .collect::<HashSet<String>>();
+                assert!(row_revs.iter().all(|row_rev| !original_row_ids.contains(&row_rev.id)));
+            }
+            DatabaseManagerScript::CloseAllDatabases { expected_database_ids } => {
+                let mut database_ids = database_manager.close_all().await;
+                database_ids.sort();
+                assert_eq!(database_ids, expected_database_ids);
+            }
+            DatabaseManagerScript::AssertOpenedDatabases { expected_database_ids } => {
+                let databases = database_manager.opened_databases().await;
+                let mut database_ids = databases
+                    .iter()
+                    .map(|database| database.id.clone())
+                    .collect::<Vec<String>>();
+                database_ids.sort();
+                assert_eq!(database_ids, expected_database_ids);
+                assert!(databases.iter().all(|database| database.ref_count > 0));
+            }
+            DatabaseManagerScript::CloseIdleDatabases {
+                idle_ttl,
+                expected_database_ids,
+            } => {
+                let mut database_ids = database_manager.close_idle_databases(idle_ttl).await;
+                database_ids.sort();
+                assert_eq!(database_ids, expected_database_ids);
+            }
+            DatabaseManagerScript::RenameDatabase { name, is_err } => {
+                let result = database_manager.rename_database(&self.view_id, &name).await;
+                assert_eq!(result.is_err(), is_err);
+            }
+            DatabaseManagerScript::AssertDatabaseName(expected) => {
+                let editor = database_manager.get_database_editor(&self.view_id).await.unwrap();
+                assert_eq!(editor.get_database_name().await, expected);
+            }
+            DatabaseManagerScript::DeleteField { field_id } => {
+                self.editor.delete_field(&field_id).await.unwrap();
+            }
+            DatabaseManagerScript::CheckDatabase { expected_issues } => {
+                let report = database_manager.check_database(&self.view_id).await.unwrap();
+                let mut issues = report.issues.iter().map(std::mem::discriminant).collect::<Vec<_>>();
+                issues.dedup();
+                let expected_issues = expected_issues.iter().map(std::mem::discriminant).collect::<Vec<_>>();
+                assert_eq!(issues, expected_issues);
+            }
+            DatabaseManagerScript::RepairDatabase { is_healthy } => {
+                let report = database_manager.repair_database(&self.view_id).await.unwrap();
+                assert_eq!(report.is_healthy(), is_healthy);
+            }
+            DatabaseManagerScript::ArchiveDatabase => {
+                database_manager.archive_database(&self.view_id).await.unwrap();
+            }
+            DatabaseManagerScript::UnarchiveDatabase => {
+                database_manager.unarchive_database(&self.view_id).await.unwrap();
+            }
+            DatabaseManagerScript::AssertIsArchived(expected) => {
+                assert_eq!(database_manager.is_archived(&self.view_id).unwrap(), expected);
+            }
+            DatabaseManagerScript::OpenDatabase { allow_archived, is_ok } => {
+                let result = database_manager
+                    .open_database_with_options(&self.view_id, allow_archived)
+                    .await;
+                assert_eq!(result.is_ok(), is_ok);
+            }
+            DatabaseManagerScript::CreateDatabasesBatch { databases } => {
+                let contexts = databases
+                    .iter()
+                    .map(|(database_id, is_ok)| {
+                        let mut build_context = make_test_grid();
+                        if !is_ok {
+                            build_context.grid_view_revision_data = "{".to_owned();
+                        }
+                        (database_id.clone(), DatabaseViewLayout::Grid, build_context)
+                    })
+                    .collect();
+                let results = database_manager.create_databases_batch(contexts).await;
+                assert_eq!(results.len(), databases.len());
+                for (result, (_, is_ok)) in results.iter().zip(databases.iter()) {
+                    assert_eq!(result.is_ok(), *is_ok);
+                }
+            }
+            DatabaseManagerScript::AssertNumberOfRowsInDatabase { database_id, expected } => {
+                let editor = database_manager.get_database_editor(&database_id).await.unwrap();
+                let row_revs = editor.get_all_row_revs(&database_id).await.unwrap();
+                assert_eq!(row_revs.len(), expected);
+            }
+            DatabaseManagerScript::AssertNumberOfCandidateRows { database_id, expected } => {
+                let rows = database_manager.get_candidate_rows(&database_id).await.unwrap();
+                assert_eq!(rows.len(), expected);
+            }
+            DatabaseManagerScript::CloseDatabase { expected_ref_count } => {
+                let ref_count = database_manager.close_database(&self.view_id).await.unwrap();
+                assert_eq!(ref_count, expected_ref_count);
+            }
+            DatabaseManagerScript::AssertEditorRefCount(expected) => {
+                let ref_counts = database_manager.editor_ref_counts().await;
+                assert_eq!(ref_counts.get(&self.view_id).cloned().unwrap_or(0), expected);
+            }
+            DatabaseManagerScript::SetRevisionCompressConfig(config) => {
+                database_manager.set_rev_compress_config(config);
+                let _ = database_manager.close_all().await;
+                self.editor = database_manager.open_database(&self.view_id).await.unwrap();
+                self.number_of_block_revisions = self.number_of_block_revisions();
+            }
+            DatabaseManagerScript::UpdateTextCell { count } => {
+                let row_id = self.row_revs[0].id.clone();
+                for i in 0..count {
+                    self.update_text_cell(row_id.clone(), &format!("text {}", i)).await;
+                }
+            }
+            DatabaseManagerScript::UpdateRowMeta { row_index, changeset } => {
+                let row_id = self.row_revs[row_index].id.clone();
+                self.editor.update_row_meta(&row_id, changeset).await.unwrap();
+            }
+            DatabaseManagerScript::AssertNumberOfNewBlockRevisions(expected) => {
+                // Closing the databases writes the pending revisions to disk.
+                let _ = database_manager.close_all().await;
+                let number_of_block_revisions = self.number_of_block_revisions();
+                assert_eq!(number_of_block_revisions - self.number_of_block_revisions, expected);
+                self.editor = database_manager.open_database(&self.view_id).await.unwrap();
+            }
+            DatabaseManagerScript::AssertNumberOfBlockSnapshots(expected) => {
+                // The snapshots are written in background
+                tokio::time::sleep(Duration::from_millis(2 * REVISION_WRITE_INTERVAL_IN_MILLIS)).await;
+                let pool = self.sdk.user_session.db_pool().unwrap();
+                let snapshot_object_id = format!("grid_block:{}", self.block_id());
+                let snapshots = SQLiteDatabaseRevisionSnapshotPersistence::new(&snapshot_object_id, pool)
+                    .read_snapshots(usize::MAX)
+                    .unwrap();
+                assert_eq!(snapshots.len(), expected);
+            }
+            DatabaseManagerScript::AckAllDatabaseRevisions => {
+                let rev_manager = self.editor.rev_manager();
+                while let Some(rev_id) = rev_manager.next_sync_rev_id().await {
+                    rev_manager.ack_revision(rev_id).await.unwrap();
+                }
+            }
+            DatabaseManagerScript::WriteDatabaseSnapshot => {
+                self.editor.generate_snapshot().await;
+            }
+            DatabaseManagerScript::CompactDatabaseHistory {
+                keep_last,
+                is_reclaimed,
+            } => {
+                let reclaimed_size = self.editor.compact_history(keep_last).await.unwrap();
+                assert_eq!(reclaimed_size > 0, is_reclaimed);
+            }
+            DatabaseManagerScript::CompactAllDatabases {
+                keep_last,
+                is_reclaimed,
+            } => {
+                let reclaimed_size = database_manager.compact_all(keep_last).await.unwrap();
+                assert_eq!(reclaimed_size > 0, is_reclaimed);
+            }
+            DatabaseManagerScript::AssertNumberOfDatabaseRevisions(expected) => {
+                let user_id = self.sdk.user_session.user_id().unwrap();
+                let pool = self.sdk.user_session.db_pool().unwrap();
+                let records = SQLiteDatabaseRevisionPersistence::new(&user_id, pool)
+                    .read_revision_records(&self.view_id, None)
+                    .unwrap();
+                assert_eq!(records.len(), expected);
+            }
+            DatabaseManagerScript::AssertIsRepairing(expected) => {
+                assert_eq!(self.editor.is_repairing(), expected);
+            }
+            DatabaseManagerScript::WaitForRepairFinished => {
+                let mut retry = 0;
+                while self.editor.is_repairing() {
+                    assert!(retry < 50, "The database is still repairing");
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    retry += 1;
+                }
+            }
+            DatabaseManagerScript::ExportAndImportDatabase { new_database_id } => {
+                let backup = database_manager.export_database_raw(&self.view_id).await.unwrap();
+                let backup = DatabaseBackup::from_json(&backup.to_json().unwrap()).unwrap();
+                database_manager
+                    .import_database_raw(backup, &new_database_id)
+                    .await
+                    .unwrap();
+            }
+            DatabaseManagerScript::AssertDatabaseDataEqual { database_id } => {
+                let expected = database_json(&self.editor).await;
+                let editor = database_manager.get_database_editor(&database_id).await.unwrap();
+                assert_eq!(database_json(&editor).await, expected);
+            }
+            DatabaseManagerScript::CorruptLatestDatabaseRevision => {
+                let sql = format!(
+                    "UPDATE grid_rev_table SET data = X'00' WHERE object_id = '{0}' AND rev_id = \
+                    (SELECT MAX(rev_id) FROM grid_rev_table WHERE object_id = '{0}')",
+                    self.view_id
+                );
+                let conn = self.sdk.user_session.db_pool().unwrap().get().unwrap();
+                assert_eq!(diesel::sql_query(sql).execute(&*conn).unwrap(), 1);
+            }
+            DatabaseManagerScript::CorruptLatestDatabaseSnapshot => {
+                let sql = format!(
+                    "UPDATE grid_rev_snapshot SET data = X'00' WHERE snapshot_id = \
+                    (SELECT snapshot_id FROM grid_rev_snapshot WHERE object_id = 'grid:{}' \
+                    ORDER BY timestamp DESC, rev_id DESC LIMIT 1)",
+                    self.view_id
+                );
+                let conn = self.sdk.user_session.db_pool().unwrap().get().unwrap();
+                assert_eq!(diesel::sql_query(sql).execute(&*conn).unwrap(), 1);
+            }
+            DatabaseManagerScript::AssertDatabaseRevisionsCorrupted(expected) => {
+                let user_id = self.sdk.user_session.user_id().unwrap();
+                let pool = self.sdk.user_session.db_pool().unwrap();
+                let report = SQLiteDatabaseRevisionPersistence::new(&user_id, pool)
+                    .verify_revisions(&self.view_id)
+                    .unwrap();
+                assert_eq!(report.is_corrupted(), expected);
+                if expected {
+                    assert!(report
+                        .issues
+                        .iter()
+                        .any(|issue| matches!(issue, RevisionIntegrityIssue::CorruptedPayload { .. })));
+                }
+            }
+            DatabaseManagerScript::ReencryptDatabase { old, new } => {
+                let _ = database_manager.close_all().await;
+                database_manager
+                    .reencrypt_database(&self.view_id, old, new)
+                    .await
+                    .unwrap();
+            }
+            DatabaseManagerScript::AssertDatabaseEncrypted(expected) => {
+                let user_id = self.sdk.user_session.user_id().unwrap();
+                let pool = self.sdk.user_session.db_pool().unwrap();
+                let result =
+                    SQLiteDatabaseRevisionPersistence::new(&user_id, pool).read_revision_records(&self.view_id, None);
+                match result {
+                    Ok(records) => {
+                        assert!(!expected);
+                        assert!(!records.is_empty());
+                    }
+                    Err(e) => {
+                        assert!(expected);
+                        assert!(e.is_database_encrypted());
+                    }
+                }
+            }
+            DatabaseManagerScript::AssertBlocksAndViewEncrypted(expected) => {
+                let user_id = self.sdk.user_session.user_id().unwrap();
+                let pool = self.sdk.user_session.db_pool().unwrap();
+                let block_disk_cache = SQLiteDatabaseBlockRevisionPersistence::new(&user_id, pool.clone());
+                let mut results = self
+                    .block_meta_revs
+                    .iter()
+                    .map(|block_meta_rev| block_disk_cache.read_revision_records(&block_meta_rev.block_id, None))
+                    .collect::<Vec<_>>();
+                results.push(
+                    SQLiteGridViewRevisionPersistence::new(&user_id, pool).read_revision_records(&self.view_id, None),
+                );
+                for result in results {
+                    match result {
+                        Ok(records) => {
+                            assert!(!expected);
+                            assert!(!records.is_empty());
+                        }
+                        Err(e) => {
+                            assert!(expected);
+                            assert!(e.is_database_encrypted());
+                        }
+                    }
+                }
+            }
+            DatabaseManagerScript::AssertDatabaseReadable { cipher, is_readable } => {
+                let user_id = self.sdk.user_session.user_id().unwrap();
+                let pool = self.sdk.user_session.db_pool().unwrap();
+                let report = SQLiteDatabaseRevisionPersistence::new(&user_id, pool)
+                    .with_cipher(cipher)
+                    .verify_revisions(&self.view_id)
+                    .unwrap();
+                assert_eq!(!report.is_corrupted(), is_readable);
+            }
+            DatabaseManagerScript::AssertOpenEncryptedDatabaseFailed => {
+                match database_manager.open_database(&self.view_id).await {
+                    Ok(_) => panic!("Opening the encrypted database without the cipher should fail"),
+                    Err(e) => assert!(e.is_database_encrypted()),
+                }
+            }
+            DatabaseManagerScript::CreateDatabaseFailedAfterBlocks { database_id } => {
+                let build_context = make_test_grid();
+                let block_ids = build_context
+                    .block_metas
+                    .iter()
+                    .map(|block_meta| block_meta.block_id.clone())
+                    .collect::<Vec<String>>();
+                assert!(!block_ids.is_empty());
+
+                let pool = self.sdk.user_session.db_pool().unwrap();
+                let rename_table = |from: &str, to: &str| {
+                    let conn = pool.get().unwrap();
+                    let sql = format!("ALTER TABLE {} RENAME TO {}", from, to);
+                    diesel::sql_query(sql).execute(&*conn).unwrap();
+                };
+                rename_table("grid_view_rev_table", "grid_view_rev_table_backup");
+                let result = make_database_view_data(
+                    "",
+                    &database_id,
+                    DatabaseViewLayout::Grid,
+                    database_manager.clone(),
+                    build_context,
+                )
+                .await;
+                rename_table("grid_view_rev_table_backup", "grid_view_rev_table");
+                assert!(result.is_err());
+
+                let user_id = self.sdk.user_session.user_id().unwrap();
+                let block_disk_cache = SQLiteDatabaseBlockRevisionPersistence::new(&user_id, pool.clone());
+                for block_id in block_ids.iter() {
+                    let records = block_disk_cache.read_revision_records(block_id, None).unwrap();
+                    assert!(records.is_empty());
+                }
+                let database_ids = SQLiteDatabaseRevisionPersistence::new(&user_id, pool)
+                    .read_object_ids()
+                    .unwrap();
+                assert!(!database_ids.contains(&database_id));
+                assert!(database_manager.open_database(&database_id).await.is_err());
+            }
+            DatabaseManagerScript::AssertRevisionStats => {
+                let editor = database_manager.get_database_editor(&self.view_id).await.unwrap();
+                let stats = editor.revision_stats().await.unwrap();
+                let user_id = self.sdk.user_session.user_id().unwrap();
+                let pool = self.sdk.user_session.db_pool().unwrap();
+                let records = SQLiteDatabaseRevisionPersistence::new(&user_id, pool.clone())
+                    .read_revision_records(&self.view_id, None)
+                    .unwrap();
+                assert_eq!(stats.number_of_revisions, records.len());
+                let payload_bytes: usize = records.iter().map(|record| record.revision.bytes.len()).sum();
+                assert_eq!(stats.payload_bytes, payload_bytes);
+
+                let snapshot_object_id = format!("grid:{}", self.view_id);
+                let snapshots = SQLiteDatabaseRevisionSnapshotPersistence::new(&snapshot_object_id, pool.clone())
+                    .read_snapshots(usize::MAX)
+                    .unwrap();
+                assert_eq!(stats.number_of_snapshots, snapshots.len());
+                assert_eq!(
+                    stats.last_snapshot_at,
+                    snapshots.iter().map(|snapshot| snapshot.timestamp).max()
+                );
+
+                let block_disk_cache = SQLiteDatabaseBlockRevisionPersistence::new(&user_id, pool);
+                assert_eq!(stats.block_revisions.len(), self.block_meta_revs.len());
+                let mut block_payload_bytes = 0;
+                for block_meta_rev in self.block_meta_revs.iter() {
+                    let records = block_disk_cache
+                        .read_revision_records(&block_meta_rev.block_id, None)
+                        .unwrap();
+                    assert_eq!(
+                        stats.block_revisions.get(&block_meta_rev.block_id),
+                        Some(&records.len())
+                    );
+                    block_payload_bytes += records.iter().map(|record| record.revision.bytes.len()).sum::<usize>();
+                }
+                assert_eq!(stats.block_payload_bytes, block_payload_bytes);
+            }
+            DatabaseManagerScript::AssertStorageReport {
+                database_ids,
+                opened_database_ids,
+            } => {
+                let report = database_manager.storage_report().await.unwrap();
+                for database_id in database_ids.iter() {
+                    let stats = report
+                        .databases
+                        .iter()
+                        .find(|stats| &stats.database_id == database_id)
+                        .unwrap();
+                    assert!(stats.number_of_revisions > 0);
+                    assert_eq!(
+                        stats.block_revisions.is_empty(),
+                        !opened_database_ids.contains(database_id)
+                    );
+                }
+
+                let number_of_database_revisions: usize =
+                    report.databases.iter().map(|stats| stats.number_of_revisions).sum();
+                let number_of_snapshots: usize = report.databases.iter().map(|stats| stats.number_of_snapshots).sum();
+                assert!(report.number_of_revisions > number_of_database_revisions);
+                assert_eq!(report.number_of_snapshots, number_of_snapshots);
+            }
+            DatabaseManagerScript::AssertMemoryRevisionStore => {
+                let user_id = self.sdk.user_session.user_id().unwrap();
+                let pool = self.sdk.user_session.db_pool().unwrap();
+                let records = SQLiteDatabaseRevisionPersistence::new(&user_id, pool)
+                    .read_revision_records(&self.view_id, None)
+                    .unwrap();
+                let latest_rev_id = records.last().unwrap().revision.rev_id;
+                let factory = MemoryGridRevisionStoreFactory::new();
+                let store = GridRevisionStoreFactory::<Arc<ConnectionPool>>::make_store(
+                    &factory,
+                    &user_id,
+                    GridRevisionObject::Database,
+                    &self.view_id,
+                    GridRevisionStoreOptions::default(),
+                )
+                .unwrap();
+                store
+                    .revision_disk_cache()
+                    .create_revision_records(records.clone())
+                    .unwrap();
+                store
+                    .snapshot_disk_cache()
+                    .write_snapshot(latest_rev_id, vec![1, 2, 3])
+                    .unwrap();
+
+                let store = GridRevisionStoreFactory::<Arc<ConnectionPool>>::make_store(
+                    &factory,
+                    &user_id,
+                    GridRevisionObject::Database,
+                    &self.view_id,
+                    GridRevisionStoreOptions::default(),
+                )
+                .unwrap();
+                let read_records = store
+                    .revision_disk_cache()
+                    .read_revision_records(&self.view_id, None)
+                    .unwrap();
+                assert_eq!(read_records.len(), records.len());
+                for (read_record, record) in read_records.iter().zip(records.iter()) {
+                    assert_eq!(read_record.revision, record.revision);
+                }
+                let snapshot = store.snapshot_disk_cache().read_last_snapshot().unwrap().unwrap();
+                assert_eq!(snapshot.rev_id, latest_rev_id);
+                assert_eq!(snapshot.data.to_vec(), vec![1, 2, 3]);
+            }
+            DatabaseManagerScript::AssertMemoryDatabaseManager => {
+                let user_id = self.sdk.user_session.user_id().unwrap();
+                let pool = self.sdk.user_session.db_pool().unwrap();
+                let user = Arc::new(TestDatabaseUser {
+                    user_id: user_id.clone(),
+                    token: self.sdk.user_session.token().unwrap(),
+                    pool: pool.clone(),
+                });
+                let memory_manager = Arc::new(DatabaseManager::new(
+                    user,
+                    Arc::new(TestRevisionWebSocket()),
+                    self.sdk.task_dispatcher.clone(),
+                    DatabaseStorage::memory(),
+                    DatabaseManagerConfig::default(),
+                    None,
+                ));
+
+                let database_id = gen_grid_id();
+                let build_context = make_test_grid();
+                let number_of_rows: usize = build_context.blocks.iter().map(|block| block.rows.len()).sum();
+                make_database_view_data(
+                    "",
+                    &database_id,
+                    DatabaseViewLayout::Grid,
+                    memory_manager.clone(),
+                    build_context,
+                )
+                .await
+                .unwrap();
+
+                let editor = memory_manager.open_database(&database_id).await.unwrap();
+                let params = CreateRowParams {
+                    database_id: database_id.clone(),
+                    start_row_id: None,
+                    group_id: None,
+                    layout: DatabaseViewLayout::Grid,
+                };
+                editor.create_row(params).await.unwrap();
+                let block_ids = editor
+                    .get_block_meta_revs()
+                    .await
+                    .unwrap()
+                    .iter()
+                    .map(|block_meta_rev| block_meta_rev.block_id.clone())
+                    .collect::<Vec<String>>();
+                memory_manager.close_database(&database_id).await.unwrap();
+
+                // The database is read back from the memory store after it's reopened.
+                let editor = memory_manager.open_database(&database_id).await.unwrap();
+                let row_revs = editor.get_all_row_revs(&database_id).await.unwrap();
+                assert_eq!(row_revs.len(), number_of_rows + 1);
+                memory_manager.close_database(&database_id).await.unwrap();
+
+                let records = SQLiteDatabaseRevisionPersistence::new(&user_id, pool.clone())
+                    .read_revision_records(&database_id, None)
+                    .unwrap();
+                assert!(records.is_empty());
+                let block_disk_cache = SQLiteDatabaseBlockRevisionPersistence::new(&user_id, pool);
+                for block_id in block_ids.iter() {
+                    let records = block_disk_cache.read_revision_records(block_id, None).unwrap();
+                    assert!(records.is_empty());
+                }
+
+                let report = memory_manager.storage_report().await.unwrap();
+                let stats = report
+                    .databases
+                    .iter()
+                    .find(|stats| stats.database_id == database_id)
+                    .unwrap();
+                assert!(stats.number_of_revisions > 0);
+
+                let backup = memory_manager.export_database_raw(&database_id).await.unwrap();
+                assert!(!backup.database_revisions.is_empty());
+                assert!(!backup.view_revisions.is_empty());
+                assert_eq!(backup.block_revisions.len(), block_ids.len());
+
+                // The archived flag is kept in memory too.
+                memory_manager.archive_database(&database_id).await.unwrap();
+                assert!(memory_manager.is_archived(&database_id).unwrap());
+                assert!(!database_manager.is_archived(&database_id).unwrap());
+
+                memory_manager.delete_database(&database_id).await.unwrap();
+                assert!(memory_manager.open_database(&database_id).await.is_err());
+            }
+            DatabaseManagerScript::AssertNumberOfRows { expected } => {
+                let editor = database_manager.get_database_editor(&self.view_id).await.unwrap();
+                let row_revs = editor.get_all_row_revs(&self.view_id).await.unwrap();
+                assert_eq!(row_revs.len(), expected);
+            }
+        }
+    }
+}
+
+/// Returns the JSON of the database's fields and rows. The ids of the rows and blocks are removed,
+/// because they are regenerated when duplicating or importing the database.
+async fn database_json(editor: &DatabaseRevisionEditor) -> serde_json::Value {
+    let grid_pad = editor.grid_pad();
+    let fields = serde_json::to_value(grid_pad.read().await.get_fields()).unwrap();
+    let rows = editor
+        .get_all_row_revs(&editor.database_id)
+        .await
+        .unwrap()
+        .iter()
+        .map(|row_rev| {
+            let mut row = serde_json::to_value(row_rev.as_ref()).unwrap();
+            let row = row.as_object_mut().unwrap();
+            row.remove("id");
+            row.remove("block_id");
+            serde_json::Value::Object(row.clone())
+        })
+        .collect::<Vec<_>>();
+    serde_json::json!({ "fields": fields, "rows": rows })
+}
+
+impl std::ops::Deref for DatabaseManagerTest {
+    type Target = DatabaseEditorTest;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl std::ops::DerefMut for DatabaseManagerTest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
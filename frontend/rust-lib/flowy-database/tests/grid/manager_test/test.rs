@@ -0,0 +1,971 @@
+use crate::grid::manager_test::script::DatabaseManagerScript::*;
+use crate::grid::manager_test::script::{DatabaseManagerScript, DatabaseManagerTest, XorRevisionCipher};
+use flowy_database::entities::FieldType;
+use flowy_database::manager::RevisionCompressConfig;
+use flowy_database::services::field::{RichTextTypeOptionBuilder, TypeOptionBuilder};
+use flowy_database::services::health::DatabaseHealthIssue;
+use flowy_database::services::persistence::rev_sqlite::RevisionCipher;
+use flowy_database::services::snapshot::DatabaseSnapshotPolicy;
+use grid_model::{gen_grid_id, RowMetaChangeset, TypeOptionDataSerializer};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[tokio::test]
+async fn database_delete_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let scripts = vec![
+        AssertCanOpenDatabase(true),
+        DeleteDatabase,
+        AssertCanOpenDatabase(false),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_delete_twice_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let scripts = vec![DeleteDatabase, DeleteDatabase, AssertCanOpenDatabase(false)];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_duplicate_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let new_database_id = gen_grid_id();
+    let scripts = vec![
+        DuplicateDatabase {
+            new_database_id: new_database_id.clone(),
+        },
+        AssertDuplicatedDatabase {
+            database_id: new_database_id,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_duplicate_frozen_field_count_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let new_database_id = gen_grid_id();
+    let scripts = vec![
+        SetFrozenFieldCount { count: 2 },
+        DuplicateDatabase {
+            new_database_id: new_database_id.clone(),
+        },
+        AssertFrozenFieldCount {
+            database_id: new_database_id,
+            expected: 2,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_resolve_related_rows_without_opening_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let new_database_id = gen_grid_id();
+    let expected = test.row_revs.len();
+    let scripts = vec![
+        DuplicateDatabase {
+            new_database_id: new_database_id.clone(),
+        },
+        AssertNumberOfCandidateRows {
+            database_id: new_database_id,
+            expected,
+        },
+        // The linked database is closed after its rows are read.
+        AssertOpenedDatabases {
+            expected_database_ids: vec![test.view_id.clone()],
+        },
+        // The opened database is borrowed, so its reference count is kept.
+        AssertNumberOfCandidateRows {
+            database_id: test.view_id.clone(),
+            expected,
+        },
+        AssertEditorRefCount(1),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_close_all_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let expected = test.row_revs.len();
+    let scripts = vec![
+        CloseAllDatabases {
+            expected_database_ids: vec![test.view_id.clone()],
+        },
+        CloseAllDatabases {
+            expected_database_ids: vec![],
+        },
+        AssertNumberOfRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_opened_databases_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let view_id = test.view_id.clone();
+    let scripts = vec![
+        AssertOpenedDatabases {
+            expected_database_ids: vec![view_id.clone()],
+        },
+        CloseAllDatabases {
+            expected_database_ids: vec![view_id],
+        },
+        AssertOpenedDatabases {
+            expected_database_ids: vec![],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_close_idle_databases_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let new_database_id = gen_grid_id();
+    let scripts = vec![
+        DuplicateDatabase {
+            new_database_id: new_database_id.clone(),
+        },
+        AssertDuplicatedDatabase {
+            database_id: new_database_id.clone(),
+        },
+        CloseIdleDatabases {
+            idle_ttl: Duration::from_secs(60),
+            expected_database_ids: vec![],
+        },
+        // The database held by the test won't be closed.
+        CloseIdleDatabases {
+            idle_ttl: Duration::ZERO,
+            expected_database_ids: vec![new_database_id.clone()],
+        },
+        // Reopen the closed database
+        AssertDuplicatedDatabase {
+            database_id: new_database_id,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_close_idle_databases_opened_by_caller_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let new_database_id = gen_grid_id();
+    test.run_scripts(vec![DuplicateDatabase {
+        new_database_id: new_database_id.clone(),
+    }])
+    .await;
+
+    // The database opened by `open_database` is kept until it's closed, even if the caller
+    // doesn't hold its editor.
+    let database_manager = test.sdk.grid_manager.clone();
+    drop(database_manager.open_database(&new_database_id).await.unwrap());
+    test.run_scripts(vec![CloseIdleDatabases {
+        idle_ttl: Duration::ZERO,
+        expected_database_ids: vec![],
+    }])
+    .await;
+    assert_eq!(database_manager.close_database(&new_database_id).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn database_rename_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let view_id = test.view_id.clone();
+    let scripts = vec![
+        RenameDatabase {
+            name: "Projects".to_owned(),
+            is_err: false,
+        },
+        AssertDatabaseName("Projects".to_owned()),
+        CloseAllDatabases {
+            expected_database_ids: vec![view_id],
+        },
+        AssertDatabaseName("Projects".to_owned()),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_rename_with_empty_name_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let scripts = vec![
+        RenameDatabase {
+            name: "".to_owned(),
+            is_err: true,
+        },
+        RenameDatabase {
+            name: "  ".to_owned(),
+            is_err: true,
+        },
+        AssertDatabaseName("".to_owned()),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_check_healthy_database_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let view_id = test.view_id.clone();
+    let scripts = vec![
+        CloseAllDatabases {
+            expected_database_ids: vec![view_id],
+        },
+        CheckDatabase {
+            expected_issues: vec![],
+        },
+        RepairDatabase { is_healthy: true },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_repair_cells_of_deleted_field_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let view_id = test.view_id.clone();
+    let field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let expected = test.row_revs.len();
+    let scripts = vec![
+        DeleteField {
+            field_id: field_id.clone(),
+        },
+        CloseAllDatabases {
+            expected_database_ids: vec![view_id],
+        },
+        CheckDatabase {
+            expected_issues: vec![DatabaseHealthIssue::CellOfDeletedField {
+                block_id: "".to_owned(),
+                row_id: "".to_owned(),
+                field_id,
+            }],
+        },
+        RepairDatabase { is_healthy: true },
+        CheckDatabase {
+            expected_issues: vec![],
+        },
+        AssertNumberOfRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_archive_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let expected = test.row_revs.len();
+    let scripts = vec![
+        AssertIsArchived(false),
+        ArchiveDatabase,
+        AssertIsArchived(true),
+        AssertCanOpenDatabase(false),
+        OpenDatabase {
+            allow_archived: true,
+            is_ok: true,
+        },
+        UnarchiveDatabase,
+        AssertIsArchived(false),
+        AssertCanOpenDatabase(true),
+        AssertNumberOfRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_delete_archived_database_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let scripts = vec![
+        ArchiveDatabase,
+        DeleteDatabase,
+        AssertIsArchived(false),
+        OpenDatabase {
+            allow_archived: true,
+            is_ok: false,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_rename_archived_database_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let scripts = vec![
+        ArchiveDatabase,
+        RenameDatabase {
+            name: "Archived".to_owned(),
+            is_err: false,
+        },
+        AssertDatabaseName("Archived".to_owned()),
+        AssertIsArchived(true),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_resolve_related_rows_of_archived_database_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let expected = test.row_revs.len();
+    let scripts = vec![
+        ArchiveDatabase,
+        AssertNumberOfCandidateRows {
+            database_id: test.view_id.clone(),
+            expected,
+        },
+        // The archived database is closed after its rows are read.
+        AssertOpenedDatabases {
+            expected_database_ids: vec![],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_create_databases_batch_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let expected = test.row_revs.len();
+    let database_ids = (0..5).map(|_| gen_grid_id()).collect::<Vec<String>>();
+    let mut scripts = vec![CreateDatabasesBatch {
+        databases: database_ids
+            .iter()
+            .map(|database_id| (database_id.clone(), true))
+            .collect(),
+    }];
+    for database_id in database_ids {
+        scripts.push(AssertNumberOfRowsInDatabase { database_id, expected });
+    }
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_create_databases_batch_with_failure_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let expected = test.row_revs.len();
+    let database_id_1 = gen_grid_id();
+    let database_id_2 = gen_grid_id();
+    let database_id_3 = gen_grid_id();
+    let scripts = vec![
+        CreateDatabasesBatch {
+            databases: vec![
+                (database_id_1.clone(), true),
+                (database_id_2.clone(), false),
+                (database_id_3.clone(), true),
+            ],
+        },
+        AssertNumberOfRowsInDatabase {
+            database_id: database_id_1,
+            expected,
+        },
+        AssertNumberOfRowsInDatabase {
+            database_id: database_id_3,
+            expected,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_open_from_two_views_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let scripts = vec![
+        // The database is opened by the test
+        AssertEditorRefCount(1),
+        // Open the database from the first view
+        OpenDatabase {
+            allow_archived: false,
+            is_ok: true,
+        },
+        // Open the database from the second view
+        OpenDatabase {
+            allow_archived: false,
+            is_ok: true,
+        },
+        AssertEditorRefCount(3),
+        AssertOpenedDatabases {
+            expected_database_ids: vec![test.view_id.clone()],
+        },
+        // Close the second view
+        CloseDatabase { expected_ref_count: 2 },
+        // Close the first view
+        CloseDatabase { expected_ref_count: 1 },
+        AssertEditorRefCount(1),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_look_up_editor_without_ref_count_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let expected = test.row_revs.len();
+    let scripts = vec![
+        AssertEditorRefCount(1),
+        // Looking up the editor doesn't need to be paired with a close
+        AssertNumberOfRows { expected },
+        AssertNumberOfRows { expected },
+        AssertEditorRefCount(1),
+        CloseDatabase { expected_ref_count: 0 },
+        AssertOpenedDatabases {
+            expected_database_ids: vec![],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_release_editor_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let expected = test.row_revs.len();
+    let scripts = vec![
+        OpenDatabase {
+            allow_archived: false,
+            is_ok: true,
+        },
+        CloseDatabase { expected_ref_count: 1 },
+        CloseDatabase { expected_ref_count: 0 },
+        AssertEditorRefCount(0),
+        AssertOpenedDatabases {
+            expected_database_ids: vec![],
+        },
+        // Closing a released database does nothing
+        CloseDatabase { expected_ref_count: 0 },
+        // Reopen the released database, looking up the editor doesn't increase the reference count
+        AssertNumberOfRows { expected },
+        AssertEditorRefCount(0),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_revision_not_merged_with_threshold_1_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let scripts = vec![
+        SetRevisionCompressConfig(RevisionCompressConfig::with_merge_threshold(1)),
+        UpdateTextCell { count: 50 },
+        AssertNumberOfNewBlockRevisions(50),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_revision_merged_with_threshold_6_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let scripts = vec![
+        SetRevisionCompressConfig(RevisionCompressConfig::with_merge_threshold(6)),
+        UpdateTextCell { count: 50 },
+        // 8 merged revisions that each contains 6 changes and the 2 remaining revisions
+        AssertNumberOfNewBlockRevisions(10),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_revision_merged_with_threshold_50_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let scripts = vec![
+        SetRevisionCompressConfig(RevisionCompressConfig::with_merge_threshold(50)),
+        UpdateTextCell { count: 50 },
+        AssertNumberOfNewBlockRevisions(1),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_generate_snapshots_periodically_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let mut config = RevisionCompressConfig::with_merge_threshold(1);
+    config.snapshot_policy = DatabaseSnapshotPolicy {
+        per_revisions: 5,
+        interval: Duration::from_secs(60 * 60),
+        retention: 2,
+    };
+    let scripts = vec![
+        SetRevisionCompressConfig(config),
+        UpdateTextCell { count: 30 },
+        // Only the latest 2 snapshots of the 6 generated snapshots are kept
+        AssertNumberOfBlockSnapshots(2),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+fn rename_database_scripts(names: &[&str]) -> Vec<DatabaseManagerScript> {
+    names
+        .iter()
+        .map(|name| RenameDatabase {
+            name: name.to_string(),
+            is_err: false,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn database_compact_history_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let mut scripts = rename_database_scripts(&["a", "b", "c"]);
+    scripts.extend(vec![
+        AckAllDatabaseRevisions,
+        WriteDatabaseSnapshot,
+        CompactDatabaseHistory {
+            keep_last: 1,
+            is_reclaimed: true,
+        },
+        // The merged revision and the last revision
+        AssertNumberOfDatabaseRevisions(2),
+        CloseAllDatabases {
+            expected_database_ids: vec![test.view_id.clone()],
+        },
+        AssertDatabaseName("c".to_string()),
+    ]);
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_compact_history_without_snapshot_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let mut scripts = rename_database_scripts(&["a", "b", "c"]);
+    scripts.extend(vec![
+        AckAllDatabaseRevisions,
+        CompactDatabaseHistory {
+            keep_last: 0,
+            is_reclaimed: false,
+        },
+    ]);
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_compact_history_keep_unsynced_revisions_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let mut scripts = rename_database_scripts(&["a", "b", "c"]);
+    scripts.extend(vec![
+        WriteDatabaseSnapshot,
+        CompactDatabaseHistory {
+            keep_last: 0,
+            is_reclaimed: false,
+        },
+        CloseAllDatabases {
+            expected_database_ids: vec![test.view_id.clone()],
+        },
+        AssertDatabaseName("c".to_string()),
+    ]);
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_compact_all_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let mut scripts = rename_database_scripts(&["a", "b", "c"]);
+    scripts.extend(vec![
+        AckAllDatabaseRevisions,
+        WriteDatabaseSnapshot,
+        // The opened database is skipped
+        CompactAllDatabases {
+            keep_last: 0,
+            is_reclaimed: false,
+        },
+        CloseAllDatabases {
+            expected_database_ids: vec![test.view_id.clone()],
+        },
+        CompactAllDatabases {
+            keep_last: 0,
+            is_reclaimed: true,
+        },
+        AssertNumberOfDatabaseRevisions(1),
+        AssertDatabaseName("c".to_string()),
+    ]);
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_open_from_snapshot_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let mut scripts = rename_database_scripts(&["a", "b", "c"]);
+    scripts.extend(vec![AckAllDatabaseRevisions, WriteDatabaseSnapshot]);
+    // The revisions after the snapshot are replayed on top of it.
+    scripts.extend(rename_database_scripts(&["d", "e"]));
+    scripts.extend(vec![
+        SetRevisionCompressConfig(RevisionCompressConfig {
+            revision_soft_limit: 1,
+            revision_hard_limit: 2,
+            ..RevisionCompressConfig::with_merge_threshold(1)
+        }),
+        AssertDatabaseName("e".to_string()),
+        WaitForRepairFinished,
+        AssertIsRepairing(false),
+        AssertDatabaseName("e".to_string()),
+    ]);
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_open_below_revision_limits_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let mut scripts = rename_database_scripts(&["a", "b"]);
+    scripts.extend(vec![
+        SetRevisionCompressConfig(RevisionCompressConfig::with_merge_threshold(1)),
+        AssertIsRepairing(false),
+        AssertDatabaseName("b".to_string()),
+    ]);
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_memory_revision_store_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let mut scripts = rename_database_scripts(&["a", "b"]);
+    scripts.extend(vec![
+        SetRevisionCompressConfig(RevisionCompressConfig::with_merge_threshold(1)),
+        AssertMemoryRevisionStore,
+    ]);
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_manager_with_memory_revision_store_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    test.run_scripts(vec![AssertMemoryDatabaseManager]).await;
+}
+
+#[tokio::test]
+async fn database_export_and_import_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let new_database_id = gen_grid_id();
+    let scripts = vec![
+        ExportAndImportDatabase {
+            new_database_id: new_database_id.clone(),
+        },
+        AssertDatabaseDataEqual {
+            database_id: new_database_id.clone(),
+        },
+        AssertNumberOfRowsInDatabase {
+            database_id: new_database_id,
+            expected: test.row_revs.len(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_export_and_import_row_meta_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let new_database_id = gen_grid_id();
+    let scripts = vec![
+        UpdateRowMeta {
+            row_index: 0,
+            changeset: RowMetaChangeset {
+                icon: Some("🚀".to_owned()),
+                cover: Some("cover.png".to_owned()),
+                color: Some("#FF8800".to_owned()),
+            },
+        },
+        ExportAndImportDatabase {
+            new_database_id: new_database_id.clone(),
+        },
+        // The meta of the row is imported with the row.
+        AssertDatabaseDataEqual {
+            database_id: new_database_id,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_export_and_import_locked_field_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let new_database_id = gen_grid_id();
+    let field_id = test
+        .field_revs
+        .iter()
+        .find(|field_rev| !field_rev.is_primary)
+        .unwrap()
+        .id
+        .clone();
+    let scripts = vec![
+        SetFieldLocked { field_id, locked: true },
+        ExportAndImportDatabase {
+            new_database_id: new_database_id.clone(),
+        },
+        // The imported field is locked as well.
+        AssertDatabaseDataEqual {
+            database_id: new_database_id,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_field_template_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::SingleSelect).id.clone();
+    let database_id = test.view_id.clone();
+    let scripts = vec![
+        SaveFieldTemplate {
+            name: "Status".to_owned(),
+            field_id: field_id.clone(),
+        },
+        AssertFieldTemplateNames(vec!["Status".to_owned()]),
+        CreateFieldFromTemplate {
+            database_id: database_id.clone(),
+            template_name: "Status".to_owned(),
+            is_ok: true,
+        },
+        AssertLastFieldFromTemplate {
+            database_id: database_id.clone(),
+            source_field_id: field_id,
+        },
+        CreateFieldFromTemplate {
+            database_id,
+            template_name: "Unknown".to_owned(),
+            is_ok: false,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_field_template_in_another_database_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let field_id = test.get_first_field_rev(FieldType::SingleSelect).id.clone();
+    let new_database_id = gen_grid_id();
+    let scripts = vec![
+        SaveFieldTemplate {
+            name: "Status".to_owned(),
+            field_id: field_id.clone(),
+        },
+        ExportAndImportDatabase {
+            new_database_id: new_database_id.clone(),
+        },
+        CreateFieldFromTemplate {
+            database_id: new_database_id.clone(),
+            template_name: "Status".to_owned(),
+            is_ok: true,
+        },
+        AssertLastFieldFromTemplate {
+            database_id: new_database_id,
+            source_field_id: field_id,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_field_template_export_and_import_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let single_select_field_id = test.get_first_field_rev(FieldType::SingleSelect).id.clone();
+    let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let scripts = vec![
+        SaveFieldTemplate {
+            name: "Status".to_owned(),
+            field_id: single_select_field_id.clone(),
+        },
+        SaveFieldTemplate {
+            name: "Price".to_owned(),
+            field_id: number_field_id,
+        },
+        // Saving with the same name replaces the template.
+        SaveFieldTemplate {
+            name: "Status".to_owned(),
+            field_id: single_select_field_id,
+        },
+        AssertFieldTemplateNames(vec!["Status".to_owned(), "Price".to_owned()]),
+        ExportAndImportFieldTemplates,
+        DeleteFieldTemplate {
+            name: "Status".to_owned(),
+        },
+        AssertFieldTemplateNames(vec!["Price".to_owned()]),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_export_and_import_after_editing_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let new_database_id = gen_grid_id();
+    let mut scripts = rename_database_scripts(&["a"]);
+    scripts.extend(vec![
+        UpdateTextCell { count: 3 },
+        ExportAndImportDatabase {
+            new_database_id: new_database_id.clone(),
+        },
+        AssertDatabaseDataEqual {
+            database_id: new_database_id,
+        },
+    ]);
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_recover_from_snapshot_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let mut scripts = rename_database_scripts(&["a"]);
+    scripts.push(WriteDatabaseSnapshot);
+    scripts.extend(rename_database_scripts(&["b"]));
+    scripts.extend(vec![
+        // Closing the database generates the latest snapshot
+        CloseAllDatabases {
+            expected_database_ids: vec![test.view_id.clone()],
+        },
+        AssertDatabaseRevisionsCorrupted(false),
+        CorruptLatestDatabaseRevision,
+        CorruptLatestDatabaseSnapshot,
+        AssertDatabaseRevisionsCorrupted(true),
+        // Recover from the snapshot that was generated after renaming the database to "a"
+        AssertCanOpenDatabase(true),
+        AssertDatabaseName("a".to_string()),
+        AssertDatabaseRevisionsCorrupted(false),
+    ]);
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_recover_without_valid_snapshot_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let mut scripts = rename_database_scripts(&["a"]);
+    scripts.extend(vec![
+        CloseAllDatabases {
+            expected_database_ids: vec![test.view_id.clone()],
+        },
+        CorruptLatestDatabaseRevision,
+        CorruptLatestDatabaseSnapshot,
+        AssertCanOpenDatabase(false),
+    ]);
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_encrypt_revisions_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let expected = test.row_revs.len();
+    let cipher: Arc<dyn RevisionCipher> = Arc::new(XorRevisionCipher(7));
+    let scripts = vec![
+        AssertDatabaseEncrypted(false),
+        AssertBlocksAndViewEncrypted(false),
+        ReencryptDatabase {
+            old: None,
+            new: Some(cipher.clone()),
+        },
+        AssertDatabaseEncrypted(true),
+        AssertBlocksAndViewEncrypted(true),
+        AssertDatabaseReadable {
+            cipher: Some(cipher.clone()),
+            is_readable: true,
+        },
+        AssertOpenEncryptedDatabaseFailed,
+        ReencryptDatabase {
+            old: Some(cipher),
+            new: None,
+        },
+        AssertDatabaseEncrypted(false),
+        AssertBlocksAndViewEncrypted(false),
+        AssertCanOpenDatabase(true),
+        AssertNumberOfRows { expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_rotate_cipher_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let old_cipher: Arc<dyn RevisionCipher> = Arc::new(XorRevisionCipher(7));
+    let new_cipher: Arc<dyn RevisionCipher> = Arc::new(XorRevisionCipher(9));
+    let scripts = vec![
+        ReencryptDatabase {
+            old: None,
+            new: Some(old_cipher.clone()),
+        },
+        ReencryptDatabase {
+            old: Some(old_cipher.clone()),
+            new: Some(new_cipher.clone()),
+        },
+        AssertDatabaseEncrypted(true),
+        AssertDatabaseReadable {
+            cipher: Some(old_cipher),
+            is_readable: false,
+        },
+        AssertDatabaseReadable {
+            cipher: Some(new_cipher),
+            is_readable: true,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_revision_stats_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let scripts = vec![
+        AssertRevisionStats,
+        UpdateTextCell { count: 3 },
+        AssertRevisionStats,
+        WriteDatabaseSnapshot,
+        AssertRevisionStats,
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_storage_report_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let database_id = test.view_id.clone();
+    let closed_database_id = gen_grid_id();
+    let scripts = vec![
+        CreateDatabasesBatch {
+            databases: vec![(closed_database_id.clone(), true)],
+        },
+        AssertStorageReport {
+            database_ids: vec![database_id.clone(), closed_database_id.clone()],
+            opened_database_ids: vec![database_id.clone()],
+        },
+        CloseAllDatabases {
+            expected_database_ids: vec![database_id.clone()],
+        },
+        AssertStorageReport {
+            database_ids: vec![database_id, closed_database_id],
+            opened_database_ids: vec![],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_create_failed_after_blocks_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let expected = test.row_revs.len();
+    let database_id = gen_grid_id();
+    let scripts = vec![
+        CreateDatabaseFailedAfterBlocks {
+            database_id: database_id.clone(),
+        },
+        // The database can be created again after the failure.
+        CreateDatabasesBatch {
+            databases: vec![(database_id.clone(), true)],
+        },
+        AssertNumberOfRowsInDatabase { database_id, expected },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn database_export_and_import_validation_rules_test() {
+    let mut test = DatabaseManagerTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::RichText).clone();
+    let type_option = RichTextTypeOptionBuilder::default().max_length(2).pattern("^[A-Z]+$");
+    test.editor
+        .update_field_type_option(
+            &test.view_id,
+            &field_rev.id,
+            type_option.serializer().protobuf_bytes().to_vec(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let new_database_id = gen_grid_id();
+    let scripts = vec![
+        ExportAndImportDatabase {
+            new_database_id: new_database_id.clone(),
+        },
+        AssertDatabaseDataEqual {
+            database_id: new_database_id,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -3,7 +3,8 @@
 // #![allow(unused_imports)]
 use crate::grid::block_test::util::GridRowTestBuilder;
 use crate::grid::mock_data::{
-    COMPLETED, FACEBOOK, FIRST_THING, GOOGLE, PAUSED, PLANNED, SECOND_THING, THIRD_THING, TWITTER,
+    ANNIE, ANNIE_ID, COMPLETED, FACEBOOK, FIRST_THING, GOOGLE, LUCAS, LUCAS_ID, NATHAN, NATHAN_ID, PAUSED, PLANNED,
+    SECOND_THING, THIRD_THING, TWITTER,
 };
 
 use flowy_client_sync::client_database::DatabaseBuilder;
@@ -88,6 +89,72 @@ pub fn make_test_board() -> BuildDatabaseContext {
                 let checklist_field = FieldBuilder::new(checklist).name("TODO").visibility(true).build();
                 grid_builder.add_field(checklist_field);
             }
+            FieldType::CreatedTime => {
+                let created_time = TimestampTypeOptionBuilder::new(FieldType::CreatedTime)
+                    .date_format(DateFormat::US)
+                    .time_format(TimeFormat::TwentyFourHour);
+                let created_time_field = FieldBuilder::new(created_time)
+                    .name("Created at")
+                    .visibility(true)
+                    .build();
+                grid_builder.add_field(created_time_field);
+            }
+            FieldType::LastEditedTime => {
+                let last_edited_time = TimestampTypeOptionBuilder::new(FieldType::LastEditedTime)
+                    .date_format(DateFormat::US)
+                    .time_format(TimeFormat::TwentyFourHour);
+                let last_edited_time_field = FieldBuilder::new(last_edited_time)
+                    .name("Last edited")
+                    .visibility(true)
+                    .build();
+                grid_builder.add_field(last_edited_time_field);
+            }
+            FieldType::Relation => {
+                let relation = RelationTypeOptionBuilder::default();
+                let relation_field = FieldBuilder::new(relation).name("Related").visibility(true).build();
+                grid_builder.add_field(relation_field);
+            }
+            FieldType::Formula => {
+                let formula = FormulaTypeOptionBuilder::default();
+                let formula_field = FieldBuilder::new(formula).name("Formula").visibility(true).build();
+                grid_builder.add_field(formula_field);
+            }
+            FieldType::Rating => {
+                let rating = RatingTypeOptionBuilder::default().symbol(RatingSymbolPB::Star);
+                let rating_field = FieldBuilder::new(rating).name("Rating").visibility(true).build();
+                grid_builder.add_field(rating_field);
+            }
+            FieldType::Progress => {
+                let progress = ProgressTypeOptionBuilder::default();
+                let progress_field = FieldBuilder::new(progress).name("Progress").visibility(true).build();
+                grid_builder.add_field(progress_field);
+            }
+            FieldType::Email => {
+                let email = EmailTypeOptionBuilder::default();
+                let email_field = FieldBuilder::new(email).name("Email").visibility(true).build();
+                grid_builder.add_field(email_field);
+            }
+            FieldType::PhoneNumber => {
+                let phone_number = PhoneNumberTypeOptionBuilder::default().country_code("1");
+                let phone_number_field = FieldBuilder::new(phone_number).name("Phone").visibility(true).build();
+                grid_builder.add_field(phone_number_field);
+            }
+            FieldType::Attachment => {
+                let attachment = AttachmentTypeOptionBuilder::default();
+                let attachment_field = FieldBuilder::new(attachment)
+                    .name("Attachments")
+                    .visibility(true)
+                    .build();
+                grid_builder.add_field(attachment_field);
+            }
+            FieldType::Person => {
+                let person = PersonTypeOptionBuilder::default()
+                    .add_member(MemberPB::new(LUCAS_ID, LUCAS))
+                    .add_member(MemberPB::new(NATHAN_ID, NATHAN))
+                    .add_member(MemberPB::new(ANNIE_ID, ANNIE));
+                let person_field = FieldBuilder::new(person).name("Assignee").visibility(true).build();
+                grid_builder.add_field(person_field);
+            }
         }
     }
 
@@ -102,6 +169,7 @@ pub fn make_test_board() -> BuildDatabaseContext {
                     match field_type {
                         FieldType::RichText => row_builder.insert_text_cell("A"),
                         FieldType::Number => row_builder.insert_number_cell("1"),
+                        FieldType::Rating => row_builder.insert_rating_cell("3"),
                         // 1647251762 => Mar 14,2022
                         FieldType::DateTime => row_builder.insert_date_cell("1647251762"),
                         FieldType::SingleSelect => {
@@ -111,6 +179,7 @@ pub fn make_test_board() -> BuildDatabaseContext {
                             .insert_multi_select_cell(|mut options| vec![options.remove(0), options.remove(0)]),
                         FieldType::Checkbox => row_builder.insert_checkbox_cell("true"),
                         FieldType::URL => row_builder.insert_url_cell("https://appflowy.io"),
+                        FieldType::Person => row_builder.insert_person_cell(&[LUCAS_ID]),
                         _ => "".to_owned(),
                     };
                 }
@@ -120,6 +189,7 @@ pub fn make_test_board() -> BuildDatabaseContext {
                     match field_type {
                         FieldType::RichText => row_builder.insert_text_cell("B"),
                         FieldType::Number => row_builder.insert_number_cell("2"),
+                        FieldType::Rating => row_builder.insert_rating_cell("3"),
                         // 1647251762 => Mar 14,2022
                         FieldType::DateTime => row_builder.insert_date_cell("1647251762"),
                         FieldType::SingleSelect => {
@@ -128,6 +198,7 @@ pub fn make_test_board() -> BuildDatabaseContext {
                         FieldType::MultiSelect => row_builder
                             .insert_multi_select_cell(|mut options| vec![options.remove(0), options.remove(0)]),
                         FieldType::Checkbox => row_builder.insert_checkbox_cell("true"),
+                        FieldType::Person => row_builder.insert_person_cell(&[LUCAS_ID, NATHAN_ID]),
                         _ => "".to_owned(),
                     };
                 }
@@ -137,6 +208,7 @@ pub fn make_test_board() -> BuildDatabaseContext {
                     match field_type {
                         FieldType::RichText => row_builder.insert_text_cell("C"),
                         FieldType::Number => row_builder.insert_number_cell("3"),
+                        FieldType::Rating => row_builder.insert_rating_cell("5"),
                         // 1647251762 => Mar 14,2022
                         FieldType::DateTime => row_builder.insert_date_cell("1647251762"),
                         FieldType::SingleSelect => {
@@ -147,6 +219,7 @@ pub fn make_test_board() -> BuildDatabaseContext {
                         }
                         FieldType::Checkbox => row_builder.insert_checkbox_cell("false"),
                         FieldType::URL => row_builder.insert_url_cell("https://github.com/AppFlowy-IO/AppFlowy"),
+                        FieldType::Person => row_builder.insert_person_cell(&[ANNIE_ID]),
                         _ => "".to_owned(),
                     };
                 }
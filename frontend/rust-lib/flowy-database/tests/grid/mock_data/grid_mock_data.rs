@@ -3,7 +3,8 @@
 // #![allow(unused_imports)]
 use crate::grid::block_test::util::GridRowTestBuilder;
 use crate::grid::mock_data::{
-    COMPLETED, FACEBOOK, FIRST_THING, GOOGLE, PAUSED, PLANNED, SECOND_THING, THIRD_THING, TWITTER,
+    ANNIE, ANNIE_ID, COMPLETED, FACEBOOK, FIRST_THING, GOOGLE, LUCAS, LUCAS_ID, NATHAN, NATHAN_ID, PAUSED, PLANNED,
+    REMOVED_MEMBER_ID, SECOND_THING, THIRD_THING, TWITTER,
 };
 
 use flowy_client_sync::client_database::DatabaseBuilder;
@@ -16,6 +17,9 @@ use grid_model::*;
 
 use strum::IntoEnumIterator;
 
+// 2022/05/27, 2022/03/14, 2022/12/25, 2022/11/17, 2022/11/13, 2022/04/15
+const CREATED_AT_TIMESTAMPS: [i64; 6] = [1653609600, 1647251762, 1671938394, 1668704685, 1668359085, 1650000000];
+
 pub fn make_test_grid() -> BuildDatabaseContext {
     let mut grid_builder = DatabaseBuilder::new();
     // Iterate through the FieldType to create the corresponding Field.
@@ -87,6 +91,72 @@ pub fn make_test_grid() -> BuildDatabaseContext {
                 let checklist_field = FieldBuilder::new(checklist).name("TODO").visibility(true).build();
                 grid_builder.add_field(checklist_field);
             }
+            FieldType::CreatedTime => {
+                let created_time = TimestampTypeOptionBuilder::new(FieldType::CreatedTime)
+                    .date_format(DateFormat::US)
+                    .time_format(TimeFormat::TwentyFourHour);
+                let created_time_field = FieldBuilder::new(created_time)
+                    .name("Created at")
+                    .visibility(true)
+                    .build();
+                grid_builder.add_field(created_time_field);
+            }
+            FieldType::LastEditedTime => {
+                let last_edited_time = TimestampTypeOptionBuilder::new(FieldType::LastEditedTime)
+                    .date_format(DateFormat::US)
+                    .time_format(TimeFormat::TwentyFourHour);
+                let last_edited_time_field = FieldBuilder::new(last_edited_time)
+                    .name("Last edited")
+                    .visibility(true)
+                    .build();
+                grid_builder.add_field(last_edited_time_field);
+            }
+            FieldType::Relation => {
+                let relation = RelationTypeOptionBuilder::default();
+                let relation_field = FieldBuilder::new(relation).name("Related").visibility(true).build();
+                grid_builder.add_field(relation_field);
+            }
+            FieldType::Formula => {
+                let formula = FormulaTypeOptionBuilder::default();
+                let formula_field = FieldBuilder::new(formula).name("Formula").visibility(true).build();
+                grid_builder.add_field(formula_field);
+            }
+            FieldType::Rating => {
+                let rating = RatingTypeOptionBuilder::default().symbol(RatingSymbolPB::Star);
+                let rating_field = FieldBuilder::new(rating).name("Rating").visibility(true).build();
+                grid_builder.add_field(rating_field);
+            }
+            FieldType::Progress => {
+                let progress = ProgressTypeOptionBuilder::default();
+                let progress_field = FieldBuilder::new(progress).name("Progress").visibility(true).build();
+                grid_builder.add_field(progress_field);
+            }
+            FieldType::Email => {
+                let email = EmailTypeOptionBuilder::default();
+                let email_field = FieldBuilder::new(email).name("Email").visibility(true).build();
+                grid_builder.add_field(email_field);
+            }
+            FieldType::PhoneNumber => {
+                let phone_number = PhoneNumberTypeOptionBuilder::default().country_code("1");
+                let phone_number_field = FieldBuilder::new(phone_number).name("Phone").visibility(true).build();
+                grid_builder.add_field(phone_number_field);
+            }
+            FieldType::Attachment => {
+                let attachment = AttachmentTypeOptionBuilder::default();
+                let attachment_field = FieldBuilder::new(attachment)
+                    .name("Attachments")
+                    .visibility(true)
+                    .build();
+                grid_builder.add_field(attachment_field);
+            }
+            FieldType::Person => {
+                let person = PersonTypeOptionBuilder::default()
+                    .add_member(MemberPB::new(LUCAS_ID, LUCAS))
+                    .add_member(MemberPB::new(NATHAN_ID, NATHAN))
+                    .add_member(MemberPB::new(ANNIE_ID, ANNIE));
+                let person_field = FieldBuilder::new(person).name("Assignee").visibility(true).build();
+                grid_builder.add_field(person_field);
+            }
         }
     }
 
@@ -106,6 +176,15 @@ pub fn make_test_grid() -> BuildDatabaseContext {
                         FieldType::Checklist => row_builder.insert_checklist_cell(|options| options),
                         FieldType::Checkbox => row_builder.insert_checkbox_cell("true"),
                         FieldType::URL => row_builder.insert_url_cell("AppFlowy website - https://www.appflowy.io"),
+                        FieldType::Rating => row_builder.insert_rating_cell("5"),
+                        FieldType::Progress => row_builder.insert_progress_cell("1"),
+                        FieldType::Email => row_builder.insert_email_cell("Lucas@AppFlowy.io"),
+                        FieldType::PhoneNumber => row_builder.insert_phone_number_cell("+1 (555) 123-4567"),
+                        FieldType::Attachment => row_builder.insert_attachment_cell(vec![
+                            AttachmentPB::from_url("logo.png", "https://appflowy.io/logo.png", "image/png"),
+                            AttachmentPB::from_url("guide.pdf", "https://appflowy.io/guide.pdf", "application/pdf"),
+                        ]),
+                        FieldType::Person => row_builder.insert_person_cell(&[LUCAS_ID]),
                         _ => "".to_owned(),
                     };
                 }
@@ -115,6 +194,16 @@ pub fn make_test_grid() -> BuildDatabaseContext {
                     match field_type {
                         FieldType::RichText => row_builder.insert_text_cell(""),
                         FieldType::Number => row_builder.insert_number_cell("2"),
+                        FieldType::Rating => row_builder.insert_rating_cell("3"),
+                        FieldType::Progress => row_builder.insert_progress_cell("0.5"),
+                        FieldType::Email => row_builder.insert_email_cell("nathan@appflowy.io"),
+                        FieldType::PhoneNumber => row_builder.insert_phone_number_cell("555.987.6543"),
+                        FieldType::Attachment => row_builder.insert_attachment_cell(vec![AttachmentPB::from_url(
+                            "roadmap.pdf",
+                            "https://appflowy.io/roadmap.pdf",
+                            "application/pdf",
+                        )]),
+                        FieldType::Person => row_builder.insert_person_cell(&[NATHAN_ID, LUCAS_ID]),
                         FieldType::DateTime => row_builder.insert_date_cell("1647251762"),
                         FieldType::MultiSelect => row_builder
                             .insert_multi_select_cell(|mut options| vec![options.remove(0), options.remove(1)]),
@@ -128,6 +217,11 @@ pub fn make_test_grid() -> BuildDatabaseContext {
                     match field_type {
                         FieldType::RichText => row_builder.insert_text_cell("C"),
                         FieldType::Number => row_builder.insert_number_cell("3"),
+                        FieldType::Rating => row_builder.insert_rating_cell("3"),
+                        FieldType::Progress => row_builder.insert_progress_cell("0.25"),
+                        FieldType::Email => row_builder.insert_email_cell("annie@example.com"),
+                        FieldType::PhoneNumber => row_builder.insert_phone_number_cell("+44 20 7946 0958"),
+                        FieldType::Person => row_builder.insert_person_cell(&[ANNIE_ID]),
                         FieldType::DateTime => row_builder.insert_date_cell("1647251762"),
                         FieldType::SingleSelect => {
                             row_builder.insert_single_select_cell(|mut options| options.remove(0))
@@ -145,6 +239,9 @@ pub fn make_test_grid() -> BuildDatabaseContext {
                     match field_type {
                         FieldType::RichText => row_builder.insert_text_cell("DA"),
                         FieldType::Number => row_builder.insert_number_cell("4"),
+                        FieldType::Rating => row_builder.insert_rating_cell("1"),
+                        FieldType::Progress => row_builder.insert_progress_cell("0.75"),
+                        FieldType::Person => row_builder.insert_person_cell(&[REMOVED_MEMBER_ID]),
                         FieldType::DateTime => row_builder.insert_date_cell("1668704685"),
                         FieldType::SingleSelect => {
                             row_builder.insert_single_select_cell(|mut options| options.remove(0))
@@ -186,7 +283,10 @@ pub fn make_test_grid() -> BuildDatabaseContext {
             _ => {}
         }
 
-        let row_rev = row_builder.build();
+        let mut row_rev = row_builder.build();
+        // The rows are treated as if they were created on different days.
+        row_rev.created_at = CREATED_AT_TIMESTAMPS[i];
+        row_rev.modified_at = row_rev.created_at;
         grid_builder.add_row(row_rev);
     }
     grid_builder.build()
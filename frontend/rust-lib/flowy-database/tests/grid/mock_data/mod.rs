@@ -17,3 +17,12 @@ pub const PAUSED: &str = "Paused";
 pub const FIRST_THING: &str = "Wake up at 6:00 am";
 pub const SECOND_THING: &str = "Get some coffee";
 pub const THIRD_THING: &str = "Start working";
+
+pub const LUCAS: &str = "Lucas";
+pub const LUCAS_ID: &str = "lucas";
+pub const NATHAN: &str = "Nathan";
+pub const NATHAN_ID: &str = "nathan";
+pub const ANNIE: &str = "Annie";
+pub const ANNIE_ID: &str = "annie";
+/// The member that was removed from the workspace, it can't be resolved anymore.
+pub const REMOVED_MEMBER_ID: &str = "removed";
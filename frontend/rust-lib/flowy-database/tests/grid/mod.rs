@@ -1,10 +1,16 @@
+mod attachment_test;
 mod block_test;
 mod cell_test;
+mod comment_test;
 mod database_editor;
 mod field_test;
 mod filter_test;
 mod group_test;
+mod manager_test;
+mod person_test;
+mod row_template_test;
 mod snapshot_test;
 mod sort_test;
+mod undo_test;
 
 mod mock_data;
@@ -0,0 +1,187 @@
+use crate::grid::database_editor::DatabaseEditorTest;
+use crate::grid::mock_data::{ANNIE, ANNIE_ID, LUCAS, LUCAS_ID, NATHAN, NATHAN_ID};
+use flowy_database::entities::{CellPathParams, FieldType};
+use flowy_database::services::field::{MemberPB, MemberResolver, PersonCellChangeset, PersonTypeOptionPB};
+use flowy_error::FlowyResult;
+use lib_infra::async_trait::async_trait;
+use parking_lot::Mutex;
+use std::sync::Arc;
+
+/// Pretends to be the members of the workspace. The members can be added or removed by the
+/// scripts.
+pub struct MockMemberResolver {
+    members: Mutex<Vec<MemberPB>>,
+}
+
+impl std::default::Default for MockMemberResolver {
+    fn default() -> Self {
+        let members = vec![
+            MemberPB::new(LUCAS_ID, LUCAS),
+            MemberPB::new(NATHAN_ID, NATHAN),
+            MemberPB::new(ANNIE_ID, ANNIE),
+        ];
+        Self {
+            members: Mutex::new(members),
+        }
+    }
+}
+
+#[async_trait]
+impl MemberResolver for MockMemberResolver {
+    async fn resolve_members(&self, member_ids: &[String]) -> FlowyResult<Vec<MemberPB>> {
+        let members = self.members.lock();
+        let resolved_members = member_ids
+            .iter()
+            .filter_map(|member_id| members.iter().find(|member| &member.id == member_id).cloned())
+            .collect();
+        Ok(resolved_members)
+    }
+
+    async fn get_members(&self) -> FlowyResult<Vec<MemberPB>> {
+        Ok(self.members.lock().clone())
+    }
+}
+
+pub enum PersonScript {
+    AddWorkspaceMember {
+        id: String,
+        name: String,
+    },
+    RemoveWorkspaceMember {
+        id: String,
+    },
+    InsertMember {
+        row_index: usize,
+        member_id: String,
+    },
+    RemoveMember {
+        row_index: usize,
+        member_id: String,
+    },
+    RefreshMembers,
+    AssertCellNames {
+        row_index: usize,
+        expected: &'static str,
+    },
+    /// Asserts the names of the members that are cached in the type option.
+    AssertCachedMembers(Vec<&'static str>),
+    AssertCandidates(Vec<&'static str>),
+}
+
+pub struct DatabasePersonTest {
+    inner: DatabaseEditorTest,
+    resolver: Arc<MockMemberResolver>,
+}
+
+impl DatabasePersonTest {
+    pub async fn new() -> Self {
+        let editor_test = DatabaseEditorTest::new_table().await;
+        let resolver = Arc::new(MockMemberResolver::default());
+        editor_test.sdk.grid_manager.set_member_resolver(Some(resolver.clone()));
+        Self {
+            inner: editor_test,
+            resolver,
+        }
+    }
+
+    fn cell_path(&self, row_index: usize) -> CellPathParams {
+        CellPathParams {
+            database_id: self.view_id.clone(),
+            field_id: self.get_first_field_rev(FieldType::Person).id.clone(),
+            row_id: self.row_revs[row_index].id.clone(),
+        }
+    }
+
+    pub async fn run_scripts(&mut self, scripts: Vec<PersonScript>) {
+        for script in scripts {
+            self.run_script(script).await;
+        }
+    }
+
+    pub async fn run_script(&mut self, script: PersonScript) {
+        match script {
+            PersonScript::AddWorkspaceMember { id, name } => {
+                self.resolver.members.lock().push(MemberPB::new(&id, &name));
+            }
+            PersonScript::RemoveWorkspaceMember { id } => {
+                self.resolver.members.lock().retain(|member| member.id != id);
+            }
+            PersonScript::InsertMember { row_index, member_id } => {
+                let cell_path = self.cell_path(row_index);
+                let changeset = PersonCellChangeset::from_insert_member_ids(vec![member_id]);
+                self.editor
+                    .update_person_cell(
+                        &cell_path.row_id,
+                        &cell_path.field_id,
+                        changeset,
+                        Some(self.resolver.as_ref()),
+                    )
+                    .await
+                    .unwrap();
+            }
+            PersonScript::RemoveMember { row_index, member_id } => {
+                let cell_path = self.cell_path(row_index);
+                let changeset = PersonCellChangeset::from_delete_member_ids(vec![member_id]);
+                self.editor
+                    .update_person_cell(
+                        &cell_path.row_id,
+                        &cell_path.field_id,
+                        changeset,
+                        Some(self.resolver.as_ref()),
+                    )
+                    .await
+                    .unwrap();
+            }
+            PersonScript::RefreshMembers => {
+                let field_id = self.get_first_field_rev(FieldType::Person).id.clone();
+                self.editor
+                    .refresh_person_members(&field_id, self.resolver.as_ref())
+                    .await
+                    .unwrap();
+            }
+            PersonScript::AssertCellNames { row_index, expected } => {
+                let cell_path = self.cell_path(row_index);
+                let s = self.editor.get_cell_display_str(&cell_path).await;
+                assert_eq!(s, expected);
+            }
+            PersonScript::AssertCachedMembers(expected) => {
+                let field_id = self.get_first_field_rev(FieldType::Person).id.clone();
+                let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
+                let type_option = PersonTypeOptionPB::from(&field_rev);
+                let names = type_option
+                    .members
+                    .iter()
+                    .map(|member| member.name.as_str())
+                    .collect::<Vec<&str>>();
+                assert_eq!(names, expected);
+            }
+            PersonScript::AssertCandidates(expected) => {
+                let field_id = self.get_first_field_rev(FieldType::Person).id.clone();
+                let candidates = self
+                    .editor
+                    .get_person_candidates(&field_id, self.resolver.as_ref())
+                    .await
+                    .unwrap();
+                let names = candidates
+                    .iter()
+                    .map(|member| member.name.as_str())
+                    .collect::<Vec<&str>>();
+                assert_eq!(names, expected);
+            }
+        }
+    }
+}
+
+impl std::ops::Deref for DatabasePersonTest {
+    type Target = DatabaseEditorTest;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl std::ops::DerefMut for DatabasePersonTest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
@@ -0,0 +1,101 @@
+use crate::grid::mock_data::{LUCAS_ID, NATHAN_ID, REMOVED_MEMBER_ID};
+use crate::grid::person_test::script::{DatabasePersonTest, PersonScript::*};
+
+#[tokio::test]
+async fn insert_member_test() {
+    let mut test = DatabasePersonTest::new().await;
+    let scripts = vec![
+        InsertMember {
+            row_index: 0,
+            member_id: NATHAN_ID.to_owned(),
+        },
+        AssertCellNames {
+            row_index: 0,
+            expected: "Lucas,Nathan",
+        },
+        RemoveMember {
+            row_index: 0,
+            member_id: LUCAS_ID.to_owned(),
+        },
+        AssertCellNames {
+            row_index: 0,
+            expected: "Nathan",
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn insert_new_workspace_member_test() {
+    let mut test = DatabasePersonTest::new().await;
+    let scripts = vec![
+        AddWorkspaceMember {
+            id: "grace".to_owned(),
+            name: "Grace".to_owned(),
+        },
+        // The new member is resolved and cached when it's inserted into the cell.
+        InsertMember {
+            row_index: 4,
+            member_id: "grace".to_owned(),
+        },
+        AssertCellNames {
+            row_index: 4,
+            expected: "Grace",
+        },
+        AssertCachedMembers(vec!["Lucas", "Nathan", "Annie", "Grace"]),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn refresh_members_test() {
+    let mut test = DatabasePersonTest::new().await;
+    let scripts = vec![
+        AssertCellNames {
+            row_index: 1,
+            expected: "Nathan,Lucas",
+        },
+        RemoveWorkspaceMember {
+            id: NATHAN_ID.to_owned(),
+        },
+        RefreshMembers,
+        // The member that can't be resolved anymore is decoded as a placeholder.
+        AssertCellNames {
+            row_index: 1,
+            expected: "Unknown member,Lucas",
+        },
+        AssertCachedMembers(vec!["Lucas", "Annie"]),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn refresh_members_keeps_unresolved_cells_test() {
+    let mut test = DatabasePersonTest::new().await;
+    let scripts = vec![
+        AddWorkspaceMember {
+            id: REMOVED_MEMBER_ID.to_owned(),
+            name: "Grace".to_owned(),
+        },
+        RefreshMembers,
+        // The member is resolved again after refreshing.
+        AssertCellNames {
+            row_index: 3,
+            expected: "Grace",
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn get_candidates_test() {
+    let mut test = DatabasePersonTest::new().await;
+    let scripts = vec![
+        AssertCandidates(vec!["Lucas", "Nathan", "Annie"]),
+        RemoveWorkspaceMember {
+            id: LUCAS_ID.to_owned(),
+        },
+        AssertCandidates(vec!["Nathan", "Annie"]),
+    ];
+    test.run_scripts(scripts).await;
+}
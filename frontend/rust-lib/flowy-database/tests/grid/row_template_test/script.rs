@@ -0,0 +1,175 @@
+use crate::grid::database_editor::DatabaseEditorTest;
+use flowy_database::entities::{CellPathParams, FieldType};
+use flowy_database::services::field::MultiSelectTypeOptionPB;
+use grid_model::TypeOptionDataSerializer;
+
+pub enum RowTemplateScript {
+    SaveRowTemplate {
+        name: String,
+        row_index: usize,
+        include_primary: bool,
+    },
+    DeleteRowTemplate {
+        name: String,
+    },
+    AssertRowTemplateNames(Vec<String>),
+    /// Exports the row templates to JSON, deletes them and imports them from the JSON.
+    ExportAndImportRowTemplates,
+    CreateRowFromTemplate {
+        name: String,
+        index: usize,
+        is_ok: bool,
+    },
+    /// Asserts the display string of the cell of the row that was last created from a template.
+    AssertCreatedRowCell {
+        field_id: String,
+        expected: &'static str,
+    },
+    AssertNumberOfRows(usize),
+    DeleteMultiSelectOption {
+        field_id: String,
+        option_name: String,
+    },
+    DeleteField {
+        field_id: String,
+    },
+    SwitchFieldType {
+        field_id: String,
+        field_type: FieldType,
+    },
+    DuplicateDatabase {
+        new_database_id: String,
+    },
+    AssertRowTemplateNamesInDatabase {
+        database_id: String,
+        expected: Vec<String>,
+    },
+}
+
+pub struct DatabaseRowTemplateTest {
+    inner: DatabaseEditorTest,
+    created_row_id: Option<String>,
+}
+
+impl DatabaseRowTemplateTest {
+    pub async fn new() -> Self {
+        let editor_test = DatabaseEditorTest::new_table().await;
+        Self {
+            inner: editor_test,
+            created_row_id: None,
+        }
+    }
+
+    pub async fn run_scripts(&mut self, scripts: Vec<RowTemplateScript>) {
+        for script in scripts {
+            self.run_script(script).await;
+        }
+    }
+
+    pub async fn run_script(&mut self, script: RowTemplateScript) {
+        match script {
+            RowTemplateScript::SaveRowTemplate {
+                name,
+                row_index,
+                include_primary,
+            } => {
+                let row_id = self.row_revs[row_index].id.clone();
+                self.editor
+                    .save_row_template(&name, &row_id, include_primary)
+                    .await
+                    .unwrap();
+            }
+            RowTemplateScript::DeleteRowTemplate { name } => {
+                self.editor.delete_row_template(&name).unwrap();
+            }
+            RowTemplateScript::AssertRowTemplateNames(expected) => {
+                let names = self
+                    .editor
+                    .list_row_templates()
+                    .unwrap()
+                    .into_iter()
+                    .map(|template| template.name)
+                    .collect::<Vec<String>>();
+                assert_eq!(names, expected);
+            }
+            RowTemplateScript::ExportAndImportRowTemplates => {
+                let templates = self.editor.list_row_templates().unwrap();
+                let json = self.editor.export_row_templates().unwrap();
+                for template in templates.iter() {
+                    self.editor.delete_row_template(&template.name).unwrap();
+                }
+                assert!(self.editor.list_row_templates().unwrap().is_empty());
+
+                self.editor.import_row_templates(&json).unwrap();
+                assert_eq!(self.editor.list_row_templates().unwrap(), templates);
+            }
+            RowTemplateScript::CreateRowFromTemplate { name, index, is_ok } => {
+                let result = self.editor.create_row_from_template(&self.view_id, &name, index).await;
+                assert_eq!(result.is_ok(), is_ok);
+                if let Ok(created_row) = result {
+                    assert_eq!(created_row.index, Some(index as i32));
+                    self.created_row_id = Some(created_row.row.id);
+                }
+            }
+            RowTemplateScript::AssertCreatedRowCell { field_id, expected } => {
+                let params = CellPathParams {
+                    database_id: self.view_id.clone(),
+                    field_id,
+                    row_id: self.created_row_id.clone().unwrap(),
+                };
+                let cell = self.editor.get_cell_display_str(&params).await;
+                assert_eq!(cell, expected);
+            }
+            RowTemplateScript::AssertNumberOfRows(expected) => {
+                let rows = self.editor.get_database(&self.view_id).await.unwrap().rows;
+                assert_eq!(rows.len(), expected);
+            }
+            RowTemplateScript::DeleteMultiSelectOption { field_id, option_name } => {
+                let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
+                let mut type_option = MultiSelectTypeOptionPB::from(&field_rev);
+                type_option.options.retain(|option| option.name != option_name);
+                self.editor
+                    .update_field_type_option(&self.view_id, &field_id, type_option.protobuf_bytes().to_vec(), None)
+                    .await
+                    .unwrap();
+            }
+            RowTemplateScript::DeleteField { field_id } => {
+                self.editor.delete_field(&field_id).await.unwrap();
+            }
+            RowTemplateScript::SwitchFieldType { field_id, field_type } => {
+                self.editor.switch_to_field_type(&field_id, &field_type).await.unwrap();
+            }
+            RowTemplateScript::DuplicateDatabase { new_database_id } => {
+                self.sdk
+                    .grid_manager
+                    .duplicate_database(&self.view_id, &new_database_id)
+                    .await
+                    .unwrap();
+            }
+            RowTemplateScript::AssertRowTemplateNamesInDatabase { database_id, expected } => {
+                let editor = self.sdk.grid_manager.get_database_editor(&database_id).await.unwrap();
+                let names = editor
+                    .list_row_templates()
+                    .unwrap()
+                    .into_iter()
+                    .map(|template| template.name)
+                    .collect::<Vec<String>>();
+                assert_eq!(names, expected);
+            }
+        }
+    }
+}
+
+impl std::ops::Deref for DatabaseRowTemplateTest {
+    type Target = DatabaseEditorTest;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl std::ops::DerefMut for DatabaseRowTemplateTest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
@@ -0,0 +1,202 @@
+use crate::grid::row_template_test::script::DatabaseRowTemplateTest;
+use crate::grid::row_template_test::script::RowTemplateScript::*;
+use flowy_database::entities::FieldType;
+use grid_model::gen_grid_id;
+
+#[tokio::test]
+async fn row_template_create_row_test() {
+    let mut test = DatabaseRowTemplateTest::new().await;
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let multi_select_field_id = test.get_first_field_rev(FieldType::MultiSelect).id.clone();
+    let email_field_id = test.get_first_field_rev(FieldType::Email).id.clone();
+    let scripts = vec![
+        SaveRowTemplate {
+            name: "Lead".to_owned(),
+            row_index: 0,
+            include_primary: false,
+        },
+        AssertRowTemplateNames(vec!["Lead".to_owned()]),
+        CreateRowFromTemplate {
+            name: "Lead".to_owned(),
+            index: 0,
+            is_ok: true,
+        },
+        AssertNumberOfRows(7),
+        // The cell of the primary field is not saved.
+        AssertCreatedRowCell {
+            field_id: text_field_id,
+            expected: "",
+        },
+        AssertCreatedRowCell {
+            field_id: multi_select_field_id,
+            expected: "Google,Facebook",
+        },
+        AssertCreatedRowCell {
+            field_id: email_field_id,
+            expected: "Lucas@AppFlowy.io",
+        },
+        CreateRowFromTemplate {
+            name: "Unknown".to_owned(),
+            index: 0,
+            is_ok: false,
+        },
+        AssertNumberOfRows(7),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn row_template_with_primary_cell_test() {
+    let mut test = DatabaseRowTemplateTest::new().await;
+    let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+    let scripts = vec![
+        SaveRowTemplate {
+            name: "Lead".to_owned(),
+            row_index: 0,
+            include_primary: false,
+        },
+        // Saving with the same name replaces the template.
+        SaveRowTemplate {
+            name: "Lead".to_owned(),
+            row_index: 0,
+            include_primary: true,
+        },
+        AssertRowTemplateNames(vec!["Lead".to_owned()]),
+        CreateRowFromTemplate {
+            name: "Lead".to_owned(),
+            index: 2,
+            is_ok: true,
+        },
+        AssertCreatedRowCell {
+            field_id: text_field_id,
+            expected: "A",
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn row_template_with_deleted_select_option_test() {
+    let mut test = DatabaseRowTemplateTest::new().await;
+    let multi_select_field_id = test.get_first_field_rev(FieldType::MultiSelect).id.clone();
+    let scripts = vec![
+        SaveRowTemplate {
+            name: "Lead".to_owned(),
+            row_index: 0,
+            include_primary: false,
+        },
+        DeleteMultiSelectOption {
+            field_id: multi_select_field_id.clone(),
+            option_name: "Google".to_owned(),
+        },
+        CreateRowFromTemplate {
+            name: "Lead".to_owned(),
+            index: 0,
+            is_ok: true,
+        },
+        // The deleted option is dropped from the cell.
+        AssertCreatedRowCell {
+            field_id: multi_select_field_id.clone(),
+            expected: "Facebook",
+        },
+        DeleteMultiSelectOption {
+            field_id: multi_select_field_id.clone(),
+            option_name: "Facebook".to_owned(),
+        },
+        CreateRowFromTemplate {
+            name: "Lead".to_owned(),
+            index: 0,
+            is_ok: true,
+        },
+        // The cell is skipped if all of its options were deleted.
+        AssertCreatedRowCell {
+            field_id: multi_select_field_id,
+            expected: "",
+        },
+        AssertNumberOfRows(8),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn row_template_with_deleted_and_retyped_fields_test() {
+    let mut test = DatabaseRowTemplateTest::new().await;
+    let multi_select_field_id = test.get_first_field_rev(FieldType::MultiSelect).id.clone();
+    let email_field_id = test.get_first_field_rev(FieldType::Email).id.clone();
+    let phone_number_field_id = test.get_first_field_rev(FieldType::PhoneNumber).id.clone();
+    let scripts = vec![
+        SaveRowTemplate {
+            name: "Lead".to_owned(),
+            row_index: 0,
+            include_primary: false,
+        },
+        DeleteField {
+            field_id: phone_number_field_id,
+        },
+        SwitchFieldType {
+            field_id: multi_select_field_id.clone(),
+            field_type: FieldType::RichText,
+        },
+        CreateRowFromTemplate {
+            name: "Lead".to_owned(),
+            index: 0,
+            is_ok: true,
+        },
+        // The cell of the retyped field is skipped.
+        AssertCreatedRowCell {
+            field_id: multi_select_field_id,
+            expected: "",
+        },
+        // The cells of the other fields are still copied.
+        AssertCreatedRowCell {
+            field_id: email_field_id,
+            expected: "Lucas@AppFlowy.io",
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn row_template_export_and_import_test() {
+    let mut test = DatabaseRowTemplateTest::new().await;
+    let scripts = vec![
+        SaveRowTemplate {
+            name: "Lead".to_owned(),
+            row_index: 0,
+            include_primary: false,
+        },
+        SaveRowTemplate {
+            name: "Task".to_owned(),
+            row_index: 2,
+            include_primary: true,
+        },
+        AssertRowTemplateNames(vec!["Lead".to_owned(), "Task".to_owned()]),
+        ExportAndImportRowTemplates,
+        DeleteRowTemplate {
+            name: "Lead".to_owned(),
+        },
+        AssertRowTemplateNames(vec!["Task".to_owned()]),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn row_template_duplicate_database_test() {
+    let mut test = DatabaseRowTemplateTest::new().await;
+    let new_database_id = gen_grid_id();
+    let scripts = vec![
+        SaveRowTemplate {
+            name: "Lead".to_owned(),
+            row_index: 0,
+            include_primary: false,
+        },
+        DuplicateDatabase {
+            new_database_id: new_database_id.clone(),
+        },
+        AssertRowTemplateNamesInDatabase {
+            database_id: new_database_id,
+            expected: vec!["Lead".to_owned()],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
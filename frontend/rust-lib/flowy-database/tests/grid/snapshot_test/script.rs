@@ -1,9 +1,12 @@
 use crate::grid::database_editor::DatabaseEditorTest;
 
+use diesel::RunQueryDsl;
 use flowy_client_sync::client_database::{DatabaseOperations, DatabaseRevisionPad};
+use flowy_database::entities::{CreateRowParams, DatabaseViewLayout};
+use flowy_database::manager::RevisionCompressConfig;
 use flowy_revision::{RevisionSnapshot, REVISION_WRITE_INTERVAL_IN_MILLIS};
 use grid_model::FieldRevision;
-use revision_model::Revision;
+use revision_model::{Revision, RevisionRange};
 use std::time::Duration;
 use tokio::time::sleep;
 
@@ -24,6 +27,53 @@ pub enum SnapshotScript {
     DeleteField {
         field_rev: FieldRevision,
     },
+    CreateEmptyRow,
+    DeleteAllRows,
+    AssertNumberOfRows(usize),
+    /// Generates the snapshots of the database and its blocks.
+    WriteDatabaseSnapshot,
+    /// Restores the database from its latest snapshot.
+    RestoreFromLatestSnapshot,
+    /// Restores the database from the snapshot that was generated before the latest one.
+    RestoreFromPreviousSnapshot,
+    /// Converts the timestamps of the existing snapshots to seconds, as the snapshots that were
+    /// generated by the old versions.
+    ConvertSnapshotTimestampsToSeconds,
+    /// Runs the migration that converts the timestamps of the snapshots to milliseconds.
+    MigrateSnapshotTimestamps,
+    AssertSnapshotTimestampsInMillis,
+    /// Closes the database and opens it again, so the rows are loaded from disk.
+    ReopenDatabase,
+    CreateCheckpoint {
+        name: String,
+    },
+    RollbackToCheckpoint {
+        name: String,
+    },
+    /// Asserts the names of the checkpoints, the latest one first.
+    AssertCheckpointNames(Vec<String>),
+    AssertNumberOfFields(usize),
+    /// Closes the database and opens it again with the merge threshold of the revisions.
+    ReopenWithMergeThreshold(usize),
+    AssertDiff {
+        from_rev_id: i64,
+        to_rev_id: i64,
+        expected: ExpectedDiff,
+    },
+    /// Asserts that the object has revisions and all of them are written by the current user on
+    /// the `device_id`.
+    AssertRevisionAuthors {
+        object_id: String,
+        device_id: &'static str,
+    },
+}
+
+pub struct ExpectedDiff {
+    pub is_approximate: bool,
+    pub inserted_field_ids: Vec<String>,
+    pub deleted_field_ids: Vec<String>,
+    pub inserted_row_ids: Vec<String>,
+    pub deleted_row_ids: Vec<String>,
 }
 
 pub struct DatabaseSnapshotTest {
@@ -85,6 +135,139 @@ impl DatabaseSnapshotTest {
             SnapshotScript::DeleteField { field_rev } => {
                 self.editor.delete_field(&field_rev.id).await.unwrap();
             }
+            SnapshotScript::CreateEmptyRow => {
+                let params = CreateRowParams {
+                    database_id: self.editor.database_id.clone(),
+                    start_row_id: None,
+                    group_id: None,
+                    layout: DatabaseViewLayout::Grid,
+                };
+                self.editor.create_row(params).await.unwrap();
+            }
+            SnapshotScript::DeleteAllRows => {
+                for row_rev in self.get_row_revs().await {
+                    self.editor.delete_row(&row_rev.id).await.unwrap();
+                }
+            }
+            SnapshotScript::AssertNumberOfRows(expected) => {
+                assert_eq!(self.get_row_revs().await.len(), expected);
+            }
+            SnapshotScript::WriteDatabaseSnapshot => {
+                self.editor.generate_snapshot().await;
+                self.current_snapshot = rev_manager.read_snapshot(None).await.unwrap();
+            }
+            SnapshotScript::RestoreFromLatestSnapshot => {
+                let snapshots = self.editor.get_snapshots(1).await.unwrap();
+                assert_eq!(snapshots.len(), 1);
+                let snapshot = &snapshots[0];
+                assert!(snapshot.size > 0);
+                self.editor.restore_from_snapshot(snapshot.rev_id).await.unwrap();
+            }
+            SnapshotScript::RestoreFromPreviousSnapshot => {
+                let snapshots = self.editor.get_snapshots(2).await.unwrap();
+                assert_eq!(snapshots.len(), 2);
+                let snapshot = &snapshots[1];
+                self.editor.restore_from_snapshot(snapshot.rev_id).await.unwrap();
+            }
+            SnapshotScript::ConvertSnapshotTimestampsToSeconds => {
+                let sql = "UPDATE grid_rev_snapshot SET timestamp = timestamp / 1000";
+                let conn = self.sdk.user_session.db_pool().unwrap().get().unwrap();
+                diesel::sql_query(sql).execute(&*conn).unwrap();
+            }
+            SnapshotScript::MigrateSnapshotTimestamps => {
+                let sql = include_str!(
+                    "../../../../flowy-sqlite/migrations/2023-02-13-021840_grid-rev-snapshot-timestamp-millis/up.sql"
+                );
+                let conn = self.sdk.user_session.db_pool().unwrap().get().unwrap();
+                diesel::sql_query(sql).execute(&*conn).unwrap();
+            }
+            SnapshotScript::AssertSnapshotTimestampsInMillis => {
+                let snapshots = self.editor.get_snapshots(usize::MAX).await.unwrap();
+                assert!(!snapshots.is_empty());
+                // 100000000000 milliseconds is in 1973, while in seconds it's in the year 5138.
+                assert!(snapshots.iter().all(|snapshot| snapshot.created_at >= 100_000_000_000));
+                assert!(snapshots
+                    .windows(2)
+                    .all(|snapshots| snapshots[0].created_at >= snapshots[1].created_at));
+            }
+            SnapshotScript::ReopenDatabase => {
+                let database_manager = self.sdk.grid_manager.clone();
+                database_manager.close_all().await;
+                self.editor = database_manager.open_database(&self.view_id).await.unwrap();
+            }
+            SnapshotScript::CreateCheckpoint { name } => {
+                let checkpoint = self.editor.create_checkpoint(&name).await.unwrap();
+                assert_eq!(checkpoint.name, name);
+            }
+            SnapshotScript::RollbackToCheckpoint { name } => {
+                self.editor.rollback_to_checkpoint(&name).await.unwrap();
+            }
+            SnapshotScript::AssertCheckpointNames(expected) => {
+                let names = self
+                    .editor
+                    .list_checkpoints()
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|checkpoint| checkpoint.name)
+                    .collect::<Vec<String>>();
+                assert_eq!(names, expected);
+            }
+            SnapshotScript::AssertNumberOfFields(expected) => {
+                assert_eq!(self.editor.get_field_revs(None).await.unwrap().len(), expected);
+            }
+            SnapshotScript::ReopenWithMergeThreshold(merge_threshold) => {
+                let database_manager = self.sdk.grid_manager.clone();
+                database_manager.set_rev_compress_config(RevisionCompressConfig::with_merge_threshold(merge_threshold));
+                database_manager.close_all().await;
+                self.editor = database_manager.open_database(&self.view_id).await.unwrap();
+            }
+            SnapshotScript::AssertDiff {
+                from_rev_id,
+                to_rev_id,
+                expected,
+            } => {
+                let diff = self.editor.diff_revisions(from_rev_id, to_rev_id).await.unwrap();
+                assert_eq!(diff.is_approximate, expected.is_approximate);
+                let inserted_field_ids = diff
+                    .inserted_fields
+                    .into_iter()
+                    .map(|field| field.id)
+                    .collect::<Vec<_>>();
+                assert_eq!(inserted_field_ids, expected.inserted_field_ids);
+                let deleted_field_ids = diff
+                    .deleted_fields
+                    .into_iter()
+                    .map(|field| field.id)
+                    .collect::<Vec<_>>();
+                assert_eq!(deleted_field_ids, expected.deleted_field_ids);
+                let inserted_row_ids = diff
+                    .blocks
+                    .iter()
+                    .flat_map(|block| block.inserted_row_ids.clone())
+                    .collect::<Vec<_>>();
+                assert_eq!(inserted_row_ids, expected.inserted_row_ids);
+                let deleted_row_ids = diff
+                    .blocks
+                    .iter()
+                    .flat_map(|block| block.deleted_row_ids.clone())
+                    .collect::<Vec<_>>();
+                assert_eq!(deleted_row_ids, expected.deleted_row_ids);
+            }
+            SnapshotScript::AssertRevisionAuthors { object_id, device_id } => {
+                let range = RevisionRange {
+                    start: 0,
+                    end: i64::MAX,
+                };
+                let authors = self.editor.get_revision_authors(&object_id, range).await.unwrap();
+                assert!(!authors.is_empty());
+                let user_id = self.sdk.user_session.user_id().unwrap();
+                for author in authors.values() {
+                    assert_eq!(author.user_id, user_id);
+                    assert_eq!(author.device_id, device_id);
+                    assert!(author.timestamp > 0);
+                }
+            }
         }
     }
 }
@@ -1,5 +1,12 @@
 use crate::grid::field_test::util::create_text_field;
-use crate::grid::snapshot_test::script::{DatabaseSnapshotTest, SnapshotScript::*};
+use crate::grid::snapshot_test::script::{DatabaseSnapshotTest, ExpectedDiff, SnapshotScript::*};
+use flowy_database::entities::FieldType;
+use flowy_database::services::persistence::rev_sqlite::LOCAL_DEVICE_ID;
+use flowy_database::services::revision_preview::RevisionConflictKind;
+use flowy_database::services::snapshot::pre_rollback_checkpoint_name;
+use revision_model::Revision;
+use std::time::Duration;
+use tokio::time::sleep;
 
 #[tokio::test]
 async fn snapshot_create_test() {
@@ -43,3 +50,236 @@ async fn snapshot_multi_version_test() {
     }])
     .await;
 }
+
+#[tokio::test]
+async fn snapshot_restore_rows_test() {
+    let mut test = DatabaseSnapshotTest::new().await;
+    let expected = test.row_revs.len() + 2;
+    let scripts = vec![
+        CreateEmptyRow,
+        CreateEmptyRow,
+        AssertNumberOfRows(expected),
+        WriteDatabaseSnapshot,
+        DeleteAllRows,
+        AssertNumberOfRows(0),
+        RestoreFromLatestSnapshot,
+        AssertNumberOfRows(expected),
+        ReopenDatabase,
+        AssertNumberOfRows(expected),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn snapshot_restore_with_legacy_timestamps_test() {
+    let mut test = DatabaseSnapshotTest::new().await;
+    let expected = test.row_revs.len();
+    let scripts = vec![
+        CreateEmptyRow,
+        WriteDatabaseSnapshot,
+        // The snapshots above are generated by the old version, in seconds
+        ConvertSnapshotTimestampsToSeconds,
+        CreateEmptyRow,
+        WriteDatabaseSnapshot,
+        MigrateSnapshotTimestamps,
+        AssertSnapshotTimestampsInMillis,
+        RestoreFromPreviousSnapshot,
+        AssertNumberOfRows(expected + 1),
+        RestoreFromLatestSnapshot,
+        AssertNumberOfRows(expected + 2),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn snapshot_restore_then_edit_test() {
+    let mut test = DatabaseSnapshotTest::new().await;
+    let expected = test.row_revs.len();
+    let scripts = vec![
+        WriteDatabaseSnapshot,
+        DeleteAllRows,
+        RestoreFromLatestSnapshot,
+        AssertNumberOfRows(expected),
+        // The database keeps working after restoring
+        CreateEmptyRow,
+        AssertNumberOfRows(expected + 1),
+        ReopenDatabase,
+        AssertNumberOfRows(expected + 1),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn checkpoint_rollback_after_field_deletion_test() {
+    let mut test = DatabaseSnapshotTest::new().await;
+    let number_of_fields = test.field_revs.len();
+    let number_of_rows = test.row_revs.len();
+    let field_rev = test.get_first_field_rev(FieldType::Number).as_ref().clone();
+    let pre_rollback_name = pre_rollback_checkpoint_name("v1");
+    let scripts = vec![
+        CreateCheckpoint { name: "v1".to_owned() },
+        DeleteField { field_rev },
+        DeleteAllRows,
+        AssertNumberOfFields(number_of_fields - 1),
+        RollbackToCheckpoint { name: "v1".to_owned() },
+        AssertNumberOfFields(number_of_fields),
+        AssertNumberOfRows(number_of_rows),
+        AssertCheckpointNames(vec![pre_rollback_name.clone(), "v1".to_owned()]),
+        ReopenDatabase,
+        AssertNumberOfFields(number_of_fields),
+        AssertNumberOfRows(number_of_rows),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn checkpoint_revert_rollback_test() {
+    let mut test = DatabaseSnapshotTest::new().await;
+    let number_of_fields = test.field_revs.len();
+    let field_rev = test.get_first_field_rev(FieldType::Number).as_ref().clone();
+    let scripts = vec![
+        CreateCheckpoint { name: "v1".to_owned() },
+        DeleteField { field_rev },
+        DeleteAllRows,
+        RollbackToCheckpoint { name: "v1".to_owned() },
+        AssertNumberOfFields(number_of_fields),
+        // Rolling back to the automatic checkpoint reverts the rollback.
+        RollbackToCheckpoint {
+            name: pre_rollback_checkpoint_name("v1"),
+        },
+        AssertNumberOfFields(number_of_fields - 1),
+        AssertNumberOfRows(0),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn diff_revisions_test() {
+    let mut test = DatabaseSnapshotTest::new().await;
+    test.run_scripts(vec![ReopenWithMergeThreshold(1)]).await;
+    let from_rev_id = test.editor.rev_manager().rev_id();
+    let row_ids = test
+        .get_row_revs()
+        .await
+        .into_iter()
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<_>>();
+    // The rows are read by the time, in seconds, when the revisions were written.
+    sleep(Duration::from_millis(1100)).await;
+
+    let (_, field_rev) = create_text_field(&test.grid_id());
+    let deleted_field_rev = test.get_first_field_rev(FieldType::Number).as_ref().clone();
+    let scripts = vec![
+        CreateField {
+            field_rev: field_rev.clone(),
+        },
+        DeleteField {
+            field_rev: deleted_field_rev.clone(),
+        },
+        CreateEmptyRow,
+    ];
+    test.run_scripts(scripts).await;
+
+    let inserted_row_ids = test
+        .get_row_revs()
+        .await
+        .into_iter()
+        .map(|row_rev| row_rev.id.clone())
+        .filter(|row_id| !row_ids.contains(row_id))
+        .collect::<Vec<_>>();
+    assert_eq!(inserted_row_ids.len(), 1);
+    let to_rev_id = test.editor.rev_manager().rev_id();
+    let scripts = vec![AssertDiff {
+        from_rev_id,
+        to_rev_id,
+        expected: ExpectedDiff {
+            is_approximate: false,
+            inserted_field_ids: vec![field_rev.id.clone()],
+            deleted_field_ids: vec![deleted_field_rev.id.clone()],
+            inserted_row_ids,
+            deleted_row_ids: vec![],
+        },
+    }];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn diff_revisions_before_oldest_revision_test() {
+    let mut test = DatabaseSnapshotTest::new().await;
+    let to_rev_id = test.editor.rev_manager().rev_id();
+    let scripts = vec![AssertDiff {
+        from_rev_id: 0,
+        to_rev_id,
+        expected: ExpectedDiff {
+            is_approximate: true,
+            inserted_field_ids: vec![],
+            deleted_field_ids: vec![],
+            inserted_row_ids: vec![],
+            deleted_row_ids: vec![],
+        },
+    }];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn revision_authors_test() {
+    let mut test = DatabaseSnapshotTest::new().await;
+    let (_, field_rev) = create_text_field(&test.grid_id());
+    let block_id = test.block_meta_revs.first().unwrap().block_id.clone();
+    let scripts = vec![
+        CreateField { field_rev },
+        CreateEmptyRow,
+        AssertRevisionAuthors {
+            object_id: test.grid_id(),
+            device_id: LOCAL_DEVICE_ID,
+        },
+        AssertRevisionAuthors {
+            object_id: block_id,
+            device_id: LOCAL_DEVICE_ID,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn preview_stale_revision_test() {
+    let mut test = DatabaseSnapshotTest::new().await;
+    let (_, field_rev) = create_text_field(&test.grid_id());
+    test.run_scripts(vec![ReopenWithMergeThreshold(1), CreateField { field_rev }])
+        .await;
+    let rev_manager = test.editor.rev_manager();
+    let latest_rev_id = rev_manager.rev_id();
+    let number_of_fields = test.editor.get_field_revs(None).await.unwrap().len();
+
+    let (_, field_rev) = create_text_field(&test.grid_id());
+    let mut database_pad = test.grid_pad().await;
+    let changeset = database_pad.create_field_rev(field_rev.clone(), None).unwrap().unwrap();
+    let bytes = changeset.operations.json_bytes();
+
+    // The revision takes the rev_id of the latest local revision, as if it was made without
+    // knowing that revision.
+    let stale_revision = Revision::new(
+        &test.grid_id(),
+        latest_rev_id - 1,
+        latest_rev_id,
+        bytes.clone(),
+        changeset.md5.clone(),
+    );
+    let preview = test.editor.preview_revisions(vec![stale_revision]).await.unwrap();
+    assert!(!preview.applies_cleanly());
+    assert_eq!(preview.conflicts[0].rev_id, latest_rev_id);
+    assert_eq!(preview.conflicts[0].kind, RevisionConflictKind::Stale);
+    assert!(preview.inserted_fields.is_empty());
+
+    // The stale revision doesn't affect the next preview.
+    let revision = Revision::new(&test.grid_id(), latest_rev_id, latest_rev_id + 1, bytes, changeset.md5);
+    let preview = test.editor.preview_revisions(vec![revision]).await.unwrap();
+    assert!(preview.applies_cleanly());
+    assert_eq!(preview.applied_rev_ids, vec![latest_rev_id + 1]);
+    assert_eq!(preview.inserted_fields.len(), 1);
+    assert_eq!(preview.inserted_fields[0].id, field_rev.id);
+
+    // Nothing is written to the database.
+    test.run_scripts(vec![AssertNumberOfFields(number_of_fields)]).await;
+    assert_eq!(rev_manager.rev_id(), latest_rev_id);
+}
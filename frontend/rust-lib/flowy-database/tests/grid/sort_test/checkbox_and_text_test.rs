@@ -2,6 +2,41 @@ use crate::grid::sort_test::script::{DatabaseSortTest, SortScript::*};
 use flowy_database::entities::FieldType;
 use grid_model::SortCondition;
 
+#[tokio::test]
+async fn sort_checkbox_and_checklist_fields_are_sortable_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let scripts = vec![AssertSortableFieldTypes(vec![
+        FieldType::Checkbox,
+        FieldType::Checklist,
+    ])];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_checkbox_without_cell_as_unchecked_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let checkbox_field = test.get_first_field_rev(FieldType::Checkbox).clone();
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let scripts = vec![
+        CreateRowAtIndex {
+            index: 6,
+            text_field_id: text_field.id.clone(),
+            text: "F".to_owned(),
+            expected_index: Some(6),
+        },
+        InsertSort {
+            field_rev: checkbox_field,
+            condition: SortCondition::Ascending,
+        },
+        // The row without the checkbox cell keeps its place among the unchecked rows.
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["C", "DA", "AE", "F", "A", "", "AE"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
 #[tokio::test]
 async fn sort_checkbox_and_then_text_by_descending_test() {
     let mut test = DatabaseSortTest::new().await;
@@ -32,7 +67,7 @@ async fn sort_checkbox_and_then_text_by_descending_test() {
         // Insert text sort. After inserting the text sort, the order of the rows
         // will be changed.
         // before: ["A", "", "AE", "C", "DA", "AE"]
-        // after: ["", "A", "AE", "AE", "C", "DA"]
+        // after: ["A", "AE", "", "AE", "C", "DA"]
         InsertSort {
             field_rev: text_field.clone(),
             condition: SortCondition::Ascending,
@@ -43,7 +78,7 @@ async fn sort_checkbox_and_then_text_by_descending_test() {
         },
         AssertCellContentOrder {
             field_id: text_field.id.clone(),
-            orders: vec!["", "A", "AE", "AE", "C", "DA"],
+            orders: vec!["A", "AE", "", "AE", "C", "DA"],
         },
     ];
     test.run_scripts(scripts).await;
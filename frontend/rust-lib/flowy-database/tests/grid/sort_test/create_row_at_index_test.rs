@@ -0,0 +1,63 @@
+use crate::grid::sort_test::script::{DatabaseSortTest, SortScript::*};
+use flowy_database::entities::FieldType;
+use grid_model::SortCondition;
+
+#[tokio::test]
+async fn create_row_at_index_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText);
+    let scripts = vec![
+        CreateRowAtIndex {
+            index: 2,
+            text_field_id: text_field.id.clone(),
+            text: "B".to_owned(),
+            expected_index: Some(2),
+        },
+        CreateRowAtIndex {
+            index: 0,
+            text_field_id: text_field.id.clone(),
+            text: "Z".to_owned(),
+            expected_index: Some(0),
+        },
+        // The row is appended if the index is out of bounds.
+        CreateRowAtIndex {
+            index: 100,
+            text_field_id: text_field.id.clone(),
+            text: "Y".to_owned(),
+            expected_index: Some(8),
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["Z", "A", "", "B", "C", "DA", "AE", "AE", "Y"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn create_row_at_index_with_sort_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText);
+    let scripts = vec![
+        InsertSort {
+            field_rev: text_field.clone(),
+            condition: SortCondition::Ascending,
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["A", "AE", "AE", "C", "DA", ""],
+        },
+        // The row is inserted at the top, then it's moved to its place by the sort.
+        CreateRowAtIndex {
+            index: 0,
+            text_field_id: text_field.id.clone(),
+            text: "B".to_owned(),
+            expected_index: Some(3),
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["A", "AE", "AE", "B", "C", "DA", ""],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -0,0 +1,119 @@
+use crate::grid::sort_test::script::{DatabaseSortTest, SortScript::*};
+use flowy_database::entities::{FieldType, RowOrderSourcePB};
+use grid_model::SortCondition;
+
+#[tokio::test]
+async fn delete_field_of_only_sort_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let number_field = test.get_first_field_rev(FieldType::Number).clone();
+    let row_ids = test
+        .get_row_revs()
+        .await
+        .into_iter()
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<String>>();
+    let scripts = vec![
+        InsertSort {
+            field_rev: number_field.clone(),
+            condition: SortCondition::Descending,
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["AE", "DA", "C", "", "A", "AE"],
+        },
+        MoveRow {
+            row_id: row_ids[3].clone(),
+            to_index: 0,
+            remove_sorts: false,
+        },
+        DeleteField {
+            field_id: number_field.id.clone(),
+        },
+        AssertSortIds(vec![]),
+        // The rows are back in the manual order once the only sort is gone.
+        AssertEffectiveRowOrder {
+            source: RowOrderSourcePB::Manual,
+            row_ids: vec![
+                row_ids[3].clone(),
+                row_ids[0].clone(),
+                row_ids[1].clone(),
+                row_ids[2].clone(),
+                row_ids[4].clone(),
+                row_ids[5].clone(),
+            ],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn delete_field_of_secondary_sort_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let checkbox_field = test.get_first_field_rev(FieldType::Checkbox).clone();
+    let number_field = test.get_first_field_rev(FieldType::Number).clone();
+    test.run_scripts(vec![InsertSort {
+        field_rev: checkbox_field.clone(),
+        condition: SortCondition::Ascending,
+    }])
+    .await;
+    let checkbox_sort_id = test.current_sort_rev.clone().unwrap().id;
+    let scripts = vec![
+        InsertSort {
+            field_rev: number_field.clone(),
+            condition: SortCondition::Descending,
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["DA", "C", "AE", "AE", "", "A"],
+        },
+        DeleteField {
+            field_id: number_field.id.clone(),
+        },
+        // Only the sort of the deleted field is removed, the rows are still sorted by the checkbox.
+        AssertSortIds(vec![checkbox_sort_id]),
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["C", "DA", "AE", "A", "", "AE"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn delete_fields_of_all_sorts_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let checkbox_field = test.get_first_field_rev(FieldType::Checkbox).clone();
+    let number_field = test.get_first_field_rev(FieldType::Number).clone();
+    let row_ids = test
+        .get_row_revs()
+        .await
+        .into_iter()
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<String>>();
+    let scripts = vec![
+        InsertSort {
+            field_rev: checkbox_field.clone(),
+            condition: SortCondition::Ascending,
+        },
+        InsertSort {
+            field_rev: number_field.clone(),
+            condition: SortCondition::Descending,
+        },
+        DeleteFields {
+            field_ids: vec![checkbox_field.id.clone(), number_field.id.clone()],
+        },
+        AssertSortIds(vec![]),
+        AssertEffectiveRowOrder {
+            source: RowOrderSourcePB::Block,
+            row_ids,
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["A", "", "C", "DA", "AE", "AE"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -0,0 +1,162 @@
+use crate::grid::sort_test::script::{DatabaseSortTest, SortScript, SortScript::*};
+use flowy_database::entities::{FieldType, SortEmptyOrderPB};
+use grid_model::{SortCondition, SortEmptyOrder};
+
+/// Appends two rows that have only the text cells, so their other cells are empty.
+fn create_rows_with_empty_cells(test: &DatabaseSortTest) -> Vec<SortScript> {
+    let text_field = test.get_first_field_rev(FieldType::RichText);
+    vec![
+        CreateRowAtIndex {
+            index: 6,
+            text_field_id: text_field.id.clone(),
+            text: "F".to_owned(),
+            expected_index: Some(6),
+        },
+        CreateRowAtIndex {
+            index: 7,
+            text_field_id: text_field.id.clone(),
+            text: "G".to_owned(),
+            expected_index: Some(7),
+        },
+    ]
+}
+
+#[tokio::test]
+async fn sort_number_by_ascending_with_empty_cells_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let number_field = test.get_first_field_rev(FieldType::Number).clone();
+    let mut scripts = create_rows_with_empty_cells(&test);
+    scripts.extend(vec![
+        InsertSort {
+            field_rev: number_field.clone(),
+            condition: SortCondition::Ascending,
+        },
+        // The empty cells are placed after the other cells by default.
+        AssertCellContentOrder {
+            field_id: number_field.id.clone(),
+            orders: vec!["$1", "$2", "$3", "$4", "$5", "", "", ""],
+        },
+        UpdateSortEmptyOrder {
+            empty_order: SortEmptyOrder::First,
+        },
+        AssertCellContentOrder {
+            field_id: number_field.id.clone(),
+            orders: vec!["", "", "", "$1", "$2", "$3", "$4", "$5"],
+        },
+        AssertEmptyOrdersOfDuplicatedView(vec![SortEmptyOrderPB::First]),
+    ]);
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_number_by_descending_with_empty_cells_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let number_field = test.get_first_field_rev(FieldType::Number).clone();
+    let mut scripts = create_rows_with_empty_cells(&test);
+    scripts.extend(vec![
+        InsertSort {
+            field_rev: number_field.clone(),
+            condition: SortCondition::Descending,
+        },
+        // The empty cells are not reversed by the descending sort.
+        AssertCellContentOrder {
+            field_id: number_field.id.clone(),
+            orders: vec!["$5", "$4", "$3", "$2", "$1", "", "", ""],
+        },
+        UpdateSortEmptyOrder {
+            empty_order: SortEmptyOrder::First,
+        },
+        AssertCellContentOrder {
+            field_id: number_field.id.clone(),
+            orders: vec!["", "", "", "$5", "$4", "$3", "$2", "$1"],
+        },
+    ]);
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_date_by_ascending_with_empty_cells_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let date_field = test.get_first_field_rev(FieldType::DateTime).clone();
+    let mut scripts = create_rows_with_empty_cells(&test);
+    scripts.extend(vec![
+        InsertSort {
+            field_rev: date_field.clone(),
+            condition: SortCondition::Ascending,
+        },
+        AssertCellContentOrder {
+            field_id: date_field.id.clone(),
+            orders: vec![
+                "2022/03/14",
+                "2022/03/14",
+                "2022/03/14",
+                "2022/11/13",
+                "2022/11/17",
+                "2022/12/25",
+                "",
+                "",
+            ],
+        },
+        UpdateSortEmptyOrder {
+            empty_order: SortEmptyOrder::First,
+        },
+        AssertCellContentOrder {
+            field_id: date_field.id.clone(),
+            orders: vec![
+                "",
+                "",
+                "2022/03/14",
+                "2022/03/14",
+                "2022/03/14",
+                "2022/11/13",
+                "2022/11/17",
+                "2022/12/25",
+            ],
+        },
+    ]);
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_date_by_descending_with_empty_cells_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let date_field = test.get_first_field_rev(FieldType::DateTime).clone();
+    let mut scripts = create_rows_with_empty_cells(&test);
+    scripts.extend(vec![
+        InsertSort {
+            field_rev: date_field.clone(),
+            condition: SortCondition::Descending,
+        },
+        AssertCellContentOrder {
+            field_id: date_field.id.clone(),
+            orders: vec![
+                "2022/12/25",
+                "2022/11/17",
+                "2022/11/13",
+                "2022/03/14",
+                "2022/03/14",
+                "2022/03/14",
+                "",
+                "",
+            ],
+        },
+        UpdateSortEmptyOrder {
+            empty_order: SortEmptyOrder::First,
+        },
+        AssertCellContentOrder {
+            field_id: date_field.id.clone(),
+            orders: vec![
+                "",
+                "",
+                "2022/12/25",
+                "2022/11/17",
+                "2022/11/13",
+                "2022/03/14",
+                "2022/03/14",
+                "2022/03/14",
+            ],
+        },
+        AssertEmptyOrdersOfDuplicatedView(vec![SortEmptyOrderPB::First]),
+    ]);
+    test.run_scripts(scripts).await;
+}
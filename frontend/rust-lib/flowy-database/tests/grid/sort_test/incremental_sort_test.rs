@@ -0,0 +1,110 @@
+use crate::grid::sort_test::script::DatabaseSortTest;
+use crate::grid::sort_test::script::SortScript::*;
+use flowy_database::entities::FieldType;
+use grid_model::SortCondition;
+
+#[tokio::test]
+async fn sort_update_text_compares_log_n_rows_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let scripts = vec![
+        InsertSort {
+            field_rev: text_field.clone(),
+            condition: SortCondition::Ascending,
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["A", "AE", "AE", "C", "DA", ""],
+        },
+        Wait { millis: 200 },
+    ];
+    test.run_scripts(scripts).await;
+
+    // The changed row is inserted into the other 5 rows by the binary search, so it's compared
+    // with at most 3 of them.
+    let row_revs = test.get_row_revs().await;
+    let scripts = vec![
+        MarkSortComparisonCount,
+        UpdateTextCell {
+            row_id: row_revs[1].id.clone(),
+            text: "D".to_string(),
+        },
+        AssertSortChanged {
+            old_row_orders: vec!["A", "D", "AE", "C", "DA", ""],
+            new_row_orders: vec!["A", "AE", "C", "D", "DA", ""],
+        },
+        AssertSortComparisonCount { at_most: 3 },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["A", "AE", "C", "D", "DA", ""],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_update_text_moves_row_to_front_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let scripts = vec![
+        InsertSort {
+            field_rev: text_field.clone(),
+            condition: SortCondition::Ascending,
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["A", "AE", "AE", "C", "DA", ""],
+        },
+        Wait { millis: 200 },
+    ];
+    test.run_scripts(scripts).await;
+
+    let row_revs = test.get_row_revs().await;
+    let scripts = vec![
+        MarkSortComparisonCount,
+        UpdateTextCell {
+            row_id: row_revs[3].id.clone(),
+            text: "0".to_string(),
+        },
+        AssertSortChanged {
+            old_row_orders: vec!["A", "AE", "AE", "0", "DA", ""],
+            new_row_orders: vec!["0", "A", "AE", "AE", "DA", ""],
+        },
+        AssertSortComparisonCount { at_most: 3 },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["0", "A", "AE", "AE", "DA", ""],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_update_unsorted_field_compares_no_rows_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let scripts = vec![
+        InsertSort {
+            field_rev: text_field.clone(),
+            condition: SortCondition::Ascending,
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["A", "AE", "AE", "C", "DA", ""],
+        },
+        Wait { millis: 200 },
+    ];
+    test.run_scripts(scripts).await;
+
+    let row_revs = test.get_row_revs().await;
+    let scripts = vec![
+        MarkSortComparisonCount,
+        UpdateCheckboxCell {
+            row_id: row_revs[2].id.clone(),
+            is_check: true,
+        },
+        Wait { millis: 200 },
+        AssertSortComparisonCount { at_most: 0 },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -0,0 +1,182 @@
+use crate::grid::sort_test::script::{DatabaseSortTest, SortScript::*};
+use flowy_database::entities::{FieldType, RowOrderSourcePB};
+use grid_model::SortCondition;
+
+#[tokio::test]
+async fn sort_manual_order_round_trip_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let row_ids = test
+        .get_row_revs()
+        .await
+        .into_iter()
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<String>>();
+    let scripts = vec![
+        AssertEffectiveRowOrder {
+            source: RowOrderSourcePB::Block,
+            row_ids: row_ids.clone(),
+        },
+        InsertSort {
+            field_rev: text_field.clone(),
+            condition: SortCondition::Ascending,
+        },
+        // Moving the sorted row keeps the sorted order, and moves the row in the manual order.
+        MoveRow {
+            row_id: row_ids[3].clone(),
+            to_index: 0,
+            remove_sorts: false,
+        },
+        AssertEffectiveRowOrder {
+            source: RowOrderSourcePB::Sort,
+            row_ids: vec![
+                row_ids[0].clone(),
+                row_ids[4].clone(),
+                row_ids[5].clone(),
+                row_ids[2].clone(),
+                row_ids[3].clone(),
+                row_ids[1].clone(),
+            ],
+        },
+    ];
+    test.run_scripts(scripts).await;
+
+    let sort_rev = test.current_sort_rev.clone().unwrap();
+    let manual_row_ids = vec![
+        row_ids[3].clone(),
+        row_ids[0].clone(),
+        row_ids[1].clone(),
+        row_ids[2].clone(),
+        row_ids[4].clone(),
+        row_ids[5].clone(),
+    ];
+    let scripts = vec![
+        DeleteSort {
+            field_rev: text_field.clone(),
+            sort_id: sort_rev.id,
+        },
+        AssertEffectiveRowOrder {
+            source: RowOrderSourcePB::Manual,
+            row_ids: manual_row_ids.clone(),
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["DA", "A", "", "C", "AE", "AE"],
+        },
+        InsertSort {
+            field_rev: text_field.clone(),
+            condition: SortCondition::Descending,
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["DA", "C", "AE", "AE", "A", ""],
+        },
+    ];
+    test.run_scripts(scripts).await;
+
+    let sort_rev = test.current_sort_rev.clone().unwrap();
+    let scripts = vec![
+        DeleteSort {
+            field_rev: text_field.clone(),
+            sort_id: sort_rev.id,
+        },
+        AssertEffectiveRowOrder {
+            source: RowOrderSourcePB::Manual,
+            row_ids: manual_row_ids,
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_move_row_in_manual_order_without_sorts_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let row_ids = test
+        .get_row_revs()
+        .await
+        .into_iter()
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<String>>();
+    let scripts = vec![InsertSort {
+        field_rev: text_field.clone(),
+        condition: SortCondition::Ascending,
+    }];
+    test.run_scripts(scripts).await;
+    let sort_rev = test.current_sort_rev.clone().unwrap();
+    let scripts = vec![
+        MoveRow {
+            row_id: row_ids[5].clone(),
+            to_index: 0,
+            remove_sorts: false,
+        },
+        DeleteSort {
+            field_rev: text_field.clone(),
+            sort_id: sort_rev.id,
+        },
+        // The rows that were moved while the view was sorted keep moving in the manual order.
+        MoveRow {
+            row_id: row_ids[2].clone(),
+            to_index: 1,
+            remove_sorts: false,
+        },
+        Wait { millis: 500 },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["AE", "C", "A", "", "DA", "AE"],
+        },
+        AssertEffectiveRowOrder {
+            source: RowOrderSourcePB::Manual,
+            row_ids: vec![
+                row_ids[5].clone(),
+                row_ids[2].clone(),
+                row_ids[0].clone(),
+                row_ids[1].clone(),
+                row_ids[3].clone(),
+                row_ids[4].clone(),
+            ],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_move_row_and_remove_sorts_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let row_ids = test
+        .get_row_revs()
+        .await
+        .into_iter()
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<String>>();
+    let scripts = vec![
+        InsertSort {
+            field_rev: text_field.clone(),
+            condition: SortCondition::Ascending,
+        },
+        // The sorted order becomes the manual order, so the row stays where it's moved to.
+        MoveRow {
+            row_id: row_ids[1].clone(),
+            to_index: 0,
+            remove_sorts: true,
+        },
+        AssertSortIds(vec![]),
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["", "A", "AE", "AE", "C", "DA"],
+        },
+        AssertEffectiveRowOrder {
+            source: RowOrderSourcePB::Manual,
+            row_ids: vec![
+                row_ids[1].clone(),
+                row_ids[0].clone(),
+                row_ids[4].clone(),
+                row_ids[5].clone(),
+                row_ids[2].clone(),
+                row_ids[3].clone(),
+            ],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -1,4 +1,14 @@
 mod checkbox_and_text_test;
+mod create_row_at_index_test;
+mod delete_field_test;
+mod empty_order_test;
+mod incremental_sort_test;
+mod manual_order_test;
 mod multi_sort_test;
+mod pin_row_test;
 mod script;
+mod select_option_sort_test;
 mod single_sort_test;
+mod sort_mode_test;
+mod sort_task_test;
+mod text_collation_test;
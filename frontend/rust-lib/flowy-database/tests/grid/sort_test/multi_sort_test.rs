@@ -23,7 +23,7 @@ async fn sort_text_with_checkbox_by_ascending_test() {
         },
         AssertCellContentOrder {
             field_id: text_field.id.clone(),
-            orders: vec!["", "A", "AE", "AE", "C", "DA"],
+            orders: vec!["A", "AE", "AE", "C", "DA", ""],
         },
     ];
     test.run_scripts(scripts).await;
@@ -35,11 +35,98 @@ async fn sort_text_with_checkbox_by_ascending_test() {
         },
         AssertCellContentOrder {
             field_id: text_field.id.clone(),
-            orders: vec!["", "A", "AE", "AE", "C", "DA"],
+            orders: vec!["A", "AE", "AE", "C", "DA", ""],
         },
         AssertCellContentOrder {
             field_id: checkbox_field.id.clone(),
-            orders: vec!["Yes", "Yes", "Yes", "No", "No"],
+            orders: vec!["Yes", "Yes", "No", "No", "No"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_text_and_then_checkbox_breaks_ties_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let checkbox_field = test.get_first_field_rev(FieldType::Checkbox).clone();
+    let scripts = vec![
+        InsertSort {
+            field_rev: text_field.clone(),
+            condition: SortCondition::Ascending,
+        },
+        // The two "AE" rows are equal in the text sort, so they keep their order.
+        AssertCellContentOrder {
+            field_id: checkbox_field.id.clone(),
+            orders: vec!["Yes", "No", "Yes", "No", "No", "Yes"],
+        },
+        Wait { millis: 200 },
+        InsertSort {
+            field_rev: checkbox_field.clone(),
+            condition: SortCondition::Descending,
+        },
+        // The secondary sort only moves the rows that are equal in the text sort.
+        AssertRowsMoved {
+            old_row_orders: vec!["Yes", "No", "Yes", "No", "No", "Yes"],
+            new_row_orders: vec!["Yes", "Yes", "No", "No", "No", "Yes"],
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["A", "AE", "AE", "C", "DA", ""],
+        },
+        AssertCellContentOrder {
+            field_id: checkbox_field.id.clone(),
+            orders: vec!["Yes", "Yes", "No", "No", "No", "Yes"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn reorder_sort_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let checkbox_field = test.get_first_field_rev(FieldType::Checkbox).clone();
+    test.run_scripts(vec![InsertSort {
+        field_rev: text_field.clone(),
+        condition: SortCondition::Ascending,
+    }])
+    .await;
+    let text_sort_id = test.current_sort_rev.clone().unwrap().id;
+    test.run_scripts(vec![InsertSort {
+        field_rev: checkbox_field.clone(),
+        condition: SortCondition::Ascending,
+    }])
+    .await;
+    let checkbox_sort_id = test.current_sort_rev.clone().unwrap().id;
+
+    let scripts = vec![
+        AssertSortIds(vec![text_sort_id.clone(), checkbox_sort_id.clone()]),
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["A", "AE", "AE", "C", "DA", ""],
+        },
+        AssertCellContentOrder {
+            field_id: checkbox_field.id.clone(),
+            orders: vec!["Yes", "No", "Yes", "No", "No", "Yes"],
+        },
+        Wait { millis: 200 },
+        ReorderSort {
+            sort_id: checkbox_sort_id.clone(),
+            new_index: 0,
+        },
+        AssertRowsMoved {
+            old_row_orders: vec!["A", "AE", "AE", "C", "DA", ""],
+            new_row_orders: vec!["AE", "C", "DA", "A", "AE", ""],
+        },
+        AssertSortIds(vec![checkbox_sort_id, text_sort_id]),
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["AE", "C", "DA", "A", "AE", ""],
+        },
+        AssertCellContentOrder {
+            field_id: checkbox_field.id.clone(),
+            orders: vec!["No", "No", "No", "Yes", "Yes", "Yes"],
         },
     ];
     test.run_scripts(scripts).await;
@@ -0,0 +1,84 @@
+use crate::grid::sort_test::script::{DatabaseSortTest, SortScript::*};
+use flowy_database::entities::FieldType;
+use grid_model::SortCondition;
+
+#[tokio::test]
+async fn pin_rows_to_top_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText);
+    let row_revs = test.get_row_revs().await;
+    let scripts = vec![
+        PinRow {
+            row_id: row_revs[3].id.clone(),
+        },
+        PinRow {
+            row_id: row_revs[0].id.clone(),
+        },
+        // Pinning the pinned row again keeps its place.
+        PinRow {
+            row_id: row_revs[3].id.clone(),
+        },
+        AssertPinnedRows(vec![row_revs[3].id.clone(), row_revs[0].id.clone()]),
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["DA", "A", "", "C", "AE", "AE"],
+        },
+        UnpinRow {
+            row_id: row_revs[3].id.clone(),
+        },
+        AssertPinnedRows(vec![row_revs[0].id.clone()]),
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["A", "", "C", "DA", "AE", "AE"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn pinned_rows_ignore_sort_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText);
+    let row_revs = test.get_row_revs().await;
+    let scripts = vec![
+        InsertSort {
+            field_rev: text_field.clone(),
+            condition: SortCondition::Ascending,
+        },
+        PinRow {
+            row_id: row_revs[3].id.clone(),
+        },
+        PinRow {
+            row_id: row_revs[2].id.clone(),
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["DA", "C", "A", "AE", "AE", ""],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn delete_pinned_row_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText);
+    let row_revs = test.get_row_revs().await;
+    let scripts = vec![
+        PinRow {
+            row_id: row_revs[4].id.clone(),
+        },
+        PinRow {
+            row_id: row_revs[2].id.clone(),
+        },
+        DeleteRow {
+            row_id: row_revs[4].id.clone(),
+        },
+        AssertPinnedRows(vec![row_revs[2].id.clone()]),
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["C", "A", "", "DA", "AE"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -1,10 +1,14 @@
 use crate::grid::database_editor::DatabaseEditorTest;
 use async_stream::stream;
-use flowy_database::entities::{AlterSortParams, CellPathParams, DeleteSortParams};
+use flowy_database::entities::{
+    AlterSortParams, CellPathParams, DeleteSortParams, FieldType, MoveRowParams, MoveRowTarget, ReorderSortParams,
+    RowDataPayload, RowOrderSourcePB, SortEmptyOrderPB, SortModePB, UpdateSortModeParams,
+};
+use flowy_database::services::field::{ChecklistCellChangeset, SingleSelectTypeOptionPB, CHECK, UNCHECK};
 use flowy_database::services::sort::SortType;
 use flowy_database::services::view_editor::GridViewChanged;
 use futures::stream::StreamExt;
-use grid_model::{FieldRevision, SortCondition, SortRevision};
+use grid_model::{gen_grid_id, FieldRevision, SortCondition, SortEmptyOrder, SortRevision, TypeOptionDataSerializer};
 use std::cmp::min;
 use std::sync::Arc;
 use std::time::Duration;
@@ -15,6 +19,32 @@ pub enum SortScript {
         field_rev: Arc<FieldRevision>,
         condition: SortCondition,
     },
+    /// Updates the condition of the current sort.
+    UpdateSortCondition {
+        condition: SortCondition,
+    },
+    /// Updates whether the numbers in the texts are compared by their values in the current sort.
+    UpdateSortNatural {
+        natural: bool,
+    },
+    /// Updates whether the select options are sorted by their names in the current sort.
+    UpdateSortAlphabetical {
+        alphabetical: bool,
+    },
+    /// Updates where the rows whose cells are empty are placed in the current sort.
+    UpdateSortEmptyOrder {
+        empty_order: SortEmptyOrder,
+    },
+    UpdateSortMode {
+        sort_mode: SortModePB,
+    },
+    ResortShuffle,
+    /// Moves the option of the single select field to the index of its options.
+    MoveSingleSelectOption {
+        field_id: String,
+        option_name: String,
+        to_index: usize,
+    },
     DeleteSort {
         field_rev: Arc<FieldRevision>,
         sort_id: String,
@@ -27,19 +57,87 @@ pub enum SortScript {
         row_id: String,
         text: String,
     },
+    UpdateChecklistCell {
+        row_id: String,
+        changeset: ChecklistCellChangeset,
+    },
+    UpdateCheckboxCell {
+        row_id: String,
+        is_check: bool,
+    },
     AssertSortChanged {
         old_row_orders: Vec<&'static str>,
         new_row_orders: Vec<&'static str>,
     },
+    /// Asserts that all the rows are reordered by one notification, so the results of the sorts
+    /// that were replaced before they were applied are discarded.
+    AssertAllRowsReorderedOnce(Vec<String>),
+    /// Asserts that only the moved rows are notified after the sorts were changed, and moving them
+    /// turns the old orders into the new orders.
+    AssertRowsMoved {
+        old_row_orders: Vec<&'static str>,
+        new_row_orders: Vec<&'static str>,
+    },
+    ReorderSort {
+        sort_id: String,
+        new_index: usize,
+    },
+    /// Asserts that the fields of the field types can be used as the keys of the sorts.
+    AssertSortableFieldTypes(Vec<FieldType>),
+    /// Asserts the ids of the sorts of the view in the order of their precedence.
+    AssertSortIds(Vec<String>),
+    /// Duplicates the database, and asserts the empty orders of the sorts of the duplicated view.
+    AssertEmptyOrdersOfDuplicatedView(Vec<SortEmptyOrderPB>),
+    MarkSortComparisonCount,
+    /// Asserts the number of the times that the rows were compared since the count was marked.
+    AssertSortComparisonCount {
+        at_most: usize,
+    },
     Wait {
         millis: u64,
     },
+    PinRow {
+        row_id: String,
+    },
+    UnpinRow {
+        row_id: String,
+    },
+    DeleteRow {
+        row_id: String,
+    },
+    DeleteField {
+        field_id: String,
+    },
+    DeleteFields {
+        field_ids: Vec<String>,
+    },
+    /// Moves the row to the index of the rows of the view.
+    MoveRow {
+        row_id: String,
+        to_index: usize,
+        remove_sorts: bool,
+    },
+    /// Asserts where the order of the rows comes from, and the ids of the rows in that order.
+    AssertEffectiveRowOrder {
+        source: RowOrderSourcePB,
+        row_ids: Vec<String>,
+    },
+    /// Asserts the ids of the pinned rows in the setting of the view.
+    AssertPinnedRows(Vec<String>),
+    /// Creates the row with the text at the index of the view, and asserts the index it ends up at.
+    CreateRowAtIndex {
+        index: usize,
+        text_field_id: String,
+        text: String,
+        expected_index: Option<usize>,
+    },
 }
 
 pub struct DatabaseSortTest {
     inner: DatabaseEditorTest,
     pub current_sort_rev: Option<SortRevision>,
     recv: Option<Receiver<GridViewChanged>>,
+    marked_comparison_count: usize,
 }
 
 impl DatabaseSortTest {
@@ -49,6 +147,7 @@ impl DatabaseSortTest {
             inner: editor_test,
             current_sort_rev: None,
             recv: None,
+            marked_comparison_count: 0,
         }
     }
     pub async fn run_scripts(&mut self, scripts: Vec<SortScript>) {
@@ -67,10 +166,108 @@ impl DatabaseSortTest {
                     sort_id: None,
                     field_type: field_rev.ty,
                     condition: condition.into(),
+                    natural: None,
+                    alphabetical: None,
+                    empty_order: None,
+                };
+                let sort_rev = self.editor.create_or_update_sort(params).await.unwrap();
+                self.current_sort_rev = Some(sort_rev);
+            }
+            SortScript::UpdateSortCondition { condition } => {
+                let sort_rev = self.current_sort_rev.clone().unwrap();
+                let params = AlterSortParams {
+                    view_id: self.view_id.clone(),
+                    field_id: sort_rev.field_id.clone(),
+                    sort_id: Some(sort_rev.id.clone()),
+                    field_type: sort_rev.field_type,
+                    condition: condition.clone().into(),
+                    natural: None,
+                    alphabetical: None,
+                    empty_order: None,
+                };
+                let sort_rev = self.editor.create_or_update_sort(params).await.unwrap();
+                assert_eq!(sort_rev.condition, condition);
+                self.current_sort_rev = Some(sort_rev);
+            }
+            SortScript::UpdateSortNatural { natural } => {
+                let sort_rev = self.current_sort_rev.clone().unwrap();
+                let params = AlterSortParams {
+                    view_id: self.view_id.clone(),
+                    field_id: sort_rev.field_id.clone(),
+                    sort_id: Some(sort_rev.id.clone()),
+                    field_type: sort_rev.field_type,
+                    condition: sort_rev.condition.into(),
+                    natural: Some(natural),
+                    alphabetical: None,
+                    empty_order: None,
+                };
+                let sort_rev = self.editor.create_or_update_sort(params).await.unwrap();
+                assert_eq!(sort_rev.natural, natural);
+                self.current_sort_rev = Some(sort_rev);
+            }
+            SortScript::UpdateSortAlphabetical { alphabetical } => {
+                let sort_rev = self.current_sort_rev.clone().unwrap();
+                let params = AlterSortParams {
+                    view_id: self.view_id.clone(),
+                    field_id: sort_rev.field_id.clone(),
+                    sort_id: Some(sort_rev.id.clone()),
+                    field_type: sort_rev.field_type,
+                    condition: sort_rev.condition.into(),
+                    natural: None,
+                    alphabetical: Some(alphabetical),
+                    empty_order: None,
+                };
+                let sort_rev = self.editor.create_or_update_sort(params).await.unwrap();
+                assert_eq!(sort_rev.alphabetical, alphabetical);
+                self.current_sort_rev = Some(sort_rev);
+            }
+            SortScript::UpdateSortEmptyOrder { empty_order } => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id).await.unwrap());
+                let sort_rev = self.current_sort_rev.clone().unwrap();
+                let params = AlterSortParams {
+                    view_id: self.view_id.clone(),
+                    field_id: sort_rev.field_id.clone(),
+                    sort_id: Some(sort_rev.id.clone()),
+                    field_type: sort_rev.field_type,
+                    condition: sort_rev.condition.into(),
+                    natural: None,
+                    alphabetical: None,
+                    empty_order: Some(empty_order as u8),
                 };
                 let sort_rev = self.editor.create_or_update_sort(params).await.unwrap();
+                assert_eq!(sort_rev.empty_order, empty_order);
                 self.current_sort_rev = Some(sort_rev);
             }
+            SortScript::UpdateSortMode { sort_mode } => {
+                let params = UpdateSortModeParams {
+                    view_id: self.view_id.clone(),
+                    sort_mode,
+                };
+                self.editor.update_sort_mode(params).await.unwrap();
+            }
+            SortScript::ResortShuffle => {
+                self.editor.resort_shuffle(&self.view_id).await.unwrap();
+            }
+            SortScript::MoveSingleSelectOption {
+                field_id,
+                option_name,
+                to_index,
+            } => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id).await.unwrap());
+                let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
+                let mut type_option = SingleSelectTypeOptionPB::from(&field_rev);
+                let index = type_option
+                    .options
+                    .iter()
+                    .position(|option| option.name == option_name)
+                    .unwrap();
+                let option = type_option.options.remove(index);
+                type_option.options.insert(to_index, option);
+                self.editor
+                    .update_field_type_option(&self.view_id, &field_id, type_option.protobuf_bytes().to_vec(), None)
+                    .await
+                    .unwrap();
+            }
             SortScript::DeleteSort { field_rev, sort_id } => {
                 self.recv = Some(self.editor.subscribe_view_changed(&self.view_id).await.unwrap());
                 let params = DeleteSortParams {
@@ -104,6 +301,14 @@ impl DatabaseSortTest {
                 self.recv = Some(self.editor.subscribe_view_changed(&self.view_id).await.unwrap());
                 self.update_text_cell(row_id, &text).await;
             }
+            SortScript::UpdateChecklistCell { row_id, changeset } => {
+                self.update_checklist_cell(row_id, changeset).await;
+            }
+            SortScript::UpdateCheckboxCell { row_id, is_check } => {
+                let field_rev = self.get_first_field_rev(FieldType::Checkbox).clone();
+                let changeset = if is_check { CHECK } else { UNCHECK };
+                self.update_cell(&field_rev.id, row_id, changeset.to_string()).await;
+            }
             SortScript::AssertSortChanged {
                 new_row_orders,
                 old_row_orders,
@@ -117,9 +322,131 @@ impl DatabaseSortTest {
                     .await;
                 }
             }
+            SortScript::AssertRowsMoved {
+                new_row_orders,
+                old_row_orders,
+            } => {
+                let receiver = self.recv.take().unwrap();
+                assert_rows_moved(
+                    receiver,
+                    new_row_orders.into_iter().map(|order| order.to_owned()).collect(),
+                    old_row_orders.into_iter().map(|order| order.to_owned()).collect(),
+                )
+                .await;
+            }
+            SortScript::AssertAllRowsReorderedOnce(expected) => {
+                let receiver = self.recv.take().unwrap();
+                assert_all_rows_reordered_once(receiver, expected).await;
+            }
+            SortScript::ReorderSort { sort_id, new_index } => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id).await.unwrap());
+                let params = ReorderSortParams {
+                    view_id: self.view_id.clone(),
+                    sort_id,
+                    new_index,
+                };
+                self.editor.reorder_sort(params).await.unwrap();
+            }
+            SortScript::AssertSortIds(expected) => {
+                let sort_ids = self
+                    .editor
+                    .get_all_sorts(&self.view_id)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|sort| sort.id)
+                    .collect::<Vec<String>>();
+                assert_eq!(sort_ids, expected);
+            }
+            SortScript::AssertSortableFieldTypes(field_types) => {
+                let sortable_fields = self.editor.get_sortable_fields().await.unwrap();
+                for field_type in field_types {
+                    assert!(sortable_fields.iter().any(|field| field.field_type == field_type));
+                }
+            }
+            SortScript::AssertEmptyOrdersOfDuplicatedView(expected) => {
+                let new_database_id = gen_grid_id();
+                self.sdk
+                    .grid_manager
+                    .duplicate_database(&self.view_id, &new_database_id)
+                    .await
+                    .unwrap();
+                let editor = self
+                    .sdk
+                    .grid_manager
+                    .get_database_editor(&new_database_id)
+                    .await
+                    .unwrap();
+                let empty_orders = editor
+                    .get_all_sorts(&new_database_id)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|sort| sort.empty_order)
+                    .collect::<Vec<SortEmptyOrderPB>>();
+                assert_eq!(empty_orders, expected);
+            }
+            SortScript::MarkSortComparisonCount => {
+                self.marked_comparison_count = self.editor.get_sort_comparison_count(&self.view_id).await.unwrap();
+            }
+            SortScript::AssertSortComparisonCount { at_most } => {
+                let comparison_count = self.editor.get_sort_comparison_count(&self.view_id).await.unwrap();
+                assert!(comparison_count - self.marked_comparison_count <= at_most);
+            }
             SortScript::Wait { millis } => {
                 tokio::time::sleep(Duration::from_millis(millis)).await;
             }
+            SortScript::PinRow { row_id } => {
+                self.editor.pin_row(&self.view_id, &row_id).await.unwrap();
+            }
+            SortScript::UnpinRow { row_id } => {
+                self.editor.unpin_row(&self.view_id, &row_id).await.unwrap();
+            }
+            SortScript::DeleteRow { row_id } => {
+                self.editor.delete_row(&row_id).await.unwrap();
+            }
+            SortScript::DeleteField { field_id } => {
+                self.editor.delete_field(&field_id).await.unwrap();
+            }
+            SortScript::DeleteFields { field_ids } => {
+                self.editor.delete_fields(field_ids).await.unwrap();
+            }
+            SortScript::MoveRow {
+                row_id,
+                to_index,
+                remove_sorts,
+            } => {
+                let params = MoveRowParams {
+                    view_id: self.view_id.clone(),
+                    from_row_id: row_id,
+                    to: MoveRowTarget::Index(to_index),
+                    remove_sorts,
+                };
+                self.editor.move_row(params).await.unwrap();
+            }
+            SortScript::AssertEffectiveRowOrder { source, row_ids } => {
+                let row_order = self.editor.get_effective_row_order(&self.view_id).await.unwrap();
+                assert_eq!(row_order.source, source);
+                assert_eq!(row_order.row_ids, row_ids);
+            }
+            SortScript::AssertPinnedRows(expected) => {
+                let setting = self.editor.get_setting().await.unwrap();
+                assert_eq!(setting.pinned_row_ids, expected);
+            }
+            SortScript::CreateRowAtIndex {
+                index,
+                text_field_id,
+                text,
+                expected_index,
+            } => {
+                let data = RowDataPayload::new().with_cell(&text_field_id, &text);
+                let created_row = self
+                    .editor
+                    .create_row_at_index(&self.view_id, index, data)
+                    .await
+                    .unwrap();
+                assert_eq!(created_row.index, expected_index.map(|index| index as i32));
+            }
         }
     }
 }
@@ -154,6 +481,46 @@ async fn assert_sort_changed(
         .await;
 }
 
+async fn assert_rows_moved(
+    mut receiver: Receiver<GridViewChanged>,
+    new_row_orders: Vec<String>,
+    old_row_orders: Vec<String>,
+) {
+    let moved_rows = tokio::time::timeout(Duration::from_secs(2), async {
+        loop {
+            match receiver.recv().await.unwrap() {
+                GridViewChanged::ReorderRowsNotification(changed) => break changed.moved_rows,
+                GridViewChanged::ReorderAllRowsNotification(_) => {
+                    panic!("Expect the moved rows instead of all the rows")
+                }
+                _ => {}
+            }
+        }
+    })
+    .await
+    .unwrap();
+
+    let mut row_orders = old_row_orders.clone();
+    for moved_row in moved_rows {
+        row_orders[moved_row.new_index] = old_row_orders[moved_row.old_index].clone();
+    }
+    assert_eq!(row_orders, new_row_orders);
+}
+
+async fn assert_all_rows_reordered_once(mut receiver: Receiver<GridViewChanged>, expected: Vec<String>) {
+    let mut reordered_rows = vec![];
+    while let Ok(changed) = tokio::time::timeout(Duration::from_secs(2), receiver.recv()).await {
+        match changed.unwrap() {
+            GridViewChanged::ReorderAllRowsNotification(changed) => reordered_rows.push(changed.row_orders),
+            GridViewChanged::ReorderRowsNotification(_) | GridViewChanged::ReorderSingleRowNotification(_) => {
+                panic!("Expect all the rows to be reordered")
+            }
+            _ => {}
+        }
+    }
+    assert_eq!(reordered_rows, vec![expected]);
+}
+
 impl std::ops::Deref for DatabaseSortTest {
     type Target = DatabaseEditorTest;
 
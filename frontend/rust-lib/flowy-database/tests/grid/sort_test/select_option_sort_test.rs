@@ -0,0 +1,58 @@
+use crate::grid::sort_test::script::{DatabaseSortTest, SortScript::*};
+use flowy_database::entities::FieldType;
+use grid_model::SortCondition;
+
+#[tokio::test]
+async fn sort_single_select_by_option_order_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let single_select = test.get_first_field_rev(FieldType::SingleSelect).clone();
+    let scripts = vec![
+        InsertSort {
+            field_rev: single_select.clone(),
+            condition: SortCondition::Ascending,
+        },
+        AssertCellContentOrder {
+            field_id: single_select.id.clone(),
+            orders: vec!["Completed", "Completed", "Planned", "Planned", "", ""],
+        },
+        Wait { millis: 200 },
+        // Moving the option before the others sorts the rows again.
+        MoveSingleSelectOption {
+            field_id: single_select.id.clone(),
+            option_name: "Planned".to_owned(),
+            to_index: 0,
+        },
+        AssertRowsMoved {
+            old_row_orders: vec!["Completed", "Completed", "Planned", "Planned", "", ""],
+            new_row_orders: vec!["Planned", "Planned", "Completed", "Completed", "", ""],
+        },
+        AssertCellContentOrder {
+            field_id: single_select.id.clone(),
+            orders: vec!["Planned", "Planned", "Completed", "Completed", "", ""],
+        },
+        UpdateSortAlphabetical { alphabetical: true },
+        AssertCellContentOrder {
+            field_id: single_select.id.clone(),
+            orders: vec!["Completed", "Completed", "Planned", "Planned", "", ""],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_multi_select_alphabetically_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let multi_select = test.get_first_field_rev(FieldType::MultiSelect).clone();
+    let scripts = vec![
+        InsertSort {
+            field_rev: multi_select.clone(),
+            condition: SortCondition::Ascending,
+        },
+        UpdateSortAlphabetical { alphabetical: true },
+        AssertCellContentOrder {
+            field_id: multi_select.id.clone(),
+            orders: vec!["Facebook", "Google,Facebook", "Google,Twitter", "", "", ""],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -1,5 +1,6 @@
-use crate::grid::sort_test::script::{DatabaseSortTest, SortScript::*};
+use crate::grid::sort_test::script::{DatabaseSortTest, SortScript, SortScript::*};
 use flowy_database::entities::FieldType;
+use flowy_database::services::field::{ChecklistCellChangeset, ChecklistItem};
 use grid_model::SortCondition;
 
 #[tokio::test]
@@ -17,7 +18,7 @@ async fn sort_text_by_ascending_test() {
         },
         AssertCellContentOrder {
             field_id: text_field.id.clone(),
-            orders: vec!["", "A", "AE", "AE", "C", "DA"],
+            orders: vec!["A", "AE", "AE", "C", "DA", ""],
         },
     ];
     test.run_scripts(scripts).await;
@@ -34,7 +35,7 @@ async fn sort_change_notification_by_update_text_test() {
         },
         AssertCellContentOrder {
             field_id: text_field.id.clone(),
-            orders: vec!["", "A", "AE", "AE", "C", "DA"],
+            orders: vec!["A", "AE", "AE", "C", "DA", ""],
         },
         // Wait the insert task to finish. The cost of time should be less than 200 milliseconds.
         Wait { millis: 200 },
@@ -48,8 +49,8 @@ async fn sort_change_notification_by_update_text_test() {
             text: "E".to_string(),
         },
         AssertSortChanged {
-            old_row_orders: vec!["", "A", "E", "AE", "C", "DA"],
-            new_row_orders: vec!["", "A", "AE", "C", "DA", "E"],
+            old_row_orders: vec!["A", "AE", "E", "C", "DA", ""],
+            new_row_orders: vec!["A", "AE", "C", "DA", "E", ""],
         },
     ];
     test.run_scripts(scripts).await;
@@ -250,7 +251,212 @@ async fn sort_multi_select_by_ascending_test() {
         },
         AssertCellContentOrder {
             field_id: multi_select.id.clone(),
-            orders: vec!["", "", "", "Facebook", "Google,Facebook", "Google,Twitter"],
+            orders: vec!["Google,Facebook", "Google,Twitter", "Facebook", "", "", ""],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_checklist_by_ascending_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let checklist_field = test.get_first_field_rev(FieldType::Checklist).clone();
+    let scripts = vec![
+        toggle_first_checklist_item_of_third_row(&test).await,
+        InsertSort {
+            field_rev: checklist_field,
+            condition: SortCondition::Ascending,
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["C", "A", "", "DA", "AE", "AE"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_checklist_by_descending_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let checklist_field = test.get_first_field_rev(FieldType::Checklist).clone();
+    let scripts = vec![
+        toggle_first_checklist_item_of_third_row(&test).await,
+        InsertSort {
+            field_rev: checklist_field,
+            condition: SortCondition::Descending,
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["A", "C", "", "DA", "AE", "AE"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_checklist_with_same_percentage_by_item_count_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let checklist_field = test.get_first_field_rev(FieldType::Checklist).clone();
+    let row_revs = test.get_row_revs().await;
+    let scripts = vec![
+        toggle_first_checklist_item_of_third_row(&test).await,
+        // None of the items of the second row and the fourth row are done.
+        UpdateChecklistCell {
+            row_id: row_revs[1].id.clone(),
+            changeset: ChecklistCellChangeset::from_insert_items(vec![
+                ChecklistItem::new("first"),
+                ChecklistItem::new("second"),
+            ]),
+        },
+        UpdateChecklistCell {
+            row_id: row_revs[3].id.clone(),
+            changeset: ChecklistCellChangeset::from_insert_items(vec![ChecklistItem::new("first")]),
+        },
+        InsertSort {
+            field_rev: checklist_field,
+            condition: SortCondition::Ascending,
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["DA", "", "C", "A", "AE", "AE"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+/// All the items of the first row are done. After toggling, one of the three items of the third
+/// row is done, and none of the items of the other rows.
+async fn toggle_first_checklist_item_of_third_row(test: &DatabaseSortTest) -> SortScript {
+    let checklist_field = test.get_first_field_rev(FieldType::Checklist);
+    let type_option = test.get_checklist_type_option(&checklist_field.id);
+    let row_revs = test.get_row_revs().await;
+    UpdateChecklistCell {
+        row_id: row_revs[2].id.clone(),
+        changeset: ChecklistCellChangeset::from_toggle_item_ids(vec![type_option.options[0].id.clone()]),
+    }
+}
+
+#[tokio::test]
+async fn sort_created_time_by_ascending_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let created_time_field = test.get_first_field_rev(FieldType::CreatedTime).clone();
+    let scripts = vec![
+        AssertCellContentOrder {
+            field_id: created_time_field.id.clone(),
+            orders: vec![
+                "2022/05/27",
+                "2022/03/14",
+                "2022/12/25",
+                "2022/11/17",
+                "2022/11/13",
+                "2022/04/15",
+            ],
+        },
+        InsertSort {
+            field_rev: created_time_field.clone(),
+            condition: SortCondition::Ascending,
+        },
+        AssertCellContentOrder {
+            field_id: created_time_field.id.clone(),
+            orders: vec![
+                "2022/03/14",
+                "2022/04/15",
+                "2022/05/27",
+                "2022/11/13",
+                "2022/11/17",
+                "2022/12/25",
+            ],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_created_time_by_descending_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let created_time_field = test.get_first_field_rev(FieldType::CreatedTime).clone();
+    let scripts = vec![
+        InsertSort {
+            field_rev: created_time_field.clone(),
+            condition: SortCondition::Descending,
+        },
+        AssertCellContentOrder {
+            field_id: created_time_field.id.clone(),
+            orders: vec![
+                "2022/12/25",
+                "2022/11/17",
+                "2022/11/13",
+                "2022/05/27",
+                "2022/04/15",
+                "2022/03/14",
+            ],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_last_edited_time_after_update_cell_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let last_edited_time_field = test.get_first_field_rev(FieldType::LastEditedTime).clone();
+    let row_revs = test.get_row_revs().await;
+    let scripts = vec![
+        // Editing the row makes it the last edited one.
+        UpdateTextCell {
+            row_id: row_revs[1].id.clone(),
+            text: "B".to_string(),
+        },
+        InsertSort {
+            field_rev: last_edited_time_field,
+            condition: SortCondition::Ascending,
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["AE", "A", "AE", "DA", "C", "B"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_person_by_ascending_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let person_field = test.get_first_field_rev(FieldType::Person);
+    let scripts = vec![
+        AssertCellContentOrder {
+            field_id: person_field.id.clone(),
+            orders: vec!["Lucas", "Nathan,Lucas", "Annie", "Unknown member", "", ""],
+        },
+        InsertSort {
+            field_rev: person_field.clone(),
+            condition: SortCondition::Ascending,
+        },
+        // The cells are sorted by the names of their members, the member that can't be resolved
+        // is sorted by the name of the placeholder.
+        AssertCellContentOrder {
+            field_id: person_field.id.clone(),
+            orders: vec!["Annie", "Lucas", "Nathan,Lucas", "Unknown member", "", ""],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_person_by_descending_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let person_field = test.get_first_field_rev(FieldType::Person);
+    let scripts = vec![
+        InsertSort {
+            field_rev: person_field.clone(),
+            condition: SortCondition::Descending,
+        },
+        AssertCellContentOrder {
+            field_id: person_field.id.clone(),
+            orders: vec!["Unknown member", "Nathan,Lucas", "Lucas", "Annie", "", ""],
         },
     ];
     test.run_scripts(scripts).await;
@@ -0,0 +1,122 @@
+use crate::grid::sort_test::script::{DatabaseSortTest, SortScript::*};
+use flowy_database::entities::{FieldType, RowOrderSourcePB, SortModePB};
+use grid_model::{SortCondition, SortModeRevision};
+
+async fn shuffle_seed(test: &DatabaseSortTest) -> u64 {
+    match test.editor.get_sort_mode(&test.view_id).await.unwrap() {
+        Some(SortModeRevision::Shuffle { seed }) => seed,
+        sort_mode => panic!("Expect the shuffle mode, but receive {:?}", sort_mode),
+    }
+}
+
+async fn row_order(test: &DatabaseSortTest) -> Vec<String> {
+    let row_order = test.editor.get_effective_row_order(&test.view_id).await.unwrap();
+    assert_eq!(row_order.source, RowOrderSourcePB::Sort);
+    row_order.row_ids
+}
+
+#[tokio::test]
+async fn sort_mode_shuffle_is_stable_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let row_revs = test.get_row_revs().await;
+    let mut row_ids = row_revs
+        .iter()
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<String>>();
+    let scripts = vec![UpdateSortMode {
+        sort_mode: SortModePB::Shuffle,
+    }];
+    test.run_scripts(scripts).await;
+    let seed = shuffle_seed(&test).await;
+    let shuffled_row_ids = row_order(&test).await;
+    let mut sorted_row_ids = shuffled_row_ids.clone();
+    sorted_row_ids.sort();
+    row_ids.sort();
+    assert_eq!(sorted_row_ids, row_ids);
+
+    // The sorts and the changed cells don't move the shuffled rows, and choosing the shuffle mode
+    // again keeps the seed.
+    let scripts = vec![
+        InsertSort {
+            field_rev: text_field.clone(),
+            condition: SortCondition::Ascending,
+        },
+        UpdateTextCell {
+            row_id: row_revs[2].id.clone(),
+            text: "Z".to_owned(),
+        },
+        UpdateSortMode {
+            sort_mode: SortModePB::Shuffle,
+        },
+        AssertEffectiveRowOrder {
+            source: RowOrderSourcePB::Sort,
+            row_ids: shuffled_row_ids,
+        },
+    ];
+    test.run_scripts(scripts).await;
+    assert_eq!(shuffle_seed(&test).await, seed);
+
+    // The sorts take effect again once the mode is removed.
+    let scripts = vec![
+        UpdateSortMode {
+            sort_mode: SortModePB::Field,
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["A", "AE", "AE", "DA", "Z", ""],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_mode_resort_shuffle_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let scripts = vec![UpdateSortMode {
+        sort_mode: SortModePB::Shuffle,
+    }];
+    test.run_scripts(scripts).await;
+    let seed = shuffle_seed(&test).await;
+    let row_count = row_order(&test).await.len();
+
+    let scripts = vec![ResortShuffle];
+    test.run_scripts(scripts).await;
+    assert_ne!(shuffle_seed(&test).await, seed);
+    assert_eq!(row_order(&test).await.len(), row_count);
+}
+
+#[tokio::test]
+async fn sort_mode_last_modified_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let row_revs = test.get_row_revs().await;
+    let scripts = vec![
+        InsertSort {
+            field_rev: text_field.clone(),
+            condition: SortCondition::Ascending,
+        },
+        UpdateTextCell {
+            row_id: row_revs[3].id.clone(),
+            text: "DB".to_owned(),
+        },
+        UpdateSortMode {
+            sort_mode: SortModePB::LastModified,
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["DB"],
+        },
+        // The modified times are in seconds, so the next row is modified a second later.
+        Wait { millis: 1100 },
+        UpdateTextCell {
+            row_id: row_revs[2].id.clone(),
+            text: "CA".to_owned(),
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["CA", "DB"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
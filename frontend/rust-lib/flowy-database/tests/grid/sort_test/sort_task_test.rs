@@ -0,0 +1,42 @@
+use crate::grid::sort_test::script::{DatabaseSortTest, SortScript::*};
+use flowy_database::entities::FieldType;
+use grid_model::SortCondition;
+
+#[tokio::test]
+async fn sort_rapidly_changed_sorts_apply_last_sort_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    let row_ids = test
+        .get_row_revs()
+        .await
+        .into_iter()
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<String>>();
+    // The sort is changed before the rows are sorted by the inserted sort, so the rows are only
+    // reordered by the descending sort.
+    let scripts = vec![
+        InsertSort {
+            field_rev: text_field.clone(),
+            condition: SortCondition::Ascending,
+        },
+        UpdateSortCondition {
+            condition: SortCondition::Ascending,
+        },
+        UpdateSortCondition {
+            condition: SortCondition::Descending,
+        },
+        AssertAllRowsReorderedOnce(vec![
+            row_ids[3].clone(),
+            row_ids[2].clone(),
+            row_ids[4].clone(),
+            row_ids[5].clone(),
+            row_ids[0].clone(),
+            row_ids[1].clone(),
+        ]),
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["DA", "C", "AE", "AE", "A", ""],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -0,0 +1,57 @@
+use crate::grid::sort_test::script::{DatabaseSortTest, SortScript::*};
+use flowy_database::entities::FieldType;
+use grid_model::SortCondition;
+
+async fn update_texts(test: &mut DatabaseSortTest, texts: &[&str]) {
+    let row_revs = test.get_row_revs().await;
+    let scripts = row_revs
+        .iter()
+        .zip(texts)
+        .map(|(row_rev, text)| UpdateTextCell {
+            row_id: row_rev.id.clone(),
+            text: text.to_string(),
+        })
+        .collect();
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_text_with_mixed_case_and_accents_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    update_texts(&mut test, &["banana", "Écharpe", "zebra", "Apple", "ecole", "Banana"]).await;
+    let scripts = vec![
+        InsertSort {
+            field_rev: text_field.clone(),
+            condition: SortCondition::Ascending,
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["Apple", "Banana", "banana", "Écharpe", "ecole", "zebra"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_text_with_numeric_suffixes_test() {
+    let mut test = DatabaseSortTest::new().await;
+    let text_field = test.get_first_field_rev(FieldType::RichText).clone();
+    update_texts(&mut test, &["item10", "item2", "Item1", "item20", "item", "item3"]).await;
+    let scripts = vec![
+        InsertSort {
+            field_rev: text_field.clone(),
+            condition: SortCondition::Ascending,
+        },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["item", "Item1", "item2", "item3", "item10", "item20"],
+        },
+        UpdateSortNatural { natural: false },
+        AssertCellContentOrder {
+            field_id: text_field.id.clone(),
+            orders: vec!["item", "Item1", "item10", "item2", "item20", "item3"],
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
@@ -0,0 +1,197 @@
+use crate::grid::database_editor::DatabaseEditorTest;
+use flowy_database::entities::{CellPathParams, CreateRowParams, DatabaseViewLayout, FieldChangesetParams, FieldType};
+use grid_model::{FieldRevision, RowMetaChangeset};
+
+pub enum UndoScript {
+    CreateEmptyRow,
+    /// Deletes the rows at the indexes with one call, so they are undone as one step.
+    DeleteRows {
+        row_indexes: Vec<usize>,
+    },
+    UpdateTextCell {
+        row_index: usize,
+        content: String,
+    },
+    UpdateRowIcon {
+        row_index: usize,
+        icon: String,
+    },
+    CreateField {
+        field_rev: FieldRevision,
+    },
+    DeleteField {
+        field_id: String,
+    },
+    UpdateFieldName {
+        field_id: String,
+        name: String,
+    },
+    Undo,
+    Redo,
+    /// Asserts there is nothing to undo.
+    AssertUndoNothing,
+    AssertRowIds(Vec<String>),
+    AssertFieldIds(Vec<String>),
+    AssertFieldName {
+        field_id: String,
+        expected: String,
+    },
+    AssertTextCell {
+        row_index: usize,
+        expected: String,
+    },
+    AssertRowIcon {
+        row_index: usize,
+        expected: Option<String>,
+    },
+    AssertNumberOfUndoSteps(usize),
+    AssertNumberOfRedoSteps(usize),
+    /// Closes the database and opens it again, so the fields and the rows are loaded from disk.
+    ReopenDatabase,
+}
+
+pub struct DatabaseUndoTest {
+    inner: DatabaseEditorTest,
+}
+
+impl DatabaseUndoTest {
+    pub async fn new() -> Self {
+        let editor_test = DatabaseEditorTest::new_table().await;
+        Self { inner: editor_test }
+    }
+
+    pub async fn row_ids(&self) -> Vec<String> {
+        self.get_row_revs()
+            .await
+            .iter()
+            .map(|row_rev| row_rev.id.clone())
+            .collect()
+    }
+
+    pub async fn field_ids(&self) -> Vec<String> {
+        self.editor
+            .get_field_revs(None)
+            .await
+            .unwrap()
+            .iter()
+            .map(|field_rev| field_rev.id.clone())
+            .collect()
+    }
+
+    pub async fn text_cell(&self, row_index: usize) -> String {
+        let row_id = self.row_ids().await[row_index].clone();
+        let field_id = self.get_first_field_rev(FieldType::RichText).id.clone();
+        let params = CellPathParams {
+            database_id: self.view_id.clone(),
+            field_id,
+            row_id,
+        };
+        self.editor.get_cell_display_str(&params).await
+    }
+
+    pub async fn run_scripts(&mut self, scripts: Vec<UndoScript>) {
+        for script in scripts {
+            self.run_script(script).await;
+        }
+    }
+
+    pub async fn run_script(&mut self, script: UndoScript) {
+        match script {
+            UndoScript::CreateEmptyRow => {
+                let params = CreateRowParams {
+                    database_id: self.editor.database_id.clone(),
+                    start_row_id: None,
+                    group_id: None,
+                    layout: DatabaseViewLayout::Grid,
+                };
+                self.editor.create_row(params).await.unwrap();
+            }
+            UndoScript::DeleteRows { row_indexes } => {
+                let row_ids = self.row_ids().await;
+                let row_ids = row_indexes
+                    .into_iter()
+                    .map(|row_index| row_ids[row_index].clone())
+                    .collect::<Vec<String>>();
+                self.editor.delete_rows(row_ids).await.unwrap();
+            }
+            UndoScript::UpdateTextCell { row_index, content } => {
+                let row_id = self.row_ids().await[row_index].clone();
+                self.update_text_cell(row_id, &content).await;
+            }
+            UndoScript::UpdateRowIcon { row_index, icon } => {
+                let row_id = self.row_ids().await[row_index].clone();
+                let changeset = RowMetaChangeset {
+                    icon: Some(icon),
+                    ..Default::default()
+                };
+                self.editor.update_row_meta(&row_id, changeset).await.unwrap();
+            }
+            UndoScript::CreateField { field_rev } => {
+                self.editor.create_new_field_rev(field_rev).await.unwrap();
+            }
+            UndoScript::DeleteField { field_id } => {
+                self.editor.delete_field(&field_id).await.unwrap();
+            }
+            UndoScript::UpdateFieldName { field_id, name } => {
+                let changeset = FieldChangesetParams {
+                    field_id,
+                    database_id: self.view_id.clone(),
+                    name: Some(name),
+                    ..Default::default()
+                };
+                self.editor.update_field(changeset).await.unwrap();
+            }
+            UndoScript::Undo => {
+                assert!(self.editor.undo().await.unwrap());
+            }
+            UndoScript::Redo => {
+                assert!(self.editor.redo().await.unwrap());
+            }
+            UndoScript::AssertUndoNothing => {
+                assert!(!self.editor.undo().await.unwrap());
+            }
+            UndoScript::AssertRowIds(expected) => {
+                assert_eq!(self.row_ids().await, expected);
+            }
+            UndoScript::AssertFieldIds(expected) => {
+                assert_eq!(self.field_ids().await, expected);
+            }
+            UndoScript::AssertFieldName { field_id, expected } => {
+                let field_rev = self.editor.get_field_rev(&field_id).await.unwrap();
+                assert_eq!(field_rev.name, expected);
+            }
+            UndoScript::AssertTextCell { row_index, expected } => {
+                assert_eq!(self.text_cell(row_index).await, expected);
+            }
+            UndoScript::AssertRowIcon { row_index, expected } => {
+                let row_rev = self.get_row_revs().await[row_index].clone();
+                assert_eq!(row_rev.meta.icon, expected);
+            }
+            UndoScript::AssertNumberOfUndoSteps(expected) => {
+                assert_eq!(self.editor.number_of_undo_steps(), expected);
+            }
+            UndoScript::AssertNumberOfRedoSteps(expected) => {
+                assert_eq!(self.editor.number_of_redo_steps(), expected);
+            }
+            UndoScript::ReopenDatabase => {
+                let database_manager = self.sdk.grid_manager.clone();
+                database_manager.close_all().await;
+                self.editor = database_manager.open_database(&self.view_id).await.unwrap();
+            }
+        }
+    }
+}
+
+impl std::ops::Deref for DatabaseUndoTest {
+    type Target = DatabaseEditorTest;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl std::ops::DerefMut for DatabaseUndoTest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
@@ -0,0 +1,193 @@
+use crate::grid::field_test::util::create_text_field;
+use crate::grid::undo_test::script::{DatabaseUndoTest, UndoScript::*};
+use flowy_database::entities::FieldType;
+use flowy_database::services::undo::DEFAULT_UNDO_DEPTH;
+
+#[tokio::test]
+async fn undo_create_row_test() {
+    let mut test = DatabaseUndoTest::new().await;
+    let row_ids = test.row_ids().await;
+    let scripts = vec![
+        CreateEmptyRow,
+        AssertNumberOfUndoSteps(1),
+        Undo,
+        AssertRowIds(row_ids),
+        AssertNumberOfUndoSteps(0),
+        AssertNumberOfRedoSteps(1),
+        AssertUndoNothing,
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn undo_delete_rows_as_one_step_test() {
+    let mut test = DatabaseUndoTest::new().await;
+    let row_ids = test.row_ids().await;
+    let remaining_row_ids = row_ids[3..].to_vec();
+    let scripts = vec![
+        DeleteRows {
+            row_indexes: vec![0, 1, 2],
+        },
+        AssertRowIds(remaining_row_ids.clone()),
+        AssertNumberOfUndoSteps(1),
+        Undo,
+        AssertRowIds(row_ids.clone()),
+        Redo,
+        AssertRowIds(remaining_row_ids),
+        Undo,
+        AssertRowIds(row_ids.clone()),
+        ReopenDatabase,
+        AssertRowIds(row_ids),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn undo_delete_non_adjacent_rows_test() {
+    let mut test = DatabaseUndoTest::new().await;
+    let row_ids = test.row_ids().await;
+    let scripts = vec![
+        DeleteRows {
+            row_indexes: vec![1, 3],
+        },
+        Undo,
+        AssertRowIds(row_ids),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn undo_update_cell_test() {
+    let mut test = DatabaseUndoTest::new().await;
+    let original_content = test.text_cell(0).await;
+    let scripts = vec![
+        UpdateTextCell {
+            row_index: 0,
+            content: "hello world".to_owned(),
+        },
+        AssertTextCell {
+            row_index: 0,
+            expected: "hello world".to_owned(),
+        },
+        Undo,
+        AssertTextCell {
+            row_index: 0,
+            expected: original_content,
+        },
+        Redo,
+        AssertTextCell {
+            row_index: 0,
+            expected: "hello world".to_owned(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn undo_update_row_meta_test() {
+    let mut test = DatabaseUndoTest::new().await;
+    let scripts = vec![
+        UpdateRowIcon {
+            row_index: 0,
+            icon: "🚀".to_owned(),
+        },
+        UpdateRowIcon {
+            row_index: 0,
+            icon: "📌".to_owned(),
+        },
+        Undo,
+        AssertRowIcon {
+            row_index: 0,
+            expected: Some("🚀".to_owned()),
+        },
+        Undo,
+        AssertRowIcon {
+            row_index: 0,
+            expected: None,
+        },
+        Redo,
+        AssertRowIcon {
+            row_index: 0,
+            expected: Some("🚀".to_owned()),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn undo_create_field_test() {
+    let mut test = DatabaseUndoTest::new().await;
+    let field_ids = test.field_ids().await;
+    let (_, field_rev) = create_text_field(&test.view_id);
+    let scripts = vec![CreateField { field_rev }, Undo, AssertFieldIds(field_ids)];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn undo_delete_field_test() {
+    let mut test = DatabaseUndoTest::new().await;
+    let field_ids = test.field_ids().await;
+    let field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+    let remaining_field_ids = field_ids
+        .iter()
+        .filter(|id| *id != &field_id)
+        .cloned()
+        .collect::<Vec<String>>();
+    let scripts = vec![
+        DeleteField { field_id },
+        AssertFieldIds(remaining_field_ids.clone()),
+        Undo,
+        AssertFieldIds(field_ids.clone()),
+        ReopenDatabase,
+        AssertFieldIds(field_ids),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn undo_update_field_test() {
+    let mut test = DatabaseUndoTest::new().await;
+    let field_rev = test.get_first_field_rev(FieldType::Number).clone();
+    let scripts = vec![
+        UpdateFieldName {
+            field_id: field_rev.id.clone(),
+            name: "Price".to_owned(),
+        },
+        Undo,
+        AssertFieldName {
+            field_id: field_rev.id.clone(),
+            expected: field_rev.name.clone(),
+        },
+        Redo,
+        AssertFieldName {
+            field_id: field_rev.id.clone(),
+            expected: "Price".to_owned(),
+        },
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn undo_new_edit_clears_redo_test() {
+    let mut test = DatabaseUndoTest::new().await;
+    let scripts = vec![
+        UpdateTextCell {
+            row_index: 0,
+            content: "hello world".to_owned(),
+        },
+        Undo,
+        AssertNumberOfRedoSteps(1),
+        CreateEmptyRow,
+        AssertNumberOfRedoSteps(0),
+        AssertNumberOfUndoSteps(1),
+    ];
+    test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn undo_depth_test() {
+    let mut test = DatabaseUndoTest::new().await;
+    let mut scripts = (0..DEFAULT_UNDO_DEPTH + 5).map(|_| CreateEmptyRow).collect::<Vec<_>>();
+    scripts.push(AssertNumberOfUndoSteps(DEFAULT_UNDO_DEPTH));
+    test.run_scripts(scripts).await;
+}
@@ -184,6 +184,36 @@ pub enum ErrorCode {
 
     #[error("Payload should not be empty")]
     UnexpectedEmptyPayload = 60,
+
+    #[error("Invalid params")]
+    InvalidParams = 61,
+
+    #[error("The database is archived")]
+    DatabaseArchived = 62,
+
+    #[error("The database is encrypted")]
+    DatabaseEncrypted = 63,
+
+    #[error("The formula references itself")]
+    FormulaCircularReference = 64,
+
+    #[error("The field is locked")]
+    FieldLocked = 65,
+
+    #[error("The content of the comment is empty")]
+    CellCommentIsEmpty = 66,
+
+    #[error("The filter groups are nested too deep")]
+    FilterGroupTooDeep = 67,
+
+    #[error("The name of the filter set is empty")]
+    FilterSetNameIsEmpty = 68,
+
+    #[error("The ranges of the number groups are invalid")]
+    NumberGroupRangeInvalid = 69,
+
+    #[error("The operation is not supported by the groups of the field")]
+    UnsupportedGroupOperation = 70,
 }
 
 impl ErrorCode {
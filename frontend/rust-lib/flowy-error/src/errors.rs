@@ -14,6 +14,11 @@ pub struct FlowyError {
 
     #[pb(index = 2)]
     pub msg: String,
+
+    /// The protobuf bytes of the structured details of the error, for example, the rule that the
+    /// cell violates. It's empty if the error has no details.
+    #[pb(index = 3)]
+    pub payload: Vec<u8>,
 }
 
 macro_rules! static_flowy_error {
@@ -30,6 +35,7 @@ impl FlowyError {
         Self {
             code: code.value() as i32,
             msg: msg.to_owned(),
+            payload: vec![],
         }
     }
     pub fn context<T: Debug>(mut self, error: T) -> Self {
@@ -37,10 +43,19 @@ impl FlowyError {
         self
     }
 
+    pub fn with_payload(mut self, payload: Vec<u8>) -> Self {
+        self.payload = payload;
+        self
+    }
+
     pub fn is_record_not_found(&self) -> bool {
         self.code == ErrorCode::RecordNotFound.value()
     }
 
+    pub fn is_database_encrypted(&self) -> bool {
+        self.code == ErrorCode::DatabaseEncrypted.value()
+    }
+
     static_flowy_error!(internal, ErrorCode::Internal);
     static_flowy_error!(record_not_found, ErrorCode::RecordNotFound);
     static_flowy_error!(workspace_name, ErrorCode::WorkspaceNameInvalid);
@@ -76,6 +91,10 @@ impl FlowyError {
     static_flowy_error!(field_record_not_found, ErrorCode::FieldRecordNotFound);
     static_flowy_error!(payload_none, ErrorCode::UnexpectedEmptyPayload);
     static_flowy_error!(http, ErrorCode::HttpError);
+    static_flowy_error!(invalid_params, ErrorCode::InvalidParams);
+    static_flowy_error!(database_archived, ErrorCode::DatabaseArchived);
+    static_flowy_error!(database_encrypted, ErrorCode::DatabaseEncrypted);
+    static_flowy_error!(field_locked, ErrorCode::FieldLocked);
 }
 
 impl std::convert::From<ErrorCode> for FlowyError {
@@ -83,6 +102,7 @@ impl std::convert::From<ErrorCode> for FlowyError {
         FlowyError {
             code: code.value() as i32,
             msg: format!("{}", code),
+            payload: vec![],
         }
     }
 }
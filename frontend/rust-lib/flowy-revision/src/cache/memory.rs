@@ -1,4 +1,3 @@
-use crate::REVISION_WRITE_INTERVAL_IN_MILLIS;
 use dashmap::DashMap;
 use flowy_error::{FlowyError, FlowyResult};
 use flowy_revision_persistence::SyncRecord;
@@ -17,16 +16,27 @@ pub(crate) struct RevisionMemoryCache {
     delegate: Arc<dyn RevisionMemoryCacheDelegate>,
     defer_write_revs: Arc<RwLock<Vec<i64>>>,
     defer_save: RwLock<Option<JoinHandle<()>>>,
+    write_interval: Duration,
+    max_pending_writes: usize,
 }
 
 impl RevisionMemoryCache {
-    pub(crate) fn new(object_id: &str, delegate: Arc<dyn RevisionMemoryCacheDelegate>) -> Self {
+    /// The pending revisions are written to disk once no revision has been added for the
+    /// `write_interval`, or immediately once there are `max_pending_writes` of them.
+    pub(crate) fn new(
+        object_id: &str,
+        delegate: Arc<dyn RevisionMemoryCacheDelegate>,
+        write_interval: Duration,
+        max_pending_writes: usize,
+    ) -> Self {
         RevisionMemoryCache {
             object_id: object_id.to_owned(),
             revs_map: Arc::new(DashMap::new()),
             delegate,
             defer_write_revs: Arc::new(RwLock::new(vec![])),
             defer_save: RwLock::new(None),
+            write_interval,
+            max_pending_writes: max_pending_writes.max(1),
         }
     }
 
@@ -44,11 +54,23 @@ impl RevisionMemoryCache {
         self.revs_map.insert(rev_id, record);
 
         let mut write_guard = self.defer_write_revs.write().await;
-        if !write_guard.contains(&rev_id) {
-            write_guard.push(rev_id);
-            drop(write_guard);
-            self.tick_checkpoint().await;
+        if write_guard.contains(&rev_id) {
+            return;
+        }
+
+        write_guard.push(rev_id);
+        if write_guard.len() >= self.max_pending_writes {
+            if let Some(handler) = self.defer_save.write().await.take() {
+                handler.abort();
+            }
+            if let Err(e) = write_pending_records(&self.revs_map, &mut write_guard, &self.delegate) {
+                tracing::error!("Write revisions of {} failed: {}", self.object_id, e);
+            }
+            return;
         }
+
+        drop(write_guard);
+        self.tick_checkpoint().await;
     }
 
     pub(crate) async fn ack(&self, rev_id: &i64) {
@@ -109,6 +131,16 @@ impl RevisionMemoryCache {
         self.tick_checkpoint().await;
     }
 
+    /// Writes the pending revisions to disk immediately instead of waiting for the checkpoint.
+    pub(crate) async fn flush(&self) -> FlowyResult<()> {
+        if let Some(handler) = self.defer_save.write().await.take() {
+            handler.abort();
+        }
+
+        let mut write_guard = self.defer_write_revs.write().await;
+        write_pending_records(&self.revs_map, &mut write_guard, &self.delegate)
+    }
+
     async fn tick_checkpoint(&self) {
         // https://github.com/async-graphql/async-graphql/blob/ed8449beec3d9c54b94da39bab33cec809903953/src/dataloader/mod.rs#L362
         if let Some(handler) = self.defer_save.write().await.take() {
@@ -122,28 +154,48 @@ impl RevisionMemoryCache {
         let rev_map = self.revs_map.clone();
         let pending_write_revs = self.defer_write_revs.clone();
         let delegate = self.delegate.clone();
+        let write_interval = self.write_interval;
 
         *self.defer_save.write().await = Some(tokio::spawn(async move {
-            tokio::time::sleep(Duration::from_millis(REVISION_WRITE_INTERVAL_IN_MILLIS)).await;
+            tokio::time::sleep(write_interval).await;
             let mut revs_write_guard = pending_write_revs.write().await;
             // It may cause performance issues because we hold the write lock of the
             // rev_order and the lock will be released after the checkpoint has been written
             // to the disk.
-            //
-            // Use saturating_sub and split_off ?
-            // https://stackoverflow.com/questions/28952411/what-is-the-idiomatic-way-to-pop-the-last-n-elements-in-a-mutable-vec
-            let mut save_records: Vec<SyncRecord> = vec![];
-            revs_write_guard.iter().for_each(|rev_id| match rev_map.get(rev_id) {
-                None => {}
-                Some(value) => {
-                    save_records.push(value.value().clone());
-                }
-            });
-
-            if delegate.send_sync(save_records).is_ok() {
-                revs_write_guard.clear();
-                drop(revs_write_guard);
-            }
+            let _ = write_pending_records(&rev_map, &mut revs_write_guard, &delegate);
+            drop(revs_write_guard);
         }));
     }
 }
+
+impl Drop for RevisionMemoryCache {
+    fn drop(&mut self) {
+        // Write the pending revisions that are waiting for the checkpoint, so they won't get lost
+        // if the cache is dropped without being flushed or closed.
+        if let Ok(mut write_guard) = self.defer_write_revs.try_write() {
+            if let Err(e) = write_pending_records(&self.revs_map, &mut write_guard, &self.delegate) {
+                tracing::error!("Write revisions of {} failed: {}", self.object_id, e);
+            }
+        }
+    }
+}
+
+/// Writes the records of the pending revisions in one batch and clears them if the write
+/// succeeds.
+fn write_pending_records(
+    revs_map: &DashMap<i64, SyncRecord>,
+    pending_write_revs: &mut Vec<i64>,
+    delegate: &Arc<dyn RevisionMemoryCacheDelegate>,
+) -> FlowyResult<()> {
+    if pending_write_revs.is_empty() {
+        return Ok(());
+    }
+
+    let save_records = pending_write_revs
+        .iter()
+        .flat_map(|rev_id| revs_map.get(rev_id).map(|record| record.value().clone()))
+        .collect::<Vec<SyncRecord>>();
+    delegate.send_sync(save_records)?;
+    pending_write_revs.clear();
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use crate::rev_queue::{RevCommand, RevCommandSender, RevQueue};
 use crate::{
-    RevisionPersistence, RevisionSnapshot, RevisionSnapshotController, RevisionSnapshotDiskCache,
-    WSDataProviderDataSource,
+    NamedRevisionSnapshot, RevisionPersistence, RevisionSnapshot, RevisionSnapshotController,
+    RevisionSnapshotDiskCache, WSDataProviderDataSource,
 };
 use bytes::Bytes;
 use flowy_error::{internal_error, FlowyError, FlowyResult};
@@ -161,11 +161,60 @@ impl<Connection: 'static> RevisionManager<Connection> {
         }
     }
 
+    /// Initializes the object from its latest snapshot and the revisions after the snapshot, so
+    /// the revisions that are covered by the snapshot are not replayed. Returns None if there is
+    /// no snapshot.
+    #[tracing::instrument(level = "trace", skip_all, fields(object_id = %self.object_id), err)]
+    pub async fn initialize_from_snapshot<B>(&mut self) -> FlowyResult<Option<B::Output>>
+    where
+        B: RevisionObjectDeserializer,
+    {
+        let snapshot = match self.rev_snapshot.read_last_snapshot()? {
+            None => return Ok(None),
+            Some(snapshot) => snapshot,
+        };
+        let revision_records = self
+            .rev_persistence
+            .load_records_after(&self.object_id, snapshot.rev_id)?;
+        let current_rev_id = revision_records
+            .last()
+            .map(|record| record.revision.rev_id)
+            .unwrap_or(snapshot.rev_id);
+        let snapshot_revision = Revision::new(
+            &self.object_id,
+            snapshot.base_rev_id,
+            snapshot.rev_id,
+            snapshot.data,
+            "".to_owned(),
+        );
+        let revisions = std::iter::once(snapshot_revision)
+            .chain(revision_records.iter().map(|record| record.revision.clone()))
+            .collect::<Vec<Revision>>();
+        let object = B::deserialize_revisions(&self.object_id, revisions)?;
+        self.rev_persistence.sync_revision_records(&revision_records).await?;
+        self.rev_id_counter.set(current_rev_id);
+        Ok(Some(object))
+    }
+
     pub async fn close(&self) {
+        // The revisions may be buffered in memory, write them to disk before the cache goes away.
+        // Otherwise, the lagging revisions that are not written to disk yet can't be compacted.
+        if let Err(e) = self.rev_persistence.flush().await {
+            tracing::error!("Flush revisions of {} failed: {:?}", self.object_id, e);
+        }
         let _ = self.rev_persistence.compact_lagging_revisions(&self.rev_compress).await;
     }
 
+    /// Writes the revisions that are waiting in the memory cache to disk.
+    pub async fn flush(&self) -> FlowyResult<()> {
+        self.rev_persistence.flush().await
+    }
+
     pub async fn generate_snapshot(&self) {
+        // Flush the pending revisions, so the snapshot contains the latest changes.
+        if let Err(e) = self.rev_persistence.flush().await {
+            tracing::error!("Flush revisions of {} failed: {:?}", self.object_id, e);
+        }
         self.rev_snapshot.generate_snapshot().await;
     }
 
@@ -176,6 +225,58 @@ impl<Connection: 'static> RevisionManager<Connection> {
         }
     }
 
+    /// Returns at most `limit` snapshots, the latest one first.
+    pub async fn read_snapshots(&self, limit: usize) -> FlowyResult<Vec<RevisionSnapshot>> {
+        self.rev_snapshot.read_snapshots(limit)
+    }
+
+    /// Generates a snapshot with the name, which replaces the existing one with the same name. The
+    /// pending revisions are written to disk first. Returns false if there is no revision.
+    pub async fn generate_named_snapshot(&self, name: &str) -> FlowyResult<bool> {
+        self.rev_persistence.flush().await?;
+        self.rev_snapshot.generate_named_snapshot(name).await
+    }
+
+    pub async fn read_named_snapshot(&self, name: &str) -> FlowyResult<Option<NamedRevisionSnapshot>> {
+        self.rev_snapshot.read_named_snapshot(name)
+    }
+
+    /// Returns all the named snapshots, the latest one first.
+    pub async fn read_named_snapshots(&self) -> FlowyResult<Vec<NamedRevisionSnapshot>> {
+        self.rev_snapshot.read_named_snapshots()
+    }
+
+    /// Merges the acked revisions that are covered by the latest snapshot into one revision, except
+    /// the latest `keep_last` revisions. Nothing will be compacted if there is no snapshot. Returns
+    /// the number of bytes that are reclaimed.
+    pub async fn compact_history(&self, keep_last: usize) -> FlowyResult<usize> {
+        self.rev_persistence.flush().await?;
+        match self.rev_snapshot.read_snapshots(1)?.pop() {
+            None => Ok(0),
+            Some(snapshot) => {
+                self.rev_persistence
+                    .compact_history(snapshot.rev_id, keep_last, &self.rev_compress)
+                    .await
+            }
+        }
+    }
+
+    /// Resets the object to the content of the snapshot. Returns the restored object.
+    ///
+    /// The content is saved as a new revision instead of reusing the snapshot's rev_id, so the
+    /// snapshots generated after restoring won't collide with the existing ones.
+    #[tracing::instrument(level = "debug", skip(self, snapshot), fields(snapshot_rev_id = snapshot.rev_id), err)]
+    pub async fn restore_snapshot<B>(&self, snapshot: RevisionSnapshot) -> FlowyResult<B::Output>
+    where
+        B: RevisionObjectDeserializer,
+    {
+        let (base_rev_id, rev_id) = self.next_rev_id_pair();
+        let revision = Revision::new(&self.object_id, base_rev_id, rev_id, snapshot.data, "".to_owned());
+        let object = B::deserialize_revisions(&self.object_id, vec![revision.clone()])?;
+        self.reset_object(vec![revision]).await?;
+        Ok(object)
+    }
+
     pub async fn load_revisions(&self) -> FlowyResult<Vec<Revision>> {
         let revisions = RevisionLoader {
             object_id: self.object_id.clone(),
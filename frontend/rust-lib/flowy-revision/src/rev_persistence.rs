@@ -6,7 +6,7 @@ use flowy_revision_persistence::{RevisionChangeset, RevisionDiskCache, RevisionS
 use revision_model::{Revision, RevisionRange};
 use std::collections::{HashMap, VecDeque};
 
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, sync::Arc, time::Duration};
 use tokio::sync::RwLock;
 use tokio::task::spawn_blocking;
 
@@ -15,29 +15,46 @@ pub const REVISION_WRITE_INTERVAL_IN_MILLIS: u64 = 600;
 #[derive(Clone)]
 pub struct RevisionPersistenceConfiguration {
     // If the number of revisions that didn't sync to the server greater than the merge_threshold
-    // then these revisions will be merged into one revision.
+    // then these revisions will be merged into one revision. The revisions won't be merged if the
+    // merge_threshold is 1.
     merge_threshold: usize,
 
     /// Indicates that the revisions that didn't sync to the server can be merged into one when
     /// `compact_lagging_revisions` get called.
     merge_lagging: bool,
+
+    /// The revisions are buffered in memory and written to disk in one batch once no revision has
+    /// been added for the `write_interval`.
+    write_interval: Duration,
+
+    /// The buffered revisions are written to disk immediately once the number of them reaches
+    /// `max_pending_writes`. Each revision is written as soon as it's added if it's 1.
+    max_pending_writes: usize,
 }
 
 impl RevisionPersistenceConfiguration {
     pub fn new(merge_threshold: usize, merge_lagging: bool) -> Self {
-        debug_assert!(merge_threshold > 1);
-        if merge_threshold > 1 {
-            Self {
-                merge_threshold,
-                merge_lagging,
-            }
-        } else {
-            Self {
-                merge_threshold: 100,
-                merge_lagging,
-            }
+        debug_assert!(merge_threshold > 0);
+        let merge_threshold = if merge_threshold > 0 { merge_threshold } else { 100 };
+        Self {
+            merge_threshold,
+            merge_lagging,
+            ..Default::default()
         }
     }
+
+    pub fn with_write_buffer(mut self, write_interval: Duration, max_pending_writes: usize) -> Self {
+        debug_assert!(max_pending_writes > 0);
+        self.write_interval = write_interval;
+        self.max_pending_writes = max_pending_writes.max(1);
+        self
+    }
+
+    /// Writes each revision to disk as soon as it's added.
+    pub fn without_write_buffer(mut self) -> Self {
+        self.max_pending_writes = 1;
+        self
+    }
 }
 
 impl std::default::Default for RevisionPersistenceConfiguration {
@@ -45,6 +62,8 @@ impl std::default::Default for RevisionPersistenceConfiguration {
         Self {
             merge_threshold: 100,
             merge_lagging: false,
+            write_interval: Duration::from_millis(REVISION_WRITE_INTERVAL_IN_MILLIS),
+            max_pending_writes: usize::MAX,
         }
     }
 }
@@ -87,7 +106,12 @@ where
         let object_id = object_id.to_owned();
         let user_id = user_id.to_owned();
         let sync_seq = RwLock::new(DeferSyncSequence::new());
-        let memory_cache = Arc::new(RevisionMemoryCache::new(&object_id, Arc::new(disk_cache.clone())));
+        let memory_cache = Arc::new(RevisionMemoryCache::new(
+            &object_id,
+            Arc::new(disk_cache.clone()),
+            configuration.write_interval,
+            configuration.max_pending_writes,
+        ));
         Self {
             user_id,
             object_id,
@@ -169,7 +193,7 @@ where
         // with the new_revision into one revision.
         let mut compact_seq = VecDeque::default();
         // tracing::info!("{}", compact_seq)
-        if sync_seq.compact_length >= self.configuration.merge_threshold - 1 {
+        if self.configuration.merge_threshold > 1 && sync_seq.compact_length >= self.configuration.merge_threshold - 1 {
             compact_seq.extend(sync_seq.compact());
         }
         if !compact_seq.is_empty() {
@@ -202,6 +226,59 @@ where
         }
     }
 
+    /// Merges the acked revisions whose rev_id is less than or equal to the `max_rev_id` into one
+    /// revision. The latest `keep_last` revisions and the revisions that are not acked yet are
+    /// never merged. Returns the number of bytes that are reclaimed.
+    #[tracing::instrument(level = "trace", skip(self, rev_compress), fields(object_id=%self.object_id), err)]
+    pub(crate) async fn compact_history<'a>(
+        &'a self,
+        max_rev_id: i64,
+        keep_last: usize,
+        rev_compress: &Arc<dyn RevisionMergeable + 'a>,
+    ) -> FlowyResult<usize> {
+        // Hold the lock, so no revisions can be added until the compaction is finished.
+        let _sync_seq = self.sync_seq.write().await;
+        let records = self.load_all_records(&self.object_id)?;
+        let compactable_len = records.len().saturating_sub(keep_last);
+        let records = records
+            .into_iter()
+            .take(compactable_len)
+            .take_while(|record| record.state == RevisionState::Ack && record.revision.rev_id <= max_rev_id)
+            .collect::<Vec<SyncRecord>>();
+        if records.len() < 2 {
+            return Ok(0);
+        }
+
+        let rev_ids = records
+            .iter()
+            .map(|record| record.revision.rev_id)
+            .collect::<Vec<i64>>();
+        let range = RevisionRange {
+            start: *rev_ids.first().unwrap(),
+            end: *rev_ids.last().unwrap(),
+        };
+        let compacted_size = records.iter().map(|record| record.revision.bytes.len()).sum::<usize>();
+        let revisions = records.into_iter().map(|record| record.revision).collect();
+        let merged_revision = rev_compress.merge_revisions(&self.user_id, &self.object_id, revisions)?;
+        let reclaimed_size = compacted_size.saturating_sub(merged_revision.bytes.len());
+
+        let record = SyncRecord {
+            revision: merged_revision,
+            state: RevisionState::Ack,
+            write_to_disk: false,
+        };
+        self.memory_cache.remove_with_range(&range);
+        self.disk_cache
+            .delete_and_insert_records(&self.object_id, Some(rev_ids), vec![record])?;
+        tracing::trace!("Compact {} revisions of {}", range.len(), self.object_id);
+        Ok(reclaimed_size)
+    }
+
+    /// Writes the revisions that are waiting in the memory cache to disk.
+    pub(crate) async fn flush(&self) -> FlowyResult<()> {
+        self.memory_cache.flush().await
+    }
+
     /// Remove the revision with rev_id from the sync sequence.
     pub(crate) async fn ack_revision(&self, rev_id: i64) -> FlowyResult<()> {
         if self.sync_seq.write().await.ack(&rev_id).is_ok() {
@@ -311,6 +388,15 @@ where
         Ok(records)
     }
 
+    /// Returns the records whose rev_ids are greater than the `rev_id`, in rev_id ascending order.
+    pub fn load_records_after(&self, object_id: &str, rev_id: i64) -> FlowyResult<Vec<SyncRecord>> {
+        let range = RevisionRange {
+            start: rev_id + 1,
+            end: i64::MAX,
+        };
+        self.disk_cache.read_revision_records_with_range(object_id, &range)
+    }
+
     // Read the revision which rev_id >= range.start && rev_id <= range.end
     pub async fn revisions_in_range(&self, range: &RevisionRange) -> FlowyResult<Vec<Revision>> {
         let range = range.clone();
@@ -3,7 +3,7 @@
 #![allow(unused_variables)]
 use crate::{RevIdCounter, RevisionMergeable, RevisionObjectDeserializer, RevisionPersistence};
 use bytes::Bytes;
-use flowy_error::FlowyResult;
+use flowy_error::{FlowyError, FlowyResult};
 use revision_model::Revision;
 use std::sync::atomic::AtomicI64;
 use std::sync::atomic::Ordering::SeqCst;
@@ -19,6 +19,63 @@ pub trait RevisionSnapshotDiskCache: Send + Sync {
     fn read_snapshot(&self, rev_id: i64) -> FlowyResult<Option<RevisionSnapshot>>;
 
     fn read_last_snapshot(&self) -> FlowyResult<Option<RevisionSnapshot>>;
+
+    /// Returns at most `limit` snapshots, the latest one first.
+    fn read_snapshots(&self, limit: usize) -> FlowyResult<Vec<RevisionSnapshot>> {
+        Ok(self.read_last_snapshot()?.into_iter().take(limit).collect())
+    }
+
+    /// Writes the snapshot with the name. The snapshot replaces the existing one with the same
+    /// name. The named snapshots are kept until they are deleted explicitly.
+    fn write_named_snapshot(&self, name: &str, rev_id: i64, data: Vec<u8>) -> FlowyResult<()> {
+        Err(FlowyError::internal().context("The named snapshots are not supported"))
+    }
+
+    fn read_named_snapshot(&self, name: &str) -> FlowyResult<Option<NamedRevisionSnapshot>> {
+        Ok(None)
+    }
+
+    /// Returns all the named snapshots, the latest one first.
+    fn read_named_snapshots(&self) -> FlowyResult<Vec<NamedRevisionSnapshot>> {
+        Ok(vec![])
+    }
+}
+
+impl<T> RevisionSnapshotDiskCache for Arc<T>
+where
+    T: RevisionSnapshotDiskCache + ?Sized,
+{
+    fn should_generate_snapshot_from_range(&self, start_rev_id: i64, current_rev_id: i64) -> bool {
+        (**self).should_generate_snapshot_from_range(start_rev_id, current_rev_id)
+    }
+
+    fn write_snapshot(&self, rev_id: i64, data: Vec<u8>) -> FlowyResult<()> {
+        (**self).write_snapshot(rev_id, data)
+    }
+
+    fn read_snapshot(&self, rev_id: i64) -> FlowyResult<Option<RevisionSnapshot>> {
+        (**self).read_snapshot(rev_id)
+    }
+
+    fn read_last_snapshot(&self) -> FlowyResult<Option<RevisionSnapshot>> {
+        (**self).read_last_snapshot()
+    }
+
+    fn read_snapshots(&self, limit: usize) -> FlowyResult<Vec<RevisionSnapshot>> {
+        (**self).read_snapshots(limit)
+    }
+
+    fn write_named_snapshot(&self, name: &str, rev_id: i64, data: Vec<u8>) -> FlowyResult<()> {
+        (**self).write_named_snapshot(name, rev_id, data)
+    }
+
+    fn read_named_snapshot(&self, name: &str) -> FlowyResult<Option<NamedRevisionSnapshot>> {
+        (**self).read_named_snapshot(name)
+    }
+
+    fn read_named_snapshots(&self) -> FlowyResult<Vec<NamedRevisionSnapshot>> {
+        (**self).read_named_snapshots()
+    }
 }
 
 /// Do nothing but just used to clam the rust compiler about the generic parameter `SP` of `RevisionManager`
@@ -86,6 +143,19 @@ where
         }
     }
 
+    /// Generates a snapshot with the name. Returns false if there is no revision to generate the
+    /// snapshot from.
+    pub async fn generate_named_snapshot(&self, name: &str) -> FlowyResult<bool> {
+        match self.generate_snapshot_data() {
+            None => Ok(false),
+            Some((rev_id, bytes)) => {
+                self.rev_snapshot_persistence
+                    .write_named_snapshot(name, rev_id, bytes.to_vec())?;
+                Ok(true)
+            }
+        }
+    }
+
     /// Find the nearest revision base on the passed-in rev_id
     #[tracing::instrument(level = "trace", skip_all)]
     pub fn restore_from_snapshot<B>(&self, rev_id: i64) -> Option<(B::Output, Revision)>
@@ -182,3 +252,11 @@ pub struct RevisionSnapshot {
     pub timestamp: i64,
     pub data: Bytes,
 }
+
+/// [NamedRevisionSnapshot] is a snapshot that was generated on demand with a name, instead of
+/// being generated automatically.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NamedRevisionSnapshot {
+    pub name: String,
+    pub snapshot: RevisionSnapshot,
+}
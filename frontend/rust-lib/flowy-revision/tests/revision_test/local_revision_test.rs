@@ -38,6 +38,31 @@ async fn revision_compress_2_revisions_with_2_threshold_test() {
     .await;
 }
 
+#[tokio::test]
+async fn revision_not_compress_with_threshold_1_test() {
+    let test = RevisionTest::new_with_configuration(1).await;
+    for content in ["1", "2", "3"] {
+        test.run_script(AddLocalRevision {
+            content: content.to_string(),
+        })
+        .await;
+    }
+
+    test.run_scripts(vec![
+        AssertNumberOfSyncRevisions { num: 3 },
+        AssertNextSyncRevisionId { rev_id: Some(1) },
+        AssertNextSyncRevisionContent {
+            expected: "1".to_string(),
+        },
+        AckRevision { rev_id: 1 },
+        AssertNextSyncRevisionId { rev_id: Some(2) },
+        AssertNextSyncRevisionContent {
+            expected: "2".to_string(),
+        },
+    ])
+    .await;
+}
+
 #[tokio::test]
 async fn revision_compress_4_revisions_with_threshold_2_test() {
     let test = RevisionTest::new_with_configuration(2).await;
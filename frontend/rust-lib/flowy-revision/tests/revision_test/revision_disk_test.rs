@@ -1,5 +1,8 @@
 use crate::revision_test::script::RevisionScript::*;
 use crate::revision_test::script::{InvalidRevisionObject, RevisionTest};
+use flowy_revision::RevisionPersistenceConfiguration;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
 
 #[tokio::test]
 async fn revision_write_to_disk_test() {
@@ -83,3 +86,96 @@ async fn revision_read_from_disk_with_invalid_record_test() {
     }])
     .await;
 }
+
+async fn add_local_revisions(test: &RevisionTest, num: usize) {
+    for i in 0..num {
+        test.run_script(AddLocalRevision {
+            content: format!("{}", i),
+        })
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn revision_write_buffer_reduce_disk_writes_test() {
+    let unbuffered_test = RevisionTest::new_with_persistence_configuration(
+        RevisionPersistenceConfiguration::new(1, false).without_write_buffer(),
+    )
+    .await;
+    add_local_revisions(&unbuffered_test, 40).await;
+    unbuffered_test
+        .run_scripts(vec![
+            AssertNumberOfDiskWrites { num: 40 },
+            AssertNumberOfRevisionsInDisk { num: 40 },
+        ])
+        .await;
+
+    let configuration = RevisionPersistenceConfiguration::new(1, false).with_write_buffer(Duration::from_secs(10), 20);
+    let buffered_test = RevisionTest::new_with_persistence_configuration(configuration).await;
+    add_local_revisions(&buffered_test, 40).await;
+    buffered_test
+        .run_scripts(vec![
+            AssertNumberOfDiskWrites { num: 2 },
+            AssertNumberOfRevisionsInDisk { num: 40 },
+        ])
+        .await;
+
+    let unbuffered_writes = unbuffered_test.number_of_disk_writes().load(Ordering::SeqCst);
+    let buffered_writes = buffered_test.number_of_disk_writes().load(Ordering::SeqCst);
+    assert!(buffered_writes * 10 < unbuffered_writes);
+}
+
+#[tokio::test]
+async fn revision_write_buffer_write_after_interval_test() {
+    let configuration =
+        RevisionPersistenceConfiguration::new(1, false).with_write_buffer(Duration::from_millis(100), 20);
+    let test = RevisionTest::new_with_persistence_configuration(configuration).await;
+    add_local_revisions(&test, 5).await;
+    test.run_scripts(vec![
+        AssertNumberOfDiskWrites { num: 0 },
+        WaitWhenWriteToDisk,
+        AssertNumberOfDiskWrites { num: 1 },
+        AssertNumberOfRevisionsInDisk { num: 5 },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn revision_write_buffer_flush_test() {
+    let configuration = RevisionPersistenceConfiguration::new(1, false).with_write_buffer(Duration::from_secs(10), 20);
+    let test = RevisionTest::new_with_persistence_configuration(configuration).await;
+    add_local_revisions(&test, 5).await;
+    test.run_scripts(vec![
+        AssertNumberOfDiskWrites { num: 0 },
+        FlushRevisions,
+        AssertNumberOfDiskWrites { num: 1 },
+        AssertNumberOfRevisionsInDisk { num: 5 },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn revision_write_buffer_write_when_closed_test() {
+    let configuration = RevisionPersistenceConfiguration::new(1, false).with_write_buffer(Duration::from_secs(10), 20);
+    let test = RevisionTest::new_with_persistence_configuration(configuration).await;
+    add_local_revisions(&test, 5).await;
+    test.run_scripts(vec![
+        AssertNumberOfDiskWrites { num: 0 },
+        CloseRevisionManager,
+        AssertNumberOfDiskWrites { num: 1 },
+        AssertNumberOfRevisionsInDisk { num: 5 },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn revision_write_buffer_write_when_dropped_test() {
+    let configuration = RevisionPersistenceConfiguration::new(1, false).with_write_buffer(Duration::from_secs(10), 20);
+    let test = RevisionTest::new_with_persistence_configuration(configuration).await;
+    add_local_revisions(&test, 5).await;
+    test.run_script(AssertNumberOfDiskWrites { num: 0 }).await;
+
+    // The revisions that are waiting for the write interval are written when the manager is dropped
+    let test = RevisionTest::new_after_dropping(test).await;
+    test.run_script(AssertNumberOfRevisionsInDisk { num: 5 }).await;
+}
@@ -11,6 +11,7 @@ use nanoid::nanoid;
 use parking_lot::RwLock;
 use revision_model::{Revision, RevisionRange};
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -23,6 +24,9 @@ pub enum RevisionScript {
     AssertNumberOfSyncRevisions { num: usize },
     AssertNumberOfRevisionsInDisk { num: usize },
     AssertNextSyncRevisionContent { expected: String },
+    AssertNumberOfDiskWrites { num: usize },
+    FlushRevisions,
+    CloseRevisionManager,
     WaitWhenWriteToDisk,
 }
 
@@ -31,6 +35,8 @@ pub struct RevisionTest {
     object_id: String,
     configuration: RevisionPersistenceConfiguration,
     rev_manager: Arc<RevisionManager<RevisionConnectionMock>>,
+    number_of_disk_writes: Arc<AtomicUsize>,
+    disk_records: Arc<RwLock<Vec<SyncRecord>>>,
 }
 
 impl RevisionTest {
@@ -39,10 +45,16 @@ impl RevisionTest {
     }
 
     pub async fn new_with_configuration(merge_threshold: i64) -> Self {
+        let configuration = RevisionPersistenceConfiguration::new(merge_threshold as usize, false);
+        Self::new_with_persistence_configuration(configuration).await
+    }
+
+    pub async fn new_with_persistence_configuration(configuration: RevisionPersistenceConfiguration) -> Self {
         let user_id = nanoid!(10);
         let object_id = nanoid!(6);
-        let configuration = RevisionPersistenceConfiguration::new(merge_threshold as usize, false);
         let disk_cache = RevisionDiskCacheMock::new(vec![]);
+        let number_of_disk_writes = disk_cache.number_of_writes.clone();
+        let disk_records = disk_cache.records.clone();
         let persistence = RevisionPersistence::new(&user_id, &object_id, disk_cache, configuration.clone());
         let compress = RevisionMergeableMock {};
         let snapshot = RevisionSnapshotMock {};
@@ -53,12 +65,16 @@ impl RevisionTest {
             object_id,
             configuration,
             rev_manager: Arc::new(rev_manager),
+            number_of_disk_writes,
+            disk_records,
         }
     }
 
     pub async fn new_with_other(old_test: RevisionTest) -> Self {
         let records = old_test.rev_manager.get_all_revision_records().unwrap();
         let disk_cache = RevisionDiskCacheMock::new(records);
+        let number_of_disk_writes = disk_cache.number_of_writes.clone();
+        let disk_records = disk_cache.records.clone();
         let configuration = old_test.configuration;
         let persistence = RevisionPersistence::new(
             &old_test.user_id,
@@ -77,8 +93,50 @@ impl RevisionTest {
             object_id: old_test.object_id,
             configuration,
             rev_manager: Arc::new(rev_manager),
+            number_of_disk_writes,
+            disk_records,
         }
     }
+
+    /// Drops the revision manager of the `old_test` without closing it, and opens the object again
+    /// with the revisions that were written to its disk cache.
+    pub async fn new_after_dropping(old_test: RevisionTest) -> Self {
+        let RevisionTest {
+            user_id,
+            object_id,
+            configuration,
+            rev_manager,
+            disk_records,
+            ..
+        } = old_test;
+        drop(rev_manager);
+        // The queue of the manager holds the persistence until it sees the manager is gone.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let records = disk_records.read().clone();
+        let disk_cache = RevisionDiskCacheMock::new(records);
+        let number_of_disk_writes = disk_cache.number_of_writes.clone();
+        let disk_records = disk_cache.records.clone();
+        let persistence = RevisionPersistence::new(&user_id, &object_id, disk_cache, configuration.clone());
+        let compress = RevisionMergeableMock {};
+        let snapshot = RevisionSnapshotMock {};
+        let mut rev_manager = RevisionManager::new(&user_id, &object_id, persistence, compress, snapshot);
+        rev_manager.initialize::<RevisionObjectMockSerde>(None).await.unwrap();
+        Self {
+            user_id,
+            object_id,
+            configuration,
+            rev_manager: Arc::new(rev_manager),
+            number_of_disk_writes,
+            disk_records,
+        }
+    }
+
+    /// Returns the number of times the revisions were written to the disk cache.
+    pub fn number_of_disk_writes(&self) -> Arc<AtomicUsize> {
+        self.number_of_disk_writes.clone()
+    }
+
     pub async fn run_scripts(&self, scripts: Vec<RevisionScript>) {
         for script in scripts {
             self.run_script(script).await;
@@ -132,6 +190,15 @@ impl RevisionTest {
                 let object = RevisionObjectMock::from_bytes(&revision.bytes).unwrap();
                 assert_eq!(object.content, expected);
             }
+            RevisionScript::AssertNumberOfDiskWrites { num } => {
+                assert_eq!(self.number_of_disk_writes.load(Ordering::SeqCst), num)
+            }
+            RevisionScript::FlushRevisions => {
+                self.rev_manager.flush().await.unwrap();
+            }
+            RevisionScript::CloseRevisionManager => {
+                self.rev_manager.close().await;
+            }
             RevisionScript::WaitWhenWriteToDisk => {
                 let milliseconds = 2 * REVISION_WRITE_INTERVAL_IN_MILLIS;
                 tokio::time::sleep(Duration::from_millis(milliseconds)).await;
@@ -141,13 +208,15 @@ impl RevisionTest {
 }
 
 pub struct RevisionDiskCacheMock {
-    records: RwLock<Vec<SyncRecord>>,
+    records: Arc<RwLock<Vec<SyncRecord>>>,
+    number_of_writes: Arc<AtomicUsize>,
 }
 
 impl RevisionDiskCacheMock {
     pub fn new(records: Vec<SyncRecord>) -> Self {
         Self {
-            records: RwLock::new(records),
+            records: Arc::new(RwLock::new(records)),
+            number_of_writes: Arc::new(AtomicUsize::new(0)),
         }
     }
 }
@@ -156,6 +225,7 @@ impl RevisionDiskCache<RevisionConnectionMock> for RevisionDiskCacheMock {
     type Error = FlowyError;
 
     fn create_revision_records(&self, revision_records: Vec<SyncRecord>) -> Result<(), Self::Error> {
+        self.number_of_writes.fetch_add(1, Ordering::SeqCst);
         self.records.write().extend(revision_records);
         Ok(())
     }
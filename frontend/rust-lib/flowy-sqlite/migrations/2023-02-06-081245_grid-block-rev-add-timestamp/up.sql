@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE grid_meta_rev_table ADD COLUMN timestamp BIGINT NOT NULL DEFAULT 0;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE grid_rev_table DROP COLUMN timestamp;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE grid_rev_snapshot DROP COLUMN name;
\ No newline at end of file
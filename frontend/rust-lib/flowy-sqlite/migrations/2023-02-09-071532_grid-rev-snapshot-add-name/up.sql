@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE grid_rev_snapshot ADD COLUMN name TEXT NOT NULL DEFAULT '';
\ No newline at end of file
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE grid_rev_table DROP COLUMN user_id;
+ALTER TABLE grid_rev_table DROP COLUMN device_id;
+ALTER TABLE grid_meta_rev_table DROP COLUMN user_id;
+ALTER TABLE grid_meta_rev_table DROP COLUMN device_id;
+ALTER TABLE grid_view_rev_table DROP COLUMN timestamp;
+ALTER TABLE grid_view_rev_table DROP COLUMN user_id;
+ALTER TABLE grid_view_rev_table DROP COLUMN device_id;
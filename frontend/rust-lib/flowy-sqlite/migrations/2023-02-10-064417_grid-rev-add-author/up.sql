@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE grid_rev_table ADD COLUMN user_id TEXT NOT NULL DEFAULT '';
+ALTER TABLE grid_rev_table ADD COLUMN device_id TEXT NOT NULL DEFAULT '';
+ALTER TABLE grid_meta_rev_table ADD COLUMN user_id TEXT NOT NULL DEFAULT '';
+ALTER TABLE grid_meta_rev_table ADD COLUMN device_id TEXT NOT NULL DEFAULT '';
+ALTER TABLE grid_view_rev_table ADD COLUMN timestamp BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE grid_view_rev_table ADD COLUMN user_id TEXT NOT NULL DEFAULT '';
+ALTER TABLE grid_view_rev_table ADD COLUMN device_id TEXT NOT NULL DEFAULT '';
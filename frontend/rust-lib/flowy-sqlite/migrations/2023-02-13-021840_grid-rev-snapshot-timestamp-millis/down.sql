@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+UPDATE grid_rev_snapshot SET timestamp = timestamp / 1000 WHERE timestamp >= 100000000000;
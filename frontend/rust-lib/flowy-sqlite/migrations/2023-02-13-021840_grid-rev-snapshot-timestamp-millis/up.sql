@@ -0,0 +1,3 @@
+-- Your SQL goes here
+-- The timestamps of the snapshots were in seconds, convert them to milliseconds.
+UPDATE grid_rev_snapshot SET timestamp = timestamp * 1000 WHERE timestamp < 100000000000;
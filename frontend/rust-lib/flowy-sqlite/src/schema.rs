@@ -63,6 +63,9 @@ diesel::table! {
         rev_id -> BigInt,
         data -> Binary,
         state -> Integer,
+        timestamp -> BigInt,
+        user_id -> Text,
+        device_id -> Text,
     }
 }
 
@@ -74,6 +77,7 @@ diesel::table! {
         base_rev_id -> BigInt,
         timestamp -> BigInt,
         data -> Binary,
+        name -> Text,
     }
 }
 
@@ -85,6 +89,9 @@ diesel::table! {
         rev_id -> BigInt,
         data -> Binary,
         state -> Integer,
+        timestamp -> BigInt,
+        user_id -> Text,
+        device_id -> Text,
     }
 }
 
@@ -96,6 +103,9 @@ diesel::table! {
         rev_id -> BigInt,
         data -> Binary,
         state -> Integer,
+        timestamp -> BigInt,
+        user_id -> Text,
+        device_id -> Text,
     }
 }
 
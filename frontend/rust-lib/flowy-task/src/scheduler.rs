@@ -111,6 +111,11 @@ impl TaskDispatcher {
         }
     }
 
+    /// Cancels all the tasks, that are not processed yet, of the handler with `handler_id`.
+    pub fn cancel_tasks_of_handler(&mut self, handler_id: &str) {
+        self.store.cancel_tasks_of_handler(handler_id);
+    }
+
     pub fn next_task_id(&self) -> TaskId {
         self.store.next_task_id()
     }
@@ -33,6 +33,13 @@ impl TaskStore {
         self.tasks.get(task_id)
     }
 
+    pub(crate) fn cancel_tasks_of_handler(&mut self, handler_id: &str) {
+        self.tasks
+            .values_mut()
+            .filter(|task| task.handler_id == handler_id)
+            .for_each(|task| task.set_state(TaskState::Cancel));
+    }
+
     pub(crate) fn clear(&mut self) {
         let tasks = mem::take(&mut self.tasks);
         tasks.into_values().for_each(|mut task| {
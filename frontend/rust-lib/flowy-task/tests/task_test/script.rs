@@ -25,6 +25,9 @@ pub enum SearchScript {
     CancelTask {
         task_id: TaskId,
     },
+    CancelTasksOfHandler {
+        handler_id: String,
+    },
     UnregisterHandler {
         handler_id: String,
     },
@@ -83,6 +86,9 @@ impl SearchTest {
             SearchScript::Wait { millisecond } => {
                 tokio::time::sleep(Duration::from_millis(millisecond)).await;
             }
+            SearchScript::CancelTasksOfHandler { handler_id } => {
+                self.scheduler.write().await.cancel_tasks_of_handler(&handler_id);
+            }
             SearchScript::UnregisterHandler { handler_id } => {
                 self.scheduler.write().await.unregister_handler(handler_id).await;
             }
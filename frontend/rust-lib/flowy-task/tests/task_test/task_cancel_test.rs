@@ -33,6 +33,35 @@ async fn task_cancel_background_task_test() {
     assert_eq!(result.state, TaskState::Cancel);
 }
 
+#[tokio::test]
+async fn task_cancel_tasks_of_handler_test() {
+    let test = SearchTest::new().await;
+    let (task_1, ret_1) = make_text_background_task(test.next_task_id().await, "Hello world");
+    let (task_2, ret_2) = make_text_background_task(test.next_task_id().await, "");
+    let handler_id = task_1.handler_id.clone();
+    test.run_scripts(vec![
+        AddTasks {
+            tasks: vec![task_1, task_2],
+        },
+        CancelTasksOfHandler { handler_id },
+        AssertTaskStatus {
+            task_id: 1,
+            expected_status: TaskState::Cancel,
+        },
+        AssertTaskStatus {
+            task_id: 2,
+            expected_status: TaskState::Cancel,
+        },
+    ])
+    .await;
+
+    let result = ret_1.await.unwrap();
+    assert_eq!(result.state, TaskState::Cancel);
+
+    let result = ret_2.await.unwrap();
+    assert_eq!(result.state, TaskState::Cancel);
+}
+
 #[tokio::test]
 async fn task_with_empty_handler_id_test() {
     let test = SearchTest::new().await;
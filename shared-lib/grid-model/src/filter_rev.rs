@@ -1,5 +1,6 @@
 use crate::FieldTypeRevision;
 use serde::{Deserialize, Serialize};
+use serde_repr::*;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, Hash)]
 pub struct FilterRevision {
@@ -10,3 +11,170 @@ pub struct FilterRevision {
     #[serde(default)]
     pub content: String,
 }
+
+/// The filter groups can be nested, the root group is at the depth of 1.
+pub const MAX_FILTER_GROUP_DEPTH: usize = 3;
+
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum FilterOperatorRevision {
+    And = 0,
+    Or = 1,
+}
+
+impl std::default::Default for FilterOperatorRevision {
+    fn default() -> Self {
+        FilterOperatorRevision::And
+    }
+}
+
+/// [FilterGroupRevision] combines its children with the operator. A child is either a filter,
+/// referenced by its id, or a nested group.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, Hash)]
+pub struct FilterGroupRevision {
+    pub id: String,
+    pub operator: FilterOperatorRevision,
+    #[serde(default)]
+    pub children: Vec<FilterNodeRevision>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum FilterNodeRevision {
+    Filter(String),
+    Group(FilterGroupRevision),
+}
+
+impl FilterGroupRevision {
+    /// Returns the group that combines the filters with the `And` operator, which is how the
+    /// filters of the views that have no filter groups are combined.
+    pub fn with_filters(id: &str, filter_ids: Vec<String>) -> Self {
+        Self {
+            id: id.to_owned(),
+            operator: FilterOperatorRevision::And,
+            children: filter_ids.into_iter().map(FilterNodeRevision::Filter).collect(),
+        }
+    }
+
+    /// Returns the depth of the group, a group without nested groups is at the depth of 1.
+    pub fn depth(&self) -> usize {
+        1 + self
+            .children
+            .iter()
+            .map(|child| match child {
+                FilterNodeRevision::Filter(_) => 0,
+                FilterNodeRevision::Group(group) => group.depth(),
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the ids of the filters in the group and its nested groups.
+    pub fn filter_ids(&self) -> Vec<&str> {
+        let mut filter_ids = vec![];
+        for child in self.children.iter() {
+            match child {
+                FilterNodeRevision::Filter(filter_id) => filter_ids.push(filter_id.as_str()),
+                FilterNodeRevision::Group(group) => filter_ids.extend(group.filter_ids()),
+            }
+        }
+        filter_ids
+    }
+
+    pub fn push_filter(&mut self, filter_id: &str) {
+        self.children.push(FilterNodeRevision::Filter(filter_id.to_owned()));
+    }
+
+    /// Removes the filter from the group and its nested groups, the nested groups that become
+    /// empty are removed too. Returns true if the filter was found.
+    pub fn remove_filter(&mut self, filter_id: &str) -> bool {
+        let mut is_removed = false;
+        self.children.retain_mut(|child| match child {
+            FilterNodeRevision::Filter(id) => {
+                let is_matched = id == filter_id;
+                is_removed |= is_matched;
+                !is_matched
+            }
+            FilterNodeRevision::Group(group) => {
+                is_removed |= group.remove_filter(filter_id);
+                !group.children.is_empty()
+            }
+        });
+        is_removed
+    }
+}
+
+/// [FilterSetRevision] is the named snapshot of the filters of a view and the groups that combine
+/// them, so the filters can be switched to the saved ones at once.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct FilterSetRevision {
+    pub name: String,
+    #[serde(default)]
+    pub filters: Vec<FilterRevision>,
+    /// It's None if the filters of the view were never grouped when the set was saved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_tree: Option<FilterGroupRevision>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{FilterGroupRevision, FilterNodeRevision, FilterOperatorRevision};
+
+    fn group(id: &str, operator: FilterOperatorRevision, children: Vec<FilterNodeRevision>) -> FilterGroupRevision {
+        FilterGroupRevision {
+            id: id.to_owned(),
+            operator,
+            children,
+        }
+    }
+
+    #[test]
+    fn filter_group_depth_and_filter_ids_test() {
+        let nested = group(
+            "2",
+            FilterOperatorRevision::Or,
+            vec![
+                FilterNodeRevision::Filter("a".to_owned()),
+                FilterNodeRevision::Filter("b".to_owned()),
+            ],
+        );
+        let root = group(
+            "1",
+            FilterOperatorRevision::And,
+            vec![
+                FilterNodeRevision::Group(nested),
+                FilterNodeRevision::Filter("c".to_owned()),
+            ],
+        );
+        assert_eq!(root.depth(), 2);
+        assert_eq!(root.filter_ids(), vec!["a", "b", "c"]);
+        assert_eq!(FilterGroupRevision::with_filters("1", vec![]).depth(), 1);
+    }
+
+    #[test]
+    fn filter_group_remove_filter_test() {
+        let nested = group(
+            "2",
+            FilterOperatorRevision::Or,
+            vec![FilterNodeRevision::Filter("a".to_owned())],
+        );
+        let mut root = group(
+            "1",
+            FilterOperatorRevision::And,
+            vec![
+                FilterNodeRevision::Group(nested),
+                FilterNodeRevision::Filter("b".to_owned()),
+            ],
+        );
+        assert!(root.remove_filter("a"));
+        assert_eq!(root.children, vec![FilterNodeRevision::Filter("b".to_owned())]);
+        assert!(!root.remove_filter("a"));
+    }
+
+    #[test]
+    fn filter_group_serde_test() {
+        let root = FilterGroupRevision::with_filters("1", vec!["a".to_owned()]);
+        let s = serde_json::to_string(&root).unwrap();
+        assert_eq!(s, r#"{"id":"1","operator":0,"children":[{"Filter":"a"}]}"#);
+        assert_eq!(serde_json::from_str::<FilterGroupRevision>(&s).unwrap(), root);
+    }
+}
@@ -8,6 +8,10 @@ pub fn gen_row_id() -> String {
     nanoid!(6)
 }
 
+pub fn gen_comment_id() -> String {
+    nanoid!(10)
+}
+
 pub const DEFAULT_ROW_HEIGHT: i32 = 42;
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -28,6 +32,30 @@ pub struct RowRevision {
     pub cells: IndexMap<FieldId, CellRevision>,
     pub height: i32,
     pub visibility: bool,
+    /// The time in seconds when the row was created. It's 0 for the rows that were created before
+    /// the time was recorded.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub created_at: i64,
+    /// The time in seconds when any cell of the row was changed last time.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub modified_at: i64,
+    /// comments contains the comment threads of the cells. They are kept outside of the cells, so
+    /// the comments are not lost when the cell data is transformed to another field type.
+    /// key: field id,
+    /// value: the comments in the order they were added
+    #[serde(default, with = "indexmap::serde_seq", skip_serializing_if = "IndexMap::is_empty")]
+    pub comments: IndexMap<FieldId, Vec<CellCommentRevision>>,
+    /// The icon, cover and color of the row that are shown on the cards of the board and the
+    /// calendar.
+    #[serde(default, skip_serializing_if = "RowMetaRevision::is_empty")]
+    pub meta: RowMetaRevision,
+    /// The id of the document that the row opens into.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_id: Option<String>,
+}
+
+fn is_zero(value: &i64) -> bool {
+    *value == 0
 }
 
 impl RowRevision {
@@ -38,9 +66,74 @@ impl RowRevision {
             cells: Default::default(),
             height: DEFAULT_ROW_HEIGHT,
             visibility: true,
+            created_at: 0,
+            modified_at: 0,
+            comments: Default::default(),
+            meta: Default::default(),
+            document_id: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RowMetaRevision {
+    /// The emoji that is used as the icon of the row.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// The reference to the cover image of the row, e.g. the url or the id of the attachment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cover: Option<String>,
+    /// The accent color of the row.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+}
+
+impl RowMetaRevision {
+    pub fn is_empty(&self) -> bool {
+        self.icon.is_none() && self.cover.is_none() && self.color.is_none()
+    }
+
+    /// Applies the changeset and returns the changeset that reverts it. Returns None if nothing
+    /// is changed.
+    pub fn apply_changeset(&mut self, changeset: RowMetaChangeset) -> Option<RowMetaChangeset> {
+        let mut reverted = RowMetaChangeset::default();
+        apply_meta_value(&mut self.icon, changeset.icon, &mut reverted.icon);
+        apply_meta_value(&mut self.cover, changeset.cover, &mut reverted.cover);
+        apply_meta_value(&mut self.color, changeset.color, &mut reverted.color);
+        if reverted.is_empty() {
+            None
+        } else {
+            Some(reverted)
+        }
+    }
+}
+
+/// The empty string clears the value.
+fn apply_meta_value(value: &mut Option<String>, new_value: Option<String>, reverted: &mut Option<String>) {
+    if let Some(new_value) = new_value {
+        let new_value = if new_value.is_empty() { None } else { Some(new_value) };
+        if *value != new_value {
+            *reverted = Some(value.take().unwrap_or_default());
+            *value = new_value;
         }
     }
 }
+
+/// The changes of the [RowMetaRevision]. The value that is None is left unchanged, and the
+/// value that is empty is cleared.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RowMetaChangeset {
+    pub icon: Option<String>,
+    pub cover: Option<String>,
+    pub color: Option<String>,
+}
+
+impl RowMetaChangeset {
+    pub fn is_empty(&self) -> bool {
+        self.icon.is_none() && self.cover.is_none() && self.color.is_none()
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct RowChangeset {
     pub row_id: String,
@@ -49,6 +142,11 @@ pub struct RowChangeset {
     // Contains the key/value changes represents as the update of the cells. For example,
     // if there is one cell was changed, then the `cell_by_field_id` will only have one key/value.
     pub cell_by_field_id: HashMap<FieldId, CellRevision>,
+    pub modified_at: Option<i64>,
+    pub meta: Option<RowMetaChangeset>,
+    /// The id of the document that the row opens into. The empty id clears the document of the
+    /// row.
+    pub document_id: Option<String>,
 }
 
 impl RowChangeset {
@@ -58,11 +156,19 @@ impl RowChangeset {
             height: None,
             visibility: None,
             cell_by_field_id: Default::default(),
+            modified_at: None,
+            meta: None,
+            document_id: None,
         }
     }
 
     pub fn is_empty(&self) -> bool {
-        self.height.is_none() && self.visibility.is_none() && self.cell_by_field_id.is_empty()
+        self.height.is_none()
+            && self.visibility.is_none()
+            && self.cell_by_field_id.is_empty()
+            && self.modified_at.is_none()
+            && self.meta.is_none()
+            && self.document_id.is_none()
     }
 }
 
@@ -70,14 +176,97 @@ impl RowChangeset {
 pub struct CellRevision {
     #[serde(rename = "data")]
     pub type_cell_data: String,
+    /// The data of the cell that couldn't be converted when the field was switched to another
+    /// field type. It's restored when the field is switched back to the field type of the data.
+    /// It's dropped once the cell is updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shadow: Option<String>,
 }
 
 impl CellRevision {
     pub fn new(data: String) -> Self {
-        Self { type_cell_data: data }
+        Self {
+            type_cell_data: data,
+            shadow: None,
+        }
     }
 
     pub fn is_empty(&self) -> bool {
         self.type_cell_data.is_empty()
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CellCommentRevision {
+    pub id: String,
+    /// The id of the user who wrote the comment.
+    pub author: String,
+    pub content: String,
+    /// The time in seconds when the comment was added.
+    pub created_at: i64,
+}
+
+impl CellCommentRevision {
+    pub fn new(author: String, content: String, created_at: i64) -> Self {
+        Self {
+            id: gen_comment_id(),
+            author,
+            content,
+            created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{RowMetaChangeset, RowRevision};
+
+    #[test]
+    fn row_revision_without_meta_serde_test() {
+        // The row that was serialized before the meta was added.
+        let json = include_str!("../tests/fixtures/row_revision.json");
+        let row_rev: RowRevision = serde_json::from_str(json).unwrap();
+        assert_eq!(row_rev.height, 60);
+        assert_eq!(row_rev.cells.len(), 1);
+        assert!(row_rev.meta.is_empty());
+        assert!(row_rev.document_id.is_none());
+
+        // The empty meta is not serialized.
+        assert!(!serde_json::to_string(&row_rev).unwrap().contains("meta"));
+    }
+
+    #[test]
+    fn row_revision_meta_serde_test() {
+        let mut row_rev = RowRevision::new("1");
+        row_rev.meta.icon = Some("🚀".to_owned());
+        row_rev.meta.color = Some("#FF8800".to_owned());
+        let json = serde_json::to_string(&row_rev).unwrap();
+        let deserialized_row_rev: RowRevision = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized_row_rev, row_rev);
+    }
+
+    #[test]
+    fn row_meta_apply_changeset_test() {
+        let mut row_rev = RowRevision::new("1");
+        let changeset = RowMetaChangeset {
+            icon: Some("🚀".to_owned()),
+            cover: Some("cover.png".to_owned()),
+            color: None,
+        };
+        let reverted = row_rev.meta.apply_changeset(changeset).unwrap();
+        assert_eq!(row_rev.meta.icon.as_deref(), Some("🚀"));
+        assert_eq!(row_rev.meta.cover.as_deref(), Some("cover.png"));
+        assert!(row_rev.meta.color.is_none());
+
+        // Applying the same values again changes nothing.
+        let changeset = RowMetaChangeset {
+            icon: Some("🚀".to_owned()),
+            ..Default::default()
+        };
+        assert!(row_rev.meta.apply_changeset(changeset).is_none());
+
+        // The reverted changeset clears the values that were added.
+        assert!(row_rev.meta.apply_changeset(reverted).is_some());
+        assert!(row_rev.meta.is_empty());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::DatabaseBlockRevision;
+use crate::{DatabaseBlockRevision, RowRevision};
 use bytes::Bytes;
 use indexmap::IndexMap;
 use nanoid::nanoid;
@@ -21,16 +21,24 @@ pub fn gen_field_id() -> String {
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DatabaseRevision {
     pub grid_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub name: String,
     pub fields: Vec<Arc<FieldRevision>>,
     pub blocks: Vec<Arc<GridBlockMetaRevision>>,
+    /// The rows that were deleted but not yet purged. They are kept outside of the blocks, so they
+    /// are excluded from the rows of the views until they are restored.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trashed_rows: Vec<Arc<TrashedRowRevision>>,
 }
 
 impl DatabaseRevision {
     pub fn new(grid_id: &str) -> Self {
         Self {
             grid_id: grid_id.to_owned(),
+            name: "".to_owned(),
             fields: vec![],
             blocks: vec![],
+            trashed_rows: vec![],
         }
     }
 
@@ -41,12 +49,25 @@ impl DatabaseRevision {
     ) -> Self {
         Self {
             grid_id: grid_id.to_owned(),
+            name: "".to_owned(),
             fields: field_revs,
             blocks: block_metas.into_iter().map(Arc::new).collect(),
+            trashed_rows: vec![],
         }
     }
 }
 
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrashedRowRevision {
+    pub row: RowRevision,
+    /// The id of the row that was right before the deleted row in its block. The row is restored
+    /// after it if it still exists.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev_row_id: Option<String>,
+    /// The time in seconds when the row was moved to the trash.
+    pub deleted_at: i64,
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GridBlockMetaRevision {
     pub block_id: String,
@@ -116,6 +137,18 @@ pub struct FieldRevision {
 
     #[serde(default = "DEFAULT_IS_PRIMARY")]
     pub is_primary: bool,
+
+    /// The description that is shown as the tooltip of the field.
+    #[serde(default)]
+    pub description: String,
+
+    /// The icon of the field, it's empty if the field uses the icon of its field type.
+    #[serde(default)]
+    pub icon: String,
+
+    /// The cells, the field type and the type-option of a locked field can't be edited.
+    #[serde(default)]
+    pub locked: bool,
 }
 
 impl AsRef<FieldRevision> for FieldRevision {
@@ -144,6 +177,9 @@ impl FieldRevision {
             width,
             type_options: Default::default(),
             is_primary,
+            description: "".to_owned(),
+            icon: "".to_owned(),
+            locked: false,
         }
     }
 
@@ -219,3 +255,32 @@ impl std::convert::TryFrom<Bytes> for BuildDatabaseContext {
 }
 
 pub type FieldTypeRevision = u8;
+
+#[cfg(test)]
+mod tests {
+    use crate::FieldRevision;
+
+    #[test]
+    fn field_revision_without_description_and_icon_serde_test() {
+        // The field that was serialized before the description and the icon were added.
+        let json = include_str!("../tests/fixtures/field_revision.json");
+        let field_rev: FieldRevision = serde_json::from_str(json).unwrap();
+        assert_eq!(field_rev.id, "Vb4fZR");
+        assert_eq!(field_rev.name, "Price");
+        assert_eq!(field_rev.width, 150);
+        assert_eq!(field_rev.type_options.len(), 1);
+        assert!(field_rev.description.is_empty());
+        assert!(field_rev.icon.is_empty());
+        assert!(!field_rev.locked);
+    }
+
+    #[test]
+    fn field_revision_description_and_icon_serde_test() {
+        let mut field_rev = FieldRevision::new("Price", "", 1u8, 150, false);
+        field_rev.description = "The price of the product".to_owned();
+        field_rev.icon = "💰".to_owned();
+        let json = serde_json::to_string(&field_rev).unwrap();
+        let deserialized_field_rev: FieldRevision = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized_field_rev, field_rev);
+    }
+}
@@ -99,6 +99,14 @@ where
         objects
     }
 
+    /// Returns the objects of the field, regardless of their field types.
+    pub fn get_objects_of_field(&self, field_id: &str) -> Vec<Arc<T>> {
+        self.inner
+            .get(field_id)
+            .map(|object_map| object_map.all_objects())
+            .unwrap_or_default()
+    }
+
     pub fn get_all_objects(&self) -> Vec<Arc<T>> {
         self.inner.values().flat_map(|map| map.all_objects()).collect()
     }
@@ -116,6 +124,14 @@ where
             .push(Arc::new(object))
     }
 
+    /// Removes the objects of the field, whatever their field types. Returns the removed objects.
+    pub fn remove_objects(&mut self, field_id: &str) -> Vec<Arc<T>> {
+        self.inner
+            .shift_remove(field_id)
+            .map(|object_map| object_map.all_objects())
+            .unwrap_or_default()
+    }
+
     pub fn clear(&mut self) {
         self.inner.clear()
     }
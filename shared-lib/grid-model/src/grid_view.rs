@@ -1,7 +1,11 @@
-use crate::{FilterConfiguration, GroupConfiguration, SortConfiguration};
+use crate::{
+    FilterConfiguration, FilterGroupRevision, FilterSetRevision, GroupConfiguration, SortConfiguration,
+    SortModeRevision,
+};
 use nanoid::nanoid;
 use serde::{Deserialize, Serialize};
 use serde_repr::*;
+use std::collections::HashMap;
 
 #[allow(dead_code)]
 pub fn gen_grid_view_id() -> String {
@@ -45,6 +49,34 @@ pub struct DatabaseViewRevision {
 
     #[serde(default)]
     pub sorts: SortConfiguration,
+
+    /// The settings that override the fields' own settings in this view. It's None if the view
+    /// was created before the fields could be overridden per view.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field_settings: Option<FieldSettingsRevision>,
+
+    /// The ids of the rows that are pinned to the top of the view, in the order they were pinned.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pinned_row_ids: Vec<String>,
+
+    /// The groups that combine the filters of the view. It's None if the filters were never
+    /// grouped, in which case all the filters are combined with the `And` operator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter_tree: Option<FilterGroupRevision>,
+
+    /// The named filter sets of the view in the order they were first saved.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub filter_sets: Vec<FilterSetRevision>,
+
+    /// The ids of the rows in the order they were placed by hand while the view was sorted. It's
+    /// empty if the rows were never moved while sorted, in which case the rows are in the order of
+    /// their blocks.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub manual_row_order: Vec<String>,
+
+    /// The special order of the rows that replaces the sorts of the fields while it's set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_mode: Option<SortModeRevision>,
 }
 
 impl DatabaseViewRevision {
@@ -56,6 +88,12 @@ impl DatabaseViewRevision {
             filters: Default::default(),
             groups: Default::default(),
             sorts: Default::default(),
+            field_settings: None,
+            pinned_row_ids: vec![],
+            filter_tree: None,
+            filter_sets: vec![],
+            manual_row_order: vec![],
+            sort_mode: None,
         }
     }
 
@@ -64,6 +102,143 @@ impl DatabaseViewRevision {
     }
 }
 
+/// Moves the pinned rows to the front in the order they were pinned. The sort is stable, so the
+/// other rows keep their order.
+pub fn move_pinned_rows_first<T, F>(pinned_row_ids: &[String], rows: &mut [T], get_row_id: F)
+where
+    F: Fn(&T) -> &str,
+{
+    if pinned_row_ids.is_empty() {
+        return;
+    }
+    let index_by_id = pinned_row_ids
+        .iter()
+        .enumerate()
+        .map(|(index, row_id)| (row_id.as_str(), index))
+        .collect::<HashMap<&str, usize>>();
+    rows.sort_by_key(|row| {
+        index_by_id
+            .get(get_row_id(row))
+            .cloned()
+            .unwrap_or(pinned_row_ids.len())
+    });
+}
+
+/// Places the rows in the manual order. The rows that are not in the manual order, for example,
+/// the rows that were created after the order was saved, follow the row that is before them in
+/// their blocks.
+pub fn apply_manual_row_order<T, F>(manual_row_order: &[String], rows: &mut [T], get_row_id: F)
+where
+    F: Fn(&T) -> &str,
+{
+    if manual_row_order.is_empty() {
+        return;
+    }
+    let index_by_id = manual_row_order
+        .iter()
+        .enumerate()
+        .map(|(index, row_id)| (row_id.as_str(), index))
+        .collect::<HashMap<&str, usize>>();
+    let mut previous_index = None;
+    let mut key_by_id = HashMap::new();
+    for row in rows.iter() {
+        let row_id = get_row_id(row);
+        let key = match index_by_id.get(row_id) {
+            Some(index) => {
+                previous_index = Some(*index);
+                (previous_index, false)
+            }
+            None => (previous_index, true),
+        };
+        key_by_id.insert(row_id.to_owned(), key);
+    }
+    rows.sort_by_key(|row| key_by_id.get(get_row_id(row)).cloned());
+}
+
+/// [FieldSettingsRevision] overrides the visibility, the width and the order of the fields in a
+/// view. The fields that are not overridden fall back to their own settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldSettingsRevision {
+    /// The visibility of the fields by their ids.
+    #[serde(default)]
+    pub visibilities: HashMap<String, bool>,
+
+    /// The ids of the fields in the order of the view. It's empty if the view uses the order of
+    /// the database. The fields that are not in it are placed after the ordered fields.
+    #[serde(default)]
+    pub field_order: Vec<String>,
+
+    /// The widths of the fields by their ids.
+    #[serde(default)]
+    pub widths: HashMap<String, i32>,
+
+    /// The number of the leading visible fields that are frozen. The frozen fields are the first
+    /// fields in the order of the view, so moving a field across the boundary changes which fields
+    /// are frozen but keeps the count.
+    #[serde(default)]
+    pub frozen_field_count: usize,
+}
+
+impl FieldSettingsRevision {
+    /// Returns the visibility of the field in the view, or the `default_visibility` if the field
+    /// is not overridden.
+    pub fn is_visible(&self, field_id: &str, default_visibility: bool) -> bool {
+        self.visibilities.get(field_id).cloned().unwrap_or(default_visibility)
+    }
+
+    /// Returns the width of the field in the view, or the `default_width` if the field is not
+    /// overridden.
+    pub fn width(&self, field_id: &str, default_width: i32) -> i32 {
+        self.widths.get(field_id).cloned().unwrap_or(default_width)
+    }
+
+    /// Returns the ids of the fields that have any settings.
+    pub fn field_ids(&self) -> Vec<String> {
+        let mut field_ids = self
+            .visibilities
+            .keys()
+            .chain(self.widths.keys())
+            .chain(self.field_order.iter())
+            .cloned()
+            .collect::<Vec<String>>();
+        field_ids.sort();
+        field_ids.dedup();
+        field_ids
+    }
+
+    /// Removes all the settings of the field. Returns true if the field had any settings.
+    pub fn remove_field(&mut self, field_id: &str) -> bool {
+        let old_len = self.field_order.len();
+        self.field_order.retain(|ordered_field_id| ordered_field_id != field_id);
+        let is_visibility_removed = self.visibilities.remove(field_id).is_some();
+        let is_width_removed = self.widths.remove(field_id).is_some();
+        is_visibility_removed || is_width_removed || old_len != self.field_order.len()
+    }
+
+    /// Sorts the fields in the order of the view.
+    pub fn sort_fields<T, F>(&self, fields: &mut [T], get_field_id: F)
+    where
+        F: Fn(&T) -> &str,
+    {
+        if self.field_order.is_empty() {
+            return;
+        }
+        let index_by_id = self
+            .field_order
+            .iter()
+            .enumerate()
+            .map(|(index, field_id)| (field_id.as_str(), index))
+            .collect::<HashMap<&str, usize>>();
+        // The sort is stable, so the fields that are not ordered keep the order of the database.
+        fields.sort_by_key(|field| {
+            index_by_id
+                .get(get_field_id(field))
+                .cloned()
+                .unwrap_or(self.field_order.len())
+        });
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RowOrderRevision {
     pub row_id: String,
@@ -71,7 +246,7 @@ pub struct RowOrderRevision {
 
 #[cfg(test)]
 mod tests {
-    use crate::DatabaseViewRevision;
+    use crate::{apply_manual_row_order, move_pinned_rows_first, DatabaseViewRevision, FieldSettingsRevision};
 
     #[test]
     fn grid_view_revision_serde_test() {
@@ -82,6 +257,12 @@ mod tests {
             filters: Default::default(),
             groups: Default::default(),
             sorts: Default::default(),
+            field_settings: None,
+            pinned_row_ids: vec![],
+            filter_tree: None,
+            filter_sets: vec![],
+            manual_row_order: vec![],
+            sort_mode: None,
         };
         let s = serde_json::to_string(&grid_view_revision).unwrap();
         assert_eq!(
@@ -89,4 +270,62 @@ mod tests {
             r#"{"view_id":"1","grid_id":"1","layout":0,"filters":[],"groups":[],"sorts":[]}"#
         );
     }
+
+    #[test]
+    fn move_pinned_rows_first_test() {
+        let pinned_row_ids = vec!["d".to_owned(), "b".to_owned(), "x".to_owned()];
+        let mut row_ids = vec!["a", "b", "c", "d", "e"];
+        move_pinned_rows_first(&pinned_row_ids, &mut row_ids, |row_id| *row_id);
+        assert_eq!(row_ids, vec!["d", "b", "a", "c", "e"]);
+    }
+
+    #[test]
+    fn apply_manual_row_order_test() {
+        let manual_row_order = vec!["c".to_owned(), "a".to_owned(), "x".to_owned(), "d".to_owned()];
+        // The `b` is not in the manual order, so it follows the `a`.
+        let mut row_ids = vec!["a", "b", "c", "d", "e"];
+        apply_manual_row_order(&manual_row_order, &mut row_ids, |row_id| *row_id);
+        assert_eq!(row_ids, vec!["c", "a", "b", "d", "e"]);
+
+        let mut row_ids = vec!["e", "d", "c"];
+        apply_manual_row_order(&manual_row_order, &mut row_ids, |row_id| *row_id);
+        assert_eq!(row_ids, vec!["e", "c", "d"]);
+    }
+
+    #[test]
+    fn field_settings_sort_fields_test() {
+        let field_settings = FieldSettingsRevision {
+            field_order: vec!["c".to_owned(), "a".to_owned()],
+            ..Default::default()
+        };
+        let mut field_ids = vec!["a", "b", "c", "d"];
+        field_settings.sort_fields(&mut field_ids, |field_id| *field_id);
+        assert_eq!(field_ids, vec!["c", "a", "b", "d"]);
+    }
+
+    #[test]
+    fn field_settings_remove_field_test() {
+        let mut field_settings = FieldSettingsRevision {
+            field_order: vec!["b".to_owned(), "a".to_owned()],
+            ..Default::default()
+        };
+        field_settings.widths.insert("a".to_owned(), 100);
+        field_settings.visibilities.insert("c".to_owned(), false);
+        assert_eq!(field_settings.field_ids(), vec!["a", "b", "c"]);
+
+        assert!(field_settings.remove_field("a"));
+        assert_eq!(field_settings.width("a", 150), 150);
+        assert_eq!(field_settings.field_order, vec!["b".to_owned()]);
+        assert!(!field_settings.remove_field("a"));
+        assert_eq!(field_settings.field_ids(), vec!["b", "c"]);
+    }
+
+    #[test]
+    fn field_settings_visibility_test() {
+        let mut field_settings = FieldSettingsRevision::default();
+        field_settings.visibilities.insert("a".to_owned(), false);
+        assert!(!field_settings.is_visible("a", true));
+        assert!(field_settings.is_visible("b", true));
+        assert!(!field_settings.is_visible("b", false));
+    }
 }
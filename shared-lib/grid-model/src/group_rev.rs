@@ -48,9 +48,34 @@ impl GroupConfigurationContentSerde for TextGroupConfigurationRevision {
     }
 }
 
-#[derive(Default, Serialize, Deserialize)]
+/// The rows are grouped by the ranges of their numbers. The ranges are between the breakpoints if
+/// there are any, otherwise each range is `width` wide and the ranges are aligned to `start`. Each
+/// range contains its lower bound but not its upper bound, so `10` is in `10–19` instead of `0–9`.
+/// The numbers are stored as decimal strings, so the bounds like `0.1` are exact.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NumberGroupConfigurationRevision {
+    #[serde(default)]
     pub hide_empty: bool,
+    #[serde(default = "DEFAULT_NUMBER_GROUP_START")]
+    pub start: String,
+    #[serde(default = "DEFAULT_NUMBER_GROUP_WIDTH")]
+    pub width: String,
+    #[serde(default)]
+    pub breakpoints: Vec<String>,
+}
+
+const DEFAULT_NUMBER_GROUP_START: fn() -> String = || "0".to_owned();
+const DEFAULT_NUMBER_GROUP_WIDTH: fn() -> String = || "10".to_owned();
+
+impl std::default::Default for NumberGroupConfigurationRevision {
+    fn default() -> Self {
+        Self {
+            hide_empty: false,
+            start: DEFAULT_NUMBER_GROUP_START(),
+            width: DEFAULT_NUMBER_GROUP_WIDTH(),
+            breakpoints: vec![],
+        }
+    }
 }
 
 impl GroupConfigurationContentSerde for NumberGroupConfigurationRevision {
@@ -91,6 +116,36 @@ impl GroupConfigurationContentSerde for CheckboxGroupConfigurationRevision {
     }
 }
 
+#[derive(Default, Serialize, Deserialize)]
+pub struct RatingGroupConfigurationRevision {
+    pub hide_empty: bool,
+}
+
+impl GroupConfigurationContentSerde for RatingGroupConfigurationRevision {
+    fn from_json(s: &str) -> Result<Self, Error> {
+        serde_json::from_str(s)
+    }
+
+    fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string(self)
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct PersonGroupConfigurationRevision {
+    pub hide_empty: bool,
+}
+
+impl GroupConfigurationContentSerde for PersonGroupConfigurationRevision {
+    fn from_json(s: &str) -> Result<Self, Error> {
+        serde_json::from_str(s)
+    }
+
+    fn to_json(&self) -> Result<String, Error> {
+        serde_json::to_string(self)
+    }
+}
+
 #[derive(Default, Serialize, Deserialize)]
 pub struct SelectOptionGroupConfigurationRevision {
     pub hide_empty: bool,
@@ -115,6 +170,11 @@ pub struct GroupRevision {
 
     #[serde(default = "GROUP_REV_VISIBILITY")]
     pub visible: bool,
+
+    /// The rows of the collapsed group are folded on the board. The flag is kept as long as the
+    /// id of the group is not changed.
+    #[serde(default)]
+    pub collapsed: bool,
 }
 
 const GROUP_REV_VISIBILITY: fn() -> bool = || true;
@@ -133,17 +193,23 @@ impl GroupRevision {
             id,
             name: group_name,
             visible: true,
+            collapsed: false,
         }
     }
 
     pub fn update_with_other(&mut self, other: &GroupRevision) {
-        self.visible = other.visible
+        self.visible = other.visible;
+        self.collapsed = other.collapsed;
     }
 }
 
-#[derive(Default, Serialize, Deserialize)]
+/// The rows are grouped by the dates of their cells, the condition decides the range of the dates
+/// in each group.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DateGroupConfigurationRevision {
+    #[serde(default)]
     pub hide_empty: bool,
+    #[serde(default)]
     pub condition: DateCondition,
 }
 
@@ -156,9 +222,10 @@ impl GroupConfigurationContentSerde for DateGroupConfigurationRevision {
     }
 }
 
-#[derive(Serialize_repr, Deserialize_repr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize_repr, Deserialize_repr)]
 #[repr(u8)]
 pub enum DateCondition {
+    /// Groups the dates relative to today, for example: `Today`, `Yesterday` and `This week`.
     Relative = 0,
     Day = 1,
     Week = 2,
@@ -174,7 +241,10 @@ impl std::default::Default for DateCondition {
 
 #[cfg(test)]
 mod tests {
-    use crate::{GroupConfigurationRevision, SelectOptionGroupConfigurationRevision};
+    use crate::{
+        DateCondition, DateGroupConfigurationRevision, GroupConfigurationContentSerde, GroupConfigurationRevision,
+        GroupRevision, NumberGroupConfigurationRevision, SelectOptionGroupConfigurationRevision,
+    };
 
     #[test]
     fn group_configuration_serde_test() {
@@ -186,6 +256,51 @@ mod tests {
         let _content: SelectOptionGroupConfigurationRevision = serde_json::from_str(&rev.content).unwrap();
     }
 
+    #[test]
+    fn date_group_configuration_serde_test() {
+        let content = DateGroupConfigurationRevision {
+            hide_empty: false,
+            condition: DateCondition::Week,
+        };
+        let json = content.to_json().unwrap();
+        let content = DateGroupConfigurationRevision::from_json(&json).unwrap();
+        assert_eq!(content.condition, DateCondition::Week);
+
+        // The content of the other field types is read as the relative date groups.
+        let content = DateGroupConfigurationRevision::from_json(r#"{"hide_empty":false}"#).unwrap();
+        assert_eq!(content.condition, DateCondition::Relative);
+    }
+
+    #[test]
+    fn number_group_configuration_serde_test() {
+        let content = NumberGroupConfigurationRevision {
+            breakpoints: vec!["0".to_owned(), "100".to_owned()],
+            ..Default::default()
+        };
+        let json = content.to_json().unwrap();
+        let content = NumberGroupConfigurationRevision::from_json(&json).unwrap();
+        assert_eq!(content.breakpoints, vec!["0".to_owned(), "100".to_owned()]);
+
+        // The content that was saved before the ranges is read as the default ranges.
+        let content = NumberGroupConfigurationRevision::from_json(r#"{"hide_empty":false}"#).unwrap();
+        assert_eq!(content.start, "0");
+        assert_eq!(content.width, "10");
+        assert!(content.breakpoints.is_empty());
+    }
+
+    #[test]
+    fn group_revision_collapsed_serde_test() {
+        // The group that was saved before the collapsed flag is read as the expanded group.
+        let group_rev: GroupRevision = serde_json::from_str(r#"{"id":"1","visible":true}"#).unwrap();
+        assert!(!group_rev.collapsed);
+
+        let mut group_rev = GroupRevision::new("1".to_owned(), "Done".to_owned());
+        group_rev.collapsed = true;
+        let json = serde_json::to_string(&group_rev).unwrap();
+        let group_rev: GroupRevision = serde_json::from_str(&json).unwrap();
+        assert!(group_rev.collapsed);
+    }
+
     #[test]
     fn group_configuration_serde_test2() {
         let content = SelectOptionGroupConfigurationRevision { hide_empty: false };
@@ -1,13 +1,43 @@
 use crate::FieldTypeRevision;
 use serde::{Deserialize, Serialize};
 use serde_repr::*;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct SortRevision {
     pub id: String,
     pub field_id: String,
     pub field_type: FieldTypeRevision,
     pub condition: SortCondition,
+    /// Compares the numbers in the texts by their values instead of their digits.
+    #[serde(default = "default_natural")]
+    pub natural: bool,
+    /// Sorts the select options by their names instead of their order in the type option.
+    #[serde(default)]
+    pub alphabetical: bool,
+    /// Places the rows whose cells are empty before or after the other rows, whatever the
+    /// condition is.
+    #[serde(default)]
+    pub empty_order: SortEmptyOrder,
+}
+
+fn default_natural() -> bool {
+    true
+}
+
+impl std::default::Default for SortRevision {
+    fn default() -> Self {
+        Self {
+            id: "".to_owned(),
+            field_id: "".to_owned(),
+            field_type: Default::default(),
+            condition: Default::default(),
+            natural: default_natural(),
+            alphabetical: false,
+            empty_order: Default::default(),
+        }
+    }
 }
 
 #[derive(Serialize_repr, Deserialize_repr, PartialEq, Eq, Hash, Clone, Debug)]
@@ -38,3 +68,71 @@ impl std::convert::From<SortCondition> for u8 {
         condition as u8
     }
 }
+
+#[derive(Serialize_repr, Deserialize_repr, PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[repr(u8)]
+pub enum SortEmptyOrder {
+    First = 0,
+    Last = 1,
+}
+
+impl std::convert::From<u8> for SortEmptyOrder {
+    fn from(num: u8) -> Self {
+        match num {
+            0 => SortEmptyOrder::First,
+            _ => SortEmptyOrder::Last,
+        }
+    }
+}
+
+impl std::default::Default for SortEmptyOrder {
+    fn default() -> Self {
+        Self::Last
+    }
+}
+
+/// [SortModeRevision] is the special order of the rows of the view. It's not the sort of a field,
+/// so it's stored beside the sorts instead of among them. The mode is the only rule that orders
+/// the rows while it's set: the sorts of the fields are kept but not used to break its ties, and
+/// they take effect again once the mode is removed.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum SortModeRevision {
+    /// The rows are in the random order that is generated from the seed, so the order doesn't
+    /// change until the rows are shuffled again with another seed.
+    Shuffle { seed: u64 },
+    /// The rows that were modified most recently are the first.
+    LastModified,
+}
+
+/// Generates the random seed of the [SortModeRevision::Shuffle].
+pub fn gen_shuffle_seed() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{SortEmptyOrder, SortModeRevision, SortRevision};
+
+    #[test]
+    fn sort_rev_natural_by_default_test() {
+        let sort_rev: SortRevision =
+            serde_json::from_str(r#"{"id":"s1","field_id":"f1","field_type":0,"condition":1}"#).unwrap();
+        assert!(sort_rev.natural);
+        assert!(!sort_rev.alphabetical);
+        assert_eq!(sort_rev.empty_order, SortEmptyOrder::Last);
+        assert!(SortRevision::default().natural);
+    }
+
+    #[test]
+    fn sort_mode_serde_test() {
+        let sort_mode = SortModeRevision::Shuffle { seed: 7 };
+        let json = serde_json::to_string(&sort_mode).unwrap();
+        assert_eq!(json, r#"{"shuffle":{"seed":7}}"#);
+        assert_eq!(serde_json::from_str::<SortModeRevision>(&json).unwrap(), sort_mode);
+        assert_eq!(
+            serde_json::to_string(&SortModeRevision::LastModified).unwrap(),
+            r#""last_modified""#
+        );
+    }
+}
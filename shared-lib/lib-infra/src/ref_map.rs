@@ -42,6 +42,19 @@ where
         self.0.get(key).map(|handler| handler.inner.clone())
     }
 
+    /// Returns the reference count of the value with the key. Returns 0 if the key doesn't exist.
+    pub fn ref_count(&self, key: &str) -> usize {
+        self.0.get(key).map(|handler| handler.ref_count).unwrap_or(0)
+    }
+
+    /// Returns the reference count of each value.
+    pub fn ref_counts(&self) -> HashMap<String, usize> {
+        self.0
+            .iter()
+            .map(|(key, handler)| (key.clone(), handler.ref_count))
+            .collect()
+    }
+
     pub fn values(&self) -> Vec<T> {
         self.0.values().map(|value| value.inner.clone()).collect::<Vec<T>>()
     }
@@ -55,22 +68,48 @@ where
         }
     }
 
-    pub async fn remove(&mut self, key: &str) {
-        let mut should_remove = false;
+    /// Inserts the value without increasing its reference count, so it doesn't need to be paired
+    /// with a `remove` call. Does nothing if the key already exists.
+    pub fn insert_without_ref(&mut self, key: String, value: T) {
+        self.0.entry(key).or_insert(RefCountHandler {
+            ref_count: 0,
+            inner: value,
+        });
+    }
+
+    /// Removes the value with the key regardless of its reference count. Unlike the `remove`
+    /// method, the `did_remove` of the value won't be called. The caller takes care of the returned value.
+    pub fn take(&mut self, key: &str) -> Option<T> {
+        self.0.remove(key).map(|handler| handler.inner)
+    }
+
+    /// Removes all the values regardless of their reference counts. Same as the `take` method, the
+    /// `did_remove` of the values won't be called.
+    pub fn drain(&mut self) -> Vec<(String, T)> {
+        self.0.drain().map(|(key, handler)| (key, handler.inner)).collect()
+    }
+
+    /// Decreases the reference count of the value with the key. The value will be removed and its
+    /// `did_remove` will be called if the reference count drops to 0.
+    ///
+    /// Returns the remaining reference count. Returns 0 if the key doesn't exist.
+    pub async fn remove(&mut self, key: &str) -> usize {
+        let mut ref_count = 0;
         if let Some(value) = self.0.get_mut(key) {
             if value.ref_count > 0 {
                 value.ref_count -= 1;
             }
-            should_remove = value.ref_count == 0;
+            ref_count = value.ref_count;
         }
 
-        if should_remove {
+        if ref_count == 0 {
             if let Some(handler) = self.0.remove(key) {
                 tokio::spawn(async move {
                     handler.inner.did_remove().await;
                 });
             }
         }
+        ref_count
     }
 }
 